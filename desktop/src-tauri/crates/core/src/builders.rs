@@ -11,7 +11,9 @@
 //! 3. Call `.build()` which validates and returns `CoreResult<Config>`
 //!
 //! Validation happens at build time, catching configuration errors
-//! before they cause runtime failures.
+//! before they cause runtime failures. `.validate()` runs the same checks
+//! without consuming the builder and reports every problem at once, e.g.
+//! `Invalid agent config: max_total_tokens must be > 0 (got 0)`.
 //!
 //! ## Session State Key Prefixes
 //!
@@ -21,11 +23,16 @@
 //! - `temp:` - Temporary state, cleared when the session ends
 //!
 //! This convention prevents key collisions between user data,
-//! application internals, and transient execution state.
+//! application internals, and transient execution state. Within a prefix,
+//! subsystems can further isolate their keys with a namespace
+//! (`app:compaction::last_run`), and entries may carry a TTL after which
+//! they are treated as absent.
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -54,6 +61,9 @@ pub struct SessionStateKey(String);
 /// The valid prefixes for session state keys.
 pub const SESSION_KEY_PREFIXES: [&str; 3] = ["user:", "app:", "temp:"];
 
+/// Separator between a subsystem namespace and the key name.
+pub const SESSION_KEY_NAMESPACE_SEPARATOR: &str = "::";
+
 impl SessionStateKey {
     /// Validate and create a session state key.
     ///
@@ -114,11 +124,62 @@ impl SessionStateKey {
         Ok(Self(format!("temp:{}", name)))
     }
 
+    /// Create a key isolated to a subsystem namespace, e.g.
+    /// `SessionStateKey::namespaced("app:", "compaction", "last_run")` produces
+    /// `app:compaction::last_run`.
+    ///
+    /// Namespaces may contain ASCII letters, digits, `_`, `-` and `.`.
+    pub fn namespaced(
+        prefix: &str,
+        namespace: impl AsRef<str>,
+        name: impl AsRef<str>,
+    ) -> CoreResult<Self> {
+        let (namespace, name) = (namespace.as_ref(), name.as_ref());
+        if !SESSION_KEY_PREFIXES.contains(&prefix) {
+            return Err(CoreError::validation(format!(
+                "Session state key must start with 'user:', 'app:', or 'temp:'. Got: '{}'",
+                prefix
+            )));
+        }
+        let valid_namespace = !namespace.is_empty()
+            && namespace
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+        if !valid_namespace {
+            return Err(CoreError::validation(format!(
+                "Invalid session state namespace: '{}'",
+                namespace
+            )));
+        }
+        if name.is_empty() {
+            return Err(CoreError::validation("Key name cannot be empty"));
+        }
+        Ok(Self(format!(
+            "{}{}{}{}",
+            prefix, namespace, SESSION_KEY_NAMESPACE_SEPARATOR, name
+        )))
+    }
+
     /// Get the key string.
     pub fn as_str(&self) -> &str {
         &self.0
     }
 
+    /// Get the subsystem namespace, if this key was namespaced.
+    pub fn namespace(&self) -> Option<&str> {
+        self.name()
+            .split_once(SESSION_KEY_NAMESPACE_SEPARATOR)
+            .map(|(namespace, _)| namespace)
+    }
+
+    /// Get the key name without prefix or namespace.
+    pub fn local_name(&self) -> &str {
+        let name = self.name();
+        name.split_once(SESSION_KEY_NAMESPACE_SEPARATOR)
+            .map(|(_, local)| local)
+            .unwrap_or(name)
+    }
+
     /// Get the prefix of this key.
     pub fn prefix(&self) -> &str {
         if self.0.starts_with("user:") {
@@ -162,10 +223,26 @@ impl std::fmt::Display for SessionStateKey {
 // SessionState - validated key-value store
 // ============================================================================
 
+/// Stored value plus its optional expiry time.
+#[derive(Debug, Clone)]
+struct SessionStateEntry {
+    value: Value,
+    expires_at: Option<Instant>,
+}
+
+impl SessionStateEntry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|at| now >= at)
+    }
+}
+
 /// A validated session state store that enforces key prefixes.
+///
+/// Entries set with a TTL expire lazily: once expired they are invisible to
+/// reads and are dropped on the next write or `purge_expired()`.
 #[derive(Debug, Clone, Default)]
 pub struct SessionState {
-    entries: HashMap<SessionStateKey, Value>,
+    entries: HashMap<SessionStateKey, SessionStateEntry>,
 }
 
 impl SessionState {
@@ -178,17 +255,73 @@ impl SessionState {
 
     /// Set a value with a validated key.
     pub fn set(&mut self, key: SessionStateKey, value: Value) {
-        self.entries.insert(key, value);
+        self.insert(key, value, None);
+    }
+
+    /// Set a value that expires after `ttl`.
+    pub fn set_with_ttl(&mut self, key: SessionStateKey, value: Value, ttl: Duration) {
+        self.insert(key, value, Some(Instant::now() + ttl));
+    }
+
+    fn insert(&mut self, key: SessionStateKey, value: Value, expires_at: Option<Instant>) {
+        self.purge_expired();
+        self.entries
+            .insert(key, SessionStateEntry { value, expires_at });
     }
 
-    /// Get a value by key.
+    /// Get a value by key. Expired entries are reported as absent.
     pub fn get(&self, key: &SessionStateKey) -> Option<&Value> {
-        self.entries.get(key)
+        let now = Instant::now();
+        self.entries
+            .get(key)
+            .filter(|entry| !entry.is_expired(now))
+            .map(|entry| &entry.value)
     }
 
-    /// Remove a value by key.
+    /// Serialize `value` and store it under `key`.
+    pub fn set_typed<T: Serialize>(&mut self, key: SessionStateKey, value: &T) -> CoreResult<()> {
+        let value = serde_json::to_value(value)?;
+        self.set(key, value);
+        Ok(())
+    }
+
+    /// Serialize `value` and store it under `key` with a TTL.
+    pub fn set_typed_with_ttl<T: Serialize>(
+        &mut self,
+        key: SessionStateKey,
+        value: &T,
+        ttl: Duration,
+    ) -> CoreResult<()> {
+        let value = serde_json::to_value(value)?;
+        self.set_with_ttl(key, value, ttl);
+        Ok(())
+    }
+
+    /// Get a value by key, deserialized as `T`.
+    ///
+    /// Returns `Ok(None)` if the key is absent or expired, and a validation
+    /// error if the stored value does not deserialize as `T`.
+    pub fn get_typed<T: DeserializeOwned>(&self, key: &SessionStateKey) -> CoreResult<Option<T>> {
+        match self.get(key) {
+            None => Ok(None),
+            Some(value) => T::deserialize(value).map(Some).map_err(|e| {
+                CoreError::validation(format!(
+                    "Session state key '{}' does not hold a {}: {}",
+                    key,
+                    std::any::type_name::<T>(),
+                    e
+                ))
+            }),
+        }
+    }
+
+    /// Remove a value by key. Expired entries are removed but not returned.
     pub fn remove(&mut self, key: &SessionStateKey) -> Option<Value> {
-        self.entries.remove(key)
+        let now = Instant::now();
+        self.entries
+            .remove(key)
+            .filter(|entry| !entry.is_expired(now))
+            .map(|entry| entry.value)
     }
 
     /// Remove all temporary keys.
@@ -196,22 +329,87 @@ impl SessionState {
         self.entries.retain(|k, _| !k.is_temp());
     }
 
-    /// Get all keys with a given prefix.
+    /// Remove every key belonging to `namespace` (across all prefixes).
+    pub fn clear_namespace(&mut self, namespace: &str) {
+        self.entries.retain(|k, _| k.namespace() != Some(namespace));
+    }
+
+    /// Drop expired entries, returning how many were removed.
+    pub fn purge_expired(&mut self) -> usize {
+        let now = Instant::now();
+        let before = self.entries.len();
+        self.entries.retain(|_, entry| !entry.is_expired(now));
+        before - self.entries.len()
+    }
+
+    /// Get all live keys with a given prefix.
     pub fn keys_with_prefix(&self, prefix: &str) -> Vec<&SessionStateKey> {
-        self.entries
-            .keys()
+        self.live_keys()
             .filter(|k| k.as_str().starts_with(prefix))
             .collect()
     }
 
-    /// Number of entries.
+    /// Get all live keys in a subsystem namespace.
+    pub fn keys_in_namespace(&self, namespace: &str) -> Vec<&SessionStateKey> {
+        self.live_keys()
+            .filter(|k| k.namespace() == Some(namespace))
+            .collect()
+    }
+
+    fn live_keys(&self) -> impl Iterator<Item = &SessionStateKey> {
+        let now = Instant::now();
+        self.entries
+            .iter()
+            .filter(move |(_, entry)| !entry.is_expired(now))
+            .map(|(key, _)| key)
+    }
+
+    /// Number of live (unexpired) entries.
     pub fn len(&self) -> usize {
-        self.entries.len()
+        self.live_keys().count()
     }
 
-    /// Whether the store is empty.
+    /// Whether the store has no live entries.
     pub fn is_empty(&self) -> bool {
-        self.entries.is_empty()
+        self.len() == 0
+    }
+}
+
+// ============================================================================
+// Builder Validation Helpers
+// ============================================================================
+
+const DEFAULT_MAX_TOTAL_TOKENS: u32 = 1_000_000;
+const MAX_SOFT_LIMIT_OVERRIDE: u32 = 10_000;
+const DEFAULT_GATE_TIMEOUT_SECS: u64 = 300;
+
+fn check_soft_limit_override(value: Option<u32>, issues: &mut Vec<String>) {
+    if let Some(n) = value {
+        if n == 0 || n > MAX_SOFT_LIMIT_OVERRIDE {
+            issues.push(format!(
+                "soft_limit_override must be between 1 and {} (got {})",
+                MAX_SOFT_LIMIT_OVERRIDE, n
+            ));
+        }
+    }
+}
+
+fn check_max_total_tokens(value: Option<u32>, issues: &mut Vec<String>) {
+    if value == Some(0) {
+        issues.push("max_total_tokens must be > 0 (got 0)".to_string());
+    }
+}
+
+/// Fold collected issues into a single `CoreError::Validation`.
+fn validation_result(config_name: &str, issues: Vec<String>) -> CoreResult<()> {
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(CoreError::validation(format!(
+            "Invalid {}: {}",
+            config_name,
+            issues.join("; ")
+        )))
     }
 }
 
@@ -286,37 +484,33 @@ impl AgentConfigBuilder {
         self
     }
 
-    /// Build and validate the configuration.
-    pub fn build(self) -> CoreResult<BuiltAgentConfig> {
-        let max_total_tokens = self.max_total_tokens.unwrap_or(1_000_000);
-        let streaming = self.streaming.unwrap_or(true);
-        let enable_compaction = self.enable_compaction.unwrap_or(true);
-
-        // Validation
-        if let Some(soft_limit_override) = self.soft_limit_override {
-            if soft_limit_override == 0 {
-                return Err(CoreError::validation("soft_limit_override must be > 0"));
-            }
-            if soft_limit_override > 10_000 {
-                return Err(CoreError::validation("soft_limit_override must be <= 10000"));
-            }
-        }
-        if max_total_tokens == 0 {
-            return Err(CoreError::validation("max_total_tokens must be > 0"));
-        }
+    /// Check the configuration without building it.
+    ///
+    /// Returns a single validation error listing every invalid field.
+    pub fn validate(&self) -> CoreResult<()> {
+        let mut issues = Vec::new();
+        check_soft_limit_override(self.soft_limit_override, &mut issues);
+        check_max_total_tokens(self.max_total_tokens, &mut issues);
         if let Some(t) = self.temperature {
             if !(0.0..=2.0).contains(&t) {
-                return Err(CoreError::validation(
-                    "temperature must be between 0.0 and 2.0",
+                issues.push(format!(
+                    "temperature must be between 0.0 and 2.0 (got {})",
+                    t
                 ));
             }
         }
+        validation_result("agent config", issues)
+    }
+
+    /// Build and validate the configuration.
+    pub fn build(self) -> CoreResult<BuiltAgentConfig> {
+        self.validate()?;
 
         Ok(BuiltAgentConfig {
             soft_limit_override: self.soft_limit_override,
-            max_total_tokens,
-            streaming,
-            enable_compaction,
+            max_total_tokens: self.max_total_tokens.unwrap_or(DEFAULT_MAX_TOTAL_TOKENS),
+            streaming: self.streaming.unwrap_or(true),
+            enable_compaction: self.enable_compaction.unwrap_or(true),
             temperature: self.temperature,
         })
     }
@@ -375,13 +569,13 @@ impl ExecutionConfigBuilder {
         self
     }
 
-    /// Set an optional soft iteration limit override.
+    /// Set an optional soft iteration limit override (must be > 0 and <= 10000).
     pub fn soft_limit_override(mut self, n: u32) -> Self {
         self.soft_limit_override = Some(n);
         self
     }
 
-    /// Set maximum total tokens.
+    /// Set maximum total tokens (must be > 0).
     pub fn max_total_tokens(mut self, n: u32) -> Self {
         self.max_total_tokens = Some(n);
         self
@@ -393,32 +587,39 @@ impl ExecutionConfigBuilder {
         self
     }
 
-    /// Build and validate the configuration.
-    pub fn build(self) -> CoreResult<BuiltExecutionConfig> {
-        let session_id = self
-            .session_id
-            .ok_or_else(|| CoreError::validation("session_id is required"))?;
-        let project_root = self
-            .project_root
-            .ok_or_else(|| CoreError::validation("project_root is required"))?;
-
-        if session_id.is_empty() {
-            return Err(CoreError::validation("session_id cannot be empty"));
+    /// Check the configuration without building it.
+    ///
+    /// Returns a single validation error listing every missing or invalid field.
+    pub fn validate(&self) -> CoreResult<()> {
+        let mut issues = Vec::new();
+        match self.session_id.as_deref() {
+            None => issues.push("session_id is required".to_string()),
+            Some(id) if id.trim().is_empty() => {
+                issues.push("session_id cannot be empty".to_string())
+            }
+            Some(_) => {}
         }
-
-        let max_total_tokens = self.max_total_tokens.unwrap_or(1_000_000);
-
-        if let Some(soft_limit_override) = self.soft_limit_override {
-            if soft_limit_override == 0 {
-                return Err(CoreError::validation("soft_limit_override must be > 0"));
+        match self.project_root.as_deref() {
+            None => issues.push("project_root is required".to_string()),
+            Some(root) if root.as_os_str().is_empty() => {
+                issues.push("project_root cannot be empty".to_string())
             }
+            Some(_) => {}
         }
+        check_soft_limit_override(self.soft_limit_override, &mut issues);
+        check_max_total_tokens(self.max_total_tokens, &mut issues);
+        validation_result("execution config", issues)
+    }
+
+    /// Build and validate the configuration.
+    pub fn build(self) -> CoreResult<BuiltExecutionConfig> {
+        self.validate()?;
 
         Ok(BuiltExecutionConfig {
-            session_id,
-            project_root,
+            session_id: self.session_id.unwrap_or_default(),
+            project_root: self.project_root.unwrap_or_default(),
             soft_limit_override: self.soft_limit_override,
-            max_total_tokens,
+            max_total_tokens: self.max_total_tokens.unwrap_or(DEFAULT_MAX_TOTAL_TOKENS),
             enable_compaction: self.enable_compaction.unwrap_or(true),
         })
     }
@@ -485,34 +686,38 @@ impl QualityGateConfigBuilder {
         self
     }
 
-    /// Build and validate the configuration.
-    pub fn build(self) -> CoreResult<BuiltQualityGateConfig> {
+    /// Check the configuration without building it.
+    ///
+    /// Returns a single validation error listing every invalid field.
+    pub fn validate(&self) -> CoreResult<()> {
+        let mut issues = Vec::new();
         if self.gates.is_empty() {
-            return Err(CoreError::validation(
-                "At least one quality gate must be specified",
-            ));
+            issues.push("At least one quality gate must be specified".to_string());
         }
 
-        // Check for duplicate gate names
         let mut seen = std::collections::HashSet::new();
-        for gate in &self.gates {
-            if !seen.insert(gate.as_str()) {
-                return Err(CoreError::validation(format!(
-                    "Duplicate quality gate: '{}'",
-                    gate
-                )));
+        for (index, gate) in self.gates.iter().enumerate() {
+            if gate.trim().is_empty() {
+                issues.push(format!("quality gate #{} has an empty name", index + 1));
+            } else if !seen.insert(gate.as_str()) {
+                issues.push(format!("Duplicate quality gate: '{}'", gate));
             }
         }
 
-        let timeout = self.timeout_secs.unwrap_or(300);
-        if timeout == 0 {
-            return Err(CoreError::validation("timeout_secs must be > 0"));
+        if self.timeout_secs == Some(0) {
+            issues.push("timeout_secs must be > 0 (got 0)".to_string());
         }
+        validation_result("quality gate config", issues)
+    }
+
+    /// Build and validate the configuration.
+    pub fn build(self) -> CoreResult<BuiltQualityGateConfig> {
+        self.validate()?;
 
         Ok(BuiltQualityGateConfig {
             gates: self.gates,
             fail_fast: self.fail_fast.unwrap_or(false),
-            timeout_secs: timeout,
+            timeout_secs: self.timeout_secs.unwrap_or(DEFAULT_GATE_TIMEOUT_SECS),
         })
     }
 }
@@ -685,6 +890,99 @@ mod tests {
         assert_eq!(temp_keys.len(), 1);
     }
 
+    // -- Namespaces, typed access, and TTL --
+
+    #[test]
+    fn test_session_state_key_namespaced() {
+        let key = SessionStateKey::namespaced("app:", "compaction", "last_run").unwrap();
+        assert_eq!(key.as_str(), "app:compaction::last_run");
+        assert_eq!(key.namespace(), Some("compaction"));
+        assert_eq!(key.local_name(), "last_run");
+        assert!(key.is_app());
+
+        let plain = SessionStateKey::app("nested:key").unwrap();
+        assert_eq!(plain.namespace(), None);
+        assert_eq!(plain.local_name(), "nested:key");
+
+        assert!(SessionStateKey::namespaced("bogus:", "ns", "x").is_err());
+        assert!(SessionStateKey::namespaced("app:", "", "x").is_err());
+        assert!(SessionStateKey::namespaced("app:", "bad ns", "x").is_err());
+        assert!(SessionStateKey::namespaced("app:", "ns", "").is_err());
+    }
+
+    #[test]
+    fn test_session_state_namespace_isolation() {
+        let mut state = SessionState::new();
+        let memory_key = SessionStateKey::namespaced("app:", "memory", "cursor").unwrap();
+        let index_key = SessionStateKey::namespaced("app:", "index", "cursor").unwrap();
+
+        state.set(memory_key.clone(), Value::from(1));
+        state.set(index_key.clone(), Value::from(2));
+        assert_eq!(state.get(&memory_key), Some(&Value::from(1)));
+        assert_eq!(state.get(&index_key), Some(&Value::from(2)));
+        assert_eq!(state.keys_in_namespace("memory"), vec![&memory_key]);
+
+        state.clear_namespace("memory");
+        assert!(state.get(&memory_key).is_none());
+        assert_eq!(state.get(&index_key), Some(&Value::from(2)));
+    }
+
+    #[test]
+    fn test_session_state_typed_round_trip() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Cursor {
+            offset: u64,
+            file: String,
+        }
+
+        let mut state = SessionState::new();
+        let key = SessionStateKey::namespaced("app:", "index", "cursor").unwrap();
+        let cursor = Cursor {
+            offset: 42,
+            file: "src/main.rs".to_string(),
+        };
+        state.set_typed(key.clone(), &cursor).unwrap();
+        assert_eq!(state.get_typed::<Cursor>(&key).unwrap(), Some(cursor));
+
+        let missing = SessionStateKey::app("missing").unwrap();
+        assert_eq!(state.get_typed::<Cursor>(&missing).unwrap(), None);
+    }
+
+    #[test]
+    fn test_session_state_typed_mismatch_is_error() {
+        let mut state = SessionState::new();
+        let key = SessionStateKey::user("count").unwrap();
+        state.set_typed(key.clone(), &"not a number").unwrap();
+
+        let err = state.get_typed::<u32>(&key).unwrap_err();
+        assert!(matches!(err, CoreError::Validation(_)));
+        assert!(err.to_string().contains("user:count"));
+        assert!(err.to_string().contains("u32"));
+    }
+
+    #[test]
+    fn test_session_state_ttl_expiry() {
+        let mut state = SessionState::new();
+        let short = SessionStateKey::temp("short").unwrap();
+        let long = SessionStateKey::temp("long").unwrap();
+        state.set_with_ttl(short.clone(), Value::Bool(true), Duration::from_millis(20));
+        state
+            .set_typed_with_ttl(long.clone(), &7u32, Duration::from_secs(60))
+            .unwrap();
+        assert_eq!(state.get(&short), Some(&Value::Bool(true)));
+        assert_eq!(state.len(), 2);
+
+        std::thread::sleep(Duration::from_millis(40));
+        assert!(state.get(&short).is_none());
+        assert_eq!(state.get_typed::<u32>(&long).unwrap(), Some(7));
+        assert_eq!(state.len(), 1);
+        assert!(state.remove(&short).is_none());
+
+        state.set_with_ttl(short.clone(), Value::Null, Duration::ZERO);
+        assert_eq!(state.purge_expired(), 1);
+        assert_eq!(state.keys_with_prefix("temp:"), vec![&long]);
+    }
+
     // -- AgentConfigBuilder tests --
 
     #[test]
@@ -745,6 +1043,36 @@ mod tests {
         assert!(AgentConfigBuilder::new().temperature(2.0).build().is_ok());
     }
 
+    #[test]
+    fn test_agent_config_builder_zero_iteration_cap_error_is_descriptive() {
+        let err = AgentConfigBuilder::new()
+            .soft_limit_override(0)
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, CoreError::Validation(_)));
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Invalid agent config: soft_limit_override must be between 1 and 10000 (got 0)"
+        );
+    }
+
+    #[test]
+    fn test_agent_config_builder_validate_reports_all_issues() {
+        let builder = AgentConfigBuilder::new()
+            .soft_limit_override(20_000)
+            .max_total_tokens(0)
+            .temperature(3.0);
+        let msg = builder.validate().unwrap_err().to_string();
+        assert!(msg.contains("soft_limit_override must be between 1 and 10000 (got 20000)"));
+        assert!(msg.contains("max_total_tokens must be > 0 (got 0)"));
+        assert!(msg.contains("temperature must be between 0.0 and 2.0 (got 3)"));
+
+        // validate() borrows, so a valid builder can still be built afterwards
+        let builder = AgentConfigBuilder::new().soft_limit_override(50);
+        assert!(builder.validate().is_ok());
+        assert_eq!(builder.build().unwrap().soft_limit_override, Some(50));
+    }
+
     // -- ExecutionConfigBuilder tests --
 
     #[test]
@@ -811,6 +1139,32 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_execution_config_builder_validate_lists_missing_fields() {
+        let err = ExecutionConfigBuilder::new()
+            .soft_limit_override(0)
+            .validate()
+            .unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.starts_with("Validation error: Invalid execution config: "));
+        assert!(msg.contains("session_id is required"));
+        assert!(msg.contains("project_root is required"));
+        assert!(msg.contains("soft_limit_override must be between 1 and 10000 (got 0)"));
+    }
+
+    #[test]
+    fn test_execution_config_builder_rejects_blank_session_and_zero_tokens() {
+        let msg = ExecutionConfigBuilder::new()
+            .session_id("   ")
+            .project_root("/proj")
+            .max_total_tokens(0)
+            .build()
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("session_id cannot be empty"));
+        assert!(msg.contains("max_total_tokens must be > 0 (got 0)"));
+    }
+
     // -- QualityGateConfigBuilder tests --
 
     #[test]
@@ -879,6 +1233,18 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_quality_gate_config_builder_rejects_blank_gate_name() {
+        let msg = QualityGateConfigBuilder::new()
+            .gate("lint")
+            .gate("  ")
+            .validate()
+            .unwrap_err()
+            .to_string();
+        assert!(msg.contains("Invalid quality gate config"));
+        assert!(msg.contains("quality gate #2 has an empty name"));
+    }
+
     // -- BuiltAgentConfig serialization tests --
 
     #[test]
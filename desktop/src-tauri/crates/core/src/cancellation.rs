@@ -0,0 +1,346 @@
+//! Cancellation and Deadlines
+//!
+//! Cancellation token shared by the context hierarchy, built on
+//! `tokio_util::sync::CancellationToken`.
+//!
+//! - Tokens form a tree: cancelling a parent cancels every child created from it
+//! - A deadline bounds a token and all of its children (children may only
+//!   tighten the deadline, never extend it)
+//! - The underlying `tokio_util` token is exposed, so the application layer
+//!   hands the same token to legacy tool contexts and provider calls instead
+//!   of bridging between two token types
+
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::error::{CoreError, CoreResult};
+
+/// Why a token was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelReason {
+    /// `cancel()` was called on this token or one of its ancestors.
+    Cancelled,
+    /// The token's deadline (or an ancestor's) elapsed.
+    DeadlineExceeded,
+}
+
+impl CancelReason {
+    fn into_error(self) -> CoreError {
+        match self {
+            CancelReason::Cancelled => CoreError::cancelled("execution was cancelled"),
+            CancelReason::DeadlineExceeded => CoreError::cancelled("execution deadline exceeded"),
+        }
+    }
+}
+
+/// Hierarchical cancellation token with an optional deadline.
+///
+/// Cloning a token yields a handle to the same token; use `child_token()` to
+/// create a token that is cancelled with (but does not cancel) its parent.
+#[derive(Clone)]
+pub struct CancellationToken {
+    token: tokio_util::sync::CancellationToken,
+    deadline: Option<Instant>,
+    /// Reason slots for this token followed by its ancestors, nearest first.
+    reasons: Vec<Arc<OnceLock<CancelReason>>>,
+}
+
+impl CancellationToken {
+    /// Create a root token without a deadline.
+    pub fn new() -> Self {
+        Self::from_tokio(tokio_util::sync::CancellationToken::new())
+    }
+
+    /// Create a root token that cancels itself at `deadline`.
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Self::new().child_with_deadline(deadline)
+    }
+
+    /// Create a root token that cancels itself after `timeout`.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self::with_deadline(Instant::now() + timeout)
+    }
+
+    /// Wrap an existing `tokio_util` token. Cancelling either handle cancels
+    /// both; pass `token.child_token()` to only follow the caller's token.
+    pub fn from_tokio(token: tokio_util::sync::CancellationToken) -> Self {
+        Self {
+            token,
+            deadline: None,
+            reasons: vec![Arc::new(OnceLock::new())],
+        }
+    }
+
+    /// A `tokio_util` token that is cancelled with this token, for code that
+    /// selects on `tokio_util` tokens directly. Cancelling it does not cancel
+    /// this token.
+    ///
+    /// The returned token observes this token's deadline only while
+    /// something awaits [`CancellationToken::cancelled`] on this token or one
+    /// of its descendants.
+    pub fn tokio_token(&self) -> tokio_util::sync::CancellationToken {
+        self.token.child_token()
+    }
+
+    /// Create a child token inheriting this token's deadline.
+    pub fn child_token(&self) -> Self {
+        self.child_with_optional_deadline(None)
+    }
+
+    /// Create a child token whose deadline is the earlier of `deadline` and
+    /// this token's deadline.
+    pub fn child_with_deadline(&self, deadline: Instant) -> Self {
+        self.child_with_optional_deadline(Some(deadline))
+    }
+
+    fn child_with_optional_deadline(&self, deadline: Option<Instant>) -> Self {
+        let deadline = match (self.deadline, deadline) {
+            (Some(parent), Some(own)) => Some(parent.min(own)),
+            (parent, own) => parent.or(own),
+        };
+        let mut reasons = Vec::with_capacity(self.reasons.len() + 1);
+        reasons.push(Arc::new(OnceLock::new()));
+        reasons.extend(self.reasons.iter().cloned());
+        Self {
+            token: self.token.child_token(),
+            deadline,
+            reasons,
+        }
+    }
+
+    /// Cancel this token and every token derived from it.
+    pub fn cancel(&self) {
+        self.cancel_with(CancelReason::Cancelled);
+    }
+
+    fn cancel_with(&self, reason: CancelReason) {
+        let _ = self.reasons[0].set(reason);
+        self.token.cancel();
+    }
+
+    /// The reason recorded by this token or its nearest cancelled ancestor.
+    fn recorded_reason(&self) -> CancelReason {
+        self.reasons
+            .iter()
+            .find_map(|r| r.get().copied())
+            .unwrap_or(CancelReason::Cancelled)
+    }
+
+    /// The reason this token was cancelled, or `None` if it is still live.
+    ///
+    /// An elapsed deadline is detected here even if no waiter is registered.
+    pub fn reason(&self) -> Option<CancelReason> {
+        if self.token.is_cancelled() {
+            return Some(self.recorded_reason());
+        }
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => {
+                self.cancel_with(CancelReason::DeadlineExceeded);
+                Some(self.recorded_reason())
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether this token (or an ancestor) has been cancelled or timed out.
+    pub fn is_cancelled(&self) -> bool {
+        self.reason().is_some()
+    }
+
+    /// The effective deadline of this token, if any.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Time left before the deadline, if any (zero once it has elapsed).
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|d| d.saturating_duration_since(Instant::now()))
+    }
+
+    /// Return an error if the token has been cancelled.
+    pub fn check(&self) -> CoreResult<()> {
+        match self.reason() {
+            None => Ok(()),
+            Some(reason) => Err(reason.into_error()),
+        }
+    }
+
+    /// Resolves once the token is cancelled or its deadline elapses.
+    pub async fn cancelled(&self) -> CancelReason {
+        if let Some(reason) = self.reason() {
+            return reason;
+        }
+        match self.deadline {
+            Some(deadline) => {
+                tokio::select! {
+                    _ = self.token.cancelled() => {}
+                    _ = tokio::time::sleep_until(deadline.into()) => {
+                        self.cancel_with(CancelReason::DeadlineExceeded);
+                    }
+                }
+            }
+            None => self.token.cancelled().await,
+        }
+        self.recorded_reason()
+    }
+
+    /// Run `fut` until it completes or this token is cancelled, whichever
+    /// happens first. The future is dropped on cancellation.
+    pub async fn run_until_cancelled<F: Future>(&self, fut: F) -> CoreResult<F::Output> {
+        tokio::select! {
+            biased;
+            reason = self.cancelled() => Err(reason.into_error()),
+            output = fut => Ok(output),
+        }
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("reason", &self.reason())
+            .field("deadline", &self.deadline)
+            .finish()
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_token_is_live() {
+        let token = CancellationToken::new();
+        assert!(!token.is_cancelled());
+        assert!(token.check().is_ok());
+        assert_eq!(token.deadline(), None);
+    }
+
+    #[test]
+    fn test_cancel_propagates_to_descendants_only() {
+        let root = CancellationToken::new();
+        let child = root.child_token();
+        let grandchild = child.child_token();
+        let sibling_root = CancellationToken::new();
+
+        child.cancel();
+        assert!(!root.is_cancelled());
+        assert!(child.is_cancelled());
+        assert!(grandchild.is_cancelled());
+
+        root.cancel();
+        assert!(root.is_cancelled());
+        assert!(!sibling_root.is_cancelled());
+    }
+
+    #[test]
+    fn test_child_of_cancelled_parent_starts_cancelled() {
+        let root = CancellationToken::new();
+        root.cancel();
+        let child = root.child_token();
+        assert_eq!(child.reason(), Some(CancelReason::Cancelled));
+    }
+
+    #[test]
+    fn test_child_deadline_never_exceeds_parent() {
+        let now = Instant::now();
+        let root = CancellationToken::with_deadline(now + Duration::from_secs(10));
+        let later = root.child_with_deadline(now + Duration::from_secs(60));
+        let sooner = root.child_with_deadline(now + Duration::from_secs(1));
+        assert_eq!(later.deadline(), root.deadline());
+        assert_eq!(sooner.deadline(), Some(now + Duration::from_secs(1)));
+        assert_eq!(root.child_token().deadline(), root.deadline());
+    }
+
+    #[test]
+    fn test_elapsed_deadline_reports_deadline_exceeded() {
+        let root = CancellationToken::with_deadline(Instant::now());
+        let child = root.child_token();
+        assert_eq!(root.reason(), Some(CancelReason::DeadlineExceeded));
+        assert_eq!(child.reason(), Some(CancelReason::DeadlineExceeded));
+        let err = root.check().unwrap_err();
+        assert!(err.to_string().contains("deadline exceeded"));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_future_wakes_on_cancel() {
+        let root = CancellationToken::new();
+        let child = root.child_token();
+        let waiter = tokio::spawn(async move { child.cancelled().await });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        root.cancel();
+        let reason = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter should wake")
+            .unwrap();
+        assert_eq!(reason, CancelReason::Cancelled);
+    }
+
+    #[tokio::test]
+    async fn test_deadline_triggers_cancellation_of_waiters() {
+        let root = CancellationToken::with_timeout(Duration::from_millis(30));
+        let child = root.child_token();
+
+        let reason = tokio::time::timeout(Duration::from_secs(2), child.cancelled())
+            .await
+            .expect("deadline should fire");
+        assert_eq!(reason, CancelReason::DeadlineExceeded);
+        assert!(root.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_run_until_cancelled_returns_output_when_live() {
+        let token = CancellationToken::new();
+        let value = token.run_until_cancelled(async { 7 }).await.unwrap();
+        assert_eq!(value, 7);
+    }
+
+    #[tokio::test]
+    async fn test_run_until_cancelled_aborts_pending_future() {
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            canceller.cancel();
+        });
+
+        let result = token
+            .run_until_cancelled(std::future::pending::<()>())
+            .await;
+        assert!(matches!(result, Err(CoreError::Cancelled(_))));
+    }
+
+    #[tokio::test]
+    async fn test_tokio_token_follows_without_cancelling_parent() {
+        let root = CancellationToken::new();
+        let bridged = root.tokio_token();
+        bridged.cancel();
+        assert!(!root.is_cancelled());
+
+        let bridged = root.tokio_token();
+        root.cancel();
+        assert!(bridged.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_from_tokio_follows_external_token() {
+        let external = tokio_util::sync::CancellationToken::new();
+        let token = CancellationToken::from_tokio(external.child_token());
+        let child = token.child_token();
+        external.cancel();
+        assert_eq!(child.cancelled().await, CancelReason::Cancelled);
+    }
+}
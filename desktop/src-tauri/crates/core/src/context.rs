@@ -10,13 +10,19 @@
 //! - Tools only see `ToolContext` (no session mutation, no execution control)
 //! - Orchestrators see `OrchestratorContext` (full session control)
 //! - Both share the immutable `ExecutionContext` base
+//!
+//! Every context carries a [`CancellationToken`]. Contexts derived from an
+//! orchestrator context (tools, sub-agents) receive child tokens, so cancelling
+//! or timing out a parent cancels everything beneath it.
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 use serde_json::Value;
 
+use crate::cancellation::CancellationToken;
 use crate::error::{CoreError, CoreResult};
 
 // ============================================================================
@@ -47,6 +53,21 @@ pub trait ExecutionContext: Send + Sync {
     fn execution_tag(&self) -> Option<&str> {
         None
     }
+
+    /// Returns the cancellation token for this execution scope, if any.
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        None
+    }
+
+    /// Whether this execution scope has been cancelled or its deadline elapsed.
+    fn is_cancelled(&self) -> bool {
+        self.cancellation().is_some_and(|t| t.is_cancelled())
+    }
+
+    /// Returns the deadline bounding this execution scope, if any.
+    fn deadline(&self) -> Option<Instant> {
+        self.cancellation().and_then(|t| t.deadline())
+    }
 }
 
 // ============================================================================
@@ -71,6 +92,8 @@ pub struct ToolContext {
     /// Shared read-only memory store for semantic search across the session.
     /// Tools can read from memory but not write to it.
     memory_store: Arc<RwLock<HashMap<String, Value>>>,
+    /// Cancellation token; a child of the orchestrator's token when derived from one.
+    cancellation: CancellationToken,
 }
 
 impl ToolContext {
@@ -88,6 +111,7 @@ impl ToolContext {
             execution_tag: None,
             tool_call_id: tool_call_id.into(),
             memory_store: Arc::new(RwLock::new(HashMap::new())),
+            cancellation: CancellationToken::new(),
         }
     }
 
@@ -103,11 +127,29 @@ impl ToolContext {
         self
     }
 
+    /// Set the cancellation token (e.g., bridged from the caller's token).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
     /// Returns the unique tool call identifier.
     pub fn tool_call_id(&self) -> &str {
         &self.tool_call_id
     }
 
+    /// Returns the cancellation token tools should observe.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Return `Err(CoreError::Cancelled)` if the tool call has been cancelled.
+    ///
+    /// Long-running tools should call this between units of work.
+    pub fn check_cancelled(&self) -> CoreResult<()> {
+        self.cancellation.check()
+    }
+
     /// Search the memory store for entries matching the given key pattern.
     ///
     /// Returns matching key-value pairs. This is a read-only operation.
@@ -137,6 +179,10 @@ impl ExecutionContext for ToolContext {
     fn execution_tag(&self) -> Option<&str> {
         self.execution_tag.as_deref()
     }
+
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        Some(&self.cancellation)
+    }
 }
 
 // ============================================================================
@@ -163,6 +209,8 @@ pub struct OrchestratorContext {
     memory_store: Arc<RwLock<HashMap<String, Value>>>,
     /// Flag indicating whether execution should be terminated.
     should_end: Arc<RwLock<bool>>,
+    /// Cancellation token propagated to tool and sub-agent contexts.
+    cancellation: CancellationToken,
 }
 
 impl OrchestratorContext {
@@ -180,6 +228,7 @@ impl OrchestratorContext {
             session_state: Arc::new(RwLock::new(HashMap::new())),
             memory_store: Arc::new(RwLock::new(HashMap::new())),
             should_end: Arc::new(RwLock::new(false)),
+            cancellation: CancellationToken::new(),
         }
     }
 
@@ -189,6 +238,30 @@ impl OrchestratorContext {
         self
     }
 
+    /// Set the cancellation token (e.g., a child of a parent orchestrator's token).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Bound this execution (and every context derived from it) by `deadline`.
+    ///
+    /// The deadline can only be tightened: an existing earlier deadline wins.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.cancellation = self.cancellation.child_with_deadline(deadline);
+        self
+    }
+
+    /// Returns the cancellation token for this orchestrator scope.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Cancel this execution and every tool/sub-agent context derived from it.
+    pub fn cancel(&self) {
+        self.cancellation.cancel();
+    }
+
     /// Get a mutable reference to the session state.
     ///
     /// Allows the orchestrator to read and write session state entries.
@@ -233,6 +306,24 @@ impl OrchestratorContext {
             execution_tag: self.execution_tag.clone(),
             tool_call_id: tool_call_id.into(),
             memory_store: Arc::clone(&self.memory_store),
+            cancellation: self.cancellation.child_token(),
+        }
+    }
+
+    /// Create an `OrchestratorContext` for a sub-agent spawned from this one.
+    ///
+    /// The child shares the memory store, starts with empty session state, and
+    /// holds a child cancellation token bounded by this context's deadline.
+    pub fn create_child_context(&self, agent_name: impl Into<String>) -> OrchestratorContext {
+        OrchestratorContext {
+            session_id: self.session_id.clone(),
+            project_root: self.project_root.clone(),
+            agent_name: agent_name.into(),
+            execution_tag: self.execution_tag.clone(),
+            session_state: Arc::new(RwLock::new(HashMap::new())),
+            memory_store: Arc::clone(&self.memory_store),
+            should_end: Arc::new(RwLock::new(false)),
+            cancellation: self.cancellation.child_token(),
         }
     }
 
@@ -277,6 +368,10 @@ impl ExecutionContext for OrchestratorContext {
     fn execution_tag(&self) -> Option<&str> {
         self.execution_tag.as_deref()
     }
+
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        Some(&self.cancellation)
+    }
 }
 
 // ============================================================================
@@ -447,6 +542,76 @@ mod tests {
         assert_eq!(results[0].1, Value::Bool(true));
     }
 
+    // -- Cancellation tests --
+
+    #[test]
+    fn test_orchestrator_cancel_propagates_to_tools_and_sub_agents() {
+        let root = OrchestratorContext::new("sess-1", "/project", "root");
+        let sub_agent = root.create_child_context("sub-agent");
+        let sub_tool = sub_agent.create_tool_context("tc-2");
+        let root_tool = root.create_tool_context("tc-1");
+
+        assert!(!root_tool.is_cancelled());
+        assert!(sub_tool.check_cancelled().is_ok());
+
+        root.cancel();
+        assert!(root.is_cancelled());
+        assert!(sub_agent.is_cancelled());
+        assert!(root_tool.is_cancelled());
+        assert!(matches!(
+            sub_tool.check_cancelled(),
+            Err(CoreError::Cancelled(_))
+        ));
+    }
+
+    #[test]
+    fn test_sub_agent_cancel_does_not_cancel_parent() {
+        let root = OrchestratorContext::new("sess-1", "/project", "root");
+        let sub_agent = root.create_child_context("sub-agent");
+        sub_agent.cancel();
+        assert!(sub_agent.is_cancelled());
+        assert!(!root.is_cancelled());
+    }
+
+    #[test]
+    fn test_child_context_shares_memory_but_not_session_state() {
+        let root = OrchestratorContext::new("sess-1", "/project", "root");
+        root.set_memory("shared", Value::Bool(true)).unwrap();
+        root.session_mut()
+            .unwrap()
+            .insert("parent_only".to_string(), Value::Bool(true));
+
+        let child = root.create_child_context("sub-agent");
+        assert_eq!(child.agent_name(), "sub-agent");
+        assert_eq!(child.get_memory("shared"), Some(Value::Bool(true)));
+        assert!(child.session_ref().unwrap().get("parent_only").is_none());
+    }
+
+    #[test]
+    fn test_deadline_bounds_derived_contexts() {
+        let deadline = Instant::now() + std::time::Duration::from_secs(60);
+        let root = OrchestratorContext::new("sess-1", "/project", "root").with_deadline(deadline);
+        let sub_agent = root.create_child_context("sub-agent");
+        let tool_ctx = sub_agent.create_tool_context("tc-1");
+        assert_eq!(root.deadline(), Some(deadline));
+        assert_eq!(sub_agent.deadline(), Some(deadline));
+        assert_eq!(tool_ctx.deadline(), Some(deadline));
+
+        // A later deadline cannot extend an earlier one
+        let extended = sub_agent.with_deadline(deadline + std::time::Duration::from_secs(60));
+        assert_eq!(extended.deadline(), Some(deadline));
+    }
+
+    #[test]
+    fn test_elapsed_deadline_cancels_context() {
+        let root =
+            OrchestratorContext::new("sess-1", "/project", "root").with_deadline(Instant::now());
+        let tool_ctx = root.create_tool_context("tc-1");
+        assert!(tool_ctx.is_cancelled());
+        let err = tool_ctx.check_cancelled().unwrap_err();
+        assert!(err.to_string().contains("deadline exceeded"));
+    }
+
     // -- Trait object tests --
 
     #[test]
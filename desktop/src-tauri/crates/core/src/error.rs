@@ -7,8 +7,75 @@
 //! The main application crate extends these with additional error variants
 //! (e.g., Database, Sqlite, Keyring) that require heavier dependencies.
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+/// Coarse classification of an error, used by retry and fallback logic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// Temporary failure (network blip, timeout, 5xx); retrying may succeed
+    Transient,
+    /// Provider throttled the request; retry after backing off
+    RateLimited,
+    /// Credentials are missing, invalid, or lack permission
+    Auth,
+    /// The request itself is wrong (bad input, config, unknown resource)
+    Invalid,
+    /// Unrecoverable failure; retrying will not help
+    Fatal,
+}
+
+impl ErrorKind {
+    /// Whether an operation that failed with this kind is worth retrying.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ErrorKind::Transient | ErrorKind::RateLimited)
+    }
+
+    /// Whether a fallback to another provider/backend may succeed.
+    ///
+    /// Auth errors are provider-specific, so a different provider can still
+    /// serve the request; invalid requests will fail everywhere.
+    pub fn allows_fallback(&self) -> bool {
+        !matches!(self, ErrorKind::Invalid)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorKind::Transient => "transient",
+            ErrorKind::RateLimited => "rate_limited",
+            ErrorKind::Auth => "auth",
+            ErrorKind::Invalid => "invalid",
+            ErrorKind::Fatal => "fatal",
+        }
+    }
+
+    /// Classify a `std::io::Error` by its kind.
+    pub fn from_io(err: &std::io::Error) -> Self {
+        use std::io::ErrorKind as IoKind;
+        match err.kind() {
+            IoKind::TimedOut
+            | IoKind::Interrupted
+            | IoKind::WouldBlock
+            | IoKind::ConnectionRefused
+            | IoKind::ConnectionReset
+            | IoKind::ConnectionAborted
+            | IoKind::NotConnected
+            | IoKind::BrokenPipe
+            | IoKind::UnexpectedEof => ErrorKind::Transient,
+            IoKind::PermissionDenied => ErrorKind::Auth,
+            IoKind::NotFound | IoKind::InvalidInput | IoKind::InvalidData => ErrorKind::Invalid,
+            _ => ErrorKind::Fatal,
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Core error type for the Plan Cascade workspace.
 ///
 /// This is the minimal error set that the core crate needs. The application
@@ -46,6 +113,25 @@ pub enum CoreError {
     /// Generic internal errors
     #[error("Internal error: {0}")]
     Internal(String),
+
+    /// Temporary failures (network, timeouts, upstream 5xx)
+    #[error("Transient error: {0}")]
+    Transient(String),
+
+    /// Rate limit exceeded; `retry_after_secs` is the provider's hint if any
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        retry_after_secs: Option<u64>,
+    },
+
+    /// Authentication/authorization failures
+    #[error("Authentication error: {0}")]
+    Auth(String),
+
+    /// Execution was cancelled or its deadline elapsed
+    #[error("Cancelled: {0}")]
+    Cancelled(String),
 }
 
 /// Result type alias for core errors
@@ -81,6 +167,62 @@ impl CoreError {
     pub fn internal(msg: impl Into<String>) -> Self {
         Self::Internal(msg.into())
     }
+
+    /// Create a transient error
+    pub fn transient(msg: impl Into<String>) -> Self {
+        Self::Transient(msg.into())
+    }
+
+    /// Create a rate-limited error
+    pub fn rate_limited(msg: impl Into<String>, retry_after_secs: Option<u64>) -> Self {
+        Self::RateLimited {
+            message: msg.into(),
+            retry_after_secs,
+        }
+    }
+
+    /// Create an authentication error
+    pub fn auth(msg: impl Into<String>) -> Self {
+        Self::Auth(msg.into())
+    }
+
+    /// Create a cancellation error
+    pub fn cancelled(msg: impl Into<String>) -> Self {
+        Self::Cancelled(msg.into())
+    }
+
+    /// Classify this error for retry/fallback decisions.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            CoreError::Transient(_) => ErrorKind::Transient,
+            CoreError::RateLimited { .. } => ErrorKind::RateLimited,
+            CoreError::Auth(_) => ErrorKind::Auth,
+            CoreError::Io(e) => ErrorKind::from_io(e),
+            CoreError::Config(_)
+            | CoreError::Serialization(_)
+            | CoreError::Validation(_)
+            | CoreError::NotFound(_)
+            | CoreError::Parse(_) => ErrorKind::Invalid,
+            CoreError::Command(_) | CoreError::Internal(_) | CoreError::Cancelled(_) => {
+                ErrorKind::Fatal
+            }
+        }
+    }
+
+    /// Whether the failed operation is worth retrying.
+    pub fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+
+    /// Suggested wait before retrying, for rate-limited errors.
+    pub fn retry_after_secs(&self) -> Option<u64> {
+        match self {
+            CoreError::RateLimited {
+                retry_after_secs, ..
+            } => *retry_after_secs,
+            _ => None,
+        }
+    }
 }
 
 /// Convert CoreError to a string
@@ -131,4 +273,45 @@ mod tests {
         let err = CoreError::internal("lock poisoned");
         assert_eq!(err.to_string(), "Internal error: lock poisoned");
     }
+
+    #[test]
+    fn test_error_kind_classification() {
+        assert_eq!(CoreError::transient("timeout").kind(), ErrorKind::Transient);
+        assert_eq!(
+            CoreError::rate_limited("slow down", Some(30)).kind(),
+            ErrorKind::RateLimited
+        );
+        assert_eq!(CoreError::auth("bad key").kind(), ErrorKind::Auth);
+        assert_eq!(CoreError::validation("bad").kind(), ErrorKind::Invalid);
+        assert_eq!(CoreError::config("bad").kind(), ErrorKind::Invalid);
+        assert_eq!(CoreError::internal("boom").kind(), ErrorKind::Fatal);
+    }
+
+    #[test]
+    fn test_retryability_hints() {
+        assert!(CoreError::transient("timeout").is_retryable());
+        assert!(CoreError::rate_limited("slow down", None).is_retryable());
+        assert!(!CoreError::auth("bad key").is_retryable());
+        assert!(!CoreError::parse("bad json").is_retryable());
+        assert!(!CoreError::internal("boom").is_retryable());
+
+        assert_eq!(
+            CoreError::rate_limited("slow down", Some(12)).retry_after_secs(),
+            Some(12)
+        );
+        assert_eq!(CoreError::transient("timeout").retry_after_secs(), None);
+        assert!(ErrorKind::Auth.allows_fallback());
+        assert!(!ErrorKind::Invalid.allows_fallback());
+    }
+
+    #[test]
+    fn test_io_error_kind_classification() {
+        let timeout: CoreError =
+            std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out").into();
+        assert_eq!(timeout.kind(), ErrorKind::Transient);
+
+        let missing: CoreError =
+            std::io::Error::new(std::io::ErrorKind::NotFound, "missing").into();
+        assert_eq!(missing.kind(), ErrorKind::Invalid);
+    }
 }
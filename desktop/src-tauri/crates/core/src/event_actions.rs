@@ -44,6 +44,11 @@ pub struct EventActions {
     /// Result of a quality gate evaluation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quality_gate_result: Option<QualityGateActionResult>,
+
+    /// Ordered batch of additional actions, applied in declared order and
+    /// all-or-nothing by the orchestrator.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub batch: Vec<EventAction>,
 }
 
 /// Request to create a checkpoint.
@@ -68,6 +73,32 @@ pub struct QualityGateActionResult {
     pub details: Option<String>,
 }
 
+/// A single action in an ordered `EventActions::batch`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventAction {
+    /// Set one session state key.
+    SetState { key: String, value: Value },
+    /// Create a checkpoint.
+    Checkpoint(CheckpointRequest),
+    /// Record a quality gate result.
+    QualityGate(QualityGateActionResult),
+    /// Transfer execution to another agent.
+    Transfer { agent: String },
+}
+
+impl EventAction {
+    /// Short name of the action kind, used in logs and errors.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EventAction::SetState { .. } => "set_state",
+            EventAction::Checkpoint(_) => "checkpoint",
+            EventAction::QualityGate(_) => "quality_gate",
+            EventAction::Transfer { .. } => "transfer",
+        }
+    }
+}
+
 impl EventActions {
     /// Create empty actions (no side effects).
     pub fn none() -> Self {
@@ -80,6 +111,13 @@ impl EventActions {
             || self.transfer_to_agent.is_some()
             || self.checkpoint_request.is_some()
             || self.quality_gate_result.is_some()
+            || !self.batch.is_empty()
+    }
+
+    /// Builder: append an action to the ordered batch.
+    pub fn then(mut self, action: EventAction) -> Self {
+        self.batch.push(action);
+        self
     }
 
     /// Builder: add a state delta entry.
@@ -135,6 +173,7 @@ impl EventActions {
     ///
     /// State delta entries are merged (later values override earlier).
     /// For optional fields, the `other` value takes precedence if set.
+    /// Batches are concatenated, `self`'s actions first.
     pub fn merge(mut self, other: EventActions) -> Self {
         for (k, v) in other.state_delta {
             self.state_delta.insert(k, v);
@@ -148,6 +187,7 @@ impl EventActions {
         if other.quality_gate_result.is_some() {
             self.quality_gate_result = other.quality_gate_result;
         }
+        self.batch.extend(other.batch);
         self
     }
 }
@@ -236,4 +276,23 @@ mod tests {
         let merged = a.merge(b);
         assert_eq!(merged.transfer_to_agent.as_deref(), Some("agent-b"));
     }
+
+    #[test]
+    fn test_event_actions_batch_keeps_declared_order() {
+        let actions = EventActions::none()
+            .then(EventAction::SetState {
+                key: "app:step".to_string(),
+                value: serde_json::json!(1),
+            })
+            .then(EventAction::Transfer {
+                agent: "reviewer".to_string(),
+            });
+        assert!(actions.has_actions());
+        let kinds: Vec<&str> = actions.batch.iter().map(|a| a.kind()).collect();
+        assert_eq!(kinds, vec!["set_state", "transfer"]);
+
+        let json = serde_json::to_string(&actions).unwrap();
+        let parsed: EventActions = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.batch, actions.batch);
+    }
 }
@@ -6,7 +6,8 @@
 //!
 //! ## Module Organization
 //!
-//! - `error` - Core error types (`CoreError`, `CoreResult`)
+//! - `cancellation` - Hierarchical cancellation tokens with deadlines (`CancellationToken`)
+//! - `error` - Core error types (`CoreError`, `CoreResult`, `ErrorKind`)
 //! - `context` - Execution context hierarchy (`ExecutionContext`, `ToolContext`, `OrchestratorContext`)
 //! - `tool_trait` - Unified tool abstraction (`ToolDefinitionTrait`, `ToolExecutable`, `UnifiedTool`)
 //! - `builders` - Builder patterns and session state types
@@ -20,6 +21,7 @@
 //! 3. **Unidirectional dependency** - this crate depends on nothing else in the workspace
 
 pub mod builders;
+pub mod cancellation;
 pub mod context;
 pub mod error;
 pub mod event_actions;
@@ -28,7 +30,10 @@ pub mod streaming;
 pub mod tool_trait;
 
 // ── Error Types ────────────────────────────────────────────────────────
-pub use error::{CoreError, CoreResult};
+pub use error::{CoreError, CoreResult, ErrorKind};
+
+// ── Cancellation ───────────────────────────────────────────────────────
+pub use cancellation::{CancelReason, CancellationToken};
 
 // ── Context Hierarchy ──────────────────────────────────────────────────
 pub use context::{ExecutionContext, OrchestratorContext, ToolContext};
@@ -43,10 +48,16 @@ pub use builders::{
 };
 
 // ── Proxy Types ────────────────────────────────────────────────────────
-pub use proxy::{ProxyConfig, ProxyProtocol, ProxyStrategy};
+pub use proxy::{
+    PacDirective, ProxyConfig, ProxyProtocol, ProxyRouteRule, ProxyRouteTarget, ProxyRoutingTable,
+    ProxyStrategy,
+};
 
 // ── Streaming Types ────────────────────────────────────────────────────
-pub use streaming::{AdapterError, StreamAdapter, UnifiedStreamEvent};
+pub use streaming::{
+    AdapterError, SequenceCheck, SequenceGapDetector, SequencedStreamEvent, SseLineBuffer,
+    StreamAdapter, StreamSequencer, UnifiedStreamEvent, INCOMPLETE_TOOL_ARGUMENTS_CODE,
+};
 
 // ── Event Actions ─────────────────────────────────────────────────────
-pub use event_actions::{CheckpointRequest, EventActions, QualityGateActionResult};
+pub use event_actions::{CheckpointRequest, EventAction, EventActions, QualityGateActionResult};
@@ -383,6 +383,21 @@ pub enum UnifiedStreamEvent {
         compaction_tokens: u32,
     },
 
+    /// Knowledge base chunks were injected into the system prompt
+    KnowledgeContextInjected {
+        /// Injected chunks, highest relevance first
+        chunks: Vec<KnowledgeContextEntry>,
+        /// Estimated tokens of all injected chunks
+        total_tokens: u32,
+        /// Retrieved chunks dropped for scoring below the relevance threshold
+        dropped_below_threshold: usize,
+        /// Relevant chunks dropped because the token budget was exhausted
+        dropped_over_budget: usize,
+        /// Relevant chunks dropped by the maximum chunk count
+        #[serde(default)]
+        dropped_over_chunk_limit: usize,
+    },
+
     // ========================================================================
     // Tool permission events (runtime approval gate)
     // ========================================================================
@@ -398,6 +413,9 @@ pub enum UnifiedStreamEvent {
         arguments: String,
         /// Risk classification: "ReadOnly", "SafeWrite", or "Dangerous"
         risk: String,
+        /// Policy reason for the prompt (e.g. why a command was classified dangerous)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reason: Option<String>,
     },
 
     /// Frontend responds to a tool permission request
@@ -409,6 +427,105 @@ pub enum UnifiedStreamEvent {
     },
 }
 
+/// A stream event tagged with its position in the emitted stream.
+///
+/// Serializes as the flattened event plus a `seq` field, so consumers that
+/// ignore `seq` keep working unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SequencedStreamEvent {
+    /// Monotonically increasing sequence number, starting at 1.
+    pub seq: u64,
+    #[serde(flatten)]
+    pub event: UnifiedStreamEvent,
+}
+
+/// Assigns sequence numbers to emitted stream events.
+#[derive(Debug, Clone, Default)]
+pub struct StreamSequencer {
+    last: u64,
+}
+
+impl StreamSequencer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserve the next sequence number.
+    pub fn next_seq(&mut self) -> u64 {
+        self.last += 1;
+        self.last
+    }
+
+    /// Tag an event with the next sequence number.
+    pub fn sequence(&mut self, event: UnifiedStreamEvent) -> SequencedStreamEvent {
+        SequencedStreamEvent {
+            seq: self.next_seq(),
+            event,
+        }
+    }
+
+    /// Sequence number of the most recently tagged event (0 if none).
+    pub fn last_seq(&self) -> u64 {
+        self.last
+    }
+}
+
+/// Result of checking a received sequence number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceCheck {
+    /// The event directly follows the previous one.
+    InOrder,
+    /// One or more events between `expected` and `received` were lost.
+    Gap { expected: u64, received: u64 },
+    /// The event was already seen or arrived out of order.
+    Stale { received: u64 },
+}
+
+impl SequenceCheck {
+    /// Number of events missing before this one.
+    pub fn missing(&self) -> u64 {
+        match self {
+            SequenceCheck::Gap { expected, received } => received - expected,
+            _ => 0,
+        }
+    }
+}
+
+/// Consumer-side gap detector for sequenced stream events.
+#[derive(Debug, Clone, Default)]
+pub struct SequenceGapDetector {
+    last: Option<u64>,
+}
+
+impl SequenceGapDetector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a received sequence number and classify it.
+    ///
+    /// The first observed event establishes the baseline, so a consumer that
+    /// attaches mid-stream does not report a gap.
+    pub fn observe(&mut self, seq: u64) -> SequenceCheck {
+        let check = match self.last {
+            None => SequenceCheck::InOrder,
+            Some(last) if seq == last + 1 => SequenceCheck::InOrder,
+            Some(last) if seq > last + 1 => SequenceCheck::Gap {
+                expected: last + 1,
+                received: seq,
+            },
+            Some(_) => return SequenceCheck::Stale { received: seq },
+        };
+        self.last = Some(seq);
+        check
+    }
+
+    /// Highest in-order sequence number observed so far.
+    pub fn last_seq(&self) -> Option<u64> {
+        self.last
+    }
+}
+
 /// A single search citation entry from provider-native web search.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SearchCitationEntry {
@@ -426,6 +543,24 @@ pub struct SearchCitationEntry {
     pub icon: Option<String>,
 }
 
+/// A knowledge chunk injected into agent context.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KnowledgeContextEntry {
+    /// Source document name
+    pub source_document: String,
+    /// Collection the chunk came from
+    pub collection_name: String,
+    /// Relevance score (0.0 to 1.0)
+    pub relevance_score: f32,
+    /// Estimated token count of the chunk
+    pub tokens: u32,
+}
+
+/// `UnifiedStreamEvent::Error` code emitted when a stream ends before a tool
+/// call's arguments form valid JSON. The call is not reported as
+/// `ToolComplete`, so callers can retry instead of parsing broken arguments.
+pub const INCOMPLETE_TOOL_ARGUMENTS_CODE: &str = "incomplete_tool_arguments";
+
 /// Errors that can occur during stream adaptation
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum AdapterError {
@@ -472,6 +607,90 @@ pub trait StreamAdapter: Send + Sync {
     fn reset(&mut self) {
         // Default implementation does nothing
     }
+
+    /// Flush buffered state when the stream ends, including an abrupt end
+    /// (network drop, cancellation) that never delivered a terminal event.
+    fn finish(&mut self) -> Vec<UnifiedStreamEvent> {
+        Vec::new()
+    }
+
+    /// Adapt raw transport chunks (as received from the network) to events.
+    ///
+    /// Chunks are framed into lines with an [`SseLineBuffer`], so chunk
+    /// boundaries may fall anywhere, including inside a JSON token or a
+    /// multibyte UTF-8 character. A trailing line without a newline is
+    /// flushed at the end, followed by [`StreamAdapter::finish`]. Lets adapter parsing be exercised without a server.
+    fn adapt_raw_chunks(
+        &mut self,
+        chunks: &[&[u8]],
+    ) -> Result<Vec<UnifiedStreamEvent>, AdapterError> {
+        let mut buffer = SseLineBuffer::new();
+        let mut events = Vec::new();
+        for chunk in chunks {
+            for line in buffer.push(chunk) {
+                events.extend(self.adapt(&line)?);
+            }
+        }
+        if let Some(line) = buffer.finish() {
+            events.extend(self.adapt(&line)?);
+        }
+        events.extend(self.finish());
+        Ok(events)
+    }
+}
+
+/// Splits a raw byte stream into complete, non-empty lines.
+///
+/// Bytes are buffered until a newline arrives and only whole lines are
+/// decoded, so multibyte UTF-8 characters split across network chunks are
+/// reassembled instead of being replaced with U+FFFD. Trailing `\r` is
+/// stripped so CRLF-framed SSE works too.
+#[derive(Debug, Default)]
+pub struct SseLineBuffer {
+    pending: Vec<u8>,
+}
+
+impl SseLineBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a chunk and return every line it completed.
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.pending.extend_from_slice(chunk);
+        let mut lines = Vec::new();
+        let mut start = 0;
+        while let Some(offset) = self.pending[start..].iter().position(|&b| b == b'\n') {
+            let end = start + offset;
+            if let Some(line) = Self::decode(&self.pending[start..end]) {
+                lines.push(line);
+            }
+            start = end + 1;
+        }
+        self.pending.drain(..start);
+        lines
+    }
+
+    /// Flush the trailing partial line at end of stream, if any.
+    pub fn finish(&mut self) -> Option<String> {
+        let rest = std::mem::take(&mut self.pending);
+        Self::decode(&rest)
+    }
+
+    /// Whether bytes of an incomplete line are buffered.
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    fn decode(bytes: &[u8]) -> Option<String> {
+        let bytes = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+        let line = String::from_utf8_lossy(bytes);
+        if line.trim().is_empty() {
+            None
+        } else {
+            Some(line.into_owned())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -520,4 +739,144 @@ mod tests {
         let err = AdapterError::UnsupportedEvent("ping".to_string());
         assert_eq!(err.to_string(), "Unsupported event: ping");
     }
+
+    #[test]
+    fn test_sequencer_numbers_mixed_stream_monotonically() {
+        let mut sequencer = StreamSequencer::new();
+        let events = vec![
+            UnifiedStreamEvent::ThinkingStart { thinking_id: None },
+            UnifiedStreamEvent::TextDelta {
+                content: "a".to_string(),
+            },
+            UnifiedStreamEvent::ToolStart {
+                tool_id: "t1".to_string(),
+                tool_name: "Read".to_string(),
+                arguments: None,
+            },
+            UnifiedStreamEvent::Usage {
+                input_tokens: 1,
+                output_tokens: 2,
+                thinking_tokens: None,
+                cache_read_tokens: None,
+                cache_creation_tokens: None,
+            },
+            UnifiedStreamEvent::Complete { stop_reason: None },
+        ];
+        let sequenced: Vec<SequencedStreamEvent> =
+            events.into_iter().map(|e| sequencer.sequence(e)).collect();
+        let seqs: Vec<u64> = sequenced.iter().map(|e| e.seq).collect();
+        assert_eq!(seqs, vec![1, 2, 3, 4, 5]);
+        assert_eq!(sequencer.last_seq(), 5);
+
+        let mut detector = SequenceGapDetector::new();
+        assert!(seqs
+            .iter()
+            .all(|seq| detector.observe(*seq) == SequenceCheck::InOrder));
+    }
+
+    #[test]
+    fn test_sequenced_event_serializes_flat_with_seq() {
+        let event = SequencedStreamEvent {
+            seq: 7,
+            event: UnifiedStreamEvent::TextDelta {
+                content: "hi".to_string(),
+            },
+        };
+        let value = serde_json::to_value(&event).unwrap();
+        assert_eq!(value["seq"], 7);
+        assert_eq!(value["type"], "text_delta");
+        assert_eq!(value["content"], "hi");
+
+        let parsed: SequencedStreamEvent = serde_json::from_value(value).unwrap();
+        assert_eq!(parsed, event);
+    }
+
+    #[test]
+    fn test_gap_detector_reports_dropped_event() {
+        let mut sequencer = StreamSequencer::new();
+        let sent: Vec<SequencedStreamEvent> = (0..5)
+            .map(|i| {
+                sequencer.sequence(UnifiedStreamEvent::TextDelta {
+                    content: i.to_string(),
+                })
+            })
+            .collect();
+
+        let mut detector = SequenceGapDetector::new();
+        let checks: Vec<SequenceCheck> = sent
+            .iter()
+            .filter(|e| e.seq != 3) // artificially drop one event
+            .map(|e| detector.observe(e.seq))
+            .collect();
+        assert_eq!(
+            checks,
+            vec![
+                SequenceCheck::InOrder,
+                SequenceCheck::InOrder,
+                SequenceCheck::Gap {
+                    expected: 3,
+                    received: 4
+                },
+                SequenceCheck::InOrder,
+            ]
+        );
+        assert_eq!(checks[2].missing(), 1);
+        assert_eq!(detector.observe(2), SequenceCheck::Stale { received: 2 });
+        assert_eq!(detector.last_seq(), Some(5));
+    }
+
+    #[test]
+    fn test_sse_line_buffer_reassembles_split_lines_and_utf8() {
+        let mut buffer = SseLineBuffer::new();
+        let bytes = "data: {\"text\": \"héllo\"}\r\n\ndata: [DONE]".as_bytes();
+        // Split inside the two-byte 'é'.
+        let split = bytes.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        assert!(buffer.push(&bytes[..split]).is_empty());
+        assert!(buffer.has_pending());
+        let lines = buffer.push(&bytes[split..]);
+        assert_eq!(lines, vec!["data: {\"text\": \"héllo\"}".to_string()]);
+        assert_eq!(buffer.finish(), Some("data: [DONE]".to_string()));
+        assert!(!buffer.has_pending());
+    }
+
+    struct EchoAdapter;
+
+    impl StreamAdapter for EchoAdapter {
+        fn provider_name(&self) -> &'static str {
+            "echo"
+        }
+
+        fn supports_thinking(&self) -> bool {
+            false
+        }
+
+        fn supports_tools(&self) -> bool {
+            false
+        }
+
+        fn adapt(&mut self, input: &str) -> Result<Vec<UnifiedStreamEvent>, AdapterError> {
+            Ok(vec![UnifiedStreamEvent::TextDelta {
+                content: input.to_string(),
+            }])
+        }
+    }
+
+    #[test]
+    fn test_adapt_raw_chunks_feeds_whole_lines() {
+        let events = EchoAdapter
+            .adapt_raw_chunks(&[b"first li", b"ne\nsec", b"ond"])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![
+                UnifiedStreamEvent::TextDelta {
+                    content: "first line".to_string()
+                },
+                UnifiedStreamEvent::TextDelta {
+                    content: "second".to_string()
+                },
+            ]
+        );
+    }
 }
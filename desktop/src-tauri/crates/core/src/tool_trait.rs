@@ -218,10 +218,16 @@ impl UnifiedToolRegistry {
 
     /// Execute a tool by name.
     ///
-    /// Returns `Err(CoreError::NotFound)` if the tool is not registered.
+    /// Returns `Err(CoreError::NotFound)` if the tool is not registered, and
+    /// `Err(CoreError::Cancelled)` if the context's cancellation token fires
+    /// (or its deadline elapses) before the tool completes.
     pub async fn execute(&self, name: &str, ctx: &ToolContext, args: Value) -> CoreResult<Value> {
         match self.tools.get(name) {
-            Some(tool) => tool.execute(ctx, args).await,
+            Some(tool) => {
+                let token = ctx.cancellation_token();
+                token.check()?;
+                token.run_until_cancelled(tool.execute(ctx, args)).await?
+            }
             None => Err(CoreError::not_found(format!("Tool not found: {}", name))),
         }
     }
@@ -335,6 +341,36 @@ mod tests {
         }
     }
 
+    /// A tool that runs until its context's cancellation token fires.
+    struct WaitForCancelTool;
+
+    impl ToolDefinitionTrait for WaitForCancelTool {
+        fn name(&self) -> &str {
+            "Wait"
+        }
+
+        fn description(&self) -> &str {
+            "Blocks until cancelled"
+        }
+
+        fn parameters_schema(&self) -> Value {
+            serde_json::json!({"type": "object"})
+        }
+
+        fn is_long_running(&self) -> bool {
+            true
+        }
+    }
+
+    #[async_trait]
+    impl ToolExecutable for WaitForCancelTool {
+        async fn execute(&self, ctx: &ToolContext, _args: Value) -> CoreResult<Value> {
+            ctx.cancellation_token().cancelled().await;
+            ctx.check_cancelled()?;
+            Ok(Value::String("unreachable".to_string()))
+        }
+    }
+
     fn make_tool_context() -> ToolContext {
         ToolContext::new("test-session", "/tmp/test", "test-agent", "tc-001")
     }
@@ -618,6 +654,92 @@ mod tests {
         }
     }
 
+    // -- Cancellation tests --
+
+    #[tokio::test]
+    async fn test_cancelling_root_context_aborts_in_flight_tool() {
+        use crate::context::{ExecutionContext, OrchestratorContext};
+
+        let root = OrchestratorContext::new("sess-1", "/project", "orchestrator");
+        let sub_agent = root.create_child_context("sub-agent");
+        let tool_ctx = sub_agent.create_tool_context("tc-1");
+
+        let handle = tokio::spawn(async move {
+            let tool = WaitForCancelTool;
+            tool.execute(&tool_ctx, Value::Null).await
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        assert!(!handle.is_finished());
+
+        root.cancel();
+        let result = tokio::time::timeout(std::time::Duration::from_secs(1), handle)
+            .await
+            .expect("tool should observe cancellation")
+            .unwrap();
+        assert!(matches!(result, Err(CoreError::Cancelled(_))));
+        assert!(sub_agent.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_stops_at_context_deadline() {
+        use crate::context::{ExecutionContext, OrchestratorContext};
+
+        struct NeverFinishes;
+
+        impl ToolDefinitionTrait for NeverFinishes {
+            fn name(&self) -> &str {
+                "Hang"
+            }
+
+            fn description(&self) -> &str {
+                "Ignores cancellation"
+            }
+
+            fn parameters_schema(&self) -> Value {
+                serde_json::json!({"type": "object"})
+            }
+        }
+
+        #[async_trait]
+        impl ToolExecutable for NeverFinishes {
+            async fn execute(&self, _ctx: &ToolContext, _args: Value) -> CoreResult<Value> {
+                std::future::pending().await
+            }
+        }
+
+        let mut registry = UnifiedToolRegistry::new();
+        registry.register(Arc::new(NeverFinishes));
+
+        let root = OrchestratorContext::new("sess-1", "/project", "orchestrator")
+            .with_deadline(std::time::Instant::now() + std::time::Duration::from_millis(30));
+        let tool_ctx = root.create_tool_context("tc-1");
+        assert_eq!(tool_ctx.deadline(), root.deadline());
+
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(2),
+            registry.execute("Hang", &tool_ctx, Value::Null),
+        )
+        .await
+        .expect("deadline should cancel the tool");
+        let err = result.unwrap_err();
+        assert!(matches!(err, CoreError::Cancelled(_)));
+        assert!(err.to_string().contains("deadline exceeded"));
+        assert!(root.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_registry_execute_rejects_already_cancelled_context() {
+        let mut registry = UnifiedToolRegistry::new();
+        registry.register(Arc::new(MockUnifiedTool::new("Echo", "Echoes input")));
+
+        let ctx = make_tool_context();
+        ctx.cancellation_token().cancel();
+        let result = registry
+            .execute("Echo", &ctx, serde_json::json!({"input": "hi"}))
+            .await;
+        assert!(matches!(result, Err(CoreError::Cancelled(_))));
+    }
+
     // -- Send + Sync assertion tests --
 
     #[test]
@@ -11,9 +11,9 @@ use super::types::{
     LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message, MessageContent, MessageRole,
     ProviderConfig, StopReason, ToolCall, ToolCallMode, ToolDefinition, UsageStats,
 };
-use crate::http_client::build_http_client;
+use crate::http_client::build_provider_http_client;
 use crate::streaming_adapters::ClaudeApiAdapter;
-use plan_cascade_core::streaming::{StreamAdapter, UnifiedStreamEvent};
+use plan_cascade_core::streaming::{SseLineBuffer, StreamAdapter, UnifiedStreamEvent};
 
 /// Default Anthropic API endpoint
 const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
@@ -30,7 +30,7 @@ pub struct AnthropicProvider {
 impl AnthropicProvider {
     /// Create a new Anthropic provider with the given configuration
     pub fn new(config: ProviderConfig) -> Self {
-        let client = build_http_client(config.proxy.as_ref());
+        let client = build_provider_http_client(&config);
         Self { config, client }
     }
 
@@ -63,11 +63,15 @@ impl AnthropicProvider {
             }]);
         }
 
-        // Add temperature (only if not using extended thinking)
+        // Add sampling parameters (only if not using extended thinking).
+        // The API has no seed parameter, so output is never reproducible.
         if !self.config.enable_thinking {
             body["temperature"] = serde_json::json!(request_options
                 .temperature_override
                 .unwrap_or(self.config.temperature));
+            if let Some(top_p) = self.config.top_p {
+                body["top_p"] = serde_json::json!(top_p);
+            }
         }
 
         // Convert messages to Claude format
@@ -102,7 +106,7 @@ impl AnthropicProvider {
 
         // Add extended thinking if enabled
         if self.config.enable_thinking {
-            if let Some(budget) = self.config.thinking_budget {
+            if let Some(budget) = request_options.resolve_thinking_budget(&self.config) {
                 body["thinking"] = serde_json::json!({
                     "type": "enabled",
                     "budget_tokens": budget
@@ -177,6 +181,15 @@ impl AnthropicProvider {
                         }
                     })
                 }
+                MessageContent::ImageUrl { url } => {
+                    serde_json::json!({
+                        "type": "image",
+                        "source": {
+                            "type": "url",
+                            "url": url
+                        }
+                    })
+                }
                 MessageContent::ToolResultMultimodal {
                     tool_use_id,
                     content,
@@ -312,6 +325,11 @@ impl LlmProvider for AnthropicProvider {
         true
     }
 
+    fn supports_prompt_cache(&self) -> bool {
+        // Prompt caching via `cache_control` breakpoints
+        true
+    }
+
     fn supports_multimodal(&self) -> bool {
         true
     }
@@ -422,24 +440,15 @@ impl LlmProvider for AnthropicProvider {
         let mut stream = response.bytes_stream();
         use futures_util::StreamExt;
 
-        let mut buffer = String::new();
+        let mut buffer = SseLineBuffer::new();
 
         while let Some(chunk) = stream.next().await {
             let chunk = chunk.map_err(|e| LlmError::NetworkError {
                 message: e.to_string(),
             })?;
 
-            buffer.push_str(&String::from_utf8_lossy(&chunk));
-
             // Process complete lines
-            while let Some(line_end) = buffer.find('\n') {
-                let line = buffer[..line_end].to_string();
-                buffer = buffer[line_end + 1..].to_string();
-
-                if line.trim().is_empty() {
-                    continue;
-                }
-
+            for line in buffer.push(&chunk) {
                 match adapter.adapt(&line) {
                     Ok(events) => {
                         for event in events {
@@ -622,6 +631,7 @@ struct ResponseUsage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ProviderCapabilities;
 
     fn test_config() -> ProviderConfig {
         ProviderConfig {
@@ -640,6 +650,23 @@ mod tests {
         assert!(provider.supports_tools());
     }
 
+    #[test]
+    fn test_capabilities() {
+        let provider = AnthropicProvider::new(test_config());
+        assert_eq!(
+            provider.capabilities(),
+            ProviderCapabilities {
+                streaming: true,
+                tools: true,
+                json_mode: false,
+                vision: true,
+                prompt_cache: true,
+                thinking: true,
+                max_context: 200_000,
+            }
+        );
+    }
+
     #[test]
     fn test_message_conversion() {
         let provider = AnthropicProvider::new(test_config());
@@ -650,6 +677,33 @@ mod tests {
         assert!(claude_msg["content"].is_array());
     }
 
+    #[test]
+    fn test_image_message_conversion() {
+        let provider = AnthropicProvider::new(test_config());
+
+        let inline = provider.message_to_claude(&Message::user_with_image(
+            "What is this?",
+            "image/png",
+            "iVBORw0KGgo=",
+        ));
+        assert_eq!(inline["content"][0]["text"], "What is this?");
+        assert_eq!(inline["content"][1]["type"], "image");
+        assert_eq!(inline["content"][1]["source"]["type"], "base64");
+        assert_eq!(inline["content"][1]["source"]["media_type"], "image/png");
+        assert_eq!(inline["content"][1]["source"]["data"], "iVBORw0KGgo=");
+
+        let linked = provider.message_to_claude(&Message::user_with_image_url(
+            "And this?",
+            "https://example.com/cat.png",
+        ));
+        assert_eq!(linked["content"][1]["type"], "image");
+        assert_eq!(linked["content"][1]["source"]["type"], "url");
+        assert_eq!(
+            linked["content"][1]["source"]["url"],
+            "https://example.com/cat.png"
+        );
+    }
+
     #[test]
     fn test_tool_conversion() {
         let provider = AnthropicProvider::new(test_config());
@@ -713,6 +767,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_thinking_budget_from_request_options() {
+        let provider = AnthropicProvider::new(ProviderConfig {
+            max_tokens: 32_000,
+            enable_thinking: true,
+            thinking_budget: Some(4_000),
+            ..test_config()
+        });
+        let messages = vec![Message::user("Design the module layout")];
+
+        let body =
+            provider.build_request_body(&messages, None, &[], false, &LlmRequestOptions::default());
+        assert_eq!(body["thinking"]["type"], "enabled");
+        assert_eq!(body["thinking"]["budget_tokens"], 4_000);
+        assert!(body.get("temperature").is_none());
+
+        let high = LlmRequestOptions {
+            reasoning_effort_override: Some("high".to_string()),
+            ..Default::default()
+        };
+        let body = provider.build_request_body(&messages, None, &[], false, &high);
+        assert_eq!(body["thinking"]["budget_tokens"], 24_576);
+
+        let explicit = LlmRequestOptions {
+            thinking_budget_override: Some(2_048),
+            ..Default::default()
+        };
+        let body = provider.build_request_body(&messages, None, &[], false, &explicit);
+        assert_eq!(body["thinking"]["budget_tokens"], 2_048);
+    }
+
+    #[test]
+    fn test_no_seed_support() {
+        let provider = AnthropicProvider::new(ProviderConfig {
+            top_p: Some(0.5),
+            seed: Some(7),
+            ..test_config()
+        });
+        assert!(!provider.supports_seed());
+
+        let body = provider.build_request_body(
+            &[Message::user("Hi")],
+            None,
+            &[],
+            false,
+            &LlmRequestOptions::default(),
+        );
+        assert_eq!(body["top_p"], 0.5);
+        assert!(body.get("seed").is_none());
+    }
+
+    #[test]
+    fn test_thinking_disabled_ignores_budget_override() {
+        let provider = AnthropicProvider::new(test_config());
+        let options = LlmRequestOptions {
+            thinking_budget_override: Some(2_048),
+            ..Default::default()
+        };
+        let body = provider.build_request_body(&[Message::user("Hi")], None, &[], false, &options);
+        assert!(body.get("thinking").is_none());
+    }
+
     #[test]
     fn test_no_system_prompt_omits_system_field() {
         let provider = AnthropicProvider::new(test_config());
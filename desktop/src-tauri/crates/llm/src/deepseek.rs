@@ -13,7 +13,7 @@ use super::openai_compat::{
     build_client, build_openai_compatible_messages, map_api_error, value_to_chat_request,
     value_to_chat_stream_request,
 };
-use super::provider::LlmProvider;
+use super::provider::{ensure_image_support, LlmProvider};
 use super::types::{
     FallbackToolFormatMode, LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message,
     ProviderConfig, StopReason, ToolCall, ToolCallMode, ToolCallReliability, ToolDefinition,
@@ -64,6 +64,10 @@ impl DeepSeekProvider {
             "stream": stream,
             "temperature": request_options.temperature_override.unwrap_or(self.config.temperature),
         });
+        // No seed parameter: output is not reproducible across calls.
+        if let Some(top_p) = self.config.top_p {
+            body["top_p"] = serde_json::json!(top_p);
+        }
 
         body["messages"] =
             serde_json::json!(build_openai_compatible_messages(messages, system));
@@ -359,6 +363,15 @@ impl LlmProvider for DeepSeekProvider {
         true
     }
 
+    fn supports_json_mode(&self) -> bool {
+        true
+    }
+
+    fn supports_prompt_cache(&self) -> bool {
+        // Automatic context caching on disk
+        true
+    }
+
     fn tool_call_reliability(&self) -> ToolCallReliability {
         if is_reliable_model(self.config.provider, &self.config.model) {
             ToolCallReliability::Reliable
@@ -397,6 +410,7 @@ impl LlmProvider for DeepSeekProvider {
         tools: Vec<ToolDefinition>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let body = self.build_request_body(
             &messages,
             system.as_deref(),
@@ -433,6 +447,7 @@ impl LlmProvider for DeepSeekProvider {
         tx: mpsc::Sender<UnifiedStreamEvent>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let body =
             self.build_request_body(&messages, system.as_deref(), &tools, true, &request_options);
         let request = value_to_chat_stream_request("deepseek", body)?;
@@ -628,6 +643,7 @@ struct ResponseUsage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ProviderCapabilities;
 
     fn test_config() -> ProviderConfig {
         ProviderConfig {
@@ -638,6 +654,32 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_image_input_rejected_before_request() {
+        let provider = DeepSeekProvider::new(test_config());
+        let messages = vec![Message::user_with_image(
+            "What is this?",
+            "image/png",
+            "AAAA",
+        )];
+
+        let err = provider
+            .send_message(messages.clone(), None, vec![], LlmRequestOptions::default())
+            .await
+            .unwrap_err();
+        match err {
+            LlmError::InvalidRequest { message } => assert!(message.contains("vision")),
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+
+        let (tx, _rx) = mpsc::channel(1);
+        let err = provider
+            .stream_message(messages, None, vec![], tx, LlmRequestOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::InvalidRequest { .. }));
+    }
+
     #[test]
     fn test_provider_creation() {
         let provider = DeepSeekProvider::new(test_config());
@@ -647,6 +689,23 @@ mod tests {
         assert!(provider.supports_tools());
     }
 
+    #[test]
+    fn test_capabilities() {
+        let provider = DeepSeekProvider::new(test_config());
+        assert_eq!(
+            provider.capabilities(),
+            ProviderCapabilities {
+                streaming: true,
+                tools: true,
+                json_mode: true,
+                vision: false,
+                prompt_cache: true,
+                thinking: false,
+                max_context: 128_000,
+            }
+        );
+    }
+
     #[test]
     fn test_r1_supports_thinking() {
         let config = ProviderConfig {
@@ -14,17 +14,17 @@ use tokio::sync::mpsc;
 use zai_rs::model::chat_base_response::{ChatCompletionResponse as ZaiResponse, Usage as ZaiUsage};
 use zai_rs::model::tools::{Function as ZaiFunction, Tools as ZaiTools};
 
-use super::provider::{missing_api_key_error, parse_http_error, LlmProvider};
+use super::provider::{ensure_image_support, missing_api_key_error, parse_http_error, LlmProvider};
 use super::types::{
     FallbackToolFormatMode, LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message,
     ProviderConfig, StopReason, ToolCall, ToolCallMode, ToolCallReliability, ToolDefinition,
     UsageStats,
 };
 use crate::openai_compat::build_openai_compatible_messages;
-use crate::http_client::build_http_client;
+use crate::http_client::build_provider_http_client;
 use crate::reliable_catalog::is_reliable_model;
 use crate::streaming_adapters::GlmAdapter;
-use plan_cascade_core::streaming::{StreamAdapter, UnifiedStreamEvent};
+use plan_cascade_core::streaming::{SseLineBuffer, StreamAdapter, UnifiedStreamEvent};
 
 #[cfg(test)]
 use super::types::{MessageContent, MessageRole};
@@ -47,7 +47,7 @@ pub struct GlmProvider {
 impl GlmProvider {
     /// Create a new GLM provider with the given configuration
     pub fn new(config: ProviderConfig) -> Self {
-        let client = build_http_client(config.proxy.as_ref());
+        let client = build_provider_http_client(&config);
         Self { config, client }
     }
 
@@ -126,6 +126,10 @@ impl GlmProvider {
             "stream": stream,
             "temperature": request_options.temperature_override.unwrap_or(self.config.temperature),
         });
+        // No seed parameter: output is not reproducible across calls.
+        if let Some(top_p) = self.config.top_p {
+            body["top_p"] = serde_json::json!(top_p);
+        }
 
         body["messages"] =
             serde_json::json!(build_openai_compatible_messages(messages, system));
@@ -421,6 +425,10 @@ impl LlmProvider for GlmProvider {
     fn supports_tools(&self) -> bool {
         true
     }
+
+    fn supports_json_mode(&self) -> bool {
+        true
+    }
     fn supports_native_search(&self) -> bool {
         self.native_search_enabled()
     }
@@ -483,6 +491,7 @@ impl LlmProvider for GlmProvider {
         tools: Vec<ToolDefinition>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let api_key = self
             .config
             .api_key
@@ -567,6 +576,7 @@ impl LlmProvider for GlmProvider {
         tx: mpsc::Sender<UnifiedStreamEvent>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let api_key = self
             .config
             .api_key
@@ -639,21 +649,14 @@ impl LlmProvider for GlmProvider {
 
         let mut stream = response.bytes_stream();
         use futures_util::StreamExt;
-        let mut buffer = String::new();
+        let mut buffer = SseLineBuffer::new();
 
         while let Some(chunk) = stream.next().await {
             let chunk = chunk.map_err(|e| LlmError::NetworkError {
                 message: e.to_string(),
             })?;
-            buffer.push_str(&String::from_utf8_lossy(&chunk));
-
-            while let Some(line_end) = buffer.find('\n') {
-                let line = buffer[..line_end].to_string();
-                buffer = buffer[line_end + 1..].to_string();
-                if line.trim().is_empty() {
-                    continue;
-                }
-
+            // Process complete lines
+            for line in buffer.push(&chunk) {
                 match adapter.adapt(&line) {
                     Ok(events) => {
                         for event in events {
@@ -781,6 +784,7 @@ impl LlmProvider for GlmProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ProviderCapabilities;
 
     fn test_config() -> ProviderConfig {
         ProviderConfig {
@@ -800,6 +804,23 @@ mod tests {
         assert!(provider.supports_tools());
     }
 
+    #[test]
+    fn test_capabilities() {
+        let provider = GlmProvider::new(test_config());
+        assert_eq!(
+            provider.capabilities(),
+            ProviderCapabilities {
+                streaming: true,
+                tools: true,
+                json_mode: true,
+                vision: false,
+                prompt_cache: false,
+                thinking: false,
+                max_context: 128_000,
+            }
+        );
+    }
+
     #[test]
     fn test_glm45_supports_reasoning() {
         let config = ProviderConfig {
@@ -1,8 +1,62 @@
 //! HTTP Client Factory
 //!
-//! Provides a factory function for building reqwest clients with proxy support.
+//! Provides factory functions for building reqwest clients with proxy support,
+//! either with a single resolved proxy, per-host routing rules, a PAC
+//! script, or the operating system proxy settings.
 
-use plan_cascade_core::proxy::ProxyConfig;
+use std::sync::Arc;
+
+use plan_cascade_core::proxy::{system_proxy_for, ProxyConfig, ProxyRoutingTable};
+
+use crate::pac::PacResolver;
+use crate::types::ProviderConfig;
+
+/// How a provider's HTTP client chooses a proxy for each request.
+#[derive(Debug, Clone)]
+pub enum ProxyRouting {
+    /// Always use this proxy, or connect directly when `None`.
+    Fixed(Option<ProxyConfig>),
+    /// Evaluate per-host rules against each request's destination host
+    /// (`ProxyStrategy::PerHost`).
+    PerHost(Arc<ProxyRoutingTable>),
+    /// Ask a PAC script for the proxy of each request URL
+    /// (`ProxyStrategy::Pac`).
+    Pac(Arc<PacResolver>),
+    /// Follow the operating system proxy settings (`ProxyStrategy::System`).
+    System,
+}
+
+impl ProxyRouting {
+    /// Build a `reqwest::Client` that applies this routing to every request.
+    pub fn build_client(&self) -> reqwest::Client {
+        match self {
+            ProxyRouting::Fixed(proxy) => build_http_client(proxy.as_ref()),
+            ProxyRouting::PerHost(routes) => build_routed_http_client(routes),
+            ProxyRouting::Pac(resolver) => build_pac_http_client(resolver.clone()),
+            ProxyRouting::System => build_system_http_client(),
+        }
+    }
+
+    /// Resolve the proxy for a single request URL, for clients that only
+    /// accept one proxy URL up front.
+    ///
+    /// Such clients cannot consult the OS network configuration, so `System`
+    /// falls back to the proxy environment variables here.
+    pub fn proxy_for_url(&self, url: &url::Url) -> Option<ProxyConfig> {
+        match self {
+            ProxyRouting::Fixed(proxy) => proxy.clone(),
+            ProxyRouting::PerHost(routes) => routes.proxy_for(request_host(url)?).cloned(),
+            ProxyRouting::Pac(resolver) => resolver.resolve(url).proxy().cloned(),
+            ProxyRouting::System => system_proxy_for(request_host(url)?),
+        }
+    }
+}
+
+/// Build the HTTP client for an LLM provider. `proxy_routing` takes
+/// precedence over the single resolved `proxy` when set.
+pub fn build_provider_http_client(config: &ProviderConfig) -> reqwest::Client {
+    config.effective_proxy_routing().build_client()
+}
 
 /// Build a `reqwest::Client` with the resolved proxy configuration.
 ///
@@ -26,6 +80,62 @@ pub fn build_http_client(proxy: Option<&ProxyConfig>) -> reqwest::Client {
     builder.build().expect("failed to build reqwest client")
 }
 
+/// Build a `reqwest::Client` that picks a proxy per request by destination
+/// host, following the rules of `routes` (`ProxyStrategy::PerHost`).
+///
+/// Hosts routed `Direct` bypass any proxy, including env-var proxies.
+pub fn build_routed_http_client(routes: &ProxyRoutingTable) -> reqwest::Client {
+    let routes = Arc::new(routes.clone());
+    let proxy = reqwest::Proxy::custom(move |url| routed_proxy_url(&routes, url));
+    reqwest::Client::builder()
+        .no_proxy()
+        .proxy(proxy)
+        .build()
+        .expect("failed to build reqwest client")
+}
+
+/// Resolve the proxy URL (with credentials) for a request URL, or `None`
+/// for a direct connection.
+pub fn routed_proxy_url(routes: &ProxyRoutingTable, url: &url::Url) -> Option<String> {
+    routes
+        .proxy_for(request_host(url)?)
+        .map(ProxyConfig::url_with_auth)
+}
+
+/// Destination host of a request URL, without IPv6 brackets.
+fn request_host(url: &url::Url) -> Option<&str> {
+    Some(
+        url.host_str()?
+            .trim_start_matches('[')
+            .trim_end_matches(']'),
+    )
+}
+
+/// Build a `reqwest::Client` that asks a PAC script for the proxy of each
+/// request (`ProxyStrategy::Pac`). `DIRECT` results bypass any proxy.
+pub fn build_pac_http_client(resolver: Arc<PacResolver>) -> reqwest::Client {
+    let proxy = reqwest::Proxy::custom(move |url| {
+        resolver
+            .resolve(url)
+            .proxy()
+            .map(ProxyConfig::url_with_auth)
+    });
+    reqwest::Client::builder()
+        .no_proxy()
+        .proxy(proxy)
+        .build()
+        .expect("failed to build reqwest client")
+}
+
+/// Build a `reqwest::Client` that follows the operating system proxy
+/// settings (`ProxyStrategy::System`): the `*_PROXY` environment variables
+/// and, on macOS and Windows, the system network configuration.
+pub fn build_system_http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .build()
+        .expect("failed to build reqwest client")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,4 +157,68 @@ mod tests {
         };
         let _client = build_http_client(Some(&cfg));
     }
+
+    #[test]
+    fn test_routed_proxy_url_bypasses_localhost() {
+        use plan_cascade_core::proxy::{ProxyRouteRule, ProxyRouteTarget};
+        use std::collections::HashMap;
+
+        let routes = ProxyRoutingTable {
+            rules: vec![ProxyRouteRule {
+                host: "localhost".to_string(),
+                target: ProxyRouteTarget::Direct,
+            }],
+            default: ProxyRouteTarget::Proxy("corp".to_string()),
+            proxies: HashMap::from([(
+                "corp".to_string(),
+                ProxyConfig {
+                    protocol: ProxyProtocol::Http,
+                    host: "proxy.corp".to_string(),
+                    port: 3128,
+                    username: Some("me".to_string()),
+                    password: Some("pw".to_string()),
+                },
+            )]),
+        };
+
+        let local = url::Url::parse("http://localhost:11434/api/chat").unwrap();
+        assert_eq!(routed_proxy_url(&routes, &local), None);
+
+        let external = url::Url::parse("https://api.anthropic.com/v1/messages").unwrap();
+        assert_eq!(
+            routed_proxy_url(&routes, &external),
+            Some("http://me:pw@proxy.corp:3128".to_string())
+        );
+
+        let _client = build_routed_http_client(&routes);
+
+        // Provider routing is evaluated against the real request host, so a
+        // custom base URL on a direct-routed host bypasses the proxy.
+        let config = ProviderConfig {
+            proxy_routing: Some(ProxyRouting::PerHost(Arc::new(routes))),
+            ..Default::default()
+        };
+        let routing = config.effective_proxy_routing();
+        assert!(routing.proxy_for_url(&local).is_none());
+        assert_eq!(
+            routing.proxy_for_url(&external).map(|p| p.host),
+            Some("proxy.corp".to_string())
+        );
+        let _client = build_provider_http_client(&config);
+    }
+
+    #[test]
+    fn test_build_pac_http_client() {
+        let resolver = Arc::new(PacResolver::new(
+            "function FindProxyForURL(url, host) { return \"DIRECT\"; }",
+        ));
+        let _client = build_pac_http_client(resolver.clone());
+        let _client = build_system_http_client();
+
+        let routing = ProxyRouting::Pac(resolver);
+        let url = url::Url::parse("https://api.anthropic.com/v1/messages").unwrap();
+        assert!(routing.proxy_for_url(&url).is_none());
+        let _client = routing.build_client();
+        let _client = ProxyRouting::System.build_client();
+    }
 }
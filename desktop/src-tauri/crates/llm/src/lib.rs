@@ -9,7 +9,8 @@
 //! - Qwen (DashScope)
 //! - Ollama (local inference)
 //!
-//! Also includes provider-specific streaming adapters and the HTTP client factory.
+//! Also includes provider-specific streaming adapters, the HTTP client factory,
+//! PAC proxy evaluation and a record/replay provider wrapper for tests.
 
 pub mod anthropic;
 pub mod deepseek;
@@ -19,8 +20,10 @@ pub mod minimax;
 pub mod ollama;
 pub mod openai;
 pub mod openai_compat;
+pub mod pac;
 pub mod provider;
 pub mod qwen;
+pub mod recording;
 pub mod reliable_catalog;
 pub mod streaming_adapters;
 pub mod types;
@@ -29,12 +32,16 @@ pub mod types;
 pub use anthropic::AnthropicProvider;
 pub use deepseek::DeepSeekProvider;
 pub use glm::GlmProvider;
-pub use http_client::build_http_client;
+pub use http_client::{
+    build_http_client, build_pac_http_client, build_provider_http_client, build_routed_http_client,
+    build_system_http_client, ProxyRouting,
+};
 pub use minimax::MinimaxProvider;
 pub use ollama::OllamaProvider;
 pub use openai::OpenAIProvider;
 pub use provider::LlmProvider;
 pub use qwen::QwenProvider;
+pub use recording::{RecordingMode, RecordingProvider};
 pub use types::*;
 
 // Re-export streaming adapters
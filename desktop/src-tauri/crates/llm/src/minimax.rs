@@ -18,16 +18,16 @@ use async_trait::async_trait;
 use futures_util::StreamExt;
 use tokio::sync::mpsc;
 
-use super::provider::{missing_api_key_error, parse_http_error, LlmProvider};
+use super::provider::{ensure_image_support, missing_api_key_error, parse_http_error, LlmProvider};
 use super::types::{
     FallbackToolFormatMode, LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message,
     MessageContent, MessageRole, ProviderConfig, StopReason, ToolCall, ToolCallMode,
     ToolCallReliability, ToolDefinition, UsageStats,
 };
-use crate::http_client::build_http_client;
+use crate::http_client::build_provider_http_client;
 use crate::reliable_catalog::is_reliable_model;
 use crate::streaming_adapters::ClaudeApiAdapter;
-use plan_cascade_core::streaming::{StreamAdapter, UnifiedStreamEvent};
+use plan_cascade_core::streaming::{SseLineBuffer, StreamAdapter, UnifiedStreamEvent};
 
 /// Default MiniMax Anthropic-compatible API base URL (global).
 const MINIMAX_ANTHROPIC_BASE_URL: &str = "https://api.minimax.io/anthropic";
@@ -63,7 +63,7 @@ impl MinimaxProvider {
 
         tracing::info!("MiniMax provider initialized: url={}", messages_url);
 
-        let http_client = build_http_client(config.proxy.as_ref());
+        let http_client = build_provider_http_client(&config);
 
         Self {
             config,
@@ -225,7 +225,7 @@ impl MinimaxProvider {
                         }))
                     }
                 }
-                MessageContent::Image { .. } => {
+                MessageContent::Image { .. } | MessageContent::ImageUrl { .. } => {
                     tracing::warn!("MiniMax: Skipping image content - not supported");
                     None
                 }
@@ -465,6 +465,7 @@ impl LlmProvider for MinimaxProvider {
         tools: Vec<ToolDefinition>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         if self.config.api_key.is_none() {
             return Err(missing_api_key_error("minimax"));
         }
@@ -533,6 +534,7 @@ impl LlmProvider for MinimaxProvider {
         tx: mpsc::Sender<UnifiedStreamEvent>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         if self.config.api_key.is_none() {
             return Err(missing_api_key_error("minimax"));
         }
@@ -586,24 +588,15 @@ impl LlmProvider for MinimaxProvider {
         let mut stop_reason = StopReason::EndTurn;
 
         let mut stream = response.bytes_stream();
-        let mut buffer = String::new();
+        let mut buffer = SseLineBuffer::new();
 
         while let Some(chunk) = stream.next().await {
             let chunk = chunk.map_err(|e| LlmError::NetworkError {
                 message: e.to_string(),
             })?;
 
-            buffer.push_str(&String::from_utf8_lossy(&chunk));
-
             // Process complete lines
-            while let Some(line_end) = buffer.find('\n') {
-                let line = buffer[..line_end].to_string();
-                buffer = buffer[line_end + 1..].to_string();
-
-                if line.trim().is_empty() {
-                    continue;
-                }
-
+            for line in buffer.push(&chunk) {
                 match adapter.adapt(&line) {
                     Ok(events) => {
                         for event in events {
@@ -752,6 +745,7 @@ impl LlmProvider for MinimaxProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ProviderCapabilities;
     use anthropic_async::types::{
         ContentBlock as ApiContentBlock, MessagesCreateResponse, Usage as ApiUsage,
     };
@@ -774,6 +768,23 @@ mod tests {
         assert!(provider.supports_tools());
     }
 
+    #[test]
+    fn test_capabilities() {
+        let provider = MinimaxProvider::new(test_config());
+        assert_eq!(
+            provider.capabilities(),
+            ProviderCapabilities {
+                streaming: true,
+                tools: true,
+                json_mode: false,
+                vision: false,
+                prompt_cache: false,
+                thinking: true,
+                max_context: 204_800,
+            }
+        );
+    }
+
     #[test]
     fn test_m2_supports_reasoning() {
         let config = ProviderConfig {
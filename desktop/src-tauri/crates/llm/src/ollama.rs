@@ -11,17 +11,17 @@ use ollama_rs::generation::parameters::ThinkType;
 use ollama_rs::generation::tools::{ToolCallFunction, ToolFunctionInfo, ToolInfo, ToolType};
 use ollama_rs::models::ModelOptions;
 use ollama_rs::Ollama;
+use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tokio_stream::StreamExt;
 
-use super::provider::LlmProvider;
+use super::provider::{ensure_image_support, LlmProvider};
 use super::types::{
     FallbackToolFormatMode, LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message,
     MessageContent, MessageRole, ProviderConfig, StopReason, ToolCall, ToolCallReliability,
     ToolDefinition, UsageStats,
 };
-use crate::http_client::build_http_client;
-use plan_cascade_core::proxy::ProxyConfig;
+use crate::http_client::ProxyRouting;
 use plan_cascade_core::streaming::UnifiedStreamEvent;
 
 /// Default Ollama API endpoint
@@ -30,6 +30,17 @@ const OLLAMA_DEFAULT_URL: &str = "http://localhost:11434";
 /// Models known to support thinking via <think> tags
 const THINKING_MODELS: &[&str] = &["deepseek-r1", "qwq", "qwen-qwq"];
 
+/// Progress update while pulling a model
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModelPullProgress {
+    /// Status message reported by Ollama (e.g. "pulling manifest")
+    pub status: String,
+    /// Bytes downloaded for the current layer
+    pub completed: Option<u64>,
+    /// Total bytes of the current layer
+    pub total: Option<u64>,
+}
+
 /// Ollama provider for local inference using the native ollama-rs SDK
 pub struct OllamaProvider {
     config: ProviderConfig,
@@ -41,7 +52,7 @@ impl OllamaProvider {
     pub fn new(config: ProviderConfig) -> Self {
         let base_url = config.base_url.as_deref().unwrap_or(OLLAMA_DEFAULT_URL);
 
-        let client = Self::create_client(base_url, config.proxy.as_ref());
+        let client = Self::create_client(base_url, config.effective_proxy_routing());
 
         Self { config, client }
     }
@@ -50,8 +61,9 @@ impl OllamaProvider {
     ///
     /// Parses the URL to extract host and port for `Ollama::new()`.
     /// Falls back to `Ollama::default()` if parsing fails.
-    /// When a proxy config is provided, injects a custom reqwest client.
-    fn create_client(base_url: &str, proxy: Option<&ProxyConfig>) -> Ollama {
+    /// Unless the routing is a plain direct connection, injects a custom
+    /// reqwest client that applies it.
+    fn create_client(base_url: &str, routing: ProxyRouting) -> Ollama {
         // Try to parse the URL to extract host and port
         if let Ok(parsed) = url::Url::parse(base_url) {
             let scheme = parsed.scheme();
@@ -59,11 +71,9 @@ impl OllamaProvider {
             let port = parsed.port().unwrap_or(11434);
             // Reconstruct the host URL without port (Ollama::new takes them separately)
             let host_url = format!("{}://{}", scheme, host);
-            if proxy.is_some() {
-                let http_client = build_http_client(proxy);
-                Ollama::new_with_client(host_url, port, http_client)
-            } else {
-                Ollama::new(host_url, port)
+            match routing {
+                ProxyRouting::Fixed(None) => Ollama::new(host_url, port),
+                routing => Ollama::new_with_client(host_url, port, routing.build_client()),
             }
         } else {
             Ollama::default()
@@ -78,6 +88,63 @@ impl OllamaProvider {
             .unwrap_or(OLLAMA_DEFAULT_URL)
     }
 
+    /// Map an SDK error for a server-level call (listing, pulling)
+    fn map_server_error(&self, error: impl std::fmt::Display) -> LlmError {
+        let msg = error.to_string();
+        if msg.contains("connect") || msg.contains("Connection refused") {
+            LlmError::ProviderUnavailable {
+                message: format!("Cannot connect to Ollama at {}", self.base_url()),
+            }
+        } else {
+            LlmError::NetworkError { message: msg }
+        }
+    }
+
+    /// Names of the models pulled into the local Ollama server
+    async fn local_model_names(&self) -> LlmResult<Vec<String>> {
+        let models = self
+            .client
+            .list_local_models()
+            .await
+            .map_err(|e| self.map_server_error(e))?;
+        Ok(models.into_iter().map(|m| m.name).collect())
+    }
+
+    /// Fail with `ModelNotFound` (listing `available`) when the configured
+    /// model has not been pulled.
+    fn ensure_model_available(&self, available: &[String]) -> LlmResult<()> {
+        let model = self.config.model.trim();
+        if model.is_empty() || model_is_available(model, available) {
+            return Ok(());
+        }
+        Err(LlmError::ModelNotFound {
+            model: model.to_string(),
+            available: available.to_vec(),
+        })
+    }
+
+    /// Pull the configured model into the local server, sending progress
+    /// updates on `progress` until the download completes.
+    pub async fn pull_model(&self, progress: mpsc::Sender<ModelPullProgress>) -> LlmResult<()> {
+        let mut stream = self
+            .client
+            .pull_model_stream(self.config.model.clone(), false)
+            .await
+            .map_err(|e| self.map_server_error(e))?;
+
+        while let Some(status) = stream.next().await {
+            let status = status.map_err(|e| self.map_server_error(e))?;
+            let _ = progress
+                .send(ModelPullProgress {
+                    status: status.message,
+                    completed: status.completed,
+                    total: status.total,
+                })
+                .await;
+        }
+        Ok(())
+    }
+
     /// Check if model supports thinking
     fn model_supports_thinking(&self) -> bool {
         let model_lower = self.config.model.to_lowercase();
@@ -124,6 +191,12 @@ impl OllamaProvider {
         if self.config.max_tokens > 0 {
             opts = opts.num_predict(self.config.max_tokens as i32);
         }
+        if let Some(top_p) = self.config.top_p {
+            opts = opts.top_p(top_p);
+        }
+        if let Some(seed) = self.config.seed {
+            opts = opts.seed(seed as i32);
+        }
         request = request.options(opts);
 
         // Enable thinking for models that support it
@@ -179,7 +252,9 @@ impl OllamaProvider {
                     // Include thinking content in text for models that support it
                     text_parts.push(thinking.clone());
                 }
-                MessageContent::Image { .. } | MessageContent::ToolResultMultimodal { .. } => {
+                MessageContent::Image { .. }
+                | MessageContent::ImageUrl { .. }
+                | MessageContent::ToolResultMultimodal { .. } => {
                     // Images/multimodal not fully supported by Ollama SDK in text mode
                     // Skip or convert to text placeholder
                 }
@@ -561,6 +636,15 @@ impl LlmProvider for OllamaProvider {
         true
     }
 
+    fn supports_json_mode(&self) -> bool {
+        // Native `format: json`
+        true
+    }
+
+    fn supports_seed(&self) -> bool {
+        true
+    }
+
     fn tool_call_reliability(&self) -> ToolCallReliability {
         ToolCallReliability::Reliable
     }
@@ -583,6 +667,7 @@ impl LlmProvider for OllamaProvider {
         tools: Vec<ToolDefinition>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let request =
             self.build_chat_request(&messages, system.as_deref(), &tools, &request_options);
 
@@ -595,6 +680,7 @@ impl LlmProvider for OllamaProvider {
             } else if msg.contains("not found") || msg.contains("404") {
                 LlmError::ModelNotFound {
                     model: self.config.model.clone(),
+                    available: Vec::new(),
                 }
             } else {
                 LlmError::NetworkError { message: msg }
@@ -612,6 +698,7 @@ impl LlmProvider for OllamaProvider {
         tx: mpsc::Sender<UnifiedStreamEvent>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let request =
             self.build_chat_request(&messages, system.as_deref(), &tools, &request_options);
 
@@ -632,6 +719,7 @@ impl LlmProvider for OllamaProvider {
                 } else if msg.contains("not found") || msg.contains("404") {
                     LlmError::ModelNotFound {
                         model: self.config.model.clone(),
+                        available: Vec::new(),
                     }
                 } else {
                     LlmError::NetworkError { message: msg }
@@ -717,19 +805,14 @@ impl LlmProvider for OllamaProvider {
     }
 
     async fn health_check(&self) -> LlmResult<()> {
-        // Use the SDK's list_local_models as a health check
-        self.client.list_local_models().await.map_err(|e| {
-            let msg = e.to_string();
-            if msg.contains("connect") || msg.contains("Connection refused") {
-                LlmError::ProviderUnavailable {
-                    message: format!("Cannot connect to Ollama at {}", self.base_url()),
-                }
-            } else {
-                LlmError::NetworkError { message: msg }
-            }
-        })?;
+        // Listing local models confirms the server is up and lets us verify
+        // the configured model has been pulled.
+        let available = self.local_model_names().await?;
+        self.ensure_model_available(&available)
+    }
 
-        Ok(())
+    async fn preflight(&self) -> LlmResult<()> {
+        self.health_check().await
     }
 
     fn config(&self) -> &ProviderConfig {
@@ -737,26 +820,37 @@ impl LlmProvider for OllamaProvider {
     }
 
     async fn list_models(&self) -> LlmResult<Option<Vec<String>>> {
-        let models = self.client.list_local_models().await.map_err(|e| {
-            let msg = e.to_string();
-            if msg.contains("connect") || msg.contains("Connection refused") {
-                LlmError::ProviderUnavailable {
-                    message: format!("Cannot connect to Ollama at {}", self.base_url()),
-                }
-            } else {
-                LlmError::NetworkError { message: msg }
-            }
-        })?;
+        Ok(Some(self.local_model_names().await?))
+    }
+}
 
-        let model_names: Vec<String> = models.into_iter().map(|m| m.name).collect();
+/// Whether `model` is among the locally available model names.
+///
+/// Ollama reports names with an explicit tag, so an untagged name such as
+/// `llama3.2` matches `llama3.2:latest`.
+pub fn model_is_available(model: &str, available: &[String]) -> bool {
+    let wanted = with_default_tag(model);
+    available
+        .iter()
+        .any(|name| with_default_tag(name) == wanted)
+}
 
-        Ok(Some(model_names))
+fn with_default_tag(name: &str) -> String {
+    let name = name.trim();
+    // Registry hosts may carry a port (`host:5000/model`), so only look for a
+    // tag in the last path segment.
+    let last_segment = name.rsplit('/').next().unwrap_or(name);
+    if last_segment.contains(':') {
+        name.to_string()
+    } else {
+        format!("{}:latest", name)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ProviderCapabilities;
 
     fn test_config() -> ProviderConfig {
         ProviderConfig {
@@ -781,6 +875,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_capabilities() {
+        let provider = OllamaProvider::new(test_config());
+        assert_eq!(
+            provider.capabilities(),
+            ProviderCapabilities {
+                streaming: true,
+                tools: true,
+                json_mode: true,
+                vision: false,
+                prompt_cache: false,
+                thinking: false,
+                max_context: 8_192,
+            }
+        );
+    }
+
     #[test]
     fn test_thinking_model() {
         let config = ProviderConfig {
@@ -890,4 +1001,93 @@ mod tests {
         let provider = OllamaProvider::new(test_config());
         assert_eq!(provider.context_window(), 8_192);
     }
+
+    /// Serve `/api/tags` with the given model names on a local port.
+    fn serve_local_models(names: &[&str]) -> String {
+        use std::io::{Read, Write};
+
+        let models: Vec<serde_json::Value> = names
+            .iter()
+            .map(|name| {
+                serde_json::json!({
+                    "name": name,
+                    "modified_at": "2026-01-01T00:00:00Z",
+                    "size": 1,
+                })
+            })
+            .collect();
+        let body = serde_json::json!({ "models": models }).to_string();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buffer = [0u8; 4096];
+                let _ = stream.read(&mut buffer);
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    fn provider_for(model: &str, base_url: String) -> OllamaProvider {
+        OllamaProvider::new(ProviderConfig {
+            model: model.to_string(),
+            base_url: Some(base_url),
+            ..test_config()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_health_check_reports_missing_model_with_available_list() {
+        let base_url = serve_local_models(&["llama3.2:latest", "qwen2.5-coder:7b"]);
+        let provider = provider_for("mistral", base_url);
+
+        match provider.health_check().await {
+            Err(LlmError::ModelNotFound { model, available }) => {
+                assert_eq!(model, "mistral");
+                assert_eq!(available, vec!["llama3.2:latest", "qwen2.5-coder:7b"]);
+            }
+            other => panic!("expected ModelNotFound, got {:?}", other),
+        }
+        let message = provider.preflight().await.unwrap_err().to_string();
+        assert!(message.contains("llama3.2:latest, qwen2.5-coder:7b"));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_succeeds_when_model_is_present() {
+        let base_url = serve_local_models(&["llama3.2:latest", "qwen2.5-coder:7b"]);
+
+        assert!(provider_for("llama3.2", base_url.clone())
+            .health_check()
+            .await
+            .is_ok());
+        assert!(provider_for("qwen2.5-coder:7b", base_url)
+            .preflight()
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    fn test_model_is_available_tag_matching() {
+        let available = vec![
+            "llama3.2:latest".to_string(),
+            "registry.local:5000/team/coder:v1".to_string(),
+        ];
+        assert!(model_is_available("llama3.2", &available));
+        assert!(model_is_available("llama3.2:latest", &available));
+        assert!(!model_is_available("llama3.2:1b", &available));
+        assert!(model_is_available(
+            "registry.local:5000/team/coder:v1",
+            &available
+        ));
+        assert!(!model_is_available(
+            "registry.local:5000/team/coder",
+            &available
+        ));
+    }
 }
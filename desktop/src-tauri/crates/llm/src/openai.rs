@@ -62,20 +62,22 @@ impl OpenAIProvider {
             "stream": stream,
         });
 
-        // Add temperature (not for o1/o3 models)
+        // Add sampling parameters (not for o1/o3 models)
         if !self.model_supports_reasoning() {
             body["temperature"] = serde_json::json!(request_options
                 .temperature_override
                 .unwrap_or(self.config.temperature));
+            if let Some(top_p) = self.config.top_p {
+                body["top_p"] = serde_json::json!(top_p);
+            }
+        }
+        if let Some(seed) = self.config.seed {
+            body["seed"] = serde_json::json!(seed);
         }
 
         // Add reasoning effort for o1/o3 models
         if self.model_supports_reasoning() {
-            if let Some(effort) = request_options
-                .reasoning_effort_override
-                .as_ref()
-                .or(self.config.reasoning_effort.as_ref())
-            {
+            if let Some(effort) = request_options.resolve_reasoning_effort(&self.config) {
                 body["reasoning_effort"] = serde_json::json!(effort);
             }
         }
@@ -222,12 +224,7 @@ impl OpenAIProvider {
         }
 
         // Check if message contains images (multimodal)
-        let has_images = message
-            .content
-            .iter()
-            .any(|c| matches!(c, MessageContent::Image { .. }));
-
-        if has_images {
+        if message.has_images() {
             // Build multimodal content array for OpenAI vision
             let content_parts: Vec<serde_json::Value> = message
                 .content
@@ -243,6 +240,10 @@ impl OpenAIProvider {
                             "url": format!("data:{};base64,{}", media_type, data)
                         }
                     })),
+                    MessageContent::ImageUrl { url } => Some(serde_json::json!({
+                        "type": "image_url",
+                        "image_url": { "url": url }
+                    })),
                     _ => None,
                 })
                 .collect();
@@ -325,7 +326,7 @@ impl OpenAIProvider {
             .map(|u| UsageStats {
                 input_tokens: u.prompt_tokens,
                 output_tokens: u.completion_tokens,
-                thinking_tokens: u.reasoning_tokens,
+                thinking_tokens: u.reasoning_tokens(),
                 cache_read_tokens: None,
                 cache_creation_tokens: None,
             })
@@ -361,6 +362,19 @@ impl LlmProvider for OpenAIProvider {
         true
     }
 
+    fn supports_json_mode(&self) -> bool {
+        true
+    }
+
+    fn supports_seed(&self) -> bool {
+        true
+    }
+
+    fn supports_prompt_cache(&self) -> bool {
+        // Automatic prompt caching for repeated prefixes
+        true
+    }
+
     fn supports_multimodal(&self) -> bool {
         true
     }
@@ -613,11 +627,31 @@ struct ResponseUsage {
     completion_tokens: u32,
     #[serde(default)]
     reasoning_tokens: Option<u32>,
+    #[serde(default)]
+    completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: Option<u32>,
+}
+
+impl ResponseUsage {
+    /// o-series models report reasoning tokens under `completion_tokens_details`;
+    /// some compatible endpoints put them at the top level.
+    fn reasoning_tokens(&self) -> Option<u32> {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|d| d.reasoning_tokens)
+            .or(self.reasoning_tokens)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ProviderCapabilities;
 
     fn test_config() -> ProviderConfig {
         ProviderConfig {
@@ -637,6 +671,23 @@ mod tests {
         assert!(provider.supports_tools());
     }
 
+    #[test]
+    fn test_capabilities() {
+        let provider = OpenAIProvider::new(test_config());
+        assert_eq!(
+            provider.capabilities(),
+            ProviderCapabilities {
+                streaming: true,
+                tools: true,
+                json_mode: true,
+                vision: true,
+                prompt_cache: true,
+                thinking: false,
+                max_context: 8_192,
+            }
+        );
+    }
+
     #[test]
     fn test_o1_supports_reasoning() {
         let config = ProviderConfig {
@@ -647,6 +698,92 @@ mod tests {
         assert!(provider.supports_thinking());
     }
 
+    #[test]
+    fn test_reasoning_effort_from_request_options() {
+        let provider = OpenAIProvider::new(ProviderConfig {
+            model: "o3-mini".to_string(),
+            reasoning_effort: Some("medium".to_string()),
+            ..test_config()
+        });
+        let messages = vec![Message::user("Hello")];
+
+        let body =
+            provider.build_request_body(&messages, None, &[], false, &LlmRequestOptions::default());
+        assert_eq!(body["reasoning_effort"], "medium");
+        assert!(body.get("temperature").is_none());
+
+        let low = LlmRequestOptions {
+            thinking_budget_override: Some(1_024),
+            ..Default::default()
+        };
+        let body = provider.build_request_body(&messages, None, &[], false, &low);
+        assert_eq!(body["reasoning_effort"], "low");
+
+        let gpt4 = OpenAIProvider::new(test_config());
+        let high = LlmRequestOptions {
+            reasoning_effort_override: Some("high".to_string()),
+            ..Default::default()
+        };
+        let body = gpt4.build_request_body(&messages, None, &[], false, &high);
+        assert!(body.get("reasoning_effort").is_none());
+    }
+
+    #[test]
+    fn test_seed_and_sampling_forwarded() {
+        let provider = OpenAIProvider::new(ProviderConfig {
+            model: "gpt-4o".to_string(),
+            temperature: 0.0,
+            top_p: Some(0.9),
+            seed: Some(1234),
+            ..test_config()
+        });
+        assert!(provider.supports_seed());
+
+        let body = provider.build_request_body(
+            &[Message::user("Hello")],
+            None,
+            &[],
+            false,
+            &LlmRequestOptions::default(),
+        );
+        assert_eq!(body["seed"], 1234);
+        assert_eq!(body["temperature"], 0.0);
+        assert!((body["top_p"].as_f64().unwrap() - 0.9).abs() < 1e-6);
+
+        let plain = OpenAIProvider::new(test_config()).build_request_body(
+            &[Message::user("Hello")],
+            None,
+            &[],
+            false,
+            &LlmRequestOptions::default(),
+        );
+        assert!(plain.get("seed").is_none());
+        assert!(plain.get("top_p").is_none());
+    }
+
+    #[test]
+    fn test_parse_response_reports_reasoning_tokens() {
+        let provider = OpenAIProvider::new(test_config());
+        let response: OpenAIResponse = serde_json::from_value(serde_json::json!({
+            "model": "o3-mini",
+            "choices": [{
+                "message": { "content": "42" },
+                "finish_reason": "stop"
+            }],
+            "usage": {
+                "prompt_tokens": 10,
+                "completion_tokens": 300,
+                "completion_tokens_details": { "reasoning_tokens": 256 }
+            }
+        }))
+        .unwrap();
+
+        let parsed = provider.parse_response(&response);
+        assert_eq!(parsed.content.as_deref(), Some("42"));
+        assert_eq!(parsed.usage.output_tokens, 300);
+        assert_eq!(parsed.usage.thinking_tokens, Some(256));
+    }
+
     #[test]
     fn test_message_conversion() {
         let provider = OpenAIProvider::new(test_config());
@@ -657,6 +794,32 @@ mod tests {
         assert_eq!(openai_msg["content"], "Hello!");
     }
 
+    #[test]
+    fn test_image_message_conversion() {
+        let provider = OpenAIProvider::new(test_config());
+
+        let inline = provider.message_to_openai(&Message::user_with_image(
+            "What is this?",
+            "image/png",
+            "iVBORw0KGgo=",
+        ));
+        assert_eq!(inline["content"][0]["type"], "text");
+        assert_eq!(inline["content"][1]["type"], "image_url");
+        assert_eq!(
+            inline["content"][1]["image_url"]["url"],
+            "data:image/png;base64,iVBORw0KGgo="
+        );
+
+        let linked = provider.message_to_openai(&Message::user_with_image_url(
+            "And this?",
+            "https://example.com/cat.png",
+        ));
+        assert_eq!(
+            linked["content"][1]["image_url"]["url"],
+            "https://example.com/cat.png"
+        );
+    }
+
     #[test]
     fn test_tool_conversion() {
         let provider = OpenAIProvider::new(test_config());
@@ -98,12 +98,18 @@ pub fn build_client(
         .unwrap_or(default_chat_completions_url);
     let endpoint = normalize_endpoint(chat_url, provider, strict_chat_completions_url)?;
 
+    // The SDK takes a single proxy URL, so resolve the routing against the
+    // endpoint this client will call.
+    let proxy = url::Url::parse(&endpoint)
+        .ok()
+        .and_then(|url| config.effective_proxy_routing().proxy_for_url(&url));
+
     let mut builder = OpenAIClient::builder()
         .with_api_key(api_key.clone())
         .with_endpoint(endpoint);
 
-    if let Some(proxy) = config.proxy.as_ref() {
-        builder = builder.with_proxy(proxy.url());
+    if let Some(proxy) = proxy.as_ref() {
+        builder = builder.with_proxy(proxy.url_with_auth());
     } else {
         builder = builder.with_no_proxy(true);
     }
@@ -0,0 +1,212 @@
+//! PAC (Proxy Auto-Config) Evaluation
+//!
+//! Evaluates a PAC script's `FindProxyForURL(url, host)` to choose a proxy
+//! per destination (`ProxyStrategy::Pac`). Results are cached per host, and
+//! any evaluation error falls back to a direct connection.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use boa_engine::{Context, Source};
+use plan_cascade_core::proxy::{parse_pac_result, PacDirective};
+
+use crate::http_client::build_http_client;
+
+/// Standard PAC helper functions, defined before the user script.
+///
+/// `dnsResolve`/`isInNet` only understand literal IPv4 addresses; host
+/// names are not resolved so evaluation never blocks on DNS.
+const PAC_PRELUDE: &str = r#"
+function isPlainHostName(host) { return host.indexOf('.') < 0; }
+function dnsDomainIs(host, domain) {
+    host = host.toLowerCase(); domain = domain.toLowerCase();
+    return host.length >= domain.length && host.substring(host.length - domain.length) === domain;
+}
+function localHostOrDomainIs(host, hostdom) {
+    return host === hostdom || hostdom.lastIndexOf(host + '.', 0) === 0;
+}
+function dnsDomainLevels(host) { return host.split('.').length - 1; }
+function shExpMatch(str, shexp) {
+    var re = shexp.replace(/[.+^${}()|[\]\\]/g, '\\$&').replace(/\*/g, '.*').replace(/\?/g, '.');
+    return new RegExp('^' + re + '$').test(str);
+}
+function dnsResolve(host) { return /^\d+\.\d+\.\d+\.\d+$/.test(host) ? host : null; }
+function isResolvable(host) { return dnsResolve(host) !== null; }
+function myIpAddress() { return '127.0.0.1'; }
+function __pacIpToInt(ip) {
+    var p = ip.split('.');
+    return ((+p[0] << 24) >>> 0) + (+p[1] << 16) + (+p[2] << 8) + (+p[3]);
+}
+function isInNet(host, pattern, mask) {
+    var ip = dnsResolve(host);
+    if (ip === null) return false;
+    var m = __pacIpToInt(mask);
+    return ((__pacIpToInt(ip) & m) >>> 0) === ((__pacIpToInt(pattern) & m) >>> 0);
+}
+function weekdayRange() { return true; }
+function dateRange() { return true; }
+function timeRange() { return true; }
+"#;
+
+/// Evaluates a PAC script with a per-host result cache.
+pub struct PacResolver {
+    script: String,
+    cache: Mutex<HashMap<String, PacDirective>>,
+}
+
+impl std::fmt::Debug for PacResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PacResolver")
+            .field("script_len", &self.script.len())
+            .field("cached_hosts", &self.cached_hosts())
+            .finish()
+    }
+}
+
+impl PacResolver {
+    /// Create a resolver from PAC script source.
+    pub fn new(script: impl Into<String>) -> Self {
+        Self {
+            script: script.into(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Download a PAC script. The download itself never uses a proxy.
+    pub async fn fetch(pac_url: &str) -> Result<Self, String> {
+        let response = build_http_client(None)
+            .get(pac_url)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to download PAC file: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!(
+                "Failed to download PAC file: HTTP {}",
+                response.status()
+            ));
+        }
+        let script = response
+            .text()
+            .await
+            .map_err(|e| format!("Failed to read PAC file: {}", e))?;
+        Ok(Self::new(script))
+    }
+
+    /// The PAC script source.
+    pub fn script(&self) -> &str {
+        &self.script
+    }
+
+    /// Choose the proxy for a request URL, consulting the per-host cache.
+    pub fn resolve(&self, url: &url::Url) -> PacDirective {
+        let host = url
+            .host_str()
+            .unwrap_or_default()
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .to_ascii_lowercase();
+        if let Some(hit) = self.cache.lock().unwrap().get(&host) {
+            return hit.clone();
+        }
+
+        let directive = match self.evaluate(url.as_str(), &host) {
+            Ok(result) => parse_pac_result(&result),
+            Err(e) => {
+                tracing::warn!("PAC evaluation failed for {}: {}; using DIRECT", host, e);
+                PacDirective::Direct
+            }
+        };
+        self.cache.lock().unwrap().insert(host, directive.clone());
+        directive
+    }
+
+    /// Run `FindProxyForURL(url, host)` and return its raw result string.
+    pub fn evaluate(&self, url: &str, host: &str) -> Result<String, String> {
+        let mut context = Context::default();
+        context
+            .eval(Source::from_bytes(PAC_PRELUDE))
+            .map_err(|e| e.to_string())?;
+        context
+            .eval(Source::from_bytes(&self.script))
+            .map_err(|e| format!("invalid PAC script: {}", e))?;
+
+        let call = format!(
+            "FindProxyForURL({}, {})",
+            serde_json::to_string(url).map_err(|e| e.to_string())?,
+            serde_json::to_string(host).map_err(|e| e.to_string())?
+        );
+        let value = context
+            .eval(Source::from_bytes(&call))
+            .map_err(|e| e.to_string())?;
+        let result = value.to_string(&mut context).map_err(|e| e.to_string())?;
+        Ok(result.to_std_string_escaped())
+    }
+
+    /// Number of hosts with a cached decision.
+    pub fn cached_hosts(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = r#"
+        function FindProxyForURL(url, host) {
+            if (isPlainHostName(host) || dnsDomainIs(host, ".internal.corp")) {
+                return "DIRECT";
+            }
+            if (shExpMatch(host, "*.anthropic.com")) {
+                return "PROXY proxy.corp:3128; DIRECT";
+            }
+            return "DIRECT";
+        }
+    "#;
+
+    fn url(s: &str) -> url::Url {
+        url::Url::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_pac_returns_proxy_for_matching_host() {
+        let resolver = PacResolver::new(SCRIPT);
+        let directive = resolver.resolve(&url("https://api.anthropic.com/v1/messages"));
+        assert_eq!(
+            directive.proxy().map(|p| p.url()),
+            Some("http://proxy.corp:3128".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pac_returns_direct_for_other_host() {
+        let resolver = PacResolver::new(SCRIPT);
+        assert!(resolver
+            .resolve(&url("http://wiki.internal.corp/page"))
+            .proxy()
+            .is_none());
+        assert!(resolver
+            .resolve(&url("http://localhost:11434/api"))
+            .proxy()
+            .is_none());
+    }
+
+    #[test]
+    fn test_pac_results_cached_per_host() {
+        let resolver = PacResolver::new(SCRIPT);
+        resolver.resolve(&url("https://api.anthropic.com/a"));
+        resolver.resolve(&url("https://api.anthropic.com/b"));
+        resolver.resolve(&url("https://example.com/"));
+        assert_eq!(resolver.cached_hosts(), 2);
+    }
+
+    #[test]
+    fn test_invalid_pac_falls_back_to_direct() {
+        let resolver = PacResolver::new("function FindProxyForURL(url, host) {");
+        assert!(resolver
+            .resolve(&url("https://api.anthropic.com/"))
+            .proxy()
+            .is_none());
+    }
+}
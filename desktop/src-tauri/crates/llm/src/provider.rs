@@ -7,7 +7,7 @@ use tokio::sync::mpsc;
 
 use super::types::{
     FallbackToolFormatMode, LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message,
-    ProviderConfig, ToolCallReliability, ToolDefinition,
+    ProviderCapabilities, ProviderConfig, ProviderFeature, ToolCallReliability, ToolDefinition,
 };
 use plan_cascade_core::streaming::UnifiedStreamEvent;
 
@@ -77,6 +77,60 @@ pub trait LlmProvider: Send + Sync {
         128_000
     }
 
+    /// Returns whether the API can constrain output to valid JSON.
+    fn supports_json_mode(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the API accepts a sampling seed.
+    ///
+    /// When false, `ProviderConfig::seed` is ignored and identical requests
+    /// may still produce different output, even at temperature 0.
+    fn supports_seed(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the API caches repeated prompt prefixes.
+    fn supports_prompt_cache(&self) -> bool {
+        false
+    }
+
+    /// Returns the full feature set for the configured model.
+    ///
+    /// Built from the individual `supports_*` methods; override those rather
+    /// than this method.
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            tools: self.supports_tools(),
+            json_mode: self.supports_json_mode(),
+            vision: self.supports_multimodal(),
+            prompt_cache: self.supports_prompt_cache(),
+            thinking: self.supports_thinking(),
+            max_context: self.context_window(),
+        }
+    }
+
+    /// Fail with `InvalidRequest` if any required feature is unsupported.
+    fn ensure_capabilities(&self, required: &[ProviderFeature]) -> LlmResult<()> {
+        let missing = self.capabilities().missing(required);
+        if missing.is_empty() {
+            return Ok(());
+        }
+        Err(LlmError::InvalidRequest {
+            message: format!(
+                "{} model '{}' does not support: {}",
+                self.name(),
+                self.model(),
+                missing
+                    .iter()
+                    .map(ProviderFeature::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        })
+    }
+
     /// Send a message and get a complete response.
     ///
     /// # Arguments
@@ -119,6 +173,14 @@ pub trait LlmProvider: Send + Sync {
     /// For Ollama, this checks if the server is running.
     async fn health_check(&self) -> LlmResult<()>;
 
+    /// Verify the provider can serve the configured model before execution
+    /// starts, so problems surface up front instead of mid-stream.
+    ///
+    /// The default is a no-op; Ollama checks the model has been pulled.
+    async fn preflight(&self) -> LlmResult<()> {
+        Ok(())
+    }
+
     /// Get the configuration for this provider.
     fn config(&self) -> &ProviderConfig;
 
@@ -130,6 +192,16 @@ pub trait LlmProvider: Send + Sync {
     }
 }
 
+/// Reject image content for providers without vision support instead of
+/// silently dropping it from the request.
+pub fn ensure_image_support(provider: &dyn LlmProvider, messages: &[Message]) -> LlmResult<()> {
+    if messages.iter().any(Message::has_images) {
+        provider.ensure_capabilities(&[ProviderFeature::Vision])
+    } else {
+        Ok(())
+    }
+}
+
 /// Helper function to create an error for missing API key
 pub fn missing_api_key_error(provider: &str) -> LlmError {
     LlmError::AuthenticationFailed {
@@ -150,6 +222,7 @@ pub fn parse_http_error(status: u16, body: &str, provider: &str) -> LlmError {
             // Try to extract model name from body
             LlmError::ModelNotFound {
                 model: body.to_string(),
+                available: Vec::new(),
             }
         }
         429 => {
@@ -198,4 +271,136 @@ mod tests {
         let err = parse_http_error(500, "internal error", "openai");
         assert!(matches!(err, LlmError::ServerError { .. }));
     }
+
+    struct StubProvider {
+        name: &'static str,
+        vision: bool,
+        config: ProviderConfig,
+    }
+
+    impl StubProvider {
+        fn new(name: &'static str, vision: bool) -> Self {
+            Self {
+                name,
+                vision,
+                config: ProviderConfig {
+                    model: format!("{}-model", name),
+                    ..Default::default()
+                },
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for StubProvider {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn model(&self) -> &str {
+            &self.config.model
+        }
+
+        fn supports_thinking(&self) -> bool {
+            false
+        }
+
+        fn supports_tools(&self) -> bool {
+            true
+        }
+
+        fn supports_multimodal(&self) -> bool {
+            self.vision
+        }
+
+        async fn send_message(
+            &self,
+            _messages: Vec<Message>,
+            _system: Option<String>,
+            _tools: Vec<ToolDefinition>,
+            _request_options: LlmRequestOptions,
+        ) -> LlmResult<LlmResponse> {
+            Err(LlmError::Other {
+                message: "stub".to_string(),
+            })
+        }
+
+        async fn stream_message(
+            &self,
+            _messages: Vec<Message>,
+            _system: Option<String>,
+            _tools: Vec<ToolDefinition>,
+            _tx: mpsc::Sender<UnifiedStreamEvent>,
+            _request_options: LlmRequestOptions,
+        ) -> LlmResult<LlmResponse> {
+            Err(LlmError::Other {
+                message: "stub".to_string(),
+            })
+        }
+
+        async fn health_check(&self) -> LlmResult<()> {
+            Ok(())
+        }
+
+        fn config(&self) -> &ProviderConfig {
+            &self.config
+        }
+    }
+
+    fn image_message() -> Message {
+        Message {
+            role: crate::types::MessageRole::User,
+            content: vec![crate::types::MessageContent::Image {
+                media_type: "image/png".to_string(),
+                data: "AAAA".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_default_capabilities_follow_supports_methods() {
+        let caps = StubProvider::new("text", false).capabilities();
+        assert!(caps.streaming);
+        assert!(caps.tools);
+        assert!(!caps.vision);
+        assert!(!caps.json_mode);
+        assert!(!caps.prompt_cache);
+        assert_eq!(caps.max_context, 128_000);
+    }
+
+    #[test]
+    fn test_ensure_capabilities_refuses_images_for_text_only_model() {
+        let required = crate::types::required_features(&[image_message()], &[]);
+        assert_eq!(required, vec![ProviderFeature::Vision]);
+
+        let err = StubProvider::new("text", false)
+            .ensure_capabilities(&required)
+            .unwrap_err();
+        match err {
+            LlmError::InvalidRequest { message } => assert!(message.contains("vision")),
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+        assert!(StubProvider::new("vision", true)
+            .ensure_capabilities(&required)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_ensure_image_support_only_checks_messages_with_images() {
+        let text_only = StubProvider::new("text", false);
+        assert!(ensure_image_support(&text_only, &[Message::user("hi")]).is_ok());
+
+        let url_message = Message::user_with_image_url("describe", "https://example.com/a.png");
+        let err = ensure_image_support(&text_only, std::slice::from_ref(&url_message)).unwrap_err();
+        match err {
+            LlmError::InvalidRequest { message } => {
+                assert!(message.contains("text-model"));
+                assert!(message.contains("vision"));
+            }
+            other => panic!("Expected InvalidRequest, got {:?}", other),
+        }
+
+        let vision = StubProvider::new("vision", true);
+        assert!(ensure_image_support(&vision, &[image_message(), url_message]).is_ok());
+    }
 }
@@ -12,13 +12,14 @@ use tokio::sync::mpsc;
 use super::openai_compat::{
     build_client, map_api_error, value_to_chat_request, value_to_chat_stream_request,
 };
-use super::provider::LlmProvider;
+use super::provider::{ensure_image_support, LlmProvider};
 use super::types::{
     FallbackToolFormatMode, LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message,
     MessageContent, MessageRole, ProviderConfig, StopReason, ToolCall, ToolCallMode,
     ToolCallReliability, ToolDefinition, UsageStats,
 };
 use crate::reliable_catalog::is_reliable_model;
+use crate::streaming_adapters::qwen::IncrementalText;
 use plan_cascade_core::streaming::UnifiedStreamEvent;
 
 /// Default DashScope OpenAI-compatible endpoint.
@@ -285,6 +286,12 @@ impl QwenProvider {
             "temperature": request_options.temperature_override.unwrap_or(self.config.temperature),
             "messages": self.build_input_messages_json(messages, system),
         });
+        if let Some(top_p) = self.config.top_p {
+            body["top_p"] = serde_json::json!(top_p);
+        }
+        if let Some(seed) = self.config.seed {
+            body["seed"] = serde_json::json!(seed);
+        }
 
         if !tools.is_empty() {
             body["tools"] = serde_json::json!(Self::convert_tools(tools));
@@ -299,13 +306,14 @@ impl QwenProvider {
                 "include_usage": true
             });
             // DashScope compatible-mode may return non-incremental chunks unless
-            // this flag is set. Keep it enabled to improve real-time rendering.
+            // this flag is set. Always request delta mode; the stream loop still
+            // de-duplicates cumulative chunks from endpoints that ignore it.
             body["incremental_output"] = serde_json::json!(true);
         }
 
         if self.config.enable_thinking && self.model_supports_reasoning() {
             body["enable_thinking"] = serde_json::json!(true);
-            if let Some(budget) = self.config.thinking_budget {
+            if let Some(budget) = request_options.resolve_thinking_budget(&self.config) {
                 body["thinking_budget"] = serde_json::json!(budget);
             }
         }
@@ -405,6 +413,14 @@ impl LlmProvider for QwenProvider {
         true
     }
 
+    fn supports_json_mode(&self) -> bool {
+        true
+    }
+
+    fn supports_seed(&self) -> bool {
+        true
+    }
+
     fn supports_native_search(&self) -> bool {
         self.native_search_enabled()
     }
@@ -469,6 +485,7 @@ impl LlmProvider for QwenProvider {
         tools: Vec<ToolDefinition>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let body = self.build_request_body(
             &messages,
             system.as_deref(),
@@ -504,6 +521,7 @@ impl LlmProvider for QwenProvider {
         tx: mpsc::Sender<UnifiedStreamEvent>,
         request_options: LlmRequestOptions,
     ) -> LlmResult<LlmResponse> {
+        ensure_image_support(self, &messages)?;
         let body =
             self.build_request_body(&messages, system.as_deref(), &tools, true, &request_options);
         let request = value_to_chat_stream_request("qwen", body)?;
@@ -519,6 +537,8 @@ impl LlmProvider for QwenProvider {
         let usage = UsageStats::default();
         let mut stop_reason = StopReason::EndTurn;
         let mut in_thinking = false;
+        let mut content_text = IncrementalText::default();
+        let mut reasoning_text = IncrementalText::default();
 
         let mut pending_tools: HashMap<String, (Option<String>, String)> = HashMap::new();
         let mut pending_order: Vec<String> = Vec::new();
@@ -527,12 +547,14 @@ impl LlmProvider for QwenProvider {
         while let Some(event) = stream.next().await {
             match event {
                 ChatCompletionStreamResponse::Content(content) => {
+                    let content = content_text.delta(&content);
                     if !content.is_empty() {
                         accumulated_content.push_str(&content);
                         let _ = tx.send(UnifiedStreamEvent::TextDelta { content }).await;
                     }
                 }
                 ChatCompletionStreamResponse::ReasoningContent(content) => {
+                    let content = reasoning_text.delta(&content);
                     if !content.is_empty() {
                         if !in_thinking {
                             in_thinking = true;
@@ -707,6 +729,7 @@ struct QwenUsage {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::ProviderCapabilities;
 
     fn test_config() -> ProviderConfig {
         ProviderConfig {
@@ -726,6 +749,23 @@ mod tests {
         assert!(provider.supports_tools());
     }
 
+    #[test]
+    fn test_capabilities() {
+        let provider = QwenProvider::new(test_config());
+        assert_eq!(
+            provider.capabilities(),
+            ProviderCapabilities {
+                streaming: true,
+                tools: true,
+                json_mode: true,
+                vision: false,
+                prompt_cache: false,
+                thinking: false,
+                max_context: 1_000_000,
+            }
+        );
+    }
+
     #[test]
     fn test_qwen3_supports_reasoning() {
         let config = ProviderConfig {
@@ -760,6 +800,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_stream_request_asks_for_delta_output() {
+        let provider = QwenProvider::new(test_config());
+        let messages = vec![Message::user("hi")];
+        let options = LlmRequestOptions::default();
+
+        let body = provider.build_request_body(&messages, None, &[], true, &options);
+        assert_eq!(body["incremental_output"], serde_json::json!(true));
+
+        let body = provider.build_request_body(&messages, None, &[], false, &options);
+        assert!(body.get("incremental_output").is_none());
+    }
+
     #[test]
     fn test_native_search_enabled_via_options() {
         let mut config = test_config();
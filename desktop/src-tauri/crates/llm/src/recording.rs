@@ -0,0 +1,612 @@
+//! Request Recording
+//!
+//! A record/replay ("VCR") wrapper around [`LlmProvider`] for deterministic
+//! offline tests. In `Record` mode every call is forwarded to the wrapped
+//! provider and the request/response pair is written to a JSON fixture named
+//! by a hash of the normalized request. In `Replay` mode fixtures are served
+//! without touching the wrapped provider, and a request with no fixture is an
+//! error. Streaming calls also record the emitted event sequence and replay
+//! it in order.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tokio::sync::mpsc;
+
+use super::provider::LlmProvider;
+use super::types::{
+    FallbackToolFormatMode, LlmError, LlmRequestOptions, LlmResponse, LlmResult, Message,
+    ProviderConfig, ToolCallReliability, ToolDefinition,
+};
+use plan_cascade_core::streaming::UnifiedStreamEvent;
+
+/// Environment variable selecting the recording mode for tests
+/// (`record`, `replay` or `auto`).
+pub const RECORDING_MODE_ENV: &str = "PLAN_CASCADE_LLM_RECORDING";
+
+/// How [`RecordingProvider`] treats each request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordingMode {
+    /// Call the wrapped provider and overwrite the fixture.
+    Record,
+    /// Serve fixtures only; a missing fixture is an error.
+    Replay,
+    /// Replay when a fixture exists, otherwise record one.
+    Auto,
+}
+
+impl RecordingMode {
+    /// Read the mode from [`RECORDING_MODE_ENV`], if set to a known value.
+    pub fn from_env() -> Option<Self> {
+        match std::env::var(RECORDING_MODE_ENV)
+            .ok()?
+            .to_lowercase()
+            .as_str()
+        {
+            "record" => Some(Self::Record),
+            "replay" => Some(Self::Replay),
+            "auto" => Some(Self::Auto),
+            _ => None,
+        }
+    }
+}
+
+/// A recorded request/response pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    /// Hash of the normalized request; also the fixture file stem.
+    pub key: String,
+    /// The normalized request, kept for readability and diffing.
+    pub request: serde_json::Value,
+    /// Events emitted by a streaming call, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub events: Vec<UnifiedStreamEvent>,
+    /// The final response.
+    pub response: LlmResponse,
+}
+
+/// Build the normalized request used for fixture keys.
+///
+/// Only inputs that affect the provider's output are included: provider,
+/// model, the streaming flag and the call arguments.
+pub fn normalize_request(
+    provider: &dyn LlmProvider,
+    messages: &[Message],
+    system: Option<&str>,
+    tools: &[ToolDefinition],
+    request_options: &LlmRequestOptions,
+    stream: bool,
+) -> serde_json::Value {
+    canonicalize(serde_json::json!({
+        "provider": provider.name(),
+        "model": provider.model(),
+        "stream": stream,
+        "system": system,
+        "messages": messages,
+        "tools": tools,
+        "options": request_options,
+    }))
+}
+
+/// Stable key for a normalized request.
+pub fn request_key(request: &serde_json::Value) -> String {
+    let hash = Sha256::digest(request.to_string().as_bytes());
+    format!("{:x}", hash)[..32].to_string()
+}
+
+/// Sort object keys recursively so the serialized form does not depend on
+/// map ordering.
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, canonicalize(v)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(canonicalize).collect())
+        }
+        other => other,
+    }
+}
+
+/// Provider wrapper that records or replays calls to fixture files.
+pub struct RecordingProvider {
+    inner: Arc<dyn LlmProvider>,
+    mode: RecordingMode,
+    fixtures_dir: PathBuf,
+}
+
+impl RecordingProvider {
+    pub fn new(
+        inner: Arc<dyn LlmProvider>,
+        mode: RecordingMode,
+        fixtures_dir: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            inner,
+            mode,
+            fixtures_dir: fixtures_dir.into(),
+        }
+    }
+
+    pub fn mode(&self) -> RecordingMode {
+        self.mode
+    }
+
+    pub fn fixtures_dir(&self) -> &Path {
+        &self.fixtures_dir
+    }
+
+    fn fixture_path(&self, key: &str) -> PathBuf {
+        self.fixtures_dir.join(format!("{}.json", key))
+    }
+
+    fn load_fixture(&self, key: &str) -> LlmResult<Option<Fixture>> {
+        let path = self.fixture_path(key);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path).map_err(|e| LlmError::Other {
+            message: format!("Failed to read fixture {}: {}", path.display(), e),
+        })?;
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| LlmError::ParseError {
+                message: format!("Invalid fixture {}: {}", path.display(), e),
+            })
+    }
+
+    fn save_fixture(&self, fixture: &Fixture) -> LlmResult<()> {
+        let path = self.fixture_path(&fixture.key);
+        let write = || -> std::io::Result<()> {
+            std::fs::create_dir_all(&self.fixtures_dir)?;
+            let json = serde_json::to_string_pretty(fixture)?;
+            std::fs::write(&path, json)
+        };
+        write().map_err(|e| LlmError::Other {
+            message: format!("Failed to write fixture {}: {}", path.display(), e),
+        })
+    }
+
+    /// Look up a fixture, honoring the mode. Returns `None` when the call
+    /// should go to the wrapped provider.
+    fn replay_fixture(&self, key: &str) -> LlmResult<Option<Fixture>> {
+        match self.mode {
+            RecordingMode::Record => Ok(None),
+            RecordingMode::Auto => self.load_fixture(key),
+            RecordingMode::Replay => match self.load_fixture(key)? {
+                Some(fixture) => Ok(Some(fixture)),
+                None => Err(LlmError::Other {
+                    message: format!(
+                        "No recorded fixture for {} request {} in {}",
+                        self.inner.name(),
+                        key,
+                        self.fixtures_dir.display()
+                    ),
+                }),
+            },
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for RecordingProvider {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    fn supports_thinking(&self) -> bool {
+        self.inner.supports_thinking()
+    }
+
+    fn supports_tools(&self) -> bool {
+        self.inner.supports_tools()
+    }
+
+    fn tool_call_reliability(&self) -> ToolCallReliability {
+        self.inner.tool_call_reliability()
+    }
+
+    fn default_fallback_mode(&self) -> FallbackToolFormatMode {
+        self.inner.default_fallback_mode()
+    }
+
+    fn supports_multimodal(&self) -> bool {
+        self.inner.supports_multimodal()
+    }
+
+    fn supports_native_search(&self) -> bool {
+        self.inner.supports_native_search()
+    }
+
+    fn context_window(&self) -> u32 {
+        self.inner.context_window()
+    }
+
+    fn supports_json_mode(&self) -> bool {
+        self.inner.supports_json_mode()
+    }
+
+    fn supports_seed(&self) -> bool {
+        self.inner.supports_seed()
+    }
+
+    fn supports_prompt_cache(&self) -> bool {
+        self.inner.supports_prompt_cache()
+    }
+
+    async fn send_message(
+        &self,
+        messages: Vec<Message>,
+        system: Option<String>,
+        tools: Vec<ToolDefinition>,
+        request_options: LlmRequestOptions,
+    ) -> LlmResult<LlmResponse> {
+        let request = normalize_request(
+            self.inner.as_ref(),
+            &messages,
+            system.as_deref(),
+            &tools,
+            &request_options,
+            false,
+        );
+        let key = request_key(&request);
+        if let Some(fixture) = self.replay_fixture(&key)? {
+            return Ok(fixture.response);
+        }
+
+        let response = self
+            .inner
+            .send_message(messages, system, tools, request_options)
+            .await?;
+        self.save_fixture(&Fixture {
+            key,
+            request,
+            events: Vec::new(),
+            response: response.clone(),
+        })?;
+        Ok(response)
+    }
+
+    async fn stream_message(
+        &self,
+        messages: Vec<Message>,
+        system: Option<String>,
+        tools: Vec<ToolDefinition>,
+        tx: mpsc::Sender<UnifiedStreamEvent>,
+        request_options: LlmRequestOptions,
+    ) -> LlmResult<LlmResponse> {
+        let request = normalize_request(
+            self.inner.as_ref(),
+            &messages,
+            system.as_deref(),
+            &tools,
+            &request_options,
+            true,
+        );
+        let key = request_key(&request);
+        if let Some(fixture) = self.replay_fixture(&key)? {
+            for event in fixture.events {
+                let _ = tx.send(event).await;
+            }
+            return Ok(fixture.response);
+        }
+
+        // Tee the inner provider's events: record each one and forward it.
+        let (inner_tx, mut inner_rx) = mpsc::channel::<UnifiedStreamEvent>(64);
+        let call = self
+            .inner
+            .stream_message(messages, system, tools, inner_tx, request_options);
+        let forward = async {
+            let mut events = Vec::new();
+            while let Some(event) = inner_rx.recv().await {
+                events.push(event.clone());
+                let _ = tx.send(event).await;
+            }
+            events
+        };
+        let (result, events) = futures_util::future::join(call, forward).await;
+
+        let response = result?;
+        self.save_fixture(&Fixture {
+            key,
+            request,
+            events,
+            response: response.clone(),
+        })?;
+        Ok(response)
+    }
+
+    async fn health_check(&self) -> LlmResult<()> {
+        if self.mode == RecordingMode::Replay {
+            return Ok(());
+        }
+        self.inner.health_check().await
+    }
+
+    async fn preflight(&self) -> LlmResult<()> {
+        if self.mode == RecordingMode::Replay {
+            return Ok(());
+        }
+        self.inner.preflight().await
+    }
+
+    fn config(&self) -> &ProviderConfig {
+        self.inner.config()
+    }
+
+    async fn list_models(&self) -> LlmResult<Option<Vec<String>>> {
+        if self.mode == RecordingMode::Replay {
+            return Ok(None);
+        }
+        self.inner.list_models().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{StopReason, UsageStats};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Provider that answers every call with a fixed reply and counts calls.
+    struct ScriptedProvider {
+        reply: &'static str,
+        calls: AtomicUsize,
+        config: ProviderConfig,
+    }
+
+    impl ScriptedProvider {
+        fn new(reply: &'static str) -> Arc<Self> {
+            Arc::new(Self {
+                reply,
+                calls: AtomicUsize::new(0),
+                config: ProviderConfig {
+                    model: "scripted-model".to_string(),
+                    ..Default::default()
+                },
+            })
+        }
+
+        fn response(&self) -> LlmResponse {
+            LlmResponse {
+                content: Some(self.reply.to_string()),
+                thinking: None,
+                tool_calls: vec![],
+                stop_reason: StopReason::EndTurn,
+                usage: UsageStats {
+                    input_tokens: 5,
+                    output_tokens: 2,
+                    ..Default::default()
+                },
+                model: self.config.model.clone(),
+                search_citations: vec![],
+            }
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for ScriptedProvider {
+        fn name(&self) -> &'static str {
+            "scripted"
+        }
+
+        fn model(&self) -> &str {
+            &self.config.model
+        }
+
+        fn supports_thinking(&self) -> bool {
+            false
+        }
+
+        fn supports_tools(&self) -> bool {
+            true
+        }
+
+        async fn send_message(
+            &self,
+            _messages: Vec<Message>,
+            _system: Option<String>,
+            _tools: Vec<ToolDefinition>,
+            _request_options: LlmRequestOptions,
+        ) -> LlmResult<LlmResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.response())
+        }
+
+        async fn stream_message(
+            &self,
+            _messages: Vec<Message>,
+            _system: Option<String>,
+            _tools: Vec<ToolDefinition>,
+            tx: mpsc::Sender<UnifiedStreamEvent>,
+            _request_options: LlmRequestOptions,
+        ) -> LlmResult<LlmResponse> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            for chunk in self.reply.split_inclusive(' ') {
+                let _ = tx
+                    .send(UnifiedStreamEvent::TextDelta {
+                        content: chunk.to_string(),
+                    })
+                    .await;
+            }
+            Ok(self.response())
+        }
+
+        async fn health_check(&self) -> LlmResult<()> {
+            Ok(())
+        }
+
+        fn config(&self) -> &ProviderConfig {
+            &self.config
+        }
+    }
+
+    async fn stream_text(
+        provider: &RecordingProvider,
+        prompt: &str,
+    ) -> LlmResult<(Vec<UnifiedStreamEvent>, LlmResponse)> {
+        let (tx, mut rx) = mpsc::channel(16);
+        let response = provider
+            .stream_message(
+                vec![Message::user(prompt)],
+                None,
+                vec![],
+                tx,
+                LlmRequestOptions::default(),
+            )
+            .await?;
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        Ok((events, response))
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_completion() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = ScriptedProvider::new("Hello there");
+        let recorder = RecordingProvider::new(live.clone(), RecordingMode::Record, dir.path());
+
+        let recorded = recorder
+            .send_message(
+                vec![Message::user("Hi")],
+                Some("Be brief".to_string()),
+                vec![],
+                LlmRequestOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(live.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        // Replay against a provider that would answer differently.
+        let offline = ScriptedProvider::new("live answer");
+        let player = RecordingProvider::new(offline.clone(), RecordingMode::Replay, dir.path());
+        let replayed = player
+            .send_message(
+                vec![Message::user("Hi")],
+                Some("Be brief".to_string()),
+                vec![],
+                LlmRequestOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(offline.calls.load(Ordering::SeqCst), 0);
+        assert_eq!(replayed.content, recorded.content);
+        assert_eq!(replayed.usage.input_tokens, 5);
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_streaming() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = ScriptedProvider::new("one two three");
+        let recorder = RecordingProvider::new(live.clone(), RecordingMode::Record, dir.path());
+        let (recorded_events, _) = stream_text(&recorder, "count").await.unwrap();
+        assert_eq!(recorded_events.len(), 3);
+
+        let offline = ScriptedProvider::new("unused");
+        let player = RecordingProvider::new(offline.clone(), RecordingMode::Replay, dir.path());
+        let (replayed_events, response) = stream_text(&player, "count").await.unwrap();
+        assert_eq!(offline.calls.load(Ordering::SeqCst), 0);
+        assert_eq!(replayed_events, recorded_events);
+        assert_eq!(response.content.as_deref(), Some("one two three"));
+
+        // Streaming and non-streaming calls use separate fixtures.
+        let err = player
+            .send_message(
+                vec![Message::user("count")],
+                None,
+                vec![],
+                LlmRequestOptions::default(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::Other { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_replay_miss_errors_without_calling_provider() {
+        let dir = tempfile::tempdir().unwrap();
+        let offline = ScriptedProvider::new("unused");
+        let player = RecordingProvider::new(offline.clone(), RecordingMode::Replay, dir.path());
+
+        let err = player
+            .send_message(
+                vec![Message::user("never recorded")],
+                None,
+                vec![],
+                LlmRequestOptions::default(),
+            )
+            .await
+            .unwrap_err();
+        match err {
+            LlmError::Other { message } => assert!(message.contains("No recorded fixture")),
+            other => panic!("Expected Other, got {:?}", other),
+        }
+        assert_eq!(offline.calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_auto_mode_records_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = ScriptedProvider::new("cached");
+        let provider = RecordingProvider::new(live.clone(), RecordingMode::Auto, dir.path());
+
+        for _ in 0..2 {
+            provider
+                .send_message(
+                    vec![Message::user("Hi")],
+                    None,
+                    vec![],
+                    LlmRequestOptions::default(),
+                )
+                .await
+                .unwrap();
+        }
+        assert_eq!(live.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_request_key_ignores_map_order_but_not_content() {
+        let a = canonicalize(serde_json::json!({"b": 1, "a": {"y": 2, "x": 3}}));
+        let b = canonicalize(serde_json::json!({"a": {"x": 3, "y": 2}, "b": 1}));
+        assert_eq!(request_key(&a), request_key(&b));
+
+        let provider = ScriptedProvider::new("x");
+        let options = LlmRequestOptions::default();
+        let hi = normalize_request(
+            provider.as_ref(),
+            &[Message::user("Hi")],
+            None,
+            &[],
+            &options,
+            false,
+        );
+        let bye = normalize_request(
+            provider.as_ref(),
+            &[Message::user("Bye")],
+            None,
+            &[],
+            &options,
+            false,
+        );
+        assert_ne!(request_key(&hi), request_key(&bye));
+        assert_eq!(request_key(&hi).len(), 32);
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! Handles the SSE format from Claude API with content_block_delta parsing.
 
-use plan_cascade_core::streaming::{AdapterError, StreamAdapter, UnifiedStreamEvent};
+use super::tool_calls::complete_tool_call;
+use plan_cascade_core::streaming::{
+    AdapterError, StreamAdapter, UnifiedStreamEvent, INCOMPLETE_TOOL_ARGUMENTS_CODE,
+};
 use serde::Deserialize;
 
 /// Internal event types from Claude API SSE format
@@ -57,6 +60,10 @@ enum ContentBlock {
     ToolUse {
         id: String,
         name: String,
+        /// Anthropic sends `{}` here and streams the input as deltas; some
+        /// compatible endpoints (MiniMax) send the full input up front instead.
+        #[serde(default)]
+        input: Option<serde_json::Value>,
     },
     #[serde(other)]
     Other,
@@ -110,19 +117,26 @@ struct ApiError {
 pub struct ClaudeApiAdapter {
     /// Track current content block for thinking correlation
     current_thinking_id: Option<String>,
+    /// Whether the open content block is a thinking block. The API does not
+    /// send a thinking id, so the id alone cannot tell us when to close it.
+    in_thinking: bool,
     /// Track current tool ID for input accumulation
     current_tool_id: Option<String>,
     current_tool_name: Option<String>,
     tool_input_buffer: String,
+    /// Non-empty input delivered in the `content_block_start` itself.
+    tool_start_input: Option<String>,
 }
 
 impl ClaudeApiAdapter {
     pub fn new() -> Self {
         Self {
             current_thinking_id: None,
+            in_thinking: false,
             current_tool_id: None,
             current_tool_name: None,
             tool_input_buffer: String::new(),
+            tool_start_input: None,
         }
     }
 }
@@ -185,12 +199,16 @@ impl StreamAdapter for ClaudeApiAdapter {
             ClaudeApiEvent::ContentBlockStart { content_block, .. } => match content_block {
                 ContentBlock::Thinking { thinking_id } => {
                     self.current_thinking_id = thinking_id.clone();
+                    self.in_thinking = true;
                     vec![UnifiedStreamEvent::ThinkingStart { thinking_id }]
                 }
-                ContentBlock::ToolUse { id, name } => {
+                ContentBlock::ToolUse { id, name, input } => {
                     self.current_tool_id = Some(id.clone());
                     self.current_tool_name = Some(name.clone());
                     self.tool_input_buffer.clear();
+                    self.tool_start_input = input
+                        .filter(|v| !matches!(v.as_object(), Some(o) if o.is_empty()))
+                        .map(|v| v.to_string());
                     vec![UnifiedStreamEvent::ToolStart {
                         tool_id: id,
                         tool_name: name,
@@ -219,7 +237,7 @@ impl StreamAdapter for ClaudeApiAdapter {
                 let mut events = vec![];
 
                 // If we were in a thinking block, emit ThinkingEnd
-                if self.current_thinking_id.is_some() {
+                if std::mem::take(&mut self.in_thinking) {
                     events.push(UnifiedStreamEvent::ThinkingEnd {
                         thinking_id: self.current_thinking_id.take(),
                     });
@@ -229,12 +247,13 @@ impl StreamAdapter for ClaudeApiAdapter {
                 if let (Some(id), Some(name)) =
                     (self.current_tool_id.take(), self.current_tool_name.take())
                 {
-                    let args = std::mem::take(&mut self.tool_input_buffer);
-                    events.push(UnifiedStreamEvent::ToolComplete {
-                        tool_id: id,
-                        tool_name: name,
-                        arguments: args,
-                    });
+                    let mut args = std::mem::take(&mut self.tool_input_buffer);
+                    if let Some(start_input) = self.tool_start_input.take() {
+                        if args.trim().is_empty() {
+                            args = start_input;
+                        }
+                    }
+                    events.push(complete_tool_call(id, name, args));
                 }
 
                 events
@@ -272,11 +291,38 @@ impl StreamAdapter for ClaudeApiAdapter {
         Ok(events)
     }
 
+    fn finish(&mut self) -> Vec<UnifiedStreamEvent> {
+        let mut events = vec![];
+        if std::mem::take(&mut self.in_thinking) {
+            events.push(UnifiedStreamEvent::ThinkingEnd {
+                thinking_id: self.current_thinking_id.take(),
+            });
+        }
+        // A tool block that never received content_block_stop was cut off.
+        if let (Some(id), Some(name)) = (self.current_tool_id.take(), self.current_tool_name.take())
+        {
+            self.tool_start_input = None;
+            events.push(UnifiedStreamEvent::Error {
+                message: format!(
+                    "Tool call '{}' ({}) ended with incomplete arguments ({} bytes received)",
+                    name,
+                    id,
+                    self.tool_input_buffer.len()
+                ),
+                code: Some(INCOMPLETE_TOOL_ARGUMENTS_CODE.to_string()),
+            });
+            self.tool_input_buffer.clear();
+        }
+        events
+    }
+
     fn reset(&mut self) {
         self.current_thinking_id = None;
+        self.in_thinking = false;
         self.current_tool_id = None;
         self.current_tool_name = None;
         self.tool_input_buffer.clear();
+        self.tool_start_input = None;
     }
 }
 
@@ -325,6 +371,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_thinking_block_without_id_is_closed() {
+        let mut adapter = ClaudeApiAdapter::new();
+
+        let events = adapter.adapt(r#"data: {"type": "content_block_start", "index": 0, "content_block": {"type": "thinking", "thinking": ""}}"#).unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::ThinkingStart { thinking_id: None }]
+        );
+
+        let events = adapter.adapt(r#"data: {"type": "content_block_delta", "index": 0, "delta": {"type": "thinking_delta", "thinking": "step 1"}}"#).unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::ThinkingDelta {
+                content: "step 1".to_string(),
+                thinking_id: None,
+            }]
+        );
+
+        let events = adapter.adapt(r#"data: {"type": "content_block_delta", "index": 0, "delta": {"type": "signature_delta", "signature": "abc"}}"#).unwrap();
+        assert!(events.is_empty());
+
+        let events = adapter
+            .adapt(r#"data: {"type": "content_block_stop", "index": 0}"#)
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::ThinkingEnd { thinking_id: None }]
+        );
+
+        // A following text block must not emit another ThinkingEnd.
+        adapter.adapt(r#"data: {"type": "content_block_start", "index": 1, "content_block": {"type": "text", "text": ""}}"#).unwrap();
+        let events = adapter
+            .adapt(r#"data: {"type": "content_block_stop", "index": 1}"#)
+            .unwrap();
+        assert!(events.is_empty());
+    }
+
     #[test]
     fn test_message_stop() {
         let mut adapter = ClaudeApiAdapter::new();
@@ -344,4 +428,23 @@ mod tests {
         assert!(adapter.adapt("").unwrap().is_empty());
         assert!(adapter.adapt("data: [DONE]").unwrap().is_empty());
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = ClaudeApiAdapter::new();
+        let raw = "data: {\"type\": \"content_block_delta\", \"index\": 0, \"delta\": {\"type\": \"text_delta\", \"text\": \"héllo wörld\"}}\n\n".as_bytes();
+        // Split inside the "text_delta" key and inside the two-byte 'é'.
+        let in_key = raw.windows(10).position(|w| w == b"text_delta").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
 }
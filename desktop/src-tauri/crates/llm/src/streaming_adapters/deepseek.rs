@@ -1,6 +1,9 @@
 //! DeepSeek API Adapter
 //!
-//! Handles DeepSeek SSE format with <think></think> tag parsing for R1 models.
+//! Handles DeepSeek SSE format for R1-style models. Reasoning arrives either
+//! in the separate `reasoning_content` delta field (deepseek-reasoner API) or
+//! inline in `content` wrapped in <think></think> tags (self-hosted R1); both
+//! are routed to thinking events, while regular `content` becomes text.
 
 use plan_cascade_core::streaming::{AdapterError, StreamAdapter, UnifiedStreamEvent};
 use serde::Deserialize;
@@ -27,6 +30,8 @@ struct Delta {
     #[serde(default)]
     content: Option<String>,
     #[serde(default)]
+    reasoning_content: Option<String>,
+    #[serde(default)]
     tool_calls: Option<Vec<ToolCallDelta>>,
 }
 
@@ -50,6 +55,14 @@ struct FunctionCallDelta {
 struct Usage {
     prompt_tokens: u32,
     completion_tokens: u32,
+    #[serde(default)]
+    completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: Option<u32>,
 }
 
 /// State machine for parsing <think> tags
@@ -68,6 +81,8 @@ pub struct DeepSeekAdapter {
     state: ThinkState,
     /// Buffer for accumulating content to check for tags
     buffer: String,
+    /// Track if we're in a `reasoning_content` block
+    in_reasoning: bool,
     /// Track tool calls being accumulated
     tool_id: Option<String>,
     tool_name: Option<String>,
@@ -80,6 +95,7 @@ impl DeepSeekAdapter {
             model: model.into(),
             state: ThinkState::Normal,
             buffer: String::new(),
+            in_reasoning: false,
             tool_id: None,
             tool_name: None,
             tool_args_buffer: String::new(),
@@ -106,6 +122,14 @@ impl DeepSeekAdapter {
         }
     }
 
+    /// Close an open `reasoning_content` block, if any.
+    fn end_reasoning(&mut self, events: &mut Vec<UnifiedStreamEvent>) {
+        if self.in_reasoning {
+            self.in_reasoning = false;
+            events.push(UnifiedStreamEvent::ThinkingEnd { thinking_id: None });
+        }
+    }
+
     /// Process buffered content and extract thinking/text events
     fn process_buffer(&mut self) -> Vec<UnifiedStreamEvent> {
         let mut events = vec![];
@@ -217,7 +241,9 @@ impl StreamAdapter for DeepSeekAdapter {
 
         if json_str.is_empty() || json_str == "[DONE]" {
             // Flush remaining buffer
-            let mut events = self.process_buffer();
+            let mut events = vec![];
+            self.end_reasoning(&mut events);
+            events.extend(self.process_buffer());
             // Flush any pending tool call
             if let Some(tool_event) = self.flush_pending_tool() {
                 events.push(tool_event);
@@ -239,7 +265,9 @@ impl StreamAdapter for DeepSeekAdapter {
             events.push(UnifiedStreamEvent::Usage {
                 input_tokens: usage.prompt_tokens,
                 output_tokens: usage.completion_tokens,
-                thinking_tokens: None,
+                thinking_tokens: usage
+                    .completion_tokens_details
+                    .and_then(|d| d.reasoning_tokens),
                 cache_read_tokens: None,
                 cache_creation_tokens: None,
             });
@@ -248,6 +276,7 @@ impl StreamAdapter for DeepSeekAdapter {
         for choice in event.choices {
             if let Some(finish_reason) = choice.finish_reason {
                 // Flush buffer and end thinking if needed
+                self.end_reasoning(&mut events);
                 events.extend(self.process_buffer());
                 // Flush any pending tool call
                 if let Some(tool_event) = self.flush_pending_tool() {
@@ -264,7 +293,25 @@ impl StreamAdapter for DeepSeekAdapter {
             }
 
             if let Some(delta) = choice.delta {
+                // Handle separate reasoning field (deepseek-reasoner)
+                if let Some(reasoning) = delta.reasoning_content {
+                    if !reasoning.is_empty() {
+                        if !self.in_reasoning {
+                            self.in_reasoning = true;
+                            events.push(UnifiedStreamEvent::ThinkingStart { thinking_id: None });
+                        }
+                        events.push(UnifiedStreamEvent::ThinkingDelta {
+                            content: reasoning,
+                            thinking_id: None,
+                        });
+                    }
+                }
+
                 if let Some(content) = delta.content {
+                    if !content.is_empty() {
+                        // Answer content ends the reasoning block
+                        self.end_reasoning(&mut events);
+                    }
                     // Add to buffer for tag processing
                     self.buffer.push_str(&content);
                     events.extend(self.process_buffer());
@@ -310,6 +357,7 @@ impl StreamAdapter for DeepSeekAdapter {
     fn reset(&mut self) {
         self.state = ThinkState::Normal;
         self.buffer.clear();
+        self.in_reasoning = false;
         self.tool_id = None;
         self.tool_name = None;
         self.tool_args_buffer.clear();
@@ -388,4 +436,75 @@ mod tests {
             _ => panic!("Expected Complete"),
         }
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = DeepSeekAdapter::new("deepseek-chat");
+        let raw =
+            "data: {\"choices\": [{\"delta\": {\"content\": \"héllo wörld\"}}]}\n\n".as_bytes();
+        // Split inside the "content" key and inside the two-byte 'é'.
+        let in_key = raw.windows(7).position(|w| w == b"content").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_reasoning_content_field_interleaved_with_content() {
+        let mut adapter = DeepSeekAdapter::new("deepseek-reasoner");
+        assert!(adapter.supports_thinking());
+
+        let stream = [
+            r#"data: {"choices": [{"delta": {"role": "assistant", "content": null, "reasoning_content": ""}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": null, "reasoning_content": "The user wants"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": null, "reasoning_content": " a greeting."}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "Hello", "reasoning_content": null}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": " there!"}}]}"#,
+            r#"data: {"choices": [{"delta": {}, "finish_reason": "stop"}], "usage": {"prompt_tokens": 10, "completion_tokens": 42, "completion_tokens_details": {"reasoning_tokens": 30}}}"#,
+        ];
+        let events: Vec<UnifiedStreamEvent> = stream
+            .iter()
+            .flat_map(|line| adapter.adapt(line).unwrap())
+            .collect();
+
+        assert_eq!(
+            events,
+            vec![
+                UnifiedStreamEvent::ThinkingStart { thinking_id: None },
+                UnifiedStreamEvent::ThinkingDelta {
+                    content: "The user wants".to_string(),
+                    thinking_id: None,
+                },
+                UnifiedStreamEvent::ThinkingDelta {
+                    content: " a greeting.".to_string(),
+                    thinking_id: None,
+                },
+                UnifiedStreamEvent::ThinkingEnd { thinking_id: None },
+                UnifiedStreamEvent::TextDelta {
+                    content: "Hello".to_string(),
+                },
+                UnifiedStreamEvent::TextDelta {
+                    content: " there!".to_string(),
+                },
+                UnifiedStreamEvent::Usage {
+                    input_tokens: 10,
+                    output_tokens: 42,
+                    thinking_tokens: Some(30),
+                    cache_read_tokens: None,
+                    cache_creation_tokens: None,
+                },
+                UnifiedStreamEvent::Complete {
+                    stop_reason: Some("stop".to_string()),
+                },
+            ]
+        );
+    }
 }
@@ -2,6 +2,8 @@
 //!
 //! Handles GLM SSE format with reasoning_content support for GLM-4.5+ models.
 //! GLM uses the same reasoning_content field as OpenAI o1/o3 (not <think> tags like DeepSeek).
+//! Tool calls share the OpenAI adapter's `ToolCallAccumulator`, so parallel
+//! calls and truncated arguments are reported identically on both providers.
 //!
 //! Note: This adapter uses custom deserialization types rather than the zai-rs SDK's
 //! `ChatStreamResponse` because the SDK's `Usage` type does not expose the
@@ -9,6 +11,7 @@
 //! in `services/llm/glm.rs` uses zai-rs `ChatCompletionResponse` for non-streaming
 //! response parsing.
 
+use super::tool_calls::ToolCallAccumulator;
 use plan_cascade_core::streaming::{AdapterError, StreamAdapter, UnifiedStreamEvent};
 use serde::Deserialize;
 
@@ -77,9 +80,7 @@ pub struct GlmAdapter {
     /// Track if we're in a reasoning block
     in_reasoning: bool,
     /// Track tool calls being accumulated
-    tool_id: Option<String>,
-    tool_name: Option<String>,
-    tool_args_buffer: String,
+    tool_calls: ToolCallAccumulator,
 }
 
 impl GlmAdapter {
@@ -87,9 +88,7 @@ impl GlmAdapter {
         Self {
             model: model.into(),
             in_reasoning: false,
-            tool_id: None,
-            tool_name: None,
-            tool_args_buffer: String::new(),
+            tool_calls: ToolCallAccumulator::new(),
         }
     }
 
@@ -102,18 +101,14 @@ impl GlmAdapter {
             || model_lower.contains("thinking")
     }
 
-    /// Flush any pending tool call, emitting a ToolComplete event
-    fn flush_pending_tool(&mut self) -> Option<UnifiedStreamEvent> {
-        if let (Some(id), Some(name)) = (self.tool_id.take(), self.tool_name.take()) {
-            let args = std::mem::take(&mut self.tool_args_buffer);
-            Some(UnifiedStreamEvent::ToolComplete {
-                tool_id: id,
-                tool_name: name,
-                arguments: args,
-            })
-        } else {
-            None
+    /// End any open reasoning block and flush pending tool calls.
+    fn flush_open_blocks(&mut self) -> Vec<UnifiedStreamEvent> {
+        let mut events = self.tool_calls.finish_all();
+        if self.in_reasoning {
+            self.in_reasoning = false;
+            events.push(UnifiedStreamEvent::ThinkingEnd { thinking_id: None });
         }
+        events
     }
 }
 
@@ -143,17 +138,8 @@ impl StreamAdapter for GlmAdapter {
         };
 
         if json_str.is_empty() || json_str == "[DONE]" {
-            let mut events = vec![];
-            // Flush any pending tool call
-            if let Some(tool_event) = self.flush_pending_tool() {
-                events.push(tool_event);
-            }
-            // End of stream - emit ThinkingEnd if we were in reasoning
-            if self.in_reasoning {
-                self.in_reasoning = false;
-                events.push(UnifiedStreamEvent::ThinkingEnd { thinking_id: None });
-            }
-            return Ok(events);
+            // End of stream - flush pending tool calls and reasoning block
+            return Ok(self.flush_open_blocks());
         }
 
         let event: GlmEvent =
@@ -174,15 +160,8 @@ impl StreamAdapter for GlmAdapter {
 
         for choice in event.choices {
             if let Some(finish_reason) = choice.finish_reason {
-                // Flush any pending tool call before completing
-                if let Some(tool_event) = self.flush_pending_tool() {
-                    events.push(tool_event);
-                }
-                // End any reasoning block
-                if self.in_reasoning {
-                    self.in_reasoning = false;
-                    events.push(UnifiedStreamEvent::ThinkingEnd { thinking_id: None });
-                }
+                // Flush pending tool calls and any reasoning block before completing
+                events.extend(self.flush_open_blocks());
                 events.push(UnifiedStreamEvent::Complete {
                     stop_reason: Some(finish_reason),
                 });
@@ -219,31 +198,14 @@ impl StreamAdapter for GlmAdapter {
                 // Handle tool calls
                 if let Some(tool_calls) = delta.tool_calls {
                     for tc in tool_calls {
-                        if let Some(id) = tc.id {
-                            // New tool call starting — flush any previous pending tool
-                            if let Some(tool_event) = self.flush_pending_tool() {
-                                events.push(tool_event);
-                            }
-                            self.tool_id = Some(id.clone());
-                            if let Some(func) = &tc.function {
-                                self.tool_name = func.name.clone();
-                            }
-                            self.tool_args_buffer.clear();
-
-                            if let Some(name) = &self.tool_name {
-                                events.push(UnifiedStreamEvent::ToolStart {
-                                    tool_id: id,
-                                    tool_name: name.clone(),
-                                    arguments: None,
-                                });
-                            }
-                        }
-
-                        // Accumulate function arguments
-                        if let Some(func) = tc.function {
-                            if let Some(args) = func.arguments {
-                                self.tool_args_buffer.push_str(&args);
-                            }
+                        let (name, arguments) = match tc.function {
+                            Some(func) => (func.name, func.arguments),
+                            None => (None, None),
+                        };
+                        if let Some(start) =
+                            self.tool_calls.push_delta(tc.index, tc.id, name, arguments)
+                        {
+                            events.push(start);
                         }
                     }
                 }
@@ -253,11 +215,13 @@ impl StreamAdapter for GlmAdapter {
         Ok(events)
     }
 
+    fn finish(&mut self) -> Vec<UnifiedStreamEvent> {
+        self.flush_open_blocks()
+    }
+
     fn reset(&mut self) {
         self.in_reasoning = false;
-        self.tool_id = None;
-        self.tool_name = None;
-        self.tool_args_buffer.clear();
+        self.tool_calls.clear();
     }
 }
 
@@ -364,4 +328,85 @@ mod tests {
         let events = adapter.adapt("data: [DONE]").unwrap();
         assert!(events.is_empty());
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = GlmAdapter::new("glm-4-flash-250414");
+        let raw =
+            "data: {\"choices\": [{\"delta\": {\"content\": \"héllo wörld\"}}]}\n\n".as_bytes();
+        // Split inside the "content" key and inside the two-byte 'é'.
+        let in_key = raw.windows(7).position(|w| w == b"content").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
+
+    fn tool_events(events: Vec<UnifiedStreamEvent>) -> Vec<UnifiedStreamEvent> {
+        events
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    UnifiedStreamEvent::ToolStart { .. } | UnifiedStreamEvent::ToolComplete { .. }
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_tool_calls_match_openai_adapter() {
+        // Recorded GLM-4.6 stream: each call arrives whole, tagged by index.
+        let stream = [
+            r#"data: {"id":"2025","created":1,"model":"glm-4.6","choices":[{"index":0,"delta":{"role":"assistant","content":""}}]}"#,
+            r#"data: {"id":"2025","created":1,"model":"glm-4.6","choices":[{"index":0,"delta":{"role":"assistant","tool_calls":[{"id":"call_-8ea1","index":0,"type":"function","function":{"name":"Read","arguments":"{\"file_path\":\"src/main.rs\"}"}}]}}]}"#,
+            r#"data: {"id":"2025","created":1,"model":"glm-4.6","choices":[{"index":0,"delta":{"role":"assistant","tool_calls":[{"id":"call_-8ea2","index":1,"type":"function","function":{"name":"Grep","arguments":"{\"pattern\":\"TODO\"}"}}]}}]}"#,
+            r#"data: {"id":"2025","created":1,"model":"glm-4.6","choices":[{"index":0,"finish_reason":"tool_calls","delta":{"role":"assistant","content":""}}],"usage":{"prompt_tokens":120,"completion_tokens":30,"total_tokens":150}}"#,
+            "data: [DONE]",
+        ];
+
+        let mut adapter = GlmAdapter::new("glm-4.6");
+        let glm_events: Vec<UnifiedStreamEvent> = stream
+            .iter()
+            .flat_map(|line| adapter.adapt(line).unwrap())
+            .collect();
+
+        let expected = vec![
+            UnifiedStreamEvent::ToolStart {
+                tool_id: "call_-8ea1".to_string(),
+                tool_name: "Read".to_string(),
+                arguments: None,
+            },
+            UnifiedStreamEvent::ToolStart {
+                tool_id: "call_-8ea2".to_string(),
+                tool_name: "Grep".to_string(),
+                arguments: None,
+            },
+            UnifiedStreamEvent::ToolComplete {
+                tool_id: "call_-8ea1".to_string(),
+                tool_name: "Read".to_string(),
+                arguments: r#"{"file_path":"src/main.rs"}"#.to_string(),
+            },
+            UnifiedStreamEvent::ToolComplete {
+                tool_id: "call_-8ea2".to_string(),
+                tool_name: "Grep".to_string(),
+                arguments: r#"{"pattern":"TODO"}"#.to_string(),
+            },
+        ];
+        assert_eq!(tool_events(glm_events), expected);
+
+        let mut openai = crate::streaming_adapters::OpenAIAdapter::new("gpt-4o");
+        let openai_events: Vec<UnifiedStreamEvent> = stream
+            .iter()
+            .flat_map(|line| openai.adapt(line).unwrap())
+            .collect();
+        assert_eq!(tool_events(openai_events), expected);
+    }
 }
@@ -52,6 +52,10 @@ impl StreamAdapter for MinimaxAdapter {
         self.inner.adapt(input)
     }
 
+    fn finish(&mut self) -> Vec<UnifiedStreamEvent> {
+        self.inner.finish()
+    }
+
     fn reset(&mut self) {
         self.inner.reset();
     }
@@ -259,4 +263,106 @@ mod tests {
             "After reset, no pending tool should be flushed"
         );
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = MinimaxAdapter::new("MiniMax-M2.5");
+        let raw = "data: {\"type\": \"content_block_delta\", \"index\": 0, \"delta\": {\"type\": \"text_delta\", \"text\": \"héllo wörld\"}}\n\n".as_bytes();
+        // Split inside the "text_delta" key and inside the two-byte 'é'.
+        let in_key = raw.windows(10).position(|w| w == b"text_delta").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
+
+    fn tool_events(events: Vec<UnifiedStreamEvent>) -> Vec<UnifiedStreamEvent> {
+        events
+            .into_iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    UnifiedStreamEvent::ToolStart { .. } | UnifiedStreamEvent::ToolComplete { .. }
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parallel_tool_calls_match_openai_adapter() {
+        // Recorded MiniMax-M2 stream: the first call carries its input in the
+        // start block, the second streams it as input_json_delta fragments.
+        let stream = [
+            r#"event: message_start"#,
+            r#"data: {"type":"message_start","message":{"id":"msg_1","type":"message","role":"assistant","model":"MiniMax-M2","content":[],"usage":{"input_tokens":120,"output_tokens":0}}}"#,
+            r#"data: {"type":"content_block_start","index":0,"content_block":{"type":"tool_use","id":"call_function_1","name":"Read","input":{"file_path":"src/main.rs"}}}"#,
+            r#"data: {"type":"content_block_stop","index":0}"#,
+            r#"data: {"type":"content_block_start","index":1,"content_block":{"type":"tool_use","id":"call_function_2","name":"Grep","input":{}}}"#,
+            r#"data: {"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"{\"pattern\":"}}"#,
+            r#"data: {"type":"content_block_delta","index":1,"delta":{"type":"input_json_delta","partial_json":"\"TODO\"}"}}"#,
+            r#"data: {"type":"content_block_stop","index":1}"#,
+            r#"data: {"type":"message_delta","delta":{"stop_reason":"tool_use"},"usage":{"output_tokens":30}}"#,
+            r#"data: {"type":"message_stop"}"#,
+        ];
+        let mut adapter = MinimaxAdapter::new("MiniMax-M2");
+        let minimax_events: Vec<UnifiedStreamEvent> = stream
+            .iter()
+            .flat_map(|line| adapter.adapt(line).unwrap())
+            .collect();
+
+        // The same two calls as an OpenAI stream.
+        let openai_stream = [
+            r#"data: {"choices":[{"delta":{"tool_calls":[{"index":0,"id":"call_function_1","function":{"name":"Read","arguments":"{\"file_path\":\"src/main.rs\"}"}}]}}]}"#,
+            r#"data: {"choices":[{"delta":{"tool_calls":[{"index":1,"id":"call_function_2","function":{"name":"Grep","arguments":"{\"pattern\":"}}]}}]}"#,
+            r#"data: {"choices":[{"delta":{"tool_calls":[{"index":1,"function":{"arguments":"\"TODO\"}"}}]}}]}"#,
+            r#"data: {"choices":[{"finish_reason":"tool_calls"}]}"#,
+        ];
+        let mut openai = crate::streaming_adapters::OpenAIAdapter::new("gpt-4o");
+        let openai_events: Vec<UnifiedStreamEvent> = openai_stream
+            .iter()
+            .flat_map(|line| openai.adapt(line).unwrap())
+            .collect();
+
+        let minimax_tools = tool_events(minimax_events);
+        let mut openai_tools = tool_events(openai_events);
+        // OpenAI defers completions to the end of the stream; compare per call.
+        openai_tools.sort_by_key(|e| match e {
+            UnifiedStreamEvent::ToolStart { tool_id, .. }
+            | UnifiedStreamEvent::ToolComplete { tool_id, .. } => tool_id.clone(),
+            _ => String::new(),
+        });
+        assert_eq!(minimax_tools, openai_tools);
+        assert_eq!(
+            minimax_tools[1],
+            UnifiedStreamEvent::ToolComplete {
+                tool_id: "call_function_1".to_string(),
+                tool_name: "Read".to_string(),
+                arguments: r#"{"file_path":"src/main.rs"}"#.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_truncated_tool_block_reports_incomplete() {
+        let mut adapter = MinimaxAdapter::new("MiniMax-M2");
+        adapter
+            .adapt(r#"data: {"type":"content_block_start","index":0,"content_block":{"type":"tool_use","id":"call_1","name":"Write","input":{}}}"#)
+            .unwrap();
+        adapter
+            .adapt(r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"input_json_delta","partial_json":"{\"path\": \"a"}}"#)
+            .unwrap();
+
+        assert!(matches!(
+            adapter.finish().as_slice(),
+            [UnifiedStreamEvent::Error { code: Some(code), .. }]
+                if code == plan_cascade_core::streaming::INCOMPLETE_TOOL_ARGUMENTS_CODE
+        ));
+    }
 }
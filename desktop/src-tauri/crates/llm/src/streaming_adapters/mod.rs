@@ -9,6 +9,7 @@ pub mod minimax;
 pub mod ollama;
 pub mod openai;
 pub mod qwen;
+mod tool_calls;
 
 pub use claude_api::ClaudeApiAdapter;
 pub use deepseek::DeepSeekAdapter;
@@ -436,4 +436,23 @@ mod tests {
         assert!(adapter.buffer.is_empty());
         assert_eq!(adapter.tool_call_counter, 0);
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = OllamaAdapter::new("llama3.2");
+        let raw = "{\"response\": \"héllo wörld\", \"done\": false}\n\n".as_bytes();
+        // Split inside the "response" key and inside the two-byte 'é'.
+        let in_key = raw.windows(8).position(|w| w == b"response").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
 }
@@ -1,7 +1,13 @@
 //! OpenAI API Adapter
 //!
 //! Handles OpenAI SSE format with reasoning_content support for o1/o3 models.
+//!
+//! Tool-call argument deltas are buffered in a `ToolCallAccumulator` and
+//! validated when the stream finishes. Arguments that are not valid JSON
+//! (e.g. a stream cut off mid-arguments) are reported as an `Error` event with
+//! `INCOMPLETE_TOOL_ARGUMENTS_CODE` instead of a `ToolComplete`.
 
+use super::tool_calls::ToolCallAccumulator;
 use plan_cascade_core::streaming::{AdapterError, StreamAdapter, UnifiedStreamEvent};
 use serde::Deserialize;
 
@@ -59,6 +65,24 @@ struct Usage {
     completion_tokens: u32,
     #[serde(default)]
     reasoning_tokens: Option<u32>,
+    #[serde(default)]
+    completion_tokens_details: Option<CompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: Option<u32>,
+}
+
+impl Usage {
+    /// o-series models nest reasoning tokens under `completion_tokens_details`.
+    fn reasoning_tokens(&self) -> Option<u32> {
+        self.completion_tokens_details
+            .as_ref()
+            .and_then(|d| d.reasoning_tokens)
+            .or(self.reasoning_tokens)
+    }
 }
 
 /// Adapter for OpenAI API SSE format
@@ -67,9 +91,7 @@ pub struct OpenAIAdapter {
     /// Track if we're in a reasoning block
     in_reasoning: bool,
     /// Track tool calls being accumulated
-    tool_id: Option<String>,
-    tool_name: Option<String>,
-    tool_args_buffer: String,
+    tool_calls: ToolCallAccumulator,
 }
 
 impl OpenAIAdapter {
@@ -77,9 +99,7 @@ impl OpenAIAdapter {
         Self {
             model: model.into(),
             in_reasoning: false,
-            tool_id: None,
-            tool_name: None,
-            tool_args_buffer: String::new(),
+            tool_calls: ToolCallAccumulator::new(),
         }
     }
 
@@ -89,18 +109,14 @@ impl OpenAIAdapter {
         model_lower.starts_with("o1") || model_lower.starts_with("o3")
     }
 
-    /// Flush any pending tool call, emitting a ToolComplete event
-    fn flush_pending_tool(&mut self) -> Option<UnifiedStreamEvent> {
-        if let (Some(id), Some(name)) = (self.tool_id.take(), self.tool_name.take()) {
-            let args = std::mem::take(&mut self.tool_args_buffer);
-            Some(UnifiedStreamEvent::ToolComplete {
-                tool_id: id,
-                tool_name: name,
-                arguments: args,
-            })
-        } else {
-            None
+    /// End any open reasoning block and flush pending tool calls.
+    fn flush_open_blocks(&mut self) -> Vec<UnifiedStreamEvent> {
+        let mut events = self.tool_calls.finish_all();
+        if self.in_reasoning {
+            self.in_reasoning = false;
+            events.push(UnifiedStreamEvent::ThinkingEnd { thinking_id: None });
         }
+        events
     }
 }
 
@@ -130,17 +146,8 @@ impl StreamAdapter for OpenAIAdapter {
         };
 
         if json_str.is_empty() || json_str == "[DONE]" {
-            let mut events = vec![];
-            // Flush any pending tool call
-            if let Some(tool_event) = self.flush_pending_tool() {
-                events.push(tool_event);
-            }
-            // End of stream - emit ThinkingEnd if we were in reasoning
-            if self.in_reasoning {
-                self.in_reasoning = false;
-                events.push(UnifiedStreamEvent::ThinkingEnd { thinking_id: None });
-            }
-            return Ok(events);
+            // End of stream - flush pending tool call and reasoning block
+            return Ok(self.flush_open_blocks());
         }
 
         let event: OpenAIEvent =
@@ -153,7 +160,7 @@ impl StreamAdapter for OpenAIAdapter {
             events.push(UnifiedStreamEvent::Usage {
                 input_tokens: usage.prompt_tokens,
                 output_tokens: usage.completion_tokens,
-                thinking_tokens: usage.reasoning_tokens,
+                thinking_tokens: usage.reasoning_tokens(),
                 cache_read_tokens: None,
                 cache_creation_tokens: None,
             });
@@ -161,15 +168,8 @@ impl StreamAdapter for OpenAIAdapter {
 
         for choice in event.choices {
             if let Some(finish_reason) = choice.finish_reason {
-                // Flush any pending tool call before completing
-                if let Some(tool_event) = self.flush_pending_tool() {
-                    events.push(tool_event);
-                }
-                // End any reasoning block
-                if self.in_reasoning {
-                    self.in_reasoning = false;
-                    events.push(UnifiedStreamEvent::ThinkingEnd { thinking_id: None });
-                }
+                // Flush pending tool calls and any reasoning block before completing
+                events.extend(self.flush_open_blocks());
                 events.push(UnifiedStreamEvent::Complete {
                     stop_reason: Some(finish_reason),
                 });
@@ -206,31 +206,14 @@ impl StreamAdapter for OpenAIAdapter {
                 // Handle tool calls
                 if let Some(tool_calls) = delta.tool_calls {
                     for tc in tool_calls {
-                        if let Some(id) = tc.id {
-                            // New tool call starting — flush any previous pending tool
-                            if let Some(tool_event) = self.flush_pending_tool() {
-                                events.push(tool_event);
-                            }
-                            self.tool_id = Some(id.clone());
-                            if let Some(func) = &tc.function {
-                                self.tool_name = func.name.clone();
-                            }
-                            self.tool_args_buffer.clear();
-
-                            if let Some(name) = &self.tool_name {
-                                events.push(UnifiedStreamEvent::ToolStart {
-                                    tool_id: id,
-                                    tool_name: name.clone(),
-                                    arguments: None,
-                                });
-                            }
-                        }
-
-                        // Accumulate function arguments
-                        if let Some(func) = tc.function {
-                            if let Some(args) = func.arguments {
-                                self.tool_args_buffer.push_str(&args);
-                            }
+                        let (name, arguments) = match tc.function {
+                            Some(func) => (func.name, func.arguments),
+                            None => (None, None),
+                        };
+                        if let Some(start) =
+                            self.tool_calls.push_delta(tc.index, tc.id, name, arguments)
+                        {
+                            events.push(start);
                         }
                     }
                 }
@@ -240,17 +223,20 @@ impl StreamAdapter for OpenAIAdapter {
         Ok(events)
     }
 
+    fn finish(&mut self) -> Vec<UnifiedStreamEvent> {
+        self.flush_open_blocks()
+    }
+
     fn reset(&mut self) {
         self.in_reasoning = false;
-        self.tool_id = None;
-        self.tool_name = None;
-        self.tool_args_buffer.clear();
+        self.tool_calls.clear();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use plan_cascade_core::streaming::INCOMPLETE_TOOL_ARGUMENTS_CODE;
 
     #[test]
     fn test_text_delta() {
@@ -289,6 +275,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_usage_reports_nested_reasoning_tokens() {
+        let mut adapter = OpenAIAdapter::new("o3-mini");
+
+        let events = adapter
+            .adapt(r#"data: {"choices": [], "usage": {"prompt_tokens": 12, "completion_tokens": 340, "completion_tokens_details": {"reasoning_tokens": 256}}}"#)
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::Usage {
+                input_tokens: 12,
+                output_tokens: 340,
+                thinking_tokens: Some(256),
+                cache_read_tokens: None,
+                cache_creation_tokens: None,
+            }]
+        );
+    }
+
     #[test]
     fn test_finish_reason() {
         let mut adapter = OpenAIAdapter::new("gpt-4");
@@ -323,4 +328,72 @@ mod tests {
         let events = adapter.adapt("data: [DONE]").unwrap();
         assert!(events.is_empty());
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = OpenAIAdapter::new("gpt-4");
+        let raw =
+            "data: {\"choices\": [{\"delta\": {\"content\": \"héllo wörld\"}}]}\n\n".as_bytes();
+        // Split inside the "content" key and inside the two-byte 'é'.
+        let in_key = raw.windows(7).position(|w| w == b"content").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_complete_tool_call_arguments() {
+        let mut adapter = OpenAIAdapter::new("gpt-4");
+        adapter
+            .adapt(r#"data: {"choices": [{"delta": {"tool_calls": [{"index": 0, "id": "call_1", "function": {"name": "Read", "arguments": "{\"path\": "}}]}}]}"#)
+            .unwrap();
+        adapter
+            .adapt(r#"data: {"choices": [{"delta": {"tool_calls": [{"index": 0, "function": {"arguments": "\"a.rs\"}"}}]}}]}"#)
+            .unwrap();
+
+        let events = adapter.adapt("data: [DONE]").unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::ToolComplete {
+                tool_id: "call_1".to_string(),
+                tool_name: "Read".to_string(),
+                arguments: r#"{"path": "a.rs"}"#.to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_truncated_tool_arguments_report_incomplete() {
+        let mut adapter = OpenAIAdapter::new("gpt-4");
+        let raw = concat!(
+            r#"data: {"choices": [{"delta": {"tool_calls": [{"index": 0, "id": "call_1", "function": {"name": "Write", "arguments": "{\"path\": \"a.rs\", "}}]}}]}"#,
+            "\n\n",
+            r#"data: {"choices": [{"delta": {"tool_calls": [{"index": 0, "function": {"arguments": "\"content\": \"fn ma"}}]}}]}"#,
+            "\n\n",
+        );
+
+        // The stream ends without a finish_reason or [DONE].
+        let events = adapter.adapt_raw_chunks(&[raw.as_bytes()]).unwrap();
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, UnifiedStreamEvent::ToolComplete { .. })));
+        match events.last() {
+            Some(UnifiedStreamEvent::Error { message, code }) => {
+                assert_eq!(code.as_deref(), Some(INCOMPLETE_TOOL_ARGUMENTS_CODE));
+                assert!(message.contains("Write"));
+            }
+            other => panic!("Expected incomplete-arguments error, got {:?}", other),
+        }
+
+        // The pending call was consumed; finishing again emits nothing.
+        assert!(adapter.finish().is_empty());
+    }
 }
@@ -3,6 +3,13 @@
 //! Handles DashScope SSE format with reasoning_content support for Qwen3/QwQ models.
 //! DashScope uses the same OpenAI-compatible format with reasoning_content field.
 //!
+//! DashScope streams deltas when `incremental_output` is set (the provider
+//! always requests it), but some endpoints and models still send cumulative
+//! chunks where each chunk repeats all text so far. `IncrementalText` treats
+//! every chunk as a delta unless it strictly extends the text emitted on its
+//! channel (content / reasoning), so cumulative text is never duplicated
+//! downstream and genuine deltas are never trimmed.
+//!
 //! Note: QwenProvider now consumes `openai-api-rs` stream events directly in
 //! `crates/llm/src/qwen.rs`, so this SSE adapter is primarily used by
 //! `AdapterFactory` and external raw-SSE integration paths.
@@ -66,11 +73,45 @@ struct Usage {
     reasoning_tokens: Option<u32>,
 }
 
+/// Converts DashScope text chunks to true deltas, whichever output mode the
+/// endpoint uses.
+///
+/// Chunks are deltas by default. A chunk is read as cumulative only when it
+/// starts with everything emitted so far and adds more; this is re-checked on
+/// every chunk, so one lookalike chunk cannot switch the whole stream.
+#[derive(Debug, Default)]
+pub(crate) struct IncrementalText {
+    emitted: String,
+}
+
+impl IncrementalText {
+    /// Return the new text carried by `chunk` (empty if it adds nothing).
+    pub(crate) fn delta(&mut self, chunk: &str) -> String {
+        let delta = if !self.emitted.is_empty()
+            && chunk.len() > self.emitted.len()
+            && chunk.starts_with(self.emitted.as_str())
+        {
+            &chunk[self.emitted.len()..]
+        } else {
+            chunk
+        };
+        self.emitted.push_str(delta);
+        delta.to_string()
+    }
+
+    pub(crate) fn reset(&mut self) {
+        self.emitted.clear();
+    }
+}
+
 /// Adapter for Qwen (DashScope) API SSE format
 pub struct QwenAdapter {
     model: String,
     /// Track if we're in a reasoning block
     in_reasoning: bool,
+    /// Delta extraction for the content and reasoning channels
+    content_text: IncrementalText,
+    reasoning_text: IncrementalText,
     /// Track tool calls being accumulated
     tool_id: Option<String>,
     tool_name: Option<String>,
@@ -82,6 +123,8 @@ impl QwenAdapter {
         Self {
             model: model.into(),
             in_reasoning: false,
+            content_text: IncrementalText::default(),
+            reasoning_text: IncrementalText::default(),
             tool_id: None,
             tool_name: None,
             tool_args_buffer: String::new(),
@@ -186,6 +229,7 @@ impl StreamAdapter for QwenAdapter {
             if let Some(delta) = choice.delta {
                 // Handle reasoning content (Qwen3 with enable_thinking, QwQ models)
                 if let Some(reasoning) = delta.reasoning_content {
+                    let reasoning = self.reasoning_text.delta(&reasoning);
                     if !reasoning.is_empty() {
                         if !self.in_reasoning {
                             self.in_reasoning = true;
@@ -200,6 +244,7 @@ impl StreamAdapter for QwenAdapter {
 
                 // Handle regular content
                 if let Some(content) = delta.content {
+                    let content = self.content_text.delta(&content);
                     if !content.is_empty() {
                         // If we were in reasoning, end it first
                         if self.in_reasoning {
@@ -263,6 +308,8 @@ impl StreamAdapter for QwenAdapter {
 
     fn reset(&mut self) {
         self.in_reasoning = false;
+        self.content_text.reset();
+        self.reasoning_text.reset();
         self.tool_id = None;
         self.tool_name = None;
         self.tool_args_buffer.clear();
@@ -555,4 +602,105 @@ mod tests {
             _ => panic!("Expected ToolComplete for second tool"),
         }
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = QwenAdapter::new("qwen-plus");
+        let raw =
+            "data: {\"choices\": [{\"delta\": {\"content\": \"héllo wörld\"}}]}\n\n".as_bytes();
+        // Split inside the "content" key and inside the two-byte 'é'.
+        let in_key = raw.windows(7).position(|w| w == b"content").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
+
+    fn text_deltas(events: &[UnifiedStreamEvent]) -> Vec<&str> {
+        events
+            .iter()
+            .filter_map(|e| match e {
+                UnifiedStreamEvent::TextDelta { content } => Some(content.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cumulative_chunks_are_deduplicated() {
+        let mut adapter = QwenAdapter::new("qwen-long");
+        let events: Vec<UnifiedStreamEvent> = [
+            r#"data: {"choices": [{"delta": {"content": "你好"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "你好，世界"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "你好，世界! Done."}}]}"#,
+        ]
+        .iter()
+        .flat_map(|line| adapter.adapt(line).unwrap())
+        .collect();
+
+        assert_eq!(text_deltas(&events), vec!["你好", "，世界", "! Done."]);
+        assert_eq!(text_deltas(&events).concat(), "你好，世界! Done.");
+    }
+
+    #[test]
+    fn test_cumulative_reasoning_is_deduplicated_separately() {
+        let mut adapter = QwenAdapter::new("qwen3-plus");
+        let events: Vec<UnifiedStreamEvent> = [
+            r#"data: {"choices": [{"delta": {"reasoning_content": "Let me"}}]}"#,
+            r#"data: {"choices": [{"delta": {"reasoning_content": "Let me think"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "Answer"}}]}"#,
+        ]
+        .iter()
+        .flat_map(|line| adapter.adapt(line).unwrap())
+        .collect();
+
+        let thinking: Vec<&str> = events
+            .iter()
+            .filter_map(|e| match e {
+                UnifiedStreamEvent::ThinkingDelta { content, .. } => Some(content.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(thinking, vec!["Let me", " think"]);
+        assert_eq!(text_deltas(&events), vec!["Answer"]);
+    }
+
+    #[test]
+    fn test_delta_chunks_pass_through_unchanged() {
+        let mut adapter = QwenAdapter::new("qwen-plus");
+        let events: Vec<UnifiedStreamEvent> = [
+            r#"data: {"choices": [{"delta": {"content": "ab"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "cd"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "ab"}}]}"#,
+        ]
+        .iter()
+        .flat_map(|line| adapter.adapt(line).unwrap())
+        .collect();
+
+        assert_eq!(text_deltas(&events), vec!["ab", "cd", "ab"]);
+    }
+
+    #[test]
+    fn test_delta_resembling_emitted_text_is_not_trimmed() {
+        let mut adapter = QwenAdapter::new("qwen-plus");
+        let events: Vec<UnifiedStreamEvent> = [
+            r#"data: {"choices": [{"delta": {"content": "I"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "I think"}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": ". "}}]}"#,
+            r#"data: {"choices": [{"delta": {"content": "It"}}]}"#,
+        ]
+        .iter()
+        .flat_map(|line| adapter.adapt(line).unwrap())
+        .collect();
+
+        // "I think" extends "I" and is read as cumulative; later deltas are not trimmed
+        assert_eq!(text_deltas(&events), vec!["I", " think", ". ", "It"]);
+    }
 }
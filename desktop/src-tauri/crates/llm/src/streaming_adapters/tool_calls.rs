@@ -0,0 +1,209 @@
+//! Streamed Tool-Call Accumulation
+//!
+//! Shared by the OpenAI-compatible adapters (OpenAI, GLM) so streamed tool
+//! calls produce identical `ToolStart` / `ToolComplete` sequences regardless
+//! of provider:
+//!
+//! - argument deltas are routed by `index`, so parallel tool calls whose
+//!   fragments interleave are reassembled correctly
+//! - a repeated `id` on later chunks continues the same call
+//! - completed calls are emitted in the order they started
+//! - empty arguments become `{}`; arguments that are not valid JSON (a stream
+//!   cut off mid-call) become an `Error` event with
+//!   `INCOMPLETE_TOOL_ARGUMENTS_CODE` instead of a `ToolComplete`
+
+use plan_cascade_core::streaming::{UnifiedStreamEvent, INCOMPLETE_TOOL_ARGUMENTS_CODE};
+
+/// Build the terminal event for a fully streamed tool call.
+pub(crate) fn complete_tool_call(
+    tool_id: String,
+    tool_name: String,
+    arguments: String,
+) -> UnifiedStreamEvent {
+    if arguments.trim().is_empty() {
+        return UnifiedStreamEvent::ToolComplete {
+            tool_id,
+            tool_name,
+            arguments: "{}".to_string(),
+        };
+    }
+    if serde_json::from_str::<serde_json::Value>(&arguments).is_err() {
+        return UnifiedStreamEvent::Error {
+            message: format!(
+                "Tool call '{}' ({}) ended with incomplete arguments ({} bytes received)",
+                tool_name,
+                tool_id,
+                arguments.len()
+            ),
+            code: Some(INCOMPLETE_TOOL_ARGUMENTS_CODE.to_string()),
+        };
+    }
+    UnifiedStreamEvent::ToolComplete {
+        tool_id,
+        tool_name,
+        arguments,
+    }
+}
+
+#[derive(Debug)]
+struct PendingToolCall {
+    index: Option<usize>,
+    id: String,
+    name: Option<String>,
+    arguments: String,
+    started: bool,
+}
+
+/// Accumulates OpenAI-style `tool_calls` deltas until the stream finishes.
+#[derive(Debug, Default)]
+pub(crate) struct ToolCallAccumulator {
+    calls: Vec<PendingToolCall>,
+}
+
+impl ToolCallAccumulator {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply one `tool_calls[]` delta entry, returning a `ToolStart` event the
+    /// first time a call's id and name are both known.
+    pub(crate) fn push_delta(
+        &mut self,
+        index: Option<usize>,
+        id: Option<String>,
+        name: Option<String>,
+        arguments: Option<String>,
+    ) -> Option<UnifiedStreamEvent> {
+        let position = match id {
+            Some(id) => match self.position_by_id(&id) {
+                Some(pos) => pos,
+                None => {
+                    self.calls.push(PendingToolCall {
+                        index,
+                        id,
+                        name: None,
+                        arguments: String::new(),
+                        started: false,
+                    });
+                    self.calls.len() - 1
+                }
+            },
+            // A fragment for a call we never saw start has nothing to attach to.
+            None => self.position_by_index(index)?,
+        };
+
+        let call = &mut self.calls[position];
+        if let Some(name) = name.filter(|n| !n.is_empty()) {
+            if call.name.is_none() {
+                call.name = Some(name);
+            }
+        }
+        if let Some(arguments) = arguments {
+            call.arguments.push_str(&arguments);
+        }
+
+        match (&call.name, call.started) {
+            (Some(name), false) => {
+                call.started = true;
+                Some(UnifiedStreamEvent::ToolStart {
+                    tool_id: call.id.clone(),
+                    tool_name: name.clone(),
+                    arguments: None,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Emit terminal events for every pending call, in start order.
+    pub(crate) fn finish_all(&mut self) -> Vec<UnifiedStreamEvent> {
+        std::mem::take(&mut self.calls)
+            .into_iter()
+            .filter_map(|call| {
+                call.name
+                    .map(|name| complete_tool_call(call.id, name, call.arguments))
+            })
+            .collect()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.calls.clear();
+    }
+
+    fn position_by_id(&self, id: &str) -> Option<usize> {
+        self.calls.iter().position(|c| c.id == id)
+    }
+
+    fn position_by_index(&self, index: Option<usize>) -> Option<usize> {
+        match index {
+            Some(index) => self
+                .calls
+                .iter()
+                .rposition(|c| c.index == Some(index))
+                .or_else(|| self.calls.len().checked_sub(1)),
+            None => self.calls.len().checked_sub(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interleaved_parallel_calls_are_routed_by_index() {
+        let mut acc = ToolCallAccumulator::new();
+        let starts: Vec<_> = [
+            acc.push_delta(
+                Some(0),
+                Some("a".into()),
+                Some("Read".into()),
+                Some("{\"path\":".into()),
+            ),
+            acc.push_delta(
+                Some(1),
+                Some("b".into()),
+                Some("Grep".into()),
+                Some("{\"pattern\":".into()),
+            ),
+            acc.push_delta(Some(0), None, None, Some("\"a.rs\"}".into())),
+            acc.push_delta(Some(1), None, None, Some("\"todo\"}".into())),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        assert_eq!(starts.len(), 2);
+
+        assert_eq!(
+            acc.finish_all(),
+            vec![
+                UnifiedStreamEvent::ToolComplete {
+                    tool_id: "a".into(),
+                    tool_name: "Read".into(),
+                    arguments: "{\"path\":\"a.rs\"}".into(),
+                },
+                UnifiedStreamEvent::ToolComplete {
+                    tool_id: "b".into(),
+                    tool_name: "Grep".into(),
+                    arguments: "{\"pattern\":\"todo\"}".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_empty_and_truncated_arguments() {
+        assert_eq!(
+            complete_tool_call("a".into(), "LS".into(), String::new()),
+            UnifiedStreamEvent::ToolComplete {
+                tool_id: "a".into(),
+                tool_name: "LS".into(),
+                arguments: "{}".into(),
+            }
+        );
+        assert!(matches!(
+            complete_tool_call("a".into(), "Write".into(), "{\"path\": \"a".into()),
+            UnifiedStreamEvent::Error { code: Some(code), .. } if code == INCOMPLETE_TOOL_ARGUMENTS_CODE
+        ));
+    }
+}
@@ -5,8 +5,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use plan_cascade_core::error::{CoreError, ErrorKind};
 use plan_cascade_core::proxy::ProxyConfig;
 
+use crate::http_client::ProxyRouting;
+
 /// Supported LLM provider types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -102,6 +105,96 @@ impl Default for FallbackToolFormatMode {
     }
 }
 
+/// Optional provider feature a request can depend on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderFeature {
+    Streaming,
+    Tools,
+    JsonMode,
+    Vision,
+    PromptCache,
+    Thinking,
+}
+
+impl ProviderFeature {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProviderFeature::Streaming => "streaming",
+            ProviderFeature::Tools => "tools",
+            ProviderFeature::JsonMode => "json_mode",
+            ProviderFeature::Vision => "vision",
+            ProviderFeature::PromptCache => "prompt_cache",
+            ProviderFeature::Thinking => "thinking",
+        }
+    }
+}
+
+impl std::fmt::Display for ProviderFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Feature set supported by a provider for its configured model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderCapabilities {
+    pub streaming: bool,
+    pub tools: bool,
+    /// Structured JSON output (`response_format` / `format: json`).
+    pub json_mode: bool,
+    /// Image inputs.
+    pub vision: bool,
+    /// Prompt prefix caching (explicit or automatic).
+    pub prompt_cache: bool,
+    pub thinking: bool,
+    /// Context window in tokens.
+    pub max_context: u32,
+}
+
+impl ProviderCapabilities {
+    /// Whether a single feature is supported.
+    pub fn supports(&self, feature: ProviderFeature) -> bool {
+        match feature {
+            ProviderFeature::Streaming => self.streaming,
+            ProviderFeature::Tools => self.tools,
+            ProviderFeature::JsonMode => self.json_mode,
+            ProviderFeature::Vision => self.vision,
+            ProviderFeature::PromptCache => self.prompt_cache,
+            ProviderFeature::Thinking => self.thinking,
+        }
+    }
+
+    /// Required features that are not supported, in the order given.
+    pub fn missing(&self, required: &[ProviderFeature]) -> Vec<ProviderFeature> {
+        required
+            .iter()
+            .copied()
+            .filter(|feature| !self.supports(*feature))
+            .collect()
+    }
+}
+
+/// Features a request needs based on its content: images require vision and
+/// a non-empty tool list requires tool calling.
+pub fn required_features(messages: &[Message], tools: &[ToolDefinition]) -> Vec<ProviderFeature> {
+    let mut required = Vec::new();
+    if !tools.is_empty() {
+        required.push(ProviderFeature::Tools);
+    }
+    let has_images = messages.iter().flat_map(|m| &m.content).any(|c| match c {
+        MessageContent::Image { .. } | MessageContent::ImageUrl { .. } => true,
+        MessageContent::ToolResultMultimodal { content, .. } => content
+            .iter()
+            .any(|block| matches!(block, ContentBlock::Image { .. })),
+        _ => false,
+    });
+    if has_images {
+        required.push(ProviderFeature::Vision);
+    }
+    required
+}
+
 /// Per-request options for provider behavior.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct LlmRequestOptions {
@@ -114,14 +207,75 @@ pub struct LlmRequestOptions {
     /// Optional temperature override.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub temperature_override: Option<f32>,
-    /// Optional reasoning effort override.
+    /// Optional reasoning effort override ("low", "medium" or "high").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reasoning_effort_override: Option<String>,
+    /// Optional thinking budget override in tokens.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_budget_override: Option<u32>,
     /// Optional analysis phase identifier for provider-side tuning.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub analysis_phase: Option<String>,
 }
 
+/// Smallest thinking budget accepted by Anthropic extended thinking.
+pub const MIN_THINKING_BUDGET: u32 = 1024;
+
+/// Map a reasoning effort level to a thinking token budget.
+pub fn thinking_budget_for_effort(effort: &str) -> Option<u32> {
+    match effort.trim().to_ascii_lowercase().as_str() {
+        "low" | "minimal" => Some(MIN_THINKING_BUDGET),
+        "medium" => Some(8_192),
+        "high" => Some(24_576),
+        _ => None,
+    }
+}
+
+/// Map a thinking token budget to the closest reasoning effort level.
+pub fn effort_for_thinking_budget(budget: u32) -> &'static str {
+    if budget <= 4_096 {
+        "low"
+    } else if budget <= 16_384 {
+        "medium"
+    } else {
+        "high"
+    }
+}
+
+impl LlmRequestOptions {
+    /// Thinking budget for budget-based providers (Anthropic, Qwen).
+    ///
+    /// Precedence: explicit budget override, then the effort override, then
+    /// the provider config. The result stays below `max_tokens`, which must
+    /// leave room for the visible answer.
+    pub fn resolve_thinking_budget(&self, config: &ProviderConfig) -> Option<u32> {
+        let budget = self
+            .thinking_budget_override
+            .or_else(|| {
+                self.reasoning_effort_override
+                    .as_deref()
+                    .and_then(thinking_budget_for_effort)
+            })
+            .or(config.thinking_budget)?;
+        let ceiling = config.max_tokens.saturating_sub(1).max(MIN_THINKING_BUDGET);
+        Some(budget.clamp(MIN_THINKING_BUDGET, ceiling))
+    }
+
+    /// Reasoning effort for effort-based providers (OpenAI o-series).
+    ///
+    /// Precedence: explicit effort override, then an effort derived from the
+    /// budget override, then the provider config.
+    pub fn resolve_reasoning_effort(&self, config: &ProviderConfig) -> Option<String> {
+        self.reasoning_effort_override
+            .clone()
+            .or_else(|| {
+                self.thinking_budget_override
+                    .map(|budget| effort_for_thinking_budget(budget).to_string())
+            })
+            .or_else(|| config.reasoning_effort.clone())
+    }
+}
+
 /// Configuration for an LLM provider
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProviderConfig {
@@ -141,6 +295,13 @@ pub struct ProviderConfig {
     /// Temperature (0.0 - 1.0)
     #[serde(default = "default_temperature")]
     pub temperature: f32,
+    /// Nucleus sampling cutoff; omitted from requests when None
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub top_p: Option<f32>,
+    /// Sampling seed for reproducible output. Only honored by providers whose
+    /// `supports_seed()` is true; others ignore it and stay non-deterministic.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub seed: Option<u64>,
     /// Enable extended thinking/reasoning if supported
     #[serde(default)]
     pub enable_thinking: bool,
@@ -162,6 +323,9 @@ pub struct ProviderConfig {
     /// None means no proxy (direct connection).
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub proxy: Option<ProxyConfig>,
+    /// Per-request proxy routing. Takes precedence over `proxy` when set.
+    #[serde(skip)]
+    pub proxy_routing: Option<ProxyRouting>,
     /// Optional override for maximum concurrent sub-agents.
     /// When None, uses `ProviderType::default_max_concurrent_subagents()`.
     #[serde(skip_serializing_if = "Option::is_none", default)]
@@ -185,6 +349,16 @@ impl ProviderConfig {
         self.max_concurrent_subagents
             .unwrap_or_else(|| self.provider.default_max_concurrent_subagents())
     }
+
+    /// Get the effective proxy routing for this provider's requests.
+    ///
+    /// Returns `proxy_routing` if set, otherwise the single resolved `proxy`
+    /// (or a direct connection).
+    pub fn effective_proxy_routing(&self) -> ProxyRouting {
+        self.proxy_routing
+            .clone()
+            .unwrap_or_else(|| ProxyRouting::Fixed(self.proxy.clone()))
+    }
 }
 
 impl Default for ProviderConfig {
@@ -196,12 +370,15 @@ impl Default for ProviderConfig {
             model: "claude-3-5-sonnet-20241022".to_string(),
             max_tokens: default_max_tokens(),
             temperature: default_temperature(),
+            top_p: None,
+            seed: None,
             enable_thinking: false,
             thinking_budget: None,
             reasoning_effort: None,
             fallback_tool_format_mode: None,
             options: HashMap::new(),
             proxy: None,
+            proxy_routing: None,
             max_concurrent_subagents: None,
         }
     }
@@ -243,6 +420,8 @@ pub enum MessageContent {
     },
     /// Image content (base64 encoded, for multimodal providers)
     Image { media_type: String, data: String },
+    /// Image referenced by URL (for multimodal providers)
+    ImageUrl { url: String },
     /// Tool result with multimodal content (text + images)
     ToolResultMultimodal {
         tool_use_id: String,
@@ -311,6 +490,46 @@ impl Message {
         }
     }
 
+    /// Create a user message with text followed by a base64-encoded image
+    pub fn user_with_image(
+        text: impl Into<String>,
+        media_type: impl Into<String>,
+        data: impl Into<String>,
+    ) -> Self {
+        Self {
+            role: MessageRole::User,
+            content: vec![
+                MessageContent::Text { text: text.into() },
+                MessageContent::Image {
+                    media_type: media_type.into(),
+                    data: data.into(),
+                },
+            ],
+        }
+    }
+
+    /// Create a user message with text followed by an image URL
+    pub fn user_with_image_url(text: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            role: MessageRole::User,
+            content: vec![
+                MessageContent::Text { text: text.into() },
+                MessageContent::ImageUrl { url: url.into() },
+            ],
+        }
+    }
+
+    /// Whether the message carries image input blocks (excluding images
+    /// nested in multimodal tool results)
+    pub fn has_images(&self) -> bool {
+        self.content.iter().any(|c| {
+            matches!(
+                c,
+                MessageContent::Image { .. } | MessageContent::ImageUrl { .. }
+            )
+        })
+    }
+
     /// Create a multimodal tool result message (text + images)
     pub fn tool_result_multimodal(
         tool_use_id: impl Into<String>,
@@ -568,7 +787,12 @@ pub enum LlmError {
         retry_after: Option<u32>,
     },
     /// Model not found or not available
-    ModelNotFound { model: String },
+    ModelNotFound {
+        model: String,
+        /// Models the provider does offer, when known
+        #[serde(default)]
+        available: Vec<String>,
+    },
     /// Invalid request (bad parameters)
     InvalidRequest { message: String },
     /// Server error from the provider
@@ -592,15 +816,24 @@ pub enum LlmError {
 }
 
 impl LlmError {
+    /// Classify this error for retry/fallback decisions.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            LlmError::NetworkError { .. }
+            | LlmError::ServerError { .. }
+            | LlmError::ProviderUnavailable { .. } => ErrorKind::Transient,
+            LlmError::RateLimited { .. } => ErrorKind::RateLimited,
+            LlmError::AuthenticationFailed { .. } => ErrorKind::Auth,
+            LlmError::ModelNotFound { .. }
+            | LlmError::InvalidRequest { .. }
+            | LlmError::ContextLengthExceeded { .. } => ErrorKind::Invalid,
+            LlmError::ParseError { .. } | LlmError::Other { .. } => ErrorKind::Fatal,
+        }
+    }
+
     /// Whether this error is transient and should be retried.
     pub fn is_retryable(&self) -> bool {
-        matches!(
-            self,
-            LlmError::NetworkError { .. }
-                | LlmError::RateLimited { .. }
-                | LlmError::ServerError { .. }
-                | LlmError::ProviderUnavailable { .. }
-        )
+        self.kind().is_retryable()
     }
 
     /// For rate-limited errors, return the suggested wait time in seconds.
@@ -622,8 +855,17 @@ impl std::fmt::Display for LlmError {
             LlmError::RateLimited { message, .. } => {
                 write!(f, "Rate limited: {}", message)
             }
-            LlmError::ModelNotFound { model } => {
-                write!(f, "Model not found: {}", model)
+            LlmError::ModelNotFound { model, available } => {
+                if available.is_empty() {
+                    write!(f, "Model not found: {}", model)
+                } else {
+                    write!(
+                        f,
+                        "Model not found: {} (available models: {})",
+                        model,
+                        available.join(", ")
+                    )
+                }
             }
             LlmError::InvalidRequest { message } => {
                 write!(f, "Invalid request: {}", message)
@@ -656,6 +898,27 @@ impl std::fmt::Display for LlmError {
 
 impl std::error::Error for LlmError {}
 
+impl From<LlmError> for CoreError {
+    fn from(err: LlmError) -> Self {
+        let message = err.to_string();
+        match err {
+            LlmError::RateLimited { retry_after, .. } => {
+                CoreError::rate_limited(message, retry_after.map(|s| s as u64))
+            }
+            LlmError::AuthenticationFailed { .. } => CoreError::auth(message),
+            LlmError::NetworkError { .. }
+            | LlmError::ServerError { .. }
+            | LlmError::ProviderUnavailable { .. } => CoreError::transient(message),
+            LlmError::ModelNotFound { .. } => CoreError::not_found(message),
+            LlmError::InvalidRequest { .. } | LlmError::ContextLengthExceeded { .. } => {
+                CoreError::validation(message)
+            }
+            LlmError::ParseError { .. } => CoreError::internal(message),
+            LlmError::Other { .. } => CoreError::internal(message),
+        }
+    }
+}
+
 /// Result type for LLM operations
 pub type LlmResult<T> = Result<T, LlmError>;
 
@@ -671,6 +934,70 @@ mod tests {
         assert!((config.temperature - 0.7).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_resolve_thinking_budget_precedence_and_clamp() {
+        let config = ProviderConfig {
+            max_tokens: 16_000,
+            thinking_budget: Some(4_000),
+            ..Default::default()
+        };
+
+        let defaults = LlmRequestOptions::default();
+        assert_eq!(defaults.resolve_thinking_budget(&config), Some(4_000));
+
+        let effort = LlmRequestOptions {
+            reasoning_effort_override: Some("high".to_string()),
+            ..Default::default()
+        };
+        // "high" maps above max_tokens and is clamped below it.
+        assert_eq!(effort.resolve_thinking_budget(&config), Some(15_999));
+
+        let explicit = LlmRequestOptions {
+            reasoning_effort_override: Some("high".to_string()),
+            thinking_budget_override: Some(100),
+            ..Default::default()
+        };
+        assert_eq!(
+            explicit.resolve_thinking_budget(&config),
+            Some(MIN_THINKING_BUDGET)
+        );
+
+        let unset = ProviderConfig::default();
+        assert_eq!(defaults.resolve_thinking_budget(&unset), None);
+    }
+
+    #[test]
+    fn test_resolve_reasoning_effort_precedence() {
+        let config = ProviderConfig {
+            reasoning_effort: Some("medium".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            LlmRequestOptions::default().resolve_reasoning_effort(&config),
+            Some("medium".to_string())
+        );
+
+        let budget = LlmRequestOptions {
+            thinking_budget_override: Some(2_000),
+            ..Default::default()
+        };
+        assert_eq!(
+            budget.resolve_reasoning_effort(&config),
+            Some("low".to_string())
+        );
+
+        let effort = LlmRequestOptions {
+            reasoning_effort_override: Some("high".to_string()),
+            thinking_budget_override: Some(2_000),
+            ..Default::default()
+        };
+        assert_eq!(
+            effort.resolve_reasoning_effort(&config),
+            Some("high".to_string())
+        );
+        assert_eq!(thinking_budget_for_effort("bogus"), None);
+    }
+
     #[test]
     fn test_provider_config_serialization() {
         let config = ProviderConfig {
@@ -680,12 +1007,15 @@ mod tests {
             model: "gpt-4".to_string(),
             max_tokens: 2048,
             temperature: 0.5,
+            top_p: None,
+            seed: None,
             enable_thinking: false,
             thinking_budget: None,
             reasoning_effort: None,
             fallback_tool_format_mode: None,
             options: HashMap::new(),
             proxy: None,
+            proxy_routing: None,
             max_concurrent_subagents: None,
         };
 
@@ -829,4 +1159,86 @@ mod tests {
         };
         assert!(err.to_string().contains("Rate limited"));
     }
+
+    #[test]
+    fn test_provider_errors_map_to_retryability_class() {
+        let cases = vec![
+            (
+                LlmError::NetworkError {
+                    message: "connection reset".to_string(),
+                },
+                ErrorKind::Transient,
+            ),
+            (
+                LlmError::ServerError {
+                    message: "bad gateway".to_string(),
+                    status: Some(502),
+                },
+                ErrorKind::Transient,
+            ),
+            (
+                LlmError::ProviderUnavailable {
+                    message: "ollama not running".to_string(),
+                },
+                ErrorKind::Transient,
+            ),
+            (
+                LlmError::RateLimited {
+                    message: "429".to_string(),
+                    retry_after: Some(20),
+                },
+                ErrorKind::RateLimited,
+            ),
+            (
+                LlmError::AuthenticationFailed {
+                    message: "invalid x-api-key".to_string(),
+                },
+                ErrorKind::Auth,
+            ),
+            (
+                LlmError::InvalidRequest {
+                    message: "max_tokens too large".to_string(),
+                },
+                ErrorKind::Invalid,
+            ),
+            (
+                LlmError::ContextLengthExceeded {
+                    message: "prompt too long".to_string(),
+                    max_tokens: Some(200_000),
+                },
+                ErrorKind::Invalid,
+            ),
+            (
+                LlmError::ModelNotFound {
+                    model: "gpt-9".to_string(),
+                    available: Vec::new(),
+                },
+                ErrorKind::Invalid,
+            ),
+            (
+                LlmError::ParseError {
+                    message: "unexpected EOF".to_string(),
+                },
+                ErrorKind::Fatal,
+            ),
+        ];
+
+        for (err, expected) in cases {
+            assert_eq!(err.kind(), expected, "kind for {err}");
+            assert_eq!(err.is_retryable(), expected.is_retryable());
+            let core: CoreError = err.into();
+            assert_eq!(core.kind(), expected, "core kind for {core}");
+        }
+    }
+
+    #[test]
+    fn test_rate_limited_conversion_keeps_retry_after() {
+        let core: CoreError = LlmError::RateLimited {
+            message: "slow down".to_string(),
+            retry_after: Some(42),
+        }
+        .into();
+        assert!(core.is_retryable());
+        assert_eq!(core.retry_after_secs(), Some(42));
+    }
 }
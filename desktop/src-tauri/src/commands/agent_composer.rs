@@ -2,11 +2,14 @@
 //!
 //! Tauri commands for managing and executing composable agent pipelines.
 //! Supports CRUD operations on AgentPipeline definitions persisted in SQLite,
-//! and execution of pipelines via the ComposerRegistry.
+//! and execution of pipelines via the ComposerRegistry, plus export/import
+//! in the portable pipeline format and export as standalone Rust/Python code.
 
 use tauri::State;
 
 use crate::models::response::CommandResponse;
+use crate::services::agent_composer::codegen::{generate_pipeline_python, generate_pipeline_rust};
+use crate::services::agent_composer::portable::{export_pipeline, import_pipeline};
 use crate::services::agent_composer::{AgentPipeline, AgentPipelineInfo};
 use crate::state::AppState;
 use crate::utils::error::{AppError, AppResult};
@@ -107,43 +110,13 @@ pub async fn create_agent_pipeline(
 ) -> Result<CommandResponse<AgentPipeline>, String> {
     let result = state
         .with_database(|db| {
-            let conn = db.pool().get().map_err(|e| {
-                AppError::database(format!("Failed to get connection: {}", e))
-            })?;
+            let conn = db
+                .pool()
+                .get()
+                .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
 
             ensure_agent_pipelines_table(&conn)?;
-
-            // Generate ID if not provided
-            let pipeline_id = if pipeline.pipeline_id.is_empty() {
-                uuid::Uuid::new_v4().to_string()
-            } else {
-                pipeline.pipeline_id.clone()
-            };
-
-            let now = chrono::Utc::now().to_rfc3339();
-            let saved_pipeline = AgentPipeline {
-                pipeline_id: pipeline_id.clone(),
-                created_at: now.clone(),
-                updated_at: Some(now.clone()),
-                ..pipeline
-            };
-
-            let definition_json = serde_json::to_string(&saved_pipeline)?;
-
-            conn.execute(
-                "INSERT INTO agent_pipelines (id, name, description, definition, created_at, updated_at)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-                rusqlite::params![
-                    saved_pipeline.pipeline_id,
-                    saved_pipeline.name,
-                    saved_pipeline.description,
-                    definition_json,
-                    saved_pipeline.created_at,
-                    saved_pipeline.updated_at,
-                ],
-            )?;
-
-            Ok(saved_pipeline)
+            insert_pipeline(&conn, pipeline)
         })
         .await;
 
@@ -246,6 +219,124 @@ pub async fn delete_agent_pipeline(
     }
 }
 
+/// Export an agent pipeline for sharing.
+///
+/// `format` is `json` (portable pipeline, the default), `rust`, or `python`.
+#[tauri::command]
+pub async fn export_agent_pipeline(
+    state: State<'_, AppState>,
+    id: String,
+    format: Option<String>,
+) -> Result<CommandResponse<String>, String> {
+    let result = state
+        .with_database(|db| {
+            let conn = db
+                .pool()
+                .get()
+                .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
+
+            ensure_agent_pipelines_table(&conn)?;
+
+            let json = conn
+                .query_row(
+                    "SELECT definition FROM agent_pipelines WHERE id = ?1",
+                    rusqlite::params![id],
+                    |row| row.get::<_, String>(0),
+                )
+                .map_err(|e| match e {
+                    rusqlite::Error::QueryReturnedNoRows => {
+                        AppError::not_found(format!("Agent pipeline not found: {}", id))
+                    }
+                    other => AppError::database(other.to_string()),
+                })?;
+            let pipeline: AgentPipeline = serde_json::from_str(&json)
+                .map_err(|e| AppError::parse(format!("Failed to parse pipeline: {}", e)))?;
+
+            match format.as_deref().unwrap_or("json") {
+                "json" => {
+                    let portable = export_pipeline(&pipeline)?;
+                    Ok(serde_json::to_string_pretty(&portable)?)
+                }
+                "rust" => Ok(generate_pipeline_rust(&pipeline)),
+                "python" => Ok(generate_pipeline_python(&pipeline)),
+                other => Err(AppError::validation(format!(
+                    "Unsupported export format: {}. Supported: json, rust, python",
+                    other
+                ))),
+            }
+        })
+        .await;
+
+    match result {
+        Ok(exported) => Ok(CommandResponse::ok(exported)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Import a pipeline from portable JSON, saving it under a new ID.
+#[tauri::command]
+pub async fn import_agent_pipeline(
+    state: State<'_, AppState>,
+    content: String,
+) -> Result<CommandResponse<AgentPipeline>, String> {
+    let result = state
+        .with_database(|db| {
+            let pipeline = import_pipeline(&content)?;
+
+            let conn = db
+                .pool()
+                .get()
+                .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
+
+            ensure_agent_pipelines_table(&conn)?;
+            insert_pipeline(&conn, pipeline)
+        })
+        .await;
+
+    match result {
+        Ok(pipeline) => Ok(CommandResponse::ok(pipeline)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Insert a new pipeline, assigning an ID if missing and fresh timestamps.
+fn insert_pipeline(
+    conn: &r2d2::PooledConnection<r2d2_sqlite::SqliteConnectionManager>,
+    pipeline: AgentPipeline,
+) -> AppResult<AgentPipeline> {
+    // Generate ID if not provided
+    let pipeline_id = if pipeline.pipeline_id.is_empty() {
+        uuid::Uuid::new_v4().to_string()
+    } else {
+        pipeline.pipeline_id.clone()
+    };
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let saved_pipeline = AgentPipeline {
+        pipeline_id: pipeline_id.clone(),
+        created_at: now.clone(),
+        updated_at: Some(now.clone()),
+        ..pipeline
+    };
+
+    let definition_json = serde_json::to_string(&saved_pipeline)?;
+
+    conn.execute(
+        "INSERT INTO agent_pipelines (id, name, description, definition, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        rusqlite::params![
+            saved_pipeline.pipeline_id,
+            saved_pipeline.name,
+            saved_pipeline.description,
+            definition_json,
+            saved_pipeline.created_at,
+            saved_pipeline.updated_at,
+        ],
+    )?;
+
+    Ok(saved_pipeline)
+}
+
 /// Ensure the agent_pipelines table exists.
 fn ensure_agent_pipelines_table(
     conn: &r2d2::PooledConnection<r2d2_sqlite::SqliteConnectionManager>,
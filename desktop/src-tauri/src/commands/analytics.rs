@@ -2,19 +2,22 @@
 //!
 //! Tauri commands for usage analytics, cost tracking, and data export.
 
-use std::sync::Arc;
-use tauri::State;
-use tokio::sync::RwLock;
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Manager, State};
+use tokio::sync::{mpsc, RwLock};
 
+use crate::commands::webhook::WebhookState;
 use crate::models::analytics::{
     AggregationPeriod, AnalyticsBreakdownRow, AnalyticsEventDetail, AnalyticsFilter,
-    AnalyticsSummary, AnalyticsUsageEvent, DashboardFilterV2, DashboardSummary, ExportJob,
-    ExportStreamingJobRequest, PricingRule, RecomputeCostsRequest, RecomputeCostsResult,
-    UsageFilter, UsageRecordV2,
+    AnalyticsSummary, AnalyticsUsageEvent, BudgetAlert, CostBudget, CostProjection,
+    DashboardFilterV2, DashboardSummary, ExportJob, ExportStreamingJobRequest, PricingImportResult,
+    PricingRule, RecomputeCostsRequest, RecomputeCostsResult, TimeSeriesPoint, UsageFilter,
+    UsageRecordV2, UsageRollup, UsageRollupDimension, UsageRollupSort,
 };
 use crate::models::response::CommandResponse;
 use crate::services::analytics::{
-    AnalyticsService, CostCalculator, UsageTracker, UsageTrackerBuilder,
+    budget_alert_payload, parse_timezone, AnalyticsService, BudgetAlertSender, CostCalculator,
+    UsageTracker, UsageTrackerBuilder,
 };
 use crate::state::AppState;
 use crate::utils::error::{AppError, AppResult};
@@ -24,14 +27,19 @@ pub struct AnalyticsState {
     service: Arc<RwLock<Option<AnalyticsService>>>,
     tracker: Arc<RwLock<Option<UsageTracker>>>,
     cost_calculator: Arc<CostCalculator>,
+    budget_alert_sender: BudgetAlertSender,
+    budget_alert_receiver: Mutex<Option<mpsc::UnboundedReceiver<Vec<BudgetAlert>>>>,
 }
 
 impl AnalyticsState {
     pub fn new() -> Self {
+        let (budget_alert_sender, budget_alert_receiver) = mpsc::unbounded_channel();
         Self {
             service: Arc::new(RwLock::new(None)),
             tracker: Arc::new(RwLock::new(None)),
             cost_calculator: Arc::new(CostCalculator::new()),
+            budget_alert_sender,
+            budget_alert_receiver: Mutex::new(Some(budget_alert_receiver)),
         }
     }
 
@@ -46,6 +54,8 @@ impl AnalyticsState {
         let pool = app_state.with_database(|db| Ok(db.pool().clone())).await?;
 
         let service = AnalyticsService::from_pool(pool)?;
+        self.cost_calculator
+            .load_pricing_rules(service.list_pricing_rules()?)?;
         let service_arc = Arc::new(service);
 
         // Initialize tracker
@@ -54,6 +64,7 @@ impl AnalyticsState {
             .flush_interval_secs(30)
             .enabled(true)
             .cost_calculator(self.cost_calculator.clone())
+            .budget_alerts(self.budget_alert_sender.clone())
             .build(service_arc.clone());
 
         // Store service (we need to unwrap the Arc for storage)
@@ -84,6 +95,12 @@ impl AnalyticsState {
         self.cost_calculator.clone()
     }
 
+    /// Reload dated pricing rules into the shared cost calculator
+    pub async fn refresh_pricing_rules(&self) -> AppResult<()> {
+        let rules = self.with_service(|s| s.list_pricing_rules()).await?;
+        self.cost_calculator.load_pricing_rules(rules)
+    }
+
     /// Get a clone of the tracker's channel sender (for injection into orchestrator)
     pub async fn get_tracker_sender(
         &self,
@@ -92,6 +109,42 @@ impl AnalyticsState {
         guard.as_ref().map(|t| t.sender())
     }
 
+    /// Evaluate budgets and dispatch a webhook for each newly crossed threshold.
+    ///
+    /// Alerts are de-duplicated by the service, so a crossing already
+    /// notified after recording usage is not notified again.
+    pub async fn check_budgets_and_notify(
+        &self,
+        app_state: &AppState,
+        webhook_state: &WebhookState,
+    ) -> AppResult<Vec<BudgetAlert>> {
+        let now = chrono::Utc::now().timestamp();
+        let alerts = self.with_service(|s| s.evaluate_budget_alerts(now)).await?;
+        notify_budget_alerts(app_state, webhook_state, &alerts).await;
+        Ok(alerts)
+    }
+
+    /// Start dispatching webhooks for the budget alerts the tracker raises
+    /// as usage is recorded. Only the first call has an effect.
+    pub fn start_budget_notifier(&self, app: AppHandle) {
+        let receiver = self
+            .budget_alert_receiver
+            .lock()
+            .ok()
+            .and_then(|mut receiver| receiver.take());
+        let Some(mut receiver) = receiver else {
+            return;
+        };
+
+        tauri::async_runtime::spawn(async move {
+            while let Some(alerts) = receiver.recv().await {
+                let app_state = app.state::<AppState>();
+                let webhook_state = app.state::<WebhookState>();
+                notify_budget_alerts(&app_state, &webhook_state, &alerts).await;
+            }
+        });
+    }
+
     pub async fn get_tracker_components(
         &self,
     ) -> Option<(
@@ -110,6 +163,32 @@ impl Default for AnalyticsState {
     }
 }
 
+/// Dispatch a webhook for each budget alert.
+async fn notify_budget_alerts(
+    app_state: &AppState,
+    webhook_state: &WebhookState,
+    alerts: &[BudgetAlert],
+) {
+    if alerts.is_empty() {
+        return;
+    }
+
+    match webhook_state.get_or_init(app_state).await {
+        Ok(webhook_service) => {
+            for alert in alerts {
+                let service = webhook_service.clone();
+                let payload = budget_alert_payload(alert);
+                tokio::spawn(async move {
+                    service.dispatch(payload).await;
+                });
+            }
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "budget alerts raised but webhook service unavailable");
+        }
+    }
+}
+
 // ============================================================================
 // Initialization Commands
 // ============================================================================
@@ -250,6 +329,152 @@ pub async fn get_dashboard_summary_v2(
     }
 }
 
+/// Usage time series at hour/day/week/month granularity with zero-filled gaps.
+///
+/// `timezone` is an IANA name (e.g. `"Asia/Tokyo"`) setting local bucket
+/// boundaries; defaults to UTC.
+#[tauri::command]
+pub async fn get_usage_time_series(
+    analytics_state: State<'_, AnalyticsState>,
+    filter: DashboardFilterV2,
+    period: Option<AggregationPeriod>,
+    timezone: Option<String>,
+) -> Result<CommandResponse<Vec<TimeSeriesPoint>>, String> {
+    let period = period.unwrap_or(AggregationPeriod::Daily);
+    let tz = match parse_timezone(timezone.as_deref().unwrap_or("UTC")) {
+        Ok(tz) => tz,
+        Err(e) => return Ok(CommandResponse::err(e.to_string())),
+    };
+    match analytics_state
+        .with_service(|s| s.get_time_series_v2(&filter, period, Some(tz)))
+        .await
+    {
+        Ok(points) => Ok(CommandResponse::ok(points)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Aggregate tokens and cost by session, split into parent vs sub-agent spend.
+#[tauri::command]
+pub async fn aggregate_by_session(
+    analytics_state: State<'_, AnalyticsState>,
+    filter: DashboardFilterV2,
+    sort_by: Option<UsageRollupSort>,
+    limit: Option<i64>,
+) -> Result<CommandResponse<Vec<UsageRollup>>, String> {
+    match analytics_state
+        .with_service(|s| {
+            s.aggregate_usage_rollup(
+                &filter,
+                UsageRollupDimension::Session,
+                sort_by.unwrap_or_default(),
+                limit,
+            )
+        })
+        .await
+    {
+        Ok(rows) => Ok(CommandResponse::ok(rows)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Aggregate tokens and cost by agent/pipeline, split into parent vs sub-agent spend.
+#[tauri::command]
+pub async fn aggregate_by_agent(
+    analytics_state: State<'_, AnalyticsState>,
+    filter: DashboardFilterV2,
+    sort_by: Option<UsageRollupSort>,
+    limit: Option<i64>,
+) -> Result<CommandResponse<Vec<UsageRollup>>, String> {
+    match analytics_state
+        .with_service(|s| {
+            s.aggregate_usage_rollup(
+                &filter,
+                UsageRollupDimension::Agent,
+                sort_by.unwrap_or_default(),
+                limit,
+            )
+        })
+        .await
+    {
+        Ok(rows) => Ok(CommandResponse::ok(rows)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Project month-end spend for a project (or all projects when omitted).
+#[tauri::command]
+pub async fn get_cost_projection(
+    analytics_state: State<'_, AnalyticsState>,
+    project_id: Option<String>,
+) -> Result<CommandResponse<CostProjection>, String> {
+    let now = chrono::Utc::now().timestamp();
+    match analytics_state
+        .with_service(|s| s.project_monthly_cost(project_id.as_deref(), now))
+        .await
+    {
+        Ok(projection) => Ok(CommandResponse::ok(projection)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// List configured monthly budgets.
+#[tauri::command]
+pub async fn list_cost_budgets(
+    analytics_state: State<'_, AnalyticsState>,
+) -> Result<CommandResponse<Vec<CostBudget>>, String> {
+    match analytics_state.with_service(|s| s.list_budgets()).await {
+        Ok(budgets) => Ok(CommandResponse::ok(budgets)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Create or update a monthly budget (global when `project_id` is empty).
+#[tauri::command]
+pub async fn upsert_cost_budget(
+    analytics_state: State<'_, AnalyticsState>,
+    budget: CostBudget,
+) -> Result<CommandResponse<CostBudget>, String> {
+    match analytics_state
+        .with_service(|s| s.upsert_budget(&budget))
+        .await
+    {
+        Ok(saved) => Ok(CommandResponse::ok(saved)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Delete a monthly budget.
+#[tauri::command]
+pub async fn delete_cost_budget(
+    analytics_state: State<'_, AnalyticsState>,
+    project_id: Option<String>,
+) -> Result<CommandResponse<bool>, String> {
+    match analytics_state
+        .with_service(|s| s.delete_budget(project_id.as_deref()))
+        .await
+    {
+        Ok(deleted) => Ok(CommandResponse::ok(deleted)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Evaluate budgets now and notify webhooks of newly crossed thresholds.
+#[tauri::command]
+pub async fn check_budget_alerts(
+    app_state: State<'_, AppState>,
+    analytics_state: State<'_, AnalyticsState>,
+    webhook_state: State<'_, WebhookState>,
+) -> Result<CommandResponse<Vec<BudgetAlert>>, String> {
+    match analytics_state
+        .check_budgets_and_notify(&app_state, &webhook_state)
+        .await
+    {
+        Ok(alerts) => Ok(CommandResponse::ok(alerts)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
 /// List pricing rules (manual maintenance).
 #[tauri::command]
 pub async fn list_pricing_rules(
@@ -274,7 +499,51 @@ pub async fn upsert_pricing_rule(
         .with_service(|s| s.upsert_pricing_rule(&rule))
         .await
     {
-        Ok(saved) => Ok(CommandResponse::ok(saved)),
+        Ok(saved) => {
+            if let Err(e) = analytics_state.refresh_pricing_rules().await {
+                tracing::warn!(error = %e, "failed to reload pricing rules");
+            }
+            Ok(CommandResponse::ok(saved))
+        }
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Bulk-import pricing rules. Conflicting batches are reported, not written.
+#[tauri::command]
+pub async fn import_pricing_rules(
+    analytics_state: State<'_, AnalyticsState>,
+    rules: Vec<PricingRule>,
+) -> Result<CommandResponse<PricingImportResult>, String> {
+    match analytics_state
+        .with_service(|s| s.import_pricing_rules(&rules))
+        .await
+    {
+        Ok(result) => {
+            if !result.imported.is_empty() {
+                if let Err(e) = analytics_state.refresh_pricing_rules().await {
+                    tracing::warn!(error = %e, "failed to reload pricing rules");
+                }
+            }
+            Ok(CommandResponse::ok(result))
+        }
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Export pricing rules in the shape accepted by `import_pricing_rules`.
+#[tauri::command]
+pub async fn export_pricing_rules(
+    analytics_state: State<'_, AnalyticsState>,
+) -> Result<CommandResponse<String>, String> {
+    match analytics_state
+        .with_service(|s| {
+            let rules = s.list_pricing_rules()?;
+            Ok(serde_json::to_string_pretty(&rules)?)
+        })
+        .await
+    {
+        Ok(json) => Ok(CommandResponse::ok(json)),
         Err(e) => Ok(CommandResponse::err(e.to_string())),
     }
 }
@@ -289,7 +558,12 @@ pub async fn delete_pricing_rule(
         .with_service(|s| s.delete_pricing_rule(&rule_id))
         .await
     {
-        Ok(ok) => Ok(CommandResponse::ok(ok)),
+        Ok(ok) => {
+            if let Err(e) = analytics_state.refresh_pricing_rules().await {
+                tracing::warn!(error = %e, "failed to reload pricing rules");
+            }
+            Ok(CommandResponse::ok(ok))
+        }
         Err(e) => Ok(CommandResponse::err(e.to_string())),
     }
 }
@@ -90,7 +90,7 @@ pub struct ArtifactLoadResponse {
 /// On first call, clones the Database from AppState and constructs a
 /// `DefaultArtifactService` with the storage root at
 /// `~/.plan-cascade/artifacts/`.
-async fn ensure_initialized(
+pub(crate) async fn ensure_initialized(
     artifact_state: &ArtifactState,
     app_state: &AppState,
 ) -> Result<(), String> {
@@ -135,6 +135,7 @@ fn make_minimal_ctx() -> crate::services::tools::trait_def::ToolExecutionContext
         knowledge_project_id: None,
         knowledge_collection_filter: None,
         knowledge_document_filter: None,
+        bash_env_allowlist: Vec::new(),
     }
 }
 
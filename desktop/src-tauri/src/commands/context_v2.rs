@@ -3563,6 +3563,7 @@ mod tests {
         SkillMatch {
             score: 1.0,
             match_reason: crate::services::skills::model::MatchReason::UserForced,
+            explanation: None,
             skill: crate::services::skills::model::SkillSummary {
                 id: format!("skill-{}", name.to_ascii_lowercase()),
                 name: name.to_string(),
@@ -18,6 +18,7 @@ use serde::{Deserialize, Serialize};
 use tauri::State;
 
 use crate::models::response::CommandResponse;
+use crate::services::health_cache::health_cache_key;
 use crate::services::orchestrator::analysis_index::{binary_extensions, default_excluded_roots};
 use crate::services::orchestrator::embedding_provider::{
     CodebaseIndexConfig, EmbeddingProvider, EmbeddingProviderCapability, EmbeddingProviderConfig,
@@ -76,15 +77,27 @@ pub struct CheckEmbeddingHealthRequest {
     pub provider: String,
     pub model: Option<String>,
     pub base_url: Option<String>,
+    /// Skip the health cache and run a fresh check.
+    #[serde(default)]
+    pub force_refresh: bool,
 }
 
 /// Response for `check_embedding_provider_health` (IPC-004).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EmbeddingHealthResponse {
     pub healthy: bool,
     pub message: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latency_ms: Option<u32>,
+    /// When the check ran (RFC 3339); cached results keep their original time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checked_at: Option<String>,
+    /// Served from the health cache instead of a fresh check.
+    #[serde(default)]
+    pub cached: bool,
+    /// Cached result past its TTL; a background refresh is in progress.
+    #[serde(default)]
+    pub stale: bool,
 }
 
 /// Request for `set_embedding_api_key` (IPC-005).
@@ -393,9 +406,13 @@ pub async fn list_embedding_providers() -> CommandResponse<Vec<EmbeddingProvider
 /// server connectivity. For remote API providers (Qwen, GLM, OpenAI), it
 /// verifies the API key exists in the keyring and attempts a lightweight
 /// validation call.
+///
+/// Results are cached briefly (see `services::health_cache`) so UI polling does
+/// not hit the provider on every call; set `force_refresh` to bypass the cache.
 #[tauri::command]
 pub async fn check_embedding_provider_health(
     request: CheckEmbeddingHealthRequest,
+    state: State<'_, AppState>,
 ) -> CommandResponse<EmbeddingHealthResponse> {
     let provider_type = match parse_provider_type(&request.provider) {
         Some(p) => p,
@@ -431,6 +448,7 @@ pub async fn check_embedding_provider_health(
                             capability.display_name, alias
                         ),
                         latency_ms: None,
+                        ..Default::default()
                     });
                 }
                 Err(e) => {
@@ -438,6 +456,7 @@ pub async fn check_embedding_provider_health(
                         healthy: false,
                         message: format!("Failed to read API key from keyring: {}", e),
                         latency_ms: None,
+                        ..Default::default()
                     });
                 }
             }
@@ -449,6 +468,7 @@ pub async fn check_embedding_provider_health(
                     capability.display_name
                 ),
                 latency_ms: None,
+                ..Default::default()
             });
         }
     }
@@ -459,20 +479,47 @@ pub async fn check_embedding_provider_health(
             healthy: false,
             message: format!("Invalid configuration: {}", e),
             latency_ms: None,
+            ..Default::default()
         });
     }
 
-    // Attempt to build the provider and run health check
+    let cache_key = health_cache_key(
+        &[
+            &request.provider,
+            &config.model,
+            config.base_url.as_deref().unwrap_or(""),
+        ],
+        config.api_key.as_deref(),
+    );
+    let cached = state
+        .embedding_health_cache()
+        .get_or_check(&cache_key, request.force_refresh, move || {
+            run_embedding_health_check(provider_type, config)
+        })
+        .await;
+    let mut response = cached.value;
+    response.checked_at = Some(cached.checked_at.to_rfc3339());
+    response.cached = cached.cached;
+    response.stale = cached.stale;
+    CommandResponse::ok(response)
+}
+
+/// Build the provider and run its health check.
+async fn run_embedding_health_check(
+    provider_type: EmbeddingProviderType,
+    config: EmbeddingProviderConfig,
+) -> EmbeddingHealthResponse {
     let start = std::time::Instant::now();
 
     match provider_type {
         EmbeddingProviderType::TfIdf => {
             // TF-IDF is always healthy (local, no dependencies)
-            CommandResponse::ok(EmbeddingHealthResponse {
+            EmbeddingHealthResponse {
                 healthy: true,
                 message: "TF-IDF provider is always available (local)".to_string(),
                 latency_ms: Some(start.elapsed().as_millis() as u32),
-            })
+                ..Default::default()
+            }
         }
         EmbeddingProviderType::Ollama => {
             // Attempt to connect to Ollama
@@ -481,16 +528,18 @@ pub async fn check_embedding_provider_health(
                     &config,
                 );
             match provider.health_check().await {
-                Ok(()) => CommandResponse::ok(EmbeddingHealthResponse {
+                Ok(()) => EmbeddingHealthResponse {
                     healthy: true,
                     message: "Ollama embedding provider is healthy".to_string(),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
-                Err(e) => CommandResponse::ok(EmbeddingHealthResponse {
+                    ..Default::default()
+                },
+                Err(e) => EmbeddingHealthResponse {
                     healthy: false,
                     message: format!("Ollama health check failed: {}", e),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
+                    ..Default::default()
+                },
             }
         }
         EmbeddingProviderType::Qwen => {
@@ -499,16 +548,18 @@ pub async fn check_embedding_provider_health(
                     &config,
                 );
             match provider.health_check().await {
-                Ok(()) => CommandResponse::ok(EmbeddingHealthResponse {
+                Ok(()) => EmbeddingHealthResponse {
                     healthy: true,
                     message: "Qwen embedding provider is healthy".to_string(),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
-                Err(e) => CommandResponse::ok(EmbeddingHealthResponse {
+                    ..Default::default()
+                },
+                Err(e) => EmbeddingHealthResponse {
                     healthy: false,
                     message: format!("Qwen health check failed: {}", e),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
+                    ..Default::default()
+                },
             }
         }
         EmbeddingProviderType::Glm => {
@@ -517,16 +568,18 @@ pub async fn check_embedding_provider_health(
                     &config,
                 );
             match provider.health_check().await {
-                Ok(()) => CommandResponse::ok(EmbeddingHealthResponse {
+                Ok(()) => EmbeddingHealthResponse {
                     healthy: true,
                     message: "GLM embedding provider is healthy".to_string(),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
-                Err(e) => CommandResponse::ok(EmbeddingHealthResponse {
+                    ..Default::default()
+                },
+                Err(e) => EmbeddingHealthResponse {
                     healthy: false,
                     message: format!("GLM health check failed: {}", e),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
+                    ..Default::default()
+                },
             }
         }
         EmbeddingProviderType::OpenAI => {
@@ -535,16 +588,18 @@ pub async fn check_embedding_provider_health(
                     &config,
                 );
             match provider.health_check().await {
-                Ok(()) => CommandResponse::ok(EmbeddingHealthResponse {
+                Ok(()) => EmbeddingHealthResponse {
                     healthy: true,
                     message: "OpenAI embedding provider is healthy".to_string(),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
-                Err(e) => CommandResponse::ok(EmbeddingHealthResponse {
+                    ..Default::default()
+                },
+                Err(e) => EmbeddingHealthResponse {
                     healthy: false,
                     message: format!("OpenAI health check failed: {}", e),
                     latency_ms: Some(start.elapsed().as_millis() as u32),
-                }),
+                    ..Default::default()
+                },
             }
         }
     }
@@ -1113,6 +1168,7 @@ mod tests {
             healthy: true,
             message: "All good".to_string(),
             latency_ms: Some(42),
+            ..Default::default()
         };
         let json = serde_json::to_string(&resp).unwrap();
         assert!(json.contains("\"healthy\":true"));
@@ -1125,6 +1181,7 @@ mod tests {
             healthy: false,
             message: "API key missing".to_string(),
             latency_ms: None,
+            ..Default::default()
         };
         let json = serde_json::to_string(&resp).unwrap();
         assert!(json.contains("\"healthy\":false"));
@@ -376,6 +376,9 @@ mod tests {
                 provider: "anthropic".to_string(),
                 model: "claude-sonnet-4-20250514".to_string(),
                 display_name: None,
+                temperature: None,
+                top_p: None,
+                seed: None,
             }],
             cases: vec![],
             status: "pending".to_string(),
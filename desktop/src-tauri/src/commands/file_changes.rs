@@ -7,7 +7,7 @@ use tokio::sync::RwLock;
 
 use crate::models::response::CommandResponse;
 use crate::services::file_change_tracker::{
-    FileChangeTracker, RestoreExecutionResult, RestorePreviewItem, RestoredFile, TurnChanges,
+    FileChangeTracker, RestoreExecutionResult, RestorePreview, RestoredFile, TurnChanges,
 };
 
 /// Tauri-managed state holding file change trackers keyed by session ID.
@@ -22,6 +22,28 @@ impl FileChangesState {
         }
     }
 
+    /// Fork the change records of the first `at_turn` turns (1-based,
+    /// inclusive) into a tracker for `new_session_id`.
+    pub async fn fork(
+        &self,
+        session_id: &str,
+        new_session_id: &str,
+        project_root: &str,
+        at_turn: u32,
+    ) -> Result<usize, String> {
+        let tracker = self.get_or_create(session_id, project_root).await;
+        let forked = tracker
+            .lock()
+            .map_err(|_| "Failed to lock tracker".to_string())?
+            .fork(new_session_id, at_turn)?;
+        let count = forked.change_count();
+        self.trackers
+            .write()
+            .await
+            .insert(new_session_id.to_string(), Arc::new(Mutex::new(forked)));
+        Ok(count)
+    }
+
     /// Get or create a tracker for the given session + project.
     pub async fn get_or_create(
         &self,
@@ -130,17 +152,20 @@ pub async fn get_file_change_diff(
     Ok(result)
 }
 
-/// Preview affected files for restoring to before the given turn index.
+/// Preview affected files and their diffs for restoring to before the given turn index.
 #[tauri::command]
 pub async fn preview_restore_to_turn(
     session_id: String,
     project_root: String,
     turn_index: u32,
     state: tauri::State<'_, FileChangesState>,
-) -> Result<CommandResponse<Vec<RestorePreviewItem>>, String> {
+) -> Result<CommandResponse<RestorePreview>, String> {
     let tracker = state.get_or_create(&session_id, &project_root).await;
     let result = match tracker.lock() {
-        Ok(t) => CommandResponse::ok(t.preview_restore_to_before_turn(turn_index)),
+        Ok(t) => match t.preview_restore_to_before_turn(turn_index) {
+            Ok(preview) => CommandResponse::ok(preview),
+            Err(e) => CommandResponse::err(e),
+        },
         Err(_) => CommandResponse::err("Failed to lock tracker"),
     };
     Ok(result)
@@ -153,12 +178,41 @@ pub async fn restore_files_to_turn_v2(
     project_root: String,
     turn_index: u32,
     create_snapshot: Option<bool>,
+    verify_on_disk: Option<bool>,
     state: tauri::State<'_, FileChangesState>,
 ) -> Result<CommandResponse<RestoreExecutionResult>, String> {
     let tracker = state.get_or_create(&session_id, &project_root).await;
     let result = match tracker.lock() {
-        Ok(mut t) => match t.restore_to_before_turn_v2(turn_index, create_snapshot.unwrap_or(true))
-        {
+        Ok(mut t) => match t.restore_to_before_turn_v2(
+            turn_index,
+            create_snapshot.unwrap_or(true),
+            verify_on_disk.unwrap_or(true),
+        ) {
+            Ok(resp) => CommandResponse::ok(resp),
+            Err(e) => CommandResponse::err(e),
+        },
+        Err(_) => CommandResponse::err("Failed to lock tracker"),
+    };
+    Ok(result)
+}
+
+/// Atomically restore a selected subset of files to before the given turn index.
+#[tauri::command]
+pub async fn restore_selected_files(
+    session_id: String,
+    project_root: String,
+    turn_index: u32,
+    paths: Vec<String>,
+    create_snapshot: Option<bool>,
+    state: tauri::State<'_, FileChangesState>,
+) -> Result<CommandResponse<RestoreExecutionResult>, String> {
+    let tracker = state.get_or_create(&session_id, &project_root).await;
+    let result = match tracker.lock() {
+        Ok(mut t) => match t.restore_files_to_before_turn(
+            turn_index,
+            &paths,
+            create_snapshot.unwrap_or(true),
+        ) {
             Ok(resp) => CommandResponse::ok(resp),
             Err(e) => CommandResponse::err(e),
         },
@@ -1274,21 +1274,21 @@ pub async fn git_configure_llm(
             .filter(|u| !u.is_empty())
     };
 
-    let proxy = app_state
+    let proxy_routing = app_state
         .with_database(|db| {
-            Ok(crate::commands::proxy::resolve_provider_proxy(
+            Ok(crate::commands::proxy::resolve_provider_proxy_routing(
                 &keyring, db, canonical,
             ))
         })
         .await
-        .unwrap_or(None);
+        .ok();
 
     let config = ProviderConfig {
         provider: provider_type,
         api_key: api_key_opt,
         base_url: resolved_base_url,
         model: resolved_model,
-        proxy,
+        proxy_routing,
         ..Default::default()
     };
 
@@ -29,6 +29,33 @@ fn ensure_graph_workflows_table(
     Ok(())
 }
 
+/// Check that error edges connect existing nodes and that each node has at
+/// most one error handler.
+fn validate_error_edges(workflow: &GraphWorkflow) -> AppResult<()> {
+    use crate::services::agent_composer::graph_types::Edge;
+
+    let mut handled = std::collections::HashSet::new();
+    for edge in &workflow.edges {
+        if let Edge::Error { from, to } = edge {
+            for node in [from, to] {
+                if !workflow.nodes.contains_key(node) {
+                    return Err(AppError::validation(format!(
+                        "Error edge {} -> {} references unknown node '{}'",
+                        from, to, node
+                    )));
+                }
+            }
+            if !handled.insert(from) {
+                return Err(AppError::validation(format!(
+                    "Node '{}' has more than one error edge",
+                    from
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 /// List all saved graph workflows (summary info only).
 #[tauri::command]
 pub async fn list_graph_workflows(
@@ -135,6 +162,7 @@ pub async fn create_graph_workflow(
                 .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
 
             ensure_graph_workflows_table(&conn)?;
+            validate_error_edges(&workflow)?;
 
             let id = uuid::Uuid::new_v4().to_string();
             let definition_json = serde_json::to_string(&workflow)?;
@@ -187,6 +215,7 @@ pub async fn update_graph_workflow(
                 )));
             }
 
+            validate_error_edges(&workflow)?;
             let definition_json = serde_json::to_string(&workflow)?;
 
             conn.execute(
@@ -295,6 +324,9 @@ fn generate_mermaid(workflow: &GraphWorkflow) -> String {
             Edge::Direct { from, to } => {
                 lines.push(format!("  {} --> {}", from, to));
             }
+            Edge::Error { from, to } => {
+                lines.push(format!("  {} -.->|error| {}", from, to));
+            }
             Edge::Conditional {
                 from,
                 condition: _,
@@ -510,6 +542,42 @@ mod tests {
         assert!(mermaid.contains("a --> b"));
     }
 
+    #[test]
+    fn test_generate_mermaid_error_edges() {
+        let mut workflow = sample_workflow();
+        workflow.edges.push(Edge::Error {
+            from: "a".to_string(),
+            to: "b".to_string(),
+        });
+        let mermaid = generate_mermaid(&workflow);
+        assert!(mermaid.contains("a -.->|error| b"));
+    }
+
+    #[test]
+    fn test_validate_error_edges() {
+        let mut workflow = sample_workflow();
+        workflow.edges.push(Edge::Error {
+            from: "a".to_string(),
+            to: "b".to_string(),
+        });
+        assert!(validate_error_edges(&workflow).is_ok());
+
+        workflow.edges.push(Edge::Error {
+            from: "a".to_string(),
+            to: "a".to_string(),
+        });
+        let err = validate_error_edges(&workflow).unwrap_err().to_string();
+        assert!(err.contains("more than one error edge"), "{}", err);
+
+        workflow.edges.pop();
+        workflow.edges.push(Edge::Error {
+            from: "b".to_string(),
+            to: "ghost".to_string(),
+        });
+        let err = validate_error_edges(&workflow).unwrap_err().to_string();
+        assert!(err.contains("unknown node 'ghost'"), "{}", err);
+    }
+
     #[test]
     fn test_generate_mermaid_conditional_edges() {
         let mut nodes = HashMap::new();
@@ -2,27 +2,34 @@
 //!
 //! Tauri IPC for listing, editing, and auditing runtime guardrails.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use tauri::State;
 use tokio::sync::RwLock;
 
 use crate::models::response::CommandResponse;
 use crate::services::guardrail::{
-    shared_guardrail_registry, CustomRuleConfig, Direction, GuardrailAction, GuardrailEventEntry,
-    GuardrailInfo, GuardrailMode, GuardrailRegistry,
+    preview_custom_rule, shared_guardrail_registry, CustomRuleConfig, CustomRulePreview, Direction,
+    GuardrailAction, GuardrailEventEntry, GuardrailInfo, GuardrailMode, GuardrailRegistry,
 };
 use crate::storage::Database;
 
+/// Interval between scheduled audit-trail retention passes.
+const GUARDRAIL_PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 /// Tauri-managed handle to the shared guardrail registry.
 pub struct GuardrailState {
     pub registry: Arc<RwLock<GuardrailRegistry>>,
+    prune_worker_started: AtomicBool,
 }
 
 impl GuardrailState {
     pub fn new() -> Self {
         Self {
             registry: shared_guardrail_registry(),
+            prune_worker_started: AtomicBool::new(false),
         }
     }
 
@@ -30,7 +37,33 @@ impl GuardrailState {
         self.registry
             .write()
             .await
-            .initialize_with_database(database)
+            .initialize_with_database(database)?;
+        self.start_prune_worker_if_needed();
+        Ok(())
+    }
+
+    /// Start the periodic retention worker once. Safe to call repeatedly.
+    fn start_prune_worker_if_needed(&self) {
+        if self
+            .prune_worker_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_err()
+        {
+            return;
+        }
+
+        let registry = self.registry.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(GUARDRAIL_PRUNE_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                let deleted = registry.read().await.prune_events();
+                if deleted > 0 {
+                    tracing::info!(deleted, "guardrail retention pruned audit events");
+                }
+            }
+        });
     }
 }
 
@@ -139,6 +172,19 @@ pub async fn toggle_guardrail(
     }
 }
 
+#[tauri::command]
+pub async fn set_guardrail_scope(
+    id: String,
+    scope: Vec<Direction>,
+    state: State<'_, GuardrailState>,
+) -> Result<CommandResponse<GuardrailInfo>, String> {
+    let mut registry = state.registry.write().await;
+    match registry.set_scope(&id, scope) {
+        Ok(info) => Ok(CommandResponse::ok(info)),
+        Err(error) => Ok(CommandResponse::err(error)),
+    }
+}
+
 #[tauri::command]
 pub async fn create_custom_guardrail(
     rule: CustomGuardrailInput,
@@ -151,6 +197,23 @@ pub async fn create_custom_guardrail(
     }
 }
 
+/// Compile a rule pattern and preview its matches against sample text
+/// without saving the rule.
+#[tauri::command]
+pub async fn test_custom_rule(
+    pattern: String,
+    sample_text: String,
+    name: Option<String>,
+) -> Result<CommandResponse<CustomRulePreview>, String> {
+    let name = name
+        .filter(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| "custom_rule".to_string());
+    match preview_custom_rule(&name, &pattern, &sample_text) {
+        Ok(preview) => Ok(CommandResponse::ok(preview)),
+        Err(error) => Ok(CommandResponse::err(error)),
+    }
+}
+
 #[tauri::command]
 pub async fn update_guardrail(
     rule: CustomGuardrailInput,
@@ -195,6 +258,38 @@ pub async fn clear_guardrail_events(
     Ok(CommandResponse::ok(registry.clear_events()))
 }
 
+#[tauri::command]
+pub async fn get_guardrail_retention(
+    state: State<'_, GuardrailState>,
+) -> Result<CommandResponse<GuardrailEventRetention>, String> {
+    let registry = state.registry.read().await;
+    Ok(CommandResponse::ok(registry.retention()))
+}
+
+#[tauri::command]
+pub async fn set_guardrail_retention(
+    retention: GuardrailEventRetention,
+    state: State<'_, GuardrailState>,
+) -> Result<CommandResponse<GuardrailEventRetention>, String> {
+    let mut registry = state.registry.write().await;
+    match registry.set_retention(retention) {
+        Ok(applied) => Ok(CommandResponse::ok(applied)),
+        Err(error) => Ok(CommandResponse::err(error)),
+    }
+}
+
+#[tauri::command]
+pub async fn aggregate_guardrail_events(
+    since: Option<String>,
+    until: Option<String>,
+    state: State<'_, GuardrailState>,
+) -> Result<CommandResponse<Vec<GuardrailEventAggregate>>, String> {
+    let registry = state.registry.read().await;
+    Ok(CommandResponse::ok(
+        registry.aggregate_events(since.as_deref(), until.as_deref()),
+    ))
+}
+
 // Compatibility wrappers for the existing frontend while it migrates.
 #[tauri::command]
 pub async fn add_custom_rule(
@@ -269,4 +364,34 @@ mod tests {
         assert_eq!(parsed.action, GuardrailAction::Block);
         assert!(!parsed.id.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_custom_rule_reports_compile_error() {
+        let response = test_custom_rule("[a-".to_string(), "abc".to_string(), None)
+            .await
+            .unwrap();
+        assert!(!response.success);
+        assert!(response
+            .error
+            .as_deref()
+            .unwrap_or_default()
+            .starts_with("Invalid regex pattern"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_rule_previews_matches() {
+        let response = test_custom_rule(
+            "TODO".to_string(),
+            "TODO: one, TODO: two".to_string(),
+            Some("No TODO".to_string()),
+        )
+        .await
+        .unwrap();
+        let preview = response.data.expect("preview");
+        assert_eq!(preview.matches.len(), 2);
+        assert_eq!(
+            preview.redacted_preview.as_deref(),
+            Some("[REDACTED:No TODO]: one, [REDACTED:No TODO]: two")
+        );
+    }
 }
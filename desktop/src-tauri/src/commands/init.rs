@@ -16,6 +16,7 @@ use crate::commands::remote::RemoteState;
 use crate::commands::spec_interview::SpecInterviewState;
 use crate::commands::standalone::StandaloneState;
 use crate::commands::webhook::WebhookState;
+use crate::commands::worktree::WorktreeState;
 use crate::models::response::CommandResponse;
 use crate::services::orchestrator::index_manager::IndexManager;
 use crate::services::recovery::detector::{IncompleteTask, RecoveryDetector};
@@ -87,6 +88,7 @@ pub async fn init_app(
     plugin_state: State<'_, PluginState>,
     spec_interview_state: State<'_, SpecInterviewState>,
     mcp_state: State<'_, McpRuntimeState>,
+    worktree_state: State<'_, WorktreeState>,
     app: AppHandle,
 ) -> Result<CommandResponse<InitResult>, String> {
     emit_init_progress(&app, InitStage::CoreState, 1);
@@ -107,6 +109,10 @@ pub async fn init_app(
                 tracing::warn!("Webhook worker initialization failed: {}", e);
             }
 
+            if let Err(e) = worktree_state.load_quota(state.inner()).await {
+                tracing::warn!("Worktree quota could not be restored: {}", e);
+            }
+
             emit_init_progress(&app, InitStage::Plugins, 2);
 
             // Initialize the plugin system early (ADR-F003).
@@ -140,7 +146,7 @@ pub async fn init_app(
             // return the previous indexed state from SQLite (fixes status not
             // showing after app restart).
             if let Ok(pool) = state.with_database(|db| Ok(db.pool().clone())).await {
-                let manager = IndexManager::new(pool);
+                let manager = IndexManager::new(pool).with_parse_cache(state.parse_cache().clone());
                 manager.set_app_handle(app.clone()).await;
 
                 // Store immediately so frontend can query status
@@ -10,14 +10,16 @@ use tokio::sync::RwLock;
 use tracing::{info, warn};
 
 use crate::models::response::CommandResponse;
-use crate::services::knowledge::chunker::{Chunker, Document, ParagraphChunker};
+use crate::services::knowledge::chunker::{Chunker, Document, MarkdownChunker, ParagraphChunker};
 use crate::services::knowledge::docs_indexer::{DocsIndexer, DocsKbStatus};
 use crate::services::knowledge::observability::{self, KnowledgeObservabilityMetrics};
 use crate::services::knowledge::pipeline::{
     CollectionUpdateCheck, DocumentSearchMatch, DocumentSummary, KnowledgeCollection,
     QueryRunSummary, RagPipeline, RagQueryResult, ScopedDocumentRef,
 };
-use crate::services::knowledge::reranker::{NoopReranker, Reranker, SearchResult};
+use crate::services::knowledge::reranker::{
+    CrossEncoderConfig, CrossEncoderReranker, NoopReranker, Reranker, SearchResult,
+};
 use crate::services::orchestrator::embedding_config_builder;
 use crate::services::orchestrator::embedding_manager::{EmbeddingManager, EmbeddingManagerConfig};
 use crate::services::orchestrator::embedding_provider::{
@@ -49,7 +51,8 @@ impl KnowledgeState {
     /// Initialize the knowledge pipeline with a pre-built embedding config.
     ///
     /// Uses the provided `EmbeddingManagerConfig` (typically from
-    /// `embedding_config_builder::build_embedding_config_from_settings`).
+    /// `embedding_config_builder::build_embedding_config_from_settings`) and
+    /// cross-encoder config (from `CrossEncoderConfig::from_settings`).
     /// When using TF-IDF, attempts to restore persisted vocabulary from disk.
     ///
     /// Subsequent calls are no-ops if already initialized.
@@ -58,13 +61,14 @@ impl KnowledgeState {
         database: Arc<Database>,
         emb_config: EmbeddingManagerConfig,
         is_tfidf: bool,
+        cross_encoder_config: CrossEncoderConfig,
     ) -> AppResult<()> {
         let mut guard = self.pipeline.write().await;
         if guard.is_some() {
             return Ok(());
         }
 
-        let chunker: Arc<dyn Chunker> = Arc::new(ParagraphChunker::new(1000));
+        let chunker: Arc<dyn Chunker> = Arc::new(MarkdownChunker::new(1000));
 
         let embedding_manager =
             Arc::new(EmbeddingManager::from_config(emb_config).map_err(|e| {
@@ -89,7 +93,11 @@ impl KnowledgeState {
             info!("Knowledge HNSW not found on disk, will try rebuilding from SQLite");
         }
 
-        let reranker: Option<Arc<dyn Reranker>> = Some(Arc::new(NoopReranker));
+        // Cross-encoder reranking is opt-in via settings; it delegates to the
+        // no-op reranker when disabled.
+        let reranker: Option<Arc<dyn Reranker>> = Some(Arc::new(
+            CrossEncoderReranker::from_config(&cross_encoder_config, Arc::new(NoopReranker)),
+        ));
 
         let pipeline = RagPipeline::new(
             chunker,
@@ -125,8 +133,9 @@ impl KnowledgeState {
 
     /// Initialize the knowledge pipeline from a Database instance.
     ///
-    /// Convenience method that defaults to TF-IDF embeddings. Used by tests
-    /// and callers that don't need custom embedding configuration.
+    /// Convenience method that defaults to TF-IDF embeddings without
+    /// cross-encoder reranking. Used by tests and callers that don't need
+    /// custom embedding configuration.
     ///
     /// Subsequent calls are no-ops if already initialized.
     pub async fn initialize(&self, database: Arc<Database>) -> AppResult<()> {
@@ -136,7 +145,7 @@ impl KnowledgeState {
             cache_enabled: false,
             cache_max_entries: 0,
         };
-        self.initialize_with_config(database, emb_config, true)
+        self.initialize_with_config(database, emb_config, true, CrossEncoderConfig::default())
             .await
     }
 
@@ -382,17 +391,21 @@ async fn ensure_initialized(
     // Build embedding config using keyring for API key resolution.
     // We use `with_keyring` to access the keyring while the lock is held,
     // computing the config we need and then dropping the lock.
-    let (emb_config, is_tfidf) = app_state
+    let (emb_config, is_tfidf, cross_encoder_config) = app_state
         .with_keyring(|keyring| {
             let (config, _dim, is_tfidf) =
                 embedding_config_builder::build_embedding_config_from_settings(&db, keyring);
-            Ok((config, is_tfidf))
+            Ok((
+                config,
+                is_tfidf,
+                CrossEncoderConfig::from_settings(&db, keyring),
+            ))
         })
         .await
         .map_err(|e| format!("Failed to build embedding config: {}", e))?;
 
     knowledge_state
-        .initialize_with_config(db, emb_config, is_tfidf)
+        .initialize_with_config(db, emb_config, is_tfidf, cross_encoder_config)
         .await
         .map_err(|e| format!("Failed to initialize knowledge pipeline: {}", e))?;
     Ok(())
@@ -482,6 +495,7 @@ pub async fn rag_query(
                 k,
                 collection_ids.as_deref(),
                 document_filters.as_deref(),
+                None,
                 retrieval_profile.as_deref(),
             )
             .await
@@ -823,7 +837,7 @@ pub async fn rag_ensure_docs_collection(
     }
 }
 
-/// Sync a docs collection with the workspace: check and apply updates.
+/// Sync a docs collection with the workspace, re-indexing only new or changed files.
 #[tauri::command]
 pub async fn rag_sync_docs_collection(
     app_handle: tauri::AppHandle,
@@ -840,46 +854,24 @@ pub async fn rag_sync_docs_collection(
         Err(e) => return Ok(CommandResponse::err(e.to_string())),
     };
 
-    let collection_name = DocsIndexer::collection_name_for_workspace(&workspace_path);
-
-    // Find the collection
-    let collections = match pipeline.list_collections(&project_id) {
-        Ok(c) => c,
-        Err(e) => return Ok(CommandResponse::err(e.to_string())),
-    };
-
-    let collection = match collections.iter().find(|c| c.name == collection_name) {
-        Some(c) => c,
-        None => return Ok(CommandResponse::ok(None)),
-    };
-
-    // Check for updates with scan_dir to detect new files too
-    let ws_path = std::path::Path::new(&workspace_path);
-    let updates = match pipeline.check_collection_updates(&collection.id, Some(ws_path)) {
-        Ok(u) => u,
-        Err(e) => return Ok(CommandResponse::err(e.to_string())),
-    };
-
-    if updates.modified.is_empty() && updates.deleted.is_empty() && updates.new_files.is_empty() {
-        return Ok(CommandResponse::ok(Some(collection.clone())));
-    }
-
-    match pipeline
-        .apply_collection_updates(&collection.id, &updates, Some(&app_handle))
+    match docs_indexer_state
+        .indexer
+        .reindex_docs_collection(&pipeline, &workspace_path, &project_id, Some(&app_handle))
         .await
     {
-        Ok(updated) => {
+        Ok(Some(report)) => {
             // Clear pending changes
             docs_indexer_state
                 .indexer
                 .take_pending_changes(&workspace_path)
                 .await;
 
-            if is_tfidf_pipeline(&pipeline) {
+            if !report.reindexed.is_empty() && is_tfidf_pipeline(&pipeline) {
                 save_tfidf_vocab(pipeline.embedding_manager());
             }
-            Ok(CommandResponse::ok(Some(updated)))
+            Ok(CommandResponse::ok(Some(report.collection)))
         }
+        Ok(None) => Ok(CommandResponse::ok(None)),
         Err(e) => Ok(CommandResponse::err(e.to_string())),
     }
 }
@@ -1426,6 +1418,7 @@ mod tests {
             minimum_relevance_score: 0.3,
             collection_ids: None,
             document_refs: None,
+            max_context_tokens: None,
         };
 
         let chunks = provider
@@ -202,6 +202,10 @@ pub async fn trigger_lsp_enrichment(
 
             // Emit "enriched" status and update cached enrichment flag
             if let Some(mgr) = &*standalone_state.index_manager.read().await {
+                // A full pass supersedes pending incremental state.
+                if let Some(enricher) = mgr.get_lsp_enricher(&project_path).await {
+                    enricher.reset_incremental_state().await;
+                }
                 mgr.set_lsp_enrichment_status(&project_path, "enriched")
                     .await;
                 mgr.set_enrichment_enabled(&project_path, true).await;
@@ -239,11 +243,36 @@ pub async fn trigger_lsp_enrichment(
 }
 
 /// Get the results of the last enrichment pass.
+///
+/// When `project_path` is given, incremental passes since the last full
+/// enrichment are folded in and `stale_files` lists files awaiting
+/// re-enrichment.
 #[tauri::command]
 pub async fn get_enrichment_report(
+    project_path: Option<String>,
     lsp_state: State<'_, LspState>,
+    standalone_state: State<'_, StandaloneState>,
 ) -> Result<CommandResponse<Option<EnrichmentReport>>, String> {
-    let report = lsp_state.last_report.read().await.clone();
+    let mut report = lsp_state.last_report.read().await.clone();
+
+    if let Some(project_path) = project_path {
+        let enricher = match &*standalone_state.index_manager.read().await {
+            Some(mgr) => mgr.get_lsp_enricher(&project_path).await,
+            None => None,
+        };
+        if let Some(enricher) = enricher {
+            if let Some(incremental) = enricher.incremental_report().await {
+                match report.as_mut() {
+                    Some(full) => full.absorb(&incremental),
+                    None => report = Some(incremental),
+                }
+            }
+            let stale_files = enricher.stale_files().await;
+            if let Some(report) = report.as_mut() {
+                report.stale_files = stale_files;
+            }
+        }
+    }
 
     Ok(CommandResponse {
         success: true,
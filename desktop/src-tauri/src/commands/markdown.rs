@@ -2,7 +2,9 @@
 //!
 //! Tauri commands for CLAUDE.md file operations.
 
-use crate::models::markdown::{ClaudeMdContent, ClaudeMdFile, FileMetadata, SaveResult};
+use crate::models::markdown::{
+    ClaudeMdContent, ClaudeMdFile, ClaudeMdWriteMode, EffectiveClaudeMd, FileMetadata, SaveResult,
+};
 use crate::models::response::CommandResponse;
 use crate::services::markdown::MarkdownService;
 
@@ -29,15 +31,27 @@ pub fn read_claude_md(path: String) -> Result<CommandResponse<ClaudeMdContent>,
 }
 
 /// Save content to a CLAUDE.md file
+///
+/// In `merge` mode `content` is regenerated output: only its managed
+/// sections replace those in the file, and user-authored text is kept.
 #[tauri::command]
 pub fn save_claude_md(
     path: String,
     content: String,
+    mode: Option<ClaudeMdWriteMode>,
 ) -> Result<CommandResponse<SaveResult>, String> {
     let service = MarkdownService::new();
 
-    match service.save_claude_md(&path, &content) {
-        Ok(()) => Ok(CommandResponse::ok(SaveResult::ok(&path))),
+    let result = match mode.unwrap_or_default() {
+        ClaudeMdWriteMode::Overwrite => service.save_claude_md(&path, &content).map(|()| None),
+        ClaudeMdWriteMode::Merge => service.merge_claude_md(&path, &content).map(Some),
+    };
+
+    match result {
+        Ok(merge) => Ok(CommandResponse::ok(SaveResult {
+            merge,
+            ..SaveResult::ok(&path)
+        })),
         Err(e) => Ok(CommandResponse::ok(SaveResult::err(&path, e.to_string()))),
     }
 }
@@ -67,6 +81,19 @@ pub fn get_claude_md_metadata(path: String) -> Result<CommandResponse<FileMetada
     }
 }
 
+/// Resolve the merged CLAUDE.md instructions that apply to a directory
+#[tauri::command]
+pub fn resolve_effective_claude_md(
+    path: String,
+) -> Result<CommandResponse<EffectiveClaudeMd>, String> {
+    let service = MarkdownService::new();
+
+    match service.resolve_effective_claude_md(&path) {
+        Ok(effective) => Ok(CommandResponse::ok(effective)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,6 +132,7 @@ mod tests {
         let result = save_claude_md(
             claude_md_path.to_str().unwrap().to_string(),
             content.clone(),
+            None,
         )
         .unwrap();
 
@@ -115,6 +143,42 @@ mod tests {
         assert_eq!(saved, content);
     }
 
+    #[test]
+    fn test_save_claude_md_merge_reports_conflict() {
+        let temp_dir = TempDir::new().unwrap();
+        let claude_md_path = temp_dir.path().join("CLAUDE.md");
+        let path = claude_md_path.to_str().unwrap().to_string();
+        let generated = |body: &str| {
+            format!(
+                "<!-- plan-cascade:begin commands -->\n{}<!-- plan-cascade:end commands -->\n",
+                body
+            )
+        };
+
+        save_claude_md(
+            path.clone(),
+            generated("npm test\n"),
+            Some(ClaudeMdWriteMode::Merge),
+        )
+        .unwrap();
+        let edited = fs::read_to_string(&claude_md_path)
+            .unwrap()
+            .replace("npm test", "pnpm test");
+        fs::write(&claude_md_path, edited).unwrap();
+
+        let result = save_claude_md(
+            path.clone(),
+            generated("npm run test\n"),
+            Some(ClaudeMdWriteMode::Merge),
+        )
+        .unwrap();
+        let merge = result.data.unwrap().merge.unwrap();
+        assert_eq!(merge.conflicts, vec!["commands"]);
+
+        let metadata = get_claude_md_metadata(path).unwrap().data.unwrap();
+        assert!(metadata.managed_sections[0].conflicted);
+    }
+
     #[test]
     fn test_create_claude_md_command() {
         let temp_dir = TempDir::new().unwrap();
@@ -1392,15 +1392,14 @@ async fn resolve_provider_for_agent_ref(
     };
 
     // Resolve proxy settings
-    let proxy = state
+    let proxy_routing = state
         .with_database(|db| {
-            Ok(crate::commands::proxy::resolve_provider_proxy(
+            Ok(crate::commands::proxy::resolve_provider_proxy_routing(
                 &keyring, db, canonical,
             ))
         })
         .await
-        .ok()
-        .flatten();
+        .ok();
 
     let resolved_model = parsed_agent
         .map(|(_, model)| model)
@@ -1421,7 +1420,7 @@ async fn resolve_provider_for_agent_ref(
         model: resolved_model,
         max_tokens: 2048,
         temperature: 0.3,
-        proxy,
+        proxy_routing,
         ..Default::default()
     };
 
@@ -52,6 +52,9 @@ pub struct RespondPermissionRequest {
 pub struct SetPolicyConfigRequest {
     /// Auto-allow Bash network calls only for these allowlisted domains.
     pub network_domain_allowlist: Vec<String>,
+    /// Custom dangerous Bash command patterns. Omitted keeps the current list.
+    #[serde(default)]
+    pub dangerous_command_patterns: Option<Vec<String>>,
 }
 
 /// Permission policy config payload returned to frontend.
@@ -65,6 +68,8 @@ pub struct PermissionPolicyConfigResponse {
     pub builtin_network_domain_allowlist_version: String,
     /// All built-in allowlist versions known by this app build.
     pub builtin_network_domain_allowlist_available_versions: Vec<String>,
+    /// Custom dangerous Bash command patterns configured by user.
+    pub dangerous_command_patterns: Vec<String>,
 }
 
 /// Set the permission level for a session.
@@ -129,6 +134,7 @@ pub async fn get_permission_policy_config(
                 .into_iter()
                 .map(|s| s.to_string())
                 .collect(),
+        dangerous_command_patterns: config.dangerous_command_patterns,
     }))
 }
 
@@ -138,10 +144,21 @@ pub async fn set_permission_policy_config(
     state: tauri::State<'_, PermissionState>,
     request: SetPolicyConfigRequest,
 ) -> Result<CommandResponse<()>, String> {
+    let dangerous_command_patterns = match request.dangerous_command_patterns {
+        Some(patterns) => patterns,
+        None => {
+            state
+                .gate
+                .get_policy_config()
+                .await
+                .dangerous_command_patterns
+        }
+    };
     state
         .gate
         .set_policy_config(PermissionPolicyConfig {
             network_domain_allowlist: request.network_domain_allowlist,
+            dangerous_command_patterns,
         })
         .await;
     Ok(CommandResponse::ok(()))
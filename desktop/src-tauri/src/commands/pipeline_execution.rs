@@ -495,16 +495,16 @@ async fn resolve_provider_config(
     }
 
     // Resolve proxy
-    let proxy = app_state
+    let proxy_routing = app_state
         .with_database(|db| {
-            Ok(crate::commands::proxy::resolve_provider_proxy(
+            Ok(crate::commands::proxy::resolve_provider_proxy_routing(
                 &keyring,
                 db,
                 canonical_provider,
             ))
         })
         .await
-        .unwrap_or(None);
+        .ok();
 
     let project_root = standalone_state.working_directory.read().await.clone();
 
@@ -522,7 +522,7 @@ async fn resolve_provider_config(
         api_key,
         base_url: None,
         model: resolved_model,
-        proxy,
+        proxy_routing,
         ..Default::default()
     };
 
@@ -193,6 +193,9 @@ pub async fn toggle_plugin(
 pub async fn refresh_plugins(
     state: State<'_, PluginState>,
 ) -> Result<CommandResponse<Vec<PluginInfo>>, String> {
+    // Explicit refresh also forces marketplace indexes to be refetched
+    marketplace::invalidate_search_cache();
+
     match state
         .with_manager_mut(|m| {
             m.refresh_plugins();
@@ -354,27 +357,12 @@ pub async fn fetch_marketplace(
             .find(|m| &m.name == marketplace_name);
 
         for entry in &manifest.plugins {
-            let local = installed_plugins.iter().find(|p| p.name == entry.name);
-
-            let source_spec = marketplace_config
-                .and_then(|cfg| marketplace::resolve_install_source(entry, cfg).ok())
-                .map(|s| s.to_spec_string())
-                .unwrap_or_default();
-
-            all_plugins.push(MarketplacePlugin {
-                name: entry.name.clone(),
-                version: entry.version.clone().unwrap_or_default(),
-                description: entry.description.clone().unwrap_or_default(),
-                author: entry.author_string(),
-                repository: entry.repository.clone(),
-                license: entry.license.clone(),
-                keywords: entry.keywords.clone(),
-                category: entry.category.clone(),
-                marketplace_name: marketplace_name.clone(),
-                source_spec,
-                installed: local.is_some(),
-                enabled: local.is_some_and(|p| p.enabled),
-            });
+            all_plugins.push(marketplace::build_marketplace_plugin(
+                entry,
+                marketplace_name,
+                marketplace_config,
+                &installed_plugins,
+            ));
         }
     }
 
@@ -403,6 +391,36 @@ pub async fn fetch_marketplace(
     Ok(CommandResponse::ok(all_plugins))
 }
 
+/// Search plugins across all enabled marketplaces.
+///
+/// Queries the persisted marketplace index, refetching any marketplace whose
+/// cached manifest is older than the TTL or was invalidated by
+/// `refresh_plugins`. Results are ordered by relevance and carry the source
+/// marketplace and local install status.
+#[tauri::command]
+pub async fn search_marketplace(
+    query: String,
+    filters: Option<MarketplaceSearchFilters>,
+    state: State<'_, PluginState>,
+) -> Result<CommandResponse<Vec<MarketplaceSearchResult>>, String> {
+    let settings = plugin_settings::load_plugin_settings();
+    let installed_plugins = state
+        .with_manager(|m| m.list_plugins())
+        .await
+        .unwrap_or_default();
+
+    let cache = marketplace::refresh_search_cache(&settings.marketplaces).await;
+    let results = marketplace::search_marketplaces(
+        &cache,
+        &settings.marketplaces,
+        &query,
+        &filters.unwrap_or_default(),
+        &installed_plugins,
+    );
+
+    Ok(CommandResponse::ok(results))
+}
+
 /// Install a plugin from a git URL.
 ///
 /// Clones the repository, validates plugin.json, installs to managed
@@ -2,17 +2,22 @@
 //!
 //! Tauri command handlers for prompt template management.
 
-use crate::models::prompt::{PromptCreateRequest, PromptTemplate, PromptUpdateRequest};
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::prompt::{
+    PromptCreateRequest, PromptSortMode, PromptTemplate, PromptUpdateRequest, RenderedPrompt,
+};
 use crate::models::response::CommandResponse;
 use crate::services::prompt::PromptService;
 use crate::state::AppState;
 
-/// List all prompts with optional category filter and search
+/// List all prompts with optional category filter, search and sort mode
 #[tauri::command]
 pub async fn list_prompts(
     category: Option<String>,
     search: Option<String>,
     locale: Option<String>,
+    sort: Option<PromptSortMode>,
     state: tauri::State<'_, AppState>,
 ) -> Result<CommandResponse<Vec<PromptTemplate>>, String> {
     let result = state
@@ -20,7 +25,12 @@ pub async fn list_prompts(
             let service = PromptService::from_database(db);
             // Seed built-in prompts on first access
             service.seed_builtins()?;
-            service.list_prompts(category.as_deref(), search.as_deref(), locale.as_deref())
+            service.list_prompts(
+                category.as_deref(),
+                search.as_deref(),
+                locale.as_deref(),
+                sort,
+            )
         })
         .await;
 
@@ -88,16 +98,39 @@ pub async fn delete_prompt(
     }
 }
 
-/// Record usage of a prompt template
+/// Render a prompt template with the given variable values
+#[tauri::command]
+pub async fn render_prompt(
+    id: String,
+    vars: Option<HashMap<String, String>>,
+    locale: Option<String>,
+    state: tauri::State<'_, AppState>,
+) -> Result<CommandResponse<RenderedPrompt>, String> {
+    let vars = vars.unwrap_or_default();
+    let result = state
+        .with_database(|db| {
+            let service = PromptService::from_database(db);
+            service.render_prompt(&id, &vars, locale.as_deref())
+        })
+        .await;
+
+    match result {
+        Ok(rendered) => Ok(CommandResponse::ok(rendered)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Record usage of a prompt template, with the variables it was rendered with
 #[tauri::command]
 pub async fn record_prompt_use(
     id: String,
+    variables: Option<BTreeMap<String, String>>,
     state: tauri::State<'_, AppState>,
 ) -> Result<CommandResponse<()>, String> {
     let result = state
         .with_database(|db| {
             let service = PromptService::from_database(db);
-            service.record_use(&id)
+            service.record_use(&id, variables.as_ref())
         })
         .await;
 
@@ -10,14 +10,19 @@
 //! - `set_proxy_config` — Set/clear the global proxy configuration
 //! - `get_provider_proxy_strategy` — Get proxy strategy for a specific provider
 //! - `set_provider_proxy_strategy` — Set proxy strategy (and custom config) for a provider
-//! - `test_proxy` — Test proxy connectivity
+//! - `set_proxy_routes` — Set/clear the per-host routing rules used by `per_host`
+//! - `test_proxy` — Test proxy connectivity with step-by-step diagnostics
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
 use tauri::State;
 
 use crate::models::response::CommandResponse;
-use crate::services::proxy::{ProxyConfig, ProxyStrategy};
+use crate::services::proxy::{
+    system_proxy_for, PacResolver, ProxyConfig, ProxyRouting, ProxyRoutingTable, ProxyStrategy,
+};
+use crate::services::proxy_diagnostics::{diagnose_proxy, ProxyDiagnostics};
 use crate::state::AppState;
 use crate::storage::KeyringService;
 
@@ -57,6 +62,19 @@ const CUSTOM_KEY_PREFIX: &str = "proxy_custom_";
 /// Settings DB key for global proxy config.
 const GLOBAL_PROXY_KEY: &str = "proxy_global";
 
+/// Settings DB key for the per-host routing table.
+const ROUTES_KEY: &str = "proxy_routes";
+
+/// Route proxy id that refers to the global proxy when not defined in the table.
+const GLOBAL_ROUTE_PROXY_ID: &str = "global";
+
+/// Settings DB key prefix for the downloaded PAC script of a provider.
+const PAC_SCRIPT_KEY_PREFIX: &str = "proxy_pac_script_";
+
+/// Process-wide PAC resolvers keyed by PAC URL, so per-host results stay
+/// cached across provider client constructions.
+static PAC_RESOLVERS: OnceLock<Mutex<HashMap<String, Arc<PacResolver>>>> = OnceLock::new();
+
 /// Keyring key prefix for proxy passwords.
 const KEYRING_PREFIX: &str = "proxy_";
 
@@ -73,6 +91,9 @@ pub struct ProxySettingsResponse {
     pub provider_strategies: HashMap<String, ProxyStrategy>,
     /// Per-provider custom proxy configs (only for providers with Custom strategy).
     pub provider_configs: HashMap<String, ProxyConfig>,
+    /// Per-host routing rules (passwords excluded).
+    #[serde(default)]
+    pub routes: Option<ProxyRoutingTable>,
 }
 
 /// Request to set global proxy configuration.
@@ -97,6 +118,16 @@ pub struct SetProviderProxyRequest {
     pub custom_password: Option<String>,
 }
 
+/// Request to set the per-host routing table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetProxyRoutesRequest {
+    /// The routing table. null = clear all rules.
+    pub routes: Option<ProxyRoutingTable>,
+    /// Passwords for named route proxies, keyed by proxy id (stored in keyring).
+    #[serde(default)]
+    pub passwords: HashMap<String, String>,
+}
+
 /// Request to test proxy connectivity.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestProxyRequest {
@@ -106,6 +137,10 @@ pub struct TestProxyRequest {
     pub password: Option<String>,
     /// URL to test against (default: https://httpbin.org/get).
     pub test_url: Option<String>,
+    /// Read the password from the keyring for this proxy ("global" or a
+    /// provider id) when `password` is not given.
+    #[serde(default)]
+    pub keyring_id: Option<String>,
 }
 
 /// Result of a proxy connectivity test.
@@ -114,8 +149,14 @@ pub struct ProxyTestResult {
     pub success: bool,
     pub latency_ms: Option<u32>,
     pub error: Option<String>,
+    /// Per-step results: TCP connect, proxy auth, end-to-end fetch.
+    #[serde(default)]
+    pub diagnostics: Option<ProxyDiagnostics>,
 }
 
+/// Timeout applied to each proxy diagnostic step.
+const PROXY_TEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
 // ---------------------------------------------------------------------------
 // IPC Commands
 // ---------------------------------------------------------------------------
@@ -156,10 +197,15 @@ pub async fn get_proxy_config(
                 }
             }
 
+            let routes: Option<ProxyRoutingTable> = db
+                .get_setting(ROUTES_KEY)?
+                .and_then(|json| serde_json::from_str(&json).ok());
+
             Ok(ProxySettingsResponse {
                 global,
                 provider_strategies,
                 provider_configs,
+                routes,
             })
         })
         .await;
@@ -250,6 +296,23 @@ pub async fn set_provider_proxy_strategy(
     request: SetProviderProxyRequest,
     state: State<'_, AppState>,
 ) -> Result<CommandResponse<bool>, String> {
+    // Download the PAC script up front so a bad URL is reported now rather
+    // than silently falling back to direct connections later.
+    let pac_script = match &request.strategy {
+        ProxyStrategy::Pac(pac_url) => match PacResolver::fetch(pac_url).await {
+            Ok(resolver) => {
+                let script = resolver.script().to_string();
+                pac_resolvers()
+                    .lock()
+                    .unwrap()
+                    .insert(pac_url.clone(), Arc::new(resolver));
+                Some(script)
+            }
+            Err(e) => return Ok(CommandResponse::err(e)),
+        },
+        _ => None,
+    };
+
     // Handle custom proxy password
     if request.strategy == ProxyStrategy::Custom {
         let keyring = KeyringService::new();
@@ -292,6 +355,12 @@ pub async fn set_provider_proxy_strategy(
                 db.delete_setting(&custom_key)?;
             }
 
+            let pac_key = format!("{}{}", PAC_SCRIPT_KEY_PREFIX, request.provider);
+            match pac_script {
+                Some(ref script) => db.set_setting(&pac_key, script)?,
+                None => db.delete_setting(&pac_key)?,
+            }
+
             Ok(true)
         })
         .await;
@@ -302,54 +371,115 @@ pub async fn set_provider_proxy_strategy(
     }
 }
 
-/// Test proxy connectivity by making a request through the proxy.
+/// Set or clear the per-host proxy routing table.
+#[tauri::command]
+pub async fn set_proxy_routes(
+    request: SetProxyRoutesRequest,
+    state: State<'_, AppState>,
+) -> Result<CommandResponse<bool>, String> {
+    let keyring = KeyringService::new();
+    for (id, password) in &request.passwords {
+        if let Err(e) = keyring.set_api_key(&route_keyring_key(id), password) {
+            return Ok(CommandResponse::err(format!(
+                "Failed to store route proxy password: {}",
+                e
+            )));
+        }
+    }
+
+    let result = state
+        .with_database(|db| {
+            match &request.routes {
+                Some(routes) => {
+                    let json = serde_json::to_string(routes).map_err(|e| {
+                        crate::utils::error::AppError::Internal(format!(
+                            "Failed to serialize proxy routes: {}",
+                            e
+                        ))
+                    })?;
+                    db.set_setting(ROUTES_KEY, &json)?;
+                }
+                None => {
+                    db.delete_setting(ROUTES_KEY)?;
+                }
+            }
+            Ok(true)
+        })
+        .await;
+
+    match result {
+        Ok(v) => Ok(CommandResponse::ok(v)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Test proxy connectivity, reporting which step fails (DNS/TCP connect,
+/// proxy authentication, TLS, or the end-to-end fetch).
 #[tauri::command]
 pub async fn test_proxy(
     request: TestProxyRequest,
 ) -> Result<CommandResponse<ProxyTestResult>, String> {
     let mut proxy = request.proxy;
     proxy.password = request.password;
+    if proxy.password.is_none() && proxy.username.is_some() {
+        if let Some(id) = &request.keyring_id {
+            proxy.password = KeyringService::new()
+                .get_api_key(&format!("{}{}", KEYRING_PREFIX, id))
+                .ok()
+                .flatten();
+        }
+    }
 
     let test_url = request
         .test_url
         .unwrap_or_else(|| "https://httpbin.org/get".to_string());
 
-    let client = crate::services::proxy::build_http_client(Some(&proxy));
-
-    let start = std::time::Instant::now();
-    match client.get(&test_url).send().await {
-        Ok(response) => {
-            let latency = start.elapsed().as_millis() as u32;
-            if response.status().is_success() || response.status().is_redirection() {
-                Ok(CommandResponse::ok(ProxyTestResult {
-                    success: true,
-                    latency_ms: Some(latency),
-                    error: None,
-                }))
-            } else {
-                Ok(CommandResponse::ok(ProxyTestResult {
-                    success: false,
-                    latency_ms: Some(latency),
-                    error: Some(format!("HTTP {}", response.status())),
-                }))
-            }
-        }
-        Err(e) => Ok(CommandResponse::ok(ProxyTestResult {
-            success: false,
-            latency_ms: None,
-            error: Some(e.to_string()),
-        })),
-    }
+    let diagnostics = diagnose_proxy(&proxy, &test_url, PROXY_TEST_TIMEOUT).await;
+    let error = diagnostics.first_failure().map(|step| step.detail.clone());
+
+    Ok(CommandResponse::ok(ProxyTestResult {
+        success: diagnostics.success,
+        latency_ms: diagnostics.fetch.as_ref().map(|step| step.latency_ms),
+        error,
+        diagnostics: Some(diagnostics),
+    }))
 }
 
 // ---------------------------------------------------------------------------
 // Proxy Resolution
 // ---------------------------------------------------------------------------
 
+/// Resolve how an LLM provider's HTTP client should route requests.
+///
+/// Used by command handlers to inject `proxy_routing` into `ProviderConfig`
+/// before provider construction. Per-host rules and PAC scripts are
+/// evaluated per request against the real destination, so custom base URLs
+/// are routed correctly. `System` follows the OS proxy settings.
+pub fn resolve_provider_proxy_routing(
+    keyring: &KeyringService,
+    db: &crate::storage::Database,
+    provider: &str,
+) -> ProxyRouting {
+    match load_strategy(db, provider) {
+        ProxyStrategy::PerHost => match load_proxy_routes(keyring, db) {
+            Some(routes) => ProxyRouting::PerHost(Arc::new(routes)),
+            None => ProxyRouting::Fixed(None),
+        },
+        ProxyStrategy::Pac(pac_url) => match pac_resolver_for(db, provider, &pac_url) {
+            Some(resolver) => ProxyRouting::Pac(resolver),
+            None => ProxyRouting::Fixed(None),
+        },
+        ProxyStrategy::System => ProxyRouting::System,
+        _ => ProxyRouting::Fixed(resolve_provider_proxy(keyring, db, provider)),
+    }
+}
+
 /// Resolve the effective proxy configuration for a given provider.
 ///
-/// Used by command handlers to inject proxy into `ProviderConfig` or
-/// `EmbeddingProviderConfig` before provider construction.
+/// Used by command handlers to inject proxy into `EmbeddingProviderConfig`
+/// and other clients that take a single proxy. Per-host rules and PAC
+/// scripts are evaluated against the provider's default host, and `System`
+/// reads the proxy environment variables only.
 ///
 /// Returns `Some(ProxyConfig)` if the provider should use a proxy,
 /// or `None` for direct connection.
@@ -358,16 +488,7 @@ pub fn resolve_provider_proxy(
     db: &crate::storage::Database,
     provider: &str,
 ) -> Option<ProxyConfig> {
-    // 1. Read strategy
-    let strategy_key = format!("{}{}", STRATEGY_KEY_PREFIX, provider);
-    let strategy: ProxyStrategy = db
-        .get_setting(&strategy_key)
-        .ok()
-        .flatten()
-        .and_then(|json| serde_json::from_str(&json).ok())
-        .unwrap_or_else(|| default_strategy_for(provider));
-
-    match strategy {
+    match load_strategy(db, provider) {
         ProxyStrategy::NoProxy => None,
         ProxyStrategy::UseGlobal => {
             // Read global proxy config
@@ -404,13 +525,127 @@ pub fn resolve_provider_proxy(
             }
             Some(proxy)
         }
+        ProxyStrategy::PerHost => {
+            let routes = load_proxy_routes(keyring, db)?;
+            routes.proxy_for(default_host_for(provider)).cloned()
+        }
+        ProxyStrategy::System => system_proxy_for(default_host_for(provider)),
+        ProxyStrategy::Pac(pac_url) => {
+            let resolver = pac_resolver_for(db, provider, &pac_url)?;
+            let url = url::Url::parse(&format!("https://{}/", default_host_for(provider))).ok()?;
+            resolver.resolve(&url).proxy().cloned()
+        }
+    }
+}
+
+/// Load the per-host routing table with proxy passwords hydrated.
+///
+/// A route targeting the `global` proxy id resolves to the global proxy
+/// unless the table defines its own proxy under that id.
+pub fn load_proxy_routes(
+    keyring: &KeyringService,
+    db: &crate::storage::Database,
+) -> Option<ProxyRoutingTable> {
+    let mut routes: ProxyRoutingTable = db
+        .get_setting(ROUTES_KEY)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())?;
+
+    for (id, proxy) in routes.proxies.iter_mut() {
+        if proxy.username.is_some() {
+            proxy.password = keyring.get_api_key(&route_keyring_key(id)).ok().flatten();
+        }
+    }
+
+    if !routes.proxies.contains_key(GLOBAL_ROUTE_PROXY_ID) {
+        let global: Option<ProxyConfig> = db
+            .get_setting(GLOBAL_PROXY_KEY)
+            .ok()
+            .flatten()
+            .and_then(|json| serde_json::from_str(&json).ok());
+        if let Some(mut global) = global {
+            if global.username.is_some() {
+                global.password = keyring
+                    .get_api_key(&format!("{}global", KEYRING_PREFIX))
+                    .ok()
+                    .flatten();
+            }
+            routes
+                .proxies
+                .insert(GLOBAL_ROUTE_PROXY_ID.to_string(), global);
+        }
     }
+
+    Some(routes)
 }
 
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Read a provider's proxy strategy, falling back to its smart default.
+fn load_strategy(db: &crate::storage::Database, provider: &str) -> ProxyStrategy {
+    let strategy_key = format!("{}{}", STRATEGY_KEY_PREFIX, provider);
+    db.get_setting(&strategy_key)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_else(|| default_strategy_for(provider))
+}
+
+fn pac_resolvers() -> &'static Mutex<HashMap<String, Arc<PacResolver>>> {
+    PAC_RESOLVERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Return the cached resolver for a PAC URL, rebuilding it from the script
+/// stored for `provider` after a restart.
+fn pac_resolver_for(
+    db: &crate::storage::Database,
+    provider: &str,
+    pac_url: &str,
+) -> Option<Arc<PacResolver>> {
+    if let Some(resolver) = pac_resolvers().lock().unwrap().get(pac_url) {
+        return Some(resolver.clone());
+    }
+    let script = db
+        .get_setting(&format!("{}{}", PAC_SCRIPT_KEY_PREFIX, provider))
+        .ok()
+        .flatten()?;
+    let resolver = Arc::new(PacResolver::new(script));
+    pac_resolvers()
+        .lock()
+        .unwrap()
+        .insert(pac_url.to_string(), resolver.clone());
+    Some(resolver)
+}
+
+/// Keyring key for the password of a named route proxy.
+fn route_keyring_key(id: &str) -> String {
+    format!("{}route_{}", KEYRING_PREFIX, id)
+}
+
+/// Return the default API host a provider connects to, used to evaluate
+/// per-host routing rules for provider clients.
+fn default_host_for(provider: &str) -> &'static str {
+    match provider {
+        "anthropic" | "claude_code" => "api.anthropic.com",
+        "openai" | "embedding_openai" => "api.openai.com",
+        "deepseek" => "api.deepseek.com",
+        "qwen" | "embedding_qwen" => "dashscope.aliyuncs.com",
+        "glm" | "embedding_glm" => "open.bigmodel.cn",
+        "minimax" => "api.minimax.io",
+        "ollama" | "embedding_ollama" => "localhost",
+        "webhook_slack" => "hooks.slack.com",
+        "webhook_feishu" => "open.feishu.cn",
+        "webhook_telegram" | "remote_telegram" => "api.telegram.org",
+        "webhook_serverchan" => "sctapi.ftqq.com",
+        "webhook_discord" => "discord.com",
+        // Unknown destinations fall through to the table's default rule.
+        _ => "",
+    }
+}
+
 /// Return the smart default proxy strategy for a given provider.
 ///
 /// - International APIs (Anthropic, OpenAI): use_global
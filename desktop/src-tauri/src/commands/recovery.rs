@@ -7,7 +7,7 @@ use tauri::{AppHandle, Emitter, State};
 
 use crate::models::CommandResponse;
 use crate::services::recovery::detector::{IncompleteTask, RecoveryDetector};
-use crate::services::recovery::resume::{ResumeEngine, ResumeResult};
+use crate::services::recovery::resume::{ResumeEngine, ResumePreview, ResumeResult};
 use crate::state::AppState;
 
 /// Detect all incomplete (interrupted) executions in the database.
@@ -76,6 +76,39 @@ pub async fn resume_task(
     }
 }
 
+/// Preview what resuming an interrupted execution would restore.
+///
+/// Read-only: reports the resume point, pending stories, the context that
+/// will be reloaded and files changed externally since the interruption so
+/// the frontend can ask for confirmation before calling `resume_task`.
+///
+/// # Arguments
+/// * `task_id` - The execution ID to preview
+///
+/// # Returns
+/// `CommandResponse<ResumePreview>` describing the pending resume.
+#[tauri::command]
+pub async fn preview_resume_task(
+    state: State<'_, AppState>,
+    task_id: String,
+) -> Result<CommandResponse<ResumePreview>, String> {
+    if task_id.trim().is_empty() {
+        return Ok(CommandResponse::err("Task ID cannot be empty"));
+    }
+
+    let result = state
+        .with_database(|db| ResumeEngine::preview_resume(db, &task_id))
+        .await;
+
+    match result {
+        Ok(preview) => Ok(CommandResponse::ok(preview)),
+        Err(e) => Ok(CommandResponse::err(format!(
+            "Failed to preview resume for task '{}': {}",
+            task_id, e
+        ))),
+    }
+}
+
 /// Discard an interrupted execution, marking it as cancelled.
 ///
 /// # Arguments
@@ -137,6 +170,7 @@ mod tests {
             session_id: Some("sess-001".to_string()),
             name: "Test task".to_string(),
             execution_mode: crate::services::recovery::detector::ExecutionMode::HybridAuto,
+            execution_kind: crate::services::recovery::detector::ExecutionKind::Standalone,
             status: "running".to_string(),
             project_path: "/project".to_string(),
             total_stories: 5,
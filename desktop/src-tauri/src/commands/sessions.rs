@@ -2,8 +2,9 @@
 //!
 //! Tauri commands for session management.
 
+use crate::commands::file_changes::FileChangesState;
 use crate::models::response::CommandResponse;
-use crate::models::session::{ResumeResult, Session, SessionDetails};
+use crate::models::session::{ForkResult, ResumeResult, Session, SessionDetails};
 use crate::services::session::SessionService;
 
 /// List all sessions for a project
@@ -39,6 +40,42 @@ pub fn resume_session(session_path: String) -> Result<CommandResponse<ResumeResu
     }
 }
 
+/// Fork a session at a conversation turn into a new session. The timeline
+/// checkpoints and, when `project_root` is given, the file-change records of
+/// the first `at_turn` turns are forked with it.
+#[tauri::command]
+pub async fn fork_session(
+    session_path: String,
+    at_turn: u32,
+    project_root: Option<String>,
+    file_changes: tauri::State<'_, FileChangesState>,
+) -> Result<CommandResponse<ForkResult>, String> {
+    let service = SessionService::new();
+
+    let mut result = match service.fork_session(&session_path, at_turn) {
+        Ok(result) => result,
+        Err(e) => return Ok(CommandResponse::err(e.to_string())),
+    };
+    if let Some(project_root) = project_root.filter(|root| !root.trim().is_empty()) {
+        match file_changes
+            .fork(
+                &result.source_session_id,
+                &result.session_id,
+                &project_root,
+                at_turn,
+            )
+            .await
+        {
+            Ok(count) => result.file_change_count = count,
+            Err(e) => {
+                let _ = std::fs::remove_file(&result.session_path);
+                return Ok(CommandResponse::err(e));
+            }
+        }
+    }
+    Ok(CommandResponse::ok(result))
+}
+
 /// Search sessions within a project
 #[tauri::command]
 pub fn search_sessions(
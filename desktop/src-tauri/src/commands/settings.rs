@@ -10,10 +10,14 @@ use tauri::{AppHandle, State};
 
 use crate::app_shell::{apply_runtime_preferences, AppShellState};
 use crate::commands::webhook::WebhookState;
-use crate::models::export::{SettingsImportResult, UnifiedSettingsExport};
+use crate::models::export::{
+    PortableSettingsBundle, SettingsImportMode, SettingsImportResult, SettingsScope,
+    UnifiedSettingsExport,
+};
 use crate::models::response::CommandResponse;
 use crate::models::settings::{AppConfig, SettingsUpdate};
 use crate::services::settings_export;
+use crate::services::tools::impls::bash::{parse_bash_env_allowlist, BASH_ENV_ALLOWLIST_SETTING};
 use crate::state::AppState;
 
 const KB_QUERY_RUNS_V2_FLAG: &str = "kb_query_runs_v2";
@@ -186,6 +190,67 @@ pub async fn set_knowledge_feature_flags(
     }
 }
 
+/// Get the parent environment variable names Bash commands may inherit.
+///
+/// Returns the default allowlist until the user saves one.
+#[tauri::command]
+pub async fn get_bash_env_allowlist(
+    state: State<'_, AppState>,
+) -> Result<CommandResponse<Vec<String>>, String> {
+    match state
+        .with_database(|db| db.get_setting(BASH_ENV_ALLOWLIST_SETTING))
+        .await
+    {
+        Ok(value) => Ok(CommandResponse::ok(parse_bash_env_allowlist(
+            value.as_deref(),
+        ))),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Save the Bash environment allowlist. `None` restores the default.
+///
+/// Applies to orchestrators created after the change.
+#[tauri::command]
+pub async fn set_bash_env_allowlist(
+    state: State<'_, AppState>,
+    names: Option<Vec<String>>,
+) -> Result<CommandResponse<Vec<String>>, String> {
+    let names = names.map(|names| {
+        names
+            .iter()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .collect::<Vec<_>>()
+    });
+    if let Some(invalid) = names
+        .iter()
+        .flatten()
+        .find(|name| name.contains('=') || name.contains('\0'))
+    {
+        return Ok(CommandResponse::err(format!(
+            "Invalid environment variable name: '{}'",
+            invalid
+        )));
+    }
+    match state
+        .with_database(move |db| match names {
+            Some(names) => {
+                db.set_setting(BASH_ENV_ALLOWLIST_SETTING, &serde_json::to_string(&names)?)?;
+                Ok(names)
+            }
+            None => {
+                db.delete_setting(BASH_ENV_ALLOWLIST_SETTING)?;
+                Ok(parse_bash_env_allowlist(None))
+            }
+        })
+        .await
+    {
+        Ok(names) => Ok(CommandResponse::ok(names)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
 /// Reset all settings to defaults (frontend callers should also reset local state).
 #[tauri::command]
 pub async fn reset_all_settings(
@@ -545,6 +610,125 @@ pub async fn import_all_settings(
     Ok(CommandResponse::ok(result))
 }
 
+/// Export selected settings scopes as a portable bundle.
+///
+/// Secrets are redacted unless a password is given to encrypt them.
+#[tauri::command]
+pub async fn export_settings(
+    state: State<'_, AppState>,
+    scopes: Option<Vec<SettingsScope>>,
+    include_secrets: Option<bool>,
+    password: Option<String>,
+) -> Result<CommandResponse<PortableSettingsBundle>, String> {
+    let config = match state.get_config().await {
+        Ok(c) => c,
+        Err(e) => return Ok(CommandResponse::err(e.to_string())),
+    };
+
+    let password = if include_secrets.unwrap_or(false) {
+        match password.filter(|pw| !pw.is_empty()) {
+            Some(pw) => Some(pw),
+            None => {
+                return Ok(CommandResponse::err(
+                    "A password is required to include secrets in the export",
+                ))
+            }
+        }
+    } else {
+        None
+    };
+
+    let secrets = match state.export_all_secrets().await {
+        Ok(secrets) => secrets,
+        Err(e) => {
+            tracing::warn!("Failed to read secrets for settings export: {}", e);
+            HashMap::new()
+        }
+    };
+
+    let scopes = scopes.unwrap_or_default();
+    match state
+        .with_database(|db| {
+            settings_export::export_settings(db, &config, &scopes, &secrets, password.as_deref())
+        })
+        .await
+    {
+        Ok(bundle) => Ok(CommandResponse::ok(bundle)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Import a portable settings bundle, merging with or replacing existing settings.
+#[tauri::command]
+pub async fn import_settings(
+    app: AppHandle,
+    state: State<'_, AppState>,
+    shell_state: State<'_, AppShellState>,
+    bundle_json: String,
+    mode: Option<SettingsImportMode>,
+    password: Option<String>,
+) -> Result<CommandResponse<SettingsImportResult>, String> {
+    let bundle = match settings_export::parse_settings_bundle(&bundle_json) {
+        Ok(b) => b,
+        Err(e) => return Ok(CommandResponse::err(e.to_string())),
+    };
+    let config = match state.get_config().await {
+        Ok(c) => c,
+        Err(e) => return Ok(CommandResponse::err(e.to_string())),
+    };
+
+    let import = match state
+        .with_database(|db| {
+            settings_export::import_settings(
+                db,
+                &config,
+                &bundle,
+                mode.unwrap_or_default(),
+                password.as_deref(),
+            )
+        })
+        .await
+    {
+        Ok(i) => i,
+        Err(e) => return Ok(CommandResponse::err(e.to_string())),
+    };
+    let mut result = import.result.clone();
+
+    match state.export_all_secrets().await {
+        Ok(existing) => {
+            if let Some(secrets) = import.merged_secrets(&existing) {
+                if let Err(e) = state.import_all_secrets(&secrets).await {
+                    result.errors.push(format!("secrets: {}", e));
+                }
+            }
+        }
+        Err(e) if !import.secrets.is_empty() => {
+            result.errors.push(format!("secrets: {}", e));
+        }
+        Err(_) => {}
+    }
+
+    if let Some(update) = import.config_update {
+        match state.update_config(update).await {
+            Ok(config) => {
+                if let Err(error) = state
+                    .with_database(|db| sync_provider_base_urls(db, &config))
+                    .await
+                {
+                    tracing::warn!("Failed to sync provider base URLs from settings: {}", error);
+                }
+                if let Err(error) = apply_runtime_preferences(&app, shell_state.inner(), &config) {
+                    tracing::warn!("Failed to apply runtime shell preferences: {}", error);
+                }
+            }
+            Err(e) => result.errors.push(format!("providers: {}", e)),
+        }
+    }
+
+    result.success = result.errors.is_empty();
+    Ok(CommandResponse::ok(result))
+}
+
 /// Import database-backed sections (everything except config which needs ConfigService).
 fn import_db_sections(
     db: &crate::storage::Database,
@@ -15,11 +15,11 @@ use crate::services::skills::config::{
 };
 use crate::services::skills::discovery::discover_all_skills;
 use crate::services::skills::generator::SkillGeneratorStore;
-use crate::services::skills::index::{build_index, compute_index_stats};
+use crate::services::skills::index::{compute_index_stats, refresh_index};
 use crate::services::skills::model::{
-    GeneratedSkill, GeneratedSkillRecord, InjectionPhase, MatchReason, SelectionPolicy,
-    SkillDocument, SkillIndex, SkillIndexStats, SkillMatch, SkillReviewStatus, SkillSource,
-    SkillSummary, SkillToolPolicyMode, SkillsOverview,
+    CachedSkillIndex, GeneratedSkill, GeneratedSkillRecord, InjectionPhase, MatchReason,
+    SelectionPolicy, SkillDocument, SkillIndex, SkillIndexStats, SkillMatch, SkillReviewStatus,
+    SkillSource, SkillSummary, SkillToolPolicyMode, SkillsOverview,
 };
 use crate::services::skills::select::{lexical_score_skills, select_skills_for_session};
 use crate::services::task_mode::context_provider::{resolve_effective_skills, SkillSelectionMode};
@@ -398,6 +398,9 @@ pub async fn toggle_generated_skill(
 }
 
 /// Refresh the skill index (re-scan all sources).
+///
+/// Only new or edited skill files are re-parsed; unchanged files keep their
+/// cached entry and deleted files are dropped.
 #[tauri::command]
 pub async fn refresh_skill_index(
     project_path: String,
@@ -450,10 +453,18 @@ pub async fn get_skills_overview(
         .into_iter()
         .collect();
 
+    let cached = state
+        .skill_index_cache()
+        .lock()
+        .ok()
+        .and_then(|guard| guard.get(&project_path).cloned());
+
     Ok(CommandResponse::ok(SkillsOverview {
         stats,
         detected_skills,
         sources,
+        last_refreshed_at: cached.as_ref().map(|c| c.refreshed_at.clone()),
+        last_refresh: cached.map(|c| c.refresh),
     }))
 }
 
@@ -1305,7 +1316,8 @@ fn source_info_from_definition(
 }
 
 /// Build a SkillIndex for a project by scanning all sources.
-/// Applies persisted disabled state from the settings table.
+/// Reuses cached entries for unchanged files (see `refresh_index`) and
+/// applies persisted disabled state from the settings table.
 async fn build_skill_index_for_project(
     project_path: &str,
     state: &State<'_, AppState>,
@@ -1321,8 +1333,31 @@ async fn build_skill_index_for_project(
     let discovered = discover_all_skills(project_root, &config, plan_cascade_dir.as_deref())
         .map_err(|e| format!("Discovery failed: {}", e))?;
 
-    // Build the index
-    let index = build_index(discovered).map_err(|e| format!("Index build failed: {}", e))?;
+    // Refresh the index against the cached one for this project
+    let previous = state
+        .skill_index_cache()
+        .lock()
+        .ok()
+        .and_then(|guard| guard.get(project_path).map(|c| c.index.clone()));
+    let (index, refresh) = refresh_index(previous.as_ref(), discovered)
+        .map_err(|e| format!("Index build failed: {}", e))?;
+    tracing::debug!(
+        "Skill index refreshed: project={}, reparsed={}, reused={}, removed={}",
+        project_path,
+        refresh.reparsed,
+        refresh.reused,
+        refresh.removed
+    );
+    if let Ok(mut guard) = state.skill_index_cache().lock() {
+        guard.insert(
+            project_path.to_string(),
+            CachedSkillIndex {
+                index: index.clone(),
+                refreshed_at: chrono::Utc::now().to_rfc3339(),
+                refresh,
+            },
+        );
+    }
 
     // Apply persisted disabled state from the database
     let disabled_ids: std::collections::HashSet<String> = state
@@ -1419,6 +1454,8 @@ fn generated_record_to_document(record: GeneratedSkillRecord) -> SkillDocument {
         license: None,
         metadata,
         hooks: None,
+        requires: vec![],
+        conflicts_with: vec![],
         source: SkillSource::Generated,
         priority: 0,
         detect: None,
@@ -1454,6 +1491,7 @@ fn apply_summary_filters(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::skills::index::build_index;
     use std::fs;
     use tempfile::TempDir;
 
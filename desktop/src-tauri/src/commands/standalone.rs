@@ -7,26 +7,37 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Emitter, State};
+use tauri::{AppHandle, Emitter, Manager, State};
 use tokio::sync::{mpsc, RwLock};
+use tokio_util::sync::CancellationToken;
 
-use crate::commands::proxy::resolve_provider_proxy;
+use crate::commands::artifacts::{
+    ensure_initialized as ensure_artifacts_initialized, ArtifactState,
+};
+use crate::commands::proxy::resolve_provider_proxy_routing;
 use crate::commands::webhook::WebhookState;
 use crate::commands::workflow::{
     emit_kernel_update_for_session, emit_session_catalog_update, emit_workflow_session_mutation,
 };
 use crate::models::orchestrator::{
     ExecuteWithSessionRequest, ExecutionProgress, ExecutionSession, ExecutionSessionSummary,
-    ExecutionStatus, ResumeExecutionRequest, StandaloneStatus,
+    ExecutionStatus, ResumeExecutionRequest, StandaloneBatchConfig, StandaloneStatus,
 };
 use crate::models::CommandResponse;
-use crate::services::llm::{LlmProvider, ProviderConfig, ProviderType};
+use crate::services::artifacts::{ArtifactScope, ArtifactService};
+use crate::services::document_export::{render_document, DocumentExportOptions, DocumentFormat};
+use crate::services::health_cache::health_cache_key;
+use crate::services::llm::ollama::{ModelPullProgress, OllamaProvider};
+use crate::services::llm::{LlmError, LlmProvider, ProviderConfig, ProviderType};
+use crate::services::orchestrator::batch::{
+    run_batch, BatchExecutionResult, BatchItem, BatchOptions, DEFAULT_BATCH_CONCURRENCY,
+};
 use crate::services::orchestrator::index_manager::{IndexManager, IndexStatusEvent};
 use crate::services::orchestrator::{
     ExecutionKind, ExecutionResult, OrchestratorConfig, OrchestratorService, SessionExecutionResult,
 };
 use crate::services::plugins::models::{PluginInvocation, ResolvedPluginInvocation};
-use crate::services::streaming::UnifiedStreamEvent;
+use crate::services::streaming::{StreamSequencer, UnifiedStreamEvent};
 use crate::services::webhook::integration::dispatch_on_event as dispatch_webhook_on_event;
 use crate::services::workflow_kernel::{ChatRuntimeDispatch, WorkflowKernelState};
 use crate::state::AppState;
@@ -41,6 +52,8 @@ pub struct StandaloneState {
     pub working_directory: Arc<RwLock<PathBuf>>,
     /// Index manager for background codebase indexing
     pub index_manager: Arc<RwLock<Option<IndexManager>>>,
+    /// Cancellation tokens for running batches by batch ID
+    pub batches: Arc<RwLock<HashMap<String, CancellationToken>>>,
 }
 
 impl Default for StandaloneState {
@@ -58,6 +71,7 @@ impl StandaloneState {
             // inputs instead of process cwd.
             working_directory: Arc::new(RwLock::new(PathBuf::from("."))),
             index_manager: Arc::new(RwLock::new(None)),
+            batches: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -222,6 +236,7 @@ fn build_memory_hook_config(
     app: &AppHandle,
     root_session_id: Option<&str>,
     auto_extract_enabled: bool,
+    session_summary_enabled: bool,
     review_mode: Option<String>,
     review_agent_ref: Option<String>,
     extraction_provider_config: Option<ProviderConfig>,
@@ -247,6 +262,8 @@ fn build_memory_hook_config(
         selected_categories,
         selected_memory_ids: memory.selected_memory_ids.clone(),
         excluded_memory_ids: memory.excluded_memory_ids.clone(),
+        session_summary: (memory.enabled && session_summary_enabled)
+            .then(crate::services::orchestrator::hooks::SessionSummaryMemoryConfig::default),
     })
 }
 
@@ -305,6 +322,118 @@ async fn wire_skill_hooks_if_enabled(
     )
 }
 
+/// Build the root orchestrator for a standalone session with the standard
+/// wiring: database, guardrails, permission gate, file-change tracking (for
+/// undo), semantic search, analytics and cost tracking, and memory, skill
+/// and plugin hooks.
+async fn build_session_orchestrator(
+    app: &AppHandle,
+    orchestrator_config: OrchestratorConfig,
+    pool: crate::storage::database::DbPool,
+    session_id: &str,
+    project_path: &str,
+    call_site: &str,
+) -> OrchestratorService {
+    let app_state = app.state::<AppState>();
+    let standalone_state = app.state::<StandaloneState>();
+    let file_changes_state = app.state::<super::file_changes::FileChangesState>();
+    let analytics_state = app.state::<super::analytics::AnalyticsState>();
+    let permission_state = app.state::<super::permissions::PermissionState>();
+    let plugin_state = app.state::<super::plugins::PluginState>();
+
+    // Clone provider config before orchestrator_config is moved
+    let provider_config_for_index = orchestrator_config.provider.clone();
+
+    // Create orchestrator with database (IndexStore is auto-wired to ToolExecutor)
+    let mut orchestrator = OrchestratorService::new(orchestrator_config)
+        .with_database(pool)
+        .with_guardrail_hooks(crate::services::guardrail::shared_guardrail_registry())
+        .with_permission_gate(permission_state.gate.clone())
+        .with_file_change_source_mode(
+            crate::services::file_change_tracker::FileChangeSourceMode::Chat,
+        )
+        .with_file_change_actor_metadata(
+            crate::services::file_change_tracker::FileChangeActorKind::RootAgent,
+            Some("chat-root".to_string()),
+            Some("Main Agent".to_string()),
+            None,
+            None,
+        );
+
+    // Wire file change tracker for AI file modification tracking
+    {
+        let tracker = file_changes_state
+            .get_or_create(session_id, project_path)
+            .await;
+        if let Ok(mut t) = tracker.lock() {
+            let next = t.turn_index() + 1;
+            t.set_turn_index(next);
+            t.set_app_handle(app.clone());
+            orchestrator = orchestrator.with_file_change_turn_index(next);
+        }
+        orchestrator = orchestrator.with_file_change_tracker(tracker);
+    }
+
+    // Wire embedding service and EmbeddingManager from IndexManager for semantic CodebaseSearch
+    if let Some(ref manager) = *standalone_state.index_manager.read().await {
+        if let Some(emb_svc) = manager.get_embedding_service(project_path).await {
+            orchestrator = orchestrator.with_embedding_service(emb_svc);
+        }
+        if let Some(emb_mgr) = manager.get_embedding_manager(project_path).await {
+            orchestrator = orchestrator.with_embedding_manager(emb_mgr);
+        }
+        // Set LLM provider on IndexManager for component classification.
+        if let Some(llm) = build_llm_provider_from_config(&provider_config_for_index) {
+            manager.set_llm_provider(llm).await;
+        }
+    }
+
+    // Wire analytics tracking for persistent usage recording
+    {
+        let _ = analytics_state.initialize(app_state.inner()).await;
+        if let Some(atx) = analytics_state.get_tracker_sender().await {
+            orchestrator = orchestrator
+                .with_analytics_tracker(atx)
+                .with_analytics_cost_calculator(analytics_state.cost_calculator())
+                .with_analytics_attribution(crate::models::analytics::AnalyticsAttribution {
+                    project_id: None,
+                    kernel_session_id: None,
+                    mode_session_id: Some(session_id.to_string()),
+                    workflow_mode: Some(crate::models::analytics::AnalyticsWorkflowMode::Chat),
+                    phase_id: Some("chat_turn".to_string()),
+                    execution_scope: Some(
+                        crate::models::analytics::AnalyticsExecutionScope::RootAgent,
+                    ),
+                    execution_id: Some(format!("chat:{}:root", session_id)),
+                    parent_execution_id: None,
+                    agent_role: Some("chat_root".to_string()),
+                    agent_name: None,
+                    step_id: None,
+                    story_id: None,
+                    gate_id: None,
+                    attempt: Some(1),
+                    request_sequence: Some(1),
+                    call_site: Some(call_site.to_string()),
+                    metadata_json: None,
+                });
+        }
+    }
+
+    // Wire memory hooks for automatic memory loading and extraction
+    if let Ok(memory_store) = app_state.get_memory_store_arc().await {
+        let loaded_memories = std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new()));
+        orchestrator = orchestrator.with_memory_hooks(memory_store, loaded_memories);
+    }
+
+    orchestrator =
+        wire_skill_hooks_if_enabled(orchestrator, app_state.inner(), project_path, None).await;
+
+    // Wire plugin context (instructions, skills, commands, hooks, permissions) from enabled plugins
+    orchestrator = plugin_state.wire_orchestrator(orchestrator, None).await;
+
+    orchestrator
+}
+
 async fn build_explicit_memory_command_context(
     app_state: &AppState,
     project_path: &str,
@@ -339,6 +468,7 @@ fn normalize_search_provider_name(provider: &str) -> &'static str {
         "tavily" => "tavily",
         "brave" | "brave_search" => "brave",
         "searxng" => "searxng",
+        "kagi" => "kagi",
         "duckduckgo" | "" => "duckduckgo",
         _ => "duckduckgo",
     }
@@ -349,6 +479,7 @@ fn search_provider_key_candidates(provider: &str) -> &'static [&'static str] {
         "tavily" => &["tavily"],
         "brave" => &["brave", "brave_search"],
         "searxng" => &["searxng"],
+        "kagi" => &["kagi"],
         _ => &[],
     }
 }
@@ -817,11 +948,15 @@ pub async fn configure_provider(
 }
 
 /// Check provider health (validate API key and connectivity)
+///
+/// Network results are cached briefly (see `services::health_cache`); pass
+/// `force_refresh` to bypass the cache.
 #[tauri::command]
 pub async fn check_provider_health(
     provider: String,
     model: String,
     base_url: Option<String>,
+    force_refresh: Option<bool>,
     app_state: State<'_, AppState>,
 ) -> Result<CommandResponse<HealthCheckResult>, String> {
     let keyring = KeyringService::new();
@@ -843,6 +978,7 @@ pub async fn check_provider_health(
                 healthy: false,
                 error: Some(format!("Failed to get API key: {}", e)),
                 latency_ms: None,
+                ..Default::default()
             }));
         }
     };
@@ -862,21 +998,37 @@ pub async fn check_provider_health(
             healthy: false,
             error: Some("API key not configured".to_string()),
             latency_ms: None,
+            ..Default::default()
         }));
     }
 
     // Resolve proxy for this provider
-    let proxy = app_state
-        .with_database(|db| Ok(resolve_provider_proxy(&keyring, db, &canonical_provider)))
+    let proxy_routing = app_state
+        .with_database(|db| {
+            Ok(resolve_provider_proxy_routing(
+                &keyring,
+                db,
+                &canonical_provider,
+            ))
+        })
         .await
-        .unwrap_or(None);
+        .ok();
+
+    let cache_key = health_cache_key(
+        &[
+            canonical_provider,
+            &model,
+            base_url.as_deref().unwrap_or(""),
+        ],
+        api_key.as_deref(),
+    );
 
     let config = ProviderConfig {
         provider: provider_type,
         api_key,
         base_url,
         model,
-        proxy,
+        proxy_routing,
         ..Default::default()
     };
 
@@ -899,29 +1051,144 @@ pub async fn check_provider_health(
         sub_agent_depth: None,
     };
 
+    let cached = app_state
+        .provider_health_cache()
+        .get_or_check(&cache_key, force_refresh.unwrap_or(false), move || {
+            run_provider_health_check(orchestrator_config)
+        })
+        .await;
+    let mut result = cached.value;
+    result.checked_at = Some(cached.checked_at.to_rfc3339());
+    result.cached = cached.cached;
+    result.stale = cached.stale;
+    Ok(CommandResponse::ok(result))
+}
+
+/// Run a provider health check over the network.
+async fn run_provider_health_check(orchestrator_config: OrchestratorConfig) -> HealthCheckResult {
     let orchestrator = OrchestratorService::new(orchestrator_config);
 
     let start = std::time::Instant::now();
     match orchestrator.health_check().await {
-        Ok(_) => Ok(CommandResponse::ok(HealthCheckResult {
+        Ok(_) => HealthCheckResult {
             healthy: true,
             error: None,
             latency_ms: Some(start.elapsed().as_millis() as u32),
-        })),
-        Err(e) => Ok(CommandResponse::ok(HealthCheckResult {
-            healthy: false,
-            error: Some(e.to_string()),
-            latency_ms: Some(start.elapsed().as_millis() as u32),
-        })),
+            ..Default::default()
+        },
+        Err(e) => {
+            let latency_ms = Some(start.elapsed().as_millis() as u32);
+            let error = Some(e.to_string());
+            match e {
+                LlmError::ModelNotFound { model, available } => HealthCheckResult {
+                    healthy: false,
+                    error,
+                    latency_ms,
+                    missing_model: Some(model),
+                    available_models: Some(available),
+                    ..Default::default()
+                },
+                _ => HealthCheckResult {
+                    healthy: false,
+                    error,
+                    latency_ms,
+                    ..Default::default()
+                },
+            }
+        }
     }
 }
 
 /// Health check result
-#[derive(serde::Serialize)]
+#[derive(Clone, Default, serde::Serialize)]
 pub struct HealthCheckResult {
     pub healthy: bool,
     pub error: Option<String>,
     pub latency_ms: Option<u32>,
+    /// When the check ran (RFC 3339); cached results keep their original time
+    pub checked_at: Option<String>,
+    /// Served from the health cache instead of a fresh network check
+    pub cached: bool,
+    /// Cached result past its TTL; a background refresh is in progress
+    pub stale: bool,
+    /// Configured model the provider does not have (e.g. an Ollama model that
+    /// has not been pulled); the UI can offer to pull it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub missing_model: Option<String>,
+    /// Models the provider does have, reported alongside `missing_model`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_models: Option<Vec<String>>,
+}
+
+/// Event channel for Ollama model pull progress
+pub const OLLAMA_PULL_PROGRESS_CHANNEL: &str = "ollama-pull-progress";
+
+/// Progress event emitted while pulling an Ollama model
+#[derive(Clone, serde::Serialize)]
+pub struct OllamaPullProgressEvent {
+    pub model: String,
+    #[serde(flatten)]
+    pub progress: ModelPullProgress,
+}
+
+/// Pull a model into the local Ollama server, emitting progress on
+/// `ollama-pull-progress`. Returns the model name once it is available.
+#[tauri::command]
+pub async fn pull_ollama_model(
+    app: AppHandle,
+    model: String,
+    base_url: Option<String>,
+    app_state: State<'_, AppState>,
+) -> Result<CommandResponse<String>, String> {
+    let model = model.trim().to_string();
+    if model.is_empty() {
+        return Ok(CommandResponse::err("Model name is required"));
+    }
+
+    let cache_key = health_cache_key(&["ollama", &model, base_url.as_deref().unwrap_or("")], None);
+    let keyring = KeyringService::new();
+    let proxy_routing = app_state
+        .with_database(|db| Ok(resolve_provider_proxy_routing(&keyring, db, "ollama")))
+        .await
+        .ok();
+    let provider = OllamaProvider::new(ProviderConfig {
+        provider: ProviderType::Ollama,
+        base_url,
+        model: model.clone(),
+        proxy_routing,
+        ..Default::default()
+    });
+
+    let (tx, mut rx) = mpsc::channel::<ModelPullProgress>(64);
+    let event_model = model.clone();
+    let forwarder = tokio::spawn(async move {
+        while let Some(progress) = rx.recv().await {
+            let _ = app.emit(
+                OLLAMA_PULL_PROGRESS_CHANNEL,
+                OllamaPullProgressEvent {
+                    model: event_model.clone(),
+                    progress,
+                },
+            );
+        }
+    });
+
+    let result = provider.pull_model(tx).await;
+    let _ = forwarder.await;
+    if let Err(e) = result {
+        return Ok(CommandResponse::err(format!(
+            "Failed to pull model '{}': {}",
+            model, e
+        )));
+    }
+
+    // Confirm the pulled model is now listed before reporting success, and
+    // drop the cached "model not found" result.
+    app_state.provider_health_cache().invalidate(&cache_key);
+    match provider.health_check().await {
+        Ok(()) => Ok(CommandResponse::ok(model)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
 }
 
 /// Get the current working directory for standalone LLM sessions
@@ -1028,6 +1295,8 @@ pub async fn get_index_status(
             embedding_chunks: 0,
             embedding_provider_name: None,
             lsp_enrichment: "none".to_string(),
+            embedding_signature: None,
+            embedding_migration_required: false,
         }));
     }
 
@@ -1051,6 +1320,8 @@ pub async fn get_index_status(
             embedding_chunks: 0,
             embedding_provider_name: None,
             lsp_enrichment: "none".to_string(),
+            embedding_signature: None,
+            embedding_migration_required: false,
         }))
     }
 }
@@ -1085,6 +1356,38 @@ pub async fn trigger_reindex(
     }
 }
 
+/// Re-embed a project whose index was built with a different embedding
+/// provider, model or dimension than the configured one.
+/// Falls back to the current working directory if no project_path is provided.
+///
+/// Returns `false` when the index is already compatible and nothing was done.
+#[tauri::command]
+pub async fn migrate_embedding_index(
+    project_path: Option<String>,
+    standalone_state: State<'_, StandaloneState>,
+) -> Result<CommandResponse<bool>, String> {
+    let requested_dir = if let Some(p) = project_path {
+        p
+    } else {
+        let wd = standalone_state.working_directory.read().await;
+        wd.to_string_lossy().to_string()
+    };
+
+    if requested_dir.is_empty() {
+        return Ok(CommandResponse::err("No directory specified".to_string()));
+    }
+
+    let mgr_lock = standalone_state.index_manager.read().await;
+    if let Some(mgr) = &*mgr_lock {
+        let dir = resolve_indexed_project_path(mgr, &requested_dir).await;
+        Ok(CommandResponse::ok(mgr.migrate_embedding_index(&dir).await))
+    } else {
+        Ok(CommandResponse::err(
+            "IndexManager not initialized".to_string(),
+        ))
+    }
+}
+
 /// Perform a semantic search over indexed embeddings for a project.
 ///
 /// Returns the top-k most similar code chunks to the query string.
@@ -1151,24 +1454,14 @@ pub async fn semantic_search(
     // Prefer the project's EmbeddingManager (ADR-F002) for query embedding.
     // This avoids rebuilding a temporary TF-IDF vocabulary from scratch.
     if let Some(emb_mgr) = mgr.get_embedding_manager(&dir).await {
-        // Dimension compatibility check: stored embeddings vs manager dimension
-        let stored_dim = index_store
-            .get_embedding_metadata(&dir)
-            .ok()
-            .and_then(|meta| meta.first().map(|m| m.embedding_dimension));
-        let manager_dim = emb_mgr.dimension();
-        let dimension_compatible = stored_dim
-            .map(|d| d == 0 || manager_dim == 0 || d == manager_dim)
-            .unwrap_or(true);
-
-        if !dimension_compatible {
+        // Signature compatibility check: stored embeddings vs the manager's
+        // provider, model and dimension.
+        let stored_signatures = index_store.get_embedding_metadata(&dir).unwrap_or_default();
+        if let Some(mismatch) = emb_mgr.check_index_signature(&stored_signatures) {
             return Ok(CommandResponse::err(format!(
-                "Semantic search not available: embedding dimension mismatch. \
-                 Index was built with {}-dimensional embeddings, but the current \
-                 embedding provider produces {}-dimensional vectors. \
-                 Re-index the project to resolve this.",
-                stored_dim.unwrap_or(0),
-                manager_dim,
+                "Semantic search not available: {}. \
+                 Migrate the embedding index (or re-index the project) to resolve this.",
+                mismatch
             )));
         }
 
@@ -1297,6 +1590,8 @@ pub async fn execute_standalone(
     max_concurrent_subagents: Option<u32>,
     memory_auto_extract_enabled: Option<bool>,
     memoryAutoExtractEnabled: Option<bool>,
+    memory_session_summary_enabled: Option<bool>,
+    memorySessionSummaryEnabled: Option<bool>,
     memory_review_mode: Option<String>,
     memoryReviewMode: Option<String>,
     memory_review_agent_ref: Option<String>,
@@ -1403,10 +1698,16 @@ pub async fn execute_standalone(
     }
 
     // Resolve proxy for this provider
-    let proxy = app_state
-        .with_database(|db| Ok(resolve_provider_proxy(&keyring, db, &canonical_provider)))
+    let proxy_routing = app_state
+        .with_database(|db| {
+            Ok(resolve_provider_proxy_routing(
+                &keyring,
+                db,
+                &canonical_provider,
+            ))
+        })
         .await
-        .unwrap_or(None);
+        .ok();
 
     let config = ProviderConfig {
         provider: provider_type,
@@ -1414,7 +1715,7 @@ pub async fn execute_standalone(
         base_url: resolved_base_url,
         model,
         enable_thinking: enable_thinking.unwrap_or(false),
-        proxy,
+        proxy_routing,
         max_concurrent_subagents: max_concurrent_subagents
             .filter(|&v| v > 0)
             .map(|v| v as usize),
@@ -1452,6 +1753,9 @@ pub async fn execute_standalone(
     let memory_auto_extract_enabled = memory_auto_extract_enabled
         .or(memoryAutoExtractEnabled)
         .unwrap_or(true);
+    let memory_session_summary_enabled = memory_session_summary_enabled
+        .or(memorySessionSummaryEnabled)
+        .unwrap_or(false);
     let memory_review_mode = memory_review_mode.or(memoryReviewMode);
     let memory_review_agent_ref = memory_review_agent_ref.or(memoryReviewAgentRef);
     let memory_review_base_url = memory_review_base_url
@@ -1683,6 +1987,7 @@ pub async fn execute_standalone(
             &app,
             kernel_session_id.as_deref(),
             memory_auto_extract_enabled,
+            memory_session_summary_enabled,
             memory_review_mode.clone(),
             memory_review_agent_ref.clone(),
             Some(provider_config_for_index.clone()),
@@ -1820,6 +2125,7 @@ pub async fn execute_standalone(
     // Spawn task to forward events to frontend
     let app_clone = app.clone();
     tokio::spawn(async move {
+        let mut sequencer = StreamSequencer::new();
         while let Some(event) = rx.recv().await {
             if let Some(service) = webhook_service.clone() {
                 dispatch_webhook_on_event(
@@ -1849,6 +2155,7 @@ pub async fn execute_standalone(
                     "execution_id".to_string(),
                     serde_json::Value::String(execution_id.clone()),
                 );
+                obj.insert("seq".to_string(), sequencer.next_seq().into());
             }
             let _ = app_clone.emit("standalone-event", &payload);
             if let Some(binding_session_id) = workflow_binding_session_id.as_ref() {
@@ -1948,21 +2255,92 @@ pub async fn execute_standalone(
     Ok(CommandResponse::ok(result))
 }
 
+/// Artifact project used for rendered exports that are not tied to a project.
+const EXPORT_ARTIFACT_PROJECT: &str = "exports";
+
 /// Save text output to a user-selected file path.
+///
+/// With `format` set to `docx`, the markdown content is rendered to that
+/// format and also stored through the artifact service, scoped to
+/// `project_id`/`session_id`. Relative image paths resolve against
+/// `project_path`. Without a format the content is written as-is.
 #[tauri::command]
-pub async fn save_output_export(path: String, content: String) -> CommandResponse<bool> {
+#[allow(clippy::too_many_arguments)]
+pub async fn save_output_export(
+    path: String,
+    content: String,
+    format: Option<String>,
+    title: Option<String>,
+    project_path: Option<String>,
+    project_id: Option<String>,
+    session_id: Option<String>,
+    artifact_state: State<'_, ArtifactState>,
+    app_state: State<'_, AppState>,
+) -> Result<CommandResponse<bool>, String> {
     let target = PathBuf::from(path.trim());
     if target.as_os_str().is_empty() {
-        return CommandResponse::err("Invalid target path");
+        return Ok(CommandResponse::err("Invalid target path"));
     }
+    let format = match DocumentFormat::from_name(format.as_deref().unwrap_or_default()) {
+        Ok(format) => format,
+        Err(e) => return Ok(CommandResponse::err(e.to_string())),
+    };
+
+    let data = match format {
+        None => content.into_bytes(),
+        Some(format) => {
+            let options = DocumentExportOptions {
+                title: title.clone(),
+                header: title,
+                footer: Some("Plan Cascade".to_string()),
+                base_dir: project_path.map(PathBuf::from),
+            };
+            let data = match render_document(&content, format, &options) {
+                Ok(data) => data,
+                Err(e) => return Ok(CommandResponse::err(e.to_string())),
+            };
+
+            ensure_artifacts_initialized(&artifact_state, &app_state).await?;
+            let service = match artifact_state.get_service().await {
+                Ok(service) => service,
+                Err(e) => return Ok(CommandResponse::err(e)),
+            };
+            let name = target
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| format!("output.{}", format.extension()));
+            let scope = ArtifactScope {
+                project_id: project_id.unwrap_or_else(|| EXPORT_ARTIFACT_PROJECT.to_string()),
+                session_id,
+                user_id: None,
+            };
+            if let Err(e) = service
+                .save(&name, &scope, format.content_type(), &data)
+                .await
+            {
+                return Ok(CommandResponse::err(format!(
+                    "Failed to store export artifact: {}",
+                    e
+                )));
+            }
+            data
+        }
+    };
+
     if let Some(parent) = target.parent() {
         if let Err(e) = std::fs::create_dir_all(parent) {
-            return CommandResponse::err(format!("Failed to prepare export directory: {}", e));
+            return Ok(CommandResponse::err(format!(
+                "Failed to prepare export directory: {}",
+                e
+            )));
         }
     }
-    match std::fs::write(&target, content) {
-        Ok(_) => CommandResponse::ok(true),
-        Err(e) => CommandResponse::err(format!("Failed to save export: {}", e)),
+    match std::fs::write(&target, data) {
+        Ok(_) => Ok(CommandResponse::ok(true)),
+        Err(e) => Ok(CommandResponse::err(format!(
+            "Failed to save export: {}",
+            e
+        ))),
     }
 }
 
@@ -2096,57 +2474,34 @@ pub async fn record_usage(
 // Session-based execution commands
 // ============================================================================
 
-/// Execute a PRD with session tracking for crash recovery
-#[tauri::command]
-pub async fn execute_standalone_with_session(
-    request: ExecuteWithSessionRequest,
-    app: AppHandle,
-    app_state: State<'_, AppState>,
-    standalone_state: State<'_, StandaloneState>,
-    file_changes_state: State<'_, super::file_changes::FileChangesState>,
-    analytics_state: State<'_, super::analytics::AnalyticsState>,
-    permission_state: State<'_, super::permissions::PermissionState>,
-    webhook_state: State<'_, WebhookState>,
-    plugin_state: State<'_, super::plugins::PluginState>,
-) -> Result<CommandResponse<SessionExecutionResult>, String> {
+/// Resolve the provider configuration for a session-style request: API key
+/// from the keyring, base URL from settings, and the provider's proxy.
+///
+/// Returns the canonical provider name alongside the config.
+async fn resolve_request_provider_config(
+    app_state: &AppState,
+    provider: &str,
+    model: &str,
+    enable_thinking: bool,
+) -> Result<(String, ProviderConfig), String> {
     let keyring = KeyringService::new();
-    let canonical_provider = match normalize_provider_name(&request.provider) {
-        Some(p) => p.to_string(),
-        None => {
-            return Ok(CommandResponse::err(format!(
-                "Unknown provider: {}",
-                request.provider
-            )))
-        }
-    };
+    let canonical_provider = normalize_provider_name(provider)
+        .ok_or_else(|| format!("Unknown provider: {}", provider))?
+        .to_string();
 
     // Get API key
-    let api_key = match get_api_key_with_aliases(&keyring, &canonical_provider) {
-        Ok(key) => key,
-        Err(e) => {
-            return Ok(CommandResponse::err(format!(
-                "Failed to get API key: {}",
-                e
-            )));
-        }
-    };
+    let api_key = get_api_key_with_aliases(&keyring, &canonical_provider)
+        .map_err(|e| format!("Failed to get API key: {}", e))?;
 
-    let provider_type = match provider_type_from_name(&canonical_provider) {
-        Some(p) => p,
-        None => {
-            return Ok(CommandResponse::err(format!(
-                "Unknown provider: {}",
-                request.provider
-            )))
-        }
-    };
+    let provider_type = provider_type_from_name(&canonical_provider)
+        .ok_or_else(|| format!("Unknown provider: {}", provider))?;
 
     // Validate API key for non-Ollama providers
     if provider_type != ProviderType::Ollama && api_key.is_none() {
-        return Ok(CommandResponse::err(format!(
+        return Err(format!(
             "API key not configured for provider '{}'",
             canonical_provider
-        )));
+        ));
     }
 
     // Resolve base_url from database settings
@@ -2161,20 +2516,49 @@ pub async fn execute_standalone_with_session(
     };
 
     // Resolve proxy for this provider
-    let proxy = app_state
-        .with_database(|db| Ok(resolve_provider_proxy(&keyring, db, &canonical_provider)))
+    let proxy_routing = app_state
+        .with_database(|db| {
+            Ok(resolve_provider_proxy_routing(
+                &keyring,
+                db,
+                &canonical_provider,
+            ))
+        })
         .await
-        .unwrap_or(None);
+        .ok();
 
     let config = ProviderConfig {
         provider: provider_type,
         api_key,
         base_url: resolved_base_url,
-        model: request.model.clone(),
-        enable_thinking: request.enable_thinking.unwrap_or(false),
-        proxy,
+        model: model.to_string(),
+        enable_thinking,
+        proxy_routing,
         ..Default::default()
     };
+    Ok((canonical_provider, config))
+}
+
+/// Execute a PRD with session tracking for crash recovery
+#[tauri::command]
+pub async fn execute_standalone_with_session(
+    request: ExecuteWithSessionRequest,
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    standalone_state: State<'_, StandaloneState>,
+    webhook_state: State<'_, WebhookState>,
+) -> Result<CommandResponse<SessionExecutionResult>, String> {
+    let (canonical_provider, config) = match resolve_request_provider_config(
+        app_state.inner(),
+        &request.provider,
+        &request.model,
+        request.enable_thinking.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(resolved) => resolved,
+        Err(e) => return Ok(CommandResponse::err(e)),
+    };
     // Generate session ID first so analysis cache reuse is scoped to this execution session.
     let session_id = uuid::Uuid::new_v4().to_string();
 
@@ -2203,96 +2587,15 @@ pub async fn execute_standalone_with_session(
         .await
         .map_err(|e| e.to_string())?;
 
-    // Clone provider config before orchestrator_config is moved
-    let provider_config_for_index = orchestrator_config.provider.clone();
-
-    // Create orchestrator with database (IndexStore is auto-wired to ToolExecutor)
-    let mut orchestrator = OrchestratorService::new(orchestrator_config)
-        .with_database(pool)
-        .with_guardrail_hooks(crate::services::guardrail::shared_guardrail_registry())
-        .with_permission_gate(permission_state.gate.clone())
-        .with_file_change_source_mode(
-            crate::services::file_change_tracker::FileChangeSourceMode::Chat,
-        )
-        .with_file_change_actor_metadata(
-            crate::services::file_change_tracker::FileChangeActorKind::RootAgent,
-            Some("chat-root".to_string()),
-            Some("Main Agent".to_string()),
-            None,
-            None,
-        );
-
-    // Wire file change tracker for AI file modification tracking
-    {
-        let tracker = file_changes_state
-            .get_or_create(&session_id, &request.project_path)
-            .await;
-        if let Ok(mut t) = tracker.lock() {
-            let next = t.turn_index() + 1;
-            t.set_turn_index(next);
-            t.set_app_handle(app.clone());
-            orchestrator = orchestrator.with_file_change_turn_index(next);
-        }
-        orchestrator = orchestrator.with_file_change_tracker(tracker);
-    }
-
-    // Wire embedding service and EmbeddingManager from IndexManager for semantic CodebaseSearch
-    if let Some(ref manager) = *standalone_state.index_manager.read().await {
-        if let Some(emb_svc) = manager.get_embedding_service(&request.project_path).await {
-            orchestrator = orchestrator.with_embedding_service(emb_svc);
-        }
-        if let Some(emb_mgr) = manager.get_embedding_manager(&request.project_path).await {
-            orchestrator = orchestrator.with_embedding_manager(emb_mgr);
-        }
-        // Set LLM provider on IndexManager for component classification.
-        if let Some(llm) = build_llm_provider_from_config(&provider_config_for_index) {
-            manager.set_llm_provider(llm).await;
-        }
-    }
-
-    // Wire analytics tracking for persistent usage recording
-    {
-        let _ = analytics_state.initialize(&app_state).await;
-        if let Some(atx) = analytics_state.get_tracker_sender().await {
-            orchestrator = orchestrator
-                .with_analytics_tracker(atx)
-                .with_analytics_cost_calculator(analytics_state.cost_calculator())
-                .with_analytics_attribution(crate::models::analytics::AnalyticsAttribution {
-                    project_id: None,
-                    kernel_session_id: None,
-                    mode_session_id: Some(session_id.clone()),
-                    workflow_mode: Some(crate::models::analytics::AnalyticsWorkflowMode::Chat),
-                    phase_id: Some("chat_turn".to_string()),
-                    execution_scope: Some(
-                        crate::models::analytics::AnalyticsExecutionScope::RootAgent,
-                    ),
-                    execution_id: Some(format!("chat:{}:root", session_id)),
-                    parent_execution_id: None,
-                    agent_role: Some("chat_root".to_string()),
-                    agent_name: None,
-                    step_id: None,
-                    story_id: None,
-                    gate_id: None,
-                    attempt: Some(1),
-                    request_sequence: Some(1),
-                    call_site: Some("standalone.resume".to_string()),
-                    metadata_json: None,
-                });
-        }
-    }
-
-    // Wire memory hooks for automatic memory loading and extraction
-    if let Ok(memory_store) = app_state.get_memory_store_arc().await {
-        let loaded_memories = std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new()));
-        orchestrator = orchestrator.with_memory_hooks(memory_store, loaded_memories);
-    }
-
-    orchestrator =
-        wire_skill_hooks_if_enabled(orchestrator, app_state.inner(), &request.project_path, None)
-            .await;
-
-    // Wire plugin context (instructions, skills, commands, hooks, permissions) from enabled plugins
-    orchestrator = plugin_state.wire_orchestrator(orchestrator, None).await;
+    let orchestrator = build_session_orchestrator(
+        &app,
+        orchestrator_config,
+        pool,
+        &session_id,
+        &request.project_path,
+        "standalone.execute_with_session",
+    )
+    .await;
 
     let orchestrator = Arc::new(orchestrator);
 
@@ -2370,6 +2673,7 @@ pub async fn execute_standalone_with_session(
     let app_clone = app.clone();
     let session_id_clone = session_id.clone();
     tokio::spawn(async move {
+        let mut sequencer = StreamSequencer::new();
         while let Some(event) = rx.recv().await {
             if let Some(service) = webhook_service.clone() {
                 dispatch_webhook_on_event(
@@ -2382,6 +2686,7 @@ pub async fn execute_standalone_with_session(
                     None,
                 );
             }
+            let event = sequencer.sequence(event);
             let _ = app_clone.emit(&format!("session-event-{}", session_id_clone), &event);
             // Also emit to general channel for dashboard
             let _ = app_clone.emit("standalone-session-event", &event);
@@ -2399,6 +2704,186 @@ pub async fn execute_standalone_with_session(
     Ok(CommandResponse::ok(result))
 }
 
+/// Stream event from one prompt of a standalone batch
+#[derive(Clone, serde::Serialize)]
+pub struct StandaloneBatchEvent {
+    pub batch_id: String,
+    pub index: usize,
+    pub session_id: String,
+    pub event: UnifiedStreamEvent,
+}
+
+/// Run the same agent configuration over a list of prompts.
+///
+/// Each prompt runs as its own session (cancellable and pausable through the
+/// usual standalone commands) with at most `max_concurrency` running at once.
+/// Stream events are emitted on `standalone-batch-event`. Failed prompts are
+/// reported per item and do not stop the rest of the batch.
+#[tauri::command]
+pub async fn execute_standalone_batch(
+    prompts: Vec<String>,
+    config: StandaloneBatchConfig,
+    app: AppHandle,
+    app_state: State<'_, AppState>,
+    standalone_state: State<'_, StandaloneState>,
+) -> Result<CommandResponse<BatchExecutionResult>, String> {
+    let prompts: Vec<String> = prompts
+        .into_iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+    if prompts.is_empty() {
+        return Ok(CommandResponse::err("No prompts to execute".to_string()));
+    }
+
+    let (_, provider_config) = match resolve_request_provider_config(
+        app_state.inner(),
+        &config.provider,
+        &config.model,
+        config.enable_thinking.unwrap_or(false),
+    )
+    .await
+    {
+        Ok(resolved) => resolved,
+        Err(e) => return Ok(CommandResponse::err(e)),
+    };
+
+    let pool = app_state
+        .with_database(|db| Ok(db.pool().clone()))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let batch_id = config
+        .batch_id
+        .clone()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let cancellation_token = CancellationToken::new();
+    {
+        let mut batches = standalone_state.batches.write().await;
+        if batches.contains_key(&batch_id) {
+            return Ok(CommandResponse::err(format!(
+                "Batch already running: {}",
+                batch_id
+            )));
+        }
+        batches.insert(batch_id.clone(), cancellation_token.clone());
+    }
+
+    let options = BatchOptions {
+        max_concurrency: config.max_concurrency.unwrap_or(DEFAULT_BATCH_CONCURRENCY),
+        abort_in_flight_on_cancel: config.abort_in_flight_on_cancel,
+    };
+    let orchestrators = standalone_state.orchestrators.clone();
+
+    let result = run_batch(
+        batch_id.clone(),
+        prompts,
+        options,
+        cancellation_token,
+        |item: BatchItem| {
+            let orchestrator_config = OrchestratorConfig {
+                provider: provider_config.clone(),
+                system_prompt: config.system_prompt.clone(),
+                execution_kind: ExecutionKind::StandaloneRoot,
+                soft_limit_override: None,
+                max_total_tokens: config.max_total_tokens.unwrap_or(1_000_000),
+                project_root: PathBuf::from(&config.project_path),
+                streaming: true,
+                enable_compaction: true,
+                analysis_artifacts_root: analysis_artifacts_root(),
+                analysis_profile: Default::default(),
+                analysis_limits: Default::default(),
+                analysis_session_id: Some(item.session_id.clone()),
+                project_id: None,
+                compaction_config: Default::default(),
+                task_type: None,
+                sub_agent_depth: None,
+            };
+            let pool = pool.clone();
+            let project_path = config.project_path.clone();
+            let orchestrators = orchestrators.clone();
+            let app = app.clone();
+            let batch_id = batch_id.clone();
+
+            async move {
+                let orchestrator = Arc::new(
+                    build_session_orchestrator(
+                        &app,
+                        orchestrator_config,
+                        pool,
+                        &item.session_id,
+                        &project_path,
+                        "standalone.batch",
+                    )
+                    .await,
+                );
+                orchestrators
+                    .write()
+                    .await
+                    .insert(item.session_id.clone(), orchestrator.clone());
+
+                // Forward stream events, tagged with the batch item
+                let (tx, mut rx) = mpsc::channel::<UnifiedStreamEvent>(100);
+                let index = item.index;
+                let session_id = item.session_id.clone();
+                tokio::spawn(async move {
+                    while let Some(event) = rx.recv().await {
+                        let _ = app.emit(
+                            "standalone-batch-event",
+                            &StandaloneBatchEvent {
+                                batch_id: batch_id.clone(),
+                                index,
+                                session_id: session_id.clone(),
+                                event,
+                            },
+                        );
+                    }
+                });
+
+                // Abort the prompt when the batch asks for it
+                let watcher = {
+                    let orchestrator = orchestrator.clone();
+                    let token = item.cancellation_token.clone();
+                    tokio::spawn(async move {
+                        token.cancelled().await;
+                        orchestrator.cancel();
+                    })
+                };
+
+                let result = orchestrator.execute(item.prompt, tx).await;
+                watcher.abort();
+                orchestrators.write().await.remove(&item.session_id);
+                result
+            }
+        },
+    )
+    .await;
+
+    standalone_state.batches.write().await.remove(&batch_id);
+    Ok(CommandResponse::ok(result))
+}
+
+/// Cancel a running standalone batch. Prompts that have not started are
+/// skipped; running prompts finish or abort per the batch configuration.
+#[tauri::command]
+pub async fn cancel_standalone_batch(
+    batch_id: String,
+    standalone_state: State<'_, StandaloneState>,
+) -> Result<CommandResponse<bool>, String> {
+    match standalone_state.batches.read().await.get(&batch_id) {
+        Some(token) => {
+            token.cancel();
+            Ok(CommandResponse::ok(true))
+        }
+        None => Ok(CommandResponse::err(format!(
+            "Batch not found: {}",
+            batch_id
+        ))),
+    }
+}
+
 /// Cancel a running standalone execution
 #[tauri::command]
 pub async fn cancel_standalone_execution(
@@ -2636,10 +3121,15 @@ pub async fn get_standalone_status(
 }
 
 /// Get detailed progress for a specific session
+///
+/// Falls back to the live pause checkpoint for executions that have no
+/// persisted session, so a paused chat execution still reports its
+/// accumulated usage.
 #[tauri::command]
 pub async fn get_standalone_progress(
     session_id: String,
     app_state: State<'_, AppState>,
+    standalone_state: State<'_, StandaloneState>,
 ) -> Result<CommandResponse<ExecutionProgress>, String> {
     // Get database pool
     let pool = match app_state.with_database(|db| Ok(db.pool().clone())).await {
@@ -2682,10 +3172,19 @@ pub async fn get_standalone_progress(
 
     match orchestrator.get_progress(&session_id).await {
         Ok(Some(progress)) => Ok(CommandResponse::ok(progress)),
-        Ok(None) => Ok(CommandResponse::err(format!(
-            "Session not found: {}",
-            session_id
-        ))),
+        Ok(None) => {
+            let live = standalone_state
+                .get_orchestrator(&session_id)
+                .await
+                .and_then(|live| live.paused_progress(&session_id));
+            match live {
+                Some(progress) => Ok(CommandResponse::ok(progress)),
+                None => Ok(CommandResponse::err(format!(
+                    "Session not found: {}",
+                    session_id
+                ))),
+            }
+        }
         Err(e) => Ok(CommandResponse::err(format!(
             "Failed to get progress: {}",
             e
@@ -2700,10 +3199,7 @@ pub async fn resume_standalone_execution(
     app: AppHandle,
     app_state: State<'_, AppState>,
     standalone_state: State<'_, StandaloneState>,
-    file_changes_state: State<'_, super::file_changes::FileChangesState>,
-    permission_state: State<'_, super::permissions::PermissionState>,
     webhook_state: State<'_, WebhookState>,
-    plugin_state: State<'_, super::plugins::PluginState>,
 ) -> Result<CommandResponse<SessionExecutionResult>, String> {
     // Get database pool
     let pool = match app_state.with_database(|db| Ok(db.pool().clone())).await {
@@ -2828,10 +3324,16 @@ pub async fn resume_standalone_execution(
     };
 
     // Resolve proxy for this provider
-    let proxy = app_state
-        .with_database(|db| Ok(resolve_provider_proxy(&keyring, db, &canonical_provider)))
+    let proxy_routing = app_state
+        .with_database(|db| {
+            Ok(resolve_provider_proxy_routing(
+                &keyring,
+                db,
+                &canonical_provider,
+            ))
+        })
         .await
-        .unwrap_or(None);
+        .ok();
 
     // Create new orchestrator with the session's config
     let config = ProviderConfig {
@@ -2839,7 +3341,7 @@ pub async fn resume_standalone_execution(
         api_key,
         base_url: resolved_base_url,
         model: session.model.clone(),
-        proxy,
+        proxy_routing,
         ..Default::default()
     };
 
@@ -2862,64 +3364,15 @@ pub async fn resume_standalone_execution(
         sub_agent_depth: None,
     };
 
-    // Clone provider config before orchestrator_config is moved
-    let provider_config_for_index = orchestrator_config.provider.clone();
-
-    let mut orchestrator = OrchestratorService::new(orchestrator_config)
-        .with_database(pool)
-        .with_guardrail_hooks(crate::services::guardrail::shared_guardrail_registry())
-        .with_permission_gate(permission_state.gate.clone())
-        .with_file_change_source_mode(
-            crate::services::file_change_tracker::FileChangeSourceMode::Chat,
-        )
-        .with_file_change_actor_metadata(
-            crate::services::file_change_tracker::FileChangeActorKind::RootAgent,
-            Some("chat-root".to_string()),
-            Some("Main Agent".to_string()),
-            None,
-            None,
-        );
-
-    // Wire file change tracker for AI file modification tracking
-    {
-        let tracker = file_changes_state
-            .get_or_create(&request.session_id, &session.project_path)
-            .await;
-        if let Ok(mut t) = tracker.lock() {
-            let next = t.turn_index() + 1;
-            t.set_turn_index(next);
-            t.set_app_handle(app.clone());
-            orchestrator = orchestrator.with_file_change_turn_index(next);
-        }
-        orchestrator = orchestrator.with_file_change_tracker(tracker);
-    }
-
-    // Wire embedding service and EmbeddingManager from IndexManager for semantic CodebaseSearch
-    if let Some(ref manager) = *standalone_state.index_manager.read().await {
-        if let Some(emb_svc) = manager.get_embedding_service(&session.project_path).await {
-            orchestrator = orchestrator.with_embedding_service(emb_svc);
-        }
-        if let Some(emb_mgr) = manager.get_embedding_manager(&session.project_path).await {
-            orchestrator = orchestrator.with_embedding_manager(emb_mgr);
-        }
-        // Set LLM provider on IndexManager for component classification.
-        if let Some(llm) = build_llm_provider_from_config(&provider_config_for_index) {
-            manager.set_llm_provider(llm).await;
-        }
-    }
-
-    // Wire memory hooks for automatic memory loading and extraction
-    if let Ok(memory_store) = app_state.get_memory_store_arc().await {
-        let loaded_memories = std::sync::Arc::new(tokio::sync::RwLock::new(Vec::new()));
-        orchestrator = orchestrator.with_memory_hooks(memory_store, loaded_memories);
-    }
-
-    orchestrator =
-        wire_skill_hooks_if_enabled(orchestrator, app_state.inner(), &session.project_path, None)
-            .await;
-
-    // Wire plugin context (instructions, skills, commands, hooks, permissions) from enabled plugins
-    orchestrator = plugin_state.wire_orchestrator(orchestrator, None).await;
+    let orchestrator = build_session_orchestrator(
+        &app,
+        orchestrator_config,
+        pool,
+        &request.session_id,
+        &session.project_path,
+        "standalone.resume",
+    )
+    .await;
 
     let orchestrator = Arc::new(orchestrator);
 
@@ -2945,6 +3398,7 @@ pub async fn resume_standalone_execution(
     let app_clone = app.clone();
     let session_id = request.session_id.clone();
     tokio::spawn(async move {
+        let mut sequencer = StreamSequencer::new();
         while let Some(event) = rx.recv().await {
             if let Some(service) = webhook_service.clone() {
                 dispatch_webhook_on_event(
@@ -2957,6 +3411,7 @@ pub async fn resume_standalone_execution(
                     None,
                 );
             }
+            let event = sequencer.sequence(event);
             let _ = app_clone.emit(&format!("session-event-{}", session_id), &event);
             let _ = app_clone.emit("standalone-session-event", &event);
         }
@@ -3273,6 +3728,7 @@ mod tests {
         assert_eq!(normalize_search_provider_name("brave"), "brave");
         assert_eq!(normalize_search_provider_name("brave_search"), "brave");
         assert_eq!(normalize_search_provider_name("searxng"), "searxng");
+        assert_eq!(normalize_search_provider_name("Kagi"), "kagi");
         assert_eq!(
             normalize_search_provider_name("unknown-provider"),
             "duckduckgo"
@@ -3288,6 +3744,7 @@ mod tests {
             &["brave", "brave_search"]
         );
         assert_eq!(search_provider_key_candidates("searxng"), &["searxng"]);
+        assert_eq!(search_provider_key_candidates("kagi"), &["kagi"]);
     }
 
     #[test]
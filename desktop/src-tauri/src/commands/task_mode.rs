@@ -26,7 +26,10 @@ use crate::services::strategy::analyzer::{
     analyze_task_for_mode, build_deterministic_recommendation, ExecutionMode, RiskLevel,
     StrategyAnalysis, StrategyRecommendationSource, TaskStrategyRecommendation,
 };
-use crate::services::task_mode::agent_resolver::AgentResolver;
+use crate::services::task_mode::agent_resolver::{AgentOverrides, AgentResolver, ExecutionPhase};
+use crate::services::task_mode::analysis_cache::{
+    AnalysisCache, AnalysisCacheInfo, AnalysisCacheLookup, AnalysisKind,
+};
 use crate::services::task_mode::batch_executor::{
     BatchExecutionProgress, BatchExecutionResult, BatchExecutor, ExecutableStory, ExecutionBatch,
     ExecutionConfig, StoryContext, StoryExecutionContext, StoryExecutionOutcome,
@@ -35,6 +38,7 @@ use crate::services::task_mode::batch_executor::{
 use crate::services::task_mode::exploration::{
     self, ExplorationResult, SummaryQuality, SummarySource,
 };
+use crate::services::task_mode::exploration_cache::{ExplorationCache, ExplorationCacheStatus};
 use crate::services::task_mode::prd_generator;
 use crate::services::workflow_kernel::{
     HandoffContextBundle, HandoffSummaryItem, WorkflowKernelState, WorkflowKernelUpdatedEvent,
@@ -116,6 +120,17 @@ pub struct TaskStory {
     pub dependencies: Vec<String>,
     /// Acceptance criteria
     pub acceptance_criteria: Vec<String>,
+    /// Locked by the user; regeneration keeps the story exactly as is
+    #[serde(default)]
+    pub locked: bool,
+}
+
+/// A PRD-level section that can be locked against regeneration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskPrdSection {
+    Title,
+    Description,
 }
 
 /// Task PRD (Product Requirements Document).
@@ -130,6 +145,20 @@ pub struct TaskPrd {
     pub stories: Vec<TaskStory>,
     /// Execution batches (calculated from dependencies)
     pub batches: Vec<ExecutionBatch>,
+    /// Sections locked by the user; regeneration keeps them as is
+    #[serde(default)]
+    pub locked_sections: Vec<TaskPrdSection>,
+}
+
+impl TaskPrd {
+    pub fn is_section_locked(&self, section: TaskPrdSection) -> bool {
+        self.locked_sections.contains(&section)
+    }
+
+    /// Whether any section or story is locked.
+    pub fn has_locks(&self) -> bool {
+        !self.locked_sections.is_empty() || self.stories.iter().any(|story| story.locked)
+    }
 }
 
 /// Task mode session.
@@ -163,6 +192,9 @@ pub struct TaskModeSession {
     pub prd: Option<TaskPrd>,
     /// Project exploration result
     pub exploration_result: Option<ExplorationResult>,
+    /// How the exploration result was obtained from the exploration cache
+    #[serde(default)]
+    pub exploration_cache: Option<ExplorationCacheStatus>,
     /// Execution progress
     pub progress: Option<BatchExecutionProgress>,
     /// Persisted execution launch metadata used for background resume.
@@ -328,6 +360,12 @@ pub struct TaskWorkflowConfig {
     pub custom_quality_gates: Vec<TaskCustomQualityGate>,
     /// Maximum parallel stories
     pub max_parallel: Option<usize>,
+    /// Cap on story attempts running at once across all batches
+    #[serde(default)]
+    pub max_concurrent_stories: Option<usize>,
+    /// Total retries shared by all stories
+    #[serde(default)]
+    pub retry_budget: Option<u32>,
     /// Skip verification gates (--no-verify)
     pub skip_verification: bool,
     /// Skip code review gate (--no-review)
@@ -375,6 +413,9 @@ pub struct GenerateTaskPrdRequest {
     pub locale: Option<String>,
     pub context_sources: Option<crate::services::task_mode::context_provider::ContextSourceConfig>,
     pub project_path: Option<String>,
+    /// PRD under review, including user edits and locks. Regenerating while
+    /// reviewing keeps its locked sections and stories.
+    pub previous_prd: Option<TaskPrd>,
 }
 
 /// Request payload for `explore_project`.
@@ -405,6 +446,9 @@ pub struct ApproveTaskPrdRequest {
     pub workflow_config: Option<TaskWorkflowConfig>,
     pub global_default_agent: Option<String>,
     pub phase_configs: Option<HashMap<String, PhaseConfigInput>>,
+    /// Agent overrides, validated against each phase before execution starts
+    #[serde(default)]
+    pub agent_overrides: Option<AgentOverrides>,
     pub locale: Option<String>,
     pub context_sources: Option<crate::services::task_mode::context_provider::ContextSourceConfig>,
     pub project_path: Option<String>,
@@ -420,6 +464,8 @@ pub struct TaskExecutionResumePayload {
     pub workflow_config: Option<TaskWorkflowConfig>,
     pub global_default_agent: Option<String>,
     pub phase_configs: Option<HashMap<String, PhaseConfigInput>>,
+    #[serde(default)]
+    pub agent_overrides: Option<AgentOverrides>,
     pub context_sources: Option<crate::services::task_mode::context_provider::ContextSourceConfig>,
     pub project_path: Option<String>,
 }
@@ -439,6 +485,9 @@ pub struct RunRequirementAnalysisRequest {
     pub locale: Option<String>,
     pub context_sources: Option<crate::services::task_mode::context_provider::ContextSourceConfig>,
     pub project_path: Option<String>,
+    /// Re-run the analysis even when a cached result matches the spec
+    #[serde(default)]
+    pub force_refresh: bool,
 }
 
 /// Request payload for `run_architecture_review`.
@@ -455,6 +504,9 @@ pub struct RunArchitectureReviewRequest {
     pub locale: Option<String>,
     pub context_sources: Option<crate::services::task_mode::context_provider::ContextSourceConfig>,
     pub project_path: Option<String>,
+    /// Re-run the review even when a cached result matches the PRD
+    #[serde(default)]
+    pub force_refresh: bool,
 }
 
 /// Request payload for `apply_task_prd_feedback`.
@@ -511,6 +563,12 @@ pub struct TaskExecutionStatus {
     pub stories_completed: usize,
     /// Stories failed
     pub stories_failed: usize,
+    /// Story attempts currently holding a concurrency slot
+    pub active_concurrency: usize,
+    /// Retries left in the shared budget; `None` when there is no budget
+    pub retry_budget_remaining: Option<u32>,
+    /// Whether the exploration result was reused from the cache
+    pub exploration_cache: Option<ExplorationCacheStatus>,
 }
 
 // ============================================================================
@@ -531,6 +589,9 @@ pub struct RequirementAnalysisResult {
     pub suggested_scope: String,
     /// Persona role that produced this analysis
     pub persona_role: String,
+    /// Source hash and timestamp of the analysis, and whether it was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<AnalysisCacheInfo>,
 }
 
 /// A concern identified during architecture review.
@@ -633,6 +694,9 @@ pub struct ArchitectureReviewResult {
     pub approved: bool,
     /// Persona role that produced this analysis
     pub persona_role: String,
+    /// Source hash and timestamp of the review, and whether it was reused
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<AnalysisCacheInfo>,
 }
 
 // ============================================================================
@@ -649,6 +713,10 @@ pub struct TaskModeState {
     operation_cancellation_tokens: Arc<RwLock<HashMap<String, (String, CancellationToken)>>>,
     /// Final execution results keyed by session id.
     execution_results: Arc<RwLock<HashMap<String, BatchExecutionResult>>>,
+    /// Exploration results keyed by project, flow level and task.
+    exploration_cache: ExplorationCache,
+    /// Requirement analysis and architecture review results keyed by project.
+    analysis_cache: AnalysisCache,
     storage_root: Arc<PathBuf>,
 }
 
@@ -675,10 +743,20 @@ impl TaskModeState {
             cancellation_tokens: Arc::new(RwLock::new(HashMap::new())),
             operation_cancellation_tokens: Arc::new(RwLock::new(HashMap::new())),
             execution_results: Arc::new(RwLock::new(HashMap::new())),
+            exploration_cache: ExplorationCache::new(storage_root.join("exploration_cache")),
+            analysis_cache: AnalysisCache::new(storage_root.join("analysis_cache")),
             storage_root: Arc::new(storage_root),
         }
     }
 
+    pub fn exploration_cache(&self) -> &ExplorationCache {
+        &self.exploration_cache
+    }
+
+    pub fn analysis_cache(&self) -> &AnalysisCache {
+        &self.analysis_cache
+    }
+
     pub async fn get_session_snapshot(&self, session_id: &str) -> Option<TaskModeSession> {
         let sessions = self.sessions.read().await;
         sessions.get(session_id).cloned()
@@ -1488,16 +1566,16 @@ pub(crate) async fn resolve_llm_provider(
     }
 
     // Resolve proxy
-    let proxy = {
+    let proxy_routing = {
         let keyring = KeyringService::new();
         app_state
             .with_database(|db| {
-                Ok(crate::commands::proxy::resolve_provider_proxy(
+                Ok(crate::commands::proxy::resolve_provider_proxy_routing(
                     &keyring, db, canonical,
                 ))
             })
             .await
-            .unwrap_or(None)
+            .ok()
     };
 
     let config = ProviderConfig {
@@ -1505,7 +1583,7 @@ pub(crate) async fn resolve_llm_provider(
         api_key,
         base_url: resolved_base_url,
         model: model.to_string(),
-        proxy,
+        proxy_routing,
         ..Default::default()
     };
 
@@ -1646,16 +1724,16 @@ pub(crate) async fn resolve_provider_config_from_app_state(
         }
     }
 
-    let proxy = {
+    let proxy_routing = {
         let keyring = KeyringService::new();
         app_state
             .with_database(|db| {
-                Ok(crate::commands::proxy::resolve_provider_proxy(
+                Ok(crate::commands::proxy::resolve_provider_proxy_routing(
                     &keyring, db, canonical,
                 ))
             })
             .await
-            .unwrap_or(None)
+            .ok()
     };
 
     Ok(ProviderConfig {
@@ -1663,7 +1741,7 @@ pub(crate) async fn resolve_provider_config_from_app_state(
         api_key,
         base_url: resolved_base_url,
         model: model.to_string(),
-        proxy,
+        proxy_routing,
         ..Default::default()
     })
 }
@@ -2714,6 +2792,7 @@ async fn execute_story_via_llm(
                     tool_name,
                     arguments,
                     risk,
+                    reason,
                 } => {
                     paused_state.store(true, Ordering::SeqCst);
                     let _ = app_for_events.emit(
@@ -2734,6 +2813,7 @@ async fn execute_story_via_llm(
                                 tool_name: tool_name.clone(),
                                 arguments: arguments.clone(),
                                 risk: risk.clone(),
+                                reason: reason.clone(),
                             }),
                             error: None,
                             progress_pct: 0.0,
@@ -2933,10 +3013,9 @@ fn build_story_prompt(
 fn build_agents_config_from_frontend(
     configs: &HashMap<String, PhaseConfigInput>,
     global_default_agent: Option<&str>,
+    overrides: Option<&AgentOverrides>,
 ) -> crate::services::task_mode::agent_resolver::AgentsConfig {
-    use crate::services::task_mode::agent_resolver::{
-        AgentDefinition, AgentOverrides, AgentsConfig, PhaseConfig,
-    };
+    use crate::services::task_mode::agent_resolver::{AgentDefinition, AgentsConfig, PhaseConfig};
 
     let mut agents: HashMap<String, AgentDefinition> = HashMap::new();
     let mut phase_defaults: HashMap<String, PhaseConfig> = HashMap::new();
@@ -2958,6 +3037,7 @@ fn build_agents_config_from_frontend(
                     description: String::new(),
                     available: true,
                     suitable_phases: vec![],
+                    capabilities: None,
                 },
             );
         }
@@ -2995,10 +3075,23 @@ fn build_agents_config_from_frontend(
                 default_agent: phase_default,
                 fallback_chain,
                 story_type_overrides: HashMap::new(),
+                required_capabilities: Vec::new(),
             },
         );
     }
 
+    // Override agents are applied (and validated) separately, but must be
+    // registered so the resolver considers them available.
+    if let Some(overrides) = overrides {
+        for agent in overrides
+            .global
+            .iter()
+            .chain(overrides.phase_overrides.values())
+        {
+            register_agent(agent.trim());
+        }
+    }
+
     let default_agent = normalized_global_default.unwrap_or_else(|| "claude-code".to_string());
     register_agent(&default_agent);
 
@@ -3010,6 +3103,51 @@ fn build_agents_config_from_frontend(
     }
 }
 
+/// Apply frontend agent overrides to `resolver`, validating each one.
+///
+/// Rejected overrides are left unset. Returns a message listing every
+/// mismatch so it can be shown to the user.
+fn apply_agent_overrides(
+    resolver: &mut AgentResolver,
+    overrides: &AgentOverrides,
+) -> Result<(), String> {
+    let mut rejected: Vec<String> = Vec::new();
+
+    if let Some(global) = overrides.global.as_deref().map(str::trim) {
+        if !global.is_empty() {
+            if let Err(mismatches) = resolver.try_set_global_override(global) {
+                rejected.extend(mismatches.iter().map(ToString::to_string));
+            }
+        }
+    }
+
+    for (phase_id, agent) in &overrides.phase_overrides {
+        let agent = agent.trim();
+        if agent.is_empty() {
+            continue;
+        }
+        let Some(phase) = ExecutionPhase::ALL
+            .into_iter()
+            .find(|phase| phase.to_string() == *phase_id)
+        else {
+            rejected.push(format!(
+                "Unknown phase '{}' for override '{}'",
+                phase_id, agent
+            ));
+            continue;
+        };
+        if let Err(mismatch) = resolver.try_set_phase_override(phase, agent) {
+            rejected.push(mismatch.to_string());
+        }
+    }
+
+    if rejected.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("Invalid agent overrides: {}", rejected.join("; ")))
+    }
+}
+
 // ============================================================================
 // Quality Score Helpers
 // ============================================================================
@@ -3087,6 +3225,7 @@ mod tests {
             priority: "high".to_string(),
             dependencies: vec![],
             acceptance_criteria: vec!["Criterion 1".to_string()],
+            locked: false,
         };
         let json = serde_json::to_string(&story).unwrap();
         assert!(json.contains("\"acceptanceCriteria\""));
@@ -3099,6 +3238,7 @@ mod tests {
             description: "A test PRD".to_string(),
             stories: vec![],
             batches: vec![],
+            locked_sections: Vec::new(),
         };
         let json = serde_json::to_string(&prd).unwrap();
         assert!(json.contains("\"stories\""));
@@ -3119,6 +3259,7 @@ mod tests {
             confirmed_config: None,
             prd: None,
             exploration_result: None,
+            exploration_cache: None,
             progress: None,
             execution_resume_payload: None,
             cancel_requested: false,
@@ -3143,6 +3284,7 @@ mod tests {
             confirmed_config: None,
             prd: None,
             exploration_result: None,
+            exploration_cache: None,
             progress: None,
             execution_resume_payload: None,
             cancel_requested: false,
@@ -3347,6 +3489,7 @@ mod tests {
         assert_eq!(ok.session_id, "session-1");
         assert_eq!(ok.task_description, "Build feature X");
         assert_eq!(ok.api_key.as_deref(), Some("k"));
+        assert!(!ok.force_refresh);
 
         let legacy_err =
             serde_json::from_value::<RunRequirementAnalysisRequest>(serde_json::json!({
@@ -3371,6 +3514,7 @@ mod tests {
         assert_eq!(ok.session_id, "session-1");
         assert_eq!(ok.prd_json, "{}");
         assert_eq!(ok.project_path.as_deref(), Some("/tmp/project"));
+        assert!(!ok.force_refresh);
 
         let legacy_err =
             serde_json::from_value::<RunArchitectureReviewRequest>(serde_json::json!({
@@ -3400,7 +3544,7 @@ mod tests {
             },
         );
 
-        let parsed = build_agents_config_from_frontend(&configs, Some("claude-code"));
+        let parsed = build_agents_config_from_frontend(&configs, Some("claude-code"), None);
         let planning = parsed
             .phase_defaults
             .get("planning")
@@ -3430,7 +3574,7 @@ mod tests {
             },
         );
 
-        let parsed = build_agents_config_from_frontend(&configs, None);
+        let parsed = build_agents_config_from_frontend(&configs, None, None);
         let planning = parsed
             .phase_defaults
             .get("planning")
@@ -3441,6 +3585,27 @@ mod tests {
         assert!(parsed.agents.contains_key("claude-code"));
     }
 
+    #[test]
+    fn test_apply_agent_overrides_reports_mismatches() {
+        let mut resolver = AgentResolver::with_defaults();
+        let mut overrides = AgentOverrides::default();
+        overrides
+            .phase_overrides
+            .insert("planning".to_string(), "claude-haiku".to_string());
+        overrides
+            .phase_overrides
+            .insert("implementation".to_string(), "claude-sonnet".to_string());
+
+        let message = apply_agent_overrides(&mut resolver, &overrides).unwrap_err();
+        assert!(message.contains("claude-haiku"));
+        let applied = &resolver.config().overrides.phase_overrides;
+        assert!(!applied.contains_key("planning"));
+        assert_eq!(
+            applied.get("implementation").map(String::as_str),
+            Some("claude-sonnet")
+        );
+    }
+
     #[test]
     fn test_quality_dimension_score_serialization() {
         let score = QualityDimensionScore {
@@ -3647,6 +3812,9 @@ mod tests {
             story_statuses: HashMap::new(),
             stories_completed: 2,
             stories_failed: 0,
+            active_concurrency: 1,
+            retry_budget_remaining: Some(3),
+            exploration_cache: None,
         };
         let json = serde_json::to_string(&status).unwrap();
         assert!(json.contains("\"currentBatch\""));
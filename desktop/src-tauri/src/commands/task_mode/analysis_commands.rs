@@ -27,6 +27,7 @@ pub async fn run_requirement_analysis(
         locale,
         context_sources,
         project_path,
+        force_refresh,
     } = request;
 
     use crate::services::persona::{PersonaRegistry, PersonaRole};
@@ -58,6 +59,34 @@ pub async fn run_requirement_analysis(
         _ = operation_token.cancelled() => Ok(CommandResponse::err(TASK_OPERATION_CANCELLED_ERROR)),
         result = async {
 
+    // Reuse the cached analysis while the spec is unchanged
+    let project_path_str = project_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .unwrap_or(".")
+        .to_string();
+    let analysis_cache = state.analysis_cache();
+    let source_hash = AnalysisCache::source_hash(&[
+        ("task", Some(task_description.as_str())),
+        ("interview", interview_result.as_deref()),
+        ("context", exploration_context.as_deref()),
+        ("locale", Some(normalize_locale(locale.as_deref()))),
+    ]);
+    if !force_refresh {
+        let cached = analysis_cache.lookup::<RequirementAnalysisResult>(
+            AnalysisKind::RequirementAnalysis,
+            std::path::Path::new(&project_path_str),
+            &source_hash,
+        );
+        if let AnalysisCacheLookup::Hit(entry) = cached {
+            let info = entry.info(true);
+            let mut analysis = entry.result;
+            analysis.cache = Some(info);
+            return Ok(CommandResponse::ok(analysis));
+        }
+    }
+
     // Resolve provider/model
     let resolved_provider = match provider {
         Some(ref p) if !p.is_empty() => p.clone(),
@@ -124,12 +153,6 @@ pub async fn run_requirement_analysis(
     .await;
 
     // Query domain knowledge for requirement analysis (only if user enabled sources)
-    let project_path_str = project_path
-        .as_deref()
-        .map(str::trim)
-        .filter(|path| !path.is_empty())
-        .unwrap_or(".")
-        .to_string();
     let enriched = assemble_enriched_context_v2(
         app_state.inner(),
         knowledge_state.inner(),
@@ -231,7 +254,7 @@ Be specific and actionable. Reference concrete technical details when available.
     {
         Ok(result) => {
             let structured = &result.structured_output;
-            Ok(CommandResponse::ok(RequirementAnalysisResult {
+            let mut analysis = RequirementAnalysisResult {
                 analysis: result.expert_analysis,
                 key_requirements: structured
                     .get("key_requirements")
@@ -257,7 +280,18 @@ Be specific and actionable. Reference concrete technical details when available.
                     .unwrap_or("Not specified")
                     .to_string(),
                 persona_role: "ProductManager".to_string(),
-            }))
+                cache: None,
+            };
+            match analysis_cache.store(
+                AnalysisKind::RequirementAnalysis,
+                std::path::Path::new(&project_path_str),
+                &source_hash,
+                &analysis,
+            ) {
+                Ok(entry) => analysis.cache = Some(entry.info(false)),
+                Err(e) => tracing::warn!("Failed to cache requirement analysis: {}", e),
+            }
+            Ok(CommandResponse::ok(analysis))
         }
         Err(e) => Ok(CommandResponse::err(format!(
             "Requirement analysis failed: {}",
@@ -306,6 +340,7 @@ pub async fn run_architecture_review(
         locale,
         context_sources,
         project_path,
+        force_refresh,
     } = request;
 
     use crate::services::persona::{PersonaRegistry, PersonaRole};
@@ -337,6 +372,33 @@ pub async fn run_architecture_review(
         _ = operation_token.cancelled() => Ok(CommandResponse::err(TASK_OPERATION_CANCELLED_ERROR)),
         result = async {
 
+    // Reuse the cached review while the PRD is unchanged
+    let project_path_str = project_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|path| !path.is_empty())
+        .unwrap_or(".")
+        .to_string();
+    let analysis_cache = state.analysis_cache();
+    let source_hash = AnalysisCache::source_hash(&[
+        ("prd", Some(prd_json.as_str())),
+        ("context", exploration_context.as_deref()),
+        ("locale", Some(normalize_locale(locale.as_deref()))),
+    ]);
+    if !force_refresh {
+        let cached = analysis_cache.lookup::<ArchitectureReviewResult>(
+            AnalysisKind::ArchitectureReview,
+            std::path::Path::new(&project_path_str),
+            &source_hash,
+        );
+        if let AnalysisCacheLookup::Hit(entry) = cached {
+            let info = entry.info(true);
+            let mut review = entry.result;
+            review.cache = Some(info);
+            return Ok(CommandResponse::ok(review));
+        }
+    }
+
     // Resolve provider/model
     let resolved_provider = match provider {
         Some(ref p) if !p.is_empty() => p.clone(),
@@ -403,12 +465,6 @@ pub async fn run_architecture_review(
     .await;
 
     // Query domain knowledge for architecture review (only if user enabled sources)
-    let project_path_str = project_path
-        .as_deref()
-        .map(str::trim)
-        .filter(|path| !path.is_empty())
-        .unwrap_or(".")
-        .to_string();
     let enriched = assemble_enriched_context_v2(
         app_state.inner(),
         knowledge_state.inner(),
@@ -541,7 +597,7 @@ testing strategy, dependency management, and integration patterns.
     {
         Ok(result) => {
             let structured = &result.structured_output;
-            Ok(CommandResponse::ok(ArchitectureReviewResult {
+            let mut review = ArchitectureReviewResult {
                 analysis: result.expert_analysis,
                 concerns: structured
                     .get("concerns")
@@ -580,7 +636,18 @@ testing strategy, dependency management, and integration patterns.
                     .and_then(|v| v.as_bool())
                     .unwrap_or(false),
                 persona_role: "SoftwareArchitect".to_string(),
-            }))
+                cache: None,
+            };
+            match analysis_cache.store(
+                AnalysisKind::ArchitectureReview,
+                std::path::Path::new(&project_path_str),
+                &source_hash,
+                &review,
+            ) {
+                Ok(entry) => review.cache = Some(entry.info(false)),
+                Err(e) => tracing::warn!("Failed to cache architecture review: {}", e),
+            }
+            Ok(CommandResponse::ok(review))
         }
         Err(e) => Ok(CommandResponse::err(format!(
             "Architecture review failed: {}",
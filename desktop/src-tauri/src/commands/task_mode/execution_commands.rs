@@ -212,6 +212,8 @@ fn apply_progress_event_to_session(session: &mut TaskModeSession, event: &TaskMo
         total_stories,
         story_statuses: HashMap::new(),
         current_phase: "executing".to_string(),
+        active_concurrency: 0,
+        retry_budget_remaining: None,
     });
 
     progress.current_batch = event.current_batch;
@@ -278,6 +280,7 @@ pub async fn approve_task_prd(
         workflow_config,
         global_default_agent,
         phase_configs,
+        agent_overrides,
         locale,
         context_sources,
         project_path,
@@ -291,6 +294,7 @@ pub async fn approve_task_prd(
         workflow_config: workflow_config.clone(),
         global_default_agent: global_default_agent.clone(),
         phase_configs: phase_configs.clone(),
+        agent_overrides: agent_overrides.clone(),
         context_sources: context_sources.clone(),
         project_path: project_path.clone(),
     })
@@ -322,6 +326,24 @@ pub async fn approve_task_prd(
         return Ok(CommandResponse::err("PRD must contain at least one story"));
     }
 
+    // Resolve agents up front so rejected overrides are reported to the user
+    // instead of only being skipped at resolve time.
+    let mut resolver = match &phase_configs {
+        Some(configs) if !configs.is_empty() => {
+            AgentResolver::new(build_agents_config_from_frontend(
+                configs,
+                global_default_agent.as_deref(),
+                agent_overrides.as_ref(),
+            ))
+        }
+        _ => AgentResolver::with_defaults(),
+    };
+    if let Some(ref overrides) = agent_overrides {
+        if let Err(message) = apply_agent_overrides(&mut resolver, overrides) {
+            return Ok(CommandResponse::err(message));
+        }
+    }
+
     // Calculate batches
     let stories: Vec<ExecutableStory> = prd
         .stories
@@ -349,6 +371,8 @@ pub async fn approve_task_prd(
         if let Some(max_p) = wc.max_parallel {
             config.max_parallel = max_p;
         }
+        config.max_concurrent_stories = wc.max_concurrent_stories;
+        config.retry_budget = wc.retry_budget;
         config.skip_verification = wc.skip_verification;
         config.skip_review = wc.skip_review;
     }
@@ -631,16 +655,6 @@ pub async fn approve_task_prd(
                     } else {
                         executor
                     };
-                    let resolver = match &phase_configs {
-                        Some(configs) if !configs.is_empty() => {
-                            AgentResolver::new(build_agents_config_from_frontend(
-                                configs,
-                                global_default_agent.as_deref(),
-                            ))
-                        }
-                        _ => AgentResolver::with_defaults(),
-                    };
-
                     // Create emit callback that sends events via Tauri AppHandle
                     let app_for_emit = app_handle_for_worker.clone();
                     let kernel_for_emit = kernel_state_handle_for_worker.clone();
@@ -651,8 +665,11 @@ pub async fn approve_task_prd(
                     let sessions_for_emit = sessions_arc_for_worker.clone();
                     let state_for_emit = state_for_persist_for_worker.clone();
                     let story_titles_for_emit = story_titles_for_worker.clone();
+                    let limits_for_emit = executor.limits();
                     let emit = move |event: TaskModeProgressEvent| {
                         let _ = app_for_emit.emit(TASK_MODE_EVENT_CHANNEL, &event);
+                        let active_concurrency = limits_for_emit.active_concurrency();
+                        let retry_budget_remaining = limits_for_emit.retry_budget_remaining();
 
                         match event.event_type.as_str() {
                             "story_started" => {
@@ -710,6 +727,10 @@ pub async fn approve_task_prd(
                                 let mut sessions = sessions_for_emit.write().await;
                                 if let Some(session) = sessions.get_mut(&sid_for_emit) {
                                     apply_progress_event_to_session(session, &event_for_persist);
+                                    if let Some(progress) = session.progress.as_mut() {
+                                        progress.active_concurrency = active_concurrency;
+                                        progress.retry_budget_remaining = retry_budget_remaining;
+                                    }
                                     let snapshot = session.clone();
                                     drop(sessions);
                                     persist_task_session_best_effort(
@@ -978,6 +999,8 @@ pub async fn get_task_execution_status(
         total_stories: session.prd.as_ref().map(|p| p.stories.len()).unwrap_or(0),
         story_statuses: HashMap::new(),
         current_phase: "idle".to_string(),
+        active_concurrency: 0,
+        retry_budget_remaining: None,
     });
 
     Ok(CommandResponse::ok(TaskExecutionStatus {
@@ -988,6 +1011,9 @@ pub async fn get_task_execution_status(
         story_statuses: progress.story_statuses,
         stories_completed: progress.stories_completed,
         stories_failed: progress.stories_failed,
+        active_concurrency: progress.active_concurrency,
+        retry_budget_remaining: progress.retry_budget_remaining,
+        exploration_cache: session.exploration_cache.clone(),
     }))
 }
 
@@ -1375,6 +1401,8 @@ pub async fn get_task_execution_report(
         total_stories: 0,
         story_statuses: HashMap::new(),
         current_phase: "complete".to_string(),
+        active_concurrency: 0,
+        retry_budget_remaining: None,
     });
 
     Ok(CommandResponse::ok(ExecutionReport {
@@ -1,4 +1,8 @@
 use super::*;
+use crate::services::task_mode::exploration_cache::{
+    build_incremental_exploration_message, CacheLookup, ExplorationCacheOutcome, RepoState,
+    MAX_INCREMENTAL_CHANGED_FILES,
+};
 use crate::services::workflow_kernel::observability::{self, WorkflowFailureRecordInput};
 
 /// Generate a task PRD from the session description using an LLM provider.
@@ -34,13 +38,14 @@ pub async fn generate_task_prd(
         locale,
         context_sources,
         project_path,
+        previous_prd,
     } = request;
 
     // Validate and extract session
-    let (description, status) = {
+    let (description, status, session_prd) = {
         let sessions = state.sessions.read().await;
         match sessions.get(&session_id) {
-            Some(s) => (s.description.clone(), s.status.clone()),
+            Some(s) => (s.description.clone(), s.status.clone(), s.prd.clone()),
             None => {
                 return Ok(CommandResponse::err(
                     "Invalid session ID or no active session",
@@ -49,12 +54,25 @@ pub async fn generate_task_prd(
         }
     };
 
-    if status != TaskModeStatus::Initialized {
+    if status != TaskModeStatus::Initialized && status != TaskModeStatus::ReviewingPrd {
         return Ok(CommandResponse::err(format!(
             "Cannot generate PRD in {:?} status",
             status
         )));
     }
+    // Regenerating during review keeps the locked parts of the current PRD.
+    // On failure or cancellation the session returns to the status it had.
+    let previous_prd = if status == TaskModeStatus::ReviewingPrd {
+        previous_prd.or(session_prd)
+    } else {
+        None
+    };
+    let restore_status = status;
+    let summary_kind = if previous_prd.is_some() {
+        "revised"
+    } else {
+        "initial"
+    };
 
     // Update status to GeneratingPrd
     {
@@ -89,7 +107,16 @@ pub async fn generate_task_prd(
         result = async {
             // If compiled_spec is provided (from interview pipeline), convert directly
             if let Some(spec_value) = compiled_spec {
-                match prd_generator::convert_compiled_prd_to_task_prd(spec_value) {
+                let converted = prd_generator::convert_compiled_prd_to_task_prd(spec_value)
+                    .and_then(|prd| match previous_prd.as_ref() {
+                        Some(previous) => prd_generator::merge_regenerated_prd(
+                            previous,
+                            prd,
+                            prd_generator::DEFAULT_MAX_PARALLEL,
+                        ),
+                        None => Ok(prd),
+                    });
+                match converted {
                     Ok(prd) => {
                         let mut updated_session: Option<TaskModeSession> = None;
                         let mut sessions = state.sessions.write().await;
@@ -125,7 +152,7 @@ pub async fn generate_task_prd(
                                 super::publish_task_handoff_summary(
                                     kernel_state.inner(),
                                     snapshot.kernel_session_id.as_deref(),
-                                    super::build_task_prd_summary_item(snapshot, prd, "initial"),
+                                    super::build_task_prd_summary_item(snapshot, prd, summary_kind),
                                 )
                                 .await;
                             }
@@ -190,7 +217,7 @@ pub async fn generate_task_prd(
                     let mut updated_session: Option<TaskModeSession> = None;
                     let mut sessions = state.sessions.write().await;
                     if let Some(s) = sessions.get_mut(&session_id) {
-                        s.status = TaskModeStatus::Initialized;
+                        s.status = restore_status.clone();
                         updated_session = Some(s.clone());
                     }
                     drop(sessions);
@@ -311,6 +338,15 @@ pub async fn generate_task_prd(
                 (base, None) => base,
             };
 
+            let combined_context = match (
+                combined_context,
+                previous_prd.as_ref().and_then(prd_generator::build_locked_prd_context),
+            ) {
+                (Some(base), Some(locked)) => Some(format!("{}\n\n{}", base, locked)),
+                (None, Some(locked)) => Some(locked),
+                (base, None) => base,
+            };
+
             let prd = match prd_generator::generate_prd_with_llm(
                 llm_provider,
                 &description,
@@ -319,14 +355,21 @@ pub async fn generate_task_prd(
                 combined_context.as_deref(),
             )
             .await
-            {
+            .and_then(|prd| match previous_prd.as_ref() {
+                Some(previous) => prd_generator::merge_regenerated_prd(
+                    previous,
+                    prd,
+                    prd_generator::DEFAULT_MAX_PARALLEL,
+                ),
+                None => Ok(prd),
+            }) {
                 Ok(prd) => prd,
                 Err(e) => {
                     // Reset status back to Initialized on failure
                     let mut updated_session: Option<TaskModeSession> = None;
                     let mut sessions = state.sessions.write().await;
                     if let Some(s) = sessions.get_mut(&session_id) {
-                        s.status = TaskModeStatus::Initialized;
+                        s.status = restore_status.clone();
                         updated_session = Some(s.clone());
                     }
                     drop(sessions);
@@ -384,7 +427,7 @@ pub async fn generate_task_prd(
                         super::publish_task_handoff_summary(
                             kernel_state.inner(),
                             snapshot.kernel_session_id.as_deref(),
-                            super::build_task_prd_summary_item(snapshot, prd, "initial"),
+                            super::build_task_prd_summary_item(snapshot, prd, summary_kind),
                         )
                         .await;
                     }
@@ -402,7 +445,7 @@ pub async fn generate_task_prd(
         let mut sessions = state.sessions.write().await;
         if let Some(session) = sessions.get_mut(&session_id) {
             if session.status == TaskModeStatus::GeneratingPrd {
-                session.status = TaskModeStatus::Initialized;
+                session.status = restore_status.clone();
                 updated_session = Some(session.clone());
             }
         }
@@ -583,6 +626,7 @@ fn parse_feedback_prd_payload(
                     .map(|criterion| criterion.trim().to_string())
                     .filter(|criterion| !criterion.is_empty())
                     .collect(),
+                locked: false,
             })
         })
         .collect::<Result<Vec<_>, String>>()?;
@@ -633,6 +677,7 @@ fn parse_feedback_prd_payload(
                 .unwrap_or_else(|| fallback_description.to_string()),
             stories,
             batches,
+            locked_sections: Vec::new(),
         },
         payload.warnings,
     ))
@@ -1009,6 +1054,7 @@ Rules:
 4. Do not create self dependencies or circular dependencies.
 5. Every story must include at least one acceptance criterion.
 6. Use priority values: high|medium|low.
+7. Stories marked "locked": true and the sections listed in "lockedSections" are final; return them unchanged.
 
 Current PRD JSON:
 {}
@@ -1137,6 +1183,19 @@ Output language:
                 Ok(parsed) => parsed,
                 Err(error) => return CommandResponse::err(error),
             };
+            // Locked parts survive feedback even if the LLM rewrote them.
+            let updated_prd = if current_prd.has_locks() {
+                match prd_generator::merge_regenerated_prd(
+                    &current_prd,
+                    updated_prd,
+                    max_parallel,
+                ) {
+                    Ok(merged) => merged,
+                    Err(error) => return CommandResponse::err(error),
+                }
+            } else {
+                updated_prd
+            };
 
             let summary = build_prd_feedback_summary(&current_prd, &updated_prd, warnings);
 
@@ -1238,6 +1297,10 @@ Output language:
 ///
 /// Exploration failure is non-blocking — returns a warning-level result and the workflow
 /// continues to PRD generation.
+///
+/// Results are cached per repo state (git HEAD + dirty files). An unchanged repo reuses
+/// the cached result; after changes, full flow re-explores only the changed files on top
+/// of the previous summary.
 #[tauri::command]
 pub async fn explore_project(
     request: ExploreProjectRequest,
@@ -1339,6 +1402,7 @@ pub async fn explore_project(
             if let Some(s) = sessions.get_mut(&session_id) {
                 s.status = TaskModeStatus::Initialized;
                 s.exploration_result = Some(result.clone());
+                s.exploration_cache = None;
                 updated_session = Some(s.clone());
             } else {
                 return Ok(CommandResponse::err(
@@ -1367,6 +1431,86 @@ pub async fn explore_project(
         wd.clone()
     };
 
+    // --- Exploration cache ---
+    let cache = state.exploration_cache().clone();
+    let cache_key = ExplorationCache::cache_key(&project_path, &flow_level, &task_description);
+    let (repo_state, cache_lookup) = {
+        let cache = cache.clone();
+        let cache_key = cache_key.clone();
+        let project_path = project_path.clone();
+        tokio::task::spawn_blocking(move || {
+            let repo_state = RepoState::capture(&project_path);
+            let lookup = match repo_state.as_ref() {
+                Some(repo_state) => cache.lookup(&cache_key, &project_path, repo_state),
+                None => CacheLookup::Miss,
+            };
+            (repo_state, lookup)
+        })
+        .await
+        .unwrap_or((None, CacheLookup::Miss))
+    };
+
+    // Previous summary and changed files when re-exploring incrementally
+    let incremental: Option<(String, Vec<String>)> = match cache_lookup {
+        CacheLookup::Hit(entry) => {
+            let cache_status = ExplorationCacheStatus {
+                outcome: ExplorationCacheOutcome::Reused,
+                signature: Some(entry.signature),
+                changed_files: vec![],
+                cached_at: Some(entry.cached_at),
+            };
+            let result = entry.result;
+            {
+                let mut updated_session: Option<TaskModeSession> = None;
+                let mut sessions = state.sessions.write().await;
+                if let Some(s) = sessions.get_mut(&session_id) {
+                    s.status = TaskModeStatus::Initialized;
+                    s.exploration_result = Some(result.clone());
+                    s.exploration_cache = Some(cache_status);
+                    updated_session = Some(s.clone());
+                } else {
+                    return Ok(CommandResponse::err(
+                        "Invalid session ID or no active session",
+                    ));
+                }
+                drop(sessions);
+                if let Some(snapshot) = updated_session.as_ref() {
+                    persist_task_session_best_effort(&state, snapshot, "explore_project.cache_reused").await;
+                    sync_kernel_task_snapshot_and_emit(
+                        &app_handle,
+                        kernel_state.inner(),
+                        snapshot,
+                        None,
+                        "task_mode.explore_project.cache_reused",
+                    )
+                    .await;
+                }
+            }
+            let _ = app_handle.emit(
+                "exploration-progress",
+                serde_json::json!({
+                    "sessionId": session_id,
+                    "phase": "complete",
+                    "durationMs": result.duration_ms,
+                    "cached": true,
+                }),
+            );
+            return Ok(CommandResponse::ok(result));
+        }
+        CacheLookup::Stale {
+            previous,
+            changed_files: Some(files),
+        } if flow_level == "full"
+            && !files.is_empty()
+            && files.len() <= MAX_INCREMENTAL_CHANGED_FILES =>
+        {
+            previous.result.llm_summary.map(|summary| (summary, files))
+        }
+        _ => None,
+    };
+    // Degraded results (LLM exploration failed) are not cached.
+    let mut cacheable = true;
+
     // --- Deterministic exploration ---
     let deterministic_result = {
         // Try to get IndexStore from standalone_state
@@ -1653,15 +1797,18 @@ pub async fn explore_project(
                 });
 
                 // Run the coordinator agentic loop
-                let coordinator_result = coordinator
-                    .execute(
-                        format!(
-                            "Explore this project's codebase to gather context for the following task:\n\n{}",
-                            task_description
-                        ),
-                        tx,
-                    )
-                    .await;
+                let exploration_message = match incremental.as_ref() {
+                    Some((previous_summary, changed_files)) => build_incremental_exploration_message(
+                        &task_description,
+                        previous_summary,
+                        changed_files,
+                    ),
+                    None => format!(
+                        "Explore this project's codebase to gather context for the following task:\n\n{}",
+                        task_description
+                    ),
+                };
+                let coordinator_result = coordinator.execute(exploration_message, tx).await;
 
                 result.used_llm_exploration = true;
 
@@ -1674,6 +1821,9 @@ pub async fn explore_project(
                     .map(exploration::is_summary_incomplete)
                     .unwrap_or(true);
                 let has_error = coordinator_result.error.is_some() || !coordinator_result.success;
+                if has_error {
+                    cacheable = false;
+                }
 
                 if let Some(summary) = parsed_summary {
                     if has_error || short_or_incomplete {
@@ -1745,6 +1895,7 @@ pub async fn explore_project(
                     e
                 );
                 // Non-blocking: continue with deterministic-only result
+                cacheable = false;
             }
         }
     }
@@ -1752,6 +1903,32 @@ pub async fn explore_project(
     // Update duration
     result.duration_ms = start.elapsed().as_millis() as u64;
 
+    let uncached = ExplorationCacheStatus {
+        outcome: ExplorationCacheOutcome::Uncached,
+        signature: None,
+        changed_files: vec![],
+        cached_at: None,
+    };
+    let cache_status = match repo_state.as_ref() {
+        Some(repo_state) if cacheable => match cache.store(&cache_key, repo_state, &result) {
+            Ok(entry) => ExplorationCacheStatus {
+                outcome: if incremental.is_some() {
+                    ExplorationCacheOutcome::Incremental
+                } else {
+                    ExplorationCacheOutcome::Refreshed
+                },
+                signature: Some(entry.signature),
+                changed_files: incremental.map(|(_, files)| files).unwrap_or_default(),
+                cached_at: Some(entry.cached_at),
+            },
+            Err(e) => {
+                eprintln!("[explore_project] Failed to cache exploration result: {}", e);
+                uncached
+            }
+        },
+        _ => uncached,
+    };
+
     // Store result and reset status
     {
         let mut updated_session: Option<TaskModeSession> = None;
@@ -1759,6 +1936,7 @@ pub async fn explore_project(
         if let Some(s) = sessions.get_mut(&session_id) {
             s.status = TaskModeStatus::Initialized;
             s.exploration_result = Some(result.clone());
+            s.exploration_cache = Some(cache_status);
             updated_session = Some(s.clone());
         } else {
             return Ok(CommandResponse::err(
@@ -1837,6 +2015,7 @@ mod tests {
                     priority: "high".to_string(),
                     dependencies: vec![],
                     acceptance_criteria: vec!["done".to_string()],
+                    locked: false,
                 },
                 TaskStory {
                     id: "story-002".to_string(),
@@ -1845,6 +2024,7 @@ mod tests {
                     priority: "medium".to_string(),
                     dependencies: vec!["story-001".to_string()],
                     acceptance_criteria: vec!["done".to_string()],
+                    locked: false,
                 },
             ],
             batches: vec![
@@ -1857,6 +2037,7 @@ mod tests {
                     story_ids: vec!["story-002".to_string()],
                 },
             ],
+            locked_sections: Vec::new(),
         }
     }
 
@@ -1924,6 +2105,7 @@ mod tests {
             priority: "low".to_string(),
             dependencies: vec!["story-002".to_string()],
             acceptance_criteria: vec!["done".to_string()],
+            locked: false,
         });
         next.batches
             .push(crate::services::task_mode::batch_executor::ExecutionBatch {
@@ -90,6 +90,7 @@ pub async fn enter_task_mode(
         confirmed_config: None,
         prd: None,
         exploration_result: None,
+        exploration_cache: None,
         progress: None,
         execution_resume_payload: None,
         cancel_requested: false,
@@ -967,6 +967,7 @@ pub async fn workflow_resume_background_runs(
                                 workflow_config: payload.workflow_config,
                                 global_default_agent: payload.global_default_agent,
                                 phase_configs: payload.phase_configs,
+                                agent_overrides: payload.agent_overrides,
                                 locale: task_session.locale.clone(),
                                 context_sources: payload.context_sources,
                                 project_path: payload.project_path,
@@ -2003,6 +2004,7 @@ mod tests {
             confirmed_config: None,
             prd: None,
             exploration_result: None,
+            exploration_cache: None,
             progress: Some(BatchExecutionProgress {
                 current_batch: 1,
                 total_batches: 1,
@@ -2010,6 +2012,8 @@ mod tests {
                 stories_failed: 0,
                 total_stories: 3,
                 current_phase: "executing".to_string(),
+                active_concurrency: 0,
+                retry_budget_remaining: None,
                 story_statuses: HashMap::from([
                     ("S001".to_string(), "completed".to_string()),
                     ("S002".to_string(), "running".to_string()),
@@ -2,6 +2,7 @@
 //!
 //! Tauri commands for Git worktree management.
 
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -10,9 +11,10 @@ use crate::commands::workflow::{emit_kernel_update_for_session, emit_session_cat
 use crate::models::response::CommandResponse;
 use crate::models::worktree::{
     CompleteWorktreeResult, CreateManagedWorktreeRequest, CreatePullRequestRequest,
-    CreatePullRequestResult, CreateWorktreeRequest, ForgeProvider, PreparePullRequestResult,
-    SessionWorktreeRuntimeBinding, Worktree, WorktreeCleanupPolicy, WorktreeRuntimeKind,
-    WorktreeStatus,
+    CreatePullRequestResult, CreateWorktreeRequest, ForgeProvider, MergeStrategy,
+    MergeToBaseOptions, MergeToBaseResult, OrphanWorktree, PreparePullRequestResult,
+    SessionWorktreeRuntimeBinding, Worktree, WorktreeAutoStash, WorktreeCleanupPolicy,
+    WorktreeDiskUsage, WorktreeQuota, WorktreeRuntimeKind, WorktreeStatus, WorktreeStatusReport,
 };
 use crate::services::workflow_kernel::{
     HandoffContextBundle, SessionRuntimeInfo, WorkflowKernelState, WorkflowMode,
@@ -21,6 +23,9 @@ use crate::services::workflow_kernel::{
 use crate::services::worktree::WorktreeManager;
 use crate::state::AppState;
 
+/// Settings key holding the worktree disk quota (JSON).
+const WORKTREE_QUOTA_SETTING: &str = "worktree_quota";
+
 /// State for the worktree service
 pub struct WorktreeState {
     pub(crate) manager: Arc<RwLock<WorktreeManager>>,
@@ -41,6 +46,20 @@ impl WorktreeState {
             manager: Arc::new(RwLock::new(WorktreeManager::new())),
         }
     }
+
+    /// Restore the worktree disk quota saved by `set_worktree_quota`.
+    pub async fn load_quota(&self, app_state: &AppState) -> Result<(), String> {
+        let saved = app_state
+            .with_database(|db| db.get_setting(WORKTREE_QUOTA_SETTING))
+            .await
+            .map_err(|e| e.to_string())?;
+        let quota = saved
+            .map(|json| serde_json::from_str::<WorktreeQuota>(&json))
+            .transpose()
+            .map_err(|e| e.to_string())?;
+        self.manager.write().await.set_quota(quota);
+        Ok(())
+    }
 }
 
 impl Default for WorktreeState {
@@ -226,13 +245,13 @@ pub async fn get_worktree(
     }
 }
 
-/// Get the status of a worktree
+/// Get the status and disk size of a worktree
 #[tauri::command]
 pub async fn get_worktree_status(
     state: tauri::State<'_, WorktreeState>,
     repo_path: String,
     worktree_id: String,
-) -> Result<CommandResponse<WorktreeStatus>, String> {
+) -> Result<CommandResponse<WorktreeStatusReport>, String> {
     let manager = state.manager.read().await;
     let repo = PathBuf::from(&repo_path);
 
@@ -282,6 +301,174 @@ pub async fn complete_worktree(
     }
 }
 
+/// Merge (or rebase) a worktree branch back into its base branch
+#[tauri::command]
+pub async fn merge_worktree_to_base(
+    state: tauri::State<'_, WorktreeState>,
+    repo_path: String,
+    worktree_id: String,
+    strategy: Option<MergeStrategy>,
+    options: Option<MergeToBaseOptions>,
+) -> Result<CommandResponse<MergeToBaseResult>, String> {
+    let manager = state.manager.read().await;
+    match manager
+        .merge_to_base(
+            &PathBuf::from(&repo_path),
+            &worktree_id,
+            strategy.unwrap_or_default(),
+            options.unwrap_or_default(),
+        )
+        .await
+    {
+        Ok(result) => Ok(CommandResponse::ok(result)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Check out a branch in a worktree, auto-stashing uncommitted changes first
+#[tauri::command]
+pub async fn switch_worktree_branch(
+    state: tauri::State<'_, WorktreeState>,
+    repo_path: String,
+    worktree_id: String,
+    branch: String,
+) -> Result<CommandResponse<Option<WorktreeAutoStash>>, String> {
+    let manager = state.manager.read().await;
+    match manager
+        .switch_worktree_branch(&PathBuf::from(&repo_path), &worktree_id, &branch)
+        .await
+    {
+        Ok(stash) => Ok(CommandResponse::ok(stash)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// List auto-stashes for a repository, optionally for a single worktree
+#[tauri::command]
+pub async fn list_worktree_auto_stashes(
+    state: tauri::State<'_, WorktreeState>,
+    repo_path: String,
+    worktree_id: Option<String>,
+) -> Result<CommandResponse<Vec<WorktreeAutoStash>>, String> {
+    let manager = state.manager.read().await;
+    match manager
+        .list_auto_stashes(&PathBuf::from(&repo_path), worktree_id.as_deref())
+        .await
+    {
+        Ok(stashes) => Ok(CommandResponse::ok(stashes)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Re-apply an auto-stash into a worktree
+#[tauri::command]
+pub async fn restore_worktree_auto_stash(
+    state: tauri::State<'_, WorktreeState>,
+    repo_path: String,
+    worktree_id: String,
+    stash_commit: String,
+) -> Result<CommandResponse<()>, String> {
+    let manager = state.manager.read().await;
+    match manager
+        .restore_auto_stash(&PathBuf::from(&repo_path), &worktree_id, &stash_commit)
+        .await
+    {
+        Ok(()) => Ok(CommandResponse::ok(())),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Discard an auto-stash
+#[tauri::command]
+pub async fn drop_worktree_auto_stash(
+    state: tauri::State<'_, WorktreeState>,
+    repo_path: String,
+    stash_commit: String,
+) -> Result<CommandResponse<()>, String> {
+    let manager = state.manager.read().await;
+    match manager
+        .drop_auto_stash(&PathBuf::from(&repo_path), &stash_commit)
+        .await
+    {
+        Ok(()) => Ok(CommandResponse::ok(())),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Get total worktree disk usage for a repository against the quota
+#[tauri::command]
+pub async fn get_worktree_disk_usage(
+    state: tauri::State<'_, WorktreeState>,
+    repo_path: String,
+) -> Result<CommandResponse<WorktreeDiskUsage>, String> {
+    let manager = state.manager.read().await;
+    match manager.disk_usage(&PathBuf::from(&repo_path)).await {
+        Ok(usage) => Ok(CommandResponse::ok(usage)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Set (or clear, with `null`) the worktree disk quota
+#[tauri::command]
+pub async fn set_worktree_quota(
+    state: tauri::State<'_, WorktreeState>,
+    app_state: tauri::State<'_, AppState>,
+    quota: Option<WorktreeQuota>,
+) -> Result<CommandResponse<()>, String> {
+    let persisted = app_state
+        .with_database(|db| match quota {
+            Some(quota) => db.set_setting(WORKTREE_QUOTA_SETTING, &serde_json::to_string(&quota)?),
+            None => db.delete_setting(WORKTREE_QUOTA_SETTING),
+        })
+        .await;
+    if let Err(e) = persisted {
+        return Ok(CommandResponse::err(e.to_string()));
+    }
+    state.manager.write().await.set_quota(quota);
+    Ok(CommandResponse::ok(()))
+}
+
+/// Find worktrees whose branch was deleted or whose session no longer exists
+#[tauri::command]
+pub async fn detect_orphan_worktrees(
+    state: tauri::State<'_, WorktreeState>,
+    kernel_state: tauri::State<'_, WorkflowKernelState>,
+    repo_path: String,
+) -> Result<CommandResponse<Vec<OrphanWorktree>>, String> {
+    let live_sessions: HashSet<String> = match kernel_state.list_sessions().await {
+        Ok(sessions) => sessions
+            .into_iter()
+            .map(|session| session.session_id)
+            .collect(),
+        Err(error) => return Ok(CommandResponse::err(error)),
+    };
+    let manager = state.manager.read().await;
+    match manager
+        .detect_orphan_worktrees(&PathBuf::from(&repo_path), Some(&live_sessions))
+        .await
+    {
+        Ok(orphans) => Ok(CommandResponse::ok(orphans)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
+/// Remove orphaned worktrees, returning the IDs that were removed
+#[tauri::command]
+pub async fn cleanup_orphan_worktrees(
+    state: tauri::State<'_, WorktreeState>,
+    repo_path: String,
+    worktree_ids: Vec<String>,
+) -> Result<CommandResponse<Vec<String>>, String> {
+    let manager = state.manager.read().await;
+    match manager
+        .cleanup_orphan_worktrees(&PathBuf::from(&repo_path), &worktree_ids)
+        .await
+    {
+        Ok(removed) => Ok(CommandResponse::ok(removed)),
+        Err(e) => Ok(CommandResponse::err(e.to_string())),
+    }
+}
+
 #[tauri::command]
 pub async fn workflow_create_isolated_session(
     initial_mode: Option<WorkflowMode>,
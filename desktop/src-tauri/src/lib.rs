@@ -22,6 +22,7 @@ pub use commands::{
     add_mcp_server,
     // Memory commands
     add_project_memory,
+    aggregate_guardrail_events,
     artifact_delete,
     artifact_list,
     artifact_load,
@@ -116,6 +117,7 @@ pub use commands::{
     get_enrichment_report,
     get_evaluation_reports,
     get_graph_workflow,
+    get_guardrail_retention,
     // Health commands
     get_health,
     get_interview_state,
@@ -253,6 +255,7 @@ pub use commands::{
     list_webhook_channels,
     list_worktrees,
     preview_install_mcp_catalog_item,
+    preview_resume_task,
     prune_agent_runs,
     rag_delete_collection,
     rag_get_observability_metrics,
@@ -296,6 +299,8 @@ pub use commands::{
     set_embedding_api_key,
     set_embedding_config,
     set_guardrail_mode,
+    set_guardrail_retention,
+    set_guardrail_scope,
     set_knowledge_feature_flags,
     set_lsp_preferences,
     set_working_directory,
@@ -304,6 +309,7 @@ pub use commands::{
     start_spec_interview,
     submit_interview_answer,
     switch_branch,
+    test_custom_rule,
     test_mcp_server,
     test_webhook_channel,
     toggle_generated_skill,
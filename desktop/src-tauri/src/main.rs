@@ -79,10 +79,14 @@ fn main() {
             plan_cascade_desktop::commands::settings::get_knowledge_feature_flags,
             plan_cascade_desktop::commands::settings::update_settings,
             plan_cascade_desktop::commands::settings::set_knowledge_feature_flags,
+            plan_cascade_desktop::commands::settings::get_bash_env_allowlist,
+            plan_cascade_desktop::commands::settings::set_bash_env_allowlist,
             plan_cascade_desktop::commands::settings::reset_all_settings,
             plan_cascade_desktop::commands::settings::clear_all_data,
             plan_cascade_desktop::commands::settings::export_all_settings,
             plan_cascade_desktop::commands::settings::import_all_settings,
+            plan_cascade_desktop::commands::settings::export_settings,
+            plan_cascade_desktop::commands::settings::import_settings,
             // Project commands
             plan_cascade_desktop::commands::projects::list_projects,
             plan_cascade_desktop::commands::projects::get_project,
@@ -91,6 +95,7 @@ fn main() {
             plan_cascade_desktop::commands::sessions::list_sessions,
             plan_cascade_desktop::commands::sessions::get_session,
             plan_cascade_desktop::commands::sessions::resume_session,
+            plan_cascade_desktop::commands::sessions::fork_session,
             plan_cascade_desktop::commands::sessions::search_sessions,
             // MCP commands
             plan_cascade_desktop::commands::mcp::list_mcp_servers,
@@ -147,6 +152,7 @@ fn main() {
             plan_cascade_desktop::commands::markdown::save_claude_md,
             plan_cascade_desktop::commands::markdown::create_claude_md,
             plan_cascade_desktop::commands::markdown::get_claude_md_metadata,
+            plan_cascade_desktop::commands::markdown::resolve_effective_claude_md,
             // Analytics commands
             plan_cascade_desktop::commands::analytics::init_analytics,
             plan_cascade_desktop::commands::analytics::list_usage_events,
@@ -157,8 +163,18 @@ fn main() {
             plan_cascade_desktop::commands::analytics::list_usage_records_v2,
             plan_cascade_desktop::commands::analytics::count_usage_records_v2,
             plan_cascade_desktop::commands::analytics::get_dashboard_summary_v2,
+            plan_cascade_desktop::commands::analytics::get_usage_time_series,
+            plan_cascade_desktop::commands::analytics::aggregate_by_session,
+            plan_cascade_desktop::commands::analytics::aggregate_by_agent,
+            plan_cascade_desktop::commands::analytics::get_cost_projection,
+            plan_cascade_desktop::commands::analytics::list_cost_budgets,
+            plan_cascade_desktop::commands::analytics::upsert_cost_budget,
+            plan_cascade_desktop::commands::analytics::delete_cost_budget,
+            plan_cascade_desktop::commands::analytics::check_budget_alerts,
             plan_cascade_desktop::commands::analytics::list_pricing_rules,
             plan_cascade_desktop::commands::analytics::upsert_pricing_rule,
+            plan_cascade_desktop::commands::analytics::import_pricing_rules,
+            plan_cascade_desktop::commands::analytics::export_pricing_rules,
             plan_cascade_desktop::commands::analytics::delete_pricing_rule,
             plan_cascade_desktop::commands::analytics::export_usage_streaming_job,
             plan_cascade_desktop::commands::analytics::recompute_costs,
@@ -185,6 +201,15 @@ fn main() {
             plan_cascade_desktop::commands::worktree::get_worktree_status,
             plan_cascade_desktop::commands::worktree::remove_worktree,
             plan_cascade_desktop::commands::worktree::complete_worktree,
+            plan_cascade_desktop::commands::worktree::get_worktree_disk_usage,
+            plan_cascade_desktop::commands::worktree::set_worktree_quota,
+            plan_cascade_desktop::commands::worktree::detect_orphan_worktrees,
+            plan_cascade_desktop::commands::worktree::cleanup_orphan_worktrees,
+            plan_cascade_desktop::commands::worktree::merge_worktree_to_base,
+            plan_cascade_desktop::commands::worktree::switch_worktree_branch,
+            plan_cascade_desktop::commands::worktree::list_worktree_auto_stashes,
+            plan_cascade_desktop::commands::worktree::restore_worktree_auto_stash,
+            plan_cascade_desktop::commands::worktree::drop_worktree_auto_stash,
             plan_cascade_desktop::commands::worktree::workflow_create_isolated_session,
             plan_cascade_desktop::commands::worktree::workflow_move_session_to_worktree,
             plan_cascade_desktop::commands::worktree::workflow_attach_session_worktree,
@@ -201,12 +226,15 @@ fn main() {
             plan_cascade_desktop::commands::standalone::get_provider_api_key,
             plan_cascade_desktop::commands::standalone::configure_provider,
             plan_cascade_desktop::commands::standalone::check_provider_health,
+            plan_cascade_desktop::commands::standalone::pull_ollama_model,
             plan_cascade_desktop::commands::standalone::execute_standalone,
             plan_cascade_desktop::commands::standalone::save_output_export,
             plan_cascade_desktop::commands::standalone::save_binary_export,
             plan_cascade_desktop::commands::standalone::get_usage_stats,
             // Session-based standalone commands
             plan_cascade_desktop::commands::standalone::execute_standalone_with_session,
+            plan_cascade_desktop::commands::standalone::execute_standalone_batch,
+            plan_cascade_desktop::commands::standalone::cancel_standalone_batch,
             plan_cascade_desktop::commands::standalone::cancel_standalone_execution,
             plan_cascade_desktop::commands::standalone::pause_standalone_execution,
             plan_cascade_desktop::commands::standalone::unpause_standalone_execution,
@@ -221,6 +249,7 @@ fn main() {
             plan_cascade_desktop::commands::standalone::set_working_directory,
             plan_cascade_desktop::commands::standalone::get_index_status,
             plan_cascade_desktop::commands::standalone::trigger_reindex,
+            plan_cascade_desktop::commands::standalone::migrate_embedding_index,
             plan_cascade_desktop::commands::standalone::semantic_search,
             // Codebase Index Management commands
             plan_cascade_desktop::commands::codebase::codebase_list_projects,
@@ -327,6 +356,7 @@ fn main() {
             plan_cascade_desktop::commands::recovery::detect_incomplete_tasks,
             plan_cascade_desktop::commands::recovery::resume_task,
             plan_cascade_desktop::commands::recovery::discard_task,
+            plan_cascade_desktop::commands::recovery::preview_resume_task,
             // Design Document commands
             plan_cascade_desktop::commands::design::generate_design_doc,
             plan_cascade_desktop::commands::design::import_design_doc,
@@ -381,6 +411,13 @@ fn main() {
             plan_cascade_desktop::commands::execution_history::rename_execution_history,
             plan_cascade_desktop::commands::execution_history::delete_execution_history,
             plan_cascade_desktop::commands::execution_history::clear_execution_history,
+            plan_cascade_desktop::commands::execution_history::export_session_transcript,
+            plan_cascade_desktop::commands::execution_history::import_session_transcript,
+            plan_cascade_desktop::commands::execution_history::export_session_bundle,
+            plan_cascade_desktop::commands::execution_history::import_session_bundle,
+            plan_cascade_desktop::commands::execution_history::get_session_bundle_public_key,
+            plan_cascade_desktop::commands::execution_history::get_session_bundle_trusted_keys,
+            plan_cascade_desktop::commands::execution_history::set_session_bundle_trusted_keys,
             // Skill commands
             plan_cascade_desktop::commands::skills::list_skills,
             plan_cascade_desktop::commands::skills::list_skills_v2,
@@ -421,6 +458,7 @@ fn main() {
             plan_cascade_desktop::commands::prompts::update_prompt,
             plan_cascade_desktop::commands::prompts::delete_prompt,
             plan_cascade_desktop::commands::prompts::record_prompt_use,
+            plan_cascade_desktop::commands::prompts::render_prompt,
             plan_cascade_desktop::commands::prompts::toggle_prompt_pin,
             // Plugin commands
             plan_cascade_desktop::commands::plugins::list_plugins,
@@ -432,6 +470,7 @@ fn main() {
             plan_cascade_desktop::commands::plugins::list_plugin_runtime_events,
             plan_cascade_desktop::commands::plugins::install_plugin,
             plan_cascade_desktop::commands::plugins::fetch_marketplace,
+            plan_cascade_desktop::commands::plugins::search_marketplace,
             plan_cascade_desktop::commands::plugins::install_plugin_from_git,
             plan_cascade_desktop::commands::plugins::uninstall_plugin,
             plan_cascade_desktop::commands::plugins::list_marketplaces,
@@ -451,11 +490,16 @@ fn main() {
             plan_cascade_desktop::commands::guardrails::list_guardrails,
             plan_cascade_desktop::commands::guardrails::set_guardrail_mode,
             plan_cascade_desktop::commands::guardrails::toggle_guardrail,
+            plan_cascade_desktop::commands::guardrails::set_guardrail_scope,
             plan_cascade_desktop::commands::guardrails::create_custom_guardrail,
             plan_cascade_desktop::commands::guardrails::update_guardrail,
+            plan_cascade_desktop::commands::guardrails::test_custom_rule,
             plan_cascade_desktop::commands::guardrails::delete_guardrail,
             plan_cascade_desktop::commands::guardrails::list_guardrail_events,
             plan_cascade_desktop::commands::guardrails::clear_guardrail_events,
+            plan_cascade_desktop::commands::guardrails::aggregate_guardrail_events,
+            plan_cascade_desktop::commands::guardrails::get_guardrail_retention,
+            plan_cascade_desktop::commands::guardrails::set_guardrail_retention,
             plan_cascade_desktop::commands::guardrails::add_custom_rule,
             plan_cascade_desktop::commands::guardrails::remove_custom_rule,
             plan_cascade_desktop::commands::guardrails::get_trigger_log,
@@ -466,6 +510,8 @@ fn main() {
             plan_cascade_desktop::commands::agent_composer::create_agent_pipeline,
             plan_cascade_desktop::commands::agent_composer::update_agent_pipeline,
             plan_cascade_desktop::commands::agent_composer::delete_agent_pipeline,
+            plan_cascade_desktop::commands::agent_composer::export_agent_pipeline,
+            plan_cascade_desktop::commands::agent_composer::import_agent_pipeline,
             // Graph Workflow commands
             plan_cascade_desktop::commands::graph_workflow::list_graph_workflows,
             plan_cascade_desktop::commands::graph_workflow::get_graph_workflow,
@@ -478,6 +524,7 @@ fn main() {
             plan_cascade_desktop::commands::proxy::set_proxy_config,
             plan_cascade_desktop::commands::proxy::get_provider_proxy_strategy,
             plan_cascade_desktop::commands::proxy::set_provider_proxy_strategy,
+            plan_cascade_desktop::commands::proxy::set_proxy_routes,
             plan_cascade_desktop::commands::proxy::test_proxy,
             // Webhook commands
             plan_cascade_desktop::commands::webhook::list_webhook_channels,
@@ -602,6 +649,7 @@ fn main() {
             plan_cascade_desktop::commands::file_changes::get_file_change_diff,
             plan_cascade_desktop::commands::file_changes::preview_restore_to_turn,
             plan_cascade_desktop::commands::file_changes::restore_files_to_turn_v2,
+            plan_cascade_desktop::commands::file_changes::restore_selected_files,
             plan_cascade_desktop::commands::file_changes::truncate_changes_from_turn,
             plan_cascade_desktop::commands::file_changes::restore_single_file,
             plan_cascade_desktop::commands::file_changes::undo_restore,
@@ -623,6 +671,8 @@ fn main() {
                 .map(|service| service.get_config().language.clone())
                 .unwrap_or_else(|_| "en".to_string());
             init_tray(app.handle(), &locale)?;
+            app.state::<AnalyticsState>()
+                .start_budget_notifier(app.handle().clone());
 
             #[cfg(debug_assertions)]
             {
@@ -247,6 +247,27 @@ impl PricingRule {
     }
 }
 
+/// A pricing rule rejected during bulk import.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PricingImportConflict {
+    /// Position of the rejected entry in the import batch
+    pub index: usize,
+    pub rule_id: String,
+    pub provider: String,
+    pub model_pattern: String,
+    pub reason: String,
+    /// Rule the entry collides with (another batch entry or a stored rule)
+    pub conflicting_rule_id: Option<String>,
+}
+
+/// Outcome of a bulk pricing import. The import is all-or-nothing: when any
+/// conflict is reported, no rule is written.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PricingImportResult {
+    pub imported: Vec<PricingRule>,
+    pub conflicts: Vec<PricingImportConflict>,
+}
+
 /// Cost breakdown persisted for auditing/traceability.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct CostBreakdown {
@@ -309,6 +330,43 @@ pub struct ProjectUsage {
     pub stats: UsageStats,
 }
 
+/// Dimension used by `aggregate_usage_rollup`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageRollupDimension {
+    /// Group by session (`session_id`, falling back to `kernel_session_id`)
+    Session,
+    /// Group by agent/pipeline name (`agent_name` column or metadata)
+    Agent,
+}
+
+/// Sort key for usage rollups (always descending).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageRollupSort {
+    #[default]
+    Cost,
+    Tokens,
+    Requests,
+}
+
+/// Usage aggregated by session or agent, split into parent vs sub-agent spend.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageRollup {
+    /// Session ID or agent name (`(unattributed)` when missing)
+    pub key: String,
+    /// Totals across all requests in the group
+    pub stats: UsageStats,
+    /// Requests issued by the parent (root) agent
+    pub parent_stats: UsageStats,
+    /// Requests issued by sub-agents
+    pub sub_agent_stats: UsageStats,
+    /// Highest agentic-loop iteration seen in the group
+    pub max_iteration: Option<i64>,
+    pub first_timestamp: i64,
+    pub last_timestamp: i64,
+}
+
 /// Time-series data point
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TimeSeriesPoint {
@@ -434,6 +492,9 @@ pub struct DashboardSummary {
     pub by_project: Vec<ProjectUsage>,
     /// Time series data for charts
     pub time_series: Vec<TimeSeriesPoint>,
+    /// Month-end cost projection for the filtered project (or all projects)
+    #[serde(default)]
+    pub cost_projection: Option<CostProjection>,
 }
 
 impl DashboardSummary {
@@ -457,6 +518,10 @@ impl DashboardSummary {
 pub enum ExportFormat {
     Csv,
     Json,
+    /// Newline-delimited JSON, one record per line
+    Ndjson,
+    /// Columnar Parquet with a typed schema (streaming export only)
+    Parquet,
 }
 
 /// Export request parameters
@@ -684,6 +749,88 @@ pub struct RecomputeCostsResult {
     pub missing_records: i64,
 }
 
+/// Monthly spend budget for one project, or for all usage when
+/// `project_id` is `None`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostBudget {
+    pub project_id: Option<String>,
+    /// Monthly budget in microdollars
+    pub monthly_budget_microdollars: i64,
+    /// Percentage of the budget at which alerts fire (e.g. 80.0)
+    #[serde(default = "default_alert_threshold_percent")]
+    pub alert_threshold_percent: f64,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+fn default_alert_threshold_percent() -> f64 {
+    100.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Which spend figure crossed the budget threshold.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetAlertKind {
+    /// Month-to-date spend crossed the threshold
+    Actual,
+    /// Month-end projection crossed the threshold
+    Projected,
+}
+
+impl BudgetAlertKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetAlertKind::Actual => "actual",
+            BudgetAlertKind::Projected => "projected",
+        }
+    }
+}
+
+/// A budget threshold crossing, raised at most once per scope/month/kind.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BudgetAlert {
+    pub project_id: Option<String>,
+    /// Month label (`YYYY-MM`)
+    pub month: String,
+    pub kind: BudgetAlertKind,
+    pub budget_microdollars: i64,
+    pub threshold_microdollars: i64,
+    pub actual_cost_microdollars: i64,
+    pub projected_cost_microdollars: i64,
+    pub triggered_at: i64,
+}
+
+/// Month-end cost projection extrapolated from recent daily usage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostProjection {
+    pub project_id: Option<String>,
+    /// Month label (`YYYY-MM`)
+    pub month: String,
+    pub days_in_month: u32,
+    /// Fractional days elapsed in the month
+    pub days_elapsed: f64,
+    /// Trailing window used to compute the daily average
+    pub lookback_days: i64,
+    /// Month-to-date spend in microdollars
+    pub actual_cost_microdollars: i64,
+    /// Average daily spend over the lookback window in microdollars
+    pub daily_average_microdollars: f64,
+    /// Projected month-end spend in microdollars
+    pub projected_cost_microdollars: i64,
+    /// Budget applying to this scope, if configured
+    pub budget: Option<CostBudget>,
+    /// Month-to-date spend as a percentage of the budget
+    pub budget_used_percent: Option<f64>,
+    /// Projected spend as a percentage of the budget
+    pub projected_budget_percent: Option<f64>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
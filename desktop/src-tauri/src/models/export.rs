@@ -3,8 +3,18 @@
 //! Data structures for the unified settings export/import system that covers
 //! both frontend (Zustand) and backend (SQLite, config, secrets) settings.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::models::settings::CustomProviderEndpoint;
+
+/// Current schema version of [`PortableSettingsBundle`].
+pub const PORTABLE_SETTINGS_SCHEMA_VERSION: u32 = 1;
+
+/// Placeholder written in place of secret values in exported settings.
+pub const REDACTED_SECRET: &str = "[REDACTED]";
+
 /// Top-level unified settings export structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnifiedSettingsExport {
@@ -114,3 +124,99 @@ pub struct SettingsImportResult {
     /// Fatal errors per section
     pub errors: Vec<String>,
 }
+
+/// A selectable part of a portable settings bundle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingsScope {
+    /// Default provider/model, per-provider models and endpoints
+    Providers,
+    /// MCP server configurations
+    McpServers,
+    /// Guardrail mode and rules
+    Guardrails,
+}
+
+impl SettingsScope {
+    pub const ALL: [SettingsScope; 3] = [
+        SettingsScope::Providers,
+        SettingsScope::McpServers,
+        SettingsScope::Guardrails,
+    ];
+
+    /// Whether a keyring entry belongs to this scope. Provider API keys are
+    /// stored under the bare provider name; MCP secrets under `mcp/<id>/...`.
+    pub fn owns_secret(self, key: &str) -> bool {
+        match self {
+            SettingsScope::Providers => !key.contains('/'),
+            SettingsScope::McpServers => key.starts_with("mcp/"),
+            SettingsScope::Guardrails => false,
+        }
+    }
+}
+
+/// How an imported bundle combines with existing settings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingsImportMode {
+    /// Add and update entries, keeping ones the bundle does not mention
+    #[default]
+    Merge,
+    /// Make each exported scope match the bundle exactly
+    Replace,
+}
+
+/// Provider settings taken from `AppConfig`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProviderSettingsExport {
+    pub default_provider: String,
+    pub default_model: String,
+    #[serde(default)]
+    pub model_by_provider: HashMap<String, String>,
+    #[serde(default)]
+    pub glm_endpoint: String,
+    #[serde(default)]
+    pub minimax_endpoint: String,
+    #[serde(default)]
+    pub qwen_endpoint: String,
+    #[serde(default)]
+    pub custom_provider_base_urls: HashMap<String, String>,
+    #[serde(default)]
+    pub custom_provider_endpoints: HashMap<String, Vec<CustomProviderEndpoint>>,
+    #[serde(default)]
+    pub selected_custom_provider_endpoint_ids: HashMap<String, String>,
+}
+
+/// Guardrail mode and rules.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GuardrailSettingsExport {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<GuardrailRuleExport>,
+}
+
+/// Portable, scope-selectable settings bundle.
+///
+/// Secrets are replaced with [`REDACTED_SECRET`] unless they were exported
+/// encrypted with a password.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortableSettingsBundle {
+    pub schema_version: u32,
+    /// ISO 8601 export timestamp
+    pub exported_at: String,
+    /// Scopes included in the bundle
+    pub scopes: Vec<SettingsScope>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub providers: Option<ProviderSettingsExport>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mcp_servers: Option<Vec<serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardrails: Option<GuardrailSettingsExport>,
+    /// Locations of values replaced with [`REDACTED_SECRET`]
+    #[serde(default)]
+    pub redacted: Vec<String>,
+    /// Password-encrypted keyring entries for the exported scopes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encrypted_secrets: Option<String>,
+}
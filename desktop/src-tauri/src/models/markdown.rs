@@ -41,6 +41,103 @@ pub struct FileMetadata {
     pub modified_at: Option<String>,
     /// Creation timestamp (ISO 8601)
     pub created_at: Option<String>,
+    /// Tool-managed sections found in the file
+    #[serde(default)]
+    pub managed_sections: Vec<ManagedSectionInfo>,
+}
+
+/// A tool-managed section of a CLAUDE.md file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ManagedSectionInfo {
+    /// Section id from the sentinel comment
+    pub id: String,
+    /// Whether the section was edited since it was generated
+    pub edited: bool,
+    /// Whether the section holds unresolved merge conflict markers
+    pub conflicted: bool,
+}
+
+/// How `save_claude_md` writes content
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaudeMdWriteMode {
+    /// Replace the file with the given content
+    #[default]
+    Overwrite,
+    /// Regenerate only the managed sections, preserving everything else
+    Merge,
+}
+
+/// Outcome of a merge-mode save
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClaudeMdMergeReport {
+    /// Managed sections in the merged file, in order
+    pub managed_sections: Vec<String>,
+    /// Managed sections that were edited by hand and now hold conflict markers
+    pub conflicts: Vec<String>,
+    /// Generated sections that were not in the file before
+    pub added: Vec<String>,
+    /// Unedited managed sections that are no longer generated
+    pub removed: Vec<String>,
+}
+
+/// Where a CLAUDE.md file sits in the instruction hierarchy
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaudeMdScope {
+    /// User-level file (`~/.claude/CLAUDE.md`)
+    User,
+    /// File at the repository root
+    Project,
+    /// File in a subdirectory between the repository root and the working directory
+    Directory,
+}
+
+/// How a section combines with the same section from farther files
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ClaudeMdMergeMode {
+    /// The nearer file replaces the section
+    Override,
+    /// The nearer file's content is added after the farther content
+    Append,
+}
+
+/// A CLAUDE.md file that contributed to the effective instructions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaudeMdSource {
+    /// Full path to the file
+    pub path: String,
+    /// Position in the hierarchy
+    pub scope: ClaudeMdScope,
+}
+
+/// One section of the effective instructions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveClaudeMdSection {
+    /// Section heading (empty for text before the first heading)
+    pub heading: String,
+    /// Merged section body
+    pub content: String,
+    /// Merge mode declared by the nearest file defining the section
+    pub mode: ClaudeMdMergeMode,
+    /// Files whose content is included, farthest first
+    pub sources: Vec<String>,
+    /// Files whose content was replaced by a nearer file
+    pub overridden: Vec<String>,
+}
+
+/// Effective instructions for a working directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EffectiveClaudeMd {
+    /// Directory the hierarchy was resolved from
+    pub working_dir: String,
+    /// Contributing files, farthest first
+    pub sources: Vec<ClaudeMdSource>,
+    /// Merged sections in document order
+    pub sections: Vec<EffectiveClaudeMdSection>,
+    /// Merged instructions as markdown
+    pub content: String,
 }
 
 /// Result of a save operation
@@ -52,6 +149,9 @@ pub struct SaveResult {
     pub path: String,
     /// Error message if save failed
     pub error: Option<String>,
+    /// Merge details when saved in merge mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge: Option<ClaudeMdMergeReport>,
 }
 
 impl SaveResult {
@@ -61,6 +161,7 @@ impl SaveResult {
             success: true,
             path: path.into(),
             error: None,
+            merge: None,
         }
     }
 
@@ -70,6 +171,7 @@ impl SaveResult {
             success: false,
             path: path.into(),
             error: Some(error.into()),
+            merge: None,
         }
     }
 }
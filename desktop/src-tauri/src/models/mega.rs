@@ -197,6 +197,10 @@ pub struct FeatureState {
     pub started_at: Option<String>,
     /// Completed timestamp
     pub completed_at: Option<String>,
+    /// Artifacts produced by the feature (e.g. merge commit), kept so a
+    /// resumed run can reuse them
+    #[serde(default)]
+    pub outputs: HashMap<String, String>,
 }
 
 impl Default for FeatureState {
@@ -211,6 +215,7 @@ impl Default for FeatureState {
             error: None,
             started_at: None,
             completed_at: None,
+            outputs: HashMap::new(),
         }
     }
 }
@@ -310,6 +315,41 @@ impl MegaStatus {
         self.updated_at = self.completed_at.clone();
     }
 
+    /// Whether a persisted status describes an interrupted run that can be resumed
+    pub fn is_resumable(&self) -> bool {
+        matches!(
+            self.status,
+            MegaExecutionStatus::Running | MegaExecutionStatus::Paused
+        ) && self
+            .features
+            .values()
+            .any(|s| s.status != FeatureStatus::Completed)
+    }
+
+    /// Prepare an interrupted status for resuming.
+    ///
+    /// Features that were in progress when the run stopped are reset to
+    /// pending (keeping their worktree/PRD artifacts for reuse); completed
+    /// features are left untouched so they never re-run. Returns the IDs of
+    /// the reset features.
+    pub fn prepare_resume(&mut self) -> Vec<String> {
+        let mut reset: Vec<String> = self
+            .features
+            .iter_mut()
+            .filter(|(_, s)| s.status == FeatureStatus::InProgress)
+            .map(|(id, s)| {
+                s.status = FeatureStatus::Pending;
+                s.started_at = None;
+                id.clone()
+            })
+            .collect();
+        reset.sort();
+
+        self.status = MegaExecutionStatus::Running;
+        self.updated_at = Some(chrono::Utc::now().to_rfc3339());
+        reset
+    }
+
     /// Get completion percentage
     pub fn completion_percentage(&self) -> f32 {
         if self.features.is_empty() {
@@ -395,4 +435,38 @@ mod tests {
         status.complete();
         assert_eq!(status.status, MegaExecutionStatus::Completed);
     }
+
+    #[test]
+    fn test_mega_status_prepare_resume() {
+        let mut status = MegaStatus::new("test-plan");
+        status.start();
+
+        let mut done = FeatureState::default();
+        done.start();
+        done.complete();
+        done.outputs
+            .insert("merge_commit".to_string(), "abc123".to_string());
+        status.features.insert("F001".to_string(), done);
+
+        let mut interrupted = FeatureState {
+            worktree: Some(PathBuf::from("/repo/.worktree/f002")),
+            ..Default::default()
+        };
+        interrupted.start();
+        status.features.insert("F002".to_string(), interrupted);
+
+        assert!(status.is_resumable());
+        assert_eq!(status.prepare_resume(), vec!["F002".to_string()]);
+
+        let f1 = &status.features["F001"];
+        assert_eq!(f1.status, FeatureStatus::Completed);
+        assert_eq!(f1.outputs["merge_commit"], "abc123");
+
+        let f2 = &status.features["F002"];
+        assert_eq!(f2.status, FeatureStatus::Pending);
+        assert_eq!(f2.worktree, Some(PathBuf::from("/repo/.worktree/f002")));
+
+        status.complete();
+        assert!(!status.is_resumable());
+    }
 }
@@ -460,6 +460,34 @@ pub struct ExecuteWithSessionRequest {
     pub max_total_tokens: Option<u32>,
 }
 
+/// Agent configuration shared by every prompt in a standalone batch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandaloneBatchConfig {
+    /// Project path
+    pub project_path: String,
+    /// LLM provider
+    pub provider: String,
+    /// Model name
+    pub model: String,
+    /// System prompt override
+    pub system_prompt: Option<String>,
+    /// Enable extended thinking/reasoning for supported models
+    pub enable_thinking: Option<bool>,
+    /// Maximum total tokens for each prompt
+    #[serde(default)]
+    pub max_total_tokens: Option<u32>,
+    /// Maximum number of prompts running at once
+    #[serde(default)]
+    pub max_concurrency: Option<usize>,
+    /// Abort running prompts when the batch is cancelled instead of
+    /// letting them finish
+    #[serde(default)]
+    pub abort_in_flight_on_cancel: bool,
+    /// Caller-chosen batch ID, used to cancel the batch while it runs
+    #[serde(default)]
+    pub batch_id: Option<String>,
+}
+
 /// Request to resume a paused/failed execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResumeExecutionRequest {
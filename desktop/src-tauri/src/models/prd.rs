@@ -34,6 +34,8 @@ pub enum StoryType {
     Refactor,
     /// Test implementation
     Test,
+    /// Documentation-only change
+    Docs,
 }
 
 impl std::fmt::Display for StoryType {
@@ -43,6 +45,7 @@ impl std::fmt::Display for StoryType {
             StoryType::Bugfix => write!(f, "bugfix"),
             StoryType::Refactor => write!(f, "refactor"),
             StoryType::Test => write!(f, "test"),
+            StoryType::Docs => write!(f, "docs"),
         }
     }
 }
@@ -182,6 +185,11 @@ impl Story {
     pub fn is_refactor(&self) -> bool {
         self.story_type == Some(StoryType::Refactor)
     }
+
+    /// Check if this story only touches documentation
+    pub fn is_docs(&self) -> bool {
+        self.story_type == Some(StoryType::Docs)
+    }
 }
 
 /// The complete PRD document
@@ -2,8 +2,40 @@
 //!
 //! Data structures for the prompt library feature.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+/// Declared schema for a `{{variable}}` placeholder
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PromptVariable {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Value used when the caller does not supply one
+    #[serde(default)]
+    pub default: Option<String>,
+    /// Whether rendering fails when no value (and no default) is given
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+impl PromptVariable {
+    /// An undeclared placeholder: required, no default.
+    pub fn named(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            default: None,
+            required: true,
+        }
+    }
+}
+
 /// A prompt template in the library
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PromptTemplate {
@@ -17,6 +49,9 @@ pub struct PromptTemplate {
     pub tags: Vec<String>,
     /// Extracted {{variable}} names from content (stored as JSON in DB)
     pub variables: Vec<String>,
+    /// Schema for each variable in `variables`, in the same order
+    #[serde(default)]
+    pub variable_schema: Vec<PromptVariable>,
     /// Whether this is a built-in prompt (cannot be deleted)
     pub is_builtin: bool,
     /// Whether this prompt is pinned to the top
@@ -24,10 +59,27 @@ pub struct PromptTemplate {
     /// Number of times this prompt has been used
     pub use_count: u32,
     pub last_used_at: Option<String>,
+    /// Recency-weighted usage: each use counts 1 when fresh and halves every
+    /// `USAGE_HALF_LIFE_DAYS`. Only computed by the `usage` sort mode.
+    #[serde(default)]
+    pub usage_score: f64,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
 }
 
+/// Ordering for `list_prompts`; pinned prompts always come first
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptSortMode {
+    /// Total use count, then title
+    #[default]
+    UseCount,
+    /// Recency-weighted usage, so prompts that were popular long ago sink
+    Usage,
+    /// Title only
+    Title,
+}
+
 /// Request to create a new prompt template
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PromptCreateRequest {
@@ -37,6 +89,9 @@ pub struct PromptCreateRequest {
     pub category: String,
     pub tags: Vec<String>,
     pub is_pinned: bool,
+    /// Declarations for the content's variables; undeclared ones are required
+    #[serde(default)]
+    pub variable_schema: Vec<PromptVariable>,
 }
 
 /// Request to update an existing prompt template
@@ -48,4 +103,15 @@ pub struct PromptUpdateRequest {
     pub category: Option<String>,
     pub tags: Option<Vec<String>>,
     pub is_pinned: Option<bool>,
+    #[serde(default)]
+    pub variable_schema: Option<Vec<PromptVariable>>,
+}
+
+/// A prompt with its variables substituted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RenderedPrompt {
+    pub prompt_id: String,
+    pub content: String,
+    /// Value used for each variable, defaults included
+    pub variables: BTreeMap<String, String>,
 }
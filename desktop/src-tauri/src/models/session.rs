@@ -107,6 +107,25 @@ impl ResumeResult {
     }
 }
 
+/// Result of forking a session at a conversation turn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForkResult {
+    /// Session ID the fork was taken from
+    pub source_session_id: String,
+    /// Newly created session ID
+    pub session_id: String,
+    /// Full path to the new session file
+    pub session_path: String,
+    /// Number of user turns copied into the fork
+    pub turn_count: u32,
+    /// Number of checkpoint entries carried over in the transcript
+    pub checkpoint_count: u32,
+    /// Number of timeline checkpoints copied to the fork
+    pub timeline_checkpoint_count: u32,
+    /// Number of file-change records copied to the fork's tracker
+    pub file_change_count: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
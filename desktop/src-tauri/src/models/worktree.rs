@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::models::quality_gates::{CustomGateConfig, GatesSummary};
+
 fn now_rfc3339() -> String {
     chrono::Utc::now().to_rfc3339()
 }
@@ -437,6 +439,87 @@ pub struct CreatePullRequestResult {
     pub state: PullRequestState,
 }
 
+/// What happens when creating a worktree would exceed the disk quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaEnforcement {
+    /// Log a warning and create the worktree anyway
+    #[default]
+    Warn,
+    /// Refuse to create the worktree
+    Block,
+}
+
+/// Disk budget for all worktrees of a repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeQuota {
+    pub max_bytes: u64,
+    #[serde(default)]
+    pub enforcement: QuotaEnforcement,
+}
+
+/// Disk usage of a single worktree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeSize {
+    pub worktree_id: String,
+    pub path: String,
+    pub disk_usage_bytes: u64,
+}
+
+/// Disk usage of a repository's worktrees against the configured quota
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeDiskUsage {
+    pub total_bytes: u64,
+    pub quota: Option<WorktreeQuota>,
+    pub over_quota: bool,
+    pub worktrees: Vec<WorktreeSize>,
+}
+
+/// Worktree status together with its size on disk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeStatusReport {
+    pub worktree_id: String,
+    pub status: WorktreeStatus,
+    pub disk_usage_bytes: u64,
+}
+
+/// Why a worktree is considered abandoned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrphanReason {
+    /// The worktree's branch no longer exists
+    BranchDeleted,
+    /// The worktree is bound to a session that no longer exists
+    SessionMissing,
+}
+
+/// A worktree that can likely be cleaned up
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanWorktree {
+    pub worktree: Worktree,
+    pub reason: OrphanReason,
+    pub disk_usage_bytes: u64,
+}
+
+/// A labeled stash created before a destructive worktree operation.
+///
+/// Stashes are identified by their commit SHA because `stash@{n}` indices
+/// shift whenever another stash is pushed or dropped.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorktreeAutoStash {
+    pub commit: String,
+    pub label: String,
+    pub worktree_id: String,
+    pub session_id: Option<String>,
+    pub operation: String,
+}
+
 fn default_execution_mode() -> String {
     "auto".to_string()
 }
@@ -456,6 +539,9 @@ pub struct CompleteWorktreeResult {
     pub warnings: Vec<String>,
     /// Error message if not successful
     pub error: Option<String>,
+    /// Auto-stashes for this worktree that still need restoring or dropping
+    #[serde(default)]
+    pub auto_stashes: Vec<WorktreeAutoStash>,
 }
 
 impl CompleteWorktreeResult {
@@ -468,6 +554,7 @@ impl CompleteWorktreeResult {
             cleaned_up,
             warnings: Vec::new(),
             error: None,
+            auto_stashes: Vec::new(),
         }
     }
 
@@ -480,6 +567,7 @@ impl CompleteWorktreeResult {
             cleaned_up: false,
             warnings: Vec::new(),
             error: Some(message.into()),
+            auto_stashes: Vec::new(),
         }
     }
 
@@ -527,6 +615,68 @@ impl MergeConflict {
     }
 }
 
+/// How a worktree branch is integrated into its base branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Create a merge commit on the base branch
+    #[default]
+    Merge,
+    /// Rebase the worktree branch onto the base, then fast-forward the base
+    Rebase,
+}
+
+/// Options for merging a worktree back into its base branch
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeToBaseOptions {
+    /// Gates to run in the worktree before merging; skipped when `None`
+    #[serde(default)]
+    pub quality_gates: Option<Vec<CustomGateConfig>>,
+    /// Remove the worktree and its branch after a successful merge
+    #[serde(default)]
+    pub remove_worktree: bool,
+    /// Merge commit message (merge strategy only)
+    #[serde(default)]
+    pub commit_message: Option<String>,
+}
+
+/// Result of merging a worktree back into its base branch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeToBaseResult {
+    pub strategy: MergeStrategy,
+    pub merged: bool,
+    /// Base branch HEAD after the merge
+    pub base_commit: Option<String>,
+    pub gates: Option<GatesSummary>,
+    /// Conflicts found; both branches are left as they were before the merge
+    pub conflict: Option<MergeConflict>,
+    pub cleaned_up: bool,
+    pub warnings: Vec<String>,
+    pub error: Option<String>,
+}
+
+impl MergeToBaseResult {
+    pub fn new(strategy: MergeStrategy) -> Self {
+        Self {
+            strategy,
+            merged: false,
+            base_commit: None,
+            gates: None,
+            conflict: None,
+            cleaned_up: false,
+            warnings: Vec::new(),
+            error: None,
+        }
+    }
+
+    pub fn with_error(mut self, message: impl Into<String>) -> Self {
+        self.error = Some(message.into());
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,11 +7,13 @@
 //!   agent definitions, graph construction, conditional routing, and invocation.
 //! - `generate_rust()` — produces Rust source with `plan_cascade_core` types,
 //!   HashMap construction, Vec edges, and interrupt-point comments.
+//! - `generate_pipeline_rust()` / `generate_pipeline_python()` — produce
+//!   standalone code rebuilding an `AgentPipeline` with full step configs.
 
 use std::fmt::Write as FmtWrite;
 
 use super::graph_types::{ChannelConfig, Edge, GraphWorkflow, Reducer, StateSchema};
-use super::types::AgentStep;
+use super::types::{AgentConfig, AgentPipeline, AgentStep, MergeStrategy, StepFailurePolicy};
 
 // ============================================================================
 // TypeScript Code Generation
@@ -110,6 +112,9 @@ pub fn generate_typescript(workflow: &GraphWorkflow) -> String {
             Edge::Direct { from, to } => {
                 writeln!(out, "graph.addEdge(\"{}\", \"{}\");", from, to).unwrap();
             }
+            Edge::Error { from, to } => {
+                writeln!(out, "graph.addErrorEdge(\"{}\", \"{}\");", from, to).unwrap();
+            }
             Edge::Conditional {
                 from,
                 condition,
@@ -358,6 +363,12 @@ pub fn generate_rust(workflow: &GraphWorkflow) -> String {
                 writeln!(out, "            to: \"{}\".to_string(),", to).unwrap();
                 writeln!(out, "        }},").unwrap();
             }
+            Edge::Error { from, to } => {
+                writeln!(out, "        Edge::Error {{").unwrap();
+                writeln!(out, "            from: \"{}\".to_string(),", from).unwrap();
+                writeln!(out, "            to: \"{}\".to_string(),", to).unwrap();
+                writeln!(out, "        }},").unwrap();
+            }
             Edge::Conditional {
                 from,
                 condition,
@@ -627,6 +638,508 @@ fn write_rust_state_schema(out: &mut String, schema: &StateSchema) {
     writeln!(out, "    }};").unwrap();
 }
 
+// ============================================================================
+// Pipeline Code Generation
+// ============================================================================
+
+/// Generate Rust source that rebuilds an `AgentPipeline`.
+///
+/// Unlike the graph generator, sub-steps are emitted in full (recursively),
+/// along with every non-default `AgentConfig` field, so the output is a
+/// complete `build_pipeline()` function using `plan_cascade_core::agent` types.
+pub fn generate_pipeline_rust(pipeline: &AgentPipeline) -> String {
+    let mut body = String::new();
+    writeln!(body, "/// Pipeline: {}", pipeline.name).unwrap();
+    if let Some(ref desc) = pipeline.description {
+        for line in desc.lines() {
+            writeln!(body, "/// {}", line).unwrap();
+        }
+    }
+    writeln!(body, "pub fn build_pipeline() -> AgentPipeline {{").unwrap();
+    writeln!(body, "    AgentPipeline {{").unwrap();
+    writeln!(
+        body,
+        "        pipeline_id: {}.to_string(),",
+        rust_str(&pipeline.pipeline_id)
+    )
+    .unwrap();
+    writeln!(
+        body,
+        "        name: {}.to_string(),",
+        rust_str(&pipeline.name)
+    )
+    .unwrap();
+    writeln!(
+        body,
+        "        description: {},",
+        rust_opt_string(pipeline.description.as_deref())
+    )
+    .unwrap();
+    writeln!(body, "        steps: vec![").unwrap();
+    for step in &pipeline.steps {
+        writeln!(
+            body,
+            "            {},",
+            rust_step_expr(step, "            ")
+        )
+        .unwrap();
+    }
+    writeln!(body, "        ],").unwrap();
+    writeln!(body, "        created_at: String::new(),").unwrap();
+    writeln!(body, "        updated_at: None,").unwrap();
+    writeln!(body, "    }}").unwrap();
+    writeln!(body, "}}").unwrap();
+
+    // Only import what the body uses so the snippet compiles warning-free
+    let mut out = String::new();
+    if body.contains("HashMap::") {
+        writeln!(out, "use std::collections::HashMap;").unwrap();
+        writeln!(out).unwrap();
+    }
+    let types: Vec<&str> = [
+        "AgentConfig",
+        "AgentPipeline",
+        "AgentStep",
+        "LlmStepConfig",
+        "MergeStrategy",
+        "StepFailurePolicy",
+    ]
+    .into_iter()
+    .filter(|ty| {
+        body.contains(&format!("{} ", ty))
+            || body.contains(&format!("{}::", ty))
+            || body.contains(&format!("{}(", ty))
+    })
+    .collect();
+    writeln!(
+        out,
+        "use plan_cascade_core::agent::{{{}}};",
+        types.join(", ")
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    out.push_str(&body);
+
+    out
+}
+
+/// Build a Rust `AgentStep` expression. Continuation lines are prefixed with
+/// `indent`; the first line is not.
+fn rust_step_expr(step: &AgentStep, indent: &str) -> String {
+    let inner = format!("{}    ", indent);
+    let mut out = String::new();
+    match step {
+        AgentStep::LlmStep(config) => {
+            writeln!(out, "AgentStep::LlmStep(LlmStepConfig {{").unwrap();
+            writeln!(
+                out,
+                "{}name: {}.to_string(),",
+                inner,
+                rust_str(&config.name)
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{}instruction: {},",
+                inner,
+                rust_opt_string(config.instruction.as_deref())
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{}model: {},",
+                inner,
+                rust_opt_string(config.model.as_deref())
+            )
+            .unwrap();
+            match &config.tools {
+                Some(tools) => {
+                    let items: Vec<String> = tools
+                        .iter()
+                        .map(|t| format!("{}.to_string()", rust_str(t)))
+                        .collect();
+                    writeln!(out, "{}tools: Some(vec![{}]),", inner, items.join(", ")).unwrap();
+                }
+                None => writeln!(out, "{}tools: None,", inner).unwrap(),
+            }
+            writeln!(
+                out,
+                "{}config: {},",
+                inner,
+                rust_agent_config_expr(&config.config, &inner)
+            )
+            .unwrap();
+            write!(out, "{}}})", indent).unwrap();
+        }
+        AgentStep::SequentialStep { name, steps } | AgentStep::ParallelStep { name, steps } => {
+            let variant = if matches!(step, AgentStep::SequentialStep { .. }) {
+                "SequentialStep"
+            } else {
+                "ParallelStep"
+            };
+            writeln!(out, "AgentStep::{} {{", variant).unwrap();
+            writeln!(out, "{}name: {}.to_string(),", inner, rust_str(name)).unwrap();
+            writeln!(out, "{}steps: vec![", inner).unwrap();
+            let item_indent = format!("{}    ", inner);
+            for sub in steps {
+                writeln!(out, "{}{},", item_indent, rust_step_expr(sub, &item_indent)).unwrap();
+            }
+            writeln!(out, "{}],", inner).unwrap();
+            write!(out, "{}}}", indent).unwrap();
+        }
+        AgentStep::ConditionalStep {
+            name,
+            condition_key,
+            branches,
+            default_branch,
+        } => {
+            writeln!(out, "AgentStep::ConditionalStep {{").unwrap();
+            writeln!(out, "{}name: {}.to_string(),", inner, rust_str(name)).unwrap();
+            writeln!(
+                out,
+                "{}condition_key: {}.to_string(),",
+                inner,
+                rust_str(condition_key)
+            )
+            .unwrap();
+            writeln!(out, "{}branches: HashMap::from([", inner).unwrap();
+            let item_indent = format!("{}    ", inner);
+            let mut keys: Vec<&String> = branches.keys().collect();
+            keys.sort();
+            for key in keys {
+                writeln!(
+                    out,
+                    "{}({}.to_string(), {}),",
+                    item_indent,
+                    rust_str(key),
+                    rust_step_expr(&branches[key], &item_indent)
+                )
+                .unwrap();
+            }
+            writeln!(out, "{}]),", inner).unwrap();
+            match default_branch {
+                Some(default) => writeln!(
+                    out,
+                    "{}default_branch: Some(Box::new({})),",
+                    inner,
+                    rust_step_expr(default, &inner)
+                )
+                .unwrap(),
+                None => writeln!(out, "{}default_branch: None,", inner).unwrap(),
+            }
+            write!(out, "{}}}", indent).unwrap();
+        }
+        AgentStep::LoopStep {
+            name,
+            condition_key,
+            soft_limit_override,
+            step,
+        } => {
+            writeln!(out, "AgentStep::LoopStep {{").unwrap();
+            writeln!(out, "{}name: {}.to_string(),", inner, rust_str(name)).unwrap();
+            writeln!(
+                out,
+                "{}condition_key: {}.to_string(),",
+                inner,
+                rust_str(condition_key)
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{}soft_limit_override: {:?},",
+                inner, soft_limit_override
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{}step: Box::new({}),",
+                inner,
+                rust_step_expr(step, &inner)
+            )
+            .unwrap();
+            write!(out, "{}}}", indent).unwrap();
+        }
+    }
+    out
+}
+
+/// Build a Rust `AgentConfig` expression listing only non-default fields.
+fn rust_agent_config_expr(config: &AgentConfig, indent: &str) -> String {
+    let defaults = AgentConfig::default();
+    let mut fields = Vec::new();
+    if config.soft_limit_override != defaults.soft_limit_override {
+        fields.push(format!(
+            "soft_limit_override: {:?}",
+            config.soft_limit_override
+        ));
+    }
+    if config.max_total_tokens != defaults.max_total_tokens {
+        fields.push(format!("max_total_tokens: {}", config.max_total_tokens));
+    }
+    if config.streaming != defaults.streaming {
+        fields.push(format!("streaming: {}", config.streaming));
+    }
+    if config.enable_compaction != defaults.enable_compaction {
+        fields.push(format!("enable_compaction: {}", config.enable_compaction));
+    }
+    if let Some(temperature) = config.temperature {
+        fields.push(format!("temperature: Some({:?})", temperature));
+    }
+    if let Some(top_p) = config.top_p {
+        fields.push(format!("top_p: Some({:?})", top_p));
+    }
+    if let Some(seed) = config.seed {
+        fields.push(format!("seed: Some({})", seed));
+    }
+    if config.merge_strategy != defaults.merge_strategy {
+        let strategy = match &config.merge_strategy {
+            MergeStrategy::Concat => "MergeStrategy::Concat".to_string(),
+            MergeStrategy::LlmSynthesize { instruction } => format!(
+                "MergeStrategy::LlmSynthesize {{ instruction: {} }}",
+                rust_opt_string(instruction.as_deref())
+            ),
+            MergeStrategy::FirstSuccess => "MergeStrategy::FirstSuccess".to_string(),
+            MergeStrategy::Custom { reducer } => format!(
+                "MergeStrategy::Custom {{ reducer: {}.to_string() }}",
+                rust_str(reducer)
+            ),
+        };
+        fields.push(format!("merge_strategy: {}", strategy));
+    }
+    if config.on_step_failure != defaults.on_step_failure {
+        let policy = match config.on_step_failure {
+            StepFailurePolicy::Abort => "StepFailurePolicy::Abort".to_string(),
+            StepFailurePolicy::Continue => "StepFailurePolicy::Continue".to_string(),
+            StepFailurePolicy::Retry(n) => format!("StepFailurePolicy::Retry({})", n),
+        };
+        fields.push(format!("on_step_failure: {}", policy));
+    }
+
+    if fields.is_empty() {
+        return "AgentConfig::default()".to_string();
+    }
+    let mut out = String::from("AgentConfig {\n");
+    for field in fields {
+        writeln!(out, "{}    {},", indent, field).unwrap();
+    }
+    write!(out, "{}    ..AgentConfig::default()\n{}}}", indent, indent).unwrap();
+    out
+}
+
+/// Generate a Python script that rebuilds and runs an `AgentPipeline`.
+///
+/// The script targets the `plan_cascade` Python SDK, whose shape mirrors the
+/// serialized pipeline: `Pipeline(name, description, steps)` plus one class
+/// per step type (`LlmStep`, `SequentialStep`, `ParallelStep`,
+/// `ConditionalStep`, `LoopStep`) taking the same fields as keyword
+/// arguments, and `AgentConfig(**overrides)` for non-default settings.
+/// Enum-valued settings use their JSON form (e.g. `{"retry": 2}`).
+/// `Pipeline.run(input)` returns a result whose `output` is the final text.
+pub fn generate_pipeline_python(pipeline: &AgentPipeline) -> String {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "\"\"\"Pipeline: {}",
+        escape_py_docstring(&pipeline.name)
+    )
+    .unwrap();
+    if let Some(ref desc) = pipeline.description {
+        writeln!(out).unwrap();
+        writeln!(out, "{}", escape_py_docstring(desc)).unwrap();
+    }
+    writeln!(out, "\"\"\"").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "import sys").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "from plan_cascade.agents import (").unwrap();
+    for name in [
+        "AgentConfig",
+        "ConditionalStep",
+        "LlmStep",
+        "LoopStep",
+        "ParallelStep",
+        "Pipeline",
+        "SequentialStep",
+    ] {
+        writeln!(out, "    {},", name).unwrap();
+    }
+    writeln!(out, ")").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "pipeline = Pipeline(").unwrap();
+    writeln!(out, "    name={},", py_str(&pipeline.name)).unwrap();
+    writeln!(
+        out,
+        "    description={},",
+        pipeline
+            .description
+            .as_deref()
+            .map(py_str)
+            .unwrap_or_else(|| "None".to_string())
+    )
+    .unwrap();
+    writeln!(out, "    steps=[").unwrap();
+    for step in &pipeline.steps {
+        writeln!(out, "        {},", py_step_expr(step, "        ")).unwrap();
+    }
+    writeln!(out, "    ],").unwrap();
+    writeln!(out, ")").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "if __name__ == \"__main__\":").unwrap();
+    writeln!(out, "    result = pipeline.run(\" \".join(sys.argv[1:]))").unwrap();
+    writeln!(out, "    print(result.output)").unwrap();
+
+    out
+}
+
+/// Build a Python step constructor call. Continuation lines are prefixed
+/// with `indent`; the first line is not.
+fn py_step_expr(step: &AgentStep, indent: &str) -> String {
+    let inner = format!("{}    ", indent);
+    let mut out = String::new();
+    match step {
+        AgentStep::LlmStep(config) => {
+            writeln!(out, "LlmStep(").unwrap();
+            writeln!(out, "{}name={},", inner, py_str(&config.name)).unwrap();
+            if let Some(ref instruction) = config.instruction {
+                writeln!(out, "{}instruction={},", inner, py_str(instruction)).unwrap();
+            }
+            if let Some(ref model) = config.model {
+                writeln!(out, "{}model={},", inner, py_str(model)).unwrap();
+            }
+            if let Some(ref tools) = config.tools {
+                let items: Vec<String> = tools.iter().map(|t| py_str(t)).collect();
+                writeln!(out, "{}tools=[{}],", inner, items.join(", ")).unwrap();
+            }
+            if let Some(config) = py_agent_config_expr(&config.config) {
+                writeln!(out, "{}config={},", inner, config).unwrap();
+            }
+            write!(out, "{})", indent).unwrap();
+        }
+        AgentStep::SequentialStep { name, steps } | AgentStep::ParallelStep { name, steps } => {
+            let class = if matches!(step, AgentStep::SequentialStep { .. }) {
+                "SequentialStep"
+            } else {
+                "ParallelStep"
+            };
+            writeln!(out, "{}(", class).unwrap();
+            writeln!(out, "{}name={},", inner, py_str(name)).unwrap();
+            writeln!(out, "{}steps=[", inner).unwrap();
+            let item_indent = format!("{}    ", inner);
+            for sub in steps {
+                writeln!(out, "{}{},", item_indent, py_step_expr(sub, &item_indent)).unwrap();
+            }
+            writeln!(out, "{}],", inner).unwrap();
+            write!(out, "{})", indent).unwrap();
+        }
+        AgentStep::ConditionalStep {
+            name,
+            condition_key,
+            branches,
+            default_branch,
+        } => {
+            writeln!(out, "ConditionalStep(").unwrap();
+            writeln!(out, "{}name={},", inner, py_str(name)).unwrap();
+            writeln!(out, "{}condition_key={},", inner, py_str(condition_key)).unwrap();
+            writeln!(out, "{}branches={{", inner).unwrap();
+            let item_indent = format!("{}    ", inner);
+            let mut keys: Vec<&String> = branches.keys().collect();
+            keys.sort();
+            for key in keys {
+                writeln!(
+                    out,
+                    "{}{}: {},",
+                    item_indent,
+                    py_str(key),
+                    py_step_expr(&branches[key], &item_indent)
+                )
+                .unwrap();
+            }
+            writeln!(out, "{}}},", inner).unwrap();
+            if let Some(default) = default_branch {
+                writeln!(
+                    out,
+                    "{}default_branch={},",
+                    inner,
+                    py_step_expr(default, &inner)
+                )
+                .unwrap();
+            }
+            write!(out, "{})", indent).unwrap();
+        }
+        AgentStep::LoopStep {
+            name,
+            condition_key,
+            soft_limit_override,
+            step,
+        } => {
+            writeln!(out, "LoopStep(").unwrap();
+            writeln!(out, "{}name={},", inner, py_str(name)).unwrap();
+            writeln!(out, "{}condition_key={},", inner, py_str(condition_key)).unwrap();
+            if let Some(limit) = soft_limit_override {
+                writeln!(out, "{}soft_limit_override={},", inner, limit).unwrap();
+            }
+            writeln!(out, "{}step={},", inner, py_step_expr(step, &inner)).unwrap();
+            write!(out, "{})", indent).unwrap();
+        }
+    }
+    out
+}
+
+/// Build a Python `AgentConfig(...)` call for non-default fields, or `None`
+/// when everything is default.
+fn py_agent_config_expr(config: &AgentConfig) -> Option<String> {
+    let defaults = serde_json::to_value(AgentConfig::default()).ok()?;
+    let current = serde_json::to_value(config).ok()?;
+    let current = current.as_object()?;
+
+    let mut keys: Vec<&String> = current.keys().collect();
+    keys.sort();
+    let args: Vec<String> = keys
+        .into_iter()
+        .filter(|key| defaults.get(key.as_str()) != current.get(key.as_str()))
+        .map(
+            |key| match (key.as_str(), config.temperature, config.top_p) {
+                // Print the f32 as written rather than its widened f64 value
+                ("temperature", Some(t), _) => format!("temperature={:?}", t),
+                ("top_p", _, Some(p)) => format!("top_p={:?}", p),
+                _ => format!("{}={}", key, py_literal(&current[key])),
+            },
+        )
+        .collect();
+
+    if args.is_empty() {
+        None
+    } else {
+        Some(format!("AgentConfig({})", args.join(", ")))
+    }
+}
+
+/// Render a JSON value as a Python literal.
+fn py_literal(value: &serde_json::Value) -> String {
+    use serde_json::Value;
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => py_str(s),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(py_literal).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let entries: Vec<String> = keys
+                .into_iter()
+                .map(|k| format!("{}: {}", py_str(k), py_literal(&map[k])))
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+    }
+}
+
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -646,6 +1159,30 @@ fn agent_step_var_name(node_id: &str) -> String {
     format!("agent_{}", sanitized)
 }
 
+/// Format a Rust string literal (quotes included).
+fn rust_str(s: &str) -> String {
+    format!("{:?}", s)
+}
+
+/// Format an `Option<String>` Rust expression.
+fn rust_opt_string(s: Option<&str>) -> String {
+    match s {
+        Some(s) => format!("Some({}.to_string())", rust_str(s)),
+        None => "None".to_string(),
+    }
+}
+
+/// Format a Python string literal (quotes included). JSON string syntax is
+/// a valid subset of Python's.
+fn py_str(s: &str) -> String {
+    serde_json::to_string(s).unwrap_or_else(|_| "\"\"".to_string())
+}
+
+/// Make text safe to embed in a triple-quoted Python docstring.
+fn escape_py_docstring(s: &str) -> String {
+    s.replace('\\', "\\\\").replace("\"\"\"", "\\\"\\\"\\\"")
+}
+
 /// Escape a string for TypeScript string literals.
 fn escape_ts_string(s: &str) -> String {
     s.replace('\\', "\\\\")
@@ -1322,4 +1859,119 @@ mod tests {
             "Missing branches count comment"
         );
     }
+
+    // ========================================================================
+    // Pipeline tests
+    // ========================================================================
+
+    fn sample_sequential_pipeline() -> AgentPipeline {
+        let mut branches = HashMap::new();
+        branches.insert("yes".to_string(), sample_llm_step("approve"));
+        AgentPipeline {
+            pipeline_id: "pipe-1".to_string(),
+            name: "Research \"Flow\"".to_string(),
+            description: Some("Gather, then summarize".to_string()),
+            steps: vec![AgentStep::SequentialStep {
+                name: "main".to_string(),
+                steps: vec![
+                    AgentStep::LlmStep(LlmStepConfig {
+                        name: "gather".to_string(),
+                        instruction: Some("Find sources.\nCite them.".to_string()),
+                        model: Some("claude-sonnet".to_string()),
+                        tools: Some(vec!["WebSearch".to_string(), "Read".to_string()]),
+                        config: AgentConfig {
+                            temperature: Some(0.2),
+                            on_step_failure: StepFailurePolicy::Retry(2),
+                            ..Default::default()
+                        },
+                    }),
+                    AgentStep::ParallelStep {
+                        name: "fan-out".to_string(),
+                        steps: vec![sample_llm_step("left"), sample_llm_step("right")],
+                    },
+                    AgentStep::ConditionalStep {
+                        name: "gate".to_string(),
+                        condition_key: "approved".to_string(),
+                        branches,
+                        default_branch: Some(Box::new(sample_llm_step("reject"))),
+                    },
+                    AgentStep::LoopStep {
+                        name: "refine".to_string(),
+                        condition_key: "needs_work".to_string(),
+                        soft_limit_override: Some(3),
+                        step: Box::new(sample_llm_step("editor")),
+                    },
+                ],
+            }],
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_pipeline_rust_parses() {
+        let rs = generate_pipeline_rust(&sample_sequential_pipeline());
+
+        let file = syn::parse_file(&rs).unwrap_or_else(|e| panic!("{}\n---\n{}", e, rs));
+        assert!(file.items.iter().any(|item| matches!(
+            item,
+            syn::Item::Fn(f) if f.sig.ident == "build_pipeline"
+        )));
+    }
+
+    #[test]
+    fn test_generate_pipeline_rust_includes_step_configs() {
+        let rs = generate_pipeline_rust(&sample_sequential_pipeline());
+
+        assert!(rs.contains("use std::collections::HashMap;"));
+        assert!(rs.contains(
+            "use plan_cascade_core::agent::{AgentConfig, AgentPipeline, AgentStep, LlmStepConfig, StepFailurePolicy};"
+        ));
+        assert!(rs.contains("name: \"Research \\\"Flow\\\"\".to_string(),"));
+        assert!(rs.contains("instruction: Some(\"Find sources.\\nCite them.\".to_string()),"));
+        assert!(rs.contains("tools: Some(vec![\"WebSearch\".to_string(), \"Read\".to_string()]),"));
+        assert!(rs.contains("temperature: Some(0.2),"));
+        assert!(rs.contains("on_step_failure: StepFailurePolicy::Retry(2),"));
+        assert!(rs.contains("..AgentConfig::default()"));
+        // Sub-steps are emitted in full, not summarized
+        assert!(rs.contains("name: \"right\".to_string(),"));
+        assert!(rs.contains("default_branch: Some(Box::new(AgentStep::LlmStep"));
+        assert!(rs.contains("soft_limit_override: Some(3),"));
+        assert!(!rs.contains("sub-steps"));
+    }
+
+    #[test]
+    fn test_generate_pipeline_python() {
+        let py = generate_pipeline_python(&sample_sequential_pipeline());
+
+        assert!(py.starts_with("\"\"\"Pipeline: Research \"Flow\"\n"));
+        assert!(py.contains("from plan_cascade.agents import ("));
+        assert!(py.contains("    name=\"Research \\\"Flow\\\"\",\n"));
+        assert!(py.contains("SequentialStep(\n"));
+        assert!(py.contains("instruction=\"Find sources.\\nCite them.\","));
+        assert!(py.contains("tools=[\"WebSearch\", \"Read\"],"));
+        assert!(py.contains("config=AgentConfig(on_step_failure={\"retry\": 2}, temperature=0.2),"));
+        assert!(py.contains("branches={\n"));
+        assert!(py.contains("\"yes\": LlmStep("));
+        assert!(py.contains("soft_limit_override=3,"));
+        assert!(py.contains("if __name__ == \"__main__\":"));
+
+        // Brackets balance outside string literals
+        let mut depth = 0i32;
+        let mut in_str = false;
+        let mut escaped = false;
+        for line in py.lines().filter(|l| !l.contains("\"\"\"")) {
+            for c in line.chars() {
+                match (in_str, escaped, c) {
+                    (true, true, _) => escaped = false,
+                    (true, false, '\\') => escaped = true,
+                    (_, false, '"') => in_str = !in_str,
+                    (false, _, '(' | '[' | '{') => depth += 1,
+                    (false, _, ')' | ']' | '}') => depth -= 1,
+                    _ => {}
+                }
+            }
+        }
+        assert_eq!(depth, 0);
+    }
 }
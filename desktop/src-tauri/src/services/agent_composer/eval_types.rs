@@ -122,6 +122,15 @@ pub struct ModelConfig {
     /// Optional display name for UI.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
+    /// Optional temperature override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Optional top_p override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// Sampling seed; the runner uses `DEFAULT_EVAL_SEED` when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
 }
 
 // ============================================================================
@@ -338,6 +347,9 @@ mod tests {
             provider: "anthropic".to_string(),
             model: "claude-sonnet-4-20250514".to_string(),
             display_name: Some("Claude 3.5 Sonnet".to_string()),
+            temperature: None,
+            top_p: None,
+            seed: None,
         };
 
         let json = serde_json::to_string(&config).unwrap();
@@ -355,6 +367,9 @@ mod tests {
                 provider: "anthropic".to_string(),
                 model: "claude-sonnet-4-20250514".to_string(),
                 display_name: None,
+                temperature: None,
+                top_p: None,
+                seed: None,
             }],
             cases: vec![EvaluationCase {
                 id: "case-1".to_string(),
@@ -24,6 +24,22 @@ use crate::utils::error::{AppError, AppResult};
 // Evaluation Engine
 // ============================================================================
 
+/// Seed applied to every evaluated model that does not set its own, so
+/// repeated runs are comparable on providers that honor seeds.
+pub const DEFAULT_EVAL_SEED: u64 = 42;
+
+/// Agent configuration used to run one evaluation case for a model.
+fn eval_agent_config(model_config: &ModelConfig) -> AgentConfig {
+    AgentConfig {
+        soft_limit_override: Some(10),
+        streaming: false,
+        temperature: model_config.temperature,
+        top_p: model_config.top_p,
+        seed: Some(model_config.seed.unwrap_or(DEFAULT_EVAL_SEED)),
+        ..Default::default()
+    }
+}
+
 /// Engine for running multi-model evaluations.
 pub struct EvaluationEngine {
     /// Database pool for persisting results.
@@ -119,11 +135,7 @@ impl EvaluationEngine {
         // Build an LlmAgent for this model
         let agent = LlmAgent::new(format!("eval-{}", model_config.model))
             .with_model(model_config.model.clone())
-            .with_config(AgentConfig {
-                soft_limit_override: Some(10),
-                streaming: false,
-                ..Default::default()
-            });
+            .with_config(eval_agent_config(model_config));
 
         let mut eval_ctx = ctx;
         eval_ctx.input = case.input.clone();
@@ -427,6 +439,34 @@ mod tests {
     use super::*;
     use crate::storage::database::Database;
 
+    // ========================================================================
+    // Agent Config Tests
+    // ========================================================================
+
+    fn model(seed: Option<u64>) -> ModelConfig {
+        ModelConfig {
+            provider: "openai".to_string(),
+            model: "gpt-4o".to_string(),
+            display_name: None,
+            temperature: Some(0.0),
+            top_p: None,
+            seed,
+        }
+    }
+
+    #[test]
+    fn test_eval_agent_config_uses_default_seed() {
+        let config = eval_agent_config(&model(None));
+        assert_eq!(config.seed, Some(DEFAULT_EVAL_SEED));
+        assert_eq!(config.temperature, Some(0.0));
+        assert!(!config.streaming);
+    }
+
+    #[test]
+    fn test_eval_agent_config_keeps_model_seed() {
+        assert_eq!(eval_agent_config(&model(Some(7))).seed, Some(7));
+    }
+
     // ========================================================================
     // Tool Trajectory Scoring Tests
     // ========================================================================
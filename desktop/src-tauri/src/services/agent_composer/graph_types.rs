@@ -3,7 +3,7 @@
 //! Defines the data structures for graph-based workflow execution:
 //! - `GraphWorkflow`: A directed graph of agent nodes connected by edges
 //! - `GraphNode`: A node containing an AgentStep with optional UI position
-//! - `Edge`: Direct or conditional connections between nodes
+//! - `Edge`: Direct, conditional, or error-handler connections between nodes
 //! - `StateSchema`: Channel configuration and reducers for graph state
 //! - `Reducer`: Operations for combining state updates (Overwrite, Append, Sum)
 
@@ -78,8 +78,8 @@ pub struct NodePosition {
 
 /// An edge connecting two nodes in a graph workflow.
 ///
-/// Edges can be direct (unconditional) or conditional (routing based on
-/// shared state values).
+/// Edges can be direct (unconditional), conditional (routing based on
+/// shared state values), or error edges (routing a failed node to a handler).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "edge_type", rename_all = "snake_case")]
 pub enum Edge {
@@ -96,6 +96,10 @@ pub enum Edge {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         default_branch: Option<String>,
     },
+    /// Error edge: when `from` fails, execution continues at `to` with the
+    /// failure recorded in the `__error` state channel instead of failing
+    /// the whole workflow.
+    Error { from: String, to: String },
 }
 
 /// Configuration for a conditional edge.
@@ -300,6 +304,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_edge_error_serialization() {
+        let json = r#"{"edge_type":"error","from":"fetch","to":"recover"}"#;
+        let parsed: Edge = serde_json::from_str(json).unwrap();
+        match &parsed {
+            Edge::Error { from, to } => {
+                assert_eq!(from, "fetch");
+                assert_eq!(to, "recover");
+            }
+            _ => panic!("Expected Error edge"),
+        }
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+    }
+
     #[test]
     fn test_state_schema_serialization() {
         let mut channels = HashMap::new();
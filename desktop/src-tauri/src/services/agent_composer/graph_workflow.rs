@@ -4,7 +4,9 @@
 //! multi-agent orchestration with:
 //! - Node traversal following direct and conditional edges
 //! - State channels with reducer support (Overwrite, Append, Sum)
+//! - Write-time validation of channel values against the state schema
 //! - Cycle detection (max 100 iterations)
+//! - Error edges routing failed nodes to handler nodes
 //! - Human review interrupt points
 //! - Checkpointer integration for pause/resume and crash recovery
 
@@ -15,7 +17,7 @@ use async_trait::async_trait;
 use futures_util::StreamExt;
 use serde_json::Value;
 
-use super::graph_types::{Edge, GraphWorkflow, Reducer};
+use super::graph_types::{Edge, GraphWorkflow, Reducer, StateSchema};
 use super::registry::ComposerRegistry;
 use super::types::{Agent, AgentContext, AgentEvent, AgentEventStream, AgentStep};
 use crate::services::graph_workflow::checkpointer::{Checkpointer, GraphCheckpoint, Interrupt};
@@ -24,6 +26,10 @@ use crate::utils::error::{AppError, AppResult};
 /// Maximum number of node traversals before cycle detection triggers.
 const MAX_ITERATIONS: usize = 100;
 
+/// State channel that receives `{ "node_id", "error" }` when a failed node
+/// is routed to its error handler.
+pub const ERROR_STATE_KEY: &str = "__error";
+
 // ============================================================================
 // GraphWorkflow Agent Implementation
 // ============================================================================
@@ -62,41 +68,65 @@ impl GraphWorkflow {
         checkpointer: Option<Arc<dyn Checkpointer + Send + Sync>>,
         thread_id: String,
     ) -> AppResult<AgentEventStream> {
-        let workflow = self.clone();
+        let state = GraphExecutionState::new(
+            self.clone(),
+            ctx,
+            Arc::new(ComposerRegistry::new()),
+            checkpointer,
+            thread_id,
+        );
+        Ok(event_stream(state))
+    }
 
-        let stream = futures_util::stream::unfold(
-            GraphExecutionState::new(workflow, ctx, checkpointer, thread_id),
-            |mut state| async move {
-                // If we have buffered events, emit them first
-                if let Some(event) = state.pending_events.pop_front() {
-                    return Some((Ok(event), state));
-                }
+    /// Run the graph workflow, resolving node agents through `registry`.
+    ///
+    /// An agent registered under a node's ID runs in place of that node's
+    /// `agent_step`; other nodes are built from their steps as usual. Lets
+    /// tests substitute agents for individual nodes.
+    #[cfg(test)]
+    pub async fn run_with_registry(
+        &self,
+        ctx: AgentContext,
+        registry: Arc<ComposerRegistry>,
+        checkpointer: Option<Arc<dyn Checkpointer + Send + Sync>>,
+        thread_id: String,
+    ) -> AppResult<AgentEventStream> {
+        let state = GraphExecutionState::new(self.clone(), ctx, registry, checkpointer, thread_id);
+        Ok(event_stream(state))
+    }
+}
 
-                // If execution is done, return None to end stream
-                if state.done {
-                    return None;
-                }
+/// Drive `state` node by node, yielding the events each node produces.
+fn event_stream(state: GraphExecutionState) -> AgentEventStream {
+    let stream = futures_util::stream::unfold(state, |mut state| async move {
+        // If we have buffered events, emit them first
+        if let Some(event) = state.pending_events.pop_front() {
+            return Some((Ok(event), state));
+        }
 
-                // Execute the next node
-                match execute_next_node(&mut state).await {
-                    Ok(()) => {
-                        // Pop the first buffered event
-                        if let Some(event) = state.pending_events.pop_front() {
-                            Some((Ok(event), state))
-                        } else {
-                            None
-                        }
-                    }
-                    Err(e) => {
-                        state.done = true;
-                        Some((Err(e), state))
-                    }
+        // If execution is done, return None to end stream
+        if state.done {
+            return None;
+        }
+
+        // Execute the next node
+        match execute_next_node(&mut state).await {
+            Ok(()) => {
+                // Pop the first buffered event
+                if let Some(event) = state.pending_events.pop_front() {
+                    Some((Ok(event), state))
+                } else {
+                    None
                 }
-            },
-        );
+            }
+            Err(e) => {
+                state.done = true;
+                Some((Err(e), state))
+            }
+        }
+    });
 
-        Ok(Box::pin(stream))
-    }
+    Box::pin(stream)
 }
 
 // ============================================================================
@@ -107,6 +137,8 @@ impl GraphWorkflow {
 struct GraphExecutionState {
     workflow: GraphWorkflow,
     ctx: AgentContext,
+    /// Registry used to build (or look up) node agents.
+    registry: Arc<ComposerRegistry>,
     current_node: Option<String>,
     graph_state: HashMap<String, Value>,
     visited_count: usize,
@@ -122,6 +154,7 @@ impl GraphExecutionState {
     fn new(
         workflow: GraphWorkflow,
         ctx: AgentContext,
+        registry: Arc<ComposerRegistry>,
         checkpointer: Option<Arc<dyn Checkpointer + Send + Sync>>,
         thread_id: String,
     ) -> Self {
@@ -138,6 +171,7 @@ impl GraphExecutionState {
         Self {
             workflow,
             ctx,
+            registry,
             current_node: Some(entry),
             graph_state,
             visited_count: 0,
@@ -163,6 +197,7 @@ impl GraphExecutionState {
         Self {
             workflow,
             ctx,
+            registry: Arc::new(ComposerRegistry::new()),
             current_node: Some(checkpoint.step.clone()),
             graph_state: checkpoint.state.clone(),
             visited_count: 0,
@@ -273,46 +308,28 @@ async fn execute_next_node(state: &mut GraphExecutionState) -> AppResult<()> {
             node_id: node_id.clone(),
         });
 
-    // Build and execute the agent for this node
-    let registry = ComposerRegistry::new();
-    let agent = build_agent_from_step(&node.agent_step, &registry)?;
-
-    let mut sub_ctx = state.ctx.clone();
-    // Inject graph state into shared state
-    {
-        let mut shared = sub_ctx.shared_state.write().await;
-        for (k, v) in &state.graph_state {
-            shared.insert(k.clone(), v.clone());
-        }
-    }
-
-    let mut stream = agent.run(sub_ctx).await?;
-    let mut node_output: Option<String> = None;
-
-    // Collect events from the sub-agent
-    while let Some(event_result) = stream.next().await {
-        let event = event_result?;
-        match &event {
-            AgentEvent::StateUpdate { key, value } => {
-                // Apply reducer to graph state
-                apply_reducer(
-                    &mut state.graph_state,
-                    key,
-                    value,
-                    &state.workflow.state_schema.reducers,
-                );
-                state.pending_events.push_back(event);
-            }
-            AgentEvent::Done { output } => {
-                node_output = output.clone();
-                // Don't forward Done events from sub-agents
-            }
-            _ => {
-                // Forward all other events
-                state.pending_events.push_back(event);
-            }
+    let node_output = match run_node(state, &node_id, &node.agent_step).await {
+        Ok(output) => output,
+        Err(e) => {
+            // Route to the error handler if one is wired; otherwise fail the run
+            let Some(handler) = find_error_handler(&node_id, &state.workflow.edges) else {
+                return Err(e);
+            };
+            let error = e.to_string();
+            state.graph_state.insert(
+                ERROR_STATE_KEY.to_string(),
+                serde_json::json!({ "node_id": node_id, "error": error }),
+            );
+            state.pending_events.push_back(AgentEvent::GraphNodeFailed {
+                node_id: node_id.clone(),
+                error,
+                handler: handler.clone(),
+            });
+            state.current_node = Some(handler);
+            save_checkpoint(state, &node_id, None).await;
+            return Ok(());
         }
-    }
+    };
 
     // Emit GraphNodeCompleted
     state
@@ -369,6 +386,59 @@ async fn execute_next_node(state: &mut GraphExecutionState) -> AppResult<()> {
     Ok(())
 }
 
+/// Build (or look up) the agent for a node, run it, and fold its events into
+/// the execution state. Returns the node's final output.
+async fn run_node(
+    state: &mut GraphExecutionState,
+    node_id: &str,
+    step: &AgentStep,
+) -> AppResult<Option<String>> {
+    let agent = match state.registry.get(node_id) {
+        Some(agent) => agent,
+        None => build_agent_from_step(step, &state.registry)?,
+    };
+
+    let mut sub_ctx = state.ctx.clone();
+    // Inject graph state into shared state
+    {
+        let mut shared = sub_ctx.shared_state.write().await;
+        for (k, v) in &state.graph_state {
+            shared.insert(k.clone(), v.clone());
+        }
+    }
+
+    let mut stream = agent.run(sub_ctx).await?;
+    let mut node_output: Option<String> = None;
+
+    // Collect events from the sub-agent
+    while let Some(event_result) = stream.next().await {
+        let event = event_result?;
+        match &event {
+            AgentEvent::StateUpdate { key, value } => {
+                // Validate against the schema, then apply reducer to graph state
+                validate_channel_write(&state.workflow.state_schema, node_id, key, value)?;
+                apply_reducer(
+                    &mut state.graph_state,
+                    key,
+                    value,
+                    &state.workflow.state_schema.reducers,
+                );
+                state.pending_events.push_back(event);
+            }
+            AgentEvent::Done { output } => {
+                node_output = output.clone();
+                // Don't forward Done events from sub-agents
+            }
+            _ => {
+                // Forward all other events
+                state.pending_events.push_back(event);
+            }
+        }
+    }
+
+    Ok(node_output)
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -414,6 +484,8 @@ fn find_next_node(
                     return Some(to.clone());
                 }
             }
+            // Only taken when the node fails; see `find_error_handler`
+            Edge::Error { .. } => {}
             Edge::Conditional {
                 from,
                 condition,
@@ -450,6 +522,100 @@ fn find_next_node(
     None
 }
 
+/// Find the handler node for a failed node via its error edge.
+fn find_error_handler(failed_node: &str, edges: &[Edge]) -> Option<String> {
+    edges.iter().find_map(|edge| match edge {
+        Edge::Error { from, to } if from == failed_node => Some(to.clone()),
+        _ => None,
+    })
+}
+
+/// JSON type name of a value, using the same vocabulary as `ChannelConfig::channel_type`.
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Whether a value satisfies a declared channel type.
+///
+/// Returns `None` for types the schema vocabulary doesn't know (e.g. "any"),
+/// which are left unchecked.
+fn value_matches_type(channel_type: &str, value: &Value) -> Option<bool> {
+    let matches = match channel_type.to_ascii_lowercase().as_str() {
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" | "bool" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => return None,
+    };
+    Some(matches)
+}
+
+/// Validate a node's write to a state channel before its reducer runs.
+///
+/// Checks the written value against the channel's declared type and the
+/// reducer's requirements: `Sum` needs a number (on a numeric channel),
+/// `Append` needs an array channel and accepts any element, and `Overwrite`
+/// replaces the value so it must match the declared type itself.
+/// Undeclared channels are only checked for reducer compatibility.
+fn validate_channel_write(
+    schema: &StateSchema,
+    node_id: &str,
+    key: &str,
+    value: &Value,
+) -> AppResult<()> {
+    let reducer = schema.reducers.get(key).unwrap_or(&Reducer::Overwrite);
+    let channel_type = schema.channels.get(key).map(|c| c.channel_type.as_str());
+    let mismatch = |expected: &str, context: &str| {
+        AppError::validation(format!(
+            "Node '{}' wrote channel '{}' with type {}, expected {}{}",
+            node_id,
+            key,
+            json_type_name(value),
+            expected,
+            context
+        ))
+    };
+
+    match reducer {
+        Reducer::Sum => {
+            if let Some(ty) = channel_type {
+                if value_matches_type(ty, &Value::from(0)) == Some(false) {
+                    return Err(AppError::validation(format!(
+                        "Node '{}' wrote channel '{}': sum reducer is incompatible with channel type {}",
+                        node_id, key, ty
+                    )));
+                }
+            }
+            if !value.is_number() {
+                return Err(mismatch("number", " (sum reducer)"));
+            }
+            Ok(())
+        }
+        Reducer::Append => match channel_type {
+            Some(ty) if value_matches_type(ty, &Value::Array(vec![])) == Some(false) => {
+                Err(AppError::validation(format!(
+                    "Node '{}' wrote channel '{}': append reducer is incompatible with channel type {}",
+                    node_id, key, ty
+                )))
+            }
+            _ => Ok(()),
+        },
+        Reducer::Overwrite => match channel_type {
+            Some(ty) if value_matches_type(ty, value) == Some(false) => Err(mismatch(ty, "")),
+            _ => Ok(()),
+        },
+    }
+}
+
 /// Apply a reducer to update graph state.
 fn apply_reducer(
     state: &mut HashMap<String, Value>,
@@ -543,6 +709,23 @@ mod tests {
         }
     }
 
+    struct FailingGraphAgent;
+
+    #[async_trait]
+    impl Agent for FailingGraphAgent {
+        fn name(&self) -> &str {
+            "failing"
+        }
+        fn description(&self) -> &str {
+            "Always fails"
+        }
+        async fn run(&self, _ctx: AgentContext) -> AppResult<AgentEventStream> {
+            let events: Vec<AppResult<AgentEvent>> =
+                vec![Err(AppError::internal("upstream timed out"))];
+            Ok(Box::pin(futures_util::stream::iter(events)))
+        }
+    }
+
     /// Mock LLM provider (never called in tests).
     struct MockProvider {
         config: crate::services::llm::ProviderConfig,
@@ -699,6 +882,65 @@ mod tests {
         assert_eq!(state.get("key"), Some(&serde_json::json!("new")));
     }
 
+    fn schema_with(channel: &str, channel_type: &str, reducer: Option<Reducer>) -> StateSchema {
+        let mut schema = StateSchema::default();
+        schema.channels.insert(
+            channel.to_string(),
+            ChannelConfig {
+                channel_type: channel_type.to_string(),
+                default_value: None,
+            },
+        );
+        if let Some(reducer) = reducer {
+            schema.reducers.insert(channel.to_string(), reducer);
+        }
+        schema
+    }
+
+    #[test]
+    fn test_validate_write_sum_rejects_wrong_type() {
+        let schema = schema_with("total", "number", Some(Reducer::Sum));
+        let err = validate_channel_write(&schema, "counter", "total", &serde_json::json!("five"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Node 'counter'"), "{}", err);
+        assert!(err.contains("channel 'total'"), "{}", err);
+        assert!(err.contains("type string, expected number"), "{}", err);
+
+        assert!(validate_channel_write(&schema, "counter", "total", &serde_json::json!(5)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_write_append_requires_array_channel() {
+        let schema = schema_with("items", "array", Some(Reducer::Append));
+        assert!(
+            validate_channel_write(&schema, "collector", "items", &serde_json::json!("x")).is_ok()
+        );
+
+        let schema = schema_with("items", "string", Some(Reducer::Append));
+        let err = validate_channel_write(&schema, "collector", "items", &serde_json::json!("x"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Node 'collector'"), "{}", err);
+        assert!(err.contains("channel 'items'"), "{}", err);
+        assert!(err.contains("append reducer is incompatible with channel type string"));
+    }
+
+    #[test]
+    fn test_validate_write_overwrite_checks_declared_type() {
+        let schema = schema_with("decision", "string", None);
+        let err = validate_channel_write(&schema, "router", "decision", &serde_json::json!(true))
+            .unwrap_err()
+            .to_string();
+        assert!(err
+            .contains("Node 'router' wrote channel 'decision' with type boolean, expected string"));
+
+        // Unknown types and undeclared channels are not checked
+        let schema = schema_with("blob", "any", None);
+        assert!(validate_channel_write(&schema, "n", "blob", &serde_json::json!([1])).is_ok());
+        assert!(validate_channel_write(&schema, "n", "other", &serde_json::json!(1)).is_ok());
+    }
+
     #[test]
     fn test_find_next_node_direct_edge() {
         let edges = vec![Edge::Direct {
@@ -821,9 +1063,111 @@ mod tests {
         assert_eq!(workflow.description(), "Graph workflow agent");
     }
 
-    // Note: Full async integration tests for graph execution require
-    // mock agents registered in the registry. The LlmAgent in the step
-    // would require the full OrchestratorService which we can't easily
-    // mock. The synchronous unit tests above cover the core logic:
-    // reducer application, edge navigation, and cycle detection.
+    fn error_edge_workflow(with_error_edge: bool) -> GraphWorkflow {
+        let mut nodes = HashMap::new();
+        for id in ["fetch", "recover", "report"] {
+            nodes.insert(
+                id.to_string(),
+                GraphNode {
+                    id: id.to_string(),
+                    agent_step: sample_llm_step(id),
+                    position: None,
+                    interrupt_before: false,
+                    interrupt_after: false,
+                },
+            );
+        }
+        let mut edges = vec![
+            Edge::Direct {
+                from: "fetch".to_string(),
+                to: "report".to_string(),
+            },
+            Edge::Direct {
+                from: "recover".to_string(),
+                to: "report".to_string(),
+            },
+        ];
+        if with_error_edge {
+            edges.push(Edge::Error {
+                from: "fetch".to_string(),
+                to: "recover".to_string(),
+            });
+        }
+        GraphWorkflow {
+            name: "Error Edges".to_string(),
+            description: None,
+            nodes,
+            edges,
+            entry_node: "fetch".to_string(),
+            state_schema: StateSchema::default(),
+        }
+    }
+
+    fn error_edge_registry() -> Arc<ComposerRegistry> {
+        let mut registry = ComposerRegistry::new();
+        registry.register("fetch", Arc::new(FailingGraphAgent));
+        registry.register(
+            "recover",
+            Arc::new(
+                MockGraphAgent::new("recover", "used cache")
+                    .with_state_update("recovered", serde_json::json!(true)),
+            ),
+        );
+        registry.register("report", Arc::new(MockGraphAgent::new("report", "done")));
+        Arc::new(registry)
+    }
+
+    #[tokio::test]
+    async fn test_error_edge_routes_failure_to_handler() {
+        let workflow = error_edge_workflow(true);
+        let mut stream = workflow
+            .run_with_registry(mock_context(), error_edge_registry(), None, "t".to_string())
+            .await
+            .unwrap();
+
+        let mut events = vec![];
+        while let Some(event) = stream.next().await {
+            events.push(event.expect("handled failure must not fail the run"));
+        }
+
+        assert!(events.iter().any(|e| matches!(
+            e,
+            AgentEvent::GraphNodeFailed { node_id, error, handler }
+                if node_id == "fetch" && error.contains("upstream timed out") && handler == "recover"
+        )));
+        let completed: Vec<&str> = events
+            .iter()
+            .filter_map(|e| match e {
+                AgentEvent::GraphNodeCompleted { node_id, .. } => Some(node_id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(completed, vec!["recover", "report"]);
+
+        let output = match events.last() {
+            Some(AgentEvent::Done { output }) => output.clone().unwrap(),
+            other => panic!("expected Done, got {:?}", other),
+        };
+        let final_state: HashMap<String, Value> = serde_json::from_str(&output).unwrap();
+        assert_eq!(final_state["recovered"], serde_json::json!(true));
+        assert_eq!(final_state[ERROR_STATE_KEY]["node_id"], "fetch");
+    }
+
+    #[tokio::test]
+    async fn test_unhandled_node_error_fails_run() {
+        let workflow = error_edge_workflow(false);
+        let mut stream = workflow
+            .run_with_registry(mock_context(), error_edge_registry(), None, "t".to_string())
+            .await
+            .unwrap();
+
+        let mut failed = false;
+        while let Some(event) = stream.next().await {
+            if let Err(e) = event {
+                assert!(e.to_string().contains("upstream timed out"));
+                failed = true;
+            }
+        }
+        assert!(failed);
+    }
 }
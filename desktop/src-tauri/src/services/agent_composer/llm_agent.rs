@@ -11,6 +11,7 @@ use async_trait::async_trait;
 use tokio::sync::mpsc;
 
 use super::types::{Agent, AgentConfig, AgentContext, AgentEvent, AgentEventStream};
+use crate::services::core::adapter::tokio_token_from_core;
 use crate::services::orchestrator::{ExecutionKind, OrchestratorConfig, OrchestratorService};
 use crate::services::streaming::UnifiedStreamEvent;
 use crate::utils::error::AppResult;
@@ -77,6 +78,22 @@ impl LlmAgent {
         self.config = config;
         self
     }
+
+    /// Provider configuration for this agent's step, applying the model
+    /// override and the step's sampling settings.
+    fn provider_config(&self, default_model: &str) -> crate::services::llm::ProviderConfig {
+        let defaults = crate::services::llm::ProviderConfig::default();
+        crate::services::llm::ProviderConfig {
+            model: self
+                .model
+                .clone()
+                .unwrap_or_else(|| default_model.to_string()),
+            temperature: self.config.temperature.unwrap_or(defaults.temperature),
+            top_p: self.config.top_p,
+            seed: self.config.seed,
+            ..defaults
+        }
+    }
 }
 
 /// Convert a `UnifiedStreamEvent` to an `AgentEvent`.
@@ -157,14 +174,7 @@ impl Agent for LlmAgent {
 
     async fn run(&self, ctx: AgentContext) -> AppResult<AgentEventStream> {
         // Build the OrchestratorConfig from the AgentContext
-        let provider_config = crate::services::llm::ProviderConfig {
-            model: if let Some(ref model) = self.model {
-                model.clone()
-            } else {
-                ctx.provider.model().to_string()
-            },
-            ..Default::default()
-        };
+        let provider_config = self.provider_config(ctx.provider.model());
 
         // Compute analysis artifacts root using the same logic as the orchestrator
         let analysis_artifacts_root = dirs::home_dir()
@@ -196,15 +206,27 @@ impl Agent for LlmAgent {
         // from the context would be used directly, but since the existing
         // OrchestratorService constructor always creates its own provider,
         // we pass the config and let it handle construction.
-        let orchestrator = OrchestratorService::new(orchestrator_config)
+        let mut orchestrator = OrchestratorService::new(orchestrator_config)
             .with_guardrail_hooks(crate::services::guardrail::shared_guardrail_registry());
 
+        // Follow the parent context's cancellation and deadline, so cancelling
+        // the workflow reaches this step's provider calls and tools.
+        if let Some(orchestrator_ctx) = ctx.orchestrator_ctx.as_ref() {
+            orchestrator = orchestrator.with_cancellation_token(tokio_token_from_core(
+                orchestrator_ctx.cancellation_token(),
+            ));
+        }
+
         // Create the mpsc channel pair
         let (tx, rx) = mpsc::channel::<UnifiedStreamEvent>(256);
 
         // Extract the message from AgentInput
         let message = ctx.input.as_text();
 
+        // Cancel the execution when the consumer drops the event stream
+        // (e.g. a losing `FirstSuccess` branch of a `ParallelAgent`).
+        let cancel_on_drop = orchestrator.cancellation_token().drop_guard();
+
         // Spawn the execute() call on a tokio task
         let _shared_state = ctx.shared_state.clone();
         tokio::spawn(async move {
@@ -213,7 +235,7 @@ impl Agent for LlmAgent {
         });
 
         // Build a stream that maps UnifiedStreamEvent -> AgentEvent
-        let stream = async_stream(rx);
+        let stream = async_stream(rx, cancel_on_drop);
         Ok(stream)
     }
 }
@@ -221,13 +243,17 @@ impl Agent for LlmAgent {
 /// Create an AgentEventStream from a receiver of UnifiedStreamEvents.
 ///
 /// Filters and maps events, skipping those without a direct AgentEvent mapping.
-fn async_stream(rx: mpsc::Receiver<UnifiedStreamEvent>) -> AgentEventStream {
-    let stream = futures_util::stream::unfold(rx, |mut rx| async move {
+/// `guard` is held for the lifetime of the stream.
+fn async_stream(
+    rx: mpsc::Receiver<UnifiedStreamEvent>,
+    guard: tokio_util::sync::DropGuard,
+) -> AgentEventStream {
+    let stream = futures_util::stream::unfold((rx, guard), |(mut rx, guard)| async move {
         loop {
             match rx.recv().await {
                 Some(event) => {
                     if let Some(agent_event) = convert_stream_event(&event) {
-                        return Some((Ok(agent_event), rx));
+                        return Some((Ok(agent_event), (rx, guard)));
                     }
                     // Skip events without a mapping, continue to next
                     continue;
@@ -431,6 +457,36 @@ mod tests {
         assert_eq!(agent.config.soft_limit_override, Some(10));
     }
 
+    #[test]
+    fn test_provider_config_forwards_step_sampling_settings() {
+        let agent = LlmAgent::new("seeded").with_config(AgentConfig {
+            temperature: Some(0.0),
+            top_p: Some(0.9),
+            seed: Some(1234),
+            ..Default::default()
+        });
+
+        let config = agent.provider_config("gpt-4o");
+        assert_eq!(config.model, "gpt-4o");
+        assert_eq!(config.temperature, 0.0);
+        assert_eq!(config.top_p, Some(0.9));
+        assert_eq!(config.seed, Some(1234));
+    }
+
+    #[test]
+    fn test_provider_config_defaults_without_overrides() {
+        let config = LlmAgent::new("plain")
+            .with_model("claude-3-5-sonnet")
+            .provider_config("ignored");
+        assert_eq!(config.model, "claude-3-5-sonnet");
+        assert_eq!(
+            config.temperature,
+            crate::services::llm::ProviderConfig::default().temperature
+        );
+        assert!(config.seed.is_none());
+        assert!(config.top_p.is_none());
+    }
+
     #[test]
     fn test_llm_agent_default() {
         let agent = LlmAgent::new("default-agent");
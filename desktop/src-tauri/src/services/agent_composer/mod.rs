@@ -9,6 +9,7 @@
 //! - **ParallelAgent**: Runs sub-agents concurrently, merging event streams
 //! - **ConditionalAgent**: Routes to branches based on shared state
 //! - **ComposerRegistry**: Named agent storage with pipeline construction
+//! - **portable**: Versioned export/import format for sharing pipelines
 
 pub mod codegen;
 pub mod conditional;
@@ -19,6 +20,7 @@ pub mod graph_workflow;
 pub mod llm_agent;
 pub mod loop_agent;
 pub mod parallel;
+pub mod portable;
 pub mod registry;
 pub mod sequential;
 pub mod types;
@@ -27,12 +29,12 @@ pub mod types;
 pub use conditional::ConditionalAgent;
 pub use llm_agent::{convert_stream_event, LlmAgent};
 pub use loop_agent::LoopAgent;
-pub use parallel::ParallelAgent;
+pub use parallel::{BranchOutput, MergeReducer, ParallelAgent};
 pub use registry::{AgentInfo, ComposerRegistry};
 pub use sequential::SequentialAgent;
 pub use types::{
     Agent, AgentConfig, AgentContext, AgentEvent, AgentEventStream, AgentInput, AgentPipeline,
-    AgentPipelineInfo, AgentStep, LlmStepConfig,
+    AgentPipelineInfo, AgentStep, LlmStepConfig, MergeStrategy, StepFailurePolicy,
 };
 
 // Re-export graph workflow types
@@ -1,28 +1,56 @@
 //! ParallelAgent — runs sub-agents concurrently, merging event streams
 //!
-//! All sub-agents are spawned via `tokio::spawn` and their event streams
-//! are merged using `futures_util::stream::select_all`. Each sub-agent's
-//! events are tagged with the agent name for disambiguation.
+//! All sub-agents are started up front and their event streams are merged
+//! using `futures_util::stream::select_all`. Each sub-agent's events are
+//! tagged with the agent name for disambiguation.
 //!
-//! The ParallelAgent completes when ALL sub-agents have emitted a `Done` event.
+//! The branches' final outputs are combined according to the context's
+//! `AgentConfig::merge_strategy`: concatenation in declaration order, an LLM
+//! synthesis step, the first branch to succeed (cancelling the others), or
+//! a named custom reducer.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use futures_util::stream::SelectAll;
+use futures_util::stream::{BoxStream, SelectAll};
 use futures_util::StreamExt;
+use tokio::sync::mpsc;
 
-use super::types::{Agent, AgentContext, AgentEvent, AgentEventStream};
+use super::llm_agent::LlmAgent;
+use super::types::{Agent, AgentContext, AgentEvent, AgentEventStream, AgentInput, MergeStrategy};
 use crate::utils::error::{AppError, AppResult};
 
+/// Separator placed between branch outputs by `MergeStrategy::Concat`.
+const CONCAT_SEPARATOR: &str = "\n\n---\n\n";
+
+/// Default instruction for `MergeStrategy::LlmSynthesize`.
+const DEFAULT_SYNTHESIS_INSTRUCTION: &str = "You are given the results of several agents that \
+    worked on the same task in parallel. Combine them into a single, coherent answer, \
+    resolving contradictions and removing duplication.";
+
+/// Final output of one parallel branch, passed to custom reducers.
+#[derive(Debug, Clone)]
+pub struct BranchOutput {
+    /// Name of the sub-agent.
+    pub agent: String,
+    /// Its final output, if it produced one.
+    pub output: Option<String>,
+}
+
+/// A user-provided reducer for `MergeStrategy::Custom`.
+///
+/// Receives the branch outputs in declaration order.
+pub type MergeReducer = Arc<dyn Fn(&[BranchOutput]) -> Option<String> + Send + Sync>;
+
 /// A composite agent that runs sub-agents concurrently.
 ///
-/// All sub-agents receive the same input and run in parallel via `tokio::spawn`.
-/// Their event streams are merged. `StateUpdate` events are prefixed with
-/// the agent name to avoid key collisions.
+/// All sub-agents receive the same input and run in parallel. Their event
+/// streams are merged. `StateUpdate` events are prefixed with the agent name
+/// to avoid key collisions.
 ///
-/// The merged stream ends when all sub-agents have emitted their `Done` events,
-/// at which point a final `Done` event is emitted with combined outputs.
+/// The merged stream ends with a single `Done` event whose output is built
+/// by the merge strategy. Dropping a branch's stream cancels that branch.
 pub struct ParallelAgent {
     /// Display name for this composite agent.
     name: String,
@@ -30,6 +58,8 @@ pub struct ParallelAgent {
     description: String,
     /// Sub-agents to run concurrently.
     agents: Vec<Arc<dyn Agent>>,
+    /// Named reducers available to `MergeStrategy::Custom`.
+    reducers: HashMap<String, MergeReducer>,
 }
 
 impl ParallelAgent {
@@ -39,6 +69,7 @@ impl ParallelAgent {
             name: name.into(),
             description: "Runs sub-agents concurrently, merging event streams".to_string(),
             agents,
+            reducers: HashMap::new(),
         }
     }
 
@@ -47,6 +78,18 @@ impl ParallelAgent {
         self.description = desc.into();
         self
     }
+
+    /// Register a reducer usable via `MergeStrategy::Custom { reducer: name }`.
+    pub fn with_reducer(mut self, name: impl Into<String>, reducer: MergeReducer) -> Self {
+        self.reducers.insert(name.into(), reducer);
+        self
+    }
+
+    /// Register several named reducers at once.
+    pub fn with_reducers(mut self, reducers: HashMap<String, MergeReducer>) -> Self {
+        self.reducers.extend(reducers);
+        self
+    }
 }
 
 #[async_trait]
@@ -64,99 +107,183 @@ impl Agent for ParallelAgent {
             return Err(AppError::validation("ParallelAgent has no sub-agents"));
         }
 
-        let total_agents = self.agents.len();
+        let strategy = ctx.config.merge_strategy.clone();
+        let reducer = match &strategy {
+            MergeStrategy::Custom { reducer } => {
+                Some(self.reducers.get(reducer).cloned().ok_or_else(|| {
+                    AppError::validation(format!(
+                        "ParallelAgent '{}' has no reducer named '{}'",
+                        self.name, reducer
+                    ))
+                })?)
+            }
+            _ => None,
+        };
 
-        // Start all sub-agents and collect their event streams
-        let mut tagged_streams: Vec<AgentEventStream> = Vec::with_capacity(total_agents);
+        // Start all sub-agents, tagging each event with its branch index
+        let mut merged: SelectAll<BoxStream<'static, (usize, AppResult<AgentEvent>)>> =
+            SelectAll::new();
+        let mut names = Vec::with_capacity(self.agents.len());
 
-        for agent in &self.agents {
-            let sub_ctx = ctx.clone();
+        for (index, agent) in self.agents.iter().enumerate() {
             let agent_name = agent.name().to_string();
+            let stream = agent.run(ctx.clone()).await.map_err(|e| {
+                AppError::internal(format!(
+                    "Failed to start sub-agent '{}': {}",
+                    agent.name(),
+                    e
+                ))
+            })?;
+            let tag_name = agent_name.clone();
+            merged.push(
+                stream
+                    .map(move |result| (index, result.map(|event| tag_event(event, &tag_name))))
+                    .boxed(),
+            );
+            names.push(agent_name);
+        }
+
+        let (tx, rx) = mpsc::channel::<AppResult<AgentEvent>>(256);
+        let driver = MergeDriver {
+            name: self.name.clone(),
+            names,
+            strategy,
+            reducer,
+            ctx,
+            tx,
+        };
+        tokio::spawn(driver.run(merged));
+
+        Ok(Box::pin(tokio_stream::wrappers::ReceiverStream::new(rx)))
+    }
+}
+
+/// Drives the merged branch streams and produces the final `Done` event.
+struct MergeDriver {
+    name: String,
+    names: Vec<String>,
+    strategy: MergeStrategy,
+    reducer: Option<MergeReducer>,
+    ctx: AgentContext,
+    tx: mpsc::Sender<AppResult<AgentEvent>>,
+}
 
-            match agent.run(sub_ctx).await {
-                Ok(stream) => {
-                    // Tag each event with the agent name
-                    let tagged =
-                        stream.map(move |result| result.map(|event| tag_event(event, &agent_name)));
-                    tagged_streams.push(Box::pin(tagged));
+impl MergeDriver {
+    async fn run(self, mut merged: SelectAll<BoxStream<'static, (usize, AppResult<AgentEvent>)>>) {
+        let total = self.names.len();
+        let mut outputs: Vec<Option<String>> = vec![None; total];
+        let mut done = vec![false; total];
+
+        while let Some((index, item)) = merged.next().await {
+            match item {
+                Ok(AgentEvent::Done { output }) => {
+                    if self.strategy == MergeStrategy::FirstSuccess && output.is_some() {
+                        // Dropping the remaining streams cancels the losing branches.
+                        drop(merged);
+                        let _ = self.tx.send(Ok(AgentEvent::Done { output })).await;
+                        return;
+                    }
+                    outputs[index] = output;
+                    done[index] = true;
+                    if done.iter().all(|d| *d) {
+                        break;
+                    }
                 }
-                Err(e) => {
-                    return Err(AppError::internal(format!(
-                        "Failed to start sub-agent '{}': {}",
-                        agent.name(),
-                        e
-                    )));
+                other => {
+                    if self.tx.send(other).await.is_err() {
+                        // Consumer went away; dropping `merged` cancels all branches.
+                        return;
+                    }
                 }
             }
         }
+        drop(merged);
+
+        let branches: Vec<BranchOutput> = self
+            .names
+            .iter()
+            .cloned()
+            .zip(outputs)
+            .map(|(agent, output)| BranchOutput { agent, output })
+            .collect();
+
+        let output = match &self.strategy {
+            MergeStrategy::Concat => concat_outputs(&branches),
+            // No branch produced an output.
+            MergeStrategy::FirstSuccess => None,
+            MergeStrategy::Custom { .. } => self.reducer.as_ref().and_then(|r| r(&branches)),
+            MergeStrategy::LlmSynthesize { instruction } => {
+                match self.synthesize(&branches, instruction.as_deref()).await {
+                    Some(output) => output,
+                    None => return,
+                }
+            }
+        };
+        let _ = self.tx.send(Ok(AgentEvent::Done { output })).await;
+    }
 
-        // Merge all streams using select_all
-        let mut merged = SelectAll::new();
-        for stream in tagged_streams {
-            merged.push(stream);
+    /// Run the synthesis LLM step, forwarding its events.
+    ///
+    /// Returns `None` if the consumer went away. Falls back to concatenation
+    /// when the synthesizer cannot be started.
+    async fn synthesize(
+        &self,
+        branches: &[BranchOutput],
+        instruction: Option<&str>,
+    ) -> Option<Option<String>> {
+        let produced: Vec<&BranchOutput> = branches.iter().filter(|b| b.output.is_some()).collect();
+        if produced.len() < 2 {
+            return Some(concat_outputs(branches));
         }
 
-        // Track Done events and produce a final Done when all complete
-        let stream = futures_util::stream::unfold(
-            ParallelState {
-                merged,
-                done_count: 0,
-                total: total_agents,
-                outputs: Vec::new(),
-            },
-            |mut state| async move {
-                loop {
-                    match state.merged.next().await {
-                        Some(Ok(event)) => {
-                            match &event {
-                                AgentEvent::Done { output } => {
-                                    if let Some(out) = output {
-                                        state.outputs.push(out.clone());
-                                    }
-                                    state.done_count += 1;
-
-                                    if state.done_count >= state.total {
-                                        // All agents done — emit final Done
-                                        let combined = if state.outputs.is_empty() {
-                                            None
-                                        } else {
-                                            Some(state.outputs.join("\n\n---\n\n"))
-                                        };
-                                        return Some((
-                                            Ok(AgentEvent::Done { output: combined }),
-                                            state,
-                                        ));
-                                    }
-                                    // Don't emit intermediate Done events
-                                    continue;
-                                }
-                                _ => {
-                                    return Some((Ok(event), state));
-                                }
-                            }
-                        }
-                        Some(Err(e)) => {
-                            return Some((Err(e), state));
-                        }
-                        None => {
-                            // All streams ended
-                            if state.done_count < state.total {
-                                // Some agents ended without Done
-                                let combined = if state.outputs.is_empty() {
-                                    None
-                                } else {
-                                    Some(state.outputs.join("\n\n---\n\n"))
-                                };
-                                return Some((Ok(AgentEvent::Done { output: combined }), state));
-                            }
-                            return None;
-                        }
-                    }
-                }
-            },
-        );
+        let prompt = produced
+            .iter()
+            .map(|b| {
+                format!(
+                    "## {}\n\n{}",
+                    b.agent,
+                    b.output.as_deref().unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let mut ctx = self.ctx.clone();
+        ctx.input = AgentInput::Text(prompt);
+
+        let synthesizer = LlmAgent::new(format!("{}-synthesizer", self.name))
+            .with_instruction(instruction.unwrap_or(DEFAULT_SYNTHESIS_INSTRUCTION))
+            .with_config(self.ctx.config.clone());
+        let mut stream = match synthesizer.run(ctx).await {
+            Ok(stream) => stream,
+            Err(e) => {
+                self.tx.send(Err(e)).await.ok()?;
+                return Some(concat_outputs(branches));
+            }
+        };
 
-        Ok(Box::pin(stream))
+        let mut output = None;
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(AgentEvent::Done {
+                    output: synthesized,
+                }) => output = synthesized,
+                other => self.tx.send(other).await.ok()?,
+            }
+        }
+        Some(output.or_else(|| concat_outputs(branches)))
+    }
+}
+
+/// Join branch outputs in declaration order, skipping branches without one.
+fn concat_outputs(branches: &[BranchOutput]) -> Option<String> {
+    let outputs: Vec<&str> = branches
+        .iter()
+        .filter_map(|b| b.output.as_deref())
+        .collect();
+    if outputs.is_empty() {
+        None
+    } else {
+        Some(outputs.join(CONCAT_SEPARATOR))
     }
 }
 
@@ -174,20 +301,13 @@ fn tag_event(event: AgentEvent, agent_name: &str) -> AgentEvent {
     }
 }
 
-/// Internal state for the parallel unfold stream.
-struct ParallelState {
-    merged: SelectAll<AgentEventStream>,
-    done_count: usize,
-    total: usize,
-    outputs: Vec<String>,
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::services::agent_composer::types::*;
     use std::collections::HashMap;
     use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::Arc;
     use tokio::sync::RwLock;
 
@@ -196,6 +316,8 @@ mod tests {
         name: String,
         output: String,
         delay_ms: u64,
+        /// Set when the consumer dropped the stream before the work finished.
+        cancelled: Arc<AtomicBool>,
     }
 
     impl MockParallelAgent {
@@ -204,6 +326,7 @@ mod tests {
                 name: name.to_string(),
                 output: output.to_string(),
                 delay_ms,
+                cancelled: Arc::new(AtomicBool::new(false)),
             }
         }
     }
@@ -226,10 +349,15 @@ mod tests {
             let (tx, rx) = tokio::sync::mpsc::channel::<AppResult<AgentEvent>>(16);
 
             let output_clone = output.clone();
+            let cancelled = self.cancelled.clone();
             tokio::spawn(async move {
-                // Simulate some work
-                if delay_ms > 0 {
-                    tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
+                // Simulate some work, stopping early if the stream is dropped
+                tokio::select! {
+                    _ = tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)) => {}
+                    _ = tx.closed() => {
+                        cancelled.store(true, Ordering::SeqCst);
+                        return;
+                    }
                 }
                 let _ = tx
                     .send(Ok(AgentEvent::TextDelta {
@@ -392,6 +520,91 @@ mod tests {
         }
     }
 
+    async fn collect(parallel: &ParallelAgent, strategy: MergeStrategy) -> Vec<AgentEvent> {
+        let mut ctx = mock_context();
+        ctx.config.merge_strategy = strategy;
+        let mut stream = parallel.run(ctx).await.unwrap();
+        let mut events = vec![];
+        while let Some(event) = stream.next().await {
+            events.push(event.unwrap());
+        }
+        events
+    }
+
+    fn final_output(events: &[AgentEvent]) -> Option<String> {
+        match events.last() {
+            Some(AgentEvent::Done { output }) => output.clone(),
+            other => panic!("Expected final Done event, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concat_preserves_declaration_order() {
+        // The first branch finishes last but still comes first in the output.
+        let slow = Arc::new(MockParallelAgent::new("slow", "output-A", 50)) as Arc<dyn Agent>;
+        let fast = Arc::new(MockParallelAgent::new("fast", "output-B", 0)) as Arc<dyn Agent>;
+        let parallel = ParallelAgent::new("par", vec![slow, fast]);
+
+        let events = collect(&parallel, MergeStrategy::Concat).await;
+        let done_count = events
+            .iter()
+            .filter(|e| matches!(e, AgentEvent::Done { .. }))
+            .count();
+        assert_eq!(done_count, 1, "only the merged Done is emitted");
+        assert_eq!(
+            final_output(&events).as_deref(),
+            Some("output-A\n\n---\n\noutput-B")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_first_success_cancels_slower_branch() {
+        let slow = Arc::new(MockParallelAgent::new("slow", "output-A", 500));
+        let slow_cancelled = slow.cancelled.clone();
+        let fast = Arc::new(MockParallelAgent::new("fast", "output-B", 0)) as Arc<dyn Agent>;
+        let parallel = ParallelAgent::new("par", vec![slow as Arc<dyn Agent>, fast]);
+
+        let started = std::time::Instant::now();
+        let events = collect(&parallel, MergeStrategy::FirstSuccess).await;
+        assert_eq!(final_output(&events).as_deref(), Some("output-B"));
+        assert!(started.elapsed() < std::time::Duration::from_millis(400));
+
+        // The slow branch observes its stream being dropped.
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(slow_cancelled.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_custom_reducer() {
+        let a = Arc::new(MockParallelAgent::new("a", "1", 0)) as Arc<dyn Agent>;
+        let b = Arc::new(MockParallelAgent::new("b", "2", 0)) as Arc<dyn Agent>;
+        let reducer: MergeReducer = Arc::new(|branches: &[BranchOutput]| {
+            Some(
+                branches
+                    .iter()
+                    .map(|b| format!("{}={}", b.agent, b.output.as_deref().unwrap_or("")))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+        });
+        let parallel = ParallelAgent::new("par", vec![a, b]).with_reducer("pairs", reducer);
+
+        let events = collect(
+            &parallel,
+            MergeStrategy::Custom {
+                reducer: "pairs".to_string(),
+            },
+        )
+        .await;
+        assert_eq!(final_output(&events).as_deref(), Some("a=1,b=2"));
+
+        let mut ctx = mock_context();
+        ctx.config.merge_strategy = MergeStrategy::Custom {
+            reducer: "missing".to_string(),
+        };
+        assert!(parallel.run(ctx).await.is_err());
+    }
+
     #[test]
     fn test_tag_event_state_update() {
         let event = AgentEvent::StateUpdate {
@@ -0,0 +1,541 @@
+//! Portable Agent Pipeline Format
+//!
+//! Versioned JSON format for sharing `AgentPipeline` definitions between
+//! machines. LLM step configurations are hoisted into a named `agents` map
+//! and referenced from steps by name; machine-specific data (ids,
+//! timestamps, home-directory paths, API keys) is stripped on export.
+//!
+//! Schema history:
+//! - v1: the raw `AgentPipeline` JSON with LLM configs inline in each step
+//!   (documents without a `schema_version` are treated as v1).
+//! - v2: hoisted `agents` map with `llm_step` steps referencing it by name.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::types::{AgentConfig, AgentPipeline, AgentStep, LlmStepConfig};
+use crate::utils::error::{AppError, AppResult};
+
+/// Current portable pipeline schema version.
+pub const PIPELINE_SCHEMA_VERSION: u32 = 2;
+
+/// Step types understood by the current schema.
+const KNOWN_STEP_TYPES: &[&str] = &[
+    "llm_step",
+    "sequential_step",
+    "parallel_step",
+    "conditional_step",
+    "loop_step",
+];
+
+/// A pipeline in the portable export format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortablePipeline {
+    pub schema_version: u32,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// LLM agents used by the pipeline, keyed by agent name.
+    #[serde(default)]
+    pub agents: BTreeMap<String, PortableAgent>,
+    pub steps: Vec<PortableStep>,
+}
+
+/// An LLM agent definition, i.e. an `LlmStepConfig` without its name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortableAgent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instruction: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<String>>,
+    #[serde(default)]
+    pub config: AgentConfig,
+}
+
+/// A pipeline step in the portable format; mirrors `AgentStep` except that
+/// LLM steps reference an entry of [`PortablePipeline::agents`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "step_type", rename_all = "snake_case")]
+pub enum PortableStep {
+    LlmStep {
+        agent: String,
+    },
+    SequentialStep {
+        name: String,
+        steps: Vec<PortableStep>,
+    },
+    ParallelStep {
+        name: String,
+        steps: Vec<PortableStep>,
+    },
+    ConditionalStep {
+        name: String,
+        condition_key: String,
+        branches: BTreeMap<String, PortableStep>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        default_branch: Option<Box<PortableStep>>,
+    },
+    LoopStep {
+        name: String,
+        condition_key: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        soft_limit_override: Option<u32>,
+        step: Box<PortableStep>,
+    },
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Convert a pipeline to the portable format, stripping machine-specific data.
+pub fn export_pipeline(pipeline: &AgentPipeline) -> AppResult<PortablePipeline> {
+    let home = dirs::home_dir();
+    let mut exporter = Exporter {
+        home: home.as_deref(),
+        agents: BTreeMap::new(),
+    };
+    let steps = pipeline
+        .steps
+        .iter()
+        .map(|step| exporter.step(step))
+        .collect::<AppResult<Vec<_>>>()?;
+
+    Ok(PortablePipeline {
+        schema_version: PIPELINE_SCHEMA_VERSION,
+        name: pipeline.name.clone(),
+        description: pipeline
+            .description
+            .as_deref()
+            .map(|d| strip_machine_specific(d, exporter.home)),
+        agents: exporter.agents,
+        steps,
+    })
+}
+
+struct Exporter<'a> {
+    home: Option<&'a Path>,
+    agents: BTreeMap<String, PortableAgent>,
+}
+
+impl Exporter<'_> {
+    fn step(&mut self, step: &AgentStep) -> AppResult<PortableStep> {
+        Ok(match step {
+            AgentStep::LlmStep(config) => PortableStep::LlmStep {
+                agent: self.agent(config)?,
+            },
+            AgentStep::SequentialStep { name, steps } => PortableStep::SequentialStep {
+                name: name.clone(),
+                steps: steps
+                    .iter()
+                    .map(|s| self.step(s))
+                    .collect::<AppResult<_>>()?,
+            },
+            AgentStep::ParallelStep { name, steps } => PortableStep::ParallelStep {
+                name: name.clone(),
+                steps: steps
+                    .iter()
+                    .map(|s| self.step(s))
+                    .collect::<AppResult<_>>()?,
+            },
+            AgentStep::ConditionalStep {
+                name,
+                condition_key,
+                branches,
+                default_branch,
+            } => PortableStep::ConditionalStep {
+                name: name.clone(),
+                condition_key: condition_key.clone(),
+                branches: branches
+                    .iter()
+                    .map(|(key, s)| Ok((key.clone(), self.step(s)?)))
+                    .collect::<AppResult<_>>()?,
+                default_branch: match default_branch {
+                    Some(s) => Some(Box::new(self.step(s)?)),
+                    None => None,
+                },
+            },
+            AgentStep::LoopStep {
+                name,
+                condition_key,
+                soft_limit_override,
+                step,
+            } => PortableStep::LoopStep {
+                name: name.clone(),
+                condition_key: condition_key.clone(),
+                soft_limit_override: *soft_limit_override,
+                step: Box::new(self.step(step)?),
+            },
+        })
+    }
+
+    /// Register an LLM step's agent and return the name it is exported under.
+    ///
+    /// Identical configurations sharing a name are stored once; differing
+    /// configurations with the same name get a numeric suffix.
+    fn agent(&mut self, config: &LlmStepConfig) -> AppResult<String> {
+        let agent = PortableAgent {
+            instruction: config
+                .instruction
+                .as_deref()
+                .map(|i| strip_machine_specific(i, self.home)),
+            model: config.model.clone(),
+            tools: config.tools.clone(),
+            config: config.config.clone(),
+        };
+        let fingerprint = serde_json::to_value(&agent)?;
+
+        let mut name = config.name.clone();
+        let mut suffix = 1;
+        while let Some(existing) = self.agents.get(&name) {
+            if serde_json::to_value(existing)? == fingerprint {
+                return Ok(name);
+            }
+            suffix += 1;
+            name = format!("{}-{}", config.name, suffix);
+        }
+        self.agents.insert(name.clone(), agent);
+        Ok(name)
+    }
+}
+
+fn secret_re() -> &'static Regex {
+    static SECRET_RE: OnceLock<Regex> = OnceLock::new();
+    SECRET_RE.get_or_init(|| {
+        Regex::new(
+            r#"(?i)\b(?:sk-ant-|sk-|xox[abp]-|ghp_|AIza)[A-Za-z0-9_\-]{16,}|\b(api[_-]?key|token|secret|password)(\s*[:=]\s*)[^\s,"']+"#,
+        )
+        .expect("valid secret regex")
+    })
+}
+
+/// Replace the home directory with `~` and redact API keys and secrets.
+fn strip_machine_specific(text: &str, home: Option<&Path>) -> String {
+    let mut text = text.to_string();
+    if let Some(home) = home.and_then(Path::to_str).filter(|h| h.len() > 1) {
+        text = text.replace(home, "~");
+    }
+    secret_re()
+        .replace_all(&text, |caps: &regex::Captures| match caps.get(1) {
+            Some(key) => format!("{}{}[REDACTED]", key.as_str(), &caps[2]),
+            None => "[REDACTED]".to_string(),
+        })
+        .into_owned()
+}
+
+// ============================================================================
+// Import
+// ============================================================================
+
+/// Parse and validate a portable pipeline document.
+///
+/// Older schema versions are migrated; newer ones are rejected. The returned
+/// pipeline has an empty `pipeline_id` so the caller assigns a fresh one.
+pub fn import_pipeline(json: &str) -> AppResult<AgentPipeline> {
+    let mut document: Value = serde_json::from_str(json)
+        .map_err(|e| AppError::parse(format!("Invalid pipeline JSON: {}", e)))?;
+
+    let version = match document.get("schema_version") {
+        None => 1,
+        Some(v) => v
+            .as_u64()
+            .ok_or_else(|| AppError::validation("schema_version must be a positive integer"))?,
+    };
+    if version == 0 || version > PIPELINE_SCHEMA_VERSION as u64 {
+        return Err(AppError::validation(format!(
+            "Unsupported pipeline schema version {} (this version supports 1 to {})",
+            version, PIPELINE_SCHEMA_VERSION
+        )));
+    }
+    if version == 1 {
+        document = migrate_v1(document)?;
+    }
+
+    let steps = document
+        .get("steps")
+        .and_then(Value::as_array)
+        .ok_or_else(|| AppError::validation("Pipeline has no steps array"))?;
+    for (i, step) in steps.iter().enumerate() {
+        check_step_types(step, &format!("steps[{}]", i))?;
+    }
+
+    let portable: PortablePipeline = serde_json::from_value(document)
+        .map_err(|e| AppError::validation(format!("Invalid pipeline: {}", e)))?;
+    if portable.steps.is_empty() {
+        return Err(AppError::validation("Pipeline has no steps"));
+    }
+
+    let steps = portable
+        .steps
+        .iter()
+        .enumerate()
+        .map(|(i, step)| resolve_step(step, &portable.agents, &format!("steps[{}]", i)))
+        .collect::<AppResult<Vec<_>>>()?;
+
+    Ok(AgentPipeline {
+        pipeline_id: String::new(),
+        name: portable.name,
+        description: portable.description,
+        steps,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        updated_at: None,
+    })
+}
+
+/// Migrate a v1 document (inline `AgentPipeline`) to the current schema.
+fn migrate_v1(mut document: Value) -> AppResult<Value> {
+    if let Some(obj) = document.as_object_mut() {
+        obj.entry("pipeline_id").or_insert_with(|| Value::from(""));
+        obj.entry("created_at").or_insert_with(|| Value::from(""));
+    }
+    if let Some(steps) = document.get("steps").and_then(Value::as_array) {
+        for (i, step) in steps.iter().enumerate() {
+            check_step_types(step, &format!("steps[{}]", i))?;
+        }
+    }
+    let pipeline: AgentPipeline = serde_json::from_value(document)
+        .map_err(|e| AppError::validation(format!("Invalid v1 pipeline: {}", e)))?;
+    let portable = export_pipeline(&pipeline)?;
+    Ok(serde_json::to_value(portable)?)
+}
+
+/// Reject unknown `step_type`s with the path of the offending step.
+fn check_step_types(step: &Value, path: &str) -> AppResult<()> {
+    let step_type = step
+        .get("step_type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| AppError::validation(format!("{}: missing step_type", path)))?;
+    if !KNOWN_STEP_TYPES.contains(&step_type) {
+        return Err(AppError::validation(format!(
+            "{}: unknown step type '{}'",
+            path, step_type
+        )));
+    }
+
+    if let Some(steps) = step.get("steps").and_then(Value::as_array) {
+        for (i, child) in steps.iter().enumerate() {
+            check_step_types(child, &format!("{}.steps[{}]", path, i))?;
+        }
+    }
+    if let Some(branches) = step.get("branches").and_then(Value::as_object) {
+        for (key, child) in branches {
+            check_step_types(child, &format!("{}.branches.{}", path, key))?;
+        }
+    }
+    for field in ["default_branch", "step"] {
+        if let Some(child) = step.get(field).filter(|v| v.is_object()) {
+            check_step_types(child, &format!("{}.{}", path, field))?;
+        }
+    }
+    Ok(())
+}
+
+fn resolve_step(
+    step: &PortableStep,
+    agents: &BTreeMap<String, PortableAgent>,
+    path: &str,
+) -> AppResult<AgentStep> {
+    let resolve_all = |steps: &[PortableStep]| {
+        steps
+            .iter()
+            .enumerate()
+            .map(|(i, s)| resolve_step(s, agents, &format!("{}.steps[{}]", path, i)))
+            .collect::<AppResult<Vec<_>>>()
+    };
+
+    Ok(match step {
+        PortableStep::LlmStep { agent } => {
+            let definition = agents.get(agent).ok_or_else(|| {
+                AppError::validation(format!("{}: references unknown agent '{}'", path, agent))
+            })?;
+            AgentStep::LlmStep(LlmStepConfig {
+                name: agent.clone(),
+                instruction: definition.instruction.clone(),
+                model: definition.model.clone(),
+                tools: definition.tools.clone(),
+                config: definition.config.clone(),
+            })
+        }
+        PortableStep::SequentialStep { name, steps } => AgentStep::SequentialStep {
+            name: name.clone(),
+            steps: resolve_all(steps)?,
+        },
+        PortableStep::ParallelStep { name, steps } => AgentStep::ParallelStep {
+            name: name.clone(),
+            steps: resolve_all(steps)?,
+        },
+        PortableStep::ConditionalStep {
+            name,
+            condition_key,
+            branches,
+            default_branch,
+        } => AgentStep::ConditionalStep {
+            name: name.clone(),
+            condition_key: condition_key.clone(),
+            branches: branches
+                .iter()
+                .map(|(key, s)| {
+                    let branch_path = format!("{}.branches.{}", path, key);
+                    Ok((key.clone(), resolve_step(s, agents, &branch_path)?))
+                })
+                .collect::<AppResult<HashMap<_, _>>>()?,
+            default_branch: match default_branch {
+                Some(s) => Some(Box::new(resolve_step(
+                    s,
+                    agents,
+                    &format!("{}.default_branch", path),
+                )?)),
+                None => None,
+            },
+        },
+        PortableStep::LoopStep {
+            name,
+            condition_key,
+            soft_limit_override,
+            step,
+        } => AgentStep::LoopStep {
+            name: name.clone(),
+            condition_key: condition_key.clone(),
+            soft_limit_override: *soft_limit_override,
+            step: Box::new(resolve_step(step, agents, &format!("{}.step", path))?),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn llm(name: &str, instruction: &str) -> AgentStep {
+        AgentStep::LlmStep(LlmStepConfig {
+            name: name.to_string(),
+            instruction: Some(instruction.to_string()),
+            model: Some("claude-sonnet-4".to_string()),
+            tools: Some(vec!["Read".to_string(), "Grep".to_string()]),
+            config: AgentConfig::default(),
+        })
+    }
+
+    fn sample_pipeline() -> AgentPipeline {
+        AgentPipeline {
+            pipeline_id: "local-id".to_string(),
+            name: "Review".to_string(),
+            description: Some("Plan, implement, review".to_string()),
+            steps: vec![
+                llm("planner", "Plan the change"),
+                AgentStep::ParallelStep {
+                    name: "implement".to_string(),
+                    steps: vec![llm("coder", "Write code"), llm("tester", "Write tests")],
+                },
+                AgentStep::LoopStep {
+                    name: "review-loop".to_string(),
+                    condition_key: "needs_changes".to_string(),
+                    soft_limit_override: Some(3),
+                    step: Box::new(llm("planner", "Plan the change")),
+                },
+            ],
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let exported = export_pipeline(&sample_pipeline()).unwrap();
+        assert_eq!(exported.schema_version, PIPELINE_SCHEMA_VERSION);
+        // "planner" is used twice with the same config and stored once.
+        assert_eq!(exported.agents.len(), 3);
+
+        let json = serde_json::to_string_pretty(&exported).unwrap();
+        assert!(!json.contains("local-id"));
+
+        let imported = import_pipeline(&json).unwrap();
+        assert!(imported.pipeline_id.is_empty());
+        assert_eq!(imported.name, "Review");
+        assert_eq!(imported.steps.len(), 3);
+        match &imported.steps[1] {
+            AgentStep::ParallelStep { steps, .. } => match &steps[1] {
+                AgentStep::LlmStep(config) => {
+                    assert_eq!(config.name, "tester");
+                    assert_eq!(config.instruction.as_deref(), Some("Write tests"));
+                    assert_eq!(config.model.as_deref(), Some("claude-sonnet-4"));
+                }
+                other => panic!("expected llm step, got {:?}", other),
+            },
+            other => panic!("expected parallel step, got {:?}", other),
+        }
+        match &imported.steps[2] {
+            AgentStep::LoopStep {
+                soft_limit_override,
+                step,
+                ..
+            } => {
+                assert_eq!(*soft_limit_override, Some(3));
+                assert!(matches!(step.as_ref(), AgentStep::LlmStep(c) if c.name == "planner"));
+            }
+            other => panic!("expected loop step, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_import_rejects_unknown_agent_reference() {
+        let json = r#"{
+            "schema_version": 2,
+            "name": "Broken",
+            "agents": {"planner": {"instruction": "Plan"}},
+            "steps": [
+                {"step_type": "llm_step", "agent": "planner"},
+                {"step_type": "sequential_step", "name": "s", "steps": [
+                    {"step_type": "llm_step", "agent": "ghost"}
+                ]}
+            ]
+        }"#;
+        let err = import_pipeline(json).unwrap_err().to_string();
+        assert!(err.contains("steps[1].steps[0]"), "{}", err);
+        assert!(err.contains("unknown agent 'ghost'"), "{}", err);
+    }
+
+    #[test]
+    fn test_import_rejects_unknown_step_type_and_future_version() {
+        let json =
+            r#"{"schema_version": 2, "name": "x", "steps": [{"step_type": "teleport_step"}]}"#;
+        let err = import_pipeline(json).unwrap_err().to_string();
+        assert!(err.contains("unknown step type 'teleport_step'"), "{}", err);
+
+        let json = r#"{"schema_version": 99, "name": "x", "steps": []}"#;
+        let err = import_pipeline(json).unwrap_err().to_string();
+        assert!(
+            err.contains("Unsupported pipeline schema version 99"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_import_migrates_v1_pipeline() {
+        let v1 = serde_json::to_string(&sample_pipeline()).unwrap();
+        let imported = import_pipeline(&v1).unwrap();
+        assert!(imported.pipeline_id.is_empty());
+        assert_eq!(imported.steps.len(), 3);
+    }
+
+    #[test]
+    fn test_strip_machine_specific() {
+        let home = Path::new("/home/alice");
+        let stripped = strip_machine_specific(
+            "Read /home/alice/work/spec.md using api_key=abc123 or sk-ant-REDACTED",
+            Some(home),
+        );
+        assert_eq!(
+            stripped,
+            "Read ~/work/spec.md using api_key=[REDACTED] or [REDACTED]"
+        );
+    }
+}
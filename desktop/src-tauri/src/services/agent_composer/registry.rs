@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use super::conditional::{ConditionFn, ConditionalAgent};
 use super::llm_agent::LlmAgent;
 use super::loop_agent::{build_loop_condition, LoopAgent};
-use super::parallel::ParallelAgent;
+use super::parallel::{MergeReducer, ParallelAgent};
 use super::sequential::SequentialAgent;
 use super::types::{Agent, AgentPipeline, AgentStep, LlmStepConfig};
 use crate::utils::error::{AppError, AppResult};
@@ -32,6 +32,8 @@ pub struct AgentInfo {
 /// serializable pipeline definitions.
 pub struct ComposerRegistry {
     agents: HashMap<String, Arc<dyn Agent>>,
+    /// Reducers for `MergeStrategy::Custom`, handed to every built `ParallelAgent`.
+    reducers: HashMap<String, MergeReducer>,
 }
 
 impl ComposerRegistry {
@@ -39,6 +41,7 @@ impl ComposerRegistry {
     pub fn new() -> Self {
         Self {
             agents: HashMap::new(),
+            reducers: HashMap::new(),
         }
     }
 
@@ -49,6 +52,11 @@ impl ComposerRegistry {
         self.agents.insert(name.into(), agent);
     }
 
+    /// Register a parallel merge reducer by name.
+    pub fn register_reducer(&mut self, name: impl Into<String>, reducer: MergeReducer) {
+        self.reducers.insert(name.into(), reducer);
+    }
+
     /// Get an agent by name.
     pub fn get(&self, name: &str) -> Option<Arc<dyn Agent>> {
         self.agents.get(name).cloned()
@@ -130,7 +138,9 @@ impl ComposerRegistry {
                     .iter()
                     .map(|s| self.build_step(s))
                     .collect::<AppResult<Vec<_>>>()?;
-                Ok(Arc::new(ParallelAgent::new(name.clone(), agents)))
+                Ok(Arc::new(
+                    ParallelAgent::new(name.clone(), agents).with_reducers(self.reducers.clone()),
+                ))
             }
             AgentStep::ConditionalStep {
                 name,
@@ -3,13 +3,20 @@
 //! The output of agent N becomes the input of agent N+1 via `AgentInput::Text`.
 //! All sub-agent events are forwarded to the caller's stream with agent-name
 //! prefixed keys for StateUpdate events.
+//!
+//! Each step reports `StepCompleted` or `StepFailed`; what happens after a
+//! failure is decided by the `StepFailurePolicy` (abort with the partial
+//! outputs, continue with the next step, or retry).
 
 use std::sync::Arc;
 
 use async_trait::async_trait;
 use futures_util::StreamExt;
+use tokio::sync::mpsc;
 
-use super::types::{Agent, AgentContext, AgentEvent, AgentEventStream, AgentInput};
+use super::types::{
+    Agent, AgentContext, AgentEvent, AgentEventStream, AgentInput, StepFailurePolicy,
+};
 use crate::utils::error::{AppError, AppResult};
 
 /// A composite agent that runs sub-agents sequentially.
@@ -24,6 +31,8 @@ pub struct SequentialAgent {
     description: String,
     /// Ordered list of sub-agents to run.
     agents: Vec<Arc<dyn Agent>>,
+    /// Failure policy override; defaults to `AgentConfig::on_step_failure`.
+    failure_policy: Option<StepFailurePolicy>,
 }
 
 impl SequentialAgent {
@@ -33,6 +42,7 @@ impl SequentialAgent {
             name: name.into(),
             description: "Runs sub-agents sequentially, chaining outputs".to_string(),
             agents,
+            failure_policy: None,
         }
     }
 
@@ -41,6 +51,12 @@ impl SequentialAgent {
         self.description = desc.into();
         self
     }
+
+    /// Override the step failure policy from the context's `AgentConfig`.
+    pub fn with_failure_policy(mut self, policy: StepFailurePolicy) -> Self {
+        self.failure_policy = Some(policy);
+        self
+    }
 }
 
 #[async_trait]
@@ -58,125 +74,142 @@ impl Agent for SequentialAgent {
             return Err(AppError::validation("SequentialAgent has no sub-agents"));
         }
 
-        // Clone what we need for the async block
-        let agents: Vec<Arc<dyn Agent>> = self.agents.clone();
-        let ctx = ctx;
-
-        // Build a stream that runs agents one at a time
-        let stream = futures_util::stream::unfold(
-            SequentialState::new(agents, ctx),
-            |mut state| async move {
-                loop {
-                    // If we have an active sub-stream, try to get the next event
-                    if let Some(ref mut sub_stream) = state.current_stream {
-                        match sub_stream.next().await {
-                            Some(Ok(event)) => {
-                                match &event {
-                                    AgentEvent::Done { output } => {
-                                        // Capture output for next agent's input
-                                        state.last_output = output.clone();
-                                        state.current_stream = None;
-                                        state.current_index += 1;
-
-                                        // If this was the last agent, emit Done
-                                        if state.current_index >= state.agents.len() {
-                                            return Some((
-                                                Ok(AgentEvent::Done {
-                                                    output: state.last_output.clone(),
-                                                }),
-                                                state,
-                                            ));
-                                        }
-
-                                        // Otherwise, don't emit the intermediate Done,
-                                        // just continue to start the next agent
-                                        continue;
-                                    }
-                                    AgentEvent::StateUpdate { key, value } => {
-                                        // Prefix state updates with agent name
-                                        let agent_name = &state.agents[state.current_index].name();
-                                        let prefixed = AgentEvent::StateUpdate {
-                                            key: format!("{}.{}", agent_name, key),
-                                            value: value.clone(),
-                                        };
-                                        return Some((Ok(prefixed), state));
-                                    }
-                                    _ => {
-                                        return Some((Ok(event), state));
-                                    }
-                                }
-                            }
-                            Some(Err(e)) => {
-                                return Some((Err(e), state));
-                            }
-                            None => {
-                                // Stream ended without Done event; move to next
-                                state.current_stream = None;
-                                state.current_index += 1;
-
-                                if state.current_index >= state.agents.len() {
-                                    return Some((
-                                        Ok(AgentEvent::Done {
-                                            output: state.last_output.clone(),
-                                        }),
-                                        state,
-                                    ));
-                                }
-                                continue;
-                            }
-                        }
-                    }
+        let policy = self.failure_policy.unwrap_or(ctx.config.on_step_failure);
+        let (tx, rx) = mpsc::channel::<AppResult<AgentEvent>>(256);
+        tokio::spawn(run_sequence(self.agents.clone(), ctx, policy, tx));
 
-                    // No active stream — check if we have more agents to run
-                    if state.current_index >= state.agents.len() {
-                        return None; // All agents done, end stream
-                    }
+        Ok(Box::pin(tokio_stream::wrappers::ReceiverStream::new(rx)))
+    }
+}
 
-                    // Start the next agent
-                    let agent = &state.agents[state.current_index];
-                    let mut sub_ctx = state.base_ctx.clone();
+/// How a single step attempt ended.
+enum StepOutcome {
+    /// The step emitted `Done`.
+    Done(Option<String>),
+    /// The step's stream ended without `Done`; the previous output is kept.
+    Ended,
+    /// The step could not start, yielded an error, or emitted `Failed`.
+    Failed(String),
+    /// The consumer dropped the stream.
+    Closed,
+}
 
-                    // Chain output from previous agent as input to current agent
-                    if let Some(ref prev_output) = state.last_output {
-                        sub_ctx.input = AgentInput::Text(prev_output.clone());
-                    }
+async fn run_sequence(
+    agents: Vec<Arc<dyn Agent>>,
+    ctx: AgentContext,
+    policy: StepFailurePolicy,
+    tx: mpsc::Sender<AppResult<AgentEvent>>,
+) {
+    let max_attempts = match policy {
+        StepFailurePolicy::Retry(retries) => retries.saturating_add(1),
+        _ => 1,
+    };
+    let mut last_output: Option<String> = None;
+    let mut partial_outputs: Vec<String> = Vec::new();
+
+    for (index, agent) in agents.iter().enumerate() {
+        let mut attempt = 0;
+        let result = loop {
+            attempt += 1;
+            let mut sub_ctx = ctx.clone();
+            // Chain output from previous agent as input to current agent
+            if let Some(ref prev_output) = last_output {
+                sub_ctx.input = AgentInput::Text(prev_output.clone());
+            }
 
-                    match agent.run(sub_ctx).await {
-                        Ok(stream) => {
-                            state.current_stream = Some(stream);
-                            continue;
-                        }
-                        Err(e) => {
-                            return Some((Err(e), state));
-                        }
-                    }
-                }
-            },
-        );
+            let error = match run_step(agent.as_ref(), sub_ctx, &tx).await {
+                StepOutcome::Done(output) => break Ok(Some(output)),
+                StepOutcome::Ended => break Ok(None),
+                StepOutcome::Closed => return,
+                StepOutcome::Failed(error) => error,
+            };
+            let will_retry = attempt < max_attempts;
+            let failed = AgentEvent::StepFailed {
+                agent: agent.name().to_string(),
+                index,
+                attempt,
+                error: error.clone(),
+                will_retry,
+            };
+            if tx.send(Ok(failed)).await.is_err() {
+                return;
+            }
+            if !will_retry {
+                break Err(error);
+            }
+        };
 
-        Ok(Box::pin(stream))
+        match result {
+            Ok(finished) => {
+                if let Some(output) = finished {
+                    partial_outputs.extend(output.clone());
+                    last_output = output;
+                }
+                let completed = AgentEvent::StepCompleted {
+                    agent: agent.name().to_string(),
+                    index,
+                    output: last_output.clone(),
+                };
+                if tx.send(Ok(completed)).await.is_err() {
+                    return;
+                }
+            }
+            Err(_) if policy == StepFailurePolicy::Continue => continue,
+            Err(error) => {
+                let aborted = AgentEvent::SequenceAborted {
+                    failed_step: agent.name().to_string(),
+                    error,
+                    partial_outputs,
+                };
+                if tx.send(Ok(aborted)).await.is_ok() {
+                    let _ = tx
+                        .send(Ok(AgentEvent::Done {
+                            output: last_output,
+                        }))
+                        .await;
+                }
+                return;
+            }
+        }
     }
-}
 
-/// Internal state for the sequential unfold stream.
-struct SequentialState {
-    agents: Vec<Arc<dyn Agent>>,
-    base_ctx: AgentContext,
-    current_index: usize,
-    current_stream: Option<AgentEventStream>,
-    last_output: Option<String>,
+    let _ = tx
+        .send(Ok(AgentEvent::Done {
+            output: last_output,
+        }))
+        .await;
 }
 
-impl SequentialState {
-    fn new(agents: Vec<Arc<dyn Agent>>, ctx: AgentContext) -> Self {
-        Self {
-            agents,
-            base_ctx: ctx,
-            current_index: 0,
-            current_stream: None,
-            last_output: None,
+/// Run one attempt of a step, forwarding its events to `tx`.
+async fn run_step(
+    agent: &dyn Agent,
+    ctx: AgentContext,
+    tx: &mpsc::Sender<AppResult<AgentEvent>>,
+) -> StepOutcome {
+    let mut stream = match agent.run(ctx).await {
+        Ok(stream) => stream,
+        Err(e) => return StepOutcome::Failed(e.to_string()),
+    };
+
+    while let Some(item) = stream.next().await {
+        let event = match item {
+            Ok(AgentEvent::Done { output }) => return StepOutcome::Done(output),
+            Ok(AgentEvent::Failed { error, .. }) => return StepOutcome::Failed(error),
+            Err(e) => return StepOutcome::Failed(e.to_string()),
+            Ok(AgentEvent::StateUpdate { key, value }) => {
+                // Prefix state updates with agent name
+                AgentEvent::StateUpdate {
+                    key: format!("{}.{}", agent.name(), key),
+                    value,
+                }
+            }
+            Ok(other) => other,
+        };
+        if tx.send(Ok(event)).await.is_err() {
+            return StepOutcome::Closed;
         }
     }
+    StepOutcome::Ended
 }
 
 #[cfg(test)]
@@ -185,6 +218,7 @@ mod tests {
     use crate::services::agent_composer::types::*;
     use std::collections::HashMap;
     use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
     use tokio::sync::RwLock;
 
@@ -234,6 +268,61 @@ mod tests {
         }
     }
 
+    /// A mock agent that fails its first `failures` runs, then succeeds.
+    struct FlakyAgent {
+        name: String,
+        failures: AtomicUsize,
+        runs: AtomicUsize,
+    }
+
+    impl FlakyAgent {
+        fn new(name: &str, failures: usize) -> Self {
+            Self {
+                name: name.to_string(),
+                failures: AtomicUsize::new(failures),
+                runs: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Agent for FlakyAgent {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn description(&self) -> &str {
+            "Flaky mock agent for testing"
+        }
+
+        async fn run(&self, ctx: AgentContext) -> AppResult<AgentEventStream> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            let remaining = self.failures.load(Ordering::SeqCst);
+            if remaining > 0 {
+                self.failures.store(remaining - 1, Ordering::SeqCst);
+                let stream = futures_util::stream::iter(vec![Err(AppError::internal(format!(
+                    "{} failed",
+                    self.name
+                )))]);
+                return Ok(Box::pin(stream));
+            }
+            let output = format!("{}+{}", ctx.input.as_text(), self.name);
+            let stream = futures_util::stream::iter(vec![Ok(AgentEvent::Done {
+                output: Some(output),
+            })]);
+            Ok(Box::pin(stream))
+        }
+    }
+
+    async fn collect_events(seq: &SequentialAgent) -> Vec<AgentEvent> {
+        let mut stream = seq.run(mock_context()).await.unwrap();
+        let mut events = vec![];
+        while let Some(event) = stream.next().await {
+            events.push(event.unwrap());
+        }
+        events
+    }
+
     /// Create a minimal AgentContext for testing (no real provider needed).
     fn mock_context() -> AgentContext {
         use crate::services::llm::types::ProviderConfig;
@@ -375,4 +464,107 @@ mod tests {
             panic!("Expected Done event");
         }
     }
+
+    #[tokio::test]
+    async fn test_abort_returns_partial_outputs() {
+        let first = Arc::new(MockAgent::new("first", "A")) as Arc<dyn Agent>;
+        let broken = Arc::new(FlakyAgent::new("broken", 1)) as Arc<dyn Agent>;
+        let never = Arc::new(FlakyAgent::new("never", 0));
+        let seq = SequentialAgent::new("seq", vec![first, broken, never.clone()])
+            .with_failure_policy(StepFailurePolicy::Abort);
+
+        let events = collect_events(&seq).await;
+
+        assert!(events.iter().any(|e| matches!(
+            e,
+            AgentEvent::StepCompleted { agent, index: 0, .. } if agent == "first"
+        )));
+        assert!(events.iter().any(|e| matches!(
+            e,
+            AgentEvent::StepFailed { agent, will_retry: false, .. } if agent == "broken"
+        )));
+        match events
+            .iter()
+            .find(|e| matches!(e, AgentEvent::SequenceAborted { .. }))
+        {
+            Some(AgentEvent::SequenceAborted {
+                failed_step,
+                error,
+                partial_outputs,
+            }) => {
+                assert_eq!(failed_step, "broken");
+                assert!(error.contains("broken failed"));
+                assert_eq!(partial_outputs, &vec!["initial+A".to_string()]);
+            }
+            _ => panic!("Expected SequenceAborted event"),
+        }
+        match events.last() {
+            Some(AgentEvent::Done { output }) => {
+                assert_eq!(output.as_deref(), Some("initial+A"));
+            }
+            other => panic!("Expected final Done, got {:?}", other),
+        }
+        assert_eq!(
+            never.runs.load(Ordering::SeqCst),
+            0,
+            "later steps are skipped"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_continue_past_failure() {
+        let first = Arc::new(MockAgent::new("first", "A")) as Arc<dyn Agent>;
+        let broken = Arc::new(FlakyAgent::new("broken", 1)) as Arc<dyn Agent>;
+        let last = Arc::new(MockAgent::new("last", "C")) as Arc<dyn Agent>;
+        let seq = SequentialAgent::new("seq", vec![first, broken, last])
+            .with_failure_policy(StepFailurePolicy::Continue);
+
+        let events = collect_events(&seq).await;
+
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, AgentEvent::SequenceAborted { .. })));
+        let completed = events
+            .iter()
+            .filter(|e| matches!(e, AgentEvent::StepCompleted { .. }))
+            .count();
+        assert_eq!(completed, 2);
+        match events.last() {
+            // The step after the failure receives the last successful output.
+            Some(AgentEvent::Done { output }) => {
+                assert_eq!(output.as_deref(), Some("initial+A+C"));
+            }
+            other => panic!("Expected final Done, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_reruns_failed_step() {
+        let flaky = Arc::new(FlakyAgent::new("flaky", 2));
+        let seq = SequentialAgent::new("seq", vec![flaky.clone() as Arc<dyn Agent>])
+            .with_failure_policy(StepFailurePolicy::Retry(2));
+
+        let events = collect_events(&seq).await;
+
+        let retries = events
+            .iter()
+            .filter(|e| {
+                matches!(
+                    e,
+                    AgentEvent::StepFailed {
+                        will_retry: true,
+                        ..
+                    }
+                )
+            })
+            .count();
+        assert_eq!(retries, 2);
+        assert_eq!(flaky.runs.load(Ordering::SeqCst), 3);
+        match events.last() {
+            Some(AgentEvent::Done { output }) => {
+                assert_eq!(output.as_deref(), Some("initial+flaky"));
+            }
+            other => panic!("Expected final Done, got {:?}", other),
+        }
+    }
 }
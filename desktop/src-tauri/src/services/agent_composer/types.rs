@@ -197,6 +197,12 @@ pub enum AgentEvent {
         node_id: String,
         output: Option<String>,
     },
+    /// A graph node failed and execution was routed to its error handler.
+    GraphNodeFailed {
+        node_id: String,
+        error: String,
+        handler: String,
+    },
     /// A human review is required before continuing execution.
     HumanReviewRequired { node_id: String, context: String },
     /// Rich content for dynamic UI rendering.
@@ -239,6 +245,30 @@ pub enum AgentEvent {
         error: String,
         duration_ms: u64,
     },
+    /// A `SequentialAgent` step finished successfully.
+    StepCompleted {
+        agent: String,
+        index: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+    },
+    /// A `SequentialAgent` step failed (possibly to be retried).
+    StepFailed {
+        agent: String,
+        index: usize,
+        /// 1-based attempt number that failed.
+        attempt: u32,
+        error: String,
+        will_retry: bool,
+    },
+    /// A `SequentialAgent` stopped early after a step failed. The final
+    /// `Done` that follows carries the last successful output.
+    SequenceAborted {
+        failed_step: String,
+        error: String,
+        /// Outputs of the steps that completed before the failure, in order.
+        partial_outputs: Vec<String>,
+    },
     /// Execution was cancelled (lifecycle event from executor).
     Cancelled { run_id: String, duration_ms: u64 },
     /// Token usage update (lifecycle event from executor).
@@ -272,6 +302,54 @@ pub struct AgentConfig {
     /// LLM temperature setting.
     #[serde(default)]
     pub temperature: Option<f32>,
+    /// Nucleus sampling (top_p) setting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// Sampling seed for reproducible runs. Ignored by providers without
+    /// seed support, whose output stays non-deterministic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// How a `ParallelAgent` combines its branches' final outputs.
+    #[serde(default)]
+    pub merge_strategy: MergeStrategy,
+    /// What a `SequentialAgent` does when one of its steps fails.
+    #[serde(default)]
+    pub on_step_failure: StepFailurePolicy,
+}
+
+/// What a `SequentialAgent` does when a step fails.
+///
+/// A step fails when it cannot be started, yields an error, or emits a
+/// `Failed` event.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StepFailurePolicy {
+    /// Stop the sequence, returning the outputs accumulated so far.
+    #[default]
+    Abort,
+    /// Skip the failed step; the next step receives the last successful output.
+    Continue,
+    /// Re-run the failed step up to this many extra times, then abort.
+    Retry(u32),
+}
+
+/// How the final outputs of parallel branches are combined.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MergeStrategy {
+    /// Join all outputs in branch declaration order.
+    #[default]
+    Concat,
+    /// Run an extra LLM step that synthesizes the branch outputs.
+    LlmSynthesize {
+        /// Optional instruction overriding the default synthesis prompt.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        instruction: Option<String>,
+    },
+    /// Use the first branch that finishes with an output and cancel the rest.
+    FirstSuccess,
+    /// Use a reducer registered on the `ParallelAgent` under this name.
+    Custom { reducer: String },
 }
 
 fn default_max_total_tokens() -> u32 {
@@ -294,6 +372,10 @@ impl Default for AgentConfig {
             streaming: default_streaming(),
             enable_compaction: default_enable_compaction(),
             temperature: None,
+            top_p: None,
+            seed: None,
+            merge_strategy: MergeStrategy::default(),
+            on_step_failure: StepFailurePolicy::default(),
         }
     }
 }
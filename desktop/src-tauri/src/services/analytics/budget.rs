@@ -0,0 +1,202 @@
+//! Cost Projection & Budget Alerts
+//!
+//! Pure helpers for extrapolating month-end spend from recent daily usage and
+//! for turning budget threshold crossings into webhook notifications.
+//! Storage and alert de-duplication live in `AnalyticsService`.
+
+use chrono::{Datelike, TimeZone, Utc};
+
+use crate::models::analytics::{BudgetAlert, BudgetAlertKind};
+use crate::services::webhook::types::{WebhookEventType, WebhookPayload};
+
+/// Number of trailing days used to estimate the daily burn rate.
+pub const DEFAULT_PROJECTION_LOOKBACK_DAYS: i64 = 7;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// UTC calendar month containing a timestamp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonthBounds {
+    /// Month label in `YYYY-MM` form
+    pub label: String,
+    /// First second of the month (inclusive)
+    pub start: i64,
+    /// First second of the following month (exclusive)
+    pub end: i64,
+}
+
+impl MonthBounds {
+    /// Resolve the UTC month that contains `timestamp`.
+    pub fn containing(timestamp: i64) -> Self {
+        let dt = Utc
+            .timestamp_opt(timestamp, 0)
+            .single()
+            .unwrap_or_else(|| Utc.timestamp_opt(0, 0).unwrap());
+        let (year, month) = (dt.year(), dt.month());
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+
+        let start = Utc
+            .with_ymd_and_hms(year, month, 1, 0, 0, 0)
+            .single()
+            .map(|d| d.timestamp())
+            .unwrap_or(0);
+        let end = Utc
+            .with_ymd_and_hms(next_year, next_month, 1, 0, 0, 0)
+            .single()
+            .map(|d| d.timestamp())
+            .unwrap_or(start);
+
+        Self {
+            label: format!("{:04}-{:02}", year, month),
+            start,
+            end,
+        }
+    }
+
+    /// Total days in the month.
+    pub fn days_in_month(&self) -> u32 {
+        ((self.end - self.start) as f64 / SECONDS_PER_DAY).round() as u32
+    }
+
+    /// Fractional days elapsed at `now` (clamped to the month).
+    pub fn days_elapsed(&self, now: i64) -> f64 {
+        (now.clamp(self.start, self.end) - self.start) as f64 / SECONDS_PER_DAY
+    }
+
+    /// Fractional days remaining after `now` (clamped to the month).
+    pub fn days_remaining(&self, now: i64) -> f64 {
+        (self.end - now.clamp(self.start, self.end)) as f64 / SECONDS_PER_DAY
+    }
+}
+
+/// Average daily spend over a trailing window.
+pub fn daily_average(window_cost_microdollars: i64, lookback_days: i64) -> f64 {
+    if lookback_days <= 0 {
+        return 0.0;
+    }
+    window_cost_microdollars as f64 / lookback_days as f64
+}
+
+/// Month-end projection: actual month-to-date spend plus the daily average
+/// carried over the remaining (possibly fractional) days.
+pub fn extrapolate_month_end(
+    actual_month_to_date: i64,
+    daily_average_microdollars: f64,
+    days_remaining: f64,
+) -> i64 {
+    actual_month_to_date + (daily_average_microdollars * days_remaining.max(0.0)).round() as i64
+}
+
+/// Spend at which a budget alert fires.
+pub fn alert_threshold_microdollars(budget_microdollars: i64, threshold_percent: f64) -> i64 {
+    (budget_microdollars as f64 * threshold_percent / 100.0).round() as i64
+}
+
+/// Build the webhook notification for a budget alert.
+pub fn budget_alert_payload(alert: &BudgetAlert) -> WebhookPayload {
+    let scope = alert
+        .project_id
+        .as_deref()
+        .map(|p| format!("project {}", p))
+        .unwrap_or_else(|| "all projects".to_string());
+    let spend_label = match alert.kind {
+        BudgetAlertKind::Actual => "Actual",
+        BudgetAlertKind::Projected => "Projected",
+    };
+    let spend = match alert.kind {
+        BudgetAlertKind::Actual => alert.actual_cost_microdollars,
+        BudgetAlertKind::Projected => alert.projected_cost_microdollars,
+    };
+
+    WebhookPayload {
+        event_type: WebhookEventType::BudgetAlert,
+        project_path: alert.project_id.clone(),
+        summary: format!(
+            "{} spend for {} in {} is ${:.2} (budget ${:.2}, alert at ${:.2})",
+            spend_label,
+            scope,
+            alert.month,
+            spend as f64 / 1_000_000.0,
+            alert.budget_microdollars as f64 / 1_000_000.0,
+            alert.threshold_microdollars as f64 / 1_000_000.0,
+        ),
+        details: serde_json::to_value(alert).ok(),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_bounds_partial_month() {
+        // 2026-02-11 12:00:00 UTC
+        let now = Utc
+            .with_ymd_and_hms(2026, 2, 11, 12, 0, 0)
+            .unwrap()
+            .timestamp();
+        let bounds = MonthBounds::containing(now);
+
+        assert_eq!(bounds.label, "2026-02");
+        assert_eq!(bounds.days_in_month(), 28);
+        assert!((bounds.days_elapsed(now) - 10.5).abs() < 1e-9);
+        assert!((bounds.days_remaining(now) - 17.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_month_bounds_december_rollover() {
+        let now = Utc
+            .with_ymd_and_hms(2026, 12, 31, 23, 0, 0)
+            .unwrap()
+            .timestamp();
+        let bounds = MonthBounds::containing(now);
+        assert_eq!(bounds.label, "2026-12");
+        assert_eq!(bounds.days_in_month(), 31);
+        assert_eq!(
+            bounds.end,
+            Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0)
+                .unwrap()
+                .timestamp()
+        );
+    }
+
+    #[test]
+    fn test_projection_math_over_known_series() {
+        // Seven days at $1, $2, ..., $7 => $28 over the window, $4/day.
+        let window: i64 = (1..=7).map(|d| d * 1_000_000).sum();
+        let avg = daily_average(window, 7);
+        assert!((avg - 4_000_000.0).abs() < 1e-6);
+
+        // $30 so far, 17.5 days left => 30 + 4 * 17.5 = $100
+        assert_eq!(extrapolate_month_end(30_000_000, avg, 17.5), 100_000_000);
+        // Nothing left to extrapolate at month end
+        assert_eq!(extrapolate_month_end(30_000_000, avg, 0.0), 30_000_000);
+        assert_eq!(daily_average(window, 0), 0.0);
+    }
+
+    #[test]
+    fn test_alert_threshold_and_payload() {
+        assert_eq!(alert_threshold_microdollars(50_000_000, 80.0), 40_000_000);
+
+        let alert = BudgetAlert {
+            project_id: Some("proj-1".to_string()),
+            month: "2026-02".to_string(),
+            kind: BudgetAlertKind::Projected,
+            budget_microdollars: 50_000_000,
+            threshold_microdollars: 40_000_000,
+            actual_cost_microdollars: 20_000_000,
+            projected_cost_microdollars: 45_000_000,
+            triggered_at: 0,
+        };
+        let payload = budget_alert_payload(&alert);
+        assert_eq!(payload.event_type, WebhookEventType::BudgetAlert);
+        assert!(payload.summary.contains("Projected"));
+        assert!(payload.summary.contains("$45.00"));
+        assert_eq!(payload.project_path.as_deref(), Some("proj-1"));
+    }
+}
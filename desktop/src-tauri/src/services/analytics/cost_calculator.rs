@@ -6,7 +6,8 @@
 use std::collections::HashMap;
 use std::sync::RwLock;
 
-use crate::models::analytics::ModelPricing;
+use crate::models::analytics::{ModelPricing, PricingRule, PricingRuleStatus, UsageRecord};
+use crate::services::analytics::AnalyticsService;
 use crate::utils::error::{AppError, AppResult};
 
 /// Default pricing data for common models (in microdollars per million tokens)
@@ -91,6 +92,8 @@ pub struct CostCalculator {
     pricing: RwLock<HashMap<(String, String), ModelPricing>>,
     /// Custom overrides by (provider, model_name)
     custom_overrides: RwLock<HashMap<(String, String), ModelPricing>>,
+    /// Dated pricing rules, preferred over flat pricing when one covers the usage time
+    dated_rules: RwLock<Vec<PricingRule>>,
 }
 
 impl Default for CostCalculator {
@@ -112,9 +115,43 @@ impl CostCalculator {
         Self {
             pricing: RwLock::new(pricing_map),
             custom_overrides: RwLock::new(HashMap::new()),
+            dated_rules: RwLock::new(Vec::new()),
         }
     }
 
+    /// Replace the dated pricing rules used for timestamp-based selection
+    pub fn load_pricing_rules(&self, rules: Vec<PricingRule>) -> AppResult<()> {
+        let mut dated = self
+            .dated_rules
+            .write()
+            .map_err(|_| AppError::internal("Failed to acquire pricing rules lock"))?;
+        *dated = rules
+            .into_iter()
+            .filter(|r| r.status == PricingRuleStatus::Active)
+            .collect();
+        Ok(())
+    }
+
+    /// Select the active rule whose effective window contains `timestamp`.
+    /// When several match, the most recently effective one wins.
+    pub fn select_pricing_rule(
+        &self,
+        provider: &str,
+        model_name: &str,
+        timestamp: i64,
+    ) -> Option<PricingRule> {
+        let dated = self.dated_rules.read().ok()?;
+        dated
+            .iter()
+            .filter(|r| {
+                r.provider == provider
+                    && pricing_window_contains(r.effective_from, r.effective_to, timestamp)
+                    && AnalyticsService::wildcard_match(&r.model_pattern, model_name)
+            })
+            .max_by_key(|r| (r.effective_from, r.updated_at))
+            .cloned()
+    }
+
     /// Load pricing from database
     pub fn load_from_pricing_list(&self, pricing_list: Vec<ModelPricing>) -> AppResult<()> {
         let mut pricing = self
@@ -176,13 +213,34 @@ impl CostCalculator {
         input_tokens: i64,
         output_tokens: i64,
     ) -> i64 {
-        if let Some(pricing) = self.get_pricing(provider, model_name) {
-            pricing.calculate_cost(input_tokens, output_tokens)
+        self.calculate_cost_at(&UsageRecord::new(
+            model_name,
+            provider,
+            input_tokens,
+            output_tokens,
+        ))
+    }
+
+    /// Calculate the cost of a usage record with the pricing in effect at its timestamp.
+    /// Dated rules also price thinking and cache tokens; flat pricing only covers input/output.
+    pub fn calculate_cost_at(&self, record: &UsageRecord) -> i64 {
+        if let Some(rule) =
+            self.select_pricing_rule(&record.provider, &record.model_name, record.timestamp)
+        {
+            return (record.input_tokens * rule.input_per_million) / 1_000_000
+                + (record.output_tokens * rule.output_per_million) / 1_000_000
+                + (record.thinking_tokens * rule.thinking_per_million) / 1_000_000
+                + (record.cache_read_tokens * rule.cache_read_per_million) / 1_000_000
+                + (record.cache_creation_tokens * rule.cache_write_per_million) / 1_000_000;
+        }
+
+        if let Some(pricing) = self.get_pricing(&record.provider, &record.model_name) {
+            pricing.calculate_cost(record.input_tokens, record.output_tokens)
         } else {
             // Unknown model - estimate using average pricing
             // Default: $5/M input, $15/M output
-            let input_cost = (input_tokens * 5_000_000) / 1_000_000;
-            let output_cost = (output_tokens * 15_000_000) / 1_000_000;
+            let input_cost = (record.input_tokens * 5_000_000) / 1_000_000;
+            let output_cost = (record.output_tokens * 15_000_000) / 1_000_000;
             input_cost + output_cost
         }
     }
@@ -282,6 +340,21 @@ impl CostCalculator {
     }
 }
 
+/// Whether `[from, to)` contains `timestamp`; an open end never expires.
+pub fn pricing_window_contains(from: i64, to: Option<i64>, timestamp: i64) -> bool {
+    from <= timestamp && to.is_none_or(|end| timestamp < end)
+}
+
+/// Whether two half-open `[from, to)` effective windows overlap.
+pub fn pricing_windows_overlap(
+    a_from: i64,
+    a_to: Option<i64>,
+    b_from: i64,
+    b_to: Option<i64>,
+) -> bool {
+    a_from < b_to.unwrap_or(i64::MAX) && b_from < a_to.unwrap_or(i64::MAX)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -446,4 +519,63 @@ mod tests {
         // Should contain GPT models
         assert!(all.iter().any(|p| p.model_name.contains("gpt")));
     }
+
+    fn dated_rule(from: i64, to: Option<i64>, input: i64) -> PricingRule {
+        let mut rule = PricingRule::new("anthropic", "claude-sonnet-*");
+        rule.effective_from = from;
+        rule.effective_to = to;
+        rule.input_per_million = input;
+        rule.output_per_million = 0;
+        rule
+    }
+
+    #[test]
+    fn test_dated_pricing_selected_by_timestamp() {
+        let calc = CostCalculator::new();
+        calc.load_pricing_rules(vec![
+            dated_rule(1_000, Some(2_000), 3_000_000),
+            dated_rule(2_000, None, 6_000_000),
+        ])
+        .unwrap();
+
+        let model = "claude-sonnet-4-20250514";
+        let at = |timestamp: i64| {
+            let mut record = UsageRecord::new(model, "anthropic", 1_000, 0);
+            record.timestamp = timestamp;
+            calc.calculate_cost_at(&record)
+        };
+        assert_eq!(at(1_500), 3_000);
+        // The boundary belongs to the newer window
+        assert_eq!(at(2_000), 6_000);
+        assert_eq!(at(9_999), 6_000);
+        // Before any dated rule, fall back to flat pricing ($3/M)
+        assert_eq!(at(500), 3_000);
+        assert!(calc.select_pricing_rule("anthropic", model, 500).is_none());
+    }
+
+    #[test]
+    fn test_dated_pricing_includes_thinking_and_cache_tokens() {
+        let calc = CostCalculator::new();
+        let mut rule = dated_rule(1_000, None, 3_000_000);
+        rule.output_per_million = 15_000_000;
+        rule.thinking_per_million = 15_000_000;
+        rule.cache_read_per_million = 300_000;
+        rule.cache_write_per_million = 3_750_000;
+        calc.load_pricing_rules(vec![rule]).unwrap();
+
+        let mut record = UsageRecord::new("claude-sonnet-4-20250514", "anthropic", 1_000, 1_000)
+            .with_extended_tokens(2_000, 10_000, 1_000);
+        record.timestamp = 1_500;
+        // 3_000 input + 15_000 output + 30_000 thinking + 3_000 cache read + 3_750 cache write
+        assert_eq!(calc.calculate_cost_at(&record), 54_750);
+    }
+
+    #[test]
+    fn test_pricing_window_overlap() {
+        assert!(pricing_windows_overlap(0, Some(10), 5, Some(15)));
+        assert!(pricing_windows_overlap(0, None, 100, None));
+        assert!(!pricing_windows_overlap(0, Some(10), 10, Some(20)));
+        assert!(pricing_window_contains(10, Some(20), 10));
+        assert!(!pricing_window_contains(10, Some(20), 20));
+    }
 }
@@ -2,12 +2,18 @@
 //!
 //! Provides usage tracking, cost calculation, data aggregation, and export functionality.
 
+mod budget;
 mod cost_calculator;
+mod parquet_export;
 mod service;
+mod time_buckets;
 mod tracked_llm;
 mod tracker;
 
+pub use budget::*;
 pub use cost_calculator::*;
+pub use parquet_export::*;
 pub use service::*;
+pub use time_buckets::*;
 pub use tracked_llm::*;
 pub use tracker::*;
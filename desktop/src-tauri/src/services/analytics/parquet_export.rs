@@ -0,0 +1,248 @@
+//! Parquet Export
+//!
+//! Typed columnar writer for v2 usage records. Each batch handed to
+//! [`UsageParquetWriter::write_batch`] becomes one row group, so exports of
+//! any size only hold a single chunk in memory.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use parquet::data_type::{ByteArray, ByteArrayType, Int64Type};
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+
+use crate::models::analytics::UsageRecordV2;
+use crate::utils::error::{AppError, AppResult};
+
+/// Parquet schema for exported usage records, mirroring `UsageRecordV2`.
+/// `timestamp` is Unix seconds, matching the rest of the analytics store.
+pub const USAGE_PARQUET_SCHEMA: &str = "
+message usage_record {
+    REQUIRED BYTE_ARRAY event_id (UTF8);
+    OPTIONAL BYTE_ARRAY session_id (UTF8);
+    OPTIONAL BYTE_ARRAY project_id (UTF8);
+    REQUIRED BYTE_ARRAY model_name (UTF8);
+    REQUIRED BYTE_ARRAY provider (UTF8);
+    REQUIRED INT64 input_tokens;
+    REQUIRED INT64 output_tokens;
+    REQUIRED INT64 thinking_tokens;
+    REQUIRED INT64 cache_read_tokens;
+    REQUIRED INT64 cache_creation_tokens;
+    REQUIRED INT64 cost_microdollars;
+    REQUIRED INT64 timestamp;
+    OPTIONAL BYTE_ARRAY metadata (UTF8);
+    OPTIONAL BYTE_ARRAY pricing_rule_id (UTF8);
+    REQUIRED BYTE_ARRAY currency (UTF8);
+    REQUIRED BYTE_ARRAY cost_status (UTF8);
+    OPTIONAL BYTE_ARRAY cost_breakdown_json (UTF8);
+}
+";
+
+/// Column values for one row group, in schema order.
+enum ColumnValues {
+    Int64(Vec<i64>),
+    Utf8(Vec<ByteArray>),
+    OptionalUtf8 {
+        values: Vec<ByteArray>,
+        def_levels: Vec<i16>,
+    },
+}
+
+impl ColumnValues {
+    fn int64(rows: &[UsageRecordV2], f: impl Fn(&UsageRecordV2) -> i64) -> Self {
+        Self::Int64(rows.iter().map(f).collect())
+    }
+
+    fn utf8(rows: &[UsageRecordV2], f: impl Fn(&UsageRecordV2) -> &str) -> Self {
+        Self::Utf8(rows.iter().map(|r| ByteArray::from(f(r))).collect())
+    }
+
+    fn optional_utf8(rows: &[UsageRecordV2], f: impl Fn(&UsageRecordV2) -> Option<&str>) -> Self {
+        let mut values = Vec::with_capacity(rows.len());
+        let mut def_levels = Vec::with_capacity(rows.len());
+        for row in rows {
+            match f(row) {
+                Some(v) => {
+                    values.push(ByteArray::from(v));
+                    def_levels.push(1);
+                }
+                None => def_levels.push(0),
+            }
+        }
+        Self::OptionalUtf8 { values, def_levels }
+    }
+}
+
+fn columns_for(rows: &[UsageRecordV2]) -> Vec<ColumnValues> {
+    vec![
+        ColumnValues::utf8(rows, |r| &r.event_id),
+        ColumnValues::optional_utf8(rows, |r| r.session_id.as_deref()),
+        ColumnValues::optional_utf8(rows, |r| r.project_id.as_deref()),
+        ColumnValues::utf8(rows, |r| &r.model_name),
+        ColumnValues::utf8(rows, |r| &r.provider),
+        ColumnValues::int64(rows, |r| r.input_tokens),
+        ColumnValues::int64(rows, |r| r.output_tokens),
+        ColumnValues::int64(rows, |r| r.thinking_tokens),
+        ColumnValues::int64(rows, |r| r.cache_read_tokens),
+        ColumnValues::int64(rows, |r| r.cache_creation_tokens),
+        ColumnValues::int64(rows, |r| r.cost_microdollars),
+        ColumnValues::int64(rows, |r| r.timestamp),
+        ColumnValues::optional_utf8(rows, |r| r.metadata.as_deref()),
+        ColumnValues::optional_utf8(rows, |r| r.pricing_rule_id.as_deref()),
+        ColumnValues::utf8(rows, |r| &r.currency),
+        ColumnValues::utf8(rows, |r| r.cost_status.as_str()),
+        ColumnValues::optional_utf8(rows, |r| r.cost_breakdown_json.as_deref()),
+    ]
+}
+
+fn parquet_error(e: ParquetError) -> AppError {
+    AppError::internal(format!("Parquet export failed: {}", e))
+}
+
+/// Streaming Parquet writer for usage records.
+pub struct UsageParquetWriter<W: Write + Send> {
+    inner: SerializedFileWriter<W>,
+}
+
+impl<W: Write + Send> UsageParquetWriter<W> {
+    pub fn new(sink: W) -> AppResult<Self> {
+        let schema = Arc::new(parse_message_type(USAGE_PARQUET_SCHEMA).map_err(parquet_error)?);
+        let props = Arc::new(WriterProperties::builder().build());
+        let inner = SerializedFileWriter::new(sink, schema, props).map_err(parquet_error)?;
+        Ok(Self { inner })
+    }
+
+    /// Write one batch of records as a row group.
+    pub fn write_batch(&mut self, rows: &[UsageRecordV2]) -> AppResult<()> {
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut row_group = self.inner.next_row_group().map_err(parquet_error)?;
+        for values in columns_for(rows) {
+            let mut column = row_group
+                .next_column()
+                .map_err(parquet_error)?
+                .ok_or_else(|| {
+                    AppError::internal("Parquet schema has fewer columns than expected")
+                })?;
+            match &values {
+                ColumnValues::Int64(v) => {
+                    column
+                        .typed::<Int64Type>()
+                        .write_batch(v, None, None)
+                        .map_err(parquet_error)?;
+                }
+                ColumnValues::Utf8(v) => {
+                    column
+                        .typed::<ByteArrayType>()
+                        .write_batch(v, None, None)
+                        .map_err(parquet_error)?;
+                }
+                ColumnValues::OptionalUtf8 { values, def_levels } => {
+                    column
+                        .typed::<ByteArrayType>()
+                        .write_batch(values, Some(def_levels), None)
+                        .map_err(parquet_error)?;
+                }
+            }
+            column.close().map_err(parquet_error)?;
+        }
+        row_group.close().map_err(parquet_error)?;
+        Ok(())
+    }
+
+    /// Write the file footer and return the underlying sink.
+    pub fn finish(self) -> AppResult<W> {
+        self.inner.into_inner().map_err(parquet_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::analytics::CostStatus;
+    use parquet::basic::{ConvertedType, Repetition, Type as PhysicalType};
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+
+    fn sample_row(id: &str, session: Option<&str>) -> UsageRecordV2 {
+        UsageRecordV2 {
+            event_id: id.to_string(),
+            session_id: session.map(str::to_string),
+            project_id: Some("proj-1".to_string()),
+            model_name: "claude-3-5-sonnet".to_string(),
+            provider: "anthropic".to_string(),
+            input_tokens: 1_000,
+            output_tokens: 500,
+            thinking_tokens: 0,
+            cache_read_tokens: 10,
+            cache_creation_tokens: 0,
+            cost_microdollars: 42,
+            timestamp: 1_700_000_000,
+            metadata: None,
+            pricing_rule_id: None,
+            currency: "USD".to_string(),
+            cost_status: CostStatus::Exact,
+            cost_breakdown_json: None,
+        }
+    }
+
+    #[test]
+    fn test_parquet_schema_has_typed_columns() {
+        let mut writer = UsageParquetWriter::new(Vec::new()).unwrap();
+        writer
+            .write_batch(&[
+                sample_row("evt-1", Some("sess-1")),
+                sample_row("evt-2", None),
+            ])
+            .unwrap();
+        writer.write_batch(&[sample_row("evt-3", None)]).unwrap();
+        let bytes = writer.finish().unwrap();
+
+        let reader = SerializedFileReader::new(bytes::Bytes::from(bytes)).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.file_metadata().num_rows(), 3);
+        assert_eq!(metadata.num_row_groups(), 2);
+
+        let schema = metadata.file_metadata().schema_descr();
+        let column = |name: &str| {
+            (0..schema.num_columns())
+                .map(|i| schema.column(i))
+                .find(|c| c.name() == name)
+                .unwrap_or_else(|| panic!("missing column {}", name))
+        };
+
+        for name in [
+            "input_tokens",
+            "output_tokens",
+            "cost_microdollars",
+            "timestamp",
+        ] {
+            let col = column(name);
+            assert_eq!(col.physical_type(), PhysicalType::INT64);
+            assert_eq!(
+                col.self_type().get_basic_info().repetition(),
+                Repetition::REQUIRED
+            );
+        }
+        for name in [
+            "event_id",
+            "model_name",
+            "provider",
+            "currency",
+            "cost_status",
+        ] {
+            let col = column(name);
+            assert_eq!(col.physical_type(), PhysicalType::BYTE_ARRAY);
+            assert_eq!(col.converted_type(), ConvertedType::UTF8);
+        }
+        let session = column("session_id");
+        assert_eq!(
+            session.self_type().get_basic_info().repetition(),
+            Repetition::OPTIONAL
+        );
+        assert_eq!(schema.num_columns(), 17);
+    }
+}
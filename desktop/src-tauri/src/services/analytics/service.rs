@@ -3,21 +3,34 @@
 //! Core service for managing analytics data with SQLite storage.
 //! Provides CRUD operations, schema initialization, and connection pooling.
 
+use chrono_tz::Tz;
 use r2d2::Pool;
 use r2d2_sqlite::SqliteConnectionManager;
 use regex::Regex;
 use rusqlite::params;
+use std::collections::{BTreeMap, HashSet};
 use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
 
 use crate::models::analytics::{
     AggregationPeriod, AnalyticsBreakdownRow, AnalyticsEventDetail, AnalyticsExecutionScope,
-    AnalyticsFilter, AnalyticsSummary, AnalyticsUsageEvent, AnalyticsWorkflowMode, CostBreakdown,
-    CostStatus, DashboardFilterV2, DashboardSummary, ExportFormat, ExportJob, ExportJobStatus,
-    ExportStreamingJobRequest, ModelPricing, ModelUsage, PricingRule, PricingRuleStatus,
-    ProjectUsage, RecomputeCostsRequest, RecomputeCostsResult, TimeSeriesPoint, UsageFilter,
-    UsageRecord, UsageRecordV2, UsageStats,
+    AnalyticsFilter, AnalyticsSummary, AnalyticsUsageEvent, AnalyticsWorkflowMode, BudgetAlert,
+    BudgetAlertKind, CostBreakdown, CostBudget, CostProjection, CostStatus, DashboardFilterV2,
+    DashboardSummary, ExportFormat, ExportJob, ExportJobStatus, ExportStreamingJobRequest,
+    ModelPricing, ModelUsage, PricingImportConflict, PricingImportResult, PricingRule,
+    PricingRuleStatus, ProjectUsage, RecomputeCostsRequest, RecomputeCostsResult, TimeSeriesPoint,
+    UsageFilter, UsageRecord, UsageRecordV2, UsageRollup, UsageRollupDimension, UsageRollupSort,
+    UsageStats,
+};
+use crate::services::analytics::budget::{
+    alert_threshold_microdollars, daily_average, extrapolate_month_end, MonthBounds,
+    DEFAULT_PROJECTION_LOOKBACK_DAYS,
+};
+use crate::services::analytics::cost_calculator::pricing_windows_overlap;
+use crate::services::analytics::parquet_export::UsageParquetWriter;
+use crate::services::analytics::time_buckets::{
+    bucket_label, bucket_range, bucket_start, slot_start_sql,
 };
 use crate::utils::error::{AppError, AppResult};
 
@@ -252,6 +265,31 @@ impl AnalyticsService {
             [],
         )?;
 
+        // Monthly budgets (scope_key is the project id, or '' for global)
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS analytics_budgets (
+                scope_key TEXT PRIMARY KEY,
+                project_id TEXT,
+                monthly_budget INTEGER NOT NULL,
+                alert_threshold_percent REAL NOT NULL DEFAULT 100.0,
+                enabled INTEGER NOT NULL DEFAULT 1,
+                updated_at INTEGER NOT NULL
+            )",
+            [],
+        )?;
+
+        // Budget alerts already raised (one per scope/month/kind)
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS analytics_budget_alerts (
+                scope_key TEXT NOT NULL,
+                month TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                triggered_at INTEGER NOT NULL,
+                PRIMARY KEY(scope_key, month, kind)
+            )",
+            [],
+        )?;
+
         // Apply migrations
         self.apply_migrations(&conn)?;
 
@@ -899,42 +937,7 @@ impl AnalyticsService {
     /// Upsert one pricing rule with overlap validation.
     pub fn upsert_pricing_rule(&self, rule: &PricingRule) -> AppResult<PricingRule> {
         let conn = self.get_connection()?;
-        let mut normalized = rule.clone();
-
-        normalized.provider = normalized.provider.trim().to_string();
-        normalized.model_pattern = normalized.model_pattern.trim().to_string();
-        normalized.currency = normalized.currency.trim().to_string();
-
-        if normalized.id.trim().is_empty() {
-            normalized.id = uuid::Uuid::new_v4().to_string();
-        }
-        if normalized.provider.is_empty() {
-            return Err(AppError::validation("provider must not be empty"));
-        }
-        if normalized.model_pattern.is_empty() {
-            return Err(AppError::validation("model_pattern must not be empty"));
-        }
-        if normalized.currency.is_empty() {
-            normalized.currency = "USD".to_string();
-        }
-        if let Some(end) = normalized.effective_to {
-            if end <= normalized.effective_from {
-                return Err(AppError::validation(
-                    "effective_to must be greater than effective_from",
-                ));
-            }
-        }
-        for price in [
-            normalized.input_per_million,
-            normalized.output_per_million,
-            normalized.cache_read_per_million,
-            normalized.cache_write_per_million,
-            normalized.thinking_per_million,
-        ] {
-            if price < 0 {
-                return Err(AppError::validation("price values must be non-negative"));
-            }
-        }
+        let mut normalized = Self::normalize_pricing_rule(rule)?;
 
         let mut existing_stmt =
             conn.prepare("SELECT created_at FROM pricing_rules WHERE id = ?1")?;
@@ -975,6 +978,160 @@ impl AnalyticsService {
             }
         }
 
+        Self::write_pricing_rule(&conn, &normalized)?;
+
+        Ok(normalized)
+    }
+
+    /// Bulk-import pricing rules, validating the whole batch first.
+    ///
+    /// Entries are rejected for duplicate IDs, invalid fields, or active
+    /// effective windows that overlap another entry for the same provider +
+    /// model pattern (within the batch or among stored rules not being
+    /// replaced). Any conflict aborts the import so nothing is shadowed
+    /// silently. Re-importing an export updates the rules in place.
+    pub fn import_pricing_rules(&self, rules: &[PricingRule]) -> AppResult<PricingImportResult> {
+        let mut conflicts = Vec::new();
+        let mut accepted: Vec<(usize, PricingRule)> = Vec::new();
+        let mut seen_ids = HashSet::new();
+
+        for (index, rule) in rules.iter().enumerate() {
+            let conflict = |reason: String, other: Option<String>| PricingImportConflict {
+                index,
+                rule_id: rule.id.clone(),
+                provider: rule.provider.clone(),
+                model_pattern: rule.model_pattern.clone(),
+                reason,
+                conflicting_rule_id: other,
+            };
+            let normalized = match Self::normalize_pricing_rule(rule) {
+                Ok(r) => r,
+                Err(e) => {
+                    conflicts.push(conflict(e.to_string(), None));
+                    continue;
+                }
+            };
+            if !seen_ids.insert(normalized.id.clone()) {
+                conflicts.push(conflict(
+                    "duplicate rule id in import".to_string(),
+                    Some(normalized.id.clone()),
+                ));
+                continue;
+            }
+            accepted.push((index, normalized));
+        }
+
+        let incoming_ids: HashSet<&str> = accepted.iter().map(|(_, r)| r.id.as_str()).collect();
+        let stored: Vec<PricingRule> = self
+            .list_pricing_rules()?
+            .into_iter()
+            .filter(|r| !incoming_ids.contains(r.id.as_str()))
+            .collect();
+
+        let overlaps = |a: &PricingRule, b: &PricingRule| {
+            a.status == PricingRuleStatus::Active
+                && b.status == PricingRuleStatus::Active
+                && a.provider == b.provider
+                && a.model_pattern == b.model_pattern
+                && pricing_windows_overlap(
+                    a.effective_from,
+                    a.effective_to,
+                    b.effective_from,
+                    b.effective_to,
+                )
+        };
+        for (pos, (index, rule)) in accepted.iter().enumerate() {
+            let clash = accepted[..pos]
+                .iter()
+                .map(|(_, other)| (other, "overlaps another imported rule"))
+                .chain(
+                    stored
+                        .iter()
+                        .map(|other| (other, "overlaps an existing pricing rule")),
+                )
+                .find(|(other, _)| overlaps(rule, *other));
+            if let Some((other, reason)) = clash {
+                conflicts.push(PricingImportConflict {
+                    index: *index,
+                    rule_id: rule.id.clone(),
+                    provider: rule.provider.clone(),
+                    model_pattern: rule.model_pattern.clone(),
+                    reason: reason.to_string(),
+                    conflicting_rule_id: Some(other.id.clone()),
+                });
+            }
+        }
+
+        if !conflicts.is_empty() {
+            conflicts.sort_by_key(|c| c.index);
+            return Ok(PricingImportResult {
+                imported: Vec::new(),
+                conflicts,
+            });
+        }
+
+        let mut conn = self.get_connection()?;
+        let tx = conn.transaction()?;
+        let now = chrono::Utc::now().timestamp();
+        let mut imported = Vec::with_capacity(accepted.len());
+        for (_, mut rule) in accepted {
+            if rule.created_at <= 0 {
+                rule.created_at = now;
+            }
+            rule.updated_at = now;
+            Self::write_pricing_rule(&tx, &rule)?;
+            imported.push(rule);
+        }
+        tx.commit()?;
+
+        Ok(PricingImportResult {
+            imported,
+            conflicts: Vec::new(),
+        })
+    }
+
+    /// Trim and validate a pricing rule, assigning an ID when missing.
+    fn normalize_pricing_rule(rule: &PricingRule) -> AppResult<PricingRule> {
+        let mut normalized = rule.clone();
+        normalized.provider = normalized.provider.trim().to_string();
+        normalized.model_pattern = normalized.model_pattern.trim().to_string();
+        normalized.currency = normalized.currency.trim().to_string();
+
+        if normalized.id.trim().is_empty() {
+            normalized.id = uuid::Uuid::new_v4().to_string();
+        }
+        if normalized.provider.is_empty() {
+            return Err(AppError::validation("provider must not be empty"));
+        }
+        if normalized.model_pattern.is_empty() {
+            return Err(AppError::validation("model_pattern must not be empty"));
+        }
+        if normalized.currency.is_empty() {
+            normalized.currency = "USD".to_string();
+        }
+        if let Some(end) = normalized.effective_to {
+            if end <= normalized.effective_from {
+                return Err(AppError::validation(
+                    "effective_to must be greater than effective_from",
+                ));
+            }
+        }
+        for price in [
+            normalized.input_per_million,
+            normalized.output_per_million,
+            normalized.cache_read_per_million,
+            normalized.cache_write_per_million,
+            normalized.thinking_per_million,
+        ] {
+            if price < 0 {
+                return Err(AppError::validation("price values must be non-negative"));
+            }
+        }
+
+        Ok(normalized)
+    }
+
+    fn write_pricing_rule(conn: &rusqlite::Connection, normalized: &PricingRule) -> AppResult<()> {
         conn.execute(
             "INSERT INTO pricing_rules
              (id, provider, model_pattern, currency, input_per_million, output_per_million,
@@ -1013,8 +1170,7 @@ impl AnalyticsService {
                 normalized.updated_at,
             ],
         )?;
-
-        Ok(normalized)
+        Ok(())
     }
 
     /// Delete pricing rule by ID.
@@ -1299,7 +1455,10 @@ impl AnalyticsService {
 
         let by_model = self.aggregate_by_model_v2(filter)?;
         let by_project = self.aggregate_by_project_v2(filter)?;
-        let time_series = self.get_time_series_v2(filter, period)?;
+        let time_series = self.get_time_series_v2(filter, period, None)?;
+        let cost_projection = self
+            .project_monthly_cost(filter.project_id.as_deref(), chrono::Utc::now().timestamp())
+            .ok();
 
         Ok(DashboardSummary {
             current_period: current_stats,
@@ -1310,9 +1469,317 @@ impl AnalyticsService {
             by_model,
             by_project,
             time_series,
+            cost_projection,
         })
     }
 
+    /// Aggregate usage by session or agent with a parent vs sub-agent split.
+    ///
+    /// A request counts as sub-agent spend when its execution scope is
+    /// `sub_agent` or its metadata carries `"is_sub_agent": true`. Results are
+    /// sorted descending by `sort` and truncated to `limit` when given.
+    pub fn aggregate_usage_rollup(
+        &self,
+        filter: &DashboardFilterV2,
+        dimension: UsageRollupDimension,
+        sort: UsageRollupSort,
+        limit: Option<i64>,
+    ) -> AppResult<Vec<UsageRollup>> {
+        let key_expr = match dimension {
+            UsageRollupDimension::Session => {
+                "COALESCE(ue.session_id, ue.kernel_session_id, '(unattributed)')".to_string()
+            }
+            UsageRollupDimension::Agent => format!(
+                "COALESCE(ue.agent_name, {}, {}, '(unattributed)')",
+                Self::metadata_field_sql("ue", "agent_name"),
+                Self::metadata_field_sql("ue", "pipeline_name"),
+            ),
+        };
+        let is_sub_agent = format!(
+            "(COALESCE(ue.execution_scope, '') = 'sub_agent' OR COALESCE({}, 0) = 1)",
+            Self::metadata_field_sql("ue", "is_sub_agent")
+        );
+        let cost = "COALESCE(uc.cost_total, 0)";
+        let order_by = match sort {
+            UsageRollupSort::Cost => "total_cost DESC, request_count DESC",
+            UsageRollupSort::Tokens => "(total_input + total_output) DESC, total_cost DESC",
+            UsageRollupSort::Requests => "request_count DESC, total_cost DESC",
+        };
+
+        let conn = self.get_connection()?;
+        let mut sql = format!(
+            "SELECT {key_expr} AS rollup_key,
+                    COALESCE(SUM(ue.input_tokens), 0) AS total_input,
+                    COALESCE(SUM(ue.output_tokens), 0) AS total_output,
+                    COALESCE(SUM({cost}), 0) AS total_cost,
+                    COUNT(*) AS request_count,
+                    COALESCE(SUM(CASE WHEN {is_sub_agent} THEN ue.input_tokens ELSE 0 END), 0),
+                    COALESCE(SUM(CASE WHEN {is_sub_agent} THEN ue.output_tokens ELSE 0 END), 0),
+                    COALESCE(SUM(CASE WHEN {is_sub_agent} THEN {cost} ELSE 0 END), 0),
+                    COALESCE(SUM(CASE WHEN {is_sub_agent} THEN 1 ELSE 0 END), 0),
+                    MAX(COALESCE({iteration}, ue.request_sequence)),
+                    MIN(ue.timestamp_utc),
+                    MAX(ue.timestamp_utc)
+             FROM usage_events ue
+             LEFT JOIN usage_costs uc ON uc.event_id = ue.event_id
+             WHERE 1=1",
+            iteration = Self::metadata_field_sql("ue", "iteration"),
+        );
+        let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        Self::append_v2_filter_clauses(&mut sql, &mut params_vec, filter, "ue", "uc");
+        sql.push_str(&format!(" GROUP BY rollup_key ORDER BY {order_by}"));
+        if let Some(limit) = limit.filter(|l| *l > 0) {
+            sql.push_str(" LIMIT ?");
+            params_vec.push(Box::new(limit));
+        }
+        let params_refs: Vec<&dyn rusqlite::ToSql> =
+            params_vec.iter().map(|p| p.as_ref()).collect();
+
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(params_refs.as_slice(), |row| {
+                let total_input: i64 = row.get(1)?;
+                let total_output: i64 = row.get(2)?;
+                let total_cost: i64 = row.get(3)?;
+                let request_count: i64 = row.get(4)?;
+                let sub_input: i64 = row.get(5)?;
+                let sub_output: i64 = row.get(6)?;
+                let sub_cost: i64 = row.get(7)?;
+                let sub_count: i64 = row.get(8)?;
+                Ok(UsageRollup {
+                    key: row.get(0)?,
+                    stats: Self::usage_stats_from_totals(
+                        total_input,
+                        total_output,
+                        total_cost,
+                        request_count,
+                    ),
+                    parent_stats: Self::usage_stats_from_totals(
+                        total_input - sub_input,
+                        total_output - sub_output,
+                        total_cost - sub_cost,
+                        request_count - sub_count,
+                    ),
+                    sub_agent_stats: Self::usage_stats_from_totals(
+                        sub_input, sub_output, sub_cost, sub_count,
+                    ),
+                    max_iteration: row.get(9)?,
+                    first_timestamp: row.get(10)?,
+                    last_timestamp: row.get(11)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(rows)
+    }
+
+    // ========================================================================
+    // Cost projection & budgets
+    // ========================================================================
+
+    /// Project month-end spend from month-to-date usage and the trailing
+    /// daily average.
+    ///
+    /// `project_id` of `None` projects spend across all projects. Partial
+    /// months are handled by extrapolating only over the remaining
+    /// (fractional) days of the UTC month containing `now`.
+    pub fn project_monthly_cost(
+        &self,
+        project_id: Option<&str>,
+        now: i64,
+    ) -> AppResult<CostProjection> {
+        let month = MonthBounds::containing(now);
+        let lookback_days = DEFAULT_PROJECTION_LOOKBACK_DAYS;
+
+        let actual = self.get_usage_stats_v2(&DashboardFilterV2 {
+            start_timestamp: Some(month.start),
+            end_timestamp: Some(now + 1),
+            project_id: project_id.map(str::to_string),
+            ..Default::default()
+        })?;
+        let window = self.get_usage_stats_v2(&DashboardFilterV2 {
+            start_timestamp: Some(now - lookback_days * 86_400),
+            end_timestamp: Some(now + 1),
+            project_id: project_id.map(str::to_string),
+            ..Default::default()
+        })?;
+
+        let daily_avg = daily_average(window.total_cost_microdollars, lookback_days);
+        let projected = extrapolate_month_end(
+            actual.total_cost_microdollars,
+            daily_avg,
+            month.days_remaining(now),
+        );
+
+        let budget = self.get_budget(project_id)?;
+        let percent_of_budget = |value: i64| {
+            budget
+                .as_ref()
+                .filter(|b| b.monthly_budget_microdollars > 0)
+                .map(|b| value as f64 / b.monthly_budget_microdollars as f64 * 100.0)
+        };
+
+        Ok(CostProjection {
+            project_id: project_id.map(str::to_string),
+            month: month.label.clone(),
+            days_in_month: month.days_in_month(),
+            days_elapsed: month.days_elapsed(now),
+            lookback_days,
+            actual_cost_microdollars: actual.total_cost_microdollars,
+            daily_average_microdollars: daily_avg,
+            projected_cost_microdollars: projected,
+            budget_used_percent: percent_of_budget(actual.total_cost_microdollars),
+            projected_budget_percent: percent_of_budget(projected),
+            budget,
+        })
+    }
+
+    /// List all configured budgets.
+    pub fn list_budgets(&self) -> AppResult<Vec<CostBudget>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT project_id, monthly_budget, alert_threshold_percent, enabled, updated_at
+             FROM analytics_budgets ORDER BY scope_key ASC",
+        )?;
+        let budgets = stmt
+            .query_map([], Self::row_to_cost_budget)?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(budgets)
+    }
+
+    /// Get the budget for a project (`None` = global budget).
+    pub fn get_budget(&self, project_id: Option<&str>) -> AppResult<Option<CostBudget>> {
+        let conn = self.get_connection()?;
+        let result = conn.query_row(
+            "SELECT project_id, monthly_budget, alert_threshold_percent, enabled, updated_at
+             FROM analytics_budgets WHERE scope_key = ?1",
+            params![project_id.unwrap_or_default()],
+            Self::row_to_cost_budget,
+        );
+        match result {
+            Ok(budget) => Ok(Some(budget)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Create or replace the budget for a project (or the global budget).
+    pub fn upsert_budget(&self, budget: &CostBudget) -> AppResult<CostBudget> {
+        if budget.monthly_budget_microdollars <= 0 {
+            return Err(AppError::validation("Monthly budget must be positive"));
+        }
+        if !(budget.alert_threshold_percent > 0.0 && budget.alert_threshold_percent <= 1000.0) {
+            return Err(AppError::validation(
+                "Alert threshold must be between 0 and 1000 percent",
+            ));
+        }
+
+        let mut saved = budget.clone();
+        saved.updated_at = chrono::Utc::now().timestamp();
+
+        let conn = self.get_connection()?;
+        conn.execute(
+            "INSERT INTO analytics_budgets
+             (scope_key, project_id, monthly_budget, alert_threshold_percent, enabled, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(scope_key) DO UPDATE SET
+                monthly_budget = excluded.monthly_budget,
+                alert_threshold_percent = excluded.alert_threshold_percent,
+                enabled = excluded.enabled,
+                updated_at = excluded.updated_at",
+            params![
+                saved.project_id.clone().unwrap_or_default(),
+                saved.project_id,
+                saved.monthly_budget_microdollars,
+                saved.alert_threshold_percent,
+                saved.enabled as i32,
+                saved.updated_at,
+            ],
+        )?;
+        Ok(saved)
+    }
+
+    /// Delete the budget for a project (`None` = global budget).
+    pub fn delete_budget(&self, project_id: Option<&str>) -> AppResult<bool> {
+        let conn = self.get_connection()?;
+        let scope_key = project_id.unwrap_or_default();
+        let deleted = conn.execute(
+            "DELETE FROM analytics_budgets WHERE scope_key = ?1",
+            params![scope_key],
+        )?;
+        conn.execute(
+            "DELETE FROM analytics_budget_alerts WHERE scope_key = ?1",
+            params![scope_key],
+        )?;
+        Ok(deleted > 0)
+    }
+
+    /// Evaluate every enabled budget and return newly crossed thresholds.
+    ///
+    /// Each scope raises at most one alert per month and kind; a crossing
+    /// that was already reported is not returned again. When actual spend
+    /// crosses first-seen, the projected alert is suppressed for the month
+    /// since the actual alert already covers it.
+    pub fn evaluate_budget_alerts(&self, now: i64) -> AppResult<Vec<BudgetAlert>> {
+        let mut alerts = Vec::new();
+
+        for budget in self.list_budgets()?.into_iter().filter(|b| b.enabled) {
+            let projection = self.project_monthly_cost(budget.project_id.as_deref(), now)?;
+            let threshold = alert_threshold_microdollars(
+                budget.monthly_budget_microdollars,
+                budget.alert_threshold_percent,
+            );
+            let scope_key = budget.project_id.clone().unwrap_or_default();
+
+            let crossed = if projection.actual_cost_microdollars >= threshold {
+                Some(BudgetAlertKind::Actual)
+            } else if projection.projected_cost_microdollars >= threshold {
+                Some(BudgetAlertKind::Projected)
+            } else {
+                None
+            };
+            let Some(kind) = crossed else {
+                continue;
+            };
+
+            let conn = self.get_connection()?;
+            let inserted = conn.execute(
+                "INSERT OR IGNORE INTO analytics_budget_alerts (scope_key, month, kind, triggered_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![scope_key, projection.month, kind.as_str(), now],
+            )?;
+            if kind == BudgetAlertKind::Actual {
+                conn.execute(
+                    "INSERT OR IGNORE INTO analytics_budget_alerts (scope_key, month, kind, triggered_at)
+                     VALUES (?1, ?2, ?3, ?4)",
+                    params![
+                        scope_key,
+                        projection.month,
+                        BudgetAlertKind::Projected.as_str(),
+                        now
+                    ],
+                )?;
+            }
+            if inserted == 0 {
+                continue;
+            }
+
+            alerts.push(BudgetAlert {
+                project_id: budget.project_id.clone(),
+                month: projection.month.clone(),
+                kind,
+                budget_microdollars: budget.monthly_budget_microdollars,
+                threshold_microdollars: threshold,
+                actual_cost_microdollars: projection.actual_cost_microdollars,
+                projected_cost_microdollars: projection.projected_cost_microdollars,
+                triggered_at: now,
+            });
+        }
+
+        Ok(alerts)
+    }
+
     /// Recompute usage costs with current pricing rules for matching records.
     pub fn recompute_costs(
         &self,
@@ -1440,6 +1907,8 @@ impl AnalyticsService {
         let extension = match request.format {
             ExportFormat::Csv => "csv",
             ExportFormat::Json => "json",
+            ExportFormat::Ndjson => "ndjson",
+            ExportFormat::Parquet => "parquet",
         };
         let file_path = request
             .file_path
@@ -1575,6 +2044,51 @@ impl AnalyticsService {
                 writeln!(writer, "  ]")?;
                 writeln!(writer, "}}")?;
             }
+            ExportFormat::Ndjson => {
+                // One record per line; the summary is omitted so every line
+                // parses as a `UsageRecordV2`.
+                let mut offset = 0_i64;
+                loop {
+                    let batch = self.list_usage_records_v2(
+                        &request.filter,
+                        Some(chunk_size),
+                        Some(offset),
+                    )?;
+                    if batch.is_empty() {
+                        break;
+                    }
+                    for row in &batch {
+                        serde_json::to_writer(&mut writer, row)?;
+                        writeln!(writer)?;
+                    }
+                    total_exported += batch.len() as i64;
+                    offset += chunk_size;
+                    if batch.len() < chunk_size as usize {
+                        break;
+                    }
+                }
+            }
+            ExportFormat::Parquet => {
+                let mut parquet = UsageParquetWriter::new(&mut writer)?;
+                let mut offset = 0_i64;
+                loop {
+                    let batch = self.list_usage_records_v2(
+                        &request.filter,
+                        Some(chunk_size),
+                        Some(offset),
+                    )?;
+                    if batch.is_empty() {
+                        break;
+                    }
+                    parquet.write_batch(&batch)?;
+                    total_exported += batch.len() as i64;
+                    offset += chunk_size;
+                    if batch.len() < chunk_size as usize {
+                        break;
+                    }
+                }
+                parquet.finish()?;
+            }
         }
 
         writer.flush()?;
@@ -1812,11 +2326,21 @@ impl AnalyticsService {
         Ok(rows)
     }
 
-    fn get_time_series_v2(
+    /// Time series bucketed by `period`.
+    ///
+    /// Without a timezone, buckets are UTC calendar periods containing usage.
+    /// With one, bucket edges follow local time in that zone and empty buckets
+    /// are zero-filled so charts have no gaps.
+    pub fn get_time_series_v2(
         &self,
         filter: &DashboardFilterV2,
         period: AggregationPeriod,
+        timezone: Option<Tz>,
     ) -> AppResult<Vec<TimeSeriesPoint>> {
+        if let Some(tz) = timezone {
+            return self.get_time_series_in_zone(filter, period, tz);
+        }
+
         if Self::is_rollup_eligible(filter) && period == AggregationPeriod::Daily {
             let conn = self.get_connection()?;
             let mut sql = String::from(
@@ -1900,6 +2424,98 @@ impl AnalyticsService {
         Ok(rows)
     }
 
+    /// Aggregates usage into UTC slots in SQL, folds the slots into local
+    /// buckets, and zero-fills between the filter bounds (or the first/last
+    /// usage when unbounded).
+    fn get_time_series_in_zone(
+        &self,
+        filter: &DashboardFilterV2,
+        period: AggregationPeriod,
+        tz: Tz,
+    ) -> AppResult<Vec<TimeSeriesPoint>> {
+        let slot_expr = slot_start_sql("ue.timestamp_utc");
+
+        let conn = self.get_connection()?;
+        let mut sql = format!(
+            "SELECT {slot_expr} AS slot_start,
+                    COALESCE(SUM(ue.input_tokens), 0) AS total_input,
+                    COALESCE(SUM(ue.output_tokens), 0) AS total_output,
+                    COALESCE(SUM(COALESCE(uc.cost_total, 0)), 0) AS total_cost,
+                    COUNT(*) AS request_count
+             FROM usage_events ue
+             LEFT JOIN usage_costs uc ON uc.event_id = ue.event_id
+             WHERE 1=1"
+        );
+        let mut params_vec: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
+        Self::append_v2_filter_clauses(&mut sql, &mut params_vec, filter, "ue", "uc");
+        sql.push_str(" GROUP BY slot_start ORDER BY slot_start ASC");
+        let params_refs: Vec<&dyn rusqlite::ToSql> =
+            params_vec.iter().map(|p| p.as_ref()).collect();
+
+        let mut stmt = conn.prepare(&sql)?;
+        let slots = stmt
+            .query_map(params_refs.as_slice(), |row| {
+                Ok((
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, i64>(1)?,
+                    row.get::<_, i64>(2)?,
+                    row.get::<_, i64>(3)?,
+                    row.get::<_, i64>(4)?,
+                ))
+            })?
+            .filter_map(|r| r.ok());
+
+        let mut totals: BTreeMap<i64, (i64, i64, i64, i64)> = BTreeMap::new();
+        for (slot, input, output, cost, count) in slots {
+            let entry = totals
+                .entry(bucket_start(period, slot, tz))
+                .or_insert((0, 0, 0, 0));
+            entry.0 += input;
+            entry.1 += output;
+            entry.2 += cost;
+            entry.3 += count;
+        }
+        let mut buckets: BTreeMap<i64, UsageStats> = totals
+            .into_iter()
+            .map(|(start, (input, output, cost, count))| {
+                (
+                    start,
+                    Self::usage_stats_from_totals(input, output, cost, count),
+                )
+            })
+            .collect();
+
+        let first = filter
+            .start_timestamp
+            .or_else(|| buckets.keys().next().copied());
+        let last_exclusive = filter
+            .end_timestamp
+            .or_else(|| buckets.keys().next_back().map(|k| k + 1));
+        let starts = match (first, last_exclusive) {
+            (Some(from), Some(to)) => bucket_range(period, from, to, tz)?,
+            _ => Vec::new(),
+        };
+
+        let mut points: Vec<TimeSeriesPoint> = starts
+            .into_iter()
+            .map(|start| TimeSeriesPoint {
+                timestamp: start,
+                timestamp_formatted: bucket_label(period, start, tz),
+                stats: buckets
+                    .remove(&start)
+                    .unwrap_or_else(|| Self::usage_stats_from_totals(0, 0, 0, 0)),
+            })
+            .collect();
+        // Anything left over falls outside the generated range; keep it rather than drop usage.
+        points.extend(buckets.into_iter().map(|(start, stats)| TimeSeriesPoint {
+            timestamp: start,
+            timestamp_formatted: bucket_label(period, start, tz),
+            stats,
+        }));
+        points.sort_by_key(|p| p.timestamp);
+        Ok(points)
+    }
+
     fn get_usage_stats_from_analytics_filter(
         &self,
         filter: &AnalyticsFilter,
@@ -2275,6 +2891,15 @@ impl AnalyticsService {
         true
     }
 
+    /// SQL expression extracting a top-level field from `metadata_json`,
+    /// yielding NULL for missing or malformed metadata.
+    fn metadata_field_sql(event_alias: &str, field: &str) -> String {
+        format!(
+            "CASE WHEN json_valid({alias}.metadata_json) THEN json_extract({alias}.metadata_json, '$.{field}') END",
+            alias = event_alias,
+        )
+    }
+
     fn usage_stats_from_totals(
         total_input: i64,
         total_output: i64,
@@ -2724,6 +3349,17 @@ impl AnalyticsService {
         })
     }
 
+    /// Convert a database row to CostBudget
+    fn row_to_cost_budget(row: &rusqlite::Row) -> rusqlite::Result<CostBudget> {
+        Ok(CostBudget {
+            project_id: row.get(0)?,
+            monthly_budget_microdollars: row.get(1)?,
+            alert_threshold_percent: row.get(2)?,
+            enabled: row.get::<_, i32>(3)? != 0,
+            updated_at: row.get(4)?,
+        })
+    }
+
     /// Convert a database row to ModelPricing
     fn row_to_model_pricing(row: &rusqlite::Row) -> rusqlite::Result<ModelPricing> {
         let is_custom_int: i32 = row.get(5)?;
@@ -2759,6 +3395,7 @@ impl std::fmt::Debug for AnalyticsService {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::analytics::time_buckets::parse_timezone;
 
     fn create_test_service() -> AppResult<AnalyticsService> {
         let manager = SqliteConnectionManager::memory();
@@ -2943,4 +3580,447 @@ mod tests {
         assert_eq!(result.recomputed_records, 1);
         assert_eq!(result.missing_records, 1);
     }
+
+    fn seed_daily_cost(service: &AnalyticsService, project: &str, timestamp: i64, cost: i64) {
+        let mut record = UsageRecord::new("budget-model", "budget-provider", 100, 50)
+            .with_project(project)
+            .with_cost(cost);
+        record.timestamp = timestamp;
+        service.insert_usage_record(&record).unwrap();
+    }
+
+    #[test]
+    fn test_project_monthly_cost_over_known_series() {
+        let service = create_test_service().unwrap();
+        // 2026-03-10 12:00:00 UTC: 9.5 days elapsed, 21.5 days remaining
+        let now = 1_773_144_000;
+        let month = MonthBounds::containing(now);
+        assert_eq!(month.label, "2026-03");
+
+        // $1/day for the last 7 days (inside the month), plus $10 on day 1
+        for day in 0..7 {
+            seed_daily_cost(&service, "proj-a", now - day * 86_400 - 3_600, 1_000_000);
+        }
+        seed_daily_cost(&service, "proj-a", month.start + 60, 10_000_000);
+        // Other project and previous month are excluded from the MTD total
+        seed_daily_cost(&service, "proj-b", now - 3_600, 50_000_000);
+        seed_daily_cost(&service, "proj-a", month.start - 86_400, 99_000_000);
+
+        let projection = service.project_monthly_cost(Some("proj-a"), now).unwrap();
+        assert_eq!(projection.month, "2026-03");
+        assert_eq!(projection.days_in_month, 31);
+        assert_eq!(projection.actual_cost_microdollars, 17_000_000);
+        assert!((projection.daily_average_microdollars - 1_000_000.0).abs() < 1e-6);
+        // 17 + 1 * 21.5 = 38.5
+        assert_eq!(projection.projected_cost_microdollars, 38_500_000);
+        assert!(projection.budget.is_none());
+    }
+
+    #[test]
+    fn test_budget_alert_fires_once_per_crossing() {
+        let service = create_test_service().unwrap();
+        let now = 1_773_144_000;
+        service
+            .upsert_budget(&CostBudget {
+                project_id: Some("proj-a".to_string()),
+                monthly_budget_microdollars: 30_000_000,
+                alert_threshold_percent: 100.0,
+                enabled: true,
+                updated_at: 0,
+            })
+            .unwrap();
+
+        // Under budget: nothing fires
+        seed_daily_cost(&service, "proj-a", now - 3_600, 100_000);
+        assert!(service.evaluate_budget_alerts(now).unwrap().is_empty());
+
+        // $2/day for 7 days => projection well over $30
+        for day in 1..7 {
+            seed_daily_cost(&service, "proj-a", now - day * 86_400, 2_000_000);
+        }
+        let alerts = service.evaluate_budget_alerts(now).unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].kind, BudgetAlertKind::Projected);
+        assert_eq!(alerts[0].project_id.as_deref(), Some("proj-a"));
+
+        // Re-evaluating the same crossing does not repeat the alert
+        assert!(service.evaluate_budget_alerts(now + 60).unwrap().is_empty());
+
+        let projection = service.project_monthly_cost(Some("proj-a"), now).unwrap();
+        assert!(projection.projected_budget_percent.unwrap() >= 100.0);
+    }
+
+    #[test]
+    fn test_upsert_budget_validation() {
+        let service = create_test_service().unwrap();
+        let result = service.upsert_budget(&CostBudget {
+            project_id: None,
+            monthly_budget_microdollars: 0,
+            alert_threshold_percent: 80.0,
+            enabled: true,
+            updated_at: 0,
+        });
+        assert!(result.is_err());
+        assert!(service.list_budgets().unwrap().is_empty());
+    }
+
+    fn seed_agent_record(
+        service: &AnalyticsService,
+        session: &str,
+        metadata: serde_json::Value,
+        cost: i64,
+    ) {
+        let mut record = UsageRecord::new("rollup-model", "rollup-provider", 1_000, 100)
+            .with_session(session)
+            .with_cost(cost);
+        record.metadata = Some(metadata.to_string());
+        service.insert_usage_record(&record).unwrap();
+    }
+
+    #[test]
+    fn test_aggregate_by_session_and_agent_with_sub_agent_split() {
+        let service = create_test_service().unwrap();
+        seed_agent_record(
+            &service,
+            "sess-1",
+            serde_json::json!({"agent_name": "planner", "iteration": 1}),
+            5_000,
+        );
+        seed_agent_record(
+            &service,
+            "sess-1",
+            serde_json::json!({"agent_name": "reviewer", "is_sub_agent": true, "iteration": 4}),
+            20_000,
+        );
+        seed_agent_record(
+            &service,
+            "sess-2",
+            serde_json::json!({"agent_name": "reviewer", "is_sub_agent": true, "iteration": 2}),
+            30_000,
+        );
+        // Malformed metadata must not break the query
+        let mut bad = UsageRecord::new("rollup-model", "rollup-provider", 10, 10)
+            .with_session("sess-3")
+            .with_cost(1);
+        bad.metadata = Some("{not json".to_string());
+        service.insert_usage_record(&bad).unwrap();
+
+        let filter = DashboardFilterV2::default();
+        let sessions = service
+            .aggregate_usage_rollup(
+                &filter,
+                UsageRollupDimension::Session,
+                UsageRollupSort::Cost,
+                None,
+            )
+            .unwrap();
+        let keys: Vec<&str> = sessions.iter().map(|r| r.key.as_str()).collect();
+        assert_eq!(keys, vec!["sess-2", "sess-1", "sess-3"]);
+
+        let sess1 = &sessions[1];
+        assert_eq!(sess1.stats.total_cost_microdollars, 25_000);
+        assert_eq!(sess1.stats.request_count, 2);
+        assert_eq!(sess1.parent_stats.total_cost_microdollars, 5_000);
+        assert_eq!(sess1.sub_agent_stats.total_cost_microdollars, 20_000);
+        assert_eq!(sess1.sub_agent_stats.request_count, 1);
+        assert_eq!(sess1.max_iteration, Some(4));
+
+        let agents = service
+            .aggregate_usage_rollup(
+                &filter,
+                UsageRollupDimension::Agent,
+                UsageRollupSort::Cost,
+                Some(2),
+            )
+            .unwrap();
+        assert_eq!(agents.len(), 2);
+        assert_eq!(agents[0].key, "reviewer");
+        assert_eq!(agents[0].stats.total_cost_microdollars, 50_000);
+        assert_eq!(agents[0].sub_agent_stats.total_cost_microdollars, 50_000);
+        assert_eq!(agents[0].parent_stats.request_count, 0);
+        assert_eq!(agents[1].key, "planner");
+        assert_eq!(agents[1].sub_agent_stats.request_count, 0);
+        assert_eq!(agents[1].parent_stats.total_cost_microdollars, 5_000);
+    }
+
+    #[test]
+    fn test_aggregate_rollup_sort_by_requests() {
+        let service = create_test_service().unwrap();
+        for _ in 0..3 {
+            seed_agent_record(&service, "busy", serde_json::json!({}), 1);
+        }
+        seed_agent_record(&service, "pricey", serde_json::json!({}), 1_000_000);
+
+        let rows = service
+            .aggregate_usage_rollup(
+                &DashboardFilterV2::default(),
+                UsageRollupDimension::Session,
+                UsageRollupSort::Requests,
+                Some(1),
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].key, "busy");
+        assert_eq!(rows[0].stats.request_count, 3);
+    }
+
+    fn seed_export_records(service: &AnalyticsService) {
+        for (project, ts) in [
+            ("proj-a", 1_000),
+            ("proj-a", 2_000),
+            ("proj-b", 2_000),
+            ("proj-a", 9_000),
+        ] {
+            let mut record = UsageRecord::new("export-model", "export-provider", 100, 50)
+                .with_project(project)
+                .with_session("sess-export")
+                .with_cost(7);
+            record.timestamp = ts;
+            record.metadata = Some(r#"{"note":"a,b\n\"c\""}"#.to_string());
+            service.insert_usage_record(&record).unwrap();
+        }
+    }
+
+    fn export_request(format: ExportFormat, path: &Path) -> ExportStreamingJobRequest {
+        ExportStreamingJobRequest {
+            filter: DashboardFilterV2 {
+                project_id: Some("proj-a".to_string()),
+                start_timestamp: Some(500),
+                end_timestamp: Some(5_000),
+                ..Default::default()
+            },
+            format,
+            include_summary: true,
+            file_path: Some(path.to_string_lossy().to_string()),
+        }
+    }
+
+    #[test]
+    fn test_export_ndjson_round_trips_filtered_records() {
+        let service = create_test_service().unwrap();
+        seed_export_records(&service);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("usage.ndjson");
+
+        let job = service
+            .export_usage_streaming_job(&export_request(ExportFormat::Ndjson, &path))
+            .unwrap();
+        assert_eq!(job.record_count, 2);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let rows: Vec<UsageRecordV2> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(rows.len(), 2);
+        let expected = service
+            .list_usage_records_v2(
+                &export_request(ExportFormat::Ndjson, &path).filter,
+                None,
+                None,
+            )
+            .unwrap();
+        for (row, original) in rows.iter().zip(expected.iter()) {
+            assert_eq!(row.event_id, original.event_id);
+            assert_eq!(row.project_id.as_deref(), Some("proj-a"));
+            assert_eq!(row.session_id.as_deref(), Some("sess-export"));
+            assert_eq!(row.input_tokens, 100);
+            assert_eq!(row.output_tokens, 50);
+            assert_eq!(row.cost_microdollars, original.cost_microdollars);
+            assert_eq!(row.timestamp, original.timestamp);
+            assert_eq!(row.metadata, original.metadata);
+            assert_eq!(row.cost_status, original.cost_status);
+        }
+    }
+
+    #[test]
+    fn test_export_parquet_writes_filtered_rows() {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let service = create_test_service().unwrap();
+        seed_export_records(&service);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("usage.parquet");
+
+        let job = service
+            .export_usage_streaming_job(&export_request(ExportFormat::Parquet, &path))
+            .unwrap();
+        assert_eq!(job.record_count, 2);
+
+        let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 2);
+    }
+
+    fn import_rule(id: &str, from: i64, to: Option<i64>, input: i64) -> PricingRule {
+        let mut rule = PricingRule::new("import-provider", "import-model-*");
+        rule.id = id.to_string();
+        rule.effective_from = from;
+        rule.effective_to = to;
+        rule.input_per_million = input;
+        rule
+    }
+
+    #[test]
+    fn test_import_pricing_rejects_overlapping_dates() {
+        let service = create_test_service().unwrap();
+        let result = service
+            .import_pricing_rules(&[
+                import_rule("rule-a", 0, Some(2_000), 1_000_000),
+                import_rule("rule-b", 1_500, None, 2_000_000),
+                import_rule("rule-a", 5_000, None, 3_000_000),
+            ])
+            .unwrap();
+
+        assert!(result.imported.is_empty());
+        assert_eq!(result.conflicts.len(), 2);
+        assert_eq!(result.conflicts[0].index, 1);
+        assert_eq!(
+            result.conflicts[0].conflicting_rule_id.as_deref(),
+            Some("rule-a")
+        );
+        assert_eq!(result.conflicts[1].index, 2);
+        assert!(result.conflicts[1].reason.contains("duplicate"));
+        // Nothing was written
+        assert!(service
+            .list_pricing_rules()
+            .unwrap()
+            .iter()
+            .all(|r| r.provider != "import-provider"));
+
+        // Overlap with an already stored rule is also reported
+        service
+            .upsert_pricing_rule(&import_rule("stored", 0, None, 1_000_000))
+            .unwrap();
+        let result = service
+            .import_pricing_rules(&[import_rule("late", 10_000, None, 2_000_000)])
+            .unwrap();
+        assert_eq!(
+            result.conflicts[0].conflicting_rule_id.as_deref(),
+            Some("stored")
+        );
+    }
+
+    #[test]
+    fn test_import_pricing_export_round_trip() {
+        let service = create_test_service().unwrap();
+        let result = service
+            .import_pricing_rules(&[
+                import_rule("rule-old", 0, Some(2_000), 1_000_000),
+                import_rule("rule-new", 2_000, None, 2_000_000),
+            ])
+            .unwrap();
+        assert!(result.conflicts.is_empty());
+        assert_eq!(result.imported.len(), 2);
+
+        let exported: Vec<PricingRule> = service
+            .list_pricing_rules()
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.provider == "import-provider")
+            .collect();
+        let json = serde_json::to_string(&exported).unwrap();
+
+        // Re-importing the export into a fresh store reproduces it
+        let other = create_test_service().unwrap();
+        let parsed: Vec<PricingRule> = serde_json::from_str(&json).unwrap();
+        assert!(other
+            .import_pricing_rules(&parsed)
+            .unwrap()
+            .conflicts
+            .is_empty());
+        let reimported: Vec<PricingRule> = other
+            .list_pricing_rules()
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.provider == "import-provider")
+            .collect();
+        assert_eq!(reimported.len(), 2);
+        for (a, b) in exported.iter().zip(reimported.iter()) {
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.effective_from, b.effective_from);
+            assert_eq!(a.effective_to, b.effective_to);
+            assert_eq!(a.input_per_million, b.input_per_million);
+            assert_eq!(a.created_at, b.created_at);
+        }
+
+        // Re-importing into the original store updates in place
+        let again = service.import_pricing_rules(&parsed).unwrap();
+        assert!(again.conflicts.is_empty());
+    }
+
+    fn seed_timed_record(service: &AnalyticsService, timestamp: i64, cost: i64) {
+        let mut record =
+            UsageRecord::new("series-model", "series-provider", 100, 10).with_cost(cost);
+        record.timestamp = timestamp;
+        service.insert_usage_record(&record).unwrap();
+    }
+
+    #[test]
+    fn test_time_series_hourly_and_daily_totals_agree() {
+        let service = create_test_service().unwrap();
+        // 2026-03-01 00:00:00 UTC
+        let day = 1_772_323_200;
+        for (hour, cost) in [(1, 10), (1, 20), (5, 30), (23, 40), (26, 50)] {
+            seed_timed_record(&service, day + hour * 3_600 + 60, cost);
+        }
+        let filter = DashboardFilterV2 {
+            start_timestamp: Some(day),
+            end_timestamp: Some(day + 2 * 86_400),
+            ..Default::default()
+        };
+
+        let hourly = service
+            .get_time_series_v2(&filter, AggregationPeriod::Hourly, Some(Tz::UTC))
+            .unwrap();
+        let daily = service
+            .get_time_series_v2(&filter, AggregationPeriod::Daily, Some(Tz::UTC))
+            .unwrap();
+        assert_eq!(hourly.len(), 48);
+        assert_eq!(daily.len(), 2);
+
+        let total = |points: &[TimeSeriesPoint]| {
+            points
+                .iter()
+                .map(|p| (p.stats.total_cost_microdollars, p.stats.request_count))
+                .fold((0, 0), |acc, v| (acc.0 + v.0, acc.1 + v.1))
+        };
+        assert_eq!(total(&hourly), (150, 5));
+        assert_eq!(total(&hourly), total(&daily));
+        assert_eq!(daily[0].stats.total_cost_microdollars, 100);
+        assert_eq!(daily[1].stats.total_cost_microdollars, 50);
+
+        // New York (UTC-5 in winter): the two 01:00 UTC events belong to the previous local day
+        let new_york = parse_timezone("America/New_York").unwrap();
+        let shifted = service
+            .get_time_series_v2(&filter, AggregationPeriod::Daily, Some(new_york))
+            .unwrap();
+        assert_eq!(shifted.len(), 3);
+        assert_eq!(shifted[0].timestamp_formatted, "2026-02-28");
+        assert_eq!(shifted[0].stats.total_cost_microdollars, 30);
+        assert_eq!(total(&shifted), (150, 5));
+    }
+
+    #[test]
+    fn test_time_series_zero_fills_empty_buckets() {
+        let service = create_test_service().unwrap();
+        let day = 1_772_323_200;
+        seed_timed_record(&service, day + 60, 10);
+        seed_timed_record(&service, day + 3 * 86_400 + 60, 20);
+
+        let points = service
+            .get_time_series_v2(
+                &DashboardFilterV2::default(),
+                AggregationPeriod::Daily,
+                Some(Tz::UTC),
+            )
+            .unwrap();
+        let costs: Vec<i64> = points
+            .iter()
+            .map(|p| p.stats.total_cost_microdollars)
+            .collect();
+        assert_eq!(costs, vec![10, 0, 0, 20]);
+        assert_eq!(points[1].stats.request_count, 0);
+        assert_eq!(points[1].timestamp, day + 86_400);
+        assert_eq!(points[1].timestamp_formatted, "2026-03-02");
+    }
 }
@@ -0,0 +1,191 @@
+//! Time-Series Buckets
+//!
+//! Bucket arithmetic for usage time series in an IANA timezone. Bucket
+//! boundaries follow local wall-clock time, so days stay aligned to local
+//! midnight across DST changes. SQLite aggregates usage into UTC slots of
+//! `SQL_SLOT_SECS`, which are fine enough to fold into any local bucket.
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+use chrono_tz::Tz;
+
+use crate::models::analytics::AggregationPeriod;
+use crate::utils::error::{AppError, AppResult};
+
+/// Upper bound on generated buckets to keep zero-filling cheap.
+pub const MAX_TIME_SERIES_BUCKETS: usize = 20_000;
+
+/// Width of the UTC slots aggregated in SQL. Every real-world UTC offset is
+/// a multiple of 15 minutes, so a slot never straddles a local bucket edge.
+pub const SQL_SLOT_SECS: i64 = 900;
+
+const HOUR_SECS: i64 = 3_600;
+const DAY_SECS: i64 = 86_400;
+
+/// Resolve an IANA timezone name (e.g. `"Asia/Tokyo"`).
+pub fn parse_timezone(name: &str) -> AppResult<Tz> {
+    name.parse::<Tz>()
+        .map_err(|_| AppError::validation(format!("unknown timezone: {}", name)))
+}
+
+/// SQLite expression yielding the start of the `SQL_SLOT_SECS` UTC slot
+/// containing `ts_expr`.
+pub fn slot_start_sql(ts_expr: &str) -> String {
+    format!("(({ts_expr} / {SQL_SLOT_SECS}) * {SQL_SLOT_SECS})")
+}
+
+fn to_local(timestamp: i64, tz: Tz) -> DateTime<Tz> {
+    tz.timestamp_opt(timestamp, 0)
+        .single()
+        .unwrap_or_else(|| tz.timestamp_opt(0, 0).unwrap())
+}
+
+/// UTC epoch second of local midnight on `date`. When midnight falls in a
+/// DST gap, the first local instant after it is used.
+fn local_day_start(date: NaiveDate, tz: Tz) -> i64 {
+    let mut local: NaiveDateTime = date.and_hms_opt(0, 0, 0).unwrap();
+    for _ in 0..(DAY_SECS / SQL_SLOT_SECS) {
+        if let Some(t) = tz.from_local_datetime(&local).earliest() {
+            return t.timestamp();
+        }
+        local += chrono::Duration::seconds(SQL_SLOT_SECS);
+    }
+    local.and_utc().timestamp()
+}
+
+/// Start of the bucket containing `timestamp` (Monday-based weeks).
+pub fn bucket_start(period: AggregationPeriod, timestamp: i64, tz: Tz) -> i64 {
+    let local = to_local(timestamp, tz);
+    match period {
+        AggregationPeriod::Hourly => {
+            timestamp - (local.minute() as i64 * 60 + local.second() as i64)
+        }
+        AggregationPeriod::Daily => local_day_start(local.date_naive(), tz),
+        AggregationPeriod::Weekly => {
+            let weekday = local.weekday().num_days_from_monday() as u64;
+            let monday = local.date_naive() - Days::new(weekday);
+            local_day_start(monday, tz)
+        }
+        AggregationPeriod::Monthly => {
+            let first = local.date_naive().with_day(1).unwrap();
+            local_day_start(first, tz)
+        }
+    }
+}
+
+/// Start of the bucket following the one starting at `start`.
+pub fn next_bucket(period: AggregationPeriod, start: i64, tz: Tz) -> i64 {
+    let date = to_local(start, tz).date_naive();
+    let next = match period {
+        AggregationPeriod::Hourly => return start + HOUR_SECS,
+        AggregationPeriod::Daily => date.checked_add_days(Days::new(1)),
+        AggregationPeriod::Weekly => date.checked_add_days(Days::new(7)),
+        AggregationPeriod::Monthly => date.checked_add_months(Months::new(1)),
+    };
+    next.map(|d| local_day_start(d, tz))
+        .unwrap_or(start + 31 * DAY_SECS)
+}
+
+/// Display label for a bucket, formatted in local time.
+pub fn bucket_label(period: AggregationPeriod, start: i64, tz: Tz) -> String {
+    to_local(start, tz).format(period.sql_format()).to_string()
+}
+
+/// Every bucket start in `[from, to)`, aligned to bucket boundaries.
+pub fn bucket_range(period: AggregationPeriod, from: i64, to: i64, tz: Tz) -> AppResult<Vec<i64>> {
+    let mut starts = Vec::new();
+    let mut cursor = bucket_start(period, from, tz);
+    while cursor < to {
+        if starts.len() >= MAX_TIME_SERIES_BUCKETS {
+            return Err(AppError::validation(
+                "time range is too large for the requested bucket size",
+            ));
+        }
+        starts.push(cursor);
+        cursor = next_bucket(period, cursor, tz);
+    }
+    Ok(starts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(tz: Tz, y: i32, m: u32, d: u32, h: u32) -> i64 {
+        tz.with_ymd_and_hms(y, m, d, h, 0, 0)
+            .earliest()
+            .unwrap()
+            .timestamp()
+    }
+
+    #[test]
+    fn test_daily_bucket_respects_timezone() {
+        let tokyo = parse_timezone("Asia/Tokyo").unwrap();
+        // 2026-03-01 23:30 UTC is already 2026-03-02 08:30 in Tokyo
+        let t = ts(Tz::UTC, 2026, 3, 1, 23) + 1_800;
+        let start = bucket_start(AggregationPeriod::Daily, t, tokyo);
+        assert_eq!(start, ts(tokyo, 2026, 3, 2, 0));
+        assert_eq!(
+            bucket_label(AggregationPeriod::Daily, start, tokyo),
+            "2026-03-02"
+        );
+    }
+
+    #[test]
+    fn test_daily_buckets_follow_dst() {
+        let new_york = parse_timezone("America/New_York").unwrap();
+        // DST starts 2026-03-08 in New York, so that local day is 23 hours long
+        let days = bucket_range(
+            AggregationPeriod::Daily,
+            ts(new_york, 2026, 3, 7, 12),
+            ts(new_york, 2026, 3, 10, 0),
+            new_york,
+        )
+        .unwrap();
+        assert_eq!(
+            days,
+            vec![
+                ts(new_york, 2026, 3, 7, 0),
+                ts(new_york, 2026, 3, 8, 0),
+                ts(new_york, 2026, 3, 9, 0),
+            ]
+        );
+        assert_eq!(days[2] - days[1], 23 * HOUR_SECS);
+        // 23:30 local on the DST day still lands in that day's bucket
+        let late = ts(new_york, 2026, 3, 8, 23) + 1_800;
+        assert_eq!(
+            bucket_start(AggregationPeriod::Daily, late, new_york),
+            days[1]
+        );
+    }
+
+    #[test]
+    fn test_weekly_and_monthly_buckets() {
+        let utc = Tz::UTC;
+        // 2026-03-05 is a Thursday; the week starts Monday 2026-03-02
+        let t = ts(utc, 2026, 3, 5, 12);
+        assert_eq!(
+            bucket_start(AggregationPeriod::Weekly, t, utc),
+            ts(utc, 2026, 3, 2, 0)
+        );
+        let month = bucket_start(AggregationPeriod::Monthly, t, utc);
+        assert_eq!(month, ts(utc, 2026, 3, 1, 0));
+        assert_eq!(
+            next_bucket(AggregationPeriod::Monthly, month, utc),
+            ts(utc, 2026, 4, 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_bucket_range_and_cap() {
+        let utc = Tz::UTC;
+        let from = ts(utc, 2026, 3, 1, 5);
+        let to = ts(utc, 2026, 3, 4, 0);
+        let days = bucket_range(AggregationPeriod::Daily, from, to, utc).unwrap();
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0], ts(utc, 2026, 3, 1, 0));
+
+        let too_many = bucket_range(AggregationPeriod::Hourly, 0, 10 * 365 * DAY_SECS, utc);
+        assert!(too_many.is_err());
+        assert!(parse_timezone("Mars/Olympus_Mons").is_err());
+    }
+}
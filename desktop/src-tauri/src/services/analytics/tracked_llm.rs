@@ -4,7 +4,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use tokio::sync::mpsc;
 
-use crate::models::analytics::{AnalyticsAttribution, AnalyticsUsageEvent, CostStatus};
+use crate::models::analytics::{
+    AnalyticsAttribution, AnalyticsUsageEvent, CostStatus, UsageRecord,
+};
 use crate::services::analytics::{CostCalculator, TrackerMessage};
 use crate::services::llm::provider::LlmProvider;
 use crate::services::llm::types::{
@@ -38,12 +40,13 @@ fn build_usage_event(
     let cache_read_tokens = response.usage.cache_read_tokens.unwrap_or(0) as i64;
     let cache_write_tokens = response.usage.cache_creation_tokens.unwrap_or(0) as i64;
 
-    let cost_total =
-        cost_calculator.calculate_cost(provider_name, model_name, input_tokens, output_tokens);
+    let record = UsageRecord::new(model_name, provider_name, input_tokens, output_tokens)
+        .with_extended_tokens(thinking_tokens, cache_read_tokens, cache_write_tokens);
+    let cost_total = cost_calculator.calculate_cost_at(&record);
 
     AnalyticsUsageEvent {
         event_id: uuid::Uuid::new_v4().to_string(),
-        timestamp_utc: chrono::Utc::now().timestamp(),
+        timestamp_utc: record.timestamp,
         provider: provider_name.to_string(),
         model: model_name.to_string(),
         input_tokens,
@@ -183,6 +186,18 @@ impl LlmProvider for TrackedLlmProvider {
         self.inner.context_window()
     }
 
+    fn supports_json_mode(&self) -> bool {
+        self.inner.supports_json_mode()
+    }
+
+    fn supports_seed(&self) -> bool {
+        self.inner.supports_seed()
+    }
+
+    fn supports_prompt_cache(&self) -> bool {
+        self.inner.supports_prompt_cache()
+    }
+
     async fn send_message(
         &self,
         messages: Vec<Message>,
@@ -220,6 +235,10 @@ impl LlmProvider for TrackedLlmProvider {
         self.inner.health_check().await
     }
 
+    async fn preflight(&self) -> LlmResult<()> {
+        self.inner.preflight().await
+    }
+
     fn config(&self) -> &ProviderConfig {
         self.inner.config()
     }
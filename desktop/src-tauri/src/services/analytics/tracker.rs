@@ -7,7 +7,7 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio::sync::RwLock;
 
-use crate::models::analytics::{AnalyticsUsageEvent, UsageRecord};
+use crate::models::analytics::{AnalyticsUsageEvent, BudgetAlert, UsageRecord};
 use crate::utils::error::{AppError, AppResult};
 
 use super::cost_calculator::CostCalculator;
@@ -26,6 +26,9 @@ pub enum TrackerMessage {
     Shutdown,
 }
 
+/// Channel receiving budget alerts raised after the tracker records usage
+pub type BudgetAlertSender = mpsc::UnboundedSender<Vec<BudgetAlert>>;
+
 /// Configuration for the usage tracker
 #[derive(Debug, Clone)]
 pub struct TrackerConfig {
@@ -67,6 +70,17 @@ impl UsageTracker {
         service: Arc<AnalyticsService>,
         cost_calculator: Arc<CostCalculator>,
         config: TrackerConfig,
+    ) -> Self {
+        Self::with_budget_alerts(service, cost_calculator, config, None)
+    }
+
+    /// Create a usage tracker that evaluates budgets after each flush and
+    /// sends newly raised alerts to `budget_alerts`
+    pub fn with_budget_alerts(
+        service: Arc<AnalyticsService>,
+        cost_calculator: Arc<CostCalculator>,
+        config: TrackerConfig,
+        budget_alerts: Option<BudgetAlertSender>,
     ) -> Self {
         let (sender, receiver) = mpsc::channel::<TrackerMessage>(1000);
 
@@ -74,7 +88,7 @@ impl UsageTracker {
         let service_clone = service.clone();
         let config_clone = config.clone();
         tokio::spawn(async move {
-            Self::process_messages(receiver, service_clone, config_clone).await;
+            Self::process_messages(receiver, service_clone, config_clone, budget_alerts).await;
         });
 
         Self {
@@ -91,6 +105,7 @@ impl UsageTracker {
         mut receiver: mpsc::Receiver<TrackerMessage>,
         service: Arc<AnalyticsService>,
         config: TrackerConfig,
+        budget_alerts: Option<BudgetAlertSender>,
     ) {
         let mut legacy_buffer: Vec<UsageRecord> = Vec::with_capacity(config.buffer_size);
         let mut event_buffer: Vec<AnalyticsUsageEvent> = Vec::with_capacity(config.buffer_size);
@@ -98,6 +113,8 @@ impl UsageTracker {
             tokio::time::interval(tokio::time::Duration::from_secs(config.flush_interval_secs));
 
         loop {
+            let mut recorded = false;
+            let mut shutdown = false;
             tokio::select! {
                 // Process incoming messages
                 msg = receiver.recv() => {
@@ -107,77 +124,107 @@ impl UsageTracker {
 
                             // Auto-flush when buffer is full
                             if legacy_buffer.len() >= config.buffer_size {
-                                Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
+                                recorded = Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
                             }
                         }
                         Some(TrackerMessage::TrackEvent(event)) => {
                             event_buffer.push(event);
                             if event_buffer.len() >= config.buffer_size {
-                                Self::flush_event_buffer(&service, &mut event_buffer).await;
+                                recorded = Self::flush_event_buffer(&service, &mut event_buffer).await;
                             }
                         }
                         Some(TrackerMessage::Flush) => {
-                            Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
-                            Self::flush_event_buffer(&service, &mut event_buffer).await;
+                            recorded |= Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
+                            recorded |= Self::flush_event_buffer(&service, &mut event_buffer).await;
                         }
                         Some(TrackerMessage::Shutdown) | None => {
                             // Flush remaining records before shutdown
-                            Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
-                            Self::flush_event_buffer(&service, &mut event_buffer).await;
-                            break;
+                            recorded |= Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
+                            recorded |= Self::flush_event_buffer(&service, &mut event_buffer).await;
+                            shutdown = true;
                         }
                     }
                 }
                 // Periodic flush
                 _ = flush_interval.tick() => {
-                    if !legacy_buffer.is_empty() {
-                        Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
-                    }
-                    if !event_buffer.is_empty() {
-                        Self::flush_event_buffer(&service, &mut event_buffer).await;
-                    }
+                    recorded |= Self::flush_legacy_buffer(&service, &mut legacy_buffer).await;
+                    recorded |= Self::flush_event_buffer(&service, &mut event_buffer).await;
+                }
+            }
+
+            if recorded {
+                if let Some(budget_alerts) = &budget_alerts {
+                    Self::send_budget_alerts(&service, budget_alerts);
                 }
             }
+            if shutdown {
+                break;
+            }
         }
     }
 
-    /// Flush legacy usage buffer to database
-    async fn flush_legacy_buffer(service: &AnalyticsService, buffer: &mut Vec<UsageRecord>) {
+    /// Evaluate budgets against newly recorded usage and forward any alerts
+    fn send_budget_alerts(service: &AnalyticsService, budget_alerts: &BudgetAlertSender) {
+        match service.evaluate_budget_alerts(chrono::Utc::now().timestamp()) {
+            Ok(alerts) if !alerts.is_empty() => {
+                let _ = budget_alerts.send(alerts);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::warn!("Failed to evaluate budget alerts: {}", e);
+            }
+        }
+    }
+
+    /// Flush legacy usage buffer to database, returning whether any records were written
+    async fn flush_legacy_buffer(
+        service: &AnalyticsService,
+        buffer: &mut Vec<UsageRecord>,
+    ) -> bool {
         if buffer.is_empty() {
-            return;
+            return false;
         }
 
-        match service.insert_usage_records_batch(buffer) {
+        let written = match service.insert_usage_records_batch(buffer) {
             Ok(_) => {
                 tracing::debug!("Flushed {} usage records to database", buffer.len());
+                true
             }
             Err(e) => {
                 tracing::error!("Failed to flush usage records: {}", e);
                 // Keep records in buffer for retry? For now, we log and clear
+                false
             }
-        }
+        };
 
         buffer.clear();
+        written
     }
 
-    async fn flush_event_buffer(service: &AnalyticsService, buffer: &mut Vec<AnalyticsUsageEvent>) {
+    async fn flush_event_buffer(
+        service: &AnalyticsService,
+        buffer: &mut Vec<AnalyticsUsageEvent>,
+    ) -> bool {
         if buffer.is_empty() {
-            return;
+            return false;
         }
 
-        match service.insert_usage_events_batch(buffer) {
+        let written = match service.insert_usage_events_batch(buffer) {
             Ok(_) => {
                 tracing::debug!(
                     "Flushed {} analytics usage events to database",
                     buffer.len()
                 );
+                true
             }
             Err(e) => {
                 tracing::error!("Failed to flush analytics usage events: {}", e);
+                false
             }
-        }
+        };
 
         buffer.clear();
+        written
     }
 
     /// Track a new API usage
@@ -217,12 +264,17 @@ impl UsageTracker {
         Ok(())
     }
 
-    /// Track with full record details
-    pub async fn track_record(&self, record: UsageRecord) -> AppResult<()> {
+    /// Track with full record details.
+    /// Unpriced records are priced with the rules in effect at their own timestamp.
+    pub async fn track_record(&self, mut record: UsageRecord) -> AppResult<()> {
         if !self.config.enabled {
             return Ok(());
         }
 
+        if record.cost_microdollars == 0 {
+            record.cost_microdollars = self.cost_calculator.calculate_cost_at(&record);
+        }
+
         self.sender
             .send(TrackerMessage::Track(record))
             .await
@@ -347,6 +399,7 @@ impl UsageTracker {
 pub struct UsageTrackerBuilder {
     config: TrackerConfig,
     cost_calculator: Option<Arc<CostCalculator>>,
+    budget_alerts: Option<BudgetAlertSender>,
 }
 
 impl Default for UsageTrackerBuilder {
@@ -361,6 +414,7 @@ impl UsageTrackerBuilder {
         Self {
             config: TrackerConfig::default(),
             cost_calculator: None,
+            budget_alerts: None,
         }
     }
 
@@ -388,12 +442,18 @@ impl UsageTrackerBuilder {
         self
     }
 
+    /// Set the channel receiving budget alerts raised after each flush
+    pub fn budget_alerts(mut self, sender: BudgetAlertSender) -> Self {
+        self.budget_alerts = Some(sender);
+        self
+    }
+
     /// Build the tracker
     pub fn build(self, service: Arc<AnalyticsService>) -> UsageTracker {
         let cost_calc = self
             .cost_calculator
             .unwrap_or_else(|| Arc::new(CostCalculator::new()));
-        UsageTracker::new(service, cost_calc, self.config)
+        UsageTracker::with_budget_alerts(service, cost_calc, self.config, self.budget_alerts)
     }
 }
 
@@ -462,7 +522,7 @@ impl SyncUsageTracker {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::analytics::UsageFilter;
+    use crate::models::analytics::{CostBudget, UsageFilter};
     use r2d2::Pool;
     use r2d2_sqlite::SqliteConnectionManager;
 
@@ -608,6 +668,40 @@ mod tests {
         assert_eq!(record.project_id, Some("project-xyz".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_budget_alerts_sent_after_flush() {
+        let service = create_test_service();
+        service
+            .upsert_budget(&CostBudget {
+                project_id: None,
+                monthly_budget_microdollars: 1,
+                alert_threshold_percent: 100.0,
+                enabled: true,
+                updated_at: 0,
+            })
+            .unwrap();
+        let (alert_tx, mut alert_rx) = mpsc::unbounded_channel();
+        let tracker = UsageTrackerBuilder::new()
+            .buffer_size(1)
+            .budget_alerts(alert_tx)
+            .build(service);
+
+        // Nothing is evaluated until usage is recorded
+        assert!(alert_rx.try_recv().is_err());
+
+        tracker
+            .track("anthropic", "claude-3-5-sonnet-20241022", 1000, 500)
+            .await
+            .unwrap();
+
+        let alerts = tokio::time::timeout(tokio::time::Duration::from_secs(1), alert_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!alerts.is_empty());
+        assert!(alerts.iter().all(|a| a.project_id.is_none()));
+    }
+
     #[tokio::test]
     async fn test_builder() {
         let service = create_test_service();
@@ -567,6 +567,7 @@ fn content_type_to_ext(content_type: &str) -> &str {
         "text/html" => "html",
         "application/json" => "json",
         "application/pdf" => "pdf",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => "docx",
         "image/png" => "png",
         "image/jpeg" => "jpg",
         "image/gif" => "gif",
@@ -0,0 +1,383 @@
+//! CLAUDE.md Hierarchy Resolution
+//!
+//! Resolves the effective instructions for a working directory from the
+//! user-level file (`~/.claude/CLAUDE.md`), the repository-root file, and any
+//! CLAUDE.md files in subdirectories down to the working directory.
+//!
+//! Files are split into sections at `## ` headings and merged farthest
+//! first, so nearer files take precedence. Each section either overrides or
+//! appends to the same section (matched by heading, case-insensitively) from
+//! farther files. The mode is declared on the heading line or the first line
+//! of the section:
+//!
+//! ```text
+//! ## Testing <!-- merge: append -->
+//! ```
+//!
+//! Sections default to override; text before the first heading defaults to
+//! append so every file's introduction is kept.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::models::markdown::{
+    ClaudeMdMergeMode, ClaudeMdScope, ClaudeMdSource, EffectiveClaudeMd, EffectiveClaudeMdSection,
+};
+use crate::utils::error::{AppError, AppResult};
+
+const CLAUDE_MD: &str = "CLAUDE.md";
+const SECTION_PREFIX: &str = "## ";
+const DIRECTIVE_PREFIX: &str = "<!-- merge:";
+const DIRECTIVE_SUFFIX: &str = "-->";
+
+/// A section of a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileSection {
+    heading: String,
+    mode: Option<ClaudeMdMergeMode>,
+    body: String,
+}
+
+impl FileSection {
+    fn key(&self) -> String {
+        self.heading.trim().to_lowercase()
+    }
+
+    fn default_mode(&self) -> ClaudeMdMergeMode {
+        if self.heading.is_empty() {
+            ClaudeMdMergeMode::Append
+        } else {
+            ClaudeMdMergeMode::Override
+        }
+    }
+}
+
+/// CLAUDE.md files that apply to `working_dir`, farthest first.
+///
+/// Walks up from `working_dir` to the repository root (the nearest ancestor
+/// containing `.git`), or to the filesystem root when there is none. The
+/// user-level file in `user_dir` comes first when present.
+pub fn discover_claude_md_hierarchy(
+    working_dir: &Path,
+    user_dir: Option<&Path>,
+) -> Vec<(PathBuf, ClaudeMdScope)> {
+    let mut dirs = Vec::new();
+    let mut repo_root = None;
+    for dir in working_dir.ancestors() {
+        dirs.push(dir);
+        if dir.join(".git").exists() {
+            repo_root = Some(dir);
+            break;
+        }
+    }
+    dirs.reverse();
+
+    let mut files: Vec<(PathBuf, ClaudeMdScope)> = Vec::new();
+    if let Some(user_file) = user_dir.map(|d| d.join(CLAUDE_MD)) {
+        if user_file.is_file() {
+            files.push((user_file, ClaudeMdScope::User));
+        }
+    }
+    for dir in dirs {
+        let file = dir.join(CLAUDE_MD);
+        if !file.is_file() || files.iter().any(|(f, _)| *f == file) {
+            continue;
+        }
+        let is_project = match repo_root {
+            Some(root) => dir == root,
+            None => !files.iter().any(|(_, s)| *s != ClaudeMdScope::User),
+        };
+        let scope = if is_project {
+            ClaudeMdScope::Project
+        } else {
+            ClaudeMdScope::Directory
+        };
+        files.push((file, scope));
+    }
+    files
+}
+
+/// Merge the CLAUDE.md hierarchy for `working_dir`.
+pub fn resolve_effective_claude_md(
+    working_dir: &Path,
+    user_dir: Option<&Path>,
+) -> AppResult<EffectiveClaudeMd> {
+    if !working_dir.exists() {
+        return Err(AppError::not_found(format!(
+            "Directory not found: {}",
+            working_dir.display()
+        )));
+    }
+    let working_dir = if working_dir.is_file() {
+        working_dir.parent().unwrap_or(working_dir)
+    } else {
+        working_dir
+    };
+
+    let mut layers = Vec::new();
+    for (path, scope) in discover_claude_md_hierarchy(working_dir, user_dir) {
+        let content = fs::read_to_string(&path)?;
+        layers.push((
+            ClaudeMdSource {
+                path: path.to_string_lossy().to_string(),
+                scope,
+            },
+            content,
+        ));
+    }
+
+    let mut effective = merge_layers(&layers);
+    effective.working_dir = working_dir.to_string_lossy().to_string();
+    Ok(effective)
+}
+
+/// Merge file contents ordered farthest first.
+fn merge_layers(layers: &[(ClaudeMdSource, String)]) -> EffectiveClaudeMd {
+    struct Merged {
+        key: String,
+        heading: String,
+        mode: ClaudeMdMergeMode,
+        parts: Vec<(String, String)>,
+        overridden: Vec<String>,
+    }
+
+    let mut merged: Vec<Merged> = Vec::new();
+    for (source, content) in layers {
+        for section in split_sections(content) {
+            let key = section.key();
+            let mode = section.mode.unwrap_or_else(|| section.default_mode());
+            let part = (
+                source.path.clone(),
+                section.body.trim_matches('\n').to_string(),
+            );
+            match merged.iter_mut().find(|m| m.key == key) {
+                Some(existing) => {
+                    existing.mode = mode;
+                    existing.heading = section.heading;
+                    if mode == ClaudeMdMergeMode::Override {
+                        let replaced = existing.parts.drain(..).map(|(path, _)| path);
+                        existing.overridden.extend(replaced);
+                    }
+                    existing.parts.push(part);
+                }
+                None => merged.push(Merged {
+                    key,
+                    heading: section.heading,
+                    mode,
+                    parts: vec![part],
+                    overridden: Vec::new(),
+                }),
+            }
+        }
+    }
+
+    let sections: Vec<EffectiveClaudeMdSection> = merged
+        .into_iter()
+        .map(|m| {
+            let content = m
+                .parts
+                .iter()
+                .map(|(_, body)| body.as_str())
+                .filter(|body| !body.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            EffectiveClaudeMdSection {
+                heading: m.heading,
+                content,
+                mode: m.mode,
+                sources: m.parts.into_iter().map(|(path, _)| path).collect(),
+                overridden: m.overridden,
+            }
+        })
+        .collect();
+
+    let content = sections
+        .iter()
+        .map(|s| {
+            if s.heading.is_empty() {
+                format!("{}\n", s.content)
+            } else if s.content.is_empty() {
+                format!("{}{}\n", SECTION_PREFIX, s.heading)
+            } else {
+                format!("{}{}\n\n{}\n", SECTION_PREFIX, s.heading, s.content)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    EffectiveClaudeMd {
+        working_dir: String::new(),
+        sources: layers.iter().map(|(source, _)| source.clone()).collect(),
+        sections,
+        content,
+    }
+}
+
+/// Split a file at `## ` headings, ignoring headings inside code fences.
+fn split_sections(content: &str) -> Vec<FileSection> {
+    let mut sections = vec![FileSection {
+        heading: String::new(),
+        mode: None,
+        body: String::new(),
+    }];
+    let mut in_fence = false;
+    let mut at_section_start = true;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        let current = sections.last_mut().expect("at least one section");
+        if !in_fence {
+            if let Some(heading) = line.strip_prefix(SECTION_PREFIX) {
+                let (heading, mode) = take_directive(heading);
+                sections.push(FileSection {
+                    heading: heading.trim().to_string(),
+                    mode,
+                    body: String::new(),
+                });
+                at_section_start = true;
+                continue;
+            }
+            if at_section_start && !line.trim().is_empty() {
+                at_section_start = false;
+                let (rest, mode) = take_directive(line);
+                if mode.is_some() && current.mode.is_none() {
+                    current.mode = mode;
+                    if rest.trim().is_empty() {
+                        continue;
+                    }
+                }
+            }
+        }
+        current.body.push_str(line);
+        current.body.push('\n');
+    }
+
+    sections.retain(|s| !s.heading.is_empty() || !s.body.trim().is_empty());
+    sections
+}
+
+/// Remove a `<!-- merge: append|override -->` directive from `text`.
+fn take_directive(text: &str) -> (String, Option<ClaudeMdMergeMode>) {
+    let Some(start) = text.find(DIRECTIVE_PREFIX) else {
+        return (text.to_string(), None);
+    };
+    let after = &text[start + DIRECTIVE_PREFIX.len()..];
+    let Some(end) = after.find(DIRECTIVE_SUFFIX) else {
+        return (text.to_string(), None);
+    };
+    let mode = match after[..end].trim().to_lowercase().as_str() {
+        "append" => ClaudeMdMergeMode::Append,
+        "override" => ClaudeMdMergeMode::Override,
+        _ => return (text.to_string(), None),
+    };
+    let rest = format!(
+        "{}{}",
+        &text[..start],
+        &after[end + DIRECTIVE_SUFFIX.len()..]
+    );
+    (rest.trim_end().to_string(), Some(mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_nested_claude_md_precedence_and_provenance() {
+        let home = TempDir::new().unwrap();
+        let repo = TempDir::new().unwrap();
+        let sub = repo.path().join("crates").join("api");
+        fs::create_dir_all(&sub).unwrap();
+        fs::create_dir(repo.path().join(".git")).unwrap();
+
+        fs::write(
+            home.path().join(CLAUDE_MD),
+            "## Style\nUse tabs.\n\n## Testing <!-- merge: append -->\nAlways run tests.\n",
+        )
+        .unwrap();
+        fs::write(
+            repo.path().join(CLAUDE_MD),
+            "# Repo\n\n## Style\nUse 4 spaces.\n\n## Testing <!-- merge: append -->\nRun `cargo test`.\n",
+        )
+        .unwrap();
+        fs::write(
+            sub.join(CLAUDE_MD),
+            "## testing\n<!-- merge: append -->\nAPI tests need the mock server.\n\n## Style\nFollow rustfmt.\n",
+        )
+        .unwrap();
+
+        let effective = resolve_effective_claude_md(&sub, Some(home.path())).unwrap();
+        let home_file = home.path().join(CLAUDE_MD).to_string_lossy().to_string();
+        let repo_file = repo.path().join(CLAUDE_MD).to_string_lossy().to_string();
+        let sub_file = sub.join(CLAUDE_MD).to_string_lossy().to_string();
+
+        let scopes: Vec<_> = effective.sources.iter().map(|s| s.scope).collect();
+        assert_eq!(
+            scopes,
+            vec![
+                ClaudeMdScope::User,
+                ClaudeMdScope::Project,
+                ClaudeMdScope::Directory
+            ]
+        );
+
+        let section = |name: &str| {
+            effective
+                .sections
+                .iter()
+                .find(|s| s.heading.eq_ignore_ascii_case(name))
+                .unwrap()
+        };
+
+        // Override: the nearest file wins and farther files are recorded.
+        let style = section("Style");
+        assert_eq!(style.content, "Follow rustfmt.");
+        assert_eq!(style.mode, ClaudeMdMergeMode::Override);
+        assert_eq!(style.sources, vec![sub_file.clone()]);
+        assert_eq!(style.overridden, vec![home_file.clone(), repo_file.clone()]);
+
+        // Append: every level contributes, farthest first.
+        let testing = section("Testing");
+        assert_eq!(testing.mode, ClaudeMdMergeMode::Append);
+        assert_eq!(
+            testing.content,
+            "Always run tests.\n\nRun `cargo test`.\n\nAPI tests need the mock server."
+        );
+        assert_eq!(
+            testing.sources,
+            vec![home_file, repo_file.clone(), sub_file]
+        );
+
+        let intro = section("");
+        assert_eq!(intro.content, "# Repo");
+        assert_eq!(intro.sources, vec![repo_file]);
+
+        assert!(!effective.content.contains("<!-- merge"));
+        assert!(effective
+            .content
+            .contains("## testing\n\nAlways run tests."));
+    }
+
+    #[test]
+    fn test_discovery_stops_at_repo_root() {
+        let outer = TempDir::new().unwrap();
+        let repo = outer.path().join("repo");
+        fs::create_dir_all(repo.join(".git")).unwrap();
+        fs::write(outer.path().join(CLAUDE_MD), "outside").unwrap();
+        fs::write(repo.join(CLAUDE_MD), "inside").unwrap();
+
+        let files = discover_claude_md_hierarchy(&repo, None);
+        assert_eq!(files, vec![(repo.join(CLAUDE_MD), ClaudeMdScope::Project)]);
+    }
+
+    #[test]
+    fn test_headings_in_code_fences_do_not_split_sections() {
+        let sections = split_sections("## Usage\n```md\n## Not a heading\n```\n");
+        assert_eq!(sections.len(), 1);
+        assert!(sections[0].body.contains("## Not a heading"));
+    }
+}
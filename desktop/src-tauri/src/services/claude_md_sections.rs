@@ -0,0 +1,416 @@
+//! CLAUDE.md Managed Sections
+//!
+//! Splits a CLAUDE.md file into user-authored text and tool-managed sections
+//! delimited by sentinel comments:
+//!
+//! ```text
+//! <!-- plan-cascade:begin commands sha256=0123abcd4567ef89 -->
+//! ...generated content...
+//! <!-- plan-cascade:end commands -->
+//! ```
+//!
+//! The hash records the body as it was generated, so a merge can tell whether
+//! a managed section was edited by hand. Everything outside the sentinels is
+//! preserved verbatim.
+
+use sha2::{Digest, Sha256};
+
+use crate::models::markdown::{ClaudeMdMergeReport, ManagedSectionInfo};
+
+const BEGIN_PREFIX: &str = "<!-- plan-cascade:begin ";
+const END_PREFIX: &str = "<!-- plan-cascade:end ";
+const COMMENT_SUFFIX: &str = "-->";
+const HASH_KEY: &str = "sha256=";
+/// Section id used when generated content carries no sentinels.
+const DEFAULT_SECTION_ID: &str = "generated";
+
+const CONFLICT_START: &str = "<<<<<<< edited";
+const CONFLICT_SEPARATOR: &str = "=======";
+const CONFLICT_END: &str = ">>>>>>> regenerated";
+
+/// A tool-managed section.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManagedSection {
+    pub id: String,
+    /// Hash of the body when it was last generated, if stamped.
+    pub hash: Option<String>,
+    pub body: String,
+}
+
+impl ManagedSection {
+    fn generated(id: impl Into<String>, body: impl Into<String>) -> Self {
+        let body = body.into();
+        Self {
+            id: id.into(),
+            hash: Some(body_hash(&body)),
+            body,
+        }
+    }
+
+    /// Whether the body differs from what was generated.
+    pub fn is_edited(&self) -> bool {
+        self.hash
+            .as_deref()
+            .is_some_and(|hash| hash != body_hash(&self.body))
+    }
+
+    /// Whether the body still holds unresolved conflict markers.
+    pub fn has_conflict(&self) -> bool {
+        self.body.lines().any(|l| l.trim_end() == CONFLICT_START)
+    }
+
+    fn render(&self, out: &mut String) {
+        out.push_str(BEGIN_PREFIX);
+        out.push_str(&self.id);
+        if let Some(hash) = &self.hash {
+            out.push(' ');
+            out.push_str(HASH_KEY);
+            out.push_str(hash);
+        }
+        out.push(' ');
+        out.push_str(COMMENT_SUFFIX);
+        out.push('\n');
+        out.push_str(&self.body);
+        if !self.body.is_empty() && !self.body.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str(END_PREFIX);
+        out.push_str(&self.id);
+        out.push(' ');
+        out.push_str(COMMENT_SUFFIX);
+        out.push('\n');
+    }
+}
+
+/// A piece of a CLAUDE.md file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClaudeMdSegment {
+    /// User-authored text, kept verbatim.
+    Text(String),
+    Managed(ManagedSection),
+}
+
+/// Result of merging regenerated content into an existing file.
+#[derive(Debug, Clone)]
+pub struct ClaudeMdMerge {
+    pub content: String,
+    pub report: ClaudeMdMergeReport,
+}
+
+/// Split `content` into user text and managed sections.
+///
+/// A begin sentinel without a matching end is treated as user text.
+pub fn parse_claude_md(content: &str) -> Vec<ClaudeMdSegment> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    // (begin line, section being read)
+    let mut open: Option<(String, ManagedSection)> = None;
+
+    for line in content.split_inclusive('\n') {
+        match open.as_mut() {
+            None => match parse_begin(line) {
+                Some((id, hash)) => {
+                    if !text.is_empty() {
+                        segments.push(ClaudeMdSegment::Text(std::mem::take(&mut text)));
+                    }
+                    let section = ManagedSection {
+                        id,
+                        hash,
+                        body: String::new(),
+                    };
+                    open = Some((line.to_string(), section));
+                }
+                None => text.push_str(line),
+            },
+            Some((_, section)) => {
+                if parse_end(line) == Some(section.id.as_str()) {
+                    let (_, section) = open.take().expect("open section");
+                    segments.push(ClaudeMdSegment::Managed(section));
+                } else {
+                    section.body.push_str(line);
+                }
+            }
+        }
+    }
+
+    if let Some((begin, section)) = open {
+        text.push_str(&begin);
+        text.push_str(&section.body);
+    }
+    if !text.is_empty() {
+        segments.push(ClaudeMdSegment::Text(text));
+    }
+    segments
+}
+
+/// Render segments back into file content.
+pub fn render_claude_md(segments: &[ClaudeMdSegment]) -> String {
+    let mut out = String::new();
+    for segment in segments {
+        match segment {
+            ClaudeMdSegment::Text(text) => out.push_str(text),
+            ClaudeMdSegment::Managed(section) => section.render(&mut out),
+        }
+    }
+    out
+}
+
+/// Managed sections of `content`, in file order.
+pub fn managed_sections(content: &str) -> Vec<ManagedSectionInfo> {
+    parse_claude_md(content)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            ClaudeMdSegment::Managed(section) => Some(ManagedSectionInfo {
+                edited: section.is_edited(),
+                conflicted: section.has_conflict(),
+                id: section.id,
+            }),
+            ClaudeMdSegment::Text(_) => None,
+        })
+        .collect()
+}
+
+/// Stamp a generation hash on every managed section that lacks one.
+pub fn stamp_managed_sections(content: &str) -> String {
+    let segments: Vec<_> = parse_claude_md(content)
+        .into_iter()
+        .map(|segment| match segment {
+            ClaudeMdSegment::Managed(section) if section.hash.is_none() => {
+                ClaudeMdSegment::Managed(ManagedSection::generated(section.id, section.body))
+            }
+            other => other,
+        })
+        .collect();
+    render_claude_md(&segments)
+}
+
+/// Merge freshly generated content into an existing CLAUDE.md.
+///
+/// User text is kept verbatim. Unedited managed sections are replaced with
+/// their regenerated bodies; a managed section that was edited by hand gets
+/// conflict markers around both versions. Generated sections missing from
+/// the file are appended; managed sections no longer generated are dropped
+/// unless they were edited.
+pub fn merge_claude_md(existing: &str, generated: &str) -> ClaudeMdMerge {
+    let mut fresh: Vec<(ManagedSection, bool)> = parse_claude_md(generated)
+        .into_iter()
+        .filter_map(|segment| match segment {
+            ClaudeMdSegment::Managed(section) => {
+                Some((ManagedSection::generated(section.id, section.body), false))
+            }
+            ClaudeMdSegment::Text(_) => None,
+        })
+        .collect();
+    if fresh.is_empty() && !generated.trim().is_empty() {
+        fresh.push((
+            ManagedSection::generated(DEFAULT_SECTION_ID, generated),
+            false,
+        ));
+    }
+
+    let mut report = ClaudeMdMergeReport::default();
+    if existing.trim().is_empty() {
+        // Nothing to preserve: keep the generated layout, text included.
+        let ids: Vec<String> = fresh.iter().map(|(s, _)| s.id.clone()).collect();
+        report.managed_sections = ids.clone();
+        report.added = ids;
+        let content = if parse_claude_md(generated)
+            .iter()
+            .any(|s| matches!(s, ClaudeMdSegment::Managed(_)))
+        {
+            stamp_managed_sections(generated)
+        } else {
+            render_claude_md(
+                &fresh
+                    .into_iter()
+                    .map(|(s, _)| ClaudeMdSegment::Managed(s))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        return ClaudeMdMerge { content, report };
+    }
+
+    let mut merged = Vec::new();
+
+    for segment in parse_claude_md(existing) {
+        let current = match segment {
+            ClaudeMdSegment::Managed(section) => section,
+            text => {
+                merged.push(text);
+                continue;
+            }
+        };
+        let replacement = fresh
+            .iter_mut()
+            .find(|(section, used)| !*used && section.id == current.id);
+        let section = match replacement {
+            Some((regenerated, used)) => {
+                *used = true;
+                let local = local_side(&current.body);
+                if !current.is_edited() || normalize(local) == normalize(&regenerated.body) {
+                    regenerated.clone()
+                } else {
+                    report.conflicts.push(current.id.clone());
+                    ManagedSection {
+                        body: conflict_body(local, &regenerated.body),
+                        ..current
+                    }
+                }
+            }
+            None if current.is_edited() => current,
+            None => {
+                report.removed.push(current.id);
+                continue;
+            }
+        };
+        report.managed_sections.push(section.id.clone());
+        merged.push(ClaudeMdSegment::Managed(section));
+    }
+
+    for (section, used) in fresh {
+        if used {
+            continue;
+        }
+        if let Some(ClaudeMdSegment::Text(text)) = merged.last_mut() {
+            if !text.ends_with("\n\n") {
+                text.push_str(if text.ends_with('\n') { "\n" } else { "\n\n" });
+            }
+        } else if !merged.is_empty() {
+            merged.push(ClaudeMdSegment::Text("\n".to_string()));
+        }
+        report.added.push(section.id.clone());
+        report.managed_sections.push(section.id.clone());
+        merged.push(ClaudeMdSegment::Managed(section));
+    }
+
+    ClaudeMdMerge {
+        content: render_claude_md(&merged),
+        report,
+    }
+}
+
+fn parse_begin(line: &str) -> Option<(String, Option<String>)> {
+    let inner = line
+        .trim()
+        .strip_prefix(BEGIN_PREFIX)?
+        .strip_suffix(COMMENT_SUFFIX)?;
+    let mut parts = inner.split_whitespace();
+    let id = parts.next()?.to_string();
+    let hash = parts
+        .find_map(|part| part.strip_prefix(HASH_KEY))
+        .map(str::to_string);
+    Some((id, hash))
+}
+
+fn parse_end(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix(END_PREFIX)?
+        .strip_suffix(COMMENT_SUFFIX)
+        .map(str::trim)
+}
+
+/// The hand-edited side of a body left with conflict markers by an earlier
+/// merge, so repeated regenerations do not nest conflicts.
+fn local_side(body: &str) -> &str {
+    let Some(start) = body.find(&format!("{}\n", CONFLICT_START)) else {
+        return body;
+    };
+    let local_start = start + CONFLICT_START.len() + 1;
+    match body[local_start..].find(&format!("{}\n", CONFLICT_SEPARATOR)) {
+        Some(end) => &body[local_start..local_start + end],
+        None => body,
+    }
+}
+
+fn conflict_body(local: &str, regenerated: &str) -> String {
+    let mut body = String::new();
+    for part in [
+        CONFLICT_START,
+        local,
+        CONFLICT_SEPARATOR,
+        regenerated,
+        CONFLICT_END,
+    ] {
+        body.push_str(part);
+        if !part.ends_with('\n') {
+            body.push('\n');
+        }
+    }
+    body
+}
+
+fn normalize(body: &str) -> String {
+    body.replace("\r\n", "\n").trim_end().to_string()
+}
+
+fn body_hash(body: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(normalize(body).as_bytes());
+    format!("{:x}", hasher.finalize())[..16].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generated(commands: &str) -> String {
+        format!(
+            "{}commands {}\n{}{}commands {}\n",
+            BEGIN_PREFIX, COMMENT_SUFFIX, commands, END_PREFIX, COMMENT_SUFFIX
+        )
+    }
+
+    #[test]
+    fn test_user_section_survives_regenerate() {
+        let first = merge_claude_md("", &generated("- `cargo test`\n")).content;
+        let edited = format!(
+            "# My Project\n\nWe prefer small PRs.\n\n{}\n## Notes\nKeep me.\n",
+            first
+        );
+
+        let merged = merge_claude_md(&edited, &generated("- `cargo test --workspace`\n"));
+
+        assert!(merged.report.conflicts.is_empty());
+        assert_eq!(merged.report.managed_sections, vec!["commands"]);
+        assert!(merged
+            .content
+            .starts_with("# My Project\n\nWe prefer small PRs.\n\n"));
+        assert!(merged.content.ends_with("\n## Notes\nKeep me.\n"));
+        assert!(merged.content.contains("cargo test --workspace"));
+        assert!(!merged.content.contains("- `cargo test`\n"));
+        assert!(managed_sections(&merged.content)
+            .iter()
+            .all(|s| !s.edited && !s.conflicted));
+    }
+
+    #[test]
+    fn test_edited_managed_section_surfaces_conflict() {
+        let first = merge_claude_md("", &generated("- `cargo test`\n")).content;
+        let hand_edited = first.replace("- `cargo test`", "- `cargo nextest run`");
+        assert!(managed_sections(&hand_edited)[0].edited);
+
+        let merged = merge_claude_md(&hand_edited, &generated("- `cargo test --workspace`\n"));
+
+        assert_eq!(merged.report.conflicts, vec!["commands"]);
+        assert!(merged.content.contains(CONFLICT_START));
+        assert!(merged.content.contains("cargo nextest run"));
+        assert!(merged.content.contains("cargo test --workspace"));
+        assert!(managed_sections(&merged.content)[0].conflicted);
+
+        // Regenerating again keeps a single conflict rather than nesting.
+        let again = merge_claude_md(&merged.content, &generated("- `cargo test --all`\n"));
+        assert_eq!(again.content.matches(CONFLICT_START).count(), 1);
+        assert!(again.content.contains("cargo nextest run"));
+        assert!(again.content.contains("cargo test --all"));
+    }
+
+    #[test]
+    fn test_unterminated_sentinel_is_kept_as_text() {
+        let content = format!("{}commands -->\nstray\n", BEGIN_PREFIX);
+        assert_eq!(
+            parse_claude_md(&content),
+            vec![ClaudeMdSegment::Text(content.clone())]
+        );
+        assert_eq!(render_claude_md(&parse_claude_md(&content)), content);
+    }
+}
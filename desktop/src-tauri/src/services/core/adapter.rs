@@ -11,6 +11,7 @@
 //! - Conversion utilities handle `ToolContext <-> ToolExecutionContext` mapping
 //! - `ParameterSchema <-> serde_json::Value` conversion
 //! - `ToolResult <-> AppResult<Value>` conversion
+//! - Core `CancellationToken <-> tokio_util::sync::CancellationToken` linking
 
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
@@ -22,6 +23,7 @@ use crate::services::llm::types::ParameterSchema;
 use crate::services::tools::executor::ToolResult;
 use crate::services::tools::trait_def::{Tool, ToolExecutionContext, ToolRegistry};
 use crate::utils::error::{AppError, AppResult};
+use plan_cascade_core::cancellation::CancellationToken;
 use plan_cascade_core::context::ToolContext;
 use plan_cascade_core::error::{CoreError, CoreResult};
 use plan_cascade_core::tool_trait::{
@@ -123,11 +125,39 @@ pub fn app_result_to_tool_result(result: AppResult<Value>) -> ToolResult {
     }
 }
 
+/// Create a core `CancellationToken` that is cancelled when `token` is.
+///
+/// Cancellation only flows from the tokio token to the core token: the core
+/// token wraps a child of `token`, so no background task is involved.
+pub fn core_token_from_tokio(token: &tokio_util::sync::CancellationToken) -> CancellationToken {
+    CancellationToken::from_tokio(token.child_token())
+}
+
+/// Create a `tokio_util` cancellation token that is cancelled when the core
+/// `token` is cancelled or its deadline elapses.
+///
+/// When the core token has a deadline, a timer task enforces it on the
+/// returned token. The task exits at the deadline or on cancellation,
+/// whichever comes first.
+pub fn tokio_token_from_core(token: &CancellationToken) -> tokio_util::sync::CancellationToken {
+    let tokio_token = token.tokio_token();
+    if token.deadline().is_some() && !token.is_cancelled() {
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            let token = token.clone();
+            handle.spawn(async move {
+                token.cancelled().await;
+            });
+        }
+    }
+    tokio_token
+}
+
 /// Create a `ToolContext` (new system) from a `ToolExecutionContext` (old system).
 ///
 /// Maps the common fields: session_id, project_root, and uses sensible defaults
 /// for fields not present in the old context (agent_name = "legacy-adapter",
-/// tool_call_id = "adapter-call").
+/// tool_call_id = "adapter-call"). The old context's cancellation token is
+/// linked to the new context's token.
 pub fn tool_execution_context_to_tool_context(ctx: &ToolExecutionContext) -> ToolContext {
     ToolContext::new(
         &ctx.session_id,
@@ -135,6 +165,7 @@ pub fn tool_execution_context_to_tool_context(ctx: &ToolExecutionContext) -> Too
         "legacy-adapter",
         "adapter-call",
     )
+    .with_cancellation(core_token_from_tokio(&ctx.cancellation_token))
 }
 
 /// Create a `ToolExecutionContext` (old system) from a `ToolContext` (new system).
@@ -152,7 +183,7 @@ pub fn tool_context_to_tool_execution_context(ctx: &ToolContext) -> ToolExecutio
         working_directory: Arc::new(Mutex::new(project_root)),
         read_cache: Arc::new(Mutex::new(HashMap::new())),
         read_files: Arc::new(Mutex::new(std::collections::HashSet::new())),
-        cancellation_token: tokio_util::sync::CancellationToken::new(),
+        cancellation_token: tokio_token_from_core(ctx.cancellation_token()),
         web_fetch: Arc::new(crate::services::tools::web_fetch::WebFetchService::new()),
         web_search: None,
         index_store: None,
@@ -175,6 +206,7 @@ pub fn tool_context_to_tool_execution_context(ctx: &ToolContext) -> ToolExecutio
         knowledge_project_id: None,
         knowledge_collection_filter: None,
         knowledge_document_filter: None,
+        bash_env_allowlist: Vec::new(),
     }
 }
 
@@ -500,6 +532,7 @@ mod tests {
             knowledge_project_id: None,
             knowledge_collection_filter: None,
             knowledge_document_filter: None,
+            bash_env_allowlist: Vec::new(),
         }
     }
 
@@ -624,6 +657,61 @@ mod tests {
         assert_eq!(original_ctx.project_root, roundtripped.project_root);
     }
 
+    #[tokio::test]
+    async fn test_tool_execution_context_cancellation_reaches_tool_context() {
+        let old_ctx = make_tool_execution_context();
+        let new_ctx = tool_execution_context_to_tool_context(&old_ctx);
+        assert!(new_ctx.check_cancelled().is_ok());
+
+        old_ctx.cancellation_token.cancel();
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            new_ctx.cancellation_token().cancelled(),
+        )
+        .await
+        .expect("core token should follow the tokio token");
+        assert!(new_ctx.check_cancelled().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_tool_context_cancellation_reaches_tool_execution_context() {
+        let new_ctx = make_tool_context();
+        let old_ctx = tool_context_to_tool_execution_context(&new_ctx);
+        assert!(!old_ctx.cancellation_token.is_cancelled());
+
+        new_ctx.cancellation_token().cancel();
+        tokio::time::timeout(
+            std::time::Duration::from_secs(1),
+            old_ctx.cancellation_token.cancelled(),
+        )
+        .await
+        .expect("tokio token should follow the core token");
+    }
+
+    #[test]
+    fn test_already_cancelled_tokens_link_without_runtime() {
+        let tokio_token = tokio_util::sync::CancellationToken::new();
+        tokio_token.cancel();
+        assert!(core_token_from_tokio(&tokio_token).is_cancelled());
+
+        let core = CancellationToken::new();
+        core.cancel();
+        assert!(tokio_token_from_core(&core).is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_core_deadline_reaches_tokio_token() {
+        let core = CancellationToken::with_timeout(std::time::Duration::from_millis(20));
+        let tokio_token = tokio_token_from_core(&core);
+        tokio::time::timeout(std::time::Duration::from_secs(1), tokio_token.cancelled())
+            .await
+            .expect("deadline should cancel the tokio token");
+        assert_eq!(
+            core.reason(),
+            Some(plan_cascade_core::CancelReason::DeadlineExceeded)
+        );
+    }
+
     // ── ToolAdapter tests (old Tool -> new UnifiedTool) ────────────────
 
     #[test]
@@ -79,6 +79,14 @@ pub struct EventActions {
     /// tool output.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quality_gate_result: Option<QualityGateActionResult>,
+
+    /// Ordered batch of additional actions.
+    ///
+    /// Applied after the single-slot fields above, strictly in declared
+    /// order, and all-or-nothing: if any action in the batch fails, the
+    /// actions before it in the batch are rolled back.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub batch: Vec<EventAction>,
 }
 
 /// Request to create a checkpoint.
@@ -103,6 +111,32 @@ pub struct QualityGateActionResult {
     pub details: Option<String>,
 }
 
+/// A single action in an ordered `EventActions::batch`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventAction {
+    /// Set one session state key.
+    SetState { key: String, value: Value },
+    /// Create a checkpoint.
+    Checkpoint(CheckpointRequest),
+    /// Record a quality gate result.
+    QualityGate(QualityGateActionResult),
+    /// Transfer execution to another agent.
+    Transfer { agent: String },
+}
+
+impl EventAction {
+    /// Short name of the action kind, used in logs and errors.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EventAction::SetState { .. } => "set_state",
+            EventAction::Checkpoint(_) => "checkpoint",
+            EventAction::QualityGate(_) => "quality_gate",
+            EventAction::Transfer { .. } => "transfer",
+        }
+    }
+}
+
 impl EventActions {
     /// Create empty actions (no side effects).
     pub fn none() -> Self {
@@ -115,6 +149,13 @@ impl EventActions {
             || self.transfer_to_agent.is_some()
             || self.checkpoint_request.is_some()
             || self.quality_gate_result.is_some()
+            || !self.batch.is_empty()
+    }
+
+    /// Builder: append an action to the ordered batch.
+    pub fn then(mut self, action: EventAction) -> Self {
+        self.batch.push(action);
+        self
     }
 
     /// Builder: add a state delta entry.
@@ -170,6 +211,7 @@ impl EventActions {
     ///
     /// State delta entries are merged (later values override earlier).
     /// For optional fields, the `other` value takes precedence if set.
+    /// Batches are concatenated, `self`'s actions first.
     pub fn merge(mut self, other: EventActions) -> Self {
         for (k, v) in other.state_delta {
             self.state_delta.insert(k, v);
@@ -183,6 +225,7 @@ impl EventActions {
         if other.quality_gate_result.is_some() {
             self.quality_gate_result = other.quality_gate_result;
         }
+        self.batch.extend(other.batch);
         self
     }
 }
@@ -438,6 +481,71 @@ mod tests {
         assert!(restored.quality_gate_result.as_ref().unwrap().passed);
     }
 
+    // ── Batch tests ──────────────────────────────────────────────────
+
+    #[test]
+    fn test_event_actions_batch_preserves_declared_order() {
+        let actions = EventActions::none()
+            .then(EventAction::SetState {
+                key: "app:step".to_string(),
+                value: serde_json::json!(1),
+            })
+            .then(EventAction::Checkpoint(CheckpointRequest {
+                label: "after-step".to_string(),
+                description: None,
+            }))
+            .then(EventAction::Transfer {
+                agent: "reviewer".to_string(),
+            });
+        assert!(actions.has_actions());
+        let kinds: Vec<&str> = actions.batch.iter().map(|a| a.kind()).collect();
+        assert_eq!(kinds, vec!["set_state", "checkpoint", "transfer"]);
+    }
+
+    #[test]
+    fn test_event_actions_merge_concatenates_batches() {
+        let a = EventActions::none().then(EventAction::Transfer {
+            agent: "a".to_string(),
+        });
+        let b = EventActions::none().then(EventAction::Transfer {
+            agent: "b".to_string(),
+        });
+        let merged = a.merge(b);
+        assert_eq!(
+            merged.batch,
+            vec![
+                EventAction::Transfer {
+                    agent: "a".to_string()
+                },
+                EventAction::Transfer {
+                    agent: "b".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_event_actions_batch_roundtrip() {
+        let actions = EventActions::none()
+            .then(EventAction::QualityGate(QualityGateActionResult {
+                gate_name: "lint".to_string(),
+                passed: true,
+                details: None,
+            }))
+            .then(EventAction::SetState {
+                key: "temp:x".to_string(),
+                value: serde_json::json!(true),
+            });
+        let json = serde_json::to_string(&actions).unwrap();
+        assert!(json.contains("\"type\":\"quality_gate\""));
+        let parsed: EventActions = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.batch, actions.batch);
+
+        // Payloads without a batch still deserialize
+        let legacy: EventActions = serde_json::from_str(r#"{"transfer_to_agent":"x"}"#).unwrap();
+        assert!(legacy.batch.is_empty());
+    }
+
     // ── CheckpointRequest tests ──────────────────────────────────────
 
     #[test]
@@ -41,7 +41,7 @@ pub use adapter::{import_legacy_tools, ToolAdapter, UnifiedToolAdapter};
 
 // Event + Actions
 pub use event_actions::{
-    AgentEventWithActions, CheckpointRequest, EventActions, QualityGateActionResult,
+    AgentEventWithActions, CheckpointRequest, EventAction, EventActions, QualityGateActionResult,
 };
 
 // Pluggable Compaction
@@ -0,0 +1,914 @@
+//! Document Export
+//!
+//! Renders markdown output to DOCX for `save_output_export`. The document
+//! is assembled as WordprocessingML inside a zip, so text in any script is
+//! stored as-is and rendered with the reader's fonts.
+//!
+//! PDF is not offered: producing one that renders CJK and other non-Latin
+//! text needs an embedded Unicode font.
+//!
+//! Styling is deliberately basic: headings, paragraphs, lists, quotes,
+//! fenced code blocks and rules, plus a running header and a page-numbered
+//! footer. Images on a line of their own (`![alt](src)`) are embedded when
+//! `src` is a local path (resolved against `base_dir`) or a `data:` URI;
+//! remote or unreadable images are rendered as a text placeholder.
+
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
+use image::{DynamicImage, ImageFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::error::{AppError, AppResult};
+
+/// Document formats `save_output_export` can render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentFormat {
+    Docx,
+}
+
+impl DocumentFormat {
+    /// Parse a format name. Returns `None` for plain text formats, which are
+    /// written without rendering.
+    pub fn from_name(name: &str) -> AppResult<Option<Self>> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "" | "text" | "txt" | "markdown" | "md" => Ok(None),
+            "docx" => Ok(Some(Self::Docx)),
+            "pdf" => Err(AppError::validation(
+                "PDF export is not supported; export as DOCX instead",
+            )),
+            other => Err(AppError::validation(format!(
+                "Unsupported export format: {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Docx => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Docx => "docx",
+        }
+    }
+}
+
+/// Options shared by the document renderers.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentExportOptions {
+    /// Title shown above the content and stored in document metadata
+    pub title: Option<String>,
+    /// Running header text; defaults to the title
+    pub header: Option<String>,
+    /// Footer text shown before the page number
+    pub footer: Option<String>,
+    /// Directory relative image paths are resolved against
+    pub base_dir: Option<PathBuf>,
+}
+
+impl DocumentExportOptions {
+    fn header_text(&self) -> Option<&str> {
+        self.header
+            .as_deref()
+            .or(self.title.as_deref())
+            .filter(|h| !h.trim().is_empty())
+    }
+}
+
+/// Render `markdown` to the bytes of a document in `format`.
+pub fn render_document(
+    markdown: &str,
+    format: DocumentFormat,
+    options: &DocumentExportOptions,
+) -> AppResult<Vec<u8>> {
+    let mut blocks = parse_blocks(markdown);
+    if let Some(title) = options.title.as_deref().filter(|t| !t.trim().is_empty()) {
+        blocks.insert(0, Block::Title(title.trim().to_string()));
+    }
+    match format {
+        DocumentFormat::Docx => render_docx(&blocks, options),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Markdown parsing
+// ---------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Title(String),
+    Heading(u8, Vec<Span>),
+    Paragraph(Vec<Span>),
+    ListItem {
+        depth: usize,
+        marker: String,
+        spans: Vec<Span>,
+    },
+    Quote(Vec<Span>),
+    Code(Vec<String>),
+    Image {
+        alt: String,
+        src: String,
+    },
+    Rule,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Span {
+    text: String,
+    bold: bool,
+    italic: bool,
+    code: bool,
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut lines = markdown.lines();
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if let Some(fence) = ["```", "~~~"].into_iter().find(|f| trimmed.starts_with(f)) {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            let code = lines
+                .by_ref()
+                .take_while(|l| !l.trim_start().starts_with(fence))
+                .map(|l| l.replace('\t', "    "))
+                .collect();
+            blocks.push(Block::Code(code));
+            continue;
+        }
+        if trimmed.is_empty() {
+            flush_paragraph(&mut paragraph, &mut blocks);
+            continue;
+        }
+
+        let block = if let Some((level, text)) = parse_heading(trimmed) {
+            Block::Heading(level, parse_inline(text))
+        } else if is_rule(trimmed) {
+            Block::Rule
+        } else if let Some((alt, src)) = parse_standalone_image(trimmed) {
+            Block::Image { alt, src }
+        } else if let Some((marker, text)) = parse_list_marker(trimmed) {
+            let indent = line.len() - line.trim_start().len();
+            Block::ListItem {
+                depth: indent / 2,
+                marker,
+                spans: parse_inline(text),
+            }
+        } else if let Some(text) = trimmed.strip_prefix('>') {
+            Block::Quote(parse_inline(text.trim()))
+        } else {
+            paragraph.push(trimmed);
+            continue;
+        };
+        flush_paragraph(&mut paragraph, &mut blocks);
+        blocks.push(block);
+    }
+    flush_paragraph(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn flush_paragraph(paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>) {
+    if !paragraph.is_empty() {
+        blocks.push(Block::Paragraph(parse_inline(&paragraph.join(" "))));
+        paragraph.clear();
+    }
+}
+
+fn parse_heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((level as u8, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && matches!(compact[0], '-' | '*' | '_')
+        && compact.iter().all(|c| *c == compact[0])
+}
+
+fn parse_list_marker(line: &str) -> Option<(String, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(bullet) {
+            return Some(("\u{2022}".to_string(), rest.trim()));
+        }
+    }
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 || digits > 9 {
+        return None;
+    }
+    let rest = &line[digits..];
+    let text = rest
+        .strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))?;
+    Some((format!("{}.", &line[..digits]), text.trim()))
+}
+
+fn parse_standalone_image(line: &str) -> Option<(String, String)> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.first() != Some(&'!') {
+        return None;
+    }
+    let (alt, target, consumed) = parse_link(&chars, 1)?;
+    (consumed + 1 == chars.len()).then_some((alt, target))
+}
+
+/// Parse `[label](target)` starting at `chars[start]`. Returns the label,
+/// the target without any title, and the number of chars consumed.
+fn parse_link(chars: &[char], start: usize) -> Option<(String, String, usize)> {
+    if chars.get(start) != Some(&'[') {
+        return None;
+    }
+    let close = start + chars[start..].iter().position(|c| *c == ']')?;
+    if chars.get(close + 1) != Some(&'(') {
+        return None;
+    }
+    let end = close + 1 + chars[close + 1..].iter().position(|c| *c == ')')?;
+    let label: String = chars[start + 1..close].iter().collect();
+    let target: String = chars[close + 2..end].iter().collect();
+    let target = target
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .to_string();
+    Some((label, target, end + 1 - start))
+}
+
+/// Split inline markdown into styled spans. Links keep only their label and
+/// inline images become an `[image: alt]` placeholder.
+fn parse_inline(text: &str) -> Vec<Span> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let (mut bold, mut italic) = (false, false);
+    let mut i = 0;
+
+    let flush = |spans: &mut Vec<Span>, current: &mut String, bold, italic| {
+        if !current.is_empty() {
+            spans.push(Span {
+                text: std::mem::take(current),
+                bold,
+                italic,
+                code: false,
+            });
+        }
+    };
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if i + 1 < chars.len() => {
+                current.push(chars[i + 1]);
+                i += 2;
+                continue;
+            }
+            '`' => {
+                if let Some(len) = chars[i + 1..].iter().position(|c| *c == '`') {
+                    flush(&mut spans, &mut current, bold, italic);
+                    spans.push(Span {
+                        text: chars[i + 1..i + 1 + len].iter().collect(),
+                        bold,
+                        italic,
+                        code: true,
+                    });
+                    i += len + 2;
+                    continue;
+                }
+            }
+            '!' if chars.get(i + 1) == Some(&'[') => {
+                if let Some((alt, _, consumed)) = parse_link(&chars, i + 1) {
+                    current.push_str(&format!("[image: {}]", alt));
+                    i += consumed + 1;
+                    continue;
+                }
+            }
+            '[' => {
+                if let Some((label, _, consumed)) = parse_link(&chars, i) {
+                    current.push_str(&label);
+                    i += consumed;
+                    continue;
+                }
+            }
+            '*' | '_' => {
+                let double = chars.get(i + 1) == Some(&c);
+                let width = if double { 2 } else { 1 };
+                let prev = i.checked_sub(1).map(|p| chars[p]);
+                let next = chars.get(i + width).copied();
+                // `2 * 3` and `snake_case` are literal, not emphasis.
+                let spaced =
+                    prev.is_none_or(char::is_whitespace) && next.is_none_or(char::is_whitespace);
+                let intraword = c == '_'
+                    && prev.is_some_and(char::is_alphanumeric)
+                    && next.is_some_and(char::is_alphanumeric);
+                if !spaced && !intraword {
+                    flush(&mut spans, &mut current, bold, italic);
+                    if double {
+                        bold = !bold;
+                    } else {
+                        italic = !italic;
+                    }
+                    i += width;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        current.push(c);
+        i += 1;
+    }
+    flush(&mut spans, &mut current, bold, italic);
+    spans
+}
+
+// ---------------------------------------------------------------------------
+// Images
+// ---------------------------------------------------------------------------
+
+struct LoadedImage {
+    bytes: Vec<u8>,
+    format: ImageFormat,
+    decoded: DynamicImage,
+}
+
+/// Load an image from a local path or `data:` URI. Remote URLs are not
+/// fetched.
+fn load_image(src: &str, base_dir: Option<&Path>) -> Option<LoadedImage> {
+    let bytes = if let Some(data) = src.strip_prefix("data:") {
+        let (_, payload) = data.split_once(";base64,")?;
+        base64::engine::general_purpose::STANDARD
+            .decode(payload.trim())
+            .ok()?
+    } else if src.contains("://") && !src.starts_with("file://") {
+        return None;
+    } else {
+        let path = Path::new(src.trim_start_matches("file://"));
+        let path = match base_dir {
+            Some(base) if path.is_relative() => base.join(path),
+            _ => path.to_path_buf(),
+        };
+        std::fs::read(path).ok()?
+    };
+    let format = image::guess_format(&bytes).ok()?;
+    let decoded = image::load_from_memory_with_format(&bytes, format).ok()?;
+    Some(LoadedImage {
+        bytes,
+        format,
+        decoded,
+    })
+}
+
+fn image_placeholder(alt: &str) -> Vec<Span> {
+    vec![Span {
+        text: format!("[image: {}]", alt),
+        italic: true,
+        ..Default::default()
+    }]
+}
+
+// ---------------------------------------------------------------------------
+// DOCX
+// ---------------------------------------------------------------------------
+
+const DOCX_NAMESPACES: &str = concat!(
+    r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" "#,
+    r#"xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" "#,
+    r#"xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" "#,
+    r#"xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" "#,
+    r#"xmlns:pic="http://schemas.openxmlformats.org/drawingml/2006/picture""#
+);
+
+const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#;
+
+/// English Metric Units per 96 dpi pixel.
+const EMU_PER_PIXEL: u64 = 9525;
+
+/// Widest image allowed in the body, 6 inches in EMU.
+const DOCX_MAX_IMAGE_EMU: u64 = 5_486_400;
+
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            // Control characters are not allowed in XML 1.0.
+            c if (c as u32) < 0x20 && !matches!(c, '\t' | '\n' | '\r') => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn docx_text_run(text: &str, properties: &str) -> String {
+    let props = if properties.is_empty() {
+        String::new()
+    } else {
+        format!("<w:rPr>{}</w:rPr>", properties)
+    };
+    format!(
+        r#"<w:r>{}<w:t xml:space="preserve">{}</w:t></w:r>"#,
+        props,
+        xml_escape(text)
+    )
+}
+
+fn docx_runs(spans: &[Span]) -> String {
+    spans
+        .iter()
+        .map(|span| {
+            let mut props = String::new();
+            if span.code {
+                props.push_str(r#"<w:rStyle w:val="CodeChar"/>"#);
+            }
+            if span.bold {
+                props.push_str("<w:b/>");
+            }
+            if span.italic {
+                props.push_str("<w:i/>");
+            }
+            docx_text_run(&span.text, &props)
+        })
+        .collect()
+}
+
+fn docx_paragraph(style: Option<&str>, extra_props: &str, runs: &str) -> String {
+    let style = style
+        .map(|s| format!(r#"<w:pStyle w:val="{}"/>"#, s))
+        .unwrap_or_default();
+    let props = format!("{}{}", style, extra_props);
+    if props.is_empty() {
+        format!("<w:p>{}</w:p>", runs)
+    } else {
+        format!("<w:p><w:pPr>{}</w:pPr>{}</w:p>", props, runs)
+    }
+}
+
+struct DocxMedia {
+    name: String,
+    bytes: Vec<u8>,
+}
+
+fn docx_image(image: &LoadedImage, alt: &str, media: &mut Vec<DocxMedia>) -> Option<String> {
+    let (ext, bytes) = match image.format {
+        ImageFormat::Png => ("png", image.bytes.clone()),
+        ImageFormat::Jpeg => ("jpeg", image.bytes.clone()),
+        ImageFormat::Gif => ("gif", image.bytes.clone()),
+        // Word does not read WebP; convert anything else to PNG.
+        _ => {
+            let mut png = Cursor::new(Vec::new());
+            image.decoded.write_to(&mut png, ImageFormat::Png).ok()?;
+            ("png", png.into_inner())
+        }
+    };
+    let id = media.len() + 1;
+    media.push(DocxMedia {
+        name: format!("image{}.{}", id, ext),
+        bytes,
+    });
+
+    let mut cx = image.decoded.width() as u64 * EMU_PER_PIXEL;
+    let mut cy = image.decoded.height() as u64 * EMU_PER_PIXEL;
+    if cx > DOCX_MAX_IMAGE_EMU {
+        cy = cy * DOCX_MAX_IMAGE_EMU / cx;
+        cx = DOCX_MAX_IMAGE_EMU;
+    }
+    let alt = xml_escape(alt);
+    Some(format!(
+        concat!(
+            r#"<w:p><w:r><w:drawing><wp:inline distT="0" distB="0" distL="0" distR="0">"#,
+            r#"<wp:extent cx="{cx}" cy="{cy}"/><wp:docPr id="{id}" name="Picture {id}" descr="{alt}"/>"#,
+            r#"<a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/picture">"#,
+            r#"<pic:pic><pic:nvPicPr><pic:cNvPr id="{id}" name="{name}" descr="{alt}"/><pic:cNvPicPr/></pic:nvPicPr>"#,
+            r#"<pic:blipFill><a:blip r:embed="rIdImage{id}"/><a:stretch><a:fillRect/></a:stretch></pic:blipFill>"#,
+            r#"<pic:spPr><a:xfrm><a:off x="0" y="0"/><a:ext cx="{cx}" cy="{cy}"/></a:xfrm>"#,
+            r#"<a:prstGeom prst="rect"><a:avLst/></a:prstGeom></pic:spPr></pic:pic>"#,
+            r#"</a:graphicData></a:graphic></wp:inline></w:drawing></w:r></w:p>"#
+        ),
+        cx = cx,
+        cy = cy,
+        id = id,
+        alt = alt,
+        name = media[id - 1].name,
+    ))
+}
+
+fn docx_document(blocks: &[Block], options: &DocumentExportOptions) -> (String, Vec<DocxMedia>) {
+    let mut body = String::new();
+    let mut media = Vec::new();
+    for block in blocks {
+        let paragraph = match block {
+            Block::Title(title) => docx_paragraph(Some("Title"), "", &docx_text_run(title, "")),
+            Block::Heading(level, spans) => docx_paragraph(
+                Some(format!("Heading{}", level).as_str()),
+                "",
+                &docx_runs(spans),
+            ),
+            Block::Paragraph(spans) => docx_paragraph(None, "", &docx_runs(spans)),
+            Block::ListItem {
+                depth,
+                marker,
+                spans,
+            } => {
+                let indent = format!(r#"<w:ind w:left="{}" w:hanging="360"/>"#, 360 * (depth + 1));
+                let runs = format!(
+                    "{}{}",
+                    docx_text_run(&format!("{}\t", marker), ""),
+                    docx_runs(spans)
+                );
+                docx_paragraph(Some("ListParagraph"), &indent, &runs)
+            }
+            Block::Quote(spans) => docx_paragraph(Some("Quote"), "", &docx_runs(spans)),
+            Block::Code(lines) if lines.is_empty() => docx_paragraph(Some("Code"), "", ""),
+            Block::Code(lines) => lines
+                .iter()
+                .map(|line| docx_paragraph(Some("Code"), "", &docx_text_run(line, "")))
+                .collect(),
+            Block::Image { alt, src } => load_image(src, options.base_dir.as_deref())
+                .and_then(|image| docx_image(&image, alt, &mut media))
+                .unwrap_or_else(|| docx_paragraph(None, "", &docx_runs(&image_placeholder(alt)))),
+            Block::Rule => docx_paragraph(
+                None,
+                r#"<w:pBdr><w:bottom w:val="single" w:sz="6" w:space="1" w:color="999999"/></w:pBdr>"#,
+                "",
+            ),
+        };
+        body.push_str(&paragraph);
+    }
+
+    let document = format!(
+        concat!(
+            "{decl}<w:document {ns}><w:body>{body}",
+            r#"<w:sectPr><w:headerReference w:type="default" r:id="rIdHeader"/>"#,
+            r#"<w:footerReference w:type="default" r:id="rIdFooter"/>"#,
+            r#"<w:pgSz w:w="11906" w:h="16838"/>"#,
+            r#"<w:pgMar w:top="1440" w:right="1440" w:bottom="1440" w:left="1440" w:header="708" w:footer="708" w:gutter="0"/>"#,
+            "</w:sectPr></w:body></w:document>"
+        ),
+        decl = XML_DECLARATION,
+        ns = DOCX_NAMESPACES,
+        body = body,
+    );
+    (document, media)
+}
+
+fn docx_styles() -> String {
+    let heading = |level: u8, size: u32| {
+        format!(
+            concat!(
+                r#"<w:style w:type="paragraph" w:styleId="Heading{level}"><w:name w:val="heading {level}"/>"#,
+                r#"<w:basedOn w:val="Normal"/><w:next w:val="Normal"/><w:qFormat/>"#,
+                r#"<w:pPr><w:keepNext/><w:spacing w:before="240" w:after="80"/><w:outlineLvl w:val="{outline}"/></w:pPr>"#,
+                r#"<w:rPr><w:b/><w:color w:val="1F3864"/><w:sz w:val="{size}"/></w:rPr></w:style>"#
+            ),
+            level = level,
+            outline = level - 1,
+            size = size,
+        )
+    };
+    let headings: String = [(1, 36), (2, 30), (3, 26), (4, 24), (5, 22), (6, 22)]
+        .into_iter()
+        .map(|(level, size)| heading(level, size))
+        .collect();
+
+    format!(
+        concat!(
+            r#"{decl}<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">"#,
+            r#"<w:docDefaults><w:rPrDefault><w:rPr><w:rFonts w:ascii="Calibri" w:hAnsi="Calibri" w:cs="Calibri"/>"#,
+            r#"<w:sz w:val="22"/></w:rPr></w:rPrDefault><w:pPrDefault><w:pPr><w:spacing w:after="120"/></w:pPr></w:pPrDefault></w:docDefaults>"#,
+            r#"<w:style w:type="paragraph" w:default="1" w:styleId="Normal"><w:name w:val="Normal"/><w:qFormat/></w:style>"#,
+            r#"<w:style w:type="paragraph" w:styleId="Title"><w:name w:val="Title"/><w:basedOn w:val="Normal"/>"#,
+            r#"<w:next w:val="Normal"/><w:qFormat/><w:pPr><w:spacing w:after="240"/></w:pPr><w:rPr><w:b/><w:sz w:val="44"/></w:rPr></w:style>"#,
+            "{headings}",
+            r#"<w:style w:type="paragraph" w:styleId="Code"><w:name w:val="Code"/><w:basedOn w:val="Normal"/>"#,
+            r#"<w:pPr><w:shd w:val="clear" w:color="auto" w:fill="F2F2F2"/><w:spacing w:after="0" w:line="240" w:lineRule="auto"/></w:pPr>"#,
+            r#"<w:rPr><w:rFonts w:ascii="Consolas" w:hAnsi="Consolas" w:cs="Consolas"/><w:sz w:val="18"/></w:rPr></w:style>"#,
+            r#"<w:style w:type="character" w:styleId="CodeChar"><w:name w:val="Code Char"/>"#,
+            r#"<w:rPr><w:rFonts w:ascii="Consolas" w:hAnsi="Consolas" w:cs="Consolas"/><w:shd w:val="clear" w:color="auto" w:fill="F2F2F2"/></w:rPr></w:style>"#,
+            r#"<w:style w:type="paragraph" w:styleId="Quote"><w:name w:val="Quote"/><w:basedOn w:val="Normal"/><w:qFormat/>"#,
+            r#"<w:pPr><w:pBdr><w:left w:val="single" w:sz="12" w:space="8" w:color="999999"/></w:pBdr><w:ind w:left="360"/></w:pPr>"#,
+            r#"<w:rPr><w:i/><w:color w:val="595959"/></w:rPr></w:style>"#,
+            r#"<w:style w:type="paragraph" w:styleId="ListParagraph"><w:name w:val="List Paragraph"/><w:basedOn w:val="Normal"/>"#,
+            r#"<w:pPr><w:spacing w:after="40"/></w:pPr></w:style>"#,
+            r#"<w:style w:type="paragraph" w:styleId="Header"><w:name w:val="header"/><w:basedOn w:val="Normal"/>"#,
+            r#"<w:rPr><w:color w:val="666666"/><w:sz w:val="16"/></w:rPr></w:style>"#,
+            r#"<w:style w:type="paragraph" w:styleId="Footer"><w:name w:val="footer"/><w:basedOn w:val="Normal"/>"#,
+            r#"<w:rPr><w:color w:val="666666"/><w:sz w:val="16"/></w:rPr></w:style>"#,
+            "</w:styles>"
+        ),
+        decl = XML_DECLARATION,
+        headings = headings,
+    )
+}
+
+fn docx_header(options: &DocumentExportOptions) -> String {
+    let runs = options
+        .header_text()
+        .map(|h| docx_text_run(h, ""))
+        .unwrap_or_default();
+    format!(
+        "{}<w:hdr {}>{}</w:hdr>",
+        XML_DECLARATION,
+        DOCX_NAMESPACES,
+        docx_paragraph(Some("Header"), "", &runs)
+    )
+}
+
+fn docx_footer(options: &DocumentExportOptions) -> String {
+    let field = |instr: &str| {
+        format!(
+            r#"<w:fldSimple w:instr=" {} ">{}</w:fldSimple>"#,
+            instr,
+            docx_text_run("1", "")
+        )
+    };
+    let mut runs = String::new();
+    if let Some(footer) = options.footer.as_deref() {
+        runs.push_str(&docx_text_run(&format!("{}\t", footer), ""));
+    }
+    runs.push_str(&docx_text_run("Page ", ""));
+    runs.push_str(&field("PAGE"));
+    runs.push_str(&docx_text_run(" of ", ""));
+    runs.push_str(&field("NUMPAGES"));
+    format!(
+        "{}<w:ftr {}>{}</w:ftr>",
+        XML_DECLARATION,
+        DOCX_NAMESPACES,
+        docx_paragraph(Some("Footer"), r#"<w:jc w:val="right"/>"#, &runs)
+    )
+}
+
+fn render_docx(blocks: &[Block], options: &DocumentExportOptions) -> AppResult<Vec<u8>> {
+    let (document, media) = docx_document(blocks, options);
+
+    let content_types = format!(
+        concat!(
+            r#"{decl}<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+            r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+            r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+            r#"<Default Extension="png" ContentType="image/png"/>"#,
+            r#"<Default Extension="jpeg" ContentType="image/jpeg"/>"#,
+            r#"<Default Extension="gif" ContentType="image/gif"/>"#,
+            r#"<Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>"#,
+            r#"<Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/>"#,
+            r#"<Override PartName="/word/header1.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.header+xml"/>"#,
+            r#"<Override PartName="/word/footer1.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.footer+xml"/>"#,
+            r#"<Override PartName="/docProps/core.xml" ContentType="application/vnd.openxmlformats-package.core-properties+xml"/>"#,
+            "</Types>"
+        ),
+        decl = XML_DECLARATION
+    );
+    let package_rels = format!(
+        concat!(
+            r#"{decl}<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+            r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>"#,
+            r#"<Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties" Target="docProps/core.xml"/>"#,
+            "</Relationships>"
+        ),
+        decl = XML_DECLARATION
+    );
+    let core = format!(
+        concat!(
+            r#"{decl}<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" "#,
+            r#"xmlns:dc="http://purl.org/dc/elements/1.1/"><dc:title>{title}</dc:title><dc:creator>Plan Cascade</dc:creator>"#,
+            "</cp:coreProperties>"
+        ),
+        decl = XML_DECLARATION,
+        title = xml_escape(options.title.as_deref().unwrap_or_default()),
+    );
+
+    let rel = |id: &str, kind: &str, target: &str| {
+        format!(
+            r#"<Relationship Id="{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/{}" Target="{}"/>"#,
+            id, kind, target
+        )
+    };
+    let mut document_rels = format!(
+        r#"{}<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+        XML_DECLARATION
+    );
+    document_rels.push_str(&rel("rIdStyles", "styles", "styles.xml"));
+    document_rels.push_str(&rel("rIdHeader", "header", "header1.xml"));
+    document_rels.push_str(&rel("rIdFooter", "footer", "footer1.xml"));
+    for (index, item) in media.iter().enumerate() {
+        document_rels.push_str(&rel(
+            &format!("rIdImage{}", index + 1),
+            "image",
+            &format!("media/{}", item.name),
+        ));
+    }
+    document_rels.push_str("</Relationships>");
+
+    let mut entries: Vec<(String, Vec<u8>)> = vec![
+        (
+            "[Content_Types].xml".to_string(),
+            content_types.into_bytes(),
+        ),
+        ("_rels/.rels".to_string(), package_rels.into_bytes()),
+        ("docProps/core.xml".to_string(), core.into_bytes()),
+        ("word/document.xml".to_string(), document.into_bytes()),
+        (
+            "word/_rels/document.xml.rels".to_string(),
+            document_rels.into_bytes(),
+        ),
+        ("word/styles.xml".to_string(), docx_styles().into_bytes()),
+        (
+            "word/header1.xml".to_string(),
+            docx_header(options).into_bytes(),
+        ),
+        (
+            "word/footer1.xml".to_string(),
+            docx_footer(options).into_bytes(),
+        ),
+    ];
+    entries.extend(
+        media
+            .into_iter()
+            .map(|item| (format!("word/media/{}", item.name), item.bytes)),
+    );
+
+    let zip_error =
+        |e: zip::result::ZipError| AppError::internal(format!("Failed to write DOCX: {}", e));
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    for (name, bytes) in &entries {
+        zip.start_file(name.as_str(), options).map_err(zip_error)?;
+        zip.write_all(bytes)?;
+    }
+    Ok(zip.finish().map_err(zip_error)?.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use tempfile::TempDir;
+
+    const SAMPLE: &str = "# Overview\n\nThe **fix** touches `main`.\n\n```rust\nfn main() {}\n```\n\n- first\n- second\n";
+
+    fn options() -> DocumentExportOptions {
+        DocumentExportOptions {
+            title: Some("Session Report".to_string()),
+            footer: Some("Plan Cascade".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn docx_part(bytes: &[u8], name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let mut content = String::new();
+        archive
+            .by_name(name)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    fn assert_well_formed(xml: &str) {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        loop {
+            match reader.read_event() {
+                Ok(quick_xml::events::Event::Eof) => break,
+                Ok(_) => {}
+                Err(e) => panic!("malformed XML: {}\n{}", e, xml),
+            }
+        }
+    }
+
+    fn write_png(dir: &Path) {
+        image::RgbImage::from_pixel(4, 2, image::Rgb([200, 30, 30]))
+            .save(dir.join("chart.png"))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_parse_blocks_recognizes_structure() {
+        let blocks = parse_blocks(SAMPLE);
+        assert!(matches!(&blocks[0], Block::Heading(1, spans) if spans[0].text == "Overview"));
+        let Block::Paragraph(spans) = &blocks[1] else {
+            panic!("expected paragraph, got {:?}", blocks[1]);
+        };
+        assert!(spans.iter().any(|s| s.bold && s.text == "fix"));
+        assert!(spans.iter().any(|s| s.code && s.text == "main"));
+        assert_eq!(blocks[2], Block::Code(vec!["fn main() {}".to_string()]));
+        assert!(matches!(&blocks[3], Block::ListItem { marker, .. } if marker == "\u{2022}"));
+        assert_eq!(blocks.len(), 5);
+
+        // Literal asterisks and underscores stay text.
+        let spans = parse_inline("2 * 3 = snake_case_name");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "2 * 3 = snake_case_name");
+    }
+
+    #[test]
+    fn test_docx_has_heading_code_block_and_header_footer() {
+        let docx = render_document(SAMPLE, DocumentFormat::Docx, &options()).unwrap();
+        let archive = zip::ZipArchive::new(Cursor::new(docx.as_slice())).unwrap();
+        let names: Vec<&str> = archive.file_names().collect();
+        for part in [
+            "[Content_Types].xml",
+            "_rels/.rels",
+            "word/document.xml",
+            "word/_rels/document.xml.rels",
+            "word/styles.xml",
+            "word/header1.xml",
+            "word/footer1.xml",
+        ] {
+            assert!(names.contains(&part), "missing {}", part);
+        }
+
+        let document = docx_part(&docx, "word/document.xml");
+        assert_well_formed(&document);
+        assert!(document.contains(
+            r#"<w:pStyle w:val="Heading1"/></w:pPr><w:r><w:t xml:space="preserve">Overview</w:t>"#
+        ));
+        assert!(document.contains(
+            r#"<w:pStyle w:val="Code"/></w:pPr><w:r><w:t xml:space="preserve">fn main() {}</w:t>"#
+        ));
+        assert!(document.contains(r#"<w:rStyle w:val="CodeChar"/>"#));
+        assert!(document.contains(r#"<w:headerReference w:type="default" r:id="rIdHeader"/>"#));
+
+        let styles = docx_part(&docx, "word/styles.xml");
+        assert_well_formed(&styles);
+        assert!(styles.contains(r#"w:styleId="Heading1""#));
+        assert!(styles.contains(r#"w:styleId="Code""#));
+
+        let header = docx_part(&docx, "word/header1.xml");
+        assert_well_formed(&header);
+        assert!(header.contains("Session Report"));
+        let footer = docx_part(&docx, "word/footer1.xml");
+        assert_well_formed(&footer);
+        assert!(footer.contains(r#"w:instr=" PAGE ""#));
+        assert_well_formed(&docx_part(&docx, "word/_rels/document.xml.rels"));
+        assert_well_formed(&docx_part(&docx, "[Content_Types].xml"));
+    }
+
+    #[test]
+    fn test_docx_keeps_non_latin_text() {
+        let markdown = "# 概要\n\nПривет, **世界** — café\n";
+        let docx = render_document(markdown, DocumentFormat::Docx, &options()).unwrap();
+        let document = docx_part(&docx, "word/document.xml");
+        assert_well_formed(&document);
+        for text in ["概要", "Привет, ", "世界", " — café"] {
+            assert!(document.contains(text), "missing {}", text);
+        }
+    }
+
+    #[test]
+    fn test_referenced_images_are_embedded() {
+        let dir = TempDir::new().unwrap();
+        write_png(dir.path());
+        let markdown =
+            "## Chart\n\n![Token usage](chart.png)\n\n![Remote](https://example.com/x.png)\n";
+        let options = DocumentExportOptions {
+            base_dir: Some(dir.path().to_path_buf()),
+            ..Default::default()
+        };
+
+        let docx = render_document(markdown, DocumentFormat::Docx, &options).unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(docx.as_slice())).unwrap();
+        let mut media = Vec::new();
+        archive
+            .by_name("word/media/image1.png")
+            .unwrap()
+            .read_to_end(&mut media)
+            .unwrap();
+        assert_eq!(media, std::fs::read(dir.path().join("chart.png")).unwrap());
+        let rels = docx_part(&docx, "word/_rels/document.xml.rels");
+        assert!(rels.contains(r#"Target="media/image1.png""#));
+        let document = docx_part(&docx, "word/document.xml");
+        assert_well_formed(&document);
+        assert!(document.contains(r#"<a:blip r:embed="rIdImage1"/>"#));
+        assert!(document.contains(r#"descr="Token usage""#));
+        assert!(document.contains("[image: Remote]"));
+    }
+
+    #[test]
+    fn test_format_names() {
+        assert!(DocumentFormat::from_name("PDF").is_err());
+        assert_eq!(
+            DocumentFormat::from_name("docx").unwrap(),
+            Some(DocumentFormat::Docx)
+        );
+        assert_eq!(DocumentFormat::from_name("markdown").unwrap(), None);
+        assert!(DocumentFormat::from_name("rtf").is_err());
+    }
+}
@@ -98,8 +98,28 @@ pub struct RestoredFile {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RestorePreviewItem {
     pub path: String,
-    pub action: String, // "restore" or "delete"
+    pub action: String, // "restore", "create" or "delete"
     pub source_turn: u32,
+    /// Hash of the file currently on disk (None if missing).
+    pub current_hash: Option<String>,
+    /// Hash the file will be restored to (None means it will be deleted).
+    pub target_hash: Option<String>,
+    /// Unified diff from the on-disk content to the restored content.
+    pub diff: String,
+    /// The on-disk content no longer matches the last tracked change.
+    pub externally_modified: bool,
+}
+
+/// Full preview of a restore, with per-file diffs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestorePreview {
+    pub files: Vec<RestorePreviewItem>,
+    /// Files that will be recreated because they are missing on disk.
+    pub created: Vec<String>,
+    /// Files that will be deleted because they did not exist before the turn.
+    pub deleted: Vec<String>,
+    /// Files modified outside the tracker since the last recorded change.
+    pub externally_modified: Vec<String>,
 }
 
 /// Result payload for v2 restore command with optional undo handle.
@@ -107,6 +127,10 @@ pub struct RestorePreviewItem {
 pub struct RestoreExecutionResult {
     pub operation_id: Option<String>,
     pub restored: Vec<RestoredFile>,
+    /// Files whose on-disk content diverged from the tracker before restoring
+    /// (only populated when verification is requested).
+    #[serde(default)]
+    pub externally_modified: Vec<String>,
 }
 
 /// A content-addressed snapshot of workspace files at a point in time.
@@ -152,6 +176,8 @@ struct RestorePreviewTarget {
     path: String,
     target_hash: Option<String>,
     source_turn: u32,
+    /// Content hash recorded by the most recent change to this file.
+    expected_hash: Option<String>,
 }
 
 // ── FileChangeTracker ───────────────────────────────────────────────────
@@ -630,6 +656,42 @@ impl FileChangeTracker {
         self.changes.len()
     }
 
+    /// All recorded changes, oldest first.
+    pub fn changes(&self) -> &[FileChange] {
+        &self.changes
+    }
+
+    /// The session this tracker records changes for.
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    /// The workspace root that relative change paths resolve against.
+    pub fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    /// Merge change records from another machine (e.g. a session bundle).
+    ///
+    /// Records are re-assigned to this tracker's session; ids already present
+    /// are skipped. The referenced CAS blobs must be stored separately via
+    /// [`store_content`](Self::store_content). Returns the number of records added.
+    pub fn import_changes(&mut self, changes: Vec<FileChange>) -> Result<usize, String> {
+        let mut added = 0;
+        for mut change in changes {
+            if self.changes.iter().any(|c| c.id == change.id) {
+                continue;
+            }
+            change.session_id = self.session_id.clone();
+            self.current_turn_index = self.current_turn_index.max(change.turn_index);
+            self.changes.push(change);
+            added += 1;
+        }
+        self.changes.sort_by_key(|c| (c.turn_index, c.timestamp));
+        self.persist()?;
+        Ok(added)
+    }
+
     // ── Diff ────────────────────────────────────────────────────────────
 
     /// Compute a unified diff between two CAS blobs.
@@ -660,15 +722,18 @@ impl FileChangeTracker {
     // ── Restore ─────────────────────────────────────────────────────────
 
     fn compute_restore_targets(&self, turn_index: u32) -> Vec<RestorePreviewTarget> {
-        // Keep the earliest change at/after target turn for each file.
+        // Keep the earliest change at/after target turn for each file, and
+        // the latest recorded content for external modification checks.
         let mut target_map: HashMap<String, RestorePreviewTarget> = HashMap::new();
         for change in self.changes.iter().filter(|c| c.turn_index >= turn_index) {
             target_map
                 .entry(change.file_path.clone())
+                .and_modify(|t| t.expected_hash = change.after_hash.clone())
                 .or_insert_with(|| RestorePreviewTarget {
                     path: change.file_path.clone(),
                     target_hash: change.before_hash.clone(),
                     source_turn: change.turn_index,
+                    expected_hash: change.after_hash.clone(),
                 });
         }
         let mut targets: Vec<RestorePreviewTarget> = target_map.into_values().collect();
@@ -676,20 +741,84 @@ impl FileChangeTracker {
         targets
     }
 
-    /// Preview all files that would be affected by restoring to before a turn.
-    pub fn preview_restore_to_before_turn(&self, turn_index: u32) -> Vec<RestorePreviewItem> {
-        self.compute_restore_targets(turn_index)
-            .into_iter()
-            .map(|t| RestorePreviewItem {
-                path: t.path,
-                action: if t.target_hash.is_some() {
-                    "restore".to_string()
-                } else {
-                    "delete".to_string()
-                },
-                source_turn: t.source_turn,
-            })
-            .collect()
+    /// Read a tracked file from disk, returning its content and hash.
+    fn read_on_disk(&self, path: &str) -> Result<Option<(Vec<u8>, String)>, String> {
+        let full_path = self.project_root.join(path);
+        if !full_path.exists() {
+            return Ok(None);
+        }
+        let bytes = fs::read(&full_path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        let hash = sha256_hex(&bytes);
+        Ok(Some((bytes, hash)))
+    }
+
+    /// Paths whose on-disk content no longer matches the last tracked change.
+    fn externally_modified_paths(
+        &self,
+        targets: &[RestorePreviewTarget],
+    ) -> Result<Vec<String>, String> {
+        let mut modified = Vec::new();
+        for target in targets {
+            let current_hash = self.read_on_disk(&target.path)?.map(|(_, h)| h);
+            if current_hash != target.expected_hash {
+                modified.push(target.path.clone());
+            }
+        }
+        Ok(modified)
+    }
+
+    /// Preview all files that would be affected by restoring to before a turn,
+    /// with a diff from the current on-disk content to the restored content.
+    pub fn preview_restore_to_before_turn(
+        &self,
+        turn_index: u32,
+    ) -> Result<RestorePreview, String> {
+        let mut preview = RestorePreview {
+            files: Vec::new(),
+            created: Vec::new(),
+            deleted: Vec::new(),
+            externally_modified: Vec::new(),
+        };
+
+        for target in self.compute_restore_targets(turn_index) {
+            let current = self.read_on_disk(&target.path)?;
+            let current_hash = current.as_ref().map(|(_, h)| h.clone());
+            let current_content = current
+                .map(|(bytes, _)| String::from_utf8_lossy(&bytes).to_string())
+                .unwrap_or_default();
+            let target_content = match target.target_hash.as_deref() {
+                Some(hash) => String::from_utf8_lossy(&self.get_content(hash)?).to_string(),
+                None => String::new(),
+            };
+
+            let action = match (&target.target_hash, &current_hash) {
+                (None, _) => {
+                    preview.deleted.push(target.path.clone());
+                    "delete"
+                }
+                (Some(_), None) => {
+                    preview.created.push(target.path.clone());
+                    "create"
+                }
+                (Some(_), Some(_)) => "restore",
+            };
+            let externally_modified = current_hash != target.expected_hash;
+            if externally_modified {
+                preview.externally_modified.push(target.path.clone());
+            }
+
+            preview.files.push(RestorePreviewItem {
+                path: target.path,
+                action: action.to_string(),
+                source_turn: target.source_turn,
+                current_hash,
+                target_hash: target.target_hash,
+                diff: unified_diff(&current_content, &target_content),
+                externally_modified,
+            });
+        }
+
+        Ok(preview)
     }
 
     fn restore_target_to_disk(
@@ -791,20 +920,31 @@ impl FileChangeTracker {
     /// - If the file didn't exist before, delete it
     /// - Otherwise, restore from CAS
     ///
-    /// Optionally creates an undo snapshot and returns an operation ID.
+    /// Optionally creates an undo snapshot and returns an operation ID. With
+    /// `verify_on_disk`, files edited outside the tracker since their last
+    /// recorded change are reported in `externally_modified`; the restore
+    /// still proceeds so the snapshot can undo it.
     pub fn restore_to_before_turn_v2(
         &mut self,
         turn_index: u32,
         create_snapshot: bool,
+        verify_on_disk: bool,
     ) -> Result<RestoreExecutionResult, String> {
         let targets = self.compute_restore_targets(turn_index);
         if targets.is_empty() {
             return Ok(RestoreExecutionResult {
                 operation_id: None,
                 restored: Vec::new(),
+                externally_modified: Vec::new(),
             });
         }
 
+        let externally_modified = if verify_on_disk {
+            self.externally_modified_paths(&targets)?
+        } else {
+            Vec::new()
+        };
+
         let operation = if create_snapshot {
             let snapshots = self.capture_restore_snapshot(&targets)?;
             let op = RestoreOperation {
@@ -843,6 +983,65 @@ impl FileChangeTracker {
         Ok(RestoreExecutionResult {
             operation_id,
             restored,
+            externally_modified,
+        })
+    }
+
+    /// Restore a selected subset of files to their state before `turn_index`.
+    ///
+    /// The restore is atomic: the selected files are snapshotted first and
+    /// put back if any write fails. Change records are left untouched, since
+    /// the unselected files still reflect the later turns.
+    pub fn restore_files_to_before_turn(
+        &mut self,
+        turn_index: u32,
+        paths: &[String],
+        create_snapshot: bool,
+    ) -> Result<RestoreExecutionResult, String> {
+        let mut targets = self.compute_restore_targets(turn_index);
+        if let Some(unknown) = paths
+            .iter()
+            .find(|p| !targets.iter().any(|t| &t.path == *p))
+        {
+            return Err(format!(
+                "No tracked change for {} at or after turn {}",
+                unknown, turn_index
+            ));
+        }
+        targets.retain(|t| paths.contains(&t.path));
+
+        let snapshots = self.capture_restore_snapshot(&targets)?;
+        let mut restored = Vec::with_capacity(targets.len());
+        for target in &targets {
+            match self.restore_target_to_disk(target) {
+                Ok(file) => restored.push(file),
+                Err(e) => {
+                    if let Err(rollback) = self.apply_snapshot_entries(&snapshots) {
+                        return Err(format!("{e} (rollback failed: {rollback})"));
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        let operation_id = if create_snapshot && !targets.is_empty() {
+            let op = RestoreOperation {
+                operation_id: uuid::Uuid::new_v4().to_string(),
+                session_id: self.session_id.clone(),
+                turn_index,
+                timestamp: chrono::Utc::now().timestamp_millis(),
+                files: snapshots,
+            };
+            self.persist_restore_operation(&op)?;
+            Some(op.operation_id)
+        } else {
+            None
+        };
+
+        Ok(RestoreExecutionResult {
+            operation_id,
+            restored,
+            externally_modified: Vec::new(),
         })
     }
 
@@ -938,6 +1137,37 @@ impl FileChangeTracker {
         self.changes.retain(|c| c.turn_index < turn_index);
         let _ = self.persist();
     }
+
+    /// Create a tracker for a forked session holding the changes recorded in
+    /// the first `at_turn` turns. `at_turn` is 1-based and inclusive, as in
+    /// `SessionService::fork_session`, while turn indices are 0-based. CAS
+    /// blobs live in the shared data dir, so the fork can diff and restore
+    /// without copying content.
+    pub fn fork(&self, new_session_id: &str, at_turn: u32) -> Result<FileChangeTracker, String> {
+        let changes: Vec<FileChange> = self
+            .changes
+            .iter()
+            .filter(|c| c.turn_index < at_turn)
+            .cloned()
+            .map(|mut c| {
+                c.session_id = new_session_id.to_string();
+                c
+            })
+            .collect();
+        let current_turn_index = changes.iter().map(|c| c.turn_index).max().unwrap_or(0);
+
+        let forked = Self {
+            session_id: new_session_id.to_string(),
+            project_root: self.project_root.clone(),
+            data_dir: self.data_dir.clone(),
+            cas_dir: self.cas_dir.clone(),
+            changes,
+            current_turn_index,
+            app_handle: self.app_handle.clone(),
+        };
+        forked.persist()?;
+        Ok(forked)
+    }
 }
 
 fn should_skip_workspace_path(
@@ -1148,7 +1378,7 @@ mod tests {
 
         // Restore to before turn 1 — should restore existing.txt, keep new.txt
         let restored = tracker
-            .restore_to_before_turn_v2(1, false)
+            .restore_to_before_turn_v2(1, false, false)
             .unwrap()
             .restored;
         assert_eq!(restored.len(), 1);
@@ -1159,7 +1389,7 @@ mod tests {
 
         // Restore to before turn 0 — should delete new.txt
         let restored = tracker
-            .restore_to_before_turn_v2(0, false)
+            .restore_to_before_turn_v2(0, false, false)
             .unwrap()
             .restored;
         assert_eq!(restored.len(), 2); // new.txt + existing.txt
@@ -1203,7 +1433,7 @@ mod tests {
             "Edited existing file",
         );
 
-        let preview_turn_0 = tracker.preview_restore_to_before_turn(0);
+        let preview_turn_0 = tracker.preview_restore_to_before_turn(0).unwrap().files;
         assert_eq!(preview_turn_0.len(), 2);
         let new_preview = preview_turn_0.iter().find(|p| p.path == "new.txt").unwrap();
         assert_eq!(new_preview.action, "delete");
@@ -1214,12 +1444,191 @@ mod tests {
         assert_eq!(existing_preview.action, "restore");
         assert_eq!(existing_preview.source_turn, 1);
 
-        let preview_turn_1 = tracker.preview_restore_to_before_turn(1);
+        let preview_turn_1 = tracker.preview_restore_to_before_turn(1).unwrap().files;
         assert_eq!(preview_turn_1.len(), 1);
         assert_eq!(preview_turn_1[0].path, "existing.txt");
         assert_eq!(preview_turn_1[0].action, "restore");
     }
 
+    #[test]
+    fn test_preview_diff_matches_subsequent_restore() {
+        let dir = TempDir::new().unwrap();
+        let mut tracker = make_tracker(dir.path());
+
+        // Turn 1: edit an existing file, create a new one, and delete a third
+        let existing = dir.path().join("existing.txt");
+        let existing_before = tracker.store_content(b"line one\nline two\n").unwrap();
+        let existing_after = tracker.store_content(b"line one\nline 2\n").unwrap();
+        fs::write(&existing, "line one\nline 2\n").unwrap();
+        let created_after = tracker.store_content(b"brand new\n").unwrap();
+        fs::write(dir.path().join("new.txt"), "brand new\n").unwrap();
+        let removed_before = tracker.store_content(b"keep me\n").unwrap();
+        tracker.set_turn_index(1);
+        tracker.record_change(
+            "tc-edit",
+            "Edit",
+            "existing.txt",
+            Some(&existing_before),
+            Some(&existing_after),
+            "Edited",
+        );
+        tracker.record_change(
+            "tc-new",
+            "Write",
+            "new.txt",
+            None,
+            Some(&created_after),
+            "Wrote",
+        );
+        tracker.record_change(
+            "tc-rm",
+            "Bash",
+            "gone.txt",
+            Some(&removed_before),
+            None,
+            "Removed",
+        );
+
+        let preview = tracker.preview_restore_to_before_turn(1).unwrap();
+        assert_eq!(preview.deleted, vec!["new.txt".to_string()]);
+        assert_eq!(preview.created, vec!["gone.txt".to_string()]);
+        assert!(preview.externally_modified.is_empty());
+        let edit = preview
+            .files
+            .iter()
+            .find(|f| f.path == "existing.txt")
+            .unwrap();
+        assert_eq!(edit.action, "restore");
+        assert!(edit.diff.contains("-line 2"));
+        assert!(edit.diff.contains("+line two"));
+
+        let result = tracker.restore_to_before_turn_v2(1, false, true).unwrap();
+        assert!(result.externally_modified.is_empty());
+        let mut restored: Vec<&str> = result.restored.iter().map(|r| r.path.as_str()).collect();
+        restored.sort();
+        let previewed: Vec<&str> = preview.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(restored, previewed);
+
+        // Every file now matches the hash the preview promised
+        for item in &preview.files {
+            let on_disk = tracker.read_on_disk(&item.path).unwrap().map(|(_, h)| h);
+            assert_eq!(on_disk, item.target_hash, "{}", item.path);
+        }
+    }
+
+    #[test]
+    fn test_restore_flags_external_modification() {
+        let dir = TempDir::new().unwrap();
+        let mut tracker = make_tracker(dir.path());
+
+        let file = dir.path().join("shared.txt");
+        let before = tracker.store_content(b"v1").unwrap();
+        let after = tracker.store_content(b"v2").unwrap();
+        fs::write(&file, "v2").unwrap();
+        tracker.set_turn_index(1);
+        tracker.record_change(
+            "tc1",
+            "Edit",
+            "shared.txt",
+            Some(&before),
+            Some(&after),
+            "Edited",
+        );
+
+        // The user edits the file outside the agent
+        fs::write(&file, "v2 plus manual edits").unwrap();
+
+        let preview = tracker.preview_restore_to_before_turn(1).unwrap();
+        assert_eq!(preview.externally_modified, vec!["shared.txt".to_string()]);
+        assert!(preview.files[0].externally_modified);
+        assert!(preview.files[0].diff.contains("-v2 plus manual edits"));
+
+        let unchecked = tracker.restore_to_before_turn_v2(1, true, false).unwrap();
+        assert!(unchecked.externally_modified.is_empty());
+        tracker
+            .undo_restore(&unchecked.operation_id.unwrap())
+            .unwrap();
+
+        let checked = tracker.restore_to_before_turn_v2(1, true, true).unwrap();
+        assert_eq!(checked.externally_modified, vec!["shared.txt".to_string()]);
+        assert_eq!(fs::read_to_string(&file).unwrap(), "v1");
+    }
+
+    /// Record an edit of `name` from `before` to `after` in turn 1 and leave
+    /// `after` on disk.
+    fn record_turn_one_edit(tracker: &mut FileChangeTracker, dir: &Path, name: &str) {
+        let before = tracker
+            .store_content(format!("{name} before").as_bytes())
+            .unwrap();
+        let after = tracker
+            .store_content(format!("{name} after").as_bytes())
+            .unwrap();
+        let full = dir.join(name);
+        fs::create_dir_all(full.parent().unwrap()).unwrap();
+        fs::write(&full, format!("{name} after")).unwrap();
+        tracker.set_turn_index(1);
+        tracker.record_change(
+            &format!("tc-{name}"),
+            "Edit",
+            name,
+            Some(&before),
+            Some(&after),
+            "Edited",
+        );
+    }
+
+    #[test]
+    fn test_restore_selected_files_leaves_others_untouched() {
+        let dir = TempDir::new().unwrap();
+        let mut tracker = make_tracker(dir.path());
+        for name in ["a.txt", "b.txt", "c.txt", "d.txt"] {
+            record_turn_one_edit(&mut tracker, dir.path(), name);
+        }
+
+        let selected = vec!["a.txt".to_string(), "c.txt".to_string()];
+        let result = tracker
+            .restore_files_to_before_turn(1, &selected, true)
+            .unwrap();
+        assert_eq!(result.restored.len(), 2);
+        assert!(result.operation_id.is_some());
+
+        let read = |name: &str| fs::read_to_string(dir.path().join(name)).unwrap();
+        assert_eq!(read("a.txt"), "a.txt before");
+        assert_eq!(read("c.txt"), "c.txt before");
+        assert_eq!(read("b.txt"), "b.txt after");
+        assert_eq!(read("d.txt"), "d.txt after");
+        // Partial restores keep every change record
+        assert_eq!(tracker.change_count(), 4);
+
+        let unknown = tracker.restore_files_to_before_turn(1, &["nope.txt".to_string()], false);
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn test_restore_selected_files_rolls_back_on_failure() {
+        let dir = TempDir::new().unwrap();
+        let mut tracker = make_tracker(dir.path());
+        record_turn_one_edit(&mut tracker, dir.path(), "a.txt");
+        record_turn_one_edit(&mut tracker, dir.path(), "sub/c.txt");
+
+        // Replace the parent directory with a file so `sub/c.txt` can't be written
+        fs::remove_dir_all(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub"), "blocker").unwrap();
+
+        let selected = vec!["a.txt".to_string(), "sub/c.txt".to_string()];
+        let result = tracker.restore_files_to_before_turn(1, &selected, true);
+        assert!(result.is_err());
+        // a.txt was restored first, then rolled back
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.txt")).unwrap(),
+            "a.txt after"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.path().join("sub")).unwrap(),
+            "blocker"
+        );
+    }
+
     #[test]
     fn test_restore_v2_and_undo_restore() {
         let dir = TempDir::new().unwrap();
@@ -1255,7 +1664,7 @@ mod tests {
         );
 
         // Restore to before turn 1
-        let restore = tracker.restore_to_before_turn_v2(1, true).unwrap();
+        let restore = tracker.restore_to_before_turn_v2(1, true, false).unwrap();
         assert!(restore.operation_id.is_some());
         assert_eq!(fs::read_to_string(&existing).unwrap(), "original");
         assert!(!new_file.exists());
@@ -1282,6 +1691,42 @@ mod tests {
         assert_eq!(tracker.turn_index(), 2);
     }
 
+    #[test]
+    fn test_fork_keeps_changes_before_turn() {
+        let dir = TempDir::new().unwrap();
+        let mut tracker = make_tracker(dir.path());
+        for turn in 0..6 {
+            tracker.set_turn_index(turn);
+            let hash = tracker
+                .store_content(format!("v{turn}").as_bytes())
+                .unwrap();
+            tracker.record_change(
+                &format!("tc{turn}"),
+                "Write",
+                "a.txt",
+                None,
+                Some(&hash),
+                "Wrote",
+            );
+        }
+
+        let forked = tracker.fork("forked-session", 3).unwrap();
+        assert_eq!(forked.change_count(), 3);
+        assert_eq!(forked.turn_index(), 2);
+        assert!(forked
+            .changes
+            .iter()
+            .all(|c| c.session_id == "forked-session"));
+        assert_eq!(tracker.change_count(), 6);
+
+        // Fork is persisted under its own session id and shares CAS blobs
+        let reloaded =
+            FileChangeTracker::new_with_data_dir("forked-session", dir.path(), dir.path());
+        assert_eq!(reloaded.change_count(), 3);
+        let hash = reloaded.changes[2].after_hash.clone().unwrap();
+        assert_eq!(reloaded.get_content(&hash).unwrap(), b"v2");
+    }
+
     #[test]
     fn test_persist_and_reload_with_metadata() {
         let dir = TempDir::new().unwrap();
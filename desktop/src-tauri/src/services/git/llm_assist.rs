@@ -184,6 +184,7 @@ fn no_tools_options() -> LlmRequestOptions {
         fallback_tool_format_mode: FallbackToolFormatMode::Off,
         temperature_override: Some(0.3),
         reasoning_effort_override: None,
+        thinking_budget_override: None,
         analysis_phase: None,
     }
 }
@@ -5,6 +5,7 @@
 
 use async_trait::async_trait;
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 
 use super::{Direction, Guardrail, GuardrailAction, GuardrailResult, GuardrailRuntimeContext};
 
@@ -45,6 +46,24 @@ impl CustomGuardrail {
         })
     }
 
+    /// Create a custom guardrail for storage, rejecting patterns that fail
+    /// `compile_custom_pattern` with its precise error.
+    pub fn try_new_with_description(
+        id: String,
+        name: String,
+        pattern: &str,
+        action: GuardrailAction,
+        description: impl Into<String>,
+    ) -> Result<Self, String> {
+        compile_custom_pattern(pattern).map(|regex| Self {
+            id,
+            rule_name: name,
+            regex,
+            action,
+            description: description.into(),
+        })
+    }
+
     /// Get the rule ID.
     pub fn id(&self) -> &str {
         &self.id
@@ -73,6 +92,75 @@ impl CustomGuardrail {
     }
 }
 
+/// A single match produced by a custom rule preview.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomRuleMatch {
+    /// Byte offset of the match start in the sample.
+    pub start: usize,
+    /// Byte offset of the match end in the sample.
+    pub end: usize,
+    /// Matched text.
+    pub text: String,
+}
+
+/// Result of testing a custom rule pattern against sample text.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomRulePreview {
+    pub matches: Vec<CustomRuleMatch>,
+    /// Sample text with every match redacted, or `None` when nothing matched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redacted_preview: Option<String>,
+}
+
+/// Compile a user-supplied rule pattern.
+///
+/// Returns the regex compile error verbatim on failure, and rejects patterns
+/// that match empty text since those would trigger on every message.
+pub fn compile_custom_pattern(pattern: &str) -> Result<Regex, String> {
+    if pattern.trim().is_empty() {
+        return Err("Pattern must not be empty".to_string());
+    }
+    let regex = Regex::new(pattern).map_err(|e| format!("Invalid regex pattern: {}", e))?;
+    if regex.is_match("") {
+        return Err(format!(
+            "Pattern '{}' matches empty text and would trigger on all content",
+            pattern
+        ));
+    }
+    Ok(regex)
+}
+
+/// Preview the matches and redactions a rule pattern would produce on a
+/// sample, without registering or storing the rule.
+pub fn preview_custom_rule(
+    name: &str,
+    pattern: &str,
+    sample_text: &str,
+) -> Result<CustomRulePreview, String> {
+    let regex = compile_custom_pattern(pattern)?;
+    let matches: Vec<CustomRuleMatch> = regex
+        .find_iter(sample_text)
+        .map(|m| CustomRuleMatch {
+            start: m.start(),
+            end: m.end(),
+            text: m.as_str().to_string(),
+        })
+        .collect();
+    let redacted_preview = if matches.is_empty() {
+        None
+    } else {
+        Some(
+            regex
+                .replace_all(sample_text, format!("[REDACTED:{}]", name))
+                .to_string(),
+        )
+    };
+    Ok(CustomRulePreview {
+        matches,
+        redacted_preview,
+    })
+}
+
 #[async_trait]
 impl Guardrail for CustomGuardrail {
     fn id(&self) -> &str {
@@ -223,6 +311,52 @@ mod tests {
         assert!(guard.is_none());
     }
 
+    #[test]
+    fn test_preview_rejects_invalid_regex_with_compile_error() {
+        let error = preview_custom_rule("Bad", r"[invalid", "sample").unwrap_err();
+        assert!(error.starts_with("Invalid regex pattern"), "{}", error);
+        assert!(error.contains("unclosed character class"), "{}", error);
+    }
+
+    #[test]
+    fn test_preview_rejects_match_everything_pattern() {
+        assert!(compile_custom_pattern("").is_err());
+        assert!(compile_custom_pattern(".*").is_err());
+    }
+
+    #[test]
+    fn test_preview_reports_matches_and_redaction() {
+        let preview = preview_custom_rule(
+            "Internal ID",
+            r"INT-\d{6}",
+            "Tickets INT-123456 and INT-654321",
+        )
+        .unwrap();
+        assert_eq!(
+            preview.matches,
+            vec![
+                CustomRuleMatch {
+                    start: 8,
+                    end: 18,
+                    text: "INT-123456".to_string(),
+                },
+                CustomRuleMatch {
+                    start: 23,
+                    end: 33,
+                    text: "INT-654321".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            preview.redacted_preview.as_deref(),
+            Some("Tickets [REDACTED:Internal ID] and [REDACTED:Internal ID]")
+        );
+
+        let no_match = preview_custom_rule("Internal ID", r"INT-\d{6}", "nothing").unwrap();
+        assert!(no_match.matches.is_empty());
+        assert!(no_match.redacted_preview.is_none());
+    }
+
     #[test]
     fn test_accessors() {
         let guard = CustomGuardrail::new(
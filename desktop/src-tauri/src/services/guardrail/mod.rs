@@ -14,7 +14,10 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 pub use code_security::CodeSecurityGuardrail;
-pub use custom::CustomGuardrail;
+pub use custom::{
+    compile_custom_pattern, preview_custom_rule, CustomGuardrail, CustomRuleMatch,
+    CustomRulePreview,
+};
 pub use registry::{register_guardrail_hooks, shared_guardrail_registry, GuardrailRegistry};
 pub use schema_validation::SchemaValidationGuardrail;
 pub use sensitive_data::SensitiveDataGuardrail;
@@ -239,6 +242,39 @@ pub struct GuardrailEventEntry {
     pub timestamp: String,
 }
 
+/// Retention policy for the guardrail audit trail.
+///
+/// Pruning keeps at most `max_events` rows and drops rows older than
+/// `max_age_days`; either limit can be disabled with `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuardrailEventRetention {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_events: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u32>,
+}
+
+impl Default for GuardrailEventRetention {
+    fn default() -> Self {
+        Self {
+            max_events: Some(10_000),
+            max_age_days: Some(30),
+        }
+    }
+}
+
+/// Trigger count for one guardrail/surface/decision combination.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GuardrailEventAggregate {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub surface: String,
+    pub decision: String,
+    pub count: u64,
+    pub first_seen: String,
+    pub last_seen: String,
+}
+
 /// Creation/update payload for custom rules.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CustomRuleConfig {
@@ -16,12 +16,14 @@ use crate::storage::database::Database;
 
 use super::{
     CodeSecurityGuardrail, CustomGuardrail, CustomRuleConfig, Direction, Guardrail,
-    GuardrailAction, GuardrailEventEntry, GuardrailInfo, GuardrailMode, GuardrailResult,
-    GuardrailRuntimeContext, SchemaValidationGuardrail, SensitiveDataGuardrail,
+    GuardrailAction, GuardrailEventAggregate, GuardrailEventEntry, GuardrailEventRetention,
+    GuardrailInfo, GuardrailMode, GuardrailResult, GuardrailRuntimeContext,
+    SchemaValidationGuardrail, SensitiveDataGuardrail,
 };
 
 static GLOBAL_GUARDRAIL_REGISTRY: OnceLock<Arc<RwLock<GuardrailRegistry>>> = OnceLock::new();
 const GUARDRAIL_MODE_SETTING_KEY: &str = "guardrail_mode_v1";
+const GUARDRAIL_RETENTION_SETTING_KEY: &str = "guardrail_event_retention_v1";
 
 fn table_has_column(conn: &rusqlite::Connection, table: &str, column: &str) -> bool {
     let sql = format!("PRAGMA table_info({})", table);
@@ -101,6 +103,7 @@ pub struct GuardrailRegistry {
     entries: Vec<GuardrailEntry>,
     database: Option<Arc<Database>>,
     mode: GuardrailMode,
+    retention: GuardrailEventRetention,
     native_runtime_managed: bool,
     claude_code_managed: bool,
     init_error: Option<String>,
@@ -112,6 +115,7 @@ impl GuardrailRegistry {
             entries: Vec::new(),
             database: None,
             mode: GuardrailMode::Strict,
+            retention: GuardrailEventRetention::default(),
             native_runtime_managed: false,
             claude_code_managed: false,
             init_error: None,
@@ -161,10 +165,16 @@ impl GuardrailRegistry {
         database
             .set_setting(GUARDRAIL_MODE_SETTING_KEY, &self.mode.to_string())
             .map_err(|e| e.to_string())?;
+        self.retention = database
+            .get_setting(GUARDRAIL_RETENTION_SETTING_KEY)
+            .map_err(|e| e.to_string())?
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
         if let Err(error) = self.sync_from_database() {
             self.init_error = Some(error.clone());
             return Err(error);
         }
+        self.prune_events();
         self.native_runtime_managed = true;
         self.init_error = None;
         Ok(())
@@ -222,7 +232,9 @@ impl GuardrailRegistry {
                guardrail_type = excluded.guardrail_type,
                builtin_key = excluded.builtin_key,
                action = excluded.action,
-               scope = excluded.scope,
+               scope = CASE WHEN guardrail_rules.scope_customized = 1
+                            THEN guardrail_rules.scope
+                            ELSE excluded.scope END,
                editable = excluded.editable,
                description = excluded.description",
             params![
@@ -255,6 +267,7 @@ impl GuardrailRegistry {
                 pattern TEXT,
                 action TEXT NOT NULL DEFAULT 'warn',
                 scope TEXT NOT NULL DEFAULT '[\"input\",\"assistant_output\",\"tool_result\"]',
+                scope_customized INTEGER NOT NULL DEFAULT 0,
                 enabled INTEGER NOT NULL DEFAULT 1,
                 editable INTEGER NOT NULL DEFAULT 1,
                 description TEXT NOT NULL DEFAULT '',
@@ -286,6 +299,13 @@ impl GuardrailRegistry {
             )
             .map_err(|e| e.to_string())?;
         }
+        if !table_has_column(&conn, "guardrail_rules", "scope_customized") {
+            conn.execute(
+                "ALTER TABLE guardrail_rules ADD COLUMN scope_customized INTEGER NOT NULL DEFAULT 0",
+                [],
+            )
+            .map_err(|e| e.to_string())?;
+        }
         if !table_has_column(&conn, "guardrail_rules", "editable") {
             conn.execute(
                 "ALTER TABLE guardrail_rules ADD COLUMN editable INTEGER NOT NULL DEFAULT 1",
@@ -457,7 +477,7 @@ impl GuardrailRegistry {
         &mut self,
         config: CustomRuleConfig,
     ) -> Result<GuardrailInfo, String> {
-        let guardrail = CustomGuardrail::new_with_description(
+        let guardrail = CustomGuardrail::try_new_with_description(
             config.id.clone(),
             config.name.clone(),
             &config.pattern,
@@ -467,8 +487,7 @@ impl GuardrailRegistry {
             } else {
                 config.description.clone()
             },
-        )
-        .ok_or_else(|| format!("Invalid regex pattern: '{}'", config.pattern))?;
+        )?;
 
         let Some(db) = &self.database else {
             return Err("Guardrail database is not available".to_string());
@@ -520,7 +539,7 @@ impl GuardrailRegistry {
             return Err(format!("Guardrail '{}' not found", config.id));
         };
 
-        let guardrail = CustomGuardrail::new_with_description(
+        let guardrail = CustomGuardrail::try_new_with_description(
             config.id.clone(),
             config.name.clone(),
             &config.pattern,
@@ -530,8 +549,7 @@ impl GuardrailRegistry {
             } else {
                 config.description.clone()
             },
-        )
-        .ok_or_else(|| format!("Invalid regex pattern: '{}'", config.pattern))?;
+        )?;
 
         let Some(db) = &self.database else {
             return Err("Guardrail database is not available".to_string());
@@ -601,6 +619,39 @@ impl GuardrailRegistry {
         Ok(entry.info())
     }
 
+    /// Restrict a guardrail (built-in or custom) to the given directions.
+    ///
+    /// The scope is persisted and survives restarts; built-in guardrails keep
+    /// a user-chosen scope instead of being reset to their defaults.
+    pub fn set_scope(&mut self, id: &str, scope: Vec<Direction>) -> Result<GuardrailInfo, String> {
+        if scope.is_empty() {
+            return Err("Guardrail scope must include at least one direction".to_string());
+        }
+        let mut scope = scope;
+        let mut seen = std::collections::HashSet::new();
+        scope.retain(|direction| seen.insert(*direction));
+
+        let Some(entry) = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.guardrail.id() == id)
+        else {
+            return Err(format!("Guardrail '{}' not found", id));
+        };
+        if let Some(db) = &self.database {
+            let conn = db.get_connection().map_err(|e| e.to_string())?;
+            conn.execute(
+                "UPDATE guardrail_rules
+                 SET scope = ?2, scope_customized = 1, updated_at = datetime('now')
+                 WHERE id = ?1",
+                params![id, serialize_scopes(&scope)],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        entry.scope = scope;
+        Ok(entry.info())
+    }
+
     pub fn delete_guardrail(&mut self, id: &str) -> Result<bool, String> {
         let Some(index) = self
             .entries
@@ -664,6 +715,102 @@ impl GuardrailRegistry {
         conn.execute("DELETE FROM guardrail_events", []).is_ok()
     }
 
+    pub fn retention(&self) -> GuardrailEventRetention {
+        self.retention
+    }
+
+    /// Update and persist the audit-trail retention policy, pruning immediately.
+    pub fn set_retention(
+        &mut self,
+        retention: GuardrailEventRetention,
+    ) -> Result<GuardrailEventRetention, String> {
+        if retention.max_events == Some(0) || retention.max_age_days == Some(0) {
+            return Err("Retention limits must be greater than zero".to_string());
+        }
+        if let Some(db) = &self.database {
+            let raw = serde_json::to_string(&retention).map_err(|e| e.to_string())?;
+            db.set_setting(GUARDRAIL_RETENTION_SETTING_KEY, &raw)
+                .map_err(|e| e.to_string())?;
+        }
+        self.retention = retention;
+        self.prune_events();
+        Ok(self.retention)
+    }
+
+    /// Apply the retention policy to the audit trail. Returns the number of
+    /// events deleted.
+    pub fn prune_events(&self) -> usize {
+        let Some(db) = &self.database else {
+            return 0;
+        };
+        let Ok(conn) = db.get_connection() else {
+            return 0;
+        };
+
+        let mut deleted = 0;
+        if let Some(days) = self.retention.max_age_days {
+            deleted += conn
+                .execute(
+                    "DELETE FROM guardrail_events WHERE timestamp < datetime('now', ?1)",
+                    params![format!("-{} days", days)],
+                )
+                .unwrap_or(0);
+        }
+        if let Some(max_events) = self.retention.max_events {
+            deleted += conn
+                .execute(
+                    "DELETE FROM guardrail_events WHERE id NOT IN (
+                         SELECT id FROM guardrail_events
+                         ORDER BY timestamp DESC, id DESC
+                         LIMIT ?1
+                     )",
+                    params![max_events as i64],
+                )
+                .unwrap_or(0);
+        }
+        deleted
+    }
+
+    /// Count triggers grouped by guardrail, surface, and decision, optionally
+    /// restricted to `[since, until]` (any SQLite-parseable timestamp).
+    pub fn aggregate_events(
+        &self,
+        since: Option<&str>,
+        until: Option<&str>,
+    ) -> Vec<GuardrailEventAggregate> {
+        let Some(db) = &self.database else {
+            return Vec::new();
+        };
+        let Ok(conn) = db.get_connection() else {
+            return Vec::new();
+        };
+        let Ok(mut stmt) = conn.prepare(
+            "SELECT rule_id, MAX(rule_name), surface, decision, COUNT(*), MIN(timestamp), MAX(timestamp)
+             FROM guardrail_events
+             WHERE (?1 IS NULL OR timestamp >= datetime(?1))
+               AND (?2 IS NULL OR timestamp <= datetime(?2))
+             GROUP BY rule_id, surface, decision
+             ORDER BY COUNT(*) DESC, rule_id ASC, surface ASC, decision ASC",
+        ) else {
+            return Vec::new();
+        };
+
+        stmt.query_map(params![since, until], |row| {
+            Ok(GuardrailEventAggregate {
+                rule_id: row.get(0)?,
+                rule_name: row.get(1)?,
+                surface: row.get(2)?,
+                decision: row.get(3)?,
+                count: row.get::<_, i64>(4)? as u64,
+                first_seen: row.get(5)?,
+                last_seen: row.get(6)?,
+            })
+        })
+        .ok()
+        .map(|rows| rows.filter_map(Result::ok).collect())
+        .unwrap_or_default()
+    }
+
     fn log_event(
         &self,
         entry: &GuardrailEntry,
@@ -1062,6 +1209,143 @@ mod tests {
         assert!(matches!(result, GuardrailResult::Block { .. }));
     }
 
+    #[tokio::test]
+    async fn output_only_scope_skips_input_and_runs_on_output() {
+        let mut registry = GuardrailRegistry::with_defaults();
+        let info = registry
+            .set_scope("builtin-sensitive-data", vec![Direction::Output])
+            .unwrap();
+        assert_eq!(info.scope, vec![Direction::Output]);
+
+        let secret = "secret sk-abcdefghijklmnopqrstuvwxyz123456789012345678";
+        let input = registry
+            .validate_all(
+                secret,
+                Direction::Input,
+                &GuardrailRuntimeContext::default(),
+            )
+            .await;
+        assert!(
+            input.is_pass(),
+            "Output-only guardrail ran on input: {:?}",
+            input
+        );
+
+        let output = registry
+            .validate_all(
+                secret,
+                Direction::Output,
+                &GuardrailRuntimeContext::default(),
+            )
+            .await;
+        assert!(
+            output.is_redact(),
+            "Output-only guardrail skipped output: {:?}",
+            output
+        );
+    }
+
+    #[test]
+    fn set_scope_rejects_empty_and_unknown_guardrails() {
+        let mut registry = GuardrailRegistry::with_defaults();
+        assert!(registry
+            .set_scope("builtin-code-security", Vec::new())
+            .is_err());
+        assert!(registry
+            .set_scope("missing", vec![Direction::Input])
+            .is_err());
+    }
+
+    #[test]
+    fn create_custom_rule_rejects_invalid_regex_before_storing() {
+        let mut registry = GuardrailRegistry::with_defaults();
+        let before = registry.list_guardrails().len();
+        let error = registry
+            .create_custom_rule(CustomRuleConfig {
+                id: "bad-rule".to_string(),
+                name: "Bad".to_string(),
+                pattern: "(unclosed".to_string(),
+                action: GuardrailAction::Block,
+                enabled: true,
+                scope: Vec::new(),
+                description: String::new(),
+            })
+            .unwrap_err();
+        assert!(error.starts_with("Invalid regex pattern"), "{}", error);
+        assert_eq!(registry.list_guardrails().len(), before);
+    }
+
+    fn registry_with_database() -> GuardrailRegistry {
+        let database = Arc::new(Database::new_in_memory().expect("in-memory database"));
+        let mut registry = GuardrailRegistry::with_defaults();
+        registry
+            .initialize_with_database(database)
+            .expect("initialize registry");
+        registry
+    }
+
+    fn insert_event(registry: &GuardrailRegistry, rule_id: &str, surface: &str, age: &str) {
+        let conn = registry
+            .database
+            .as_ref()
+            .unwrap()
+            .get_connection()
+            .unwrap();
+        conn.execute(
+            "INSERT INTO guardrail_events
+             (rule_id, rule_name, surface, decision, content_hash, safe_preview, timestamp)
+             VALUES (?1, ?1, ?2, 'block', 'hash', '', datetime('now', ?3))",
+            params![rule_id, surface, age],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn prune_events_drops_old_and_excess_entries() {
+        let mut registry = registry_with_database();
+        insert_event(&registry, "rule-a", "input", "-40 days");
+        insert_event(&registry, "rule-a", "input", "-3 minutes");
+        insert_event(&registry, "rule-a", "input", "-2 minutes");
+        insert_event(&registry, "rule-a", "input", "-1 minutes");
+
+        registry
+            .set_retention(GuardrailEventRetention {
+                max_events: Some(2),
+                max_age_days: Some(30),
+            })
+            .unwrap();
+
+        let remaining = registry.get_events(10, 0);
+        assert_eq!(remaining.len(), 2);
+        assert_eq!(registry.prune_events(), 0);
+    }
+
+    #[test]
+    fn aggregate_events_counts_per_guardrail_and_surface() {
+        let registry = registry_with_database();
+        insert_event(&registry, "rule-a", "input", "-1 minutes");
+        insert_event(&registry, "rule-a", "input", "-2 minutes");
+        insert_event(&registry, "rule-a", "tool_call", "-2 minutes");
+        insert_event(&registry, "rule-b", "input", "-3 minutes");
+        insert_event(&registry, "rule-b", "input", "-10 days");
+
+        let all = registry.aggregate_events(None, None);
+        let count_for = |rows: &[GuardrailEventAggregate], rule: &str, surface: &str| {
+            rows.iter()
+                .find(|row| row.rule_id == rule && row.surface == surface)
+                .map(|row| row.count)
+                .unwrap_or(0)
+        };
+        assert_eq!(count_for(&all, "rule-a", "input"), 2);
+        assert_eq!(count_for(&all, "rule-a", "tool_call"), 1);
+        assert_eq!(count_for(&all, "rule-b", "input"), 2);
+        assert_eq!(all[0].rule_id, "rule-a");
+
+        let since = (chrono::Utc::now() - chrono::Duration::days(1)).to_rfc3339();
+        let recent = registry.aggregate_events(Some(&since), None);
+        assert_eq!(count_for(&recent, "rule-b", "input"), 1);
+    }
+
     #[test]
     fn shared_registry_is_singleton() {
         let a = shared_guardrail_registry();
@@ -1,6 +1,7 @@
 //! Schema Validation Guardrail
 //!
-//! Validates agent JSON output against registered JSON Schemas.
+//! Validates agent JSON output against registered JSON Schemas, and
+//! tool-call arguments against the called tool's declared parameter schema.
 //! Implements the `Guardrail` trait to integrate with the guardrail
 //! lifecycle hooks.
 //!
@@ -76,11 +77,14 @@ impl Default for SchemaRegistry {
 
 /// Guardrail that validates agent JSON output against registered JSON Schemas.
 ///
-/// Only validates `Direction::Output`. For other directions or when no schema
-/// is active, returns `GuardrailResult::Pass`.
+/// Validates `Direction::Output`/`Direction::Artifact` against the active task
+/// schema, and `Direction::ToolCall` arguments against the schema of the tool
+/// named in the runtime context. For other directions or when no schema
+/// applies, returns `GuardrailResult::Pass`.
 pub struct SchemaValidationGuardrail {
     registry: RwLock<SchemaRegistry>,
     active_schema: RwLock<Option<String>>,
+    tool_schemas: RwLock<HashMap<String, Value>>,
 }
 
 impl SchemaValidationGuardrail {
@@ -95,6 +99,7 @@ impl SchemaValidationGuardrail {
         Self {
             registry: RwLock::new(registry),
             active_schema: RwLock::new(None),
+            tool_schemas: RwLock::new(HashMap::new()),
         }
     }
 
@@ -103,6 +108,7 @@ impl SchemaValidationGuardrail {
         Self {
             registry: RwLock::new(SchemaRegistry::new()),
             active_schema: RwLock::new(None),
+            tool_schemas: RwLock::new(HashMap::new()),
         }
     }
 
@@ -134,6 +140,74 @@ impl SchemaValidationGuardrail {
             .and_then(|guard| guard.clone())
     }
 
+    /// Register an explicit argument schema for a tool.
+    ///
+    /// Takes precedence over the schema declared by the tool registry.
+    pub fn register_tool_schema(&self, tool_name: &str, schema: Value) -> Result<(), String> {
+        if !schema.is_object() {
+            return Err("Schema must be a JSON object".to_string());
+        }
+        let mut tool_schemas = self.tool_schemas.write().map_err(|e| e.to_string())?;
+        tool_schemas.insert(tool_name.to_string(), schema);
+        Ok(())
+    }
+
+    /// Resolve the argument schema for a tool: explicit registrations first,
+    /// then the built-in tool registry, then runtime (MCP) tools.
+    fn tool_schema(&self, tool_name: &str) -> Option<Value> {
+        if let Some(schema) = self
+            .tool_schemas
+            .read()
+            .ok()
+            .and_then(|guard| guard.get(tool_name).cloned())
+        {
+            return Some(schema);
+        }
+        let tool = crate::services::tools::definitions::cached_registry()
+            .get(tool_name)
+            .or_else(|| crate::services::tools::runtime_tools::get(tool_name))?;
+        serde_json::to_value(tool.parameters_schema()).ok()
+    }
+
+    /// Validate tool-call arguments against the called tool's schema.
+    fn validate_tool_arguments(&self, content: &str, tool_name: &str) -> GuardrailResult {
+        let Some(schema) = self.tool_schema(tool_name) else {
+            return GuardrailResult::Pass;
+        };
+
+        let json_value: Value = match serde_json::from_str(content) {
+            Ok(v) => v,
+            Err(e) => {
+                return GuardrailResult::Block {
+                    reason: format!("Invalid JSON arguments for tool '{}': {}", tool_name, e),
+                };
+            }
+        };
+        if !json_value.is_object() {
+            return GuardrailResult::Block {
+                reason: format!(
+                    "Invalid arguments for tool '{}': expected a JSON object, got {}",
+                    tool_name,
+                    json_type_name(&json_value)
+                ),
+            };
+        }
+
+        let errors = self.validate_json_against_schema(&json_value, &schema);
+        if errors.is_empty() {
+            GuardrailResult::Pass
+        } else {
+            GuardrailResult::Block {
+                reason: format!(
+                    "Invalid arguments for tool '{}' ({} errors):\n{}\nFix the arguments and call the tool again.",
+                    tool_name,
+                    errors.len(),
+                    format_error_list(&errors)
+                ),
+            }
+        }
+    }
+
     /// Validate a JSON value against a schema, returning detailed errors.
     fn validate_json_against_schema(&self, json_value: &Value, schema: &Value) -> Vec<String> {
         let mut errors = Vec::new();
@@ -156,7 +230,7 @@ impl SchemaValidationGuardrail {
                     // Check type constraint
                     if let Some(expected_type) = prop_schema.get("type").and_then(|v| v.as_str()) {
                         let actual_type = json_type_name(value);
-                        if actual_type != expected_type {
+                        if !type_matches(expected_type, value) {
                             errors.push(format!(
                                 "Type mismatch for '{}': expected {}, got {}",
                                 prop_name, expected_type, actual_type
@@ -172,7 +246,7 @@ impl SchemaValidationGuardrail {
                             {
                                 for (idx, item) in value.as_array().unwrap().iter().enumerate() {
                                     let item_actual_type = json_type_name(item);
-                                    if item_actual_type != item_type {
+                                    if !type_matches(item_type, item) {
                                         errors.push(format!(
                                             "Array item type mismatch for '{}[{}]': expected {}, got {}",
                                             prop_name, idx, item_type, item_actual_type
@@ -207,7 +281,7 @@ impl SchemaValidationGuardrail {
                                                     .and_then(|v| v.as_str())
                                                 {
                                                     let act_type = json_type_name(item_value);
-                                                    if act_type != exp_type {
+                                                    if !type_matches(exp_type, item_value) {
                                                         errors.push(format!(
                                                             "Type mismatch for '{}[{}].{}': expected {}, got {}",
                                                             prop_name, idx, item_prop_name, exp_type, act_type
@@ -252,6 +326,21 @@ impl SchemaValidationGuardrail {
                         }
                     }
 
+                    // Check enum constraint
+                    if let Some(allowed) = prop_schema.get("enum").and_then(|v| v.as_array()) {
+                        if !allowed.is_empty() && !allowed.contains(value) {
+                            let allowed_list = allowed
+                                .iter()
+                                .map(|v| v.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            errors.push(format!(
+                                "Invalid value for '{}': {} is not one of [{}]",
+                                prop_name, value, allowed_list
+                            ));
+                        }
+                    }
+
                     // Check pattern constraint
                     if let Some(pattern_str) = prop_schema.get("pattern").and_then(|v| v.as_str()) {
                         if let Some(str_value) = value.as_str() {
@@ -324,7 +413,7 @@ impl Guardrail for SchemaValidationGuardrail {
     }
 
     fn default_scopes(&self) -> Vec<Direction> {
-        vec![Direction::Output, Direction::Artifact]
+        vec![Direction::ToolCall, Direction::Output, Direction::Artifact]
     }
 
     fn default_action_label(&self) -> &'static str {
@@ -337,6 +426,12 @@ impl Guardrail for SchemaValidationGuardrail {
         direction: Direction,
         runtime: &GuardrailRuntimeContext,
     ) -> GuardrailResult {
+        if direction == Direction::ToolCall {
+            return match runtime.tool_name.as_deref() {
+                Some(tool_name) => self.validate_tool_arguments(content, tool_name),
+                None => GuardrailResult::Pass,
+            };
+        }
         if direction != Direction::Output && direction != Direction::Artifact {
             return GuardrailResult::Pass;
         }
@@ -379,12 +474,7 @@ impl Guardrail for SchemaValidationGuardrail {
                     "Schema validation failed for '{}' ({} errors):\n{}",
                     active_task_type,
                     errors.len(),
-                    errors
-                        .iter()
-                        .enumerate()
-                        .map(|(i, e)| format!("  {}. {}", i + 1, e))
-                        .collect::<Vec<_>>()
-                        .join("\n")
+                    format_error_list(&errors)
                 ),
             }
         }
@@ -407,6 +497,21 @@ fn detect_content_kind(content: &str) -> Option<String> {
     None
 }
 
+fn format_error_list(errors: &[String]) -> String {
+    errors
+        .iter()
+        .enumerate()
+        .map(|(i, e)| format!("  {}. {}", i + 1, e))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Check a value against a JSON Schema `type`; `number` also accepts integers.
+fn type_matches(expected_type: &str, value: &Value) -> bool {
+    let actual_type = json_type_name(value);
+    actual_type == expected_type || (expected_type == "number" && actual_type == "integer")
+}
+
 /// Get the JSON Schema type name for a serde_json::Value.
 fn json_type_name(value: &Value) -> &'static str {
     match value {
@@ -796,6 +901,92 @@ mod tests {
         assert!(result.is_block());
     }
 
+    // ======================================================================
+    // Tool-call argument validation
+    // ======================================================================
+
+    fn tool_runtime(tool_name: &str) -> GuardrailRuntimeContext {
+        GuardrailRuntimeContext {
+            tool_name: Some(tool_name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn register_read_file_schema(g: &SchemaValidationGuardrail) {
+        g.register_tool_schema(
+            "test_read_file",
+            serde_json::json!({
+                "type": "object",
+                "required": ["file_path"],
+                "properties": {
+                    "file_path": { "type": "string" },
+                    "offset": { "type": "number" },
+                    "mode": { "type": "string", "enum": ["text", "binary"] }
+                }
+            }),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn tool_call_missing_required_argument_blocks() {
+        let g = SchemaValidationGuardrail::new_empty();
+        register_read_file_schema(&g);
+
+        let result = g
+            .validate(
+                r#"{"offset": 10}"#,
+                Direction::ToolCall,
+                &tool_runtime("test_read_file"),
+            )
+            .await;
+        match result {
+            GuardrailResult::Block { reason } => {
+                assert!(reason.contains("test_read_file"), "{}", reason);
+                assert!(
+                    reason.contains("Missing required field: 'file_path'"),
+                    "{}",
+                    reason
+                );
+            }
+            other => panic!("Missing argument should block, got: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn tool_call_valid_arguments_pass() {
+        let g = SchemaValidationGuardrail::new_empty();
+        register_read_file_schema(&g);
+
+        let result = g
+            .validate(
+                r#"{"file_path": "src/main.rs", "offset": 10, "mode": "text"}"#,
+                Direction::ToolCall,
+                &tool_runtime("test_read_file"),
+            )
+            .await;
+        assert!(
+            result.is_pass(),
+            "Valid arguments should pass: {:?}",
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn tool_call_enum_violation_blocks() {
+        let g = SchemaValidationGuardrail::new_empty();
+        register_read_file_schema(&g);
+
+        let result = g
+            .validate(
+                r#"{"file_path": "src/main.rs", "mode": "hex"}"#,
+                Direction::ToolCall,
+                &tool_runtime("test_read_file"),
+            )
+            .await;
+        assert!(result.is_block(), "Enum violation should block");
+    }
+
     // ======================================================================
     // json_type_name helper tests
     // ======================================================================
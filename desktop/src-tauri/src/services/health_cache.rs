@@ -0,0 +1,378 @@
+//! Provider Health Cache
+//!
+//! Caches provider health check results so UI polling does not hit the
+//! network on every call. Results younger than the TTL are served as-is.
+//! Older results, up to `max_stale`, are still served immediately while a
+//! background refresh runs (stale-while-revalidate). Anything older, or a
+//! forced refresh, runs the check inline; concurrent callers missing the
+//! same key wait for a single check instead of each running their own.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+/// How long a health result is served without re-checking.
+pub const HEALTH_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// How long a result may be served while a background refresh runs.
+pub const HEALTH_CACHE_MAX_STALE: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone)]
+struct CacheEntry<T> {
+    value: T,
+    checked_at: DateTime<Utc>,
+    stored_at: Instant,
+}
+
+/// A health result together with when and how it was obtained.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedHealth<T> {
+    pub value: T,
+    /// When the underlying check ran
+    pub checked_at: DateTime<Utc>,
+    /// Served from the cache rather than a check made for this call
+    pub cached: bool,
+    /// Older than the TTL; a background refresh has been started
+    pub stale: bool,
+}
+
+/// Keyed cache of health check results with stale-while-revalidate.
+pub struct HealthCache<T> {
+    ttl: Duration,
+    max_stale: Duration,
+    entries: Mutex<HashMap<String, CacheEntry<T>>>,
+    refreshing: Mutex<HashSet<String>>,
+    in_flight: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+/// Clears a key from `refreshing` when a background refresh ends, even if
+/// the check panics.
+struct RefreshGuard<T> {
+    cache: Arc<HealthCache<T>>,
+    key: String,
+}
+
+impl<T> Drop for RefreshGuard<T> {
+    fn drop(&mut self) {
+        if let Ok(mut refreshing) = self.cache.refreshing.lock() {
+            refreshing.remove(&self.key);
+        }
+    }
+}
+
+impl<T: Clone + Send + 'static> HealthCache<T> {
+    pub fn new(ttl: Duration, max_stale: Duration) -> Self {
+        Self {
+            ttl,
+            max_stale: max_stale.max(ttl),
+            entries: Mutex::new(HashMap::new()),
+            refreshing: Mutex::new(HashSet::new()),
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return the cached result for `key`, running `check` when there is no
+    /// usable entry or `force` is set.
+    pub async fn get_or_check<F, Fut>(
+        self: &Arc<Self>,
+        key: &str,
+        force: bool,
+        check: F,
+    ) -> CachedHealth<T>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        if !force {
+            if let Some(entry) = self.lookup(key) {
+                let age = entry.stored_at.elapsed();
+                if age < self.ttl {
+                    return CachedHealth {
+                        value: entry.value,
+                        checked_at: entry.checked_at,
+                        cached: true,
+                        stale: false,
+                    };
+                }
+                if age < self.max_stale {
+                    self.spawn_refresh(key, check);
+                    return CachedHealth {
+                        value: entry.value,
+                        checked_at: entry.checked_at,
+                        cached: true,
+                        stale: true,
+                    };
+                }
+            }
+        }
+
+        let flight = self.flight(key);
+        let result = {
+            let _running = flight.lock().await;
+            match self
+                .lookup(key)
+                .filter(|e| !force && e.stored_at.elapsed() < self.ttl)
+            {
+                // Another caller finished the check while this one waited.
+                Some(entry) => CachedHealth {
+                    value: entry.value,
+                    checked_at: entry.checked_at,
+                    cached: true,
+                    stale: false,
+                },
+                None => {
+                    let value = check().await;
+                    let checked_at = self.store(key, value.clone());
+                    CachedHealth {
+                        value,
+                        checked_at,
+                        cached: false,
+                        stale: false,
+                    }
+                }
+            }
+        };
+        self.release_flight(key, flight);
+        result
+    }
+
+    /// Drop the cached result for `key`.
+    pub fn invalidate(&self, key: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
+
+    /// Lock serializing inline checks for `key`.
+    fn flight(&self, key: &str) -> Arc<tokio::sync::Mutex<()>> {
+        match self.in_flight.lock() {
+            Ok(mut in_flight) => in_flight.entry(key.to_string()).or_default().clone(),
+            Err(_) => Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// Drop the lock for `key` once no other caller is waiting on it.
+    fn release_flight(&self, key: &str, flight: Arc<tokio::sync::Mutex<()>>) {
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            // One reference is held by the map and one by `flight`.
+            if Arc::strong_count(&flight) <= 2 {
+                in_flight.remove(key);
+            }
+        }
+    }
+
+    fn lookup(&self, key: &str) -> Option<CacheEntry<T>> {
+        self.entries.lock().ok()?.get(key).cloned()
+    }
+
+    fn store(&self, key: &str, value: T) -> DateTime<Utc> {
+        let checked_at = Utc::now();
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                key.to_string(),
+                CacheEntry {
+                    value,
+                    checked_at,
+                    stored_at: Instant::now(),
+                },
+            );
+        }
+        checked_at
+    }
+
+    /// Run `check` in the background unless a refresh for `key` is already
+    /// in flight.
+    fn spawn_refresh<F, Fut>(self: &Arc<Self>, key: &str, check: F)
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = T> + Send + 'static,
+    {
+        match self.refreshing.lock() {
+            Ok(mut refreshing) => {
+                if !refreshing.insert(key.to_string()) {
+                    return;
+                }
+            }
+            Err(_) => return,
+        }
+
+        let guard = RefreshGuard {
+            cache: Arc::clone(self),
+            key: key.to_string(),
+        };
+        tokio::spawn(async move {
+            let value = check().await;
+            guard.cache.store(&guard.key, value);
+        });
+    }
+}
+
+/// Build a cache key from the inputs that affect a health check.
+///
+/// The credential is folded in as a short hash so changing an API key
+/// invalidates earlier results without keeping the key itself around.
+pub fn health_cache_key(parts: &[&str], credential: Option<&str>) -> String {
+    let mut key = parts.join("|");
+    if let Some(credential) = credential.filter(|c| !c.is_empty()) {
+        let digest = Sha256::digest(credential.as_bytes());
+        key.push('|');
+        for byte in &digest[..8] {
+            key.push_str(&format!("{:02x}", byte));
+        }
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn counting_check(
+        calls: &Arc<AtomicUsize>,
+    ) -> impl FnOnce() -> std::future::Ready<usize> + Send + 'static {
+        let calls = Arc::clone(calls);
+        move || std::future::ready(calls.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+
+    #[tokio::test]
+    async fn test_repeated_calls_within_ttl_reuse_cached_result() {
+        let cache = Arc::new(HealthCache::new(
+            Duration::from_secs(60),
+            Duration::from_secs(120),
+        ));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let first = cache
+            .get_or_check("ollama", false, counting_check(&calls))
+            .await;
+        assert!(!first.cached);
+        for _ in 0..5 {
+            let again = cache
+                .get_or_check("ollama", false, counting_check(&calls))
+                .await;
+            assert!(again.cached);
+            assert!(!again.stale);
+            assert_eq!(again.value, 1);
+            assert_eq!(again.checked_at, first.checked_at);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // A different key is checked separately.
+        cache
+            .get_or_check("openai", false, counting_check(&calls))
+            .await;
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_force_refresh_bypasses_cache() {
+        let cache = Arc::new(HealthCache::new(
+            Duration::from_secs(60),
+            Duration::from_secs(120),
+        ));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        cache
+            .get_or_check("ollama", false, counting_check(&calls))
+            .await;
+        let forced = cache
+            .get_or_check("ollama", true, counting_check(&calls))
+            .await;
+        assert!(!forced.cached);
+        assert_eq!(forced.value, 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // The forced result replaces the cached one.
+        let cached = cache
+            .get_or_check("ollama", false, counting_check(&calls))
+            .await;
+        assert_eq!(cached.value, 2);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stale_result_is_served_while_refreshing() {
+        let cache = Arc::new(HealthCache::new(Duration::ZERO, Duration::from_secs(60)));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        cache
+            .get_or_check("ollama", false, counting_check(&calls))
+            .await;
+        let stale = cache
+            .get_or_check("ollama", false, counting_check(&calls))
+            .await;
+        assert!(stale.cached);
+        assert!(stale.stale);
+        assert_eq!(stale.value, 1);
+
+        for _ in 0..100 {
+            if calls.load(Ordering::SeqCst) == 2 && cache.lookup("ollama").unwrap().value == 2 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert_eq!(cache.lookup("ollama").unwrap().value, 2);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_cold_misses_share_one_check() {
+        let cache = Arc::new(HealthCache::new(
+            Duration::from_secs(60),
+            Duration::from_secs(120),
+        ));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let slow_check = |calls: &Arc<AtomicUsize>| {
+            let calls = Arc::clone(calls);
+            move || async move {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                calls.fetch_add(1, Ordering::SeqCst) + 1
+            }
+        };
+        let results = futures_util::future::join_all(
+            (0..5).map(|_| cache.get_or_check("ollama", false, slow_check(&calls))),
+        )
+        .await;
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(results.iter().all(|r| r.value == 1));
+        assert_eq!(results.iter().filter(|r| !r.cached).count(), 1);
+        assert!(cache.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_panicking_refresh_releases_key() {
+        let cache = Arc::new(HealthCache::new(Duration::ZERO, Duration::from_secs(60)));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        cache
+            .get_or_check("ollama", false, counting_check(&calls))
+            .await;
+        let stale = cache
+            .get_or_check("ollama", false, || async { panic!("check failed") })
+            .await;
+        assert!(stale.stale);
+
+        for _ in 0..100 {
+            if cache.refreshing.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        assert!(cache.refreshing.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cache_key_changes_with_credential() {
+        let a = health_cache_key(&["openai", "gpt-4o"], Some("sk-1"));
+        let b = health_cache_key(&["openai", "gpt-4o"], Some("sk-2"));
+        assert_ne!(a, b);
+        assert!(!a.contains("sk-1"));
+        assert_eq!(health_cache_key(&["ollama", ""], None), "ollama|");
+    }
+}
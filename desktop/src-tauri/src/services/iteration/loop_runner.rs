@@ -3,6 +3,7 @@
 //! Implements the auto-iteration system for executing PRD stories with
 //! quality gates integration and retry logic.
 
+use std::collections::HashMap;
 use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
@@ -14,8 +15,22 @@ use tokio::sync::{mpsc, RwLock};
 use tokio_util::sync::CancellationToken;
 
 use crate::models::iteration::{IterationConfig, IterationMode, IterationResult, IterationState};
-use crate::models::prd::{Prd, StoryStatus};
+use crate::models::prd::{Prd, Story, StoryStatus};
 use crate::services::dependency::{Batch, DependencyAnalyzer, DependencyError};
+use crate::services::fallback::FailureReason;
+
+use super::story_executor::{RetryQueue, StoryRetryInfo};
+
+/// Default number of consecutive identical failures treated as "no progress"
+const DEFAULT_STALL_WINDOW: u32 = 3;
+
+/// Gates that can't be affected by a documentation-only story
+const DOCS_SKIPPED_GATES: &[&str] = &["test", "lint"];
+
+/// Story metadata key listing the only gate ids to run for a story
+const GATES_METADATA_KEY: &str = "quality_gates";
+/// Story metadata key listing gate ids to skip for a story
+const SKIP_GATES_METADATA_KEY: &str = "skip_quality_gates";
 
 /// Configuration for the iteration loop
 #[derive(Debug, Clone)]
@@ -28,6 +43,12 @@ pub struct IterationLoopConfig {
     pub prd_path: PathBuf,
     /// Whether to save state after each iteration
     pub persist_state: bool,
+    /// Number of consecutive identical failures (same error and findings)
+    /// after which a story is considered stalled and failed without further
+    /// retries. `0` disables stall detection.
+    pub stall_window: u32,
+    /// Hard cap on loop iterations regardless of `iteration.mode`
+    pub max_total_iterations: Option<u32>,
 }
 
 impl Default for IterationLoopConfig {
@@ -37,6 +58,8 @@ impl Default for IterationLoopConfig {
             project_root: PathBuf::from("."),
             prd_path: PathBuf::from("prd.json"),
             persist_state: true,
+            stall_window: DEFAULT_STALL_WINDOW,
+            max_total_iterations: None,
         }
     }
 }
@@ -90,6 +113,12 @@ pub enum IterationEvent {
     QualityGatesStarted { story_id: String },
     /// Quality gates completed
     QualityGatesCompleted { story_id: String, passed: bool },
+    /// Story kept failing identically; it is failed instead of retried
+    Stalled {
+        story_id: String,
+        consecutive_failures: u32,
+        reason: String,
+    },
     /// Batch completed
     BatchCompleted { batch_index: usize },
     /// Iteration completed
@@ -109,6 +138,85 @@ pub enum IterationEvent {
     Error { message: String },
 }
 
+/// Which quality gates apply to a single story.
+///
+/// Derived from explicit story metadata (`quality_gates` /
+/// `skip_quality_gates` id lists) or, failing that, from the story type.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GateSelection {
+    /// Gate ids to run; `None` runs every configured gate
+    pub include: Option<Vec<String>>,
+    /// Gate ids never run for this story
+    pub exclude: Vec<String>,
+}
+
+impl GateSelection {
+    /// Run every configured gate
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Run only the given gates
+    pub fn only(gate_ids: Vec<String>) -> Self {
+        Self {
+            include: Some(gate_ids),
+            exclude: Vec::new(),
+        }
+    }
+
+    /// Run every gate except the given ones
+    pub fn excluding(gate_ids: Vec<String>) -> Self {
+        Self {
+            include: None,
+            exclude: gate_ids,
+        }
+    }
+
+    /// Derive the selection for a story
+    pub fn for_story(story: &Story) -> Self {
+        let metadata_ids = |key: &str| -> Option<Vec<String>> {
+            story
+                .metadata
+                .get(key)
+                .and_then(|v| v.as_array())
+                .map(|ids| {
+                    ids.iter()
+                        .filter_map(|id| id.as_str().map(str::to_string))
+                        .collect()
+                })
+        };
+
+        let include = metadata_ids(GATES_METADATA_KEY);
+        let exclude = metadata_ids(SKIP_GATES_METADATA_KEY);
+        if include.is_some() || exclude.is_some() {
+            return Self {
+                include,
+                exclude: exclude.unwrap_or_default(),
+            };
+        }
+
+        if story.is_docs() {
+            return Self::excluding(DOCS_SKIPPED_GATES.iter().map(|g| g.to_string()).collect());
+        }
+
+        Self::all()
+    }
+
+    /// Whether the gate with this id should run
+    pub fn includes(&self, gate_id: &str) -> bool {
+        !self.exclude.iter().any(|id| id == gate_id)
+            && self
+                .include
+                .as_ref()
+                .is_none_or(|ids| ids.iter().any(|id| id == gate_id))
+    }
+
+    /// Whether the selection can't match any gate
+    pub fn is_empty(&self) -> bool {
+        self.include.as_ref().is_some_and(|ids| ids.is_empty())
+    }
+}
+
 /// Quality gate result with details from the pipeline execution.
 #[derive(Debug, Clone)]
 pub struct QualityGateResult {
@@ -116,6 +224,8 @@ pub struct QualityGateResult {
     pub error: Option<String>,
     /// Detailed findings from individual gates (for retry context injection)
     pub details: Vec<String>,
+    /// The gate selection the story was checked with
+    pub gate_selection: GateSelection,
 }
 
 impl QualityGateResult {
@@ -125,6 +235,7 @@ impl QualityGateResult {
             passed: true,
             error: None,
             details: Vec::new(),
+            gate_selection: GateSelection::default(),
         }
     }
 
@@ -134,6 +245,7 @@ impl QualityGateResult {
             passed: false,
             error: Some(error),
             details,
+            gate_selection: GateSelection::default(),
         }
     }
 }
@@ -145,6 +257,8 @@ pub struct QualityGateContext {
     pub story_id: String,
     /// Project root path for running gates against
     pub project_root: PathBuf,
+    /// Gates that apply to this story
+    pub gate_selection: GateSelection,
 }
 
 /// Type alias for the quality gate runner callback.
@@ -180,6 +294,8 @@ pub struct StoryExecutionContext {
     pub acceptance_criteria: Vec<String>,
     /// Project root path
     pub project_root: PathBuf,
+    /// Quality gates that will be run for this story
+    pub gate_selection: GateSelection,
 }
 
 /// Type alias for the story executor callback.
@@ -209,6 +325,10 @@ pub struct IterationLoop {
     story_executor: Option<StoryExecutorFn>,
     /// Quality gate runner callback for validating story results
     quality_gate_runner: Option<QualityGateRunnerFn>,
+    /// Retry bookkeeping, including stall reasons
+    retry_queue: Arc<RwLock<RetryQueue>>,
+    /// Last failure signature and its consecutive repeat count per story
+    failure_signatures: RwLock<HashMap<String, (String, u32)>>,
 }
 
 impl IterationLoop {
@@ -225,6 +345,8 @@ impl IterationLoop {
             cancellation_token: CancellationToken::new(),
             story_executor: None,
             quality_gate_runner: None,
+            retry_queue: Arc::new(RwLock::new(RetryQueue::new())),
+            failure_signatures: RwLock::new(HashMap::new()),
         })
     }
 
@@ -267,6 +389,8 @@ impl IterationLoop {
             cancellation_token: CancellationToken::new(),
             story_executor: None,
             quality_gate_runner: None,
+            retry_queue: Arc::new(RwLock::new(RetryQueue::new())),
+            failure_signatures: RwLock::new(HashMap::new()),
         })
     }
 
@@ -285,6 +409,12 @@ impl IterationLoop {
         self.state.read().await.clone()
     }
 
+    /// Get stories whose retries were abandoned as stalled
+    pub async fn stalled_stories(&self) -> Vec<StoryRetryInfo> {
+        let queue = self.retry_queue.read().await;
+        queue.stalled().into_iter().cloned().collect()
+    }
+
     /// Main iteration loop
     pub async fn run(
         &self,
@@ -369,6 +499,15 @@ impl IterationLoop {
 
                         if gate_result.passed {
                             self.mark_story_complete(&story_id).await;
+                        } else if let Some(reason) =
+                            self.detect_stall(&story_id, &gate_result).await
+                        {
+                            let error = self.handle_stall(&story_id, reason, &event_tx).await;
+                            if self.config.iteration.stop_on_failure {
+                                let mut state = self.state.write().await;
+                                state.fail(&error);
+                                return Err(IterationLoopError::QualityGateError(error));
+                            }
                         } else if self.can_retry(&story_id).await {
                             self.queue_retry(&story_id, &gate_result).await;
 
@@ -400,13 +539,17 @@ impl IterationLoop {
                     }
                 } else {
                     let error = result.error.unwrap_or_else(|| "Unknown error".to_string());
+                    let failure = QualityGateResult::fail(error.clone(), Vec::new());
 
-                    if self.can_retry(&story_id).await {
-                        self.queue_retry(
-                            &story_id,
-                            &QualityGateResult::fail(error.clone(), Vec::new()),
-                        )
-                        .await;
+                    if let Some(reason) = self.detect_stall(&story_id, &failure).await {
+                        let error = self.handle_stall(&story_id, reason, &event_tx).await;
+                        if self.config.iteration.stop_on_failure {
+                            let mut state = self.state.write().await;
+                            state.fail(&error);
+                            return Err(IterationLoopError::StoryExecutionError(error));
+                        }
+                    } else if self.can_retry(&story_id).await {
+                        self.queue_retry(&story_id, &failure).await;
                     } else {
                         self.mark_story_failed(&story_id, Some(error.clone())).await;
 
@@ -481,6 +624,14 @@ impl IterationLoop {
     async fn should_terminate(&self) -> bool {
         let state = self.state.read().await;
 
+        if self
+            .config
+            .max_total_iterations
+            .is_some_and(|max| state.iteration_count >= max)
+        {
+            return true;
+        }
+
         match self.config.iteration.mode {
             IterationMode::UntilComplete => self.all_stories_complete().await,
             IterationMode::MaxIterations(max) => state.iteration_count >= max,
@@ -584,7 +735,7 @@ impl IterationLoop {
                     }
 
                     // Get story details for context
-                    let (title, description, acceptance_criteria) = {
+                    let (title, description, acceptance_criteria, gate_selection) = {
                         let prd = prd.read().await;
                         match prd.get_story(&story_id) {
                             Some(s) => (
@@ -594,8 +745,14 @@ impl IterationLoop {
                                     .iter()
                                     .map(|ac| ac.description.clone())
                                     .collect::<Vec<_>>(),
+                                GateSelection::for_story(s),
+                            ),
+                            None => (
+                                String::new(),
+                                String::new(),
+                                Vec::new(),
+                                GateSelection::all(),
                             ),
-                            None => (String::new(), String::new(), Vec::new()),
                         }
                     };
 
@@ -621,6 +778,7 @@ impl IterationLoop {
                             story_description: description,
                             acceptance_criteria,
                             project_root,
+                            gate_selection,
                         };
 
                         // Run with timeout and cancellation
@@ -674,19 +832,32 @@ impl IterationLoop {
     ///
     /// If a `QualityGateRunnerFn` callback has been set via
     /// `with_quality_gate_runner()`, it is invoked with a
-    /// `QualityGateContext` containing the story ID and project root.
-    /// Otherwise, falls back to a hardcoded pass for backward compatibility.
+    /// `QualityGateContext` containing the story ID, project root and the
+    /// story's gate selection. Otherwise, falls back to a hardcoded pass for
+    /// backward compatibility. A selection matching no gates passes without
+    /// invoking the runner.
     async fn run_quality_gates(&self, story_id: &str) -> QualityGateResult {
-        if let Some(ref runner) = self.quality_gate_runner {
-            let context = QualityGateContext {
-                story_id: story_id.to_string(),
-                project_root: self.config.project_root.clone(),
-            };
-            runner(context).await
-        } else {
+        let gate_selection = {
+            let prd = self.prd.read().await;
+            prd.get_story(story_id)
+                .map(GateSelection::for_story)
+                .unwrap_or_default()
+        };
+
+        let mut result = match self.quality_gate_runner {
+            Some(ref runner) if !gate_selection.is_empty() => {
+                let context = QualityGateContext {
+                    story_id: story_id.to_string(),
+                    project_root: self.config.project_root.clone(),
+                    gate_selection: gate_selection.clone(),
+                };
+                runner(context).await
+            }
             // No runner configured -- default to pass (backward compat)
-            QualityGateResult::pass()
-        }
+            _ => QualityGateResult::pass(),
+        };
+        result.gate_selection = gate_selection;
+        result
     }
 
     /// Check if story can be retried
@@ -695,8 +866,83 @@ impl IterationLoop {
         state.can_retry(story_id, self.config.iteration.max_retries)
     }
 
+    /// Record a failure and check whether the story is making no progress.
+    ///
+    /// A failure's signature is its error plus findings; once the same
+    /// signature repeats `stall_window` times in a row the story is stalled
+    /// and the returned reason describes why.
+    async fn detect_stall(
+        &self,
+        story_id: &str,
+        gate_result: &QualityGateResult,
+    ) -> Option<(u32, String)> {
+        if self.config.stall_window == 0 {
+            return None;
+        }
+
+        let signature = format!("{:?}|{:?}", gate_result.error, gate_result.details);
+        let mut signatures = self.failure_signatures.write().await;
+        let entry = signatures
+            .entry(story_id.to_string())
+            .or_insert_with(|| (signature.clone(), 0));
+        if entry.0 == signature {
+            entry.1 += 1;
+        } else {
+            *entry = (signature, 1);
+        }
+
+        if entry.1 < self.config.stall_window {
+            return None;
+        }
+
+        let error = gate_result.error.as_deref().unwrap_or("unknown error");
+        Some((
+            entry.1,
+            format!(
+                "No progress after {} consecutive identical failures: {}",
+                entry.1, error
+            ),
+        ))
+    }
+
+    /// Fail a stalled story, record the stall and emit `Stalled`. Other
+    /// stories keep running.
+    ///
+    /// Returns the error describing the stall.
+    async fn handle_stall(
+        &self,
+        story_id: &str,
+        (consecutive_failures, reason): (u32, String),
+        event_tx: &mpsc::Sender<IterationEvent>,
+    ) -> String {
+        self.retry_queue
+            .write()
+            .await
+            .record_stall(story_id, reason.clone());
+        self.mark_story_failed(story_id, Some(reason.clone())).await;
+
+        let _ = event_tx
+            .send(IterationEvent::Stalled {
+                story_id: story_id.to_string(),
+                consecutive_failures,
+                reason: reason.clone(),
+            })
+            .await;
+
+        format!("Story {} stalled: {}", story_id, reason)
+    }
+
     /// Queue a story for retry
     async fn queue_retry(&self, story_id: &str, gate_result: &QualityGateResult) {
+        self.retry_queue.write().await.enqueue(
+            story_id,
+            FailureReason::Error,
+            gate_result
+                .error
+                .clone()
+                .unwrap_or_else(|| "Unknown error".to_string()),
+        );
+
         {
             let mut state = self.state.write().await;
             state.queue_retry(story_id, gate_result.error.clone());
@@ -713,6 +959,9 @@ impl IterationLoop {
 
     /// Mark a story as complete
     async fn mark_story_complete(&self, story_id: &str) {
+        self.retry_queue.write().await.remove(story_id);
+        self.failure_signatures.write().await.remove(story_id);
+
         {
             let mut state = self.state.write().await;
             state.mark_complete(story_id);
@@ -790,7 +1039,7 @@ impl IterationLoop {
 mod tests {
     use super::*;
     use crate::models::iteration::{IterationMode, IterationStatus};
-    use crate::models::prd::{AcceptanceCriteria, Story};
+    use crate::models::prd::{AcceptanceCriteria, Story, StoryType};
 
     fn create_test_prd() -> Prd {
         let mut prd = Prd::new("Test PRD");
@@ -892,6 +1141,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -950,6 +1200,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1001,6 +1252,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1044,6 +1296,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1083,6 +1336,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd).unwrap();
@@ -1128,6 +1382,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1182,6 +1437,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::from_state(config, prd, state)
@@ -1230,6 +1486,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1294,6 +1551,7 @@ mod tests {
             project_root: PathBuf::from("/my/project"),
             prd_path: PathBuf::from("/my/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1355,6 +1613,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1415,6 +1674,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1450,6 +1710,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd).unwrap();
@@ -1492,6 +1753,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1529,6 +1791,7 @@ mod tests {
             project_root: PathBuf::from("/test/project"),
             prd_path: PathBuf::from("/test/prd.json"),
             persist_state: false,
+            ..Default::default()
         };
 
         let loop_runner = IterationLoop::new(config, prd)
@@ -1559,6 +1822,325 @@ mod tests {
         rx.close();
     }
 
+    #[tokio::test]
+    async fn test_identical_gate_failures_stall_before_max_retries() {
+        let call_count: Arc<tokio::sync::Mutex<u32>> = Arc::new(tokio::sync::Mutex::new(0));
+        let count_clone = call_count.clone();
+
+        // Gate that always fails with exactly the same findings
+        let gate_runner: QualityGateRunnerFn = Arc::new(move |_ctx: QualityGateContext| {
+            let count = count_clone.clone();
+            Box::pin(async move {
+                *count.lock().await += 1;
+                QualityGateResult::fail(
+                    "Lint errors found".to_string(),
+                    vec!["src/lib.rs:3 - unused import".to_string()],
+                )
+            })
+        });
+
+        let mut prd = Prd::new("Test PRD");
+        prd.add_story(Story::new("S001", "Never Passes"));
+
+        let config = IterationLoopConfig {
+            iteration: IterationConfig {
+                mode: IterationMode::UntilComplete,
+                poll_interval_seconds: 0,
+                run_quality_gates: true,
+                stop_on_failure: false,
+                max_retries: 10,
+                story_timeout_seconds: 60,
+                ..Default::default()
+            },
+            project_root: PathBuf::from("/test/project"),
+            prd_path: PathBuf::from("/test/prd.json"),
+            persist_state: false,
+            stall_window: 3,
+            max_total_iterations: Some(20),
+        };
+
+        let loop_runner = IterationLoop::new(config, prd)
+            .unwrap()
+            .with_quality_gate_runner(gate_runner);
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let result = loop_runner.run(tx).await.unwrap();
+
+        // Stalled after the stall window, well before max_retries
+        assert_eq!(*call_count.lock().await, 3);
+        assert_eq!(result.iteration_count, 3);
+        assert_eq!(result.failed_stories, 1);
+        assert!(!result.success);
+
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+        assert!(events.iter().any(|e| matches!(
+            e,
+            IterationEvent::Stalled { story_id, consecutive_failures: 3, .. } if story_id == "S001"
+        )));
+
+        let stalled = loop_runner.stalled_stories().await;
+        assert_eq!(stalled.len(), 1);
+        assert_eq!(stalled[0].story_id, "S001");
+        assert_eq!(stalled[0].retry_count, 2);
+        assert!(stalled[0]
+            .stall_reason
+            .as_deref()
+            .unwrap()
+            .contains("Lint errors found"));
+
+        let state = loop_runner.get_state().await;
+        assert_eq!(state.failed_stories, vec!["S001".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_stalled_story_does_not_stop_other_stories() {
+        // S001 always fails identically; S002 passes on its second attempt
+        let attempts: Arc<tokio::sync::Mutex<HashMap<String, u32>>> =
+            Arc::new(tokio::sync::Mutex::new(HashMap::new()));
+        let attempts_clone = attempts.clone();
+        let gate_runner: QualityGateRunnerFn = Arc::new(move |ctx: QualityGateContext| {
+            let attempts = attempts_clone.clone();
+            Box::pin(async move {
+                let mut attempts = attempts.lock().await;
+                let n = attempts.entry(ctx.story_id.clone()).or_insert(0);
+                *n += 1;
+                if ctx.story_id == "S002" && *n >= 2 {
+                    QualityGateResult::pass()
+                } else {
+                    QualityGateResult::fail(
+                        format!("{} gate failed", ctx.story_id),
+                        vec!["same finding".to_string()],
+                    )
+                }
+            })
+        });
+
+        let mut prd = Prd::new("Test PRD");
+        prd.add_story(Story::new("S001", "Never Passes"));
+        prd.add_story(Story::new("S002", "Passes On Retry"));
+
+        let config = IterationLoopConfig {
+            iteration: IterationConfig {
+                mode: IterationMode::UntilComplete,
+                poll_interval_seconds: 0,
+                run_quality_gates: true,
+                stop_on_failure: false,
+                max_retries: 10,
+                story_timeout_seconds: 60,
+                ..Default::default()
+            },
+            project_root: PathBuf::from("/test/project"),
+            prd_path: PathBuf::from("/test/prd.json"),
+            persist_state: false,
+            stall_window: 2,
+            max_total_iterations: Some(20),
+        };
+
+        let loop_runner = IterationLoop::new(config, prd)
+            .unwrap()
+            .with_quality_gate_runner(gate_runner);
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let result = loop_runner.run(tx).await.unwrap();
+
+        assert_eq!(result.completed_stories, 1);
+        assert_eq!(result.failed_stories, 1);
+        let state = loop_runner.get_state().await;
+        assert!(state.completed_stories.contains(&"S002".to_string()));
+        assert_eq!(state.failed_stories, vec!["S001".to_string()]);
+        assert_eq!(loop_runner.stalled_stories().await.len(), 1);
+
+        rx.close();
+    }
+
+    #[tokio::test]
+    async fn test_changing_failures_are_not_a_stall() {
+        let call_count: Arc<tokio::sync::Mutex<u32>> = Arc::new(tokio::sync::Mutex::new(0));
+        let count_clone = call_count.clone();
+
+        // Findings shrink each attempt, i.e. the story is making progress
+        let gate_runner: QualityGateRunnerFn = Arc::new(move |_ctx: QualityGateContext| {
+            let count = count_clone.clone();
+            Box::pin(async move {
+                let mut c = count.lock().await;
+                *c += 1;
+                if *c < 4 {
+                    QualityGateResult::fail(
+                        "Lint errors found".to_string(),
+                        vec![format!("{} warnings left", 4 - *c)],
+                    )
+                } else {
+                    QualityGateResult::pass()
+                }
+            })
+        });
+
+        let mut prd = Prd::new("Test PRD");
+        prd.add_story(Story::new("S001", "Converging"));
+
+        let config = IterationLoopConfig {
+            iteration: IterationConfig {
+                mode: IterationMode::UntilComplete,
+                poll_interval_seconds: 0,
+                run_quality_gates: true,
+                max_retries: 5,
+                story_timeout_seconds: 60,
+                ..Default::default()
+            },
+            project_root: PathBuf::from("/test/project"),
+            prd_path: PathBuf::from("/test/prd.json"),
+            persist_state: false,
+            stall_window: 2,
+            max_total_iterations: None,
+        };
+
+        let loop_runner = IterationLoop::new(config, prd)
+            .unwrap()
+            .with_quality_gate_runner(gate_runner);
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let result = loop_runner.run(tx).await.unwrap();
+
+        assert!(result.success);
+        assert_eq!(*call_count.lock().await, 4);
+        assert!(loop_runner.stalled_stories().await.is_empty());
+
+        rx.close();
+    }
+
+    #[tokio::test]
+    async fn test_max_total_iterations_caps_loop() {
+        let gate_runner: QualityGateRunnerFn = Arc::new(|_ctx: QualityGateContext| {
+            Box::pin(async { QualityGateResult::fail("flaky".to_string(), Vec::new()) })
+        });
+
+        let mut prd = Prd::new("Test PRD");
+        prd.add_story(Story::new("S001", "Never Passes"));
+
+        let config = IterationLoopConfig {
+            iteration: IterationConfig {
+                mode: IterationMode::UntilComplete,
+                poll_interval_seconds: 0,
+                run_quality_gates: true,
+                max_retries: 10,
+                story_timeout_seconds: 60,
+                ..Default::default()
+            },
+            project_root: PathBuf::from("/test/project"),
+            prd_path: PathBuf::from("/test/prd.json"),
+            persist_state: false,
+            stall_window: 0,
+            max_total_iterations: Some(2),
+        };
+
+        let loop_runner = IterationLoop::new(config, prd)
+            .unwrap()
+            .with_quality_gate_runner(gate_runner);
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let result = loop_runner.run(tx).await.unwrap();
+
+        assert_eq!(result.iteration_count, 2);
+        assert_eq!(result.completed_stories, 0);
+
+        rx.close();
+    }
+
+    #[test]
+    fn test_gate_selection_for_story() {
+        let code = Story::new("S001", "Add login").with_story_type(StoryType::Feature);
+        assert_eq!(GateSelection::for_story(&code), GateSelection::all());
+
+        let docs = Story::new("S002", "Document login").with_story_type(StoryType::Docs);
+        let selection = GateSelection::for_story(&docs);
+        assert!(!selection.includes("test"));
+        assert!(!selection.includes("lint"));
+        assert!(selection.includes("typecheck"));
+
+        // Explicit metadata wins over the story type
+        let mut explicit =
+            Story::new("S003", "Docs with examples").with_story_type(StoryType::Docs);
+        explicit
+            .metadata
+            .insert("quality_gates".to_string(), serde_json::json!(["test"]));
+        let selection = GateSelection::for_story(&explicit);
+        assert!(selection.includes("test"));
+        assert!(!selection.includes("typecheck"));
+    }
+
+    #[tokio::test]
+    async fn test_docs_story_skips_test_and_lint_gates() {
+        const GATES: &[&str] = &["typecheck", "test", "lint"];
+
+        let ran_gates: Arc<tokio::sync::Mutex<Vec<(String, String)>>> =
+            Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        let ran_clone = ran_gates.clone();
+
+        // Simulates a pipeline that honours the selection it is given
+        let gate_runner: QualityGateRunnerFn = Arc::new(move |ctx: QualityGateContext| {
+            let ran = ran_clone.clone();
+            Box::pin(async move {
+                let mut ran = ran.lock().await;
+                for gate in GATES {
+                    if ctx.gate_selection.includes(gate) {
+                        ran.push((ctx.story_id.clone(), gate.to_string()));
+                    }
+                }
+                QualityGateResult::pass()
+            })
+        });
+
+        let mut prd = Prd::new("Test PRD");
+        prd.add_story(Story::new("S001", "Implement login").with_story_type(StoryType::Feature));
+        prd.add_story(Story::new("S002", "Document login").with_story_type(StoryType::Docs));
+
+        let config = IterationLoopConfig {
+            iteration: IterationConfig {
+                mode: IterationMode::UntilComplete,
+                poll_interval_seconds: 0,
+                run_quality_gates: true,
+                story_timeout_seconds: 60,
+                ..Default::default()
+            },
+            project_root: PathBuf::from("/test/project"),
+            prd_path: PathBuf::from("/test/prd.json"),
+            persist_state: false,
+            ..Default::default()
+        };
+
+        let loop_runner = IterationLoop::new(config, prd)
+            .unwrap()
+            .with_quality_gate_runner(gate_runner);
+
+        let docs_result = loop_runner.run_quality_gates("S002").await;
+        assert!(docs_result.passed);
+        assert!(!docs_result.gate_selection.includes("test"));
+        assert!(!docs_result.gate_selection.includes("lint"));
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let result = loop_runner.run(tx).await.unwrap();
+        assert!(result.success);
+
+        let ran = ran_gates.lock().await;
+        let gates_for = |story: &str| -> Vec<&str> {
+            let mut gates: Vec<&str> = ran
+                .iter()
+                .filter(|(id, _)| id == story)
+                .map(|(_, gate)| gate.as_str())
+                .collect();
+            gates.sort_unstable();
+            gates.dedup();
+            gates
+        };
+        assert_eq!(gates_for("S001"), vec!["lint", "test", "typecheck"]);
+        assert_eq!(gates_for("S002"), vec!["typecheck"]);
+
+        rx.close();
+    }
+
     #[tokio::test]
     async fn test_quality_gate_result_constructors() {
         let pass = QualityGateResult::pass();
@@ -6,8 +6,9 @@ mod loop_runner;
 mod story_executor;
 
 pub use loop_runner::{
-    IterationEvent, IterationLoop, IterationLoopConfig, IterationLoopError, QualityGateContext,
-    QualityGateResult, QualityGateRunnerFn, StoryExecutionContext, StoryExecutorFn,
+    GateSelection, IterationEvent, IterationLoop, IterationLoopConfig, IterationLoopError,
+    QualityGateContext, QualityGateResult, QualityGateRunnerFn, StoryExecutionContext,
+    StoryExecutorFn,
 };
 pub use story_executor::{
     RetryQueue, StoryExecutionResult, StoryExecutor, StoryExecutorConfig, StoryExecutorError,
@@ -444,6 +444,9 @@ pub struct StoryRetryInfo {
     pub last_failure_reason: Option<FailureReason>,
     /// Last error message
     pub last_error: Option<String>,
+    /// Why retrying was abandoned as making no progress, if it was
+    #[serde(default)]
+    pub stall_reason: Option<String>,
     /// Queued at timestamp
     pub queued_at: String,
 }
@@ -456,6 +459,7 @@ impl StoryRetryInfo {
             retry_count: 0,
             last_failure_reason: None,
             last_error: None,
+            stall_reason: None,
             queued_at: chrono::Utc::now().to_rfc3339(),
         }
     }
@@ -474,7 +478,7 @@ impl StoryRetryInfo {
 }
 
 /// Retry queue for failed stories
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct RetryQueue {
     /// Stories queued for retry
     queue: Vec<StoryRetryInfo>,
@@ -500,6 +504,28 @@ impl RetryQueue {
         }
     }
 
+    /// Record that retrying a story stalled (no progress between attempts)
+    pub fn record_stall(&mut self, story_id: impl Into<String>, reason: impl Into<String>) {
+        let story_id = story_id.into();
+        let reason = Some(reason.into());
+
+        if let Some(info) = self.queue.iter_mut().find(|i| i.story_id == story_id) {
+            info.stall_reason = reason;
+        } else {
+            let mut info = StoryRetryInfo::new(story_id);
+            info.stall_reason = reason;
+            self.queue.push(info);
+        }
+    }
+
+    /// Get all stories whose retries stalled
+    pub fn stalled(&self) -> Vec<&StoryRetryInfo> {
+        self.queue
+            .iter()
+            .filter(|i| i.stall_reason.is_some())
+            .collect()
+    }
+
     /// Get next story to retry
     pub fn dequeue(&mut self) -> Option<StoryRetryInfo> {
         if self.queue.is_empty() {
@@ -629,6 +655,24 @@ mod tests {
         assert!(queue.is_empty());
     }
 
+    #[test]
+    fn test_retry_queue_records_stall() {
+        let mut queue = RetryQueue::new();
+        queue.enqueue("S001", FailureReason::Error, "Lint failed".to_string());
+        queue.enqueue("S002", FailureReason::Error, "Tests failed".to_string());
+
+        queue.record_stall("S001", "Same lint findings 3 times");
+
+        let stalled = queue.stalled();
+        assert_eq!(stalled.len(), 1);
+        assert_eq!(stalled[0].story_id, "S001");
+        assert_eq!(stalled[0].retry_count, 1);
+        assert_eq!(
+            stalled[0].stall_reason.as_deref(),
+            Some("Same lint findings 3 times")
+        );
+    }
+
     #[tokio::test]
     async fn test_story_executor_creation() {
         let config = StoryExecutorConfig {
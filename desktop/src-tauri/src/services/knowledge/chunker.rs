@@ -4,6 +4,7 @@
 //! - `ParagraphChunker`: splits on blank lines and markdown headers
 //! - `TokenChunker`: fixed token-count windows with configurable overlap
 //! - `SemanticChunker`: splits on topic boundaries using embedding similarity
+//! - `MarkdownChunker`: splits markdown on heading boundaries with breadcrumbs
 //!
 //! ## Usage
 //!
@@ -102,6 +103,99 @@ pub struct Chunk {
     pub metadata: HashMap<String, String>,
 }
 
+// ---------------------------------------------------------------------------
+// Source spans
+// ---------------------------------------------------------------------------
+
+/// Chunk metadata keys recording where a chunk came from in its document.
+pub const SPAN_BYTE_START_KEY: &str = "span_byte_start";
+pub const SPAN_BYTE_END_KEY: &str = "span_byte_end";
+pub const SPAN_LINE_START_KEY: &str = "span_line_start";
+pub const SPAN_LINE_END_KEY: &str = "span_line_end";
+
+/// Provenance of a chunk: source document path plus the byte range
+/// `[byte_start, byte_end)` and 1-based inclusive line range it covers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceCitation {
+    pub source_path: Option<String>,
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub line_start: usize,
+    pub line_end: usize,
+}
+
+impl SourceCitation {
+    /// Read a citation back from chunk metadata written by
+    /// [`annotate_source_spans`].
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Option<Self> {
+        let get = |key: &str| metadata.get(key).and_then(|v| v.parse::<usize>().ok());
+        Some(Self {
+            source_path: metadata.get("source_path").cloned(),
+            byte_start: get(SPAN_BYTE_START_KEY)?,
+            byte_end: get(SPAN_BYTE_END_KEY)?,
+            line_start: get(SPAN_LINE_START_KEY)?,
+            line_end: get(SPAN_LINE_END_KEY)?,
+        })
+    }
+}
+
+/// Record each chunk's exact source span in its metadata.
+///
+/// Chunk text may be trimmed or whitespace-normalized relative to the
+/// document, so the span is located from the chunk's `char_offset` hint:
+/// first as an exact substring, then by matching its words in order.
+/// Chunks that cannot be located are left unannotated.
+pub fn annotate_source_spans(document: &Document, chunks: &mut [Chunk]) {
+    let content = document.content.as_str();
+    for chunk in chunks.iter_mut() {
+        let Some((start, end)) = locate_span(content, &chunk.content, chunk.char_offset) else {
+            continue;
+        };
+        let line_start = content[..start].matches('\n').count() + 1;
+        let line_end = line_start + content[start..end].matches('\n').count();
+        chunk
+            .metadata
+            .insert(SPAN_BYTE_START_KEY.to_string(), start.to_string());
+        chunk
+            .metadata
+            .insert(SPAN_BYTE_END_KEY.to_string(), end.to_string());
+        chunk
+            .metadata
+            .insert(SPAN_LINE_START_KEY.to_string(), line_start.to_string());
+        chunk
+            .metadata
+            .insert(SPAN_LINE_END_KEY.to_string(), line_end.to_string());
+    }
+}
+
+fn locate_span(content: &str, text: &str, hint: usize) -> Option<(usize, usize)> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+    let mut hint = hint.min(content.len());
+    while !content.is_char_boundary(hint) {
+        hint -= 1;
+    }
+
+    if let Some(pos) = content[hint..].find(text) {
+        return Some((hint + pos, hint + pos + text.len()));
+    }
+    if let Some(pos) = content.find(text) {
+        return Some((pos, pos + text.len()));
+    }
+
+    // Whitespace-normalized chunks (e.g. token windows): match words in order.
+    let mut cursor = hint;
+    let mut start = None;
+    for word in text.split_whitespace() {
+        let pos = cursor + content[cursor..].find(word)?;
+        start.get_or_insert(pos);
+        cursor = pos + word.len();
+    }
+    start.map(|s| (s, cursor))
+}
+
 // ---------------------------------------------------------------------------
 // Chunker trait
 // ---------------------------------------------------------------------------
@@ -139,6 +233,11 @@ pub enum ChunkerConfig {
         /// Minimum sentences per chunk (default 2).
         min_sentences: usize,
     },
+    /// Split markdown on heading boundaries, merging small sections.
+    Markdown {
+        /// Target characters per chunk (default 1000).
+        target_chunk_size: usize,
+    },
     /// Split source code using tree-sitter symbol boundaries.
     Code {
         /// Programming language hint (e.g. "rust", "python").
@@ -170,6 +269,9 @@ impl ChunkerConfig {
                 threshold,
                 min_sentences,
             } => Box::new(SemanticChunker::new(*threshold, *min_sentences)),
+            ChunkerConfig::Markdown { target_chunk_size } => {
+                Box::new(MarkdownChunker::new(*target_chunk_size))
+            }
             ChunkerConfig::Code { language } => match language {
                 Some(lang) => Box::new(super::code_chunker::CodeChunker::with_language(lang)),
                 None => Box::new(super::code_chunker::CodeChunker::new()),
@@ -347,6 +449,253 @@ fn split_by_sentences(text: &str, max_size: usize, base_offset: usize) -> Vec<(u
     result
 }
 
+// ---------------------------------------------------------------------------
+// MarkdownChunker
+// ---------------------------------------------------------------------------
+
+/// Chunk metadata key holding the heading breadcrumb, e.g. `# A > ## B`.
+pub const HEADING_PATH_KEY: &str = "heading_path";
+
+/// Splits markdown documents on heading boundaries.
+///
+/// Each chunk starts at a heading (or at a paragraph break inside an
+/// oversized section) and records its heading breadcrumb under
+/// [`HEADING_PATH_KEY`]. Adjacent small sections are merged up to
+/// `target_chunk_size`, but never across an H1. Fenced code blocks are
+/// never split, even when they exceed the target size.
+///
+/// Non-markdown documents are delegated to a [`ParagraphChunker`].
+pub struct MarkdownChunker {
+    target_chunk_size: usize,
+    fallback: ParagraphChunker,
+}
+
+/// A contiguous byte range of the document under one heading path.
+struct MarkdownSpan {
+    start: usize,
+    end: usize,
+    headings: Vec<String>,
+    /// Incremented at every H1; spans in different groups never merge.
+    h1_group: usize,
+    /// Whether the span covers a whole section rather than a split piece.
+    whole: bool,
+}
+
+impl MarkdownChunker {
+    pub fn new(target_chunk_size: usize) -> Self {
+        Self {
+            target_chunk_size: target_chunk_size.max(1),
+            fallback: ParagraphChunker::new(target_chunk_size),
+        }
+    }
+
+    fn is_markdown(document: &Document) -> bool {
+        let ext = document
+            .metadata
+            .get("source_type")
+            .cloned()
+            .or_else(|| {
+                document
+                    .source_path
+                    .as_deref()
+                    .and_then(|p| std::path::Path::new(p).extension())
+                    .and_then(|e| e.to_str())
+                    .map(|e| e.to_string())
+            })
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        matches!(ext.as_str(), "md" | "markdown" | "mdx")
+    }
+
+    /// Split the document into heading-delimited sections.
+    fn sections(content: &str) -> Vec<MarkdownSpan> {
+        let mut sections = Vec::new();
+        let mut stack: Vec<(usize, String)> = Vec::new();
+        let mut fence: Option<String> = None;
+        let mut h1_group = 0usize;
+        let mut current = MarkdownSpan {
+            start: 0,
+            end: 0,
+            headings: Vec::new(),
+            h1_group,
+            whole: true,
+        };
+        let mut pos = 0usize;
+
+        for line in content.split_inclusive('\n') {
+            if update_fence(&mut fence, line) || fence.is_some() {
+                pos += line.len();
+                continue;
+            }
+            if let Some((level, title)) = parse_heading(line) {
+                if pos > current.start {
+                    current.end = pos;
+                    sections.push(current);
+                }
+                stack.retain(|(l, _)| *l < level);
+                stack.push((level, format!("{} {}", "#".repeat(level), title)));
+                if level == 1 {
+                    h1_group += 1;
+                }
+                current = MarkdownSpan {
+                    start: pos,
+                    end: pos,
+                    headings: stack.iter().map(|(_, h)| h.clone()).collect(),
+                    h1_group,
+                    whole: true,
+                };
+            }
+            pos += line.len();
+        }
+        if pos > current.start {
+            current.end = pos;
+            sections.push(current);
+        }
+        sections
+    }
+
+    /// Split an oversized section at blank lines outside code fences and
+    /// pack the resulting blocks up to the target size.
+    fn split_section(&self, content: &str, section: MarkdownSpan) -> Vec<MarkdownSpan> {
+        let mut blocks: Vec<(usize, usize)> = Vec::new();
+        let mut fence: Option<String> = None;
+        let mut block_start = section.start;
+        let mut pos = section.start;
+        for line in content[section.start..section.end].split_inclusive('\n') {
+            let in_fence = update_fence(&mut fence, line) || fence.is_some();
+            pos += line.len();
+            if !in_fence && line.trim().is_empty() {
+                blocks.push((block_start, pos));
+                block_start = pos;
+            }
+        }
+        if pos > block_start {
+            blocks.push((block_start, pos));
+        }
+
+        let mut pieces: Vec<MarkdownSpan> = Vec::new();
+        for (start, end) in blocks {
+            match pieces.last_mut() {
+                Some(last) if end - last.start <= self.target_chunk_size => last.end = end,
+                _ => pieces.push(MarkdownSpan {
+                    start,
+                    end,
+                    headings: section.headings.clone(),
+                    h1_group: section.h1_group,
+                    whole: false,
+                }),
+            }
+        }
+        pieces
+    }
+}
+
+/// Track fenced code blocks. Returns `true` when `line` opens or closes a fence.
+fn update_fence(fence: &mut Option<String>, line: &str) -> bool {
+    let trimmed = line.trim_start();
+    match fence {
+        Some(marker) => {
+            if trimmed.starts_with(marker.as_str()) && trimmed[marker.len()..].trim().is_empty() {
+                *fence = None;
+                return true;
+            }
+            false
+        }
+        None => {
+            for ch in ['`', '~'] {
+                let run = trimmed.chars().take_while(|c| *c == ch).count();
+                if run >= 3 {
+                    *fence = Some(ch.to_string().repeat(run));
+                    return true;
+                }
+            }
+            false
+        }
+    }
+}
+
+/// Parse an ATX heading (`# Title` .. `###### Title`) into level and text.
+fn parse_heading(line: &str) -> Option<(usize, &str)> {
+    let line = line.trim_end();
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') && !rest.starts_with('\t') {
+        return None;
+    }
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+impl Chunker for MarkdownChunker {
+    fn chunk(&self, document: &Document) -> AppResult<Vec<Chunk>> {
+        if !Self::is_markdown(document) {
+            return self.fallback.chunk(document);
+        }
+        let content = &document.content;
+        if content.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut units = Vec::new();
+        for section in Self::sections(content) {
+            if section.end - section.start > self.target_chunk_size {
+                units.extend(self.split_section(content, section));
+            } else {
+                units.push(section);
+            }
+        }
+
+        let mut merged: Vec<MarkdownSpan> = Vec::new();
+        for unit in units {
+            if let Some(last) = merged.last_mut() {
+                if last.whole
+                    && unit.whole
+                    && last.h1_group == unit.h1_group
+                    && unit.end - last.start <= self.target_chunk_size
+                {
+                    let common = last
+                        .headings
+                        .iter()
+                        .zip(&unit.headings)
+                        .take_while(|(a, b)| a == b)
+                        .count();
+                    last.headings.truncate(common);
+                    last.end = unit.end;
+                    continue;
+                }
+            }
+            merged.push(unit);
+        }
+
+        let mut chunks = Vec::new();
+        for span in merged {
+            let raw = &content[span.start..span.end];
+            let text = raw.trim();
+            if text.is_empty() {
+                continue;
+            }
+            let leading = raw.len() - raw.trim_start().len();
+            let mut metadata = document.metadata.clone();
+            if !span.headings.is_empty() {
+                metadata.insert(HEADING_PATH_KEY.to_string(), span.headings.join(" > "));
+            }
+            let index = chunks.len();
+            chunks.push(Chunk {
+                chunk_id: format!("{}:{}", document.id, index),
+                document_id: document.id.clone(),
+                content: text.to_string(),
+                index,
+                char_offset: span.start + leading,
+                metadata,
+            });
+        }
+
+        Ok(chunks)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // TokenChunker
 // ---------------------------------------------------------------------------
@@ -1004,6 +1353,18 @@ mod tests {
         assert!(!chunks.is_empty());
     }
 
+    #[test]
+    fn chunker_config_markdown_build() {
+        let config = ChunkerConfig::Markdown {
+            target_chunk_size: 500,
+        };
+        let chunker = config.build();
+        let doc = Document::with_source("d1", "# A\n\nHello.", "/docs/a.md");
+        let chunks = chunker.chunk(&doc).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].metadata.get(HEADING_PATH_KEY).unwrap(), "# A");
+    }
+
     #[test]
     fn chunker_config_default_is_paragraph() {
         let config = ChunkerConfig::default();
@@ -1034,6 +1395,127 @@ mod tests {
     // split_into_sentences tests
     // ======================================================================
 
+    // ======================================================================
+    // MarkdownChunker tests
+    // ======================================================================
+
+    const STRUCTURED_MD: &str = "# Guide\n\nIntro.\n\n## Install\n\nRun it.\n\n\
+        ## Usage\n\nCall the binary:\n\n```sh\n# not a heading\n\nplan-cascade run\n```\n\n\
+        # Reference\n\n## API\n\nDetails about the API.\n";
+
+    #[test]
+    fn markdown_chunker_aligns_chunks_to_headings() {
+        let doc = Document::with_source("d1", STRUCTURED_MD, "/docs/guide.md");
+        let chunks = MarkdownChunker::new(40).chunk(&doc).unwrap();
+
+        for chunk in &chunks {
+            assert!(
+                !(chunk.content.contains("# Guide") && chunk.content.contains("# Reference")),
+                "merged across an H1: {:?}",
+                chunk.content
+            );
+            assert_eq!(
+                &STRUCTURED_MD[chunk.char_offset..chunk.char_offset + chunk.content.len()],
+                chunk.content
+            );
+        }
+
+        // Tiny sections under the same H1 merge, keeping the shared breadcrumb.
+        assert!(chunks[0].content.starts_with("# Guide"));
+        assert!(chunks[0].content.contains("## Install"));
+        assert_eq!(chunks[0].metadata.get(HEADING_PATH_KEY).unwrap(), "# Guide");
+
+        let usage = chunks
+            .iter()
+            .find(|c| c.content.starts_with("## Usage"))
+            .expect("usage chunk");
+        assert_eq!(
+            usage.metadata.get(HEADING_PATH_KEY).unwrap(),
+            "# Guide > ## Usage"
+        );
+
+        let reference = chunks
+            .iter()
+            .position(|c| c.content.starts_with("# Reference"))
+            .expect("reference chunk");
+        assert!(chunks[..reference]
+            .iter()
+            .all(|c| c.metadata[HEADING_PATH_KEY].starts_with("# Guide")));
+        // Headings inside code fences are ignored.
+        assert!(chunks
+            .iter()
+            .all(|c| !c.metadata[HEADING_PATH_KEY].contains("not a heading")));
+    }
+
+    #[test]
+    fn markdown_chunker_keeps_code_fences_intact() {
+        let doc = Document::with_source("d1", STRUCTURED_MD, "/docs/guide.md");
+        let chunks = MarkdownChunker::new(20).chunk(&doc).unwrap();
+
+        let fence = chunks
+            .iter()
+            .find(|c| c.content.contains("```sh"))
+            .expect("fence chunk");
+        assert!(fence
+            .content
+            .contains("# not a heading\n\nplan-cascade run\n```"));
+        for chunk in &chunks {
+            assert_eq!(chunk.content.matches("```").count() % 2, 0);
+        }
+
+        let api = chunks
+            .iter()
+            .find(|c| c.content.starts_with("## API"))
+            .expect("api chunk");
+        assert_eq!(
+            api.metadata.get(HEADING_PATH_KEY).unwrap(),
+            "# Reference > ## API"
+        );
+    }
+
+    #[test]
+    fn markdown_chunker_delegates_non_markdown() {
+        let doc = Document::with_source("d1", "# Title\n\nBody.", "/docs/notes.txt");
+        let chunks = MarkdownChunker::new(500).chunk(&doc).unwrap();
+        assert!(chunks
+            .iter()
+            .all(|c| !c.metadata.contains_key(HEADING_PATH_KEY)));
+    }
+
+    #[test]
+    fn annotate_source_spans_maps_back_to_document() {
+        let content =
+            "# Title\n\n  Indented paragraph one.\n\nSecond   paragraph\nspans two lines.";
+        let mut doc = Document::with_source("d1", content, "/docs/guide.md");
+        doc.metadata
+            .insert("source_path".to_string(), "/docs/guide.md".to_string());
+
+        for chunker in [
+            Box::new(ParagraphChunker::new(100)) as Box<dyn Chunker>,
+            Box::new(TokenChunker::new(3, 1)),
+        ] {
+            let mut chunks = chunker.chunk(&doc).unwrap();
+            annotate_source_spans(&doc, &mut chunks);
+            for chunk in &chunks {
+                let citation = SourceCitation::from_metadata(&chunk.metadata).unwrap();
+                assert_eq!(citation.source_path.as_deref(), Some("/docs/guide.md"));
+                let span = &content[citation.byte_start..citation.byte_end];
+                let normalized: Vec<&str> = span.split_whitespace().collect();
+                let expected: Vec<&str> = chunk.content.split_whitespace().collect();
+                assert_eq!(normalized, expected);
+            }
+        }
+
+        let mut chunks = ParagraphChunker::new(100).chunk(&doc).unwrap();
+        annotate_source_spans(&doc, &mut chunks);
+        let last = SourceCitation::from_metadata(&chunks.last().unwrap().metadata).unwrap();
+        assert_eq!((last.line_start, last.line_end), (5, 6));
+        assert_eq!(
+            &content[last.byte_start..last.byte_end],
+            "Second   paragraph\nspans two lines."
+        );
+    }
+
     #[test]
     fn split_sentences_basic() {
         let sentences = split_into_sentences("Hello world. How are you? I am fine!");
@@ -17,6 +17,7 @@ use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 use std::sync::Arc;
 
+use crate::services::knowledge::chunker::HEADING_PATH_KEY;
 use crate::services::knowledge::pipeline::{RagPipeline, ScopedDocumentRef};
 use crate::utils::error::AppResult;
 
@@ -39,6 +40,9 @@ pub struct ContextChunk {
     pub collection_name: String,
     /// Relevance score (0.0 to 1.0).
     pub relevance_score: f32,
+    /// Markdown heading breadcrumb of the chunk, e.g. `# A > ## B`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heading_path: Option<String>,
 }
 
 /// Configuration for knowledge context retrieval.
@@ -56,6 +60,9 @@ pub struct KnowledgeContextConfig {
     /// Optional: only keep results from these scoped document refs. `None` = keep all.
     #[serde(default)]
     pub document_refs: Option<Vec<ScopedDocumentRef>>,
+    /// Maximum estimated tokens of injected chunk content. `None` = unbounded.
+    #[serde(default)]
+    pub max_context_tokens: Option<usize>,
 }
 
 impl Default for KnowledgeContextConfig {
@@ -66,8 +73,83 @@ impl Default for KnowledgeContextConfig {
             minimum_relevance_score: 0.3,
             collection_ids: None,
             document_refs: None,
+            max_context_tokens: None,
+        }
+    }
+}
+
+/// Chunks selected for injection, plus what was left out and why.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ContextSelection {
+    /// Selected chunks, highest relevance first.
+    pub chunks: Vec<ContextChunk>,
+    /// Estimated tokens of each selected chunk, parallel to `chunks`.
+    pub chunk_tokens: Vec<usize>,
+    /// Sum of `chunk_tokens`.
+    pub injected_tokens: usize,
+    /// Retrieved chunks dropped for scoring below the relevance threshold.
+    pub below_threshold: usize,
+    /// Relevant chunks dropped because the token budget was exhausted.
+    pub over_budget: usize,
+    /// Relevant chunks dropped by the `max_context_chunks` cap.
+    pub over_chunk_limit: usize,
+}
+
+/// Rough token estimate (~4 characters per token).
+fn estimate_tokens(text: &str) -> usize {
+    (text.chars().count() + 3) / 4
+}
+
+/// Select the highest-scoring chunks that clear the relevance threshold and
+/// fit within the chunk count and token budget.
+///
+/// Chunks are taken in descending score order; selection stops at the first
+/// chunk that would exceed the budget so that a lower-scoring chunk never
+/// displaces a higher-scoring one.
+pub fn select_within_budget(
+    mut chunks: Vec<ContextChunk>,
+    config: &KnowledgeContextConfig,
+) -> ContextSelection {
+    let mut selection = ContextSelection::default();
+
+    let before = chunks.len();
+    chunks.retain(|c| c.relevance_score >= config.minimum_relevance_score);
+    selection.below_threshold = before - chunks.len();
+
+    chunks.sort_by(|a, b| {
+        b.relevance_score
+            .partial_cmp(&a.relevance_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Deduplicate by content hash (keep highest-scored version).
+    // Uses SHA-256 instead of a 200-char prefix to avoid false
+    // dedup of chunks that share a prefix but differ later.
+    let mut seen: HashSet<[u8; 32]> = HashSet::new();
+    chunks.retain(|chunk| {
+        let hash: [u8; 32] = Sha256::digest(chunk.content.as_bytes()).into();
+        seen.insert(hash)
+    });
+
+    let relevant = chunks.len();
+    for chunk in chunks {
+        if selection.chunks.len() >= config.max_context_chunks {
+            selection.over_chunk_limit = relevant - selection.chunks.len();
+            break;
+        }
+        let tokens = estimate_tokens(&chunk.content);
+        if let Some(budget) = config.max_context_tokens {
+            if selection.injected_tokens + tokens > budget {
+                selection.over_budget = relevant - selection.chunks.len();
+                break;
+            }
         }
+        selection.injected_tokens += tokens;
+        selection.chunk_tokens.push(tokens);
+        selection.chunks.push(chunk);
     }
+
+    selection
 }
 
 // ---------------------------------------------------------------------------
@@ -98,15 +180,29 @@ impl KnowledgeContextProvider {
         query: &str,
         config: &KnowledgeContextConfig,
     ) -> AppResult<Vec<ContextChunk>> {
+        Ok(self
+            .query_for_context_with_report(project_id, query, config)
+            .await?
+            .chunks)
+    }
+
+    /// Like [`Self::query_for_context`], but also reports how many chunks
+    /// were dropped by the relevance threshold and the token budget.
+    pub async fn query_for_context_with_report(
+        &self,
+        project_id: &str,
+        query: &str,
+        config: &KnowledgeContextConfig,
+    ) -> AppResult<ContextSelection> {
         if !config.enabled {
-            return Ok(Vec::new());
+            return Ok(ContextSelection::default());
         }
 
         // List all collections for the project
         let mut collections = self.pipeline.list_collections(project_id)?;
 
         if collections.is_empty() {
-            return Ok(Vec::new());
+            return Ok(ContextSelection::default());
         }
 
         // Filter by collection_ids if specified
@@ -115,14 +211,13 @@ impl KnowledgeContextProvider {
                 let id_set: HashSet<&str> = ids.iter().map(|s| s.as_str()).collect();
                 collections.retain(|c| id_set.contains(c.id.as_str()));
                 if collections.is_empty() {
-                    return Ok(Vec::new());
+                    return Ok(ContextSelection::default());
                 }
             }
         }
 
         // Query collections in parallel
         let top_k = config.max_context_chunks * 2; // Fetch more for dedup
-        let min_score = config.minimum_relevance_score;
 
         let futures: Vec<_> = collections
             .iter()
@@ -147,16 +242,15 @@ impl KnowledgeContextProvider {
             match result {
                 Ok(query_result) => {
                     for search_result in query_result.results {
-                        if search_result.score >= min_score {
-                            all_chunks.push(ContextChunk {
-                                content: search_result.chunk_text,
-                                source_document: search_result.document_id,
-                                document_uid: search_result.document_uid,
-                                collection_id: search_result.collection_id,
-                                collection_name: search_result.collection_name,
-                                relevance_score: search_result.score,
-                            });
-                        }
+                        all_chunks.push(ContextChunk {
+                            heading_path: search_result.metadata.get(HEADING_PATH_KEY).cloned(),
+                            content: search_result.chunk_text,
+                            source_document: search_result.document_id,
+                            document_uid: search_result.document_uid,
+                            collection_id: search_result.collection_id,
+                            collection_name: search_result.collection_name,
+                            relevance_score: search_result.score,
+                        });
                     }
                 }
                 Err(e) => {
@@ -188,26 +282,7 @@ impl KnowledgeContextProvider {
             }
         }
 
-        // Sort by relevance score descending
-        all_chunks.sort_by(|a, b| {
-            b.relevance_score
-                .partial_cmp(&a.relevance_score)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
-        // Deduplicate by content hash (keep highest-scored version).
-        // Uses SHA-256 instead of a 200-char prefix to avoid false
-        // dedup of chunks that share a prefix but differ later.
-        let mut seen: HashSet<[u8; 32]> = HashSet::new();
-        all_chunks.retain(|chunk| {
-            let hash: [u8; 32] = Sha256::digest(chunk.content.as_bytes()).into();
-            seen.insert(hash)
-        });
-
-        // Truncate to max_context_chunks
-        all_chunks.truncate(config.max_context_chunks);
-
-        Ok(all_chunks)
+        Ok(select_within_budget(all_chunks, config))
     }
 
     /// Format context chunks as a structured context block for agent prompts.
@@ -220,11 +295,17 @@ impl KnowledgeContextProvider {
         block.push_str("The following context was automatically retrieved from the project knowledge base:\n\n");
 
         for (i, chunk) in chunks.iter().enumerate() {
+            let section = chunk
+                .heading_path
+                .as_deref()
+                .map(|path| format!(", section: {}", path))
+                .unwrap_or_default();
             block.push_str(&format!(
-                "### Context {} (relevance: {:.2}, source: {}, collection: {})\n\n",
+                "### Context {} (relevance: {:.2}, source: {}{}, collection: {})\n\n",
                 i + 1,
                 chunk.relevance_score,
                 chunk.source_document,
+                section,
                 chunk.collection_name,
             ));
             block.push_str(&chunk.content);
@@ -256,6 +337,7 @@ mod tests {
             collection_id: "col-1-id".to_string(),
             collection_name: "col-1".to_string(),
             relevance_score: 0.85,
+            heading_path: None,
         };
         let json = serde_json::to_string(&chunk).unwrap();
         let deserialized: ContextChunk = serde_json::from_str(&json).unwrap();
@@ -273,6 +355,92 @@ mod tests {
         assert!(config.enabled);
         assert_eq!(config.max_context_chunks, 5);
         assert!((config.minimum_relevance_score - 0.3).abs() < 0.001);
+        assert_eq!(config.max_context_tokens, None);
+    }
+
+    #[test]
+    fn config_missing_budget_is_unbounded() {
+        let json = r#"{"enabled":true,"max_context_chunks":5,"minimum_relevance_score":0.3}"#;
+        let config: KnowledgeContextConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.max_context_tokens, None);
+    }
+
+    // ======================================================================
+    // select_within_budget tests
+    // ======================================================================
+
+    fn scored_chunk(content: &str, score: f32) -> ContextChunk {
+        ContextChunk {
+            content: content.to_string(),
+            source_document: "doc".to_string(),
+            document_uid: format!("uid-{}", content.len()),
+            collection_id: "col-id".to_string(),
+            collection_name: "col".to_string(),
+            relevance_score: score,
+            heading_path: None,
+        }
+    }
+
+    #[test]
+    fn select_omits_sub_threshold_chunks() {
+        let config = KnowledgeContextConfig::default();
+        let selection = select_within_budget(
+            vec![
+                scored_chunk("relevant chunk", 0.9),
+                scored_chunk("noise", 0.1),
+                scored_chunk("borderline", 0.29),
+            ],
+            &config,
+        );
+        assert_eq!(selection.chunks.len(), 1);
+        assert_eq!(selection.chunks[0].content, "relevant chunk");
+        assert_eq!(selection.below_threshold, 2);
+
+        let nothing = select_within_budget(vec![scored_chunk("noise", 0.1)], &config);
+        assert!(nothing.chunks.is_empty());
+        assert_eq!(nothing.injected_tokens, 0);
+        assert!(KnowledgeContextProvider::format_context_block(&nothing.chunks).is_empty());
+    }
+
+    #[test]
+    fn select_stops_at_token_budget() {
+        let config = KnowledgeContextConfig {
+            max_context_chunks: 10,
+            max_context_tokens: Some(50),
+            ..KnowledgeContextConfig::default()
+        };
+        // 100 chars = 25 tokens each; only two fit in 50 tokens.
+        let selection = select_within_budget(
+            vec![
+                scored_chunk(&"c".repeat(100), 0.5),
+                scored_chunk(&"a".repeat(100), 0.9),
+                scored_chunk(&"b".repeat(100), 0.7),
+                scored_chunk(&"d".repeat(4), 0.4),
+            ],
+            &config,
+        );
+        let scores: Vec<f32> = selection.chunks.iter().map(|c| c.relevance_score).collect();
+        assert_eq!(scores, vec![0.9, 0.7]);
+        assert_eq!(selection.chunk_tokens, vec![25, 25]);
+        assert_eq!(selection.injected_tokens, 50);
+        assert_eq!(selection.over_budget, 2);
+        assert_eq!(selection.over_chunk_limit, 0);
+        assert_eq!(selection.below_threshold, 0);
+    }
+
+    #[test]
+    fn select_reports_chunk_cap_separately() {
+        let config = KnowledgeContextConfig {
+            max_context_chunks: 1,
+            ..KnowledgeContextConfig::default()
+        };
+        let selection = select_within_budget(
+            vec![scored_chunk("first", 0.9), scored_chunk("second", 0.8)],
+            &config,
+        );
+        assert_eq!(selection.chunks.len(), 1);
+        assert_eq!(selection.over_chunk_limit, 1);
+        assert_eq!(selection.over_budget, 0);
     }
 
     #[test]
@@ -283,6 +451,7 @@ mod tests {
             minimum_relevance_score: 0.5,
             collection_ids: None,
             document_refs: None,
+            max_context_tokens: None,
         };
         let json = serde_json::to_string(&config).unwrap();
         let deserialized: KnowledgeContextConfig = serde_json::from_str(&json).unwrap();
@@ -310,6 +479,7 @@ mod tests {
                 collection_id: "docs-id".to_string(),
                 collection_name: "docs".to_string(),
                 relevance_score: 0.95,
+                heading_path: Some("# Rust > ## Ownership".to_string()),
             },
             ContextChunk {
                 content: "Lifetimes prevent dangling references".to_string(),
@@ -318,6 +488,7 @@ mod tests {
                 collection_id: "docs-id".to_string(),
                 collection_name: "docs".to_string(),
                 relevance_score: 0.8,
+                heading_path: None,
             },
         ];
 
@@ -326,6 +497,7 @@ mod tests {
         assert!(block.contains("Rust uses ownership"));
         assert!(block.contains("Lifetimes prevent"));
         assert!(block.contains("0.95"));
+        assert!(block.contains("source: rust-guide, section: # Rust > ## Ownership"));
         assert!(block.contains("Context 1"));
         assert!(block.contains("Context 2"));
     }
@@ -387,6 +559,7 @@ mod tests {
             minimum_relevance_score: 0.3,
             collection_ids: None,
             document_refs: None,
+            max_context_tokens: None,
         };
 
         let chunks = provider
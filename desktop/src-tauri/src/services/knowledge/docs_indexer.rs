@@ -3,7 +3,8 @@
 //! Automatically discovers and indexes documentation files (.md, .mdx, .txt,
 //! .pdf, .doc, .docx) from a workspace into a dedicated "[Docs]" knowledge
 //! collection. Watches for file changes and notifies the frontend so users
-//! can trigger incremental sync.
+//! can trigger incremental sync. A per-collection manifest of content hashes
+//! and chunk ids lets a re-index skip files that have not changed.
 
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
@@ -15,7 +16,7 @@ use tauri::Emitter;
 use tokio::sync::RwLock;
 
 use crate::services::knowledge::chunker::Document;
-use crate::services::knowledge::pipeline::{KnowledgeCollection, RagPipeline};
+use crate::services::knowledge::pipeline::{DocsReindexReport, KnowledgeCollection, RagPipeline};
 use crate::utils::error::{AppError, AppResult};
 
 /// File extensions treated as documentation.
@@ -462,6 +463,7 @@ impl DocsIndexer {
                         .await;
                     match updated_result {
                         Ok(updated) => {
+                            Self::record_manifest(pipeline, &updated.id);
                             self.start_doc_watcher(workspace_path).await;
                             self.mark_success(workspace_path, project_id, scanned_files)
                                 .await;
@@ -530,6 +532,7 @@ impl DocsIndexer {
         // Set workspace_path on the collection
         let collection =
             pipeline.update_collection(&collection.id, None, None, Some(Some(workspace_path)))?;
+        Self::record_manifest(pipeline, &collection.id);
 
         // Start file watcher
         self.start_doc_watcher(workspace_path).await;
@@ -622,6 +625,7 @@ impl DocsIndexer {
 
         match rebuild_result {
             Ok(collection) => {
+                Self::record_manifest(pipeline, &collection.id);
                 self.start_doc_watcher(workspace_path).await;
                 self.mark_success(workspace_path, project_id, scanned_files)
                     .await;
@@ -635,6 +639,66 @@ impl DocsIndexer {
         }
     }
 
+    /// Incrementally re-index a workspace's docs collection.
+    ///
+    /// Only new or changed files are re-chunked and re-embedded; chunks for
+    /// deleted files are removed. Returns `None` if the collection does not
+    /// exist yet (use `ensure_docs_collection` for the initial index).
+    pub async fn reindex_docs_collection(
+        &self,
+        pipeline: &RagPipeline,
+        workspace_path: &str,
+        project_id: &str,
+        app_handle: Option<&tauri::AppHandle>,
+    ) -> AppResult<Option<DocsReindexReport>> {
+        let collection_name = Self::collection_name_for_workspace(workspace_path);
+        let Some(collection) = pipeline
+            .list_collections(project_id)?
+            .into_iter()
+            .find(|c| c.name == collection_name)
+        else {
+            return Ok(None);
+        };
+
+        let doc_files = Self::scan_doc_files(Path::new(workspace_path));
+        let scanned_files = doc_files.len();
+        self.mark_attempt(workspace_path, project_id, scanned_files)
+            .await;
+
+        let documents = Self::build_documents(&doc_files);
+        match pipeline
+            .reindex_workspace_docs(&collection.id, documents, app_handle)
+            .await
+        {
+            Ok(report) => {
+                tracing::info!(
+                    collection = %collection.name,
+                    reindexed = report.reindexed.len(),
+                    removed = report.removed.len(),
+                    retained = report.retained,
+                    "Docs indexer: incremental re-index complete"
+                );
+                self.start_doc_watcher(workspace_path).await;
+                self.mark_success(workspace_path, project_id, scanned_files)
+                    .await;
+                Ok(Some(report))
+            }
+            Err(err) => {
+                self.mark_failure(workspace_path, project_id, scanned_files, &err.to_string())
+                    .await;
+                Err(err)
+            }
+        }
+    }
+
+    /// Snapshot the collection's manifest after a full ingest. Failures only
+    /// cost the next re-index its shortcut, so they are logged, not returned.
+    fn record_manifest(pipeline: &RagPipeline, collection_id: &str) {
+        if let Err(e) = pipeline.sync_docs_manifest(collection_id) {
+            tracing::warn!(error = %e, "Failed to record docs manifest");
+        }
+    }
+
     /// Start watching a workspace for documentation file changes.
     pub async fn start_doc_watcher(&self, workspace_path: &str) {
         let normalized = Self::normalize_path(workspace_path);
@@ -15,7 +15,9 @@ use std::sync::Arc;
 use std::time::Instant;
 use tauri::Emitter;
 
-use crate::services::knowledge::chunker::{Chunk, Chunker, Document};
+use crate::services::knowledge::chunker::{
+    annotate_source_spans, Chunk, Chunker, Document, SourceCitation,
+};
 use crate::services::knowledge::observability;
 use crate::services::knowledge::reranker::{Reranker, SearchResult};
 use crate::services::orchestrator::embedding_manager::EmbeddingManager;
@@ -27,6 +29,13 @@ use crate::storage::database::Database;
 use crate::utils::error::{AppError, AppResult};
 use crate::utils::paths::database_path;
 
+/// Columns selected for a `KnowledgeCollection` (aliased as `c`), including
+/// document count and last index time derived from `knowledge_documents`.
+const COLLECTION_COLUMNS: &str = "c.id, c.name, c.project_id, c.description, c.chunk_count,
+    c.created_at, c.updated_at, c.workspace_path,
+    (SELECT COUNT(*) FROM knowledge_documents d WHERE d.collection_id = c.id),
+    (SELECT MAX(d.last_indexed_at) FROM knowledge_documents d WHERE d.collection_id = c.id)";
+
 // ---------------------------------------------------------------------------
 // Data structures
 // ---------------------------------------------------------------------------
@@ -51,6 +60,12 @@ pub struct KnowledgeCollection {
     /// Optional workspace path associating this collection with a project directory.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workspace_path: Option<String>,
+    /// Number of documents indexed into this collection.
+    #[serde(default)]
+    pub doc_count: i64,
+    /// Most recent time any document in this collection was (re-)indexed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_indexed_at: Option<String>,
 }
 
 /// Query result from the RAG pipeline.
@@ -71,6 +86,57 @@ pub struct ScopedDocumentRef {
     pub document_uid: String,
 }
 
+/// Chunk metadata filter applied during scoped queries.
+///
+/// Every `equals` entry must match the chunk metadata exactly, and when
+/// `path_prefix` is set the chunk's `source_path` must start with it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MetadataFilter {
+    #[serde(default)]
+    pub equals: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path_prefix: Option<String>,
+}
+
+impl MetadataFilter {
+    /// Metadata key holding the document source path.
+    pub const SOURCE_PATH_KEY: &'static str = "source_path";
+    /// Filter key interpreted as a source path prefix by [`MetadataFilter::from_map`].
+    pub const PATH_PREFIX_KEY: &'static str = "path_prefix";
+
+    /// Build a filter from a flat key/value map. The `path_prefix` key becomes
+    /// the path prefix; every other entry is an exact metadata match.
+    pub fn from_map(map: HashMap<String, String>) -> Self {
+        let mut filter = Self::default();
+        for (key, value) in map {
+            if key == Self::PATH_PREFIX_KEY {
+                filter.path_prefix = Some(value);
+            } else {
+                filter.equals.insert(key, value);
+            }
+        }
+        filter
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.equals.is_empty() && self.path_prefix.is_none()
+    }
+
+    /// Whether a chunk with the given metadata passes the filter.
+    pub fn matches(&self, metadata: &HashMap<String, String>) -> bool {
+        let equals_ok = self
+            .equals
+            .iter()
+            .all(|(key, value)| metadata.get(key) == Some(value));
+        let prefix_ok = self.path_prefix.as_deref().is_none_or(|prefix| {
+            metadata
+                .get(Self::SOURCE_PATH_KEY)
+                .is_some_and(|path| path.starts_with(prefix))
+        });
+        equals_ok && prefix_ok
+    }
+}
+
 /// Summary of a document within a collection.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocumentSummary {
@@ -121,6 +187,32 @@ pub struct CollectionUpdateCheck {
     pub unchanged: usize,
 }
 
+/// One workspace file recorded in a docs collection's index manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocsManifestEntry {
+    /// Absolute path of the indexed file.
+    pub source_path: String,
+    /// SHA-256 of the parsed content that was chunked.
+    pub content_hash: String,
+    pub document_uid: String,
+    /// Row ids of the chunks produced from this file.
+    pub chunk_ids: Vec<i64>,
+    /// When this file was last chunked and embedded.
+    pub indexed_at: String,
+}
+
+/// Outcome of an incremental docs re-index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocsReindexReport {
+    pub collection: KnowledgeCollection,
+    /// Files re-chunked and re-embedded because they are new or changed.
+    pub reindexed: Vec<String>,
+    /// Files whose chunks were dropped because they no longer exist.
+    pub removed: Vec<String>,
+    /// Number of unchanged files whose chunks were kept.
+    pub retained: usize,
+}
+
 /// A single recorded query execution run for local observability.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QueryRunSummary {
@@ -269,6 +361,7 @@ impl RagPipeline {
         Self::ensure_collections_table(&conn)?;
         Self::ensure_collection_workspace_path(&conn)?;
         self.migrate_to_knowledge_v2(&mut conn)?;
+        Self::ensure_docs_manifest_table(&conn)?;
         if let Err(e) = Self::migrate_query_runs_to_v3(&conn) {
             tracing::warn!(
                 error = %e,
@@ -370,6 +463,26 @@ impl RagPipeline {
         Ok(())
     }
 
+    fn ensure_docs_manifest_table(conn: &rusqlite::Connection) -> AppResult<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS knowledge_docs_manifest (
+                collection_id TEXT NOT NULL,
+                source_path TEXT NOT NULL,
+                content_hash TEXT NOT NULL,
+                document_uid TEXT NOT NULL,
+                chunk_ids TEXT NOT NULL DEFAULT '[]',
+                indexed_at TEXT NOT NULL,
+                PRIMARY KEY (collection_id, source_path),
+                FOREIGN KEY (collection_id) REFERENCES knowledge_collections(id) ON DELETE CASCADE
+            )",
+            [],
+        )
+        .map_err(|e| {
+            AppError::database(format!("Failed to create knowledge_docs_manifest: {}", e))
+        })?;
+        Ok(())
+    }
+
     fn table_exists(conn: &rusqlite::Connection, table_name: &str) -> AppResult<bool> {
         let exists: i64 = conn
             .query_row(
@@ -1044,7 +1157,8 @@ impl RagPipeline {
 
         let mut all_chunks: Vec<Chunk> = Vec::new();
         for doc in &documents {
-            let chunks = self.chunker.chunk(doc)?;
+            let mut chunks = self.chunker.chunk(doc)?;
+            annotate_source_spans(doc, &mut chunks);
             all_chunks.extend(chunks);
         }
 
@@ -1325,6 +1439,7 @@ impl RagPipeline {
                 Some(&[collection_id]),
                 None,
                 None,
+                None,
             )
             .await?;
         result.collection_name = collection_name.to_string();
@@ -1393,6 +1508,7 @@ impl RagPipeline {
         let metadata: HashMap<String, String> =
             serde_json::from_str(&metadata_json).unwrap_or_default();
         let embedding = bytes_to_embedding(&emb_bytes);
+        let citation = SourceCitation::from_metadata(&metadata);
         Ok(Some((
             SearchResult {
                 chunk_text: content,
@@ -1402,6 +1518,7 @@ impl RagPipeline {
                 collection_name,
                 score: 0.0,
                 metadata,
+                citation,
             },
             embedding,
         )))
@@ -1646,6 +1763,7 @@ impl RagPipeline {
         top_k: usize,
         collection_ids: Option<&[String]>,
         document_filters: Option<&[ScopedDocumentRef]>,
+        metadata_filter: Option<&MetadataFilter>,
         retrieval_profile: Option<&str>,
     ) -> AppResult<RagQueryResult> {
         let started = Instant::now();
@@ -1678,6 +1796,7 @@ impl RagPipeline {
             .map(|d| (d.collection_id.clone(), d.document_uid.clone()))
             .collect();
         let has_doc_filter = !scoped_documents.is_empty();
+        let metadata_filter = metadata_filter.filter(|f| !f.is_empty());
 
         let query_embedding = self
             .embedding_manager
@@ -1717,6 +1836,9 @@ impl RagPipeline {
                 {
                     continue;
                 }
+                if metadata_filter.is_some_and(|f| !f.matches(&result.metadata)) {
+                    continue;
+                }
                 result.score = (1.0 - distance).clamp(0.0, 1.0);
                 vector_ranked.push(*chunk_id as i64);
                 candidate_map
@@ -1770,6 +1892,9 @@ impl RagPipeline {
                 else {
                     continue;
                 };
+                if metadata_filter.is_some_and(|f| !f.matches(&result.metadata)) {
+                    continue;
+                }
                 let bm25_score = (1.0 / (1.0 + bm25_rank.abs() as f32)).clamp(0.0, 1.0);
                 result.score = bm25_score;
                 bm25_ranked.push(chunk_id);
@@ -1816,6 +1941,7 @@ impl RagPipeline {
                 } else {
                     None
                 },
+                metadata_filter,
             )?;
             return Ok(RagQueryResult {
                 total_searched: fallback.len(),
@@ -1873,6 +1999,7 @@ impl RagPipeline {
         query_embedding: &[f32],
         limit: usize,
         scoped_documents: Option<&HashSet<(String, String)>>,
+        metadata_filter: Option<&MetadataFilter>,
     ) -> AppResult<Vec<SearchResult>> {
         if collection_ids.is_empty() {
             return Ok(Vec::new());
@@ -1939,6 +2066,9 @@ impl RagPipeline {
                     }
                     let metadata: HashMap<String, String> =
                         serde_json::from_str(&metadata_json).unwrap_or_default();
+                    if metadata_filter.is_some_and(|f| !f.matches(&metadata)) {
+                        return None;
+                    }
                     let lexical_bonus =
                         if content.to_lowercase().contains(&query_text.to_lowercase()) {
                             0.05
@@ -1954,6 +2084,7 @@ impl RagPipeline {
                         score: (cosine_similarity(query_embedding, &chunk_embedding)
                             + lexical_bonus)
                             .clamp(0.0, 1.0),
+                        citation: SourceCitation::from_metadata(&metadata),
                         metadata,
                     })
                 },
@@ -2219,25 +2350,14 @@ impl RagPipeline {
             .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
 
         let mut stmt = conn
-            .prepare(
-                "SELECT id, name, project_id, description, chunk_count, created_at, updated_at, workspace_path
-                 FROM knowledge_collections WHERE project_id = ?1 ORDER BY name",
-            )
+            .prepare(&format!(
+                "SELECT {} FROM knowledge_collections c WHERE c.project_id = ?1 ORDER BY c.name",
+                COLLECTION_COLUMNS
+            ))
             .map_err(|e| AppError::database(format!("Failed to prepare query: {}", e)))?;
 
         let rows = stmt
-            .query_map(rusqlite::params![project_id], |row| {
-                Ok(KnowledgeCollection {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    project_id: row.get(2)?,
-                    description: row.get(3)?,
-                    chunk_count: row.get(4)?,
-                    created_at: row.get(5)?,
-                    updated_at: row.get(6)?,
-                    workspace_path: row.get(7)?,
-                })
-            })
+            .query_map(rusqlite::params![project_id], Self::collection_from_row)
             .map_err(|e| AppError::database(format!("Failed to query collections: {}", e)))?;
 
         Ok(rows.filter_map(|r| r.ok()).collect())
@@ -2542,6 +2662,12 @@ impl RagPipeline {
             )
             .map_err(|e| AppError::database(format!("Failed to delete documents: {}", e)))?;
 
+            conn.execute(
+                "DELETE FROM knowledge_docs_manifest WHERE collection_id = ?1",
+                rusqlite::params![collection_id],
+            )
+            .map_err(|e| AppError::database(format!("Failed to delete docs manifest: {}", e)))?;
+
             conn.execute(
                 "DELETE FROM knowledge_collections WHERE id = ?1",
                 rusqlite::params![collection_id],
@@ -2906,24 +3032,217 @@ impl RagPipeline {
             .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
 
         conn.query_row(
-            "SELECT id, name, project_id, description, chunk_count, created_at, updated_at, workspace_path
-             FROM knowledge_collections WHERE id = ?1",
+            &format!(
+                "SELECT {} FROM knowledge_collections c WHERE c.id = ?1",
+                COLLECTION_COLUMNS
+            ),
             rusqlite::params![collection_id],
-            |row| {
-                Ok(KnowledgeCollection {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    project_id: row.get(2)?,
-                    description: row.get(3)?,
-                    chunk_count: row.get(4)?,
-                    created_at: row.get(5)?,
-                    updated_at: row.get(6)?,
-                    workspace_path: row.get(7)?,
-                })
-            },
+            Self::collection_from_row,
         )
         .map_err(|e| AppError::database(format!("Collection not found: {}", e)))
     }
+
+    fn collection_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<KnowledgeCollection> {
+        Ok(KnowledgeCollection {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            project_id: row.get(2)?,
+            description: row.get(3)?,
+            chunk_count: row.get(4)?,
+            created_at: row.get(5)?,
+            updated_at: row.get(6)?,
+            workspace_path: row.get(7)?,
+            doc_count: row.get(8)?,
+            last_indexed_at: row.get(9)?,
+        })
+    }
+
+    // -----------------------------------------------------------------------
+    // Docs manifest
+    // -----------------------------------------------------------------------
+
+    /// Load the docs manifest for a collection, keyed by source path.
+    pub fn load_docs_manifest(
+        &self,
+        collection_id: &str,
+    ) -> AppResult<HashMap<String, DocsManifestEntry>> {
+        let conn = self
+            .database
+            .get_connection()
+            .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT source_path, content_hash, document_uid, chunk_ids, indexed_at
+                 FROM knowledge_docs_manifest WHERE collection_id = ?1",
+            )
+            .map_err(|e| AppError::database(format!("Failed to prepare query: {}", e)))?;
+
+        let rows = stmt
+            .query_map(rusqlite::params![collection_id], |row| {
+                let chunk_ids: String = row.get(3)?;
+                Ok(DocsManifestEntry {
+                    source_path: row.get(0)?,
+                    content_hash: row.get(1)?,
+                    document_uid: row.get(2)?,
+                    chunk_ids: serde_json::from_str(&chunk_ids).unwrap_or_default(),
+                    indexed_at: row.get(4)?,
+                })
+            })
+            .map_err(|e| AppError::database(format!("Failed to query docs manifest: {}", e)))?;
+
+        Ok(rows
+            .filter_map(|r| r.ok())
+            .map(|entry| (entry.source_path.clone(), entry))
+            .collect())
+    }
+
+    /// Rewrite the docs manifest to mirror the workspace documents currently
+    /// stored in the collection.
+    pub fn sync_docs_manifest(
+        &self,
+        collection_id: &str,
+    ) -> AppResult<HashMap<String, DocsManifestEntry>> {
+        {
+            let mut conn = self
+                .database
+                .get_connection()
+                .map_err(|e| AppError::database(format!("Failed to get connection: {}", e)))?;
+
+            let tx = conn
+                .transaction()
+                .map_err(|e| AppError::database(format!("Failed to begin transaction: {}", e)))?;
+
+            let documents: Vec<(String, String, String, String)> = {
+                let mut stmt = tx
+                    .prepare(
+                        "SELECT document_uid, source_locator, content_hash,
+                                COALESCE(last_indexed_at, updated_at, datetime('now'))
+                         FROM knowledge_documents
+                         WHERE collection_id = ?1 AND source_kind = 'workspace'",
+                    )
+                    .map_err(|e| AppError::database(format!("Failed to prepare query: {}", e)))?;
+                let rows = stmt
+                    .query_map(rusqlite::params![collection_id], |row| {
+                        Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+                    })
+                    .map_err(|e| AppError::database(format!("Failed to query documents: {}", e)))?;
+                rows.filter_map(|r| r.ok()).collect()
+            };
+
+            tx.execute(
+                "DELETE FROM knowledge_docs_manifest WHERE collection_id = ?1",
+                rusqlite::params![collection_id],
+            )
+            .map_err(|e| AppError::database(format!("Failed to clear docs manifest: {}", e)))?;
+
+            for (document_uid, source_path, content_hash, indexed_at) in &documents {
+                let chunk_ids: Vec<i64> = {
+                    let mut stmt = tx
+                        .prepare(
+                            "SELECT id FROM knowledge_chunks
+                             WHERE collection_id = ?1 AND document_uid = ?2 ORDER BY chunk_index",
+                        )
+                        .map_err(|e| {
+                            AppError::database(format!("Failed to prepare query: {}", e))
+                        })?;
+                    let rows = stmt
+                        .query_map(rusqlite::params![collection_id, document_uid], |row| {
+                            row.get(0)
+                        })
+                        .map_err(|e| {
+                            AppError::database(format!("Failed to query chunk ids: {}", e))
+                        })?;
+                    rows.filter_map(|r| r.ok()).collect()
+                };
+
+                tx.execute(
+                    "INSERT INTO knowledge_docs_manifest
+                     (collection_id, source_path, content_hash, document_uid, chunk_ids, indexed_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    rusqlite::params![
+                        collection_id,
+                        source_path,
+                        content_hash,
+                        document_uid,
+                        serde_json::to_string(&chunk_ids)?,
+                        indexed_at,
+                    ],
+                )
+                .map_err(|e| {
+                    AppError::database(format!("Failed to write docs manifest entry: {}", e))
+                })?;
+            }
+
+            tx.commit()
+                .map_err(|e| AppError::database(format!("Failed to commit: {}", e)))?;
+        }
+
+        self.load_docs_manifest(collection_id)
+    }
+
+    /// Incrementally re-index workspace documents against the collection's
+    /// manifest: only new or changed files are re-chunked and re-embedded, and
+    /// chunks for files missing from `documents` are removed.
+    pub async fn reindex_workspace_docs(
+        &self,
+        collection_id: &str,
+        documents: Vec<Document>,
+        app_handle: Option<&tauri::AppHandle>,
+    ) -> AppResult<DocsReindexReport> {
+        let mut manifest = self.load_docs_manifest(collection_id)?;
+        if manifest.is_empty() {
+            // Collections indexed before the manifest existed
+            manifest = self.sync_docs_manifest(collection_id)?;
+        }
+
+        let mut seen = HashSet::new();
+        let mut changed = Vec::new();
+        let mut reindexed = Vec::new();
+        let mut retained = 0usize;
+        for doc in documents {
+            let Some(source_path) = doc.source_path.clone() else {
+                continue;
+            };
+            seen.insert(source_path.clone());
+            let content_hash = format!("{:x}", Sha256::digest(doc.content.as_bytes()));
+            match manifest.get(&source_path) {
+                Some(entry) if entry.content_hash == content_hash => retained += 1,
+                _ => {
+                    reindexed.push(source_path);
+                    changed.push(doc);
+                }
+            }
+        }
+
+        let mut removed: Vec<String> = manifest
+            .keys()
+            .filter(|path| !seen.contains(*path))
+            .cloned()
+            .collect();
+        removed.sort();
+        for path in &removed {
+            let document_uid = &manifest[path].document_uid;
+            match self.delete_document(collection_id, document_uid).await {
+                Ok(()) | Err(AppError::NotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        if !changed.is_empty() {
+            self.ingest_into_collection_with_progress(collection_id, changed, app_handle, None)
+                .await?;
+        }
+
+        self.sync_docs_manifest(collection_id)?;
+        reindexed.sort();
+        Ok(DocsReindexReport {
+            collection: self.get_collection(collection_id)?,
+            reindexed,
+            removed,
+            retained,
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -3040,6 +3359,32 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn query_results_carry_source_citation() {
+        let (pipeline, dir) = create_test_pipeline().await;
+        let source = dir.path().join("guide.md").to_string_lossy().to_string();
+        let content = "# Setup\n\nInstall the toolchain first.\n\n\
+                       Configure the zeppelin telemetry exporter\nbefore starting the daemon.";
+        let docs = vec![Document::with_source("guide", content, source.clone())];
+        pipeline
+            .ingest("cite-col", "proj-1", "Citations", docs)
+            .await
+            .unwrap();
+
+        let result = pipeline
+            .query("cite-col", "proj-1", "zeppelin telemetry exporter", 3)
+            .await
+            .unwrap();
+        let top = &result.results[0];
+        let citation = top.citation.as_ref().expect("citation");
+        assert_eq!(citation.source_path.as_deref(), Some(source.as_str()));
+        assert_eq!(
+            &content[citation.byte_start..citation.byte_end],
+            top.chunk_text
+        );
+        assert_eq!((citation.line_start, citation.line_end), (5, 6));
+    }
+
     #[tokio::test]
     async fn ingest_same_collection_twice_appends() {
         let (pipeline, _dir) = create_test_pipeline().await;
@@ -3257,11 +3602,27 @@ mod tests {
         let scope_a = vec![col_a.id.clone()];
         let scope_b = vec![col_b.id.clone()];
         pipeline
-            .query_scoped("proj-1", "alpha", 5, Some(&scope_a), None, Some("balanced"))
+            .query_scoped(
+                "proj-1",
+                "alpha",
+                5,
+                Some(&scope_a),
+                None,
+                None,
+                Some("balanced"),
+            )
             .await
             .unwrap();
         pipeline
-            .query_scoped("proj-1", "bravo", 5, Some(&scope_b), None, Some("balanced"))
+            .query_scoped(
+                "proj-1",
+                "bravo",
+                5,
+                Some(&scope_b),
+                None,
+                None,
+                Some("balanced"),
+            )
             .await
             .unwrap();
 
@@ -3279,6 +3640,132 @@ mod tests {
         assert_ne!(runs_a[0].id, runs_b[0].id);
     }
 
+    #[tokio::test]
+    async fn query_scoped_collection_filter_restricts_results() {
+        let (pipeline, _dir) = create_test_pipeline().await;
+
+        let api_docs = pipeline
+            .ingest(
+                "api-docs",
+                "proj-1",
+                "API docs",
+                vec![Document::new(
+                    "auth",
+                    "Authentication tokens expire hourly.",
+                )],
+            )
+            .await
+            .unwrap();
+        pipeline
+            .ingest(
+                "guides",
+                "proj-1",
+                "Guides",
+                vec![Document::new(
+                    "auth-guide",
+                    "Authentication guide for new users.",
+                )],
+            )
+            .await
+            .unwrap();
+
+        let scope = vec![api_docs.id.clone()];
+        let result = pipeline
+            .query_scoped(
+                "proj-1",
+                "authentication",
+                10,
+                Some(&scope),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.results.is_empty());
+        assert!(result
+            .results
+            .iter()
+            .all(|r| r.collection_id == api_docs.id));
+    }
+
+    #[tokio::test]
+    async fn query_scoped_path_prefix_filter_excludes_other_paths() {
+        let (pipeline, _dir) = create_test_pipeline().await;
+
+        pipeline
+            .ingest(
+                "docs",
+                "proj-1",
+                "Docs",
+                vec![
+                    Document::with_source(
+                        "api-auth",
+                        "Authentication uses bearer tokens.",
+                        "docs/api/auth.md",
+                    ),
+                    Document::with_source(
+                        "guide-auth",
+                        "Authentication walkthrough for beginners.",
+                        "docs/guides/auth.md",
+                    ),
+                ],
+            )
+            .await
+            .unwrap();
+
+        let filter = MetadataFilter {
+            path_prefix: Some("docs/api/".to_string()),
+            ..Default::default()
+        };
+        let result = pipeline
+            .query_scoped(
+                "proj-1",
+                "authentication",
+                10,
+                None,
+                None,
+                Some(&filter),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(!result.results.is_empty());
+        assert!(result.results.iter().all(|r| r
+            .metadata
+            .get("source_path")
+            .is_some_and(|p| p.starts_with("docs/api/"))));
+    }
+
+    #[test]
+    fn metadata_filter_from_map_splits_path_prefix() {
+        let filter = MetadataFilter::from_map(HashMap::from([
+            ("path_prefix".to_string(), "docs/".to_string()),
+            ("source_type".to_string(), "md".to_string()),
+        ]));
+        assert_eq!(filter.path_prefix.as_deref(), Some("docs/"));
+        assert_eq!(
+            filter.equals.get("source_type").map(String::as_str),
+            Some("md")
+        );
+
+        let matching = HashMap::from([
+            ("source_path".to_string(), "docs/a.md".to_string()),
+            ("source_type".to_string(), "md".to_string()),
+        ]);
+        let wrong_type = HashMap::from([
+            ("source_path".to_string(), "docs/a.rs".to_string()),
+            ("source_type".to_string(), "rs".to_string()),
+        ]);
+        let no_path = HashMap::from([("source_type".to_string(), "md".to_string())]);
+        assert!(filter.matches(&matching));
+        assert!(!filter.matches(&wrong_type));
+        assert!(!filter.matches(&no_path));
+        assert!(MetadataFilter::default().matches(&no_path));
+    }
+
     #[tokio::test]
     async fn query_runs_are_isolated_by_project_id() {
         let (pipeline, _dir) = create_test_pipeline().await;
@@ -3311,6 +3798,7 @@ mod tests {
                 5,
                 Some(&scope_proj1),
                 None,
+                None,
                 Some("balanced"),
             )
             .await
@@ -3322,6 +3810,7 @@ mod tests {
                 5,
                 Some(&scope_proj2),
                 None,
+                None,
                 Some("balanced"),
             )
             .await
@@ -3352,7 +3841,15 @@ mod tests {
             .unwrap();
         let scope = vec![collection.id.clone()];
         pipeline
-            .query_scoped("proj-1", "scope", 5, Some(&scope), None, Some("balanced"))
+            .query_scoped(
+                "proj-1",
+                "scope",
+                5,
+                Some(&scope),
+                None,
+                None,
+                Some("balanced"),
+            )
             .await
             .unwrap();
 
@@ -3398,6 +3895,7 @@ mod tests {
                 5,
                 Some(&scope),
                 None,
+                None,
                 Some("unknown-mode"),
             )
             .await
@@ -3544,6 +4042,90 @@ mod tests {
         assert_ne!(project_two[0].collection_id, col_b.id);
     }
 
+    fn workspace_doc(path: &std::path::Path) -> Document {
+        let content = std::fs::read_to_string(path).unwrap();
+        Document::from_parsed_content(
+            path.file_name().unwrap().to_str().unwrap(),
+            content,
+            path.to_string_lossy(),
+            "md",
+        )
+    }
+
+    #[tokio::test]
+    async fn reindex_workspace_docs_only_reembeds_changed_files() {
+        let (pipeline, dir) = create_test_pipeline().await;
+        let ws = dir.path().join("ws");
+        std::fs::create_dir_all(&ws).unwrap();
+        let paths: Vec<PathBuf> = ["a.md", "b.md", "c.md"]
+            .iter()
+            .map(|name| ws.join(name))
+            .collect();
+        for (i, path) in paths.iter().enumerate() {
+            std::fs::write(path, format!("Doc {} about topic {}.\n\nMore text.", i, i)).unwrap();
+        }
+        let collection_id = pipeline
+            .get_or_create_collection("[Docs] ws", "proj-1", "docs")
+            .unwrap();
+        let docs = |paths: &[PathBuf]| {
+            paths
+                .iter()
+                .filter(|p| p.exists())
+                .map(|p| workspace_doc(p))
+                .collect::<Vec<_>>()
+        };
+
+        let first = pipeline
+            .reindex_workspace_docs(&collection_id, docs(&paths), None)
+            .await
+            .unwrap();
+        assert_eq!(first.reindexed.len(), 3);
+        assert_eq!(first.collection.doc_count, 3);
+        assert!(first.collection.last_indexed_at.is_some());
+        let before = pipeline.load_docs_manifest(&collection_id).unwrap();
+        assert_eq!(before.len(), 3);
+
+        // Modify one doc: only its chunks are replaced
+        std::fs::write(&paths[1], "Doc 1 rewritten.\n\nWith new content.").unwrap();
+        let second = pipeline
+            .reindex_workspace_docs(&collection_id, docs(&paths), None)
+            .await
+            .unwrap();
+        let b_path = paths[1].to_string_lossy().to_string();
+        assert_eq!(second.reindexed, vec![b_path.clone()]);
+        assert_eq!(second.retained, 2);
+        assert!(second.removed.is_empty());
+
+        let after = pipeline.load_docs_manifest(&collection_id).unwrap();
+        for path in [&paths[0], &paths[2]] {
+            let key = path.to_string_lossy().to_string();
+            assert_eq!(before[&key].chunk_ids, after[&key].chunk_ids);
+            assert_eq!(before[&key].content_hash, after[&key].content_hash);
+        }
+        assert_ne!(before[&b_path].content_hash, after[&b_path].content_hash);
+        assert!(after[&b_path]
+            .chunk_ids
+            .iter()
+            .all(|id| !before[&b_path].chunk_ids.contains(id)));
+
+        // Delete a doc: its chunks and manifest entry go away
+        std::fs::remove_file(&paths[2]).unwrap();
+        let third = pipeline
+            .reindex_workspace_docs(&collection_id, docs(&paths), None)
+            .await
+            .unwrap();
+        assert_eq!(third.removed, vec![paths[2].to_string_lossy().to_string()]);
+        assert!(third.reindexed.is_empty());
+        assert_eq!(third.collection.doc_count, 2);
+        assert_eq!(
+            pipeline.load_docs_manifest(&collection_id).unwrap().len(),
+            2
+        );
+
+        let listed = pipeline.list_collections("proj-1").unwrap();
+        assert_eq!(listed[0].doc_count, 2);
+    }
+
     #[test]
     fn knowledge_collection_serde() {
         let col = KnowledgeCollection {
@@ -3555,6 +4137,8 @@ mod tests {
             created_at: "2026-01-01T00:00:00Z".to_string(),
             updated_at: "2026-01-01T00:00:00Z".to_string(),
             workspace_path: None,
+            doc_count: 3,
+            last_indexed_at: None,
         };
         let json = serde_json::to_string(&col).unwrap();
         let deserialized: KnowledgeCollection = serde_json::from_str(&json).unwrap();
@@ -3574,6 +4158,8 @@ mod tests {
             created_at: "2026-01-01T00:00:00Z".to_string(),
             updated_at: "2026-01-01T00:00:00Z".to_string(),
             workspace_path: Some("/home/user/project".to_string()),
+            doc_count: 1,
+            last_indexed_at: Some("2026-01-01 00:00:00".to_string()),
         };
         let json = serde_json::to_string(&col).unwrap();
         assert!(json.contains("workspace_path"));
@@ -6,16 +6,29 @@
 //! - `NoopReranker`: pass-through, preserves original order
 //! - `LlmReranker`: uses keyword-overlap scoring to reorder results
 //!   (In production, this would use an LlmProvider for scoring)
+//! - `CrossEncoderReranker`: scores each (query, chunk) pair of the top-N
+//!   candidates with a `CrossEncoderScorer`, falling back to another reranker
+//!   when unconfigured, failing, or over its time budget
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
+use crate::services::knowledge::chunker::SourceCitation;
 use crate::services::llm::provider::LlmProvider;
 use crate::services::llm::types::{LlmRequestOptions, Message};
-use crate::utils::error::AppResult;
+use crate::storage::database::Database;
+use crate::storage::keyring::KeyringService;
+use crate::utils::error::{AppError, AppResult};
+
+/// Settings key holding the JSON-encoded `CrossEncoderConfig`.
+pub const CROSS_ENCODER_SETTINGS_KEY: &str = "knowledge.cross_encoder_rerank";
+
+/// Keyring alias for the rerank endpoint API key.
+pub const CROSS_ENCODER_KEYRING_ALIAS: &str = "knowledge_rerank";
 
 /// A search result to be reranked.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +47,9 @@ pub struct SearchResult {
     pub score: f32,
     /// Additional metadata.
     pub metadata: HashMap<String, String>,
+    /// Source document path and span this chunk was cut from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub citation: Option<SourceCitation>,
 }
 
 /// Trait for reranking search results.
@@ -255,6 +271,278 @@ impl Reranker for LlmReranker {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Cross-encoder reranking
+// ---------------------------------------------------------------------------
+
+/// Configuration for the optional cross-encoder reranker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CrossEncoderConfig {
+    /// Whether cross-encoder reranking is applied at all.
+    pub enabled: bool,
+    /// Rerank endpoint (Cohere/Jina-compatible `POST .../rerank`).
+    pub endpoint: Option<String>,
+    /// Endpoint API key. Resolved from the keyring and never written to the
+    /// settings DB; a legacy plaintext value is still read so it can be
+    /// migrated.
+    #[serde(skip_serializing)]
+    pub api_key: Option<String>,
+    pub model: Option<String>,
+    /// Number of top retrieval candidates sent to the cross-encoder.
+    pub top_n: usize,
+    /// Time budget for scoring before falling back.
+    pub timeout_ms: u64,
+}
+
+impl Default for CrossEncoderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: None,
+            api_key: None,
+            model: None,
+            top_n: 20,
+            timeout_ms: 3_000,
+        }
+    }
+}
+
+impl CrossEncoderConfig {
+    /// Load the config from app settings and its API key from the keyring;
+    /// missing or malformed values yield the (disabled) default. A plaintext
+    /// key left in the settings by older versions is moved to the keyring.
+    pub fn from_settings(database: &Database, keyring: &KeyringService) -> Self {
+        let mut config: Self = match database.get_setting(CROSS_ENCODER_SETTINGS_KEY) {
+            Ok(Some(raw)) => serde_json::from_str(&raw).unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Invalid cross-encoder rerank settings, ignoring");
+                Self::default()
+            }),
+            _ => Self::default(),
+        };
+
+        if let Some(legacy_key) = config.api_key.take().filter(|k| !k.trim().is_empty()) {
+            let migrated = keyring
+                .set_api_key(CROSS_ENCODER_KEYRING_ALIAS, legacy_key.trim())
+                .and_then(|_| {
+                    let json = serde_json::to_string(&config)?;
+                    database.set_setting(CROSS_ENCODER_SETTINGS_KEY, &json)
+                });
+            if let Err(e) = migrated {
+                tracing::warn!(error = %e, "Failed to move cross-encoder API key to keyring");
+            }
+            config.api_key = Some(legacy_key.trim().to_string());
+            return config;
+        }
+
+        config.api_key = match keyring.get_api_key(CROSS_ENCODER_KEYRING_ALIAS) {
+            Ok(key) => key.filter(|k| !k.is_empty()),
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read cross-encoder API key from keyring");
+                None
+            }
+        };
+        config
+    }
+}
+
+/// Scores (query, passage) pairs jointly, returning one relevance score per
+/// passage in input order.
+#[async_trait]
+pub trait CrossEncoderScorer: Send + Sync {
+    async fn score(&self, query: &str, passages: &[String]) -> AppResult<Vec<f32>>;
+}
+
+/// Scorer backed by a provider rerank API (`{model, query, documents}` in,
+/// `{results: [{index, relevance_score}]}` out).
+pub struct HttpRerankScorer {
+    client: reqwest::Client,
+    endpoint: String,
+    api_key: Option<String>,
+    model: Option<String>,
+}
+
+impl HttpRerankScorer {
+    pub fn new(
+        endpoint: impl Into<String>,
+        api_key: Option<String>,
+        model: Option<String>,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            endpoint: endpoint.into(),
+            api_key,
+            model,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RerankApiResponse {
+    results: Vec<RerankApiResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RerankApiResult {
+    index: usize,
+    relevance_score: f32,
+}
+
+#[async_trait]
+impl CrossEncoderScorer for HttpRerankScorer {
+    async fn score(&self, query: &str, passages: &[String]) -> AppResult<Vec<f32>> {
+        let mut body = serde_json::json!({
+            "query": query,
+            "documents": passages,
+            "top_n": passages.len(),
+        });
+        if let Some(model) = &self.model {
+            body["model"] = serde_json::Value::String(model.clone());
+        }
+
+        let mut request = self.client.post(&self.endpoint).json(&body);
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AppError::internal(format!("Rerank request failed: {}", e)))?
+            .error_for_status()
+            .map_err(|e| AppError::internal(format!("Rerank endpoint error: {}", e)))?
+            .json::<RerankApiResponse>()
+            .await
+            .map_err(|e| AppError::parse(format!("Invalid rerank response: {}", e)))?;
+
+        let mut scores = vec![0.0; passages.len()];
+        for result in response.results {
+            if let Some(slot) = scores.get_mut(result.index) {
+                *slot = result.relevance_score;
+            }
+        }
+        Ok(scores)
+    }
+}
+
+/// Reranker that re-scores the top-N candidates with a cross-encoder.
+///
+/// Candidates beyond `top_n` keep their retrieval order after the reranked
+/// head, with scores capped just below the lowest head score so the list
+/// stays sorted by score. Without a scorer, or when scoring errors or exceeds
+/// `timeout`, the fallback reranker handles the full list instead.
+pub struct CrossEncoderReranker {
+    scorer: Option<Arc<dyn CrossEncoderScorer>>,
+    fallback: Arc<dyn Reranker>,
+    top_n: usize,
+    timeout: Duration,
+}
+
+impl CrossEncoderReranker {
+    pub fn new(
+        scorer: Option<Arc<dyn CrossEncoderScorer>>,
+        fallback: Arc<dyn Reranker>,
+        top_n: usize,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            scorer,
+            fallback,
+            top_n: top_n.max(1),
+            timeout,
+        }
+    }
+
+    /// Build a reranker from config; an endpoint scorer is attached only when
+    /// the config is enabled and names an endpoint.
+    pub fn from_config(config: &CrossEncoderConfig, fallback: Arc<dyn Reranker>) -> Self {
+        let scorer: Option<Arc<dyn CrossEncoderScorer>> = match &config.endpoint {
+            Some(endpoint) if config.enabled && !endpoint.trim().is_empty() => {
+                Some(Arc::new(HttpRerankScorer::new(
+                    endpoint.trim(),
+                    config.api_key.clone(),
+                    config.model.clone(),
+                )))
+            }
+            _ => None,
+        };
+        Self::new(
+            scorer,
+            fallback,
+            config.top_n,
+            Duration::from_millis(config.timeout_ms),
+        )
+    }
+}
+
+#[async_trait]
+impl Reranker for CrossEncoderReranker {
+    async fn rerank(
+        &self,
+        query: &str,
+        mut results: Vec<SearchResult>,
+    ) -> AppResult<Vec<SearchResult>> {
+        let Some(scorer) = &self.scorer else {
+            return self.fallback.rerank(query, results).await;
+        };
+        if results.is_empty() {
+            return Ok(results);
+        }
+
+        let head_len = self.top_n.min(results.len());
+        let passages: Vec<String> = results[..head_len]
+            .iter()
+            .map(|r| r.chunk_text.clone())
+            .collect();
+
+        let scores = match tokio::time::timeout(self.timeout, scorer.score(query, &passages)).await
+        {
+            Ok(Ok(scores)) if scores.len() == head_len => scores,
+            Ok(Ok(scores)) => {
+                tracing::warn!(
+                    expected = head_len,
+                    got = scores.len(),
+                    "Cross-encoder returned wrong score count, using fallback reranker"
+                );
+                return self.fallback.rerank(query, results).await;
+            }
+            Ok(Err(e)) => {
+                tracing::warn!(error = %e, "Cross-encoder scoring failed, using fallback reranker");
+                return self.fallback.rerank(query, results).await;
+            }
+            Err(_) => {
+                tracing::warn!(
+                    timeout_ms = self.timeout.as_millis() as u64,
+                    "Cross-encoder scoring timed out, using fallback reranker"
+                );
+                return self.fallback.rerank(query, results).await;
+            }
+        };
+
+        let mut tail = results.split_off(head_len);
+        for (result, score) in results.iter_mut().zip(scores) {
+            result.score = score;
+        }
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        // Retrieval and cross-encoder scores are on different scales, so the
+        // tail is ranked strictly below the head instead of compared to it.
+        let floor = results
+            .iter()
+            .map(|r| r.score)
+            .fold(f32::INFINITY, f32::min);
+        let ceiling = floor - f32::EPSILON * floor.abs().max(1.0);
+        for result in &mut tail {
+            result.score = result.score.min(ceiling);
+        }
+        results.extend(tail);
+        Ok(results)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -272,6 +560,7 @@ mod tests {
             collection_name: "col".to_string(),
             score,
             metadata: Default::default(),
+            citation: None,
         }
     }
 
@@ -379,6 +668,98 @@ mod tests {
         assert!(LlmReranker::parse_scores("] some text [", 1).is_none());
     }
 
+    /// Stand-in cross-encoder that knows which passage actually answers the
+    /// question, regardless of word overlap.
+    struct JudgingScorer {
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl CrossEncoderScorer for JudgingScorer {
+        async fn score(&self, _query: &str, passages: &[String]) -> AppResult<Vec<f32>> {
+            tokio::time::sleep(self.delay).await;
+            Ok(passages
+                .iter()
+                .map(|p| {
+                    if p.contains("drop the guard") {
+                        0.95
+                    } else {
+                        0.2
+                    }
+                })
+                .collect())
+        }
+    }
+
+    fn lexical_vs_subtle() -> Vec<SearchResult> {
+        vec![
+            make_result(
+                "How to release a mutex lock in Rust: mutex lock release",
+                0.8,
+            ),
+            make_result(
+                "Locks are freed when you drop the guard at end of scope",
+                0.7,
+            ),
+            make_result("Unrelated tail chunk", 0.75),
+        ]
+    }
+
+    #[tokio::test]
+    async fn cross_encoder_moves_subtle_match_above_lexical_match() {
+        let query = "how to release a mutex lock in Rust";
+
+        // The keyword heuristic prefers the lexically similar chunk
+        let heuristic = LlmReranker::new()
+            .rerank(query, lexical_vs_subtle())
+            .await
+            .unwrap();
+        assert!(heuristic[0].chunk_text.starts_with("How to release"));
+
+        let reranker = CrossEncoderReranker::new(
+            Some(Arc::new(JudgingScorer {
+                delay: Duration::ZERO,
+            })),
+            Arc::new(LlmReranker::new()),
+            2,
+            Duration::from_secs(1),
+        );
+        let reranked = reranker.rerank(query, lexical_vs_subtle()).await.unwrap();
+        assert!(reranked[0].chunk_text.contains("drop the guard"));
+        assert!(reranked[1].chunk_text.starts_with("How to release"));
+        // Candidates beyond top_n keep their place after the reranked head
+        assert_eq!(reranked[2].chunk_text, "Unrelated tail chunk");
+        assert!((reranked[0].score - 0.95).abs() < 0.001);
+        assert!(reranked[2].score < reranked[1].score);
+    }
+
+    #[tokio::test]
+    async fn cross_encoder_falls_back_when_unconfigured_or_slow() {
+        let query = "how to release a mutex lock in Rust";
+
+        let unconfigured = CrossEncoderReranker::from_config(
+            &CrossEncoderConfig::default(),
+            Arc::new(NoopReranker),
+        );
+        let reranked = unconfigured
+            .rerank(query, lexical_vs_subtle())
+            .await
+            .unwrap();
+        assert!(reranked[0].chunk_text.starts_with("How to release"));
+        assert!((reranked[0].score - 0.8).abs() < 0.001);
+
+        let slow = CrossEncoderReranker::new(
+            Some(Arc::new(JudgingScorer {
+                delay: Duration::from_millis(500),
+            })),
+            Arc::new(NoopReranker),
+            10,
+            Duration::from_millis(20),
+        );
+        let reranked = slow.rerank(query, lexical_vs_subtle()).await.unwrap();
+        assert!(reranked[0].chunk_text.starts_with("How to release"));
+    }
+
     #[test]
     fn parse_scores_extracts_from_preamble() {
         // LLM may include preamble text before the JSON array
@@ -5,7 +5,13 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::models::markdown::{ClaudeMdContent, ClaudeMdFile, FileMetadata};
+use crate::models::markdown::{
+    ClaudeMdContent, ClaudeMdFile, ClaudeMdMergeReport, EffectiveClaudeMd, FileMetadata,
+};
+use crate::services::claude_md_hierarchy;
+use crate::services::claude_md_sections::{
+    managed_sections, merge_claude_md, stamp_managed_sections,
+};
 use crate::utils::error::{AppError, AppResult};
 
 /// Service for managing CLAUDE.md files
@@ -208,7 +214,31 @@ impl MarkdownService {
             )));
         }
 
-        self.save_claude_md(path, template_content)
+        self.save_claude_md(path, &stamp_managed_sections(template_content))
+    }
+
+    /// Save regenerated content, replacing only the tool-managed sections.
+    ///
+    /// User-authored text is preserved verbatim; managed sections edited by
+    /// hand get conflict markers instead of being overwritten.
+    pub fn merge_claude_md(&self, path: &str, generated: &str) -> AppResult<ClaudeMdMergeReport> {
+        let file_path = PathBuf::from(path);
+        let existing = if file_path.is_file() {
+            fs::read_to_string(&file_path)?
+        } else {
+            String::new()
+        };
+
+        let merged = merge_claude_md(&existing, generated);
+        self.save_claude_md(path, &merged.content)?;
+        Ok(merged.report)
+    }
+
+    /// Resolve the effective instructions for `path` from the user-level,
+    /// repository-root and subdirectory CLAUDE.md files, nearer files first.
+    pub fn resolve_effective_claude_md(&self, path: &str) -> AppResult<EffectiveClaudeMd> {
+        let user_dir = dirs::home_dir().map(|home| home.join(".claude"));
+        claude_md_hierarchy::resolve_effective_claude_md(Path::new(path), user_dir.as_deref())
     }
 
     /// Get file metadata for a CLAUDE.md file
@@ -231,11 +261,16 @@ impl MarkdownService {
             datetime.to_rfc3339()
         });
 
+        let managed_sections = fs::read_to_string(&file_path)
+            .map(|content| managed_sections(&content))
+            .unwrap_or_default();
+
         Ok(FileMetadata {
             path: path.to_string(),
             size: metadata.len(),
             modified_at,
             created_at,
+            managed_sections,
         })
     }
 }
@@ -366,5 +401,43 @@ mod tests {
 
         assert_eq!(metadata.size, content.len() as u64);
         assert!(metadata.modified_at.is_some());
+        assert!(metadata.managed_sections.is_empty());
+    }
+
+    #[test]
+    fn test_merge_claude_md_preserves_user_sections() {
+        let temp_dir = TempDir::new().unwrap();
+        let claude_md_path = temp_dir.path().join("CLAUDE.md");
+        let path = claude_md_path.to_str().unwrap();
+        let generated = |body: &str| {
+            format!(
+                "<!-- plan-cascade:begin overview -->\n{}<!-- plan-cascade:end overview -->\n",
+                body
+            )
+        };
+
+        let service = MarkdownService::new();
+        service
+            .merge_claude_md(path, &generated("Rust app.\n"))
+            .unwrap();
+        let with_user = format!(
+            "# Team rules\nNo force pushes.\n\n{}",
+            fs::read_to_string(&claude_md_path).unwrap()
+        );
+        fs::write(&claude_md_path, with_user).unwrap();
+
+        let report = service
+            .merge_claude_md(path, &generated("Rust + Tauri app.\n"))
+            .unwrap();
+        assert!(report.conflicts.is_empty());
+
+        let saved = fs::read_to_string(&claude_md_path).unwrap();
+        assert!(saved.starts_with("# Team rules\nNo force pushes.\n\n"));
+        assert!(saved.contains("Rust + Tauri app."));
+
+        let metadata = service.get_file_metadata(path).unwrap();
+        assert_eq!(metadata.managed_sections.len(), 1);
+        assert_eq!(metadata.managed_sections[0].id, "overview");
+        assert!(!metadata.managed_sections[0].edited);
     }
 }
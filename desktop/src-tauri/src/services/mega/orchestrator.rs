@@ -3,7 +3,7 @@
 //! Orchestrates multi-feature development with dependency-aware batch execution.
 //! Creates isolated worktrees for each feature and manages parallel execution.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -14,10 +14,14 @@ use tokio_util::sync::CancellationToken;
 use crate::models::mega::{
     Feature, FeatureState, FeatureStatus, MegaExecutionStatus, MegaPlan, MegaStatus,
 };
-use crate::models::prd::Prd;
+use crate::models::prd::{Prd, Story, StoryStatus};
 use crate::models::worktree::CreateWorktreeRequest;
+use crate::services::dependency::{Batch, DependencyAnalyzer, DependencyError};
 use crate::services::worktree::WorktreeManager;
 
+/// File (relative to the project root) the execution checkpoint is saved to
+const MEGA_STATUS_FILE: &str = ".mega-status.json";
+
 /// Configuration for the mega orchestrator
 #[derive(Debug, Clone)]
 pub struct MegaOrchestratorConfig {
@@ -29,6 +33,9 @@ pub struct MegaOrchestratorConfig {
     pub auto_generate_prds: bool,
     /// Poll interval for status updates (seconds)
     pub poll_interval_seconds: u64,
+    /// Inter-feature dependencies in addition to those declared in the plan,
+    /// keyed by feature ID (e.g. feature B needs feature A's API)
+    pub feature_dependencies: HashMap<String, Vec<String>>,
 }
 
 impl Default for MegaOrchestratorConfig {
@@ -38,6 +45,7 @@ impl Default for MegaOrchestratorConfig {
             max_concurrent: 3,
             auto_generate_prds: true,
             poll_interval_seconds: 5,
+            feature_dependencies: HashMap::new(),
         }
     }
 }
@@ -60,6 +68,9 @@ pub enum MegaOrchestratorError {
     #[error("Dependency error: {0}")]
     DependencyError(String),
 
+    #[error("Circular feature dependency: {}", .0.join(" -> "))]
+    CircularDependency(Vec<String>),
+
     #[error("Cancelled")]
     Cancelled,
 
@@ -152,15 +163,74 @@ impl MegaOrchestrator {
         self.status.read().await.clone()
     }
 
+    /// Dependencies of a feature: those declared in the plan plus any
+    /// configured in `MegaOrchestratorConfig::feature_dependencies`
+    fn effective_dependencies(&self, feature: &Feature) -> Vec<String> {
+        let mut dependencies = feature.dependencies.clone();
+        if let Some(extra) = self.config.feature_dependencies.get(&feature.id) {
+            for dep in extra {
+                if !dependencies.contains(dep) {
+                    dependencies.push(dep.clone());
+                }
+            }
+        }
+        dependencies
+    }
+
+    /// Resolve the feature execution order as dependency batches.
+    ///
+    /// Features are mapped onto stories so the same `DependencyAnalyzer` that
+    /// orders PRD stories validates and orders features; completed features
+    /// are treated as already satisfied. Each batch holds features that can
+    /// run in parallel.
+    pub async fn feature_batches(&self) -> Result<Vec<Batch>, MegaOrchestratorError> {
+        let plan = self.mega_plan.read().await;
+        let status = self.status.read().await;
+
+        let mut graph = Prd::new(&plan.name);
+        for feature in &plan.features {
+            let mut story = Story::new(&feature.id, &feature.name);
+            story.dependencies = self.effective_dependencies(feature);
+            if status
+                .features
+                .get(&feature.id)
+                .is_some_and(|s| s.status == FeatureStatus::Completed)
+            {
+                story.status = StoryStatus::Completed;
+            }
+            graph.stories.push(story);
+        }
+
+        DependencyAnalyzer::generate_batches(&graph).map_err(|e| match e {
+            DependencyError::CircularDependency(cycle) => {
+                MegaOrchestratorError::CircularDependency(cycle)
+            }
+            DependencyError::UnknownDependency { story, dependency } => {
+                MegaOrchestratorError::DependencyError(format!(
+                    "Feature '{}' depends on unknown feature '{}'",
+                    story, dependency
+                ))
+            }
+            other => MegaOrchestratorError::DependencyError(other.to_string()),
+        })
+    }
+
     /// Execute the mega plan with full automation
     pub async fn execute_auto(
         &self,
         event_tx: mpsc::Sender<MegaEvent>,
     ) -> Result<MegaStatus, MegaOrchestratorError> {
-        // Start execution
+        // Reject unknown or circular feature dependencies before touching anything
+        self.feature_batches().await?;
+
+        // Start execution (a resumed run keeps its original start time)
         {
             let mut status = self.status.write().await;
-            status.start();
+            if status.started_at.is_none() {
+                status.start();
+            } else {
+                status.status = MegaExecutionStatus::Running;
+            }
         }
 
         let plan_id = {
@@ -204,6 +274,19 @@ impl MegaOrchestrator {
                     return Err(MegaOrchestratorError::Cancelled);
                 }
 
+                // Reuse the worktree of a feature interrupted by a previous run
+                let existing_worktree = {
+                    let status = self.status.read().await;
+                    status
+                        .features
+                        .get(&feature.id)
+                        .and_then(|s| s.worktree.clone())
+                        .filter(|path| path.exists())
+                };
+                if existing_worktree.is_some() {
+                    continue;
+                }
+
                 match self
                     .create_feature_worktree(&feature.id, &target_branch)
                     .await
@@ -234,12 +317,28 @@ impl MegaOrchestrator {
                 }
             }
 
-            // Generate PRDs for each feature if configured
+            // Generate PRDs for each feature if configured, skipping PRDs a
+            // previous run already generated
             if self.config.auto_generate_prds {
-                self.generate_prds_parallel(&batch, event_tx.clone())
+                let needs_prd: Vec<Feature> = {
+                    let status = self.status.read().await;
+                    batch
+                        .iter()
+                        .filter(|f| {
+                            !status.features.get(&f.id).is_some_and(|s| {
+                                s.prd_generated && s.prd_path.as_ref().is_some_and(|p| p.exists())
+                            })
+                        })
+                        .cloned()
+                        .collect()
+                };
+                self.generate_prds_parallel(&needs_prd, event_tx.clone())
                     .await?;
             }
 
+            // Checkpoint the batch's artifacts before executing
+            self.save_status().await?;
+
             // Execute features in parallel
             self.execute_features_parallel(&batch, event_tx.clone())
                 .await?;
@@ -264,10 +363,13 @@ impl MegaOrchestrator {
 
                 if feature_status == FeatureStatus::InProgress {
                     match self.complete_feature(&feature.id).await {
-                        Ok(_) => {
+                        Ok(commit_message) => {
                             let mut status = self.status.write().await;
                             if let Some(state) = status.features.get_mut(&feature.id) {
                                 state.complete();
+                                if let Some(message) = commit_message {
+                                    state.outputs.insert("commit_message".to_string(), message);
+                                }
                             }
 
                             let _ = event_tx
@@ -290,6 +392,10 @@ impl MegaOrchestrator {
                                 .await;
                         }
                     }
+
+                    // Checkpoint each finished feature so a crash later in the
+                    // batch never re-runs it
+                    self.save_status().await?;
                 }
             }
 
@@ -376,7 +482,10 @@ impl MegaOrchestrator {
                     .unwrap_or(FeatureStatus::Pending);
 
                 feature_status == FeatureStatus::Pending
-                    && f.dependencies.iter().all(|dep| completed.contains(dep))
+                    && self
+                        .effective_dependencies(f)
+                        .iter()
+                        .all(|dep| completed.contains(dep))
             })
             .take(self.config.max_concurrent)
             .cloned()
@@ -573,7 +682,12 @@ impl MegaOrchestrator {
     }
 
     /// Complete a feature (commit, merge, cleanup)
-    async fn complete_feature(&self, feature_id: &str) -> Result<(), MegaOrchestratorError> {
+    ///
+    /// Returns the commit message used when a worktree was completed.
+    async fn complete_feature(
+        &self,
+        feature_id: &str,
+    ) -> Result<Option<String>, MegaOrchestratorError> {
         let worktree_path = {
             let status = self.status.read().await;
             status
@@ -590,15 +704,17 @@ impl MegaOrchestrator {
                 .complete_worktree(&self.config.project_root, feature_id, Some(&commit_message))
                 .await
                 .map_err(|e| MegaOrchestratorError::WorktreeError(e.to_string()))?;
+
+            return Ok(Some(commit_message));
         }
 
-        Ok(())
+        Ok(None)
     }
 
     /// Save current status to file
     async fn save_status(&self) -> Result<(), MegaOrchestratorError> {
         let status = self.status.read().await;
-        let status_path = self.config.project_root.join(".mega-status.json");
+        let status_path = self.config.project_root.join(MEGA_STATUS_FILE);
 
         status
             .to_file(&status_path)
@@ -611,7 +727,7 @@ impl MegaOrchestrator {
     pub async fn load_status(
         project_root: &Path,
     ) -> Result<Option<MegaStatus>, MegaOrchestratorError> {
-        let status_path = project_root.join(".mega-status.json");
+        let status_path = project_root.join(MEGA_STATUS_FILE);
 
         if !status_path.exists() {
             return Ok(None);
@@ -623,19 +739,30 @@ impl MegaOrchestrator {
         Ok(Some(status))
     }
 
+    /// Load the checkpoint of an interrupted run, if there is one to resume
+    pub async fn find_resumable(
+        project_root: &Path,
+    ) -> Result<Option<MegaStatus>, MegaOrchestratorError> {
+        Ok(Self::load_status(project_root)
+            .await?
+            .filter(MegaStatus::is_resumable))
+    }
+
     /// Resume execution from saved status
+    ///
+    /// Completed features are skipped; features that were in progress are
+    /// re-run, reusing any worktree or PRD they had already produced.
     pub async fn resume(
         &self,
         event_tx: mpsc::Sender<MegaEvent>,
     ) -> Result<MegaStatus, MegaOrchestratorError> {
         // Load saved status
-        if let Some(saved_status) = Self::load_status(&self.config.project_root).await? {
+        if let Some(mut saved_status) = Self::load_status(&self.config.project_root).await? {
+            saved_status.prepare_resume();
+
             // Update our status with the saved one
-            {
-                let mut status = self.status.write().await;
-                *status = saved_status;
-                status.status = MegaExecutionStatus::Running;
-            }
+            let mut status = self.status.write().await;
+            *status = saved_status;
         }
 
         // Continue execution
@@ -702,4 +829,194 @@ mod tests {
         orchestrator.cancel();
         assert!(orchestrator.cancellation_token.is_cancelled());
     }
+
+    /// Plan with three independent features, listed in reverse of the
+    /// order the configured dependencies require
+    fn create_chain_plan() -> (MegaPlan, HashMap<String, Vec<String>>) {
+        let mut plan = MegaPlan::new("Chain Project");
+        plan.add_feature(Feature::new("F003", "Dashboard"));
+        plan.add_feature(Feature::new("F002", "API"));
+        plan.add_feature(Feature::new("F001", "Schema"));
+
+        let mut dependencies = HashMap::new();
+        dependencies.insert("F003".to_string(), vec!["F002".to_string()]);
+        dependencies.insert("F002".to_string(), vec!["F001".to_string()]);
+        (plan, dependencies)
+    }
+
+    #[tokio::test]
+    async fn test_feature_dependency_chain_executes_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let (plan, feature_dependencies) = create_chain_plan();
+        let config = MegaOrchestratorConfig {
+            project_root: dir.path().to_path_buf(),
+            auto_generate_prds: false,
+            poll_interval_seconds: 0,
+            feature_dependencies,
+            ..Default::default()
+        };
+        let orchestrator = MegaOrchestrator::new(config, plan);
+
+        let batches = orchestrator.feature_batches().await.unwrap();
+        let order: Vec<Vec<String>> = batches.into_iter().map(|b| b.story_ids).collect();
+        assert_eq!(order, vec![vec!["F001"], vec!["F002"], vec!["F003"]]);
+
+        let (tx, mut rx) = mpsc::channel(100);
+        let status = orchestrator.execute_auto(tx).await.unwrap();
+        assert_eq!(status.status, MegaExecutionStatus::Completed);
+
+        let mut started = Vec::new();
+        let mut batch_count = 0;
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                MegaEvent::FeatureStarted { feature_id } => started.push(feature_id),
+                MegaEvent::BatchStarted { feature_count, .. } => {
+                    assert_eq!(feature_count, 1);
+                    batch_count += 1;
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(started, vec!["F001", "F002", "F003"]);
+        assert_eq!(batch_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_independent_features_share_a_batch() {
+        let (plan, mut feature_dependencies) = create_chain_plan();
+        // F003 now only needs F001, so it can run alongside F002
+        feature_dependencies.insert("F003".to_string(), vec!["F001".to_string()]);
+        let config = MegaOrchestratorConfig {
+            feature_dependencies,
+            ..Default::default()
+        };
+        let orchestrator = MegaOrchestrator::new(config, plan);
+
+        let batches = orchestrator.feature_batches().await.unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].story_ids, vec!["F001"]);
+        assert_eq!(batches[1].story_ids, vec!["F003", "F002"]);
+    }
+
+    #[tokio::test]
+    async fn test_feature_dependency_cycle_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let (plan, mut feature_dependencies) = create_chain_plan();
+        feature_dependencies.insert("F001".to_string(), vec!["F003".to_string()]);
+        let config = MegaOrchestratorConfig {
+            project_root: dir.path().to_path_buf(),
+            feature_dependencies,
+            ..Default::default()
+        };
+        let orchestrator = MegaOrchestrator::new(config, plan);
+
+        let (tx, _rx) = mpsc::channel(100);
+        match orchestrator.execute_auto(tx).await {
+            Err(MegaOrchestratorError::CircularDependency(cycle)) => {
+                for id in ["F001", "F002", "F003"] {
+                    assert!(
+                        cycle.iter().any(|c| c == id),
+                        "{} missing from {:?}",
+                        id,
+                        cycle
+                    );
+                }
+            }
+            other => panic!(
+                "Expected CircularDependency, got {:?}",
+                other.map(|s| s.status)
+            ),
+        }
+
+        // Nothing was started
+        let status = orchestrator.get_status().await;
+        assert_eq!(status.status, MegaExecutionStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_resume_after_crash_skips_completed_feature() {
+        let dir = tempfile::tempdir().unwrap();
+        let (plan, feature_dependencies) = create_chain_plan();
+        let config = MegaOrchestratorConfig {
+            project_root: dir.path().to_path_buf(),
+            poll_interval_seconds: 0,
+            feature_dependencies,
+            ..Default::default()
+        };
+
+        // Checkpoint left behind by a run that crashed while F002 was executing
+        // after F001 had finished
+        let f2_prd = dir.path().join("f002-prd.json");
+        std::fs::write(&f2_prd, "{\"generated\": \"before crash\"}").unwrap();
+        let mut crashed = MegaStatus::new("Chain Project");
+        crashed.start();
+        let mut f1 = FeatureState::default();
+        f1.start();
+        f1.complete();
+        f1.outputs
+            .insert("commit_message".to_string(), "feat(F001): done".to_string());
+        let f1_completed_at = f1.completed_at.clone();
+        crashed.features.insert("F001".to_string(), f1);
+        let mut f2 = FeatureState {
+            prd_generated: true,
+            prd_path: Some(f2_prd.clone()),
+            ..Default::default()
+        };
+        f2.start();
+        crashed.features.insert("F002".to_string(), f2);
+        crashed.current_batch = 1;
+        crashed.completed_batches = vec![0];
+        crashed.to_file(&dir.path().join(MEGA_STATUS_FILE)).unwrap();
+
+        let resumable = MegaOrchestrator::find_resumable(dir.path())
+            .await
+            .unwrap()
+            .expect("interrupted run should be resumable");
+        assert_eq!(resumable.features.len(), 2);
+
+        // A fresh orchestrator, as after an application restart
+        let orchestrator = MegaOrchestrator::new(config, plan);
+        let (tx, mut rx) = mpsc::channel(100);
+        let status = orchestrator.resume(tx).await.unwrap();
+
+        let mut started = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            if let MegaEvent::FeatureStarted { feature_id } = event {
+                started.push(feature_id);
+            }
+        }
+
+        // F001 is not repeated; F002 continues with its existing PRD
+        assert_eq!(started, vec!["F002", "F003"]);
+        assert_eq!(status.status, MegaExecutionStatus::Completed);
+
+        let f1 = &status.features["F001"];
+        assert_eq!(f1.completed_at, f1_completed_at);
+        assert_eq!(f1.outputs["commit_message"], "feat(F001): done");
+        assert_eq!(status.features["F002"].prd_path, Some(f2_prd.clone()));
+        assert_eq!(
+            std::fs::read_to_string(&f2_prd).unwrap(),
+            "{\"generated\": \"before crash\"}"
+        );
+
+        // The final checkpoint is no longer resumable
+        assert!(MegaOrchestrator::find_resumable(dir.path())
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_feature_dependency_is_an_error() {
+        let (plan, mut feature_dependencies) = create_chain_plan();
+        feature_dependencies.insert("F001".to_string(), vec!["F999".to_string()]);
+        let config = MegaOrchestratorConfig {
+            feature_dependencies,
+            ..Default::default()
+        };
+        let orchestrator = MegaOrchestrator::new(config, plan);
+
+        let err = orchestrator.feature_batches().await.unwrap_err();
+        assert!(matches!(err, MegaOrchestratorError::DependencyError(msg) if msg.contains("F999")));
+    }
 }
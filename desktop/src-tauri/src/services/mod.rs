@@ -10,16 +10,20 @@ pub mod agent_executor;
 pub mod analytics;
 pub mod artifacts;
 pub mod claude_code;
+pub mod claude_md_hierarchy;
+pub mod claude_md_sections;
 pub mod context;
 pub mod core;
 pub mod debug_mode;
 pub mod dependency;
 pub mod design;
+pub mod document_export;
 pub mod fallback;
 pub mod file_change_tracker;
 pub mod git;
 pub mod graph_workflow;
 pub mod guardrail;
+pub mod health_cache;
 pub mod iteration;
 pub mod knowledge;
 pub mod llm;
@@ -38,6 +42,7 @@ pub mod plugins;
 pub mod project;
 pub mod prompt;
 pub mod proxy;
+pub mod proxy_diagnostics;
 pub mod quality_gates;
 pub mod recovery;
 pub mod remote;
@@ -5,6 +5,13 @@
 //! agentic loop, and the Analyze tool defaults to quick mode instead of using
 //! keyword-based scope detection. These functions are retained for reference
 //! and may be removed in a future cleanup.
+//!
+//! `select_files_within_budget` is the token-budget-aware replacement for a
+//! fixed file count: candidates are ranked by classifier, recency and
+//! embedding-similarity signals and packed into a budget derived from the
+//! model's context window.
+
+use super::component_classifier::FileClassification;
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -266,9 +273,235 @@ pub(super) fn is_exploration_task(message: &str) -> bool {
     has_en_analysis && has_en_context
 }
 
+// ---------------------------------------------------------------------------
+// Token-budget file selection
+// ---------------------------------------------------------------------------
+
+/// Share of the context window reserved for file context.
+const SCOPE_CONTEXT_SHARE: f64 = 0.4;
+const MIN_SCOPE_TOKEN_BUDGET: usize = 4_000;
+const MAX_SCOPE_TOKEN_BUDGET: usize = 120_000;
+/// Age at which the recency signal has decayed to one half.
+const RECENCY_HALF_LIFE_SECS: f32 = 24.0 * 60.0 * 60.0;
+
+/// Token budget for analysis file context given a model's context window.
+#[allow(dead_code)]
+pub(super) fn scope_token_budget(context_window: u32) -> usize {
+    ((context_window as f64 * SCOPE_CONTEXT_SHARE) as usize)
+        .clamp(MIN_SCOPE_TOKEN_BUDGET, MAX_SCOPE_TOKEN_BUDGET)
+}
+
+/// Relevance of a classified file to a task that touches `task_labels`:
+/// the best confidence among the matching labels, 0.0 if unclassified.
+#[allow(dead_code)]
+pub(super) fn classifier_relevance(
+    classification: &FileClassification,
+    task_labels: &[&str],
+) -> f32 {
+    task_labels
+        .iter()
+        .map(|label| classification.confidence_for(label))
+        .fold(0.0, f32::max)
+}
+
+/// A file that may be included in the analysis context.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub(super) struct ScopeCandidate {
+    pub path: String,
+    pub estimated_tokens: usize,
+    /// Classifier relevance in `[0, 1]` (see `classifier_relevance`).
+    pub classifier_score: Option<f32>,
+    /// Seconds since the file was last modified.
+    pub modified_secs_ago: Option<u64>,
+    /// Embedding similarity to the task in `[0, 1]`.
+    pub similarity: Option<f32>,
+}
+
+/// How much each signal contributes to a candidate's relevance.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub(super) struct RelevanceWeights {
+    pub classifier: f32,
+    pub recency: f32,
+    pub similarity: f32,
+}
+
+impl Default for RelevanceWeights {
+    fn default() -> Self {
+        Self {
+            classifier: 0.3,
+            recency: 0.2,
+            similarity: 0.5,
+        }
+    }
+}
+
+impl ScopeCandidate {
+    /// Weighted relevance in `[0, 1]`. Missing signals are left out and the
+    /// remaining weights renormalized, so a file is not penalized for a
+    /// signal that was never computed.
+    #[allow(dead_code)]
+    pub(super) fn relevance(&self, weights: &RelevanceWeights) -> f32 {
+        let recency = self
+            .modified_secs_ago
+            .map(|age| RECENCY_HALF_LIFE_SECS / (RECENCY_HALF_LIFE_SECS + age as f32));
+        let signals = [
+            (self.classifier_score, weights.classifier),
+            (recency, weights.recency),
+            (self.similarity, weights.similarity),
+        ];
+
+        let (sum, total_weight) = signals
+            .iter()
+            .filter_map(|(value, weight)| value.map(|v| (v.clamp(0.0, 1.0) * weight, *weight)))
+            .fold((0.0, 0.0), |(s, w), (v, wt)| (s + v, w + wt));
+        if total_weight > 0.0 {
+            sum / total_weight
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A candidate with its computed relevance.
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct ScoredFile {
+    pub path: String,
+    pub relevance: f32,
+    pub estimated_tokens: usize,
+}
+
+/// Outcome of a budgeted selection.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub(super) struct ScopeSelection {
+    /// Selected files, most relevant first.
+    pub included: Vec<ScoredFile>,
+    /// Files that did not fit, most relevant first.
+    pub excluded: Vec<ScoredFile>,
+    pub budget_tokens: usize,
+    pub used_tokens: usize,
+}
+
+/// Rank candidates by relevance and pack them into `budget_tokens`.
+///
+/// Files are visited from most to least relevant; a file that does not fit
+/// in the remaining budget is excluded, but smaller, less relevant files may
+/// still fill the space left behind. The total never exceeds the budget.
+#[allow(dead_code)]
+pub(super) fn select_files_within_budget(
+    candidates: &[ScopeCandidate],
+    weights: &RelevanceWeights,
+    budget_tokens: usize,
+) -> ScopeSelection {
+    let mut scored: Vec<ScoredFile> = candidates
+        .iter()
+        .map(|c| ScoredFile {
+            path: c.path.clone(),
+            relevance: c.relevance(weights),
+            estimated_tokens: c.estimated_tokens,
+        })
+        .collect();
+    scored.sort_by(|a, b| {
+        b.relevance
+            .total_cmp(&a.relevance)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    let mut included = Vec::new();
+    let mut excluded = Vec::new();
+    let mut used_tokens = 0;
+    for file in scored {
+        if used_tokens + file.estimated_tokens <= budget_tokens {
+            used_tokens += file.estimated_tokens;
+            included.push(file);
+        } else {
+            excluded.push(file);
+        }
+    }
+
+    ScopeSelection {
+        included,
+        excluded,
+        budget_tokens,
+        used_tokens,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::orchestrator::component_classifier::{
+        classify_file_concerns, DEFAULT_CONCERN_CONFIDENCE_THRESHOLD,
+    };
+
+    fn candidate(path: &str, tokens: usize, similarity: f32) -> ScopeCandidate {
+        ScopeCandidate {
+            path: path.to_string(),
+            estimated_tokens: tokens,
+            classifier_score: None,
+            modified_secs_ago: None,
+            similarity: Some(similarity),
+        }
+    }
+
+    #[test]
+    fn test_selection_stops_at_budget_and_prefers_relevance() {
+        let candidates = vec![
+            candidate("src/low.rs", 300, 0.1),
+            candidate("src/top.rs", 400, 0.9),
+            candidate("src/large.rs", 700, 0.6),
+            candidate("src/second.rs", 400, 0.8),
+            candidate("src/small.rs", 150, 0.3),
+        ];
+        let selection =
+            select_files_within_budget(&candidates, &RelevanceWeights::default(), 1_000);
+
+        let included: Vec<&str> = selection.included.iter().map(|f| f.path.as_str()).collect();
+        let excluded: Vec<&str> = selection.excluded.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            included,
+            vec!["src/top.rs", "src/second.rs", "src/small.rs"]
+        );
+        assert_eq!(excluded, vec!["src/large.rs", "src/low.rs"]);
+        assert_eq!(selection.used_tokens, 950);
+        assert!(selection.used_tokens <= selection.budget_tokens);
+    }
+
+    #[test]
+    fn test_relevance_combines_classifier_recency_and_similarity() {
+        let api = classify_file_concerns(
+            "src/api/routes.rs",
+            "#[tauri::command]\n#[tauri::command]\n",
+            DEFAULT_CONCERN_CONFIDENCE_THRESHOLD,
+        );
+        let classifier_score = classifier_relevance(&api, &["api", "model"]);
+        assert!(classifier_score >= DEFAULT_CONCERN_CONFIDENCE_THRESHOLD);
+
+        let fresh = ScopeCandidate {
+            path: "src/api/routes.rs".into(),
+            estimated_tokens: 100,
+            classifier_score: Some(classifier_score),
+            modified_secs_ago: Some(60),
+            similarity: Some(0.7),
+        };
+        let stale = ScopeCandidate {
+            modified_secs_ago: Some(30 * 24 * 60 * 60),
+            ..fresh.clone()
+        };
+        let weights = RelevanceWeights::default();
+        assert!(fresh.relevance(&weights) > stale.relevance(&weights));
+        assert_eq!(candidate("a.rs", 1, 0.7).relevance(&weights), 0.7);
+    }
+
+    #[test]
+    fn test_scope_token_budget_scales_with_context_window() {
+        assert_eq!(scope_token_budget(8_000), MIN_SCOPE_TOKEN_BUDGET);
+        assert_eq!(scope_token_budget(128_000), 51_200);
+        assert_eq!(scope_token_budget(1_000_000), MAX_SCOPE_TOKEN_BUDGET);
+    }
 
     #[test]
     fn test_is_exploration_task_chinese() {
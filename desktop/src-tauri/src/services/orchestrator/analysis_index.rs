@@ -3,6 +3,7 @@
 //! The analysis pipeline uses this module to build a deterministic file inventory
 //! and split it into stable chunks. Chunk summaries are then merged upstream.
 
+use super::tree_sitter_parser::ParseCache;
 use crate::utils::error::AppResult;
 use ignore::WalkBuilder;
 use regex::Regex;
@@ -263,6 +264,138 @@ pub fn build_file_inventory_with_limits(
     excluded_roots: &[String],
     excluded_extensions: &[String],
     limits: &AnalysisLimits,
+) -> AppResult<FileInventory> {
+    build_inventory(
+        project_root,
+        excluded_roots,
+        excluded_extensions,
+        limits,
+        None,
+        None,
+    )
+}
+
+/// Like `build_file_inventory_with_limits`, reusing parse trees from
+/// `parse_cache` for files whose content has not changed.
+pub fn build_file_inventory_with_cache(
+    project_root: &Path,
+    excluded_roots: &[String],
+    excluded_extensions: &[String],
+    limits: &AnalysisLimits,
+    parse_cache: &ParseCache,
+) -> AppResult<FileInventory> {
+    build_inventory(
+        project_root,
+        excluded_roots,
+        excluded_extensions,
+        limits,
+        None,
+        Some(parse_cache),
+    )
+}
+
+/// Per-file analysis result, reusable while the file content hash is unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileAnalysisCacheEntry {
+    pub content_hash: String,
+    pub line_count: usize,
+    pub symbols: Vec<SymbolInfo>,
+    /// Unix timestamp (seconds) of the analysis that produced this entry
+    pub analyzed_at: i64,
+}
+
+/// Incremental analysis cache keyed by relative file path.
+///
+/// An entry is reused only when the file's content hash matches; the TTL is a
+/// backstop that forces periodic re-analysis of unchanged files.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FileAnalysisCache {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub entries: BTreeMap<String, FileAnalysisCacheEntry>,
+}
+
+impl FileAnalysisCache {
+    /// Load a cache file, starting empty when it is missing or unreadable.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist the cache, creating the parent directory if needed.
+    pub fn save(&self, path: &Path) -> AppResult<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// Counts from an incremental inventory build.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IncrementalInventoryStats {
+    /// Files served from the cache
+    pub reused: usize,
+    /// Files (re-)analyzed because they were new, edited, or expired
+    pub analyzed: usize,
+    /// Cache entries dropped because the file no longer exists
+    pub evicted: usize,
+}
+
+struct IncrementalState<'a> {
+    cache: &'a mut FileAnalysisCache,
+    ttl_secs: i64,
+    now: i64,
+    seen: HashSet<String>,
+    stats: IncrementalInventoryStats,
+}
+
+/// Build the inventory, re-analyzing only files whose content changed since
+/// they were cached and merging cached results for the rest.
+pub fn build_file_inventory_incremental(
+    project_root: &Path,
+    excluded_roots: &[String],
+    excluded_extensions: &[String],
+    limits: &AnalysisLimits,
+    cache: &mut FileAnalysisCache,
+    ttl_secs: i64,
+) -> AppResult<(FileInventory, IncrementalInventoryStats)> {
+    let mut state = IncrementalState {
+        cache,
+        ttl_secs,
+        now: chrono::Utc::now().timestamp(),
+        seen: HashSet::new(),
+        stats: IncrementalInventoryStats::default(),
+    };
+    let inventory = build_inventory(
+        project_root,
+        excluded_roots,
+        excluded_extensions,
+        limits,
+        Some(&mut state),
+        None,
+    )?;
+
+    let before = state.cache.entries.len();
+    let seen = std::mem::take(&mut state.seen);
+    state.cache.entries.retain(|path, _| seen.contains(path));
+    state.stats.evicted = before - state.cache.entries.len();
+    state.cache.version = 1;
+
+    Ok((inventory, state.stats))
+}
+
+fn build_inventory(
+    project_root: &Path,
+    excluded_roots: &[String],
+    excluded_extensions: &[String],
+    limits: &AnalysisLimits,
+    mut incremental: Option<&mut IncrementalState<'_>>,
+    parse_cache: Option<&ParseCache>,
 ) -> AppResult<FileInventory> {
     let max_symbol_file_size: u64 = 500_000; // 500KB threshold for symbol extraction
     let mut excluded = HashSet::new();
@@ -327,15 +460,14 @@ pub fn build_file_inventory_with_limits(
             Ok(meta) => meta,
             Err(_) => continue,
         };
-        let language = detect_language(ext_lower.as_deref());
+        let mut language = detect_language(ext_lower.as_deref());
         let is_test = is_test_path(&rel_norm);
         let component = detect_component_heuristic(&rel_norm);
-        let line_count = estimate_line_count(path, metadata.len()).unwrap_or(0);
 
         // Single disk read: compute content hash and extract symbols from the
         // same bytes so that downstream consumers (run_full_index, catch-up sync)
         // do not need to re-read the file.
-        let (symbols, content_hash) = if metadata.len() <= max_symbol_file_size {
+        let (symbols, content_hash, line_count) = if metadata.len() <= max_symbol_file_size {
             match fs::read(path) {
                 Ok(bytes) => {
                     let hash = {
@@ -343,17 +475,62 @@ pub fn build_file_inventory_with_limits(
                         hasher.update(&bytes);
                         format!("{:x}", hasher.finalize())
                     };
+                    // Extensionless scripts and ambiguous headers need the
+                    // content (shebang, C++ markers) to pick a grammar.
+                    if language == "other" || language == "c" {
+                        if let Ok(text) = std::str::from_utf8(&bytes) {
+                            language =
+                                super::tree_sitter_parser::detect_file_language(&rel_norm, text);
+                        }
+                    }
+                    if let Some(state) = incremental.as_deref_mut() {
+                        state.seen.insert(rel_norm.clone());
+                        let cached = state.cache.entries.get(&rel_norm).filter(|entry| {
+                            entry.content_hash == hash
+                                && state.now - entry.analyzed_at <= state.ttl_secs
+                        });
+                        if let Some(entry) = cached {
+                            state.stats.reused += 1;
+                            let (symbols, line_count) = (entry.symbols.clone(), entry.line_count);
+                            items.push(FileInventoryItem {
+                                path: rel_norm,
+                                component,
+                                language,
+                                extension: ext_lower,
+                                size_bytes: metadata.len(),
+                                line_count,
+                                is_test,
+                                symbols,
+                                content_hash: Some(hash),
+                            });
+                            continue;
+                        }
+                    }
+                    let line_count = count_lines(&bytes);
                     let syms = match String::from_utf8(bytes) {
-                        Ok(content) => extract_symbols_from_str(
+                        Ok(content) => extract_symbols_with_cache(
+                            parse_cache,
                             &content,
                             &language,
                             limits.max_symbols_per_file,
                         ),
                         Err(_) => Vec::new(),
                     };
-                    (syms, Some(hash))
+                    if let Some(state) = incremental.as_deref_mut() {
+                        state.stats.analyzed += 1;
+                        state.cache.entries.insert(
+                            rel_norm.clone(),
+                            FileAnalysisCacheEntry {
+                                content_hash: hash.clone(),
+                                line_count,
+                                symbols: syms.clone(),
+                                analyzed_at: state.now,
+                            },
+                        );
+                    }
+                    (syms, Some(hash), line_count)
                 }
-                Err(_) => (Vec::new(), None),
+                Err(_) => (Vec::new(), None, 0),
             }
         } else {
             // File too large for symbol extraction — still compute hash.
@@ -365,7 +542,8 @@ pub fn build_file_inventory_with_limits(
                 }
                 Err(_) => None,
             };
-            (Vec::new(), hash)
+            let line_count = estimate_line_count(path, metadata.len()).unwrap_or(0);
+            (Vec::new(), hash, line_count)
         };
 
         items.push(FileInventoryItem {
@@ -635,10 +813,24 @@ pub fn extract_symbols_from_str(
     content: &str,
     language: &str,
     max_symbols: usize,
+) -> Vec<SymbolInfo> {
+    extract_symbols_with_cache(None, content, language, max_symbols)
+}
+
+/// Like `extract_symbols_from_str`, reusing parse trees from `parse_cache`
+/// when one is given.
+pub fn extract_symbols_with_cache(
+    parse_cache: Option<&ParseCache>,
+    content: &str,
+    language: &str,
+    max_symbols: usize,
 ) -> Vec<SymbolInfo> {
     // Try tree-sitter first for supported languages
     if super::tree_sitter_parser::is_language_supported(language) {
-        let symbols = super::tree_sitter_parser::parse_symbols(content, language, max_symbols);
+        let symbols = match parse_cache {
+            Some(cache) => cache.parse_symbols(content, language, max_symbols),
+            None => super::tree_sitter_parser::parse_symbols(content, language, max_symbols),
+        };
         if !symbols.is_empty() {
             return symbols;
         }
@@ -870,10 +1062,14 @@ fn estimate_line_count(path: &Path, file_size: u64) -> Option<usize> {
         return Some(0);
     }
     let bytes = fs::read(path).ok()?;
+    Some(count_lines(&bytes))
+}
+
+fn count_lines(bytes: &[u8]) -> usize {
     if bytes.is_empty() {
-        return Some(0);
+        return 0;
     }
-    Some(bytes.iter().filter(|&&b| b == b'\n').count() + 1)
+    bytes.iter().filter(|&&b| b == b'\n').count() + 1
 }
 
 fn component_slug(component: &str) -> String {
@@ -924,6 +1120,102 @@ mod tests {
         assert_eq!(plan.chunks.len(), 2);
     }
 
+    #[test]
+    fn incremental_inventory_reanalyzes_only_changed_files() {
+        let dir = tempdir().expect("temp dir");
+        fs::create_dir_all(dir.path().join("src")).expect("mkdir");
+        fs::write(dir.path().join("src/stable.rs"), "pub fn stable() {}\n").expect("write");
+        fs::write(dir.path().join("src/edited.rs"), "pub fn before() {}\n").expect("write");
+
+        let mut cache = FileAnalysisCache::default();
+        let (_, stats) = build_file_inventory_incremental(
+            dir.path(),
+            &[],
+            &[],
+            &AnalysisLimits::default(),
+            &mut cache,
+            3600,
+        )
+        .expect("first");
+        assert_eq!(stats.analyzed, 2);
+        assert_eq!(stats.reused, 0);
+
+        // Poison the cached symbols for the unchanged file: a cache hit must serve them as-is
+        cache.entries.get_mut("src/stable.rs").unwrap().symbols[0].name = "from_cache".to_string();
+        fs::write(
+            dir.path().join("src/edited.rs"),
+            "pub fn after() {}\npub fn extra() {}\n",
+        )
+        .expect("write");
+
+        let (inventory, stats) = build_file_inventory_incremental(
+            dir.path(),
+            &[],
+            &[],
+            &AnalysisLimits::default(),
+            &mut cache,
+            3600,
+        )
+        .expect("second");
+        assert_eq!(stats.reused, 1);
+        assert_eq!(stats.analyzed, 1);
+
+        let stable = inventory
+            .items
+            .iter()
+            .find(|i| i.path == "src/stable.rs")
+            .unwrap();
+        assert_eq!(stable.symbols[0].name, "from_cache");
+        let edited = inventory
+            .items
+            .iter()
+            .find(|i| i.path == "src/edited.rs")
+            .unwrap();
+        let names: Vec<&str> = edited.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["after", "extra"]);
+        assert_eq!(
+            cache.entries["src/edited.rs"].content_hash,
+            edited.content_hash.clone().unwrap()
+        );
+
+        // Expired entries are re-analyzed even when the hash matches
+        cache.entries.get_mut("src/stable.rs").unwrap().analyzed_at -= 7200;
+        fs::remove_file(dir.path().join("src/edited.rs")).expect("remove");
+        let (_, stats) = build_file_inventory_incremental(
+            dir.path(),
+            &[],
+            &[],
+            &AnalysisLimits::default(),
+            &mut cache,
+            3600,
+        )
+        .expect("third");
+        assert_eq!(stats.analyzed, 1);
+        assert_eq!(stats.evicted, 1);
+        assert!(!cache.entries.contains_key("src/edited.rs"));
+    }
+
+    #[test]
+    fn incremental_inventory_skips_excluded_extensions() {
+        let dir = tempdir().expect("temp dir");
+        fs::write(dir.path().join("main.rs"), "fn main() {}\n").expect("write");
+        fs::write(dir.path().join("debug.log"), "noise\n").expect("write");
+
+        let mut cache = FileAnalysisCache::default();
+        let (inventory, _) = build_file_inventory_incremental(
+            dir.path(),
+            &[],
+            &["log".to_string()],
+            &AnalysisLimits::default(),
+            &mut cache,
+            3600,
+        )
+        .expect("inventory");
+        let paths: Vec<&str> = inventory.items.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, vec!["main.rs"]);
+        assert!(!cache.entries.contains_key("debug.log"));
+    }
+
     #[test]
     fn deep_profile_shards_chunks_across_phases() {
         let dir = tempdir().expect("temp dir");
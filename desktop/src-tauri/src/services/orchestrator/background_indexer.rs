@@ -15,9 +15,8 @@ use std::sync::Arc;
 use tracing::{debug, info, warn};
 
 use super::analysis_index::{
-    build_file_inventory, build_file_inventory_with_limits, default_excluded_roots,
-    detect_component_heuristic, detect_language, extract_symbols_from_str, is_binary_extension,
-    is_test_path, AnalysisLimits,
+    build_file_inventory_with_cache, default_excluded_roots, detect_component_heuristic,
+    detect_language, extract_symbols_with_cache, is_binary_extension, is_test_path, AnalysisLimits,
 };
 use super::component_classifier::{self, ComponentMapping};
 use super::embedding_manager::EmbeddingManager;
@@ -26,7 +25,7 @@ use super::embedding_provider_tfidf::TfIdfEmbeddingProvider;
 use super::embedding_service::{embedding_to_bytes, EmbeddingService};
 use super::hnsw_index::HnswIndex;
 use super::index_store::IndexStore;
-use super::tree_sitter_parser;
+use super::tree_sitter_parser::{self, ParseCache};
 use crate::services::llm::provider::LlmProvider;
 
 /// Callback type for reporting indexing progress.
@@ -117,6 +116,8 @@ pub struct BackgroundIndexer {
     extra_excluded_extensions: Vec<String>,
     /// Optional LLM provider for component classification (Phase 1a).
     llm_provider: Option<Arc<dyn LlmProvider>>,
+    /// Parse trees shared by symbol extraction and chunking.
+    parse_cache: Arc<ParseCache>,
 }
 
 impl BackgroundIndexer {
@@ -136,6 +137,7 @@ impl BackgroundIndexer {
             extra_excluded_dirs: Vec::new(),
             extra_excluded_extensions: Vec::new(),
             llm_provider: None,
+            parse_cache: Arc::new(ParseCache::default()),
         }
     }
 
@@ -232,6 +234,13 @@ impl BackgroundIndexer {
         self
     }
 
+    /// Share a parse-tree cache (normally the app-wide one from `AppState`)
+    /// so unchanged files are not re-parsed across index passes.
+    pub fn with_parse_cache(mut self, cache: Arc<ParseCache>) -> Self {
+        self.parse_cache = cache;
+        self
+    }
+
     /// Optionally attach an LLM provider (no-op when `None`).
     pub fn with_llm_provider_opt(mut self, provider: Option<Arc<dyn LlmProvider>>) -> Self {
         self.llm_provider = provider;
@@ -260,6 +269,7 @@ impl BackgroundIndexer {
         let enrichment_callback = self.enrichment_callback;
         let extra_excluded_dirs = self.extra_excluded_dirs;
         let extra_excluded_extensions = self.extra_excluded_extensions;
+        let parse_cache = self.parse_cache;
         let llm_provider = self.llm_provider;
 
         tokio::spawn(async move {
@@ -274,6 +284,7 @@ impl BackgroundIndexer {
                 progress_callback.as_ref(),
                 &extra_excluded_dirs,
                 &extra_excluded_extensions,
+                &parse_cache,
             ) {
                 warn!(
                     error = %e,
@@ -309,6 +320,7 @@ impl BackgroundIndexer {
                     &index_store,
                     emb_mgr,
                     hnsw_index.as_ref(),
+                    &parse_cache,
                 )
                 .await
                 {
@@ -334,9 +346,14 @@ impl BackgroundIndexer {
                 }
             } else if let Some(ref emb_svc) = embedding_service {
                 info!("background indexer: starting embedding generation");
-                if let Err(e) =
-                    run_embedding_pass(&project_root, &index_store, emb_svc, hnsw_index.as_ref())
-                        .await
+                if let Err(e) = run_embedding_pass(
+                    &project_root,
+                    &index_store,
+                    emb_svc,
+                    hnsw_index.as_ref(),
+                    &parse_cache,
+                )
+                .await
                 {
                     warn!(
                         error = %e,
@@ -366,6 +383,7 @@ impl BackgroundIndexer {
                     enrichment_callback.as_ref(),
                     &extra_excluded_dirs,
                     &extra_excluded_extensions,
+                    &parse_cache,
                 )
                 .await;
             }
@@ -390,6 +408,7 @@ impl BackgroundIndexer {
         let enrichment_callback = self.enrichment_callback;
         let extra_excluded_dirs = self.extra_excluded_dirs;
         let extra_excluded_extensions = self.extra_excluded_extensions;
+        let parse_cache = self.parse_cache;
 
         tokio::spawn(async move {
             if let Some(mut rx) = change_rx {
@@ -411,6 +430,7 @@ impl BackgroundIndexer {
                     enrichment_callback.as_ref(),
                     &extra_excluded_dirs,
                     &extra_excluded_extensions,
+                    &parse_cache,
                 )
                 .await;
             }
@@ -434,6 +454,7 @@ impl BackgroundIndexer {
         let enrichment_callback = self.enrichment_callback;
         let extra_excluded_dirs = self.extra_excluded_dirs;
         let extra_excluded_extensions = self.extra_excluded_extensions;
+        let parse_cache = self.parse_cache;
 
         tokio::spawn(async move {
             let gitignore = build_gitignore_matcher(&project_root);
@@ -453,6 +474,7 @@ impl BackgroundIndexer {
                 enrichment_callback.as_ref(),
                 &extra_excluded_dirs,
                 &extra_excluded_extensions,
+                &parse_cache,
             )
             .await;
 
@@ -470,6 +492,7 @@ impl BackgroundIndexer {
                     enrichment_callback.as_ref(),
                     &extra_excluded_dirs,
                     &extra_excluded_extensions,
+                    &parse_cache,
                 )
                 .await;
             }
@@ -625,6 +648,7 @@ async fn run_incremental_loop(
     enrichment_callback: Option<&EnrichmentCallback>,
     extra_excluded_dirs: &[String],
     extra_excluded_extensions: &[String],
+    parse_cache: &ParseCache,
 ) {
     let project_path = project_root.to_string_lossy().to_string();
 
@@ -680,7 +704,7 @@ async fn run_incremental_loop(
                 debug!(path = %changed_path.display(), "background indexer: skipping excluded/gitignored path");
                 continue;
             }
-            match run_incremental_index(project_root, index_store, &changed_path) {
+            match run_incremental_index(project_root, index_store, &changed_path, parse_cache) {
                 Ok(IncrementalResult::Deleted { rel_path }) => {
                     // Mark HNSW entries stale, then delete embeddings from SQLite.
                     if let Some(hnsw) = hnsw_index {
@@ -744,6 +768,7 @@ async fn run_incremental_loop(
                             &language,
                             &rel_path,
                             hnsw_index,
+                            parse_cache,
                         )
                         .await
                         {
@@ -763,6 +788,7 @@ async fn run_incremental_loop(
                             &language,
                             &rel_path,
                             hnsw_index,
+                            parse_cache,
                         )
                         .await
                         {
@@ -829,6 +855,7 @@ async fn run_incremental_loop(
                     enrichment_callback,
                     extra_excluded_dirs,
                     extra_excluded_extensions,
+                    parse_cache,
                 )
                 .await;
             }
@@ -905,6 +932,7 @@ async fn run_catchup_sync(
     enrichment_callback: Option<&EnrichmentCallback>,
     extra_excluded_dirs: &[String],
     extra_excluded_extensions: &[String],
+    parse_cache: &ParseCache,
 ) {
     let project_path = project_root.to_string_lossy().to_string();
     let mut changed_rel_paths: HashSet<String> = HashSet::new();
@@ -950,7 +978,7 @@ async fn run_catchup_sync(
                 content,
                 language,
                 rel_path,
-            }) = run_incremental_index(project_root, index_store, &abs_path)
+            }) = run_incremental_index(project_root, index_store, &abs_path, parse_cache)
             {
                 updated += 1;
                 changed_rel_paths.insert(rel_path.clone());
@@ -965,6 +993,7 @@ async fn run_catchup_sync(
                         &language,
                         &rel_path,
                         hnsw_index,
+                        parse_cache,
                     )
                     .await;
                 } else if let Some(emb_svc) = embedding_service {
@@ -977,6 +1006,7 @@ async fn run_catchup_sync(
                         &language,
                         &rel_path,
                         hnsw_index,
+                        parse_cache,
                     )
                     .await;
                 }
@@ -1038,7 +1068,7 @@ async fn run_catchup_sync(
                 continue;
             }
 
-            match run_incremental_index(project_root, index_store, &abs_path) {
+            match run_incremental_index(project_root, index_store, &abs_path, parse_cache) {
                 Ok(IncrementalResult::Updated {
                     content,
                     language,
@@ -1057,6 +1087,7 @@ async fn run_catchup_sync(
                                 &language,
                                 &rel_path,
                                 hnsw_index,
+                                parse_cache,
                             )
                             .await;
                         } else if let Some(emb_svc) = embedding_service {
@@ -1069,6 +1100,7 @@ async fn run_catchup_sync(
                                 &language,
                                 &rel_path,
                                 hnsw_index,
+                                parse_cache,
                             )
                             .await;
                         }
@@ -1130,8 +1162,9 @@ fn run_full_index(
     progress_callback: Option<&IndexProgressCallback>,
     extra_excluded_dirs: &[String],
     extra_excluded_extensions: &[String],
+    parse_cache: &ParseCache,
 ) -> Result<(), String> {
-    let inventory = build_file_inventory_with_limits(
+    let inventory = build_file_inventory_with_cache(
         project_root,
         &extra_excluded_dirs
             .iter()
@@ -1139,6 +1172,7 @@ fn run_full_index(
             .collect::<Vec<_>>(),
         extra_excluded_extensions,
         &AnalysisLimits::default(),
+        parse_cache,
     )
     .map_err(|e| e.to_string())?;
 
@@ -1212,6 +1246,7 @@ fn run_incremental_index(
     project_root: &Path,
     index_store: &IndexStore,
     changed_path: &Path,
+    parse_cache: &ParseCache,
 ) -> Result<IncrementalResult, String> {
     let rel = changed_path
         .strip_prefix(project_root)
@@ -1284,7 +1319,12 @@ fn run_incremental_index(
     let language = detect_language(ext.as_deref());
 
     let limits = AnalysisLimits::default();
-    let symbols = extract_symbols_from_str(&content, &language, limits.max_symbols_per_file);
+    let symbols = extract_symbols_with_cache(
+        Some(parse_cache),
+        &content,
+        &language,
+        limits.max_symbols_per_file,
+    );
 
     // Compute line count from already-read content instead of re-reading from disk.
     let line_count = if content.is_empty() {
@@ -1358,6 +1398,16 @@ pub struct FileChunk {
 /// boundaries (functions, classes, structs, etc.).  For unsupported languages,
 /// a fixed-size sliding window is used.
 pub fn chunk_file_content(content: &str, language: &str) -> Vec<FileChunk> {
+    chunk_file_content_with_cache(None, content, language)
+}
+
+/// Like `chunk_file_content`, reusing parse trees from `parse_cache` when
+/// one is given.
+pub fn chunk_file_content_with_cache(
+    parse_cache: Option<&ParseCache>,
+    content: &str,
+    language: &str,
+) -> Vec<FileChunk> {
     if content.is_empty() {
         return Vec::new();
     }
@@ -1368,7 +1418,10 @@ pub fn chunk_file_content(content: &str, language: &str) -> Vec<FileChunk> {
     }
 
     if tree_sitter_parser::is_language_supported(language) {
-        let symbols = tree_sitter_parser::parse_symbols(content, language, 200);
+        let symbols = match parse_cache {
+            Some(cache) => cache.parse_symbols(content, language, 200),
+            None => tree_sitter_parser::parse_symbols(content, language, 200),
+        };
         if !symbols.is_empty() {
             return chunk_by_symbols(&lines, &symbols);
         }
@@ -1501,11 +1554,19 @@ async fn run_embedding_pass(
     index_store: &IndexStore,
     embedding_service: &EmbeddingService,
     hnsw_index: Option<&Arc<HnswIndex>>,
+    parse_cache: &ParseCache,
 ) -> Result<(), String> {
     let project_path = project_root.to_string_lossy().to_string();
 
     // Collect all chunks first (to build vocabulary from the full corpus)
-    let inventory = build_file_inventory(project_root, &[]).map_err(|e| e.to_string())?;
+    let inventory = build_file_inventory_with_cache(
+        project_root,
+        &[],
+        &[],
+        &AnalysisLimits::default(),
+        parse_cache,
+    )
+    .map_err(|e| e.to_string())?;
 
     let mut all_chunks: Vec<(String, FileChunk)> = Vec::new(); // (relative_path, chunk)
 
@@ -1518,7 +1579,7 @@ async fn run_embedding_pass(
             Ok(c) => c,
             Err(_) => continue, // skip unreadable files
         };
-        let chunks = chunk_file_content(&content, &item.language);
+        let chunks = chunk_file_content_with_cache(Some(parse_cache), &content, &item.language);
         for chunk in chunks {
             all_chunks.push((item.path.clone(), chunk));
         }
@@ -1745,6 +1806,54 @@ async fn run_incremental_embedding(
     Ok(())
 }
 
+/// Fold a re-chunked file into the TF-IDF vocabulary: the file's previously
+/// stored chunks are retracted and `new_texts` added, so IDF weights follow
+/// the corpus across incremental updates without changing the dimension.
+///
+/// Returns the retracted texts so the update can be reverted if storing the
+/// new embeddings fails.
+fn update_tfidf_vocabulary(
+    index_store: &IndexStore,
+    embedding_service: &EmbeddingService,
+    project_path: &str,
+    rel_path: &str,
+    new_texts: &[&str],
+) -> Vec<String> {
+    let previous = index_store
+        .get_chunk_texts_for_file(project_path, rel_path)
+        .unwrap_or_default();
+    let previous_refs: Vec<&str> = previous.iter().map(|s| s.as_str()).collect();
+    embedding_service.update_documents(&previous_refs, new_texts);
+    previous
+}
+
+/// Undo an `update_tfidf_vocabulary` whose embeddings were not stored.
+fn revert_tfidf_vocabulary(
+    embedding_service: &EmbeddingService,
+    new_texts: &[&str],
+    previous_texts: &[String],
+) {
+    let previous_refs: Vec<&str> = previous_texts.iter().map(|s| s.as_str()).collect();
+    embedding_service.update_documents(new_texts, &previous_refs);
+}
+
+/// Persist the TF-IDF vocabulary after an incremental update so the next
+/// session queries with the same IDF weights.
+fn save_tfidf_vocabulary(
+    index_store: &IndexStore,
+    embedding_service: &EmbeddingService,
+    project_path: &str,
+) {
+    if let Some(vocab_json) = embedding_service.export_vocabulary() {
+        if let Err(e) = index_store.save_vocabulary(project_path, &vocab_json) {
+            warn!(
+                error = %e,
+                "background indexer: failed to save updated vocabulary to SQLite"
+            );
+        }
+    }
+}
+
 /// Re-embed a single changed file using already-read content.
 ///
 /// Avoids redundant disk I/O by accepting the file content, language, and
@@ -1760,6 +1869,7 @@ async fn run_incremental_embedding_with_content(
     language: &str,
     rel_str: &str,
     hnsw_index: Option<&Arc<HnswIndex>>,
+    parse_cache: &ParseCache,
 ) -> Result<(), String> {
     let project_path = project_root.to_string_lossy().to_string();
 
@@ -1800,11 +1910,21 @@ async fn run_incremental_embedding_with_content(
     }
 
     // Generate all embeddings first (outside any transaction).
-    let chunks = chunk_file_content(content, language);
+    let chunks = chunk_file_content_with_cache(Some(parse_cache), content, language);
     if chunks.is_empty() {
         return Ok(());
     }
 
+    // Update IDF weights for the file's new chunks before embedding them.
+    let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+    let previous_texts = update_tfidf_vocabulary(
+        index_store,
+        embedding_service,
+        &project_path,
+        rel_str,
+        &texts,
+    );
+
     let embeddings: Vec<Vec<f32>> = chunks
         .iter()
         .map(|chunk| embedding_service.embed_text(&chunk.text))
@@ -1825,8 +1945,10 @@ async fn run_incremental_embedding_with_content(
             error = %e,
             "background indexer: transactional embedding replace failed"
         );
+        revert_tfidf_vocabulary(embedding_service, &texts, &previous_texts);
         return Err(e.to_string());
     }
+    save_tfidf_vocabulary(index_store, embedding_service, &project_path);
 
     // Mark old HNSW entries as stale NOW — after embedding + SQLite replace succeeded.
     if let Some(hnsw) = hnsw_index {
@@ -1906,11 +2028,19 @@ async fn run_embedding_pass_managed(
     index_store: &IndexStore,
     manager: &EmbeddingManager,
     hnsw_index: Option<&Arc<HnswIndex>>,
+    parse_cache: &ParseCache,
 ) -> Result<EmbeddingPassStats, String> {
     let project_path = project_root.to_string_lossy().to_string();
 
     // Collect all chunks first, grouped by file for per-file fault tolerance.
-    let inventory = build_file_inventory(project_root, &[]).map_err(|e| e.to_string())?;
+    let inventory = build_file_inventory_with_cache(
+        project_root,
+        &[],
+        &[],
+        &AnalysisLimits::default(),
+        parse_cache,
+    )
+    .map_err(|e| e.to_string())?;
 
     // Group chunks by relative file path
     let mut file_chunks: HashMap<String, Vec<FileChunk>> = HashMap::new();
@@ -1925,7 +2055,7 @@ async fn run_embedding_pass_managed(
             Ok(c) => c,
             Err(_) => continue,
         };
-        let chunks = chunk_file_content(&content, &item.language);
+        let chunks = chunk_file_content_with_cache(Some(parse_cache), &content, &item.language);
         for chunk in &chunks {
             all_texts_for_vocab.push(chunk.text.clone());
         }
@@ -1977,16 +2107,15 @@ async fn run_embedding_pass_managed(
                     })
                     .collect();
 
-                let provider_type_str = format!("{:?}", manager.provider_type()).to_lowercase();
-                let provider_model = manager.display_name().to_string();
+                let signature = manager.signature();
                 let dim = embeddings.first().map(|e| e.len() as i64).unwrap_or(0);
 
                 match index_store.replace_file_embeddings(
                     &project_path,
                     rel_path,
                     &batch,
-                    &provider_type_str,
-                    &provider_model,
+                    &signature.provider_type,
+                    &signature.provider_model,
                     dim,
                 ) {
                     Ok(()) => {
@@ -2244,6 +2373,7 @@ async fn run_incremental_embedding_managed_with_content(
     language: &str,
     rel_str: &str,
     hnsw_index: Option<&Arc<HnswIndex>>,
+    parse_cache: &ParseCache,
 ) -> Result<(), String> {
     let project_path = project_root.to_string_lossy().to_string();
 
@@ -2290,18 +2420,44 @@ async fn run_incremental_embedding_managed_with_content(
         }
     }
 
-    let chunks = chunk_file_content(content, language);
+    let chunks = chunk_file_content_with_cache(Some(parse_cache), content, language);
     if chunks.is_empty() {
         return Ok(());
     }
 
     let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
 
+    // TF-IDF: update IDF weights for the file's new chunks before embedding
+    // them, and drop vectors cached under the previous weights.
+    let tfidf_service = if manager.provider_type() == EmbeddingProviderType::TfIdf {
+        manager
+            .primary_provider()
+            .as_any()
+            .downcast_ref::<TfIdfEmbeddingProvider>()
+            .map(|tfidf| tfidf.inner())
+    } else {
+        None
+    };
+    let previous_texts = match tfidf_service {
+        Some(service) => {
+            let previous =
+                update_tfidf_vocabulary(index_store, service, &project_path, rel_str, &texts);
+            manager.cache_invalidate_all();
+            previous
+        }
+        None => Vec::new(),
+    };
+
     // Generate embeddings OUTSIDE the transaction (may call external API).
-    let embeddings = manager
-        .embed_documents(&texts)
-        .await
-        .map_err(|e| format!("embedding manager incremental embed failed: {}", e))?;
+    let embeddings = match manager.embed_documents(&texts).await {
+        Ok(embeddings) => embeddings,
+        Err(e) => {
+            if let Some(service) = tfidf_service {
+                revert_tfidf_vocabulary(service, &texts, &previous_texts);
+            }
+            return Err(format!("embedding manager incremental embed failed: {}", e));
+        }
+    };
 
     // Build the batch for the atomic replace.
     let embedding_bytes: Vec<Vec<u8>> = embeddings.iter().map(|e| embedding_to_bytes(e)).collect();
@@ -2312,8 +2468,7 @@ async fn run_incremental_embedding_managed_with_content(
         .collect();
 
     // Determine provider metadata for the stored embeddings.
-    let provider_type_str = format!("{:?}", manager.provider_type()).to_lowercase();
-    let provider_model = manager.display_name().to_string();
+    let signature = manager.signature();
     let dim = embeddings.first().map(|e| e.len() as i64).unwrap_or(0);
 
     // Atomic delete-then-insert in a single transaction.
@@ -2321,8 +2476,8 @@ async fn run_incremental_embedding_managed_with_content(
         &project_path,
         rel_str,
         &batch,
-        &provider_type_str,
-        &provider_model,
+        &signature.provider_type,
+        &signature.provider_model,
         dim,
     ) {
         warn!(
@@ -2330,8 +2485,14 @@ async fn run_incremental_embedding_managed_with_content(
             error = %e,
             "background indexer: transactional embedding replace failed (managed)"
         );
+        if let Some(service) = tfidf_service {
+            revert_tfidf_vocabulary(service, &texts, &previous_texts);
+        }
         return Err(e.to_string());
     }
+    if let Some(service) = tfidf_service {
+        save_tfidf_vocabulary(index_store, service, &project_path);
+    }
 
     // Mark old HNSW entries as stale NOW — after API + SQLite replace succeeded.
     if let Some(hnsw) = hnsw_index {
@@ -2528,7 +2689,8 @@ mod tests {
         fs::write(dir.path().join("src/lib.rs"), "pub struct Config;\n").expect("write");
 
         let store = test_store();
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let project_path = dir.path().to_string_lossy().to_string();
         let summary = store.get_project_summary(&project_path).expect("summary");
@@ -2548,7 +2710,8 @@ mod tests {
         let store = test_store();
 
         // Full index first
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let project_path = dir.path().to_string_lossy().to_string();
 
@@ -2560,7 +2723,8 @@ mod tests {
         assert!(!stale_before, "file should NOT be stale after full index");
 
         // Incremental on the same unchanged file should be a no-op
-        run_incremental_index(dir.path(), &store, &file).expect("incremental");
+        run_incremental_index(dir.path(), &store, &file, &ParseCache::default())
+            .expect("incremental");
 
         // Still exactly one file in the index
         let summary = store.get_project_summary(&project_path).expect("summary");
@@ -2574,7 +2738,8 @@ mod tests {
         fs::write(&file, "def old_func():\n    pass\n").expect("write");
 
         let store = test_store();
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let project_path = dir.path().to_string_lossy().to_string();
         let symbols_v1 = store
@@ -2587,7 +2752,8 @@ mod tests {
         fs::write(&file, "def new_func():\n    pass\n").expect("write");
 
         // Incremental should detect the change and re-index
-        run_incremental_index(dir.path(), &store, &file).expect("incremental");
+        run_incremental_index(dir.path(), &store, &file, &ParseCache::default())
+            .expect("incremental");
 
         let symbols_v2 = store
             .get_file_symbols(&project_path, "lib.py")
@@ -2603,13 +2769,14 @@ mod tests {
         fs::write(&file, "x = 1\n").expect("write");
 
         let store = test_store();
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         // Delete the file
         fs::remove_file(&file).expect("remove");
 
         // Incremental should succeed (skip gracefully)
-        let result = run_incremental_index(dir.path(), &store, &file);
+        let result = run_incremental_index(dir.path(), &store, &file, &ParseCache::default());
         assert!(result.is_ok());
     }
 
@@ -2638,7 +2805,15 @@ mod tests {
             calls_clone.lock().unwrap().push((done, total));
         });
 
-        run_full_index(dir.path(), &store, Some(&cb), &[], &[]).expect("full index");
+        run_full_index(
+            dir.path(),
+            &store,
+            Some(&cb),
+            &[],
+            &[],
+            &ParseCache::default(),
+        )
+        .expect("full index");
 
         let recorded = calls.lock().unwrap();
         // Should have calls at 10, 20, and final (25)
@@ -2670,7 +2845,15 @@ mod tests {
             calls_clone.lock().unwrap().push((done, total));
         });
 
-        run_full_index(dir.path(), &store, Some(&cb), &[], &[]).expect("full index");
+        run_full_index(
+            dir.path(),
+            &store,
+            Some(&cb),
+            &[],
+            &[],
+            &ParseCache::default(),
+        )
+        .expect("full index");
 
         let recorded = calls.lock().unwrap();
         // Final callback with (0, 0) is expected
@@ -2844,10 +3027,11 @@ pub fn process_config(config: &Config) -> String {
 
         let store = test_store();
         // First run full index so files are in file_index table
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let emb_svc = EmbeddingService::new();
-        run_embedding_pass(dir.path(), &store, &emb_svc, None)
+        run_embedding_pass(dir.path(), &store, &emb_svc, None, &ParseCache::default())
             .await
             .expect("embedding pass");
 
@@ -2889,10 +3073,11 @@ pub fn process_config(config: &Config) -> String {
         .expect("write");
 
         let store = test_store();
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let emb_svc = EmbeddingService::new();
-        run_embedding_pass(dir.path(), &store, &emb_svc, None)
+        run_embedding_pass(dir.path(), &store, &emb_svc, None, &ParseCache::default())
             .await
             .expect("embedding pass");
 
@@ -2941,9 +3126,10 @@ pub fn process_config(config: &Config) -> String {
         let project_path = dir.path().to_string_lossy().to_string();
 
         // First: full index + embedding pass to build and save vocabulary
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
         let emb_svc1 = EmbeddingService::new();
-        run_embedding_pass(dir.path(), &store, &emb_svc1, None)
+        run_embedding_pass(dir.path(), &store, &emb_svc1, None, &ParseCache::default())
             .await
             .expect("embedding pass");
 
@@ -2988,6 +3174,82 @@ pub fn process_config(config: &Config) -> String {
         );
     }
 
+    #[tokio::test]
+    async fn incremental_embedding_updates_persisted_vocabulary() {
+        let dir = tempdir().expect("tempdir");
+        fs::create_dir_all(dir.path().join("src")).expect("mkdir");
+        fs::write(
+            dir.path().join("src/main.rs"),
+            "pub fn main() {\n    println!(\"hello\");\n}\n",
+        )
+        .expect("write");
+        fs::write(
+            dir.path().join("src/lib.rs"),
+            "pub struct Config {\n    name: String,\n}\n",
+        )
+        .expect("write");
+
+        let store = test_store();
+        let project_path = dir.path().to_string_lossy().to_string();
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
+        let emb_svc = EmbeddingService::new();
+        run_embedding_pass(dir.path(), &store, &emb_svc, None, &ParseCache::default())
+            .await
+            .expect("embedding pass");
+        let docs_before = emb_svc.document_count();
+        let dim_before = emb_svc.dimension();
+
+        // A new file is folded into the vocabulary without changing its size.
+        let content = "pub fn helper(config: Config) -> String {\n    config.name\n}\n";
+        let path = dir.path().join("src/helper.rs");
+        fs::write(&path, content).expect("write");
+        run_incremental_embedding_with_content(
+            dir.path(),
+            &store,
+            &emb_svc,
+            &path,
+            content,
+            "rust",
+            "src/helper.rs",
+            None,
+            &ParseCache::default(),
+        )
+        .await
+        .expect("incremental embedding");
+        assert!(emb_svc.document_count() > docs_before);
+        assert_eq!(emb_svc.dimension(), dim_before);
+
+        // The persisted vocabulary carries the update, so a restarted session
+        // embeds queries exactly like the live one.
+        let restored = EmbeddingService::new();
+        restored
+            .import_vocabulary(&store.load_vocabulary(&project_path).unwrap().unwrap())
+            .unwrap();
+        assert_eq!(restored.document_count(), emb_svc.document_count());
+        assert_eq!(
+            restored.embed_text("config helper"),
+            emb_svc.embed_text("config helper")
+        );
+
+        // Re-embedding the same content retracts the old chunks first.
+        let docs_after_add = emb_svc.document_count();
+        run_incremental_embedding_with_content(
+            dir.path(),
+            &store,
+            &emb_svc,
+            &path,
+            content,
+            "rust",
+            "src/helper.rs",
+            None,
+            &ParseCache::default(),
+        )
+        .await
+        .expect("incremental embedding");
+        assert_eq!(emb_svc.document_count(), docs_after_add);
+    }
+
     #[tokio::test]
     async fn incremental_embedding_skips_when_no_vocab_in_db() {
         let dir = tempdir().expect("tempdir");
@@ -2996,7 +3258,8 @@ pub fn process_config(config: &Config) -> String {
         let store = test_store();
 
         // Full index but NO embedding pass — so no vocab in DB
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let emb_svc = EmbeddingService::new();
         assert!(!emb_svc.is_ready());
@@ -3075,10 +3338,11 @@ pub fn process_config(config: &Config) -> String {
         .expect("write");
 
         let store = test_store();
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let manager = test_tfidf_manager();
-        run_embedding_pass_managed(dir.path(), &store, &manager, None)
+        run_embedding_pass_managed(dir.path(), &store, &manager, None, &ParseCache::default())
             .await
             .expect("managed embedding pass");
 
@@ -3115,10 +3379,11 @@ pub fn process_config(config: &Config) -> String {
         .expect("write");
 
         let store = test_store();
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let manager = test_tfidf_manager();
-        run_embedding_pass_managed(dir.path(), &store, &manager, None)
+        run_embedding_pass_managed(dir.path(), &store, &manager, None, &ParseCache::default())
             .await
             .expect("managed embedding pass");
 
@@ -3149,9 +3414,10 @@ pub fn process_config(config: &Config) -> String {
         let project_path = dir.path().to_string_lossy().to_string();
 
         // Full index + managed embedding pass to build and save vocabulary
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
         let manager = test_tfidf_manager();
-        run_embedding_pass_managed(dir.path(), &store, &manager, None)
+        run_embedding_pass_managed(dir.path(), &store, &manager, None, &ParseCache::default())
             .await
             .expect("managed embedding pass");
 
@@ -3211,7 +3477,8 @@ pub fn process_config(config: &Config) -> String {
         let store = test_store();
 
         // Full index but NO embedding pass — so no vocab in DB
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
 
         let manager = test_tfidf_manager();
         let primary = manager.primary_provider();
@@ -3325,9 +3592,10 @@ pub fn process_config(config: &Config) -> String {
         let project_path = dir.path().to_string_lossy().to_string();
 
         // Full index + embedding pass
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
         let emb_svc = EmbeddingService::new();
-        run_embedding_pass(dir.path(), &store, &emb_svc, None)
+        run_embedding_pass(dir.path(), &store, &emb_svc, None, &ParseCache::default())
             .await
             .expect("embedding pass");
 
@@ -3455,9 +3723,10 @@ pub fn process_config(config: &Config) -> String {
         let project_path = dir.path().to_string_lossy().to_string();
 
         // Full index + embedding pass
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
         let emb_svc = EmbeddingService::new();
-        run_embedding_pass(dir.path(), &store, &emb_svc, None)
+        run_embedding_pass(dir.path(), &store, &emb_svc, None, &ParseCache::default())
             .await
             .expect("embedding pass");
 
@@ -3573,9 +3842,10 @@ pub fn process_config(config: &Config) -> String {
         let project_path = dir.path().to_string_lossy().to_string();
 
         // Full index + embedding pass
-        run_full_index(dir.path(), &store, None, &[], &[]).expect("full index");
+        run_full_index(dir.path(), &store, None, &[], &[], &ParseCache::default())
+            .expect("full index");
         let emb_svc = EmbeddingService::new();
-        run_embedding_pass(dir.path(), &store, &emb_svc, None)
+        run_embedding_pass(dir.path(), &store, &emb_svc, None, &ParseCache::default())
             .await
             .expect("embedding pass");
 
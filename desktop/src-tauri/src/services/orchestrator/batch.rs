@@ -0,0 +1,385 @@
+//! Standalone Batch Execution
+//!
+//! Runs the same agent configuration over a list of prompts. Each prompt runs
+//! as its own session, at most `max_concurrency` at a time. A failing prompt
+//! only fails its own item; usage is summed across the whole batch.
+//!
+//! Cancelling the batch stops prompts that have not started. Prompts already
+//! running either finish normally or are aborted, depending on
+//! `abort_in_flight_on_cancel`.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+
+use super::service::{merge_usage, ExecutionResult};
+use crate::services::llm::UsageStats;
+
+/// Concurrency used when the caller does not specify one.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 3;
+
+/// Upper bound on concurrent prompts, regardless of what the caller asks for.
+pub const MAX_BATCH_CONCURRENCY: usize = 16;
+
+/// How a batch responds to cancellation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// Maximum number of prompts running at once
+    pub max_concurrency: usize,
+    /// Abort running prompts on cancellation instead of letting them finish
+    pub abort_in_flight_on_cancel: bool,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            max_concurrency: DEFAULT_BATCH_CONCURRENCY,
+            abort_in_flight_on_cancel: false,
+        }
+    }
+}
+
+/// A single prompt handed to the item runner.
+#[derive(Debug, Clone)]
+pub struct BatchItem {
+    /// Position of the prompt in the batch
+    pub index: usize,
+    pub prompt: String,
+    /// Session ID the prompt runs under
+    pub session_id: String,
+    /// Cancelled when this item should abort
+    pub cancellation_token: CancellationToken,
+}
+
+/// Final state of a batch item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchItemStatus {
+    Completed,
+    Failed,
+    /// Never started, or aborted by batch cancellation
+    Cancelled,
+}
+
+/// Result summary for one prompt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchItemResult {
+    pub index: usize,
+    pub prompt: String,
+    /// Session ID, absent when the prompt never started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    pub status: BatchItemStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub usage: UsageStats,
+    pub iterations: u32,
+}
+
+impl BatchItemResult {
+    fn not_started(index: usize, prompt: String) -> Self {
+        Self {
+            index,
+            prompt,
+            session_id: None,
+            status: BatchItemStatus::Cancelled,
+            response: None,
+            error: Some("Batch cancelled before this prompt started".to_string()),
+            usage: UsageStats::default(),
+            iterations: 0,
+        }
+    }
+}
+
+/// Result of a whole batch, with items in prompt order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchExecutionResult {
+    pub batch_id: String,
+    pub items: Vec<BatchItemResult>,
+    pub completed: usize,
+    pub failed: usize,
+    pub cancelled: usize,
+    /// Usage summed over every item, including failed and aborted ones
+    pub usage: UsageStats,
+}
+
+/// Run `run_item` for every prompt with bounded concurrency.
+///
+/// Panics inside an item are reported as a failed item rather than failing
+/// the batch.
+pub async fn run_batch<F, Fut>(
+    batch_id: String,
+    prompts: Vec<String>,
+    options: BatchOptions,
+    cancellation_token: CancellationToken,
+    run_item: F,
+) -> BatchExecutionResult
+where
+    F: Fn(BatchItem) -> Fut,
+    Fut: Future<Output = ExecutionResult> + Send + 'static,
+{
+    let max_concurrency = options.max_concurrency.clamp(1, MAX_BATCH_CONCURRENCY);
+    let semaphore = Arc::new(Semaphore::new(max_concurrency));
+    let mut join_set = JoinSet::new();
+    let mut slots: Vec<Option<BatchItemResult>> = vec![None; prompts.len()];
+    let mut started: Vec<(String, String)> = vec![(String::new(), String::new()); prompts.len()];
+
+    for (index, prompt) in prompts.into_iter().enumerate() {
+        let permit = if cancellation_token.is_cancelled() {
+            None
+        } else {
+            tokio::select! {
+                biased;
+                _ = cancellation_token.cancelled() => None,
+                permit = semaphore.clone().acquire_owned() => permit.ok(),
+            }
+        };
+        let Some(permit) = permit else {
+            slots[index] = Some(BatchItemResult::not_started(index, prompt));
+            continue;
+        };
+
+        let item_token = if options.abort_in_flight_on_cancel {
+            cancellation_token.child_token()
+        } else {
+            CancellationToken::new()
+        };
+        let session_id = uuid::Uuid::new_v4().to_string();
+        started[index] = (prompt.clone(), session_id.clone());
+        let future = run_item(BatchItem {
+            index,
+            prompt,
+            session_id,
+            cancellation_token: item_token.clone(),
+        });
+        join_set.spawn(async move {
+            let _permit = permit;
+            let result = future.await;
+            (index, result, item_token.is_cancelled())
+        });
+    }
+
+    while let Some(joined) = join_set.join_next().await {
+        let Ok((index, result, aborted)) = joined else {
+            continue;
+        };
+        let (prompt, session_id) = std::mem::take(&mut started[index]);
+        let status = if result.success {
+            BatchItemStatus::Completed
+        } else if aborted {
+            BatchItemStatus::Cancelled
+        } else {
+            BatchItemStatus::Failed
+        };
+        slots[index] = Some(BatchItemResult {
+            index,
+            prompt,
+            session_id: Some(session_id),
+            status,
+            response: result.response,
+            error: result.error,
+            usage: result.usage,
+            iterations: result.iterations,
+        });
+    }
+
+    // Any slot still empty belongs to a task that panicked.
+    let items: Vec<BatchItemResult> = slots
+        .into_iter()
+        .enumerate()
+        .map(|(index, slot)| {
+            slot.unwrap_or_else(|| {
+                let (prompt, session_id) = std::mem::take(&mut started[index]);
+                BatchItemResult {
+                    index,
+                    prompt,
+                    session_id: Some(session_id),
+                    status: BatchItemStatus::Failed,
+                    response: None,
+                    error: Some("Batch item terminated unexpectedly".to_string()),
+                    usage: UsageStats::default(),
+                    iterations: 0,
+                }
+            })
+        })
+        .collect();
+
+    let mut usage = UsageStats::default();
+    for item in &items {
+        merge_usage(&mut usage, &item.usage);
+    }
+    let count = |status: BatchItemStatus| items.iter().filter(|i| i.status == status).count();
+
+    BatchExecutionResult {
+        batch_id,
+        completed: count(BatchItemStatus::Completed),
+        failed: count(BatchItemStatus::Failed),
+        cancelled: count(BatchItemStatus::Cancelled),
+        items,
+        usage,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    fn result(success: bool, input: u32, output: u32) -> ExecutionResult {
+        ExecutionResult {
+            response: success.then(|| "done".to_string()),
+            usage: UsageStats {
+                input_tokens: input,
+                output_tokens: output,
+                ..Default::default()
+            },
+            iterations: 1,
+            success,
+            error: (!success).then(|| "boom".to_string()),
+        }
+    }
+
+    fn prompts(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("prompt {}", i)).collect()
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_is_bounded() {
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let options = BatchOptions {
+            max_concurrency: 3,
+            ..Default::default()
+        };
+
+        let outcome = run_batch(
+            "batch".to_string(),
+            prompts(10),
+            options,
+            CancellationToken::new(),
+            |_item| {
+                let active = Arc::clone(&active);
+                let peak = Arc::clone(&peak);
+                async move {
+                    let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    active.fetch_sub(1, Ordering::SeqCst);
+                    result(true, 1, 1)
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(outcome.completed, 10);
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_isolated_and_usage_sums() {
+        let outcome = run_batch(
+            "batch".to_string(),
+            prompts(6),
+            BatchOptions::default(),
+            CancellationToken::new(),
+            |item| async move {
+                if item.index == 2 {
+                    panic!("item crashed");
+                }
+                let tokens = item.index as u32 + 1;
+                result(item.index != 4, tokens * 100, tokens * 10)
+            },
+        )
+        .await;
+
+        let statuses: Vec<_> = outcome.items.iter().map(|i| i.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                BatchItemStatus::Completed,
+                BatchItemStatus::Completed,
+                BatchItemStatus::Failed,
+                BatchItemStatus::Completed,
+                BatchItemStatus::Failed,
+                BatchItemStatus::Completed,
+            ]
+        );
+        assert_eq!(outcome.completed, 4);
+        assert_eq!(outcome.failed, 2);
+        assert_eq!(outcome.items[4].error.as_deref(), Some("boom"));
+        assert_eq!(outcome.items[2].prompt, "prompt 2");
+        assert!(outcome.items.iter().all(|i| i.session_id.is_some()));
+
+        // Every item except the panicked one reports usage: 1+2+4+5+6 = 18.
+        assert_eq!(outcome.usage.input_tokens, 1800);
+        assert_eq!(outcome.usage.output_tokens, 180);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_skips_pending_and_lets_in_flight_finish() {
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+
+        let outcome = run_batch(
+            "batch".to_string(),
+            prompts(5),
+            BatchOptions {
+                max_concurrency: 2,
+                abort_in_flight_on_cancel: false,
+            },
+            token,
+            move |item| {
+                let cancel = cancel.clone();
+                async move {
+                    cancel.cancel();
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    result(!item.cancellation_token.is_cancelled(), 10, 1)
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(outcome.completed, 2);
+        assert_eq!(outcome.cancelled, 3);
+        assert!(outcome.items[2..].iter().all(|i| i.session_id.is_none()));
+        assert_eq!(outcome.usage.input_tokens, 20);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_aborts_in_flight_when_requested() {
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+
+        let outcome = run_batch(
+            "batch".to_string(),
+            prompts(4),
+            BatchOptions {
+                max_concurrency: 2,
+                abort_in_flight_on_cancel: true,
+            },
+            token,
+            move |item| {
+                let cancel = cancel.clone();
+                async move {
+                    cancel.cancel();
+                    item.cancellation_token.cancelled().await;
+                    result(false, 5, 0)
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(outcome.completed, 0);
+        assert_eq!(outcome.failed, 0);
+        assert_eq!(outcome.cancelled, 4);
+        // Aborted items still report the usage they accrued.
+        assert_eq!(outcome.usage.input_tokens, 10);
+    }
+}
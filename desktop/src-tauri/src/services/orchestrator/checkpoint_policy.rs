@@ -0,0 +1,427 @@
+//! Checkpoint-on-Event Policy
+//!
+//! Automatically requests Timeline checkpoints at well-defined recovery
+//! points instead of relying on tools to declare them explicitly:
+//!
+//! - **before a destructive tool** runs (Write, Edit, Bash, ...)
+//! - **after a phase completes** successfully
+//! - **when a quality gate passes**
+//!
+//! The policy only *requests* checkpoints by filling in
+//! `EventActions::checkpoint_request`; the `event_actions_applicator` and the
+//! `TimelineService` remain responsible for creating them. Requests are
+//! debounced and suppressed when the tracked files have not changed since the
+//! last checkpoint, so recovery granularity improves without checkpoint spam.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::services::core::event_actions::{CheckpointRequest, EventActions};
+
+/// Tools treated as destructive by default.
+pub const DEFAULT_DESTRUCTIVE_TOOLS: &[&str] = &["Write", "Edit", "MultiEdit", "Bash"];
+
+/// Default minimum interval between automatic checkpoints.
+pub const DEFAULT_CHECKPOINT_DEBOUNCE_SECS: u64 = 30;
+
+/// Settings key holding the checkpoint policy configuration (JSON). Automatic
+/// checkpoints are disabled while it is unset.
+pub const CHECKPOINT_POLICY_SETTING: &str = "checkpoint_policy";
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Event types that may trigger an automatic checkpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckpointTrigger {
+    BeforeDestructiveTool,
+    PhaseCompleted,
+    GatePassed,
+}
+
+/// Configuration for `CheckpointPolicy`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointPolicyConfig {
+    /// Event types that request a checkpoint.
+    #[serde(default = "default_triggers")]
+    pub triggers: Vec<CheckpointTrigger>,
+    /// Minimum seconds between two automatic checkpoints.
+    #[serde(default = "default_debounce_secs")]
+    pub debounce_secs: u64,
+    /// Tool names considered destructive for `BeforeDestructiveTool`.
+    #[serde(default = "default_destructive_tools")]
+    pub destructive_tools: Vec<String>,
+    /// Skip the checkpoint when tracked files are unchanged since the last one.
+    #[serde(default = "default_true")]
+    pub skip_if_unchanged: bool,
+}
+
+fn default_triggers() -> Vec<CheckpointTrigger> {
+    vec![
+        CheckpointTrigger::BeforeDestructiveTool,
+        CheckpointTrigger::PhaseCompleted,
+        CheckpointTrigger::GatePassed,
+    ]
+}
+
+fn default_debounce_secs() -> u64 {
+    DEFAULT_CHECKPOINT_DEBOUNCE_SECS
+}
+
+fn default_destructive_tools() -> Vec<String> {
+    DEFAULT_DESTRUCTIVE_TOOLS
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for CheckpointPolicyConfig {
+    fn default() -> Self {
+        Self {
+            triggers: default_triggers(),
+            debounce_secs: default_debounce_secs(),
+            destructive_tools: default_destructive_tools(),
+            skip_if_unchanged: true,
+        }
+    }
+}
+
+impl CheckpointPolicyConfig {
+    /// Parse the stored `CHECKPOINT_POLICY_SETTING` value. Returns `None` when
+    /// it is unset or malformed, which leaves automatic checkpoints off.
+    pub fn from_setting(value: Option<&str>) -> Option<Self> {
+        value.and_then(|json| serde_json::from_str(json).ok())
+    }
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+/// An execution event the policy is asked to evaluate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckpointPolicyEvent<'a> {
+    /// A tool is about to be executed.
+    BeforeTool { tool_name: &'a str },
+    /// A phase (story, plan step, ...) finished.
+    PhaseCompleted { phase: &'a str, success: bool },
+    /// A quality gate was evaluated.
+    GateEvaluated { gate_name: &'a str, passed: bool },
+}
+
+impl CheckpointPolicyEvent<'_> {
+    /// The trigger this event maps to, if it is eligible for a checkpoint.
+    fn trigger(&self, config: &CheckpointPolicyConfig) -> Option<CheckpointTrigger> {
+        match self {
+            Self::BeforeTool { tool_name } => config
+                .destructive_tools
+                .iter()
+                .any(|t| t.eq_ignore_ascii_case(tool_name))
+                .then_some(CheckpointTrigger::BeforeDestructiveTool),
+            Self::PhaseCompleted { success: true, .. } => Some(CheckpointTrigger::PhaseCompleted),
+            Self::GateEvaluated { passed: true, .. } => Some(CheckpointTrigger::GatePassed),
+            _ => None,
+        }
+    }
+
+    fn request(&self) -> CheckpointRequest {
+        let (label, description) = match self {
+            Self::BeforeTool { tool_name } => (
+                format!("auto:before-{}", tool_name.to_ascii_lowercase()),
+                format!("Automatic checkpoint before running {}", tool_name),
+            ),
+            Self::PhaseCompleted { phase, .. } => (
+                format!("auto:phase-{}", phase),
+                format!("Automatic checkpoint after phase '{}' completed", phase),
+            ),
+            Self::GateEvaluated { gate_name, .. } => (
+                format!("auto:gate-{}", gate_name),
+                format!("Automatic checkpoint after gate '{}' passed", gate_name),
+            ),
+        };
+        CheckpointRequest {
+            label,
+            description: Some(description),
+        }
+    }
+}
+
+// ============================================================================
+// Policy
+// ============================================================================
+
+/// Stateful policy deciding when to request automatic checkpoints.
+///
+/// One policy instance should be kept per session so debounce and change
+/// tracking span the whole execution.
+#[derive(Debug, Clone)]
+pub struct CheckpointPolicy {
+    config: CheckpointPolicyConfig,
+    last_checkpoint_at: Option<Instant>,
+    last_fingerprint: Option<u64>,
+}
+
+impl CheckpointPolicy {
+    pub fn new(config: CheckpointPolicyConfig) -> Self {
+        Self {
+            config,
+            last_checkpoint_at: None,
+            last_fingerprint: None,
+        }
+    }
+
+    pub fn config(&self) -> &CheckpointPolicyConfig {
+        &self.config
+    }
+
+    /// Whether `event` would request a checkpoint now, before looking at the
+    /// workspace. Lets callers skip fingerprinting for events that cannot fire.
+    pub fn is_due(&self, event: CheckpointPolicyEvent<'_>) -> bool {
+        self.is_due_at(event, Instant::now())
+    }
+
+    fn is_due_at(&self, event: CheckpointPolicyEvent<'_>, now: Instant) -> bool {
+        let Some(trigger) = event.trigger(&self.config) else {
+            return false;
+        };
+        if !self.config.triggers.contains(&trigger) {
+            return false;
+        }
+        self.last_checkpoint_at.is_none_or(|last| {
+            now.saturating_duration_since(last) >= Duration::from_secs(self.config.debounce_secs)
+        })
+    }
+
+    /// Evaluate `event` and return a checkpoint request if one is due.
+    ///
+    /// `fingerprint` identifies the current state of the tracked files (see
+    /// `workspace_fingerprint`).
+    pub fn evaluate(
+        &mut self,
+        event: CheckpointPolicyEvent<'_>,
+        fingerprint: u64,
+    ) -> Option<CheckpointRequest> {
+        self.evaluate_at(event, fingerprint, Instant::now())
+    }
+
+    /// Same as `evaluate`, with an explicit clock for deterministic callers.
+    pub fn evaluate_at(
+        &mut self,
+        event: CheckpointPolicyEvent<'_>,
+        fingerprint: u64,
+        now: Instant,
+    ) -> Option<CheckpointRequest> {
+        if !self.is_due_at(event, now) {
+            return None;
+        }
+        if self.config.skip_if_unchanged && self.last_fingerprint == Some(fingerprint) {
+            return None;
+        }
+        self.record_checkpoint_at(fingerprint, now);
+        Some(event.request())
+    }
+
+    /// Add an automatic checkpoint request to `actions` if one is due.
+    ///
+    /// An explicit `checkpoint_request` already present in `actions` is kept
+    /// and counted as the latest checkpoint for debounce purposes.
+    pub fn apply_to(
+        &mut self,
+        event: CheckpointPolicyEvent<'_>,
+        fingerprint: u64,
+        mut actions: EventActions,
+    ) -> EventActions {
+        if actions.checkpoint_request.is_some() {
+            self.record_checkpoint_at(fingerprint, Instant::now());
+        } else {
+            actions.checkpoint_request = self.evaluate(event, fingerprint);
+        }
+        actions
+    }
+
+    /// Note that a checkpoint was created outside the policy.
+    pub fn record_checkpoint(&mut self, fingerprint: u64) {
+        self.record_checkpoint_at(fingerprint, Instant::now());
+    }
+
+    fn record_checkpoint_at(&mut self, fingerprint: u64, now: Instant) {
+        self.last_checkpoint_at = Some(now);
+        self.last_fingerprint = Some(fingerprint);
+    }
+}
+
+impl Default for CheckpointPolicy {
+    fn default() -> Self {
+        Self::new(CheckpointPolicyConfig::default())
+    }
+}
+
+/// Fingerprint the tracked files under `project_root`.
+///
+/// Hashes each file's relative path, size and modification time (missing
+/// files hash as absent), so the value changes whenever a tracked file is
+/// created, modified or deleted without reading any file contents.
+pub fn workspace_fingerprint(project_root: &Path, tracked_files: &[String]) -> u64 {
+    let mut files: Vec<&String> = tracked_files.iter().collect();
+    files.sort();
+    files.dedup();
+
+    let mut hasher = DefaultHasher::new();
+    for file in files {
+        file.hash(&mut hasher);
+        std::fs::metadata(project_root.join(file))
+            .ok()
+            .map(|meta| (meta.len(), meta.modified().ok()))
+            .hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_without_debounce() -> CheckpointPolicy {
+        CheckpointPolicy::new(CheckpointPolicyConfig {
+            debounce_secs: 0,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_requests_checkpoint_before_destructive_tool() {
+        let mut policy = policy_without_debounce();
+        let request = policy
+            .evaluate(CheckpointPolicyEvent::BeforeTool { tool_name: "Write" }, 1)
+            .expect("checkpoint should be requested");
+        assert_eq!(request.label, "auto:before-write");
+
+        assert!(policy
+            .evaluate(CheckpointPolicyEvent::BeforeTool { tool_name: "Read" }, 2)
+            .is_none());
+    }
+
+    #[test]
+    fn test_only_configured_triggers_fire() {
+        let mut policy = CheckpointPolicy::new(CheckpointPolicyConfig {
+            triggers: vec![CheckpointTrigger::GatePassed],
+            debounce_secs: 0,
+            ..Default::default()
+        });
+        let phase = CheckpointPolicyEvent::PhaseCompleted {
+            phase: "story-1",
+            success: true,
+        };
+        assert!(policy.evaluate(phase, 1).is_none());
+
+        let failed_gate = CheckpointPolicyEvent::GateEvaluated {
+            gate_name: "lint",
+            passed: false,
+        };
+        assert!(policy.evaluate(failed_gate, 1).is_none());
+
+        let passed_gate = CheckpointPolicyEvent::GateEvaluated {
+            gate_name: "lint",
+            passed: true,
+        };
+        assert_eq!(
+            policy.evaluate(passed_gate, 1).unwrap().label,
+            "auto:gate-lint"
+        );
+    }
+
+    #[test]
+    fn test_suppressed_when_no_file_changed() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("main.rs"), "fn main() {}").unwrap();
+        let tracked = vec!["main.rs".to_string()];
+        let mut policy = policy_without_debounce();
+        let event = CheckpointPolicyEvent::PhaseCompleted {
+            phase: "story-1",
+            success: true,
+        };
+
+        let fp = workspace_fingerprint(dir.path(), &tracked);
+        assert!(policy.evaluate(event, fp).is_some());
+
+        let unchanged = workspace_fingerprint(dir.path(), &tracked);
+        assert!(policy.evaluate(event, unchanged).is_none());
+
+        std::fs::write(dir.path().join("main.rs"), "fn main() { run(); }").unwrap();
+        let changed = workspace_fingerprint(dir.path(), &tracked);
+        assert!(policy.evaluate(event, changed).is_some());
+    }
+
+    #[test]
+    fn test_config_from_setting_is_opt_in() {
+        assert!(CheckpointPolicyConfig::from_setting(None).is_none());
+        assert!(CheckpointPolicyConfig::from_setting(Some("not json")).is_none());
+
+        let config = CheckpointPolicyConfig::from_setting(Some(r#"{"debounce_secs": 5}"#))
+            .expect("valid setting");
+        assert_eq!(config.debounce_secs, 5);
+        assert_eq!(config.triggers, default_triggers());
+    }
+
+    #[test]
+    fn test_is_due_ignores_non_triggering_events() {
+        let policy = policy_without_debounce();
+        assert!(policy.is_due(CheckpointPolicyEvent::BeforeTool { tool_name: "Edit" }));
+        assert!(!policy.is_due(CheckpointPolicyEvent::BeforeTool { tool_name: "Grep" }));
+        assert!(!policy.is_due(CheckpointPolicyEvent::PhaseCompleted {
+            phase: "story-1",
+            success: false,
+        }));
+    }
+
+    #[test]
+    fn test_debounce_suppresses_rapid_checkpoints() {
+        let mut policy = CheckpointPolicy::new(CheckpointPolicyConfig {
+            debounce_secs: 30,
+            ..Default::default()
+        });
+        let event = CheckpointPolicyEvent::BeforeTool { tool_name: "Edit" };
+        let start = Instant::now();
+
+        assert!(policy.evaluate_at(event, 1, start).is_some());
+        assert!(policy
+            .evaluate_at(event, 2, start + Duration::from_secs(5))
+            .is_none());
+        assert!(policy
+            .evaluate_at(event, 3, start + Duration::from_secs(31))
+            .is_some());
+    }
+
+    #[test]
+    fn test_apply_to_fills_checkpoint_request_and_respects_explicit_one() {
+        let mut policy = policy_without_debounce();
+        let event = CheckpointPolicyEvent::BeforeTool { tool_name: "Bash" };
+
+        let actions = policy.apply_to(event, 1, EventActions::none());
+        assert_eq!(
+            actions.checkpoint_request.unwrap().label,
+            "auto:before-bash"
+        );
+
+        let explicit = policy.apply_to(event, 2, EventActions::none().with_checkpoint("manual"));
+        assert_eq!(explicit.checkpoint_request.unwrap().label, "manual");
+
+        // The explicit checkpoint already covers fingerprint 2.
+        let actions = policy.apply_to(event, 2, EventActions::none());
+        assert!(actions.checkpoint_request.is_none());
+    }
+}
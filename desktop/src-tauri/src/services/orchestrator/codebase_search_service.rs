@@ -26,6 +26,89 @@ pub struct CodebaseSearchFilters {
     pub component: Option<String>,
     pub language: Option<String>,
     pub file_path_prefix: Option<String>,
+    /// Restrict results to symbol definitions or references.
+    #[serde(default)]
+    pub kind: SymbolMatchKind,
+    /// Restrict results to a category of symbol.
+    #[serde(default)]
+    pub symbol_type: SymbolTypeFilter,
+}
+
+/// Which occurrences of a symbol a search returns.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolMatchKind {
+    Definition,
+    Reference,
+    #[default]
+    Any,
+}
+
+impl SymbolMatchKind {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "definition" => Some(Self::Definition),
+            "reference" => Some(Self::Reference),
+            "any" => Some(Self::Any),
+            _ => None,
+        }
+    }
+}
+
+/// Which category of symbol a search returns.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolTypeFilter {
+    Function,
+    Type,
+    Variable,
+    #[default]
+    Any,
+}
+
+impl SymbolTypeFilter {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "function" => Some(Self::Function),
+            "type" => Some(Self::Type),
+            "variable" => Some(Self::Variable),
+            "any" => Some(Self::Any),
+            _ => None,
+        }
+    }
+
+    /// Whether an indexed symbol kind (as stored by `IndexStore`) belongs to this category.
+    fn matches_stored_kind(self, kind: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Function => kind == "Function",
+            Self::Type => matches!(kind, "Class" | "Struct" | "Enum" | "Interface" | "Type"),
+            Self::Variable => kind == "Const",
+        }
+    }
+}
+
+/// How a search hit relates to the queried symbol.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum HitKind {
+    /// The site where the symbol is declared
+    Definition,
+    /// A use of the symbol found by the language server
+    Reference,
+    /// A full-text, path, or semantic match without structural information
+    #[default]
+    Text,
+}
+
+impl std::fmt::Display for HitKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HitKind::Definition => write!(f, "definition"),
+            HitKind::Reference => write!(f, "reference"),
+            HitKind::Text => write!(f, "text"),
+        }
+    }
 }
 
 /// Single channel score contribution for a search hit.
@@ -51,6 +134,8 @@ pub struct SearchHit {
     pub language: Option<String>,
     pub channels: Vec<String>,
     pub query_id: String,
+    #[serde(default)]
+    pub hit_kind: HitKind,
 }
 
 /// Search diagnostics for UI/tool troubleshooting.
@@ -172,19 +257,6 @@ impl CodebaseSearchService {
             .resolve_equivalent_project_path(&request.project_path)
             .unwrap_or_else(|_| request.project_path.clone());
 
-        let mut engine = HybridSearchEngine::with_defaults(
-            Arc::clone(&self.index_store),
-            self.embedding_manager.clone(),
-        );
-        if let Some(hnsw) = self.hnsw_index.clone() {
-            engine.set_hnsw_index(hnsw);
-        }
-
-        let outcome = engine
-            .search(&request.query, &resolved_project_path)
-            .await
-            .map_err(|e| format!("Search failed: {}", e))?;
-
         let parsed_modes = parse_requested_modes(&request.modes)?;
         let include_snippet = request.include_snippet.unwrap_or(true);
         let filters = request.filters.unwrap_or_default();
@@ -233,6 +305,55 @@ impl CodebaseSearchService {
             };
 
         let query_id = uuid::Uuid::new_v4().to_string();
+        let path_allowed = |file_path: &str| {
+            allowed_component_paths
+                .as_ref()
+                .is_none_or(|allowed| allowed.contains(file_path))
+                && path_prefix_filter.is_none_or(|prefix| file_path.starts_with(prefix))
+                && allowed_language_paths
+                    .as_ref()
+                    .is_none_or(|allowed| allowed.contains(file_path))
+        };
+
+        let structural =
+            filters.kind != SymbolMatchKind::Any || filters.symbol_type != SymbolTypeFilter::Any;
+        if structural {
+            let hits: Vec<SearchHit> = self
+                .structural_hits(
+                    &resolved_project_path,
+                    request.query.trim(),
+                    filters.kind,
+                    filters.symbol_type,
+                    include_snippet,
+                    &query_id,
+                )
+                .into_iter()
+                .filter(|hit| path_allowed(&hit.file_path))
+                .collect();
+            if !hits.is_empty() {
+                return Ok(Self::paginate(
+                    hits,
+                    request.offset,
+                    request.limit,
+                    query_id,
+                    None,
+                ));
+            }
+        }
+
+        let mut engine = HybridSearchEngine::with_defaults(
+            Arc::clone(&self.index_store),
+            self.embedding_manager.clone(),
+        );
+        if let Some(hnsw) = self.hnsw_index.clone() {
+            engine.set_hnsw_index(hnsw);
+        }
+
+        let outcome = engine
+            .search(&request.query, &resolved_project_path)
+            .await
+            .map_err(|e| format!("Search failed: {}", e))?;
+
         let mut file_meta_cache: HashMap<String, (Option<String>, Option<String>)> = HashMap::new();
         let mut symbol_line_cache: HashMap<(String, String), (Option<usize>, Option<usize>)> =
             HashMap::new();
@@ -249,25 +370,7 @@ impl CodebaseSearchService {
                     return false;
                 }
 
-                if let Some(ref allowed) = allowed_component_paths {
-                    if !allowed.contains(&result.file_path) {
-                        return false;
-                    }
-                }
-
-                if let Some(prefix) = path_prefix_filter {
-                    if !result.file_path.starts_with(prefix) {
-                        return false;
-                    }
-                }
-
-                if let Some(ref allowed) = allowed_language_paths {
-                    if !allowed.contains(&result.file_path) {
-                        return false;
-                    }
-                }
-
-                true
+                path_allowed(&result.file_path)
             })
             .map(|result| {
                 let file_path = result.file_path;
@@ -352,14 +455,20 @@ impl CodebaseSearchService {
                     language,
                     channels,
                     query_id: query_id.clone(),
+                    // A symbol hit that resolved to indexed lines is its declaration
+                    hit_kind: if line_start.is_some() {
+                        HitKind::Definition
+                    } else {
+                        HitKind::Text
+                    },
                 }
             })
             .collect();
 
-        let total = hits.len();
-        let offset = request.offset.unwrap_or(0).min(total);
-        let limit = request.limit.unwrap_or(20).clamp(1, 100);
-        hits = hits.into_iter().skip(offset).take(limit).collect();
+        // Text fallback: a declaration is never a reference
+        if structural && filters.kind == SymbolMatchKind::Reference {
+            hits.retain(|hit| hit.hit_kind != HitKind::Definition);
+        }
 
         let semantic_degraded = outcome.semantic_degraded;
         let semantic_error = outcome.semantic_error.clone();
@@ -373,23 +482,124 @@ impl CodebaseSearchService {
             .map(|channel| channel.to_string())
             .collect();
 
-        Ok(CodeSearchResponse {
+        let diagnostics = CodeSearchDiagnostics {
+            query_id: query_id.clone(),
+            active_channels,
+            semantic_degraded,
+            semantic_error,
+            provider_display,
+            embedding_dimension,
+            hnsw_used,
+            hnsw_vector_count,
+        };
+        Ok(Self::paginate(
+            hits,
+            request.offset,
+            request.limit,
+            query_id,
+            Some(diagnostics),
+        ))
+    }
+
+    fn paginate(
+        hits: Vec<SearchHit>,
+        offset: Option<usize>,
+        limit: Option<usize>,
+        query_id: String,
+        diagnostics: Option<CodeSearchDiagnostics>,
+    ) -> CodeSearchResponse {
+        let total = hits.len();
+        let offset = offset.unwrap_or(0).min(total);
+        let limit = limit.unwrap_or(20).clamp(1, 100);
+        let hits = hits.into_iter().skip(offset).take(limit).collect();
+        let (semantic_degraded, semantic_error) = diagnostics
+            .as_ref()
+            .map(|d| (d.semantic_degraded, d.semantic_error.clone()))
+            .unwrap_or((false, None));
+
+        CodeSearchResponse {
             hits,
             total,
             semantic_degraded,
-            semantic_error: semantic_error.clone(),
-            query_id: query_id.clone(),
-            diagnostics: Some(CodeSearchDiagnostics {
-                query_id,
-                active_channels,
-                semantic_degraded,
-                semantic_error,
-                provider_display,
-                embedding_dimension,
-                hnsw_used,
-                hnsw_vector_count,
-            }),
-        })
+            semantic_error,
+            query_id,
+            diagnostics,
+        }
+    }
+
+    /// Resolve definitions from the symbol index and references from
+    /// LSP cross-references for an exact symbol name.
+    fn structural_hits(
+        &self,
+        project_path: &str,
+        symbol: &str,
+        kind: SymbolMatchKind,
+        symbol_type: SymbolTypeFilter,
+        include_snippet: bool,
+        query_id: &str,
+    ) -> Vec<SearchHit> {
+        let definitions: Vec<_> = self
+            .index_store
+            .query_symbols(project_path, symbol)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|m| m.symbol_name == symbol)
+            .filter(|m| symbol_type.matches_stored_kind(&m.symbol_kind))
+            .collect();
+
+        let mut hits = Vec::new();
+        if kind != SymbolMatchKind::Reference {
+            for def in &definitions {
+                hits.push(SearchHit {
+                    file_path: def.file_path.clone(),
+                    symbol_name: Some(def.symbol_name.clone()),
+                    snippet: if include_snippet {
+                        def.signature.clone()
+                    } else {
+                        None
+                    },
+                    similarity: None,
+                    score: 1.0,
+                    score_breakdown: Vec::new(),
+                    line_start: Some(def.line_number),
+                    line_end: Some(def.end_line.max(def.line_number)),
+                    component: None,
+                    language: None,
+                    channels: vec![SearchChannel::Symbol.to_string()],
+                    query_id: query_id.to_string(),
+                    hit_kind: HitKind::Definition,
+                });
+            }
+        }
+
+        // A typed reference search only counts uses of a matching definition
+        let references_possible = symbol_type == SymbolTypeFilter::Any || !definitions.is_empty();
+        if kind != SymbolMatchKind::Definition && references_possible {
+            let references = self
+                .index_store
+                .get_references_to_symbol(project_path, symbol)
+                .unwrap_or_default();
+            for reference in references {
+                let line = reference.source_line.max(0) as usize;
+                hits.push(SearchHit {
+                    file_path: reference.source_file,
+                    symbol_name: Some(symbol.to_string()),
+                    snippet: None,
+                    similarity: None,
+                    score: 0.5,
+                    score_breakdown: Vec::new(),
+                    line_start: Some(line),
+                    line_end: Some(line),
+                    component: None,
+                    language: None,
+                    channels: vec!["reference".to_string()],
+                    query_id: query_id.to_string(),
+                    hit_kind: HitKind::Reference,
+                });
+            }
+        }
+
+        hits
     }
 }
 
@@ -431,4 +641,115 @@ mod tests {
         assert!(channel_matches_modes(SearchChannel::FilePath, &modes));
         assert!(channel_matches_modes(SearchChannel::Semantic, &modes));
     }
+
+    fn store_with_symbol_and_call_site() -> Arc<IndexStore> {
+        use super::super::analysis_index::{FileInventoryItem, SymbolInfo, SymbolKind};
+
+        let db = crate::storage::database::Database::new_in_memory().expect("in-memory db");
+        let store = IndexStore::new(db.pool().clone());
+        for (path, symbols) in [
+            (
+                "src/config.rs",
+                vec![SymbolInfo::basic(
+                    "parse_config".to_string(),
+                    SymbolKind::Function,
+                    12,
+                )],
+            ),
+            ("src/main.rs", vec![]),
+        ] {
+            let item = FileInventoryItem {
+                path: path.to_string(),
+                component: "app".to_string(),
+                language: "rust".to_string(),
+                extension: Some("rs".to_string()),
+                size_bytes: 512,
+                line_count: 40,
+                is_test: false,
+                symbols,
+                content_hash: None,
+            };
+            store.upsert_file_index("/proj", &item, path).unwrap();
+        }
+        store
+            .insert_cross_reference(
+                "/proj",
+                "src/main.rs",
+                30,
+                Some("main"),
+                "src/config.rs",
+                12,
+                Some("parse_config"),
+                "call",
+            )
+            .unwrap();
+        Arc::new(store)
+    }
+
+    fn symbol_request(
+        kind: SymbolMatchKind,
+        symbol_type: SymbolTypeFilter,
+    ) -> CodebaseSearchRequest {
+        CodebaseSearchRequest {
+            project_path: "/proj".to_string(),
+            query: "parse_config".to_string(),
+            filters: Some(CodebaseSearchFilters {
+                kind,
+                symbol_type,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn definition_filter_returns_defining_site_only() {
+        let service = CodebaseSearchService::new(store_with_symbol_and_call_site(), None, None);
+
+        let response = service
+            .search(symbol_request(
+                SymbolMatchKind::Definition,
+                SymbolTypeFilter::Function,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.hits.len(), 1);
+        let hit = &response.hits[0];
+        assert_eq!(hit.file_path, "src/config.rs");
+        assert_eq!(hit.line_start, Some(12));
+        assert_eq!(hit.hit_kind, HitKind::Definition);
+        assert!(response.hits.iter().all(|h| h.file_path != "src/main.rs"));
+    }
+
+    #[tokio::test]
+    async fn reference_filter_returns_call_sites_only() {
+        let service = CodebaseSearchService::new(store_with_symbol_and_call_site(), None, None);
+
+        let response = service
+            .search(symbol_request(
+                SymbolMatchKind::Reference,
+                SymbolTypeFilter::Any,
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.hits.len(), 1);
+        assert_eq!(response.hits[0].file_path, "src/main.rs");
+        assert_eq!(response.hits[0].line_start, Some(30));
+        assert_eq!(response.hits[0].hit_kind, HitKind::Reference);
+    }
+
+    #[test]
+    fn symbol_type_maps_stored_kinds() {
+        assert!(SymbolTypeFilter::Type.matches_stored_kind("Struct"));
+        assert!(SymbolTypeFilter::Function.matches_stored_kind("Function"));
+        assert!(!SymbolTypeFilter::Function.matches_stored_kind("Struct"));
+        assert!(SymbolTypeFilter::Variable.matches_stored_kind("Const"));
+        assert_eq!(
+            SymbolMatchKind::parse("Definition"),
+            Some(SymbolMatchKind::Definition)
+        );
+        assert_eq!(SymbolTypeFilter::parse("bogus"), None);
+    }
 }
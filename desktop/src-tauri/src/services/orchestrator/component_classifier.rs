@@ -3,6 +3,11 @@
 //! Classifies indexed files into logical components using either LLM analysis
 //! or a heuristic fallback (top-level directory names).  Results are cached in
 //! the `component_mappings` SQLite table for incremental reuse.
+//!
+//! Independently of the component a file belongs to, `classify_file_concerns`
+//! scores which concerns it implements ("api", "model", "ui", ...). A file may
+//! carry several labels, each with a confidence; files with no label above
+//! the threshold are reported as `"unclassified"`.
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -41,6 +46,51 @@ pub struct ClassificationResult {
     pub files_updated: usize,
 }
 
+/// Label emitted when no concern reaches the confidence threshold.
+pub const UNCLASSIFIED_LABEL: &str = "unclassified";
+
+/// Default minimum confidence for a concern label to be emitted.
+pub const DEFAULT_CONCERN_CONFIDENCE_THRESHOLD: f32 = 0.5;
+
+/// A concern label with its confidence in `[0, 1]`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelScore {
+    pub label: String,
+    pub confidence: f32,
+}
+
+/// Multi-label concern classification of a single file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileClassification {
+    pub file_path: String,
+    /// Labels at or above the threshold, highest confidence first. Holds a
+    /// single `"unclassified"` entry when nothing qualified; its confidence
+    /// is `1 - best_score`.
+    pub labels: Vec<LabelScore>,
+}
+
+impl FileClassification {
+    /// Whether no concern reached the threshold.
+    pub fn is_unclassified(&self) -> bool {
+        self.labels.iter().all(|l| l.label == UNCLASSIFIED_LABEL)
+    }
+
+    /// Confidence for `label`, or 0.0 if it was not emitted.
+    pub fn confidence_for(&self, label: &str) -> f32 {
+        self.labels
+            .iter()
+            .find(|l| l.label == label)
+            .map_or(0.0, |l| l.confidence)
+    }
+
+    /// The highest-confidence label.
+    pub fn primary_label(&self) -> &str {
+        self.labels
+            .first()
+            .map_or(UNCLASSIFIED_LABEL, |l| l.label.as_str())
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Directory tree builder
 // ---------------------------------------------------------------------------
@@ -147,6 +197,209 @@ pub fn heuristic_classify(file_paths: &[String]) -> Vec<ComponentMapping> {
     mappings
 }
 
+// ---------------------------------------------------------------------------
+// Concern classification
+// ---------------------------------------------------------------------------
+
+/// Evidence for one concern: path tokens, file extensions and content markers.
+struct ConcernRule {
+    label: &'static str,
+    path_tokens: &'static [&'static str],
+    extensions: &'static [&'static str],
+    content_markers: &'static [&'static str],
+}
+
+/// Weight of a matching path token or extension.
+const PATH_SIGNAL_WEIGHT: f32 = 0.4;
+/// Weight of each occurrence of a content marker.
+const CONTENT_SIGNAL_WEIGHT: f32 = 0.25;
+/// Occurrences of a single marker beyond this add no further evidence.
+const MAX_MARKER_HITS: usize = 3;
+
+const CONCERN_RULES: &[ConcernRule] = &[
+    ConcernRule {
+        label: "api",
+        path_tokens: &[
+            "api",
+            "apis",
+            "routes",
+            "router",
+            "handlers",
+            "controllers",
+            "endpoints",
+            "commands",
+        ],
+        extensions: &[],
+        content_markers: &[
+            "#[tauri::command]",
+            "#[get(",
+            "#[post(",
+            "@app.route",
+            "@app.get(",
+            "@app.post(",
+            "@router.",
+            "APIRouter",
+            "app.get(",
+            "app.post(",
+            "@RestController",
+            "@GetMapping",
+            "@PostMapping",
+            "HttpResponse",
+            "http.HandleFunc",
+        ],
+    },
+    ConcernRule {
+        label: "model",
+        path_tokens: &[
+            "models", "model", "entities", "entity", "schema", "schemas", "domain", "dto",
+        ],
+        extensions: &[],
+        content_markers: &[
+            "#[derive(Serialize",
+            "#[derive(Deserialize",
+            "(BaseModel)",
+            "pydantic",
+            "@dataclass",
+            "models.Model",
+            "@Entity",
+            "@Table",
+            "pub struct",
+            "interface ",
+        ],
+    },
+    ConcernRule {
+        label: "ui",
+        path_tokens: &[
+            "components",
+            "component",
+            "views",
+            "view",
+            "pages",
+            "ui",
+            "widgets",
+            "screens",
+        ],
+        extensions: &["tsx", "jsx", "vue", "svelte", "css", "scss"],
+        content_markers: &[
+            "useState(",
+            "useEffect(",
+            "className=",
+            "<template>",
+            "render(",
+            "@Component",
+        ],
+    },
+    ConcernRule {
+        label: "test",
+        path_tokens: &["tests", "test", "__tests__", "spec", "specs"],
+        extensions: &[],
+        content_markers: &[
+            "#[test]",
+            "#[tokio::test]",
+            "#[cfg(test)]",
+            "describe(",
+            "it('",
+            "it(\"",
+            "def test_",
+            "@Test",
+            "assert",
+        ],
+    },
+    ConcernRule {
+        label: "storage",
+        path_tokens: &[
+            "db",
+            "database",
+            "storage",
+            "repository",
+            "repositories",
+            "migrations",
+            "dao",
+            "store",
+        ],
+        extensions: &["sql"],
+        content_markers: &[
+            "SELECT ",
+            "INSERT INTO",
+            "CREATE TABLE",
+            "rusqlite",
+            "sqlx::",
+            "diesel::",
+            "session.query",
+            "prisma.",
+        ],
+    },
+    ConcernRule {
+        label: "config",
+        path_tokens: &["config", "configs", "settings", "env"],
+        extensions: &["toml", "yaml", "yml", "ini", "env"],
+        content_markers: &["std::env::var", "os.environ", "process.env", "dotenv"],
+    },
+];
+
+/// Score every concern for a file from its path and content.
+///
+/// Signals combine as a noisy-or (`1 - Π(1 - w)`), so independent evidence
+/// raises confidence without ever reaching 1. Every concern whose confidence
+/// is at least `threshold` is emitted; a file can therefore be both "api" and
+/// "model". When none qualifies, the result is a single `"unclassified"`
+/// label.
+pub fn classify_file_concerns(
+    file_path: &str,
+    content: &str,
+    threshold: f32,
+) -> FileClassification {
+    let lower_path = file_path.to_lowercase();
+    let path_tokens: Vec<&str> = lower_path
+        .split(['/', '\\', '.', '_', '-'])
+        .filter(|t| !t.is_empty())
+        .collect();
+    let extension = lower_path
+        .rsplit_once('.')
+        .map(|(_, ext)| ext)
+        .filter(|ext| !ext.contains('/'));
+
+    let mut scores: Vec<LabelScore> = CONCERN_RULES
+        .iter()
+        .map(|rule| {
+            let mut miss = 1.0f32;
+            if rule.path_tokens.iter().any(|t| path_tokens.contains(t)) {
+                miss *= 1.0 - PATH_SIGNAL_WEIGHT;
+            }
+            if extension.is_some_and(|ext| rule.extensions.contains(&ext)) {
+                miss *= 1.0 - PATH_SIGNAL_WEIGHT;
+            }
+            for marker in rule.content_markers {
+                let hits = content.matches(marker).take(MAX_MARKER_HITS).count();
+                miss *= (1.0 - CONTENT_SIGNAL_WEIGHT).powi(hits as i32);
+            }
+            LabelScore {
+                label: rule.label.to_string(),
+                confidence: 1.0 - miss,
+            }
+        })
+        .collect();
+    scores.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+
+    let best = scores.first().map_or(0.0, |s| s.confidence);
+    let labels: Vec<LabelScore> = scores
+        .into_iter()
+        .filter(|s| s.confidence > 0.0 && s.confidence >= threshold)
+        .collect();
+
+    FileClassification {
+        file_path: file_path.to_string(),
+        labels: if labels.is_empty() {
+            vec![LabelScore {
+                label: UNCLASSIFIED_LABEL.to_string(),
+                confidence: 1.0 - best,
+            }]
+        } else {
+            labels
+        },
+    }
+}
+
 // ---------------------------------------------------------------------------
 // LLM classification
 // ---------------------------------------------------------------------------
@@ -518,6 +771,65 @@ mod tests {
         assert_eq!(lookup_component(&mappings, "unknown/deep/file.rs"), "other");
     }
 
+    #[test]
+    fn test_mixed_concern_file_gets_multiple_labels() {
+        let content = r#"
+from fastapi import APIRouter
+from pydantic import BaseModel
+
+router = APIRouter()
+
+class UserCreate(BaseModel):
+    name: str
+
+class User(BaseModel):
+    id: int
+    name: str
+
+@router.get("/users/{id}")
+def get_user(id: int) -> User:
+    ...
+"#;
+        let result = classify_file_concerns(
+            "backend/api/users.py",
+            content,
+            DEFAULT_CONCERN_CONFIDENCE_THRESHOLD,
+        );
+
+        assert!(!result.is_unclassified());
+        assert!(result.confidence_for("api") >= DEFAULT_CONCERN_CONFIDENCE_THRESHOLD);
+        assert!(result.confidence_for("model") >= DEFAULT_CONCERN_CONFIDENCE_THRESHOLD);
+        assert_eq!(result.primary_label(), "api");
+        assert_eq!(result.confidence_for("ui"), 0.0);
+        // Confidences are sorted and bounded.
+        assert!(result
+            .labels
+            .windows(2)
+            .all(|w| w[0].confidence >= w[1].confidence));
+        assert!(result.labels.iter().all(|l| l.confidence < 1.0));
+    }
+
+    #[test]
+    fn test_low_signal_file_is_unclassified() {
+        let result = classify_file_concerns(
+            "misc/notes.txt",
+            "Remember to rename things later.",
+            DEFAULT_CONCERN_CONFIDENCE_THRESHOLD,
+        );
+        assert!(result.is_unclassified());
+        assert_eq!(result.primary_label(), UNCLASSIFIED_LABEL);
+        assert_eq!(result.labels.len(), 1);
+
+        // A single weak signal stays below the threshold too.
+        let result = classify_file_concerns(
+            "src/lib.rs",
+            "pub struct Wrapper(u8);",
+            DEFAULT_CONCERN_CONFIDENCE_THRESHOLD,
+        );
+        assert!(result.is_unclassified());
+        assert!(result.labels[0].confidence < 1.0);
+    }
+
     #[test]
     fn test_to_kebab_case() {
         assert_eq!(to_kebab_case("src_tauri"), "src-tauri");
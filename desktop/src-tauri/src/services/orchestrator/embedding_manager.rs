@@ -11,6 +11,14 @@
 //! `Arc<EmbeddingManager>`. It centralises provider selection, fallback logic,
 //! and caching policies so individual consumers never manage providers directly.
 //!
+//! ## Index Signatures
+//!
+//! Every stored embedding records the provider type, model and dimension that
+//! produced it (`EmbeddingMetadata`). `check_index_signature` compares those
+//! against the active provider so that a provider or model switch is reported
+//! as an `EmbeddingIndexMismatch` (and the index re-embedded) instead of
+//! querying old vectors with an incompatible embedder.
+//!
 //! ## Thread Safety
 //!
 //! The manager is `Send + Sync`. The internal cache uses `mini_moka::sync::Cache`
@@ -56,6 +64,7 @@ use super::embedding_provider_openai::OpenAIEmbeddingProvider;
 use super::embedding_provider_qwen::QwenEmbeddingProvider;
 use super::embedding_provider_tfidf::TfIdfEmbeddingProvider;
 use super::embedding_service::EmbeddingService;
+use super::index_store::EmbeddingMetadata;
 
 #[derive(Default)]
 struct CloudGateSchedule {
@@ -200,6 +209,78 @@ impl CacheKey {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Index signature
+// ---------------------------------------------------------------------------
+
+/// An existing index was built with a different embedding signature than the
+/// active provider produces, so its vectors cannot be queried meaningfully.
+///
+/// Resolved by re-embedding the project with the active provider.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EmbeddingIndexMismatch {
+    /// Signature recorded with the existing index.
+    pub stored: EmbeddingMetadata,
+    /// Signature of the currently configured provider.
+    pub current: EmbeddingMetadata,
+}
+
+impl EmbeddingIndexMismatch {
+    /// Whether both sides have a known dimension and those differ.
+    pub fn is_dimension_change(&self) -> bool {
+        self.stored.embedding_dimension > 0
+            && self.current.embedding_dimension > 0
+            && self.stored.embedding_dimension != self.current.embedding_dimension
+    }
+
+    /// Convert into the error returned to callers that try to use the index.
+    pub fn to_error(&self) -> EmbeddingError {
+        if self.is_dimension_change() {
+            EmbeddingError::DimensionMismatch {
+                model: self.current.provider_model.clone(),
+                expected: self.stored.embedding_dimension,
+                actual: self.current.embedding_dimension,
+            }
+        } else {
+            EmbeddingError::InvalidConfig {
+                message: format!(
+                    "index was built with {} ({}) but the active embedding provider is {} ({}); \
+                     re-embed the project to migrate the index",
+                    self.stored.provider_model,
+                    self.stored.provider_type,
+                    self.current.provider_model,
+                    self.current.provider_type
+                ),
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for EmbeddingIndexMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_error().fmt(f)
+    }
+}
+
+/// Whether vectors recorded under `stored` can be searched with vectors
+/// produced under `current`.
+///
+/// TF-IDF dimensions follow the persisted vocabulary rather than the provider,
+/// so only the provider type is compared for it. A dimension of 0 means
+/// "not yet known" and matches anything.
+fn signatures_compatible(stored: &EmbeddingMetadata, current: &EmbeddingMetadata) -> bool {
+    if stored.provider_type != current.provider_type {
+        return false;
+    }
+    if current.provider_type == EmbeddingProviderType::TfIdf.to_string() {
+        return true;
+    }
+    stored.provider_model == current.provider_model
+        && (stored.embedding_dimension == 0
+            || current.embedding_dimension == 0
+            || stored.embedding_dimension == current.embedding_dimension)
+}
+
 // ---------------------------------------------------------------------------
 // EmbeddingManager
 // ---------------------------------------------------------------------------
@@ -404,6 +485,34 @@ impl EmbeddingManager {
         self.primary.display_name()
     }
 
+    /// The signature recorded with embeddings produced by this manager.
+    pub fn signature(&self) -> EmbeddingMetadata {
+        EmbeddingMetadata {
+            provider_type: self.provider_type().to_string(),
+            provider_model: self.display_name().to_string(),
+            embedding_dimension: self.dimension(),
+        }
+    }
+
+    /// Compare the signatures stored with an index against this manager.
+    ///
+    /// Returns the first incompatible stored signature, or `None` if every
+    /// stored vector can be searched with this manager's embeddings (including
+    /// when the index is empty).
+    pub fn check_index_signature(
+        &self,
+        stored: &[EmbeddingMetadata],
+    ) -> Option<EmbeddingIndexMismatch> {
+        let current = self.signature();
+        stored
+            .iter()
+            .find(|s| !signatures_compatible(s, &current))
+            .map(|s| EmbeddingIndexMismatch {
+                stored: s.clone(),
+                current,
+            })
+    }
+
     /// Returns a reference to the primary provider for direct access
     /// (e.g., TF-IDF vocabulary operations via downcast).
     pub fn primary_provider(&self) -> &dyn EmbeddingProvider {
@@ -744,6 +853,11 @@ mod tests {
             self
         }
 
+        fn with_dim(mut self, dim: usize) -> Self {
+            self.dim = dim;
+            self
+        }
+
         fn with_failure(mut self, retryable: bool) -> Self {
             self.should_fail = true;
             self.fail_retryable = retryable;
@@ -1318,6 +1432,96 @@ mod tests {
         assert!(manager.health_check().await.is_err());
     }
 
+    // =====================================================================
+    // Index signature tests
+    // =====================================================================
+
+    fn stored_signature(provider_type: &str, model: &str, dim: usize) -> EmbeddingMetadata {
+        EmbeddingMetadata {
+            provider_type: provider_type.to_string(),
+            provider_model: model.to_string(),
+            embedding_dimension: dim,
+        }
+    }
+
+    #[test]
+    fn signature_reflects_primary_provider() {
+        let primary = MockProvider::new("Ollama (nomic-embed-text)", EmbeddingProviderType::Ollama)
+            .with_dim(768);
+        let manager = EmbeddingManager::new(Box::new(primary), None, test_config(false));
+        assert_eq!(
+            manager.signature(),
+            stored_signature("ollama", "Ollama (nomic-embed-text)", 768)
+        );
+    }
+
+    #[test]
+    fn check_index_signature_detects_dimension_mismatch() {
+        let primary = MockProvider::new(
+            "OpenAI (text-embedding-3-small)",
+            EmbeddingProviderType::OpenAI,
+        )
+        .with_dim(1536);
+        let manager = EmbeddingManager::new(Box::new(primary), None, test_config(false));
+
+        let stored = vec![stored_signature(
+            "openai",
+            "OpenAI (text-embedding-3-small)",
+            512,
+        )];
+        let mismatch = manager
+            .check_index_signature(&stored)
+            .expect("dimension change should be detected");
+        assert!(mismatch.is_dimension_change());
+        assert!(matches!(
+            mismatch.to_error(),
+            EmbeddingError::DimensionMismatch {
+                expected: 512,
+                actual: 1536,
+                ..
+            }
+        ));
+
+        let stored = vec![stored_signature(
+            "openai",
+            "OpenAI (text-embedding-3-small)",
+            1536,
+        )];
+        assert!(manager.check_index_signature(&stored).is_none());
+        assert!(manager.check_index_signature(&[]).is_none());
+    }
+
+    #[test]
+    fn check_index_signature_detects_provider_and_model_switch() {
+        let primary =
+            MockProvider::new("Ollama (mxbai-embed-large)", EmbeddingProviderType::Ollama)
+                .with_dim(1024);
+        let manager = EmbeddingManager::new(Box::new(primary), None, test_config(false));
+
+        // Same dimension, different model: vectors live in unrelated spaces.
+        let stored = vec![stored_signature("qwen", "Qwen (text-embedding-v3)", 1024)];
+        let mismatch = manager.check_index_signature(&stored).unwrap();
+        assert!(!mismatch.is_dimension_change());
+        assert!(mismatch.to_string().contains("re-embed"));
+
+        // A partially migrated index is still a mismatch.
+        let stored = vec![
+            stored_signature("ollama", "Ollama (mxbai-embed-large)", 1024),
+            stored_signature("tfidf", "TF-IDF (Local)", 0),
+        ];
+        let mismatch = manager.check_index_signature(&stored).unwrap();
+        assert_eq!(mismatch.stored.provider_type, "tfidf");
+    }
+
+    #[test]
+    fn check_index_signature_ignores_tfidf_vocabulary_size() {
+        let primary =
+            MockProvider::new("TF-IDF (Local)", EmbeddingProviderType::TfIdf).with_dim(42);
+        let manager = EmbeddingManager::new(Box::new(primary), None, test_config(false));
+        let stored = vec![stored_signature("tfidf", "tfidf-v1", 0)];
+        assert!(manager.check_index_signature(&stored).is_none());
+    }
+
     // =====================================================================
     // CacheKey tests
     // =====================================================================
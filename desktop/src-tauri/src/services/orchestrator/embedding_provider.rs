@@ -66,6 +66,14 @@ pub enum EmbeddingError {
     /// Configuration is invalid or incomplete.
     InvalidConfig { message: String },
 
+    /// The model produces vectors whose dimension differs from the one the
+    /// existing index was built with.
+    DimensionMismatch {
+        model: String,
+        expected: usize,
+        actual: usize,
+    },
+
     /// Any other error.
     Other { message: String },
 }
@@ -100,6 +108,16 @@ impl fmt::Display for EmbeddingError {
             }
             Self::RateLimited { message, .. } => write!(f, "rate limited: {}", message),
             Self::InvalidConfig { message } => write!(f, "invalid config: {}", message),
+            Self::DimensionMismatch {
+                model,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "dimension mismatch: model '{}' produces {}-dimensional embeddings but the \
+                 existing index uses {}; re-index or choose a model with matching dimensions",
+                model, actual, expected
+            ),
             Self::Other { message } => write!(f, "{}", message),
         }
     }
@@ -6,8 +6,17 @@
 //!
 //! ## Default Model
 //!
-//! Uses `nomic-embed-text` (768-dimensional) by default. The dimension is
-//! detected automatically after the first successful embedding call.
+//! Uses `nomic-embed-text` (768-dimensional) by default. The embedding model is
+//! configured independently of the chat model; the health check reports it as
+//! missing if the local Ollama server does not have it yet.
+//!
+//! ## Dimensions
+//!
+//! Before the first call the dimension is taken from the config, or from a
+//! table of well-known embedding models. When the config pins a dimension
+//! (the one an existing index was built with), embeddings of any other size
+//! are rejected with `EmbeddingError::DimensionMismatch`; otherwise the
+//! dimension is detected from the first successful response.
 
 use async_trait::async_trait;
 use ollama_rs::generation::embeddings::request::{EmbeddingsInput, GenerateEmbeddingsRequest};
@@ -19,6 +28,7 @@ use super::embedding_provider::{
     EmbeddingError, EmbeddingProvider, EmbeddingProviderConfig, EmbeddingProviderType,
     EmbeddingResult,
 };
+use crate::services::llm::ollama::model_is_available;
 use crate::services::proxy::{build_http_client, ProxyConfig};
 
 /// Default Ollama API endpoint.
@@ -33,6 +43,27 @@ const DEFAULT_DIMENSION: usize = 768;
 /// Maximum batch size for Ollama embedding requests.
 const MAX_BATCH_SIZE: usize = 64;
 
+/// Output dimensions of common Ollama embedding models, keyed by base name.
+const KNOWN_MODEL_DIMENSIONS: &[(&str, usize)] = &[
+    ("nomic-embed-text", 768),
+    ("mxbai-embed-large", 1024),
+    ("all-minilm", 384),
+    ("snowflake-arctic-embed", 1024),
+    ("snowflake-arctic-embed2", 1024),
+    ("bge-m3", 1024),
+    ("bge-large", 1024),
+    ("granite-embedding", 384),
+];
+
+/// Known output dimension for an Ollama embedding model (tag ignored).
+pub fn known_model_dimension(model: &str) -> Option<usize> {
+    let base = model.split(':').next().unwrap_or(model).trim();
+    KNOWN_MODEL_DIMENSIONS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(base))
+        .map(|(_, dim)| *dim)
+}
+
 /// Ollama embedding provider using the native ollama-rs SDK.
 ///
 /// This provider runs entirely locally, requiring a running Ollama server
@@ -47,6 +78,9 @@ pub struct OllamaEmbeddingProvider {
     /// Uses AtomicUsize for lock-free interior mutability compatible with
     /// the `Send + Sync` requirement of the trait.
     dimension: AtomicUsize,
+    /// Dimension pinned by the config (e.g. that of an existing index).
+    /// Responses of any other size are rejected.
+    expected_dimension: Option<usize>,
     /// Human-readable display name for this provider instance.
     display_name: String,
     /// The base URL string (for error messages).
@@ -78,13 +112,17 @@ impl OllamaEmbeddingProvider {
 
         let client = Self::create_client(&base_url, config.proxy.as_ref());
 
-        let initial_dimension = config.dimension.unwrap_or(DEFAULT_DIMENSION);
+        let initial_dimension = config
+            .dimension
+            .or_else(|| known_model_dimension(&model))
+            .unwrap_or(DEFAULT_DIMENSION);
         let display_name = format!("Ollama ({})", model);
 
         Self {
             client,
             model,
             dimension: AtomicUsize::new(initial_dimension),
+            expected_dimension: config.dimension,
             display_name,
             base_url,
         }
@@ -138,12 +176,68 @@ impl OllamaEmbeddingProvider {
     }
 
     /// Update the stored dimension from a successful embedding response.
-    fn update_dimension(&self, embeddings: &[Vec<f32>]) {
+    ///
+    /// Fails if the config pins a dimension and the model produced another.
+    fn update_dimension(&self, embeddings: &[Vec<f32>]) -> EmbeddingResult<()> {
         if let Some(first) = embeddings.first() {
             if !first.is_empty() {
+                self.check_dimension(first.len())?;
                 self.dimension.store(first.len(), Ordering::Relaxed);
             }
         }
+        Ok(())
+    }
+
+    fn check_dimension(&self, actual: usize) -> EmbeddingResult<()> {
+        match self.expected_dimension {
+            Some(expected) if expected != actual => Err(EmbeddingError::DimensionMismatch {
+                model: self.model.clone(),
+                expected,
+                actual,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// The embedding model this provider requests.
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Make sure the configured model exists locally.
+    ///
+    /// Never pulls: a missing model is reported so the user can pull it
+    /// explicitly (`pull_ollama_model`), since embedding models can be large.
+    pub async fn ensure_model(&self) -> EmbeddingResult<()> {
+        let models = self.client.list_local_models().await.map_err(|e| {
+            let msg = e.to_string();
+            if msg.contains("connect") || msg.contains("Connection refused") {
+                EmbeddingError::ProviderUnavailable {
+                    message: format!(
+                        "Cannot connect to Ollama at {}. Is the Ollama server running? \
+                         Start it with: ollama serve",
+                        self.base_url
+                    ),
+                }
+            } else {
+                EmbeddingError::NetworkError { message: msg }
+            }
+        })?;
+
+        let available: Vec<String> = models.into_iter().map(|m| m.name).collect();
+        if model_is_available(&self.model, &available) {
+            return Ok(());
+        }
+
+        Err(EmbeddingError::ModelNotFound {
+            model: format!(
+                "'{}' is not available locally. Available models: [{}]. \
+                 Pull it with: ollama pull {}",
+                self.model,
+                available.join(", "),
+                self.model
+            ),
+        })
     }
 }
 
@@ -185,7 +279,7 @@ impl EmbeddingProvider for OllamaEmbeddingProvider {
         }
 
         // Update cached dimension from the first embedding
-        self.update_dimension(&response.embeddings);
+        self.update_dimension(&response.embeddings)?;
 
         Ok(response.embeddings)
     }
@@ -203,7 +297,7 @@ impl EmbeddingProvider for OllamaEmbeddingProvider {
             .map_err(|e| self.map_ollama_error(e))?;
 
         // Update cached dimension
-        self.update_dimension(&response.embeddings);
+        self.update_dimension(&response.embeddings)?;
 
         response
             .embeddings
@@ -219,43 +313,8 @@ impl EmbeddingProvider for OllamaEmbeddingProvider {
     }
 
     async fn health_check(&self) -> EmbeddingResult<()> {
-        // Step 1: Check if Ollama server is reachable by listing models
-        let models = self.client.list_local_models().await.map_err(|e| {
-            let msg = e.to_string();
-            if msg.contains("connect") || msg.contains("Connection refused") {
-                EmbeddingError::ProviderUnavailable {
-                    message: format!(
-                        "Cannot connect to Ollama at {}. Is the Ollama server running? \
-                             Start it with: ollama serve",
-                        self.base_url
-                    ),
-                }
-            } else {
-                EmbeddingError::NetworkError { message: msg }
-            }
-        })?;
-
-        // Step 2: Check if the configured model is available locally
-        let model_base = self.model.split(':').next().unwrap_or(&self.model);
-        let model_available = models.iter().any(|m| {
-            let local_base = m.name.split(':').next().unwrap_or(&m.name);
-            local_base == model_base || m.name == self.model
-        });
-
-        if !model_available {
-            let available_names: Vec<&str> = models.iter().map(|m| m.name.as_str()).collect();
-            return Err(EmbeddingError::ModelNotFound {
-                model: format!(
-                    "'{}' is not available locally. Available models: [{}]. \
-                     Pull it with: ollama pull {}",
-                    self.model,
-                    available_names.join(", "),
-                    self.model
-                ),
-            });
-        }
-
-        Ok(())
+        // Reachability check plus presence of the embedding model.
+        self.ensure_model().await
     }
 
     fn is_local(&self) -> bool {
@@ -409,18 +468,59 @@ mod tests {
 
         // Simulate receiving embeddings with different dimension
         let fake_embeddings = vec![vec![0.0f32; 384]];
-        provider.update_dimension(&fake_embeddings);
+        provider.update_dimension(&fake_embeddings).unwrap();
         assert_eq!(provider.dimension(), 384);
     }
 
     #[test]
     fn update_dimension_ignores_empty_embeddings() {
         let provider = OllamaEmbeddingProvider::new(&default_config());
-        provider.update_dimension(&[]);
+        provider.update_dimension(&[]).unwrap();
         assert_eq!(provider.dimension(), 768); // unchanged
 
-        provider.update_dimension(&[vec![]]);
+        provider.update_dimension(&[vec![]]).unwrap();
+        assert_eq!(provider.dimension(), 768); // unchanged
+    }
+
+    #[test]
+    fn configured_embedding_model_reports_known_dimension() {
+        let provider = OllamaEmbeddingProvider::new(&config_with_model("mxbai-embed-large:latest"));
+        assert_eq!(provider.model(), "mxbai-embed-large:latest");
+        assert_eq!(provider.dimension(), 1024);
+
+        let provider = OllamaEmbeddingProvider::new(&config_with_model("all-minilm"));
+        assert_eq!(provider.dimension(), 384);
+
+        // Unknown models fall back to the default until the first response.
+        let provider = OllamaEmbeddingProvider::new(&config_with_model("my-custom-embedder"));
+        assert_eq!(provider.dimension(), DEFAULT_DIMENSION);
+    }
+
+    #[test]
+    fn pinned_dimension_rejects_mismatched_embeddings() {
+        let config = EmbeddingProviderConfig {
+            model: "all-minilm".to_string(),
+            dimension: Some(768),
+            ..default_config()
+        };
+        let provider = OllamaEmbeddingProvider::new(&config);
+        assert_eq!(provider.dimension(), 768);
+
+        let err = provider.update_dimension(&[vec![0.0f32; 384]]).unwrap_err();
+        assert!(matches!(
+            err,
+            EmbeddingError::DimensionMismatch {
+                expected: 768,
+                actual: 384,
+                ..
+            }
+        ));
+        let msg = err.to_string();
+        assert!(msg.contains("all-minilm"));
+        assert!(msg.contains("re-index"));
         assert_eq!(provider.dimension(), 768); // unchanged
+
+        provider.update_dimension(&[vec![0.0f32; 768]]).unwrap();
     }
 
     // =========================================================================
@@ -11,6 +11,11 @@
 //! * **No external ML dependency** — pure Rust, zero ONNX overhead.
 //! * **Fixed-size vocabulary** — built from the first `embed_batch` call.
 //!   Subsequent calls reuse the same vocabulary for consistency.
+//! * **Incremental IDF** — the vocabulary keeps per-token document
+//!   frequencies, so documents added (or replaced) after the initial build
+//!   update the IDF weights without changing the token set. The vector
+//!   dimension therefore stays that of the persisted index; tokens first seen
+//!   after the build are ignored until the next full rebuild.
 //! * **Thread-safe** via `Arc<Mutex<...>>` so it can be shared across the
 //!   background indexer and tool executor.
 
@@ -42,6 +47,58 @@ struct Vocabulary {
     idf: Vec<f32>,
     /// Total number of documents the vocabulary was built from.
     num_docs: usize,
+    /// Document frequency for each token (same order as `idf`).
+    ///
+    /// Missing from vocabularies persisted before incremental updates; it is
+    /// derived from `idf` and `num_docs` on import.
+    #[serde(default)]
+    doc_freq: Vec<usize>,
+}
+
+impl Vocabulary {
+    /// Reconstruct document frequencies from IDF weights (`idf = ln(N/df) + 1`).
+    fn derive_doc_freq(&mut self) {
+        let n = self.num_docs.max(1) as f32;
+        self.doc_freq = self
+            .idf
+            .iter()
+            .map(|idf| {
+                let df = (n / (idf - 1.0).exp()).round() as usize;
+                df.clamp(1, self.num_docs.max(1))
+            })
+            .collect();
+    }
+
+    /// Replace the contribution of `removed` documents with `added` ones and
+    /// recompute every IDF weight from the updated counts.
+    fn apply_document_delta(&mut self, removed: &[&str], added: &[&str]) {
+        for doc in removed {
+            for idx in self.unique_token_indices(doc) {
+                self.doc_freq[idx] = self.doc_freq[idx].saturating_sub(1);
+            }
+        }
+        for doc in added {
+            for idx in self.unique_token_indices(doc) {
+                self.doc_freq[idx] += 1;
+            }
+        }
+        self.num_docs = (self.num_docs + added.len())
+            .saturating_sub(removed.len())
+            .max(1);
+
+        let n = self.num_docs;
+        for (idf, df) in self.idf.iter_mut().zip(&self.doc_freq) {
+            *idf = idf_weight(n, (*df).clamp(1, n));
+        }
+    }
+
+    /// Vocabulary indices of the distinct known tokens in `doc`.
+    fn unique_token_indices(&self, doc: &str) -> std::collections::HashSet<usize> {
+        tokenize(doc)
+            .iter()
+            .filter_map(|tok| self.token_to_idx.get(tok.as_str()).copied())
+            .collect()
+    }
 }
 
 /// Thread-safe TF-IDF embedding service.
@@ -117,6 +174,33 @@ impl EmbeddingService {
         texts.iter().map(|t| tfidf_vector(t, vocab)).collect()
     }
 
+    /// Fold a change in the corpus into the existing vocabulary.
+    ///
+    /// `removed` are the texts of documents that no longer exist (e.g. the
+    /// previous chunks of a modified file) and `added` the new ones. Only the
+    /// IDF weights change — the token set and dimension stay fixed so vectors
+    /// already stored with the index remain comparable.
+    ///
+    /// Returns `false` (and does nothing) if no vocabulary has been built.
+    pub fn update_documents(&self, removed: &[&str], added: &[&str]) -> bool {
+        let mut guard = self.inner.lock().unwrap();
+        match guard.vocab.as_mut() {
+            Some(vocab) => {
+                vocab.apply_document_delta(removed, added);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Number of documents the current vocabulary accounts for.
+    ///
+    /// Returns 0 if the vocabulary has not been built yet.
+    pub fn document_count(&self) -> usize {
+        let guard = self.inner.lock().unwrap();
+        guard.vocab.as_ref().map_or(0, |v| v.num_docs)
+    }
+
     /// Check whether the vocabulary has been initialised.
     pub fn is_ready(&self) -> bool {
         self.inner.lock().unwrap().vocab.is_some()
@@ -137,7 +221,7 @@ impl EmbeddingService {
     ///
     /// Validates that `token_to_idx.len() == idf.len()` before accepting.
     pub fn import_vocabulary(&self, json: &str) -> Result<(), String> {
-        let vocab: Vocabulary =
+        let mut vocab: Vocabulary =
             serde_json::from_str(json).map_err(|e| format!("invalid vocabulary JSON: {}", e))?;
         if vocab.token_to_idx.len() != vocab.idf.len() {
             return Err(format!(
@@ -146,6 +230,9 @@ impl EmbeddingService {
                 vocab.idf.len()
             ));
         }
+        if vocab.doc_freq.len() != vocab.idf.len() {
+            vocab.derive_doc_freq();
+        }
         let mut guard = self.inner.lock().unwrap();
         guard.vocab = Some(vocab);
         Ok(())
@@ -278,21 +365,27 @@ fn build_vocab(corpus: &[&str]) -> Vocabulary {
 
     let mut token_to_idx = HashMap::with_capacity(entries.len());
     let mut idf = Vec::with_capacity(entries.len());
+    let mut doc_freq = Vec::with_capacity(entries.len());
 
     for (idx, (token, freq)) in entries.into_iter().enumerate() {
         token_to_idx.insert(token, idx);
-        // Standard IDF formula: log(N / df) + 1 (the +1 prevents zero IDF).
-        let idf_val = ((num_docs as f32) / (freq as f32)).ln() + 1.0;
-        idf.push(idf_val);
+        idf.push(idf_weight(num_docs, freq));
+        doc_freq.push(freq);
     }
 
     Vocabulary {
         token_to_idx,
         idf,
         num_docs,
+        doc_freq,
     }
 }
 
+/// Standard IDF formula: log(N / df) + 1 (the +1 prevents zero IDF).
+fn idf_weight(num_docs: usize, doc_freq: usize) -> f32 {
+    ((num_docs as f32) / (doc_freq as f32)).ln() + 1.0
+}
+
 // ---------------------------------------------------------------------------
 // TF-IDF vector computation
 // ---------------------------------------------------------------------------
@@ -641,6 +734,103 @@ mod tests {
         }
     }
 
+    // =========================================================================
+    // Incremental vocabulary updates
+    // =========================================================================
+
+    fn idf_by_token(svc: &EmbeddingService) -> HashMap<String, f32> {
+        let guard = svc.inner.lock().unwrap();
+        let vocab = guard.vocab.as_ref().unwrap();
+        vocab
+            .token_to_idx
+            .iter()
+            .map(|(tok, &idx)| (tok.clone(), vocab.idf[idx]))
+            .collect()
+    }
+
+    #[test]
+    fn adding_document_matches_full_rebuild_idf() {
+        let base = [
+            "fn parse config file",
+            "struct config value",
+            "fn render view",
+        ];
+        let added = "fn parse view";
+
+        let incremental = EmbeddingService::new();
+        incremental.build_vocabulary(&base);
+        let dim = incremental.dimension();
+        assert!(incremental.update_documents(&[], &[added]));
+        assert_eq!(incremental.dimension(), dim, "token set must stay fixed");
+        assert_eq!(incremental.document_count(), 4);
+
+        let rebuilt = EmbeddingService::new();
+        rebuilt.build_vocabulary(&[base[0], base[1], base[2], added]);
+
+        let inc = idf_by_token(&incremental);
+        let full = idf_by_token(&rebuilt);
+        assert_eq!(inc.len(), full.len());
+        for (tok, idf) in &full {
+            assert!(
+                (inc[tok] - idf).abs() < 1e-6,
+                "idf for {} should match a full rebuild: {} vs {}",
+                tok,
+                inc[tok],
+                idf
+            );
+        }
+
+        // Replacing the added document with its original self is a no-op.
+        incremental.update_documents(&[added], &[added]);
+        assert_eq!(idf_by_token(&incremental), inc);
+    }
+
+    #[test]
+    fn query_uses_persisted_updated_vocabulary() {
+        let svc = EmbeddingService::new();
+        svc.build_vocabulary(&["fn parse config", "struct config", "fn render view"]);
+        svc.update_documents(&[], &["fn parse view"]);
+        let json = svc.export_vocabulary().unwrap();
+
+        // A restarted session only sees the persisted vocabulary.
+        let restored = EmbeddingService::new();
+        restored.import_vocabulary(&json).unwrap();
+        assert_eq!(restored.document_count(), 4);
+        assert_eq!(
+            restored.embed_text("parse the config"),
+            svc.embed_text("parse the config")
+        );
+
+        // Further updates continue from the persisted counts.
+        svc.update_documents(&[], &["struct view"]);
+        restored.update_documents(&[], &["struct view"]);
+        assert_eq!(idf_by_token(&restored), idf_by_token(&svc));
+    }
+
+    #[test]
+    fn legacy_vocabulary_without_doc_freq_supports_updates() {
+        let svc = EmbeddingService::new();
+        svc.build_vocabulary(&["fn parse config", "struct config", "fn render view"]);
+        let mut legacy: serde_json::Value =
+            serde_json::from_str(&svc.export_vocabulary().unwrap()).unwrap();
+        legacy.as_object_mut().unwrap().remove("doc_freq");
+
+        let restored = EmbeddingService::new();
+        restored.import_vocabulary(&legacy.to_string()).unwrap();
+        svc.update_documents(&[], &["fn view"]);
+        restored.update_documents(&[], &["fn view"]);
+        for (tok, idf) in idf_by_token(&svc) {
+            assert!((idf_by_token(&restored)[&tok] - idf).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn update_documents_without_vocabulary_is_noop() {
+        let svc = EmbeddingService::new();
+        assert!(!svc.update_documents(&[], &["fn main"]));
+        assert!(!svc.is_ready());
+    }
+
     #[test]
     fn import_rejects_malformed_json() {
         let svc = EmbeddingService::new();
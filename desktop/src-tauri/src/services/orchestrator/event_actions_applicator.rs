@@ -8,10 +8,16 @@
 //! 2. **checkpoint_request** — Create a Timeline checkpoint
 //! 3. **quality_gate_result** — Record gate result and emit to frontend
 //! 4. **transfer_to_agent** — Hand off execution to another agent
+//! 5. **batch** — Ordered actions, applied in declared order, all-or-nothing
 //!
 //! This ordering ensures that state is updated before checkpoints capture it,
 //! quality gate results are recorded before any transfer, and transfers happen
 //! last since they change the executing agent.
+//!
+//! Batched actions are staged: state changes go to a copy of the session
+//! state, frontend events are buffered, and checkpoints created by the batch
+//! are deleted again if a later action in the batch fails. Only a fully
+//! successful batch is committed.
 
 use std::collections::HashMap;
 
@@ -19,7 +25,7 @@ use serde_json::Value;
 use tokio::sync::mpsc;
 
 use crate::services::core::builders::SessionStateKey;
-use crate::services::core::event_actions::{EventActions, QualityGateActionResult};
+use crate::services::core::event_actions::{EventAction, EventActions, QualityGateActionResult};
 use crate::services::streaming::UnifiedStreamEvent;
 use crate::services::timeline::TimelineService;
 use crate::utils::error::{AppError, AppResult};
@@ -78,13 +84,7 @@ pub fn merge_state_delta(
 
     for key in keys {
         let value = &state_delta[key];
-        // Auto-prefix keys without a recognized scope
-        let effective_key =
-            if key.starts_with("user:") || key.starts_with("app:") || key.starts_with("temp:") {
-                key.clone()
-            } else {
-                format!("app:{}", key)
-            };
+        let effective_key = effective_state_key(key);
 
         match SessionStateKey::new(&effective_key) {
             Ok(_validated) => {
@@ -100,6 +100,15 @@ pub fn merge_state_delta(
     (merged, errors)
 }
 
+/// Auto-prefix keys without a recognized scope with `app:`.
+fn effective_state_key(key: &str) -> String {
+    if key.starts_with("user:") || key.starts_with("app:") || key.starts_with("temp:") {
+        key.to_string()
+    } else {
+        format!("app:{}", key)
+    }
+}
+
 // ============================================================================
 // Checkpoint Application
 // ============================================================================
@@ -200,46 +209,20 @@ pub async fn apply_actions(
                 tracked_files,
             ) {
                 Ok(cp_id) => {
-                    result.checkpoint_created = true;
-                    result.checkpoint_id = Some(cp_id.clone());
-
-                    // Store checkpoint ID in session state for subsequent actions
-                    session_state.insert(
-                        "app:last_checkpoint_id".to_string(),
-                        Value::String(cp_id.clone()),
-                    );
-
-                    // Store checkpoint label for context
-                    session_state.insert(
-                        "app:last_checkpoint_label".to_string(),
-                        Value::String(cp_req.label.clone()),
+                    // Store checkpoint ID/label/description in session state for
+                    // subsequent actions and emit the checkpoint event with full metadata
+                    let event = checkpoint_side_effects(
+                        session_state,
+                        &cp_id,
+                        &cp_req.label,
+                        cp_req.description.as_deref(),
+                        session_id,
+                        tracked_files.len(),
                     );
+                    let _ = tx.send(event).await;
 
-                    // Store description if provided
-                    if let Some(ref desc) = cp_req.description {
-                        session_state.insert(
-                            "app:last_checkpoint_description".to_string(),
-                            Value::String(desc.clone()),
-                        );
-                    }
-
-                    // Emit checkpoint event to frontend with full metadata
-                    let checkpoint_data = serde_json::json!({
-                        "action": "checkpoint_created",
-                        "checkpoint_id": cp_id,
-                        "label": cp_req.label,
-                        "description": cp_req.description,
-                        "session_id": session_id,
-                        "tracked_files_count": tracked_files.len(),
-                    });
-
-                    let _ = tx
-                        .send(UnifiedStreamEvent::ToolResult {
-                            tool_id: format!("checkpoint:{}", cp_req.label),
-                            result: Some(checkpoint_data.to_string()),
-                            error: None,
-                        })
-                        .await;
+                    result.checkpoint_created = true;
+                    result.checkpoint_id = Some(cp_id);
                 }
                 Err(e) => {
                     eprintln!(
@@ -268,9 +251,182 @@ pub async fn apply_actions(
         result.transfer_target = Some(target.clone());
     }
 
+    // Step 5: Apply the ordered batch (all-or-nothing)
+    if !actions.batch.is_empty() {
+        apply_action_batch(
+            &actions.batch,
+            session_state,
+            timeline,
+            project_path,
+            session_id,
+            tracked_files,
+            tx,
+            &mut result,
+        )
+        .await?;
+    }
+
     Ok(result)
 }
 
+/// Store checkpoint metadata in session state and build the frontend event.
+fn checkpoint_side_effects(
+    session_state: &mut HashMap<String, Value>,
+    cp_id: &str,
+    label: &str,
+    description: Option<&str>,
+    session_id: &str,
+    tracked_files_count: usize,
+) -> UnifiedStreamEvent {
+    session_state.insert(
+        "app:last_checkpoint_id".to_string(),
+        Value::String(cp_id.to_string()),
+    );
+    session_state.insert(
+        "app:last_checkpoint_label".to_string(),
+        Value::String(label.to_string()),
+    );
+    if let Some(desc) = description {
+        session_state.insert(
+            "app:last_checkpoint_description".to_string(),
+            Value::String(desc.to_string()),
+        );
+    }
+
+    let checkpoint_data = serde_json::json!({
+        "action": "checkpoint_created",
+        "checkpoint_id": cp_id,
+        "label": label,
+        "description": description,
+        "session_id": session_id,
+        "tracked_files_count": tracked_files_count,
+    });
+    UnifiedStreamEvent::ToolResult {
+        tool_id: format!("checkpoint:{}", label),
+        result: Some(checkpoint_data.to_string()),
+        error: None,
+    }
+}
+
+/// Apply an ordered batch of actions all-or-nothing.
+///
+/// Actions run strictly in declared order against a staged copy of the
+/// session state. If an action fails, checkpoints created earlier in the
+/// batch are deleted, the staged state and buffered events are discarded,
+/// and an error naming the failing action is returned. On success the
+/// staged state replaces `session_state`, buffered events are emitted in
+/// order, and `result` is updated.
+#[allow(clippy::too_many_arguments)]
+pub async fn apply_action_batch(
+    batch: &[EventAction],
+    session_state: &mut HashMap<String, Value>,
+    timeline: Option<&TimelineService>,
+    project_path: &str,
+    session_id: &str,
+    tracked_files: &[String],
+    tx: &mpsc::Sender<UnifiedStreamEvent>,
+    result: &mut ApplyActionsResult,
+) -> AppResult<()> {
+    let mut staged = session_state.clone();
+    let mut events = Vec::new();
+    let mut created_checkpoints: Vec<String> = Vec::new();
+    let mut state_entries = 0usize;
+    let mut gate_recorded = false;
+    let mut transfer_target: Option<String> = None;
+
+    for (index, action) in batch.iter().enumerate() {
+        let outcome: AppResult<()> = match action {
+            EventAction::SetState { key, value } => {
+                let effective_key = effective_state_key(key);
+                SessionStateKey::new(&effective_key)
+                    .map(|_| {
+                        staged.insert(effective_key, value.clone());
+                        state_entries += 1;
+                    })
+                    .map_err(|e| {
+                        AppError::validation(format!("invalid state key '{}': {}", key, e))
+                    })
+            }
+            EventAction::Checkpoint(cp_req) => match timeline {
+                None => Err(AppError::validation(format!(
+                    "checkpoint '{}' requested but no TimelineService available",
+                    cp_req.label
+                ))),
+                Some(timeline_svc) => apply_checkpoint(
+                    timeline_svc,
+                    project_path,
+                    session_id,
+                    &cp_req.label,
+                    tracked_files,
+                )
+                .map(|cp_id| {
+                    events.push(checkpoint_side_effects(
+                        &mut staged,
+                        &cp_id,
+                        &cp_req.label,
+                        cp_req.description.as_deref(),
+                        session_id,
+                        tracked_files.len(),
+                    ));
+                    created_checkpoints.push(cp_id);
+                }),
+            },
+            EventAction::QualityGate(gate) => {
+                events.push(build_quality_gate_event(session_id, gate));
+                gate_recorded = true;
+                Ok(())
+            }
+            EventAction::Transfer { agent } => {
+                if agent.trim().is_empty() {
+                    Err(AppError::validation("transfer target cannot be empty"))
+                } else {
+                    transfer_target = Some(agent.clone());
+                    Ok(())
+                }
+            }
+        };
+
+        if let Err(e) = outcome {
+            if let Some(timeline_svc) = timeline {
+                // Delete newest first so parent checkpoints have no children left
+                for cp_id in created_checkpoints.iter().rev() {
+                    if let Err(del_err) =
+                        timeline_svc.delete_checkpoint(project_path, session_id, cp_id)
+                    {
+                        eprintln!(
+                            "[event-actions] Failed to roll back checkpoint '{}': {}",
+                            cp_id, del_err
+                        );
+                    }
+                }
+            }
+            return Err(AppError::validation(format!(
+                "Event action batch failed at action {} ({}): {}; rolled back {} prior action(s)",
+                index + 1,
+                action.kind(),
+                e,
+                index
+            )));
+        }
+    }
+
+    *session_state = staged;
+    for event in events {
+        let _ = tx.send(event).await;
+    }
+
+    result.state_entries_merged += state_entries;
+    if let Some(cp_id) = created_checkpoints.pop() {
+        result.checkpoint_created = true;
+        result.checkpoint_id = Some(cp_id);
+    }
+    result.quality_gate_recorded |= gate_recorded;
+    if transfer_target.is_some() {
+        result.transfer_target = transfer_target;
+    }
+    Ok(())
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -793,4 +949,156 @@ mod tests {
         drop(tx);
         assert!(rx.try_recv().is_err());
     }
+
+    // ── Batched actions (ordered, all-or-nothing) ───────────────────
+
+    fn temp_project(tag: &str) -> (std::path::PathBuf, String) {
+        let temp_dir = std::env::temp_dir().join(format!("{}_{}", tag, uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let project_path = temp_dir.to_string_lossy().to_string();
+        (temp_dir, project_path)
+    }
+
+    fn set_state(key: &str, value: Value) -> EventAction {
+        EventAction::SetState {
+            key: key.to_string(),
+            value,
+        }
+    }
+
+    fn checkpoint(label: &str) -> EventAction {
+        EventAction::Checkpoint(crate::services::core::event_actions::CheckpointRequest {
+            label: label.to_string(),
+            description: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_apply_batch_applies_in_declared_order() {
+        let actions = EventActions::none()
+            .then(set_state("app:step", Value::from(1)))
+            .then(checkpoint("first"))
+            .then(set_state("app:step", Value::from(2)))
+            .then(EventAction::QualityGate(QualityGateActionResult {
+                gate_name: "lint".to_string(),
+                passed: true,
+                details: None,
+            }))
+            .then(checkpoint("second"))
+            .then(EventAction::Transfer {
+                agent: "reviewer".to_string(),
+            });
+
+        let timeline = TimelineService::new();
+        let (temp_dir, project_path) = temp_project("ea_batch_order");
+        let mut state = HashMap::new();
+        let (tx, mut rx) = mpsc::channel(16);
+
+        let result = apply_actions(
+            &actions,
+            &mut state,
+            Some(&timeline),
+            &project_path,
+            "sess-batch",
+            &[],
+            &tx,
+        )
+        .await
+        .unwrap();
+
+        // Later writes win, and the last checkpoint is the one reported
+        assert_eq!(state["app:step"], Value::from(2));
+        assert_eq!(result.state_entries_merged, 2);
+        assert!(result.quality_gate_recorded);
+        assert_eq!(result.transfer_target.as_deref(), Some("reviewer"));
+        assert_eq!(
+            state["app:last_checkpoint_label"],
+            Value::String("second".to_string())
+        );
+        assert_eq!(
+            state["app:last_checkpoint_id"],
+            Value::String(result.checkpoint_id.clone().unwrap())
+        );
+
+        // Events are emitted in declared order
+        drop(tx);
+        let mut order = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            match event {
+                UnifiedStreamEvent::ToolResult { tool_id, .. } => order.push(tool_id),
+                UnifiedStreamEvent::QualityGatesResult { story_id, .. } => order.push(story_id),
+                other => panic!("Unexpected event {:?}", other),
+            }
+        }
+        assert_eq!(
+            order,
+            vec!["checkpoint:first", "action:lint", "checkpoint:second"]
+        );
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_apply_batch_mid_failure_rolls_back_prior_actions() {
+        let actions = EventActions::none()
+            .then(set_state("app:step", Value::from(1)))
+            .then(checkpoint("before-failure"))
+            .then(set_state("app:", Value::Null))
+            .then(set_state("app:never", Value::Bool(true)));
+
+        let timeline = TimelineService::new();
+        let (temp_dir, project_path) = temp_project("ea_batch_rollback");
+        let mut state = HashMap::new();
+        state.insert("app:step".to_string(), Value::from(0));
+        let (tx, mut rx) = mpsc::channel(16);
+
+        let err = apply_actions(
+            &actions,
+            &mut state,
+            Some(&timeline),
+            &project_path,
+            "sess-rollback",
+            &[],
+            &tx,
+        )
+        .await
+        .unwrap_err();
+
+        let msg = err.to_string();
+        assert!(msg.contains("action 3 (set_state)"), "{}", msg);
+        assert!(msg.contains("rolled back 2 prior action(s)"), "{}", msg);
+
+        // Session state is untouched
+        assert_eq!(state.len(), 1);
+        assert_eq!(state["app:step"], Value::from(0));
+        assert!(!state.contains_key("app:last_checkpoint_id"));
+
+        // The checkpoint created earlier in the batch was deleted
+        let metadata_checkpoints = timeline
+            .list_checkpoints(&project_path, "sess-rollback", None)
+            .unwrap_or_default();
+        assert!(metadata_checkpoints.is_empty());
+
+        // No buffered events leaked to the frontend
+        drop(tx);
+        assert!(rx.try_recv().is_err());
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
+    #[tokio::test]
+    async fn test_apply_batch_checkpoint_without_timeline_fails_batch() {
+        let actions = EventActions::none()
+            .with_state("app:legacy", Value::Bool(true))
+            .then(set_state("app:batched", Value::Bool(true)))
+            .then(checkpoint("needs-timeline"));
+        let mut state = HashMap::new();
+        let (tx, _rx) = mpsc::channel(16);
+
+        let result = apply_actions(&actions, &mut state, None, "/tmp", "sess", &[], &tx).await;
+        assert!(result.is_err());
+        // Single-slot fields keep their lenient semantics; the batch is discarded
+        assert_eq!(state.get("app:legacy"), Some(&Value::Bool(true)));
+        assert!(!state.contains_key("app:batched"));
+    }
 }
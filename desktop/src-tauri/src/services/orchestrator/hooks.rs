@@ -17,8 +17,9 @@
 //! 4. `on_after_llm`      - Post-LLM response (response analysis)
 //! 5. `on_before_tool`    - Pre-tool execution (permission checks, skip decisions)
 //! 6. `on_after_tool`     - Post-tool execution (result tracking)
-//! 7. `on_session_end`    - Session teardown (memory extraction)
-//! 8. `on_compaction`     - Context compaction (memory extraction from compacted content)
+//! 7. `on_tool_result`    - Post-tool result transform (redaction, summarization, annotation)
+//! 8. `on_session_end`    - Session teardown (memory extraction)
+//! 9. `on_compaction`     - Context compaction (memory extraction from compacted content)
 
 use regex::Regex;
 use serde_json::Value;
@@ -30,6 +31,7 @@ use std::sync::Arc;
 use tokio::process::Command;
 use tokio::sync::RwLock;
 
+use crate::services::memory::extraction::{run_session_extraction, MemoryExtractor};
 use crate::services::memory::query_policy_v2::{memory_query_tuning_v2, MemoryQueryPresetV2};
 use crate::services::memory::query_v2::{
     list_memory_entries_v2 as list_memory_entries_unified_v2,
@@ -37,11 +39,20 @@ use crate::services::memory::query_v2::{
     UnifiedMemoryQueryRequestV2,
 };
 use crate::services::memory::store::ProjectMemoryStore;
-use crate::services::memory::store::{MemoryCategory, NewMemoryEntry};
-use crate::services::skills::generator::SkillGeneratorStore;
+use crate::services::memory::store::{MemoryCategory, NewMemoryEntry, UpsertResult};
+use crate::services::skills::config::load_skills_config;
+use crate::services::skills::discovery::discover_all_skills;
+use crate::services::skills::generator::{
+    count_user_corrections, find_near_duplicate, SessionSuccessSignal, SkillGeneratorStore,
+    DEFAULT_DUPLICATE_SIMILARITY,
+};
+use crate::services::skills::index::build_index;
 use crate::services::skills::model::GeneratedSkill;
-use crate::services::skills::model::{InjectionPhase, SelectionPolicy, SkillIndex, SkillMatch};
+use crate::services::skills::model::{
+    InjectionPhase, SelectionPolicy, SkillDocument, SkillIndex, SkillMatch,
+};
 use crate::services::skills::select::select_skills_for_session;
+use crate::services::tools::executor::ToolResult;
 use crate::utils::configure_background_process;
 
 /// Context provided to all hooks, describing the current session state.
@@ -59,6 +70,65 @@ pub struct HookContext {
     pub execution_id: Option<String>,
     /// Structured task/content type if known (e.g. "prd").
     pub task_type: Option<String>,
+    /// Read-only snapshot of what has happened so far in the session.
+    ///
+    /// Hooks receive a clone of the context, so changes made by a hook are
+    /// not visible to the loop or to later hooks.
+    pub history: HookSessionSnapshot,
+}
+
+/// One tool invocation recorded in [`HookSessionSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookToolCall {
+    /// Name of the tool that was invoked
+    pub tool_name: String,
+    /// Whether the tool reported success
+    pub success: bool,
+}
+
+/// Session progress visible to hooks: loop iteration and tool-call history.
+#[derive(Debug, Clone, Default)]
+pub struct HookSessionSnapshot {
+    /// Current agentic loop iteration (0 before the first LLM call)
+    pub iteration: u32,
+    /// Tool calls completed so far, in execution order
+    pub tool_calls: Vec<HookToolCall>,
+}
+
+impl HookSessionSnapshot {
+    /// Append a completed tool call to the history.
+    pub fn record_tool_call(&mut self, tool_name: impl Into<String>, success: bool) {
+        self.tool_calls.push(HookToolCall {
+            tool_name: tool_name.into(),
+            success,
+        });
+    }
+
+    /// Total number of tool calls completed so far.
+    pub fn total_tool_calls(&self) -> usize {
+        self.tool_calls.len()
+    }
+
+    /// Number of completed calls to the given tool.
+    pub fn tool_call_count(&self, tool_name: &str) -> usize {
+        self.tool_calls
+            .iter()
+            .filter(|call| call.tool_name == tool_name)
+            .count()
+    }
+
+    /// Number of failed calls to the given tool.
+    pub fn failed_call_count(&self, tool_name: &str) -> usize {
+        self.tool_calls
+            .iter()
+            .filter(|call| call.tool_name == tool_name && !call.success)
+            .count()
+    }
+
+    /// The most recently completed tool call, if any.
+    pub fn last_tool_call(&self) -> Option<&HookToolCall> {
+        self.tool_calls.last()
+    }
 }
 
 /// Summary of a completed session, provided to on_session_end hooks.
@@ -201,6 +271,19 @@ pub type OnAfterToolHook = Box<
         + Sync,
 >;
 
+/// Hook fired after `on_after_tool` with the full tool result, before it is
+/// re-injected into the conversation. Returns the (possibly modified) result,
+/// which is passed to the next hook in the chain.
+pub type OnToolResultHook = Box<
+    dyn Fn(
+            HookContext,
+            String,
+            ToolResult,
+        ) -> Pin<Box<dyn Future<Output = Result<ToolResult, String>> + Send>>
+        + Send
+        + Sync,
+>;
+
 /// Hook fired at session end. Receives the session summary.
 pub type OnSessionEndHook = Box<
     dyn Fn(HookContext, SessionSummary) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>>
@@ -231,6 +314,7 @@ pub struct AgenticHooks {
     on_after_llm: Vec<OnAfterLlmHook>,
     on_before_tool: Vec<OnBeforeToolHook>,
     on_after_tool: Vec<OnAfterToolHook>,
+    on_tool_result: Vec<OnToolResultHook>,
     on_session_end: Vec<OnSessionEndHook>,
     on_compaction: Vec<OnCompactionHook>,
     requested_stop: Arc<RwLock<Option<String>>>,
@@ -245,6 +329,7 @@ impl std::fmt::Debug for AgenticHooks {
             .field("on_after_llm", &self.on_after_llm.len())
             .field("on_before_tool", &self.on_before_tool.len())
             .field("on_after_tool", &self.on_after_tool.len())
+            .field("on_tool_result", &self.on_tool_result.len())
             .field("on_session_end", &self.on_session_end.len())
             .field("on_compaction", &self.on_compaction.len())
             .finish()
@@ -261,6 +346,7 @@ impl AgenticHooks {
             on_after_llm: Vec::new(),
             on_before_tool: Vec::new(),
             on_after_tool: Vec::new(),
+            on_tool_result: Vec::new(),
             on_session_end: Vec::new(),
             on_compaction: Vec::new(),
             requested_stop: Arc::new(RwLock::new(None)),
@@ -275,6 +361,7 @@ impl AgenticHooks {
             && self.on_after_llm.is_empty()
             && self.on_before_tool.is_empty()
             && self.on_after_tool.is_empty()
+            && self.on_tool_result.is_empty()
             && self.on_session_end.is_empty()
             && self.on_compaction.is_empty()
     }
@@ -287,6 +374,7 @@ impl AgenticHooks {
             + self.on_after_llm.len()
             + self.on_before_tool.len()
             + self.on_after_tool.len()
+            + self.on_tool_result.len()
             + self.on_session_end.len()
             + self.on_compaction.len()
     }
@@ -325,6 +413,11 @@ impl AgenticHooks {
         self.on_after_tool.push(hook);
     }
 
+    /// Register a hook to transform tool results before re-injection.
+    pub fn register_on_tool_result(&mut self, hook: OnToolResultHook) {
+        self.on_tool_result.push(hook);
+    }
+
     /// Register a hook to fire at session end.
     pub fn register_on_session_end(&mut self, hook: OnSessionEndHook) {
         self.on_session_end.push(hook);
@@ -514,6 +607,29 @@ impl AgenticHooks {
         aggregate
     }
 
+    /// Fire all on_tool_result hooks sequentially, threading the result
+    /// through each hook in registration order.
+    ///
+    /// A failing hook is logged and skipped; the result it received is passed
+    /// on unchanged.
+    pub async fn fire_on_tool_result(
+        &self,
+        ctx: &HookContext,
+        tool_name: &str,
+        result: ToolResult,
+    ) -> ToolResult {
+        let mut current = result;
+        for (i, hook) in self.on_tool_result.iter().enumerate() {
+            match hook(ctx.clone(), tool_name.to_string(), current.clone()).await {
+                Ok(transformed) => current = transformed,
+                Err(e) => {
+                    tracing::info!("[hooks] on_tool_result hook {} failed: {}", i, e);
+                }
+            }
+        }
+        current
+    }
+
     /// Fire all on_session_end hooks sequentially.
     pub async fn fire_on_session_end(&self, ctx: &HookContext, summary: SessionSummary) {
         for (i, hook) in self.on_session_end.iter().enumerate() {
@@ -1009,6 +1125,8 @@ pub struct MemoryHookConfig {
     pub selected_memory_ids: Vec<String>,
     /// Explicit denylist of memory ids to exclude.
     pub excluded_memory_ids: Vec<String>,
+    /// Opt-in session-summary memory hook; requires an LLM provider.
+    pub session_summary: Option<SessionSummaryMemoryConfig>,
 }
 
 impl Default for MemoryHookConfig {
@@ -1026,6 +1144,7 @@ impl Default for MemoryHookConfig {
             selected_categories: vec![],
             selected_memory_ids: vec![],
             excluded_memory_ids: vec![],
+            session_summary: None,
         }
     }
 }
@@ -1151,6 +1270,12 @@ pub fn register_memory_hooks_with_config(
                 .map(|provider| provider.config().clone())
         });
     let extraction_review_base_url = memory_hook_config.review_base_url.clone();
+    if let (Some(summary_config), Some(provider)) = (
+        memory_hook_config.session_summary.clone(),
+        llm_provider.clone(),
+    ) {
+        register_session_summary_memory_hook(hooks, memory_store.clone(), provider, summary_config);
+    }
     let selected_ids: HashSet<String> =
         memory_hook_config.selected_memory_ids.into_iter().collect();
     let excluded_ids: HashSet<String> =
@@ -1376,6 +1501,125 @@ pub fn register_memory_hooks_with_config(
     }));
 }
 
+/// Bounds for the opt-in session-summary memory hook.
+#[derive(Debug, Clone)]
+pub struct SessionSummaryMemoryConfig {
+    /// Sessions with fewer agentic turns are treated as trivial and skipped.
+    pub min_turns: u32,
+    /// Sessions with less conversation text are treated as trivial and skipped.
+    pub min_content_chars: usize,
+    /// Conversation text sent to the LLM is truncated to this many chars.
+    /// Kept at or below `MemoryExtractor::SUMMARIZE_THRESHOLD` so extraction
+    /// costs a single LLM call.
+    pub max_conversation_chars: usize,
+    /// Maximum number of memories written per session.
+    pub max_memories: usize,
+}
+
+impl Default for SessionSummaryMemoryConfig {
+    fn default() -> Self {
+        Self {
+            min_turns: 3,
+            min_content_chars: 200,
+            max_conversation_chars: MemoryExtractor::SUMMARIZE_THRESHOLD,
+            max_memories: 5,
+        }
+    }
+}
+
+/// Register an opt-in `on_session_end` hook that distills the session into
+/// key learnings via `MemoryExtractor` and writes them to the project memory
+/// store, deduplicated against existing memories.
+///
+/// Unsuccessful and trivial sessions are skipped without an LLM call.
+pub fn register_session_summary_memory_hook(
+    hooks: &mut AgenticHooks,
+    memory_store: Arc<ProjectMemoryStore>,
+    llm_provider: Arc<dyn crate::services::llm::provider::LlmProvider>,
+    config: SessionSummaryMemoryConfig,
+) {
+    hooks.register_on_session_end(Box::new(move |ctx, summary| {
+        let store = memory_store.clone();
+        let provider = llm_provider.clone();
+        let config = config.clone();
+        Box::pin(async move {
+            if !summary.success
+                || summary.total_turns < config.min_turns
+                || summary.conversation_content.trim().len() < config.min_content_chars
+            {
+                tracing::info!(
+                    "[hooks] Session summary memory skipped (trivial session): session={}, turns={}, content_len={}",
+                    ctx.session_id,
+                    summary.total_turns,
+                    summary.conversation_content.len(),
+                );
+                return Ok(());
+            }
+
+            let project_path = ctx.project_path.to_string_lossy().to_string();
+            let existing = store
+                .list_memories(&project_path, None, 0, 200)
+                .unwrap_or_default();
+            let conversation: String = summary
+                .conversation_content
+                .chars()
+                .take(config.max_conversation_chars)
+                .collect();
+
+            let mut entries = run_session_extraction(
+                provider.as_ref(),
+                &project_path,
+                &summary.task_description,
+                &summary.files_read,
+                &summary.key_findings,
+                &conversation,
+                Some(&ctx.session_id),
+                &existing,
+                None,
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+
+            let known: HashSet<String> = existing
+                .iter()
+                .map(|m| m.content.trim().to_lowercase())
+                .collect();
+            entries.retain(|entry| !known.contains(&entry.content.trim().to_lowercase()));
+            entries.sort_by(|a, b| {
+                b.importance
+                    .partial_cmp(&a.importance)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            entries.truncate(config.max_memories);
+
+            let mut written = 0usize;
+            for mut entry in entries {
+                entry.source_context = Some(format!(
+                    "session_summary_hook; {}",
+                    entry.source_context.unwrap_or_default()
+                ));
+                match store.upsert_memory(entry) {
+                    Ok(UpsertResult::Inserted(_)) | Ok(UpsertResult::Merged { .. }) => {
+                        written += 1
+                    }
+                    Ok(UpsertResult::Skipped { .. }) => {}
+                    Err(e) => tracing::info!(
+                        "[hooks] Session summary memory write failed: session={}, error={}",
+                        ctx.session_id,
+                        e,
+                    ),
+                }
+            }
+            tracing::info!(
+                "[hooks] Session summary memory: session={}, written={}",
+                ctx.session_id,
+                written,
+            );
+            Ok(())
+        })
+    }));
+}
+
 /// Rule-based fallback memory extraction from key_findings.
 /// Used when no LLM provider is available or when LLM extraction fails.
 fn rule_based_memory_extraction(
@@ -1440,15 +1684,20 @@ fn rule_based_memory_extraction(
 
 /// P1: Optionally auto-generate a reusable skill from a successful, non-trivial session.
 ///
-/// Generation criteria:
+/// Generation criteria (see `SessionSuccessSignal`):
 /// - Session must be successful
 /// - Session must include at least 3 tool calls
+/// - No user turn may correct or reject the assistant's work
 /// - Conversation content should be substantial
 /// - LLM provider must be available
 ///
 /// Duplicate prevention:
 /// - Reject if name collides (case-insensitive)
 /// - Reject if token Jaccard similarity with existing generated skills >= 0.80
+/// - Reject if TF-IDF cosine similarity with any indexed skill (file-based or
+///   generated) >= `DEFAULT_DUPLICATE_SIMILARITY`
+///
+/// Saved skills land in `pending_review` and are not injected until approved.
 async fn maybe_generate_skill_from_session(
     provider: Option<Arc<dyn crate::services::llm::provider::LlmProvider>>,
     memory_store: &ProjectMemoryStore,
@@ -1456,8 +1705,15 @@ async fn maybe_generate_skill_from_session(
     summary: &SessionSummary,
     project_path: &str,
 ) {
-    let total_tool_calls: usize = summary.tool_usage.values().sum();
-    if !summary.success || total_tool_calls < 3 || summary.conversation_content.len() < 160 {
+    let signal = session_success_signal(summary);
+    if !signal.is_sufficient() {
+        tracing::info!(
+            "[hooks] Skill generation skipped (weak success signal): session={}, success={}, tool_calls={}, user_corrections={}",
+            ctx.session_id,
+            signal.success,
+            signal.tool_calls,
+            signal.user_corrections
+        );
         return;
     }
 
@@ -1584,6 +1840,29 @@ async fn maybe_generate_skill_from_session(
         return;
     }
 
+    let indexed = load_file_skill_documents(&ctx.project_path);
+    let near_duplicate = find_near_duplicate(
+        &candidate,
+        indexed
+            .iter()
+            .map(|d| (d.name.as_str(), d.description.as_str(), d.body.as_str()))
+            .chain(
+                existing
+                    .iter()
+                    .map(|r| (r.name.as_str(), r.description.as_str(), r.body.as_str())),
+            ),
+        DEFAULT_DUPLICATE_SIMILARITY,
+    );
+    if let Some((similar_to, similarity)) = near_duplicate {
+        tracing::info!(
+            "[hooks] Skill generation deduped: session={}, candidate={}, similar_to={}, similarity={:.2}",
+            ctx.session_id,
+            candidate.name,
+            similar_to,
+            similarity
+        );
+        return;
+    }
     match skill_store.save_generated_skill(project_path, &candidate) {
         Ok(saved) => {
             tracing::info!(
@@ -1603,6 +1882,26 @@ async fn maybe_generate_skill_from_session(
     }
 }
 
+/// Derive the skill-generation success signal from a session summary.
+fn session_success_signal(summary: &SessionSummary) -> SessionSuccessSignal {
+    SessionSuccessSignal {
+        success: summary.success,
+        tool_calls: summary.tool_usage.values().sum(),
+        user_corrections: count_user_corrections(&summary.conversation_content),
+        conversation_len: summary.conversation_content.len(),
+    }
+}
+
+/// Load file-based skills (builtin, external, user, project) for dedup checks.
+fn load_file_skill_documents(project_root: &std::path::Path) -> Vec<SkillDocument> {
+    let config = load_skills_config(&project_root.join("external-skills.json")).unwrap_or_default();
+    let plan_cascade_dir = crate::utils::paths::plan_cascade_dir().ok();
+    discover_all_skills(project_root, &config, plan_cascade_dir.as_deref())
+        .and_then(build_index)
+        .map(|index| index.skills().to_vec())
+        .unwrap_or_default()
+}
+
 fn parse_generated_skill_response(response_text: &str, session_id: &str) -> Option<GeneratedSkill> {
     let json_str = extract_first_json_object(response_text)?;
     let parsed: serde_json::Value = serde_json::from_str(&json_str).ok()?;
@@ -1728,6 +2027,9 @@ mod tests {
             project_path: PathBuf::from("/tmp/test-project"),
             provider_name: "anthropic".to_string(),
             model_name: "claude-3-5-sonnet".to_string(),
+            execution_id: None,
+            task_type: None,
+            history: HookSessionSnapshot::default(),
         }
     }
 
@@ -1951,6 +2253,54 @@ mod tests {
         assert_eq!(result.skip_reason.unwrap(), "Bash disabled by policy");
     }
 
+    #[tokio::test]
+    async fn test_before_tool_hook_branches_on_session_history() {
+        let mut hooks = AgenticHooks::new();
+        hooks.register_on_before_tool(Box::new(|ctx, tool_name, _args| {
+            Box::pin(async move {
+                if tool_name == "Write" && ctx.history.failed_call_count("Edit") >= 3 {
+                    return Ok(BeforeToolResult {
+                        skip: true,
+                        skip_reason: Some(format!(
+                            "Write blocked after {} prior tool calls with repeated Edit failures",
+                            ctx.history.total_tool_calls()
+                        )),
+                        modified_arguments: None,
+                    });
+                }
+                Ok(BeforeToolResult::default())
+            })
+        }));
+
+        let mut ctx = test_context();
+        assert!(hooks
+            .fire_on_before_tool(&ctx, "Write", "{}")
+            .await
+            .is_none());
+
+        ctx.history.iteration = 4;
+        ctx.history.record_tool_call("Read", true);
+        for _ in 0..3 {
+            ctx.history.record_tool_call("Edit", false);
+        }
+        assert_eq!(ctx.history.total_tool_calls(), 4);
+        assert_eq!(ctx.history.tool_call_count("Edit"), 3);
+
+        let result = hooks
+            .fire_on_before_tool(&ctx, "Write", "{}")
+            .await
+            .expect("Write should be skipped");
+        assert!(result.skip);
+        assert_eq!(
+            result.skip_reason.unwrap(),
+            "Write blocked after 4 prior tool calls with repeated Edit failures"
+        );
+        assert!(hooks
+            .fire_on_before_tool(&ctx, "Read", "{}")
+            .await
+            .is_none());
+    }
+
     #[tokio::test]
     async fn test_fire_on_after_tool() {
         let counter = Arc::new(AtomicU32::new(0));
@@ -1972,6 +2322,60 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn test_fire_on_tool_result_truncates_large_output() {
+        let mut hooks = AgenticHooks::new();
+        hooks.register_on_tool_result(Box::new(|_ctx, _name, mut result| {
+            Box::pin(async move {
+                if let Some(output) = result.success_message_owned() {
+                    if output.len() > 16 {
+                        result.set_message(format!("{}... [truncated]", &output[..16]));
+                    }
+                }
+                Ok(result)
+            })
+        }));
+
+        let ctx = test_context();
+        let result = hooks
+            .fire_on_tool_result(&ctx, "Read", ToolResult::ok("x".repeat(1000)))
+            .await;
+        assert!(result.is_success());
+        assert_eq!(
+            result.success_message(),
+            Some("xxxxxxxxxxxxxxxx... [truncated]")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fire_on_tool_result_applies_hooks_in_order() {
+        let mut hooks = AgenticHooks::new();
+        hooks.register_on_tool_result(Box::new(|_ctx, _name, mut result| {
+            Box::pin(async move {
+                let output = result.success_message_owned().unwrap_or_default();
+                result.set_message(format!("{}[first]", output));
+                Ok(result)
+            })
+        }));
+        hooks.register_on_tool_result(Box::new(|_ctx, _name, _result| {
+            Box::pin(async move { Err("transform failed".to_string()) })
+        }));
+        hooks.register_on_tool_result(Box::new(|_ctx, tool_name, mut result| {
+            Box::pin(async move {
+                let output = result.success_message_owned().unwrap_or_default();
+                result.set_message(format!("{}[second:{}]", output, tool_name));
+                Ok(result)
+            })
+        }));
+
+        let ctx = test_context();
+        let result = hooks
+            .fire_on_tool_result(&ctx, "Grep", ToolResult::ok("out"))
+            .await;
+        assert_eq!(result.success_message(), Some("out[first][second:Grep]"));
+        assert_eq!(hooks.total_hooks(), 3);
+    }
+
     #[tokio::test]
     async fn test_fire_on_before_llm() {
         let iteration_seen = Arc::new(AtomicU32::new(0));
@@ -2315,6 +2719,150 @@ mod tests {
         );
     }
 
+    struct ExtractionMockProvider {
+        response: String,
+        calls: Arc<AtomicU32>,
+        config: crate::services::llm::types::ProviderConfig,
+    }
+
+    #[async_trait::async_trait]
+    impl crate::services::llm::provider::LlmProvider for ExtractionMockProvider {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+        fn model(&self) -> &str {
+            &self.config.model
+        }
+        fn supports_thinking(&self) -> bool {
+            false
+        }
+        fn supports_tools(&self) -> bool {
+            false
+        }
+        async fn send_message(
+            &self,
+            _messages: Vec<crate::services::llm::types::Message>,
+            _system: Option<String>,
+            _tools: Vec<crate::services::llm::types::ToolDefinition>,
+            _request_options: crate::services::llm::types::LlmRequestOptions,
+        ) -> crate::services::llm::types::LlmResult<crate::services::llm::types::LlmResponse>
+        {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(crate::services::llm::types::LlmResponse {
+                model: self.config.model.clone(),
+                content: Some(self.response.clone()),
+                thinking: None,
+                usage: crate::services::llm::types::UsageStats {
+                    input_tokens: 0,
+                    output_tokens: 0,
+                    thinking_tokens: None,
+                    cache_read_tokens: None,
+                    cache_creation_tokens: None,
+                },
+                stop_reason: crate::services::llm::types::StopReason::EndTurn,
+                tool_calls: vec![],
+                search_citations: vec![],
+            })
+        }
+        async fn stream_message(
+            &self,
+            _messages: Vec<crate::services::llm::types::Message>,
+            _system: Option<String>,
+            _tools: Vec<crate::services::llm::types::ToolDefinition>,
+            _tx: tokio::sync::mpsc::Sender<plan_cascade_core::streaming::UnifiedStreamEvent>,
+            _request_options: crate::services::llm::types::LlmRequestOptions,
+        ) -> crate::services::llm::types::LlmResult<crate::services::llm::types::LlmResponse>
+        {
+            unimplemented!()
+        }
+        async fn health_check(&self) -> crate::services::llm::types::LlmResult<()> {
+            Ok(())
+        }
+        fn config(&self) -> &crate::services::llm::types::ProviderConfig {
+            &self.config
+        }
+    }
+
+    fn extraction_mock_provider(calls: Arc<AtomicU32>) -> Arc<ExtractionMockProvider> {
+        Arc::new(ExtractionMockProvider {
+            response: r#"[{"category":"pattern","content":"Auth middleware validates JWT expiry before any admin route handler runs","keywords":["auth","jwt"],"importance":0.8,"suggested_scope":"project"}]"#.to_string(),
+            calls,
+            config: crate::services::llm::types::ProviderConfig {
+                model: "mock".to_string(),
+                ..Default::default()
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_session_summary_memory_hook_persists_notable_decision() {
+        let mut hooks = AgenticHooks::new();
+        let store = create_test_memory_store();
+        let calls = Arc::new(AtomicU32::new(0));
+        register_session_summary_memory_hook(
+            &mut hooks,
+            store.clone(),
+            extraction_mock_provider(calls.clone()),
+            SessionSummaryMemoryConfig::default(),
+        );
+
+        let ctx = test_context();
+        let summary = SessionSummary {
+            task_description: "Fix admin route authentication".to_string(),
+            files_read: vec!["src/auth.rs".to_string()],
+            key_findings: vec![],
+            tool_usage: HashMap::new(),
+            total_turns: 6,
+            success: true,
+            conversation_content: "[User]: Admin routes accept expired tokens.\n\n[Assistant]: We decided to move JWT expiry validation into the auth middleware so every admin route handler is covered, instead of checking expiry inside each handler.".repeat(2),
+        };
+        hooks.fire_on_session_end(&ctx, summary).await;
+
+        let project_path = ctx.project_path.to_string_lossy().to_string();
+        let memories = store
+            .list_memories(&project_path, None, 0, 100)
+            .unwrap_or_default();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(memories.len(), 1);
+        assert!(memories[0].content.contains("Auth middleware"));
+    }
+
+    #[tokio::test]
+    async fn test_session_summary_memory_hook_skips_trivial_session() {
+        let mut hooks = AgenticHooks::new();
+        let store = create_test_memory_store();
+        let calls = Arc::new(AtomicU32::new(0));
+        register_session_summary_memory_hook(
+            &mut hooks,
+            store.clone(),
+            extraction_mock_provider(calls.clone()),
+            SessionSummaryMemoryConfig::default(),
+        );
+
+        let ctx = test_context();
+        let summary = SessionSummary {
+            task_description: "Quick question".to_string(),
+            files_read: vec![],
+            key_findings: vec![],
+            tool_usage: HashMap::new(),
+            total_turns: 1,
+            success: true,
+            conversation_content: "[User]: hi\n\n[Assistant]: hello".to_string(),
+        };
+        hooks.fire_on_session_end(&ctx, summary).await;
+
+        let project_path = ctx.project_path.to_string_lossy().to_string();
+        let memories = store
+            .list_memories(&project_path, None, 0, 100)
+            .unwrap_or_default();
+        assert_eq!(
+            calls.load(Ordering::SeqCst),
+            0,
+            "trivial session must not call the LLM"
+        );
+        assert!(memories.is_empty());
+    }
+
     #[tokio::test]
     async fn test_register_memory_hooks_compaction_no_memory_writes() {
         let mut hooks = AgenticHooks::new();
@@ -2562,6 +3110,27 @@ suffix {"name":"Skill B"}"#;
         assert!(parsed.is_none());
     }
 
+    #[test]
+    fn test_session_success_signal_rejects_corrected_session() {
+        let summary = SessionSummary {
+            task_description: "Add a migration".to_string(),
+            files_read: vec![],
+            key_findings: vec![],
+            tool_usage: HashMap::from([("Edit".to_string(), 4)]),
+            total_turns: 6,
+            success: true,
+            conversation_content: format!(
+                "[User]: Add a migration\n\n[Assistant]: {}\n\n[User]: That's wrong, use the other table\n\n",
+                "Done. ".repeat(40)
+            ),
+        };
+
+        let signal = session_success_signal(&summary);
+        assert_eq!(signal.tool_calls, 4);
+        assert_eq!(signal.user_corrections, 1);
+        assert!(!signal.is_sufficient());
+    }
+
     #[test]
     fn test_generated_skill_similarity_identity_and_distance() {
         let identical = generated_skill_similarity(
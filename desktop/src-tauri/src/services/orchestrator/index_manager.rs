@@ -8,6 +8,9 @@
 //!   no existing index.
 //! - `trigger_reindex` – always clears the old index and starts fresh.
 //! - `get_status` – returns the current `IndexStatusEvent`.
+//! - `migrate_embedding_index` – re-embeds a project whose index was built
+//!   with a different embedding provider/model/dimension than the configured
+//!   one (see `EmbeddingManager::check_index_signature`).
 
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -24,16 +27,17 @@ use super::background_indexer::{
 };
 use super::component_classifier;
 use super::embedding_config_builder;
-use super::embedding_manager::{EmbeddingManager, EmbeddingManagerConfig};
+use super::embedding_manager::{EmbeddingIndexMismatch, EmbeddingManager, EmbeddingManagerConfig};
 use super::embedding_provider::{
     CodebaseIndexConfig, EmbeddingProviderConfig, EmbeddingProviderType, CODEBASE_INDEX_CONFIG_KEY,
 };
 use super::embedding_provider_tfidf::TfIdfEmbeddingProvider;
 use super::embedding_service::EmbeddingService;
 use super::hnsw_index::HnswIndex;
-use super::index_store::IndexStore;
+use super::index_store::{EmbeddingMetadata, IndexStore};
 use super::lsp_enricher::LspEnricher;
 use super::lsp_registry::LspServerRegistry;
+use super::tree_sitter_parser::ParseCache;
 use crate::services::llm::provider::LlmProvider;
 use crate::storage::database::{Database, DbPool};
 use crate::storage::KeyringService;
@@ -69,6 +73,14 @@ pub struct IndexStatusEvent {
     /// LSP enrichment state: "none", "enriching", or "enriched".
     #[serde(default = "default_lsp_enrichment_none")]
     pub lsp_enrichment: String,
+    /// Provider, model and dimension the stored embeddings were built with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embedding_signature: Option<EmbeddingMetadata>,
+    /// The configured embedding provider no longer matches
+    /// `embedding_signature`; the index must be re-embedded before semantic
+    /// search returns meaningful neighbours.
+    #[serde(default)]
+    pub embedding_migration_required: bool,
 }
 
 fn default_lsp_enrichment_none() -> String {
@@ -132,6 +144,8 @@ pub struct IndexManager {
     lsp_incremental_debounce_ms: Arc<AtomicU64>,
     /// Optional LLM provider for component classification (Phase 1a).
     llm_provider: RwLock<Option<Arc<dyn LlmProvider>>>,
+    /// Parse trees shared by every indexer this manager starts.
+    parse_cache: Arc<ParseCache>,
 }
 
 impl IndexManager {
@@ -178,9 +192,17 @@ impl IndexManager {
             enrichment_enabled: RwLock::new(HashMap::new()),
             lsp_incremental_debounce_ms: Arc::new(AtomicU64::new(initial_debounce)),
             llm_provider: RwLock::new(None),
+            parse_cache: Arc::new(ParseCache::default()),
         }
     }
 
+    /// Use `cache` (normally the app-wide one from `AppState`) for the
+    /// indexers this manager starts.
+    pub fn with_parse_cache(mut self, cache: Arc<ParseCache>) -> Self {
+        self.parse_cache = cache;
+        self
+    }
+
     /// Set or replace the LLM provider for component classification.
     pub async fn set_llm_provider(&self, provider: Arc<dyn LlmProvider>) {
         let mut guard = self.llm_provider.write().await;
@@ -272,10 +294,32 @@ impl IndexManager {
                     });
                 }
 
+                // Compare the signature the embeddings were built with against
+                // the configured provider. On a mismatch the index is reported
+                // as stale instead of being searched with incompatible vectors.
+                let stored_signatures = self
+                    .index_store
+                    .get_embedding_metadata(project_path)
+                    .unwrap_or_default();
+                let mismatch = {
+                    let managers = self.embedding_managers.read().await;
+                    managers
+                        .get(project_path)
+                        .and_then(|m| m.check_index_signature(&stored_signatures))
+                };
+                if let Some(ref mismatch) = mismatch {
+                    warn!(
+                        project = %project_path,
+                        stored = ?mismatch.stored,
+                        current = ?mismatch.current,
+                        "index manager: embedding signature changed, index needs migration"
+                    );
+                }
+
                 // Load or rebuild HNSW index for fast semantic search.
                 // Infer dimension from the embedding manager if available,
                 // otherwise pass 0 and let load_from_disk restore it from metadata.
-                if summary.embedding_chunks > 0 {
+                if summary.embedding_chunks > 0 && mismatch.is_none() {
                     let dim = {
                         let managers = self.embedding_managers.read().await;
                         managers
@@ -303,14 +347,20 @@ impl IndexManager {
                 };
                 let event = IndexStatusEvent {
                     project_path: project_path.to_string(),
-                    status: "indexed".to_string(),
+                    status: if mismatch.is_some() {
+                        "stale".to_string()
+                    } else {
+                        "indexed".to_string()
+                    },
                     indexed_files: summary.total_files,
                     total_files: summary.total_files,
-                    error_message: None,
+                    error_message: mismatch.as_ref().map(|m| m.to_string()),
                     total_symbols: summary.total_symbols,
                     embedding_chunks: summary.embedding_chunks,
                     embedding_provider_name,
                     lsp_enrichment,
+                    embedding_signature: stored_signatures.into_iter().next(),
+                    embedding_migration_required: mismatch.is_some(),
                 };
                 self.set_status_and_emit(project_path, event).await;
 
@@ -353,6 +403,8 @@ impl IndexManager {
             embedding_chunks: 0,
             embedding_provider_name: None,
             lsp_enrichment: "none".to_string(),
+            embedding_signature: None,
+            embedding_migration_required: false,
         };
         self.set_status_and_emit(project_path, initial_event).await;
 
@@ -378,6 +430,8 @@ impl IndexManager {
                     embedding_chunks: 0,
                     embedding_provider_name: None,
                     lsp_enrichment: prev_lsp,
+                    embedding_signature: None,
+                    embedding_migration_required: false,
                 };
                 map.insert(pp_for_cb.clone(), event.clone());
                 Some(event)
@@ -437,6 +491,7 @@ impl IndexManager {
 
         // Clone LLM provider for component classification (Phase 1a).
         let llm_provider = self.llm_provider.read().await.clone();
+        let parse_cache = Arc::clone(&self.parse_cache);
 
         let handle = tokio::task::spawn(async move {
             // Build batch callback for incremental status refresh.
@@ -464,6 +519,11 @@ impl IndexManager {
                             embedding_chunks: summary.embedding_chunks,
                             embedding_provider_name: Some(provider_name_for_batch.clone()),
                             lsp_enrichment: prev_lsp,
+                            embedding_signature: stored_embedding_signature(
+                                &store_for_batch,
+                                &pp_for_batch,
+                            ),
+                            embedding_migration_required: false,
                         };
                         map.insert(pp_for_batch.clone(), event.clone());
                         Some(event)
@@ -479,6 +539,7 @@ impl IndexManager {
             });
 
             let indexer = BackgroundIndexer::new(project_root, index_store.clone())
+                .with_parse_cache(parse_cache)
                 .with_progress_callback(progress_cb)
                 .with_llm_provider_opt(llm_provider)
                 .with_embedding_service(embedding_svc)
@@ -555,6 +616,8 @@ impl IndexManager {
                         embedding_chunks: summary.embedding_chunks,
                         embedding_provider_name: Some(provider_display_name.clone()),
                         lsp_enrichment: prev_lsp,
+                        embedding_signature: stored_embedding_signature(&index_store, &pp_for_task),
+                        embedding_migration_required: false,
                     };
                     map.insert(pp_for_task.clone(), event.clone());
                     drop(map);
@@ -572,6 +635,8 @@ impl IndexManager {
                         embedding_chunks: 0,
                         embedding_provider_name: None,
                         lsp_enrichment: "none".to_string(),
+                        embedding_signature: None,
+                        embedding_migration_required: false,
                     };
                     map.insert(pp_for_task.clone(), event.clone());
                     drop(map);
@@ -632,6 +697,54 @@ impl IndexManager {
         self.start_indexing(project_path).await;
     }
 
+    /// Compare the embedding signature stored with a project's index against
+    /// the currently configured embedding provider.
+    ///
+    /// Builds the provider from the persisted config rather than reusing the
+    /// cached manager, so a provider change that has not been reindexed yet
+    /// is detected.
+    pub async fn check_embedding_signature(
+        &self,
+        project_path: &str,
+    ) -> Option<EmbeddingIndexMismatch> {
+        let stored = self
+            .index_store
+            .get_embedding_metadata(project_path)
+            .unwrap_or_default();
+        if stored.is_empty() {
+            return None;
+        }
+        let embedding_svc = {
+            let mut embeds = self.embedding_services.write().await;
+            embeds
+                .entry(project_path.to_string())
+                .or_insert_with(|| Arc::new(EmbeddingService::new()))
+                .clone()
+        };
+        let (current, _is_tfidf) = self.build_embedding_manager_from_config(embedding_svc);
+        current.check_index_signature(&stored)
+    }
+
+    /// Re-embed a project whose index was built with a different embedding
+    /// signature than the configured provider produces.
+    ///
+    /// The old index is discarded and rebuilt with the configured provider.
+    /// Returns `false` without touching the index when it is already
+    /// compatible.
+    pub async fn migrate_embedding_index(&self, project_path: &str) -> bool {
+        let Some(mismatch) = self.check_embedding_signature(project_path).await else {
+            return false;
+        };
+        info!(
+            project = %project_path,
+            stored = ?mismatch.stored,
+            current = ?mismatch.current,
+            "index manager: migrating embedding index to the configured provider"
+        );
+        self.trigger_reindex(project_path).await;
+        true
+    }
+
     /// Get the current indexing status for a project directory.
     ///
     /// Returns a cached `IndexStatusEvent` if one exists, otherwise queries
@@ -648,11 +761,17 @@ impl IndexManager {
         // Fallback: query the index store.
         match self.index_store.get_project_summary(project_path) {
             Ok(summary) if summary.total_files > 0 => {
-                let embedding_provider_name = {
+                let stored_signatures = self
+                    .index_store
+                    .get_embedding_metadata(project_path)
+                    .unwrap_or_default();
+                let (embedding_provider_name, mismatch) = {
                     let managers = self.embedding_managers.read().await;
-                    managers
-                        .get(project_path)
-                        .map(|m| m.display_name().to_string())
+                    let manager = managers.get(project_path);
+                    (
+                        manager.map(|m| m.display_name().to_string()),
+                        manager.and_then(|m| m.check_index_signature(&stored_signatures)),
+                    )
                 };
                 let lsp_enrichment = if self
                     .index_store
@@ -665,14 +784,20 @@ impl IndexManager {
                 };
                 IndexStatusEvent {
                     project_path: project_path.to_string(),
-                    status: "indexed".to_string(),
+                    status: if mismatch.is_some() {
+                        "stale".to_string()
+                    } else {
+                        "indexed".to_string()
+                    },
                     indexed_files: summary.total_files,
                     total_files: summary.total_files,
-                    error_message: None,
+                    error_message: mismatch.as_ref().map(|m| m.to_string()),
                     total_symbols: summary.total_symbols,
                     embedding_chunks: summary.embedding_chunks,
                     embedding_provider_name,
                     lsp_enrichment,
+                    embedding_signature: stored_signatures.into_iter().next(),
+                    embedding_migration_required: mismatch.is_some(),
                 }
             }
             _ => IndexStatusEvent {
@@ -685,6 +810,8 @@ impl IndexManager {
                 embedding_chunks: 0,
                 embedding_provider_name: None,
                 lsp_enrichment: "none".to_string(),
+                embedding_signature: None,
+                embedding_migration_required: false,
             },
         }
     }
@@ -860,6 +987,11 @@ impl IndexManager {
             .clone()
     }
 
+    /// The persistent `LspEnricher` for a project, if one has been created.
+    pub async fn get_lsp_enricher(&self, project_path: &str) -> Option<Arc<LspEnricher>> {
+        self.lsp_enrichers.read().await.get(project_path).cloned()
+    }
+
     /// Cache whether a project has enrichment data.
     pub async fn set_enrichment_enabled(&self, project_path: &str, enabled: bool) {
         let mut map = self.enrichment_enabled.write().await;
@@ -1030,16 +1162,29 @@ impl IndexManager {
                         .get(&pp_for_batch)
                         .map(|e| e.lsp_enrichment.clone())
                         .unwrap_or_else(|| "none".to_string());
+                    // A pending embedding migration stays visible until the
+                    // project is re-embedded.
+                    let (status, error_message, migration_required) = match map.get(&pp_for_batch) {
+                        Some(prev) if prev.embedding_migration_required => {
+                            ("stale".to_string(), prev.error_message.clone(), true)
+                        }
+                        _ => ("indexed".to_string(), None, false),
+                    };
                     let event = IndexStatusEvent {
                         project_path: pp_for_batch.clone(),
-                        status: "indexed".to_string(),
+                        status,
                         indexed_files: summary.total_files,
                         total_files: summary.total_files,
-                        error_message: None,
+                        error_message,
                         total_symbols: summary.total_symbols,
                         embedding_chunks: summary.embedding_chunks,
                         embedding_provider_name: Some(provider_name_for_batch.clone()),
                         lsp_enrichment: prev_lsp,
+                        embedding_signature: stored_embedding_signature(
+                            &store_for_batch,
+                            &pp_for_batch,
+                        ),
+                        embedding_migration_required: migration_required,
                     };
                     map.insert(pp_for_batch.clone(), event.clone());
                     Some(event)
@@ -1061,6 +1206,7 @@ impl IndexManager {
         let codebase_config = self.load_codebase_index_config();
 
         let mut indexer = BackgroundIndexer::new(project_root, index_store)
+            .with_parse_cache(Arc::clone(&self.parse_cache))
             .with_change_receiver(change_rx)
             .with_channel_overflow_flag(overflow_flag)
             .with_batch_callback(batch_cb)
@@ -1370,6 +1516,17 @@ impl IndexManager {
     }
 }
 
+/// The signature recorded with a project's stored embeddings, if any.
+fn stored_embedding_signature(
+    index_store: &IndexStore,
+    project_path: &str,
+) -> Option<EmbeddingMetadata> {
+    index_store
+        .get_embedding_metadata(project_path)
+        .ok()
+        .and_then(|meta| meta.into_iter().next())
+}
+
 // ---------------------------------------------------------------------------
 // Enrichment debounce loop
 // ---------------------------------------------------------------------------
@@ -1424,6 +1581,8 @@ async fn run_enrichment_debounce_loop(
         {
             continue;
         }
+        let changed: Vec<String> = pending.drain().collect();
+        enricher.mark_stale(&changed).await;
 
         // 5. Try to acquire enrichment lock (non-blocking).
         let _guard = match lock.try_lock() {
@@ -1437,14 +1596,14 @@ async fn run_enrichment_debounce_loop(
             }
         };
 
-        // 6. Run incremental enrichment.
-        let file_paths: Vec<String> = pending.drain().collect();
+        // 6. Run incremental enrichment on the changed files and their
+        //    direct dependents. Files skipped above stay marked stale.
         info!(
             project = %project_path,
-            files = file_paths.len(),
+            files = changed.len(),
             "index manager: triggering incremental LSP enrichment"
         );
-        if let Err(e) = enricher.enrich_files(project_path, &file_paths).await {
+        if let Err(e) = enricher.enrich_changed_files(project_path, &changed).await {
             warn!(
                 project = %project_path,
                 error = %e,
@@ -1624,6 +1783,8 @@ mod tests {
                     embedding_chunks: 0,
                     embedding_provider_name: None,
                     lsp_enrichment: "none".to_string(),
+                    embedding_signature: None,
+                    embedding_migration_required: false,
                 },
             );
         }
@@ -1737,6 +1898,71 @@ mod tests {
         );
     }
 
+    // -----------------------------------------------------------------------
+    // Embedding signature mismatch + migration
+    // -----------------------------------------------------------------------
+
+    #[tokio::test]
+    async fn embedding_signature_mismatch_is_detected_and_migration_rebuilds() {
+        let dir = tempdir().expect("tempdir");
+        fs::write(dir.path().join("app.py"), "def run():\n    pass\n").expect("write");
+
+        let pool = test_pool();
+        let project_path = dir.path().to_string_lossy().to_string();
+
+        // Index with the configured (TF-IDF) provider.
+        let mgr = IndexManager::new(pool.clone());
+        mgr.ensure_indexed(&project_path).await;
+        tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+        assert!(mgr.check_embedding_signature(&project_path).await.is_none());
+
+        // Simulate vectors left behind by a previously configured provider.
+        let store = IndexStore::new(pool.clone());
+        store
+            .upsert_chunk_embedding_with_provider(
+                &project_path,
+                "app.py",
+                99,
+                "def run():",
+                &[0, 0, 128, 63],
+                Some("ollama"),
+                Some("Ollama (nomic-embed-text)"),
+                Some(768),
+            )
+            .unwrap();
+
+        // A fresh manager (app restart) reports the index as stale.
+        let mgr2 = IndexManager::new(pool.clone());
+        mgr2.ensure_indexed(&project_path).await;
+        let status = mgr2.get_status(&project_path).await;
+        assert_eq!(status.status, "stale");
+        assert!(status.embedding_migration_required);
+        assert!(status.embedding_signature.is_some());
+        let mismatch = mgr2
+            .check_embedding_signature(&project_path)
+            .await
+            .expect("mismatch should be detected");
+        assert_eq!(mismatch.stored.provider_type, "ollama");
+        assert_eq!(mismatch.current.provider_type, "tfidf");
+
+        // Migration rebuilds the index with the configured provider.
+        assert!(mgr2.migrate_embedding_index(&project_path).await);
+        tokio::time::sleep(tokio::time::Duration::from_millis(1000)).await;
+
+        let meta = store.get_embedding_metadata(&project_path).unwrap();
+        assert!(meta.iter().all(|m| m.provider_type == "tfidf"));
+        assert!(mgr2
+            .check_embedding_signature(&project_path)
+            .await
+            .is_none());
+        let status = mgr2.get_status(&project_path).await;
+        assert!(!status.embedding_migration_required);
+        assert!(status.total_files > 0);
+
+        // Nothing left to migrate.
+        assert!(!mgr2.migrate_embedding_index(&project_path).await);
+    }
+
     #[tokio::test]
     async fn trigger_reindex_preserves_embedding_manager() {
         let dir = tempdir().expect("tempdir");
@@ -68,7 +68,7 @@ pub struct ProjectIndexSummary {
 ///
 /// Returned by `IndexStore::get_embedding_metadata` to help callers decide
 /// whether existing embeddings are compatible with the current provider.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EmbeddingMetadata {
     pub provider_type: String,
     pub provider_model: String,
@@ -592,6 +592,30 @@ impl IndexStore {
         Ok(rowids)
     }
 
+    /// Return the stored chunk texts for a specific file, in chunk order.
+    ///
+    /// Used to retract a file's previous chunks from the TF-IDF document
+    /// frequencies before its new chunks are added.
+    pub fn get_chunk_texts_for_file(
+        &self,
+        project_path: &str,
+        file_path: &str,
+    ) -> AppResult<Vec<String>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT chunk_text FROM file_embeddings
+             WHERE project_path = ?1 AND file_path = ?2
+             ORDER BY chunk_index",
+        )?;
+        let texts = stmt
+            .query_map(params![project_path, file_path], |row| {
+                row.get::<_, String>(0)
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+        Ok(texts)
+    }
+
     /// Return the SQLite ROWID of a single embedding chunk.
     ///
     /// Uses the `UNIQUE(project_path, file_path, chunk_index)` index for O(1) lookup.
@@ -1598,6 +1622,53 @@ impl IndexStore {
         Ok(())
     }
 
+    /// Files that reference symbols defined in `file_paths`, excluding the
+    /// given files themselves.
+    ///
+    /// Enrichment records a reference with the defining file as
+    /// `source_file` and the usage location as `target_file`, so these are
+    /// the direct dependents whose enrichment goes stale when the files change.
+    pub fn get_referencing_files(
+        &self,
+        project_path: &str,
+        file_paths: &[&str],
+    ) -> AppResult<Vec<String>> {
+        if file_paths.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.get_connection()?;
+
+        let placeholders: Vec<String> = (0..file_paths.len())
+            .map(|i| format!("?{}", i + 2))
+            .collect();
+        let in_clause = placeholders.join(", ");
+
+        let sql = format!(
+            "SELECT DISTINCT target_file FROM cross_references
+             WHERE project_path = ?1
+               AND source_file IN ({in_clause})
+               AND target_file NOT IN ({in_clause})
+             ORDER BY target_file"
+        );
+
+        let mut params_vec: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
+        params_vec.push(Box::new(project_path.to_string()));
+        for fp in file_paths {
+            params_vec.push(Box::new(fp.to_string()));
+        }
+        let param_refs: Vec<&dyn rusqlite::types::ToSql> =
+            params_vec.iter().map(|p| p.as_ref()).collect();
+
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map(param_refs.as_slice(), |row| row.get::<_, String>(0))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(rows)
+    }
+
     /// Check whether any LSP enrichment data exists for a project.
     ///
     /// Returns `true` if at least one symbol has a non-NULL `resolved_type`
@@ -1654,6 +1725,41 @@ impl IndexStore {
         Ok(rows)
     }
 
+    /// Get cross-references whose target is the named symbol.
+    pub fn get_references_to_symbol(
+        &self,
+        project_path: &str,
+        symbol_name: &str,
+    ) -> AppResult<Vec<CrossReference>> {
+        let conn = self.get_connection()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, source_file, source_line, source_symbol,
+                    target_file, target_line, target_symbol, reference_kind
+             FROM cross_references
+             WHERE project_path = ?1 AND target_symbol = ?2
+             ORDER BY source_file, source_line",
+        )?;
+
+        let rows = stmt
+            .query_map(params![project_path, symbol_name], |row| {
+                Ok(CrossReference {
+                    id: row.get(0)?,
+                    source_file: row.get(1)?,
+                    source_line: row.get(2)?,
+                    source_symbol: row.get(3)?,
+                    target_file: row.get(4)?,
+                    target_line: row.get(5)?,
+                    target_symbol: row.get(6)?,
+                    reference_kind: row.get(7)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(rows)
+    }
+
     /// Insert or update an LSP server detection cache entry.
     pub fn upsert_lsp_server(
         &self,
@@ -3917,6 +4023,23 @@ mod tests {
         assert_eq!(refs[0].reference_kind, "call");
     }
 
+    #[test]
+    fn test_get_references_to_symbol() {
+        let store = create_test_store();
+
+        store
+            .insert_cross_reference("/test", "a.rs", 3, None, "b.rs", 5, Some("foo"), "call")
+            .unwrap();
+        store
+            .insert_cross_reference("/test", "c.rs", 9, None, "b.rs", 7, Some("bar"), "call")
+            .unwrap();
+
+        let refs = store.get_references_to_symbol("/test", "foo").unwrap();
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].source_file, "a.rs");
+        assert_eq!(refs[0].source_line, 3);
+    }
+
     #[test]
     fn test_insert_cross_reference_ignores_duplicates() {
         let store = create_test_store();
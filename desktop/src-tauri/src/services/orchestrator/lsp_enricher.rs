@@ -4,8 +4,12 @@
 //! For each detected language server: starts an LspClient, queries
 //! hover/references/definition for each symbol, and stores the results
 //! in the IndexStore.
+//!
+//! Incremental mode (`enrich_changed_files`) re-enriches only the changed
+//! files and the files that reference them, and tracks which files are
+//! waiting for enrichment so the UI can show stale data.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
@@ -24,12 +28,88 @@ use super::lsp_registry::LspServerRegistry;
 const MAX_REQUESTS_PER_SECOND: u32 = 10;
 
 /// Report returned after an enrichment pass.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EnrichmentReport {
     pub languages_enriched: Vec<String>,
     pub symbols_enriched: usize,
     pub references_found: usize,
     pub duration_ms: u64,
+    /// Changed files re-enriched by the latest incremental pass.
+    #[serde(default)]
+    pub files_enriched: Vec<String>,
+    /// Files re-enriched because they reference a changed file.
+    #[serde(default)]
+    pub dependents_enriched: Vec<String>,
+    /// Files whose enrichment is out of date.
+    #[serde(default)]
+    pub stale_files: Vec<String>,
+}
+
+impl EnrichmentReport {
+    /// Fold an incremental pass into this report.
+    ///
+    /// Counts and durations accumulate; the file lists describe the latest pass.
+    pub fn absorb(&mut self, incremental: &EnrichmentReport) {
+        for language in &incremental.languages_enriched {
+            if !self.languages_enriched.contains(language) {
+                self.languages_enriched.push(language.clone());
+            }
+        }
+        self.symbols_enriched += incremental.symbols_enriched;
+        self.references_found += incremental.references_found;
+        self.duration_ms += incremental.duration_ms;
+        self.files_enriched = incremental.files_enriched.clone();
+        self.dependents_enriched = incremental.dependents_enriched.clone();
+        self.stale_files = incremental.stale_files.clone();
+    }
+}
+
+/// Files to re-enrich after an edit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncrementalEnrichmentPlan {
+    /// The changed files themselves (sorted, de-duplicated).
+    pub changed: Vec<String>,
+    /// Files that reference symbols in `changed` (direct dependents only).
+    pub dependents: Vec<String>,
+}
+
+impl IncrementalEnrichmentPlan {
+    /// Every file the plan covers.
+    pub fn files(&self) -> Vec<String> {
+        self.changed
+            .iter()
+            .chain(self.dependents.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+/// Compute which files an incremental pass must re-enrich.
+///
+/// Must run before the changed files' cross-references are cleared, since
+/// the stored references from the previous pass identify the dependents.
+pub fn plan_incremental_enrichment(
+    index_store: &IndexStore,
+    project_path: &str,
+    changed_files: &[String],
+) -> IncrementalEnrichmentPlan {
+    let changed: Vec<String> = changed_files
+        .iter()
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let refs: Vec<&str> = changed.iter().map(|s| s.as_str()).collect();
+    let dependents = index_store
+        .get_referencing_files(project_path, &refs)
+        .unwrap_or_else(|e| {
+            warn!(error = %e, "Failed to look up referencing files for incremental enrichment");
+            Vec::new()
+        });
+    IncrementalEnrichmentPlan {
+        changed,
+        dependents,
+    }
 }
 
 /// Orchestrates LSP-based semantic enrichment of indexed symbols.
@@ -41,6 +121,10 @@ pub struct LspEnricher {
     /// Timestamp of the last enrichment activity.
     /// Used by [`shutdown_if_idle`] to decide when to reclaim idle clients.
     last_activity: RwLock<Option<Instant>>,
+    /// Files changed since they were last enriched.
+    stale_files: RwLock<BTreeSet<String>>,
+    /// Incremental passes accumulated since the last full enrichment.
+    incremental_report: RwLock<Option<EnrichmentReport>>,
 }
 
 impl LspEnricher {
@@ -51,6 +135,8 @@ impl LspEnricher {
             index_store,
             clients: RwLock::new(HashMap::new()),
             last_activity: RwLock::new(None),
+            stale_files: RwLock::new(BTreeSet::new()),
+            incremental_report: RwLock::new(None),
         }
     }
 
@@ -64,12 +150,7 @@ impl LspEnricher {
     /// 6. Shutdown all clients
     pub async fn enrich_project(&self, project_path: &str) -> anyhow::Result<EnrichmentReport> {
         let start = Instant::now();
-        let mut report = EnrichmentReport {
-            languages_enriched: Vec::new(),
-            symbols_enriched: 0,
-            references_found: 0,
-            duration_ms: 0,
-        };
+        let mut report = EnrichmentReport::default();
 
         // Step 1: Detect available language servers
         let detected = self.registry.detect_all();
@@ -301,6 +382,7 @@ impl LspEnricher {
         if report.languages_enriched.is_empty() {
             return Err(anyhow::anyhow!("LSP_NO_LIVE_CLIENTS"));
         }
+        self.reset_incremental_state().await;
 
         report.duration_ms = start.elapsed().as_millis() as u64;
         info!(
@@ -370,12 +452,7 @@ impl LspEnricher {
         file_paths: &[String],
     ) -> anyhow::Result<EnrichmentReport> {
         let start = Instant::now();
-        let mut report = EnrichmentReport {
-            languages_enriched: Vec::new(),
-            symbols_enriched: 0,
-            references_found: 0,
-            duration_ms: 0,
-        };
+        let mut report = EnrichmentReport::default();
 
         if file_paths.is_empty() {
             report.duration_ms = start.elapsed().as_millis() as u64;
@@ -595,6 +672,61 @@ impl LspEnricher {
         Ok(report)
     }
 
+    /// Incrementally re-enrich `changed_files` and their direct dependents.
+    ///
+    /// The files are marked stale first and only cleared once a language
+    /// server actually processed them, so skipped or failed passes stay
+    /// visible through [`stale_files`](Self::stale_files).
+    pub async fn enrich_changed_files(
+        &self,
+        project_path: &str,
+        changed_files: &[String],
+    ) -> anyhow::Result<EnrichmentReport> {
+        let plan = plan_incremental_enrichment(&self.index_store, project_path, changed_files);
+        let files = plan.files();
+        self.mark_stale(&files).await;
+
+        let mut report = self.enrich_files(project_path, &files).await?;
+        if !report.languages_enriched.is_empty() {
+            let mut stale = self.stale_files.write().await;
+            for file in &files {
+                stale.remove(file);
+            }
+        }
+
+        report.files_enriched = plan.changed;
+        report.dependents_enriched = plan.dependents;
+        report.stale_files = self.stale_files().await;
+
+        let mut cumulative = self.incremental_report.write().await;
+        match cumulative.as_mut() {
+            Some(existing) => existing.absorb(&report),
+            None => *cumulative = Some(report.clone()),
+        }
+        Ok(report)
+    }
+
+    /// Record files whose enrichment is out of date.
+    pub async fn mark_stale(&self, files: &[String]) {
+        self.stale_files.write().await.extend(files.iter().cloned());
+    }
+
+    /// Files changed since they were last enriched, sorted.
+    pub async fn stale_files(&self) -> Vec<String> {
+        self.stale_files.read().await.iter().cloned().collect()
+    }
+
+    /// Incremental passes accumulated since the last full enrichment.
+    pub async fn incremental_report(&self) -> Option<EnrichmentReport> {
+        self.incremental_report.read().await.clone()
+    }
+
+    /// Forget stale files and incremental totals after a full pass.
+    pub async fn reset_incremental_state(&self) {
+        self.stale_files.write().await.clear();
+        *self.incremental_report.write().await = None;
+    }
+
     /// Shutdown clients if they have been idle for longer than the given duration.
     ///
     /// Returns `true` if clients were shut down.
@@ -846,6 +978,7 @@ mod tests {
             symbols_enriched: 100,
             references_found: 50,
             duration_ms: 1500,
+            ..Default::default()
         };
 
         assert_eq!(report.languages_enriched.len(), 1);
@@ -861,6 +994,7 @@ mod tests {
             symbols_enriched: 42,
             references_found: 17,
             duration_ms: 3000,
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&report).unwrap();
@@ -870,6 +1004,87 @@ mod tests {
         assert_eq!(deserialized.languages_enriched.len(), 2);
     }
 
+    #[test]
+    fn test_editing_one_file_plans_it_and_its_referencing_files_only() {
+        let db = crate::storage::database::Database::new_in_memory().expect("in-memory db");
+        let store = IndexStore::new(db.pool().clone());
+        // Symbols defined in a.rs are used from b.rs and c.rs.
+        for (source, line, target, target_line) in [
+            ("a.rs", 3, "b.rs", 10),
+            ("a.rs", 8, "c.rs", 4),
+            ("a.rs", 8, "a.rs", 20),
+            // Unrelated edges: b.rs is used by d.rs, e.rs by a.rs.
+            ("b.rs", 1, "d.rs", 2),
+            ("e.rs", 5, "a.rs", 1),
+        ] {
+            store
+                .insert_cross_reference(
+                    "/p",
+                    source,
+                    line,
+                    None,
+                    target,
+                    target_line,
+                    None,
+                    "usage",
+                )
+                .unwrap();
+        }
+
+        let plan =
+            plan_incremental_enrichment(&store, "/p", &["a.rs".to_string(), "a.rs".to_string()]);
+        assert_eq!(plan.changed, vec!["a.rs"]);
+        assert_eq!(plan.dependents, vec!["b.rs", "c.rs"]);
+        assert_eq!(plan.files(), vec!["a.rs", "b.rs", "c.rs"]);
+
+        let untouched = plan_incremental_enrichment(&store, "/p", &["d.rs".to_string()]);
+        assert!(untouched.dependents.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_stale_files_tracked_until_full_enrichment() {
+        let db = crate::storage::database::Database::new_in_memory().expect("in-memory db");
+        let enricher = LspEnricher::new(
+            Arc::new(LspServerRegistry::new()),
+            Arc::new(IndexStore::new(db.pool().clone())),
+        );
+        enricher
+            .mark_stale(&["b.rs".to_string(), "a.rs".to_string(), "b.rs".to_string()])
+            .await;
+        assert_eq!(enricher.stale_files().await, vec!["a.rs", "b.rs"]);
+
+        enricher.reset_incremental_state().await;
+        assert!(enricher.stale_files().await.is_empty());
+        assert!(enricher.incremental_report().await.is_none());
+    }
+
+    #[test]
+    fn test_absorb_accumulates_incremental_passes() {
+        let mut report = EnrichmentReport {
+            languages_enriched: vec!["rust".to_string()],
+            symbols_enriched: 10,
+            references_found: 4,
+            duration_ms: 100,
+            ..Default::default()
+        };
+        report.absorb(&EnrichmentReport {
+            languages_enriched: vec!["rust".to_string(), "python".to_string()],
+            symbols_enriched: 2,
+            references_found: 1,
+            duration_ms: 5,
+            files_enriched: vec!["a.py".to_string()],
+            dependents_enriched: vec!["b.py".to_string()],
+            stale_files: vec!["c.rs".to_string()],
+        });
+        assert_eq!(report.languages_enriched, vec!["rust", "python"]);
+        assert_eq!(report.symbols_enriched, 12);
+        assert_eq!(report.references_found, 5);
+        assert_eq!(report.duration_ms, 105);
+        assert_eq!(report.files_enriched, vec!["a.py"]);
+        assert_eq!(report.dependents_enriched, vec!["b.py"]);
+        assert_eq!(report.stale_files, vec!["c.rs"]);
+    }
+
     #[test]
     fn test_uri_to_relative_path_decodes_spaces() {
         let project = "/tmp/demo project";
@@ -9,6 +9,8 @@ mod analysis_merge;
 mod analysis_scheduler;
 mod analysis_store;
 pub mod background_indexer;
+pub mod batch;
+pub mod checkpoint_policy;
 pub mod codebase_search_service;
 pub mod component_classifier;
 pub mod embedding_config_builder;
@@ -34,6 +36,7 @@ pub mod permission_gate;
 pub mod permissions;
 pub mod rate_limit_classifier;
 mod service;
+pub mod session_bundle;
 pub mod transfer;
 pub mod tree_sitter_parser;
 
@@ -47,5 +50,6 @@ pub use iteration_budget::{
 };
 pub(crate) use service::text_describes_pending_action;
 pub use service::{
-    ExecutionResult, OrchestratorConfig, OrchestratorService, ProviderInfo, SessionExecutionResult,
+    ExecutionResult, OrchestratorConfig, OrchestratorService, PauseCheckpoint, ProviderInfo,
+    SessionExecutionResult, ToolOutputLimit, TruncationProfile,
 };
@@ -17,9 +17,9 @@ use tokio::sync::{mpsc, RwLock};
 use tokio_util::sync::CancellationToken;
 
 use super::analysis_index::{
-    build_chunk_plan, build_file_inventory, compute_coverage_report, select_chunks_for_phase,
-    AnalysisCoverageReport, AnalysisLimits, AnalysisProfile, ChunkPlan, FileInventory,
-    FileInventoryItem, InventoryChunk,
+    build_chunk_plan, build_file_inventory_incremental, compute_coverage_report,
+    select_chunks_for_phase, AnalysisCoverageReport, AnalysisLimits, AnalysisProfile, ChunkPlan,
+    FileAnalysisCache, FileInventory, FileInventoryItem, InventoryChunk,
 };
 use super::analysis_merge::{merge_chunk_summaries, ChunkSummaryRecord};
 use super::analysis_scheduler::build_phase_plan;
@@ -27,6 +27,7 @@ use super::analysis_store::{
     AnalysisPhaseResultRecord, AnalysisRunHandle, AnalysisRunStore, CoverageMetrics,
     EvidenceRecord, SubAgentResultRecord,
 };
+use super::checkpoint_policy::CheckpointPolicy;
 use super::embedding_manager::EmbeddingManager;
 use super::embedding_service::EmbeddingService;
 use super::hnsw_index::HnswIndex;
@@ -45,10 +46,10 @@ use crate::services::knowledge::context_provider::{
     KnowledgeContextConfig, KnowledgeContextProvider,
 };
 use crate::services::llm::{
-    AnthropicProvider, DeepSeekProvider, FallbackToolFormatMode, GlmProvider, LlmProvider,
-    LlmRequestOptions, LlmResponse, Message, MessageContent, MinimaxProvider, OllamaProvider,
-    OpenAIProvider, ProviderConfig, ProviderType, QwenProvider, ToolCallMode, ToolCallReliability,
-    ToolDefinition, UsageStats,
+    required_features, AnthropicProvider, DeepSeekProvider, FallbackToolFormatMode, GlmProvider,
+    LlmProvider, LlmRequestOptions, LlmResponse, Message, MessageContent, MinimaxProvider,
+    OllamaProvider, OpenAIProvider, ProviderConfig, ProviderType, QwenProvider, ToolCallMode,
+    ToolCallReliability, ToolDefinition, UsageStats,
 };
 use crate::services::streaming::UnifiedStreamEvent;
 #[allow(deprecated)]
@@ -57,8 +58,9 @@ use crate::services::tools::{
     build_project_summary, build_skills_section, build_sub_agent_tool_guidance,
     build_system_prompt_with_memories, build_tool_call_instructions, build_tool_priority_section,
     detect_language, extract_text_without_tool_calls, format_tool_result,
-    get_basic_tool_definitions_from_registry, get_tool_definitions_from_registry,
-    merge_system_prompts, parse_tool_calls, ParsedToolCall, SubAgentType, TaskContext,
+    format_tool_result_with_limit, get_basic_tool_definitions_from_registry,
+    get_tool_definitions_from_registry, merge_system_prompts, parse_tool_calls,
+    truncate_tool_result, ParsedToolCall, ResultLimit, SubAgentType, TaskContext,
     TaskExecutionResult, TaskSpawner, ToolExecutor, MAX_SUB_AGENT_DEPTH,
 };
 use crate::utils::error::{AppError, AppResult};
@@ -346,6 +348,22 @@ pub struct ExecutionResult {
     pub error: Option<String>,
 }
 
+/// Loop state captured when execution parks on a pause.
+///
+/// Taken at the top of an iteration, after the in-flight LLM and tool calls
+/// have finished, so resuming from it never repeats completed work.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PauseCheckpoint {
+    /// Conversation so far, including tool results
+    pub messages: Vec<Message>,
+    /// Iterations completed before the pause
+    pub iterations: u32,
+    /// Usage accumulated before the pause
+    pub usage: UsageStats,
+    /// When the loop parked (unix seconds)
+    pub paused_at: i64,
+}
+
 /// Session-based execution result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionExecutionResult {
@@ -380,6 +398,13 @@ pub struct OrchestratorService {
     cancellation_token: CancellationToken,
     /// Pause flag: when true, the agentic loop sleeps until unpaused or cancelled.
     paused: Arc<AtomicBool>,
+    /// Checkpoint recorded while the agentic loop is parked on a pause.
+    pause_checkpoint: Mutex<Option<PauseCheckpoint>>,
+    /// Session whose persisted progress follows pause checkpoints.
+    checkpoint_session_id: Mutex<Option<String>>,
+    /// Per-session policy requesting automatic timeline checkpoints
+    /// (configured through the `checkpoint_policy` setting).
+    checkpoint_policy: Mutex<Option<CheckpointPolicy>>,
     /// Database pool for session persistence
     db_pool: Option<Pool<SqliteConnectionManager>>,
     /// Active sessions (in-memory cache)
@@ -432,6 +457,9 @@ pub struct OrchestratorService {
     plugin_skills: Option<Vec<crate::services::plugins::models::PluginSkill>>,
     /// Plugin commands (from enabled plugins' commands/), cached at construction.
     plugin_commands: Option<Vec<crate::services::plugins::models::PluginCommand>>,
+    /// Explicit tool-result truncation profile. When None, the profile is
+    /// derived from the provider's context window.
+    truncation_profile: Option<TruncationProfile>,
 }
 
 /// Task spawner that creates sub-agent OrchestratorService instances
@@ -477,6 +505,8 @@ struct OrchestratorTaskSpawner {
     knowledge_document_filter: Option<Vec<crate::services::knowledge::pipeline::ScopedDocumentRef>>,
     /// Pre-built knowledge awareness section for sub-agent system prompts.
     knowledge_awareness_snapshot: Option<String>,
+    /// Bash environment allowlist inherited from the parent executor.
+    bash_env_allowlist: Vec<String>,
     /// Shared analytics tracking channel from the parent orchestrator.
     shared_analytics_tx: Option<mpsc::Sender<crate::services::analytics::TrackerMessage>>,
     /// Shared cost calculator from the parent orchestrator.
@@ -593,6 +623,9 @@ struct AnalysisPhasePolicy {
     max_attempts: u32,
     force_tool_mode_attempts: u32,
     temperature_override: f32,
+    /// Reasoning depth for the phase; providers map it to a thinking budget
+    /// or `reasoning_effort`. Unused when the user configured either.
+    reasoning_effort: &'static str,
     quota: AnalysisToolQuota,
 }
 
@@ -603,6 +636,7 @@ impl AnalysisPhasePolicy {
                 max_attempts: 2,
                 force_tool_mode_attempts: 1,
                 temperature_override: 0.0,
+                reasoning_effort: "low",
                 quota: AnalysisToolQuota {
                     min_total_calls: 4,
                     min_read_calls: 1,
@@ -614,6 +648,7 @@ impl AnalysisPhasePolicy {
                 max_attempts: 1,
                 force_tool_mode_attempts: 1,
                 temperature_override: 0.0,
+                reasoning_effort: "high",
                 quota: AnalysisToolQuota {
                     min_total_calls: 6,
                     min_read_calls: 3,
@@ -625,6 +660,7 @@ impl AnalysisPhasePolicy {
                 max_attempts: 1,
                 force_tool_mode_attempts: 1,
                 temperature_override: 0.0,
+                reasoning_effort: "medium",
                 quota: AnalysisToolQuota {
                     min_total_calls: 6,
                     min_read_calls: 3,
@@ -766,7 +802,8 @@ enum AnalysisPhaseStatus {
 
 #[path = "service_helpers/mod.rs"]
 mod service_helpers;
-pub(crate) use service_helpers::text_describes_pending_action;
+pub(crate) use service_helpers::{merge_usage, text_describes_pending_action};
+pub use service_helpers::{ToolOutputLimit, TruncationProfile};
 
 /// Information about the current provider
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,9 +1,20 @@
 use super::*;
+use crate::services::core::adapter::core_token_from_tokio;
+use crate::services::core::event_actions::EventActions;
+use crate::services::orchestrator::checkpoint_policy::{
+    workspace_fingerprint, CheckpointPolicyEvent,
+};
+use crate::services::orchestrator::embedding_provider::{
+    CodebaseIndexConfig, CODEBASE_INDEX_CONFIG_KEY,
+};
+use crate::services::orchestrator::event_actions_applicator::{apply_actions, ApplyActionsResult};
 use crate::services::orchestrator::{
     assess_progress, build_iteration_budget, IterationBudgetHints, IterationProgressAssessment,
     IterationProgressSnapshot,
 };
+use crate::services::timeline::TimelineService;
 use crate::services::tools::executor::ToolResult;
+use sha2::{Digest, Sha256};
 
 #[derive(Debug, Clone, Copy, Default)]
 struct CodebaseIndexStatus {
@@ -150,6 +161,74 @@ impl OrchestratorService {
             .unwrap_or(0)
     }
 
+    /// Distinct files recorded by the file change tracker, which scope the
+    /// snapshots of automatic checkpoints.
+    fn tracked_change_files(&self) -> Vec<String> {
+        let Some(tracker) = self.tool_executor.get_file_change_tracker() else {
+            return Vec::new();
+        };
+        let Ok(guard) = tracker.lock() else {
+            return Vec::new();
+        };
+        let mut files: Vec<String> = guard
+            .changes()
+            .iter()
+            .map(|c| c.file_path.clone())
+            .collect();
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    /// Apply `actions` for `session_id`, letting the session's checkpoint
+    /// policy add an automatic checkpoint request for `event` first.
+    ///
+    /// Checkpoint requests only reach the timeline when a policy is configured.
+    pub(super) async fn apply_event_actions(
+        &self,
+        event: Option<CheckpointPolicyEvent<'_>>,
+        mut actions: EventActions,
+        session_id: &str,
+        event_actions_state: &mut HashMap<String, serde_json::Value>,
+        tx: &mpsc::Sender<UnifiedStreamEvent>,
+    ) -> AppResult<ApplyActionsResult> {
+        let mut tracked_files = Vec::new();
+        let policy_enabled = match self.checkpoint_policy.lock() {
+            Ok(mut policy) => match policy.as_mut() {
+                Some(policy) => {
+                    let due_event = event.filter(|event| policy.is_due(*event));
+                    if due_event.is_some() || actions.checkpoint_request.is_some() {
+                        tracked_files = self.tracked_change_files();
+                        let fingerprint =
+                            workspace_fingerprint(&self.config.project_root, &tracked_files);
+                        actions = match due_event {
+                            Some(event) => policy.apply_to(event, fingerprint, actions),
+                            None => {
+                                policy.record_checkpoint(fingerprint);
+                                actions
+                            }
+                        };
+                    }
+                    true
+                }
+                None => false,
+            },
+            Err(_) => false,
+        };
+
+        let timeline = policy_enabled.then(TimelineService::new);
+        apply_actions(
+            &actions,
+            event_actions_state,
+            timeline.as_ref(),
+            &self.config.project_root.to_string_lossy(),
+            session_id,
+            &tracked_files,
+            tx,
+        )
+        .await
+    }
+
     fn current_shared_state_fingerprint(&self) -> Option<u64> {
         None
     }
@@ -182,6 +261,65 @@ impl OrchestratorService {
             .join("analysis-tool-cache.json")
     }
 
+    /// Per-project cache file, so switching projects does not invalidate the
+    /// cached analysis of the other project.
+    fn analysis_file_cache_path(&self) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(self.config.project_root.to_string_lossy().as_bytes());
+        let project_hash = format!("{:x}", hasher.finalize());
+        let file_name = format!("{}.json", &project_hash[..16]);
+        match self.config.analysis_artifacts_root.parent() {
+            Some(parent) => parent.join("analysis-file-cache").join(file_name),
+            None => self
+                .config
+                .analysis_artifacts_root
+                .join("analysis-file-cache")
+                .join(file_name),
+        }
+    }
+
+    /// File extensions the user excluded from codebase indexing.
+    fn configured_excluded_extensions(&self) -> Vec<String> {
+        let Some(pool) = self.db_pool.as_ref() else {
+            return Vec::new();
+        };
+        crate::storage::Database::from_pool(pool.clone())
+            .get_setting(CODEBASE_INDEX_CONFIG_KEY)
+            .ok()
+            .flatten()
+            .and_then(|json| serde_json::from_str::<CodebaseIndexConfig>(&json).ok())
+            .map(|config| config.extra_excluded_extensions)
+            .unwrap_or_default()
+    }
+
+    /// Build the file inventory, re-analyzing only files whose content hash
+    /// changed since the last run.
+    pub(super) fn build_file_inventory_cached(
+        &self,
+        excluded_roots: &[String],
+    ) -> AppResult<FileInventory> {
+        let cache_path = self.analysis_file_cache_path();
+        let mut cache = FileAnalysisCache::load(&cache_path);
+        let (inventory, stats) = build_file_inventory_incremental(
+            &self.config.project_root,
+            excluded_roots,
+            &self.configured_excluded_extensions(),
+            &self.config.analysis_limits,
+            &mut cache,
+            ANALYZE_CACHE_TTL_SECS,
+        )?;
+        tracing::debug!(
+            reused = stats.reused,
+            analyzed = stats.analyzed,
+            evicted = stats.evicted,
+            "Incremental file inventory built"
+        );
+        if stats.analyzed > 0 || stats.evicted > 0 {
+            let _ = cache.save(&cache_path);
+        }
+        Ok(inventory)
+    }
+
     fn normalize_analyze_cache_fragment(value: &str) -> String {
         value
             .trim()
@@ -776,17 +914,28 @@ impl OrchestratorService {
         tx: mpsc::Sender<UnifiedStreamEvent>,
         request_options: LlmRequestOptions,
         force_prompt_fallback: bool,
+        resume_from: Option<PauseCheckpoint>,
     ) -> ExecutionResult {
         let reliability = self.provider.tool_call_reliability();
         let use_prompt_fallback =
             force_prompt_fallback || matches!(reliability, ToolCallReliability::None);
-        let mut messages = vec![Message::user(prompt.to_string())];
-        let mut total_usage = UsageStats::default();
-        let mut iterations = 0;
+        // Continue from a pause checkpoint when given, so completed
+        // iterations are neither repeated nor billed again.
+        let (mut messages, mut total_usage, mut iterations) =
+            match resume_from.filter(|checkpoint| !checkpoint.messages.is_empty()) {
+                Some(checkpoint) => (checkpoint.messages, checkpoint.usage, checkpoint.iterations),
+                None => (
+                    vec![Message::user(prompt.to_string())],
+                    UsageStats::default(),
+                    0,
+                ),
+            };
         let mut fallback_call_counter = 0u32;
         let mut repair_retry_count = 0u32;
         let mut last_assistant_text: Option<String> = None;
         let mut loop_detector = ToolCallLoopDetector::new(3, 20);
+        // Bounds for prompt-fallback tool results injected into the conversation.
+        let truncation_profile = self.effective_truncation_profile();
         // Track whether any tool has been successfully executed in this loop.
         // When true and this is a sub-agent, a text-only response is treated as
         // a final summary rather than a repair-worthy narration.
@@ -1028,19 +1177,19 @@ impl OrchestratorService {
                 };
             }
 
-            // Wait while paused (sleep-poll until unpaused or cancelled)
-            while self.is_paused() {
-                if self.cancellation_token.is_cancelled() {
-                    emit_usage(&tx, &total_usage).await;
-                    return ExecutionResult {
-                        response: None,
-                        usage: total_usage,
-                        iterations,
-                        success: false,
-                        error: Some("Execution cancelled".to_string()),
-                    };
-                }
-                tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            // Wait while paused (checkpoint, then sleep-poll until unpaused or cancelled)
+            if !self
+                .wait_while_paused(&messages, iterations, &total_usage)
+                .await
+            {
+                emit_usage(&tx, &total_usage).await;
+                return ExecutionResult {
+                    response: None,
+                    usage: total_usage,
+                    iterations,
+                    success: false,
+                    error: Some("Execution cancelled".to_string()),
+                };
             }
 
             if iterations >= iteration_budget.hard_limit {
@@ -1130,8 +1279,12 @@ impl OrchestratorService {
             let max_retries: u32 = 10;
             let max_delay_secs: u64 = 60;
             let response = 'retry_loop: {
-                let mut last_err = None;
+                // A capability mismatch is not retryable; report it like an exhausted retry.
+                let mut last_err = self.ensure_request_supported(&messages, api_tools).err();
                 for attempt in 0..=max_retries {
+                    if last_err.is_some() {
+                        break;
+                    }
                     let result = if self.config.streaming {
                         tokio::select! {
                             r = self.provider.stream_message(
@@ -1863,11 +2016,16 @@ impl OrchestratorService {
                                 false,
                             ));
                         } else {
-                            tool_results.push(format_tool_result(
+                            tool_results.push(format_tool_result_with_limit(
                                 &effective_tool_name,
                                 &tool_id,
                                 &context_tool_output,
                                 result.is_error(),
+                                ResultLimit::Chars(
+                                    truncation_profile
+                                        .limits_for(&effective_tool_name)
+                                        .max_chars,
+                                ),
                             ));
                         }
 
@@ -1971,11 +2129,14 @@ impl OrchestratorService {
                                 false,
                             ));
                         } else {
-                            tool_results.push(format_tool_result(
+                            tool_results.push(format_tool_result_with_limit(
                                 effective_tool_name,
                                 tool_id,
                                 &context_tool_output,
                                 result.is_error(),
+                                ResultLimit::Chars(
+                                    truncation_profile.limits_for(effective_tool_name).max_chars,
+                                ),
                             ));
                         }
 
@@ -2373,6 +2534,7 @@ impl OrchestratorService {
             knowledge_collection_filter: self.tool_executor.get_knowledge_collection_filter(),
             knowledge_document_filter: self.tool_executor.get_knowledge_document_filter(),
             knowledge_awareness_snapshot: self.knowledge_awareness_section.clone(),
+            bash_env_allowlist: self.tool_executor.get_bash_env_allowlist(),
             shared_analytics_tx: self.analytics_tx.clone(),
             shared_analytics_cost_calculator: self.analytics_cost_calculator.clone(),
             shared_analytics_attribution: self.analytics_attribution.clone(),
@@ -2413,6 +2575,28 @@ impl OrchestratorService {
             *lang = Some(detect_language(&user_message).to_string());
         }
 
+        // Fail fast when the provider cannot serve the configured model
+        // (e.g. an Ollama model that has not been pulled).
+        if let Err(e) = self.provider.preflight().await {
+            let code = match e {
+                crate::services::llm::LlmError::ModelNotFound { .. } => "model_not_found",
+                _ => "provider_preflight_failed",
+            };
+            let _ = tx
+                .send(UnifiedStreamEvent::Error {
+                    message: e.to_string(),
+                    code: Some(code.to_string()),
+                })
+                .await;
+            return ExecutionResult {
+                response: None,
+                usage: UsageStats::default(),
+                iterations: 0,
+                success: false,
+                error: Some(e.to_string()),
+            };
+        }
+
         let tools = get_tool_definitions_from_registry();
         let reliability = self.provider.tool_call_reliability();
         // For None reliability (Ollama), don't pass tools to API at all
@@ -2428,6 +2612,8 @@ impl OrchestratorService {
         let mut last_assistant_text: Option<String> = None;
         let mut loop_detector = ToolCallLoopDetector::new(3, 20);
         let codebase_index_status = self.current_codebase_index_status();
+        // Bounds for tool output injected into the LLM messages vec.
+        let truncation_profile = self.effective_truncation_profile();
         // Track whether any tool has been successfully executed in this loop.
         // Used to suppress repair hints when the model produces a final summary.
         let mut has_executed_tools = false;
@@ -2514,13 +2700,14 @@ impl OrchestratorService {
             .map(|s| s.trim().to_string())
             .filter(|s| !s.is_empty())
             .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
-        let hook_ctx = crate::services::orchestrator::hooks::HookContext {
+        let mut hook_ctx = crate::services::orchestrator::hooks::HookContext {
             session_id: hook_session_id,
             project_path: self.config.project_root.clone(),
             provider_name: self.provider.name().to_string(),
             model_name: self.config.provider.model.clone(),
             execution_id: self.config.analysis_session_id.clone(),
             task_type: self.config.task_type.clone(),
+            history: Default::default(),
         };
 
         // Hook: on_session_start
@@ -2561,7 +2748,7 @@ impl OrchestratorService {
         }
 
         // Populate knowledge context from RAG pipeline (if configured)
-        self.populate_knowledge_context(&user_message_for_knowledge)
+        self.populate_knowledge_context(&user_message_for_knowledge, &tx)
             .await;
 
         loop {
@@ -2577,19 +2764,19 @@ impl OrchestratorService {
                 };
             }
 
-            // Wait while paused (sleep-poll until unpaused or cancelled)
-            while self.is_paused() {
-                if self.cancellation_token.is_cancelled() {
-                    emit_usage(&tx, &total_usage).await;
-                    return ExecutionResult {
-                        response: None,
-                        usage: total_usage,
-                        iterations,
-                        success: false,
-                        error: Some("Execution cancelled".to_string()),
-                    };
-                }
-                tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            // Wait while paused (checkpoint, then sleep-poll until unpaused or cancelled)
+            if !self
+                .wait_while_paused(&messages, iterations, &total_usage)
+                .await
+            {
+                emit_usage(&tx, &total_usage).await;
+                return ExecutionResult {
+                    response: None,
+                    usage: total_usage,
+                    iterations,
+                    success: false,
+                    error: Some("Execution cancelled".to_string()),
+                };
             }
 
             if iterations >= iteration_budget.hard_limit {
@@ -2643,6 +2830,7 @@ impl OrchestratorService {
             }
 
             iterations += 1;
+            hook_ctx.history.iteration = iterations;
 
             // Update Layer 2 session memory before each LLM call.
             // Accumulates file reads from the tool executor and key findings
@@ -3007,6 +3195,15 @@ impl OrchestratorService {
                             result.set_message(format!("{}\n\n{}", existing, injected));
                         }
 
+                        // Hook: on_tool_result — transform the full result before re-injection
+                        result = self
+                            .hooks
+                            .fire_on_tool_result(&hook_ctx, &effective_tool_name, result)
+                            .await;
+                        hook_ctx
+                            .history
+                            .record_tool_call(effective_tool_name.to_string(), result.is_success());
+
                         // Emit tool result event after guardrail mutations
                         emit_tool_result_event(&tx, tc_id.clone(), &result).await;
 
@@ -3021,9 +3218,10 @@ impl OrchestratorService {
                                 false,
                             ));
                         } else {
-                            let context_content = truncate_tool_output_for_context(
+                            let context_content = truncate_tool_output_with_profile(
                                 &effective_tool_name,
                                 &result.to_content(),
+                                &truncation_profile,
                             );
                             if let Some((mime, b64)) = &result.image_data {
                                 if self.provider.supports_multimodal() {
@@ -3194,6 +3392,25 @@ impl OrchestratorService {
                             }
                         }
 
+                        // Checkpoint before a destructive tool if the policy asks for one
+                        if let Err(e) = self
+                            .apply_event_actions(
+                                Some(CheckpointPolicyEvent::BeforeTool {
+                                    tool_name: effective_tool_name,
+                                }),
+                                EventActions::none(),
+                                &hook_ctx.session_id,
+                                &mut event_actions_state,
+                                &tx,
+                            )
+                            .await
+                        {
+                            eprintln!(
+                                "[event-actions] Failed to checkpoint before {}: {}",
+                                effective_tool_name, e
+                            );
+                        }
+
                         let (mut result, nested_usage, nested_iterations) = self
                             .execute_tool_with_usage(
                                 &hook_ctx.session_id,
@@ -3227,21 +3444,37 @@ impl OrchestratorService {
                             result.set_message(format!("{}\n\n{}", existing, injected));
                         }
 
+                        // Hook: on_tool_result — transform the full result before re-injection
+                        result = self
+                            .hooks
+                            .fire_on_tool_result(&hook_ctx, effective_tool_name, result)
+                            .await;
+                        hook_ctx
+                            .history
+                            .record_tool_call(effective_tool_name.to_string(), result.is_success());
+
                         // Emit tool result event (always for frontend display)
                         emit_tool_result_event(&tx, tc_id.clone(), &result).await;
 
                         // Apply EventActions if the tool declared any side effects.
                         if let Some(ref actions) = result.event_actions {
                             if actions.has_actions() {
-                                let apply_result = crate::services::orchestrator::event_actions_applicator::apply_actions(
-                                    actions,
-                                    &mut event_actions_state,
-                                    None,
-                                    &self.config.project_root.to_string_lossy(),
-                                    &hook_ctx.session_id,
-                                    &[],
-                                    &tx,
-                                ).await;
+                                let gate = actions.quality_gate_result.clone();
+                                let gate_event = gate.as_ref().map(|gate| {
+                                    CheckpointPolicyEvent::GateEvaluated {
+                                        gate_name: &gate.gate_name,
+                                        passed: gate.passed,
+                                    }
+                                });
+                                let apply_result = self
+                                    .apply_event_actions(
+                                        gate_event,
+                                        actions.clone(),
+                                        &hook_ctx.session_id,
+                                        &mut event_actions_state,
+                                        &tx,
+                                    )
+                                    .await;
                                 match apply_result {
                                     Ok(ref action_outcome) => {
                                         if let Some(ref target_agent) =
@@ -3277,9 +3510,10 @@ impl OrchestratorService {
                                 false,
                             ));
                         } else {
-                            let context_content = truncate_tool_output_for_context(
+                            let context_content = truncate_tool_output_with_profile(
                                 effective_tool_name,
                                 &result.to_content(),
+                                &truncation_profile,
                             );
                             if let Some((mime, b64)) = &result.image_data {
                                 if self.provider.supports_multimodal() {
@@ -3529,6 +3763,15 @@ impl OrchestratorService {
                             result.set_message(format!("{}\n\n{}", existing, injected));
                         }
 
+                        // Hook: on_tool_result — transform the full result before re-injection
+                        result = self
+                            .hooks
+                            .fire_on_tool_result(&hook_ctx, &effective_tool_name, result)
+                            .await;
+                        hook_ctx
+                            .history
+                            .record_tool_call(effective_tool_name.to_string(), result.is_success());
+
                         emit_tool_result_event(&tx, tool_id.clone(), &result).await;
 
                         if result.is_dedup {
@@ -3542,9 +3785,10 @@ impl OrchestratorService {
                                 false,
                             ));
                         } else {
-                            let context_content = truncate_tool_output_for_context(
+                            let context_content = truncate_tool_output_with_profile(
                                 &effective_tool_name,
                                 &result.to_content(),
+                                &truncation_profile,
                             );
                             tool_results.push(format_tool_result(
                                 &effective_tool_name,
@@ -3668,6 +3912,25 @@ impl OrchestratorService {
                             }
                         }
 
+                        // Checkpoint before a destructive tool if the policy asks for one
+                        if let Err(e) = self
+                            .apply_event_actions(
+                                Some(CheckpointPolicyEvent::BeforeTool {
+                                    tool_name: effective_tool_name,
+                                }),
+                                EventActions::none(),
+                                &hook_ctx.session_id,
+                                &mut event_actions_state,
+                                &tx,
+                            )
+                            .await
+                        {
+                            eprintln!(
+                                "[event-actions] Failed to checkpoint before {}: {}",
+                                effective_tool_name, e
+                            );
+                        }
+
                         let (mut result, nested_usage, nested_iterations) = self
                             .execute_tool_with_usage(
                                 &hook_ctx.session_id,
@@ -3701,16 +3964,36 @@ impl OrchestratorService {
                             result.set_message(format!("{}\n\n{}", existing, injected));
                         }
 
+                        // Hook: on_tool_result — transform the full result before re-injection
+                        result = self
+                            .hooks
+                            .fire_on_tool_result(&hook_ctx, effective_tool_name, result)
+                            .await;
+                        hook_ctx
+                            .history
+                            .record_tool_call(effective_tool_name.to_string(), result.is_success());
+
                         emit_tool_result_event(&tx, tool_id.clone(), &result).await;
 
                         // Apply EventActions if the tool declared any side effects
                         if let Some(ref actions) = result.event_actions {
                             if actions.has_actions() {
-                                let apply_result = crate::services::orchestrator::event_actions_applicator::apply_actions(
-                                    actions, &mut event_actions_state, None,
-                                    &self.config.project_root.to_string_lossy(),
-                                    &hook_ctx.session_id, &[], &tx,
-                                ).await;
+                                let gate = actions.quality_gate_result.clone();
+                                let gate_event = gate.as_ref().map(|gate| {
+                                    CheckpointPolicyEvent::GateEvaluated {
+                                        gate_name: &gate.gate_name,
+                                        passed: gate.passed,
+                                    }
+                                });
+                                let apply_result = self
+                                    .apply_event_actions(
+                                        gate_event,
+                                        actions.clone(),
+                                        &hook_ctx.session_id,
+                                        &mut event_actions_state,
+                                        &tx,
+                                    )
+                                    .await;
                                 match apply_result {
                                     Ok(ref action_outcome) => {
                                         if let Some(ref target_agent) =
@@ -3743,9 +4026,10 @@ impl OrchestratorService {
                                 false,
                             ));
                         } else {
-                            let context_content = truncate_tool_output_for_context(
+                            let context_content = truncate_tool_output_with_profile(
                                 effective_tool_name,
                                 &result.to_content(),
+                                &truncation_profile,
                             );
                             tool_results.push(format_tool_result(
                                 effective_tool_name,
@@ -4210,7 +4494,7 @@ impl OrchestratorService {
         tx: &mpsc::Sender<UnifiedStreamEvent>,
     ) -> Option<String> {
         let excluded_roots = analysis_excluded_roots_for_message(message);
-        let inventory = build_file_inventory(&self.config.project_root, &excluded_roots).ok()?;
+        let inventory = self.build_file_inventory_cached(&excluded_roots).ok()?;
         if inventory.total_files == 0 {
             return None;
         }
@@ -4770,6 +5054,17 @@ impl OrchestratorService {
         ))
     }
 
+    /// Refuse a request the provider cannot serve (for example images sent to
+    /// a text-only model) before it reaches the provider.
+    fn ensure_request_supported(
+        &self,
+        messages: &[Message],
+        api_tools: &[ToolDefinition],
+    ) -> Result<(), crate::services::llm::LlmError> {
+        self.provider
+            .ensure_capabilities(&required_features(messages, api_tools))
+    }
+
     /// Call the LLM with non-streaming mode.
     ///
     /// `api_tools` are sent to the provider API (empty for prompt-fallback providers).
@@ -4784,6 +5079,7 @@ impl OrchestratorService {
         prompt_tools: &[ToolDefinition],
         request_options: LlmRequestOptions,
     ) -> Result<LlmResponse, crate::services::llm::LlmError> {
+        self.ensure_request_supported(messages, api_tools)?;
         let system = self.effective_system_prompt(prompt_tools, &request_options);
         let max_retries: u32 = 10;
         let max_delay_secs: u64 = 60;
@@ -4849,6 +5145,7 @@ impl OrchestratorService {
         tx: mpsc::Sender<UnifiedStreamEvent>,
         request_options: LlmRequestOptions,
     ) -> Result<LlmResponse, crate::services::llm::LlmError> {
+        self.ensure_request_supported(messages, api_tools)?;
         let system = self.effective_system_prompt(prompt_tools, &request_options);
         let max_retries: u32 = 10;
         let max_delay_secs: u64 = 60;
@@ -5132,7 +5429,8 @@ impl OrchestratorService {
                     &self.config.project_root,
                     target_agent,
                 )
-                .with_initial_state(initial_state),
+                .with_initial_state(initial_state)
+                .with_cancellation(core_token_from_tokio(&self.cancellation_token)),
             )
         };
 
@@ -5295,6 +5593,7 @@ mod skill_tool_policy_tests {
         crate::services::skills::model::SkillMatch {
             score: 1.0,
             match_reason: crate::services::skills::model::MatchReason::UserForced,
+            explanation: None,
             skill: crate::services::skills::model::SkillSummary {
                 id: id.to_string(),
                 name: id.to_string(),
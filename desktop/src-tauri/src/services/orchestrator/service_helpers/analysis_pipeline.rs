@@ -156,7 +156,7 @@ impl OrchestratorService {
         };
 
         let excluded_roots = analysis_excluded_roots_for_message(&message);
-        let inventory = match build_file_inventory(&self.config.project_root, &excluded_roots) {
+        let inventory = match self.build_file_inventory_cached(&excluded_roots) {
             Ok(inv) => inv,
             Err(err) => {
                 let _ = tx
@@ -2221,6 +2221,10 @@ impl OrchestratorService {
             let phase_agent =
                 OrchestratorService::new_sub_agent(phase_config, self.cancellation_token.clone());
 
+            // The phase policy only picks a reasoning level when the user has not
+            // configured a thinking budget or reasoning effort themselves
+            let user_reasoning_configured = self.config.provider.thinking_budget.is_some()
+                || self.config.provider.reasoning_effort.is_some();
             let request_options = LlmRequestOptions {
                 tool_call_mode: if enforce_quota_gate && attempt <= policy.force_tool_mode_attempts
                 {
@@ -2230,7 +2234,9 @@ impl OrchestratorService {
                 },
                 fallback_tool_format_mode: FallbackToolFormatMode::Strict,
                 temperature_override: Some(policy.temperature_override),
-                reasoning_effort_override: None,
+                reasoning_effort_override: (!user_reasoning_configured)
+                    .then(|| policy.reasoning_effort.to_string()),
+                thinking_budget_override: None,
                 analysis_phase: Some(phase_id.clone()),
             };
             let force_prompt_fallback = !self.provider.supports_tools();
@@ -2247,6 +2253,7 @@ impl OrchestratorService {
                         sub_tx,
                         request_options,
                         force_prompt_fallback,
+                        None,
                     )
                     .await;
                 let _ = result_tx.send(result);
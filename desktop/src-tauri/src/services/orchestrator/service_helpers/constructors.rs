@@ -1,4 +1,9 @@
 use super::*;
+use crate::services::core::event_actions::EventActions;
+use crate::services::orchestrator::checkpoint_policy::{
+    CheckpointPolicyConfig, CheckpointPolicyEvent, CHECKPOINT_POLICY_SETTING,
+};
+use crate::services::tools::impls::bash::{parse_bash_env_allowlist, BASH_ENV_ALLOWLIST_SETTING};
 use crate::services::tools::task_spawner::MAX_SUB_AGENT_DEPTH;
 
 // ── Explore auto-routing helpers ────────────────────────────────────────
@@ -311,6 +316,9 @@ impl TaskSpawner for OrchestratorTaskSpawner {
             self.knowledge_document_filter.clone(),
         );
         sub_agent.knowledge_awareness_section = self.knowledge_awareness_snapshot.clone();
+        sub_agent
+            .tool_executor
+            .set_bash_env_allowlist(self.bash_env_allowlist.clone());
 
         let result = sub_agent.execute_story(&effective_prompt, &tools, tx).await;
 
@@ -476,6 +484,74 @@ fn build_subagent_prompt(
     prompt
 }
 
+/// Session metadata key holding a serialized [`PauseCheckpoint`].
+pub(super) const PAUSE_CHECKPOINT_METADATA_KEY: &str = "pause_checkpoint";
+
+/// Record `checkpoint` as the current story's progress and mark the
+/// session paused.
+pub(super) fn apply_pause_checkpoint(session: &mut ExecutionSession, checkpoint: &PauseCheckpoint) {
+    if let Some(story) = session.stories.get_mut(session.current_story_index) {
+        session.total_input_tokens = session
+            .total_input_tokens
+            .saturating_sub(story.input_tokens)
+            + checkpoint.usage.input_tokens;
+        session.total_output_tokens = session
+            .total_output_tokens
+            .saturating_sub(story.output_tokens)
+            + checkpoint.usage.output_tokens;
+        story.input_tokens = checkpoint.usage.input_tokens;
+        story.output_tokens = checkpoint.usage.output_tokens;
+        story.iterations = checkpoint.iterations;
+    }
+    if let Ok(json) = serde_json::to_string(checkpoint) {
+        session
+            .metadata
+            .insert(PAUSE_CHECKPOINT_METADATA_KEY.to_string(), json);
+    }
+    session.pause();
+}
+
+/// Remove a persisted pause checkpoint from the session.
+///
+/// The current story's partial usage is taken back out of the session
+/// totals because the resumed story reports it again in its result.
+pub(super) fn take_pause_checkpoint(session: &mut ExecutionSession) -> Option<PauseCheckpoint> {
+    let raw = session.metadata.remove(PAUSE_CHECKPOINT_METADATA_KEY)?;
+    let checkpoint: PauseCheckpoint = serde_json::from_str(&raw).ok()?;
+    if let Some(story) = session.stories.get_mut(session.current_story_index) {
+        session.total_input_tokens = session
+            .total_input_tokens
+            .saturating_sub(story.input_tokens);
+        session.total_output_tokens = session
+            .total_output_tokens
+            .saturating_sub(story.output_tokens);
+        story.input_tokens = 0;
+        story.output_tokens = 0;
+    }
+    Some(checkpoint)
+}
+
+/// Clears the session that pause checkpoints are persisted onto when
+/// `execute_session` returns, on every exit path.
+struct CheckpointSessionGuard<'a>(&'a Mutex<Option<String>>);
+
+impl<'a> CheckpointSessionGuard<'a> {
+    fn set(slot: &'a Mutex<Option<String>>, session_id: &str) -> Self {
+        if let Ok(mut current) = slot.lock() {
+            *current = Some(session_id.to_string());
+        }
+        Self(slot)
+    }
+}
+
+impl Drop for CheckpointSessionGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut current) = self.0.lock() {
+            *current = None;
+        }
+    }
+}
+
 impl OrchestratorService {
     /// Create a new orchestrator service
     pub fn new(config: OrchestratorConfig) -> Self {
@@ -502,6 +578,9 @@ impl OrchestratorService {
             compactor,
             cancellation_token,
             paused: Arc::new(AtomicBool::new(false)),
+            pause_checkpoint: Mutex::new(None),
+            checkpoint_session_id: Mutex::new(None),
+            checkpoint_policy: Mutex::new(None),
             db_pool: None,
             active_sessions: Arc::new(RwLock::new(HashMap::new())),
             analysis_store: AnalysisRunStore::new(analysis_artifacts_root),
@@ -522,6 +601,7 @@ impl OrchestratorService {
             plugin_instructions: None,
             plugin_skills: None,
             plugin_commands: None,
+            truncation_profile: None,
         }
     }
 
@@ -582,6 +662,9 @@ impl OrchestratorService {
             compactor,
             cancellation_token,
             paused: Arc::new(AtomicBool::new(false)),
+            pause_checkpoint: Mutex::new(None),
+            checkpoint_session_id: Mutex::new(None),
+            checkpoint_policy: Mutex::new(None),
             db_pool: None,
             active_sessions: Arc::new(RwLock::new(HashMap::new())),
             analysis_store: AnalysisRunStore::new(analysis_artifacts_root),
@@ -602,6 +685,7 @@ impl OrchestratorService {
             plugin_instructions: None,
             plugin_skills: None,
             plugin_commands: None,
+            truncation_profile: None,
         }
     }
 
@@ -688,6 +772,9 @@ impl OrchestratorService {
             compactor,
             cancellation_token,
             paused: shared_paused.unwrap_or_else(|| Arc::new(AtomicBool::new(false))),
+            pause_checkpoint: Mutex::new(None),
+            checkpoint_session_id: Mutex::new(None),
+            checkpoint_policy: Mutex::new(None),
             db_pool: None,
             active_sessions: Arc::new(RwLock::new(HashMap::new())),
             analysis_store: AnalysisRunStore::new(analysis_artifacts_root),
@@ -708,9 +795,25 @@ impl OrchestratorService {
             plugin_instructions: plugin_instructions_snapshot,
             plugin_skills: plugin_skills_snapshot,
             plugin_commands: plugin_commands_snapshot,
+            truncation_profile: None,
         }
     }
 
+    /// Override the tool-result truncation profile used when injecting tool
+    /// output into the LLM context. Event content sent to the frontend is unaffected.
+    pub fn with_truncation_profile(mut self, profile: TruncationProfile) -> Self {
+        self.truncation_profile = Some(profile);
+        self
+    }
+
+    /// Tool-result truncation profile for this execution context: the explicit
+    /// override if set, otherwise one sized for the provider's context window.
+    pub fn effective_truncation_profile(&self) -> TruncationProfile {
+        self.truncation_profile.unwrap_or_else(|| {
+            TruncationProfile::for_context_window(self.provider.context_window())
+        })
+    }
+
     /// Wire a permission gate for tool execution approval.
     /// Also wires the gate to the tool executor so all tool calls go through approval.
     pub fn with_permission_gate(
@@ -1020,8 +1123,13 @@ impl OrchestratorService {
     ///
     /// This is called at the beginning of execution (once per session/message).
     /// The cached block is then injected into every system prompt during the
-    /// agentic loop without re-querying the knowledge base.
-    pub(super) async fn populate_knowledge_context(&self, user_query: &str) {
+    /// agentic loop without re-querying the knowledge base. What was injected
+    /// (and what was dropped) is reported as a `KnowledgeContextInjected` event.
+    pub(super) async fn populate_knowledge_context(
+        &self,
+        user_query: &str,
+        tx: &mpsc::Sender<UnifiedStreamEvent>,
+    ) {
         let provider = match &self.knowledge_context {
             Some(p) => p,
             None => return,
@@ -1041,16 +1149,38 @@ impl OrchestratorService {
         });
 
         match provider
-            .query_for_context(&project_id, user_query, &self.knowledge_context_config)
+            .query_for_context_with_report(&project_id, user_query, &self.knowledge_context_config)
             .await
         {
-            Ok(chunks) => {
-                if !chunks.is_empty() {
+            Ok(selection) => {
+                if !selection.chunks.is_empty() {
                     let block =
-                        crate::services::knowledge::context_provider::KnowledgeContextProvider::format_context_block(&chunks);
+                        crate::services::knowledge::context_provider::KnowledgeContextProvider::format_context_block(&selection.chunks);
                     let mut cached = self.cached_knowledge_block.lock().unwrap();
                     *cached = Some(block);
                 }
+                let entries = selection
+                    .chunks
+                    .iter()
+                    .zip(&selection.chunk_tokens)
+                    .map(
+                        |(chunk, tokens)| plan_cascade_core::streaming::KnowledgeContextEntry {
+                            source_document: chunk.source_document.clone(),
+                            collection_name: chunk.collection_name.clone(),
+                            relevance_score: chunk.relevance_score,
+                            tokens: *tokens as u32,
+                        },
+                    )
+                    .collect();
+                let _ = tx
+                    .send(UnifiedStreamEvent::KnowledgeContextInjected {
+                        chunks: entries,
+                        total_tokens: selection.injected_tokens as u32,
+                        dropped_below_threshold: selection.below_threshold,
+                        dropped_over_budget: selection.over_budget,
+                        dropped_over_chunk_limit: selection.over_chunk_limit,
+                    })
+                    .await;
             }
             Err(e) => {
                 eprintln!("[knowledge] Failed to query knowledge context: {}", e);
@@ -1082,7 +1212,8 @@ impl OrchestratorService {
         self
     }
 
-    /// Set the database pool for session persistence.
+    /// Set the database pool for session persistence, and apply the Bash
+    /// environment allowlist stored in settings.
     ///
     /// Indexing is no longer started here; use `IndexManager::ensure_indexed()`
     /// instead.
@@ -1093,6 +1224,22 @@ impl OrchestratorService {
         let store = Arc::new(IndexStore::new(pool.clone()));
         // Wire the index store to the tool executor so CodebaseSearch works
         self.tool_executor.set_index_store(Arc::clone(&store));
+        // Apply the user's Bash environment allowlist
+        let allowlist_setting = crate::storage::Database::from_pool(pool.clone())
+            .get_setting(BASH_ENV_ALLOWLIST_SETTING)
+            .ok()
+            .flatten();
+        self.tool_executor
+            .set_bash_env_allowlist(parse_bash_env_allowlist(allowlist_setting.as_deref()));
+        // Enable automatic timeline checkpoints when the user configured a policy
+        let policy_setting = crate::storage::Database::from_pool(pool.clone())
+            .get_setting(CHECKPOINT_POLICY_SETTING)
+            .ok()
+            .flatten();
+        self.checkpoint_policy = Mutex::new(
+            CheckpointPolicyConfig::from_setting(policy_setting.as_deref())
+                .map(CheckpointPolicy::new),
+        );
         self.index_store = Some(store);
         self.db_pool = Some(pool);
         self
@@ -1166,6 +1313,15 @@ impl OrchestratorService {
         Ok(())
     }
 
+    /// Use `token` for cancellation instead of the orchestrator's own token,
+    /// so cancelling it (or any token it derives from) stops in-flight
+    /// provider calls and tools.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.tool_executor.set_cancellation_token(token.clone());
+        self.cancellation_token = token;
+        self
+    }
+
     /// Get the cancellation token for external cancellation
     pub fn cancellation_token(&self) -> CancellationToken {
         self.cancellation_token.clone()
@@ -1196,6 +1352,106 @@ impl OrchestratorService {
         self.paused.load(Ordering::SeqCst)
     }
 
+    /// Checkpoint of the agentic loop while it is parked on a pause.
+    pub fn pause_checkpoint(&self) -> Option<PauseCheckpoint> {
+        self.pause_checkpoint
+            .lock()
+            .ok()
+            .and_then(|checkpoint| checkpoint.clone())
+    }
+
+    /// Progress for a paused execution that has no persisted session
+    /// (chat executions), built from the pause checkpoint.
+    pub fn paused_progress(&self, session_id: &str) -> Option<ExecutionProgress> {
+        let checkpoint = self.pause_checkpoint()?;
+        Some(ExecutionProgress {
+            session_id: session_id.to_string(),
+            current_story: 0,
+            total_stories: 0,
+            story_id: None,
+            story_title: None,
+            percentage: 0.0,
+            status: ExecutionStatus::Paused,
+            total_input_tokens: checkpoint.usage.input_tokens,
+            total_output_tokens: checkpoint.usage.output_tokens,
+            current_iteration: checkpoint.iterations,
+            estimated_remaining_secs: None,
+        })
+    }
+
+    /// Park the agentic loop while paused.
+    ///
+    /// Called between iterations, so in-flight LLM and tool calls have
+    /// already completed. Records a checkpoint of the loop state (persisted
+    /// to the session being executed, if any) and clears it on resume.
+    /// Returns `false` if execution was cancelled while paused.
+    pub(super) async fn wait_while_paused(
+        &self,
+        messages: &[Message],
+        iterations: u32,
+        usage: &UsageStats,
+    ) -> bool {
+        if !self.is_paused() {
+            return true;
+        }
+
+        let checkpoint = PauseCheckpoint {
+            messages: messages.to_vec(),
+            iterations,
+            usage: usage.clone(),
+            paused_at: chrono::Utc::now().timestamp(),
+        };
+        self.persist_pause_checkpoint(Some(&checkpoint)).await;
+        if let Ok(mut slot) = self.pause_checkpoint.lock() {
+            *slot = Some(checkpoint);
+        }
+
+        let mut cancelled = false;
+        while self.is_paused() {
+            if self.cancellation_token.is_cancelled() {
+                cancelled = true;
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        }
+
+        if let Ok(mut slot) = self.pause_checkpoint.lock() {
+            *slot = None;
+        }
+        if !cancelled {
+            self.persist_pause_checkpoint(None).await;
+        }
+        !cancelled
+    }
+
+    /// Mirror a pause checkpoint onto the session being executed so
+    /// `get_progress` reports the paused state and accumulated usage.
+    async fn persist_pause_checkpoint(&self, checkpoint: Option<&PauseCheckpoint>) {
+        let session_id = match self.checkpoint_session_id.lock() {
+            Ok(session_id) => session_id.clone(),
+            Err(_) => None,
+        };
+        let Some(session_id) = session_id else {
+            return;
+        };
+        let mut session = match self.load_session(&session_id).await {
+            Ok(Some(session)) => session,
+            _ => return,
+        };
+
+        match checkpoint {
+            Some(checkpoint) => apply_pause_checkpoint(&mut session, checkpoint),
+            None => {
+                session.metadata.remove(PAUSE_CHECKPOINT_METADATA_KEY);
+                session.status = ExecutionStatus::Running;
+                session.updated_at = chrono::Utc::now().timestamp();
+            }
+        }
+        if let Err(e) = self.save_session(&session).await {
+            eprintln!("Failed to save pause checkpoint: {}", e);
+        }
+    }
+
     /// Save a session to the database
     pub async fn save_session(&self, session: &ExecutionSession) -> AppResult<()> {
         let pool = self
@@ -1505,6 +1761,12 @@ impl OrchestratorService {
     ) -> SessionExecutionResult {
         let tools = get_tool_definitions_from_registry();
 
+        let _checkpoint_session =
+            CheckpointSessionGuard::set(&self.checkpoint_session_id, &session.id);
+        // A session persisted while paused continues its current story from
+        // the checkpoint instead of starting the story over.
+        let mut resume_checkpoint = take_pause_checkpoint(session);
+
         session.start();
         if let Err(e) = self.save_session(session).await {
             eprintln!("Failed to save session start: {}", e);
@@ -1575,7 +1837,16 @@ impl OrchestratorService {
                 .await;
 
             // Execute the story
-            let result = self.execute_story(&story_prompt, &tools, tx.clone()).await;
+            let result = self
+                .execute_story_with_request_options(
+                    &story_prompt,
+                    &tools,
+                    tx.clone(),
+                    LlmRequestOptions::default(),
+                    false,
+                    resume_checkpoint.take(),
+                )
+                .await;
 
             // Update story state and session tokens
             {
@@ -1605,6 +1876,23 @@ impl OrchestratorService {
                         error: story_error,
                     })
                     .await;
+
+                // Checkpoint the completed story if the policy asks for one
+                if let Err(e) = self
+                    .apply_event_actions(
+                        Some(CheckpointPolicyEvent::PhaseCompleted {
+                            phase: &story_id,
+                            success: story_success,
+                        }),
+                        EventActions::none(),
+                        &session_id,
+                        &mut HashMap::new(),
+                        &tx,
+                    )
+                    .await
+                {
+                    eprintln!("Failed to checkpoint story {}: {}", story_id, e);
+                }
             } else {
                 // Mark story as failed
                 let error_msg = result
@@ -1702,6 +1990,7 @@ impl OrchestratorService {
             tx,
             LlmRequestOptions::default(),
             false,
+            None,
         )
         .await
     }
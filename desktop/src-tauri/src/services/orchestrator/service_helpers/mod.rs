@@ -21,7 +21,8 @@ use path_utils::*;
 use session_state::*;
 use tool_call_parsing::*;
 
-// The only pub(crate) export from this module
+// Exports visible outside this module
+pub use path_utils::{ToolOutputLimit, TruncationProfile};
 pub(crate) use tool_call_parsing::text_describes_pending_action;
 
 // ── Shared constants (used by 3+ submodules) ──────────────────────────
@@ -147,16 +148,20 @@ fn track_analytics(
         None => return,
     };
 
+    let record = crate::models::analytics::UsageRecord::new(
+        model_name,
+        provider_name,
+        usage.input_tokens as i64,
+        usage.output_tokens as i64,
+    )
+    .with_extended_tokens(
+        usage.thinking_tokens.unwrap_or(0) as i64,
+        usage.cache_read_tokens.unwrap_or(0) as i64,
+        usage.cache_creation_tokens.unwrap_or(0) as i64,
+    );
     let cost = cost_calculator
         .as_ref()
-        .map(|calc| {
-            calc.calculate_cost(
-                provider_name,
-                model_name,
-                usage.input_tokens as i64,
-                usage.output_tokens as i64,
-            )
-        })
+        .map(|calc| calc.calculate_cost_at(&record))
         .unwrap_or(0);
 
     let mut metadata = attribution
@@ -169,14 +174,14 @@ fn track_analytics(
 
     let mut event = crate::models::analytics::AnalyticsUsageEvent {
         event_id: uuid::Uuid::new_v4().to_string(),
-        timestamp_utc: chrono::Utc::now().timestamp(),
+        timestamp_utc: record.timestamp,
         provider: provider_name.to_string(),
         model: model_name.to_string(),
-        input_tokens: usage.input_tokens as i64,
-        output_tokens: usage.output_tokens as i64,
-        thinking_tokens: usage.thinking_tokens.unwrap_or(0) as i64,
-        cache_read_tokens: usage.cache_read_tokens.unwrap_or(0) as i64,
-        cache_write_tokens: usage.cache_creation_tokens.unwrap_or(0) as i64,
+        input_tokens: record.input_tokens,
+        output_tokens: record.output_tokens,
+        thinking_tokens: record.thinking_tokens,
+        cache_read_tokens: record.cache_read_tokens,
+        cache_write_tokens: record.cache_creation_tokens,
         cost_total: cost,
         cost_status: if cost > 0 {
             crate::models::analytics::CostStatus::Estimated
@@ -211,7 +216,7 @@ fn track_analytics(
     ));
 }
 
-fn merge_usage(total: &mut UsageStats, delta: &UsageStats) {
+pub(crate) fn merge_usage(total: &mut UsageStats, delta: &UsageStats) {
     total.input_tokens += delta.input_tokens;
     total.output_tokens += delta.output_tokens;
     if let Some(thinking) = delta.thinking_tokens {
@@ -31,23 +31,128 @@ pub(super) fn tool_output_for_model_context(
     }
 }
 
+/// Line and character bounds applied to one tool's output before it is
+/// injected into the LLM messages vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolOutputLimit {
+    pub max_lines: usize,
+    pub max_chars: usize,
+}
+
+impl ToolOutputLimit {
+    pub const fn new(max_lines: usize, max_chars: usize) -> Self {
+        Self {
+            max_lines,
+            max_chars,
+        }
+    }
+}
+
+/// Per-execution-context truncation limits for regular (non-analysis) tool results.
+///
+/// By default the profile is derived from the active model's context window
+/// (see [`TruncationProfile::for_context_window`]); callers can override it
+/// with `OrchestratorService::with_truncation_profile`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TruncationProfile {
+    /// Limits for Read output.
+    pub read: ToolOutputLimit,
+    /// Limits for Grep output.
+    pub grep: ToolOutputLimit,
+    /// Limits for LS/Glob output.
+    pub listing: ToolOutputLimit,
+    /// Limits for Bash output and any tool without a dedicated entry.
+    pub bash: ToolOutputLimit,
+}
+
+impl TruncationProfile {
+    /// Context windows below this size use the compact profile.
+    pub const COMPACT_CONTEXT_WINDOW: u32 = 64_000;
+    /// Context windows at or above this size use the extended profile.
+    pub const EXTENDED_CONTEXT_WINDOW: u32 = 400_000;
+
+    /// Tight limits for small-context models.
+    pub const fn compact() -> Self {
+        Self {
+            read: ToolOutputLimit::new(REGULAR_READ_MAX_LINES / 2, REGULAR_READ_MAX_CHARS / 2),
+            grep: ToolOutputLimit::new(REGULAR_GREP_MAX_LINES / 2, REGULAR_GREP_MAX_CHARS / 2),
+            listing: ToolOutputLimit::new(REGULAR_LS_MAX_LINES / 2, REGULAR_LS_MAX_CHARS / 2),
+            bash: ToolOutputLimit::new(REGULAR_BASH_MAX_LINES / 2, REGULAR_BASH_MAX_CHARS / 2),
+        }
+    }
+
+    /// The default limits (`REGULAR_*_MAX_*`).
+    pub const fn standard() -> Self {
+        Self {
+            read: ToolOutputLimit::new(REGULAR_READ_MAX_LINES, REGULAR_READ_MAX_CHARS),
+            grep: ToolOutputLimit::new(REGULAR_GREP_MAX_LINES, REGULAR_GREP_MAX_CHARS),
+            listing: ToolOutputLimit::new(REGULAR_LS_MAX_LINES, REGULAR_LS_MAX_CHARS),
+            bash: ToolOutputLimit::new(REGULAR_BASH_MAX_LINES, REGULAR_BASH_MAX_CHARS),
+        }
+    }
+
+    /// Generous limits for very large context windows.
+    pub const fn extended() -> Self {
+        Self {
+            read: ToolOutputLimit::new(REGULAR_READ_MAX_LINES * 4, REGULAR_READ_MAX_CHARS * 4),
+            grep: ToolOutputLimit::new(REGULAR_GREP_MAX_LINES * 4, REGULAR_GREP_MAX_CHARS * 4),
+            listing: ToolOutputLimit::new(REGULAR_LS_MAX_LINES * 4, REGULAR_LS_MAX_CHARS * 4),
+            bash: ToolOutputLimit::new(REGULAR_BASH_MAX_LINES * 4, REGULAR_BASH_MAX_CHARS * 4),
+        }
+    }
+
+    /// Pick a profile sized for the given model context window (in tokens).
+    pub fn for_context_window(context_window: u32) -> Self {
+        if context_window < Self::COMPACT_CONTEXT_WINDOW {
+            Self::compact()
+        } else if context_window >= Self::EXTENDED_CONTEXT_WINDOW {
+            Self::extended()
+        } else {
+            Self::standard()
+        }
+    }
+
+    /// Limits that apply to the named tool.
+    pub fn limits_for(&self, tool_name: &str) -> ToolOutputLimit {
+        match tool_name {
+            "Read" => self.read,
+            "Grep" => self.grep,
+            "LS" | "Glob" => self.listing,
+            _ => self.bash,
+        }
+    }
+}
+
+impl Default for TruncationProfile {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Truncate tool output for the messages vector during regular (non-analysis) execution,
+/// using the standard truncation profile.
+pub(super) fn truncate_tool_output_for_context(tool_name: &str, content: &str) -> String {
+    truncate_tool_output_with_profile(tool_name, content, &TruncationProfile::standard())
+}
+
 /// Truncate tool output for the messages vector during regular (non-analysis) execution.
 ///
 /// This applies bounded truncation so that large tool results do not bloat the LLM
 /// context window. The frontend ToolResult event still receives the full content;
 /// only the messages vec (what the LLM sees) is truncated.
-pub(super) fn truncate_tool_output_for_context(tool_name: &str, content: &str) -> String {
+pub(super) fn truncate_tool_output_with_profile(
+    tool_name: &str,
+    content: &str,
+    profile: &TruncationProfile,
+) -> String {
     if content.is_empty() {
         return String::new();
     }
 
-    let (max_lines, max_chars) = match tool_name {
-        "Read" => (REGULAR_READ_MAX_LINES, REGULAR_READ_MAX_CHARS),
-        "Grep" => (REGULAR_GREP_MAX_LINES, REGULAR_GREP_MAX_CHARS),
-        "LS" | "Glob" => (REGULAR_LS_MAX_LINES, REGULAR_LS_MAX_CHARS),
-        "Bash" => (REGULAR_BASH_MAX_LINES, REGULAR_BASH_MAX_CHARS),
-        _ => (REGULAR_BASH_MAX_LINES, REGULAR_BASH_MAX_CHARS),
-    };
+    let ToolOutputLimit {
+        max_lines,
+        max_chars,
+    } = profile.limits_for(tool_name);
 
     let original_len = content.len();
     let original_line_count = content.lines().count();
@@ -57,6 +162,11 @@ pub(super) fn truncate_tool_output_for_context(tool_name: &str, content: &str) -
         return content.to_string();
     }
 
+    // Keep JSON results parseable by eliding array elements instead of cutting lines
+    if serde_json::from_str::<serde_json::Value>(content.trim()).is_ok() {
+        return truncate_tool_result(content, ResultLimit::Chars(max_chars));
+    }
+
     // Truncate by line count first
     let mut truncated: String = content
         .lines()
@@ -674,6 +674,21 @@ fn test_analysis_phase_min_workers_before_early_exit() {
     );
 }
 
+#[test]
+fn test_analysis_phase_reasoning_effort_tracks_phase_depth() {
+    let effort = |phase| AnalysisPhasePolicy::for_phase(phase).reasoning_effort;
+    assert_eq!(effort(AnalysisPhase::StructureDiscovery), "low");
+    assert_eq!(effort(AnalysisPhase::ArchitectureTrace), "high");
+    assert_eq!(effort(AnalysisPhase::ConsistencyCheck), "medium");
+    for phase in [
+        AnalysisPhase::StructureDiscovery,
+        AnalysisPhase::ArchitectureTrace,
+        AnalysisPhase::ConsistencyCheck,
+    ] {
+        assert!(crate::services::llm::thinking_budget_for_effort(effort(phase)).is_some());
+    }
+}
+
 #[test]
 fn test_evaluate_analysis_quota_reports_missing_requirements() {
     let capture = PhaseCapture {
@@ -1452,6 +1467,16 @@ fn test_truncate_bash_below_limit_passes_through() {
     assert_eq!(result, content, "Content below Bash limit should pass through unchanged");
 }
 
+#[test]
+fn test_truncate_json_output_stays_parseable() {
+    let items: Vec<serde_json::Value> = (0..2000).map(|i| serde_json::json!({"id": i})).collect();
+    let content = serde_json::to_string_pretty(&serde_json::json!({"items": items})).unwrap();
+    let result = truncate_tool_output_for_context("Bash", &content);
+    assert!(result.len() <= REGULAR_BASH_MAX_CHARS, "JSON should be truncated to the char limit");
+    let parsed: serde_json::Value = serde_json::from_str(&result).expect("truncated JSON should parse");
+    assert_eq!(parsed["items"][0]["id"], 0);
+}
+
 #[test]
 fn test_truncate_unknown_tool_uses_bash_defaults() {
     // Unknown tool names should still get truncation (using Bash defaults)
@@ -1498,6 +1523,78 @@ fn test_regular_truncation_constants_exist() {
     assert_eq!(REGULAR_BASH_MAX_CHARS, 8000);
 }
 
+#[test]
+fn test_truncation_profile_for_context_window() {
+    assert_eq!(TruncationProfile::for_context_window(32_000), TruncationProfile::compact());
+    assert_eq!(TruncationProfile::for_context_window(200_000), TruncationProfile::standard());
+    assert_eq!(TruncationProfile::for_context_window(1_000_000), TruncationProfile::extended());
+    assert_eq!(
+        TruncationProfile::standard().limits_for("Read"),
+        ToolOutputLimit::new(REGULAR_READ_MAX_LINES, REGULAR_READ_MAX_CHARS)
+    );
+    assert_eq!(
+        TruncationProfile::standard().limits_for("Glob"),
+        TruncationProfile::standard().listing
+    );
+    assert_eq!(
+        TruncationProfile::standard().limits_for("WebFetch"),
+        TruncationProfile::standard().bash
+    );
+}
+
+#[test]
+fn test_effective_truncation_profile_defaults_to_context_window() {
+    let orchestrator = OrchestratorService::new(test_config());
+    assert_eq!(
+        orchestrator.effective_truncation_profile(),
+        TruncationProfile::for_context_window(orchestrator.provider.context_window())
+    );
+}
+
+#[test]
+fn test_effective_truncation_profile_override() {
+    let profile = TruncationProfile {
+        read: ToolOutputLimit::new(10, 500),
+        ..TruncationProfile::standard()
+    };
+    let orchestrator = OrchestratorService::new(test_config()).with_truncation_profile(profile);
+    assert_eq!(orchestrator.effective_truncation_profile(), profile);
+}
+
+#[tokio::test]
+async fn test_truncation_profile_bounds_injected_content_but_not_event() {
+    let profile = TruncationProfile {
+        read: ToolOutputLimit::new(10, 500),
+        ..TruncationProfile::standard()
+    };
+    let content = (0..100).map(|i| format!("line {}", i)).collect::<Vec<_>>().join("\n");
+    let result = crate::services::tools::executor::ToolResult::ok(content.clone());
+
+    let injected = truncate_tool_output_with_profile("Read", &result.to_content(), &profile);
+    assert!(injected.lines().count() <= 10 + 3, "Injected content should respect the profile line limit");
+    assert!(injected.contains("[truncated for context"));
+    assert!(!injected.contains("line 50"));
+
+    let (tx, mut rx) = mpsc::channel(4);
+    emit_tool_result_event(&tx, "tool-1".to_string(), &result).await;
+    match rx.recv().await {
+        Some(UnifiedStreamEvent::ToolResult { result: Some(full), .. }) => {
+            assert_eq!(full, content, "Frontend event should carry the full content");
+        }
+        other => panic!("expected ToolResult event, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_compact_profile_truncates_below_standard() {
+    let content = (0..150).map(|i| format!("line {}", i)).collect::<Vec<_>>().join("\n");
+    let standard = truncate_tool_output_with_profile("Read", &content, &TruncationProfile::standard());
+    let compact = truncate_tool_output_with_profile("Read", &content, &TruncationProfile::compact());
+    assert_eq!(standard, content, "150 lines fits the standard Read profile");
+    assert!(compact.contains("[truncated for context"));
+    assert!(compact.lines().count() <= TruncationProfile::compact().read.max_lines + 3);
+}
+
 // --- Story-010 (prefix-stable compaction): compact_messages_prefix_stable tests ---
 
 #[test]
@@ -4094,6 +4191,7 @@ fn test_sub_agent_inherits_skills_snapshot() {
     let skills = vec![SkillMatch {
         score: 1.0,
         match_reason: MatchReason::AutoDetected,
+        explanation: None,
         skill: SkillSummary {
             id: "rust-best-practices".to_string(),
             name: "Rust Best Practices".to_string(),
@@ -4235,6 +4333,7 @@ fn test_sub_agent_knowledge_block_capped() {
         knowledge_collection_filter: None,
         knowledge_document_filter: None,
         knowledge_awareness_snapshot: None,
+        bash_env_allowlist: Vec::new(),
         shared_analytics_tx: None,
         shared_analytics_cost_calculator: None,
         shared_analytics_attribution: None,
@@ -4323,3 +4422,248 @@ fn test_sub_agent_empty_snapshots_remain_none() {
     let cached = sub.cached_knowledge_block.lock().unwrap();
     assert!(cached.is_none(), "No knowledge block should be None");
 }
+
+// =============================================================================
+// Pause checkpoint tests
+// =============================================================================
+
+/// Provider that replays scripted responses and requests a pause while its
+/// first call is in flight.
+struct PausingScriptedProvider {
+    config: ProviderConfig,
+    paused: Arc<AtomicBool>,
+    responses: Mutex<VecDeque<LlmResponse>>,
+    calls: std::sync::atomic::AtomicUsize,
+}
+
+impl PausingScriptedProvider {
+    fn next_response(&self) -> crate::services::llm::LlmResult<LlmResponse> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+            self.paused.store(true, Ordering::SeqCst);
+        }
+        Ok(self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("unexpected LLM call"))
+    }
+}
+
+#[async_trait]
+impl LlmProvider for PausingScriptedProvider {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+    fn model(&self) -> &str {
+        "mock"
+    }
+    fn supports_thinking(&self) -> bool {
+        false
+    }
+    fn supports_tools(&self) -> bool {
+        true
+    }
+    async fn send_message(
+        &self,
+        _: Vec<Message>,
+        _: Option<String>,
+        _: Vec<ToolDefinition>,
+        _: LlmRequestOptions,
+    ) -> crate::services::llm::LlmResult<LlmResponse> {
+        self.next_response()
+    }
+    async fn stream_message(
+        &self,
+        _: Vec<Message>,
+        _: Option<String>,
+        _: Vec<ToolDefinition>,
+        _: mpsc::Sender<UnifiedStreamEvent>,
+        _: LlmRequestOptions,
+    ) -> crate::services::llm::LlmResult<LlmResponse> {
+        self.next_response()
+    }
+    async fn health_check(&self) -> crate::services::llm::LlmResult<()> {
+        Ok(())
+    }
+    fn config(&self) -> &ProviderConfig {
+        &self.config
+    }
+}
+
+fn scripted_response(
+    content: Option<&str>,
+    tool_calls: Vec<crate::services::llm::ToolCall>,
+    input_tokens: u32,
+    output_tokens: u32,
+) -> LlmResponse {
+    LlmResponse {
+        content: content.map(str::to_string),
+        thinking: None,
+        stop_reason: if tool_calls.is_empty() {
+            crate::services::llm::StopReason::EndTurn
+        } else {
+            crate::services::llm::StopReason::ToolUse
+        },
+        tool_calls,
+        usage: UsageStats {
+            input_tokens,
+            output_tokens,
+            ..Default::default()
+        },
+        model: "mock".to_string(),
+        search_citations: Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn test_pause_after_first_iteration_persists_checkpoint_and_resumes() {
+    let project = tempfile::TempDir::new().unwrap();
+    let db = crate::storage::database::Database::new_in_memory().unwrap();
+    let config = OrchestratorConfig {
+        project_root: project.path().to_path_buf(),
+        enable_compaction: false,
+        ..test_config()
+    };
+    let mut orchestrator =
+        OrchestratorService::new(config.clone()).with_database(db.pool().clone());
+    let provider = Arc::new(PausingScriptedProvider {
+        config: ProviderConfig::default(),
+        paused: Arc::clone(&orchestrator.paused),
+        responses: Mutex::new(VecDeque::from(vec![
+            scripted_response(
+                None,
+                vec![crate::services::llm::ToolCall {
+                    id: "call_1".to_string(),
+                    name: "LS".to_string(),
+                    arguments: serde_json::json!({ "path": "." }),
+                }],
+                100,
+                20,
+            ),
+            scripted_response(Some("Story implemented."), Vec::new(), 40, 10),
+        ])),
+        calls: std::sync::atomic::AtomicUsize::new(0),
+    });
+    orchestrator.provider = provider.clone();
+    let orchestrator = Arc::new(orchestrator);
+
+    let mut session = ExecutionSession::new(
+        "pause-session",
+        project.path().to_string_lossy(),
+        "mock",
+        "mock",
+    );
+    session.add_story("story-1", "Implement the feature");
+
+    let (tx, mut rx) = mpsc::channel::<UnifiedStreamEvent>(100);
+    tokio::spawn(async move { while rx.recv().await.is_some() {} });
+    let runner = Arc::clone(&orchestrator);
+    let handle = tokio::spawn(async move {
+        let result = runner.execute_session(&mut session, tx, false).await;
+        (result, session)
+    });
+
+    // The pause requested during the first call takes effect once that
+    // call and its tool execution have completed.
+    let mut checkpoint = None;
+    for _ in 0..200 {
+        checkpoint = orchestrator.pause_checkpoint();
+        if checkpoint.is_some() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+    let checkpoint = checkpoint.expect("loop should park on the pause");
+    assert_eq!(checkpoint.iterations, 1);
+    assert_eq!(checkpoint.usage.input_tokens, 100);
+    assert_eq!(checkpoint.usage.output_tokens, 20);
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+    // At least the prompt, the assistant tool call, and the tool result.
+    assert!(checkpoint.messages.len() >= 3);
+
+    let progress = orchestrator
+        .get_progress("pause-session")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(progress.status, ExecutionStatus::Paused);
+    assert_eq!(progress.total_input_tokens, 100);
+    assert_eq!(progress.total_output_tokens, 20);
+    assert_eq!(progress.current_iteration, 1);
+
+    // The checkpoint is persisted, not just held in memory.
+    let reader = OrchestratorService::new(config).with_database(db.pool().clone());
+    let persisted = reader.load_session("pause-session").await.unwrap().unwrap();
+    assert_eq!(persisted.status, ExecutionStatus::Paused);
+    let stored: PauseCheckpoint = serde_json::from_str(
+        persisted
+            .metadata
+            .get(PAUSE_CHECKPOINT_METADATA_KEY)
+            .expect("checkpoint metadata"),
+    )
+    .unwrap();
+    assert_eq!(stored.iterations, 1);
+    assert_eq!(stored.messages.len(), checkpoint.messages.len());
+    drop(reader);
+
+    orchestrator.unpause();
+    let (result, session) = tokio::time::timeout(std::time::Duration::from_secs(10), handle)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(result.success, "{:?}", result.error);
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+    assert!(orchestrator.pause_checkpoint().is_none());
+    // Later pauses outside `execute_session` no longer touch this session.
+    assert!(orchestrator.checkpoint_session_id.lock().unwrap().is_none());
+    // Usage before and after the pause is counted exactly once.
+    assert_eq!(result.usage.input_tokens, 140);
+    assert_eq!(result.usage.output_tokens, 30);
+    assert_eq!(session.total_input_tokens, 140);
+    assert_eq!(session.total_output_tokens, 30);
+    assert_eq!(session.stories[0].iterations, 2);
+
+    let progress = orchestrator
+        .get_progress("pause-session")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(progress.status, ExecutionStatus::Completed);
+    assert_eq!(progress.total_input_tokens, 140);
+    assert_eq!(progress.total_output_tokens, 30);
+}
+
+#[test]
+fn test_take_pause_checkpoint_rolls_back_partial_story_usage() {
+    let mut session = ExecutionSession::new("s", "/tmp", "mock", "mock");
+    session.add_story("story-1", "First");
+    session.add_story("story-2", "Second");
+    session.add_tokens(500, 50);
+    session.current_story_index = 1;
+
+    let checkpoint = PauseCheckpoint {
+        messages: vec![Message::user("Execute story-2")],
+        iterations: 3,
+        usage: UsageStats {
+            input_tokens: 120,
+            output_tokens: 12,
+            ..Default::default()
+        },
+        paused_at: 0,
+    };
+    apply_pause_checkpoint(&mut session, &checkpoint);
+    // Applying the same checkpoint twice does not double count.
+    apply_pause_checkpoint(&mut session, &checkpoint);
+    assert_eq!(session.status, ExecutionStatus::Paused);
+    assert_eq!(session.total_input_tokens, 620);
+    assert_eq!(session.total_output_tokens, 62);
+
+    let taken = take_pause_checkpoint(&mut session).unwrap();
+    assert_eq!(taken.iterations, 3);
+    assert_eq!(taken.usage.input_tokens, 120);
+    assert_eq!(session.total_input_tokens, 500);
+    assert_eq!(session.total_output_tokens, 50);
+    assert!(take_pause_checkpoint(&mut session).is_none());
+}
@@ -0,0 +1,896 @@
+//! Session Bundles
+//!
+//! Packages everything needed to resume a session on another machine into a
+//! single signed zip archive:
+//!
+//! - `transcript.jsonl`: messages and tool history (the session transcript)
+//! - `file_changes.json` + `cas/<hash>`: file-change records and snapshots
+//! - `memory.json`: project- and session-scoped memories
+//! - `index.json`: index metadata of the source workspace
+//! - `manifest.json`: bundle metadata plus the SHA-256 of every entry above
+//! - `signature.json`: Ed25519 signature over `manifest.json`
+//!
+//! Import verifies the signature and every entry hash, rebases workspace
+//! paths onto the target workspace, and reports missing providers or an
+//! unindexed workspace as warnings rather than failing. The signature only
+//! counts when the signing key is trusted: this installation's own key, or
+//! one of the public keys the user added to the trusted-key allowlist.
+//!
+//! The signing key lives in the encrypted keyring store, never in a plain
+//! file.
+
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use rusqlite::params;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::index_store::{EmbeddingMetadata, IndexStore, ProjectIndexSummary};
+use crate::commands::execution_history::{
+    read_session_transcript, upsert_record, write_session_transcript, ExecutionHistoryRecord,
+};
+use crate::services::file_change_tracker::{FileChange, FileChangeTracker};
+use crate::services::memory::store::normalize_memory_session_id;
+use crate::storage::database::Database;
+use crate::storage::keyring::KeyringService;
+use crate::utils::error::{AppError, AppResult};
+
+/// Current bundle format version.
+pub const SESSION_BUNDLE_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const SIGNATURE_ENTRY: &str = "signature.json";
+const TRANSCRIPT_ENTRY: &str = "transcript.jsonl";
+const FILE_CHANGES_ENTRY: &str = "file_changes.json";
+const MEMORY_ENTRY: &str = "memory.json";
+const INDEX_ENTRY: &str = "index.json";
+const CAS_PREFIX: &str = "cas/";
+/// Keyring entry holding this installation's signing key (base64 secret).
+const SIGNING_KEY_KEYRING_ENTRY: &str = "session_bundle_signing_key";
+
+/// Setting holding the JSON array of trusted signer public keys (base64).
+pub const TRUSTED_KEYS_SETTING: &str = "session_bundle_trusted_keys";
+
+/// Bundle metadata, signed as a whole.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionBundleManifest {
+    pub format_version: u32,
+    pub history_id: String,
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Workspace the session ran in; paths under it are rebased on import.
+    #[serde(default)]
+    pub source_workspace: Option<String>,
+    pub created_at: String,
+    #[serde(default)]
+    pub llm_provider: Option<String>,
+    #[serde(default)]
+    pub llm_model: Option<String>,
+    /// SHA-256 (hex) of every other entry in the archive.
+    pub entries: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BundleSignature {
+    algorithm: String,
+    public_key: String,
+    signature: String,
+}
+
+/// A memory row carried in the bundle (embeddings are recomputed on import).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledMemory {
+    pub id: String,
+    pub scope: String,
+    #[serde(default)]
+    pub project_path: Option<String>,
+    #[serde(default)]
+    pub session_id: Option<String>,
+    pub category: String,
+    pub content: String,
+    pub content_hash: String,
+    pub keywords: String,
+    pub importance: f64,
+    #[serde(default)]
+    pub source_session_id: Option<String>,
+    #[serde(default)]
+    pub source_context: Option<String>,
+    pub status: String,
+    pub risk_tier: String,
+}
+
+/// Index metadata of the source workspace.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BundledIndexMetadata {
+    pub summary: ProjectIndexSummary,
+    #[serde(default)]
+    pub embeddings: Vec<EmbeddingMetadata>,
+}
+
+/// Options for [`import_session_bundle`].
+#[derive(Debug, Clone)]
+pub struct SessionBundleImportOptions {
+    /// Workspace on this machine that replaces the source workspace.
+    pub target_workspace: PathBuf,
+    /// Providers with credentials configured on this machine.
+    pub configured_providers: Vec<String>,
+    /// Signer keys whose bundles are accepted. Bundles signed by any other
+    /// key are rejected, even when the signature itself is valid.
+    pub trusted_keys: Vec<VerifyingKey>,
+}
+
+/// Outcome of an import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionBundleImportResult {
+    pub history_id: String,
+    pub session_id: Option<String>,
+    pub workspace_path: String,
+    /// Short fingerprint of the key that signed the bundle.
+    pub signer_fingerprint: String,
+    pub file_changes: usize,
+    pub snapshots: usize,
+    pub memories: usize,
+    /// Number of paths rewritten from the source to the target workspace.
+    pub rebased_paths: usize,
+    pub warnings: Vec<String>,
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Write a signed bundle for `history_id` to `out`.
+///
+/// `tracker` supplies the session's file-change records and snapshots; pass
+/// `None` when the session never modified files.
+pub fn export_session_bundle<W: Write + Seek>(
+    db: &Database,
+    history_id: &str,
+    tracker: Option<&FileChangeTracker>,
+    signing_key: &SigningKey,
+    out: W,
+) -> AppResult<SessionBundleManifest> {
+    let conn = db.get_connection()?;
+    let mut transcript = Vec::new();
+    write_session_transcript(&conn, history_id, &mut transcript)?;
+    let record = read_session_transcript(transcript.as_slice())?.record;
+    let workspace = record.workspace_path.clone();
+
+    let mut entries: Vec<(String, Vec<u8>)> = vec![(TRANSCRIPT_ENTRY.to_string(), transcript)];
+
+    if let Some(tracker) = tracker {
+        let changes = tracker.changes().to_vec();
+        let mut hashes: Vec<&str> = changes
+            .iter()
+            .flat_map(|c| [c.before_hash.as_deref(), c.after_hash.as_deref()])
+            .flatten()
+            .collect();
+        hashes.sort_unstable();
+        hashes.dedup();
+        for hash in hashes {
+            match tracker.get_content(hash) {
+                Ok(content) => entries.push((format!("{}{}", CAS_PREFIX, hash), content)),
+                Err(e) => tracing::warn!(hash, error = %e, "session bundle: snapshot missing"),
+            }
+        }
+        entries.push((
+            FILE_CHANGES_ENTRY.to_string(),
+            serde_json::to_vec(&changes)?,
+        ));
+    }
+
+    let memories =
+        load_bundled_memories(&conn, workspace.as_deref(), record.session_id.as_deref())?;
+    entries.push((MEMORY_ENTRY.to_string(), serde_json::to_vec(&memories)?));
+    // The index store checks out its own connection.
+    drop(conn);
+
+    let index = match workspace.as_deref() {
+        Some(workspace) => {
+            let store = IndexStore::new(db.pool().clone());
+            BundledIndexMetadata {
+                summary: store.get_project_summary(workspace).unwrap_or_default(),
+                embeddings: store.get_embedding_metadata(workspace).unwrap_or_default(),
+            }
+        }
+        None => BundledIndexMetadata::default(),
+    };
+    entries.push((INDEX_ENTRY.to_string(), serde_json::to_vec(&index)?));
+
+    let manifest = SessionBundleManifest {
+        format_version: SESSION_BUNDLE_FORMAT_VERSION,
+        history_id: record.id.clone(),
+        session_id: record.session_id.clone(),
+        source_workspace: workspace,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        llm_provider: record.llm_provider.clone(),
+        llm_model: record.llm_model.clone(),
+        entries: entries
+            .iter()
+            .map(|(name, bytes)| (name.clone(), sha256_hex(bytes)))
+            .collect(),
+    };
+    let manifest_bytes = serde_json::to_vec_pretty(&manifest)?;
+    let signature = BundleSignature {
+        algorithm: "ed25519".to_string(),
+        public_key: public_key_base64(signing_key),
+        signature: BASE64.encode(signing_key.sign(&manifest_bytes).to_bytes()),
+    };
+
+    let mut zip = zip::ZipWriter::new(out);
+    let options = zip::write::SimpleFileOptions::default();
+    let mut write_entry = |name: &str, bytes: &[u8]| -> AppResult<()> {
+        zip.start_file(name, options).map_err(zip_error)?;
+        zip.write_all(bytes)?;
+        Ok(())
+    };
+    write_entry(MANIFEST_ENTRY, &manifest_bytes)?;
+    write_entry(SIGNATURE_ENTRY, &serde_json::to_vec_pretty(&signature)?)?;
+    for (name, bytes) in &entries {
+        write_entry(name, bytes)?;
+    }
+    zip.finish().map_err(zip_error)?;
+
+    Ok(manifest)
+}
+
+fn load_bundled_memories(
+    conn: &rusqlite::Connection,
+    workspace: Option<&str>,
+    session_id: Option<&str>,
+) -> AppResult<Vec<BundledMemory>> {
+    let session_id = session_id.and_then(normalize_memory_session_id);
+    if workspace.is_none() && session_id.is_none() {
+        return Ok(Vec::new());
+    }
+    let mut stmt = conn.prepare(
+        "SELECT id, scope, project_path, session_id, category, content, content_hash,
+                keywords, importance, source_session_id, source_context, status, risk_tier
+         FROM memory_entries_v2
+         WHERE status != 'deleted'
+           AND ((scope = 'project' AND project_path = ?1)
+             OR (scope = 'session' AND session_id = ?2))
+         ORDER BY created_at ASC",
+    )?;
+    let rows = stmt
+        .query_map(params![workspace, session_id], |row| {
+            Ok(BundledMemory {
+                id: row.get(0)?,
+                scope: row.get(1)?,
+                project_path: row.get(2)?,
+                session_id: row.get(3)?,
+                category: row.get(4)?,
+                content: row.get(5)?,
+                content_hash: row.get(6)?,
+                keywords: row.get(7)?,
+                importance: row.get(8)?,
+                source_session_id: row.get(9)?,
+                source_context: row.get(10)?,
+                status: row.get(11)?,
+                risk_tier: row.get(12)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(rows)
+}
+
+// ============================================================================
+// Import
+// ============================================================================
+
+/// Verify and restore a bundle written by [`export_session_bundle`].
+///
+/// `tracker_for` creates the file-change tracker for the restored session
+/// (given its id and the target workspace). A bundle whose signature or
+/// entry hashes do not match is rejected; a missing provider or unindexed
+/// workspace only adds a warning.
+pub fn import_session_bundle<R, F>(
+    db: &Database,
+    bundle: R,
+    options: &SessionBundleImportOptions,
+    tracker_for: F,
+) -> AppResult<SessionBundleImportResult>
+where
+    R: Read + Seek,
+    F: FnOnce(&str, &Path) -> FileChangeTracker,
+{
+    let mut archive = zip::ZipArchive::new(bundle).map_err(zip_error)?;
+    let manifest_bytes = read_entry(&mut archive, MANIFEST_ENTRY)?;
+    let signature: BundleSignature =
+        serde_json::from_slice(&read_entry(&mut archive, SIGNATURE_ENTRY)?)?;
+    let signer_fingerprint = verify_signature(&manifest_bytes, &signature, &options.trusted_keys)?;
+
+    let manifest: SessionBundleManifest = serde_json::from_slice(&manifest_bytes)?;
+    if manifest.format_version > SESSION_BUNDLE_FORMAT_VERSION {
+        return Err(AppError::validation(format!(
+            "session bundle format v{} is newer than supported v{}",
+            manifest.format_version, SESSION_BUNDLE_FORMAT_VERSION
+        )));
+    }
+    let mut entries = BTreeMap::new();
+    for (name, expected) in &manifest.entries {
+        let bytes = read_entry(&mut archive, name)?;
+        if &sha256_hex(&bytes) != expected {
+            return Err(AppError::validation(format!(
+                "session bundle entry '{}' does not match its signed hash",
+                name
+            )));
+        }
+        entries.insert(name.clone(), bytes);
+    }
+
+    let target = options.target_workspace.to_string_lossy().to_string();
+    let rebase = PathRebase::new(manifest.source_workspace.as_deref(), &target);
+    let mut rebased_paths = 0;
+    let mut warnings = Vec::new();
+
+    // Messages and tool history
+    let transcript = entries
+        .get(TRANSCRIPT_ENTRY)
+        .ok_or_else(|| AppError::validation("session bundle has no transcript"))?;
+    let mut record = read_session_transcript(transcript.as_slice())?.record;
+    rebased_paths += rebase_record(&mut record, &rebase);
+    record.workspace_path = Some(target.clone());
+    let conn = db.get_connection()?;
+    upsert_record(&conn, &record)?;
+
+    // File changes and snapshots
+    let mut file_changes = 0;
+    let mut snapshots = 0;
+    if let Some(raw) = entries.get(FILE_CHANGES_ENTRY) {
+        let mut changes: Vec<FileChange> = serde_json::from_slice(raw)?;
+        for change in &mut changes {
+            if let Some(path) = rebase.apply(&change.file_path) {
+                change.file_path = path;
+                rebased_paths += 1;
+            }
+        }
+        let session_key = record
+            .session_id
+            .as_deref()
+            .and_then(normalize_memory_session_id)
+            .unwrap_or_else(|| record.id.clone());
+        let mut tracker = tracker_for(&session_key, &options.target_workspace);
+        for (name, bytes) in &entries {
+            if let Some(hash) = name.strip_prefix(CAS_PREFIX) {
+                let stored = tracker.store_content(bytes).map_err(AppError::internal)?;
+                if stored != hash {
+                    return Err(AppError::validation(format!(
+                        "snapshot '{}' does not match its content",
+                        hash
+                    )));
+                }
+                snapshots += 1;
+            }
+        }
+        file_changes = tracker
+            .import_changes(changes)
+            .map_err(AppError::internal)?;
+    }
+
+    // Memories
+    let mut memories = 0;
+    if let Some(raw) = entries.get(MEMORY_ENTRY) {
+        let bundled: Vec<BundledMemory> = serde_json::from_slice(raw)?;
+        for mut memory in bundled {
+            if memory.scope == "project" {
+                memory.project_path = Some(target.clone());
+            }
+            memories += conn.execute(
+                "INSERT OR IGNORE INTO memory_entries_v2
+                 (id, scope, project_path, session_id, category, content, content_hash,
+                  keywords, importance, source_session_id, source_context, status, risk_tier)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+                params![
+                    memory.id,
+                    memory.scope,
+                    memory.project_path,
+                    memory.session_id,
+                    memory.category,
+                    memory.content,
+                    memory.content_hash,
+                    memory.keywords,
+                    memory.importance,
+                    memory.source_session_id,
+                    memory.source_context,
+                    memory.status,
+                    memory.risk_tier,
+                ],
+            )?;
+        }
+    }
+
+    drop(conn);
+
+    // Index metadata: the index itself is rebuilt locally.
+    if let Some(raw) = entries.get(INDEX_ENTRY) {
+        let bundled: BundledIndexMetadata = serde_json::from_slice(raw)?;
+        let store = IndexStore::new(db.pool().clone());
+        let local = store.get_project_summary(&target).unwrap_or_default();
+        if local.total_files == 0 && bundled.summary.total_files > 0 {
+            warnings.push(format!(
+                "Workspace '{}' is not indexed on this machine ({} files were indexed at the source); index it before resuming",
+                target, bundled.summary.total_files
+            ));
+        }
+    }
+
+    // Providers
+    if let Some(provider) = record.llm_provider.as_deref().filter(|p| !p.is_empty()) {
+        let configured = options
+            .configured_providers
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(provider));
+        if !configured {
+            warnings.push(format!(
+                "Provider '{}'{} is not configured on this machine; add its API key or pick another model to resume",
+                provider,
+                record
+                    .llm_model
+                    .as_deref()
+                    .map(|m| format!(" (model '{}')", m))
+                    .unwrap_or_default()
+            ));
+        }
+    }
+
+    Ok(SessionBundleImportResult {
+        history_id: record.id,
+        session_id: record.session_id,
+        workspace_path: target,
+        signer_fingerprint,
+        file_changes,
+        snapshots,
+        memories,
+        rebased_paths,
+        warnings,
+    })
+}
+
+fn read_entry<R: Read + Seek>(archive: &mut zip::ZipArchive<R>, name: &str) -> AppResult<Vec<u8>> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|_| AppError::validation(format!("session bundle is missing entry '{}'", name)))?;
+    let mut bytes = Vec::new();
+    entry.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn verify_signature(
+    manifest: &[u8],
+    signature: &BundleSignature,
+    trusted_keys: &[VerifyingKey],
+) -> AppResult<String> {
+    if signature.algorithm != "ed25519" {
+        return Err(AppError::validation(format!(
+            "unsupported session bundle signature algorithm '{}'",
+            signature.algorithm
+        )));
+    }
+    let invalid = || AppError::validation("session bundle signature is malformed");
+    let public_key: [u8; 32] = BASE64
+        .decode(&signature.public_key)
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(invalid)?;
+    let signature_bytes: [u8; 64] = BASE64
+        .decode(&signature.signature)
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(invalid)?;
+    let fingerprint = key_fingerprint(&public_key);
+    if !trusted_keys.iter().any(|k| k.as_bytes() == &public_key) {
+        return Err(AppError::validation(format!(
+            "session bundle was signed by an untrusted key ({}); add the signer's public key to the trusted bundle keys to import it",
+            fingerprint
+        )));
+    }
+    let verifying_key = VerifyingKey::from_bytes(&public_key).map_err(|_| invalid())?;
+    verifying_key
+        .verify(manifest, &Signature::from_bytes(&signature_bytes))
+        .map_err(|_| {
+            AppError::validation("session bundle signature does not match its manifest")
+        })?;
+    Ok(fingerprint)
+}
+
+/// Rewrites absolute paths under the source workspace onto the target.
+struct PathRebase {
+    source: Option<String>,
+    target: String,
+}
+
+impl PathRebase {
+    fn new(source: Option<&str>, target: &str) -> Self {
+        let source = source
+            .map(|s| s.trim_end_matches(['/', '\\']).to_string())
+            .filter(|s| !s.is_empty() && s != target.trim_end_matches(['/', '\\']));
+        Self {
+            source,
+            target: target.trim_end_matches(['/', '\\']).to_string(),
+        }
+    }
+
+    /// The rebased path, or `None` when `path` is not under the source.
+    fn apply(&self, path: &str) -> Option<String> {
+        let source = self.source.as_deref()?;
+        let rest = path.strip_prefix(source)?;
+        if !rest.is_empty() && !rest.starts_with(['/', '\\']) {
+            return None;
+        }
+        Some(format!("{}{}", self.target, rest))
+    }
+
+    /// Replace every occurrence of the source workspace in free text.
+    fn apply_text(&self, text: &mut String) -> usize {
+        let Some(source) = self.source.as_deref() else {
+            return 0;
+        };
+        let count = text.matches(source).count();
+        if count > 0 {
+            *text = text.replace(source, &self.target);
+        }
+        count
+    }
+}
+
+fn rebase_record(record: &mut ExecutionHistoryRecord, rebase: &PathRebase) -> usize {
+    let mut count = 0;
+    if let Some(content) = record.conversation_content.as_mut() {
+        count += rebase.apply_text(content);
+    }
+    for line in record.conversation_lines.iter_mut().flatten() {
+        count += rebase.apply_text(&mut line.content);
+    }
+    count
+}
+
+// ============================================================================
+// Signing key
+// ============================================================================
+
+/// Load this installation's bundle signing key from the keyring, creating
+/// it on first use.
+///
+/// A stored key that cannot be decoded is an error rather than being
+/// replaced: a new key would stop other machines from trusting bundles
+/// signed earlier.
+pub fn load_or_create_signing_key(keyring: &KeyringService) -> AppResult<SigningKey> {
+    if let Some(encoded) = keyring.get_api_key(SIGNING_KEY_KEYRING_ENTRY)? {
+        let secret: [u8; 32] = BASE64
+            .decode(encoded.trim())
+            .ok()
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(|| AppError::keyring("stored session bundle signing key is corrupt"))?;
+        return Ok(SigningKey::from_bytes(&secret));
+    }
+    let mut secret = [0u8; 32];
+    OsRng.fill_bytes(&mut secret);
+    keyring.set_api_key(SIGNING_KEY_KEYRING_ENTRY, &BASE64.encode(secret))?;
+    Ok(SigningKey::from_bytes(&secret))
+}
+
+/// Base64 public key of `signing_key`, as shared with other machines.
+pub fn public_key_base64(signing_key: &SigningKey) -> String {
+    public_key_base64_of(&signing_key.verifying_key())
+}
+
+/// Base64 encoding of a verifying key.
+pub fn public_key_base64_of(key: &VerifyingKey) -> String {
+    BASE64.encode(key.as_bytes())
+}
+
+/// Parse a base64 Ed25519 public key.
+pub fn parse_public_key(encoded: &str) -> AppResult<VerifyingKey> {
+    let bytes: [u8; 32] = BASE64
+        .decode(encoded.trim())
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| AppError::validation("public key must be 32 base64-encoded bytes"))?;
+    VerifyingKey::from_bytes(&bytes)
+        .map_err(|_| AppError::validation("public key is not a valid Ed25519 key"))
+}
+
+/// Short fingerprint shown to users when comparing keys.
+pub fn key_fingerprint(public_key: &[u8; 32]) -> String {
+    sha256_hex(public_key)[..16].to_string()
+}
+
+/// Trusted signer keys from the allowlist setting.
+pub fn load_trusted_keys(db: &Database) -> AppResult<Vec<VerifyingKey>> {
+    let Some(raw) = db.get_setting(TRUSTED_KEYS_SETTING)? else {
+        return Ok(Vec::new());
+    };
+    let encoded: Vec<String> = serde_json::from_str(&raw)?;
+    encoded.iter().map(|k| parse_public_key(k)).collect()
+}
+
+/// Replace the trusted-key allowlist. Every key must parse.
+pub fn save_trusted_keys(db: &Database, public_keys: &[String]) -> AppResult<Vec<VerifyingKey>> {
+    let keys = public_keys
+        .iter()
+        .map(|k| parse_public_key(k))
+        .collect::<AppResult<Vec<_>>>()?;
+    let encoded: Vec<String> = keys.iter().map(public_key_base64_of).collect();
+    db.set_setting(TRUSTED_KEYS_SETTING, &serde_json::to_string(&encoded)?)?;
+    Ok(keys)
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    format!("{:x}", hasher.finalize())
+}
+
+fn zip_error(e: zip::result::ZipError) -> AppError {
+    AppError::internal(format!("session bundle archive error: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::execution_history::HistoryConversationLine;
+    use std::io::Cursor;
+
+    fn line(line_type: &str, content: &str, turn_id: i64) -> HistoryConversationLine {
+        HistoryConversationLine {
+            line_type: line_type.to_string(),
+            content: content.to_string(),
+            card_payload: None,
+            sub_agent_id: None,
+            sub_agent_depth: None,
+            turn_id: Some(turn_id),
+            turn_boundary: None,
+        }
+    }
+
+    fn seed_session(db: &Database, workspace: &str) -> Vec<HistoryConversationLine> {
+        let lines = vec![
+            HistoryConversationLine {
+                turn_boundary: Some("user".to_string()),
+                ..line("info", "add a greeting", 1)
+            },
+            line(
+                "tool",
+                &format!(
+                    "{{\"name\":\"Write\",\"path\":\"{}/src/greet.rs\"}}",
+                    workspace
+                ),
+                1,
+            ),
+            line("tool_result", "wrote 1 file", 1),
+            HistoryConversationLine {
+                turn_boundary: Some("assistant".to_string()),
+                ..line("text", "Added src/greet.rs.", 1)
+            },
+        ];
+        let record = ExecutionHistoryRecord {
+            id: "hist-bundle".to_string(),
+            title: Some("Greeting".to_string()),
+            task_description: "add a greeting".to_string(),
+            workspace_path: Some(workspace.to_string()),
+            strategy: None,
+            status: "completed".to_string(),
+            started_at: 1,
+            completed_at: Some(2),
+            duration: Some(1),
+            completed_stories: None,
+            total_stories: None,
+            success: true,
+            error: None,
+            conversation_content: None,
+            conversation_lines: Some(lines.clone()),
+            session_id: Some("standalone:sess-1".to_string()),
+            llm_backend: None,
+            llm_provider: Some("anthropic".to_string()),
+            llm_model: Some("claude-sonnet".to_string()),
+        };
+        let conn = db.get_connection().unwrap();
+        upsert_record(&conn, &record).unwrap();
+        conn.execute(
+            "INSERT INTO memory_entries_v2 (id, scope, project_path, category, content, content_hash)
+             VALUES ('mem-1', 'project', ?1, 'convention', 'Use snake_case', 'h1')",
+            params![workspace],
+        )
+        .unwrap();
+        lines
+    }
+
+    #[test]
+    fn test_session_bundle_round_trips_across_workspaces() {
+        let source_ws = tempfile::tempdir().unwrap();
+        let target_ws = tempfile::tempdir().unwrap();
+        let source_data = tempfile::tempdir().unwrap();
+        let target_data = tempfile::tempdir().unwrap();
+        let source_path = source_ws.path().to_string_lossy().to_string();
+
+        let source_db = Database::new_in_memory().unwrap();
+        let lines = seed_session(&source_db, &source_path);
+
+        let mut tracker =
+            FileChangeTracker::new_with_data_dir("sess-1", source_ws.path(), source_data.path());
+        let after = tracker.store_content(b"pub fn greet() {}\n").unwrap();
+        tracker.record_change(
+            "tc-1",
+            "Write",
+            "src/greet.rs",
+            None,
+            Some(&after),
+            "create greet.rs",
+        );
+
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let mut archive = Cursor::new(Vec::new());
+        let manifest = export_session_bundle(
+            &source_db,
+            "hist-bundle",
+            Some(&tracker),
+            &key,
+            &mut archive,
+        )
+        .unwrap();
+        assert_eq!(manifest.session_id.as_deref(), Some("standalone:sess-1"));
+        assert!(manifest
+            .entries
+            .contains_key(&format!("{}{}", CAS_PREFIX, after)));
+
+        let target_db = Database::new_in_memory().unwrap();
+        let options = SessionBundleImportOptions {
+            target_workspace: target_ws.path().to_path_buf(),
+            configured_providers: vec!["openai".to_string()],
+            trusted_keys: vec![key.verifying_key()],
+        };
+        archive.set_position(0);
+        let result = import_session_bundle(&target_db, archive, &options, |sid, root| {
+            FileChangeTracker::new_with_data_dir(sid, root, target_data.path())
+        })
+        .unwrap();
+
+        let target_path = target_ws.path().to_string_lossy().to_string();
+        assert_eq!(result.history_id, "hist-bundle");
+        assert_eq!(result.workspace_path, target_path);
+        assert_eq!(result.file_changes, 1);
+        assert_eq!(result.snapshots, 1);
+        assert_eq!(result.memories, 1);
+        assert_eq!(result.rebased_paths, 1);
+        // Missing provider warns instead of failing.
+        assert!(result.warnings.iter().any(|w| w.contains("anthropic")));
+
+        // The conversation resumes in the new workspace.
+        let conn = target_db.get_connection().unwrap();
+        let mut transcript = Vec::new();
+        write_session_transcript(&conn, "hist-bundle", &mut transcript).unwrap();
+        let restored = read_session_transcript(transcript.as_slice())
+            .unwrap()
+            .record;
+        assert_eq!(
+            restored.workspace_path.as_deref(),
+            Some(target_path.as_str())
+        );
+        let restored_lines = restored.conversation_lines.unwrap();
+        assert_eq!(restored_lines.len(), lines.len());
+        assert!(restored_lines[1].content.contains(&target_path));
+        assert!(!restored_lines[1].content.contains(&source_path));
+
+        // File changes can be restored into the target workspace.
+        let resumed =
+            FileChangeTracker::new_with_data_dir("sess-1", target_ws.path(), target_data.path());
+        assert_eq!(resumed.change_count(), 1);
+        let change = &resumed.changes()[0];
+        assert!(resumed
+            .restore_single_file(&change.file_path, change.after_hash.as_deref().unwrap())
+            .unwrap());
+        assert_eq!(
+            std::fs::read_to_string(target_ws.path().join("src/greet.rs")).unwrap(),
+            "pub fn greet() {}\n"
+        );
+
+        let memory_path: String = conn
+            .query_row(
+                "SELECT project_path FROM memory_entries_v2 WHERE id = 'mem-1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(memory_path, target_path);
+    }
+
+    #[test]
+    fn test_tampered_bundle_is_rejected() {
+        let ws = tempfile::tempdir().unwrap();
+        let db = Database::new_in_memory().unwrap();
+        seed_session(&db, &ws.path().to_string_lossy());
+
+        let key = SigningKey::from_bytes(&[9u8; 32]);
+        let mut archive = Cursor::new(Vec::new());
+        export_session_bundle(&db, "hist-bundle", None, &key, &mut archive).unwrap();
+
+        // Re-pack with a modified transcript but the original manifest.
+        archive.set_position(0);
+        let mut original = zip::ZipArchive::new(archive).unwrap();
+        let mut tampered = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for i in 0..original.len() {
+            let mut entry = original.by_index(i).unwrap();
+            let name = entry.name().to_string();
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes).unwrap();
+            if name == TRANSCRIPT_ENTRY {
+                bytes.extend_from_slice(b"\n");
+            }
+            tampered
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            tampered.write_all(&bytes).unwrap();
+        }
+        let mut tampered = tampered.finish().unwrap();
+        tampered.set_position(0);
+
+        let options = SessionBundleImportOptions {
+            target_workspace: ws.path().to_path_buf(),
+            configured_providers: Vec::new(),
+            trusted_keys: vec![key.verifying_key()],
+        };
+        let err = import_session_bundle(
+            &Database::new_in_memory().unwrap(),
+            tampered,
+            &options,
+            |sid, root| FileChangeTracker::new_with_data_dir(sid, root, ws.path().join("data")),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("signed hash"));
+    }
+
+    #[test]
+    fn test_bundle_resigned_with_untrusted_key_is_rejected() {
+        let ws = tempfile::tempdir().unwrap();
+        let db = Database::new_in_memory().unwrap();
+        seed_session(&db, &ws.path().to_string_lossy());
+
+        // An attacker re-signs the bundle with their own key; the signature
+        // is internally consistent but the key is not trusted.
+        let trusted = SigningKey::from_bytes(&[9u8; 32]);
+        let attacker = SigningKey::from_bytes(&[3u8; 32]);
+        let mut archive = Cursor::new(Vec::new());
+        export_session_bundle(&db, "hist-bundle", None, &attacker, &mut archive).unwrap();
+
+        let import = |trusted_keys: Vec<VerifyingKey>, mut archive: Cursor<Vec<u8>>| {
+            archive.set_position(0);
+            let options = SessionBundleImportOptions {
+                target_workspace: ws.path().to_path_buf(),
+                configured_providers: Vec::new(),
+                trusted_keys,
+            };
+            import_session_bundle(
+                &Database::new_in_memory().unwrap(),
+                archive,
+                &options,
+                |sid, root| FileChangeTracker::new_with_data_dir(sid, root, ws.path().join("data")),
+            )
+        };
+
+        let err = import(vec![trusted.verifying_key()], archive.clone()).unwrap_err();
+        assert!(err.to_string().contains("untrusted key"));
+
+        // Once the user adds the signer to the allowlist it imports.
+        let db = Database::new_in_memory().unwrap();
+        save_trusted_keys(&db, &[public_key_base64(&attacker)]).unwrap();
+        let mut keys = load_trusted_keys(&db).unwrap();
+        keys.push(trusted.verifying_key());
+        assert!(import(keys, archive).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_trusted_key_is_rejected() {
+        let db = Database::new_in_memory().unwrap();
+        assert!(save_trusted_keys(&db, &["not-a-key".to_string()]).is_err());
+        assert!(load_trusted_keys(&db).unwrap().is_empty());
+    }
+}
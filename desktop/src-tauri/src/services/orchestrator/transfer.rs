@@ -6,6 +6,8 @@
 //! 2. Creates a new `AgentContext` with shared session state
 //! 3. Continues execution with the target agent
 //! 4. Tracks transfer chains for debugging (with max depth to prevent cycles)
+//!
+//! Moving a whole session to another machine is handled by `session_bundle`.
 
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
@@ -3,8 +3,19 @@
 //! Provides accurate, language-aware symbol extraction using tree-sitter grammars.
 //! Supports Python, Rust, TypeScript, JavaScript, Go, and Java.
 //! Falls back gracefully when a language is not supported.
+//!
+//! A `ParseCache` keeps parse trees by content hash so unchanged files are
+//! not re-parsed; the app-wide cache lives in `AppState` and is handed to
+//! the background indexer. `detect_file_language` combines the file
+//! extension with shebang and content heuristics for ambiguous files.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 
-use super::analysis_index::{SymbolInfo, SymbolKind};
+use sha2::{Digest, Sha256};
+
+use super::analysis_index::{detect_language, SymbolInfo, SymbolKind};
 
 /// Check whether tree-sitter parsing is available for the given language.
 pub fn is_language_supported(language: &str) -> bool {
@@ -21,26 +32,52 @@ pub fn is_language_supported(language: &str) -> bool {
 ///
 /// The `max_symbols` parameter limits the number of returned symbols.
 pub fn parse_symbols(content: &str, language: &str, max_symbols: usize) -> Vec<SymbolInfo> {
-    let lang = match language {
+    match parse_tree(content, language) {
+        Some(tree) => symbols_from_tree(&tree, content, language, max_symbols),
+        None => Vec::new(),
+    }
+}
+
+/// Result of parsing a file with `ParseCache::parse_file`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileParseResult {
+    /// The file was parsed; `cache_hit` is true when the tree was reused.
+    Parsed {
+        language: String,
+        symbols: Vec<SymbolInfo>,
+        cache_hit: bool,
+    },
+    /// No bundled grammar exists for the detected language.
+    UnsupportedLanguage { language: String },
+    /// A grammar exists but tree-sitter could not produce a tree.
+    ParseFailed { language: String },
+}
+
+fn grammar_for(language: &str) -> Option<tree_sitter::Language> {
+    Some(match language {
         "python" => tree_sitter_python::LANGUAGE.into(),
         "rust" => tree_sitter_rust::LANGUAGE.into(),
         "typescript" => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
         "javascript" => tree_sitter_typescript::LANGUAGE_TSX.into(), // TSX grammar handles JS well
         "go" => tree_sitter_go::LANGUAGE.into(),
         "java" => tree_sitter_java::LANGUAGE.into(),
-        _ => return Vec::new(),
-    };
+        _ => return None,
+    })
+}
 
+fn parse_tree(content: &str, language: &str) -> Option<tree_sitter::Tree> {
+    let grammar = grammar_for(language)?;
     let mut parser = tree_sitter::Parser::new();
-    if parser.set_language(&lang).is_err() {
-        return Vec::new();
-    }
-
-    let tree = match parser.parse(content, None) {
-        Some(t) => t,
-        None => return Vec::new(),
-    };
+    parser.set_language(&grammar).ok()?;
+    parser.parse(content, None)
+}
 
+fn symbols_from_tree(
+    tree: &tree_sitter::Tree,
+    content: &str,
+    language: &str,
+    max_symbols: usize,
+) -> Vec<SymbolInfo> {
     let lines: Vec<&str> = content.lines().collect();
     let mut symbols = Vec::new();
 
@@ -57,6 +94,225 @@ pub fn parse_symbols(content: &str, language: &str, max_symbols: usize) -> Vec<S
     symbols
 }
 
+// ============================================================================
+// Parse cache
+// ============================================================================
+
+/// Maximum number of trees kept by a default `ParseCache`.
+const DEFAULT_PARSE_CACHE_CAPACITY: usize = 512;
+
+/// Hit/miss counters for a `ParseCache`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+#[derive(Default)]
+struct ParseCacheEntries {
+    trees: HashMap<(String, String), tree_sitter::Tree>,
+    /// Insertion order, oldest first, for eviction.
+    order: VecDeque<(String, String)>,
+}
+
+/// Parse-tree cache keyed by `(language, SHA-256 of content)`.
+///
+/// Re-parsing an unchanged file returns a clone of the cached tree (cheap:
+/// tree-sitter trees are reference counted). The oldest entry is evicted
+/// once `capacity` is reached.
+pub struct ParseCache {
+    entries: Mutex<ParseCacheEntries>,
+    capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl ParseCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(ParseCacheEntries::default()),
+            capacity: capacity.max(1),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Parse `content`, reusing a cached tree when the content is unchanged.
+    ///
+    /// Returns the tree and whether it came from the cache, or `None` when
+    /// the language has no grammar or parsing failed.
+    pub fn parse(&self, content: &str, language: &str) -> Option<(tree_sitter::Tree, bool)> {
+        grammar_for(language)?;
+        let key = (language.to_string(), content_hash(content));
+
+        if let Some(tree) = self.lock().trees.get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Some((tree.clone(), true));
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let tree = parse_tree(content, language)?;
+
+        let mut entries = self.lock();
+        if !entries.trees.contains_key(&key) {
+            while entries.order.len() >= self.capacity {
+                match entries.order.pop_front() {
+                    Some(oldest) => {
+                        entries.trees.remove(&oldest);
+                    }
+                    None => break,
+                }
+            }
+            entries.order.push_back(key.clone());
+            entries.trees.insert(key, tree.clone());
+        }
+        Some((tree, false))
+    }
+
+    /// Cached counterpart of `parse_symbols`.
+    pub fn parse_symbols(
+        &self,
+        content: &str,
+        language: &str,
+        max_symbols: usize,
+    ) -> Vec<SymbolInfo> {
+        match self.parse(content, language) {
+            Some((tree, _)) => symbols_from_tree(&tree, content, language, max_symbols),
+            None => Vec::new(),
+        }
+    }
+
+    /// Detect the file's language, then parse and extract symbols.
+    pub fn parse_file(&self, path: &str, content: &str, max_symbols: usize) -> FileParseResult {
+        let language = detect_file_language(path, content);
+        if !is_language_supported(&language) {
+            return FileParseResult::UnsupportedLanguage { language };
+        }
+        match self.parse(content, &language) {
+            Some((tree, cache_hit)) => FileParseResult::Parsed {
+                symbols: symbols_from_tree(&tree, content, &language, max_symbols),
+                language,
+                cache_hit,
+            },
+            None => FileParseResult::ParseFailed { language },
+        }
+    }
+
+    pub fn stats(&self) -> ParseCacheStats {
+        ParseCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.lock().trees.len(),
+        }
+    }
+
+    pub fn clear(&self) {
+        let mut entries = self.lock();
+        entries.trees.clear();
+        entries.order.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, ParseCacheEntries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for ParseCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_PARSE_CACHE_CAPACITY)
+    }
+}
+
+fn content_hash(content: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(content.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+// ============================================================================
+// Language detection
+// ============================================================================
+
+/// Detect a file's language from its path and content.
+///
+/// The extension decides when it is unambiguous. Files without a known
+/// extension fall back to well-known file names and then the shebang line;
+/// `.h` headers are treated as C++ when they contain C++-only constructs.
+/// Returns `"other"` when nothing matches.
+pub fn detect_file_language(path: &str, content: &str) -> String {
+    let file_name = path
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or(path)
+        .to_ascii_lowercase();
+    let ext = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext)
+        .filter(|_| !file_name.starts_with('.') || file_name.matches('.').count() > 1);
+
+    let language = detect_language(ext);
+    if language == "c" && ext == Some("h") && looks_like_cpp(content) {
+        return "cpp".to_string();
+    }
+    if language != "other" {
+        return language;
+    }
+
+    match file_name.as_str() {
+        "dockerfile" | "containerfile" => return "dockerfile".to_string(),
+        "makefile" | "gnumakefile" => return "makefile".to_string(),
+        "rakefile" | "gemfile" => return "ruby".to_string(),
+        _ => {}
+    }
+
+    shebang_language(content)
+        .map(str::to_string)
+        .unwrap_or(language)
+}
+
+/// Language named by a `#!` interpreter line, if any.
+pub fn shebang_language(content: &str) -> Option<&'static str> {
+    let line = content.lines().next()?.strip_prefix("#!")?;
+    let mut parts = line.split_whitespace();
+    let mut interpreter = parts.next()?.rsplit('/').next()?;
+    if interpreter == "env" {
+        // `#!/usr/bin/env -S python3 -u`: skip env's own flags.
+        interpreter = parts.find(|p| !p.starts_with('-'))?;
+    }
+    // python3.11 -> python, ruby2.7 -> ruby
+    let name = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+
+    Some(match name {
+        "python" | "pypy" => "python",
+        "node" | "nodejs" | "bun" => "javascript",
+        "deno" | "ts-node" | "tsx" => "typescript",
+        "sh" | "bash" | "zsh" | "dash" | "ksh" | "fish" => "shell",
+        "ruby" => "ruby",
+        "perl" => "perl",
+        "php" => "php",
+        "lua" | "luajit" => "lua",
+        "Rscript" => "r",
+        "elixir" => "elixir",
+        "pwsh" => "powershell",
+        "rust-script" => "rust",
+        _ => return None,
+    })
+}
+
+fn looks_like_cpp(content: &str) -> bool {
+    [
+        "namespace ",
+        "template<",
+        "template <",
+        "class ",
+        "std::",
+        "public:",
+    ]
+    .iter()
+    .any(|marker| content.contains(marker))
+}
+
 /// Recursively extract symbols from a tree-sitter node.
 fn extract_from_node(
     node: tree_sitter::Node,
@@ -1159,6 +1415,111 @@ public enum Status {
         assert!(!is_language_supported("other"));
     }
 
+    #[test]
+    fn test_parse_cache_hits_for_unchanged_content() {
+        let cache = ParseCache::new(8);
+        let src = "def greet(name):\n    return name\n";
+
+        let first = cache.parse_file("app/greet.py", src, 30);
+        let second = cache.parse_file("app/greet.py", src, 30);
+        match (&first, &second) {
+            (
+                FileParseResult::Parsed {
+                    symbols: a,
+                    cache_hit: false,
+                    ..
+                },
+                FileParseResult::Parsed {
+                    symbols: b,
+                    cache_hit: true,
+                    ..
+                },
+            ) => assert_eq!(a, b),
+            other => panic!("expected miss then hit, got {:?}", other),
+        }
+        assert_eq!(
+            cache.stats(),
+            ParseCacheStats {
+                hits: 1,
+                misses: 1,
+                entries: 1
+            }
+        );
+
+        // Edited content misses again.
+        let edited = "def greet(name):\n    return name.upper()\n";
+        assert!(matches!(
+            cache.parse_file("app/greet.py", edited, 30),
+            FileParseResult::Parsed {
+                cache_hit: false,
+                ..
+            }
+        ));
+        assert_eq!(cache.stats().misses, 2);
+    }
+
+    #[test]
+    fn test_parse_cache_evicts_oldest_entry() {
+        let cache = ParseCache::new(1);
+        assert!(cache.parse("fn a() {}", "rust").is_some());
+        assert!(cache.parse("fn b() {}", "rust").is_some());
+        assert_eq!(cache.stats().entries, 1);
+        let (_, hit) = cache.parse("fn a() {}", "rust").unwrap();
+        assert!(!hit);
+    }
+
+    #[test]
+    fn test_shebang_only_script_is_detected() {
+        let script = "#!/usr/bin/env python3\n\ndef main():\n    print('deploy')\n";
+        assert_eq!(detect_file_language("scripts/deploy", script), "python");
+        match ParseCache::default().parse_file("scripts/deploy", script, 30) {
+            FileParseResult::Parsed {
+                language, symbols, ..
+            } => {
+                assert_eq!(language, "python");
+                assert!(symbols.iter().any(|s| s.name == "main"));
+            }
+            other => panic!("expected parsed script, got {:?}", other),
+        }
+
+        assert_eq!(
+            detect_file_language("bin/run", "#!/bin/bash\necho hi\n"),
+            "shell"
+        );
+        assert_eq!(
+            detect_file_language("bin/serve", "#!/usr/bin/env -S node --no-warnings\n"),
+            "javascript"
+        );
+        assert_eq!(detect_file_language("notes", "just text\n"), "other");
+    }
+
+    #[test]
+    fn test_extension_and_content_heuristics() {
+        assert_eq!(detect_file_language("src/main.rs", "#!/bin/bash\n"), "rust");
+        assert_eq!(
+            detect_file_language("include/util.h", "int add(int a, int b);"),
+            "c"
+        );
+        assert_eq!(
+            detect_file_language("include/util.h", "namespace util { int add(int, int); }"),
+            "cpp"
+        );
+        assert_eq!(
+            detect_file_language("docker/Dockerfile", "FROM rust"),
+            "dockerfile"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_language_result() {
+        assert_eq!(
+            ParseCache::default().parse_file("src/Main.hs", "main = putStrLn \"hi\"", 30),
+            FileParseResult::UnsupportedLanguage {
+                language: "haskell".to_string()
+            }
+        );
+    }
+
     #[test]
     fn test_end_lines_are_populated() {
         let src = r#"
@@ -75,6 +75,12 @@ pub struct PhaseConfig {
     /// Maximum retry attempts
     #[serde(default = "default_max_retries")]
     pub max_retries: u32,
+    /// Condition over accumulated run state under which this phase is skipped
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skip_when: Option<SkipCondition>,
+    /// Phases whose output this phase needs; they run first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<Phase>,
 }
 
 fn default_agent() -> String {
@@ -97,6 +103,8 @@ impl Default for PhaseConfig {
             story_type_overrides: HashMap::new(),
             timeout_seconds: default_timeout(),
             max_retries: default_max_retries(),
+            skip_when: None,
+            depends_on: Vec::new(),
         }
     }
 }
@@ -128,6 +136,116 @@ impl PhaseConfig {
         self.timeout_seconds = seconds;
         self
     }
+
+    /// Skip this phase when the condition holds at the time it would run
+    pub fn with_skip_when(mut self, condition: SkipCondition) -> Self {
+        self.skip_when = Some(condition);
+        self
+    }
+
+    /// Declare a dependency on another phase
+    pub fn with_dependency(mut self, phase: Phase) -> Self {
+        if !self.depends_on.contains(&phase) {
+            self.depends_on.push(phase);
+        }
+        self
+    }
+}
+
+/// Runtime condition under which a phase is skipped
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SkipCondition {
+    /// Skip when the state value is `true`
+    Flag { key: String },
+    /// Skip when the state value equals `value`
+    Equals {
+        key: String,
+        value: serde_json::Value,
+    },
+    /// Skip when the numeric state value is below `threshold`
+    Below { key: String, threshold: f64 },
+    /// Skip when the key has not been set by an earlier phase
+    Missing { key: String },
+}
+
+impl SkipCondition {
+    /// Evaluate the condition against the accumulated state
+    pub fn matches(&self, state: &PhaseState) -> bool {
+        match self {
+            SkipCondition::Flag { key } => state.get(key).and_then(|v| v.as_bool()) == Some(true),
+            SkipCondition::Equals { key, value } => state.get(key) == Some(value),
+            SkipCondition::Below { key, threshold } => state
+                .get(key)
+                .and_then(|v| v.as_f64())
+                .is_some_and(|n| n < *threshold),
+            SkipCondition::Missing { key } => state.get(key).is_none(),
+        }
+    }
+}
+
+impl std::fmt::Display for SkipCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipCondition::Flag { key } => write!(f, "{} is set", key),
+            SkipCondition::Equals { key, value } => write!(f, "{} == {}", key, value),
+            SkipCondition::Below { key, threshold } => write!(f, "{} < {}", key, threshold),
+            SkipCondition::Missing { key } => write!(f, "{} is missing", key),
+        }
+    }
+}
+
+/// State accumulated across the phases of a run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhaseState {
+    values: HashMap<String, serde_json::Value>,
+}
+
+impl PhaseState {
+    /// Create an empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a value
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) {
+        self.values.insert(key.into(), value.into());
+    }
+
+    /// Set a value (builder style)
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    /// Get a value
+    pub fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.values.get(key)
+    }
+}
+
+/// Outcome of a single phase in a run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum PhaseOutcome {
+    /// The phase ran to completion
+    Completed { phase: Phase },
+    /// The phase was skipped because its skip condition held
+    Skipped { phase: Phase, reason: String },
+}
+
+impl PhaseOutcome {
+    /// The phase this outcome belongs to
+    pub fn phase(&self) -> Phase {
+        match self {
+            PhaseOutcome::Completed { phase } | PhaseOutcome::Skipped { phase, .. } => *phase,
+        }
+    }
+
+    /// Whether the phase was skipped
+    pub fn is_skipped(&self) -> bool {
+        matches!(self, PhaseOutcome::Skipped { .. })
+    }
 }
 
 /// Errors that can occur in phase management
@@ -144,6 +262,12 @@ pub enum PhaseError {
 
     #[error("Configuration not found")]
     ConfigNotFound,
+
+    #[error("Phase {phase} depends on {dependency}, which is not part of the run")]
+    MissingDependency { phase: Phase, dependency: Phase },
+
+    #[error("Circular phase dependency: {}", .0.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(" -> "))]
+    CircularDependency(Vec<Phase>),
 }
 
 /// Phase defaults configuration file structure
@@ -351,6 +475,109 @@ impl PhaseManager {
         std::fs::write(path, content)?;
         Ok(())
     }
+
+    /// Get the dependencies declared for a phase
+    pub fn get_dependencies(&self, phase: Phase) -> &[Phase] {
+        self.configs
+            .get(&phase)
+            .map(|c| c.depends_on.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Order the requested phases so every phase runs after its dependencies
+    ///
+    /// Phases without ordering constraints keep their requested order. Every
+    /// dependency must itself be part of the requested run.
+    pub fn order_phases(&self, phases: &[Phase]) -> Result<Vec<Phase>, PhaseError> {
+        let mut requested: Vec<Phase> = Vec::new();
+        for phase in phases {
+            if !requested.contains(phase) {
+                requested.push(*phase);
+            }
+        }
+
+        for phase in &requested {
+            for dependency in self.get_dependencies(*phase) {
+                if !requested.contains(dependency) {
+                    return Err(PhaseError::MissingDependency {
+                        phase: *phase,
+                        dependency: *dependency,
+                    });
+                }
+            }
+        }
+
+        let mut ordered: Vec<Phase> = Vec::with_capacity(requested.len());
+        let mut remaining = requested;
+        while !remaining.is_empty() {
+            let ready = remaining.iter().position(|phase| {
+                self.get_dependencies(*phase)
+                    .iter()
+                    .all(|dep| ordered.contains(dep))
+            });
+            match ready {
+                Some(index) => ordered.push(remaining.remove(index)),
+                None => return Err(PhaseError::CircularDependency(self.find_cycle(&remaining))),
+            }
+        }
+
+        Ok(ordered)
+    }
+
+    /// Follow unresolved dependencies from the first blocked phase until one repeats
+    fn find_cycle(&self, blocked: &[Phase]) -> Vec<Phase> {
+        let mut path: Vec<Phase> = Vec::new();
+        let mut current = blocked[0];
+        while !path.contains(&current) {
+            path.push(current);
+            match self
+                .get_dependencies(current)
+                .iter()
+                .find(|dep| blocked.contains(dep))
+            {
+                Some(next) => current = *next,
+                None => return path,
+            }
+        }
+        let start = path.iter().position(|p| *p == current).unwrap_or(0);
+        let mut cycle = path.split_off(start);
+        cycle.push(current);
+        cycle
+    }
+
+    /// Return the reason a phase should be skipped, if its skip condition holds
+    pub fn skip_reason(&self, phase: Phase, state: &PhaseState) -> Option<String> {
+        self.configs
+            .get(&phase)
+            .and_then(|c| c.skip_when.as_ref())
+            .filter(|condition| condition.matches(state))
+            .map(|condition| format!("skip condition met: {}", condition))
+    }
+
+    /// Run the requested phases in dependency order
+    ///
+    /// Each phase's skip condition is evaluated against the state accumulated by
+    /// the phases before it. A skipped phase still satisfies its dependents.
+    pub fn run_phases<F>(
+        &self,
+        phases: &[Phase],
+        state: &mut PhaseState,
+        mut execute: F,
+    ) -> Result<Vec<PhaseOutcome>, PhaseError>
+    where
+        F: FnMut(Phase, &mut PhaseState) -> Result<(), PhaseError>,
+    {
+        let mut outcomes = Vec::new();
+        for phase in self.order_phases(phases)? {
+            if let Some(reason) = self.skip_reason(phase, state) {
+                outcomes.push(PhaseOutcome::Skipped { phase, reason });
+                continue;
+            }
+            execute(phase, state)?;
+            outcomes.push(PhaseOutcome::Completed { phase });
+        }
+        Ok(outcomes)
+    }
 }
 
 #[cfg(test)]
@@ -480,4 +707,112 @@ mod tests {
         // Should use defaults when file doesn't exist
         assert_eq!(manager.get_agent_for_phase(Phase::Planning), "codex");
     }
+
+    #[test]
+    fn test_conditionally_skipped_phase() {
+        let mut manager = PhaseManager::new();
+        manager.set_config(
+            Phase::Review,
+            PhaseConfig::new("claude-code").with_skip_when(SkipCondition::Below {
+                key: "changed_lines".to_string(),
+                threshold: 20.0,
+            }),
+        );
+
+        let mut state = PhaseState::new();
+        let mut executed = Vec::new();
+        let outcomes = manager
+            .run_phases(
+                &[Phase::Implementation, Phase::Review],
+                &mut state,
+                |phase, state| {
+                    executed.push(phase);
+                    if phase == Phase::Implementation {
+                        state.set("changed_lines", 3);
+                    }
+                    Ok(())
+                },
+            )
+            .unwrap();
+
+        assert_eq!(executed, vec![Phase::Implementation]);
+        assert_eq!(outcomes.len(), 2);
+        assert!(!outcomes[0].is_skipped());
+        assert!(outcomes[1].is_skipped());
+        assert_eq!(outcomes[1].phase(), Phase::Review);
+
+        // A larger change runs the review
+        let mut state = PhaseState::new().with("changed_lines", 250);
+        let outcomes = manager
+            .run_phases(&[Phase::Review], &mut state, |_, _| Ok(()))
+            .unwrap();
+        assert_eq!(
+            outcomes,
+            vec![PhaseOutcome::Completed {
+                phase: Phase::Review
+            }]
+        );
+    }
+
+    #[test]
+    fn test_dependency_ordered_run() {
+        let mut manager = PhaseManager::new();
+        manager.set_config(
+            Phase::Review,
+            PhaseConfig::new("claude-code").with_dependency(Phase::Refactor),
+        );
+        manager.set_config(
+            Phase::Refactor,
+            PhaseConfig::new("aider").with_dependency(Phase::Implementation),
+        );
+
+        let mut executed = Vec::new();
+        manager
+            .run_phases(
+                &[Phase::Review, Phase::Refactor, Phase::Implementation],
+                &mut PhaseState::new(),
+                |phase, _| {
+                    executed.push(phase);
+                    Ok(())
+                },
+            )
+            .unwrap();
+
+        assert_eq!(
+            executed,
+            vec![Phase::Implementation, Phase::Refactor, Phase::Review]
+        );
+
+        let err = manager.order_phases(&[Phase::Review]).unwrap_err();
+        assert!(matches!(
+            err,
+            PhaseError::MissingDependency {
+                phase: Phase::Review,
+                dependency: Phase::Refactor
+            }
+        ));
+    }
+
+    #[test]
+    fn test_circular_phase_dependency() {
+        let mut manager = PhaseManager::new();
+        manager.set_config(
+            Phase::Review,
+            PhaseConfig::new("claude-code").with_dependency(Phase::Refactor),
+        );
+        manager.set_config(
+            Phase::Refactor,
+            PhaseConfig::new("aider").with_dependency(Phase::Review),
+        );
+
+        let err = manager
+            .order_phases(&[Phase::Refactor, Phase::Review])
+            .unwrap_err();
+        match err {
+            PhaseError::CircularDependency(cycle) => {
+                assert_eq!(cycle, vec![Phase::Refactor, Phase::Review, Phase::Refactor]);
+            }
+            other => panic!("unexpected error: {other}"),
+        }
+    }
 }
@@ -4,4 +4,6 @@
 
 mod manager;
 
-pub use manager::{Phase, PhaseConfig, PhaseError, PhaseManager};
+pub use manager::{
+    Phase, PhaseConfig, PhaseError, PhaseManager, PhaseOutcome, PhaseState, SkipCondition,
+};
@@ -1062,6 +1062,9 @@ mod tests {
             project_path: PathBuf::from("/tmp/test-project"),
             provider_name: "anthropic".to_string(),
             model_name: "claude-3".to_string(),
+            execution_id: None,
+            task_type: None,
+            history: Default::default(),
         }
     }
 
@@ -7,14 +7,27 @@
 //! - **Browsing**: HTTP-fetch `marketplace.json` from raw GitHub URLs (fast)
 //! - **Installing**: Git clone on demand (only when user clicks Install)
 //! - **Fallback**: For non-GitHub URLs, git clone to temp, extract marketplace.json
+//!
+//! ## Search
+//! `search_marketplaces` queries a persisted index of every enabled
+//! marketplace (`marketplace-cache/search-index.json`). Index entries are
+//! refreshed when older than [`SEARCH_CACHE_TTL_SECS`] or after
+//! `refresh_plugins` invalidates them.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
 
 use crate::services::plugins::models::{
-    MarketplaceConfig, MarketplaceManifest, MarketplacePluginEntry, MarketplaceSourceType,
+    MarketplaceConfig, MarketplaceManifest, MarketplacePlugin, MarketplacePluginEntry,
+    MarketplaceSearchFilters, MarketplaceSearchResult, MarketplaceSourceType, PluginInfo,
 };
 use crate::utils::configure_background_process;
 
+/// How long a cached marketplace index is considered fresh (6 hours).
+pub const SEARCH_CACHE_TTL_SECS: i64 = 6 * 60 * 60;
+
 /// Cache directory for marketplace manifests.
 fn cache_dir() -> Option<PathBuf> {
     dirs::home_dir().map(|home| home.join(".plan-cascade").join("marketplace-cache"))
@@ -188,6 +201,37 @@ pub async fn fetch_all_marketplaces(
     results
 }
 
+/// Convert a marketplace entry into a UI-facing plugin enriched with local
+/// install/enable status.
+pub fn build_marketplace_plugin(
+    entry: &MarketplacePluginEntry,
+    marketplace_name: &str,
+    marketplace_config: Option<&MarketplaceConfig>,
+    installed_plugins: &[PluginInfo],
+) -> MarketplacePlugin {
+    let local = installed_plugins.iter().find(|p| p.name == entry.name);
+
+    let source_spec = marketplace_config
+        .and_then(|cfg| resolve_install_source(entry, cfg).ok())
+        .map(|s| s.to_spec_string())
+        .unwrap_or_default();
+
+    MarketplacePlugin {
+        name: entry.name.clone(),
+        version: entry.version.clone().unwrap_or_default(),
+        description: entry.description.clone().unwrap_or_default(),
+        author: entry.author_string(),
+        repository: entry.repository.clone(),
+        license: entry.license.clone(),
+        keywords: entry.keywords.clone(),
+        category: entry.category.clone(),
+        marketplace_name: marketplace_name.to_string(),
+        source_spec,
+        installed: local.is_some(),
+        enabled: local.is_some_and(|p| p.enabled),
+    }
+}
+
 /// Resolve the install source for a marketplace plugin entry.
 ///
 /// Converts the plugin's source field into a git URL or local path.
@@ -316,6 +360,256 @@ pub fn remove_cached_manifest(name: &str) {
     }
 }
 
+// ============================================================================
+// Search index cache
+// ============================================================================
+
+/// A marketplace manifest captured in the search index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedMarketplace {
+    /// Unix timestamp (seconds) of the last successful fetch.
+    /// `0` marks the entry as stale so the next search refetches it.
+    pub fetched_at: i64,
+    pub manifest: MarketplaceManifest,
+}
+
+/// Persisted index of marketplace manifests, keyed by marketplace name.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarketplaceSearchCache {
+    #[serde(default)]
+    pub marketplaces: HashMap<String, CachedMarketplace>,
+}
+
+impl MarketplaceSearchCache {
+    /// Whether the named marketplace is missing or older than `ttl_secs`.
+    pub fn is_stale(&self, name: &str, now: i64, ttl_secs: i64) -> bool {
+        match self.marketplaces.get(name) {
+            Some(cached) => cached.fetched_at <= 0 || now - cached.fetched_at >= ttl_secs,
+            None => true,
+        }
+    }
+
+    /// Store a freshly fetched manifest.
+    pub fn insert(&mut self, name: &str, manifest: MarketplaceManifest, now: i64) {
+        self.marketplaces.insert(
+            name.to_string(),
+            CachedMarketplace {
+                fetched_at: now,
+                manifest,
+            },
+        );
+    }
+
+    /// Mark every entry stale while keeping manifests as an offline fallback.
+    pub fn invalidate(&mut self) {
+        for cached in self.marketplaces.values_mut() {
+            cached.fetched_at = 0;
+        }
+    }
+
+    /// Load the cache from `path`, returning an empty cache on any error.
+    pub fn load_from(path: &Path) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist the cache to `path`, creating parent directories as needed.
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create cache dir: {}", e))?;
+        }
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize search cache: {}", e))?;
+        std::fs::write(path, content).map_err(|e| format!("Failed to write search cache: {}", e))
+    }
+}
+
+/// Path of the persisted search index.
+fn search_cache_path() -> Option<PathBuf> {
+    cache_dir().map(|dir| dir.join("search-index.json"))
+}
+
+/// Load the persisted search index (empty if missing or unreadable).
+pub fn load_search_cache() -> MarketplaceSearchCache {
+    search_cache_path()
+        .map(|path| MarketplaceSearchCache::load_from(&path))
+        .unwrap_or_default()
+}
+
+/// Mark all cached marketplace indexes stale so the next search refetches.
+pub fn invalidate_search_cache() {
+    if let Some(path) = search_cache_path() {
+        let mut cache = MarketplaceSearchCache::load_from(&path);
+        if cache.marketplaces.is_empty() {
+            return;
+        }
+        cache.invalidate();
+        if let Err(e) = cache.save_to(&path) {
+            eprintln!("[marketplace] Failed to invalidate search cache: {}", e);
+        }
+    }
+}
+
+/// Refresh stale cache entries for all enabled marketplaces and return the
+/// updated cache.
+///
+/// Fetch failures keep the previous (stale) manifest so search keeps working
+/// offline.
+pub async fn refresh_search_cache(configs: &[MarketplaceConfig]) -> MarketplaceSearchCache {
+    let mut cache = load_search_cache();
+    let now = chrono::Utc::now().timestamp();
+
+    let stale: Vec<MarketplaceConfig> = configs
+        .iter()
+        .filter(|c| c.enabled && cache.is_stale(&c.name, now, SEARCH_CACHE_TTL_SECS))
+        .cloned()
+        .collect();
+
+    if stale.is_empty() {
+        return cache;
+    }
+
+    for (name, manifest) in fetch_all_marketplaces(&stale).await {
+        cache.insert(&name, manifest, now);
+    }
+
+    if let Some(path) = search_cache_path() {
+        if let Err(e) = cache.save_to(&path) {
+            eprintln!("[marketplace] Failed to persist search cache: {}", e);
+        }
+    }
+    cache
+}
+
+/// Score how well an entry matches a single lowercase query term.
+///
+/// Returns `0` when the term matches nothing.
+fn score_term(entry: &MarketplacePluginEntry, term: &str) -> u32 {
+    let name = entry.name.to_lowercase();
+    let mut score = if name == term {
+        100
+    } else if name.starts_with(term) {
+        60
+    } else if name.contains(term) {
+        40
+    } else {
+        0
+    };
+
+    if entry.keywords.iter().any(|k| k.to_lowercase() == term) {
+        score += 30;
+    } else if entry
+        .keywords
+        .iter()
+        .any(|k| k.to_lowercase().contains(term))
+    {
+        score += 15;
+    }
+
+    if entry
+        .category
+        .as_deref()
+        .is_some_and(|c| c.to_lowercase().contains(term))
+    {
+        score += 10;
+    }
+
+    if entry
+        .description
+        .as_deref()
+        .is_some_and(|d| d.to_lowercase().contains(term))
+    {
+        score += 10;
+    }
+
+    score
+}
+
+/// Search the cached marketplace indexes.
+///
+/// Only marketplaces that are currently configured and enabled are searched,
+/// so cache entries left behind by removed marketplaces are ignored. Every whitespace-separated query term must match the plugin name,
+/// keywords, category, or description. Results are ordered by descending
+/// relevance, then by name. An empty query matches every plugin that passes
+/// the filters.
+pub fn search_marketplaces(
+    cache: &MarketplaceSearchCache,
+    configs: &[MarketplaceConfig],
+    query: &str,
+    filters: &MarketplaceSearchFilters,
+    installed_plugins: &[PluginInfo],
+) -> Vec<MarketplaceSearchResult> {
+    let terms: Vec<String> = query.split_whitespace().map(|t| t.to_lowercase()).collect();
+    let tags: Vec<String> = filters.tags.iter().map(|t| t.to_lowercase()).collect();
+    let category = filters.category.as_deref().map(str::to_lowercase);
+
+    let mut results = Vec::new();
+
+    for (marketplace_name, cached) in &cache.marketplaces {
+        let Some(config) = configs.iter().find(|c| &c.name == marketplace_name) else {
+            continue;
+        };
+        if !config.enabled {
+            continue;
+        }
+        if !filters.marketplaces.is_empty() && !filters.marketplaces.contains(marketplace_name) {
+            continue;
+        }
+
+        for entry in &cached.manifest.plugins {
+            let keywords: Vec<String> = entry.keywords.iter().map(|k| k.to_lowercase()).collect();
+            if !tags.iter().all(|t| keywords.contains(t)) {
+                continue;
+            }
+            if let Some(category) = &category {
+                if entry.category.as_deref().map(str::to_lowercase).as_ref() != Some(category) {
+                    continue;
+                }
+            }
+
+            let mut score = 0;
+            let mut all_matched = true;
+            for term in &terms {
+                let term_score = score_term(entry, term);
+                if term_score == 0 {
+                    all_matched = false;
+                    break;
+                }
+                score += term_score;
+            }
+            if !all_matched {
+                continue;
+            }
+
+            let plugin =
+                build_marketplace_plugin(entry, marketplace_name, Some(config), installed_plugins);
+            if filters
+                .installed
+                .is_some_and(|want| plugin.installed != want)
+            {
+                continue;
+            }
+
+            results.push(MarketplaceSearchResult { plugin, score });
+        }
+    }
+
+    results.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.plugin.name.cmp(&b.plugin.name))
+            .then_with(|| a.plugin.marketplace_name.cmp(&b.plugin.marketplace_name))
+    });
+
+    if let Some(limit) = filters.limit {
+        results.truncate(limit);
+    }
+    results
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -450,4 +744,207 @@ mod tests {
         let result = resolve_install_source(&entry, &config).unwrap();
         matches!(result, InstallSource::GitUrl(_));
     }
+
+    fn entry(name: &str, description: &str, keywords: &[&str]) -> MarketplacePluginEntry {
+        MarketplacePluginEntry {
+            name: name.to_string(),
+            source: Some(serde_json::Value::String(format!("./plugins/{}", name))),
+            description: Some(description.to_string()),
+            version: Some("1.0.0".to_string()),
+            author: None,
+            category: None,
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            homepage: None,
+            repository: None,
+            license: None,
+        }
+    }
+
+    fn manifest(name: &str, plugins: Vec<MarketplacePluginEntry>) -> MarketplaceManifest {
+        MarketplaceManifest {
+            name: name.to_string(),
+            owner: None,
+            metadata: None,
+            plugins,
+        }
+    }
+
+    fn config(name: &str) -> MarketplaceConfig {
+        MarketplaceConfig {
+            name: name.to_string(),
+            source: MarketplaceSourceType::LocalPath {
+                path: format!("/tmp/{}", name),
+            },
+            enabled: true,
+        }
+    }
+
+    fn seeded_cache() -> MarketplaceSearchCache {
+        let mut cache = MarketplaceSearchCache::default();
+        cache.insert(
+            "official",
+            manifest(
+                "official",
+                vec![
+                    entry("git-helper", "Git workflow commands", &["git", "vcs"]),
+                    entry("lint-runner", "Run linters on save", &["lint", "quality"]),
+                ],
+            ),
+            1_000,
+        );
+        cache.insert(
+            "community",
+            manifest(
+                "community",
+                vec![
+                    entry("gitflow", "Branching model helpers", &["git"]),
+                    entry("docs-writer", "Write docs with git history", &["docs"]),
+                ],
+            ),
+            1_000,
+        );
+        cache
+    }
+
+    #[test]
+    fn test_search_tag_filter_across_marketplaces() {
+        let cache = seeded_cache();
+        let configs = vec![config("official"), config("community")];
+        let filters = MarketplaceSearchFilters {
+            tags: vec!["GIT".to_string()],
+            ..Default::default()
+        };
+
+        let results = search_marketplaces(&cache, &configs, "", &filters, &[]);
+        let found: Vec<(&str, &str)> = results
+            .iter()
+            .map(|r| (r.plugin.name.as_str(), r.plugin.marketplace_name.as_str()))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![("git-helper", "official"), ("gitflow", "community")]
+        );
+    }
+
+    #[test]
+    fn test_search_name_query_ranks_by_relevance() {
+        let cache = seeded_cache();
+        let configs = vec![config("official"), config("community")];
+
+        let results = search_marketplaces(
+            &cache,
+            &configs,
+            "git",
+            &MarketplaceSearchFilters::default(),
+            &[],
+        );
+        let names: Vec<&str> = results.iter().map(|r| r.plugin.name.as_str()).collect();
+
+        // Name prefix + exact tag beats a description-only match.
+        assert_eq!(names, vec!["git-helper", "gitflow", "docs-writer"]);
+        assert!(results[0].score > results[2].score);
+        assert_eq!(results[1].plugin.marketplace_name, "community");
+        assert!(results[1]
+            .plugin
+            .source_spec
+            .starts_with("marketplace:community:"));
+    }
+
+    #[test]
+    fn test_search_marks_installed_and_filters() {
+        let cache = seeded_cache();
+        let configs = vec![config("official"), config("community")];
+        let installed = vec![PluginInfo {
+            name: "gitflow".to_string(),
+            version: "1.0.0".to_string(),
+            description: String::new(),
+            source: crate::services::plugins::models::PluginSource::Installed,
+            enabled: true,
+            skill_count: 0,
+            command_count: 0,
+            hook_count: 0,
+            has_instructions: false,
+            author: None,
+            compat_level: crate::services::plugins::models::PluginCompatLevel::Full,
+            compat_summary: String::new(),
+            compat_checked_at: 0,
+        }];
+        let filters = MarketplaceSearchFilters {
+            installed: Some(true),
+            ..Default::default()
+        };
+
+        let results = search_marketplaces(&cache, &configs, "git", &filters, &installed);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].plugin.name, "gitflow");
+        assert!(results[0].plugin.installed);
+        assert!(results[0].plugin.enabled);
+    }
+
+    #[test]
+    fn test_search_skips_disabled_marketplaces() {
+        let cache = seeded_cache();
+        let mut community = config("community");
+        community.enabled = false;
+        let configs = vec![config("official"), community];
+
+        let results = search_marketplaces(
+            &cache,
+            &configs,
+            "git",
+            &MarketplaceSearchFilters::default(),
+            &[],
+        );
+        assert!(results
+            .iter()
+            .all(|r| r.plugin.marketplace_name == "official"));
+    }
+
+    #[test]
+    fn test_search_skips_removed_marketplaces() {
+        let cache = seeded_cache();
+        let configs = vec![config("official")];
+
+        let results = search_marketplaces(
+            &cache,
+            &configs,
+            "git",
+            &MarketplaceSearchFilters::default(),
+            &[],
+        );
+        assert!(!results.is_empty());
+        assert!(results
+            .iter()
+            .all(|r| r.plugin.marketplace_name == "official"));
+    }
+
+    #[test]
+    fn test_search_cache_ttl_and_invalidate() {
+        let mut cache = seeded_cache();
+        assert!(!cache.is_stale("official", 1_000 + 60, SEARCH_CACHE_TTL_SECS));
+        assert!(cache.is_stale(
+            "official",
+            1_000 + SEARCH_CACHE_TTL_SECS,
+            SEARCH_CACHE_TTL_SECS
+        ));
+        assert!(cache.is_stale("unknown", 1_000, SEARCH_CACHE_TTL_SECS));
+
+        cache.invalidate();
+        assert!(cache.is_stale("official", 1_000, SEARCH_CACHE_TTL_SECS));
+        // Manifests are kept as an offline fallback
+        assert_eq!(cache.marketplaces["official"].manifest.plugins.len(), 2);
+    }
+
+    #[test]
+    fn test_search_cache_persists_across_loads() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cache").join("search-index.json");
+
+        seeded_cache().save_to(&path).unwrap();
+        let loaded = MarketplaceSearchCache::load_from(&path);
+
+        assert_eq!(loaded.marketplaces.len(), 2);
+        assert_eq!(loaded.marketplaces["community"].fetched_at, 1_000);
+    }
 }
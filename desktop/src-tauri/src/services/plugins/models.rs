@@ -682,6 +682,36 @@ pub struct MarketplacePlugin {
     pub enabled: bool,
 }
 
+/// Filters applied by `search_marketplace`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarketplaceSearchFilters {
+    /// Plugins must carry every one of these keywords (case-insensitive)
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Restrict to a single category (case-insensitive)
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Restrict to these marketplaces (empty = all enabled marketplaces)
+    #[serde(default)]
+    pub marketplaces: Vec<String>,
+    /// Only installed (`true`) or only not-installed (`false`) plugins
+    #[serde(default)]
+    pub installed: Option<bool>,
+    /// Maximum number of results
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// A marketplace search hit with its relevance score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketplaceSearchResult {
+    /// The matched plugin (includes marketplace name and install status)
+    #[serde(flatten)]
+    pub plugin: MarketplacePlugin,
+    /// Relevance score (higher is better; 0 for an empty query)
+    pub score: u32,
+}
+
 /// Marketplace info for UI listing.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketplaceInfo {
@@ -4,15 +4,22 @@
 
 use regex::Regex;
 use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
-use crate::models::prompt::{PromptCreateRequest, PromptTemplate, PromptUpdateRequest};
+use crate::models::prompt::{
+    PromptCreateRequest, PromptSortMode, PromptTemplate, PromptUpdateRequest, PromptVariable,
+    RenderedPrompt,
+};
 use crate::storage::database::DbPool;
 use crate::storage::Database;
 use crate::utils::error::{AppError, AppResult};
 
 use rusqlite::OptionalExtension;
 
+/// Days after which a use counts half as much toward `usage_score`.
+pub const USAGE_HALF_LIFE_DAYS: f64 = 14.0;
+
 #[derive(Clone, Copy)]
 struct BuiltinPromptCatalogEntry {
     id: &'static str,
@@ -308,6 +315,8 @@ fn localize_builtin_prompt(mut prompt: PromptTemplate, locale: Option<&str>) ->
         prompt.content = entry.content.to_string();
         prompt.description = Some(entry.description.to_string());
         prompt.variables = extract_variables(entry.content);
+        prompt.variable_schema =
+            resolve_variable_schema(&prompt.variables, &prompt.variable_schema);
     }
     prompt
 }
@@ -371,12 +380,13 @@ impl PromptService {
         Ok(())
     }
 
-    /// List prompts with optional category filter and search
+    /// List prompts with optional category filter, search and ordering
     pub fn list_prompts(
         &self,
         category: Option<&str>,
         search: Option<&str>,
         locale: Option<&str>,
+        sort: Option<PromptSortMode>,
     ) -> AppResult<Vec<PromptTemplate>> {
         let conn = self
             .pool
@@ -385,7 +395,8 @@ impl PromptService {
 
         let mut sql = String::from(
             "SELECT id, title, content, description, category, tags, variables,
-                    is_builtin, is_pinned, use_count, last_used_at, created_at, updated_at
+                    is_builtin, is_pinned, use_count, last_used_at, created_at, updated_at,
+                    variable_schema
              FROM prompts WHERE 1=1",
         );
         let mut params_vec: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
@@ -414,16 +425,67 @@ impl PromptService {
         }
 
         prompts.retain(|prompt| matches_search(prompt, search));
-        prompts.sort_by_key(|prompt| {
-            (
-                !prompt.is_pinned,
-                Reverse(prompt.use_count),
-                prompt.title.to_ascii_lowercase(),
-            )
-        });
+        match sort.unwrap_or_default() {
+            PromptSortMode::UseCount => prompts.sort_by_key(|prompt| {
+                (
+                    !prompt.is_pinned,
+                    Reverse(prompt.use_count),
+                    prompt.title.to_ascii_lowercase(),
+                )
+            }),
+            PromptSortMode::Title => {
+                prompts.sort_by_key(|prompt| (!prompt.is_pinned, prompt.title.to_ascii_lowercase()))
+            }
+            PromptSortMode::Usage => {
+                let scores = Self::usage_scores(&conn)?;
+                for prompt in &mut prompts {
+                    prompt.usage_score = scores.get(&prompt.id).copied().unwrap_or(0.0);
+                }
+                rank_by_usage(&mut prompts);
+            }
+        }
         Ok(prompts)
     }
 
+    /// Recency-weighted usage per prompt id.
+    ///
+    /// Logged uses decay by age; uses counted before per-use logging existed
+    /// are weighted as if they happened at `last_used_at`.
+    fn usage_scores(conn: &rusqlite::Connection) -> AppResult<HashMap<String, f64>> {
+        let mut scores: HashMap<String, f64> = HashMap::new();
+
+        let mut stmt = conn
+            .prepare("SELECT prompt_id, julianday('now') - julianday(used_at) FROM prompt_uses")?;
+        let mut logged: HashMap<String, u32> = HashMap::new();
+        for row in stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Option<f64>>(1)?))
+        })? {
+            let (id, age_days) = row?;
+            *scores.entry(id.clone()).or_default() += decayed_weight(age_days);
+            *logged.entry(id).or_default() += 1;
+        }
+
+        let mut stmt = conn.prepare(
+            "SELECT id, use_count, julianday('now') - julianday(last_used_at)
+             FROM prompts WHERE use_count > 0",
+        )?;
+        for row in stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, u32>(1)?,
+                row.get::<_, Option<f64>>(2)?,
+            ))
+        })? {
+            let (id, use_count, age_days) = row?;
+            let unlogged = use_count.saturating_sub(logged.get(&id).copied().unwrap_or(0));
+            if unlogged > 0 {
+                *scores.entry(id).or_default() += unlogged as f64 * decayed_weight(age_days);
+            }
+        }
+
+        Ok(scores)
+    }
+
     /// Get a single prompt by ID
     pub fn get_prompt(&self, id: &str) -> AppResult<Option<PromptTemplate>> {
         let conn = self
@@ -433,7 +495,8 @@ impl PromptService {
 
         let mut stmt = conn.prepare(
             "SELECT id, title, content, description, category, tags, variables,
-                    is_builtin, is_pinned, use_count, last_used_at, created_at, updated_at
+                    is_builtin, is_pinned, use_count, last_used_at, created_at, updated_at,
+                    variable_schema
              FROM prompts WHERE id = ?1",
         )?;
 
@@ -463,12 +526,14 @@ impl PromptService {
 
         let id = Uuid::new_v4().to_string();
         let variables = extract_variables(&req.content);
+        let schema = resolve_variable_schema(&variables, &req.variable_schema);
         let tags_json = serde_json::to_string(&req.tags).unwrap_or_else(|_| "[]".to_string());
         let variables_json = serde_json::to_string(&variables).unwrap_or_else(|_| "[]".to_string());
+        let schema_json = serde_json::to_string(&schema).unwrap_or_else(|_| "[]".to_string());
 
         conn.execute(
-            "INSERT INTO prompts (id, title, content, description, category, tags, variables, is_builtin, is_pinned, use_count)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0, ?8, 0)",
+            "INSERT INTO prompts (id, title, content, description, category, tags, variables, is_builtin, is_pinned, use_count, variable_schema)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 0, ?8, 0, ?9)",
             rusqlite::params![
                 id,
                 req.title,
@@ -478,6 +543,7 @@ impl PromptService {
                 tags_json,
                 variables_json,
                 req.is_pinned as i32,
+                schema_json,
             ],
         )?;
 
@@ -502,15 +568,19 @@ impl PromptService {
         let category = normalize_prompt_category(&req.category.unwrap_or(existing.category));
         let tags = req.tags.unwrap_or(existing.tags);
         let is_pinned = req.is_pinned.unwrap_or(existing.is_pinned);
+        let declared = req.variable_schema.unwrap_or(existing.variable_schema);
 
         let variables = extract_variables(&content);
+        let schema = resolve_variable_schema(&variables, &declared);
         let tags_json = serde_json::to_string(&tags).unwrap_or_else(|_| "[]".to_string());
         let variables_json = serde_json::to_string(&variables).unwrap_or_else(|_| "[]".to_string());
+        let schema_json = serde_json::to_string(&schema).unwrap_or_else(|_| "[]".to_string());
 
         conn.execute(
             "UPDATE prompts SET title = ?1, content = ?2, description = ?3, category = ?4,
-             tags = ?5, variables = ?6, is_pinned = ?7, updated_at = datetime('now')
-             WHERE id = ?8",
+             tags = ?5, variables = ?6, is_pinned = ?7, variable_schema = ?8,
+             updated_at = datetime('now')
+             WHERE id = ?9",
             rusqlite::params![
                 title,
                 content,
@@ -519,6 +589,7 @@ impl PromptService {
                 tags_json,
                 variables_json,
                 is_pinned as i32,
+                schema_json,
                 id,
             ],
         )?;
@@ -553,8 +624,36 @@ impl PromptService {
         Ok(())
     }
 
-    /// Record usage of a prompt (increment use_count, update last_used_at)
-    pub fn record_use(&self, id: &str) -> AppResult<()> {
+    /// Substitute `vars` into a prompt's `{{variable}}` placeholders.
+    ///
+    /// Missing variables take their declared default; a required variable
+    /// with neither a value nor a default is a validation error.
+    pub fn render_prompt(
+        &self,
+        id: &str,
+        vars: &HashMap<String, String>,
+        locale: Option<&str>,
+    ) -> AppResult<RenderedPrompt> {
+        let prompt = self
+            .get_prompt_localized(id, locale)?
+            .ok_or_else(|| AppError::not_found(format!("Prompt not found: {}", id)))?;
+        let (content, variables) = render_template(&prompt.content, &prompt.variable_schema, vars)?;
+        Ok(RenderedPrompt {
+            prompt_id: prompt.id,
+            content,
+            variables,
+        })
+    }
+
+    /// Record usage of a prompt (increment use_count, update last_used_at).
+    ///
+    /// `variables` are the values the prompt was rendered with, kept for
+    /// usage analytics.
+    pub fn record_use(
+        &self,
+        id: &str,
+        variables: Option<&BTreeMap<String, String>>,
+    ) -> AppResult<()> {
         let conn = self
             .pool
             .get()
@@ -566,6 +665,15 @@ impl PromptService {
             rusqlite::params![id],
         )?;
 
+        let variables_json = variables
+            .map(serde_json::to_string)
+            .transpose()?
+            .unwrap_or_else(|| "{}".to_string());
+        conn.execute(
+            "INSERT INTO prompt_uses (prompt_id, variables) VALUES (?1, ?2)",
+            rusqlite::params![id, variables_json],
+        )?;
+
         Ok(())
     }
 
@@ -599,10 +707,95 @@ fn extract_variables(content: &str) -> Vec<String> {
     vars
 }
 
+/// Weight of one use `age_days` old; unknown ages count as fully decayed.
+fn decayed_weight(age_days: Option<f64>) -> f64 {
+    match age_days {
+        Some(age) => 0.5_f64.powf(age.max(0.0) / USAGE_HALF_LIFE_DAYS),
+        None => 0.0,
+    }
+}
+
+/// Pinned prompts first, then by usage score, use count and title.
+fn rank_by_usage(prompts: &mut [PromptTemplate]) {
+    prompts.sort_by(|a, b| {
+        b.is_pinned
+            .cmp(&a.is_pinned)
+            .then_with(|| b.usage_score.total_cmp(&a.usage_score))
+            .then_with(|| b.use_count.cmp(&a.use_count))
+            .then_with(|| {
+                a.title
+                    .to_ascii_lowercase()
+                    .cmp(&b.title.to_ascii_lowercase())
+            })
+    });
+}
+
+/// Schema for `variables` in order, taking declarations from `declared` and
+/// treating undeclared placeholders as required.
+fn resolve_variable_schema(
+    variables: &[String],
+    declared: &[PromptVariable],
+) -> Vec<PromptVariable> {
+    let mut schema: Vec<PromptVariable> = Vec::with_capacity(variables.len());
+    for name in variables {
+        if schema.iter().any(|v| &v.name == name) {
+            continue;
+        }
+        schema.push(
+            declared
+                .iter()
+                .find(|v| &v.name == name)
+                .cloned()
+                .unwrap_or_else(|| PromptVariable::named(name.clone())),
+        );
+    }
+    schema
+}
+
+/// Substitute placeholders in one pass, so values that themselves contain
+/// `{{...}}` are left as-is. Returns the content and the values used.
+fn render_template(
+    content: &str,
+    schema: &[PromptVariable],
+    vars: &HashMap<String, String>,
+) -> AppResult<(String, BTreeMap<String, String>)> {
+    let resolved_schema = resolve_variable_schema(&extract_variables(content), schema);
+    let mut values = BTreeMap::new();
+    let mut missing = Vec::new();
+    for variable in &resolved_schema {
+        match vars.get(&variable.name).or(variable.default.as_ref()) {
+            Some(value) => {
+                values.insert(variable.name.clone(), value.clone());
+            }
+            None if variable.required => missing.push(variable.name.as_str()),
+            None => {
+                values.insert(variable.name.clone(), String::new());
+            }
+        }
+    }
+    if !missing.is_empty() {
+        return Err(AppError::validation(format!(
+            "Missing required prompt variables: {}",
+            missing.join(", ")
+        )));
+    }
+
+    let re = Regex::new(r"\{\{(\w+)\}\}").unwrap();
+    let rendered = re
+        .replace_all(content, |caps: &regex::Captures| values[&caps[1]].clone())
+        .into_owned();
+    Ok((rendered, values))
+}
+
 /// Convert a database row to a PromptTemplate
 fn row_to_prompt(row: &rusqlite::Row) -> PromptTemplate {
     let tags_str: String = row.get::<_, String>(5).unwrap_or_else(|_| "[]".to_string());
     let variables_str: String = row.get::<_, String>(6).unwrap_or_else(|_| "[]".to_string());
+    let schema_str: String = row
+        .get::<_, String>(13)
+        .unwrap_or_else(|_| "[]".to_string());
+    let variables: Vec<String> = serde_json::from_str(&variables_str).unwrap_or_default();
+    let declared: Vec<PromptVariable> = serde_json::from_str(&schema_str).unwrap_or_default();
 
     PromptTemplate {
         id: row.get(0).unwrap_or_default(),
@@ -611,13 +804,15 @@ fn row_to_prompt(row: &rusqlite::Row) -> PromptTemplate {
         description: row.get(3).unwrap_or(None),
         category: normalize_prompt_category(&row.get::<_, String>(4).unwrap_or_default()),
         tags: serde_json::from_str(&tags_str).unwrap_or_default(),
-        variables: serde_json::from_str(&variables_str).unwrap_or_default(),
+        variable_schema: resolve_variable_schema(&variables, &declared),
+        variables,
         is_builtin: row.get::<_, i32>(7).unwrap_or(0) != 0,
         is_pinned: row.get::<_, i32>(8).unwrap_or(0) != 0,
         use_count: row.get::<_, u32>(9).unwrap_or(0),
         last_used_at: row.get(10).unwrap_or(None),
         created_at: row.get(11).unwrap_or(None),
         updated_at: row.get(12).unwrap_or(None),
+        usage_score: 0.0,
     }
 }
 
@@ -635,7 +830,9 @@ mod tests {
         let service = create_service();
         service.seed_builtins().unwrap();
 
-        let prompts = service.list_prompts(None, None, Some("zh-CN")).unwrap();
+        let prompts = service
+            .list_prompts(None, None, Some("zh-CN"), None)
+            .unwrap();
         let review = prompts
             .into_iter()
             .find(|prompt| prompt.id == "builtin-code-review")
@@ -656,10 +853,11 @@ mod tests {
                 category: "".to_string(),
                 tags: vec![],
                 is_pinned: false,
+                variable_schema: vec![],
             })
             .unwrap();
 
-        let prompts = service.list_prompts(None, None, Some("ja")).unwrap();
+        let prompts = service.list_prompts(None, None, Some("ja"), None).unwrap();
         let custom = prompts
             .into_iter()
             .find(|prompt| prompt.id == created.id)
@@ -669,4 +867,203 @@ mod tests {
         assert!(custom.category.is_empty());
         assert_eq!(custom.content, "Keep this custom text");
     }
+
+    fn create_templated(service: &PromptService) -> PromptTemplate {
+        service
+            .create_prompt(PromptCreateRequest {
+                title: "Translate".to_string(),
+                content: "Translate {{text}} into {{language}} ({{tone}}).".to_string(),
+                description: None,
+                category: "writing".to_string(),
+                tags: vec![],
+                is_pinned: false,
+                variable_schema: vec![
+                    PromptVariable {
+                        name: "language".to_string(),
+                        description: Some("Target language".to_string()),
+                        default: Some("English".to_string()),
+                        required: true,
+                    },
+                    PromptVariable {
+                        name: "tone".to_string(),
+                        description: None,
+                        default: None,
+                        required: false,
+                    },
+                ],
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn render_prompt_substitutes_variables() {
+        let service = create_service();
+        let prompt = create_templated(&service);
+        assert_eq!(
+            prompt
+                .variable_schema
+                .iter()
+                .map(|v| v.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["text", "language", "tone"]
+        );
+
+        let vars = HashMap::from([
+            ("text".to_string(), "{{hola}}".to_string()),
+            ("language".to_string(), "French".to_string()),
+            ("tone".to_string(), "formal".to_string()),
+        ]);
+        let rendered = service.render_prompt(&prompt.id, &vars, None).unwrap();
+        // Values are not re-expanded.
+        assert_eq!(rendered.content, "Translate {{hola}} into French (formal).");
+    }
+
+    #[test]
+    fn render_prompt_applies_defaults() {
+        let service = create_service();
+        let prompt = create_templated(&service);
+
+        let vars = HashMap::from([("text".to_string(), "hola".to_string())]);
+        let rendered = service.render_prompt(&prompt.id, &vars, None).unwrap();
+        assert_eq!(rendered.content, "Translate hola into English ().");
+        assert_eq!(rendered.variables["language"], "English");
+        assert_eq!(rendered.variables["tone"], "");
+    }
+
+    #[test]
+    fn render_prompt_rejects_missing_required_variable() {
+        let service = create_service();
+        let prompt = create_templated(&service);
+
+        let err = service
+            .render_prompt(&prompt.id, &HashMap::new(), None)
+            .unwrap_err();
+        assert!(matches!(err, AppError::Validation(_)));
+        assert!(err.to_string().contains("text"));
+        assert!(!err.to_string().contains("language"));
+    }
+
+    #[test]
+    fn record_use_stores_rendered_variables() {
+        let service = create_service();
+        let prompt = create_templated(&service);
+        let vars = HashMap::from([("text".to_string(), "hola".to_string())]);
+        let rendered = service.render_prompt(&prompt.id, &vars, None).unwrap();
+
+        service
+            .record_use(&prompt.id, Some(&rendered.variables))
+            .unwrap();
+
+        let conn = service.pool.get().unwrap();
+        let stored: String = conn
+            .query_row(
+                "SELECT variables FROM prompt_uses WHERE prompt_id = ?1",
+                rusqlite::params![prompt.id],
+                |row| row.get(0),
+            )
+            .unwrap();
+        let stored: BTreeMap<String, String> = serde_json::from_str(&stored).unwrap();
+        assert_eq!(stored, rendered.variables);
+        assert_eq!(
+            service.get_prompt(&prompt.id).unwrap().unwrap().use_count,
+            1
+        );
+    }
+
+    fn create_plain(service: &PromptService, title: &str, is_pinned: bool) -> PromptTemplate {
+        service
+            .create_prompt(PromptCreateRequest {
+                title: title.to_string(),
+                content: format!("{} prompt", title),
+                description: None,
+                category: "".to_string(),
+                tags: vec![],
+                is_pinned,
+                variable_schema: vec![],
+            })
+            .unwrap()
+    }
+
+    fn log_uses(service: &PromptService, id: &str, count: usize, days_ago: u32) {
+        let conn = service.pool.get().unwrap();
+        for _ in 0..count {
+            conn.execute(
+                "INSERT INTO prompt_uses (prompt_id, used_at)
+                 VALUES (?1, datetime('now', ?2))",
+                rusqlite::params![id, format!("-{} days", days_ago)],
+            )
+            .unwrap();
+        }
+        conn.execute(
+            "UPDATE prompts SET use_count = use_count + ?2,
+             last_used_at = datetime('now', ?3) WHERE id = ?1",
+            rusqlite::params![id, count as u32, format!("-{} days", days_ago)],
+        )
+        .unwrap();
+    }
+
+    fn ranked_titles(service: &PromptService) -> Vec<String> {
+        service
+            .list_prompts(Some(""), None, None, Some(PromptSortMode::Usage))
+            .unwrap()
+            .into_iter()
+            .map(|p| p.title)
+            .collect()
+    }
+
+    #[test]
+    fn usage_sort_weighs_frequency_and_recency() {
+        let service = create_service();
+        let stale = create_plain(&service, "Stale favourite", false);
+        let recent = create_plain(&service, "Recent", false);
+        let frequent = create_plain(&service, "Frequent", false);
+        create_plain(&service, "Unused", false);
+
+        // 20 uses three months ago have decayed below a handful of fresh ones.
+        log_uses(&service, &stale.id, 20, 90);
+        log_uses(&service, &recent.id, 2, 0);
+        log_uses(&service, &frequent.id, 6, 1);
+
+        assert_eq!(
+            ranked_titles(&service),
+            vec!["Frequent", "Recent", "Stale favourite", "Unused"]
+        );
+
+        let listed = service
+            .list_prompts(Some(""), None, None, Some(PromptSortMode::Usage))
+            .unwrap();
+        assert_eq!(listed[0].use_count, 6);
+        assert!(listed[0].last_used_at.is_some());
+        assert!(listed[0].usage_score > listed[1].usage_score);
+    }
+
+    #[test]
+    fn usage_sort_keeps_pinned_prompts_on_top() {
+        let service = create_service();
+        let popular = create_plain(&service, "Popular", false);
+        let pinned = create_plain(&service, "Pinned", true);
+        log_uses(&service, &popular.id, 50, 0);
+        log_uses(&service, &pinned.id, 1, 30);
+
+        assert_eq!(ranked_titles(&service), vec!["Pinned", "Popular"]);
+    }
+
+    #[test]
+    fn usage_sort_counts_uses_recorded_before_logging() {
+        let service = create_service();
+        let legacy = create_plain(&service, "Legacy", false);
+        let logged = create_plain(&service, "Logged", false);
+        service
+            .pool
+            .get()
+            .unwrap()
+            .execute(
+                "UPDATE prompts SET use_count = 5, last_used_at = datetime('now') WHERE id = ?1",
+                rusqlite::params![legacy.id],
+            )
+            .unwrap();
+        service.record_use(&logged.id, None).unwrap();
+
+        assert_eq!(ranked_titles(&service), vec!["Legacy", "Logged"]);
+    }
 }
@@ -7,8 +7,12 @@
 // Re-export proxy types from core
 pub use plan_cascade_core::proxy::*;
 
-// Re-export build_http_client from LLM crate
-pub use plan_cascade_llm::http_client::build_http_client;
+// Re-export the HTTP client factories and PAC resolver from LLM crate
+pub use plan_cascade_llm::http_client::{
+    build_http_client, build_pac_http_client, build_routed_http_client, build_system_http_client,
+    ProxyRouting,
+};
+pub use plan_cascade_llm::pac::PacResolver;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,512 @@
+//! Proxy Diagnostics
+//!
+//! Step-by-step connectivity checks for a proxy configuration so that a
+//! failing proxy reports *why* it fails: DNS, TCP connect, proxy
+//! authentication (HTTP `CONNECT` or SOCKS5 handshake), TLS, or the
+//! end-to-end fetch itself.
+
+use std::time::{Duration, Instant};
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::services::proxy::{build_http_client, ProxyConfig, ProxyProtocol};
+
+/// Classification of a failed diagnostic step.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProxyFailureKind {
+    /// The proxy or target host name could not be resolved.
+    Dns,
+    /// The TCP connection was refused or reset.
+    Connect,
+    /// A step did not complete within the timeout.
+    Timeout,
+    /// The proxy requires credentials but none were supplied.
+    AuthRequired,
+    /// The proxy rejected the supplied credentials.
+    AuthRejected,
+    /// TLS negotiation with the target failed.
+    Tls,
+    /// The proxy answered with something other than the expected protocol.
+    Protocol,
+    /// The end-to-end request returned a non-success HTTP status.
+    Http,
+}
+
+/// Outcome of a single diagnostic step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticStep {
+    pub success: bool,
+    pub latency_ms: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<ProxyFailureKind>,
+    /// Human-readable detail (error message or what was verified).
+    pub detail: String,
+}
+
+impl DiagnosticStep {
+    fn ok(started: Instant, detail: impl Into<String>) -> Self {
+        Self {
+            success: true,
+            latency_ms: elapsed_ms(started),
+            failure: None,
+            detail: detail.into(),
+        }
+    }
+
+    fn failed(started: Instant, failure: ProxyFailureKind, detail: impl Into<String>) -> Self {
+        Self {
+            success: false,
+            latency_ms: elapsed_ms(started),
+            failure: Some(failure),
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Structured result of [`diagnose_proxy`].
+///
+/// Later steps are skipped (`None`) once an earlier step fails.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProxyDiagnostics {
+    pub success: bool,
+    pub tcp_connect: DiagnosticStep,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auth: Option<DiagnosticStep>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetch: Option<DiagnosticStep>,
+}
+
+impl ProxyDiagnostics {
+    /// The first failing step, if any.
+    pub fn first_failure(&self) -> Option<&DiagnosticStep> {
+        [
+            Some(&self.tcp_connect),
+            self.auth.as_ref(),
+            self.fetch.as_ref(),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|step| !step.success)
+    }
+}
+
+fn elapsed_ms(started: Instant) -> u32 {
+    started.elapsed().as_millis().min(u32::MAX as u128) as u32
+}
+
+/// Run TCP connect, proxy authentication and end-to-end fetch checks.
+pub async fn diagnose_proxy(
+    proxy: &ProxyConfig,
+    test_url: &str,
+    timeout: Duration,
+) -> ProxyDiagnostics {
+    let started = Instant::now();
+    let mut stream = match connect(proxy, timeout).await {
+        Ok(stream) => stream,
+        Err((failure, detail)) => {
+            return ProxyDiagnostics {
+                success: false,
+                tcp_connect: DiagnosticStep::failed(started, failure, detail),
+                auth: None,
+                fetch: None,
+            }
+        }
+    };
+    let tcp_connect = DiagnosticStep::ok(
+        started,
+        format!("Connected to {}:{}", proxy.host, proxy.port),
+    );
+
+    let started = Instant::now();
+    let auth = match tokio::time::timeout(timeout, probe_auth(&mut stream, proxy, test_url)).await {
+        Ok(Ok(Some(detail))) => Some(DiagnosticStep::ok(started, detail)),
+        Ok(Ok(None)) => None,
+        Ok(Err((failure, detail))) => Some(DiagnosticStep::failed(started, failure, detail)),
+        Err(_) => Some(DiagnosticStep::failed(
+            started,
+            ProxyFailureKind::Timeout,
+            "Proxy did not answer the handshake",
+        )),
+    };
+    drop(stream);
+
+    if auth.as_ref().is_some_and(|step| !step.success) {
+        return ProxyDiagnostics {
+            success: false,
+            tcp_connect,
+            auth,
+            fetch: None,
+        };
+    }
+
+    let fetch = fetch_through(proxy, test_url, timeout).await;
+    ProxyDiagnostics {
+        success: fetch.success,
+        tcp_connect,
+        auth,
+        fetch: Some(fetch),
+    }
+}
+
+async fn connect(
+    proxy: &ProxyConfig,
+    timeout: Duration,
+) -> Result<TcpStream, (ProxyFailureKind, String)> {
+    let addrs: Vec<_> = match tokio::time::timeout(
+        timeout,
+        tokio::net::lookup_host((proxy.host.as_str(), proxy.port)),
+    )
+    .await
+    {
+        Ok(Ok(addrs)) => addrs.collect(),
+        Ok(Err(e)) => {
+            return Err((
+                ProxyFailureKind::Dns,
+                format!("Cannot resolve {}: {}", proxy.host, e),
+            ))
+        }
+        Err(_) => {
+            return Err((
+                ProxyFailureKind::Timeout,
+                format!("Resolving {} timed out", proxy.host),
+            ))
+        }
+    };
+    if addrs.is_empty() {
+        return Err((
+            ProxyFailureKind::Dns,
+            format!("{} resolved to no addresses", proxy.host),
+        ));
+    }
+
+    match tokio::time::timeout(timeout, TcpStream::connect(&addrs[..])).await {
+        Ok(Ok(stream)) => Ok(stream),
+        Ok(Err(e)) => Err((
+            ProxyFailureKind::Connect,
+            format!("Cannot connect to {}:{}: {}", proxy.host, proxy.port, e),
+        )),
+        Err(_) => Err((
+            ProxyFailureKind::Timeout,
+            format!("Connecting to {}:{} timed out", proxy.host, proxy.port),
+        )),
+    }
+}
+
+/// Probe the proxy handshake. Returns `Ok(None)` when the protocol cannot
+/// be probed over plain TCP (HTTPS proxies).
+async fn probe_auth(
+    stream: &mut TcpStream,
+    proxy: &ProxyConfig,
+    test_url: &str,
+) -> Result<Option<String>, (ProxyFailureKind, String)> {
+    let credentials = proxy.username.as_deref().zip(proxy.password.as_deref());
+    match proxy.protocol {
+        ProxyProtocol::Http => probe_http_connect(stream, credentials, test_url)
+            .await
+            .map(Some),
+        ProxyProtocol::Socks5 => probe_socks5(stream, credentials).await.map(Some),
+        ProxyProtocol::Https => Ok(None),
+    }
+}
+
+fn io_failure(e: std::io::Error) -> (ProxyFailureKind, String) {
+    (
+        ProxyFailureKind::Protocol,
+        format!("Proxy closed the connection: {}", e),
+    )
+}
+
+async fn probe_http_connect(
+    stream: &mut TcpStream,
+    credentials: Option<(&str, &str)>,
+    test_url: &str,
+) -> Result<String, (ProxyFailureKind, String)> {
+    let target = url::Url::parse(test_url)
+        .ok()
+        .and_then(|u| Some(format!("{}:{}", u.host_str()?, u.port_or_known_default()?)))
+        .unwrap_or_else(|| "example.com:443".to_string());
+
+    let mut request = format!("CONNECT {target} HTTP/1.1\r\nHost: {target}\r\n");
+    if let Some((user, pass)) = credentials {
+        let token = BASE64.encode(format!("{}:{}", user, pass));
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", token));
+    }
+    request.push_str("\r\n");
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .map_err(io_failure)?;
+
+    let mut buf = [0u8; 512];
+    let n = stream.read(&mut buf).await.map_err(io_failure)?;
+    let response = String::from_utf8_lossy(&buf[..n]);
+    let status = response
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| {
+            (
+                ProxyFailureKind::Protocol,
+                "Proxy did not answer with an HTTP status line".to_string(),
+            )
+        })?;
+
+    match status {
+        200..=299 if credentials.is_some() => Ok("Proxy accepted the credentials".to_string()),
+        200..=299 => Ok("Proxy does not require authentication".to_string()),
+        407 if credentials.is_some() => Err((
+            ProxyFailureKind::AuthRejected,
+            "Proxy rejected the credentials (HTTP 407)".to_string(),
+        )),
+        407 => Err((
+            ProxyFailureKind::AuthRequired,
+            "Proxy requires authentication (HTTP 407)".to_string(),
+        )),
+        other => Err((
+            ProxyFailureKind::Protocol,
+            format!("Proxy refused CONNECT with HTTP {}", other),
+        )),
+    }
+}
+
+async fn probe_socks5(
+    stream: &mut TcpStream,
+    credentials: Option<(&str, &str)>,
+) -> Result<String, (ProxyFailureKind, String)> {
+    // Greeting: offer "no auth", plus username/password when configured.
+    let greeting: &[u8] = if credentials.is_some() {
+        &[0x05, 0x02, 0x00, 0x02]
+    } else {
+        &[0x05, 0x01, 0x00]
+    };
+    stream.write_all(greeting).await.map_err(io_failure)?;
+
+    let mut choice = [0u8; 2];
+    stream.read_exact(&mut choice).await.map_err(io_failure)?;
+    if choice[0] != 0x05 {
+        return Err((
+            ProxyFailureKind::Protocol,
+            "Proxy is not a SOCKS5 server".to_string(),
+        ));
+    }
+
+    match (choice[1], credentials) {
+        (0x00, _) => Ok("Proxy does not require authentication".to_string()),
+        (0x02, Some((user, pass))) => {
+            // RFC 1929 username/password sub-negotiation.
+            let mut auth = vec![0x01, user.len().min(255) as u8];
+            auth.extend_from_slice(&user.as_bytes()[..user.len().min(255)]);
+            auth.push(pass.len().min(255) as u8);
+            auth.extend_from_slice(&pass.as_bytes()[..pass.len().min(255)]);
+            stream.write_all(&auth).await.map_err(io_failure)?;
+
+            let mut status = [0u8; 2];
+            stream.read_exact(&mut status).await.map_err(io_failure)?;
+            if status[1] == 0x00 {
+                Ok("Proxy accepted the credentials".to_string())
+            } else {
+                Err((
+                    ProxyFailureKind::AuthRejected,
+                    "Proxy rejected the credentials".to_string(),
+                ))
+            }
+        }
+        (0xFF, None) | (0x02, None) => Err((
+            ProxyFailureKind::AuthRequired,
+            "Proxy requires authentication".to_string(),
+        )),
+        (method, _) => Err((
+            ProxyFailureKind::AuthRejected,
+            format!("Proxy offered no acceptable auth method (0x{:02x})", method),
+        )),
+    }
+}
+
+async fn fetch_through(proxy: &ProxyConfig, test_url: &str, timeout: Duration) -> DiagnosticStep {
+    let client = build_http_client(Some(proxy));
+    let started = Instant::now();
+    match client.get(test_url).timeout(timeout).send().await {
+        Ok(response) => {
+            let status = response.status();
+            if status.is_success() || status.is_redirection() {
+                DiagnosticStep::ok(started, format!("Fetched {} (HTTP {})", test_url, status))
+            } else if status.as_u16() == 407 {
+                DiagnosticStep::failed(
+                    started,
+                    ProxyFailureKind::AuthRejected,
+                    "Proxy rejected the credentials (HTTP 407)",
+                )
+            } else {
+                DiagnosticStep::failed(started, ProxyFailureKind::Http, format!("HTTP {}", status))
+            }
+        }
+        Err(e) => DiagnosticStep::failed(started, classify_reqwest_error(&e), e.to_string()),
+    }
+}
+
+fn classify_reqwest_error(e: &reqwest::Error) -> ProxyFailureKind {
+    if e.is_timeout() {
+        return ProxyFailureKind::Timeout;
+    }
+    let mut chain = e.to_string();
+    let mut source = std::error::Error::source(e);
+    while let Some(inner) = source {
+        chain.push_str(&format!(": {}", inner));
+        source = inner.source();
+    }
+    let chain = chain.to_ascii_lowercase();
+    if chain.contains("dns") || chain.contains("resolve") || chain.contains("lookup") {
+        ProxyFailureKind::Dns
+    } else if chain.contains("certificate") || chain.contains("tls") || chain.contains("ssl") {
+        ProxyFailureKind::Tls
+    } else if chain.contains("auth") || chain.contains("407") {
+        ProxyFailureKind::AuthRejected
+    } else {
+        ProxyFailureKind::Connect
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Minimal HTTP proxy: answers CONNECT and absolute-form GET requests,
+    /// requiring `Proxy-Authorization` for `user:secret`.
+    async fn spawn_http_proxy() -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let expected = format!("Basic {}", BASE64.encode("user:secret"));
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let expected = expected.clone();
+                tokio::spawn(async move {
+                    let mut buf = vec![0u8; 4096];
+                    let n = socket.read(&mut buf).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buf[..n]);
+                    let authorized = request.lines().any(|line| {
+                        line.to_ascii_lowercase()
+                            .starts_with("proxy-authorization:")
+                            && line.split_once(':').map(|(_, v)| v.trim()) == Some(&expected)
+                    });
+                    let response = if !authorized {
+                        "HTTP/1.1 407 Proxy Authentication Required\r\nContent-Length: 0\r\n\r\n"
+                    } else if request.starts_with("CONNECT") {
+                        "HTTP/1.1 200 Connection established\r\n\r\n"
+                    } else {
+                        "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok"
+                    };
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        port
+    }
+
+    fn http_proxy(port: u16, password: &str) -> ProxyConfig {
+        ProxyConfig {
+            protocol: ProxyProtocol::Http,
+            host: "127.0.0.1".to_string(),
+            port,
+            username: Some("user".to_string()),
+            password: Some(password.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_http_proxy_success() {
+        let port = spawn_http_proxy().await;
+        let diag = diagnose_proxy(
+            &http_proxy(port, "secret"),
+            "http://example.test/health",
+            Duration::from_secs(5),
+        )
+        .await;
+
+        assert!(diag.success, "{:?}", diag);
+        assert!(diag.tcp_connect.success);
+        let auth = diag.auth.as_ref().unwrap();
+        assert!(auth.success);
+        assert_eq!(auth.detail, "Proxy accepted the credentials");
+        assert!(diag.fetch.as_ref().unwrap().success);
+        assert!(diag.first_failure().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_http_proxy_auth_failure() {
+        let port = spawn_http_proxy().await;
+        let diag = diagnose_proxy(
+            &http_proxy(port, "wrong"),
+            "http://example.test/health",
+            Duration::from_secs(5),
+        )
+        .await;
+
+        assert!(!diag.success);
+        assert!(diag.tcp_connect.success);
+        let auth = diag.auth.as_ref().unwrap();
+        assert_eq!(auth.failure, Some(ProxyFailureKind::AuthRejected));
+        assert!(diag.fetch.is_none(), "fetch is skipped after auth failure");
+
+        let mut anonymous = http_proxy(port, "");
+        anonymous.username = None;
+        anonymous.password = None;
+        let diag = diagnose_proxy(&anonymous, "http://example.test/", Duration::from_secs(5)).await;
+        assert_eq!(
+            diag.first_failure().unwrap().failure,
+            Some(ProxyFailureKind::AuthRequired)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_socks5_auth_failure() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut greeting = [0u8; 4];
+            socket.read_exact(&mut greeting).await.unwrap();
+            socket.write_all(&[0x05, 0x02]).await.unwrap();
+            let mut auth = [0u8; 64];
+            let _ = socket.read(&mut auth).await.unwrap();
+            socket.write_all(&[0x01, 0x01]).await.unwrap();
+        });
+
+        let proxy = ProxyConfig {
+            protocol: ProxyProtocol::Socks5,
+            ..http_proxy(port, "wrong")
+        };
+        let diag = diagnose_proxy(&proxy, "http://example.test/", Duration::from_secs(5)).await;
+        assert!(!diag.success);
+        assert_eq!(
+            diag.auth.as_ref().unwrap().failure,
+            Some(ProxyFailureKind::AuthRejected)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_diagnose_connect_refused() {
+        // Bind then drop to get a port with nothing listening.
+        let port = {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap().port()
+        };
+        let diag = diagnose_proxy(
+            &http_proxy(port, "secret"),
+            "http://example.test/",
+            Duration::from_secs(5),
+        )
+        .await;
+        assert!(!diag.success);
+        assert_eq!(diag.tcp_connect.failure, Some(ProxyFailureKind::Connect));
+        assert!(diag.auth.is_none());
+    }
+}
@@ -5,11 +5,13 @@
 //! and the checkpoints table for incomplete checkpoint chains.
 //!
 //! Supports all execution modes: mega_plan, hybrid_auto, hybrid_worktree, direct.
+//! Also scans `agent_runs` for sub-agent runs that never finished and
+//! `graph_checkpoints` for graph workflow threads with pending nodes.
 //! Produces structured `IncompleteTask` summaries for the frontend.
 
 use serde::{Deserialize, Serialize};
 
-use crate::storage::database::Database;
+use crate::storage::database::{Database, GraphRunRow, IncompleteAgentRunRow};
 use crate::utils::error::AppResult;
 
 /// Execution mode for the interrupted task
@@ -59,6 +61,30 @@ impl std::fmt::Display for ExecutionMode {
     }
 }
 
+/// Which kind of execution was interrupted, used to route resumption
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionKind {
+    /// Task execution tracked in the `executions` table
+    #[default]
+    Standalone,
+    /// Graph workflow thread resumable from its checkpoints
+    Graph,
+    /// Sub-agent run from the `agent_runs` table
+    SubAgent,
+}
+
+impl ExecutionKind {
+    /// Classify an `executions` row by its execution mode
+    pub fn from_execution_mode(mode: &str) -> Self {
+        match mode {
+            "graph" | "graph_workflow" => ExecutionKind::Graph,
+            "sub_agent" => ExecutionKind::SubAgent,
+            _ => ExecutionKind::Standalone,
+        }
+    }
+}
+
 /// Summary of an interrupted execution found during detection
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IncompleteTask {
@@ -70,6 +96,9 @@ pub struct IncompleteTask {
     pub name: String,
     /// Execution mode that was running
     pub execution_mode: ExecutionMode,
+    /// Kind of execution, determining the resume path
+    #[serde(default)]
+    pub execution_kind: ExecutionKind,
     /// Current status when interrupted (running, paused, failed)
     pub status: String,
     /// Project path for the execution
@@ -102,14 +131,10 @@ impl RecoveryDetector {
     ///
     /// Queries the `executions` table for rows with status not in
     /// ('completed', 'cancelled'), then enriches each result with
-    /// checkpoint information and recoverability assessment.
+    /// checkpoint information and recoverability assessment. Interrupted
+    /// sub-agent runs and graph workflow threads are appended after them.
     pub fn detect(db: &Database) -> AppResult<Vec<IncompleteTask>> {
         let rows = db.get_incomplete_executions()?;
-
-        if rows.is_empty() {
-            return Ok(Vec::new());
-        }
-
         let mut tasks = Vec::with_capacity(rows.len());
 
         for row in rows {
@@ -140,12 +165,14 @@ impl RecoveryDetector {
             );
 
             let mode = ExecutionMode::from_str(&row.execution_mode);
+            let kind = ExecutionKind::from_execution_mode(&row.execution_mode);
 
             tasks.push(IncompleteTask {
                 id: row.id,
                 session_id: row.session_id,
                 name: row.name,
                 execution_mode: mode,
+                execution_kind: kind,
                 status: row.status,
                 project_path: row.project_path,
                 total_stories: row.total_stories,
@@ -160,9 +187,82 @@ impl RecoveryDetector {
             });
         }
 
+        tasks.extend(
+            db.get_incomplete_agent_runs()?
+                .into_iter()
+                .map(Self::agent_run_task),
+        );
+        tasks.extend(
+            db.get_graph_runs()?
+                .into_iter()
+                .filter_map(Self::graph_run_task),
+        );
+
         Ok(tasks)
     }
 
+    /// Summarize a sub-agent run left pending or running.
+    fn agent_run_task(run: IncompleteAgentRunRow) -> IncompleteTask {
+        IncompleteTask {
+            name: run
+                .agent_name
+                .unwrap_or_else(|| format!("Agent {}", run.agent_id)),
+            id: run.id,
+            session_id: None,
+            execution_mode: ExecutionMode::Direct,
+            execution_kind: ExecutionKind::SubAgent,
+            status: run.status,
+            project_path: String::new(),
+            total_stories: 1,
+            completed_stories: 0,
+            current_story_id: None,
+            progress: 0.0,
+            last_checkpoint_timestamp: run.created_at,
+            recoverable: true,
+            recovery_note: Some("Sub-agent run will restart from its original input".to_string()),
+            checkpoint_count: 0,
+            error_message: run.error,
+        }
+    }
+
+    /// Summarize a graph workflow thread whose latest checkpoint still has
+    /// pending nodes. Finished threads (no pending nodes) yield `None`.
+    fn graph_run_task(run: GraphRunRow) -> Option<IncompleteTask> {
+        let pending: Vec<String> = serde_json::from_str(&run.pending_nodes).ok()?;
+        let next_node = pending.first()?.clone();
+        let (recoverable, recovery_note) =
+            if serde_json::from_str::<serde_json::Value>(&run.state).is_ok() {
+                (
+                    true,
+                    Some(format!("Graph will resume at node '{}'", next_node)),
+                )
+            } else {
+                (
+                    false,
+                    Some("Graph checkpoint state is corrupted".to_string()),
+                )
+            };
+
+        Some(IncompleteTask {
+            name: format!("Graph workflow run {}", run.thread_id),
+            id: run.thread_id,
+            session_id: None,
+            execution_mode: ExecutionMode::Direct,
+            execution_kind: ExecutionKind::Graph,
+            status: "paused".to_string(),
+            project_path: String::new(),
+            total_stories: 0,
+            completed_stories: 0,
+            current_story_id: Some(next_node),
+            progress: 0.0,
+            last_checkpoint_timestamp: Some(run.created_at),
+            recoverable,
+            recovery_note,
+            checkpoint_count: run.checkpoint_count,
+            error_message: None,
+        })
+    }
+
     /// Assess whether an interrupted execution can be resumed.
     ///
     /// Checks for valid context snapshot, progress state, and execution mode
@@ -344,6 +444,64 @@ mod tests {
         assert!(note.is_none());
     }
 
+    #[test]
+    fn test_execution_kind_from_execution_mode() {
+        assert_eq!(
+            ExecutionKind::from_execution_mode("hybrid_auto"),
+            ExecutionKind::Standalone
+        );
+        assert_eq!(
+            ExecutionKind::from_execution_mode("graph_workflow"),
+            ExecutionKind::Graph
+        );
+        assert_eq!(
+            ExecutionKind::from_execution_mode("sub_agent"),
+            ExecutionKind::SubAgent
+        );
+    }
+
+    #[tokio::test]
+    async fn test_detect_interrupted_graph_workflow_run() {
+        use crate::services::graph_workflow::checkpoint_store::SqliteCheckpointer;
+        use crate::services::graph_workflow::checkpointer::{Checkpointer, GraphCheckpoint};
+        use std::collections::HashMap;
+        use std::sync::Arc;
+
+        let db = Database::new_in_memory().unwrap();
+        let checkpointer = SqliteCheckpointer::new(Arc::new(db.pool().clone())).unwrap();
+
+        let mut interrupted = GraphCheckpoint::new(
+            "thread-interrupted",
+            HashMap::from([("draft".to_string(), serde_json::json!("v1"))]),
+            "draft",
+            vec!["review".to_string()],
+            None,
+        );
+        interrupted.created_at = "2025-01-15T10:00:00Z".to_string();
+        checkpointer.save(interrupted).await.unwrap();
+
+        // A finished thread's latest checkpoint has no pending nodes
+        let mut started =
+            GraphCheckpoint::new("thread-done", HashMap::new(), "a", vec!["b".into()], None);
+        started.created_at = "2025-01-15T09:00:00Z".to_string();
+        checkpointer.save(started).await.unwrap();
+        let mut finished = GraphCheckpoint::new("thread-done", HashMap::new(), "b", vec![], None);
+        finished.created_at = "2025-01-15T09:05:00Z".to_string();
+        checkpointer.save(finished).await.unwrap();
+
+        let tasks = RecoveryDetector::detect(&db).unwrap();
+        assert_eq!(tasks.len(), 1);
+        let task = &tasks[0];
+        assert_eq!(task.id, "thread-interrupted");
+        assert_eq!(task.execution_kind, ExecutionKind::Graph);
+        assert_eq!(task.current_story_id.as_deref(), Some("review"));
+        assert_eq!(task.checkpoint_count, 1);
+        assert!(task.recoverable);
+
+        let json = serde_json::to_string(task).unwrap();
+        assert!(json.contains("\"execution_kind\":\"graph\""));
+    }
+
     #[test]
     fn test_incomplete_task_serialization() {
         let task = IncompleteTask {
@@ -351,6 +509,7 @@ mod tests {
             session_id: Some("sess-001".to_string()),
             name: "Build auth system".to_string(),
             execution_mode: ExecutionMode::HybridAuto,
+            execution_kind: ExecutionKind::Standalone,
             status: "running".to_string(),
             project_path: "/project".to_string(),
             total_stories: 5,
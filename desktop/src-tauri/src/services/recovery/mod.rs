@@ -11,5 +11,5 @@
 pub mod detector;
 pub mod resume;
 
-pub use detector::{IncompleteTask, RecoveryDetector};
-pub use resume::{ResumeEngine, ResumeEvent, ResumeResult};
+pub use detector::{ExecutionKind, IncompleteTask, RecoveryDetector};
+pub use resume::{ResumeEngine, ResumeEvent, ResumePreview, ResumeResult};
@@ -5,12 +5,17 @@
 //! Skips already-completed stories and emits Tauri events for
 //! progress updates.
 
+use std::path::Path;
+use std::time::SystemTime;
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use ignore::WalkBuilder;
 use serde::{Deserialize, Serialize};
 
-use crate::storage::database::Database;
+use crate::storage::database::{Database, GraphRunRow, IncompleteAgentRunRow};
 use crate::utils::error::{AppError, AppResult};
 
-use super::detector::ExecutionMode;
+use super::detector::{ExecutionKind, ExecutionMode};
 
 /// Context restored from a checkpoint for resumption
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +24,9 @@ pub struct RestoredContext {
     pub execution_id: String,
     /// Execution mode
     pub execution_mode: ExecutionMode,
+    /// Kind of execution, telling the caller which runner continues it
+    #[serde(default)]
+    pub execution_kind: ExecutionKind,
     /// Project path
     pub project_path: String,
     /// Task name
@@ -117,6 +125,53 @@ impl ResumeResult {
     }
 }
 
+/// A project file that changed after the execution was interrupted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExternalFileChange {
+    /// Path relative to the project root
+    pub path: String,
+    /// Last modification time (ISO 8601), `None` if the file was deleted
+    pub modified_at: Option<String>,
+}
+
+/// What resuming an execution would restore, shown for confirmation
+/// before anything is changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumePreview {
+    /// The execution ID being previewed
+    pub execution_id: String,
+    /// Execution mode
+    pub execution_mode: ExecutionMode,
+    /// Task name
+    pub name: String,
+    /// Project path
+    pub project_path: String,
+    /// Last story known to have completed before the interruption
+    pub last_completed_story_id: Option<String>,
+    /// Story that execution will resume from
+    pub resume_from_story_id: Option<String>,
+    /// IDs of already-completed stories that will be skipped
+    pub completed_story_ids: Vec<String>,
+    /// IDs of stories still to execute
+    pub pending_story_ids: Vec<String>,
+    /// Number of conversation messages in the snapshot that will be reloaded
+    pub message_count: usize,
+    /// Top-level context snapshot keys that will be restored
+    pub context_keys: Vec<String>,
+    /// Number of checkpoints available for the session
+    pub checkpoint_count: usize,
+    /// Most recent checkpoint ID
+    pub latest_checkpoint_id: Option<String>,
+    /// Time of the last recorded activity (ISO 8601)
+    pub interrupted_at: Option<String>,
+    /// Files modified outside the execution since the interruption
+    pub externally_modified_files: Vec<ExternalFileChange>,
+    /// Whether `resume` would accept this execution
+    pub resumable: bool,
+    /// Issues the user should review before resuming
+    pub warnings: Vec<String>,
+}
+
 /// Engine for resuming interrupted executions
 pub struct ResumeEngine;
 
@@ -129,11 +184,23 @@ impl ResumeEngine {
     /// 4. Determines completed vs remaining stories
     /// 5. Updates execution status to 'running'
     /// 6. Returns the restored context for the caller to continue execution
+    ///
+    /// IDs not found in `executions` are routed to graph workflow threads and
+    /// then sub-agent runs, matching what `RecoveryDetector` reports.
     pub fn resume(db: &Database, execution_id: &str) -> AppResult<ResumeResult> {
         // Step 1: Load the execution record
-        let execution = db.get_execution(execution_id)?.ok_or_else(|| {
-            AppError::not_found(format!("Execution '{}' not found", execution_id))
-        })?;
+        let Some(execution) = db.get_execution(execution_id)? else {
+            if let Some(run) = db.get_graph_run(execution_id)? {
+                return Self::resume_graph_run(run);
+            }
+            if let Some(run) = db.get_agent_run_row(execution_id)? {
+                return Ok(Self::resume_agent_run(run));
+            }
+            return Err(AppError::not_found(format!(
+                "Execution '{}' not found",
+                execution_id
+            )));
+        };
 
         let mode = ExecutionMode::from_str(&execution.execution_mode);
 
@@ -187,6 +254,7 @@ impl ResumeEngine {
         let restored = RestoredContext {
             execution_id: execution_id.to_string(),
             execution_mode: mode.clone(),
+            execution_kind: ExecutionKind::from_execution_mode(&execution.execution_mode),
             project_path: execution.project_path.clone(),
             name: execution.name.clone(),
             completed_story_ids: completed_ids,
@@ -218,6 +286,178 @@ impl ResumeEngine {
         Ok(result)
     }
 
+    /// Restore a graph workflow thread from its latest checkpoint. The graph
+    /// runner continues it via the checkpointer using the thread ID.
+    fn resume_graph_run(run: GraphRunRow) -> AppResult<ResumeResult> {
+        let thread_id = run.thread_id.clone();
+        let pending: Vec<String> = serde_json::from_str(&run.pending_nodes).map_err(|e| {
+            AppError::parse(format!(
+                "Failed to parse pending nodes for graph run '{}': {}",
+                thread_id, e
+            ))
+        })?;
+        if pending.is_empty() {
+            return Ok(ResumeResult::failure(
+                &thread_id,
+                format!("Graph run '{}' has already finished", thread_id),
+            ));
+        }
+        let state: serde_json::Value = serde_json::from_str(&run.state).map_err(|e| {
+            AppError::parse(format!(
+                "Failed to parse checkpoint state for graph run '{}': {}",
+                thread_id, e
+            ))
+        })?;
+
+        let restored = RestoredContext {
+            execution_id: thread_id.clone(),
+            execution_mode: ExecutionMode::Direct,
+            execution_kind: ExecutionKind::Graph,
+            project_path: String::new(),
+            name: format!("Graph workflow run {}", thread_id),
+            completed_story_ids: Vec::new(),
+            remaining_story_ids: pending.clone(),
+            context_snapshot: state,
+            total_stories: 0,
+            completed_stories: 0,
+            progress: 0.0,
+        };
+        Ok(ResumeResult::success(&thread_id, restored)
+            .with_event(ResumeEvent::ContextRestored {
+                execution_id: thread_id.clone(),
+                remaining_stories: pending.len() as i32,
+            })
+            .with_event(ResumeEvent::Resuming {
+                execution_id: thread_id,
+                from_story_id: pending.first().cloned(),
+            }))
+    }
+
+    /// Restore a sub-agent run, which restarts from its original input.
+    fn resume_agent_run(run: IncompleteAgentRunRow) -> ResumeResult {
+        if run.status != "pending" && run.status != "running" {
+            return ResumeResult::failure(
+                &run.id,
+                format!(
+                    "Agent run '{}' has status '{}' and cannot be resumed",
+                    run.id, run.status
+                ),
+            );
+        }
+        let restored = RestoredContext {
+            execution_id: run.id.clone(),
+            execution_mode: ExecutionMode::Direct,
+            execution_kind: ExecutionKind::SubAgent,
+            project_path: String::new(),
+            name: run
+                .agent_name
+                .unwrap_or_else(|| format!("Agent {}", run.agent_id)),
+            completed_story_ids: Vec::new(),
+            remaining_story_ids: Vec::new(),
+            context_snapshot: serde_json::json!({
+                "agent_id": run.agent_id,
+                "input": run.input,
+            }),
+            total_stories: 1,
+            completed_stories: 0,
+            progress: 0.0,
+        };
+        ResumeResult::success(&run.id, restored).with_event(ResumeEvent::Resuming {
+            execution_id: run.id.clone(),
+            from_story_id: None,
+        })
+    }
+
+    /// Preview what `resume` would restore without modifying anything.
+    ///
+    /// Reports the resume point, the pending stories, the context that will be
+    /// reloaded and any project files changed since the interruption. Files
+    /// listed under `modified_files` in the snapshot are checked; when none
+    /// are recorded the whole project (respecting `.gitignore`) is scanned.
+    pub fn preview_resume(db: &Database, execution_id: &str) -> AppResult<ResumePreview> {
+        let execution = db.get_execution(execution_id)?.ok_or_else(|| {
+            AppError::not_found(format!("Execution '{}' not found", execution_id))
+        })?;
+
+        let mode = ExecutionMode::from_str(&execution.execution_mode);
+        let mut warnings = Vec::new();
+        let mut resumable = true;
+
+        if execution.status == "completed" || execution.status == "cancelled" {
+            resumable = false;
+            warnings.push(format!(
+                "Execution '{}' has status '{}' and cannot be resumed",
+                execution_id, execution.status
+            ));
+        }
+
+        let context_value =
+            match serde_json::from_str::<serde_json::Value>(&execution.context_snapshot) {
+                Ok(value) => value,
+                Err(e) => {
+                    resumable = false;
+                    warnings.push(format!("Context snapshot is corrupted: {}", e));
+                    serde_json::Value::Null
+                }
+            };
+        let (completed_ids, remaining_ids) = Self::extract_story_progress(&context_value, &mode);
+
+        let checkpoints = match execution.session_id {
+            Some(ref sid) => db.get_checkpoints_for_session(sid)?,
+            None => Vec::new(),
+        };
+        let latest_checkpoint = checkpoints.first();
+
+        let interrupted_at = [
+            latest_checkpoint.and_then(|c| c.created_at.as_deref()),
+            execution.updated_at.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(parse_db_timestamp)
+        .max();
+
+        let externally_modified_files = match interrupted_at {
+            Some(since) => Self::find_external_changes(
+                Path::new(&execution.project_path),
+                &context_value,
+                since,
+            ),
+            None => Vec::new(),
+        };
+        if !externally_modified_files.is_empty() {
+            warnings.push(format!(
+                "{} file(s) changed since the interruption; the restored context may be stale",
+                externally_modified_files.len()
+            ));
+        }
+
+        Ok(ResumePreview {
+            execution_id: execution_id.to_string(),
+            execution_mode: mode,
+            name: execution.name,
+            project_path: execution.project_path,
+            last_completed_story_id: completed_ids.last().cloned(),
+            resume_from_story_id: remaining_ids.first().cloned(),
+            completed_story_ids: completed_ids,
+            pending_story_ids: remaining_ids,
+            message_count: context_value
+                .get("messages")
+                .and_then(|v| v.as_array())
+                .map_or(0, Vec::len),
+            context_keys: context_value
+                .as_object()
+                .map(|obj| obj.keys().cloned().collect())
+                .unwrap_or_default(),
+            checkpoint_count: checkpoints.len(),
+            latest_checkpoint_id: latest_checkpoint.map(|c| c.id.clone()),
+            interrupted_at: interrupted_at.map(|ts| ts.to_rfc3339()),
+            externally_modified_files,
+            resumable,
+            warnings,
+        })
+    }
+
     /// Discard an interrupted execution, marking it as cancelled.
     pub fn discard(db: &Database, execution_id: &str) -> AppResult<()> {
         let execution = db.get_execution(execution_id)?.ok_or_else(|| {
@@ -234,6 +474,64 @@ impl ResumeEngine {
         Ok(())
     }
 
+    /// Files under `project_path` modified (or deleted) after `since`.
+    fn find_external_changes(
+        project_path: &Path,
+        context: &serde_json::Value,
+        since: DateTime<Utc>,
+    ) -> Vec<ExternalFileChange> {
+        let since: SystemTime = since.into();
+        let changed_after = |path: &Path| -> Option<Option<String>> {
+            match std::fs::metadata(path).and_then(|m| m.modified()) {
+                Ok(modified) if modified > since => {
+                    Some(Some(DateTime::<Utc>::from(modified).to_rfc3339()))
+                }
+                Ok(_) => None,
+                Err(_) => Some(None),
+            }
+        };
+
+        let recorded: Vec<&str> = context
+            .get("modified_files")
+            .and_then(|v| v.as_array())
+            .map(|files| files.iter().filter_map(|f| f.as_str()).collect())
+            .unwrap_or_default();
+
+        let mut changes = Vec::new();
+        if !recorded.is_empty() {
+            for file in recorded {
+                if let Some(modified_at) = changed_after(&project_path.join(file)) {
+                    changes.push(ExternalFileChange {
+                        path: file.to_string(),
+                        modified_at,
+                    });
+                }
+            }
+            return changes;
+        }
+
+        if !project_path.is_dir() {
+            return changes;
+        }
+        for entry in WalkBuilder::new(project_path).build().flatten() {
+            if !entry.file_type().is_some_and(|ft| ft.is_file()) {
+                continue;
+            }
+            if let Some(Some(modified_at)) = changed_after(entry.path()) {
+                let relative = entry
+                    .path()
+                    .strip_prefix(project_path)
+                    .unwrap_or(entry.path());
+                changes.push(ExternalFileChange {
+                    path: relative.to_string_lossy().replace('\\', "/"),
+                    modified_at: Some(modified_at),
+                });
+            }
+        }
+        changes.sort_by(|a, b| a.path.cmp(&b.path));
+        changes
+    }
+
     /// Extract completed and remaining story IDs from the context snapshot.
     ///
     /// The context snapshot format varies by execution mode, but generally
@@ -312,6 +610,18 @@ impl ResumeEngine {
     }
 }
 
+/// Parse a database timestamp (SQLite `CURRENT_TIMESTAMP` or RFC 3339) as UTC.
+fn parse_db_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|dt| dt.and_utc())
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -321,6 +631,7 @@ mod tests {
         let context = RestoredContext {
             execution_id: "exec-001".to_string(),
             execution_mode: ExecutionMode::HybridAuto,
+            execution_kind: ExecutionKind::Standalone,
             project_path: "/project".to_string(),
             name: "Test task".to_string(),
             completed_story_ids: vec!["s1".to_string()],
@@ -442,6 +753,7 @@ mod tests {
         let context = RestoredContext {
             execution_id: "exec-001".to_string(),
             execution_mode: ExecutionMode::MegaPlan,
+            execution_kind: ExecutionKind::Standalone,
             project_path: "/my/project".to_string(),
             name: "Build app".to_string(),
             completed_story_ids: vec!["s1".to_string()],
@@ -459,6 +771,69 @@ mod tests {
         assert_eq!(parsed.remaining_story_ids, vec!["s2"]);
     }
 
+    #[test]
+    fn test_preview_reports_resume_point_and_external_changes() {
+        let project = tempfile::tempdir().unwrap();
+        std::fs::write(project.path().join("untouched.rs"), "fn a() {}").unwrap();
+        std::fs::write(project.path().join("edited.rs"), "fn b() {}").unwrap();
+        let old = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(946_684_800);
+        std::fs::File::options()
+            .write(true)
+            .open(project.path().join("untouched.rs"))
+            .unwrap()
+            .set_modified(old)
+            .unwrap();
+
+        let db = Database::new_in_memory().unwrap();
+        let snapshot = serde_json::json!({
+            "completed_story_ids": ["s1", "s2"],
+            "prd": {"stories": [{"id": "s1"}, {"id": "s2"}, {"id": "s3"}]},
+            "messages": [{"role": "user"}, {"role": "assistant"}],
+            "modified_files": ["untouched.rs", "edited.rs", "removed.rs"],
+        });
+        db.insert_execution(
+            "exec-001",
+            None,
+            "Build auth",
+            "hybrid_auto",
+            &project.path().to_string_lossy(),
+            3,
+            &snapshot.to_string(),
+        )
+        .unwrap();
+        db.get_connection()
+            .unwrap()
+            .execute(
+                "UPDATE executions SET updated_at = '2001-01-01 00:00:00' WHERE id = 'exec-001'",
+                [],
+            )
+            .unwrap();
+
+        let preview = ResumeEngine::preview_resume(&db, "exec-001").unwrap();
+        assert!(preview.resumable);
+        assert_eq!(preview.last_completed_story_id.as_deref(), Some("s2"));
+        assert_eq!(preview.resume_from_story_id.as_deref(), Some("s3"));
+        assert_eq!(preview.pending_story_ids, vec!["s3"]);
+        assert_eq!(preview.message_count, 2);
+        assert_eq!(
+            preview.interrupted_at.as_deref(),
+            Some("2001-01-01T00:00:00+00:00")
+        );
+
+        let flagged: Vec<&str> = preview
+            .externally_modified_files
+            .iter()
+            .map(|change| change.path.as_str())
+            .collect();
+        assert_eq!(flagged, vec!["edited.rs", "removed.rs"]);
+        assert!(preview.externally_modified_files[1].modified_at.is_none());
+        assert_eq!(preview.warnings.len(), 1);
+
+        // Previewing must not change the execution status
+        let execution = db.get_execution("exec-001").unwrap().unwrap();
+        assert_eq!(execution.status, "running");
+    }
+
     #[test]
     fn test_resume_result_serialization() {
         let result = ResumeResult::failure("exec-001", "test error");
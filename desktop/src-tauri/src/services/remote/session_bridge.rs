@@ -9,7 +9,7 @@ use super::types::{
     RemoteActionButton, RemoteActionCard, RemoteError, RemoteResponse, RemoteSessionMapping,
     SessionType, StreamingMode,
 };
-use crate::commands::proxy::resolve_provider_proxy;
+use crate::commands::proxy::resolve_provider_proxy_routing;
 use crate::commands::standalone::{
     get_api_key_with_aliases, get_search_api_key_with_aliases, normalize_provider_name,
     provider_type_from_name,
@@ -452,7 +452,11 @@ impl SessionBridge {
 
             let base_url = self.resolve_base_url(canonical_provider);
 
-            let proxy = resolve_provider_proxy(&svc.keyring, &self.db, canonical_provider);
+            let proxy_routing = Some(resolve_provider_proxy_routing(
+                &svc.keyring,
+                &self.db,
+                canonical_provider,
+            ));
 
             let provider_config = ProviderConfig {
                 provider: provider_type,
@@ -461,7 +465,7 @@ impl SessionBridge {
                 model: resolved_model.clone(),
                 max_tokens: 4096,
                 temperature: 0.7,
-                proxy,
+                proxy_routing,
                 ..Default::default()
             };
 
@@ -809,7 +813,11 @@ impl SessionBridge {
         };
 
         let base_url = self.resolve_base_url(canonical_provider);
-        let proxy = resolve_provider_proxy(&svc.keyring, &self.db, canonical_provider);
+        let proxy_routing = Some(resolve_provider_proxy_routing(
+            &svc.keyring,
+            &self.db,
+            canonical_provider,
+        ));
 
         let provider_config = ProviderConfig {
             provider: provider_type,
@@ -818,7 +826,7 @@ impl SessionBridge {
             model,
             max_tokens: 4096,
             temperature: 0.7,
-            proxy,
+            proxy_routing,
             ..Default::default()
         };
 
@@ -1107,6 +1107,7 @@ impl RemoteWorkflowFacade {
                     locale: None,
                     context_sources: session.context_sources.clone(),
                     project_path: session.project_path.clone(),
+                    previous_prd: None,
                 },
                 self.app.state::<TaskModeState>(),
                 self.app.state::<AppState>(),
@@ -1175,6 +1176,7 @@ impl RemoteWorkflowFacade {
                     workflow_config,
                     global_default_agent: None,
                     phase_configs: None,
+                    agent_overrides: None,
                     locale: None,
                     context_sources: session.context_sources.clone(),
                     project_path: session.project_path.clone(),
@@ -1812,6 +1814,8 @@ impl RemoteWorkflowFacade {
             quality_retry_max_attempts: None,
             custom_quality_gates: Vec::new(),
             max_parallel: Some(recommended.max_parallel),
+            max_concurrent_stories: None,
+            retry_budget: None,
             skip_verification: recommended.skip_verification,
             skip_review: recommended.skip_review,
             global_agent_override: recommended.global_agent_override.clone(),
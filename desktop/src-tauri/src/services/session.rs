@@ -3,12 +3,14 @@
 //! Parses and manages Claude Code session files (JSONL format)
 
 use std::fs::{self, File};
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, BufWriter, Write};
 use std::path::PathBuf;
 
 use serde::Deserialize;
 
-use crate::models::session::{MessageSummary, ResumeResult, Session, SessionDetails};
+use crate::models::checkpoint::Checkpoint;
+use crate::models::session::{ForkResult, MessageSummary, ResumeResult, Session, SessionDetails};
+use crate::services::timeline::TimelineService;
 use crate::utils::error::{AppError, AppResult};
 
 /// Maximum length for message previews
@@ -253,6 +255,162 @@ impl SessionService {
         ))
     }
 
+    /// Fork a session, copying every entry up to and including user turn
+    /// `at_turn` (1-based) into a new session file, along with the timeline
+    /// checkpoints created before the cut. The original is untouched.
+    pub fn fork_session(&self, session_path: &str, at_turn: u32) -> AppResult<ForkResult> {
+        let path = PathBuf::from(session_path);
+        if !path.exists() {
+            return Err(AppError::not_found(format!(
+                "Session file not found: {}",
+                session_path
+            )));
+        }
+        if at_turn == 0 {
+            return Err(AppError::validation("at_turn must be at least 1"));
+        }
+
+        let source_session_id = path
+            .file_stem()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let sessions_dir = path
+            .parent()
+            .ok_or_else(|| AppError::validation("Session file has no parent directory"))?;
+        let new_session_id = uuid::Uuid::new_v4().to_string();
+        let new_path = sessions_dir.join(format!("{}.jsonl", new_session_id));
+
+        let reader = BufReader::new(File::open(&path)?);
+        let mut writer = BufWriter::new(File::create(&new_path)?);
+        let mut turn_count = 0u32;
+        let mut checkpoint_count = 0u32;
+        // `None` while the whole transcript is copied; otherwise the timestamp
+        // of the first entry left out of the fork, if it has one.
+        let mut cut_at: Option<Option<String>> = None;
+
+        let copy_result = (|| -> AppResult<()> {
+            for line in reader.lines() {
+                let line = line?;
+                let value = serde_json::from_str::<serde_json::Value>(&line).ok();
+                let entry = value
+                    .as_ref()
+                    .and_then(|v| SessionEntry::deserialize(v).ok());
+
+                if let Some(entry) = &entry {
+                    if Self::starts_turn(entry) {
+                        if turn_count == at_turn {
+                            cut_at = Some(entry.timestamp.clone());
+                            break;
+                        }
+                        turn_count += 1;
+                    }
+                    if entry.is_checkpoint || entry.entry_type.as_deref() == Some("checkpoint") {
+                        checkpoint_count += 1;
+                    }
+                }
+
+                match value {
+                    Some(mut v) if v.get("sessionId").is_some() => {
+                        v["sessionId"] = serde_json::Value::String(new_session_id.clone());
+                        writeln!(writer, "{}", v)?;
+                    }
+                    _ => writeln!(writer, "{}", line)?,
+                }
+            }
+            writer.flush()?;
+            Ok(())
+        })();
+
+        let project_path = sessions_dir
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let result = copy_result
+            .and_then(|_| {
+                if turn_count < at_turn {
+                    Err(AppError::validation(format!(
+                        "Session has {} turns, cannot fork at turn {}",
+                        turn_count, at_turn
+                    )))
+                } else {
+                    Ok(())
+                }
+            })
+            .and_then(|_| {
+                TimelineService::new().fork_timeline(
+                    &project_path,
+                    &source_session_id,
+                    &new_session_id,
+                    |checkpoint| Self::checkpoint_before_cut(checkpoint, cut_at.as_ref()),
+                )
+            });
+        let timeline_checkpoint_count = match result {
+            Ok(count) => count,
+            Err(e) => {
+                let _ = fs::remove_file(&new_path);
+                return Err(e);
+            }
+        };
+
+        Ok(ForkResult {
+            source_session_id,
+            session_id: new_session_id,
+            session_path: new_path.to_string_lossy().to_string(),
+            turn_count,
+            checkpoint_count,
+            timeline_checkpoint_count,
+            file_change_count: 0,
+        })
+    }
+
+    /// Whether a timeline checkpoint belongs to the forked part of a session.
+    /// Checkpoints are only placed by timestamp, so when the first dropped
+    /// entry carries none, nothing can be attributed to the fork.
+    fn checkpoint_before_cut(checkpoint: &Checkpoint, cut_at: Option<&Option<String>>) -> bool {
+        let cut = match cut_at {
+            None => return true,
+            Some(None) => return false,
+            Some(Some(cut)) => cut,
+        };
+        match (
+            chrono::DateTime::parse_from_rfc3339(&checkpoint.timestamp),
+            chrono::DateTime::parse_from_rfc3339(cut),
+        ) {
+            (Ok(created), Ok(cut)) => created < cut,
+            _ => false,
+        }
+    }
+
+    /// Whether an entry opens a new conversation turn. User entries that only
+    /// carry tool results continue the previous turn.
+    fn starts_turn(entry: &SessionEntry) -> bool {
+        let is_user =
+            entry.role.as_deref() == Some("user") || entry.entry_type.as_deref() == Some("user");
+        if !is_user {
+            return false;
+        }
+        match entry.content.as_ref().and_then(|c| c.as_array()) {
+            Some(blocks) if !blocks.is_empty() => !blocks
+                .iter()
+                .all(|b| b.get("type").and_then(|t| t.as_str()) == Some("tool_result")),
+            _ => true,
+        }
+    }
+
+    /// Count the conversation turns in a session file
+    pub fn count_turns(&self, session_path: &str) -> AppResult<u32> {
+        let reader = BufReader::new(File::open(session_path)?);
+        let mut turns = 0;
+        for line in reader.lines() {
+            if let Ok(entry) = serde_json::from_str::<SessionEntry>(&line?) {
+                if Self::starts_turn(&entry) {
+                    turns += 1;
+                }
+            }
+        }
+        Ok(turns)
+    }
+
     /// Search sessions across a project
     pub fn search_sessions(&self, project_path: &str, query: &str) -> AppResult<Vec<Session>> {
         let sessions = self.list_sessions(project_path)?;
@@ -312,6 +470,122 @@ mod tests {
         assert_eq!(preview, Some("Hello World".to_string()));
     }
 
+    fn write_six_turn_session(dir: &std::path::Path) -> PathBuf {
+        let sessions_dir = dir.join("sessions");
+        fs::create_dir_all(&sessions_dir).unwrap();
+        let path = sessions_dir.join("original.jsonl");
+        let mut lines = vec![serde_json::json!({"type": "summary", "summary": "demo"})];
+        for turn in 1..=6 {
+            lines.push(serde_json::json!({
+                "type": "user",
+                "sessionId": "original",
+                "content": format!("question {}", turn),
+            }));
+            lines.push(serde_json::json!({
+                "type": "assistant",
+                "sessionId": "original",
+                "content": [{"type": "tool_use", "id": format!("t{}", turn), "name": "Read"}],
+            }));
+            lines.push(serde_json::json!({
+                "type": "user",
+                "sessionId": "original",
+                "content": [{"type": "tool_result", "tool_use_id": format!("t{}", turn)}],
+            }));
+            lines.push(serde_json::json!({
+                "type": "assistant",
+                "sessionId": "original",
+                "content": format!("answer {}", turn),
+                "is_checkpoint": turn % 2 == 0,
+            }));
+        }
+        let body: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+        fs::write(&path, body.join("\n") + "\n").unwrap();
+        path
+    }
+
+    #[test]
+    fn test_fork_session_at_turn_three() {
+        let dir = tempfile::tempdir().unwrap();
+        let original = write_six_turn_session(dir.path());
+        let original_path = original.to_string_lossy().to_string();
+        let service = SessionService::new();
+
+        let fork = service.fork_session(&original_path, 3).unwrap();
+        assert_eq!(fork.source_session_id, "original");
+        assert_ne!(fork.session_id, "original");
+        assert_eq!(fork.turn_count, 3);
+        assert_eq!(fork.checkpoint_count, 1);
+
+        assert_eq!(service.count_turns(&fork.session_path).unwrap(), 3);
+        assert_eq!(service.count_turns(&original_path).unwrap(), 6);
+
+        let forked = fs::read_to_string(&fork.session_path).unwrap();
+        assert!(forked.contains("answer 3"));
+        assert!(!forked.contains("question 4"));
+        assert!(!forked.contains("\"sessionId\":\"original\""));
+
+        let sessions = service
+            .list_sessions(&dir.path().to_string_lossy())
+            .unwrap();
+        assert_eq!(sessions.len(), 2);
+    }
+
+    #[test]
+    fn test_fork_session_rejects_out_of_range_turn() {
+        let dir = tempfile::tempdir().unwrap();
+        let original = write_six_turn_session(dir.path());
+        let service = SessionService::new();
+
+        assert!(service
+            .fork_session(&original.to_string_lossy(), 0)
+            .is_err());
+        assert!(service
+            .fork_session(&original.to_string_lossy(), 7)
+            .is_err());
+        // Failed forks leave no partial files behind
+        assert_eq!(
+            fs::read_dir(dir.path().join("sessions")).unwrap().count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_fork_session_copies_checkpoints_before_cut() {
+        let dir = tempfile::tempdir().unwrap();
+        let original = write_six_turn_session(dir.path());
+        let project_path = dir.path().to_string_lossy().to_string();
+        let timeline = TimelineService::new();
+        timeline
+            .create_checkpoint(&project_path, "original", "before", &[])
+            .unwrap();
+
+        // Stamp turn 4 after the checkpoint so the cut falls behind it
+        let body = fs::read_to_string(&original).unwrap().replacen(
+            "\"content\":\"question 4\"",
+            "\"content\":\"question 4\",\"timestamp\":\"2999-01-01T00:00:00Z\"",
+            1,
+        );
+        fs::write(&original, body).unwrap();
+
+        let service = SessionService::new();
+        let fork = service
+            .fork_session(&original.to_string_lossy(), 3)
+            .unwrap();
+        assert_eq!(fork.timeline_checkpoint_count, 1);
+        let forked = timeline
+            .list_checkpoints(&project_path, &fork.session_id, None)
+            .unwrap();
+        assert_eq!(forked.len(), 1);
+        assert_eq!(forked[0].session_id, fork.session_id);
+        assert_eq!(
+            timeline
+                .list_checkpoints(&project_path, "original", None)
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
     #[test]
     fn test_resume_result() {
         let service = SessionService::new();
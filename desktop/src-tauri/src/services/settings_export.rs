@@ -3,7 +3,7 @@
 //! Provides password-based encryption for API key export and
 //! collection/restoration of all backend settings from various data sources.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use aes_gcm::aead::{Aead, KeyInit};
 use aes_gcm::{Aes256Gcm, Key, Nonce};
@@ -15,10 +15,11 @@ use rusqlite::params;
 use sha2::Sha256;
 
 use crate::models::export::{
-    BackendSettingsExport, GuardrailRuleExport, LspPreferencesExport, ProxyExport, RemoteExport,
-    SettingsImportResult,
+    BackendSettingsExport, GuardrailRuleExport, GuardrailSettingsExport, LspPreferencesExport,
+    PortableSettingsBundle, ProviderSettingsExport, ProxyExport, RemoteExport, SettingsImportMode,
+    SettingsImportResult, SettingsScope, PORTABLE_SETTINGS_SCHEMA_VERSION, REDACTED_SECRET,
 };
-use crate::models::settings::AppConfig;
+use crate::models::settings::{AppConfig, SettingsUpdate};
 use crate::services::guardrail::GuardrailMode;
 use crate::storage::{ConfigService, Database};
 use crate::utils::error::{AppError, AppResult};
@@ -540,6 +541,388 @@ where
     }
 }
 
+// ============================================================================
+// Portable settings bundles
+// ============================================================================
+
+/// Outcome of [`import_settings`].
+///
+/// The database-backed scopes are already written; the config update and
+/// secrets are returned for the caller to apply through `ConfigService` and
+/// the keyring.
+#[derive(Debug)]
+pub struct PortableSettingsImport {
+    pub result: SettingsImportResult,
+    pub config_update: Option<SettingsUpdate>,
+    /// Keyring entries carried by the bundle
+    pub secrets: HashMap<String, String>,
+    /// Scopes whose existing keyring entries are replaced by `secrets`
+    pub replaced_secret_scopes: Vec<SettingsScope>,
+}
+
+impl PortableSettingsImport {
+    /// Combine the imported secrets with the current keyring contents.
+    ///
+    /// Returns `None` when the keyring does not need to change.
+    pub fn merged_secrets(
+        &self,
+        existing: &HashMap<String, String>,
+    ) -> Option<HashMap<String, String>> {
+        if self.secrets.is_empty() && self.replaced_secret_scopes.is_empty() {
+            return None;
+        }
+        let mut merged = existing.clone();
+        merged.retain(|key, _| {
+            !self
+                .replaced_secret_scopes
+                .iter()
+                .any(|scope| scope.owns_secret(key))
+        });
+        merged.extend(self.secrets.clone());
+        Some(merged)
+    }
+}
+
+/// Export the selected scopes (all when empty) as a portable bundle.
+///
+/// `secrets` are the decrypted keyring entries. Entries owned by the exported
+/// scopes are encrypted with `password` when one is given; otherwise they are
+/// left out and listed in `redacted`. MCP server env and header values are
+/// always replaced with [`REDACTED_SECRET`].
+pub fn export_settings(
+    db: &Database,
+    config: &AppConfig,
+    scopes: &[SettingsScope],
+    secrets: &HashMap<String, String>,
+    password: Option<&str>,
+) -> AppResult<PortableSettingsBundle> {
+    let scopes = normalize_scopes(scopes);
+    let mut redacted = Vec::new();
+
+    let providers = scopes
+        .contains(&SettingsScope::Providers)
+        .then(|| ProviderSettingsExport {
+            default_provider: config.default_provider.clone(),
+            default_model: config.default_model.clone(),
+            model_by_provider: config.model_by_provider.clone(),
+            glm_endpoint: config.glm_endpoint.clone(),
+            minimax_endpoint: config.minimax_endpoint.clone(),
+            qwen_endpoint: config.qwen_endpoint.clone(),
+            custom_provider_base_urls: config.custom_provider_base_urls.clone(),
+            custom_provider_endpoints: config.custom_provider_endpoints.clone(),
+            selected_custom_provider_endpoint_ids: config
+                .selected_custom_provider_endpoint_ids
+                .clone(),
+        });
+
+    let mcp_servers = if scopes.contains(&SettingsScope::McpServers) {
+        let mut servers = Vec::new();
+        for mut server in db.list_mcp_servers()? {
+            for (field, values) in [("env", &mut server.env), ("headers", &mut server.headers)] {
+                for (name, value) in values.iter_mut() {
+                    *value = REDACTED_SECRET.to_string();
+                    redacted.push(format!("mcp_servers.{}.{}.{}", server.id, field, name));
+                }
+            }
+            server.status = crate::models::McpServerStatus::Unknown;
+            server.last_error = None;
+            servers.push(serde_json::to_value(&server)?);
+        }
+        Some(servers)
+    } else {
+        None
+    };
+
+    let guardrails = if scopes.contains(&SettingsScope::Guardrails) {
+        Some(GuardrailSettingsExport {
+            mode: db.get_setting("guardrail_mode_v1")?,
+            rules: collect_guardrail_rules(db)?,
+        })
+    } else {
+        None
+    };
+
+    let scoped_secrets: HashMap<&String, &String> = secrets
+        .iter()
+        .filter(|(key, _)| scopes.iter().any(|scope| scope.owns_secret(key)))
+        .collect();
+    let encrypted_secrets = match password.filter(|pw| !pw.is_empty()) {
+        Some(pw) if !scoped_secrets.is_empty() => Some(encrypt_with_password(
+            &serde_json::to_string(&scoped_secrets)?,
+            pw,
+        )?),
+        _ => {
+            redacted.extend(scoped_secrets.keys().map(|key| format!("secrets.{}", key)));
+            None
+        }
+    };
+    redacted.sort();
+
+    Ok(PortableSettingsBundle {
+        schema_version: PORTABLE_SETTINGS_SCHEMA_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        scopes,
+        providers,
+        mcp_servers,
+        guardrails,
+        redacted,
+        encrypted_secrets,
+    })
+}
+
+/// Parse a portable settings bundle, checking its schema version first.
+pub fn parse_settings_bundle(raw: &str) -> AppResult<PortableSettingsBundle> {
+    let value: serde_json::Value = serde_json::from_str(raw)
+        .map_err(|e| AppError::parse(format!("Invalid settings bundle: {}", e)))?;
+    let version = value
+        .get("schema_version")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| AppError::validation("Settings bundle is missing schema_version"))?;
+    validate_schema_version(version)?;
+    serde_json::from_value(value)
+        .map_err(|e| AppError::parse(format!("Invalid settings bundle: {}", e)))
+}
+
+/// Import a portable settings bundle.
+///
+/// Only scopes listed in the bundle are touched. In `Merge` mode entries are
+/// added or updated; in `Replace` mode each imported scope ends up matching
+/// the bundle. Redacted values are skipped and reported as warnings.
+pub fn import_settings(
+    db: &Database,
+    config: &AppConfig,
+    bundle: &PortableSettingsBundle,
+    mode: SettingsImportMode,
+    password: Option<&str>,
+) -> AppResult<PortableSettingsImport> {
+    validate_schema_version(bundle.schema_version as u64)?;
+    let scopes = normalize_scopes(&bundle.scopes);
+
+    let mut result = SettingsImportResult {
+        success: true,
+        frontend: None,
+        imported_sections: Vec::new(),
+        skipped_sections: Vec::new(),
+        warnings: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    let mut secrets: HashMap<String, String> = HashMap::new();
+    let mut replaced_secret_scopes = Vec::new();
+    if let Some(ref encrypted) = bundle.encrypted_secrets {
+        match password.filter(|pw| !pw.is_empty()) {
+            Some(pw) => {
+                let decrypted = decrypt_with_password(encrypted, pw).map_err(|_| {
+                    AppError::validation("Wrong password: failed to decrypt secrets")
+                })?;
+                secrets = serde_json::from_str(&decrypted)?;
+                secrets.retain(|key, _| scopes.iter().any(|scope| scope.owns_secret(key)));
+                if mode == SettingsImportMode::Replace {
+                    replaced_secret_scopes = scopes.clone();
+                }
+            }
+            None => result.warnings.push(
+                "Bundle contains encrypted secrets but no password was provided. Secrets were not imported."
+                    .to_string(),
+            ),
+        }
+    }
+    if !bundle.redacted.is_empty() {
+        result.warnings.push(format!(
+            "{} redacted secret value(s) were not imported and must be re-entered.",
+            bundle.redacted.len()
+        ));
+    }
+
+    let mut config_update = None;
+    for scope in SettingsScope::ALL {
+        let name = scope_name(scope);
+        if !scopes.contains(&scope) {
+            result.skipped_sections.push(name.to_string());
+            continue;
+        }
+        match scope {
+            SettingsScope::Providers => match bundle.providers {
+                Some(ref providers) => {
+                    config_update = Some(provider_settings_update(config, providers, mode));
+                    result.imported_sections.push(name.to_string());
+                }
+                None => result.skipped_sections.push(name.to_string()),
+            },
+            SettingsScope::McpServers => match bundle.mcp_servers {
+                Some(ref servers) => import_section(&mut result, name, || {
+                    import_portable_mcp_servers(db, servers, mode, &mut secrets)
+                }),
+                None => result.skipped_sections.push(name.to_string()),
+            },
+            SettingsScope::Guardrails => match bundle.guardrails {
+                Some(ref guardrails) => import_section(&mut result, name, || {
+                    import_portable_guardrails(db, guardrails, mode)
+                }),
+                None => result.skipped_sections.push(name.to_string()),
+            },
+        }
+    }
+
+    result.success = result.errors.is_empty();
+    Ok(PortableSettingsImport {
+        result,
+        config_update,
+        secrets,
+        replaced_secret_scopes,
+    })
+}
+
+fn validate_schema_version(version: u64) -> AppResult<()> {
+    if version == 0 || version > PORTABLE_SETTINGS_SCHEMA_VERSION as u64 {
+        return Err(AppError::validation(format!(
+            "Unsupported settings schema version {} (supported: 1..={})",
+            version, PORTABLE_SETTINGS_SCHEMA_VERSION
+        )));
+    }
+    Ok(())
+}
+
+/// Deduplicate scopes in canonical order; an empty selection means all.
+fn normalize_scopes(scopes: &[SettingsScope]) -> Vec<SettingsScope> {
+    if scopes.is_empty() {
+        return SettingsScope::ALL.to_vec();
+    }
+    SettingsScope::ALL
+        .into_iter()
+        .filter(|scope| scopes.contains(scope))
+        .collect()
+}
+
+fn scope_name(scope: SettingsScope) -> &'static str {
+    match scope {
+        SettingsScope::Providers => "providers",
+        SettingsScope::McpServers => "mcp_servers",
+        SettingsScope::Guardrails => "guardrails",
+    }
+}
+
+fn provider_settings_update(
+    config: &AppConfig,
+    providers: &ProviderSettingsExport,
+    mode: SettingsImportMode,
+) -> SettingsUpdate {
+    let providers = providers.clone();
+    let (model_by_provider, custom_provider_base_urls, custom_provider_endpoints, selected_ids) =
+        match mode {
+            SettingsImportMode::Replace => (
+                providers.model_by_provider,
+                providers.custom_provider_base_urls,
+                providers.custom_provider_endpoints,
+                providers.selected_custom_provider_endpoint_ids,
+            ),
+            SettingsImportMode::Merge => {
+                let mut models = config.model_by_provider.clone();
+                models.extend(providers.model_by_provider);
+                let mut base_urls = config.custom_provider_base_urls.clone();
+                base_urls.extend(providers.custom_provider_base_urls);
+                let mut endpoints = config.custom_provider_endpoints.clone();
+                endpoints.extend(providers.custom_provider_endpoints);
+                let mut selected = config.selected_custom_provider_endpoint_ids.clone();
+                selected.extend(providers.selected_custom_provider_endpoint_ids);
+                (models, base_urls, endpoints, selected)
+            }
+        };
+    SettingsUpdate {
+        default_provider: Some(providers.default_provider),
+        default_model: Some(providers.default_model),
+        model_by_provider: Some(model_by_provider),
+        glm_endpoint: Some(providers.glm_endpoint),
+        minimax_endpoint: Some(providers.minimax_endpoint),
+        qwen_endpoint: Some(providers.qwen_endpoint),
+        custom_provider_base_urls: Some(custom_provider_base_urls),
+        custom_provider_endpoints: Some(custom_provider_endpoints),
+        selected_custom_provider_endpoint_ids: Some(selected_ids),
+        ..Default::default()
+    }
+}
+
+/// Upsert MCP servers, moving any plain env/header values into `secrets`.
+fn import_portable_mcp_servers(
+    db: &Database,
+    servers: &[serde_json::Value],
+    mode: SettingsImportMode,
+    secrets: &mut HashMap<String, String>,
+) -> AppResult<()> {
+    let mut imported_ids = HashSet::new();
+    for value in servers {
+        let mut server: crate::models::McpServer = serde_json::from_value(value.clone())?;
+        server.env.retain(|_, v| v != REDACTED_SECRET);
+        server.headers.retain(|_, v| v != REDACTED_SECRET);
+        if !server.env.is_empty() {
+            secrets.insert(
+                format!("mcp/{}/env", server.id),
+                serde_json::to_string(&server.env)?,
+            );
+            server.has_env_secret = true;
+            server.env.clear();
+        }
+        if !server.headers.is_empty() {
+            secrets.insert(
+                format!("mcp/{}/headers", server.id),
+                serde_json::to_string(&server.headers)?,
+            );
+            server.has_headers_secret = true;
+            server.headers.clear();
+        }
+        match db.get_mcp_server(&server.id)? {
+            Some(_) => db.update_mcp_server(&server)?,
+            None => db.insert_mcp_server(&server)?,
+        }
+        imported_ids.insert(server.id);
+    }
+
+    if mode == SettingsImportMode::Replace {
+        for existing in db.list_mcp_servers()? {
+            if !imported_ids.contains(&existing.id) {
+                db.delete_mcp_server(&existing.id)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn import_portable_guardrails(
+    db: &Database,
+    guardrails: &GuardrailSettingsExport,
+    mode: SettingsImportMode,
+) -> AppResult<()> {
+    if let Some(ref raw_mode) = guardrails.mode {
+        let mode = GuardrailMode::parse(raw_mode).unwrap_or_default();
+        db.set_setting("guardrail_mode_v1", &mode.to_string())?;
+    }
+
+    let conn = db.get_connection()?;
+    if mode == SettingsImportMode::Replace {
+        conn.execute("DELETE FROM guardrail_rules", [])?;
+    }
+    for rule in &guardrails.rules {
+        conn.execute(
+            "INSERT OR REPLACE INTO guardrail_rules
+             (id, name, guardrail_type, builtin_key, pattern, action, scope, enabled, editable, description, created_at, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, datetime('now'), datetime('now'))",
+            params![
+                rule.id,
+                rule.name,
+                if rule.guardrail_type.is_empty() { "custom".to_string() } else { rule.guardrail_type.clone() },
+                rule.builtin_key,
+                rule.pattern,
+                rule.action,
+                serde_json::to_string(&rule.scope)?,
+                rule.enabled as i32,
+                rule.editable as i32,
+                rule.description,
+            ],
+        )?;
+    }
+    Ok(())
+}
+
 fn parse_lsp_preferences_export(raw: &str) -> Option<LspPreferencesExport> {
     let value = serde_json::from_str::<serde_json::Value>(raw).ok()?;
     let auto_enrich = value
@@ -643,4 +1026,189 @@ mod tests {
         assert!(lsp.auto_enrich);
         assert_eq!(lsp.incremental_debounce_ms, 5000);
     }
+
+    fn seed_portable_settings(db: &Database) -> HashMap<String, String> {
+        let mut server = crate::models::McpServer::new_stream_http(
+            "mcp-1".to_string(),
+            "Docs".to_string(),
+            "https://mcp.example.com".to_string(),
+        );
+        server
+            .headers
+            .insert("Authorization".to_string(), "Bearer tok-123".to_string());
+        db.insert_mcp_server(&server).unwrap();
+        import_portable_guardrails(
+            db,
+            &GuardrailSettingsExport {
+                mode: Some("balanced".to_string()),
+                rules: vec![guardrail_rule("rule-1", "No secrets")],
+            },
+            SettingsImportMode::Replace,
+        )
+        .unwrap();
+
+        HashMap::from([
+            ("anthropic".to_string(), "sk-ant-123".to_string()),
+            ("mcp/mcp-1/headers".to_string(), "{}".to_string()),
+        ])
+    }
+
+    fn guardrail_rule(id: &str, name: &str) -> GuardrailRuleExport {
+        GuardrailRuleExport {
+            id: id.to_string(),
+            name: name.to_string(),
+            guardrail_type: "custom".to_string(),
+            builtin_key: None,
+            pattern: Some("secret".to_string()),
+            action: "block".to_string(),
+            enabled: true,
+            scope: vec!["input".to_string()],
+            editable: true,
+            description: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_export_settings_redacts_secrets_by_default() {
+        let db = Database::new_in_memory().unwrap();
+        let secrets = seed_portable_settings(&db);
+        let bundle = export_settings(&db, &AppConfig::default(), &[], &secrets, None).unwrap();
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        assert!(!json.contains("sk-ant-123"));
+        assert!(!json.contains("tok-123"));
+        assert!(bundle.encrypted_secrets.is_none());
+        assert_eq!(
+            bundle.redacted,
+            vec![
+                "mcp_servers.mcp-1.headers.Authorization",
+                "secrets.anthropic",
+                "secrets.mcp/mcp-1/headers",
+            ]
+        );
+        let servers = bundle.mcp_servers.unwrap();
+        assert_eq!(servers[0]["headers"]["Authorization"], REDACTED_SECRET);
+
+        // Opting in encrypts the secrets instead.
+        let bundle =
+            export_settings(&db, &AppConfig::default(), &[], &secrets, Some("pw")).unwrap();
+        let decrypted = decrypt_with_password(&bundle.encrypted_secrets.unwrap(), "pw").unwrap();
+        assert!(decrypted.contains("sk-ant-123"));
+        assert!(!bundle.redacted.iter().any(|p| p.starts_with("secrets.")));
+    }
+
+    #[test]
+    fn test_export_settings_includes_only_selected_scope() {
+        let db = Database::new_in_memory().unwrap();
+        let secrets = seed_portable_settings(&db);
+        let bundle = export_settings(
+            &db,
+            &AppConfig::default(),
+            &[SettingsScope::Guardrails],
+            &secrets,
+            Some("pw"),
+        )
+        .unwrap();
+
+        assert_eq!(bundle.scopes, vec![SettingsScope::Guardrails]);
+        assert!(bundle.providers.is_none());
+        assert!(bundle.mcp_servers.is_none());
+        assert!(bundle.encrypted_secrets.is_none());
+        assert!(bundle.redacted.is_empty());
+        let guardrails = bundle.guardrails.unwrap();
+        assert_eq!(guardrails.mode.as_deref(), Some("balanced"));
+        assert_eq!(guardrails.rules.len(), 1);
+
+        let json = serde_json::to_value(
+            export_settings(
+                &db,
+                &AppConfig::default(),
+                &[SettingsScope::Providers],
+                &secrets,
+                Some("pw"),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert!(json.get("providers").is_some());
+        assert!(json.get("mcp_servers").is_none());
+        assert!(json.get("guardrails").is_none());
+    }
+
+    #[test]
+    fn test_import_settings_validates_schema_version() {
+        let err = parse_settings_bundle(r#"{"exported_at":"","scopes":[]}"#).unwrap_err();
+        assert!(err.to_string().contains("schema_version"));
+
+        let newer = format!(
+            r#"{{"schema_version":{},"exported_at":"","scopes":[]}}"#,
+            PORTABLE_SETTINGS_SCHEMA_VERSION + 1
+        );
+        assert!(matches!(
+            parse_settings_bundle(&newer),
+            Err(AppError::Validation(_))
+        ));
+
+        let db = Database::new_in_memory().unwrap();
+        let mut bundle =
+            export_settings(&db, &AppConfig::default(), &[], &HashMap::new(), None).unwrap();
+        let raw = serde_json::to_string(&bundle).unwrap();
+        assert!(parse_settings_bundle(&raw).is_ok());
+
+        bundle.schema_version = 0;
+        assert!(import_settings(
+            &db,
+            &AppConfig::default(),
+            &bundle,
+            SettingsImportMode::Merge,
+            None
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_import_settings_merge_and_replace_guardrails() {
+        let db = Database::new_in_memory().unwrap();
+        seed_portable_settings(&db);
+        let bundle = PortableSettingsBundle {
+            schema_version: PORTABLE_SETTINGS_SCHEMA_VERSION,
+            exported_at: String::new(),
+            scopes: vec![SettingsScope::Guardrails],
+            providers: None,
+            mcp_servers: None,
+            guardrails: Some(GuardrailSettingsExport {
+                mode: None,
+                rules: vec![guardrail_rule("rule-2", "No keys")],
+            }),
+            redacted: Vec::new(),
+            encrypted_secrets: None,
+        };
+
+        let import = import_settings(
+            &db,
+            &AppConfig::default(),
+            &bundle,
+            SettingsImportMode::Merge,
+            None,
+        )
+        .unwrap();
+        assert_eq!(import.result.imported_sections, vec!["guardrails"]);
+        assert!(import.config_update.is_none());
+        assert!(import.merged_secrets(&HashMap::new()).is_none());
+        assert_eq!(collect_guardrail_rules(&db).unwrap().len(), 2);
+        // Other scopes are left untouched.
+        assert_eq!(db.list_mcp_servers().unwrap().len(), 1);
+
+        import_settings(
+            &db,
+            &AppConfig::default(),
+            &bundle,
+            SettingsImportMode::Replace,
+            None,
+        )
+        .unwrap();
+        let rules = collect_guardrail_rules(&db).unwrap();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].id, "rule-2");
+    }
 }
@@ -2,14 +2,134 @@
 //!
 //! Auto-generates skills from successful sessions and manages skill_library
 //! database operations (CRUD).
+//!
+//! Generation is gated on a minimum session success signal, and candidates
+//! that embed too close to an already indexed skill are skipped.
 
 use rusqlite::params;
 use std::sync::Arc;
 
+use crate::services::orchestrator::embedding_service::{cosine_similarity, EmbeddingService};
 use crate::services::skills::model::{GeneratedSkill, GeneratedSkillRecord, SkillReviewStatus};
 use crate::storage::database::{Database, DbPool};
 use crate::utils::error::{AppError, AppResult};
 
+/// Cosine similarity at or above which a candidate counts as a near-duplicate.
+pub const DEFAULT_DUPLICATE_SIMILARITY: f32 = 0.80;
+
+/// Minimum tool calls a session needs before a skill is generated from it.
+pub const MIN_GENERATION_TOOL_CALLS: usize = 3;
+
+/// Minimum conversation excerpt length (bytes) for skill generation.
+pub const MIN_GENERATION_CONVERSATION_LEN: usize = 160;
+
+/// Phrases that mark a user turn as pushing back on the assistant's work.
+const CORRECTION_MARKERS: &[&str] = &[
+    "that's wrong",
+    "that is wrong",
+    "not what i",
+    "doesn't work",
+    "does not work",
+    "didn't work",
+    "still fail",
+    "still broken",
+    "revert",
+    "undo",
+    "try again",
+];
+
+/// Success signals observed in a session, used to gate skill generation.
+#[derive(Debug, Clone, Default)]
+pub struct SessionSuccessSignal {
+    /// Whether the session completed successfully
+    pub success: bool,
+    /// Total tool invocations
+    pub tool_calls: usize,
+    /// User turns that corrected or rejected the assistant's work
+    pub user_corrections: usize,
+    /// Length of the conversation excerpt in bytes
+    pub conversation_len: usize,
+}
+
+impl SessionSuccessSignal {
+    /// Whether the session is strong enough evidence to generate a skill from.
+    pub fn is_sufficient(&self) -> bool {
+        self.success
+            && self.tool_calls >= MIN_GENERATION_TOOL_CALLS
+            && self.user_corrections == 0
+            && self.conversation_len >= MIN_GENERATION_CONVERSATION_LEN
+    }
+}
+
+/// Count user turns in a `[User]: ...` transcript that correct the assistant.
+///
+/// The first user turn is the task itself and is never counted.
+pub fn count_user_corrections(conversation: &str) -> usize {
+    conversation
+        .split("[User]: ")
+        .skip(2)
+        .map(|turn| {
+            turn.split("[Assistant]: ")
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_lowercase()
+        })
+        .filter(|text| {
+            text == "no"
+                || text.starts_with("no,")
+                || text.starts_with("no ")
+                || text.starts_with("wrong")
+                || CORRECTION_MARKERS.iter().any(|m| text.contains(m))
+        })
+        .count()
+}
+
+/// Find the existing skill closest to `candidate` by TF-IDF embedding.
+///
+/// `existing` yields `(name, description, body)` for every indexed skill.
+/// Returns the closest skill's name and similarity when it reaches
+/// `threshold`; an exact (case-insensitive) name match always counts.
+pub fn find_near_duplicate<'a, I>(
+    candidate: &GeneratedSkill,
+    existing: I,
+    threshold: f32,
+) -> Option<(&'a str, f32)>
+where
+    I: IntoIterator<Item = (&'a str, &'a str, &'a str)>,
+{
+    let existing: Vec<(&str, String)> = existing
+        .into_iter()
+        .map(|(name, description, body)| (name, format!("{}\n{}\n{}", name, description, body)))
+        .collect();
+    if existing.is_empty() {
+        return None;
+    }
+    if let Some((name, _)) = existing
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(&candidate.name))
+    {
+        return Some((name, 1.0));
+    }
+
+    let candidate_text = format!(
+        "{}\n{}\n{}",
+        candidate.name, candidate.description, candidate.body
+    );
+    let mut corpus: Vec<&str> = existing.iter().map(|(_, text)| text.as_str()).collect();
+    corpus.push(&candidate_text);
+
+    let embeddings = EmbeddingService::new().embed_batch(&corpus);
+    let (candidate_vec, existing_vecs) = embeddings.split_last()?;
+
+    existing
+        .iter()
+        .zip(existing_vecs)
+        .map(|((name, _), vec)| (*name, cosine_similarity(candidate_vec, vec)))
+        .filter(|(_, similarity)| *similarity >= threshold)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+}
+
 /// Service for managing auto-generated skills in the skill_library table.
 pub struct SkillGeneratorStore {
     pool: DbPool,
@@ -29,6 +149,8 @@ impl SkillGeneratorStore {
     }
 
     /// Save a generated skill to the database.
+    ///
+    /// New skills start in `pending_review` so they are not injected until approved.
     pub fn save_generated_skill(
         &self,
         project_path: &str,
@@ -46,8 +168,8 @@ impl SkillGeneratorStore {
         {
             let conn = self.get_connection()?;
             conn.execute(
-                "INSERT INTO skill_library (id, project_path, name, description, tags, body, source_type, source_session_ids, keywords)
-                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                "INSERT INTO skill_library (id, project_path, name, description, tags, body, source_type, source_session_ids, keywords, review_status)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
                 params![
                     id,
                     project_path,
@@ -58,6 +180,7 @@ impl SkillGeneratorStore {
                     "generated",
                     session_ids_json,
                     keywords_json,
+                    review_status_to_sql(&SkillReviewStatus::PendingReview),
                 ],
             )?;
         } // conn dropped here
@@ -589,4 +712,80 @@ mod tests {
         let fetched = store.get_generated_skill("nonexistent").unwrap();
         assert!(fetched.is_none());
     }
+
+    #[test]
+    fn test_find_near_duplicate_skips_similar_skill() {
+        let existing = make_generated_skill("Run database migrations safely");
+        let mut candidate = make_generated_skill("Apply database migrations safely");
+        candidate.body = existing.body.replace("Do thing", "Do the thing");
+
+        let found = find_near_duplicate(
+            &candidate,
+            [(
+                existing.name.as_str(),
+                existing.description.as_str(),
+                existing.body.as_str(),
+            )],
+            DEFAULT_DUPLICATE_SIMILARITY,
+        );
+        let (name, similarity) = found.expect("near-duplicate should be detected");
+        assert_eq!(name, "Run database migrations safely");
+        assert!(similarity >= DEFAULT_DUPLICATE_SIMILARITY);
+    }
+
+    #[test]
+    fn test_find_near_duplicate_allows_distinct_skill() {
+        let existing = make_generated_skill("Run database migrations safely");
+        let candidate = GeneratedSkill {
+            name: "Profile slow React renders".to_string(),
+            description: "Find wasted renders with the profiler".to_string(),
+            tags: vec!["react".to_string()],
+            body: "# Profile\n\n1. Open devtools profiler\n2. Record interaction\n3. Memoize hot components"
+                .to_string(),
+            source_session_ids: vec![],
+        };
+
+        let found = find_near_duplicate(
+            &candidate,
+            [(
+                existing.name.as_str(),
+                existing.description.as_str(),
+                existing.body.as_str(),
+            )],
+            DEFAULT_DUPLICATE_SIMILARITY,
+        );
+        assert!(found.is_none());
+    }
+
+    #[test]
+    fn test_session_signal_requires_no_user_corrections() {
+        let conversation = "[User]: add a migration\n\n[Assistant]: Done.\n\n\
+                            [User]: No, that's the wrong table\n\n[Assistant]: Fixed.\n\n";
+        assert_eq!(count_user_corrections(conversation), 1);
+
+        let mut signal = SessionSuccessSignal {
+            success: true,
+            tool_calls: 5,
+            user_corrections: 0,
+            conversation_len: MIN_GENERATION_CONVERSATION_LEN,
+        };
+        assert!(signal.is_sufficient());
+
+        signal.user_corrections = count_user_corrections(conversation);
+        assert!(!signal.is_sufficient());
+
+        let low_activity = SessionSuccessSignal {
+            tool_calls: 1,
+            user_corrections: 0,
+            ..signal
+        };
+        assert!(!low_activity.is_sufficient());
+    }
+
+    #[test]
+    fn test_count_user_corrections_ignores_task_turn() {
+        let conversation = "[User]: undo the last refactor\n\n[Assistant]: Reverted.\n\n\
+                            [User]: thanks, now add tests\n\n";
+        assert_eq!(count_user_corrections(conversation), 0);
+    }
 }
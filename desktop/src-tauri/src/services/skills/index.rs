@@ -2,17 +2,17 @@
 //!
 //! SkillIndex construction with SHA-256 hashing for change detection.
 //! Builds a SkillIndex from discovered skills by parsing each file and
-//! generating unique IDs.
+//! generating unique IDs. Refreshes reuse entries whose file hash is unchanged.
 
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 use crate::services::skills::model::{
-    DiscoveredSkill, SkillDocument, SkillIndex, SkillIndexStats, SkillSource,
+    DiscoveredSkill, IndexRefreshStats, SkillDocument, SkillIndex, SkillIndexStats, SkillSource,
 };
 use crate::services::skills::parser::parse_skill_file;
-use crate::utils::error::AppResult;
+use crate::utils::error::{AppError, AppResult};
 
 /// Build a SkillIndex from discovered skills.
 ///
@@ -21,58 +21,108 @@ use crate::utils::error::AppResult;
 /// 2. Compute SHA-256 hash of file content
 /// 3. Generate unique ID: normalized-name + "-" + first 12 chars of hex hash
 /// 4. Merge into index, deduplicating by name (higher priority wins)
+/// 5. Reject the index if `requires` declarations form a cycle
 pub fn build_index(skills: Vec<DiscoveredSkill>) -> AppResult<SkillIndex> {
+    refresh_index(None, skills).map(|(index, _)| index)
+}
+
+/// Rebuild a SkillIndex, reusing entries from `previous` whose file hash is unchanged.
+///
+/// A discovered file whose path and SHA-256 match a previous entry keeps the
+/// cached parse; only new or edited files are parsed again. Previous entries
+/// whose files were not discovered are dropped. Source, priority, detection
+/// and enabled state always come from the fresh discovery.
+pub fn refresh_index(
+    previous: Option<&SkillIndex>,
+    skills: Vec<DiscoveredSkill>,
+) -> AppResult<(SkillIndex, IndexRefreshStats)> {
+    let cached: HashMap<&Path, &SkillDocument> = previous
+        .map(|index| {
+            index
+                .skills()
+                .iter()
+                .map(|d| (d.path.as_path(), d))
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut stats = IndexRefreshStats::default();
+    let mut discovered_paths: HashSet<PathBuf> = HashSet::new();
+
     let mut docs: Vec<SkillDocument> = Vec::new();
     let mut seen_names: HashMap<String, usize> = HashMap::new(); // name -> index in docs
 
     for skill in skills {
-        let parsed = match parse_skill_file(&skill.path, &skill.content) {
-            Ok(p) => p,
-            Err(e) => {
-                tracing::warn!("Failed to parse skill file {}: {}", skill.path.display(), e);
-                continue;
-            }
-        };
-
+        discovered_paths.insert(skill.path.clone());
         let hash = compute_sha256(&skill.content);
-        let id = generate_skill_id(&parsed.name, &hash);
-
-        let last_modified = skill
-            .path
-            .metadata()
-            .ok()
-            .and_then(|m| m.modified().ok())
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_secs() as i64);
-
-        let doc = SkillDocument {
-            id,
-            name: parsed.name.clone(),
-            description: parsed.description,
-            version: parsed.version,
-            tags: parsed.tags,
-            body: parsed.body,
-            path: skill.path,
-            hash,
-            last_modified,
-            user_invocable: parsed.user_invocable,
-            tool_policy_mode: parsed.tool_policy_mode,
-            allowed_tools: parsed.allowed_tools,
-            license: parsed.license,
-            metadata: parsed.metadata,
-            hooks: parsed.hooks,
-            source: skill.source,
-            priority: skill.priority,
-            detect: skill.detect,
-            inject_into: skill.inject_into,
-            enabled: skill.enabled,
-            review_status: None,
-            review_notes: None,
-            reviewed_at: None,
+
+        let doc = match cached.get(skill.path.as_path()) {
+            Some(prev) if prev.hash == hash => {
+                stats.reused += 1;
+                SkillDocument {
+                    source: skill.source,
+                    priority: skill.priority,
+                    detect: skill.detect,
+                    inject_into: skill.inject_into,
+                    enabled: skill.enabled,
+                    ..(*prev).clone()
+                }
+            }
+            _ => {
+                let parsed = match parse_skill_file(&skill.path, &skill.content) {
+                    Ok(p) => p,
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to parse skill file {}: {}",
+                            skill.path.display(),
+                            e
+                        );
+                        continue;
+                    }
+                };
+                stats.reparsed += 1;
+
+                let id = generate_skill_id(&parsed.name, &hash);
+
+                let last_modified = skill
+                    .path
+                    .metadata()
+                    .ok()
+                    .and_then(|m| m.modified().ok())
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+                    .map(|d| d.as_secs() as i64);
+
+                SkillDocument {
+                    id,
+                    name: parsed.name,
+                    description: parsed.description,
+                    version: parsed.version,
+                    tags: parsed.tags,
+                    body: parsed.body,
+                    path: skill.path,
+                    hash,
+                    last_modified,
+                    user_invocable: parsed.user_invocable,
+                    tool_policy_mode: parsed.tool_policy_mode,
+                    allowed_tools: parsed.allowed_tools,
+                    license: parsed.license,
+                    metadata: parsed.metadata,
+                    hooks: parsed.hooks,
+                    requires: parsed.requires,
+                    conflicts_with: parsed.conflicts_with,
+                    source: skill.source,
+                    priority: skill.priority,
+                    detect: skill.detect,
+                    inject_into: skill.inject_into,
+                    enabled: skill.enabled,
+                    review_status: None,
+                    review_notes: None,
+                    reviewed_at: None,
+                }
+            }
         };
 
         // Dedup by name: higher priority wins
-        let normalized_name = parsed.name.to_lowercase();
+        let normalized_name = doc.name.to_lowercase();
         if let Some(&existing_idx) = seen_names.get(&normalized_name) {
             if doc.priority > docs[existing_idx].priority {
                 docs[existing_idx] = doc;
@@ -83,10 +133,74 @@ pub fn build_index(skills: Vec<DiscoveredSkill>) -> AppResult<SkillIndex> {
         }
     }
 
+    stats.removed = cached
+        .keys()
+        .filter(|path| !discovered_paths.contains(**path))
+        .count();
+
     // Sort by priority descending (highest priority first)
     docs.sort_by(|a, b| b.priority.cmp(&a.priority));
 
-    Ok(SkillIndex::new(docs))
+    check_requires_acyclic(&docs)?;
+
+    Ok((SkillIndex::new(docs), stats))
+}
+
+/// Fail if any chain of `requires` declarations leads back to its start.
+///
+/// Names are matched case-insensitively; requirements naming skills that are
+/// not in the index are logged and otherwise ignored.
+fn check_requires_acyclic(docs: &[SkillDocument]) -> AppResult<()> {
+    let by_name: HashMap<String, &SkillDocument> =
+        docs.iter().map(|d| (d.name.to_lowercase(), d)).collect();
+
+    // 0 = unvisited, 1 = on the current path, 2 = done
+    let mut state: HashMap<String, u8> = HashMap::new();
+
+    fn visit(
+        name: &str,
+        by_name: &HashMap<String, &SkillDocument>,
+        state: &mut HashMap<String, u8>,
+        path: &mut Vec<String>,
+    ) -> AppResult<()> {
+        match state.get(name) {
+            Some(1) => {
+                path.push(name.to_string());
+                return Err(AppError::validation(format!(
+                    "Skill requires cycle: {}",
+                    path.join(" -> ")
+                )));
+            }
+            Some(_) => return Ok(()),
+            None => {}
+        }
+        let Some(doc) = by_name.get(name) else {
+            return Ok(());
+        };
+        state.insert(name.to_string(), 1);
+        path.push(doc.name.clone());
+        for required in &doc.requires {
+            let required = required.to_lowercase();
+            if !by_name.contains_key(&required) {
+                tracing::warn!("Skill '{}' requires unknown skill '{}'", doc.name, required);
+                continue;
+            }
+            visit(&required, by_name, state, path)?;
+        }
+        path.pop();
+        state.insert(name.to_string(), 2);
+        Ok(())
+    }
+
+    for doc in docs {
+        visit(
+            &doc.name.to_lowercase(),
+            &by_name,
+            &mut state,
+            &mut Vec::new(),
+        )?;
+    }
+    Ok(())
 }
 
 /// Compute SHA-256 hash of content, returning the full hex string.
@@ -411,6 +525,8 @@ mod tests {
                 license: None,
                 metadata: HashMap::new(),
                 hooks: None,
+                requires: vec![],
+                conflicts_with: vec![],
                 source,
                 priority,
                 detect,
@@ -456,4 +572,138 @@ mod tests {
         assert_eq!(index.len(), 1);
         assert_eq!(index.skills()[0].name, "CLAUDE");
     }
+
+    #[test]
+    fn test_build_index_requires_cycle_fails() {
+        let skills = vec![
+            make_discovered(
+                "a",
+                "---\nname: a\ndescription: A\nrequires: [b]\n---\nA",
+                SkillSource::ProjectLocal,
+                201,
+            ),
+            make_discovered(
+                "b",
+                "---\nname: b\ndescription: B\nrequires: [c]\n---\nB",
+                SkillSource::ProjectLocal,
+                201,
+            ),
+            make_discovered(
+                "c",
+                "---\nname: c\ndescription: C\nrequires: [a]\n---\nC",
+                SkillSource::ProjectLocal,
+                201,
+            ),
+        ];
+
+        let err = build_index(skills).unwrap_err();
+        assert!(err.to_string().contains("requires cycle"));
+    }
+
+    #[test]
+    fn test_build_index_requires_unknown_skill_ignored() {
+        let skills = vec![make_discovered(
+            "a",
+            "---\nname: a\ndescription: A\nrequires: [missing]\n---\nA",
+            SkillSource::ProjectLocal,
+            201,
+        )];
+
+        let index = build_index(skills).unwrap();
+        assert_eq!(index.skills()[0].requires, vec!["missing".to_string()]);
+    }
+
+    fn discover_dir(dir: &Path) -> Vec<DiscoveredSkill> {
+        let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().path())
+            .collect();
+        paths.sort();
+        paths
+            .into_iter()
+            .map(|path| DiscoveredSkill {
+                content: std::fs::read_to_string(&path).unwrap(),
+                path,
+                source: SkillSource::ProjectLocal,
+                priority: 201,
+                detect: None,
+                inject_into: vec![InjectionPhase::Always],
+                enabled: true,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_refresh_index_reparses_only_changed_file() {
+        let dir = TempDir::new().unwrap();
+        for name in ["alpha", "beta", "gamma"] {
+            std::fs::write(
+                dir.path().join(format!("{}.md", name)),
+                format!("---\nname: {0}\ndescription: {0} v1\n---\n# {0}", name),
+            )
+            .unwrap();
+        }
+
+        let (first, stats) = refresh_index(None, discover_dir(dir.path())).unwrap();
+        assert_eq!(
+            stats,
+            IndexRefreshStats {
+                reparsed: 3,
+                reused: 0,
+                removed: 0
+            }
+        );
+
+        std::fs::write(
+            dir.path().join("beta.md"),
+            "---\nname: beta\ndescription: beta v2\n---\n# beta",
+        )
+        .unwrap();
+
+        let (second, stats) = refresh_index(Some(&first), discover_dir(dir.path())).unwrap();
+        assert_eq!(
+            stats,
+            IndexRefreshStats {
+                reparsed: 1,
+                reused: 2,
+                removed: 0
+            }
+        );
+
+        let by_name = |index: &SkillIndex, name: &str| {
+            index
+                .skills()
+                .iter()
+                .find(|d| d.name == name)
+                .cloned()
+                .unwrap()
+        };
+        assert_eq!(by_name(&second, "beta").description, "beta v2");
+        assert_ne!(by_name(&second, "beta").hash, by_name(&first, "beta").hash);
+        for name in ["alpha", "gamma"] {
+            assert_eq!(by_name(&second, name).id, by_name(&first, name).id);
+            assert_eq!(by_name(&second, name).hash, by_name(&first, name).hash);
+        }
+    }
+
+    #[test]
+    fn test_refresh_index_drops_deleted_file() {
+        let dir = TempDir::new().unwrap();
+        for name in ["alpha", "beta"] {
+            std::fs::write(
+                dir.path().join(format!("{}.md", name)),
+                format!("---\nname: {0}\ndescription: {0}\n---\n# {0}", name),
+            )
+            .unwrap();
+        }
+        let (first, _) = refresh_index(None, discover_dir(dir.path())).unwrap();
+
+        std::fs::remove_file(dir.path().join("alpha.md")).unwrap();
+        let (second, stats) = refresh_index(Some(&first), discover_dir(dir.path())).unwrap();
+
+        assert_eq!(stats.removed, 1);
+        assert_eq!(stats.reused, 1);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second.skills()[0].name, "beta");
+    }
 }
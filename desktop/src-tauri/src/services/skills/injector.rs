@@ -109,7 +109,7 @@ fn format_source(source: &SkillSource) -> String {
 }
 
 /// Truncate body text to max_lines, appending a truncation notice if needed.
-fn truncate_body(body: &str, max_lines: usize) -> String {
+pub(crate) fn truncate_body(body: &str, max_lines: usize) -> String {
     let lines: Vec<&str> = body.lines().collect();
     if lines.len() <= max_lines {
         body.to_string()
@@ -135,6 +135,7 @@ mod tests {
         SkillMatch {
             score: 10.0,
             match_reason: MatchReason::AutoDetected,
+            explanation: None,
             skill: SkillSummary {
                 id: format!("{}-test123", name),
                 name: name.to_string(),
@@ -234,6 +235,8 @@ mod tests {
             license: None,
             metadata: std::collections::HashMap::new(),
             hooks: None,
+            requires: vec![],
+            conflicts_with: vec![],
             source: SkillSource::External {
                 source_name: "vercel".to_string(),
             },
@@ -280,6 +283,8 @@ mod tests {
             license: None,
             metadata: std::collections::HashMap::new(),
             hooks: None,
+            requires: vec![],
+            conflicts_with: vec![],
             source: SkillSource::Builtin,
             priority: 10,
             detect: None,
@@ -306,6 +311,8 @@ mod tests {
             license: None,
             metadata: std::collections::HashMap::new(),
             hooks: None,
+            requires: vec![],
+            conflicts_with: vec![],
             source: SkillSource::ProjectLocal,
             priority: 201,
             detect: None,
@@ -125,6 +125,12 @@ pub struct SkillDocument {
     pub metadata: HashMap<String, String>,
     /// Pre/Post tool hooks
     pub hooks: Option<SkillHooks>,
+    /// Names of skills that must be injected alongside this one
+    #[serde(default)]
+    pub requires: Vec<String>,
+    /// Names of skills that must not be injected alongside this one
+    #[serde(default)]
+    pub conflicts_with: Vec<String>,
 
     // --- Source & priority ---
     /// Which source tier this skill belongs to
@@ -183,6 +189,19 @@ pub enum MatchReason {
     LexicalMatch { query: String },
     /// User explicitly enabled for this session
     UserForced,
+    /// Pulled in by another selected skill's `requires`
+    Required { by: String },
+}
+
+/// The concrete signals behind a match, so the UI can explain selection
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MatchExplanation {
+    /// Detect files found in the project root (auto-detection)
+    pub matched_files: Vec<String>,
+    /// Detect patterns found in those files (auto-detection)
+    pub matched_patterns: Vec<String>,
+    /// Query terms that hit the skill's name, description, tags or body (lexical)
+    pub matched_terms: Vec<String>,
 }
 
 /// A matched skill with relevance score
@@ -192,6 +211,9 @@ pub struct SkillMatch {
     pub score: f32,
     /// Why this skill was selected
     pub match_reason: MatchReason,
+    /// Signals that produced the match. `None` for forced or required skills.
+    #[serde(default)]
+    pub explanation: Option<MatchExplanation>,
     /// Skill summary
     pub skill: SkillSummary,
 }
@@ -209,6 +231,9 @@ pub struct SelectionPolicy {
     pub exclude_tags: Vec<String>,
     /// Max lines per skill body (default: 200)
     pub max_content_lines: usize,
+    /// Max estimated tokens of injected skill bodies. `None` = unbounded.
+    #[serde(default)]
+    pub max_tokens: Option<usize>,
 }
 
 impl Default for SelectionPolicy {
@@ -219,10 +244,45 @@ impl Default for SelectionPolicy {
             include_tags: vec![],
             exclude_tags: vec![],
             max_content_lines: 200,
+            max_tokens: None,
         }
     }
 }
 
+/// Why a candidate skill was left out of the selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillDropReason {
+    /// Lexical score below `SelectionPolicy::min_score`
+    BelowThreshold,
+    /// `SelectionPolicy::top_k` already reached
+    OverCount,
+    /// Body would exceed `SelectionPolicy::max_tokens`
+    OverTokenBudget,
+    /// Conflicts with a higher-priority selected skill
+    Conflict,
+}
+
+/// A candidate skill that was not selected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DroppedSkill {
+    pub skill_id: String,
+    pub name: String,
+    pub score: f32,
+    pub reason: SkillDropReason,
+}
+
+/// Skills selected for injection, plus the candidates that were dropped
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillSelection {
+    /// Selected skills, auto-detected first, then by score
+    pub selected: Vec<SkillMatch>,
+    /// Sum of estimated tokens of the selected skill bodies
+    pub injected_tokens: usize,
+    /// Candidates left out, in the order they were considered
+    pub dropped: Vec<DroppedSkill>,
+}
+
 /// Immutable collection of indexed skills
 #[derive(Debug, Clone)]
 pub struct SkillIndex {
@@ -275,6 +335,13 @@ impl SkillIndex {
     pub fn get_by_id(&self, id: &str) -> Option<&SkillDocument> {
         self.skills.iter().find(|s| s.id == id)
     }
+
+    /// Find a skill by name (case-insensitive)
+    pub fn get_by_name(&self, name: &str) -> Option<&SkillDocument> {
+        self.skills
+            .iter()
+            .find(|s| s.name.eq_ignore_ascii_case(name))
+    }
 }
 
 impl SkillDocument {
@@ -316,12 +383,35 @@ pub struct SkillIndexStats {
     pub detected_count: usize,
 }
 
+/// What an incremental index refresh did
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexRefreshStats {
+    /// New or changed files that were parsed
+    pub reparsed: usize,
+    /// Unchanged files whose cached entry was kept
+    pub reused: usize,
+    /// Previously indexed files that no longer exist
+    pub removed: usize,
+}
+
+/// A project's file-based skill index, kept between refreshes
+#[derive(Debug, Clone)]
+pub struct CachedSkillIndex {
+    pub index: SkillIndex,
+    pub refreshed_at: String,
+    pub refresh: IndexRefreshStats,
+}
+
 /// Overview of skills configuration and state for a project
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillsOverview {
     pub stats: SkillIndexStats,
     pub detected_skills: Vec<SkillSummary>,
     pub sources: Vec<String>,
+    /// RFC 3339 time of the last file index refresh, if one has run
+    pub last_refreshed_at: Option<String>,
+    /// Counts from the last file index refresh
+    pub last_refresh: Option<IndexRefreshStats>,
 }
 
 /// A skill discovered during filesystem scanning (before indexing)
@@ -382,6 +472,8 @@ pub struct ParsedSkill {
     pub license: Option<String>,
     pub metadata: HashMap<String, String>,
     pub hooks: Option<SkillHooks>,
+    pub requires: Vec<String>,
+    pub conflicts_with: Vec<String>,
 }
 
 #[cfg(test)]
@@ -405,6 +497,8 @@ mod tests {
             license: None,
             metadata: HashMap::new(),
             hooks: None,
+            requires: vec![],
+            conflicts_with: vec![],
             source,
             priority,
             detect: None,
@@ -422,6 +516,7 @@ mod tests {
         assert_eq!(policy.top_k, 3);
         assert_eq!(policy.min_score, 1.0);
         assert_eq!(policy.max_content_lines, 200);
+        assert_eq!(policy.max_tokens, None);
         assert!(policy.include_tags.is_empty());
         assert!(policy.exclude_tags.is_empty());
     }
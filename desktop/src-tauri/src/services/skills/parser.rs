@@ -133,6 +133,15 @@ fn parse_with_frontmatter(frontmatter: &str, body: &str, path: &Path) -> AppResu
         .map(|v| extract_string_list(v))
         .unwrap_or_default();
     let license = fields.get("license").map(|v| extract_string(v));
+    let requires = fields
+        .get("requires")
+        .map(|v| extract_string_list(v))
+        .unwrap_or_default();
+    let conflicts_with = fields
+        .get("conflicts-with")
+        .or_else(|| fields.get("conflicts_with"))
+        .map(|v| extract_string_list(v))
+        .unwrap_or_default();
 
     // Parse hooks if present
     let hooks = parse_hooks(&fields);
@@ -153,6 +162,9 @@ fn parse_with_frontmatter(frontmatter: &str, body: &str, path: &Path) -> AppResu
         "license",
         "hooks",
         "metadata",
+        "requires",
+        "conflicts-with",
+        "conflicts_with",
     ];
 
     // Parse explicit metadata field
@@ -183,6 +195,8 @@ fn parse_with_frontmatter(frontmatter: &str, body: &str, path: &Path) -> AppResu
         license,
         metadata,
         hooks,
+        requires,
+        conflicts_with,
     })
 }
 
@@ -225,6 +239,8 @@ fn parse_convention_file(path: &Path, content: &str) -> AppResult<ParsedSkill> {
         license: None,
         metadata: HashMap::new(),
         hooks: None,
+        requires: vec![],
+        conflicts_with: vec![],
     })
 }
 
@@ -1043,6 +1059,24 @@ allowed-tools:
         assert_eq!(result.allowed_tools, vec!["Read", "Write"]);
     }
 
+    #[test]
+    fn test_parse_requires_and_conflicts() {
+        let content = r#"---
+name: vue-skill
+description: Vue conventions
+requires: [typescript]
+conflicts-with:
+  - react-skill
+---
+
+# Body
+"#;
+        let result = parse_skill_file(&PathBuf::from("/test/SKILL.md"), content).unwrap();
+        assert_eq!(result.requires, vec!["typescript"]);
+        assert_eq!(result.conflicts_with, vec!["react-skill"]);
+        assert!(!result.metadata.contains_key("conflicts-with"));
+    }
+
     #[test]
     fn test_convention_file_empty_content() {
         let content = "";
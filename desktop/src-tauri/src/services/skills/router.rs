@@ -113,6 +113,7 @@ fn match_reason_label(reason: &MatchReason) -> String {
         MatchReason::AutoDetected => "auto_detected".to_string(),
         MatchReason::LexicalMatch { .. } => "lexical_match".to_string(),
         MatchReason::UserForced => "user_forced".to_string(),
+        MatchReason::Required { .. } => "required".to_string(),
     }
 }
 
@@ -530,6 +531,7 @@ mod tests {
             match_reason: MatchReason::LexicalMatch {
                 query: "test".to_string(),
             },
+            explanation: None,
             skill: crate::services::skills::model::SkillSummary {
                 id: id.to_string(),
                 name: name.to_string(),
@@ -7,35 +7,59 @@
 //!
 //! Phase 2: Lexical matching (per user message)
 //!   Score project-local skills against user query using weighted term matching.
+//!
+//! Candidates from both phases are then fit into the policy's count and token
+//! budgets, auto-detected skills first.
 
 use std::collections::HashSet;
 use std::path::Path;
 
+use crate::services::skills::injector::truncate_body;
 use crate::services::skills::model::{
-    InjectionPhase, MatchReason, SelectionPolicy, SkillIndex, SkillMatch,
+    DroppedSkill, InjectionPhase, MatchExplanation, MatchReason, SelectionPolicy, SkillDropReason,
+    SkillIndex, SkillMatch, SkillSelection, SkillSummary,
 };
 
 /// Select skills for a session using two-phase approach.
 ///
+/// Thin wrapper over [`select_skills_within_budget`] for callers that only
+/// need the selected skills.
+pub fn select_skills_for_session(
+    index: &SkillIndex,
+    project_root: &Path,
+    user_message: &str,
+    phase: &InjectionPhase,
+    policy: &SelectionPolicy,
+) -> Vec<SkillMatch> {
+    select_skills_within_budget(index, project_root, user_message, phase, policy).selected
+}
+
+/// Select skills for a session and report which candidates were dropped.
+///
 /// Phase 1: Auto-detected skills (have detect rules that match the project)
 /// Phase 2: Lexical scoring against user message
 ///
-/// Auto-detected skills always included (up to top_k).
-/// Lexical matches fill remaining slots.
-/// Priority breaks ties.
-pub fn select_skills_for_session(
+/// Auto-detected skills are considered first, then lexical matches that clear
+/// `min_score` in descending score order (priority breaks ties). Each
+/// candidate's `requires` are pulled in right after it, and of two conflicting
+/// candidates only the higher-priority one is kept. A candidate
+/// is taken while fewer than `top_k` skills are selected and its truncated
+/// body fits in what is left of `max_tokens`; a candidate too large for the
+/// remaining budget is skipped so a smaller one further down can still fit.
+pub fn select_skills_within_budget(
     index: &SkillIndex,
     project_root: &Path,
     user_message: &str,
     phase: &InjectionPhase,
     policy: &SelectionPolicy,
-) -> Vec<SkillMatch> {
-    let mut results = Vec::new();
+) -> SkillSelection {
+    let mut candidates = Vec::new();
+    let mut selection = SkillSelection::default();
 
     // Phase 1: Auto-detected skills
     let auto_detected = detect_applicable_skills(index, project_root, phase);
-    for (skill_idx, _score) in &auto_detected {
-        let skill = &index.skills()[*skill_idx];
+    for (skill_idx, _score, explanation) in auto_detected {
+        let skill = &index.skills()[skill_idx];
         if !skill.enabled {
             continue;
         }
@@ -48,23 +72,23 @@ pub fn select_skills_for_session(
             continue;
         }
 
-        results.push(SkillMatch {
+        candidates.push(SkillMatch {
             score: 100.0, // Auto-detected skills always have high score
             match_reason: MatchReason::AutoDetected,
+            explanation: Some(explanation),
             skill: skill.to_summary(true),
         });
     }
 
-    // Phase 2: Lexical scoring for remaining slots
-    if results.len() < policy.top_k && !user_message.is_empty() {
-        let detected_ids: HashSet<String> = results.iter().map(|r| r.skill.id.clone()).collect();
+    // Phase 2: Lexical scoring
+    if !user_message.is_empty() {
+        let detected_ids: HashSet<String> = candidates.iter().map(|c| c.skill.id.clone()).collect();
 
         let mut lexical_matches = lexical_score_skills(index, user_message, phase);
 
-        // Filter out already-detected skills and apply policy
+        // Filter out already-detected skills and apply tag policy
         lexical_matches.retain(|m| {
             !detected_ids.contains(&m.skill.id)
-                && m.score >= policy.min_score
                 && m.skill.enabled
                 && (policy.include_tags.is_empty()
                     || m.skill.tags.iter().any(|t| policy.include_tags.contains(t)))
@@ -79,11 +103,163 @@ pub fn select_skills_for_session(
                 .then_with(|| b.skill.priority.cmp(&a.skill.priority))
         });
 
-        let remaining_slots = policy.top_k - results.len();
-        results.extend(lexical_matches.into_iter().take(remaining_slots));
+        for m in lexical_matches {
+            if m.score >= policy.min_score {
+                candidates.push(m);
+            } else {
+                selection
+                    .dropped
+                    .push(dropped_skill(&m, SkillDropReason::BelowThreshold));
+            }
+        }
+    }
+
+    let candidates = expand_requires(index, candidates);
+    let candidates = resolve_conflicts(index, candidates, &mut selection.dropped);
+
+    for candidate in candidates {
+        if selection.selected.len() >= policy.top_k {
+            selection
+                .dropped
+                .push(dropped_skill(&candidate, SkillDropReason::OverCount));
+            continue;
+        }
+        let tokens = index
+            .get_by_id(&candidate.skill.id)
+            .map(|doc| estimate_tokens(&truncate_body(&doc.body, policy.max_content_lines)))
+            .unwrap_or(0);
+        if let Some(budget) = policy.max_tokens {
+            if selection.injected_tokens + tokens > budget {
+                selection
+                    .dropped
+                    .push(dropped_skill(&candidate, SkillDropReason::OverTokenBudget));
+                continue;
+            }
+        }
+        selection.injected_tokens += tokens;
+        selection.selected.push(candidate);
+    }
+
+    selection
+}
+
+fn dropped_skill(candidate: &SkillMatch, reason: SkillDropReason) -> DroppedSkill {
+    DroppedSkill {
+        skill_id: candidate.skill.id.clone(),
+        name: candidate.skill.name.clone(),
+        score: candidate.score,
+        reason,
+    }
+}
+
+/// Insert each candidate's required skills (transitively) right after it.
+///
+/// Required skills inherit the requiring candidate's score. A skill that is
+/// both required and a later candidate is kept only at the earlier position.
+fn expand_requires(index: &SkillIndex, candidates: Vec<SkillMatch>) -> Vec<SkillMatch> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut expanded = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if !seen.insert(candidate.skill.id.clone()) {
+            continue;
+        }
+        let id = candidate.skill.id.clone();
+        let score = candidate.score;
+        expanded.push(candidate);
+        push_required(index, &id, score, &mut seen, &mut expanded);
     }
+    expanded
+}
 
-    results
+fn push_required(
+    index: &SkillIndex,
+    skill_id: &str,
+    score: f32,
+    seen: &mut HashSet<String>,
+    out: &mut Vec<SkillMatch>,
+) {
+    let Some(doc) = index.get_by_id(skill_id) else {
+        return;
+    };
+    for name in &doc.requires {
+        let Some(required) = index.get_by_name(name) else {
+            continue;
+        };
+        if !required.enabled || !seen.insert(required.id.clone()) {
+            continue;
+        }
+        out.push(SkillMatch {
+            score,
+            match_reason: MatchReason::Required {
+                by: doc.name.clone(),
+            },
+            explanation: None,
+            skill: required.to_summary(false),
+        });
+        push_required(index, &required.id, score, seen, out);
+    }
+}
+
+/// Drop candidates that conflict with each other, keeping the higher-priority one.
+///
+/// A `conflicts_with` declared on either side counts. On equal priority the
+/// candidate considered first wins.
+fn resolve_conflicts(
+    index: &SkillIndex,
+    candidates: Vec<SkillMatch>,
+    dropped: &mut Vec<DroppedSkill>,
+) -> Vec<SkillMatch> {
+    let mut kept: Vec<SkillMatch> = Vec::new();
+    for candidate in candidates {
+        let rivals: Vec<usize> = kept
+            .iter()
+            .enumerate()
+            .filter(|(_, k)| conflicts(index, &k.skill, &candidate.skill))
+            .map(|(i, _)| i)
+            .collect();
+
+        if let Some(winner) = rivals
+            .iter()
+            .map(|&i| &kept[i])
+            .find(|k| k.skill.priority >= candidate.skill.priority)
+        {
+            tracing::info!(
+                "Dropping skill '{}': conflicts with higher-priority skill '{}'",
+                candidate.skill.name,
+                winner.skill.name
+            );
+            dropped.push(dropped_skill(&candidate, SkillDropReason::Conflict));
+            continue;
+        }
+
+        for i in rivals.into_iter().rev() {
+            let loser = kept.remove(i);
+            tracing::info!(
+                "Dropping skill '{}': conflicts with higher-priority skill '{}'",
+                loser.skill.name,
+                candidate.skill.name
+            );
+            dropped.push(dropped_skill(&loser, SkillDropReason::Conflict));
+        }
+        kept.push(candidate);
+    }
+    kept
+}
+
+fn conflicts(index: &SkillIndex, a: &SkillSummary, b: &SkillSummary) -> bool {
+    let declares = |x: &SkillSummary, y: &SkillSummary| {
+        index.get_by_id(&x.id).is_some_and(|d| {
+            d.conflicts_with
+                .iter()
+                .any(|n| n.eq_ignore_ascii_case(&y.name))
+        })
+    };
+    declares(a, b) || declares(b, a)
+}
+
+/// Rough token estimate (~4 characters per token).
+fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
 }
 
 /// Select a wider candidate set for downstream LLM reranking.
@@ -110,12 +286,13 @@ pub fn select_skill_candidates_for_session(
 /// 1. Check detect.files -- do any exist in project root?
 /// 2. Check detect.patterns -- do patterns appear in those files?
 ///
-/// Returns (skill_index, match_score) tuples.
+/// Returns (skill_index, match_score, explanation) tuples, where the
+/// explanation lists the detect files and patterns that fired.
 fn detect_applicable_skills(
     index: &SkillIndex,
     project_root: &Path,
     phase: &InjectionPhase,
-) -> Vec<(usize, f32)> {
+) -> Vec<(usize, f32, MatchExplanation)> {
     let mut matches = Vec::new();
 
     for (idx, skill) in index.skills().iter().enumerate() {
@@ -167,7 +344,15 @@ fn detect_applicable_skills(
 
             // Matched! Score based on number of matches
             let score = files_matched.len() as f32 + patterns_matched.len() as f32;
-            matches.push((idx, score));
+            matches.push((
+                idx,
+                score,
+                MatchExplanation {
+                    matched_files: files_matched,
+                    matched_patterns: patterns_matched,
+                    matched_terms: vec![],
+                },
+            ));
         }
     }
 
@@ -185,6 +370,8 @@ fn detect_applicable_skills(
 /// - Tags match:        +2.0 per token
 /// - Body match:        +1.0 per token
 /// - Normalize by sqrt(body_token_count)
+///
+/// Each match's explanation lists the query terms that contributed to its score.
 pub fn lexical_score_skills(
     index: &SkillIndex,
     query: &str,
@@ -218,9 +405,11 @@ pub fn lexical_score_skills(
         let body_token_count = body_tokens.len().max(1);
 
         let mut score = 0.0_f32;
+        let mut matched_terms = Vec::new();
 
         // Score each matching token
         for token in &query_tokens {
+            let before = score;
             if name_tokens.contains(token) {
                 score += 4.0;
             }
@@ -233,7 +422,11 @@ pub fn lexical_score_skills(
             if body_tokens.contains(token) {
                 score += 1.0;
             }
+            if score > before {
+                matched_terms.push(token.clone());
+            }
         }
+        matched_terms.sort();
 
         // Normalize by sqrt(body_token_count) to avoid bias toward long skills
         score /= (body_token_count as f32).sqrt();
@@ -244,6 +437,10 @@ pub fn lexical_score_skills(
                 match_reason: MatchReason::LexicalMatch {
                     query: query.to_string(),
                 },
+                explanation: Some(MatchExplanation {
+                    matched_terms,
+                    ..Default::default()
+                }),
                 skill: skill.to_summary(false),
             });
         }
@@ -301,6 +498,8 @@ mod tests {
             license: None,
             metadata: HashMap::new(),
             hooks: None,
+            requires: vec![],
+            conflicts_with: vec![],
             source,
             priority,
             detect,
@@ -672,4 +871,284 @@ mod tests {
             .iter()
             .all(|r| !r.skill.tags.contains(&"vue".to_string())));
     }
+
+    #[test]
+    fn test_select_within_budget_excludes_sub_threshold() {
+        let dir = TempDir::new().unwrap();
+
+        let index = SkillIndex::new(vec![
+            make_doc(
+                "testing",
+                "Testing guidelines",
+                SkillSource::ProjectLocal,
+                201,
+                None,
+                vec![],
+            ),
+            make_doc(
+                "deploy",
+                "Deployment checklist with testing notes",
+                SkillSource::ProjectLocal,
+                201,
+                None,
+                vec![],
+            ),
+        ]);
+        let testing_score = lexical_score_skills(&index, "testing", &InjectionPhase::Always)
+            .into_iter()
+            .find(|m| m.skill.name == "testing")
+            .unwrap()
+            .score;
+        let policy = SelectionPolicy {
+            min_score: testing_score,
+            ..Default::default()
+        };
+
+        let selection = select_skills_within_budget(
+            &index,
+            dir.path(),
+            "testing",
+            &InjectionPhase::Always,
+            &policy,
+        );
+
+        assert_eq!(selection.selected.len(), 1);
+        assert_eq!(selection.selected[0].skill.name, "testing");
+        assert_eq!(selection.dropped.len(), 1);
+        assert_eq!(selection.dropped[0].name, "deploy");
+        assert_eq!(selection.dropped[0].reason, SkillDropReason::BelowThreshold);
+    }
+
+    #[test]
+    fn test_select_within_budget_token_budget_caps_count() {
+        let dir = TempDir::new().unwrap();
+
+        let docs: Vec<SkillDocument> = (0..5)
+            .map(|i| {
+                make_doc(
+                    &format!("skill-{}", i),
+                    "Test skill",
+                    SkillSource::ProjectLocal,
+                    201,
+                    None,
+                    vec!["test"],
+                )
+            })
+            .collect();
+        let per_skill = estimate_tokens(&docs[0].body);
+        let index = SkillIndex::new(docs);
+        let policy = SelectionPolicy {
+            top_k: 5,
+            min_score: 0.0,
+            max_tokens: Some(per_skill * 2 + per_skill / 2),
+            ..Default::default()
+        };
+
+        let selection = select_skills_within_budget(
+            &index,
+            dir.path(),
+            "test skill",
+            &InjectionPhase::Always,
+            &policy,
+        );
+
+        assert_eq!(selection.selected.len(), 2);
+        assert_eq!(selection.injected_tokens, per_skill * 2);
+        assert_eq!(selection.dropped.len(), 3);
+        assert!(selection
+            .dropped
+            .iter()
+            .all(|d| d.reason == SkillDropReason::OverTokenBudget));
+    }
+
+    #[test]
+    fn test_select_within_budget_auto_detected_take_priority() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("Cargo.toml"), "[package]").unwrap();
+
+        let index = SkillIndex::new(vec![
+            make_doc(
+                "component",
+                "Component helper",
+                SkillSource::ProjectLocal,
+                201,
+                None,
+                vec![],
+            ),
+            make_doc(
+                "rust",
+                "Rust conventions",
+                SkillSource::Builtin,
+                10,
+                Some(SkillDetection {
+                    files: vec!["Cargo.toml".to_string()],
+                    patterns: vec![],
+                }),
+                vec![],
+            ),
+        ]);
+        let policy = SelectionPolicy {
+            top_k: 1,
+            min_score: 0.0,
+            ..Default::default()
+        };
+
+        let selection = select_skills_within_budget(
+            &index,
+            dir.path(),
+            "build a component",
+            &InjectionPhase::Always,
+            &policy,
+        );
+
+        assert_eq!(selection.selected.len(), 1);
+        assert_eq!(selection.selected[0].skill.name, "rust");
+        assert_eq!(selection.dropped.len(), 1);
+        assert_eq!(selection.dropped[0].name, "component");
+        assert_eq!(selection.dropped[0].reason, SkillDropReason::OverCount);
+    }
+
+    #[test]
+    fn test_select_conflict_higher_priority_wins() {
+        let dir = TempDir::new().unwrap();
+
+        let mut vue = make_doc(
+            "vue",
+            "Frontend framework conventions",
+            SkillSource::User,
+            150,
+            None,
+            vec![],
+        );
+        vue.conflicts_with = vec!["react".to_string()];
+        let react = make_doc(
+            "react",
+            "Frontend framework conventions",
+            SkillSource::ProjectLocal,
+            201,
+            None,
+            vec![],
+        );
+        let index = SkillIndex::new(vec![vue, react]);
+        let policy = SelectionPolicy {
+            min_score: 0.0,
+            ..Default::default()
+        };
+
+        let selection = select_skills_within_budget(
+            &index,
+            dir.path(),
+            "frontend framework",
+            &InjectionPhase::Always,
+            &policy,
+        );
+
+        assert_eq!(selection.selected.len(), 1);
+        assert_eq!(selection.selected[0].skill.name, "react");
+        assert_eq!(selection.dropped.len(), 1);
+        assert_eq!(selection.dropped[0].name, "vue");
+        assert_eq!(selection.dropped[0].reason, SkillDropReason::Conflict);
+    }
+
+    #[test]
+    fn test_select_pulls_in_required_skill() {
+        let dir = TempDir::new().unwrap();
+
+        let mut nextjs = make_doc(
+            "nextjs",
+            "Next.js routing",
+            SkillSource::ProjectLocal,
+            201,
+            None,
+            vec![],
+        );
+        nextjs.requires = vec!["TypeScript".to_string()];
+        let typescript = make_doc(
+            "typescript",
+            "Strict typing rules",
+            SkillSource::Builtin,
+            10,
+            None,
+            vec![],
+        );
+        let index = SkillIndex::new(vec![nextjs, typescript]);
+        let policy = SelectionPolicy {
+            min_score: 0.0,
+            ..Default::default()
+        };
+
+        let selection = select_skills_within_budget(
+            &index,
+            dir.path(),
+            "nextjs routing",
+            &InjectionPhase::Always,
+            &policy,
+        );
+
+        assert_eq!(selection.selected.len(), 2);
+        assert_eq!(selection.selected[0].skill.name, "nextjs");
+        assert_eq!(selection.selected[1].skill.name, "typescript");
+        assert!(matches!(
+            &selection.selected[1].match_reason,
+            MatchReason::Required { by } if by == "nextjs"
+        ));
+    }
+
+    #[test]
+    fn test_select_explains_auto_detected_and_lexical_matches() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("package.json"),
+            r#"{"dependencies": {"react": "18"}}"#,
+        )
+        .unwrap();
+
+        let index = SkillIndex::new(vec![
+            make_doc(
+                "react",
+                "React best practices",
+                SkillSource::External {
+                    source_name: "vercel".to_string(),
+                },
+                100,
+                Some(SkillDetection {
+                    files: vec!["package.json".to_string()],
+                    patterns: vec!["react".to_string()],
+                }),
+                vec![],
+            ),
+            make_doc(
+                "testing",
+                "Write focused unit tests",
+                SkillSource::ProjectLocal,
+                201,
+                None,
+                vec!["qa"],
+            ),
+        ]);
+        let policy = SelectionPolicy {
+            min_score: 0.0,
+            ..Default::default()
+        };
+
+        let results = select_skills_for_session(
+            &index,
+            dir.path(),
+            "add unit tests to the qa flow",
+            &InjectionPhase::Always,
+            &policy,
+        );
+
+        let react = results.iter().find(|m| m.skill.name == "react").unwrap();
+        let explanation = react.explanation.as_ref().unwrap();
+        assert_eq!(explanation.matched_files, vec!["package.json"]);
+        assert_eq!(explanation.matched_patterns, vec!["react"]);
+        assert!(explanation.matched_terms.is_empty());
+
+        let testing = results.iter().find(|m| m.skill.name == "testing").unwrap();
+        let explanation = testing.explanation.as_ref().unwrap();
+        assert_eq!(explanation.matched_terms, vec!["qa", "tests", "unit"]);
+        assert!(explanation.matched_files.is_empty());
+        assert!(testing.score > 0.0);
+    }
 }
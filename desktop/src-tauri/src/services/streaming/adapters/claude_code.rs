@@ -880,4 +880,23 @@ mod tests {
             "I checked the repo.\n```tool_call\n{\"tool\":\"read_file\"}\n```"
         ));
     }
+
+    #[test]
+    fn test_raw_chunks_split_mid_token_reassemble() {
+        let mut adapter = ClaudeCodeAdapter::new();
+        let raw = "{\"type\":\"stream_event\",\"event\":{\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"héllo wörld\"}}}\n".as_bytes();
+        // Split inside the "text_delta" token and inside the two-byte 'é'.
+        let in_key = raw.windows(10).position(|w| w == b"text_delta").unwrap() + 3;
+        let in_char = raw.iter().position(|&b| b == 0xC3).unwrap() + 1;
+
+        let events = adapter
+            .adapt_raw_chunks(&[&raw[..in_key], &raw[in_key..in_char], &raw[in_char..]])
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![UnifiedStreamEvent::TextDelta {
+                content: "héllo wörld".to_string()
+            }]
+        );
+    }
 }
@@ -0,0 +1,402 @@
+//! Streaming Backpressure
+//!
+//! Slow-consumer handling for the streaming event channel. The producer either
+//! waits for the consumer (`Block`), drops the oldest intermediate deltas
+//! (`DropOldest`) or merges consecutive deltas (`Coalesce`) while the
+//! consumer lags. Non-delta events (tool, usage, error, complete, ...) are
+//! never dropped: they flush everything buffered ahead of them and are then
+//! delivered with backpressure, preserving event order.
+//!
+//! Events travel as [`SequencedStreamEvent`] envelopes. A dropped delta leaves
+//! a gap in the sequence numbers the consumer sees; a coalesced delta carries
+//! the number of the last delta merged into it, so the merged numbers show up
+//! as a gap too (counted in [`BackpressureStats::coalesced`]).
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+use super::service::StreamError;
+use super::unified::{SequencedStreamEvent, UnifiedStreamEvent};
+
+/// Default number of deltas buffered on the producer side before the
+/// slow-consumer policy kicks in.
+pub const DEFAULT_MAX_BUFFERED_EVENTS: usize = 64;
+
+/// How the producer reacts when the consumer cannot keep up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackpressurePolicy {
+    /// Wait for channel capacity before sending every event.
+    #[default]
+    Block,
+    /// Drop the oldest buffered deltas once the buffer is full.
+    DropOldest,
+    /// Merge consecutive deltas into one event while the consumer lags.
+    Coalesce,
+}
+
+/// Counters describing how the policy affected delivery.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BackpressureStats {
+    /// Deltas discarded by `DropOldest`.
+    pub dropped: usize,
+    /// Deltas merged into a previous event by `Coalesce`.
+    pub coalesced: usize,
+}
+
+/// Whether an event is an intermediate delta that the policy may drop or
+/// merge. Everything else must be delivered.
+pub fn is_droppable(event: &UnifiedStreamEvent) -> bool {
+    matches!(
+        event,
+        UnifiedStreamEvent::TextDelta { .. } | UnifiedStreamEvent::ThinkingDelta { .. }
+    )
+}
+
+/// Append `next` to `prev` when both are deltas of the same stream; the
+/// merged envelope takes `next`'s sequence number.
+///
+/// Returns `next` back when the events cannot be merged.
+fn try_coalesce(
+    prev: &mut SequencedStreamEvent,
+    next: SequencedStreamEvent,
+) -> Option<SequencedStreamEvent> {
+    let SequencedStreamEvent { seq, event } = next;
+    let unmerged = match (&mut prev.event, event) {
+        (
+            UnifiedStreamEvent::TextDelta { content },
+            UnifiedStreamEvent::TextDelta { content: more },
+        ) => {
+            content.push_str(&more);
+            None
+        }
+        (
+            UnifiedStreamEvent::ThinkingDelta {
+                content,
+                thinking_id,
+            },
+            UnifiedStreamEvent::ThinkingDelta {
+                content: more,
+                thinking_id: next_id,
+            },
+        ) if *thinking_id == next_id => {
+            content.push_str(&more);
+            None
+        }
+        (_, event) => Some(event),
+    };
+    match unmerged {
+        None => {
+            prev.seq = seq;
+            None
+        }
+        Some(event) => Some(SequencedStreamEvent { seq, event }),
+    }
+}
+
+/// Channel sender that applies a [`BackpressurePolicy`] to stream events.
+#[derive(Debug)]
+pub struct BackpressureSender {
+    tx: mpsc::Sender<SequencedStreamEvent>,
+    policy: BackpressurePolicy,
+    max_buffered: usize,
+    pending: VecDeque<SequencedStreamEvent>,
+    stats: BackpressureStats,
+}
+
+impl BackpressureSender {
+    /// Wrap a channel sender with the given policy.
+    pub fn new(tx: mpsc::Sender<SequencedStreamEvent>, policy: BackpressurePolicy) -> Self {
+        Self {
+            tx,
+            policy,
+            max_buffered: DEFAULT_MAX_BUFFERED_EVENTS,
+            pending: VecDeque::new(),
+            stats: BackpressureStats::default(),
+        }
+    }
+
+    /// Replace the slow-consumer policy.
+    pub fn with_policy(mut self, policy: BackpressurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Set the producer-side buffer size (minimum 1).
+    pub fn with_max_buffered(mut self, max_buffered: usize) -> Self {
+        self.max_buffered = max_buffered.max(1);
+        self
+    }
+
+    pub fn policy(&self) -> BackpressurePolicy {
+        self.policy
+    }
+
+    pub fn stats(&self) -> BackpressureStats {
+        self.stats
+    }
+
+    /// Number of events buffered on the producer side.
+    pub fn buffered(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Send an event according to the policy.
+    pub async fn send(&mut self, event: SequencedStreamEvent) -> Result<(), StreamError> {
+        if self.policy == BackpressurePolicy::Block || !is_droppable(&event.event) {
+            self.flush().await?;
+            return self
+                .tx
+                .send(event)
+                .await
+                .map_err(|e| StreamError::ChannelError(e.to_string()));
+        }
+
+        self.buffer(event);
+        self.drain_ready()?;
+
+        if self.pending.len() > self.max_buffered {
+            match self.policy {
+                BackpressurePolicy::DropOldest => {
+                    while self.pending.len() > self.max_buffered {
+                        self.pending.pop_front();
+                        self.stats.dropped += 1;
+                    }
+                }
+                // Merging is lossless; once it no longer keeps the buffer
+                // bounded, fall back to waiting for the consumer.
+                BackpressurePolicy::Coalesce | BackpressurePolicy::Block => {
+                    self.flush().await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Deliver every buffered event, waiting for channel capacity.
+    pub async fn flush(&mut self) -> Result<(), StreamError> {
+        while let Some(event) = self.pending.pop_front() {
+            self.tx
+                .send(event)
+                .await
+                .map_err(|e| StreamError::ChannelError(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    fn buffer(&mut self, event: SequencedStreamEvent) {
+        let event = match (self.policy, self.pending.back_mut()) {
+            (BackpressurePolicy::Coalesce, Some(prev)) => match try_coalesce(prev, event) {
+                None => {
+                    self.stats.coalesced += 1;
+                    return;
+                }
+                Some(event) => event,
+            },
+            _ => event,
+        };
+        self.pending.push_back(event);
+    }
+
+    /// Move buffered events into the channel while it has free capacity.
+    fn drain_ready(&mut self) -> Result<(), StreamError> {
+        while let Some(event) = self.pending.pop_front() {
+            match self.tx.try_send(event) {
+                Ok(()) => {}
+                Err(TrySendError::Full(event)) => {
+                    self.pending.push_front(event);
+                    break;
+                }
+                Err(TrySendError::Closed(_)) => {
+                    return Err(StreamError::ChannelError("channel closed".to_string()));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::streaming::unified::StreamSequencer;
+
+    const DELTAS: usize = 50;
+
+    fn usage() -> UnifiedStreamEvent {
+        UnifiedStreamEvent::Usage {
+            input_tokens: 10,
+            output_tokens: 20,
+            thinking_tokens: None,
+            cache_read_tokens: None,
+            cache_creation_tokens: None,
+        }
+    }
+
+    fn complete() -> UnifiedStreamEvent {
+        UnifiedStreamEvent::Complete {
+            stop_reason: Some("end_turn".to_string()),
+        }
+    }
+
+    /// Produce `DELTAS` text deltas followed by usage and complete events into
+    /// a capacity-2 channel whose consumer only runs when the producer yields.
+    async fn run_slow_consumer(
+        policy: BackpressurePolicy,
+    ) -> (Vec<SequencedStreamEvent>, BackpressureStats) {
+        let (tx, mut rx) = mpsc::channel(2);
+        let consumer = tokio::spawn(async move {
+            let mut received = Vec::new();
+            while let Some(event) = rx.recv().await {
+                received.push(event);
+                tokio::task::yield_now().await;
+            }
+            received
+        });
+
+        let mut sequencer = StreamSequencer::new();
+        let mut sender = BackpressureSender::new(tx, policy).with_max_buffered(4);
+        for i in 0..DELTAS {
+            sender
+                .send(sequencer.sequence(UnifiedStreamEvent::TextDelta {
+                    content: format!("{i},"),
+                }))
+                .await
+                .unwrap();
+        }
+        sender.send(sequencer.sequence(usage())).await.unwrap();
+        sender.send(sequencer.sequence(complete())).await.unwrap();
+        let stats = sender.stats();
+        assert_eq!(sender.buffered(), 0);
+        drop(sender);
+
+        (consumer.await.unwrap(), stats)
+    }
+
+    fn text_of(events: &[SequencedStreamEvent]) -> String {
+        events
+            .iter()
+            .filter_map(|e| match &e.event {
+                UnifiedStreamEvent::TextDelta { content } => Some(content.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn expected_text() -> String {
+        (0..DELTAS).map(|i| format!("{i},")).collect()
+    }
+
+    fn assert_terminal_tail(events: &[SequencedStreamEvent]) {
+        let n = events.len();
+        assert!(n >= 2);
+        assert_eq!(events[n - 2].event, usage());
+        assert_eq!(events[n - 1].event, complete());
+        assert_eq!(events[n - 1].seq, DELTAS as u64 + 2);
+    }
+
+    /// Sequence numbers missing between consecutive delivered events.
+    fn sequence_gaps(events: &[SequencedStreamEvent]) -> u64 {
+        events.windows(2).map(|w| w[1].seq - w[0].seq - 1).sum()
+    }
+
+    #[tokio::test]
+    async fn test_block_delivers_every_event_in_order() {
+        let (events, stats) = run_slow_consumer(BackpressurePolicy::Block).await;
+        assert_eq!(events.len(), DELTAS + 2);
+        assert_eq!(text_of(&events), expected_text());
+        assert_eq!(stats, BackpressureStats::default());
+        assert_eq!(sequence_gaps(&events), 0);
+        assert_terminal_tail(&events);
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_drops_deltas_but_keeps_terminal_events() {
+        let (events, stats) = run_slow_consumer(BackpressurePolicy::DropOldest).await;
+        assert!(stats.dropped > 0);
+        assert_eq!(events.len(), DELTAS + 2 - stats.dropped);
+        // The newest deltas survive.
+        assert!(text_of(&events).ends_with(&format!("{},", DELTAS - 1)));
+        // Consumers can tell exactly how many events were dropped.
+        assert_eq!(
+            sequence_gaps(&events) + events[0].seq - 1,
+            stats.dropped as u64
+        );
+        assert_terminal_tail(&events);
+    }
+
+    #[tokio::test]
+    async fn test_coalesce_merges_deltas_without_losing_text() {
+        let (events, stats) = run_slow_consumer(BackpressurePolicy::Coalesce).await;
+        assert!(stats.coalesced > 0);
+        assert_eq!(stats.dropped, 0);
+        assert!(events.len() < DELTAS + 2);
+        assert_eq!(text_of(&events), expected_text());
+        assert_terminal_tail(&events);
+    }
+
+    #[tokio::test]
+    async fn test_terminal_event_flushes_buffered_deltas_first() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut sequencer = StreamSequencer::new();
+        let mut sender =
+            BackpressureSender::new(tx, BackpressurePolicy::Coalesce).with_max_buffered(8);
+        for text in ["a", "b", "c"] {
+            sender
+                .send(sequencer.sequence(UnifiedStreamEvent::TextDelta {
+                    content: text.to_string(),
+                }))
+                .await
+                .unwrap();
+        }
+        assert_eq!(sender.buffered(), 1);
+
+        let send = tokio::spawn(async move {
+            sender
+                .send(sequencer.sequence(UnifiedStreamEvent::Error {
+                    message: "boom".to_string(),
+                    code: None,
+                }))
+                .await
+        });
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        send.await.unwrap().unwrap();
+
+        assert_eq!(text_of(&events), "abc");
+        assert!(matches!(
+            events.last(),
+            Some(SequencedStreamEvent {
+                seq: 4,
+                event: UnifiedStreamEvent::Error { .. }
+            })
+        ));
+    }
+
+    #[test]
+    fn test_thinking_deltas_coalesce_only_within_same_block() {
+        let mut sequencer = StreamSequencer::new();
+        let mut prev = sequencer.sequence(UnifiedStreamEvent::ThinkingDelta {
+            content: "a".to_string(),
+            thinking_id: Some("t1".to_string()),
+        });
+        let same = sequencer.sequence(UnifiedStreamEvent::ThinkingDelta {
+            content: "b".to_string(),
+            thinking_id: Some("t1".to_string()),
+        });
+        let other = sequencer.sequence(UnifiedStreamEvent::ThinkingDelta {
+            content: "c".to_string(),
+            thinking_id: Some("t2".to_string()),
+        });
+        assert!(try_coalesce(&mut prev, same).is_none());
+        assert_eq!(prev.seq, 2);
+        assert!(try_coalesce(&mut prev, other).is_some());
+        assert!(try_coalesce(&mut prev, sequencer.sequence(complete())).is_some());
+        assert!(!is_droppable(&usage()));
+        assert!(!is_droppable(&complete()));
+    }
+}
@@ -7,12 +7,17 @@
 
 pub mod adapter;
 pub mod adapters;
+pub mod backpressure;
 pub mod factory;
 pub mod service;
 pub mod unified;
 
 // Re-export main types (from core, for backward compatibility)
+pub use backpressure::{BackpressurePolicy, BackpressureSender, BackpressureStats};
 pub use factory::AdapterFactory;
 pub use plan_cascade_core::streaming::StreamAdapter;
-pub use plan_cascade_core::streaming::{AdapterError, UnifiedStreamEvent};
+pub use plan_cascade_core::streaming::{
+    AdapterError, SequenceCheck, SequenceGapDetector, SequencedStreamEvent, StreamSequencer,
+    UnifiedStreamEvent,
+};
 pub use service::UnifiedStreamingService;
@@ -5,8 +5,9 @@
 use tokio::sync::mpsc;
 
 use super::adapter::StreamAdapter;
+use super::backpressure::{BackpressurePolicy, BackpressureSender, BackpressureStats};
 use super::factory::AdapterFactory;
-use super::unified::{AdapterError, UnifiedStreamEvent};
+use super::unified::{AdapterError, SequencedStreamEvent, StreamSequencer, UnifiedStreamEvent};
 
 /// Errors that can occur during stream processing
 #[derive(Debug, Clone)]
@@ -43,7 +44,9 @@ pub struct UnifiedStreamingService {
     /// The adapter for this provider/model
     adapter: Box<dyn StreamAdapter>,
     /// Optional event sender for async event emission
-    event_tx: Option<mpsc::Sender<UnifiedStreamEvent>>,
+    event_tx: Option<BackpressureSender>,
+    /// Sequence numbers for every event produced by this service
+    sequencer: StreamSequencer,
 }
 
 impl UnifiedStreamingService {
@@ -52,11 +55,15 @@ impl UnifiedStreamingService {
     /// # Arguments
     /// * `provider` - Provider name (claude-code, openai, etc.)
     /// * `model` - Model identifier
-    /// * `event_tx` - Optional channel sender for event emission
+    /// * `event_tx` - Optional channel sender; events are emitted with their
+    ///   sequence numbers
+    ///
+    /// Events are sent with [`BackpressurePolicy::Block`]; use
+    /// [`Self::with_backpressure`] to tolerate slow consumers.
     pub fn new(
         provider: impl Into<String>,
         model: impl Into<String>,
-        event_tx: Option<mpsc::Sender<UnifiedStreamEvent>>,
+        event_tx: Option<mpsc::Sender<SequencedStreamEvent>>,
     ) -> Self {
         let provider = provider.into();
         let model = model.into();
@@ -66,10 +73,22 @@ impl UnifiedStreamingService {
             provider,
             model,
             adapter,
-            event_tx,
+            event_tx: event_tx.map(|tx| BackpressureSender::new(tx, BackpressurePolicy::Block)),
+            sequencer: StreamSequencer::new(),
         }
     }
 
+    /// Apply a slow-consumer policy to the event channel.
+    ///
+    /// `max_buffered` bounds how many deltas are held back while the consumer
+    /// lags before the policy drops, merges or waits.
+    pub fn with_backpressure(mut self, policy: BackpressurePolicy, max_buffered: usize) -> Self {
+        self.event_tx = self
+            .event_tx
+            .map(|sender| sender.with_policy(policy).with_max_buffered(max_buffered));
+        self
+    }
+
     /// Process a raw stream line and return unified events.
     ///
     /// If an event channel is configured, events are also sent through it.
@@ -77,26 +96,71 @@ impl UnifiedStreamingService {
         &mut self,
         line: &str,
     ) -> Result<Vec<UnifiedStreamEvent>, StreamError> {
-        let events = self.adapter.adapt(line)?;
-
-        // Send events through channel if configured
-        if let Some(tx) = &self.event_tx {
-            for event in &events {
-                tx.send(event.clone())
-                    .await
-                    .map_err(|e| StreamError::ChannelError(e.to_string()))?;
-            }
-        }
+        Ok(self
+            .process_line_sequenced(line)
+            .await?
+            .into_iter()
+            .map(|sequenced| sequenced.event)
+            .collect())
+    }
 
+    /// Process a raw stream line and tag each event with its sequence number.
+    ///
+    /// Numbering is shared with [`Self::process_line`] and keeps increasing
+    /// across [`Self::reset`], so consumers can detect dropped events.
+    pub async fn process_line_sequenced(
+        &mut self,
+        line: &str,
+    ) -> Result<Vec<SequencedStreamEvent>, StreamError> {
+        let events = self.process_line_sync(line)?;
+        self.emit(&events).await?;
         Ok(events)
     }
 
     /// Process a line synchronously (without sending through channel).
+    ///
+    /// Events are numbered by the same sequencer as the channel path.
     pub fn process_line_sync(
         &mut self,
         line: &str,
-    ) -> Result<Vec<UnifiedStreamEvent>, StreamError> {
-        Ok(self.adapter.adapt(line)?)
+    ) -> Result<Vec<SequencedStreamEvent>, StreamError> {
+        let events = self.adapter.adapt(line)?;
+        Ok(self.sequence(events))
+    }
+
+    fn sequence(&mut self, events: Vec<UnifiedStreamEvent>) -> Vec<SequencedStreamEvent> {
+        events
+            .into_iter()
+            .map(|event| self.sequencer.sequence(event))
+            .collect()
+    }
+
+    /// Send sequenced events through the channel, if one is configured.
+    async fn emit(&mut self, events: &[SequencedStreamEvent]) -> Result<(), StreamError> {
+        if let Some(tx) = &mut self.event_tx {
+            for sequenced in events {
+                tx.send(sequenced.clone()).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sequence number of the last event produced (0 before any event).
+    pub fn last_seq(&self) -> u64 {
+        self.sequencer.last_seq()
+    }
+
+    /// Deliver any deltas still held back by the backpressure policy.
+    pub async fn flush(&mut self) -> Result<(), StreamError> {
+        match &mut self.event_tx {
+            Some(tx) => tx.flush().await,
+            None => Ok(()),
+        }
+    }
+
+    /// Drop/coalesce counters for the event channel, if one is configured.
+    pub fn backpressure_stats(&self) -> Option<BackpressureStats> {
+        self.event_tx.as_ref().map(BackpressureSender::stats)
     }
 
     /// Check if the current provider/model supports thinking blocks.
@@ -211,8 +275,9 @@ mod tests {
             .process_line_sync(r#"{"type": "thinking", "thinking_id": "t1"}"#)
             .unwrap();
         assert_eq!(events.len(), 1);
+        assert_eq!(events[0].seq, 1);
         assert!(matches!(
-            events[0],
+            events[0].event,
             UnifiedStreamEvent::ThinkingStart { .. }
         ));
     }
@@ -226,9 +291,10 @@ mod tests {
 
         assert_eq!(events.len(), 1);
 
-        // Check event was also sent through channel
+        // Check event was also sent through channel, with its sequence number
         let received = rx.recv().await.unwrap();
-        match received {
+        assert_eq!(received.seq, 1);
+        match received.event {
             UnifiedStreamEvent::TextDelta { content } => {
                 assert_eq!(content, "Hello");
             }
@@ -236,6 +302,80 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_process_line_with_drop_oldest_keeps_terminal_event() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let mut service = UnifiedStreamingService::new("claude-code", "claude-3-opus", Some(tx))
+            .with_backpressure(BackpressurePolicy::DropOldest, 1);
+
+        for _ in 0..5 {
+            service
+                .process_line(r#"{"type": "content_block_delta", "delta": {"type": "text_delta", "text": "x"}}"#)
+                .await
+                .unwrap();
+        }
+        let consumer = tokio::spawn(async move {
+            let mut received = Vec::new();
+            while let Some(event) = rx.recv().await {
+                received.push(event);
+            }
+            received
+        });
+        service
+            .process_line(r#"{"type": "result", "stop_reason": "end_turn", "usage": {"input_tokens": 1, "output_tokens": 5}}"#)
+            .await
+            .unwrap();
+        assert_eq!(service.backpressure_stats().unwrap().dropped, 3);
+        drop(service);
+
+        let received = consumer.await.unwrap();
+        assert_eq!(received.len(), 4);
+        assert!(matches!(
+            received[2].event,
+            UnifiedStreamEvent::Usage { .. }
+        ));
+        assert!(matches!(
+            received.last().map(|e| &e.event),
+            Some(UnifiedStreamEvent::Complete { .. })
+        ));
+        // The dropped deltas show up as a gap before the surviving delta.
+        let mut detector = plan_cascade_core::streaming::SequenceGapDetector::new();
+        let checks: Vec<_> = received.iter().map(|e| detector.observe(e.seq)).collect();
+        assert_eq!(received[1].seq, 5);
+        assert_eq!(checks[1].missing(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_process_line_sequenced_numbers_increase_across_lines() {
+        let (tx, mut rx) = mpsc::channel(16);
+        let mut service = UnifiedStreamingService::new("claude-code", "claude-3-opus", Some(tx));
+
+        let mut seqs = Vec::new();
+        for line in [
+            r#"{"type": "thinking", "thinking_id": "t1"}"#,
+            r#"{"type": "content_block_delta", "delta": {"type": "text_delta", "text": "Hi"}}"#,
+            r#"{"type": "result", "stop_reason": "end_turn", "usage": {"input_tokens": 1, "output_tokens": 2}}"#,
+        ] {
+            let events = service.process_line_sequenced(line).await.unwrap();
+            seqs.extend(events.iter().map(|e| e.seq));
+        }
+        service.reset();
+        let events = service
+            .process_line(r#"{"type": "thinking", "thinking_id": "t2"}"#)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+
+        assert_eq!(seqs, (1..=seqs.len() as u64).collect::<Vec<_>>());
+        assert_eq!(service.last_seq(), seqs.len() as u64 + 1);
+        drop(service);
+        let mut forwarded = Vec::new();
+        while let Some(event) = rx.recv().await {
+            forwarded.push(event.seq);
+        }
+        assert_eq!(forwarded, (1..=seqs.len() as u64 + 1).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_reset() {
         let mut service = UnifiedStreamingService::new("deepseek", "deepseek-r1", None);
@@ -253,6 +393,6 @@ mod tests {
             .unwrap();
         assert!(events
             .iter()
-            .any(|e| matches!(e, UnifiedStreamEvent::TextDelta { .. })));
+            .any(|e| matches!(e.event, UnifiedStreamEvent::TextDelta { .. })));
     }
 }
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tauri::{AppHandle, Emitter, Runtime};
 
+use super::structured::SemanticChange;
+
 /// Event channel names for file system sync events
 pub mod channels {
     /// Project list changes (new/deleted projects)
@@ -17,6 +19,8 @@ pub mod channels {
     pub const PRD_CHANGE: &str = "sync:prd_change";
     /// Progress file changes
     pub const PROGRESS_CHANGE: &str = "sync:progress_change";
+    /// Parsed, semantic PRD/progress changes
+    pub const STRUCTURED_CHANGE: &str = "sync:structured_change";
     /// Watch error events
     pub const WATCH_ERROR: &str = "sync:watch_error";
     /// Watch status events (started, stopped)
@@ -180,6 +184,52 @@ impl ProgressChangeEvent {
     }
 }
 
+/// File a structured change was parsed from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StructuredSource {
+    /// prd.json
+    Prd,
+    /// progress.txt
+    Progress,
+}
+
+/// Structured change event payload
+///
+/// Emitted alongside the raw PRD/progress change event with the semantic
+/// differences to the previously parsed version of the file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredChangeEvent {
+    /// Which file was parsed
+    pub source: StructuredSource,
+    /// Full path to the file
+    pub path: String,
+    /// Project ID (derived from the parent directory)
+    pub project_id: Option<String>,
+    /// Semantic changes, in document order
+    pub changes: Vec<SemanticChange>,
+    /// Timestamp of the event
+    pub timestamp: String,
+}
+
+impl StructuredChangeEvent {
+    /// Create a new structured change event
+    pub fn new(source: StructuredSource, path: PathBuf, changes: Vec<SemanticChange>) -> Self {
+        let project_id = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().to_string());
+
+        Self {
+            source,
+            path: path.to_string_lossy().to_string(),
+            project_id,
+            changes,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
 /// Watch error event payload
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WatchErrorEvent {
@@ -203,6 +253,8 @@ pub enum WatchErrorKind {
     PathNotFound,
     /// Too many files to watch
     MaxFilesReached,
+    /// A watched PRD/progress file could not be parsed
+    ParseError,
     /// Generic watch error
     Generic,
 }
@@ -318,6 +370,13 @@ impl<R: Runtime> SyncEventEmitter<R> {
         }
     }
 
+    /// Emit a structured PRD/progress change event
+    pub fn emit_structured_change(&self, event: StructuredChangeEvent) {
+        if let Err(e) = self.app_handle.emit(channels::STRUCTURED_CHANGE, &event) {
+            eprintln!("[WARN] Failed to emit structured change event: {}", e);
+        }
+    }
+
     /// Emit a watch error event
     pub fn emit_watch_error(&self, event: WatchErrorEvent) {
         if let Err(e) = self.app_handle.emit(channels::WATCH_ERROR, &event) {
@@ -351,6 +410,7 @@ mod tests {
         assert_eq!(channels::FILE_CHANGE, "sync:file_change");
         assert_eq!(channels::PRD_CHANGE, "sync:prd_change");
         assert_eq!(channels::PROGRESS_CHANGE, "sync:progress_change");
+        assert_eq!(channels::STRUCTURED_CHANGE, "sync:structured_change");
         assert_eq!(channels::WATCH_ERROR, "sync:watch_error");
         assert_eq!(channels::WATCH_STATUS, "sync:watch_status");
     }
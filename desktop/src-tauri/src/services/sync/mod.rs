@@ -6,12 +6,15 @@
 //! Features:
 //! - Watch ~/.claude/projects/ for project changes
 //! - Watch current project directories for file modifications
-//! - Watch prd.json and progress.txt for story updates
+//! - Watch prd.json and progress.txt for story updates, emitting structured
+//!   (story status / new story / progress marker) change events
 //! - Debounce rapid changes (100ms threshold)
 //! - Broadcast events via Tauri IPC to all windows
 
 mod events;
+mod structured;
 mod watcher;
 
 pub use events::*;
+pub use structured::*;
 pub use watcher::*;
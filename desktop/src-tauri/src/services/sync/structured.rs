@@ -0,0 +1,476 @@
+//! Structured PRD / Progress Change Detection
+//!
+//! Parses `prd.json` and `progress.txt` whenever they change and diffs them
+//! against the last successfully parsed version, so consumers receive
+//! semantic changes (story status transitions, new stories, progress markers)
+//! instead of a bare "file changed" notification.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use super::events::{StructuredChangeEvent, StructuredSource, WatchErrorEvent, WatchErrorKind};
+
+/// File name of the PRD document
+pub const PRD_FILE_NAME: &str = "prd.json";
+/// File name of the progress log
+pub const PROGRESS_FILE_NAME: &str = "progress.txt";
+
+/// A single semantic change detected between two versions of a file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SemanticChange {
+    /// A story appeared in the PRD
+    StoryAdded {
+        story_id: String,
+        title: String,
+        status: String,
+    },
+    /// A story was removed from the PRD
+    StoryRemoved { story_id: String },
+    /// A story's status changed
+    StoryStatusChanged {
+        story_id: String,
+        from: String,
+        to: String,
+    },
+    /// A story's title changed
+    StoryTitleChanged {
+        story_id: String,
+        from: String,
+        to: String,
+    },
+    /// A new marker line (e.g. `[STORY_COMPLETE] story-001`) was written to progress.txt
+    ProgressMarkerAdded {
+        marker: String,
+        subject: Option<String>,
+    },
+}
+
+/// The parts of a story that are tracked for changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorySnapshot {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+}
+
+/// Parsed view of a `prd.json` file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrdSnapshot {
+    /// Stories in document order
+    pub stories: Vec<StorySnapshot>,
+}
+
+impl PrdSnapshot {
+    /// Parse PRD content.
+    ///
+    /// Only `stories[].id`, `title` and `status` are read so that PRDs written
+    /// by older tools (or with extra fields) still parse.
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let value: serde_json::Value =
+            serde_json::from_str(content).map_err(|e| format!("Invalid PRD JSON: {}", e))?;
+        let stories = value
+            .get("stories")
+            .and_then(|s| s.as_array())
+            .ok_or_else(|| "PRD is missing a 'stories' array".to_string())?;
+
+        let mut snapshots = Vec::with_capacity(stories.len());
+        for (index, story) in stories.iter().enumerate() {
+            let id = story
+                .get("id")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| format!("Story at index {} has no string 'id'", index))?;
+            snapshots.push(StorySnapshot {
+                id: id.to_string(),
+                title: story
+                    .get("title")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                status: story
+                    .get("status")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("pending")
+                    .to_string(),
+            });
+        }
+
+        Ok(Self { stories: snapshots })
+    }
+
+    /// Diff against a previous snapshot (`None` means the file is new)
+    pub fn diff(&self, previous: Option<&PrdSnapshot>) -> Vec<SemanticChange> {
+        let previous_by_id: HashMap<&str, &StorySnapshot> = previous
+            .map(|p| p.stories.iter().map(|s| (s.id.as_str(), s)).collect())
+            .unwrap_or_default();
+        let mut changes = Vec::new();
+
+        for story in &self.stories {
+            match previous_by_id.get(story.id.as_str()) {
+                None => changes.push(SemanticChange::StoryAdded {
+                    story_id: story.id.clone(),
+                    title: story.title.clone(),
+                    status: story.status.clone(),
+                }),
+                Some(old) => {
+                    if old.status != story.status {
+                        changes.push(SemanticChange::StoryStatusChanged {
+                            story_id: story.id.clone(),
+                            from: old.status.clone(),
+                            to: story.status.clone(),
+                        });
+                    }
+                    if old.title != story.title {
+                        changes.push(SemanticChange::StoryTitleChanged {
+                            story_id: story.id.clone(),
+                            from: old.title.clone(),
+                            to: story.title.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(previous) = previous {
+            let current_ids: HashSet<&str> = self.stories.iter().map(|s| s.id.as_str()).collect();
+            for old in &previous.stories {
+                if !current_ids.contains(old.id.as_str()) {
+                    changes.push(SemanticChange::StoryRemoved {
+                        story_id: old.id.clone(),
+                    });
+                }
+            }
+        }
+
+        changes
+    }
+}
+
+/// A marker line in `progress.txt`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgressMarker {
+    /// Marker tag without brackets (e.g. `STORY_COMPLETE`)
+    pub marker: String,
+    /// First token after the marker (usually a story or feature id)
+    pub subject: Option<String>,
+}
+
+/// Parsed view of a `progress.txt` file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgressSnapshot {
+    /// Marker lines in file order
+    pub markers: Vec<ProgressMarker>,
+}
+
+impl ProgressSnapshot {
+    /// Parse progress content; lines without a `[MARKER]` tag are skipped
+    pub fn parse(content: &str) -> Self {
+        Self {
+            markers: content.lines().filter_map(parse_marker_line).collect(),
+        }
+    }
+
+    /// Diff against a previous snapshot.
+    ///
+    /// progress.txt is append-only in practice, so when the old markers are a
+    /// prefix only the tail is reported; otherwise every marker the previous
+    /// version did not contain is reported.
+    pub fn diff(&self, previous: Option<&ProgressSnapshot>) -> Vec<SemanticChange> {
+        let added: Vec<&ProgressMarker> = match previous {
+            Some(prev) if self.markers.starts_with(&prev.markers) => {
+                self.markers[prev.markers.len()..].iter().collect()
+            }
+            Some(prev) => self
+                .markers
+                .iter()
+                .filter(|m| !prev.markers.contains(m))
+                .collect(),
+            None => self.markers.iter().collect(),
+        };
+
+        added
+            .into_iter()
+            .map(|m| SemanticChange::ProgressMarkerAdded {
+                marker: m.marker.clone(),
+                subject: m.subject.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Extract the last `[UPPER_CASE]` tag of a line and the token following it.
+///
+/// The last tag is used so that lines prefixed with a bracketed timestamp
+/// (`[2026-01-01 10:00] [STORY_COMPLETE] story-001`) resolve to the marker.
+fn parse_marker_line(line: &str) -> Option<ProgressMarker> {
+    let mut found: Option<(String, usize)> = None;
+    let mut rest = line;
+    let mut offset = 0;
+
+    while let Some(open) = rest.find('[') {
+        let Some(close) = rest[open..].find(']') else {
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        let end = offset + open + close + 1;
+        if tag.starts_with(|c: char| c.is_ascii_uppercase())
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        {
+            found = Some((tag.to_string(), end));
+        }
+        offset = end;
+        rest = &line[end..];
+    }
+
+    let (marker, end) = found?;
+    let subject = line[end..]
+        .split_whitespace()
+        .next()
+        .map(|s| s.trim_end_matches([':', ',', '.']).to_string())
+        .filter(|s| !s.is_empty());
+
+    Some(ProgressMarker { marker, subject })
+}
+
+/// Last successfully parsed version of a tracked file
+#[derive(Debug, Clone)]
+enum TrackedSnapshot {
+    Prd(PrdSnapshot),
+    Progress(ProgressSnapshot),
+}
+
+/// Tracks parsed PRD/progress files and turns changes into structured events.
+///
+/// Cheap to clone; clones share the same baseline state so it can be moved
+/// into watcher callbacks.
+#[derive(Debug, Clone, Default)]
+pub struct StructuredFileTracker {
+    snapshots: Arc<Mutex<HashMap<PathBuf, TrackedSnapshot>>>,
+}
+
+impl StructuredFileTracker {
+    /// Create an empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Which structured source a path is, if any
+    pub fn source_for(path: &Path) -> Option<StructuredSource> {
+        match path.file_name()?.to_str()? {
+            PRD_FILE_NAME => Some(StructuredSource::Prd),
+            PROGRESS_FILE_NAME => Some(StructuredSource::Progress),
+            _ => None,
+        }
+    }
+
+    /// Record the current content of a file as the baseline without emitting
+    /// changes. Missing or unparseable files are left untracked.
+    pub fn prime(&self, path: &Path) {
+        let Some(source) = Self::source_for(path) else {
+            return;
+        };
+        let Ok(content) = std::fs::read_to_string(path) else {
+            return;
+        };
+        if let Ok(snapshot) = parse_snapshot(source, &content) {
+            self.lock().insert(path.to_path_buf(), snapshot);
+        }
+    }
+
+    /// Re-read a changed file and diff it against its baseline.
+    ///
+    /// Returns `Ok(None)` for untracked file names, deletions and saves that
+    /// don't change anything semantically. A parse failure keeps the previous
+    /// baseline (so the next valid save is diffed against the last good
+    /// version) and is reported as a `ParseError` watch error.
+    pub fn process(&self, path: &Path) -> Result<Option<StructuredChangeEvent>, WatchErrorEvent> {
+        let Some(source) = Self::source_for(path) else {
+            return Ok(None);
+        };
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                self.lock().remove(path);
+                return Ok(None);
+            }
+            Err(e) => {
+                return Err(WatchErrorEvent::new(
+                    format!("Failed to read {:?}: {}", path, e),
+                    Some(path.to_path_buf()),
+                    WatchErrorKind::ParseError,
+                ))
+            }
+        };
+
+        let snapshot = parse_snapshot(source, &content).map_err(|e| {
+            WatchErrorEvent::new(e, Some(path.to_path_buf()), WatchErrorKind::ParseError)
+        })?;
+
+        let mut snapshots = self.lock();
+        let changes = match (&snapshot, snapshots.get(path)) {
+            (TrackedSnapshot::Prd(new), Some(TrackedSnapshot::Prd(old))) => new.diff(Some(old)),
+            (TrackedSnapshot::Prd(new), _) => new.diff(None),
+            (TrackedSnapshot::Progress(new), Some(TrackedSnapshot::Progress(old))) => {
+                new.diff(Some(old))
+            }
+            (TrackedSnapshot::Progress(new), _) => new.diff(None),
+        };
+        snapshots.insert(path.to_path_buf(), snapshot);
+
+        if changes.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(StructuredChangeEvent::new(
+            source,
+            path.to_path_buf(),
+            changes,
+        )))
+    }
+
+    /// Stop tracking a file
+    pub fn forget(&self, path: &Path) {
+        self.lock().remove(path);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, TrackedSnapshot>> {
+        self.snapshots
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn parse_snapshot(source: StructuredSource, content: &str) -> Result<TrackedSnapshot, String> {
+    match source {
+        StructuredSource::Prd => PrdSnapshot::parse(content).map(TrackedSnapshot::Prd),
+        StructuredSource::Progress => {
+            Ok(TrackedSnapshot::Progress(ProgressSnapshot::parse(content)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prd_json(stories: &[(&str, &str, &str)]) -> String {
+        let stories: Vec<serde_json::Value> = stories
+            .iter()
+            .map(|(id, title, status)| {
+                serde_json::json!({ "id": id, "title": title, "status": status })
+            })
+            .collect();
+        serde_json::json!({ "name": "demo", "stories": stories }).to_string()
+    }
+
+    #[test]
+    fn test_prd_status_edit_produces_status_changed() {
+        let old = PrdSnapshot::parse(&prd_json(&[
+            ("story-001", "Login", "pending"),
+            ("story-002", "Logout", "pending"),
+        ]))
+        .unwrap();
+        let new = PrdSnapshot::parse(&prd_json(&[
+            ("story-001", "Login", "completed"),
+            ("story-002", "Logout", "pending"),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            new.diff(Some(&old)),
+            vec![SemanticChange::StoryStatusChanged {
+                story_id: "story-001".to_string(),
+                from: "pending".to_string(),
+                to: "completed".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_prd_added_and_removed_stories() {
+        let old = PrdSnapshot::parse(&prd_json(&[("story-001", "Login", "pending")])).unwrap();
+        let new = PrdSnapshot::parse(&prd_json(&[("story-002", "Logout", "pending")])).unwrap();
+
+        assert_eq!(
+            new.diff(Some(&old)),
+            vec![
+                SemanticChange::StoryAdded {
+                    story_id: "story-002".to_string(),
+                    title: "Logout".to_string(),
+                    status: "pending".to_string(),
+                },
+                SemanticChange::StoryRemoved {
+                    story_id: "story-001".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prd_parse_errors() {
+        assert!(PrdSnapshot::parse("{ not json").is_err());
+        assert!(PrdSnapshot::parse(r#"{"name": "x"}"#).is_err());
+        assert!(PrdSnapshot::parse(r#"{"stories": [{"title": "no id"}]}"#).is_err());
+    }
+
+    #[test]
+    fn test_progress_markers_are_diffed_by_tail() {
+        let old = ProgressSnapshot::parse("# Progress\n[STORY_COMPLETE] story-001\n");
+        let new = ProgressSnapshot::parse(
+            "# Progress\n[STORY_COMPLETE] story-001\n[2026-01-01 10:00] [FAILED] story-002: tests\n",
+        );
+
+        assert_eq!(
+            new.diff(Some(&old)),
+            vec![SemanticChange::ProgressMarkerAdded {
+                marker: "FAILED".to_string(),
+                subject: Some("story-002".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_tracker_emits_status_change_and_parse_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(PRD_FILE_NAME);
+        std::fs::write(&path, prd_json(&[("story-001", "Login", "pending")])).unwrap();
+
+        let tracker = StructuredFileTracker::new();
+        tracker.prime(&path);
+
+        // Re-saving identical content is not a semantic change
+        assert!(tracker.process(&path).unwrap().is_none());
+
+        std::fs::write(&path, prd_json(&[("story-001", "Login", "in_progress")])).unwrap();
+        let event = tracker.process(&path).unwrap().expect("structured event");
+        assert_eq!(event.source, StructuredSource::Prd);
+        assert_eq!(
+            event.changes,
+            vec![SemanticChange::StoryStatusChanged {
+                story_id: "story-001".to_string(),
+                from: "pending".to_string(),
+                to: "in_progress".to_string(),
+            }]
+        );
+
+        std::fs::write(&path, "{ broken").unwrap();
+        let error = tracker.process(&path).unwrap_err();
+        assert_eq!(error.kind, WatchErrorKind::ParseError);
+
+        // The baseline survives the failed parse
+        std::fs::write(&path, prd_json(&[("story-001", "Login", "completed")])).unwrap();
+        let event = tracker.process(&path).unwrap().expect("structured event");
+        assert_eq!(
+            event.changes,
+            vec![SemanticChange::StoryStatusChanged {
+                story_id: "story-001".to_string(),
+                from: "in_progress".to_string(),
+                to: "completed".to_string(),
+            }]
+        );
+    }
+}
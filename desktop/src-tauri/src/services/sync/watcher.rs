@@ -1,8 +1,10 @@
 //! File System Watcher Service
 //!
 //! Implements real-time file system watching using the `notify` crate.
-//! Supports watching multiple directories with debounced event handling.
+//! Supports watching multiple directories with debounced event handling,
+//! glob/`.gitignore` based ignore rules and per-path event coalescing.
 
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use notify::{RecommendedWatcher, RecursiveMode};
 use notify_debouncer_mini::{new_debouncer, DebouncedEventKind, Debouncer};
 use std::collections::HashMap;
@@ -16,12 +18,16 @@ use super::events::{
     ChangeType, FileChangeEvent, PrdChangeEvent, ProgressChangeEvent, ProjectChangeEvent,
     SyncEventEmitter, WatchErrorEvent, WatchErrorKind, WatchStatus, WatchStatusEvent,
 };
+use super::structured::{StructuredFileTracker, PRD_FILE_NAME, PROGRESS_FILE_NAME};
 use crate::utils::error::{AppError, AppResult};
 use crate::utils::paths::claude_projects_dir;
 
 /// Default debounce duration in milliseconds
 const DEFAULT_DEBOUNCE_MS: u64 = 100;
 
+/// Ignore globs applied to project watches by default (gitignore syntax)
+const DEFAULT_IGNORE_GLOBS: &[&str] = &[".git/", "target/", "node_modules/", "dist/", "build/"];
+
 /// Watch target types
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WatchTarget {
@@ -40,6 +46,10 @@ pub struct WatcherConfig {
     pub debounce_ms: u64,
     /// Whether to watch recursively
     pub recursive: bool,
+    /// Ignore globs (gitignore syntax) for project directory watches
+    pub ignore_globs: Vec<String>,
+    /// Whether the project's `.gitignore` rules are applied as well
+    pub respect_gitignore: bool,
 }
 
 impl Default for WatcherConfig {
@@ -47,10 +57,102 @@ impl Default for WatcherConfig {
         Self {
             debounce_ms: DEFAULT_DEBOUNCE_MS,
             recursive: true,
+            ignore_globs: DEFAULT_IGNORE_GLOBS.iter().map(|g| g.to_string()).collect(),
+            respect_gitignore: true,
         }
     }
 }
 
+/// Filters and coalesces a batch of debounced events for one watch.
+///
+/// Ignore rules only apply to project directory watches; specific-file and
+/// projects-directory watches always see their events (e.g. a gitignored
+/// `progress.txt` must still be reported).
+#[derive(Debug, Clone)]
+pub struct WatchEventFilter {
+    /// Root the ignore rules are relative to
+    root: PathBuf,
+    /// Compiled ignore rules
+    matcher: Gitignore,
+}
+
+impl WatchEventFilter {
+    /// Build the filter for a watch target rooted at `path`
+    pub fn for_target(target: &WatchTarget, path: &Path, config: &WatcherConfig) -> Self {
+        match target {
+            WatchTarget::ProjectDirectory(_) => Self::new(path, config),
+            _ => Self {
+                root: path.to_path_buf(),
+                matcher: Gitignore::empty(),
+            },
+        }
+    }
+
+    /// Build a filter from the configured globs (plus `.gitignore` if enabled)
+    pub fn new(root: &Path, config: &WatcherConfig) -> Self {
+        let mut builder = GitignoreBuilder::new(root);
+        if config.respect_gitignore {
+            builder.add(root.join(".gitignore"));
+        }
+        for glob in &config.ignore_globs {
+            if let Err(e) = builder.add_line(None, glob) {
+                tracing::warn!("Ignoring invalid watcher glob {:?}: {}", glob, e);
+            }
+        }
+
+        Self {
+            root: root.to_path_buf(),
+            matcher: builder.build().unwrap_or_else(|_| Gitignore::empty()),
+        }
+    }
+
+    /// Check whether a path is excluded by the ignore rules.
+    ///
+    /// PRD/progress files are never ignored, even when gitignored, since
+    /// structured change events depend on them.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        if StructuredFileTracker::source_for(path).is_some() {
+            return false;
+        }
+        let Ok(rel_path) = path.strip_prefix(&self.root) else {
+            return false;
+        };
+        if rel_path.as_os_str().is_empty() {
+            return false;
+        }
+        self.matcher
+            .matched_path_or_any_parents(rel_path, path.is_dir())
+            .is_ignore()
+    }
+
+    /// Drop ignored paths and coalesce repeated events on the same path.
+    ///
+    /// Each path is reported once, in the order it was first seen, with the
+    /// kind of its latest event.
+    pub fn apply(
+        &self,
+        events: impl IntoIterator<Item = (PathBuf, DebouncedEventKind)>,
+    ) -> Vec<(PathBuf, DebouncedEventKind)> {
+        let mut coalesced: Vec<(PathBuf, DebouncedEventKind)> = Vec::new();
+        let mut positions: HashMap<PathBuf, usize> = HashMap::new();
+
+        for (path, kind) in events {
+            if self.is_ignored(&path) {
+                continue;
+            }
+            match positions.get(&path) {
+                Some(&index) => coalesced[index].1 = kind,
+                None => {
+                    positions.insert(path.clone(), coalesced.len());
+                    coalesced.push((path, kind));
+                }
+            }
+        }
+
+        coalesced
+    }
+}
+
 /// File system watcher service state
 struct WatcherState {
     /// Active watchers by target
@@ -84,6 +186,8 @@ pub struct FileWatcherService<R: Runtime> {
     state: Arc<RwLock<WatcherState>>,
     /// Watcher configuration
     config: WatcherConfig,
+    /// Parsed PRD/progress baselines for structured change events
+    tracker: StructuredFileTracker,
     /// Shutdown signal sender
     shutdown_tx: Option<mpsc::Sender<()>>,
 }
@@ -97,6 +201,7 @@ impl<R: Runtime> FileWatcherService<R> {
             emitter,
             state: Arc::new(RwLock::new(WatcherState::new())),
             config: WatcherConfig::default(),
+            tracker: StructuredFileTracker::new(),
             shutdown_tx: None,
         }
     }
@@ -109,6 +214,7 @@ impl<R: Runtime> FileWatcherService<R> {
             emitter,
             state: Arc::new(RwLock::new(WatcherState::new())),
             config,
+            tracker: StructuredFileTracker::new(),
             shutdown_tx: None,
         }
     }
@@ -140,6 +246,9 @@ impl<R: Runtime> FileWatcherService<R> {
             )));
         }
 
+        self.tracker.prime(&project_path.join(PRD_FILE_NAME));
+        self.tracker.prime(&project_path.join(PROGRESS_FILE_NAME));
+
         self.add_watch(
             WatchTarget::ProjectDirectory(project_path.clone()),
             &project_path,
@@ -150,6 +259,8 @@ impl<R: Runtime> FileWatcherService<R> {
 
     /// Start watching a specific file (prd.json or progress.txt)
     pub async fn watch_file(&self, file_path: PathBuf) -> AppResult<()> {
+        self.tracker.prime(&file_path);
+
         if !file_path.exists() {
             // Watch the parent directory instead, to catch creation
             if let Some(parent) = file_path.parent() {
@@ -179,6 +290,10 @@ impl<R: Runtime> FileWatcherService<R> {
         let mut state = self.state.write().await;
 
         if let Some(_watcher) = state.watchers.remove(target) {
+            if let WatchTarget::SpecificFile(file_path) = target {
+                self.tracker.forget(file_path);
+            }
+
             // Find and remove the watched path
             let path_to_remove = state
                 .watched_paths
@@ -236,8 +351,10 @@ impl<R: Runtime> FileWatcherService<R> {
         }
 
         let emitter = self.emitter.clone();
+        let tracker = self.tracker.clone();
         let target_clone = target.clone();
         let path_buf = path.to_path_buf();
+        let filter = WatchEventFilter::for_target(&target, path, &self.config);
 
         // Create debounced watcher
         let debounce_duration = Duration::from_millis(self.config.debounce_ms);
@@ -247,8 +364,9 @@ impl<R: Runtime> FileWatcherService<R> {
             move |result: Result<Vec<notify_debouncer_mini::DebouncedEvent>, notify::Error>| {
                 match result {
                     Ok(events) => {
-                        for event in events {
-                            Self::handle_event(&emitter, &target_clone, event);
+                        let events = events.into_iter().map(|event| (event.path, event.kind));
+                        for (path, kind) in filter.apply(events) {
+                            Self::handle_event(&emitter, &tracker, &target_clone, path, kind);
                         }
                     }
                     Err(error) => {
@@ -289,11 +407,12 @@ impl<R: Runtime> FileWatcherService<R> {
     /// Handle a debounced file system event
     fn handle_event(
         emitter: &SyncEventEmitter<R>,
+        tracker: &StructuredFileTracker,
         target: &WatchTarget,
-        event: notify_debouncer_mini::DebouncedEvent,
+        path: PathBuf,
+        kind: DebouncedEventKind,
     ) {
-        let path = event.path;
-        let change_type = match event.kind {
+        let change_type = match kind {
             DebouncedEventKind::Any => ChangeType::Modified,
             DebouncedEventKind::AnyContinuous => ChangeType::Modified,
             // Handle any future variants added to the non-exhaustive enum
@@ -311,16 +430,18 @@ impl<R: Runtime> FileWatcherService<R> {
                 // Check for special files
                 if let Some(file_name) = path.file_name() {
                     let name = file_name.to_string_lossy();
-                    if name == "prd.json" {
+                    if name == PRD_FILE_NAME {
                         emitter.emit_prd_change(PrdChangeEvent::new(
                             change_type.clone(),
                             path.clone(),
                         ));
-                    } else if name == "progress.txt" {
+                        Self::emit_structured(emitter, tracker, &path);
+                    } else if name == PROGRESS_FILE_NAME {
                         emitter.emit_progress_change(ProgressChangeEvent::new(
                             change_type.clone(),
                             path.clone(),
                         ));
+                        Self::emit_structured(emitter, tracker, &path);
                     }
                 }
 
@@ -336,11 +457,15 @@ impl<R: Runtime> FileWatcherService<R> {
                 if path == *watched_file {
                     if let Some(file_name) = path.file_name() {
                         let name = file_name.to_string_lossy();
-                        if name == "prd.json" {
-                            emitter.emit_prd_change(PrdChangeEvent::new(change_type, path));
-                        } else if name == "progress.txt" {
-                            emitter
-                                .emit_progress_change(ProgressChangeEvent::new(change_type, path));
+                        if name == PRD_FILE_NAME {
+                            emitter.emit_prd_change(PrdChangeEvent::new(change_type, path.clone()));
+                            Self::emit_structured(emitter, tracker, &path);
+                        } else if name == PROGRESS_FILE_NAME {
+                            emitter.emit_progress_change(ProgressChangeEvent::new(
+                                change_type,
+                                path.clone(),
+                            ));
+                            Self::emit_structured(emitter, tracker, &path);
                         } else {
                             emitter.emit_file_change(FileChangeEvent::new(change_type, path, None));
                         }
@@ -349,6 +474,21 @@ impl<R: Runtime> FileWatcherService<R> {
             }
         }
     }
+
+    /// Parse a changed PRD/progress file and emit its semantic changes.
+    ///
+    /// Parse failures are surfaced as watch errors rather than dropped.
+    fn emit_structured(
+        emitter: &SyncEventEmitter<R>,
+        tracker: &StructuredFileTracker,
+        path: &Path,
+    ) {
+        match tracker.process(path) {
+            Ok(Some(event)) => emitter.emit_structured_change(event),
+            Ok(None) => {}
+            Err(error) => emitter.emit_watch_error(error),
+        }
+    }
 }
 
 impl<R: Runtime> Clone for FileWatcherService<R> {
@@ -358,6 +498,7 @@ impl<R: Runtime> Clone for FileWatcherService<R> {
             emitter: self.emitter.clone(),
             state: self.state.clone(),
             config: self.config.clone(),
+            tracker: self.tracker.clone(),
             shutdown_tx: self.shutdown_tx.clone(),
         }
     }
@@ -390,6 +531,24 @@ impl<R: Runtime> FileWatcherBuilder<R> {
         self
     }
 
+    /// Replace the ignore globs (gitignore syntax)
+    pub fn ignore_globs(mut self, globs: Vec<String>) -> Self {
+        self.config.ignore_globs = globs;
+        self
+    }
+
+    /// Add an ignore glob on top of the current set
+    pub fn ignore_glob(mut self, glob: impl Into<String>) -> Self {
+        self.config.ignore_globs.push(glob.into());
+        self
+    }
+
+    /// Set whether the project's `.gitignore` is applied
+    pub fn respect_gitignore(mut self, respect: bool) -> Self {
+        self.config.respect_gitignore = respect;
+        self
+    }
+
     /// Build the watcher service
     pub fn build(self) -> FileWatcherService<R> {
         FileWatcherService::with_config(self.app_handle, self.config)
@@ -424,6 +583,103 @@ mod tests {
         let config = WatcherConfig::default();
         assert_eq!(config.debounce_ms, DEFAULT_DEBOUNCE_MS);
         assert!(config.recursive);
+        assert!(config.respect_gitignore);
+        assert!(config.ignore_globs.iter().any(|g| g == "target/"));
+        assert!(config.ignore_globs.iter().any(|g| g == "node_modules/"));
+    }
+
+    fn project_with_gitignore() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".gitignore"),
+            "*.log\ngenerated/\nprogress.txt\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::create_dir_all(dir.path().join("target/debug")).unwrap();
+        std::fs::create_dir_all(dir.path().join("node_modules/pkg")).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_filter_drops_ignored_paths() {
+        let dir = project_with_gitignore();
+        let root = dir.path();
+        let target = WatchTarget::ProjectDirectory(root.to_path_buf());
+        let filter = WatchEventFilter::for_target(&target, root, &WatcherConfig::default());
+
+        let events = vec![
+            (root.join("target/debug/app"), DebouncedEventKind::Any),
+            (
+                root.join("node_modules/pkg/index.js"),
+                DebouncedEventKind::Any,
+            ),
+            (root.join("build.log"), DebouncedEventKind::Any),
+            (root.join("generated/schema.rs"), DebouncedEventKind::Any),
+            (root.join(".git/index"), DebouncedEventKind::Any),
+        ];
+        assert!(filter.apply(events).is_empty());
+
+        assert!(!filter.is_ignored(&root.join("progress.txt")));
+        let kept = filter.apply(vec![(root.join("src/main.rs"), DebouncedEventKind::Any)]);
+        assert_eq!(
+            kept,
+            vec![(root.join("src/main.rs"), DebouncedEventKind::Any)]
+        );
+    }
+
+    #[test]
+    fn test_filter_gitignore_can_be_disabled() {
+        let dir = project_with_gitignore();
+        let root = dir.path();
+        let config = WatcherConfig {
+            respect_gitignore: false,
+            ..WatcherConfig::default()
+        };
+        let filter = WatchEventFilter::new(root, &config);
+
+        assert!(!filter.is_ignored(&root.join("build.log")));
+        assert!(filter.is_ignored(&root.join("target/debug/app")));
+    }
+
+    #[test]
+    fn test_filter_does_not_ignore_specific_file_watches() {
+        let dir = project_with_gitignore();
+        let root = dir.path();
+        let file = root.join("progress.log");
+        let target = WatchTarget::SpecificFile(file.clone());
+        let filter = WatchEventFilter::for_target(&target, root, &WatcherConfig::default());
+
+        assert_eq!(
+            filter
+                .apply(vec![(file.clone(), DebouncedEventKind::Any)])
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_burst_of_same_path_saves_coalesces() {
+        let dir = project_with_gitignore();
+        let root = dir.path();
+        let filter = WatchEventFilter::new(root, &WatcherConfig::default());
+        let main = root.join("src/main.rs");
+        let lib = root.join("src/lib.rs");
+
+        let mut events: Vec<_> = (0..50)
+            .map(|_| (main.clone(), DebouncedEventKind::AnyContinuous))
+            .collect();
+        events.push((lib.clone(), DebouncedEventKind::Any));
+        events.push((main.clone(), DebouncedEventKind::Any));
+
+        let coalesced = filter.apply(events);
+        assert_eq!(
+            coalesced,
+            vec![
+                (main, DebouncedEventKind::Any),
+                (lib, DebouncedEventKind::Any),
+            ]
+        );
     }
 
     #[test]
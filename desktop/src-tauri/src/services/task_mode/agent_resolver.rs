@@ -10,6 +10,12 @@
 //! 5. Phase defaults
 //! 6. Fallback chain traversal
 //! 7. Default agent
+//!
+//! Overrides (levels 1 and 2) are checked against the phase's required
+//! capabilities before they are honoured. An override naming an agent that
+//! is unsuitable for the phase or lacks a required capability is rejected and
+//! resolution continues down the chain. Every assignment carries a trace of
+//! the levels that were considered and why each was skipped or selected.
 
 use std::collections::HashMap;
 
@@ -33,6 +39,16 @@ pub enum ExecutionPhase {
     Review,
 }
 
+impl ExecutionPhase {
+    /// All phases, in execution order.
+    pub const ALL: [ExecutionPhase; 4] = [
+        ExecutionPhase::Planning,
+        ExecutionPhase::Implementation,
+        ExecutionPhase::Retry,
+        ExecutionPhase::Review,
+    ];
+}
+
 impl std::fmt::Display for ExecutionPhase {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -124,6 +140,34 @@ impl std::fmt::Display for StoryType {
     }
 }
 
+/// Capability an agent can provide and a phase can require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentCapability {
+    /// Can call tools
+    ToolUse,
+    /// Can edit files in the workspace
+    FileEdit,
+    /// Can run shell commands
+    ShellExec,
+    /// Supports extended thinking for deep reasoning
+    ExtendedThinking,
+    /// Handles large context windows
+    LongContext,
+}
+
+impl std::fmt::Display for AgentCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgentCapability::ToolUse => write!(f, "tool_use"),
+            AgentCapability::FileEdit => write!(f, "file_edit"),
+            AgentCapability::ShellExec => write!(f, "shell_exec"),
+            AgentCapability::ExtendedThinking => write!(f, "extended_thinking"),
+            AgentCapability::LongContext => write!(f, "long_context"),
+        }
+    }
+}
+
 // ============================================================================
 // Configuration
 // ============================================================================
@@ -140,6 +184,9 @@ pub struct PhaseConfig {
     /// Story type overrides (story_type -> agent_name)
     #[serde(default)]
     pub story_type_overrides: HashMap<String, String>,
+    /// Capabilities an override agent must have to be used in this phase
+    #[serde(default)]
+    pub required_capabilities: Vec<AgentCapability>,
 }
 
 impl Default for PhaseConfig {
@@ -148,6 +195,7 @@ impl Default for PhaseConfig {
             default_agent: None,
             fallback_chain: Vec::new(),
             story_type_overrides: HashMap::new(),
+            required_capabilities: Vec::new(),
         }
     }
 }
@@ -163,9 +211,12 @@ pub struct AgentDefinition {
     /// Whether this agent is currently available
     #[serde(default = "default_true")]
     pub available: bool,
-    /// Phases this agent is suitable for
+    /// Phases this agent is suitable for (empty means any phase)
     #[serde(default)]
     pub suitable_phases: Vec<ExecutionPhase>,
+    /// Capabilities this agent provides (`None` means undeclared, not checked)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Vec<AgentCapability>>,
 }
 
 fn default_true() -> bool {
@@ -215,6 +266,13 @@ impl Default for AgentsConfig {
                     ExecutionPhase::Retry,
                     ExecutionPhase::Review,
                 ],
+                capabilities: Some(vec![
+                    AgentCapability::ToolUse,
+                    AgentCapability::FileEdit,
+                    AgentCapability::ShellExec,
+                    AgentCapability::ExtendedThinking,
+                    AgentCapability::LongContext,
+                ]),
             },
         );
         agents.insert(
@@ -228,6 +286,13 @@ impl Default for AgentsConfig {
                     ExecutionPhase::Implementation,
                     ExecutionPhase::Review,
                 ],
+                capabilities: Some(vec![
+                    AgentCapability::ToolUse,
+                    AgentCapability::FileEdit,
+                    AgentCapability::ShellExec,
+                    AgentCapability::ExtendedThinking,
+                    AgentCapability::LongContext,
+                ]),
             },
         );
         agents.insert(
@@ -237,6 +302,11 @@ impl Default for AgentsConfig {
                 description: "Claude Haiku - fastest, best for simple tasks".to_string(),
                 available: true,
                 suitable_phases: vec![ExecutionPhase::Implementation, ExecutionPhase::Retry],
+                capabilities: Some(vec![
+                    AgentCapability::ToolUse,
+                    AgentCapability::FileEdit,
+                    AgentCapability::ShellExec,
+                ]),
             },
         );
 
@@ -247,6 +317,10 @@ impl Default for AgentsConfig {
                 default_agent: Some("claude-opus".to_string()),
                 fallback_chain: vec!["claude-sonnet".to_string()],
                 story_type_overrides: HashMap::new(),
+                required_capabilities: vec![
+                    AgentCapability::ExtendedThinking,
+                    AgentCapability::LongContext,
+                ],
             },
         );
         phase_defaults.insert(
@@ -259,6 +333,7 @@ impl Default for AgentsConfig {
                     m.insert("documentation".to_string(), "claude-haiku".to_string());
                     m
                 },
+                required_capabilities: vec![AgentCapability::ToolUse, AgentCapability::FileEdit],
             },
         );
         phase_defaults.insert(
@@ -267,6 +342,11 @@ impl Default for AgentsConfig {
                 default_agent: Some("claude-opus".to_string()),
                 fallback_chain: vec!["claude-sonnet".to_string()],
                 story_type_overrides: HashMap::new(),
+                required_capabilities: vec![
+                    AgentCapability::ToolUse,
+                    AgentCapability::FileEdit,
+                    AgentCapability::ShellExec,
+                ],
             },
         );
         phase_defaults.insert(
@@ -275,6 +355,7 @@ impl Default for AgentsConfig {
                 default_agent: Some("claude-opus".to_string()),
                 fallback_chain: vec!["claude-sonnet".to_string()],
                 story_type_overrides: HashMap::new(),
+                required_capabilities: vec![AgentCapability::LongContext],
             },
         );
 
@@ -337,6 +418,97 @@ pub struct AgentAssignment {
     pub resolution_level: ResolutionLevel,
     /// Human-readable reasoning
     pub reasoning: String,
+    /// Levels considered before (and including) the selected one
+    #[serde(default)]
+    pub trace: Vec<ResolutionStep>,
+}
+
+impl AgentAssignment {
+    /// Render the resolution trace as one line per considered level.
+    pub fn explain(&self) -> String {
+        self.trace
+            .iter()
+            .map(|step| step.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// One level of the priority chain as considered during resolution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolutionStep {
+    /// Priority level that was considered
+    pub level: ResolutionLevel,
+    /// Candidate agent at this level, if the level named one
+    pub agent_name: Option<String>,
+    /// Whether this step produced the assignment
+    pub selected: bool,
+    /// Why the candidate was selected or skipped
+    pub reason: String,
+}
+
+impl ResolutionStep {
+    fn skipped(level: ResolutionLevel, agent_name: Option<&str>, reason: String) -> Self {
+        Self {
+            level,
+            agent_name: agent_name.map(str::to_string),
+            selected: false,
+            reason,
+        }
+    }
+}
+
+impl std::fmt::Display for ResolutionStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let outcome = if self.selected { "selected" } else { "skipped" };
+        match self.agent_name {
+            Some(ref agent) => write!(f, "{} [{}] {}: {}", self.level, agent, outcome, self.reason),
+            None => write!(f, "{} {}: {}", self.level, outcome, self.reason),
+        }
+    }
+}
+
+/// An override that names an agent the phase cannot use.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverrideMismatch {
+    /// Overridden agent
+    pub agent_name: String,
+    /// Phase the override was checked against
+    pub phase: ExecutionPhase,
+    /// Override level (global or phase)
+    pub level: ResolutionLevel,
+    /// The agent does not list this phase as suitable
+    pub unsuitable_phase: bool,
+    /// Required capabilities the agent does not declare
+    pub missing_capabilities: Vec<AgentCapability>,
+}
+
+impl std::fmt::Display for OverrideMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut problems = Vec::new();
+        if self.unsuitable_phase {
+            problems.push(format!("not suitable for the {} phase", self.phase));
+        }
+        if !self.missing_capabilities.is_empty() {
+            let missing = self
+                .missing_capabilities
+                .iter()
+                .map(|c| c.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            problems.push(format!("missing required capabilities: {}", missing));
+        }
+        write!(
+            f,
+            "{} '{}' rejected for {} phase: {}",
+            self.level,
+            self.agent_name,
+            self.phase,
+            problems.join("; ")
+        )
+    }
 }
 
 // ============================================================================
@@ -386,104 +558,295 @@ impl AgentResolver {
     /// 5. Phase defaults
     /// 6. Fallback chain traversal
     /// 7. Default agent
+    ///
+    /// Overrides that fail [`check_override`](Self::check_override) are
+    /// rejected with a warning. The returned assignment's `trace` records
+    /// every level considered.
     pub fn resolve(&self, story: &StoryInfo, phase: ExecutionPhase) -> AgentAssignment {
+        let mut trace = Vec::new();
+        let phase_config = self.config.phase_defaults.get(&phase.to_string());
+
         // Level 1: Global override
-        if let Some(ref global) = self.config.overrides.global {
-            if self.is_available(global) {
-                return AgentAssignment {
-                    agent_name: global.clone(),
-                    phase,
-                    resolution_level: ResolutionLevel::GlobalOverride,
-                    reasoning: format!("Global override to '{}'", global),
-                };
+        match self.config.overrides.global {
+            Some(ref global) => {
+                match self.override_rejection(global, phase, ResolutionLevel::GlobalOverride) {
+                    Some(reason) => trace.push(ResolutionStep::skipped(
+                        ResolutionLevel::GlobalOverride,
+                        Some(global),
+                        reason,
+                    )),
+                    None => {
+                        return Self::select(
+                            global,
+                            phase,
+                            ResolutionLevel::GlobalOverride,
+                            format!("Global override to '{}'", global),
+                            trace,
+                        );
+                    }
+                }
             }
+            None => trace.push(ResolutionStep::skipped(
+                ResolutionLevel::GlobalOverride,
+                None,
+                "No global override set".to_string(),
+            )),
         }
 
         // Level 2: Phase-specific override
-        if let Some(phase_agent) = self
+        match self
             .config
             .overrides
             .phase_overrides
             .get(&phase.to_string())
         {
-            if self.is_available(phase_agent) {
-                return AgentAssignment {
-                    agent_name: phase_agent.clone(),
-                    phase,
-                    resolution_level: ResolutionLevel::PhaseOverride,
-                    reasoning: format!("Phase override for {} to '{}'", phase, phase_agent),
-                };
+            Some(phase_agent) => {
+                match self.override_rejection(phase_agent, phase, ResolutionLevel::PhaseOverride) {
+                    Some(reason) => trace.push(ResolutionStep::skipped(
+                        ResolutionLevel::PhaseOverride,
+                        Some(phase_agent),
+                        reason,
+                    )),
+                    None => {
+                        return Self::select(
+                            phase_agent,
+                            phase,
+                            ResolutionLevel::PhaseOverride,
+                            format!("Phase override for {} to '{}'", phase, phase_agent),
+                            trace,
+                        );
+                    }
+                }
             }
+            None => trace.push(ResolutionStep::skipped(
+                ResolutionLevel::PhaseOverride,
+                None,
+                format!("No override for {} phase", phase),
+            )),
         }
 
         // Level 3: Story-level agent
-        if let Some(ref story_agent) = story.agent {
-            if self.is_available(story_agent) {
-                return AgentAssignment {
-                    agent_name: story_agent.clone(),
+        match story.agent {
+            Some(ref story_agent) if self.is_available(story_agent) => {
+                return Self::select(
+                    story_agent,
                     phase,
-                    resolution_level: ResolutionLevel::StoryLevel,
-                    reasoning: format!("Story-level agent assignment to '{}'", story_agent),
-                };
+                    ResolutionLevel::StoryLevel,
+                    format!("Story-level agent assignment to '{}'", story_agent),
+                    trace,
+                );
             }
+            Some(ref story_agent) => trace.push(ResolutionStep::skipped(
+                ResolutionLevel::StoryLevel,
+                Some(story_agent),
+                format!("Agent '{}' is not available", story_agent),
+            )),
+            None => trace.push(ResolutionStep::skipped(
+                ResolutionLevel::StoryLevel,
+                None,
+                "Story has no assigned agent".to_string(),
+            )),
         }
 
         // Level 4: Story type inference
         let story_type = StoryType::infer(&story.title, &story.description);
-        if let Some(phase_config) = self.config.phase_defaults.get(&phase.to_string()) {
-            if let Some(type_agent) = phase_config
-                .story_type_overrides
-                .get(&story_type.to_string())
-            {
-                if self.is_available(type_agent) {
-                    return AgentAssignment {
-                        agent_name: type_agent.clone(),
-                        phase,
-                        resolution_level: ResolutionLevel::StoryTypeInference,
-                        reasoning: format!(
-                            "Story type '{}' maps to '{}' for {} phase",
-                            story_type, type_agent, phase
-                        ),
-                    };
-                }
+        match phase_config.and_then(|pc| pc.story_type_overrides.get(&story_type.to_string())) {
+            Some(type_agent) if self.is_available(type_agent) => {
+                return Self::select(
+                    type_agent,
+                    phase,
+                    ResolutionLevel::StoryTypeInference,
+                    format!(
+                        "Story type '{}' maps to '{}' for {} phase",
+                        story_type, type_agent, phase
+                    ),
+                    trace,
+                );
             }
+            Some(type_agent) => trace.push(ResolutionStep::skipped(
+                ResolutionLevel::StoryTypeInference,
+                Some(type_agent),
+                format!("Agent '{}' is not available", type_agent),
+            )),
+            None => trace.push(ResolutionStep::skipped(
+                ResolutionLevel::StoryTypeInference,
+                None,
+                format!("No {} phase mapping for story type '{}'", phase, story_type),
+            )),
         }
 
-        // Level 5: Phase defaults
-        if let Some(phase_config) = self.config.phase_defaults.get(&phase.to_string()) {
-            if let Some(ref default_agent) = phase_config.default_agent {
-                if self.is_available(default_agent) {
-                    return AgentAssignment {
-                        agent_name: default_agent.clone(),
+        if let Some(phase_config) = phase_config {
+            // Level 5: Phase defaults
+            match phase_config.default_agent {
+                Some(ref default_agent) if self.is_available(default_agent) => {
+                    return Self::select(
+                        default_agent,
                         phase,
-                        resolution_level: ResolutionLevel::PhaseDefault,
-                        reasoning: format!("Phase default for {} is '{}'", phase, default_agent),
-                    };
+                        ResolutionLevel::PhaseDefault,
+                        format!("Phase default for {} is '{}'", phase, default_agent),
+                        trace,
+                    );
                 }
+                Some(ref default_agent) => trace.push(ResolutionStep::skipped(
+                    ResolutionLevel::PhaseDefault,
+                    Some(default_agent),
+                    format!("Agent '{}' is not available", default_agent),
+                )),
+                None => trace.push(ResolutionStep::skipped(
+                    ResolutionLevel::PhaseDefault,
+                    None,
+                    format!("No default agent for {} phase", phase),
+                )),
             }
 
             // Level 6: Fallback chain traversal
             for fallback in &phase_config.fallback_chain {
                 if self.is_available(fallback) {
-                    return AgentAssignment {
-                        agent_name: fallback.clone(),
+                    return Self::select(
+                        fallback,
                         phase,
-                        resolution_level: ResolutionLevel::FallbackChain,
-                        reasoning: format!(
-                            "Fallback chain for {} phase selected '{}'",
-                            phase, fallback
-                        ),
-                    };
+                        ResolutionLevel::FallbackChain,
+                        format!("Fallback chain for {} phase selected '{}'", phase, fallback),
+                        trace,
+                    );
                 }
+                trace.push(ResolutionStep::skipped(
+                    ResolutionLevel::FallbackChain,
+                    Some(fallback),
+                    format!("Agent '{}' is not available", fallback),
+                ));
             }
+        } else {
+            trace.push(ResolutionStep::skipped(
+                ResolutionLevel::PhaseDefault,
+                None,
+                format!("No configuration for {} phase", phase),
+            ));
         }
 
         // Level 7: Default agent
+        let default_agent = self.config.default_agent.clone();
+        Self::select(
+            &default_agent,
+            phase,
+            ResolutionLevel::DefaultAgent,
+            format!("Using default agent '{}'", default_agent),
+            trace,
+        )
+    }
+
+    /// Check whether `agent_name` may be used as an override for `phase`.
+    ///
+    /// An agent fails when it lists suitable phases that do not include
+    /// `phase`, or when it declares capabilities that do not cover the
+    /// phase's `required_capabilities`. Agents without a definition or
+    /// without declared capabilities are not checked for capabilities.
+    pub fn check_override(
+        &self,
+        agent_name: &str,
+        phase: ExecutionPhase,
+        level: ResolutionLevel,
+    ) -> Result<(), OverrideMismatch> {
+        let Some(agent) = self.config.agents.get(agent_name) else {
+            return Ok(());
+        };
+
+        let unsuitable_phase =
+            !agent.suitable_phases.is_empty() && !agent.suitable_phases.contains(&phase);
+        let missing_capabilities = match (
+            agent.capabilities.as_ref(),
+            self.config.phase_defaults.get(&phase.to_string()),
+        ) {
+            (Some(capabilities), Some(phase_config)) => phase_config
+                .required_capabilities
+                .iter()
+                .filter(|required| !capabilities.contains(required))
+                .copied()
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        if unsuitable_phase || !missing_capabilities.is_empty() {
+            return Err(OverrideMismatch {
+                agent_name: agent_name.to_string(),
+                phase,
+                level,
+                unsuitable_phase,
+                missing_capabilities,
+            });
+        }
+        Ok(())
+    }
+
+    /// Validate the configured overrides against every phase they apply to.
+    ///
+    /// The global override is checked against all phases; phase overrides
+    /// only against their own phase. Returns every mismatch found.
+    pub fn validate_overrides(&self) -> Vec<OverrideMismatch> {
+        let overrides = &self.config.overrides;
+        let mut mismatches = Vec::new();
+
+        if let Some(ref global) = overrides.global {
+            for phase in ExecutionPhase::ALL {
+                if let Err(mismatch) =
+                    self.check_override(global, phase, ResolutionLevel::GlobalOverride)
+                {
+                    mismatches.push(mismatch);
+                }
+            }
+        }
+
+        for phase in ExecutionPhase::ALL {
+            if let Some(agent) = overrides.phase_overrides.get(&phase.to_string()) {
+                if let Err(mismatch) =
+                    self.check_override(agent, phase, ResolutionLevel::PhaseOverride)
+                {
+                    mismatches.push(mismatch);
+                }
+            }
+        }
+
+        mismatches
+    }
+
+    /// Reason an override cannot be used for `phase`, if any.
+    fn override_rejection(
+        &self,
+        agent_name: &str,
+        phase: ExecutionPhase,
+        level: ResolutionLevel,
+    ) -> Option<String> {
+        if !self.is_available(agent_name) {
+            return Some(format!("Agent '{}' is not available", agent_name));
+        }
+        match self.check_override(agent_name, phase, level) {
+            Ok(()) => None,
+            Err(mismatch) => {
+                tracing::warn!("{}", mismatch);
+                Some(mismatch.to_string())
+            }
+        }
+    }
+
+    fn select(
+        agent_name: &str,
+        phase: ExecutionPhase,
+        resolution_level: ResolutionLevel,
+        reasoning: String,
+        mut trace: Vec<ResolutionStep>,
+    ) -> AgentAssignment {
+        trace.push(ResolutionStep {
+            level: resolution_level,
+            agent_name: Some(agent_name.to_string()),
+            selected: true,
+            reason: reasoning.clone(),
+        });
         AgentAssignment {
-            agent_name: self.config.default_agent.clone(),
+            agent_name: agent_name.to_string(),
             phase,
-            resolution_level: ResolutionLevel::DefaultAgent,
-            reasoning: format!("Using default agent '{}'", self.config.default_agent),
+            resolution_level,
+            reasoning,
+            trace,
         }
     }
 
@@ -511,6 +874,41 @@ impl AgentResolver {
         self.config.overrides.global = agent;
     }
 
+    /// Set a global override after validating it against every phase.
+    ///
+    /// The override is left unchanged when the agent is rejected for any
+    /// phase.
+    pub fn try_set_global_override(&mut self, agent: &str) -> Result<(), Vec<OverrideMismatch>> {
+        let mismatches: Vec<OverrideMismatch> = ExecutionPhase::ALL
+            .into_iter()
+            .filter_map(|phase| {
+                self.check_override(agent, phase, ResolutionLevel::GlobalOverride)
+                    .err()
+            })
+            .collect();
+        if !mismatches.is_empty() {
+            return Err(mismatches);
+        }
+        self.config.overrides.global = Some(agent.to_string());
+        Ok(())
+    }
+
+    /// Set a phase-level override after validating it for that phase.
+    ///
+    /// The override is left unchanged when the agent is rejected.
+    pub fn try_set_phase_override(
+        &mut self,
+        phase: ExecutionPhase,
+        agent: &str,
+    ) -> Result<(), OverrideMismatch> {
+        self.check_override(agent, phase, ResolutionLevel::PhaseOverride)?;
+        self.config
+            .overrides
+            .phase_overrides
+            .insert(phase.to_string(), agent.to_string());
+        Ok(())
+    }
+
     /// Set a phase-level override.
     pub fn set_phase_override(&mut self, phase: ExecutionPhase, agent: Option<String>) {
         match agent {
@@ -726,6 +1124,130 @@ mod tests {
         assert_eq!(retry_assignment.agent_name, "claude-opus");
     }
 
+    // ========================================================================
+    // Override Validation Tests
+    // ========================================================================
+
+    #[test]
+    fn test_override_to_incapable_agent_is_rejected() {
+        let mut resolver = AgentResolver::with_defaults();
+
+        // Haiku lacks extended thinking and long context, and is not a
+        // planning agent.
+        let err = resolver
+            .try_set_phase_override(ExecutionPhase::Planning, "claude-haiku")
+            .unwrap_err();
+        assert_eq!(err.agent_name, "claude-haiku");
+        assert_eq!(err.level, ResolutionLevel::PhaseOverride);
+        assert!(err.unsuitable_phase);
+        assert_eq!(
+            err.missing_capabilities,
+            vec![
+                AgentCapability::ExtendedThinking,
+                AgentCapability::LongContext
+            ]
+        );
+        assert!(resolver.config().overrides.phase_overrides.is_empty());
+
+        // Opus cannot take the retry phase, so a global override is refused.
+        let errs = resolver.try_set_global_override("claude-opus").unwrap_err();
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].phase, ExecutionPhase::Retry);
+        assert!(resolver.config().overrides.global.is_none());
+
+        // A capable agent is accepted.
+        resolver
+            .try_set_phase_override(ExecutionPhase::Planning, "claude-sonnet")
+            .unwrap();
+        assert!(resolver.validate_overrides().is_empty());
+    }
+
+    #[test]
+    fn test_incapable_override_is_skipped_during_resolution() {
+        let mut config = AgentsConfig::default();
+        config
+            .overrides
+            .phase_overrides
+            .insert("planning".to_string(), "claude-haiku".to_string());
+        let resolver = AgentResolver::new(config);
+
+        let mismatches = resolver.validate_overrides();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].phase, ExecutionPhase::Planning);
+
+        let assignment = resolver.resolve(&test_story(), ExecutionPhase::Planning);
+        assert_eq!(assignment.agent_name, "claude-opus");
+        assert_eq!(assignment.resolution_level, ResolutionLevel::PhaseDefault);
+
+        let rejected = assignment
+            .trace
+            .iter()
+            .find(|step| step.level == ResolutionLevel::PhaseOverride)
+            .unwrap();
+        assert!(!rejected.selected);
+        assert_eq!(rejected.agent_name.as_deref(), Some("claude-haiku"));
+        assert!(rejected.reason.contains("extended_thinking"));
+    }
+
+    #[test]
+    fn test_undeclared_capabilities_are_not_checked() {
+        let mut config = AgentsConfig::default();
+        config
+            .agents
+            .get_mut("claude-haiku")
+            .unwrap()
+            .suitable_phases
+            .clear();
+        config.agents.get_mut("claude-haiku").unwrap().capabilities = None;
+        let mut resolver = AgentResolver::new(config);
+
+        resolver
+            .try_set_phase_override(ExecutionPhase::Planning, "claude-haiku")
+            .unwrap();
+        let assignment = resolver.resolve(&test_story(), ExecutionPhase::Planning);
+        assert_eq!(assignment.agent_name, "claude-haiku");
+        assert_eq!(assignment.resolution_level, ResolutionLevel::PhaseOverride);
+    }
+
+    #[test]
+    fn test_resolution_trace_explains_pick() {
+        let mut config = AgentsConfig::default();
+        config.agents.get_mut("claude-opus").unwrap().available = false;
+        let resolver = AgentResolver::new(config);
+
+        let assignment = resolver.resolve(&test_story(), ExecutionPhase::Planning);
+        assert_eq!(assignment.agent_name, "claude-sonnet");
+
+        let levels: Vec<_> = assignment.trace.iter().map(|s| s.level).collect();
+        assert_eq!(
+            levels,
+            vec![
+                ResolutionLevel::GlobalOverride,
+                ResolutionLevel::PhaseOverride,
+                ResolutionLevel::StoryLevel,
+                ResolutionLevel::StoryTypeInference,
+                ResolutionLevel::PhaseDefault,
+                ResolutionLevel::FallbackChain,
+            ]
+        );
+        let (last, skipped) = assignment.trace.split_last().unwrap();
+        assert!(last.selected);
+        assert_eq!(last.reason, assignment.reasoning);
+        assert!(skipped.iter().all(|step| !step.selected));
+        assert_eq!(
+            skipped[4].reason,
+            "Agent 'claude-opus' is not available".to_string()
+        );
+
+        let explanation = assignment.explain();
+        assert_eq!(explanation.lines().count(), 6);
+        assert!(explanation
+            .lines()
+            .last()
+            .unwrap()
+            .starts_with("fallback_chain [claude-sonnet] selected"));
+    }
+
     // ========================================================================
     // Serialization Tests
     // ========================================================================
@@ -770,6 +1292,7 @@ mod tests {
             phase: ExecutionPhase::Implementation,
             resolution_level: ResolutionLevel::PhaseDefault,
             reasoning: "Phase default".to_string(),
+            trace: Vec::new(),
         };
         let json = serde_json::to_string(&assignment).unwrap();
         assert!(json.contains("\"agentName\""));
@@ -0,0 +1,282 @@
+//! Analysis Result Cache
+//!
+//! Caches requirement-analysis and architecture-review results on disk, one
+//! entry per project and analysis kind. Each entry records a hash of the
+//! source it was produced from: the task description, interview spec and
+//! exploration context for requirement analysis, or the PRD and exploration
+//! context for architecture review.
+//!
+//! A cached result is reused only while the source hash matches. When the
+//! source changes the entry is invalidated on lookup and the analysis runs
+//! again. Callers can bypass the cache entirely with a forced refresh.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::utils::error::AppResult;
+
+/// Which analysis pass a cache entry belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisKind {
+    RequirementAnalysis,
+    ArchitectureReview,
+}
+
+impl AnalysisKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AnalysisKind::RequirementAnalysis => "requirement_analysis",
+            AnalysisKind::ArchitectureReview => "architecture_review",
+        }
+    }
+}
+
+/// A cached analysis result and the source it was produced from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedAnalysis<T> {
+    pub kind: AnalysisKind,
+    /// Hash of the spec the analysis ran on
+    pub source_hash: String,
+    /// RFC 3339 timestamp of when the entry was written
+    pub cached_at: String,
+    pub result: T,
+}
+
+impl<T> CachedAnalysis<T> {
+    /// Cache details to report alongside the result.
+    pub fn info(&self, reused: bool) -> AnalysisCacheInfo {
+        AnalysisCacheInfo {
+            source_hash: self.source_hash.clone(),
+            cached_at: self.cached_at.clone(),
+            reused,
+        }
+    }
+}
+
+/// Cache details attached to an analysis result returned to the frontend.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisCacheInfo {
+    /// Hash of the spec the analysis ran on
+    pub source_hash: String,
+    /// When the analysis was produced
+    pub cached_at: String,
+    /// Served from the cache rather than a fresh LLM pass
+    pub reused: bool,
+}
+
+/// Outcome of looking up a cache entry.
+#[derive(Debug, Clone)]
+pub enum AnalysisCacheLookup<T> {
+    /// The source is unchanged; the cached result can be reused
+    Hit(CachedAnalysis<T>),
+    /// The source changed since the entry was written. The entry has been
+    /// invalidated.
+    Stale {
+        previous_hash: String,
+    },
+    Miss,
+}
+
+/// On-disk store of analysis results.
+#[derive(Debug, Clone)]
+pub struct AnalysisCache {
+    root: PathBuf,
+}
+
+impl AnalysisCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Hash the labelled source sections an analysis depends on.
+    ///
+    /// Surrounding whitespace is ignored, and an absent section hashes
+    /// differently from an empty one.
+    pub fn source_hash(sections: &[(&str, Option<&str>)]) -> String {
+        let mut material = String::new();
+        for (label, value) in sections {
+            match value {
+                Some(value) => {
+                    let value = value.trim();
+                    material.push_str(&format!("{}:{}\n{}\n", label, value.len(), value));
+                }
+                None => material.push_str(&format!("{}:none\n", label)),
+            }
+        }
+        sha256_hex(material.as_bytes())
+    }
+
+    /// Look up the `kind` entry for `project_root` against `source_hash`.
+    /// A stale entry is removed before returning. Entries that no longer
+    /// deserialize as `T` are treated as misses.
+    pub fn lookup<T: DeserializeOwned>(
+        &self,
+        kind: AnalysisKind,
+        project_root: &Path,
+        source_hash: &str,
+    ) -> AnalysisCacheLookup<T> {
+        let Some(entry) = self.read::<T>(kind, project_root) else {
+            return AnalysisCacheLookup::Miss;
+        };
+        if entry.source_hash == source_hash {
+            return AnalysisCacheLookup::Hit(entry);
+        }
+        self.invalidate(kind, project_root);
+        AnalysisCacheLookup::Stale {
+            previous_hash: entry.source_hash,
+        }
+    }
+
+    /// Store `result` for `kind` in `project_root`, replacing any previous
+    /// entry.
+    pub fn store<T: Serialize + Clone>(
+        &self,
+        kind: AnalysisKind,
+        project_root: &Path,
+        source_hash: &str,
+        result: &T,
+    ) -> AppResult<CachedAnalysis<T>> {
+        let entry = CachedAnalysis {
+            kind,
+            source_hash: source_hash.to_string(),
+            cached_at: chrono::Utc::now().to_rfc3339(),
+            result: result.clone(),
+        };
+        fs::create_dir_all(&self.root)?;
+        fs::write(
+            self.entry_path(kind, project_root),
+            serde_json::to_vec_pretty(&entry)?,
+        )?;
+        Ok(entry)
+    }
+
+    pub fn invalidate(&self, kind: AnalysisKind, project_root: &Path) {
+        let _ = fs::remove_file(self.entry_path(kind, project_root));
+    }
+
+    fn read<T: DeserializeOwned>(
+        &self,
+        kind: AnalysisKind,
+        project_root: &Path,
+    ) -> Option<CachedAnalysis<T>> {
+        let bytes = fs::read(self.entry_path(kind, project_root)).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    fn entry_path(&self, kind: AnalysisKind, project_root: &Path) -> PathBuf {
+        let key = sha256_hex(project_root.to_string_lossy().as_bytes());
+        self.root.join(format!("{}-{}.json", kind.as_str(), key))
+    }
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Analysis {
+        summary: String,
+    }
+
+    fn analysis(summary: &str) -> Analysis {
+        Analysis {
+            summary: summary.to_string(),
+        }
+    }
+
+    fn spec_hash(task: &str, interview: Option<&str>) -> String {
+        AnalysisCache::source_hash(&[
+            ("task", Some(task)),
+            ("interview", interview),
+            ("context", None),
+            ("locale", Some("en")),
+        ])
+    }
+
+    #[test]
+    fn test_unchanged_spec_reuses_cached_analysis() {
+        let cache_dir = TempDir::new().unwrap();
+        let cache = AnalysisCache::new(cache_dir.path());
+        let project = Path::new("/tmp/project");
+        let kind = AnalysisKind::RequirementAnalysis;
+
+        let hash = spec_hash("Add login", Some("{\"goal\":\"auth\"}"));
+        assert!(matches!(
+            cache.lookup::<Analysis>(kind, project, &hash),
+            AnalysisCacheLookup::Miss
+        ));
+        let stored = cache
+            .store(kind, project, &hash, &analysis("needs OAuth"))
+            .unwrap();
+        assert_eq!(stored.source_hash, hash);
+        assert!(chrono::DateTime::parse_from_rfc3339(&stored.cached_at).is_ok());
+
+        // The same spec, even with different surrounding whitespace, hits.
+        let again = spec_hash("  Add login\n", Some("{\"goal\":\"auth\"}"));
+        assert_eq!(again, hash);
+        for _ in 0..2 {
+            match cache.lookup::<Analysis>(kind, project, &again) {
+                AnalysisCacheLookup::Hit(entry) => {
+                    assert_eq!(entry.result, analysis("needs OAuth"));
+                    assert_eq!(entry.cached_at, stored.cached_at);
+                    let info = entry.info(true);
+                    assert!(info.reused);
+                    assert_eq!(info.source_hash, hash);
+                }
+                other => panic!("expected cache hit, got {:?}", other),
+            }
+        }
+
+        // Other kinds and projects are cached separately.
+        assert!(matches!(
+            cache.lookup::<Analysis>(AnalysisKind::ArchitectureReview, project, &hash),
+            AnalysisCacheLookup::Miss
+        ));
+        assert!(matches!(
+            cache.lookup::<Analysis>(kind, Path::new("/tmp/other"), &hash),
+            AnalysisCacheLookup::Miss
+        ));
+    }
+
+    #[test]
+    fn test_editing_spec_invalidates_cached_analysis() {
+        let cache_dir = TempDir::new().unwrap();
+        let cache = AnalysisCache::new(cache_dir.path());
+        let project = Path::new("/tmp/project");
+        let kind = AnalysisKind::ArchitectureReview;
+
+        let original = spec_hash("Add login", None);
+        cache
+            .store(kind, project, &original, &analysis("v1"))
+            .unwrap();
+
+        let edited = spec_hash("Add login with SSO", None);
+        assert_ne!(edited, original);
+        match cache.lookup::<Analysis>(kind, project, &edited) {
+            AnalysisCacheLookup::Stale { previous_hash } => assert_eq!(previous_hash, original),
+            other => panic!("expected stale entry, got {:?}", other),
+        }
+        // The stale entry is gone, even for the original spec.
+        assert!(matches!(
+            cache.lookup::<Analysis>(kind, project, &original),
+            AnalysisCacheLookup::Miss
+        ));
+
+        // Adding a section that was absent also changes the hash.
+        assert_ne!(spec_hash("Add login", Some("")), original);
+    }
+}
@@ -7,11 +7,12 @@
 use std::collections::{HashMap, HashSet};
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
 use serde::{Deserialize, Serialize};
-use tokio::sync::RwLock;
+use tokio::sync::{OwnedSemaphorePermit, RwLock, Semaphore};
 use tokio_util::sync::CancellationToken;
 
 use crate::commands::task_mode::TaskCustomQualityGate;
@@ -78,6 +79,18 @@ pub struct ExecutionConfig {
     /// Whether retry is enabled
     #[serde(default = "default_retry_enabled")]
     pub retry_enabled: bool,
+    /// Maximum story attempts running at once, across all batches.
+    /// `None` leaves concurrency bounded only by `max_parallel`.
+    #[serde(default)]
+    pub max_concurrent_stories: Option<usize>,
+    /// Total retries shared by all stories. `None` means only the
+    /// per-story `max_retries` applies.
+    #[serde(default)]
+    pub retry_budget: Option<u32>,
+    /// Semaphore gating story attempts. When set it takes precedence over
+    /// `max_concurrent_stories`, so several executors can share one cap.
+    #[serde(skip)]
+    pub concurrency_semaphore: Option<Arc<Semaphore>>,
     /// Whether story quality gates are enabled.
     #[serde(default = "default_quality_gates_enabled")]
     pub quality_gates_enabled: bool,
@@ -138,6 +151,9 @@ impl Default for ExecutionConfig {
             max_parallel: default_max_parallel(),
             max_retries: default_max_retries(),
             retry_enabled: default_retry_enabled(),
+            max_concurrent_stories: None,
+            retry_budget: None,
+            concurrency_semaphore: None,
             quality_gates_enabled: default_quality_gates_enabled(),
             selected_quality_gate_ids: vec![],
             custom_quality_gates: vec![],
@@ -280,6 +296,12 @@ pub struct BatchExecutionProgress {
     pub story_statuses: HashMap<String, String>,
     /// Current phase: "dor", "executing", "gates", "dod"
     pub current_phase: String,
+    /// Story attempts currently holding a concurrency slot
+    #[serde(default)]
+    pub active_concurrency: usize,
+    /// Retries left in the shared budget; `None` when there is no budget
+    #[serde(default)]
+    pub retry_budget_remaining: Option<u32>,
 }
 
 /// Event channel name for task mode progress events.
@@ -521,6 +543,8 @@ pub struct TaskToolPermissionRequest {
     pub tool_name: String,
     pub arguments: String,
     pub risk: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
 fn task_gate_summary_from_results(
@@ -695,6 +719,90 @@ pub fn calculate_batches(
     Ok(batches)
 }
 
+// ============================================================================
+// Shared Execution Limits
+// ============================================================================
+
+/// Concurrency cap and retry budget shared by every story of an execution.
+///
+/// Cloning is cheap; clones observe and consume the same limits.
+#[derive(Debug, Clone)]
+pub struct SharedExecutionLimits {
+    semaphore: Option<Arc<Semaphore>>,
+    active: Arc<AtomicUsize>,
+    retry_budget: Option<Arc<AtomicU32>>,
+}
+
+/// Held while a story attempt runs; releases the concurrency slot on drop.
+struct ConcurrencySlot {
+    _permit: Option<OwnedSemaphorePermit>,
+    active: Arc<AtomicUsize>,
+}
+
+impl Drop for ConcurrencySlot {
+    fn drop(&mut self) {
+        self.active.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl SharedExecutionLimits {
+    pub fn from_config(config: &ExecutionConfig) -> Self {
+        let semaphore = config.concurrency_semaphore.clone().or_else(|| {
+            config
+                .max_concurrent_stories
+                .map(|limit| Arc::new(Semaphore::new(limit.max(1))))
+        });
+        Self {
+            semaphore,
+            active: Arc::new(AtomicUsize::new(0)),
+            retry_budget: config
+                .retry_budget
+                .map(|budget| Arc::new(AtomicU32::new(budget))),
+        }
+    }
+
+    /// Number of story attempts currently running.
+    pub fn active_concurrency(&self) -> usize {
+        self.active.load(Ordering::SeqCst)
+    }
+
+    /// Retries left in the shared budget, or `None` when unlimited.
+    pub fn retry_budget_remaining(&self) -> Option<u32> {
+        self.retry_budget
+            .as_ref()
+            .map(|budget| budget.load(Ordering::SeqCst))
+    }
+
+    /// Take one retry from the budget. Returns `false` once it is spent.
+    fn try_consume_retry(&self) -> bool {
+        match &self.retry_budget {
+            Some(budget) => budget
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |left| {
+                    left.checked_sub(1)
+                })
+                .is_ok(),
+            None => true,
+        }
+    }
+
+    /// Wait for a concurrency slot. Returns `None` if cancelled while waiting.
+    async fn acquire(&self, cancel_token: &CancellationToken) -> Option<ConcurrencySlot> {
+        let permit = match &self.semaphore {
+            Some(semaphore) => tokio::select! {
+                biased;
+                _ = cancel_token.cancelled() => return None,
+                permit = semaphore.clone().acquire_owned() => permit.ok(),
+            },
+            None => None,
+        };
+        self.active.fetch_add(1, Ordering::SeqCst);
+        Some(ConcurrencySlot {
+            _permit: permit,
+            active: self.active.clone(),
+        })
+    }
+}
+
 // ============================================================================
 // Batch Executor
 // ============================================================================
@@ -709,6 +817,8 @@ pub struct BatchExecutor {
     cancellation_token: CancellationToken,
     /// Current execution state
     state: Arc<RwLock<BatchExecutionState>>,
+    /// Concurrency cap and retry budget shared across batches
+    limits: SharedExecutionLimits,
     /// Optional LLM provider for AI quality gates (verification, code review)
     llm_provider: Option<Arc<dyn LlmProvider>>,
     /// Optional analytics tracker for story and quality gate usage attribution
@@ -748,10 +858,12 @@ impl BatchExecutor {
             .map(|s| (s.id.clone(), StoryExecutionState::Pending))
             .collect();
 
+        let limits = SharedExecutionLimits::from_config(&config);
         Self {
             stories,
             config,
             cancellation_token,
+            limits,
             state: Arc::new(RwLock::new(BatchExecutionState {
                 story_states,
                 current_batch: 0,
@@ -791,10 +903,12 @@ impl BatchExecutor {
             })
             .collect();
 
+        let limits = SharedExecutionLimits::from_config(&config);
         Self {
             stories,
             config,
             cancellation_token,
+            limits,
             state: Arc::new(RwLock::new(BatchExecutionState {
                 story_states,
                 current_batch: 0,
@@ -916,9 +1030,16 @@ impl BatchExecutor {
             total_stories: state.story_states.len(),
             story_statuses,
             current_phase: "executing".to_string(),
+            active_concurrency: self.limits.active_concurrency(),
+            retry_budget_remaining: self.limits.retry_budget_remaining(),
         }
     }
 
+    /// Handle to the shared limits, for reporting while execution runs.
+    pub fn limits(&self) -> SharedExecutionLimits {
+        self.limits.clone()
+    }
+
     /// Get the completed story IDs.
     pub async fn completed_story_ids(&self) -> Vec<String> {
         let state = self.state.read().await;
@@ -1170,6 +1291,7 @@ impl BatchExecutor {
                 let plugin_gates = self.config.plugin_quality_gates.clone();
                 let cancel_token = self.cancellation_token.clone();
                 let state_ref = self.state.clone();
+                let limits = self.limits.clone();
                 let resolver_config = agent_resolver.config().clone();
                 let se = story_executor.clone();
                 let provider = self.llm_provider.clone();
@@ -1209,6 +1331,7 @@ impl BatchExecutor {
                         plugin_gates.clone(),
                         cancel_token,
                         state_ref,
+                        limits,
                         resolver_config,
                         provider,
                         analytics_tx,
@@ -1301,7 +1424,8 @@ impl BatchExecutor {
     ///    retry. Soft mode adds warning to gate results.
     ///
     /// On failure, retries up to `max_retries` times with a different agent
-    /// (resolved via the Retry phase).
+    /// (resolved via the Retry phase), as long as the shared retry budget
+    /// allows. Each attempt holds a shared concurrency slot while it runs.
     async fn execute_story_with_retry<E>(
         session_id: &str,
         batch_index: usize,
@@ -1321,6 +1445,7 @@ impl BatchExecutor {
         plugin_quality_gates: Vec<crate::services::plugins::models::PluginQualityGate>,
         cancel_token: CancellationToken,
         state: Arc<RwLock<BatchExecutionState>>,
+        limits: SharedExecutionLimits,
         agents_config: crate::services::task_mode::agent_resolver::AgentsConfig,
         llm_provider: Option<Arc<dyn LlmProvider>>,
         analytics_tx: Option<tokio::sync::mpsc::Sender<crate::services::analytics::TrackerMessage>>,
@@ -1426,6 +1551,8 @@ impl BatchExecutor {
             None
         };
 
+        let mut attempts_made = 0;
+        let mut retry_budget_exhausted = false;
         for attempt in 1..=max_attempts {
             // Check cancellation
             if cancel_token.is_cancelled() {
@@ -1435,6 +1562,23 @@ impl BatchExecutor {
                 return;
             }
 
+            if attempt > 1 && !limits.try_consume_retry() {
+                retry_budget_exhausted = true;
+                break;
+            }
+
+            // Released at the end of the attempt, including on retry
+            let _slot = match limits.acquire(&cancel_token).await {
+                Some(slot) => slot,
+                None => {
+                    let mut s = state.write().await;
+                    s.story_states
+                        .insert(story_id.to_string(), StoryExecutionState::Cancelled);
+                    return;
+                }
+            };
+            attempts_made = attempt;
+
             // Update state to Running with current attempt
             {
                 let mut s = state.write().await;
@@ -2030,13 +2174,21 @@ impl BatchExecutor {
         }
 
         // All retries exhausted -- mark as failed
+        if retry_budget_exhausted {
+            tracing::warn!(
+                story_id = %story_id,
+                attempts = attempts_made,
+                "Shared retry budget exhausted -- not retrying story"
+            );
+            last_error = format!("{} (shared retry budget exhausted)", last_error);
+        }
         {
             let mut s = state.write().await;
             s.story_states.insert(
                 story_id.to_string(),
                 StoryExecutionState::Failed {
                     reason: last_error.clone(),
-                    attempts: max_attempts,
+                    attempts: attempts_made,
                     last_agent: current_agent.clone(),
                 },
             );
@@ -2360,11 +2512,15 @@ mod tests {
             total_stories: 5,
             story_statuses: HashMap::new(),
             current_phase: "executing".to_string(),
+            active_concurrency: 1,
+            retry_budget_remaining: Some(2),
         };
         let json = serde_json::to_string(&progress).unwrap();
         assert!(json.contains("\"currentBatch\""));
         assert!(json.contains("\"totalBatches\""));
         assert!(json.contains("\"storiesCompleted\""));
+        assert!(json.contains("\"activeConcurrency\":1"));
+        assert!(json.contains("\"retryBudgetRemaining\":2"));
     }
 
     #[test]
@@ -2814,6 +2970,172 @@ mod tests {
         assert_eq!(story_started_count, 3);
     }
 
+    // ========================================================================
+    // Shared Execution Limits Tests
+    // ========================================================================
+
+    /// Story executor that records peak concurrency and optionally fails.
+    fn tracking_story_executor(
+        active: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+        calls: Arc<AtomicUsize>,
+        succeed: bool,
+    ) -> impl Fn(StoryExecutionContext) -> Pin<Box<dyn Future<Output = StoryExecutionOutcome> + Send>>
+           + Send
+           + Sync
+           + Clone {
+        move |_ctx| {
+            let active = active.clone();
+            let peak = peak.clone();
+            let calls = calls.clone();
+            Box::pin(async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+                active.fetch_sub(1, Ordering::SeqCst);
+                StoryExecutionOutcome {
+                    success: succeed,
+                    error: (!succeed).then(|| "flaky".to_string()),
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_global_concurrency_capped_across_batches() {
+        // Two executors, each with two batches, share one semaphore of 2.
+        let semaphore = Arc::new(Semaphore::new(2));
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let build = |prefix: &str| {
+            let id = |n: u32| format!("{prefix}{n}");
+            let stories = vec![
+                story(&id(1), vec![]),
+                story(&id(2), vec![]),
+                story(&id(3), vec![]),
+                story(&id(4), vec![id(1).as_str()]),
+                story(&id(5), vec![id(2).as_str()]),
+            ];
+            let config = ExecutionConfig {
+                quality_gates_enabled: false,
+                concurrency_semaphore: Some(semaphore.clone()),
+                ..Default::default()
+            };
+            BatchExecutor::new(stories, config, CancellationToken::new())
+        };
+        let first = build("a");
+        let second = build("b");
+        let resolver = AgentResolver::with_defaults();
+        let path = std::path::PathBuf::from("/tmp");
+        let executor_fn = tracking_story_executor(active, peak.clone(), calls.clone(), true);
+
+        let (first_result, second_result) = tokio::join!(
+            first.execute("s-a", &resolver, path.clone(), |_| {}, executor_fn.clone()),
+            second.execute("s-b", &resolver, path, |_| {}, executor_fn),
+        );
+
+        assert_eq!(first_result.unwrap().completed, 5);
+        assert_eq!(second_result.unwrap().completed, 5);
+        assert_eq!(calls.load(Ordering::SeqCst), 10);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(first.get_progress().await.active_concurrency, 0);
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_stories_caps_parallel_batch() {
+        let stories = (1..=6).map(|i| story(&format!("s{i}"), vec![])).collect();
+        let config = ExecutionConfig {
+            max_parallel: 6,
+            max_concurrent_stories: Some(3),
+            quality_gates_enabled: false,
+            ..Default::default()
+        };
+        let executor = BatchExecutor::new(stories, config, CancellationToken::new());
+        let limits = executor.limits();
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let reported_peak = Arc::new(AtomicUsize::new(0));
+        let inner =
+            tracking_story_executor(active, peak.clone(), Arc::new(AtomicUsize::new(0)), true);
+        let reported = reported_peak.clone();
+        let story_executor = move |ctx: StoryExecutionContext| {
+            reported.fetch_max(limits.active_concurrency(), Ordering::SeqCst);
+            inner(ctx)
+        };
+
+        let result = executor
+            .execute(
+                "test-session",
+                &AgentResolver::with_defaults(),
+                std::path::PathBuf::from("/tmp"),
+                |_| {},
+                story_executor,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(result.completed, 6);
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+        assert_eq!(reported_peak.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_shared_retry_budget_enforced() {
+        let stories = vec![
+            story("s1", vec![]),
+            story("s2", vec![]),
+            story("s3", vec![]),
+        ];
+        let config = ExecutionConfig {
+            max_retries: 2,
+            retry_budget: Some(3),
+            quality_gates_enabled: false,
+            ..Default::default()
+        };
+        let executor = BatchExecutor::new(stories, config, CancellationToken::new());
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let result = executor
+            .execute(
+                "test-session",
+                &AgentResolver::with_defaults(),
+                std::path::PathBuf::from("/tmp"),
+                |_| {},
+                tracking_story_executor(
+                    Arc::new(AtomicUsize::new(0)),
+                    Arc::new(AtomicUsize::new(0)),
+                    calls.clone(),
+                    false,
+                ),
+            )
+            .await
+            .unwrap();
+
+        // Without the budget each story would run 3 times (9 calls).
+        assert_eq!(result.failed, 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 6);
+        let attempts: u32 = result
+            .story_results
+            .values()
+            .map(|state| match state {
+                StoryExecutionState::Failed { attempts, .. } => *attempts,
+                other => panic!("unexpected state {other:?}"),
+            })
+            .sum();
+        assert_eq!(attempts, 6);
+        assert!(result.story_results.values().any(|state| matches!(
+            state,
+            StoryExecutionState::Failed { reason, .. } if reason.contains("retry budget exhausted")
+        )));
+
+        let progress = executor.get_progress().await;
+        assert_eq!(progress.retry_budget_remaining, Some(0));
+        assert_eq!(progress.active_concurrency, 0);
+    }
+
     #[tokio::test]
     async fn test_execute_progress_events_include_required_fields() {
         let stories = vec![story("s1", vec![])];
@@ -2962,6 +3284,7 @@ mod tests {
                 description: "Default agent".to_string(),
                 available: true,
                 suitable_phases: vec![ExecutionPhase::Implementation, ExecutionPhase::Retry],
+                capabilities: None,
             },
         );
         agents.insert(
@@ -2971,6 +3294,7 @@ mod tests {
                 description: "Specialized bugfix retry agent".to_string(),
                 available: true,
                 suitable_phases: vec![ExecutionPhase::Retry],
+                capabilities: None,
             },
         );
 
@@ -2981,6 +3305,7 @@ mod tests {
                 default_agent: Some("default-agent".to_string()),
                 fallback_chain: vec![],
                 story_type_overrides: HashMap::new(),
+                required_capabilities: Vec::new(),
             },
         );
         phase_defaults.insert(
@@ -2994,6 +3319,7 @@ mod tests {
                     m.insert("bugfix".to_string(), "bugfix-retry-agent".to_string());
                     m
                 },
+                required_capabilities: Vec::new(),
             },
         );
 
@@ -263,13 +263,17 @@ async fn ensure_knowledge_initialized(knowledge_state: &KnowledgeState, app_stat
         }
     };
 
-    let (emb_config, is_tfidf) = match app_state
+    let (emb_config, is_tfidf, cross_encoder_config) = match app_state
         .with_keyring(|keyring| {
             let (config, _dim, is_tfidf) =
                 crate::services::orchestrator::embedding_config_builder::build_embedding_config_from_settings(
                     &db, keyring,
                 );
-            Ok((config, is_tfidf))
+            let cross_encoder_config =
+                crate::services::knowledge::reranker::CrossEncoderConfig::from_settings(
+                    &db, keyring,
+                );
+            Ok((config, is_tfidf, cross_encoder_config))
         })
         .await
     {
@@ -281,7 +285,7 @@ async fn ensure_knowledge_initialized(knowledge_state: &KnowledgeState, app_stat
     };
 
     if let Err(e) = knowledge_state
-        .initialize_with_config(db, emb_config, is_tfidf)
+        .initialize_with_config(db, emb_config, is_tfidf, cross_encoder_config)
         .await
     {
         tracing::warn!(
@@ -822,6 +826,7 @@ fn select_skill_matches_by_ids(
         .map(|doc| SkillMatch {
             score: 1.0,
             match_reason: crate::services::skills::model::MatchReason::UserForced,
+            explanation: None,
             skill: doc.to_summary(false),
         })
         .collect()
@@ -1269,6 +1274,8 @@ fn generated_record_to_skill_document(record: GeneratedSkillRecord) -> SkillDocu
         license: None,
         metadata: std::collections::HashMap::new(),
         hooks: None,
+        requires: vec![],
+        conflicts_with: vec![],
         source: SkillSource::Generated,
         priority: 0,
         detect: None,
@@ -0,0 +1,449 @@
+//! Exploration Result Cache
+//!
+//! Caches task-mode exploration results on disk, keyed by project, flow level
+//! and task description. Each entry records the repo-state signature it was
+//! produced under: the git HEAD plus a content hash of every dirty file.
+//!
+//! A cached result is reused only while the signature matches. When it
+//! changes the entry is invalidated, and the files that differ (commits since
+//! the cached HEAD plus dirty files whose content changed) are reported so
+//! the caller can re-explore just those areas on top of the previous result.
+//! Directories that are not git repositories are never cached.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::exploration::ExplorationResult;
+use crate::services::worktree::GitOps;
+use crate::utils::error::AppResult;
+
+/// Above this many changed files a full re-exploration is cheaper than an
+/// incremental one.
+pub const MAX_INCREMENTAL_CHANGED_FILES: usize = 200;
+
+/// Content hash recorded for dirty paths that no longer exist on disk.
+const DELETED_MARKER: &str = "deleted";
+
+/// Git state of a project at one point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoState {
+    /// Commit checked out, `None` for a repository without commits
+    pub head: Option<String>,
+    /// Dirty and untracked paths mapped to a hash of their content
+    pub dirty: BTreeMap<String, String>,
+}
+
+impl RepoState {
+    /// Capture the state of the repository containing `project_root`.
+    /// Returns `None` when it is not a git repository.
+    pub fn capture(project_root: &Path) -> Option<Self> {
+        let git = GitOps::new();
+        let status = git
+            .execute(
+                project_root,
+                &["status", "--porcelain", "-z", "--untracked-files=all"],
+            )
+            .ok()?
+            .into_result()
+            .ok()?;
+        let head = git
+            .execute(project_root, &["rev-parse", "--verify", "-q", "HEAD"])
+            .ok()
+            .filter(|r| r.success)
+            .map(|r| r.stdout.trim().to_string())
+            .filter(|h| !h.is_empty());
+        // Porcelain paths are relative to the repository root.
+        let repo_root = git
+            .get_repo_root(project_root)
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| project_root.to_path_buf());
+
+        let mut dirty = BTreeMap::new();
+        let mut entries = status.split('\0');
+        while let Some(entry) = entries.next() {
+            if entry.len() < 4 {
+                continue;
+            }
+            let (code, path) = entry.split_at(3);
+            if code.contains('R') || code.contains('C') {
+                // Renames and copies are followed by the original path.
+                entries.next();
+            }
+            let hash = match fs::read(repo_root.join(path)) {
+                Ok(bytes) => sha256_hex(&bytes),
+                Err(_) => DELETED_MARKER.to_string(),
+            };
+            dirty.insert(path.to_string(), hash);
+        }
+        Some(Self { head, dirty })
+    }
+
+    /// Stable signature of this state.
+    pub fn signature(&self) -> String {
+        let mut material = format!("head:{}\n", self.head.as_deref().unwrap_or("none"));
+        for (path, hash) in &self.dirty {
+            material.push_str(&format!("{}\t{}\n", path, hash));
+        }
+        sha256_hex(material.as_bytes())
+    }
+}
+
+/// A cached exploration result and the repo state it was produced under.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedExploration {
+    pub signature: String,
+    pub head: Option<String>,
+    pub dirty: BTreeMap<String, String>,
+    pub result: ExplorationResult,
+    /// RFC 3339 timestamp of when the entry was written
+    pub cached_at: String,
+}
+
+/// Outcome of looking up a cache entry.
+#[derive(Debug, Clone)]
+pub enum CacheLookup {
+    /// The repo state is unchanged; the cached result can be reused
+    Hit(CachedExploration),
+    /// The repo state changed. The entry has been invalidated; `changed_files`
+    /// lists what differs, or is `None` when that could not be determined.
+    Stale {
+        previous: CachedExploration,
+        changed_files: Option<Vec<String>>,
+    },
+    Miss,
+}
+
+/// How an exploration result was obtained, reported through task status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExplorationCacheOutcome {
+    /// Served from the cache without exploring
+    Reused,
+    /// Re-explored only the changed areas on top of the previous result
+    Incremental,
+    /// Explored from scratch and cached
+    Refreshed,
+    /// Explored without caching: not a git repository, or LLM exploration
+    /// failed and the result is degraded
+    Uncached,
+}
+
+/// Cache details for the latest exploration of a task-mode session.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExplorationCacheStatus {
+    pub outcome: ExplorationCacheOutcome,
+    /// Repo-state signature the result belongs to
+    pub signature: Option<String>,
+    /// Files re-explored by an incremental update
+    #[serde(default)]
+    pub changed_files: Vec<String>,
+    /// When the result in use was first cached
+    pub cached_at: Option<String>,
+}
+
+/// On-disk store of exploration results.
+#[derive(Debug, Clone)]
+pub struct ExplorationCache {
+    root: PathBuf,
+}
+
+impl ExplorationCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Key for an exploration of `project_root` at `flow_level` for a task.
+    pub fn cache_key(project_root: &Path, flow_level: &str, task_description: &str) -> String {
+        let material = format!(
+            "{}\n{}\n{}",
+            project_root.to_string_lossy(),
+            flow_level,
+            task_description.trim()
+        );
+        sha256_hex(material.as_bytes())
+    }
+
+    /// Look up `key` against the current repo state. A stale entry is
+    /// removed before it is returned.
+    pub fn lookup(&self, key: &str, project_root: &Path, current: &RepoState) -> CacheLookup {
+        let Some(entry) = self.read(key) else {
+            return CacheLookup::Miss;
+        };
+        if entry.signature == current.signature() {
+            return CacheLookup::Hit(entry);
+        }
+        self.invalidate(key);
+        let changed_files = changed_files(project_root, &entry, current);
+        CacheLookup::Stale {
+            previous: entry,
+            changed_files,
+        }
+    }
+
+    /// Store `result` for `key` under the repo state it was produced from.
+    pub fn store(
+        &self,
+        key: &str,
+        state: &RepoState,
+        result: &ExplorationResult,
+    ) -> AppResult<CachedExploration> {
+        let entry = CachedExploration {
+            signature: state.signature(),
+            head: state.head.clone(),
+            dirty: state.dirty.clone(),
+            result: result.clone(),
+            cached_at: chrono::Utc::now().to_rfc3339(),
+        };
+        fs::create_dir_all(&self.root)?;
+        fs::write(self.entry_path(key), serde_json::to_vec_pretty(&entry)?)?;
+        Ok(entry)
+    }
+
+    pub fn invalidate(&self, key: &str) {
+        let _ = fs::remove_file(self.entry_path(key));
+    }
+
+    fn read(&self, key: &str) -> Option<CachedExploration> {
+        let bytes = fs::read(self.entry_path(key)).ok()?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.root.join(format!("{}.json", key))
+    }
+}
+
+/// Files that differ between a cached entry and the current state: paths
+/// touched by commits between the two HEADs, plus dirty paths whose content
+/// hash changed. `None` when the commit range cannot be diffed, e.g. after
+/// a history rewrite.
+pub fn changed_files(
+    project_root: &Path,
+    previous: &CachedExploration,
+    current: &RepoState,
+) -> Option<Vec<String>> {
+    let mut changed = BTreeSet::new();
+    if previous.head != current.head {
+        let (Some(from), Some(to)) = (&previous.head, &current.head) else {
+            return None;
+        };
+        let range = format!("{}..{}", from, to);
+        let output = GitOps::new()
+            .execute(project_root, &["diff", "--name-only", &range])
+            .ok()?
+            .into_result()
+            .ok()?;
+        changed.extend(
+            output
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(str::to_string),
+        );
+    }
+    for (path, hash) in &current.dirty {
+        if previous.dirty.get(path) != Some(hash) {
+            changed.insert(path.clone());
+        }
+    }
+    for path in previous.dirty.keys() {
+        if !current.dirty.contains_key(path) {
+            changed.insert(path.clone());
+        }
+    }
+    Some(changed.into_iter().collect())
+}
+
+/// Task message for re-exploring only `changed_files` on top of a previous
+/// exploration summary.
+pub fn build_incremental_exploration_message(
+    task_description: &str,
+    previous_summary: &str,
+    changed_files: &[String],
+) -> String {
+    let files = changed_files
+        .iter()
+        .map(|f| format!("- {}", f))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        "Update an earlier exploration of this project's codebase for the following task:\n\n{}\n\n\
+         Since that exploration, only these files changed:\n{}\n\n\
+         Previous exploration summary:\n{}\n\n\
+         Explore only the changed files and the areas they affect. Return the complete updated \
+         summary in the same format, keeping findings about unchanged areas as they are.",
+        task_description, files, previous_summary
+    )
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::task_mode::exploration::{
+        SummaryQuality, SummarySource, TechStackSummary,
+    };
+    use tempfile::TempDir;
+
+    fn git(cwd: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(cwd)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn init_repo() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        git(dir.path(), &["init", "-q", "-b", "main"]);
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/lib.rs"), "pub fn a() {}\n").unwrap();
+        fs::write(dir.path().join("README.md"), "hello\n").unwrap();
+        git(dir.path(), &["add", "."]);
+        git(dir.path(), &["commit", "-q", "-m", "init"]);
+        dir
+    }
+
+    fn result(summary: &str) -> ExplorationResult {
+        ExplorationResult {
+            tech_stack: TechStackSummary {
+                languages: vec!["rust".to_string()],
+                frameworks: vec![],
+                build_tools: vec![],
+                test_frameworks: vec![],
+                package_manager: None,
+            },
+            key_files: vec![],
+            components: vec![],
+            patterns: vec![],
+            llm_summary: Some(summary.to_string()),
+            summary_quality: SummaryQuality::Complete,
+            summary_source: SummarySource::Llm,
+            summary_notes: None,
+            duration_ms: 1200,
+            used_llm_exploration: true,
+        }
+    }
+
+    #[test]
+    fn test_unchanged_repo_reuses_cached_result() {
+        let repo = init_repo();
+        let cache_dir = TempDir::new().unwrap();
+        let cache = ExplorationCache::new(cache_dir.path());
+        let key = ExplorationCache::cache_key(repo.path(), "full", "Add login");
+
+        let state = RepoState::capture(repo.path()).unwrap();
+        assert!(state.head.is_some());
+        assert!(state.dirty.is_empty());
+        assert!(matches!(
+            cache.lookup(&key, repo.path(), &state),
+            CacheLookup::Miss
+        ));
+        cache
+            .store(&key, &state, &result("auth lives in src"))
+            .unwrap();
+
+        // A second exploration with nothing changed is a hit, repeatedly.
+        for _ in 0..2 {
+            let again = RepoState::capture(repo.path()).unwrap();
+            assert_eq!(again.signature(), state.signature());
+            match cache.lookup(&key, repo.path(), &again) {
+                CacheLookup::Hit(entry) => {
+                    assert_eq!(
+                        entry.result.llm_summary.as_deref(),
+                        Some("auth lives in src")
+                    );
+                }
+                other => panic!("expected cache hit, got {:?}", other),
+            }
+        }
+
+        // Other flow levels and tasks are cached separately.
+        let other = ExplorationCache::cache_key(repo.path(), "standard", "Add login");
+        assert!(matches!(
+            cache.lookup(&other, repo.path(), &state),
+            CacheLookup::Miss
+        ));
+    }
+
+    #[test]
+    fn test_edit_invalidates_and_reports_changed_files() {
+        let repo = init_repo();
+        let cache_dir = TempDir::new().unwrap();
+        let cache = ExplorationCache::new(cache_dir.path());
+        let key = ExplorationCache::cache_key(repo.path(), "full", "Add login");
+        let state = RepoState::capture(repo.path()).unwrap();
+        cache.store(&key, &state, &result("v1")).unwrap();
+
+        fs::write(repo.path().join("src/lib.rs"), "pub fn b() {}\n").unwrap();
+        let edited = RepoState::capture(repo.path()).unwrap();
+        assert_ne!(edited.signature(), state.signature());
+        match cache.lookup(&key, repo.path(), &edited) {
+            CacheLookup::Stale {
+                previous,
+                changed_files,
+            } => {
+                assert_eq!(previous.result.llm_summary.as_deref(), Some("v1"));
+                assert_eq!(changed_files, Some(vec!["src/lib.rs".to_string()]));
+            }
+            other => panic!("expected stale entry, got {:?}", other),
+        }
+        // The stale entry is gone.
+        assert!(matches!(
+            cache.lookup(&key, repo.path(), &edited),
+            CacheLookup::Miss
+        ));
+
+        // Editing an already-dirty file changes the signature again, and
+        // committing reports files from the commit range.
+        cache.store(&key, &edited, &result("v2")).unwrap();
+        fs::write(repo.path().join("src/lib.rs"), "pub fn c() {}\n").unwrap();
+        let reedited = RepoState::capture(repo.path()).unwrap();
+        assert_ne!(reedited.signature(), edited.signature());
+
+        cache.store(&key, &reedited, &result("v3")).unwrap();
+        fs::write(repo.path().join("README.md"), "changed\n").unwrap();
+        git(repo.path(), &["add", "."]);
+        git(repo.path(), &["commit", "-q", "-m", "edit"]);
+        let committed = RepoState::capture(repo.path()).unwrap();
+        assert!(committed.dirty.is_empty());
+        match cache.lookup(&key, repo.path(), &committed) {
+            CacheLookup::Stale { changed_files, .. } => assert_eq!(
+                changed_files,
+                Some(vec!["README.md".to_string(), "src/lib.rs".to_string()])
+            ),
+            other => panic!("expected stale entry, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_git_directory_is_not_cacheable() {
+        let dir = TempDir::new().unwrap();
+        assert!(RepoState::capture(dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_incremental_message_lists_changed_files() {
+        let message = build_incremental_exploration_message(
+            "Add login",
+            "Auth is in src/auth.rs",
+            &["src/auth.rs".to_string()],
+        );
+        assert!(message.contains("Add login"));
+        assert!(message.contains("- src/auth.rs"));
+        assert!(message.contains("Auth is in src/auth.rs"));
+    }
+}
@@ -6,14 +6,16 @@
 //! - Task mode session management types
 
 pub mod agent_resolver;
+pub mod analysis_cache;
 pub mod batch_executor;
 pub mod context_provider;
 pub mod exploration;
+pub mod exploration_cache;
 pub mod prd_generator;
 
 pub use agent_resolver::{
-    AgentAssignment, AgentOverrides, AgentResolver, AgentsConfig, ExecutionPhase,
-    PhaseConfig as AgentPhaseConfig, StoryType,
+    AgentAssignment, AgentCapability, AgentOverrides, AgentResolver, AgentsConfig, ExecutionPhase,
+    OverrideMismatch, PhaseConfig as AgentPhaseConfig, ResolutionStep, StoryType,
 };
 pub use batch_executor::{
     calculate_batches, BatchExecutionProgress, BatchExecutionResult, BatchExecutor,
@@ -4,11 +4,12 @@
 //! Implements retry-with-repair per ADR-F002: on JSON parse failure, retries once with
 //! a repair prompt that includes the parse error and original response.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use tracing::debug;
 
-use crate::commands::task_mode::{ConversationTurnInput, TaskPrd, TaskStory};
+use crate::commands::task_mode::{ConversationTurnInput, TaskPrd, TaskPrdSection, TaskStory};
 use crate::services::analytics::send_message_tracked;
 use crate::services::llm::provider::LlmProvider;
 use crate::services::llm::types::{LlmRequestOptions, Message};
@@ -18,7 +19,7 @@ use crate::services::task_mode::calculate_batches;
 use crate::services::tools::extract_text_without_tool_calls;
 
 /// Default maximum parallel stories per batch for PRD generation.
-const DEFAULT_MAX_PARALLEL: usize = 4;
+pub const DEFAULT_MAX_PARALLEL: usize = 4;
 
 /// Token budget reserved for system prompt + PRD request + response.
 /// Increased from 4000 to 8000 to accommodate the two-step expert+formatter pipeline.
@@ -355,6 +356,150 @@ pub fn build_task_prd(task_description: &str, stories: Vec<TaskStory>) -> Result
         description: task_description.to_string(),
         stories,
         batches,
+        locked_sections: Vec::new(),
+    })
+}
+
+// ============================================================================
+// Regeneration with Locks
+// ============================================================================
+
+/// Describe the locked parts of a PRD for the LLM, so a regeneration does not
+/// duplicate locked stories or reuse their IDs.
+///
+/// Returns `None` when nothing is locked.
+pub fn build_locked_prd_context(previous: &TaskPrd) -> Option<String> {
+    if !previous.has_locks() {
+        return None;
+    }
+
+    let mut context = String::from(
+        "## Locked PRD Content\n\
+         The user is regenerating an existing PRD and has locked the parts below. \
+         They will be kept exactly as written.\n",
+    );
+    if previous.is_section_locked(TaskPrdSection::Title) {
+        context.push_str(&format!("\nLocked title: {}\n", previous.title));
+    }
+    if previous.is_section_locked(TaskPrdSection::Description) {
+        context.push_str(&format!("\nLocked description: {}\n", previous.description));
+    }
+
+    let locked: Vec<&TaskStory> = previous.stories.iter().filter(|s| s.locked).collect();
+    if !locked.is_empty() {
+        context.push_str(
+            "\nLocked stories (do NOT include them in your output and do NOT reuse their IDs; \
+             new stories may depend on them by ID):\n",
+        );
+        context.push_str(&serde_json::to_string_pretty(&locked).unwrap_or_else(|_| "[]".into()));
+        context.push('\n');
+    }
+
+    Some(context)
+}
+
+/// Merge a regenerated PRD into the previous one, keeping locked parts.
+///
+/// Locked sections and stories are copied from `previous` unchanged. Unlocked
+/// stories are replaced by the regenerated ones; a regenerated story that
+/// reuses a locked ID is dropped. Unlocked stories a locked story depends on
+/// are carried over when the regeneration no longer contains them, so locked
+/// dependencies stay valid. Dependencies of regenerated stories on unknown IDs
+/// are removed and batches are recalculated.
+pub fn merge_regenerated_prd(
+    previous: &TaskPrd,
+    regenerated: TaskPrd,
+    max_parallel: usize,
+) -> Result<TaskPrd, String> {
+    let previous_by_id: HashMap<&str, &TaskStory> = previous
+        .stories
+        .iter()
+        .map(|story| (story.id.as_str(), story))
+        .collect();
+    let locked_ids: HashSet<&str> = previous
+        .stories
+        .iter()
+        .filter(|story| story.locked)
+        .map(|story| story.id.as_str())
+        .collect();
+    let regenerated_ids: HashSet<&str> = regenerated
+        .stories
+        .iter()
+        .map(|story| story.id.as_str())
+        .filter(|id| !locked_ids.contains(id))
+        .collect();
+
+    let mut carried = locked_ids.clone();
+    let mut pending: Vec<&TaskStory> = previous.stories.iter().filter(|s| s.locked).collect();
+    while let Some(story) = pending.pop() {
+        for dependency in &story.dependencies {
+            let dependency = dependency.as_str();
+            if carried.contains(dependency) || regenerated_ids.contains(dependency) {
+                continue;
+            }
+            if let Some(previous_story) = previous_by_id.get(dependency) {
+                carried.insert(dependency);
+                pending.push(previous_story);
+            }
+        }
+    }
+
+    let mut stories: Vec<TaskStory> = previous
+        .stories
+        .iter()
+        .filter(|story| carried.contains(story.id.as_str()))
+        .cloned()
+        .collect();
+    let fresh: Vec<TaskStory> = regenerated
+        .stories
+        .into_iter()
+        .filter(|story| !carried.contains(story.id.as_str()))
+        .collect();
+    let known_ids: HashSet<String> = stories
+        .iter()
+        .chain(fresh.iter())
+        .map(|story| story.id.clone())
+        .collect();
+    stories.extend(fresh.into_iter().map(|mut story| {
+        let id = story.id.clone();
+        story
+            .dependencies
+            .retain(|dependency| dependency != &id && known_ids.contains(dependency));
+        story.locked = false;
+        story
+    }));
+
+    let executable: Vec<ExecutableStory> = stories
+        .iter()
+        .map(|s| ExecutableStory {
+            id: s.id.clone(),
+            title: s.title.clone(),
+            description: s.description.clone(),
+            dependencies: s.dependencies.clone(),
+            acceptance_criteria: s.acceptance_criteria.clone(),
+            agent: None,
+        })
+        .collect();
+    let batches = calculate_batches(&executable, max_parallel.max(1))
+        .map_err(|e| format!("Failed to calculate execution batches: {}", e))?;
+
+    let title = if previous.is_section_locked(TaskPrdSection::Title) {
+        previous.title.clone()
+    } else {
+        regenerated.title
+    };
+    let description = if previous.is_section_locked(TaskPrdSection::Description) {
+        previous.description.clone()
+    } else {
+        regenerated.description
+    };
+
+    Ok(TaskPrd {
+        title,
+        description,
+        stories,
+        batches,
+        locked_sections: previous.locked_sections.clone(),
     })
 }
 
@@ -719,6 +864,7 @@ pub fn convert_compiled_prd_to_task_prd(spec_value: serde_json::Value) -> Result
             priority,
             dependencies,
             acceptance_criteria,
+            locked: false,
         });
     }
 
@@ -743,6 +889,7 @@ pub fn convert_compiled_prd_to_task_prd(spec_value: serde_json::Value) -> Result
         description,
         stories,
         batches,
+        locked_sections: Vec::new(),
     })
 }
 
@@ -1075,6 +1222,7 @@ Hope this helps!"#;
                 priority: "high".to_string(),
                 dependencies: vec![],
                 acceptance_criteria: vec!["Compiles".to_string()],
+                locked: false,
             },
             TaskStory {
                 id: "story-002".to_string(),
@@ -1083,6 +1231,7 @@ Hope this helps!"#;
                 priority: "medium".to_string(),
                 dependencies: vec!["story-001".to_string()],
                 acceptance_criteria: vec!["Feature works".to_string()],
+                locked: false,
             },
         ];
 
@@ -1106,6 +1255,7 @@ Hope this helps!"#;
                 priority: "high".to_string(),
                 dependencies: vec!["story-002".to_string()],
                 acceptance_criteria: vec![],
+                locked: false,
             },
             TaskStory {
                 id: "story-002".to_string(),
@@ -1114,6 +1264,7 @@ Hope this helps!"#;
                 priority: "high".to_string(),
                 dependencies: vec!["story-001".to_string()],
                 acceptance_criteria: vec![],
+                locked: false,
             },
         ];
 
@@ -1132,6 +1283,7 @@ Hope this helps!"#;
                 priority: "high".to_string(),
                 dependencies: vec![],
                 acceptance_criteria: vec![],
+                locked: false,
             },
             TaskStory {
                 id: "story-002".to_string(),
@@ -1140,6 +1292,7 @@ Hope this helps!"#;
                 priority: "high".to_string(),
                 dependencies: vec![],
                 acceptance_criteria: vec![],
+                locked: false,
             },
             TaskStory {
                 id: "story-003".to_string(),
@@ -1148,6 +1301,7 @@ Hope this helps!"#;
                 priority: "medium".to_string(),
                 dependencies: vec![],
                 acceptance_criteria: vec![],
+                locked: false,
             },
         ];
 
@@ -1157,6 +1311,130 @@ Hope this helps!"#;
         assert_eq!(prd.batches[0].story_ids.len(), 3);
     }
 
+    // ========================================================================
+    // Regeneration with Locks Tests
+    // ========================================================================
+
+    fn story(id: &str, title: &str, dependencies: &[&str], criteria: &[&str]) -> TaskStory {
+        TaskStory {
+            id: id.to_string(),
+            title: title.to_string(),
+            description: format!("{} description", title),
+            priority: "medium".to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            acceptance_criteria: criteria.iter().map(|c| c.to_string()).collect(),
+            locked: false,
+        }
+    }
+
+    fn locked_prd() -> TaskPrd {
+        let mut refined = story(
+            "story-001",
+            "Schema",
+            &[],
+            &[
+                "users table has a unique email index",
+                "migration is reversible",
+            ],
+        );
+        refined.priority = "high".to_string();
+        refined.locked = true;
+        let mut prd = build_task_prd(
+            "Build auth",
+            vec![
+                refined,
+                story("story-002", "Login API", &["story-001"], &["login works"]),
+                story("story-003", "Logout API", &["story-002"], &["logout works"]),
+            ],
+        )
+        .unwrap();
+        prd.title = "Auth service".to_string();
+        prd.locked_sections = vec![TaskPrdSection::Title];
+        prd
+    }
+
+    #[tokio::test]
+    async fn test_regeneration_keeps_locked_story_and_regenerates_unlocked() {
+        let previous = locked_prd();
+        // The LLM ignores the lock hint and re-emits story-001 with other criteria.
+        let mock_response = r#"[
+            {"id": "story-001", "title": "Schema v2", "description": "", "priority": "low",
+             "dependencies": [], "acceptanceCriteria": ["replaced"]},
+            {"id": "story-002", "title": "Login endpoint", "description": "JWT login",
+             "priority": "high", "dependencies": ["story-001"], "acceptanceCriteria": ["returns token"]},
+            {"id": "story-004", "title": "Password reset", "description": "Reset flow",
+             "priority": "medium", "dependencies": ["story-002", "story-999"], "acceptanceCriteria": ["email sent"]}
+        ]"#;
+        let provider = Arc::new(MockLlmProvider::with_text_response(mock_response));
+        let regenerated = generate_prd_with_llm(
+            provider,
+            "Build auth",
+            &[],
+            200_000,
+            build_locked_prd_context(&previous).as_deref(),
+        )
+        .await
+        .unwrap();
+
+        let merged = merge_regenerated_prd(&previous, regenerated, DEFAULT_MAX_PARALLEL).unwrap();
+
+        let ids: Vec<&str> = merged.stories.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["story-001", "story-002", "story-004"]);
+        assert_eq!(
+            serde_json::to_value(&merged.stories[0]).unwrap(),
+            serde_json::to_value(&previous.stories[0]).unwrap()
+        );
+        assert_eq!(merged.stories[1].title, "Login endpoint");
+        assert_eq!(merged.stories[1].acceptance_criteria, vec!["returns token"]);
+        assert!(!merged.stories[1].locked);
+        assert_eq!(merged.stories[2].dependencies, vec!["story-002"]);
+
+        // The locked title survives; the unlocked description is regenerated.
+        assert_eq!(merged.title, "Auth service");
+        assert_eq!(merged.description, "Build auth");
+        assert_eq!(merged.locked_sections, vec![TaskPrdSection::Title]);
+        assert_eq!(merged.batches.len(), 3);
+        assert_eq!(merged.batches[0].story_ids, vec!["story-001"]);
+    }
+
+    #[test]
+    fn test_merge_carries_over_unlocked_dependency_of_locked_story() {
+        let mut previous = locked_prd();
+        previous.stories[0].locked = false;
+        previous.stories[1].locked = true;
+
+        let regenerated = build_task_prd(
+            "Build auth",
+            vec![story(
+                "story-010",
+                "Audit log",
+                &["story-002"],
+                &["events stored"],
+            )],
+        )
+        .unwrap();
+        let merged = merge_regenerated_prd(&previous, regenerated, DEFAULT_MAX_PARALLEL).unwrap();
+
+        // story-001 is not locked but the locked story-002 depends on it.
+        let ids: Vec<&str> = merged.stories.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["story-001", "story-002", "story-010"]);
+        assert_eq!(merged.stories[1].dependencies, vec!["story-001"]);
+        assert_eq!(merged.stories[2].dependencies, vec!["story-002"]);
+    }
+
+    #[test]
+    fn test_locked_prd_context_lists_locked_parts_only() {
+        let mut previous = locked_prd();
+        let context = build_locked_prd_context(&previous).unwrap();
+        assert!(context.contains("Locked title: Auth service"));
+        assert!(context.contains("unique email index"));
+        assert!(!context.contains("Logout API"));
+
+        previous.locked_sections.clear();
+        previous.stories[0].locked = false;
+        assert!(build_locked_prd_context(&previous).is_none());
+    }
+
     // ========================================================================
     // LLM Integration Tests (with mock)
     // ========================================================================
@@ -306,6 +306,58 @@ impl TimelineService {
         Ok(())
     }
 
+    /// Copy the checkpoints accepted by `keep` into the timeline of a forked
+    /// session. Branches are kept only when their parent checkpoint survives.
+    /// Returns the number of checkpoints copied.
+    pub fn fork_timeline(
+        &self,
+        project_path: &str,
+        source_session_id: &str,
+        new_session_id: &str,
+        keep: impl Fn(&Checkpoint) -> bool,
+    ) -> AppResult<u32> {
+        let source = self.load_metadata(project_path, source_session_id)?;
+        let checkpoints: Vec<Checkpoint> = source
+            .checkpoints
+            .iter()
+            .filter(|cp| keep(cp))
+            .cloned()
+            .map(|mut cp| {
+                cp.session_id = new_session_id.to_string();
+                cp
+            })
+            .collect();
+        if checkpoints.is_empty() {
+            return Ok(0);
+        }
+
+        let branches: Vec<CheckpointBranch> = source
+            .branches
+            .into_iter()
+            .filter(|b| checkpoints.iter().any(|cp| cp.id == b.parent_checkpoint_id))
+            .collect();
+        let current_checkpoint_id = source
+            .current_checkpoint_id
+            .filter(|id| checkpoints.iter().any(|cp| &cp.id == id))
+            .or_else(|| checkpoints.last().map(|cp| cp.id.clone()));
+        let current_branch_id = source
+            .current_branch_id
+            .filter(|id| branches.iter().any(|b| &b.id == id))
+            .or_else(|| branches.iter().find(|b| b.is_main).map(|b| b.id.clone()));
+
+        let count = checkpoints.len() as u32;
+        let metadata = TimelineMetadata {
+            session_id: new_session_id.to_string(),
+            checkpoints,
+            branches,
+            current_checkpoint_id,
+            current_branch_id,
+        };
+        self.save_metadata(project_path, &metadata)?;
+
+        Ok(count)
+    }
+
     // ========== Branch Management Methods (Story-002) ==========
 
     /// Fork a new branch from a checkpoint
@@ -1146,6 +1198,45 @@ mod tests {
         cleanup_temp_project(&temp_dir);
     }
 
+    #[test]
+    fn test_fork_timeline_keeps_selected_checkpoints() {
+        let service = TimelineService::new();
+        let temp_dir = create_temp_project();
+        let project_path = temp_dir.to_string_lossy().to_string();
+
+        let first = service
+            .create_checkpoint(&project_path, "sess1", "First", &[])
+            .unwrap();
+        service
+            .create_checkpoint(&project_path, "sess1", "Second", &[])
+            .unwrap();
+
+        let count = service
+            .fork_timeline(&project_path, "sess1", "sess2", |cp| cp.label == "First")
+            .unwrap();
+        assert_eq!(count, 1);
+
+        let forked = service.get_timeline(&project_path, "sess2").unwrap();
+        assert_eq!(forked.checkpoints.len(), 1);
+        assert_eq!(forked.checkpoints[0].session_id, "sess2");
+        assert_eq!(forked.current_checkpoint_id, Some(first.id.clone()));
+        // The main branch starts at the first checkpoint, so it survives
+        assert_eq!(forked.branches.len(), 1);
+        assert_eq!(
+            forked.current_branch_id,
+            Some(forked.branches[0].id.clone())
+        );
+        assert_eq!(
+            service
+                .list_checkpoints(&project_path, "sess1", None)
+                .unwrap()
+                .len(),
+            2
+        );
+
+        cleanup_temp_project(&temp_dir);
+    }
+
     #[test]
     fn test_fork_branch_duplicate_name() {
         let service = TimelineService::new();
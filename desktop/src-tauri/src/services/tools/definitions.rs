@@ -10,7 +10,7 @@ use crate::services::llm::types::ToolDefinition;
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
-use super::trait_def::ToolRegistry;
+use super::trait_def::{ToolFilterContext, ToolRegistry};
 
 /// Lazily-initialized global tool registry.
 ///
@@ -29,11 +29,20 @@ pub(crate) fn cached_registry() -> &'static ToolRegistry {
 /// auto-generated from the `Tool` trait implementations and always in sync.
 /// Uses a cached `OnceLock<ToolRegistry>` to avoid rebuilding on every call.
 pub fn get_tool_definitions_from_registry() -> Vec<ToolDefinition> {
-    let mut defs = cached_registry().definitions();
+    get_tool_definitions_for_context(&ToolFilterContext::default())
+}
+
+/// Get the tool definitions exposed under a filter context.
+///
+/// Tools the context hides (for example mutating tools in a read-only
+/// session) are left out so the model never sees a tool it cannot use.
+pub fn get_tool_definitions_for_context(filter_ctx: &ToolFilterContext) -> Vec<ToolDefinition> {
+    let mut defs = cached_registry().filtered_definitions(filter_ctx);
     let mut seen: HashSet<String> = defs.iter().map(|d| d.name.clone()).collect();
 
     for def in super::runtime_tools::definitions() {
-        if seen.insert(def.name.clone()) {
+        let mutating = super::runtime_tools::is_mutating(&def.name).unwrap_or(true);
+        if filter_ctx.allows(&def.name, mutating) && seen.insert(def.name.clone()) {
             defs.push(def);
         }
     }
@@ -192,4 +201,32 @@ mod tests {
         assert!(first_names.contains("Write"));
         assert!(second_names.is_superset(&first_names) || first_names.is_superset(&second_names));
     }
+
+    #[test]
+    fn test_read_only_context_hides_mutating_tools() {
+        let defs = get_tool_definitions_for_context(&ToolFilterContext::read_only());
+        let names: Vec<&str> = defs.iter().map(|d| d.name.as_str()).collect();
+        for hidden in ["Write", "Edit", "Bash"] {
+            assert!(!names.contains(&hidden), "read-only must hide {}", hidden);
+        }
+        assert!(names.contains(&"Read"));
+        assert!(names.contains(&"Grep"));
+    }
+
+    #[test]
+    fn test_plan_mode_context_hides_write_and_bash() {
+        let defs = get_tool_definitions_for_context(&ToolFilterContext::plan_mode());
+        let names: Vec<&str> = defs.iter().map(|d| d.name.as_str()).collect();
+        assert!(!names.contains(&"Write"));
+        assert!(!names.contains(&"Bash"));
+        assert!(names.contains(&"Read"));
+
+        // The same rule is inert outside the planning phase
+        let ctx = ToolFilterContext {
+            execution_phase: Some("implementation".to_string()),
+            ..ToolFilterContext::plan_mode()
+        };
+        let defs = get_tool_definitions_for_context(&ctx);
+        assert!(defs.iter().any(|d| d.name == "Write"));
+    }
 }
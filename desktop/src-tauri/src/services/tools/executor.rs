@@ -112,6 +112,59 @@ pub struct ToolResult {
     /// without directly causing them.
     #[serde(skip)]
     pub event_actions: Option<crate::services::core::event_actions::EventActions>,
+    /// Provenance of the call that produced this result, populated by the executor.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<ToolProvenance>,
+}
+
+/// Classification of how a tool call ended.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolCallOutcome {
+    Success,
+    Partial,
+    /// The tool ran and reported an error.
+    ToolError,
+    /// The permission gate rejected the call before execution.
+    PermissionDenied,
+    /// No built-in or runtime tool matched the requested name.
+    UnknownTool,
+}
+
+/// Audit/replay record of a single tool call.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ToolProvenance {
+    pub tool_name: String,
+    /// Arguments with object keys sorted recursively.
+    pub arguments: serde_json::Value,
+    /// RFC 3339 timestamp taken before dispatch.
+    pub started_at: String,
+    /// RFC 3339 timestamp taken after the tool returned.
+    pub finished_at: String,
+    pub duration_ms: u64,
+    pub outcome: ToolCallOutcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+}
+
+/// Rebuild a JSON value with object keys in sorted order so equal arguments
+/// serialize identically regardless of how the provider ordered them.
+pub fn canonicalize_arguments(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let mut sorted = serde_json::Map::with_capacity(map.len());
+            for key in keys {
+                sorted.insert(key.clone(), canonicalize_arguments(&map[key]));
+            }
+            serde_json::Value::Object(sorted)
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(canonicalize_arguments).collect())
+        }
+        other => other.clone(),
+    }
 }
 
 impl ToolResult {
@@ -127,6 +180,7 @@ impl ToolResult {
             image_data: None,
             is_dedup: false,
             event_actions: None,
+            provenance: None,
         }
     }
 
@@ -142,6 +196,7 @@ impl ToolResult {
             image_data: None,
             is_dedup: false,
             event_actions: None,
+            provenance: None,
         }
     }
 
@@ -161,6 +216,7 @@ impl ToolResult {
             image_data: Some((mime_type, base64_data)),
             is_dedup: false,
             event_actions: None,
+            provenance: None,
         }
     }
 
@@ -179,6 +235,7 @@ impl ToolResult {
             image_data: None,
             is_dedup: true,
             event_actions: None,
+            provenance: None,
         }
     }
 
@@ -215,6 +272,12 @@ impl ToolResult {
         self
     }
 
+    /// Attach call provenance.
+    pub fn with_provenance(mut self, provenance: ToolProvenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
     /// Attach arbitrary metadata payload.
     pub fn with_metadata(mut self, metadata: serde_json::Value) -> Self {
         if !metadata.is_null() {
@@ -235,6 +298,7 @@ impl ToolResult {
             image_data: None,
             is_dedup: false,
             event_actions: None,
+            provenance: None,
         }
     }
 
@@ -376,6 +440,11 @@ pub struct ToolExecutor {
     knowledge_collection_filter: Option<Vec<String>>,
     /// Optional filter: only return results from these scoped document refs.
     knowledge_document_filter: Option<Vec<ScopedDocumentRef>>,
+    /// Extra parent environment variables passed through to Bash commands,
+    /// on top of the Bash tool's safe defaults. Starts as
+    /// `DEFAULT_BASH_ENV_ALLOWLIST`; orchestrators replace it with the user's
+    /// `bash_env_allowlist` setting.
+    bash_env_allowlist: Vec<String>,
 }
 
 impl ToolExecutor {
@@ -441,6 +510,7 @@ impl ToolExecutor {
             knowledge_project_id: None,
             knowledge_collection_filter: None,
             knowledge_document_filter: None,
+            bash_env_allowlist: super::impls::bash::default_bash_env_allowlist(),
         }
     }
 
@@ -480,6 +550,7 @@ impl ToolExecutor {
             knowledge_project_id: None,
             knowledge_collection_filter: None,
             knowledge_document_filter: None,
+            bash_env_allowlist: super::impls::bash::default_bash_env_allowlist(),
         }
     }
 
@@ -623,6 +694,17 @@ impl ToolExecutor {
         self.cancellation_token = token;
     }
 
+    /// Set extra environment variable names that Bash commands may inherit
+    /// from the parent process. All other parent variables are withheld.
+    pub fn set_bash_env_allowlist(&mut self, names: Vec<String>) {
+        self.bash_env_allowlist = names;
+    }
+
+    /// Get the Bash environment allowlist, for sharing with sub-agents.
+    pub fn get_bash_env_allowlist(&self) -> Vec<String> {
+        self.bash_env_allowlist.clone()
+    }
+
     /// Set the permission gate for tool execution approval.
     pub fn set_permission_gate(
         &mut self,
@@ -740,6 +822,7 @@ impl ToolExecutor {
             knowledge_project_id: self.knowledge_project_id.clone(),
             knowledge_collection_filter: self.knowledge_collection_filter.clone(),
             knowledge_document_filter: self.knowledge_document_filter.clone(),
+            bash_env_allowlist: self.bash_env_allowlist.clone(),
         }
     }
 
@@ -770,6 +853,34 @@ impl ToolExecutor {
         tool_name: &str,
         arguments: &serde_json::Value,
     ) -> ToolResult {
+        let started_at = chrono::Utc::now();
+        let clock = std::time::Instant::now();
+        let (result, outcome) = self.dispatch(ctx, tool_name, arguments).await;
+        let outcome = outcome.unwrap_or(match result.status {
+            ToolResultStatus::Success => ToolCallOutcome::Success,
+            ToolResultStatus::Partial => ToolCallOutcome::Partial,
+            ToolResultStatus::Error => ToolCallOutcome::ToolError,
+        });
+        let provenance = ToolProvenance {
+            tool_name: tool_name.to_string(),
+            arguments: canonicalize_arguments(arguments),
+            started_at: started_at.to_rfc3339(),
+            finished_at: chrono::Utc::now().to_rfc3339(),
+            duration_ms: clock.elapsed().as_millis() as u64,
+            outcome,
+            error_code: result.error_code.clone(),
+        };
+        result.with_provenance(provenance)
+    }
+
+    /// Dispatch to the permission gate and tool, returning the outcome when it
+    /// is decided before a tool runs.
+    async fn dispatch(
+        &self,
+        ctx: &super::trait_def::ToolExecutionContext,
+        tool_name: &str,
+        arguments: &serde_json::Value,
+    ) -> (ToolResult, Option<ToolCallOutcome>) {
         if let Some(ref gate) = ctx.permission_gate {
             let working_dir = ctx.working_directory_snapshot();
             if let Err(reason) = gate
@@ -782,19 +893,25 @@ impl ToolExecutor {
                 )
                 .await
             {
-                return ToolResult::err(reason);
+                return (
+                    ToolResult::err(reason),
+                    Some(ToolCallOutcome::PermissionDenied),
+                );
             }
         }
 
         if let Some(tool) = self.registry.get(tool_name) {
-            return tool.execute(ctx, arguments.clone()).await;
+            return (tool.execute(ctx, arguments.clone()).await, None);
         }
 
         if let Some(tool) = super::runtime_tools::get(tool_name) {
-            return tool.execute(ctx, arguments.clone()).await;
+            return (tool.execute(ctx, arguments.clone()).await, None);
         }
 
-        ToolResult::err(format!("Unknown tool: {}", tool_name))
+        (
+            ToolResult::err(format!("Unknown tool: {}", tool_name)),
+            Some(ToolCallOutcome::UnknownTool),
+        )
     }
 
     /// Execute a tool by name with given arguments.
@@ -2455,4 +2572,54 @@ mod tests {
             "child should have the shared manager"
         );
     }
+
+    #[tokio::test]
+    async fn test_provenance_for_successful_call() {
+        let dir = setup_test_dir();
+        let executor = ToolExecutor::new(dir.path());
+
+        let args = serde_json::json!({
+            "offset": 1,
+            "file_path": dir.path().join("test.txt").to_string_lossy()
+        });
+        let result = executor.execute("Read", &args).await;
+        assert!(result.is_success());
+
+        let provenance = result.provenance.expect("provenance populated");
+        assert_eq!(provenance.tool_name, "Read");
+        assert_eq!(provenance.outcome, ToolCallOutcome::Success);
+        assert_eq!(provenance.arguments, args);
+        let keys: Vec<&String> = provenance.arguments.as_object().unwrap().keys().collect();
+        assert_eq!(keys, vec!["file_path", "offset"]);
+        let started = chrono::DateTime::parse_from_rfc3339(&provenance.started_at).unwrap();
+        let finished = chrono::DateTime::parse_from_rfc3339(&provenance.finished_at).unwrap();
+        assert!(finished >= started);
+        assert!(provenance.duration_ms <= (finished - started).num_milliseconds() as u64 + 1);
+    }
+
+    #[tokio::test]
+    async fn test_provenance_for_failed_call() {
+        let dir = setup_test_dir();
+        let executor = ToolExecutor::new(dir.path());
+
+        let args = serde_json::json!({
+            "file_path": dir.path().join("missing.txt").to_string_lossy()
+        });
+        let result = executor.execute("Read", &args).await;
+        assert!(result.is_error());
+        let provenance = result.provenance.clone().expect("provenance populated");
+        assert_eq!(provenance.outcome, ToolCallOutcome::ToolError);
+        assert_eq!(provenance.error_code, result.error_code);
+
+        let result = executor.execute("NoSuchTool", &serde_json::json!({})).await;
+        let provenance = result.provenance.expect("provenance populated");
+        assert_eq!(provenance.outcome, ToolCallOutcome::UnknownTool);
+        assert_eq!(provenance.tool_name, "NoSuchTool");
+
+        // Provenance round-trips and stays absent for results built directly
+        let json = serde_json::to_value(&provenance).unwrap();
+        assert_eq!(json["outcome"], "unknown_tool");
+        let plain = serde_json::to_value(ToolResult::ok("x")).unwrap();
+        assert!(plain.get("provenance").is_none());
+    }
 }
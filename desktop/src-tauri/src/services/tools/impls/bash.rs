@@ -1,7 +1,8 @@
 //! Bash Tool Implementation
 //!
 //! Executes shell commands with timeout, blocked command checking,
-//! and persistent working directory tracking via ToolExecutionContext.
+//! persistent working directory tracking via ToolExecutionContext, and an
+//! allowlisted environment so the parent's secrets do not leak into commands.
 
 use async_trait::async_trait;
 use serde_json::Value;
@@ -37,11 +38,137 @@ const DEFAULT_TIMEOUT_MS: u64 = 120_000;
 /// Maximum timeout in milliseconds (10 minutes)
 const MAX_TIMEOUT_MS: u64 = 600_000;
 
+/// Parent environment variables always passed through to commands.
+/// Everything else must be allowlisted via `ToolExecutionContext::bash_env_allowlist`.
+const SAFE_DEFAULT_ENV_VARS: &[&str] = &[
+    "PATH",
+    "HOME",
+    "USER",
+    "LOGNAME",
+    "SHELL",
+    "LANG",
+    "LC_ALL",
+    "LC_CTYPE",
+    "TERM",
+    "TMPDIR",
+    "TZ",
+    // Windows essentials
+    "SYSTEMROOT",
+    "WINDIR",
+    "COMSPEC",
+    "PATHEXT",
+    "TEMP",
+    "TMP",
+    "USERPROFILE",
+];
+
+/// Settings key holding the user's Bash environment allowlist (a JSON array
+/// of variable names).
+pub const BASH_ENV_ALLOWLIST_SETTING: &str = "bash_env_allowlist";
+
+/// Allowlist used until the user configures one: toolchain locations, the
+/// SSH agent and proxy settings that real builds depend on.
+pub const DEFAULT_BASH_ENV_ALLOWLIST: &[&str] = &[
+    "JAVA_HOME",
+    "CARGO_HOME",
+    "RUSTUP_HOME",
+    "GOPATH",
+    "GOROOT",
+    "NVM_DIR",
+    "PYENV_ROOT",
+    "VIRTUAL_ENV",
+    "CONDA_PREFIX",
+    "ANDROID_HOME",
+    "GRADLE_USER_HOME",
+    "DOTNET_ROOT",
+    "SSH_AUTH_SOCK",
+    "HTTP_PROXY",
+    "HTTPS_PROXY",
+    "NO_PROXY",
+    "ALL_PROXY",
+    "http_proxy",
+    "https_proxy",
+    "no_proxy",
+    "all_proxy",
+    "XDG_CONFIG_HOME",
+    "XDG_CACHE_HOME",
+    "XDG_DATA_HOME",
+    "XDG_RUNTIME_DIR",
+    // Windows
+    "APPDATA",
+    "LOCALAPPDATA",
+    "PROGRAMDATA",
+    "PROGRAMFILES",
+    "PROGRAMFILES(X86)",
+];
+
+/// The default allowlist as owned names.
+pub fn default_bash_env_allowlist() -> Vec<String> {
+    DEFAULT_BASH_ENV_ALLOWLIST
+        .iter()
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Parse the stored allowlist setting, falling back to the default when it
+/// is unset or malformed.
+pub fn parse_bash_env_allowlist(value: Option<&str>) -> Vec<String> {
+    value
+        .and_then(|json| serde_json::from_str::<Vec<String>>(json).ok())
+        .unwrap_or_else(default_bash_env_allowlist)
+}
+
+/// Build the environment for a command: safe defaults and allowlisted names
+/// looked up in the parent environment, then per-call overrides on top.
+fn build_command_env(
+    parent: impl Fn(&str) -> Option<String>,
+    allowlist: &[String],
+    overrides: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut env = HashMap::new();
+    let names = SAFE_DEFAULT_ENV_VARS
+        .iter()
+        .copied()
+        .chain(allowlist.iter().map(String::as_str));
+    for name in names {
+        if let Some(value) = parent(name) {
+            env.insert(name.to_string(), value);
+        }
+    }
+    env.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+    env
+}
+
+/// Parse the optional per-call `env` argument (an object of string values).
+fn parse_env_overrides(value: Option<&Value>) -> Result<HashMap<String, String>, String> {
+    let Some(value) = value.filter(|v| !v.is_null()) else {
+        return Ok(HashMap::new());
+    };
+    let Some(object) = value.as_object() else {
+        return Err("Parameter 'env' must be an object of string values".to_string());
+    };
+    let mut overrides = HashMap::new();
+    for (key, entry) in object {
+        if key.is_empty() || key.contains('=') || key.contains('\0') {
+            return Err(format!("Invalid environment variable name: '{}'", key));
+        }
+        let Some(text) = entry.as_str() else {
+            return Err(format!("Environment variable '{}' must be a string", key));
+        };
+        overrides.insert(key.clone(), text.to_string());
+    }
+    Ok(overrides)
+}
+
 /// Bash command tool — executes shell commands with safety checks.
 ///
 /// Uses `ctx.working_directory` (Arc<Mutex<PathBuf>>) for persistent
 /// working directory tracking. When a simple `cd <path>` command succeeds,
 /// the shared working directory is updated for all subsequent tool calls.
+///
+/// Commands do not inherit the parent environment: only
+/// `SAFE_DEFAULT_ENV_VARS`, `ctx.bash_env_allowlist`, and the per-call
+/// `env` argument are visible.
 pub struct BashTool;
 
 impl BashTool {
@@ -115,6 +242,17 @@ impl Tool for BashTool {
                 "Working directory for the command (must be inside workspace)",
             )),
         );
+        properties.insert(
+            "env".to_string(),
+            ParameterSchema::object(
+                Some(
+                    "Optional environment variables for this command (string values). \
+                     Merged over the allowlisted environment.",
+                ),
+                HashMap::new(),
+                vec![],
+            ),
+        );
         ParameterSchema::object(
             Some("Bash command parameters"),
             properties,
@@ -126,6 +264,10 @@ impl Tool for BashTool {
         true
     }
 
+    fn is_mutating(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, ctx: &ToolExecutionContext, args: Value) -> ToolResult {
         let command = match args.get("command").and_then(|v| v.as_str()) {
             Some(c) => c,
@@ -138,6 +280,11 @@ impl Tool for BashTool {
             .unwrap_or(DEFAULT_TIMEOUT_MS)
             .min(MAX_TIMEOUT_MS);
 
+        let env_overrides = match parse_env_overrides(args.get("env")) {
+            Ok(overrides) => overrides,
+            Err(e) => return ToolResult::err(e),
+        };
+
         let cwd_snapshot = ctx.working_directory_snapshot();
         let requested_working_dir = args
             .get("working_dir")
@@ -199,6 +346,12 @@ impl Tool for BashTool {
         cmd.arg(shell_arg)
             .arg(command)
             .current_dir(&working_dir)
+            .env_clear()
+            .envs(build_command_env(
+                |name| std::env::var(name).ok(),
+                &ctx.bash_env_allowlist,
+                &env_overrides,
+            ))
             .stdout(Stdio::piped())
             .stderr(Stdio::piped());
         configure_background_process(&mut cmd);
@@ -415,6 +568,115 @@ mod tests {
         assert!(tool.is_long_running());
     }
 
+    #[tokio::test]
+    async fn test_bash_tool_runs_in_configured_working_dir() {
+        let dir = TempDir::new().unwrap();
+        let subdir = dir.path().join("session-cwd");
+        std::fs::create_dir(&subdir).unwrap();
+        let tool = BashTool::new();
+        let ctx = make_test_ctx(dir.path());
+        *ctx.working_directory.lock().unwrap() = subdir.clone();
+
+        let result = tool
+            .execute(&ctx, serde_json::json!({"command": "pwd"}))
+            .await;
+        assert!(result.is_success());
+        let output = result.success_message_owned().unwrap();
+        assert_eq!(
+            PathBuf::from(output.trim()).canonicalize().unwrap(),
+            subdir.canonicalize().unwrap()
+        );
+    }
+
+    fn parent_env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_command_env_withholds_non_allowlisted_vars() {
+        let parent = parent_env(&[("PATH", "/usr/bin"), ("API_SECRET", "leaked")]);
+        let env = build_command_env(&parent, &[], &HashMap::new());
+        assert_eq!(env.get("PATH").map(String::as_str), Some("/usr/bin"));
+        assert!(!env.contains_key("API_SECRET"));
+    }
+
+    #[test]
+    fn test_command_env_applies_allowlist_and_overrides() {
+        let parent = parent_env(&[
+            ("JAVA_HOME", "/opt/jdk"),
+            ("OVERRIDDEN", "from-parent"),
+            ("API_SECRET", "leaked"),
+        ]);
+        let allowlist = vec!["JAVA_HOME".to_string(), "OVERRIDDEN".to_string()];
+        let overrides = HashMap::from([
+            ("OVERRIDDEN".to_string(), "from-call".to_string()),
+            ("CALL_ONLY".to_string(), "extra".to_string()),
+        ]);
+        let env = build_command_env(&parent, &allowlist, &overrides);
+        assert_eq!(env.get("JAVA_HOME").map(String::as_str), Some("/opt/jdk"));
+        assert_eq!(env.get("OVERRIDDEN").map(String::as_str), Some("from-call"));
+        assert_eq!(env.get("CALL_ONLY").map(String::as_str), Some("extra"));
+        assert!(!env.contains_key("API_SECRET"));
+    }
+
+    #[test]
+    fn test_bash_env_allowlist_setting_defaults() {
+        let defaults = parse_bash_env_allowlist(None);
+        assert!(defaults.iter().any(|name| name == "JAVA_HOME"));
+        assert!(defaults.iter().any(|name| name == "SSH_AUTH_SOCK"));
+        assert_eq!(parse_bash_env_allowlist(Some("not json")), defaults);
+        assert_eq!(
+            parse_bash_env_allowlist(Some(r#"["CARGO_HOME"]"#)),
+            vec!["CARGO_HOME".to_string()]
+        );
+        assert!(parse_bash_env_allowlist(Some("[]")).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bash_tool_applies_env_overrides() {
+        let dir = TempDir::new().unwrap();
+        let tool = BashTool::new();
+        let ctx = make_test_ctx(dir.path());
+
+        let result = tool
+            .execute(
+                &ctx,
+                serde_json::json!({
+                    "command": "echo \"[$CALL_ONLY]\"",
+                    "env": {"CALL_ONLY": "extra"}
+                }),
+            )
+            .await;
+        assert!(result.is_success());
+        assert_eq!(result.success_message_owned().unwrap().trim(), "[extra]");
+    }
+
+    #[tokio::test]
+    async fn test_bash_tool_rejects_invalid_env() {
+        let dir = TempDir::new().unwrap();
+        let tool = BashTool::new();
+        let ctx = make_test_ctx(dir.path());
+
+        let result = tool
+            .execute(
+                &ctx,
+                serde_json::json!({"command": "true", "env": {"A=B": "x"}}),
+            )
+            .await;
+        assert!(result.is_error());
+        let result = tool
+            .execute(
+                &ctx,
+                serde_json::json!({"command": "true", "env": {"N": 1}}),
+            )
+            .await;
+        assert!(result.is_error());
+    }
+
     #[tokio::test]
     async fn test_bash_tool_cd_updates_shared_working_dir() {
         let dir = TempDir::new().unwrap();
@@ -1648,6 +1648,10 @@ impl Tool for BrowserTool {
         true
     }
 
+    fn is_mutating(&self) -> bool {
+        true // Can submit forms and trigger page actions
+    }
+
     async fn execute(&self, _ctx: &ToolExecutionContext, args: Value) -> ToolResult {
         // Parse the action from arguments
         let action = match Self::parse_action(&args) {
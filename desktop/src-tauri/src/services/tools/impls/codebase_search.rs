@@ -10,7 +10,7 @@ use std::collections::HashMap;
 use crate::services::llm::types::ParameterSchema;
 use crate::services::orchestrator::codebase_search_service::{
     CodeSearchResponse, CodebaseSearchFilters, CodebaseSearchRequest, CodebaseSearchService,
-    SearchHit,
+    HitKind, SearchHit, SymbolMatchKind, SymbolTypeFilter,
 };
 use crate::services::orchestrator::index_store::IndexStore;
 use crate::services::tools::executor::ToolResult;
@@ -87,6 +87,9 @@ impl CodebaseSearchTool {
         if !hit.channels.is_empty() {
             line.push_str(&format!(" [{}]", hit.channels.join(", ")));
         }
+        if hit.hit_kind != HitKind::Text {
+            line.push_str(&format!(" <{}>", hit.hit_kind));
+        }
         line.push('\n');
 
         if let Some(ref symbol) = hit.symbol_name {
@@ -212,6 +215,30 @@ impl Tool for CodebaseSearchTool {
         scope_schema.default = Some(serde_json::Value::String("hybrid".to_string()));
         properties.insert("scope".to_string(), scope_schema);
 
+        let mut kind_schema = ParameterSchema::string(Some(
+            "Symbol occurrence filter: 'definition' (declaring site), 'reference' (usages), or 'any'. \
+             Non-'any' values search the exact symbol name structurally and fall back to text search. Default: 'any'.",
+        ));
+        kind_schema.enum_values = Some(vec![
+            "definition".to_string(),
+            "reference".to_string(),
+            "any".to_string(),
+        ]);
+        kind_schema.default = Some(serde_json::Value::String("any".to_string()));
+        properties.insert("kind".to_string(), kind_schema);
+
+        let mut symbol_type_schema = ParameterSchema::string(Some(
+            "Symbol category filter: 'function', 'type', 'variable', or 'any'. Default: 'any'.",
+        ));
+        symbol_type_schema.enum_values = Some(vec![
+            "function".to_string(),
+            "type".to_string(),
+            "variable".to_string(),
+            "any".to_string(),
+        ]);
+        symbol_type_schema.default = Some(serde_json::Value::String("any".to_string()));
+        properties.insert("symbol_type".to_string(), symbol_type_schema);
+
         properties.insert(
             "project_path".to_string(),
             ParameterSchema::string(Some(
@@ -283,6 +310,33 @@ impl Tool for CodebaseSearchTool {
             Err(error) => return ToolResult::err(error).with_error_code("invalid_scope"),
         };
 
+        let kind = match args.get("kind").and_then(|v| v.as_str()) {
+            Some(raw) => match SymbolMatchKind::parse(raw) {
+                Some(kind) => kind,
+                None => {
+                    return ToolResult::err(format!(
+                        "Invalid kind '{}'. Use one of: definition, reference, any.",
+                        raw
+                    ))
+                    .with_error_code("invalid_kind");
+                }
+            },
+            None => SymbolMatchKind::Any,
+        };
+        let symbol_type = match args.get("symbol_type").and_then(|v| v.as_str()) {
+            Some(raw) => match SymbolTypeFilter::parse(raw) {
+                Some(symbol_type) => symbol_type,
+                None => {
+                    return ToolResult::err(format!(
+                        "Invalid symbol_type '{}'. Use one of: function, type, variable, any.",
+                        raw
+                    ))
+                    .with_error_code("invalid_symbol_type");
+                }
+            },
+            None => SymbolTypeFilter::Any,
+        };
+
         let filters = args.get("filters").and_then(|v| v.as_object());
         let component = Self::normalized_arg(filters.and_then(|f| f.get("component")));
         let language = Self::normalized_arg(filters.and_then(|f| f.get("language")));
@@ -328,6 +382,8 @@ impl Tool for CodebaseSearchTool {
                 component,
                 language,
                 file_path_prefix,
+                kind,
+                symbol_type,
             }),
         };
 
@@ -384,6 +440,8 @@ mod tests {
         assert!(props.contains_key("limit"));
         assert!(props.contains_key("include_snippet"));
         assert!(props.contains_key("filters"));
+        assert!(props.contains_key("kind"));
+        assert!(props.contains_key("symbol_type"));
 
         let scope = props.get("scope").unwrap();
         let enum_vals = scope.enum_values.as_ref().unwrap();
@@ -439,4 +497,21 @@ mod tests {
         assert!(result.is_error());
         assert!(result.error_message().unwrap().contains("Invalid scope"));
     }
+
+    #[tokio::test]
+    async fn test_codebase_search_rejects_unknown_kind() {
+        let tool = CodebaseSearchTool::new();
+        let ctx = make_test_ctx(Path::new("/tmp"));
+        let result = tool
+            .execute(
+                &ctx,
+                serde_json::json!({
+                    "query": "App",
+                    "kind": "usage"
+                }),
+            )
+            .await;
+        assert!(result.is_error());
+        assert_eq!(result.error_code.as_deref(), Some("invalid_kind"));
+    }
 }
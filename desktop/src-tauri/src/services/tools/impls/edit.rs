@@ -64,6 +64,10 @@ impl Tool for EditTool {
         )
     }
 
+    fn is_mutating(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, ctx: &ToolExecutionContext, args: Value) -> ToolResult {
         let file_path = match args.get("file_path").and_then(|v| v.as_str()) {
             Some(p) => p,
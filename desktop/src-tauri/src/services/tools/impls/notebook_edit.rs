@@ -68,6 +68,10 @@ impl Tool for NotebookEditTool {
         )
     }
 
+    fn is_mutating(&self) -> bool {
+        true
+    }
+
     async fn execute(&self, ctx: &ToolExecutionContext, args: Value) -> ToolResult {
         let notebook_path = match args.get("notebook_path").and_then(|v| v.as_str()) {
             Some(p) => p,
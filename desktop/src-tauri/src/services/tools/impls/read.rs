@@ -410,6 +410,25 @@ mod tests {
         assert!(r2.success_message_owned().unwrap().contains("[DEDUP]"));
     }
 
+    #[tokio::test]
+    async fn test_read_tool_image_returns_image_block() {
+        let dir = TempDir::new().unwrap();
+        // PNG signature only; metadata falls back to "dimensions unknown".
+        let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        std::fs::write(dir.path().join("shot.png"), png).unwrap();
+        let tool = ReadTool::new();
+        let ctx = make_test_ctx(dir.path());
+
+        let args = serde_json::json!({
+            "file_path": dir.path().join("shot.png").to_string_lossy().to_string()
+        });
+        let result = tool.execute(&ctx, args).await;
+        assert!(result.is_success());
+        let (mime, data) = result.image_data.expect("image data");
+        assert_eq!(mime, "image/png");
+        assert_eq!(data, "iVBORw0KGgo=");
+    }
+
     #[tokio::test]
     async fn test_read_tool_missing_param() {
         let dir = TempDir::new().unwrap();
@@ -9,6 +9,7 @@ use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
 
+use crate::services::knowledge::pipeline::MetadataFilter;
 use crate::services::llm::types::ParameterSchema;
 use crate::services::tools::executor::ToolResult;
 use crate::services::tools::trait_def::{Tool, ToolExecutionContext};
@@ -16,7 +17,8 @@ use crate::services::tools::trait_def::{Tool, ToolExecutionContext};
 /// Tool for on-demand semantic search of project knowledge collections.
 ///
 /// Reads `knowledge_pipeline`, `knowledge_project_id`, and optional
-/// filter fields from `ToolExecutionContext`.
+/// filter fields from `ToolExecutionContext`. Without a `collection`
+/// argument the search covers the context's configured collection scope.
 pub struct SearchKnowledgeTool;
 
 impl SearchKnowledgeTool {
@@ -58,6 +60,31 @@ impl Tool for SearchKnowledgeTool {
             )),
         );
 
+        let mut filter_properties = HashMap::new();
+        filter_properties.insert(
+            "path_prefix".to_string(),
+            ParameterSchema::string(Some(
+                "Only return chunks whose source path starts with this prefix (e.g. 'docs/api/').",
+            )),
+        );
+        filter_properties.insert(
+            "source_type".to_string(),
+            ParameterSchema::string(Some(
+                "Only return chunks of this document type (e.g. 'md').",
+            )),
+        );
+        properties.insert(
+            "filters".to_string(),
+            ParameterSchema::object(
+                Some(
+                    "Optional metadata filters. `path_prefix` matches the source path prefix; \
+                     any other key must equal the chunk metadata value exactly.",
+                ),
+                filter_properties,
+                vec![],
+            ),
+        );
+
         let mut top_k_schema =
             ParameterSchema::integer(Some("Number of results to return (default: 5, max: 20)."));
         top_k_schema.default = Some(Value::Number(serde_json::Number::from(5)));
@@ -105,6 +132,13 @@ impl Tool for SearchKnowledgeTool {
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
 
+        let metadata_filter = match parse_metadata_filter(args.get("filters")) {
+            Ok(filter) => filter,
+            Err(message) => {
+                return ToolResult::err(message).with_error_code("invalid_filters");
+            }
+        };
+
         let top_k = args
             .get("top_k")
             .and_then(|v| v.as_u64())
@@ -160,6 +194,7 @@ impl Tool for SearchKnowledgeTool {
                 top_k,
                 effective_collection_ids.as_deref(),
                 ctx.knowledge_document_filter.as_deref(),
+                metadata_filter.as_ref(),
                 None,
             )
             .await
@@ -186,6 +221,27 @@ impl Tool for SearchKnowledgeTool {
     }
 }
 
+/// Parse the optional `filters` argument into a metadata filter.
+///
+/// Returns `Ok(None)` when absent or empty; every value must be a string.
+fn parse_metadata_filter(value: Option<&Value>) -> Result<Option<MetadataFilter>, String> {
+    let Some(value) = value.filter(|v| !v.is_null()) else {
+        return Ok(None);
+    };
+    let Some(object) = value.as_object() else {
+        return Err("Parameter 'filters' must be an object of string values".to_string());
+    };
+    let mut map = HashMap::new();
+    for (key, entry) in object {
+        let Some(text) = entry.as_str() else {
+            return Err(format!("Filter '{}' must be a string value", key));
+        };
+        map.insert(key.clone(), text.to_string());
+    }
+    let filter = MetadataFilter::from_map(map);
+    Ok((!filter.is_empty()).then_some(filter))
+}
+
 /// Format raw SearchResult entries into a readable markdown block.
 fn format_search_results(
     results: &[crate::services::knowledge::reranker::SearchResult],
@@ -243,4 +299,28 @@ mod tests {
         assert!(result.is_error());
         assert!(result.error_message().unwrap().contains("query"));
     }
+
+    #[test]
+    fn test_parse_metadata_filter() {
+        assert_eq!(parse_metadata_filter(None), Ok(None));
+        assert_eq!(
+            parse_metadata_filter(Some(&serde_json::json!({}))),
+            Ok(None)
+        );
+
+        let filter = parse_metadata_filter(Some(&serde_json::json!({
+            "path_prefix": "docs/api/",
+            "source_type": "md"
+        })))
+        .unwrap()
+        .unwrap();
+        assert_eq!(filter.path_prefix.as_deref(), Some("docs/api/"));
+        assert_eq!(
+            filter.equals.get("source_type").map(String::as_str),
+            Some("md")
+        );
+
+        assert!(parse_metadata_filter(Some(&serde_json::json!("docs"))).is_err());
+        assert!(parse_metadata_filter(Some(&serde_json::json!({ "source_type": 3 }))).is_err());
+    }
 }
@@ -98,6 +98,10 @@ impl Tool for TaskTool {
         true // Each sub-agent has independent context
     }
 
+    fn is_mutating(&self) -> bool {
+        true // Sub-agents may write files or run commands
+    }
+
     async fn execute(&self, ctx: &ToolExecutionContext, args: Value) -> ToolResult {
         let prompt = match args.get("prompt").and_then(|v| v.as_str()) {
             Some(p) => p.to_string(),
@@ -38,5 +38,6 @@ pub(crate) fn make_test_ctx(dir: &Path) -> ToolExecutionContext {
         knowledge_project_id: None,
         knowledge_collection_filter: None,
         knowledge_document_filter: None,
+        bash_env_allowlist: Vec::new(),
     }
 }
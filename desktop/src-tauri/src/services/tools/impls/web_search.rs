@@ -1,6 +1,6 @@
 //! WebSearch Tool Implementation
 //!
-//! Provides web search via pluggable providers (Tavily, Brave, DuckDuckGo).
+//! Provides web search via pluggable backends (Tavily, Brave, DuckDuckGo, SearXNG, Kagi).
 //! Uses WebSearchService from ToolExecutionContext for actual searching.
 
 use async_trait::async_trait;
@@ -31,7 +31,7 @@ impl Tool for WebSearchTool {
     }
 
     fn description(&self) -> &str {
-        "Search the web for current information. Returns titles, URLs, snippets, and structured citations. Supports optional domain allow/block filters and provider backends (Tavily, Brave Search, DuckDuckGo, SearXNG)."
+        "Search the web for current information. Returns titles, URLs, snippets, and structured citations. Supports optional domain allow/block filters and provider backends (Tavily, Brave Search, DuckDuckGo, SearXNG, Kagi)."
     }
 
     fn parameters_schema(&self) -> ParameterSchema {
@@ -58,6 +58,12 @@ impl Tool for WebSearchTool {
                 ParameterSchema::string(Some("Domain to block (e.g., reddit.com)")),
             ),
         );
+        properties.insert(
+            "max_per_domain".to_string(),
+            ParameterSchema::integer(Some(
+                "Optional cap on results from any single domain, so top results span more sources.",
+            )),
+        );
         ParameterSchema::object(
             Some("WebSearch parameters"),
             properties,
@@ -107,6 +113,12 @@ impl Tool for WebSearchTool {
                 Some(allow_domains.clone())
             },
             block_domains.clone(),
+        )
+        .with_max_per_domain(
+            args.get("max_per_domain")
+                .and_then(|v| v.as_u64())
+                .map(|n| n.min(10) as usize)
+                .unwrap_or(0),
         );
 
         match &ctx.web_search {
@@ -114,8 +126,16 @@ impl Tool for WebSearchTool {
                 .search_with_constraints(query, Some(max_results), &constraints)
                 .await
             {
-                Ok(results) => {
-                    let content = service.format_results_markdown(query, &results);
+                Ok(outcome) => {
+                    let results = outcome.results;
+                    let mut content = service.format_results_markdown(query, &results);
+                    if let Some(note) = dedup_note(
+                        outcome.duplicates_removed,
+                        outcome.domain_capped,
+                        constraints.max_per_domain,
+                    ) {
+                        content.push_str(&format!("\n_{}_\n", note));
+                    }
                     let citations = results
                         .iter()
                         .map(|r| {
@@ -140,28 +160,57 @@ impl Tool for WebSearchTool {
                         "allow_domains": allow_domains,
                         "block_domains": block_domains,
                         "result_count": citations.len(),
+                        "duplicates_removed": outcome.duplicates_removed,
+                        "domain_capped": outcome.domain_capped,
+                        "max_per_domain": constraints.max_per_domain,
                     });
                     ToolResult::ok(content)
                         .with_metadata(metadata)
                         .with_citations(citations)
                 }
-                Err(e) => ToolResult::err(e)
+                Err(e) => ToolResult::err(e.to_string())
                     .with_error_code("web_search_failed")
-                    .with_retryable(true),
+                    .with_retryable(e.is_retryable()),
             },
             None => ToolResult::err(
-                "WebSearch is not configured. Set a search provider (tavily, brave, duckduckgo, or searxng) in Settings > LLM Backend > Search Provider, and provide an API key if required."
+                "WebSearch is not configured. Set a search provider (tavily, brave, duckduckgo, searxng, or kagi) in Settings > LLM Backend > Search Provider, and provide an API key if required."
             )
             .with_error_code("web_search_not_configured"),
         }
     }
 }
 
+/// Describe results removed by deduplication or the per-domain cap, if any.
+fn dedup_note(
+    duplicates_removed: usize,
+    domain_capped: usize,
+    max_per_domain: Option<usize>,
+) -> Option<String> {
+    let mut parts = Vec::new();
+    if duplicates_removed > 0 {
+        parts.push(format!(
+            "removed {} duplicate result(s)",
+            duplicates_removed
+        ));
+    }
+    if let (true, Some(cap)) = (domain_capped > 0, max_per_domain) {
+        parts.push(format!(
+            "dropped {} result(s) over the {}-per-domain cap",
+            domain_capped, cap
+        ));
+    }
+    (!parts.is_empty()).then(|| format!("Note: {}.", parts.join("; ")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::test_helpers::make_test_ctx;
     use super::*;
+    use crate::services::tools::web_search::{
+        SearchBackend, SearchError, SearchResult, WebSearchService,
+    };
     use std::path::Path;
+    use std::sync::Arc;
 
     #[test]
     fn test_web_search_tool_name() {
@@ -188,6 +237,58 @@ mod tests {
             .contains("not configured"));
     }
 
+    struct FixedBackend(Vec<SearchResult>);
+
+    #[async_trait]
+    impl SearchBackend for FixedBackend {
+        fn name(&self) -> &'static str {
+            "Fixed"
+        }
+
+        async fn search(
+            &self,
+            _query: &str,
+            _max_results: u32,
+        ) -> Result<Vec<SearchResult>, SearchError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_web_search_tool_reports_dedup_and_domain_cap() {
+        let page = |url: &str, title: &str| SearchResult {
+            title: title.to_string(),
+            url: url.to_string(),
+            snippet: format!("About {}", title),
+        };
+        let backend = FixedBackend(vec![
+            page("https://site.com/a", "A"),
+            page("https://www.site.com/a/", "A again"),
+            page("https://site.com/b", "B"),
+            page("https://site.com/c", "C"),
+            page("https://other.com/d", "D"),
+        ]);
+        let mut ctx = make_test_ctx(Path::new("/tmp"));
+        ctx.web_search = Some(Arc::new(WebSearchService::from_backend(Box::new(backend))));
+
+        let result = WebSearchTool::new()
+            .execute(
+                &ctx,
+                serde_json::json!({"query": "site", "max_per_domain": 2}),
+            )
+            .await;
+
+        assert!(!result.is_error());
+        let metadata = result.metadata.clone().unwrap();
+        assert_eq!(metadata["result_count"], 3);
+        assert_eq!(metadata["duplicates_removed"], 1);
+        assert_eq!(metadata["domain_capped"], 1);
+        let content = result.success_message().unwrap();
+        assert!(content.contains("removed 1 duplicate result(s)"));
+        assert!(content.contains("2-per-domain cap"));
+        assert!(!content.contains("https://site.com/c"));
+    }
+
     #[tokio::test]
     async fn test_web_search_tool_missing_query() {
         let tool = WebSearchTool::new();
@@ -343,6 +343,7 @@ for line in sys.stdin:
             knowledge_project_id: None,
             knowledge_collection_filter: None,
             knowledge_document_filter: None,
+            bash_env_allowlist: Vec::new(),
         };
 
         let result = adapter.execute(&ctx, json!({"name": "Rust"})).await;
@@ -33,16 +33,21 @@ pub mod web_fetch;
 pub mod web_search;
 
 pub use definitions::{
-    get_basic_tool_definitions_from_registry, get_tool_definitions_from_registry,
+    get_basic_tool_definitions_from_registry, get_tool_definitions_for_context,
+    get_tool_definitions_from_registry,
+};
+pub use executor::{
+    canonicalize_arguments, ReadCacheEntry, ToolCallOutcome, ToolCitation, ToolExecutor,
+    ToolProvenance, ToolResult, ToolResultStatus,
 };
-pub use executor::{ReadCacheEntry, ToolCitation, ToolExecutor, ToolResult, ToolResultStatus};
 pub use mcp_adapter::McpToolAdapter;
 pub use mcp_client::{McpClient, McpServerConfig, McpToolInfo, McpTransportConfig};
 pub use mcp_manager::{ConnectedServerInfo, McpManager};
 pub use mcp_schema::sanitize_schema;
 pub use prompt_fallback::{
     build_tool_call_instructions, extract_text_without_tool_calls, format_tool_result,
-    parse_tool_calls, ParsedToolCall,
+    format_tool_result_with_limit, parse_tool_calls, truncate_tool_result, ParsedToolCall,
+    ResultLimit,
 };
 pub use system_prompt::{
     build_memory_section, build_mode_addendum, build_plugin_commands_section,
@@ -54,4 +59,6 @@ pub use system_prompt::{
 pub use task_spawner::{
     SubAgentType, TaskContext, TaskExecutionResult, TaskSpawner, MAX_SUB_AGENT_DEPTH,
 };
-pub use trait_def::{Tool, ToolExecutionContext, ToolRegistry};
+pub use trait_def::{
+    Tool, ToolExecutionContext, ToolFilterContext, ToolFilterRule, ToolRegistry, Toolset,
+};
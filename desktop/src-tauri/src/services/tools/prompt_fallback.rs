@@ -1328,6 +1328,179 @@ pub fn format_tool_result(tool_name: &str, tool_id: &str, result: &str, is_error
     }
 }
 
+/// Rough characters-per-token ratio used when no token counter is available.
+const CHARS_PER_TOKEN: usize = 4;
+
+/// Size budget for a tool result re-injected into the conversation.
+#[derive(Clone, Copy)]
+pub enum ResultLimit<'a> {
+    /// Maximum number of characters
+    Chars(usize),
+    /// Maximum number of tokens as measured by `counter`
+    Tokens {
+        max_tokens: usize,
+        counter: &'a dyn Fn(&str) -> usize,
+    },
+}
+
+impl<'a> ResultLimit<'a> {
+    /// Budget in tokens, measured with `counter` when available and
+    /// approximated in characters otherwise.
+    pub fn new(max_tokens: usize, counter: Option<&'a dyn Fn(&str) -> usize>) -> Self {
+        match counter {
+            Some(counter) => ResultLimit::Tokens {
+                max_tokens,
+                counter,
+            },
+            None => ResultLimit::Chars(max_tokens.saturating_mul(CHARS_PER_TOKEN)),
+        }
+    }
+
+    fn fits(&self, text: &str) -> bool {
+        match self {
+            ResultLimit::Chars(max) => text.chars().count() <= *max,
+            ResultLimit::Tokens {
+                max_tokens,
+                counter,
+            } => counter(text) <= *max_tokens,
+        }
+    }
+}
+
+/// Format a tool result like `format_tool_result`, truncating the payload to `limit`.
+pub fn format_tool_result_with_limit(
+    tool_name: &str,
+    tool_id: &str,
+    result: &str,
+    is_error: bool,
+    limit: ResultLimit<'_>,
+) -> String {
+    let truncated = truncate_tool_result(result, limit);
+    format_tool_result(tool_name, tool_id, &truncated, is_error)
+}
+
+/// Truncate a tool result to `limit` without breaking its structure.
+///
+/// JSON results keep the first and last elements of their longest arrays with a
+/// `"…N more…"` marker in between, so the output still parses. Other output
+/// keeps head and tail lines around an elision marker, as does JSON that
+/// cannot shrink below the limit by eliding array elements.
+pub fn truncate_tool_result(result: &str, limit: ResultLimit<'_>) -> String {
+    if limit.fits(result) {
+        return result.to_string();
+    }
+
+    let trimmed = result.trim();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(trimmed) {
+            return truncate_json(&value, trimmed.contains('\n'), limit);
+        }
+    }
+
+    truncate_lines(result, limit)
+}
+
+fn truncate_json(value: &serde_json::Value, pretty: bool, limit: ResultLimit<'_>) -> String {
+    let render = |v: &serde_json::Value| {
+        if pretty {
+            serde_json::to_string_pretty(v).unwrap_or_default()
+        } else {
+            serde_json::to_string(v).unwrap_or_default()
+        }
+    };
+
+    let mut keep = longest_array(value) / 2;
+    loop {
+        let candidate = render(&elide_arrays(value, keep));
+        if limit.fits(&candidate) {
+            return candidate;
+        }
+        if keep == 0 {
+            // No arrays left to shorten (or large scalar values): give up on
+            // structure and truncate the text instead
+            return truncate_lines(&candidate, limit);
+        }
+        keep /= 2;
+    }
+}
+
+fn longest_array(value: &serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(longest_array)
+            .max()
+            .unwrap_or(0)
+            .max(items.len()),
+        serde_json::Value::Object(map) => map.values().map(longest_array).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+/// Keep at most `keep` leading and trailing elements of every array.
+fn elide_arrays(value: &serde_json::Value, keep: usize) -> serde_json::Value {
+    match value {
+        serde_json::Value::Array(items) if items.len() > keep * 2 + 1 => {
+            let omitted = items.len() - keep * 2;
+            let mut out: Vec<serde_json::Value> = items[..keep]
+                .iter()
+                .map(|v| elide_arrays(v, keep))
+                .collect();
+            out.push(serde_json::Value::String(format!("…{} more…", omitted)));
+            out.extend(
+                items[items.len() - keep..]
+                    .iter()
+                    .map(|v| elide_arrays(v, keep)),
+            );
+            serde_json::Value::Array(out)
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(|v| elide_arrays(v, keep)).collect())
+        }
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), elide_arrays(v, keep)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn truncate_lines(result: &str, limit: ResultLimit<'_>) -> String {
+    let lines: Vec<&str> = result.lines().collect();
+    let mut keep = lines.len() / 2;
+    while keep > 0 {
+        let omitted = lines.len().saturating_sub(keep * 2);
+        let candidate = format!(
+            "{}\n… {} more lines …\n{}",
+            lines[..keep].join("\n"),
+            omitted,
+            lines[lines.len() - keep..].join("\n")
+        );
+        if limit.fits(&candidate) {
+            return candidate;
+        }
+        keep /= 2;
+    }
+
+    // A few very long lines: fall back to head and tail characters
+    let chars: Vec<char> = result.chars().collect();
+    let mut keep = chars.len() / 2;
+    loop {
+        let omitted = chars.len().saturating_sub(keep * 2);
+        let candidate = format!(
+            "{}… {} more chars …{}",
+            chars[..keep].iter().collect::<String>(),
+            omitted,
+            chars[chars.len() - keep..].iter().collect::<String>()
+        );
+        if limit.fits(&candidate) || keep == 0 {
+            return candidate;
+        }
+        keep /= 2;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2382,4 +2555,67 @@ Done."#;
         assert!(!clean.contains("<ls>"));
         assert!(!clean.contains("tool_call"));
     }
+
+    #[test]
+    fn test_truncated_json_still_parses() {
+        let items: Vec<serde_json::Value> = (0..200)
+            .map(|i| serde_json::json!({"path": format!("src/file_{}.rs", i), "line": i}))
+            .collect();
+        let result = serde_json::json!({"total": 200, "matches": items}).to_string();
+
+        let truncated = truncate_tool_result(&result, ResultLimit::Chars(1_000));
+        assert!(truncated.chars().count() <= 1_000);
+        let parsed: serde_json::Value = serde_json::from_str(&truncated).unwrap();
+        let matches = parsed["matches"].as_array().unwrap();
+        assert_eq!(matches.first().unwrap()["line"], 0);
+        assert_eq!(matches.last().unwrap()["line"], 199);
+        assert!(matches
+            .iter()
+            .any(|m| m.as_str().is_some_and(|s| s.contains("more"))));
+        assert_eq!(parsed["total"], 200);
+    }
+
+    #[test]
+    fn test_truncated_lines_keep_head_and_tail() {
+        let result: String = (1..=500)
+            .map(|i| format!("line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let words = |text: &str| text.split_whitespace().count();
+        let limit = ResultLimit::new(100, Some(&words));
+        let truncated = truncate_tool_result(&result, limit);
+
+        assert!(words(&truncated) <= 100);
+        assert!(truncated.starts_with("line 1\nline 2\n"));
+        assert!(truncated.ends_with("line 499\nline 500"));
+        assert!(truncated.contains("more lines"));
+
+        // Without a counter the budget falls back to characters
+        let formatted =
+            format_tool_result_with_limit("Bash", "t1", &result, false, ResultLimit::new(50, None));
+        assert!(formatted.starts_with("[Tool Result: Bash (id: t1)]\nline 1\n"));
+        assert!(formatted.ends_with("line 500"));
+    }
+
+    #[test]
+    fn test_large_json_object_without_arrays_is_truncated() {
+        let fields: serde_json::Map<String, serde_json::Value> = (0..200)
+            .map(|i| (format!("key_{}", i), format!("value {}", i).into()))
+            .collect();
+        let result = serde_json::Value::Object(fields).to_string();
+
+        let truncated = truncate_tool_result(&result, ResultLimit::Chars(500));
+        assert!(truncated.chars().count() <= 500);
+        assert!(truncated.contains("more chars"));
+    }
+
+    #[test]
+    fn test_truncate_under_limit_is_unchanged() {
+        let result = "short output";
+        assert_eq!(
+            truncate_tool_result(result, ResultLimit::Chars(100)),
+            result
+        );
+    }
 }
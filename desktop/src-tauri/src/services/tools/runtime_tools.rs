@@ -124,6 +124,22 @@ pub fn is_parallel_safe(name: &str) -> Option<bool> {
     get(name).map(|tool| tool.is_parallel_safe())
 }
 
+/// Check whether a runtime tool may change state.
+///
+/// Runtime tools are treated as mutating unless the tool itself or its
+/// metadata declares it read-only.
+pub fn is_mutating(name: &str) -> Option<bool> {
+    let tool = get(name)?;
+    if tool.is_mutating() {
+        return Some(true);
+    }
+    let declared_read_only = metadata_for(name).is_some_and(|metadata| {
+        metadata.write_behavior.as_deref() == Some("read_only")
+            || metadata.capability_class.as_deref() == Some("observe")
+    });
+    Some(!declared_read_only)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
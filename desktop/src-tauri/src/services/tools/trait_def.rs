@@ -114,6 +114,10 @@ pub struct ToolExecutionContext {
     pub knowledge_collection_filter: Option<Vec<String>>,
     /// Optional filter: only return results from these document IDs.
     pub knowledge_document_filter: Option<Vec<ScopedDocumentRef>>,
+
+    /// Extra parent environment variables the Bash tool may pass through,
+    /// in addition to its built-in safe defaults.
+    pub bash_env_allowlist: Vec<String>,
 }
 
 impl ToolExecutionContext {
@@ -192,6 +196,14 @@ pub trait Tool: Send + Sync {
         false
     }
 
+    /// Whether this tool can modify files, run commands, or otherwise change state.
+    ///
+    /// Mutating tools are hidden from read-only and plan-mode filter contexts.
+    /// Default: false.
+    fn is_mutating(&self) -> bool {
+        false
+    }
+
     /// Execute the tool with the given context and arguments.
     ///
     /// Returns a `ToolResult` indicating success/failure with output or error.
@@ -254,6 +266,21 @@ impl ToolRegistry {
             .collect()
     }
 
+    /// Get the definitions of tools allowed by a filter context.
+    /// Returned in registration order.
+    pub fn filtered_definitions(&self, filter_ctx: &ToolFilterContext) -> Vec<ToolDefinition> {
+        self.order
+            .iter()
+            .filter_map(|name| self.tools.get(name))
+            .filter(|tool| filter_ctx.allows_tool(tool.as_ref()))
+            .map(|tool| ToolDefinition {
+                name: tool.name().to_string(),
+                description: tool.description().to_string(),
+                input_schema: tool.parameters_schema(),
+            })
+            .collect()
+    }
+
     /// Get all registered tool names in registration order.
     pub fn names(&self) -> Vec<String> {
         self.order.clone()
@@ -335,7 +362,7 @@ impl ToolRegistry {
         let new_tools: Vec<Arc<dyn Tool>> = self
             .toolsets
             .iter()
-            .flat_map(|ts| ts.available_tools(filter_ctx))
+            .flat_map(|ts| ts.filtered(filter_ctx))
             .collect();
 
         // Re-register
@@ -465,6 +492,94 @@ pub struct ToolFilterContext {
     pub execution_phase: Option<String>,
     /// Tools explicitly allowed by a skill configuration
     pub skill_allowed_tools: Option<Vec<String>>,
+    /// Hide every mutating tool (read-only sessions)
+    pub read_only: bool,
+    /// Additional declarative rules; a tool must pass all of them
+    pub rules: Vec<ToolFilterRule>,
+}
+
+impl ToolFilterContext {
+    /// Context for a read-only session.
+    pub fn read_only() -> Self {
+        Self {
+            read_only: true,
+            ..Default::default()
+        }
+    }
+
+    /// Context for plan mode: the planning phase with mutating tools hidden.
+    pub fn plan_mode() -> Self {
+        Self {
+            execution_phase: Some("planning".to_string()),
+            rules: vec![ToolFilterRule::plan_mode()],
+            ..Default::default()
+        }
+    }
+
+    /// Add a rule.
+    pub fn with_rule(mut self, rule: ToolFilterRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Whether a tool with the given name and mutability is exposed.
+    pub fn allows(&self, name: &str, mutating: bool) -> bool {
+        if let Some(ref allowed) = self.skill_allowed_tools {
+            if !allowed.iter().any(|n| n == name) {
+                return false;
+            }
+        }
+        if self.read_only && mutating {
+            return false;
+        }
+        self.rules
+            .iter()
+            .all(|rule| rule.permits(name, mutating, self))
+    }
+
+    /// Whether a tool is exposed.
+    pub fn allows_tool(&self, tool: &dyn Tool) -> bool {
+        self.allows(tool.name(), tool.is_mutating())
+    }
+}
+
+/// A declarative, composable rule selecting which tools are exposed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ToolFilterRule {
+    /// Expose only the named tools
+    Allow(Vec<String>),
+    /// Hide the named tools
+    Deny(Vec<String>),
+    /// Hide mutating tools
+    ReadOnly,
+    /// Apply the inner rule only during the given execution phase
+    InPhase {
+        phase: String,
+        rule: Box<ToolFilterRule>,
+    },
+}
+
+impl ToolFilterRule {
+    /// Hide mutating tools during the planning phase.
+    pub fn plan_mode() -> Self {
+        ToolFilterRule::InPhase {
+            phase: "planning".to_string(),
+            rule: Box::new(ToolFilterRule::ReadOnly),
+        }
+    }
+
+    /// Whether this rule lets the tool through.
+    pub fn permits(&self, name: &str, mutating: bool, ctx: &ToolFilterContext) -> bool {
+        match self {
+            ToolFilterRule::Allow(names) => names.iter().any(|n| n == name),
+            ToolFilterRule::Deny(names) => !names.iter().any(|n| n == name),
+            ToolFilterRule::ReadOnly => !mutating,
+            ToolFilterRule::InPhase { phase, rule } => {
+                ctx.execution_phase.as_deref() != Some(phase.as_str())
+                    || rule.permits(name, mutating, ctx)
+            }
+        }
+    }
 }
 
 /// A collection of tools that can be dynamically filtered.
@@ -481,6 +596,14 @@ pub trait Toolset: Send + Sync {
 
     /// Human-readable name for this toolset.
     fn name(&self) -> &str;
+
+    /// Return the available tools that the filter context also allows.
+    fn filtered(&self, ctx: &ToolFilterContext) -> Vec<Arc<dyn Tool>> {
+        self.available_tools(ctx)
+            .into_iter()
+            .filter(|tool| ctx.allows_tool(tool.as_ref()))
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -577,6 +700,7 @@ mod tests {
             knowledge_project_id: None,
             knowledge_collection_filter: None,
             knowledge_document_filter: None,
+            bash_env_allowlist: Vec::new(),
         }
     }
 
@@ -862,6 +986,7 @@ mod tests {
             project_type: Some("rust".to_string()),
             execution_phase: Some("implementation".to_string()),
             skill_allowed_tools: Some(vec!["Read".to_string(), "Write".to_string()]),
+            ..Default::default()
         };
         assert_eq!(ctx.project_type.as_deref(), Some("rust"));
         assert_eq!(ctx.execution_phase.as_deref(), Some("implementation"));
@@ -932,6 +1057,7 @@ mod tests {
             project_type: Some("rust".to_string()),
             execution_phase: None,
             skill_allowed_tools: None,
+            ..Default::default()
         };
         registry.refresh_toolsets(&filter);
 
@@ -952,4 +1078,25 @@ mod tests {
         assert!(result.is_success());
         assert_eq!(result.success_message_owned().unwrap(), "ToolsetA executed");
     }
+
+    #[test]
+    fn test_toolset_filtered_applies_rules() {
+        let toolset = MockToolset::new("TestToolset");
+        let ctx = ToolFilterContext {
+            project_type: Some("rust".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(toolset.filtered(&ctx).len(), 2);
+
+        let ctx = ctx.with_rule(ToolFilterRule::Deny(vec!["ToolsetB".to_string()]));
+        let names: Vec<String> = toolset
+            .filtered(&ctx)
+            .iter()
+            .map(|t| t.name().to_string())
+            .collect();
+        assert_eq!(names, vec!["ToolsetA".to_string()]);
+
+        let ctx = ctx.with_rule(ToolFilterRule::Allow(vec!["ToolsetB".to_string()]));
+        assert!(toolset.filtered(&ctx).is_empty());
+    }
 }
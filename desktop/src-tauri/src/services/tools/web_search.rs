@@ -1,6 +1,6 @@
 //! WebSearch Service
 //!
-//! Pluggable web search with support for Tavily, Brave Search, DuckDuckGo, and SearXNG providers.
+//! Pluggable web search with support for Tavily, Brave Search, DuckDuckGo, SearXNG, and Kagi backends.
 
 use async_trait::async_trait;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -9,6 +9,13 @@ use std::time::Instant;
 
 /// Maximum search requests per minute
 const MAX_SEARCHES_PER_MIN: u32 = 20;
+/// Backend results requested per returned result, so that deduplication and
+/// domain caps can still fill the result budget.
+const SEARCH_OVERSAMPLE_FACTOR: u32 = 2;
+/// Upper bound on results requested from a backend in one call.
+const MAX_BACKEND_RESULTS: u32 = 20;
+/// Query parameters that only carry tracking state and never change content.
+const TRACKING_QUERY_PARAMS: &[&str] = &["fbclid", "gclid", "msclkid", "ref", "ref_src"];
 
 /// A search result entry
 #[derive(Debug, Clone)]
@@ -25,6 +32,8 @@ pub struct SearchConstraints {
     pub allow_domains: Option<Vec<String>>,
     /// Results from these domains are always excluded.
     pub block_domains: Vec<String>,
+    /// If set, at most this many results are kept per domain.
+    pub max_per_domain: Option<usize>,
 }
 
 impl SearchConstraints {
@@ -36,8 +45,25 @@ impl SearchConstraints {
         Self {
             allow_domains,
             block_domains,
+            max_per_domain: None,
         }
     }
+
+    /// Keep at most `max` results from any single domain (0 disables the cap).
+    pub fn with_max_per_domain(mut self, max: usize) -> Self {
+        self.max_per_domain = (max > 0).then_some(max);
+        self
+    }
+}
+
+/// Results of a search after filtering, deduplication, and domain capping.
+#[derive(Debug, Clone, Default)]
+pub struct SearchOutcome {
+    pub results: Vec<SearchResult>,
+    /// Results dropped because their normalized URL or content was already seen.
+    pub duplicates_removed: usize,
+    /// Results dropped by `SearchConstraints::max_per_domain`.
+    pub domain_capped: usize,
 }
 
 fn normalize_domain(value: &str) -> Option<String> {
@@ -107,170 +133,341 @@ fn is_result_allowed(url: &str, constraints: &SearchConstraints) -> bool {
     }
 }
 
-/// Trait for pluggable search providers
+/// Error returned by search backends, normalized across providers.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SearchError {
+    #[error("Search query cannot be empty")]
+    EmptyQuery,
+    #[error("Rate limited: too many search requests (max {limit} per minute)")]
+    RateLimited { limit: u32 },
+    #[error("{backend} request failed: {message}")]
+    Request {
+        backend: &'static str,
+        message: String,
+    },
+    #[error("{backend} API error ({status}): {body}")]
+    Api {
+        backend: &'static str,
+        status: u16,
+        body: String,
+    },
+    #[error("Failed to parse {backend} response: {message}")]
+    InvalidResponse {
+        backend: &'static str,
+        message: String,
+    },
+}
+
+impl SearchError {
+    /// Whether retrying the same query later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::EmptyQuery | Self::InvalidResponse { .. } => false,
+            Self::RateLimited { .. } | Self::Request { .. } => true,
+            Self::Api { status, .. } => *status == 429 || *status >= 500,
+        }
+    }
+}
+
+/// Trait for pluggable search backends.
+///
+/// Each backend turns a query into ranked results with title/url/snippet;
+/// `WebSearchService` normalizes and filters them afterwards.
 #[async_trait]
-pub trait SearchProvider: Send + Sync {
-    /// Provider name for display
-    fn name(&self) -> &str;
+pub trait SearchBackend: Send + Sync {
+    /// Backend name for display
+    fn name(&self) -> &'static str;
 
     /// Execute a search query
-    async fn search(&self, query: &str, max_results: u32) -> Result<Vec<SearchResult>, String>;
+    async fn search(&self, query: &str, max_results: u32)
+        -> Result<Vec<SearchResult>, SearchError>;
+}
+
+/// Send a request and decode a JSON body, mapping transport and HTTP errors.
+async fn fetch_json(
+    backend: &'static str,
+    request: reqwest::RequestBuilder,
+) -> Result<serde_json::Value, SearchError> {
+    let response = request.send().await.map_err(|e| SearchError::Request {
+        backend,
+        message: e.to_string(),
+    })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(SearchError::Api {
+            backend,
+            status: status.as_u16(),
+            body: response.text().await.unwrap_or_default(),
+        });
+    }
+
+    response
+        .json()
+        .await
+        .map_err(|e| SearchError::InvalidResponse {
+            backend,
+            message: e.to_string(),
+        })
+}
+
+/// Build results from a JSON array using backend-specific field names.
+fn results_from_array(
+    items: Option<&serde_json::Value>,
+    title_key: &str,
+    url_key: &str,
+    snippet_key: &str,
+) -> Vec<SearchResult> {
+    let field = |item: &serde_json::Value, key: &str| {
+        item.get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or("")
+            .to_string()
+    };
+    items
+        .and_then(|r| r.as_array())
+        .map(|arr| {
+            arr.iter()
+                .map(|item| SearchResult {
+                    title: field(item, title_key),
+                    url: field(item, url_key),
+                    snippet: field(item, snippet_key),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Normalize backend results: strip markup, trim whitespace, and drop
+/// entries without a URL.
+fn normalize_results(results: Vec<SearchResult>) -> Vec<SearchResult> {
+    results
+        .into_iter()
+        .filter_map(|r| {
+            let url = r.url.trim().to_string();
+            if url.is_empty() {
+                return None;
+            }
+            Some(SearchResult {
+                title: strip_html_tags(&r.title),
+                url,
+                snippet: strip_html_tags(&r.snippet),
+            })
+        })
+        .collect()
+}
+
+/// Canonical form of a result URL used for deduplication: scheme, `www.`,
+/// fragment, tracking parameters, and trailing slashes are ignored, and the
+/// remaining query parameters are sorted.
+fn normalize_result_url(url: &str) -> String {
+    let Ok(parsed) = url::Url::parse(url) else {
+        return url.trim().trim_end_matches('/').to_ascii_lowercase();
+    };
+    let host = parsed
+        .host_str()
+        .map(|h| h.to_ascii_lowercase())
+        .unwrap_or_default();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let path = parsed.path().trim_end_matches('/');
+    let mut params = parsed
+        .query_pairs()
+        .filter(|(key, _)| {
+            !key.starts_with("utm_") && !TRACKING_QUERY_PARAMS.contains(&key.as_ref())
+        })
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>();
+    params.sort();
+    if params.is_empty() {
+        format!("{}{}", host, path)
+    } else {
+        format!("{}{}?{}", host, path, params.join("&"))
+    }
+}
+
+/// Canonical content key (case-folded alphanumeric words of title and snippet).
+/// Returns `None` when a result has no textual content to compare.
+fn canonical_content(result: &SearchResult) -> Option<String> {
+    let words = format!("{} {}", result.title, result.snippet)
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!words.is_empty()).then_some(words)
+}
+
+/// Drop duplicate results (by normalized URL or canonical content), apply the
+/// optional per-domain cap, and keep at most `max_results`. Earlier results win.
+pub fn dedupe_results(
+    results: Vec<SearchResult>,
+    max_per_domain: Option<usize>,
+    max_results: usize,
+) -> SearchOutcome {
+    let mut seen_urls = std::collections::HashSet::new();
+    let mut seen_content = std::collections::HashSet::new();
+    let mut per_domain: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    let mut outcome = SearchOutcome::default();
+
+    for result in results {
+        if outcome.results.len() >= max_results {
+            break;
+        }
+        let url_key = normalize_result_url(&result.url);
+        let content_key = canonical_content(&result);
+        if seen_urls.contains(&url_key)
+            || content_key
+                .as_ref()
+                .is_some_and(|key| seen_content.contains(key))
+        {
+            outcome.duplicates_removed += 1;
+            continue;
+        }
+        if let Some(cap) = max_per_domain {
+            let domain = extract_result_domain(&result.url).unwrap_or_default();
+            let count = per_domain.entry(domain).or_insert(0);
+            if *count >= cap {
+                outcome.domain_capped += 1;
+                continue;
+            }
+            *count += 1;
+        }
+        seen_urls.insert(url_key);
+        if let Some(key) = content_key {
+            seen_content.insert(key);
+        }
+        outcome.results.push(result);
+    }
+    outcome
+}
+
+fn parse_tavily_response(data: &serde_json::Value) -> Vec<SearchResult> {
+    results_from_array(data.get("results"), "title", "url", "content")
 }
 
-/// Tavily search provider (requires API key)
-struct TavilyProvider {
+fn parse_brave_response(data: &serde_json::Value) -> Vec<SearchResult> {
+    results_from_array(
+        data.get("web").and_then(|w| w.get("results")),
+        "title",
+        "url",
+        "description",
+    )
+}
+
+fn parse_searxng_response(data: &serde_json::Value) -> Vec<SearchResult> {
+    results_from_array(data.get("results"), "title", "url", "content")
+}
+
+/// Kagi returns search hits (`t == 0`) mixed with related-search entries
+/// (`t == 1`); only the former are results.
+fn parse_kagi_response(data: &serde_json::Value) -> Result<Vec<SearchResult>, SearchError> {
+    if let Some(message) = data
+        .get("error")
+        .and_then(|e| e.as_array())
+        .and_then(|errors| errors.first())
+        .and_then(|e| e.get("msg"))
+        .and_then(|m| m.as_str())
+    {
+        return Err(SearchError::InvalidResponse {
+            backend: "Kagi",
+            message: message.to_string(),
+        });
+    }
+
+    let hits = data
+        .get("data")
+        .and_then(|d| d.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter(|item| item.get("t").and_then(|t| t.as_u64()) == Some(0))
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    Ok(results_from_array(
+        Some(&serde_json::Value::Array(hits)),
+        "title",
+        "url",
+        "snippet",
+    ))
+}
+
+/// Tavily search backend (requires API key)
+struct TavilyBackend {
     client: reqwest::Client,
     api_key: String,
 }
 
 #[async_trait]
-impl SearchProvider for TavilyProvider {
-    fn name(&self) -> &str {
+impl SearchBackend for TavilyBackend {
+    fn name(&self) -> &'static str {
         "Tavily"
     }
 
-    async fn search(&self, query: &str, max_results: u32) -> Result<Vec<SearchResult>, String> {
+    async fn search(
+        &self,
+        query: &str,
+        max_results: u32,
+    ) -> Result<Vec<SearchResult>, SearchError> {
         let body = serde_json::json!({
             "api_key": self.api_key,
             "query": query,
             "max_results": max_results,
             "include_answer": false,
         });
-
-        let response = self
+        let request = self
             .client
             .post("https://api.tavily.com/search")
-            .json(&body)
-            .send()
-            .await
-            .map_err(|e| format!("Tavily request failed: {}", e))?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let err_body = response.text().await.unwrap_or_default();
-            return Err(format!(
-                "Tavily API error ({}): {}",
-                status.as_u16(),
-                err_body
-            ));
-        }
-
-        let data: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse Tavily response: {}", e))?;
-
-        let results = data
-            .get("results")
-            .and_then(|r| r.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .map(|item| SearchResult {
-                        title: item
-                            .get("title")
-                            .and_then(|t| t.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        url: item
-                            .get("url")
-                            .and_then(|u| u.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        snippet: item
-                            .get("content")
-                            .and_then(|c| c.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        Ok(results)
+            .json(&body);
+        let data = fetch_json(self.name(), request).await?;
+        Ok(parse_tavily_response(&data))
     }
 }
 
-/// Brave Search provider (requires API key)
-struct BraveSearchProvider {
+/// Brave Search backend (requires API key)
+struct BraveSearchBackend {
     client: reqwest::Client,
     api_key: String,
 }
 
 #[async_trait]
-impl SearchProvider for BraveSearchProvider {
-    fn name(&self) -> &str {
+impl SearchBackend for BraveSearchBackend {
+    fn name(&self) -> &'static str {
         "Brave Search"
     }
 
-    async fn search(&self, query: &str, max_results: u32) -> Result<Vec<SearchResult>, String> {
-        let response = self
+    async fn search(
+        &self,
+        query: &str,
+        max_results: u32,
+    ) -> Result<Vec<SearchResult>, SearchError> {
+        let request = self
             .client
             .get("https://api.search.brave.com/res/v1/web/search")
             .header("X-Subscription-Token", &self.api_key)
             .header("Accept", "application/json")
-            .query(&[("q", query), ("count", &max_results.to_string())])
-            .send()
-            .await
-            .map_err(|e| format!("Brave Search request failed: {}", e))?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let err_body = response.text().await.unwrap_or_default();
-            return Err(format!(
-                "Brave Search API error ({}): {}",
-                status.as_u16(),
-                err_body
-            ));
-        }
-
-        let data: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse Brave Search response: {}", e))?;
-
-        let results = data
-            .get("web")
-            .and_then(|w| w.get("results"))
-            .and_then(|r| r.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .map(|item| SearchResult {
-                        title: item
-                            .get("title")
-                            .and_then(|t| t.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        url: item
-                            .get("url")
-                            .and_then(|u| u.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        snippet: item
-                            .get("description")
-                            .and_then(|d| d.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        Ok(results)
+            .query(&[("q", query), ("count", &max_results.to_string())]);
+        let data = fetch_json(self.name(), request).await?;
+        Ok(parse_brave_response(&data))
     }
 }
 
-/// DuckDuckGo search provider (no API key required, scrapes HTML results)
-struct DuckDuckGoProvider {
+/// DuckDuckGo search backend (no API key required, scrapes HTML results)
+struct DuckDuckGoBackend {
     client: reqwest::Client,
 }
 
 #[async_trait]
-impl SearchProvider for DuckDuckGoProvider {
-    fn name(&self) -> &str {
+impl SearchBackend for DuckDuckGoBackend {
+    fn name(&self) -> &'static str {
         "DuckDuckGo"
     }
 
-    async fn search(&self, query: &str, max_results: u32) -> Result<Vec<SearchResult>, String> {
+    async fn search(
+        &self,
+        query: &str,
+        max_results: u32,
+    ) -> Result<Vec<SearchResult>, SearchError> {
         let response = self
             .client
             .post("https://html.duckduckgo.com/html/")
@@ -278,107 +475,118 @@ impl SearchProvider for DuckDuckGoProvider {
             .body(format!("q={}", urlencoding::encode(query)))
             .send()
             .await
-            .map_err(|e| format!("DuckDuckGo request failed: {}", e))?;
+            .map_err(|e| SearchError::Request {
+                backend: self.name(),
+                message: e.to_string(),
+            })?;
 
         let html = response
             .text()
             .await
-            .map_err(|e| format!("Failed to read DuckDuckGo response: {}", e))?;
-
-        let mut results = Vec::new();
-        // Parse result blocks: each result is in a div with class "result"
-        // Links are in <a class="result__a" href="...">Title</a>
-        // Snippets are in <a class="result__snippet" ...>text</a>
-        let mut pos = 0;
-        while results.len() < max_results as usize {
-            // Find next result link
-            let link_marker = "class=\"result__a\"";
-            let link_start = match html[pos..].find(link_marker) {
-                Some(i) => pos + i,
-                None => break,
-            };
-
-            // Extract href from the <a> tag
-            let href_start = match html[..link_start].rfind("href=\"") {
-                Some(i) => i + 6,
-                None => {
-                    pos = link_start + link_marker.len();
-                    continue;
-                }
-            };
-            let href_end = match html[href_start..].find('"') {
-                Some(i) => href_start + i,
-                None => {
-                    pos = link_start + link_marker.len();
-                    continue;
-                }
-            };
-            let raw_url = &html[href_start..href_end];
-
-            // DuckDuckGo wraps URLs in a redirect: extract the actual URL
-            let url = if raw_url.contains("uddg=") {
-                raw_url
-                    .split("uddg=")
-                    .nth(1)
-                    .and_then(|u| u.split('&').next())
-                    .map(|u| urlencoding::decode(u).unwrap_or_default().to_string())
-                    .unwrap_or_else(|| raw_url.to_string())
-            } else {
-                raw_url.to_string()
-            };
-
-            // Extract title: text between > and </a> after the link_marker
-            let title_start = match html[link_start..].find('>') {
-                Some(i) => link_start + i + 1,
-                None => {
-                    pos = link_start + link_marker.len();
-                    continue;
-                }
-            };
-            let title_end = match html[title_start..].find("</a>") {
-                Some(i) => title_start + i,
-                None => {
-                    pos = link_start + link_marker.len();
-                    continue;
-                }
-            };
-            let title = strip_html_tags(&html[title_start..title_end]);
-
-            // Extract snippet
-            pos = title_end;
-            let snippet_marker = "class=\"result__snippet\"";
-            let snippet = if let Some(snippet_pos) = html[pos..].find(snippet_marker) {
-                let snippet_abs = pos + snippet_pos;
-                if let Some(snippet_content_start) = html[snippet_abs..].find('>') {
-                    let s_start = snippet_abs + snippet_content_start + 1;
-                    if let Some(s_end) = html[s_start..]
-                        .find("</a>")
-                        .or_else(|| html[s_start..].find("</span>"))
-                    {
-                        strip_html_tags(&html[s_start..s_start + s_end])
-                    } else {
-                        String::new()
-                    }
+            .map_err(|e| SearchError::InvalidResponse {
+                backend: self.name(),
+                message: e.to_string(),
+            })?;
+
+        Ok(parse_duckduckgo_html(&html, max_results))
+    }
+}
+
+/// Extract results from a DuckDuckGo HTML results page.
+fn parse_duckduckgo_html(html: &str, max_results: u32) -> Vec<SearchResult> {
+    let mut results = Vec::new();
+    // Parse result blocks: each result is in a div with class "result"
+    // Links are in <a class="result__a" href="...">Title</a>
+    // Snippets are in <a class="result__snippet" ...>text</a>
+    let mut pos = 0;
+    while results.len() < max_results as usize {
+        // Find next result link
+        let link_marker = "class=\"result__a\"";
+        let link_start = match html[pos..].find(link_marker) {
+            Some(i) => pos + i,
+            None => break,
+        };
+
+        // Extract href from the <a> tag
+        let href_start = match html[..link_start].rfind("href=\"") {
+            Some(i) => i + 6,
+            None => {
+                pos = link_start + link_marker.len();
+                continue;
+            }
+        };
+        let href_end = match html[href_start..].find('"') {
+            Some(i) => href_start + i,
+            None => {
+                pos = link_start + link_marker.len();
+                continue;
+            }
+        };
+        let raw_url = &html[href_start..href_end];
+
+        // DuckDuckGo wraps URLs in a redirect: extract the actual URL
+        let url = if raw_url.contains("uddg=") {
+            raw_url
+                .split("uddg=")
+                .nth(1)
+                .and_then(|u| u.split('&').next())
+                .map(|u| urlencoding::decode(u).unwrap_or_default().to_string())
+                .unwrap_or_else(|| raw_url.to_string())
+        } else {
+            raw_url.to_string()
+        };
+
+        // Extract title: text between > and </a> after the link_marker
+        let title_start = match html[link_start..].find('>') {
+            Some(i) => link_start + i + 1,
+            None => {
+                pos = link_start + link_marker.len();
+                continue;
+            }
+        };
+        let title_end = match html[title_start..].find("</a>") {
+            Some(i) => title_start + i,
+            None => {
+                pos = link_start + link_marker.len();
+                continue;
+            }
+        };
+        let title = strip_html_tags(&html[title_start..title_end]);
+
+        // Extract snippet
+        pos = title_end;
+        let snippet_marker = "class=\"result__snippet\"";
+        let snippet = if let Some(snippet_pos) = html[pos..].find(snippet_marker) {
+            let snippet_abs = pos + snippet_pos;
+            if let Some(snippet_content_start) = html[snippet_abs..].find('>') {
+                let s_start = snippet_abs + snippet_content_start + 1;
+                if let Some(s_end) = html[s_start..]
+                    .find("</a>")
+                    .or_else(|| html[s_start..].find("</span>"))
+                {
+                    strip_html_tags(&html[s_start..s_start + s_end])
                 } else {
                     String::new()
                 }
             } else {
                 String::new()
-            };
-
-            if !url.is_empty() && !title.is_empty() {
-                results.push(SearchResult {
-                    title,
-                    url,
-                    snippet,
-                });
             }
+        } else {
+            String::new()
+        };
 
-            pos = title_end + 1;
+        if !url.is_empty() && !title.is_empty() {
+            results.push(SearchResult {
+                title,
+                url,
+                snippet,
+            });
         }
 
-        Ok(results)
+        pos = title_end + 1;
     }
+
+    results
 }
 
 /// Strip HTML tags and decode common HTML entities from a string.
@@ -406,80 +614,67 @@ fn strip_html_tags(html: &str) -> String {
         .to_string()
 }
 
-/// SearXNG self-hosted search provider.
+/// SearXNG self-hosted search backend.
 ///
 /// Queries a SearXNG instance's JSON API. The base URL is configured via
 /// the `api_key` field (e.g., "https://searxng.example.com").
-struct SearxngProvider {
+struct SearxngBackend {
     client: reqwest::Client,
     base_url: String,
 }
 
 #[async_trait]
-impl SearchProvider for SearxngProvider {
-    fn name(&self) -> &str {
+impl SearchBackend for SearxngBackend {
+    fn name(&self) -> &'static str {
         "SearXNG"
     }
 
-    async fn search(&self, query: &str, max_results: u32) -> Result<Vec<SearchResult>, String> {
+    async fn search(
+        &self,
+        query: &str,
+        _max_results: u32,
+    ) -> Result<Vec<SearchResult>, SearchError> {
         let url = format!("{}/search", self.base_url.trim_end_matches('/'));
-        let response = self
-            .client
-            .get(&url)
-            .query(&[("q", query), ("format", "json"), ("categories", "general")])
-            .send()
-            .await
-            .map_err(|e| format!("SearXNG request failed: {}", e))?;
-
-        let status = response.status();
-        if !status.is_success() {
-            let err_body = response.text().await.unwrap_or_default();
-            return Err(format!(
-                "SearXNG API error ({}): {}",
-                status.as_u16(),
-                err_body
-            ));
-        }
+        let request = self.client.get(&url).query(&[
+            ("q", query),
+            ("format", "json"),
+            ("categories", "general"),
+        ]);
+        let data = fetch_json(self.name(), request).await?;
+        Ok(parse_searxng_response(&data))
+    }
+}
 
-        let data: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse SearXNG response: {}", e))?;
-
-        let results = data
-            .get("results")
-            .and_then(|r| r.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .take(max_results as usize)
-                    .map(|item| SearchResult {
-                        title: item
-                            .get("title")
-                            .and_then(|t| t.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        url: item
-                            .get("url")
-                            .and_then(|u| u.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                        snippet: item
-                            .get("content")
-                            .and_then(|c| c.as_str())
-                            .unwrap_or("")
-                            .to_string(),
-                    })
-                    .collect()
-            })
-            .unwrap_or_default();
+/// Kagi Search API backend (requires API key).
+struct KagiBackend {
+    client: reqwest::Client,
+    api_key: String,
+}
+
+#[async_trait]
+impl SearchBackend for KagiBackend {
+    fn name(&self) -> &'static str {
+        "Kagi"
+    }
 
-        Ok(results)
+    async fn search(
+        &self,
+        query: &str,
+        max_results: u32,
+    ) -> Result<Vec<SearchResult>, SearchError> {
+        let request = self
+            .client
+            .get("https://kagi.com/api/v0/search")
+            .header("Authorization", format!("Bot {}", self.api_key))
+            .query(&[("q", query), ("limit", &max_results.to_string())]);
+        let data = fetch_json(self.name(), request).await?;
+        parse_kagi_response(&data)
     }
 }
 
-/// WebSearch service with pluggable provider
+/// WebSearch service with pluggable backend
 pub struct WebSearchService {
-    provider: Box<dyn SearchProvider>,
+    provider: Box<dyn SearchBackend>,
     request_count: AtomicU32,
     window_start: Mutex<Instant>,
 }
@@ -490,6 +685,7 @@ impl WebSearchService {
     /// - `"tavily"` requires an API key
     /// - `"brave"` requires an API key
     /// - `"searxng"` requires a base URL (passed via the `api_key` parameter)
+    /// - `"kagi"` requires an API key
     /// - `"duckduckgo"` works without an API key (scrapes HTML results)
     pub fn new(provider_name: &str, api_key: Option<&str>) -> Result<Self, String> {
         let client = reqwest::Client::builder()
@@ -498,13 +694,13 @@ impl WebSearchService {
             .build()
             .unwrap_or_else(|_| reqwest::Client::new());
 
-        let provider: Box<dyn SearchProvider> = match provider_name.to_lowercase().as_str() {
+        let provider: Box<dyn SearchBackend> = match provider_name.to_lowercase().as_str() {
             "tavily" => {
                 let key = api_key.filter(|k| !k.is_empty()).ok_or_else(|| {
                     "Tavily requires an API key. Configure it in Settings > LLM Backend."
                         .to_string()
                 })?;
-                Box::new(TavilyProvider {
+                Box::new(TavilyBackend {
                     client,
                     api_key: key.to_string(),
                 })
@@ -514,7 +710,7 @@ impl WebSearchService {
                     "Brave Search requires an API key. Configure it in Settings > LLM Backend."
                         .to_string()
                 })?;
-                Box::new(BraveSearchProvider {
+                Box::new(BraveSearchBackend {
                     client,
                     api_key: key.to_string(),
                 })
@@ -523,25 +719,39 @@ impl WebSearchService {
                 let base_url = api_key.filter(|k| !k.is_empty()).ok_or_else(|| {
                     "SearXNG requires a base URL. Configure it in Settings > LLM Backend (use the API key field for the base URL).".to_string()
                 })?;
-                Box::new(SearxngProvider {
+                Box::new(SearxngBackend {
                     client,
                     base_url: base_url.to_string(),
                 })
             }
-            "duckduckgo" | "" => Box::new(DuckDuckGoProvider { client }),
+            "kagi" => {
+                let key = api_key.filter(|k| !k.is_empty()).ok_or_else(|| {
+                    "Kagi requires an API key. Configure it in Settings > LLM Backend.".to_string()
+                })?;
+                Box::new(KagiBackend {
+                    client,
+                    api_key: key.to_string(),
+                })
+            }
+            "duckduckgo" | "" => Box::new(DuckDuckGoBackend { client }),
             other => {
                 return Err(format!(
-                    "Unknown search provider: '{}'. Supported: tavily, brave, duckduckgo, searxng",
+                    "Unknown search provider: '{}'. Supported: tavily, brave, duckduckgo, searxng, kagi",
                     other
                 ))
             }
         };
 
-        Ok(Self {
+        Ok(Self::from_backend(provider))
+    }
+
+    /// Create a service around an already constructed backend.
+    pub(crate) fn from_backend(provider: Box<dyn SearchBackend>) -> Self {
+        Self {
             provider,
             request_count: AtomicU32::new(0),
             window_start: Mutex::new(Instant::now()),
-        })
+        }
     }
 
     /// Execute a web search and return structured results.
@@ -550,7 +760,7 @@ impl WebSearchService {
         query: &str,
         max_results: Option<u32>,
         constraints: &SearchConstraints,
-    ) -> Result<Vec<SearchResult>, String> {
+    ) -> Result<SearchOutcome, SearchError> {
         let max_results = max_results.unwrap_or(5).min(10);
 
         // Sanitize query: strip control chars
@@ -560,7 +770,7 @@ impl WebSearchService {
             .collect();
 
         if query.trim().is_empty() {
-            return Err("Search query cannot be empty".to_string());
+            return Err(SearchError::EmptyQuery);
         }
 
         // Rate limit check
@@ -574,18 +784,22 @@ impl WebSearchService {
         }
         let count = self.request_count.fetch_add(1, Ordering::Relaxed);
         if count >= MAX_SEARCHES_PER_MIN {
-            return Err(format!(
-                "Rate limited: too many search requests (max {} per minute)",
-                MAX_SEARCHES_PER_MIN
-            ));
+            return Err(SearchError::RateLimited {
+                limit: MAX_SEARCHES_PER_MIN,
+            });
         }
 
-        let raw_results = self.provider.search(&query, max_results).await?;
-        let filtered = raw_results
+        let requested = (max_results * SEARCH_OVERSAMPLE_FACTOR).min(MAX_BACKEND_RESULTS);
+        let raw_results = self.provider.search(&query, requested).await?;
+        let filtered = normalize_results(raw_results)
             .into_iter()
             .filter(|r| is_result_allowed(&r.url, constraints))
             .collect::<Vec<_>>();
-        Ok(filtered)
+        Ok(dedupe_results(
+            filtered,
+            constraints.max_per_domain,
+            max_results as usize,
+        ))
     }
 
     /// Format structured search results as markdown text.
@@ -613,23 +827,27 @@ impl WebSearchService {
     }
 
     /// Execute a web search and format results as markdown.
-    pub async fn search(&self, query: &str, max_results: Option<u32>) -> Result<String, String> {
+    pub async fn search(
+        &self,
+        query: &str,
+        max_results: Option<u32>,
+    ) -> Result<String, SearchError> {
         let normalized_query: String = query
             .chars()
             .filter(|c| !c.is_control() || *c == ' ')
             .collect();
-        let results = self
+        let outcome = self
             .search_with_constraints(
                 &normalized_query,
                 max_results,
                 &SearchConstraints::default(),
             )
             .await?;
-        Ok(self.format_results_markdown(&normalized_query, &results))
+        Ok(self.format_results_markdown(&normalized_query, &outcome.results))
     }
 
     /// Get the name of the underlying search provider
-    pub fn provider_name(&self) -> &str {
+    pub fn provider_name(&self) -> &'static str {
         self.provider.name()
     }
 }
@@ -691,6 +909,209 @@ mod tests {
         assert!(service.is_err());
     }
 
+    #[test]
+    fn test_create_kagi_provider() {
+        let service = WebSearchService::new("kagi", Some("kagi-token"));
+        assert!(service.is_ok());
+        assert_eq!(service.unwrap().provider_name(), "Kagi");
+    }
+
+    #[test]
+    fn test_kagi_requires_key() {
+        assert!(WebSearchService::new("kagi", None).is_err());
+        assert!(WebSearchService::new("kagi", Some("")).is_err());
+    }
+
+    #[test]
+    fn test_parse_searxng_response_normalizes_results() {
+        let data = serde_json::json!({
+            "query": "rust async",
+            "results": [
+                {
+                    "title": "  <b>Async</b> Rust  ",
+                    "url": "https://rust-lang.github.io/async-book/",
+                    "content": "The <em>async</em> book &amp; guide",
+                    "engine": "duckduckgo"
+                },
+                { "title": "No URL", "content": "dropped" },
+                {
+                    "title": "Async Rust (duplicate)",
+                    "url": "https://rust-lang.github.io/async-book/",
+                    "content": "duplicate"
+                },
+                { "title": "Tokio", "url": "https://tokio.rs", "content": "Runtime" }
+            ]
+        });
+        let results =
+            dedupe_results(normalize_results(parse_searxng_response(&data)), None, 10).results;
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "Async Rust");
+        assert_eq!(results[0].url, "https://rust-lang.github.io/async-book/");
+        assert_eq!(results[0].snippet, "The async book & guide");
+        assert_eq!(results[1].url, "https://tokio.rs");
+
+        let capped = dedupe_results(normalize_results(parse_searxng_response(&data)), None, 1);
+        assert_eq!(capped.results.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_kagi_response_keeps_search_hits_only() {
+        let data = serde_json::json!({
+            "meta": { "id": "abc", "ms": 120 },
+            "data": [
+                {
+                    "t": 0,
+                    "rank": 1,
+                    "url": "https://doc.rust-lang.org/book/",
+                    "title": "The Rust Programming Language",
+                    "snippet": "An introductory book about Rust."
+                },
+                { "t": 1, "list": ["rust book pdf", "rust by example"] },
+                {
+                    "t": 0,
+                    "rank": 2,
+                    "url": "https://doc.rust-lang.org/rust-by-example/",
+                    "title": "Rust by Example",
+                    "snippet": "A collection of runnable examples."
+                }
+            ]
+        });
+        let results = normalize_results(parse_kagi_response(&data).unwrap());
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].title, "The Rust Programming Language");
+        assert_eq!(results[0].snippet, "An introductory book about Rust.");
+        assert_eq!(results[1].url, "https://doc.rust-lang.org/rust-by-example/");
+    }
+
+    #[test]
+    fn test_parse_kagi_error_maps_to_search_error() {
+        let data = serde_json::json!({
+            "meta": { "id": "abc" },
+            "data": null,
+            "error": [{ "code": 1, "msg": "Insufficient credit" }]
+        });
+        let err = parse_kagi_response(&data).unwrap_err();
+        assert_eq!(
+            err,
+            SearchError::InvalidResponse {
+                backend: "Kagi",
+                message: "Insufficient credit".to_string(),
+            }
+        );
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_search_error_retryability() {
+        let api = |status| SearchError::Api {
+            backend: "SearXNG",
+            status,
+            body: String::new(),
+        };
+        assert!(api(429).is_retryable());
+        assert!(api(503).is_retryable());
+        assert!(!api(401).is_retryable());
+        assert!(SearchError::RateLimited { limit: 20 }.is_retryable());
+        assert!(!SearchError::EmptyQuery.is_retryable());
+        assert_eq!(api(401).to_string(), "SearXNG API error (401): ");
+    }
+
+    fn result(title: &str, url: &str, snippet: &str) -> SearchResult {
+        SearchResult {
+            title: title.to_string(),
+            url: url.to_string(),
+            snippet: snippet.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_dedupe_collapses_duplicate_urls() {
+        let results = vec![
+            result("Tokio", "https://tokio.rs/tokio/tutorial", "Tutorial intro"),
+            result(
+                "Tokio tutorial",
+                "http://www.tokio.rs/tokio/tutorial/?utm_source=feed#setup",
+                "Different snippet",
+            ),
+            result(
+                "Async book",
+                "https://rust-lang.github.io/async-book",
+                "Book",
+            ),
+        ];
+        let outcome = dedupe_results(results, None, 10);
+        assert_eq!(outcome.results.len(), 2);
+        assert_eq!(outcome.duplicates_removed, 1);
+        assert_eq!(outcome.results[0].url, "https://tokio.rs/tokio/tutorial");
+    }
+
+    #[test]
+    fn test_dedupe_collapses_duplicate_content() {
+        let results = vec![
+            result(
+                "Rust Book",
+                "https://doc.rust-lang.org/book/",
+                "Learn Rust.",
+            ),
+            result(
+                "rust book",
+                "https://mirror.example.com/book/",
+                "Learn   Rust!",
+            ),
+        ];
+        let outcome = dedupe_results(results, None, 10);
+        assert_eq!(outcome.results.len(), 1);
+        assert_eq!(outcome.duplicates_removed, 1);
+    }
+
+    #[test]
+    fn test_domain_cap_limits_single_site() {
+        let results = (0..5)
+            .map(|i| {
+                result(
+                    &format!("Page {}", i),
+                    &format!("https://docs.example.com/page{}", i),
+                    &format!("snippet {}", i),
+                )
+            })
+            .chain(std::iter::once(result(
+                "Other",
+                "https://other.org/",
+                "other",
+            )))
+            .collect::<Vec<_>>();
+        let outcome = dedupe_results(results, Some(2), 10);
+        let from_example = outcome
+            .results
+            .iter()
+            .filter(|r| r.url.contains("docs.example.com"))
+            .count();
+        assert_eq!(from_example, 2);
+        assert_eq!(outcome.domain_capped, 3);
+        assert_eq!(outcome.results.len(), 3);
+        assert_eq!(outcome.results[2].url, "https://other.org/");
+    }
+
+    #[test]
+    fn test_normalize_result_url_ignores_tracking_and_order() {
+        assert_eq!(
+            normalize_result_url("https://Example.com/a/?b=2&a=1&utm_medium=x&fbclid=y"),
+            normalize_result_url("http://www.example.com/a?a=1&b=2")
+        );
+        assert_ne!(
+            normalize_result_url("https://example.com/a?id=1"),
+            normalize_result_url("https://example.com/a?id=2")
+        );
+    }
+
+    #[test]
+    fn test_max_per_domain_zero_disables_cap() {
+        let constraints = SearchConstraints::default().with_max_per_domain(0);
+        assert_eq!(constraints.max_per_domain, None);
+        let constraints = SearchConstraints::default().with_max_per_domain(2);
+        assert_eq!(constraints.max_per_domain, Some(2));
+    }
+
     #[test]
     fn test_search_constraints_normalization() {
         let constraints = SearchConstraints::new(
@@ -96,6 +96,7 @@ impl WebhookChannel for DiscordChannel {
             WebhookEventType::StoryComplete => 0x3498DB,
             WebhookEventType::PrdComplete => 0x9B59B6,
             WebhookEventType::ProgressMilestone => 0x1ABC9C,
+            WebhookEventType::BudgetAlert => 0xE67E22,
         };
 
         let mut fields = Vec::new();
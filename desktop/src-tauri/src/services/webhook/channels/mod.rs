@@ -87,18 +87,21 @@ pub(super) fn localized_event_name(
         ("zh", WebhookEventType::StoryComplete) => "故事完成",
         ("zh", WebhookEventType::PrdComplete) => "PRD完成",
         ("zh", WebhookEventType::ProgressMilestone) => "进度里程碑",
+        ("zh", WebhookEventType::BudgetAlert) => "预算提醒",
         ("ja", WebhookEventType::TaskComplete) => "タスク完了",
         ("ja", WebhookEventType::TaskFailed) => "タスク失敗",
         ("ja", WebhookEventType::TaskCancelled) => "タスクキャンセル",
         ("ja", WebhookEventType::StoryComplete) => "ストーリー完了",
         ("ja", WebhookEventType::PrdComplete) => "PRD完了",
         ("ja", WebhookEventType::ProgressMilestone) => "進捗マイルストーン",
+        ("ja", WebhookEventType::BudgetAlert) => "予算アラート",
         ("en", WebhookEventType::TaskComplete) => "TaskComplete",
         ("en", WebhookEventType::TaskFailed) => "TaskFailed",
         ("en", WebhookEventType::TaskCancelled) => "TaskCancelled",
         ("en", WebhookEventType::StoryComplete) => "StoryComplete",
         ("en", WebhookEventType::PrdComplete) => "PrdComplete",
         ("en", WebhookEventType::ProgressMilestone) => "ProgressMilestone",
+        ("en", WebhookEventType::BudgetAlert) => "BudgetAlert",
         _ => "TaskComplete",
     }
 }
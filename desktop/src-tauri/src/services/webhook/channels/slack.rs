@@ -101,6 +101,7 @@ impl WebhookChannel for SlackChannel {
             WebhookEventType::StoryComplete => "bookmark",
             WebhookEventType::PrdComplete => "tada",
             WebhookEventType::ProgressMilestone => "chart_with_upwards_trend",
+            WebhookEventType::BudgetAlert => "moneybag",
         };
 
         let title = format!(
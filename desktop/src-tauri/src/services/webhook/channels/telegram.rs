@@ -132,6 +132,7 @@ impl WebhookChannel for TelegramNotifyChannel {
             WebhookEventType::StoryComplete => "\u{1F516}",
             WebhookEventType::PrdComplete => "\u{1F389}",
             WebhookEventType::ProgressMilestone => "\u{1F4C8}",
+            WebhookEventType::BudgetAlert => "\u{1F4B0}",
         };
 
         let event_name =
@@ -89,6 +89,8 @@ pub enum WebhookEventType {
     PrdComplete,
     /// Long-running task progress milestone (25%, 50%, 75%)
     ProgressMilestone,
+    /// Actual or projected monthly spend crossed a configured budget
+    BudgetAlert,
 }
 
 impl fmt::Display for WebhookEventType {
@@ -100,6 +102,7 @@ impl fmt::Display for WebhookEventType {
             Self::StoryComplete => write!(f, "StoryComplete"),
             Self::PrdComplete => write!(f, "PrdComplete"),
             Self::ProgressMilestone => write!(f, "ProgressMilestone"),
+            Self::BudgetAlert => write!(f, "BudgetAlert"),
         }
     }
 }
@@ -364,6 +367,7 @@ mod tests {
             WebhookEventType::StoryComplete,
             WebhookEventType::PrdComplete,
             WebhookEventType::ProgressMilestone,
+            WebhookEventType::BudgetAlert,
         ];
         for evt in events {
             let json = serde_json::to_string(&evt).unwrap();
@@ -300,6 +300,40 @@ impl GitOps {
         Ok(())
     }
 
+    /// Rebase the current branch onto another branch
+    pub fn rebase(&self, cwd: &Path, onto: &str) -> AppResult<MergeResult> {
+        let result = self.execute(cwd, &["rebase", onto])?;
+
+        if result.success {
+            Ok(MergeResult::Success)
+        } else if result.stdout.contains("CONFLICT") || result.stderr.contains("CONFLICT") {
+            let conflicts = self.get_conflicting_files(cwd)?;
+            Ok(MergeResult::Conflict(conflicts))
+        } else {
+            Ok(MergeResult::Error(result.stderr.trim().to_string()))
+        }
+    }
+
+    /// Abort a rebase in progress
+    pub fn rebase_abort(&self, cwd: &Path) -> AppResult<()> {
+        self.execute(cwd, &["rebase", "--abort"])?.into_result()?;
+        Ok(())
+    }
+
+    /// Fast-forward the current branch to another branch
+    pub fn merge_ff_only(&self, cwd: &Path, branch: &str) -> AppResult<()> {
+        self.execute(cwd, &["merge", "--ff-only", branch])?
+            .into_result()?;
+        Ok(())
+    }
+
+    /// Resolve a revision to its commit SHA
+    pub fn rev_parse(&self, cwd: &Path, rev: &str) -> AppResult<String> {
+        self.execute(cwd, &["rev-parse", rev])?
+            .into_result()
+            .map(|s| s.trim().to_string())
+    }
+
     /// Get list of conflicting files
     pub fn get_conflicting_files(&self, cwd: &Path) -> AppResult<Vec<String>> {
         let output = self
@@ -392,6 +426,54 @@ impl GitOps {
         Ok(())
     }
 
+    /// Stash the given paths (including untracked files) with a message,
+    /// returning the stash commit SHA
+    pub fn stash_push(&self, cwd: &Path, message: &str, paths: &[&str]) -> AppResult<String> {
+        let mut args = vec!["stash", "push", "--include-untracked", "-m", message, "--"];
+        args.extend(paths);
+        self.execute(cwd, &args)?.into_result()?;
+        self.execute(cwd, &["rev-parse", "stash@{0}"])?
+            .into_result()
+            .map(|s| s.trim().to_string())
+    }
+
+    /// List stash entries, newest first
+    pub fn list_stashes(&self, cwd: &Path) -> AppResult<Vec<StashInfo>> {
+        let output = self
+            .execute(cwd, &["stash", "list", "--format=%gd%x1f%H%x1f%s"])?
+            .into_result()?;
+
+        Ok(output
+            .lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.splitn(3, '\x1f').collect();
+                if parts.len() == 3 {
+                    Some(StashInfo {
+                        stash_ref: parts[0].to_string(),
+                        commit: parts[1].to_string(),
+                        subject: parts[2].to_string(),
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect())
+    }
+
+    /// Apply and drop a stash entry (e.g. `stash@{0}`)
+    pub fn stash_pop(&self, cwd: &Path, stash_ref: &str) -> AppResult<()> {
+        self.execute(cwd, &["stash", "pop", stash_ref])?
+            .into_result()?;
+        Ok(())
+    }
+
+    /// Drop a stash entry without applying it
+    pub fn stash_drop(&self, cwd: &Path, stash_ref: &str) -> AppResult<()> {
+        self.execute(cwd, &["stash", "drop", stash_ref])?
+            .into_result()?;
+        Ok(())
+    }
+
     /// Check if path is excluded by gitignore pattern
     pub fn check_ignore(&self, cwd: &Path, path: &str) -> AppResult<bool> {
         let result = self.execute(cwd, &["check-ignore", "-q", path])?;
@@ -461,6 +543,14 @@ pub struct CommitInfo {
     pub date: String,
 }
 
+/// Git stash entry
+#[derive(Debug, Clone)]
+pub struct StashInfo {
+    pub stash_ref: String,
+    pub commit: String,
+    pub subject: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
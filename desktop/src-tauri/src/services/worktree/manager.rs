@@ -2,7 +2,7 @@
 //!
 //! Manages git worktrees as first-class session runtimes.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -11,13 +11,17 @@ use sha2::{Digest, Sha256};
 use tokio::sync::RwLock;
 use url::Url;
 
+use crate::models::quality_gates::GateStatus;
 use crate::models::worktree::{
     CompleteWorktreeResult, CreateManagedWorktreeRequest, CreatePullRequestRequest,
     CreatePullRequestResult, CreateWorktreeRequest, ForgeProvider, ManagedWorktreeMetadata,
-    MergeConflict, PreparePullRequestResult, PullRequestInfo, PullRequestState,
-    SessionWorktreeRuntimeBinding, Worktree, WorktreeCleanupPolicy, WorktreeRuntimeKind,
-    WorktreeStatus,
+    MergeConflict, MergeStrategy, MergeToBaseOptions, MergeToBaseResult, OrphanReason,
+    OrphanWorktree, PreparePullRequestResult, PullRequestInfo, PullRequestState, QuotaEnforcement,
+    SessionWorktreeRuntimeBinding, Worktree, WorktreeAutoStash, WorktreeCleanupPolicy,
+    WorktreeDiskUsage, WorktreeQuota, WorktreeRuntimeKind, WorktreeSize, WorktreeStatus,
+    WorktreeStatusReport,
 };
+use crate::services::quality_gates::QualityGateRunner;
 use crate::utils::error::{AppError, AppResult};
 use crate::utils::paths::ensure_worktrees_dir;
 
@@ -25,12 +29,14 @@ use super::config::PlanningConfigService;
 use super::git_ops::{GitOps, MergeResult};
 
 const METADATA_FILE: &str = ".plan-cascade-worktree.json";
+const AUTO_STASH_MARKER: &str = "plan-cascade auto-stash";
 
 /// Manager for git worktree lifecycle operations
 pub struct WorktreeManager {
     git: GitOps,
     config_service: PlanningConfigService,
     worktrees: Arc<RwLock<HashMap<String, Worktree>>>,
+    quota: Option<WorktreeQuota>,
 }
 
 impl WorktreeManager {
@@ -39,9 +45,19 @@ impl WorktreeManager {
             git: GitOps::new(),
             config_service: PlanningConfigService::new(),
             worktrees: Arc::new(RwLock::new(HashMap::new())),
+            quota: None,
         }
     }
 
+    /// Configure the per-repository worktree disk quota (`None` disables it).
+    pub fn set_quota(&mut self, quota: Option<WorktreeQuota>) {
+        self.quota = quota;
+    }
+
+    pub fn quota(&self) -> Option<WorktreeQuota> {
+        self.quota
+    }
+
     /// Default managed worktree root for a repository.
     pub fn get_worktree_base(&self, repo_path: &Path) -> PathBuf {
         let repo_root = self
@@ -61,6 +77,7 @@ impl WorktreeManager {
         request: CreateWorktreeRequest,
     ) -> AppResult<Worktree> {
         let repo_root = self.canonical_repo_root(repo_path)?;
+        self.enforce_quota(&repo_root).await?;
         let sanitized_name = self.sanitize_task_name(&request.task_name);
         let base_path = request
             .base_path
@@ -125,6 +142,7 @@ impl WorktreeManager {
         request: CreateManagedWorktreeRequest,
     ) -> AppResult<SessionWorktreeRuntimeBinding> {
         let repo_root = self.canonical_repo_root(repo_path)?;
+        self.enforce_quota(&repo_root).await?;
         let repo_id = self.compute_repo_id(&repo_root)?;
         let task_slug = self.sanitize_task_name(&request.task_name);
         let worktree_id = self.derive_worktree_id(&request.session_id, &task_slug);
@@ -382,8 +400,86 @@ impl WorktreeManager {
         &self,
         repo_path: &Path,
         worktree_id: &str,
-    ) -> AppResult<WorktreeStatus> {
-        Ok(self.get_worktree(repo_path, worktree_id).await?.status)
+    ) -> AppResult<WorktreeStatusReport> {
+        let worktree = self.get_worktree(repo_path, worktree_id).await?;
+        Ok(WorktreeStatusReport {
+            disk_usage_bytes: directory_size_blocking(&worktree.path).await,
+            worktree_id: worktree.id,
+            status: worktree.status,
+        })
+    }
+
+    /// Total disk usage of the repository's worktrees, checked against the quota.
+    pub async fn disk_usage(&self, repo_path: &Path) -> AppResult<WorktreeDiskUsage> {
+        let mut worktrees = Vec::new();
+        for worktree in self.list_worktrees(repo_path).await? {
+            worktrees.push(WorktreeSize {
+                disk_usage_bytes: directory_size_blocking(&worktree.path).await,
+                worktree_id: worktree.id,
+                path: worktree.path,
+            });
+        }
+        let total_bytes = worktrees.iter().map(|size| size.disk_usage_bytes).sum();
+        Ok(WorktreeDiskUsage {
+            total_bytes,
+            quota: self.quota,
+            over_quota: self
+                .quota
+                .is_some_and(|quota| total_bytes > quota.max_bytes),
+            worktrees,
+        })
+    }
+
+    /// Find worktrees whose branch was deleted or, when `live_sessions` is
+    /// given, whose bound session no longer exists.
+    pub async fn detect_orphan_worktrees(
+        &self,
+        repo_path: &Path,
+        live_sessions: Option<&HashSet<String>>,
+    ) -> AppResult<Vec<OrphanWorktree>> {
+        let repo_root = self.canonical_repo_root(repo_path)?;
+        let mut orphans = Vec::new();
+        for worktree in self.list_worktrees(&repo_root).await? {
+            let reason = if worktree.branch.is_empty()
+                || !self.git.branch_exists(&repo_root, &worktree.branch)?
+            {
+                OrphanReason::BranchDeleted
+            } else if matches!(
+                (&worktree.session_id, live_sessions),
+                (Some(session_id), Some(live)) if !live.contains(session_id)
+            ) {
+                OrphanReason::SessionMissing
+            } else {
+                continue;
+            };
+            orphans.push(OrphanWorktree {
+                disk_usage_bytes: directory_size_blocking(&worktree.path).await,
+                worktree,
+                reason,
+            });
+        }
+        Ok(orphans)
+    }
+
+    /// Force-remove the given orphaned worktrees, returning the IDs removed.
+    pub async fn cleanup_orphan_worktrees(
+        &self,
+        repo_path: &Path,
+        worktree_ids: &[String],
+    ) -> AppResult<Vec<String>> {
+        let repo_root = self.canonical_repo_root(repo_path)?;
+        let mut removed = Vec::new();
+        for worktree_id in worktree_ids {
+            match self.remove_worktree(&repo_root, worktree_id, true).await {
+                Ok(()) => removed.push(worktree_id.clone()),
+                Err(error) => tracing::warn!(
+                    "Failed to clean up orphan worktree '{}': {}",
+                    worktree_id,
+                    error
+                ),
+            }
+        }
+        Ok(removed)
     }
 
     pub async fn remove_worktree(
@@ -447,6 +543,19 @@ impl WorktreeManager {
                 worktree.status
             )));
         }
+        // Completion removes the worktree; never let pending stashes vanish with it.
+        let pending = self
+            .list_auto_stashes(&repo_root, Some(worktree_id))
+            .await?;
+        if !pending.is_empty() {
+            let mut result = CompleteWorktreeResult::error(format!(
+                "Worktree '{}' has {} un-restored auto-stash(es); restore or drop them before completing",
+                worktree_id,
+                pending.len()
+            ));
+            result.auto_stashes = pending;
+            return Ok(result);
+        }
 
         let status = self.git.status(&wt_path)?;
         let all_files: Vec<String> = status
@@ -531,6 +640,193 @@ impl WorktreeManager {
         Ok(result)
     }
 
+    /// Check out `branch` inside a worktree. Uncommitted changes are moved to
+    /// a labeled auto-stash first instead of blocking the switch.
+    pub async fn switch_worktree_branch(
+        &self,
+        repo_path: &Path,
+        worktree_id: &str,
+        branch: &str,
+    ) -> AppResult<Option<WorktreeAutoStash>> {
+        self.validate_branch_name(branch)?;
+        let repo_root = self.canonical_repo_root(repo_path)?;
+        let worktree = self.get_worktree(&repo_root, worktree_id).await?;
+        let wt_path = PathBuf::from(&worktree.path);
+        let stash = self.auto_stash(&wt_path, &worktree, "switch")?;
+
+        if let Err(error) = self.git.checkout(&wt_path, branch) {
+            if let Some(ref stash) = stash {
+                self.pop_auto_stash(&wt_path, &stash.commit)?;
+            }
+            return Err(error);
+        }
+        if let Some(mut metadata) = self.read_metadata(&wt_path)? {
+            metadata.branch = branch.to_string();
+            metadata.updated_at = chrono::Utc::now().to_rfc3339();
+            self.persist_metadata(&metadata)?;
+        }
+        {
+            let mut cache = self.worktrees.write().await;
+            cache.remove(worktree_id);
+        }
+        Ok(stash)
+    }
+
+    /// Stash a worktree's uncommitted changes under a label referencing the
+    /// worktree and its session. Returns `None` when there is nothing to stash.
+    pub async fn auto_stash_worktree(
+        &self,
+        repo_path: &Path,
+        worktree_id: &str,
+        operation: &str,
+    ) -> AppResult<Option<WorktreeAutoStash>> {
+        let worktree = self.get_worktree(repo_path, worktree_id).await?;
+        self.auto_stash(Path::new(&worktree.path), &worktree, operation)
+    }
+
+    /// Auto-stashes in the repository, optionally limited to one worktree.
+    pub async fn list_auto_stashes(
+        &self,
+        repo_path: &Path,
+        worktree_id: Option<&str>,
+    ) -> AppResult<Vec<WorktreeAutoStash>> {
+        let repo_root = self.canonical_repo_root(repo_path)?;
+        Ok(self
+            .git
+            .list_stashes(&repo_root)?
+            .into_iter()
+            .filter_map(|entry| parse_auto_stash(&entry.commit, &entry.subject))
+            .filter(|stash| worktree_id.is_none_or(|id| stash.worktree_id == id))
+            .collect())
+    }
+
+    /// Re-apply an auto-stash into a worktree and drop it on success.
+    pub async fn restore_auto_stash(
+        &self,
+        repo_path: &Path,
+        worktree_id: &str,
+        stash_commit: &str,
+    ) -> AppResult<()> {
+        let worktree = self.get_worktree(repo_path, worktree_id).await?;
+        self.pop_auto_stash(Path::new(&worktree.path), stash_commit)
+    }
+
+    /// Explicitly discard an auto-stash.
+    pub async fn drop_auto_stash(&self, repo_path: &Path, stash_commit: &str) -> AppResult<()> {
+        let repo_root = self.canonical_repo_root(repo_path)?;
+        let stash_ref = self.auto_stash_ref(&repo_root, stash_commit)?;
+        self.git.stash_drop(&repo_root, &stash_ref)
+    }
+
+    /// Merge (or rebase) a worktree branch into its base branch.
+    ///
+    /// Configured quality gates run in the worktree first. Conflicts are
+    /// aborted and reported, leaving both branches as they were.
+    pub async fn merge_to_base(
+        &self,
+        repo_path: &Path,
+        worktree_id: &str,
+        strategy: MergeStrategy,
+        options: MergeToBaseOptions,
+    ) -> AppResult<MergeToBaseResult> {
+        let repo_root = self.canonical_repo_root(repo_path)?;
+        let worktree = self.get_worktree(&repo_root, worktree_id).await?;
+        let wt_path = PathBuf::from(&worktree.path);
+        let base = worktree.target_branch.clone();
+        self.ensure_target_branch_exists(&repo_root, &base)?;
+        let mut result = MergeToBaseResult::new(strategy);
+
+        let uncommitted = self.uncommitted_files(&wt_path)?;
+        if !uncommitted.is_empty() {
+            return Ok(result.with_error(format!(
+                "Worktree '{}' has {} uncommitted change(s); commit or stash them before merging",
+                worktree_id,
+                uncommitted.len()
+            )));
+        }
+
+        if let Some(gates) = options.quality_gates {
+            let summary = QualityGateRunner::new(&wt_path).run_custom(gates).await?;
+            let failed: Vec<String> = summary
+                .results
+                .iter()
+                .filter(|gate| gate.status == GateStatus::Failed)
+                .map(|gate| gate.gate_name.clone())
+                .collect();
+            result.gates = Some(summary);
+            if !failed.is_empty() {
+                return Ok(
+                    result.with_error(format!("Quality gates failed: {}", failed.join(", ")))
+                );
+            }
+        }
+
+        let outcome = match strategy {
+            MergeStrategy::Merge => {
+                self.git.checkout(&repo_root, &base)?;
+                let message = options
+                    .commit_message
+                    .unwrap_or_else(|| format!("Merge {} into {}", worktree.branch, base));
+                let outcome = self
+                    .git
+                    .merge(&repo_root, &worktree.branch, Some(&message))?;
+                if matches!(outcome, MergeResult::Conflict(_)) {
+                    self.git.merge_abort(&repo_root).ok();
+                }
+                outcome
+            }
+            MergeStrategy::Rebase => {
+                let outcome = self.git.rebase(&wt_path, &base)?;
+                if !matches!(outcome, MergeResult::Success) {
+                    self.git.rebase_abort(&wt_path).ok();
+                }
+                outcome
+            }
+        };
+        match outcome {
+            MergeResult::Success => {}
+            MergeResult::Conflict(conflicts) => {
+                let conflict = MergeConflict::new(conflicts, worktree.branch.clone(), base);
+                let message = format!(
+                    "Merge conflict detected in {} files. Please resolve manually.",
+                    conflict.conflicting_files.len()
+                );
+                result.conflict = Some(conflict);
+                return Ok(result.with_error(message));
+            }
+            MergeResult::Error(message) => {
+                return Ok(result.with_error(format!("Merge failed: {}", message)));
+            }
+        }
+        if strategy == MergeStrategy::Rebase {
+            self.git.checkout(&repo_root, &base)?;
+            self.git.merge_ff_only(&repo_root, &worktree.branch)?;
+        }
+        result.merged = true;
+        result.base_commit = Some(self.git.rev_parse(&repo_root, &base)?);
+
+        if options.remove_worktree {
+            let pending = self
+                .list_auto_stashes(&repo_root, Some(worktree_id))
+                .await?;
+            if !pending.is_empty() {
+                result.warnings.push(format!(
+                    "Kept worktree '{}': it has {} un-restored auto-stash(es)",
+                    worktree_id,
+                    pending.len()
+                ));
+            } else {
+                match self.remove_worktree(&repo_root, worktree_id, true).await {
+                    Ok(()) => result.cleaned_up = true,
+                    Err(error) => result
+                        .warnings
+                        .push(format!("Failed to cleanup worktree: {}", error)),
+                }
+            }
+        }
+        Ok(result)
+    }
+
     pub async fn prepare_pull_request(
         &self,
         repo_path: &Path,
@@ -771,6 +1067,99 @@ impl WorktreeManager {
         Ok(())
     }
 
+    /// Warn or fail (per the quota's enforcement) when the repository's
+    /// worktrees already use up the disk quota.
+    async fn enforce_quota(&self, repo_root: &Path) -> AppResult<()> {
+        let Some(quota) = self.quota else {
+            return Ok(());
+        };
+        let usage = self.disk_usage(repo_root).await?;
+        if !usage.over_quota {
+            return Ok(());
+        }
+        let message = format!(
+            "Worktree disk quota exceeded: {} bytes used of {} allowed",
+            usage.total_bytes, quota.max_bytes
+        );
+        match quota.enforcement {
+            QuotaEnforcement::Warn => {
+                tracing::warn!("{}", message);
+                Ok(())
+            }
+            QuotaEnforcement::Block => Err(AppError::validation(message)),
+        }
+    }
+
+    fn auto_stash(
+        &self,
+        wt_path: &Path,
+        worktree: &Worktree,
+        operation: &str,
+    ) -> AppResult<Option<WorktreeAutoStash>> {
+        let paths = self.uncommitted_files(wt_path)?;
+        if paths.is_empty() {
+            return Ok(None);
+        }
+
+        let session_id = worktree.session_id.clone();
+        let label = format!(
+            "{} [worktree={} session={} op={}]",
+            AUTO_STASH_MARKER,
+            worktree.id,
+            session_id.as_deref().unwrap_or("-"),
+            operation
+        );
+        let path_refs: Vec<&str> = paths.iter().map(String::as_str).collect();
+        let commit = self.git.stash_push(wt_path, &label, &path_refs)?;
+        Ok(Some(WorktreeAutoStash {
+            commit,
+            label,
+            worktree_id: worktree.id.clone(),
+            session_id,
+            operation: operation.to_string(),
+        }))
+    }
+
+    /// Uncommitted changes in a worktree, excluding planning and metadata files.
+    fn uncommitted_files(&self, wt_path: &Path) -> AppResult<Vec<String>> {
+        let status = self.git.status(wt_path)?;
+        let mut changed: Vec<String> = status
+            .staged
+            .iter()
+            .chain(status.modified.iter())
+            .chain(status.deleted.iter())
+            .chain(status.untracked.iter())
+            .cloned()
+            .chain(status.renamed.iter().flat_map(|entry| {
+                entry
+                    .split(" -> ")
+                    .map(ToOwned::to_owned)
+                    .collect::<Vec<_>>()
+            }))
+            .filter(|file| file != METADATA_FILE)
+            .collect();
+        changed.sort();
+        changed.dedup();
+        // Planning files stay in place; they describe the worktree, not the work.
+        Ok(self.config_service.get_committable_files(wt_path, &changed))
+    }
+
+    fn auto_stash_ref(&self, cwd: &Path, stash_commit: &str) -> AppResult<String> {
+        self.git
+            .list_stashes(cwd)?
+            .into_iter()
+            .find(|entry| {
+                entry.commit.starts_with(stash_commit) && entry.subject.contains(AUTO_STASH_MARKER)
+            })
+            .map(|entry| entry.stash_ref)
+            .ok_or_else(|| AppError::not_found(format!("Auto-stash not found: {}", stash_commit)))
+    }
+
+    fn pop_auto_stash(&self, cwd: &Path, stash_commit: &str) -> AppResult<()> {
+        let stash_ref = self.auto_stash_ref(cwd, stash_commit)?;
+        self.git.stash_pop(cwd, &stash_ref)
+    }
+
     async fn find_worktree_by_id(
         &self,
         repo_root: &Path,
@@ -1053,6 +1442,60 @@ impl WorktreeManager {
     }
 }
 
+/// Total size of the files under `path`, not following symlinks.
+/// Parse an auto-stash label of the form
+/// `plan-cascade auto-stash [worktree=<id> session=<id|-> op=<operation>]`.
+fn parse_auto_stash(commit: &str, subject: &str) -> Option<WorktreeAutoStash> {
+    let label = &subject[subject.find(AUTO_STASH_MARKER)?..];
+    let fields = label
+        .strip_prefix(AUTO_STASH_MARKER)?
+        .trim_start()
+        .strip_prefix('[')?
+        .strip_suffix(']')?;
+    let (mut worktree_id, mut session_id, mut operation) = (None, None, None);
+    for field in fields.split_whitespace() {
+        match field.split_once('=') {
+            Some(("worktree", value)) => worktree_id = Some(value.to_string()),
+            Some(("session", value)) if value != "-" => session_id = Some(value.to_string()),
+            Some(("op", value)) => operation = Some(value.to_string()),
+            _ => {}
+        }
+    }
+    Some(WorktreeAutoStash {
+        commit: commit.to_string(),
+        label: label.to_string(),
+        worktree_id: worktree_id?,
+        session_id,
+        operation: operation?,
+    })
+}
+
+/// `directory_size` on the blocking pool, so walking a large worktree does not
+/// stall the async runtime.
+async fn directory_size_blocking(path: &str) -> u64 {
+    let path = PathBuf::from(path);
+    tokio::task::spawn_blocking(move || directory_size(&path))
+        .await
+        .unwrap_or(0)
+}
+
+fn directory_size(path: &Path) -> u64 {
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return 0;
+    };
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+    fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| directory_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
 #[derive(Debug)]
 struct ParsedForgeRemote {
     provider: ForgeProvider,
@@ -1076,6 +1519,7 @@ impl std::fmt::Debug for WorktreeManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::quality_gates::CustomGateConfig;
 
     #[test]
     fn test_sanitize_task_name() {
@@ -1118,6 +1562,376 @@ mod tests {
         assert!(manager.validate_branch_name("-feature").is_err());
     }
 
+    fn git(cwd: &Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(cwd)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    fn init_repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "-q", "-b", "main"]);
+        git(&repo, &["config", "user.name", "test"]);
+        git(&repo, &["config", "user.email", "test@example.com"]);
+        fs::write(repo.join("README.md"), "hello\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "-q", "-m", "init"]);
+        dir
+    }
+
+    fn request(dir: &tempfile::TempDir, task_name: &str) -> CreateWorktreeRequest {
+        CreateWorktreeRequest {
+            task_name: task_name.to_string(),
+            target_branch: "main".to_string(),
+            base_path: Some(dir.path().join("worktrees").to_string_lossy().to_string()),
+            prd_path: None,
+            execution_mode: "auto".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_detect_orphan_worktree_with_deleted_branch() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        let manager = WorktreeManager::new();
+        let kept = manager
+            .create_worktree(&repo, request(&dir, "kept"))
+            .await
+            .unwrap();
+        let orphan = manager
+            .create_worktree(&repo, request(&dir, "abandoned"))
+            .await
+            .unwrap();
+
+        git(
+            &repo,
+            &["update-ref", "-d", &format!("refs/heads/{}", orphan.branch)],
+        );
+
+        let orphans = manager.detect_orphan_worktrees(&repo, None).await.unwrap();
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].worktree.branch, orphan.branch);
+        assert_eq!(orphans[0].reason, OrphanReason::BranchDeleted);
+        assert!(orphans[0].disk_usage_bytes > 0);
+        assert_ne!(orphans[0].worktree.branch, kept.branch);
+
+        let removed = manager
+            .cleanup_orphan_worktrees(&repo, &[orphans[0].worktree.id.clone()])
+            .await
+            .unwrap();
+        assert_eq!(removed.len(), 1);
+        assert!(!Path::new(&orphan.path).exists());
+        assert!(manager
+            .detect_orphan_worktrees(&repo, None)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_quota_blocks_creation_when_over_budget() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        let mut manager = WorktreeManager::new();
+        manager
+            .create_worktree(&repo, request(&dir, "first"))
+            .await
+            .unwrap();
+
+        let usage = manager.disk_usage(&repo).await.unwrap();
+        assert!(usage.total_bytes > 0);
+        assert!(!usage.over_quota);
+
+        // Warn mode still allows creation
+        manager.set_quota(Some(WorktreeQuota {
+            max_bytes: 1,
+            enforcement: QuotaEnforcement::Warn,
+        }));
+        manager
+            .create_worktree(&repo, request(&dir, "second"))
+            .await
+            .unwrap();
+
+        manager.set_quota(Some(WorktreeQuota {
+            max_bytes: 1,
+            enforcement: QuotaEnforcement::Block,
+        }));
+        let err = manager
+            .create_worktree(&repo, request(&dir, "third"))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("quota exceeded"), "{}", err);
+        assert!(!dir.path().join("worktrees").join("third").exists());
+
+        // Raising the budget lets creation proceed again
+        manager.set_quota(Some(WorktreeQuota {
+            max_bytes: u64::MAX,
+            enforcement: QuotaEnforcement::Block,
+        }));
+        let created = manager
+            .create_worktree(&repo, request(&dir, "third"))
+            .await
+            .unwrap();
+        let listed = manager
+            .list_worktrees(&repo)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|worktree| worktree.branch == created.branch)
+            .unwrap();
+        let report = manager
+            .get_worktree_status(&repo, &listed.id)
+            .await
+            .unwrap();
+        assert!(report.disk_usage_bytes > 0);
+    }
+
+    fn listed_by_branch(worktrees: Vec<Worktree>, branch: &str) -> Worktree {
+        worktrees
+            .into_iter()
+            .find(|worktree| worktree.branch == branch)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_auto_stash_label() {
+        let stash = parse_auto_stash(
+            "abc123",
+            "On pc/feature: plan-cascade auto-stash [worktree=feature session=s-1 op=switch]",
+        )
+        .unwrap();
+        assert_eq!(stash.worktree_id, "feature");
+        assert_eq!(stash.session_id.as_deref(), Some("s-1"));
+        assert_eq!(stash.operation, "switch");
+        assert!(parse_auto_stash("abc123", "On main: WIP").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_switch_with_dirty_tree_stashes_and_restores() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        let manager = WorktreeManager::new();
+        let created = manager
+            .create_worktree(&repo, request(&dir, "switcher"))
+            .await
+            .unwrap();
+        let wt_path = PathBuf::from(&created.path);
+        let worktree = listed_by_branch(
+            manager.list_worktrees(&repo).await.unwrap(),
+            &created.branch,
+        );
+        git(&repo, &["branch", "pc/other", "main"]);
+        fs::write(wt_path.join("README.md"), "edited\n").unwrap();
+        fs::write(wt_path.join("notes.txt"), "draft\n").unwrap();
+
+        let stash = manager
+            .switch_worktree_branch(&repo, &worktree.id, "pc/other")
+            .await
+            .unwrap()
+            .expect("dirty tree should be stashed");
+        assert_eq!(stash.worktree_id, worktree.id);
+        assert!(stash.label.contains(&format!("worktree={}", worktree.id)));
+        assert_eq!(
+            fs::read_to_string(wt_path.join("README.md")).unwrap(),
+            "hello\n"
+        );
+        assert!(!wt_path.join("notes.txt").exists());
+        assert_eq!(
+            manager
+                .list_auto_stashes(&repo, Some(&worktree.id))
+                .await
+                .unwrap(),
+            vec![stash.clone()]
+        );
+
+        // Switching back restores the original worktree identity
+        assert!(manager
+            .switch_worktree_branch(&repo, "other", &created.branch)
+            .await
+            .unwrap()
+            .is_none());
+        manager
+            .restore_auto_stash(&repo, &worktree.id, &stash.commit)
+            .await
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(wt_path.join("README.md")).unwrap(),
+            "edited\n"
+        );
+        assert_eq!(
+            fs::read_to_string(wt_path.join("notes.txt")).unwrap(),
+            "draft\n"
+        );
+        assert!(manager
+            .list_auto_stashes(&repo, None)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_complete_refuses_unrestored_auto_stash() {
+        let dir = init_repo();
+        let repo = dir.path().join("repo");
+        let manager = WorktreeManager::new();
+        let created = manager
+            .create_worktree(&repo, request(&dir, "finisher"))
+            .await
+            .unwrap();
+        let wt_path = PathBuf::from(&created.path);
+        let worktree = listed_by_branch(
+            manager.list_worktrees(&repo).await.unwrap(),
+            &created.branch,
+        );
+        fs::write(wt_path.join("feature.txt"), "work\n").unwrap();
+        let stash = manager
+            .auto_stash_worktree(&repo, &worktree.id, "manual")
+            .await
+            .unwrap()
+            .unwrap();
+
+        let refused = manager
+            .complete_worktree(&repo, &worktree.id, None)
+            .await
+            .unwrap();
+        assert!(!refused.success);
+        assert!(refused.error.unwrap().contains("un-restored auto-stash"));
+        assert_eq!(refused.auto_stashes, vec![stash.clone()]);
+        assert!(wt_path.exists());
+
+        manager
+            .restore_auto_stash(&repo, &worktree.id, &stash.commit)
+            .await
+            .unwrap();
+        let completed = manager
+            .complete_worktree(&repo, &worktree.id, None)
+            .await
+            .unwrap();
+        assert!(completed.success, "{:?}", completed.error);
+        assert!(completed.merged);
+        assert_eq!(
+            fs::read_to_string(repo.join("feature.txt")).unwrap(),
+            "work\n"
+        );
+    }
+
+    fn commit_file(cwd: &Path, file: &str, content: &str, message: &str) {
+        fs::write(cwd.join(file), content).unwrap();
+        git(cwd, &["add", file]);
+        git(cwd, &["commit", "-q", "-m", message]);
+    }
+
+    fn head(cwd: &Path, rev: &str) -> String {
+        GitOps::new().rev_parse(cwd, rev).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_merge_to_base_clean_merge_back() {
+        for strategy in [MergeStrategy::Merge, MergeStrategy::Rebase] {
+            let dir = init_repo();
+            let repo = dir.path().join("repo");
+            let manager = WorktreeManager::new();
+            let created = manager
+                .create_worktree(&repo, request(&dir, "story"))
+                .await
+                .unwrap();
+            let wt_path = PathBuf::from(&created.path);
+            let worktree = listed_by_branch(
+                manager.list_worktrees(&repo).await.unwrap(),
+                &created.branch,
+            );
+            commit_file(&wt_path, "story.txt", "done\n", "story");
+            commit_file(&repo, "other.txt", "main work\n", "main moved on");
+
+            let options = MergeToBaseOptions {
+                quality_gates: Some(vec![CustomGateConfig {
+                    id: "check".to_string(),
+                    name: "Check".to_string(),
+                    command: "git".to_string(),
+                    args: vec!["--version".to_string()],
+                    required: true,
+                    timeout_secs: 30,
+                }]),
+                remove_worktree: true,
+                commit_message: None,
+            };
+            let result = manager
+                .merge_to_base(&repo, &worktree.id, strategy, options)
+                .await
+                .unwrap();
+            assert!(result.merged, "{:?}: {:?}", strategy, result.error);
+            assert!(result.conflict.is_none());
+            assert_eq!(result.gates.unwrap().passed_gates, 1);
+            assert_eq!(result.base_commit, Some(head(&repo, "main")));
+            assert!(result.cleaned_up);
+            assert!(!wt_path.exists());
+            assert_eq!(
+                fs::read_to_string(repo.join("story.txt")).unwrap(),
+                "done\n"
+            );
+            assert!(repo.join("other.txt").exists());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_to_base_conflict_leaves_branches_untouched() {
+        for strategy in [MergeStrategy::Merge, MergeStrategy::Rebase] {
+            let dir = init_repo();
+            let repo = dir.path().join("repo");
+            let manager = WorktreeManager::new();
+            let created = manager
+                .create_worktree(&repo, request(&dir, "clash"))
+                .await
+                .unwrap();
+            let wt_path = PathBuf::from(&created.path);
+            let worktree = listed_by_branch(
+                manager.list_worktrees(&repo).await.unwrap(),
+                &created.branch,
+            );
+            commit_file(&wt_path, "README.md", "from worktree\n", "worktree edit");
+            commit_file(&repo, "README.md", "from main\n", "main edit");
+            let main_before = head(&repo, "main");
+            let branch_before = head(&repo, &created.branch);
+
+            let result = manager
+                .merge_to_base(
+                    &repo,
+                    &worktree.id,
+                    strategy,
+                    MergeToBaseOptions {
+                        remove_worktree: true,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap();
+            assert!(!result.merged);
+            assert!(!result.cleaned_up);
+            let conflict = result.conflict.expect("conflict should be reported");
+            assert_eq!(conflict.conflicting_files, vec!["README.md".to_string()]);
+            assert_eq!(conflict.target_branch, "main");
+
+            assert_eq!(head(&repo, "main"), main_before, "{:?}", strategy);
+            assert_eq!(
+                head(&repo, &created.branch),
+                branch_before,
+                "{:?}",
+                strategy
+            );
+            assert!(GitOps::new().is_clean(&repo).unwrap());
+            assert_eq!(
+                fs::read_to_string(wt_path.join("README.md")).unwrap(),
+                "from worktree\n"
+            );
+        }
+    }
+
     #[test]
     fn test_parse_github_remote() {
         let manager = WorktreeManager::new();
@@ -2,15 +2,21 @@
 //!
 //! Global state managed by Tauri, containing all services.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 use tracing::warn;
 
+use crate::commands::embedding::EmbeddingHealthResponse;
+use crate::commands::standalone::HealthCheckResult;
 use crate::models::settings::{AppConfig, SettingsUpdate};
+use crate::services::health_cache::{HealthCache, HEALTH_CACHE_MAX_STALE, HEALTH_CACHE_TTL};
 use crate::services::memory::ProjectMemoryStore;
 use crate::services::orchestrator::embedding_config_builder::build_embedding_config_from_settings;
 use crate::services::orchestrator::embedding_manager::EmbeddingManager;
 use crate::services::orchestrator::embedding_service::EmbeddingService;
+use crate::services::orchestrator::tree_sitter_parser::ParseCache;
+use crate::services::skills::model::CachedSkillIndex;
 use crate::storage::{ConfigService, Database, KeyringService};
 use crate::utils::error::{AppError, AppResult};
 
@@ -24,6 +30,14 @@ pub struct AppState {
     config: Arc<RwLock<Option<ConfigService>>>,
     /// Project memory store for cross-session persistent memory
     memory_store: Arc<RwLock<Option<Arc<ProjectMemoryStore>>>>,
+    /// Cached LLM provider health check results
+    provider_health_cache: Arc<HealthCache<HealthCheckResult>>,
+    /// Cached embedding provider health check results
+    embedding_health_cache: Arc<HealthCache<EmbeddingHealthResponse>>,
+    /// File-based skill index per project path, reused across refreshes
+    skill_index_cache: Mutex<HashMap<String, CachedSkillIndex>>,
+    /// Tree-sitter parse trees shared by the codebase indexers
+    parse_cache: Arc<ParseCache>,
     /// Whether the state has been initialized
     initialized: Arc<RwLock<bool>>,
 }
@@ -36,6 +50,16 @@ impl AppState {
             keyring: Arc::new(RwLock::new(None)),
             config: Arc::new(RwLock::new(None)),
             memory_store: Arc::new(RwLock::new(None)),
+            provider_health_cache: Arc::new(HealthCache::new(
+                HEALTH_CACHE_TTL,
+                HEALTH_CACHE_MAX_STALE,
+            )),
+            embedding_health_cache: Arc::new(HealthCache::new(
+                HEALTH_CACHE_TTL,
+                HEALTH_CACHE_MAX_STALE,
+            )),
+            skill_index_cache: Mutex::new(HashMap::new()),
+            parse_cache: Arc::new(ParseCache::default()),
             initialized: Arc::new(RwLock::new(false)),
         }
     }
@@ -268,6 +292,26 @@ impl AppState {
             )),
         }
     }
+
+    /// Health results shared across LLM provider health checks
+    pub fn provider_health_cache(&self) -> &Arc<HealthCache<HealthCheckResult>> {
+        &self.provider_health_cache
+    }
+
+    /// Health results shared across embedding provider health checks
+    pub fn embedding_health_cache(&self) -> &Arc<HealthCache<EmbeddingHealthResponse>> {
+        &self.embedding_health_cache
+    }
+
+    /// File-based skill indexes by project path
+    pub fn skill_index_cache(&self) -> &Mutex<HashMap<String, CachedSkillIndex>> {
+        &self.skill_index_cache
+    }
+
+    /// Tree-sitter parse trees shared by the codebase indexers
+    pub fn parse_cache(&self) -> &Arc<ParseCache> {
+        &self.parse_cache
+    }
 }
 
 impl Default for AppState {
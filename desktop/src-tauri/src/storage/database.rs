@@ -40,6 +40,29 @@ pub struct CheckpointRow {
     pub created_at: Option<String>,
 }
 
+/// Agent run that never reached a terminal status
+#[derive(Debug, Clone)]
+pub struct IncompleteAgentRunRow {
+    pub id: String,
+    pub agent_id: String,
+    pub agent_name: Option<String>,
+    pub input: String,
+    pub status: String,
+    pub error: Option<String>,
+    pub created_at: Option<String>,
+}
+
+/// Latest checkpoint of a graph workflow thread
+#[derive(Debug, Clone)]
+pub struct GraphRunRow {
+    pub thread_id: String,
+    pub step: String,
+    pub pending_nodes: String,
+    pub state: String,
+    pub created_at: String,
+    pub checkpoint_count: i32,
+}
+
 /// Raw MCP catalog cache row.
 #[derive(Debug, Clone)]
 pub struct McpCatalogCacheRow {
@@ -597,11 +620,36 @@ impl Database {
                 use_count INTEGER NOT NULL DEFAULT 0,
                 last_used_at TEXT,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
-                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+                updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+                variable_schema TEXT NOT NULL DEFAULT '[]'
             )",
             [],
         )?;
 
+        // Migration: variable schema added after the initial prompt library.
+        if !Self::table_has_column(&conn, "prompts", "variable_schema") {
+            let _ = conn.execute(
+                "ALTER TABLE prompts ADD COLUMN variable_schema TEXT NOT NULL DEFAULT '[]'",
+                [],
+            );
+        }
+
+        // Per-use log with the variables each prompt was rendered with
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS prompt_uses (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                prompt_id TEXT NOT NULL,
+                variables TEXT NOT NULL DEFAULT '{}',
+                used_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+            [],
+        )?;
+
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_prompt_uses_prompt ON prompt_uses(prompt_id, used_at)",
+            [],
+        )?;
+
         conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_prompts_category ON prompts(category)",
             [],
@@ -2116,6 +2164,104 @@ impl Database {
         Ok(rows)
     }
 
+    /// Get agent runs that are still pending or running
+    pub fn get_incomplete_agent_runs(&self) -> AppResult<Vec<IncompleteAgentRunRow>> {
+        self.query_agent_runs("WHERE r.status IN ('pending', 'running')", params![])
+    }
+
+    /// Get a single agent run by ID
+    pub fn get_agent_run_row(&self, id: &str) -> AppResult<Option<IncompleteAgentRunRow>> {
+        Ok(self
+            .query_agent_runs("WHERE r.id = ?1", params![id])?
+            .into_iter()
+            .next())
+    }
+
+    fn query_agent_runs(
+        &self,
+        filter: &str,
+        params: &[&dyn rusqlite::ToSql],
+    ) -> AppResult<Vec<IncompleteAgentRunRow>> {
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT r.id, r.agent_id, a.name, r.input, r.status, r.error, r.created_at
+             FROM agent_runs r LEFT JOIN agents a ON a.id = r.agent_id
+             {}
+             ORDER BY r.created_at DESC",
+            filter
+        ))?;
+
+        let rows = stmt
+            .query_map(params, |row| {
+                Ok(IncompleteAgentRunRow {
+                    id: row.get(0)?,
+                    agent_id: row.get(1)?,
+                    agent_name: row.get(2)?,
+                    input: row.get(3)?,
+                    status: row.get(4)?,
+                    error: row.get(5)?,
+                    created_at: row.get(6)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(rows)
+    }
+
+    /// Get the latest checkpoint of every graph workflow thread.
+    ///
+    /// The `graph_checkpoints` table is created lazily by the graph
+    /// checkpointer, so a missing table yields no runs.
+    pub fn get_graph_runs(&self) -> AppResult<Vec<GraphRunRow>> {
+        self.query_graph_runs("", params![])
+    }
+
+    /// Get the latest checkpoint of a single graph workflow thread
+    pub fn get_graph_run(&self, thread_id: &str) -> AppResult<Option<GraphRunRow>> {
+        Ok(self
+            .query_graph_runs("AND g.thread_id = ?1", params![thread_id])?
+            .into_iter()
+            .next())
+    }
+
+    fn query_graph_runs(
+        &self,
+        filter: &str,
+        params: &[&dyn rusqlite::ToSql],
+    ) -> AppResult<Vec<GraphRunRow>> {
+        let conn = self.get_connection()?;
+        if !Self::table_exists(&conn, "graph_checkpoints") {
+            return Ok(Vec::new());
+        }
+        let mut stmt = conn.prepare(&format!(
+            "SELECT g.thread_id, g.step, g.pending_nodes, g.state, g.created_at,
+                    (SELECT COUNT(*) FROM graph_checkpoints c WHERE c.thread_id = g.thread_id)
+             FROM graph_checkpoints g
+             WHERE g.created_at = (
+                 SELECT MAX(created_at) FROM graph_checkpoints WHERE thread_id = g.thread_id
+             ) {}
+             ORDER BY g.created_at DESC",
+            filter
+        ))?;
+
+        let rows = stmt
+            .query_map(params, |row| {
+                Ok(GraphRunRow {
+                    thread_id: row.get(0)?,
+                    step: row.get(1)?,
+                    pending_nodes: row.get(2)?,
+                    state: row.get(3)?,
+                    created_at: row.get(4)?,
+                    checkpoint_count: row.get(5)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(rows)
+    }
+
     // ========================================================================
     // MCP Server Operations
     // ========================================================================
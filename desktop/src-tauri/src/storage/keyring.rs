@@ -392,6 +392,7 @@ impl KeyringService {
                 "brave".to_string(),
                 "brave_search".to_string(),
                 "searxng".to_string(),
+                "kagi".to_string(),
             ],
             inner,
         }
@@ -3,6 +3,7 @@
 //! Unified error types for the application.
 //! Uses thiserror for ergonomic error definitions.
 
+use plan_cascade_core::ErrorKind;
 use thiserror::Error;
 
 /// Application-wide error type
@@ -51,6 +52,25 @@ pub enum AppError {
     /// Generic internal errors
     #[error("Internal error: {0}")]
     Internal(String),
+
+    /// Temporary failures (network, timeouts, upstream 5xx)
+    #[error("Transient error: {0}")]
+    Transient(String),
+
+    /// Rate limit exceeded
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        message: String,
+        retry_after_secs: Option<u64>,
+    },
+
+    /// Authentication/authorization failures
+    #[error("Authentication error: {0}")]
+    Auth(String),
+
+    /// Execution was cancelled or its deadline elapsed
+    #[error("Cancelled: {0}")]
+    Cancelled(String),
 }
 
 /// Result type alias for application errors
@@ -96,6 +116,40 @@ impl AppError {
     pub fn internal(msg: impl Into<String>) -> Self {
         Self::Internal(msg.into())
     }
+
+    /// Classify this error for retry/fallback decisions.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            AppError::Transient(_) => ErrorKind::Transient,
+            AppError::RateLimited { .. } => ErrorKind::RateLimited,
+            AppError::Auth(_) => ErrorKind::Auth,
+            AppError::Io(e) => ErrorKind::from_io(e),
+            AppError::Sqlite(rusqlite::Error::SqliteFailure(e, _))
+                if matches!(
+                    e.code,
+                    rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked
+                ) =>
+            {
+                ErrorKind::Transient
+            }
+            AppError::Config(_)
+            | AppError::Serialization(_)
+            | AppError::Validation(_)
+            | AppError::NotFound(_)
+            | AppError::Parse(_) => ErrorKind::Invalid,
+            AppError::Database(_)
+            | AppError::Sqlite(_)
+            | AppError::Keyring(_)
+            | AppError::Command(_)
+            | AppError::Internal(_)
+            | AppError::Cancelled(_) => ErrorKind::Fatal,
+        }
+    }
+
+    /// Whether the failed operation is worth retrying.
+    pub fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
 }
 
 /// Convert AppError to a string suitable for Tauri command responses
@@ -120,6 +174,16 @@ impl From<plan_cascade_core::CoreError> for AppError {
             plan_cascade_core::CoreError::NotFound(msg) => AppError::NotFound(msg),
             plan_cascade_core::CoreError::Parse(msg) => AppError::Parse(msg),
             plan_cascade_core::CoreError::Internal(msg) => AppError::Internal(msg),
+            plan_cascade_core::CoreError::Transient(msg) => AppError::Transient(msg),
+            plan_cascade_core::CoreError::RateLimited {
+                message,
+                retry_after_secs,
+            } => AppError::RateLimited {
+                message,
+                retry_after_secs,
+            },
+            plan_cascade_core::CoreError::Auth(msg) => AppError::Auth(msg),
+            plan_cascade_core::CoreError::Cancelled(msg) => AppError::Cancelled(msg),
         }
     }
 }
@@ -147,4 +211,30 @@ mod tests {
         let app_err: AppError = io_err.into();
         assert!(matches!(app_err, AppError::Io(_)));
     }
+
+    #[test]
+    fn test_core_error_conversion_preserves_kind() {
+        let cases = vec![
+            plan_cascade_core::CoreError::transient("connection reset"),
+            plan_cascade_core::CoreError::rate_limited("429", Some(5)),
+            plan_cascade_core::CoreError::auth("invalid api key"),
+            plan_cascade_core::CoreError::validation("missing field"),
+            plan_cascade_core::CoreError::internal("panic"),
+        ];
+        for core_err in cases {
+            let kind = core_err.kind();
+            let app_err: AppError = core_err.into();
+            assert_eq!(app_err.kind(), kind);
+        }
+
+        let app_err: AppError = plan_cascade_core::CoreError::rate_limited("429", Some(5)).into();
+        assert!(app_err.is_retryable());
+        assert!(matches!(
+            app_err,
+            AppError::RateLimited {
+                retry_after_secs: Some(5),
+                ..
+            }
+        ));
+    }
 }
@@ -2,7 +2,8 @@
  * GraphEdgeComponent
  *
  * Renders an SVG line/arrow between two nodes.
- * Dashed lines for conditional edges, solid for direct edges.
+ * Dashed lines for conditional edges, dotted red for error edges,
+ * solid for direct edges.
  */
 
 import type { Edge, GraphNode } from '../../types/graphWorkflow';
@@ -25,7 +26,7 @@ export function GraphEdgeComponent({ edge, index: _index, nodes, isSelected, onC
 
   // Get target node(s)
   let toNodeId: string | null = null;
-  if (edge.edge_type === 'direct') {
+  if (edge.edge_type === 'direct' || edge.edge_type === 'error') {
     toNodeId = edge.to;
   } else {
     // For conditional edges, draw to default_branch if set
@@ -42,6 +43,8 @@ export function GraphEdgeComponent({ edge, index: _index, nodes, isSelected, onC
   const toY = toNode.position.y;
 
   const isConditional = edge.edge_type === 'conditional';
+  const isError = edge.edge_type === 'error';
+  const color = isSelected ? '#3b82f6' : isError ? '#ef4444' : isConditional ? '#f59e0b' : '#6b7280';
 
   // Calculate arrowhead
   const angle = Math.atan2(toY - fromY, toX - fromX);
@@ -68,15 +71,15 @@ export function GraphEdgeComponent({ edge, index: _index, nodes, isSelected, onC
         y1={fromY}
         x2={toX}
         y2={toY}
-        stroke={isSelected ? '#3b82f6' : isConditional ? '#f59e0b' : '#6b7280'}
+        stroke={color}
         strokeWidth={isSelected ? 2.5 : 2}
-        strokeDasharray={isConditional ? '6,4' : 'none'}
+        strokeDasharray={isError ? '2,4' : isConditional ? '6,4' : 'none'}
       />
 
       {/* Arrowhead */}
       <polygon
         points={`${toX},${toY} ${ax1},${ay1} ${ax2},${ay2}`}
-        fill={isSelected ? '#3b82f6' : isConditional ? '#f59e0b' : '#6b7280'}
+        fill={color}
       />
 
       {/* Conditional label */}
@@ -45,8 +45,8 @@ export function GraphEdgeConfig({ edge, onUpdate, onDelete }: GraphEdgeConfigPro
         </div>
       </div>
 
-      {/* Direct edge: To node */}
-      {edge.edge_type === 'direct' && (
+      {/* Direct/error edge: To node */}
+      {(edge.edge_type === 'direct' || edge.edge_type === 'error') && (
         <div>
           <label className="text-xs text-gray-500 dark:text-gray-400 block mb-1">
             {t('graphWorkflow.edgeConfig.to')}
@@ -159,10 +159,10 @@ export function EmbeddingSection() {
     // On failure, the store sets `error` directly — no additional handling needed.
   }, [saveConfig, saveIndexConfig, clearError, clearHealthResult]);
 
-  // Handle health check
+  // Handle health check (explicit user action, so skip the health cache)
   const handleHealthCheck = useCallback(async () => {
     clearHealthResult();
-    await checkHealth();
+    await checkHealth(true);
   }, [checkHealth, clearHealthResult]);
 
   // Handle API key save
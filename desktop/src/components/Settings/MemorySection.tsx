@@ -109,6 +109,29 @@ export function MemorySection() {
           </div>
         </label>
 
+        <label
+          className={clsx(
+            'flex items-start gap-4 p-4 rounded-lg border cursor-pointer',
+            'transition-colors border-gray-200 dark:border-gray-700',
+            'hover:bg-gray-50 dark:hover:bg-gray-800',
+          )}
+        >
+          <input
+            type="checkbox"
+            checked={memorySettings.sessionSummaryEnabled}
+            onChange={(event) => updateMemorySettings({ sessionSummaryEnabled: event.target.checked })}
+            className="mt-1 text-primary-600"
+          />
+          <div>
+            <div className="font-medium text-gray-900 dark:text-white text-sm">
+              {t('memory.extraction.sessionSummary')}
+            </div>
+            <div className="text-sm text-gray-500 dark:text-gray-400 mt-1">
+              {t('memory.extraction.sessionSummaryDescription')}
+            </div>
+          </div>
+        </label>
+
         <div className="rounded-lg border border-gray-200 dark:border-gray-700 px-4 py-3">
           <div className="text-sm font-medium text-gray-900 dark:text-white">{t('memory.extraction.successOnly')}</div>
           <p className="text-sm text-gray-500 dark:text-gray-400 mt-1">
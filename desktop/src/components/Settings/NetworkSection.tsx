@@ -709,25 +709,74 @@ interface ProviderStrategyRowProps {
 
 function ProviderStrategyRow({ providerId, strategy, onStrategyChange, t }: ProviderStrategyRowProps) {
   const currentStrategy = strategy ?? 'no_proxy';
+  const currentPacUrl = typeof currentStrategy === 'object' ? currentStrategy.pac : '';
+  const [editingPac, setEditingPac] = useState(false);
+  const [pacUrl, setPacUrl] = useState(currentPacUrl);
+  const selectValue = editingPac || typeof currentStrategy === 'object' ? 'pac' : currentStrategy;
+
+  const handleSelect = (value: string) => {
+    if (value === 'pac') {
+      setEditingPac(true);
+      return;
+    }
+    setEditingPac(false);
+    onStrategyChange(providerId, value as ProxyStrategy);
+  };
 
   return (
-    <div className="flex items-center justify-between py-2 px-3 rounded-lg bg-gray-50 dark:bg-gray-800/50">
-      <span className="text-sm text-gray-700 dark:text-gray-300">{t(`network.providers.${providerId}`)}</span>
-      <select
-        value={currentStrategy}
-        onChange={(e) => onStrategyChange(providerId, e.target.value as ProxyStrategy)}
-        className={clsx(
-          'px-3 py-1.5 rounded-lg border text-sm',
-          'border-gray-300 dark:border-gray-600',
-          'bg-white dark:bg-gray-800',
-          'text-gray-700 dark:text-gray-300',
-          'focus:ring-2 focus:ring-primary-500 focus:border-transparent',
-        )}
-      >
-        <option value="use_global">{t('network.providerProxy.useGlobal')}</option>
-        <option value="no_proxy">{t('network.providerProxy.noProxy')}</option>
-        <option value="custom">{t('network.providerProxy.custom')}</option>
-      </select>
+    <div className="py-2 px-3 rounded-lg bg-gray-50 dark:bg-gray-800/50 space-y-2">
+      <div className="flex items-center justify-between">
+        <span className="text-sm text-gray-700 dark:text-gray-300">{t(`network.providers.${providerId}`)}</span>
+        <select
+          value={selectValue}
+          onChange={(e) => handleSelect(e.target.value)}
+          className={clsx(
+            'px-3 py-1.5 rounded-lg border text-sm',
+            'border-gray-300 dark:border-gray-600',
+            'bg-white dark:bg-gray-800',
+            'text-gray-700 dark:text-gray-300',
+            'focus:ring-2 focus:ring-primary-500 focus:border-transparent',
+          )}
+        >
+          <option value="use_global">{t('network.providerProxy.useGlobal')}</option>
+          <option value="no_proxy">{t('network.providerProxy.noProxy')}</option>
+          <option value="custom">{t('network.providerProxy.custom')}</option>
+          <option value="per_host">{t('network.providerProxy.perHost')}</option>
+          <option value="system">{t('network.providerProxy.system')}</option>
+          <option value="pac">{t('network.providerProxy.pac')}</option>
+        </select>
+      </div>
+      {selectValue === 'pac' && (
+        <div className="flex items-center gap-2">
+          <input
+            type="url"
+            value={pacUrl}
+            onChange={(e) => setPacUrl(e.target.value)}
+            placeholder={t('network.providerProxy.pacUrlPlaceholder')}
+            className={clsx(
+              'flex-1 px-3 py-1.5 rounded-lg border text-sm',
+              'border-gray-300 dark:border-gray-600',
+              'bg-white dark:bg-gray-800',
+              'text-gray-700 dark:text-gray-300',
+              'focus:ring-2 focus:ring-primary-500 focus:border-transparent',
+            )}
+          />
+          <button
+            onClick={() => {
+              setEditingPac(false);
+              onStrategyChange(providerId, { pac: pacUrl.trim() });
+            }}
+            disabled={!pacUrl.trim() || pacUrl.trim() === currentPacUrl}
+            className={clsx(
+              'px-3 py-1.5 rounded-lg text-sm font-medium',
+              'bg-primary-600 text-white hover:bg-primary-700',
+              'disabled:opacity-50 disabled:cursor-not-allowed',
+            )}
+          >
+            {t('network.providerProxy.applyPac')}
+          </button>
+        </div>
+      )}
     </div>
   );
 }
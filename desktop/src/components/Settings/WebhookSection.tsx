@@ -33,6 +33,7 @@ const EVENT_TYPES: { value: WebhookEventType; labelKey: string }[] = [
   { value: 'StoryComplete', labelKey: 'webhook.events.storyComplete' },
   { value: 'PrdComplete', labelKey: 'webhook.events.prdComplete' },
   { value: 'ProgressMilestone', labelKey: 'webhook.events.progressMilestone' },
+  { value: 'BudgetAlert', labelKey: 'webhook.events.budgetAlert' },
 ];
 
 const MAX_NAME_LEN = 80;
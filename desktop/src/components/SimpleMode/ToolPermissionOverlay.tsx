@@ -93,6 +93,11 @@ export function ToolPermissionOverlay({ request, onRespond, loading, queueSize }
         )}
       </div>
 
+      {/* Policy reason */}
+      {request.reason && (
+        <p className="mb-2 text-[11px] text-gray-600 dark:text-gray-400 break-words">{request.reason}</p>
+      )}
+
       {/* Arguments preview */}
       {request.arguments && request.arguments !== '{}' && (
         <div className="mb-2">
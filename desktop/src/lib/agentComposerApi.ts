@@ -2,7 +2,7 @@
  * Agent Composer API
  *
  * TypeScript wrapper for the agent_composer Tauri commands.
- * Provides typed access to pipeline CRUD operations.
+ * Provides typed access to pipeline CRUD and export/import operations.
  */
 
 import { invoke } from '@tauri-apps/api/core';
@@ -62,3 +62,26 @@ export async function deleteAgentPipeline(id: string): Promise<boolean> {
   }
   throw new Error(response.error ?? 'Failed to delete agent pipeline');
 }
+
+/**
+ * Export an agent pipeline as portable JSON (machine-specific data stripped),
+ * or as standalone Rust/Python code that rebuilds it.
+ */
+export async function exportAgentPipeline(id: string, format: 'json' | 'rust' | 'python' = 'json'): Promise<string> {
+  const response = await invoke<CommandResponse<string>>('export_agent_pipeline', { id, format });
+  if (response.success && response.data) {
+    return response.data;
+  }
+  throw new Error(response.error ?? 'Failed to export agent pipeline');
+}
+
+/**
+ * Import a pipeline from portable JSON. The pipeline is saved under a new ID.
+ */
+export async function importAgentPipeline(content: string): Promise<AgentPipeline> {
+  const response = await invoke<CommandResponse<AgentPipeline>>('import_agent_pipeline', { content });
+  if (response.success && response.data) {
+    return response.data;
+  }
+  throw new Error(response.error ?? 'Failed to import agent pipeline');
+}
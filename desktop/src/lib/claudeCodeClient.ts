@@ -91,6 +91,7 @@ export type UnifiedStreamEvent =
       tool_name: string;
       arguments: string;
       risk: 'ReadOnly' | 'SafeWrite' | 'Dangerous';
+      reason?: string;
     }
   | { type: 'usage'; input_tokens: number; output_tokens: number; thinking_tokens?: number }
   | { type: 'error'; message: string; code?: string }
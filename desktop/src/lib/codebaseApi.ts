@@ -68,6 +68,8 @@ export interface IndexStatusEvent {
   embedding_chunks: number;
   embedding_provider_name: string | null;
   lsp_enrichment: LspEnrichmentStatus;
+  embedding_signature?: EmbeddingMetadata | null;
+  embedding_migration_required?: boolean;
   phase?: 'queued' | 'parse' | 'embedding' | 'lsp' | 'done';
   job_id?: string | null;
   updated_at?: string | null;
@@ -277,6 +279,16 @@ export async function triggerReindex(projectPath: string): Promise<CommandRespon
   }
 }
 
+export async function migrateEmbeddingIndex(projectPath: string): Promise<CommandResponse<boolean>> {
+  try {
+    return await invoke<CommandResponse<boolean>>('migrate_embedding_index', {
+      projectPath,
+    });
+  } catch (e) {
+    return { success: false, data: null, error: String(e) };
+  }
+}
+
 export async function getIndexStatus(projectPath: string): Promise<CommandResponse<IndexStatusEvent>> {
   try {
     return await invoke<CommandResponse<IndexStatusEvent>>('get_index_status', {
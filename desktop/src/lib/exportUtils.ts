@@ -50,6 +50,50 @@ export async function saveTextWithDialog(filename: string, content: string): Pro
   return true;
 }
 
+export type DocumentExportFormat = 'docx';
+
+export interface DocumentExportOptions {
+  title?: string;
+  /** Directory relative image paths in the markdown resolve against */
+  projectPath?: string;
+  /** Artifact scope the rendered document is stored under */
+  projectId?: string;
+  sessionId?: string;
+}
+
+/**
+ * Render markdown to DOCX on the backend and save it to a
+ * user-selected path. The document is also stored as an artifact.
+ */
+export async function saveDocumentWithDialog(
+  filename: string,
+  markdown: string,
+  format: DocumentExportFormat,
+  options: DocumentExportOptions = {},
+): Promise<boolean> {
+  const { save } = await import('@tauri-apps/plugin-dialog');
+  const selected = await save({
+    title: 'Export Output',
+    defaultPath: filename,
+    canCreateDirectories: true,
+    filters: [{ name: format.toUpperCase(), extensions: [format] }],
+  });
+  if (!selected || Array.isArray(selected)) return false;
+  const result = await invoke<CommandResponse<boolean>>('save_output_export', {
+    path: selected,
+    content: markdown,
+    format,
+    title: options.title ?? null,
+    projectPath: options.projectPath ?? null,
+    projectId: options.projectId ?? null,
+    sessionId: options.sessionId ?? null,
+  });
+  if (!result.success) {
+    throw new Error(result.error || 'Failed to save export');
+  }
+  return true;
+}
+
 export async function saveBinaryWithDialog(filename: string, dataBase64: string): Promise<boolean> {
   const { save } = await import('@tauri-apps/plugin-dialog');
   const selected = await save({
@@ -44,6 +44,21 @@ export interface GuardrailEventEntry {
   timestamp: string;
 }
 
+export interface GuardrailEventRetention {
+  max_events?: number | null;
+  max_age_days?: number | null;
+}
+
+export interface GuardrailEventAggregate {
+  rule_id: string;
+  rule_name: string;
+  surface: string;
+  decision: string;
+  count: number;
+  first_seen: string;
+  last_seen: string;
+}
+
 export interface CustomGuardrailInput {
   id?: string;
   name: string;
@@ -54,6 +69,17 @@ export interface CustomGuardrailInput {
   description: string;
 }
 
+export interface CustomRuleMatch {
+  start: number;
+  end: number;
+  text: string;
+}
+
+export interface CustomRulePreview {
+  matches: CustomRuleMatch[];
+  redacted_preview?: string | null;
+}
+
 function toErrorResponse<T>(error: unknown): CommandResponse<T> {
   return {
     success: false,
@@ -78,6 +104,17 @@ export async function toggleGuardrailEnabled(id: string, enabled: boolean): Prom
   }
 }
 
+export async function setGuardrailScope(
+  id: string,
+  scope: GuardrailScope[],
+): Promise<CommandResponse<GuardrailInfo>> {
+  try {
+    return await invoke<CommandResponse<GuardrailInfo>>('set_guardrail_scope', { id, scope });
+  } catch (error) {
+    return toErrorResponse(error);
+  }
+}
+
 export async function setGuardrailMode(mode: GuardrailMode): Promise<CommandResponse<GuardrailRuntimeStatus>> {
   try {
     return await invoke<CommandResponse<GuardrailRuntimeStatus>>('set_guardrail_mode', { mode });
@@ -102,6 +139,22 @@ export async function updateGuardrail(rule: CustomGuardrailInput): Promise<Comma
   }
 }
 
+export async function testCustomRule(
+  pattern: string,
+  sampleText: string,
+  name?: string,
+): Promise<CommandResponse<CustomRulePreview>> {
+  try {
+    return await invoke<CommandResponse<CustomRulePreview>>('test_custom_rule', {
+      pattern,
+      sampleText,
+      name: name ?? null,
+    });
+  } catch (error) {
+    return toErrorResponse(error);
+  }
+}
+
 export async function deleteGuardrail(id: string): Promise<CommandResponse<boolean>> {
   try {
     return await invoke<CommandResponse<boolean>>('delete_guardrail', { id });
@@ -132,6 +185,38 @@ export async function clearGuardrailEvents(): Promise<CommandResponse<boolean>>
   }
 }
 
+export async function getGuardrailRetention(): Promise<CommandResponse<GuardrailEventRetention>> {
+  try {
+    return await invoke<CommandResponse<GuardrailEventRetention>>('get_guardrail_retention');
+  } catch (error) {
+    return toErrorResponse(error);
+  }
+}
+
+export async function setGuardrailRetention(
+  retention: GuardrailEventRetention,
+): Promise<CommandResponse<GuardrailEventRetention>> {
+  try {
+    return await invoke<CommandResponse<GuardrailEventRetention>>('set_guardrail_retention', { retention });
+  } catch (error) {
+    return toErrorResponse(error);
+  }
+}
+
+export async function aggregateGuardrailEvents(
+  since?: string,
+  until?: string,
+): Promise<CommandResponse<GuardrailEventAggregate[]>> {
+  try {
+    return await invoke<CommandResponse<GuardrailEventAggregate[]>>('aggregate_guardrail_events', {
+      since: since ?? null,
+      until: until ?? null,
+    });
+  } catch (error) {
+    return toErrorResponse(error);
+  }
+}
+
 // Compatibility exports for existing tests/callers.
 export const addCustomRule = (name: string, pattern: string, action: string) =>
   createCustomGuardrail({
@@ -61,6 +61,8 @@ export {
   resetAllSettings,
   exportAllSettings,
   importAllSettings,
+  exportSettings,
+  importSettings,
   isTauriAvailable,
 } from './settingsApi';
 export type {
@@ -69,6 +71,9 @@ export type {
   KnowledgeFeatureFlags,
   UnifiedSettingsExport,
   ImportResult,
+  SettingsScope,
+  SettingsImportMode,
+  PortableSettingsBundle,
 } from './settingsApi';
 
 // Embedding API (v5.0)
@@ -30,6 +30,10 @@ export interface KnowledgeCollection {
   updated_at: string;
   /** Optional workspace path associating this collection with a project directory. */
   workspace_path?: string;
+  /** Number of documents indexed into this collection. */
+  doc_count?: number;
+  /** Most recent time any document was (re-)indexed. */
+  last_indexed_at?: string;
 }
 
 /** A document to ingest into a collection. */
@@ -51,6 +55,16 @@ export interface SearchResult {
   collection_name: string;
   score: number;
   metadata: Record<string, string>;
+  citation?: SourceCitation;
+}
+
+/** Source document and span a retrieved chunk was cut from. */
+export interface SourceCitation {
+  source_path: string | null;
+  byte_start: number;
+  byte_end: number;
+  line_start: number;
+  line_end: number;
 }
 
 /** Summary of a document within a collection. */
@@ -85,10 +85,15 @@ export async function triggerLspEnrichment(projectPath: string): Promise<Command
 
 /**
  * Get the results of the last enrichment pass.
+ *
+ * With a project path, incremental passes and stale files for that project
+ * are included.
  */
-export async function getEnrichmentReport(): Promise<CommandResponse<EnrichmentReport | null>> {
+export async function getEnrichmentReport(projectPath?: string): Promise<CommandResponse<EnrichmentReport | null>> {
   try {
-    return await invoke<CommandResponse<EnrichmentReport | null>>('get_enrichment_report');
+    return await invoke<CommandResponse<EnrichmentReport | null>>('get_enrichment_report', {
+      projectPath: projectPath ?? null,
+    });
   } catch (error) {
     return {
       success: false,
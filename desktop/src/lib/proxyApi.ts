@@ -14,7 +14,7 @@ import type { CommandResponse } from './tauri';
 
 export type ProxyProtocol = 'http' | 'https' | 'socks5';
 
-export type ProxyStrategy = 'use_global' | 'no_proxy' | 'custom';
+export type ProxyStrategy = 'use_global' | 'no_proxy' | 'custom' | 'per_host' | 'system' | { pac: string };
 
 export interface ProxyConfig {
   protocol: ProxyProtocol;
@@ -24,10 +24,31 @@ export interface ProxyConfig {
   password?: string;
 }
 
+export type ProxyRouteTarget = { type: 'direct' } | { type: 'proxy'; id: string };
+
+export interface ProxyRouteRule {
+  /** Host glob, e.g. `localhost` or `*.internal.corp`. */
+  host: string;
+  target: ProxyRouteTarget;
+}
+
+export interface ProxyRoutingTable {
+  rules: ProxyRouteRule[];
+  default: ProxyRouteTarget;
+  proxies: Record<string, ProxyConfig>;
+}
+
 export interface ProxySettingsResponse {
   global: ProxyConfig | null;
   provider_strategies: Record<string, ProxyStrategy>;
   provider_configs: Record<string, ProxyConfig>;
+  routes?: ProxyRoutingTable | null;
+}
+
+export interface SetProxyRoutesRequest {
+  routes: ProxyRoutingTable | null;
+  /** Passwords for named route proxies, keyed by proxy id. */
+  passwords?: Record<string, string>;
 }
 
 export interface SetProxyConfigRequest {
@@ -46,12 +67,39 @@ export interface TestProxyRequest {
   proxy: ProxyConfig;
   password?: string;
   test_url?: string;
+  /** Read the password from the keyring ("global" or a provider id). */
+  keyring_id?: string;
+}
+
+export type ProxyFailureKind =
+  | 'dns'
+  | 'connect'
+  | 'timeout'
+  | 'auth_required'
+  | 'auth_rejected'
+  | 'tls'
+  | 'protocol'
+  | 'http';
+
+export interface ProxyDiagnosticStep {
+  success: boolean;
+  latency_ms: number;
+  failure?: ProxyFailureKind;
+  detail: string;
+}
+
+export interface ProxyDiagnostics {
+  success: boolean;
+  tcp_connect: ProxyDiagnosticStep;
+  auth?: ProxyDiagnosticStep;
+  fetch?: ProxyDiagnosticStep;
 }
 
 export interface ProxyTestResult {
   success: boolean;
   latency_ms?: number;
   error?: string;
+  diagnostics?: ProxyDiagnostics | null;
 }
 
 // ---------------------------------------------------------------------------
@@ -120,6 +168,21 @@ export async function setProviderProxyStrategy(request: SetProviderProxyRequest)
   }
 }
 
+/**
+ * Set or clear the per-host proxy routing rules.
+ */
+export async function setProxyRoutes(request: SetProxyRoutesRequest): Promise<CommandResponse<boolean>> {
+  try {
+    return await invoke<CommandResponse<boolean>>('set_proxy_routes', { request });
+  } catch (error) {
+    return {
+      success: false,
+      data: null,
+      error: error instanceof Error ? error.message : String(error),
+    };
+  }
+}
+
 /**
  * Test proxy connectivity.
  */
@@ -106,6 +106,38 @@ export interface ImportResult {
   errors: string[];
 }
 
+/** Selectable part of a portable settings bundle */
+export type SettingsScope = 'providers' | 'mcp_servers' | 'guardrails';
+
+/** How an imported bundle combines with existing settings */
+export type SettingsImportMode = 'merge' | 'replace';
+
+/** Portable, scope-selectable settings bundle */
+export interface PortableSettingsBundle {
+  schema_version: number;
+  exported_at: string;
+  scopes: SettingsScope[];
+  providers?: {
+    default_provider: string;
+    default_model: string;
+    model_by_provider: Record<string, string>;
+    glm_endpoint: string;
+    minimax_endpoint: string;
+    qwen_endpoint: string;
+    custom_provider_base_urls: Record<string, string>;
+    custom_provider_endpoints: Record<string, CustomProviderEndpoint[]>;
+    selected_custom_provider_endpoint_ids: Record<string, string>;
+  };
+  mcp_servers?: Record<string, unknown>[];
+  guardrails?: {
+    mode?: string;
+    rules: Record<string, unknown>[];
+  };
+  /** Locations of values replaced with "[REDACTED]" */
+  redacted: string[];
+  encrypted_secrets?: string;
+}
+
 // ============================================================================
 // Settings API Functions
 // ============================================================================
@@ -156,6 +188,28 @@ export async function setKnowledgeFeatureFlags(flags: KnowledgeFeatureFlags): Pr
   return result.data;
 }
 
+/**
+ * Get the parent environment variables Bash commands may inherit.
+ */
+export async function getBashEnvAllowlist(): Promise<string[]> {
+  const result = await invoke<CommandResponse<string[]>>('get_bash_env_allowlist');
+  if (!result.success || !result.data) {
+    throw new Error(result.error || 'Failed to get Bash environment allowlist');
+  }
+  return result.data;
+}
+
+/**
+ * Save the Bash environment allowlist. Pass `null` to restore the default.
+ */
+export async function setBashEnvAllowlist(names: string[] | null): Promise<string[]> {
+  const result = await invoke<CommandResponse<string[]>>('set_bash_env_allowlist', { names });
+  if (!result.success || !result.data) {
+    throw new Error(result.error || 'Failed to set Bash environment allowlist');
+  }
+  return result.data;
+}
+
 /**
  * Reset all settings (frontend + backend persisted state)
  */
@@ -209,6 +263,45 @@ export async function importAllSettings(exportJson: string, password: string | n
   return result.data;
 }
 
+/**
+ * Export selected settings scopes (all when empty) as a portable bundle.
+ * Secrets are redacted unless `includeSecrets` is set with a password to encrypt them.
+ */
+export async function exportSettings(
+  scopes: SettingsScope[] = [],
+  includeSecrets = false,
+  password: string | null = null,
+): Promise<PortableSettingsBundle> {
+  const result = await invoke<CommandResponse<PortableSettingsBundle>>('export_settings', {
+    scopes,
+    includeSecrets,
+    password,
+  });
+  if (!result.success || !result.data) {
+    throw new Error(result.error || 'Failed to export settings');
+  }
+  return result.data;
+}
+
+/**
+ * Import a portable settings bundle JSON string
+ */
+export async function importSettings(
+  bundleJson: string,
+  mode: SettingsImportMode = 'merge',
+  password: string | null = null,
+): Promise<ImportResult> {
+  const result = await invoke<CommandResponse<ImportResult>>('import_settings', {
+    bundleJson,
+    mode,
+    password,
+  });
+  if (!result.success || !result.data) {
+    throw new Error(result.error || 'Failed to import settings');
+  }
+  return result.data;
+}
+
 /**
  * Check if running in Tauri context
  */
@@ -225,5 +318,7 @@ export default {
   clearAllData,
   exportAllSettings,
   importAllSettings,
+  exportSettings,
+  importSettings,
   isTauriAvailable,
 };
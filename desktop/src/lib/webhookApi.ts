@@ -22,7 +22,8 @@ export type WebhookEventType =
   | 'TaskCancelled'
   | 'StoryComplete'
   | 'PrdComplete'
-  | 'ProgressMilestone';
+  | 'ProgressMilestone'
+  | 'BudgetAlert';
 
 export type DeliveryStatus = 'Pending' | 'Success' | 'Failed' | 'Retrying';
 
@@ -60,7 +60,8 @@ export interface EmbeddingState {
   setBatchSize: (batchSize: number) => void;
   setFallbackProvider: (fallbackProvider: string) => void;
   saveConfig: () => Promise<boolean>;
-  checkHealth: () => Promise<void>;
+  /** Check provider health; `force` bypasses the backend health cache. */
+  checkHealth: (force?: boolean) => Promise<void>;
   saveApiKey: (provider: string, apiKey: string) => Promise<boolean>;
   loadApiKey: (provider: string) => Promise<string | null>;
   addExcludedDir: (dir: string) => void;
@@ -236,7 +237,7 @@ export const useEmbeddingStore = create<EmbeddingState>()((set, get) => ({
     }
   },
 
-  checkHealth: async () => {
+  checkHealth: async (force = false) => {
     const state = get();
     set({ healthChecking: true, healthResult: null, error: null });
     try {
@@ -244,6 +245,7 @@ export const useEmbeddingStore = create<EmbeddingState>()((set, get) => ({
         provider: state.provider,
         model: state.model || undefined,
         base_url: state.baseUrl || undefined,
+        force_refresh: force || undefined,
       });
       if (result.success && result.data) {
         set({ healthChecking: false, healthResult: result.data });
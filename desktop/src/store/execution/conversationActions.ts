@@ -626,6 +626,7 @@ export function createConversationActions(deps: ConversationActionDeps): Convers
             executionId: standaloneExecutionId,
             kernelSessionId: kernelTranscript.rootSessionId,
             memoryAutoExtractEnabled: settingsSnapshot.memorySettings.autoExtractEnabled,
+            memorySessionSummaryEnabled: settingsSnapshot.memorySettings.sessionSummaryEnabled,
             memoryReviewMode: settingsSnapshot.memorySettings.reviewMode,
             memoryReviewAgentRef: settingsSnapshot.memorySettings.reviewAgentRef || null,
             memoryReviewBaseUrl: memoryReviewBaseUrl || null,
@@ -936,6 +937,7 @@ export function createConversationActions(deps: ConversationActionDeps): Convers
             executionId: standaloneExecutionId,
             kernelSessionId: kernelTranscript.rootSessionId,
             memoryAutoExtractEnabled: settingsSnapshot.memorySettings.autoExtractEnabled,
+            memorySessionSummaryEnabled: settingsSnapshot.memorySettings.sessionSummaryEnabled,
             memoryReviewMode: settingsSnapshot.memorySettings.reviewMode,
             memoryReviewAgentRef: settingsSnapshot.memorySettings.reviewAgentRef || null,
             memoryReviewBaseUrl: memoryReviewBaseUrl || null,
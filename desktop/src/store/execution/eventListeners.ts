@@ -9,6 +9,7 @@ import { useExecutionStore } from '../execution';
 import { useWorkflowKernelStore } from '../workflowKernel';
 import { formatToolArgs } from './messageDispatch';
 import { clearPendingDeltas, flushPendingDeltas, getPending, scheduleFlush } from './streamDeltas';
+import { StreamSequenceTracker } from './streamSequence';
 import {
   appendToBackgroundSession,
   findBackgroundSessionByTaskId,
@@ -29,10 +30,12 @@ import { selectStableConversationLines } from '../../lib/conversationUtils';
 let unlisteners: UnlistenFn[] = [];
 let listenerSetupVersion = 0;
 let lateEventDroppedCount = 0;
+const streamSequenceTracker = new StreamSequenceTracker();
 type ChatRuntimeSource = 'claude' | 'standalone';
 
 export function resetExecutionEventListenerState(): void {
   lateEventDroppedCount = 0;
+  streamSequenceTracker.reset();
 }
 
 export function cleanupExecutionEventListeners(): void {
@@ -42,10 +45,13 @@ export function cleanupExecutionEventListeners(): void {
   }
   unlisteners = [];
   lateEventDroppedCount = 0;
+  streamSequenceTracker.reset();
 }
 
 interface UnifiedEventPayload {
   type: string;
+  /** Per-execution sequence number stamped by the backend forwarder */
+  seq?: number;
   execution_id?: string;
   run_id?: string;
   run_dir?: string;
@@ -416,6 +422,21 @@ function handleUnifiedExecutionEvent(
   get: () => ExecutionState,
   set: (partial: Partial<ExecutionState>) => void,
 ) {
+  if (typeof payload.seq === 'number') {
+    const streamKey = payload.execution_id || payload.session_id || 'default';
+    const check = streamSequenceTracker.observe(streamKey, payload.seq);
+    if (check.kind === 'stale') {
+      return;
+    }
+    if (check.kind === 'gap') {
+      reportNonFatal('execution.streamSequenceGap', new Error(`Missed ${check.missing} stream event(s)`), {
+        stream: streamKey,
+        expected_seq: check.expected,
+        received_seq: check.received,
+      });
+    }
+  }
+
   const state = get();
   const isTerminalWhileCancelling =
     payload.type === 'complete' || payload.type === 'error' || payload.type === 'session_complete';
@@ -595,6 +616,7 @@ function handleUnifiedExecutionEvent(
               toolName: payload.tool_name!,
               arguments: payload.arguments || '{}',
               risk: (payload.risk || 'Dangerous') as 'ReadOnly' | 'SafeWrite' | 'Dangerous',
+              reason: payload.reason,
             });
           });
         }
@@ -620,6 +642,7 @@ function handleUnifiedExecutionEvent(
             toolName: payload.tool_name!,
             arguments: payload.arguments || '{}',
             risk: (payload.risk || 'Dangerous') as 'ReadOnly' | 'SafeWrite' | 'Dangerous',
+            reason: payload.reason,
           });
         });
       }
@@ -1388,6 +1411,32 @@ function handleUnifiedExecutionEvent(
       break;
     }
 
+    case 'knowledge_context_injected': {
+      const report = payload as unknown as {
+        chunks?: { source_document: string }[];
+        total_tokens?: number;
+        dropped_below_threshold?: number;
+        dropped_over_budget?: number;
+        dropped_over_chunk_limit?: number;
+      };
+      const sources = (report.chunks || []).map((c) => c.source_document);
+      get().addLog(
+        sources.length > 0
+          ? `Knowledge context: injected ${sources.length} chunk(s), ~${report.total_tokens || 0} tokens from ${[...new Set(sources)].join(', ')}`
+          : 'Knowledge context: no chunks cleared the relevance threshold',
+      );
+      const dropped =
+        (report.dropped_below_threshold || 0) +
+        (report.dropped_over_budget || 0) +
+        (report.dropped_over_chunk_limit || 0);
+      if (dropped > 0) {
+        get().addLog(
+          `Knowledge context: dropped ${report.dropped_below_threshold || 0} below threshold, ${report.dropped_over_budget || 0} over token budget, ${report.dropped_over_chunk_limit || 0} over chunk limit`,
+        );
+      }
+      break;
+    }
+
     case 'session_complete':
       if (payload.success !== undefined) {
         clearPermissionRequestsForSession(payload.session_id);
@@ -1595,6 +1644,7 @@ export async function setupExecutionEventListeners(
                 toolName: streamEvent.tool_name,
                 arguments: streamEvent.arguments,
                 risk: streamEvent.risk,
+                reason: streamEvent.reason,
               });
             });
             break;
@@ -1616,6 +1666,7 @@ export async function setupExecutionEventListeners(
               toolName: streamEvent.tool_name,
               arguments: streamEvent.arguments,
               risk: streamEvent.risk,
+              reason: streamEvent.reason,
             });
           });
           return;
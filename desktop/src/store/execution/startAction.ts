@@ -456,6 +456,7 @@ export function createStartAction(
           executionId: standaloneExecutionId,
           kernelSessionId: activeKernelRootSessionId,
           memoryAutoExtractEnabled: settings.memorySettings.autoExtractEnabled,
+          memorySessionSummaryEnabled: settings.memorySettings.sessionSummaryEnabled,
           memoryReviewMode: settings.memorySettings.reviewMode,
           memoryReviewAgentRef: settings.memorySettings.reviewAgentRef || null,
           memoryReviewBaseUrl: memoryReviewBaseUrl || null,
@@ -0,0 +1,28 @@
+import { describe, expect, it } from 'vitest';
+import { StreamSequenceTracker } from './streamSequence';
+
+describe('StreamSequenceTracker', () => {
+  it('accepts monotonically increasing sequence numbers', () => {
+    const tracker = new StreamSequenceTracker();
+    for (const seq of [1, 2, 3, 4]) {
+      expect(tracker.observe('exec-1', seq)).toEqual({ kind: 'in_order' });
+    }
+    expect(tracker.lastSeq('exec-1')).toBe(4);
+  });
+
+  it('detects a dropped event', () => {
+    const tracker = new StreamSequenceTracker();
+    tracker.observe('exec-1', 1);
+    tracker.observe('exec-1', 2);
+    expect(tracker.observe('exec-1', 4)).toEqual({ kind: 'gap', expected: 3, received: 4, missing: 1 });
+    expect(tracker.observe('exec-1', 3)).toEqual({ kind: 'stale', received: 3 });
+    expect(tracker.observe('exec-1', 5)).toEqual({ kind: 'in_order' });
+  });
+
+  it('tracks streams independently and baselines on first event', () => {
+    const tracker = new StreamSequenceTracker();
+    expect(tracker.observe('exec-1', 10)).toEqual({ kind: 'in_order' });
+    expect(tracker.observe('exec-2', 1)).toEqual({ kind: 'in_order' });
+    expect(tracker.observe('exec-1', 11)).toEqual({ kind: 'in_order' });
+  });
+});
@@ -0,0 +1,49 @@
+/**
+ * Gap detection for sequenced backend stream events.
+ *
+ * The backend stamps every forwarded `UnifiedStreamEvent` with a monotonically
+ * increasing `seq` per execution. A jump in `seq` means events were lost in
+ * transit and the rendered transcript may be incomplete.
+ */
+
+export type SequenceCheck =
+  | { kind: 'in_order' }
+  | { kind: 'gap'; expected: number; received: number; missing: number }
+  | { kind: 'stale'; received: number };
+
+const MAX_TRACKED_STREAMS = 64;
+
+export class StreamSequenceTracker {
+  private lastSeqByStream = new Map<string, number>();
+
+  /**
+   * Record `seq` for `streamKey` and classify it. The first event of a stream
+   * sets the baseline, so attaching mid-stream is not reported as a gap.
+   */
+  observe(streamKey: string, seq: number): SequenceCheck {
+    const last = this.lastSeqByStream.get(streamKey);
+    if (last !== undefined && seq <= last) {
+      return { kind: 'stale', received: seq };
+    }
+
+    this.lastSeqByStream.delete(streamKey);
+    this.lastSeqByStream.set(streamKey, seq);
+    if (this.lastSeqByStream.size > MAX_TRACKED_STREAMS) {
+      const oldest = this.lastSeqByStream.keys().next().value;
+      if (oldest !== undefined) this.lastSeqByStream.delete(oldest);
+    }
+
+    if (last === undefined || seq === last + 1) {
+      return { kind: 'in_order' };
+    }
+    return { kind: 'gap', expected: last + 1, received: seq, missing: seq - last - 1 };
+  }
+
+  lastSeq(streamKey: string): number | undefined {
+    return this.lastSeqByStream.get(streamKey);
+  }
+
+  reset(): void {
+    this.lastSeqByStream.clear();
+  }
+}
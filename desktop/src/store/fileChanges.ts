@@ -8,7 +8,13 @@
 import { create } from 'zustand';
 import { invoke } from '@tauri-apps/api/core';
 import type { CommandResponse } from '../lib/tauri';
-import type { RestoreExecutionResult, RestorePreviewItem, RestoredFile, TurnChanges } from '../types/fileChanges';
+import type {
+  RestoreExecutionResult,
+  RestorePreview,
+  RestorePreviewItem,
+  RestoredFile,
+  TurnChanges,
+} from '../types/fileChanges';
 
 // ============================================================================
 // State
@@ -47,6 +53,14 @@ interface FileChangesState {
     turnIndex: number,
     createSnapshot?: boolean,
   ) => Promise<RestoreExecutionResult | null>;
+  /** Atomically restore only the selected files; change records are kept. */
+  restoreFiles: (
+    sessionId: string,
+    projectRoot: string,
+    turnIndex: number,
+    paths: string[],
+    createSnapshot?: boolean,
+  ) => Promise<RestoreExecutionResult | null>;
   undoRestore: (sessionId: string, projectRoot: string, operationId: string) => Promise<RestoredFile[] | null>;
   restoreSingleFile: (sessionId: string, projectRoot: string, filePath: string, hash: string) => Promise<boolean>;
   truncateFromTurn: (sessionId: string, projectRoot: string, turnIndex: number) => Promise<void>;
@@ -108,13 +122,13 @@ export const useFileChangesStore = create<FileChangesState>((set, get) => ({
 
   previewRestoreToTurn: async (sessionId, projectRoot, turnIndex) => {
     try {
-      const resp = await invoke<CommandResponse<RestorePreviewItem[]>>('preview_restore_to_turn', {
+      const resp = await invoke<CommandResponse<RestorePreview>>('preview_restore_to_turn', {
         sessionId,
         projectRoot,
         turnIndex,
       });
       if (resp.success && resp.data) {
-        return resp.data;
+        return resp.data.files;
       }
       set({ error: resp.error ?? 'Restore preview failed' });
       return null;
@@ -143,6 +157,26 @@ export const useFileChangesStore = create<FileChangesState>((set, get) => ({
     }
   },
 
+  restoreFiles: async (sessionId, projectRoot, turnIndex, paths, createSnapshot = true) => {
+    try {
+      const resp = await invoke<CommandResponse<RestoreExecutionResult>>('restore_selected_files', {
+        sessionId,
+        projectRoot,
+        turnIndex,
+        paths,
+        createSnapshot,
+      });
+      if (resp.success && resp.data) {
+        return resp.data;
+      }
+      set({ error: resp.error ?? 'Restore failed' });
+      return null;
+    } catch (err) {
+      set({ error: String(err) });
+      return null;
+    }
+  },
+
   undoRestore: async (sessionId, projectRoot, operationId) => {
     try {
       const resp = await invoke<CommandResponse<RestoredFile[]>>('undo_restore', {
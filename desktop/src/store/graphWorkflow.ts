@@ -182,7 +182,7 @@ export const useGraphWorkflowStore = create<GraphWorkflowState>((set, get) => ({
     delete nodes[nodeId];
     // Remove edges referencing this node
     const edges = current.edges.filter((e) => {
-      if (e.edge_type === 'direct') {
+      if (e.edge_type === 'direct' || e.edge_type === 'error') {
         return e.from !== nodeId && e.to !== nodeId;
       }
       return e.from !== nodeId;
@@ -73,6 +73,7 @@ export type {
 
 export { useRecoveryStore, EXECUTION_MODE_LABELS } from './recovery';
 export type {
+  ExecutionKind,
   ExecutionMode,
   IncompleteTask,
   RestoredContext,
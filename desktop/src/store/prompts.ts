@@ -8,7 +8,13 @@
 import { create } from 'zustand';
 import { invoke } from '@tauri-apps/api/core';
 import i18n from '../i18n';
-import type { PromptTemplate, PromptCreateRequest, PromptUpdateRequest, CommandResponse } from '../types/prompt';
+import type {
+  PromptTemplate,
+  PromptCreateRequest,
+  PromptUpdateRequest,
+  PromptSortMode,
+  CommandResponse,
+} from '../types/prompt';
 
 interface PromptsState {
   /** List of all prompts */
@@ -42,11 +48,11 @@ interface PromptsState {
   pendingInsertContent: string | null;
 
   /** Actions */
-  fetchPrompts: (category?: string, search?: string) => Promise<void>;
+  fetchPrompts: (category?: string, search?: string, sort?: PromptSortMode) => Promise<void>;
   createPrompt: (req: PromptCreateRequest) => Promise<PromptTemplate | null>;
   updatePrompt: (id: string, req: PromptUpdateRequest) => Promise<PromptTemplate | null>;
   deletePrompt: (id: string) => Promise<boolean>;
-  recordUse: (id: string) => Promise<void>;
+  recordUse: (id: string, variables?: Record<string, string>) => Promise<void>;
   togglePin: (id: string) => Promise<void>;
 
   togglePanel: () => void;
@@ -73,7 +79,7 @@ export const usePromptsStore = create<PromptsState>((set, get) => ({
   selectedPrompt: null,
   pendingInsertContent: null,
 
-  fetchPrompts: async (category?: string, search?: string) => {
+  fetchPrompts: async (category?: string, search?: string, sort?: PromptSortMode) => {
     set({
       loading: true,
       error: null,
@@ -86,6 +92,7 @@ export const usePromptsStore = create<PromptsState>((set, get) => ({
         category: category !== undefined ? category : null,
         search: search || null,
         locale: i18n.language || null,
+        sort: sort ?? null,
       });
 
       if (response.success && response.data) {
@@ -157,9 +164,9 @@ export const usePromptsStore = create<PromptsState>((set, get) => ({
     }
   },
 
-  recordUse: async (id: string) => {
+  recordUse: async (id: string, variables?: Record<string, string>) => {
     try {
-      await invoke<CommandResponse<void>>('record_prompt_use', { id });
+      await invoke<CommandResponse<void>>('record_prompt_use', { id, variables: variables ?? null });
       // Update local state without full refetch
       set((state) => ({
         prompts: state.prompts.map((p) => (p.id === id ? { ...p, use_count: p.use_count + 1 } : p)),
@@ -26,6 +26,9 @@ export const EXECUTION_MODE_LABELS: Record<ExecutionMode, string> = {
   mega_plan: 'Mega Plan',
 };
 
+/** Kind of interrupted execution, matching Rust ExecutionKind */
+export type ExecutionKind = 'standalone' | 'graph' | 'sub_agent';
+
 /** An incomplete task detected by the recovery detector */
 export interface IncompleteTask {
   /** Unique execution identifier */
@@ -36,6 +39,8 @@ export interface IncompleteTask {
   name: string;
   /** Execution mode that was running */
   execution_mode: ExecutionMode;
+  /** Kind of execution, determining the resume path */
+  execution_kind: ExecutionKind;
   /** Current status when interrupted */
   status: string;
   /** Project path for the execution */
@@ -64,6 +69,7 @@ export interface IncompleteTask {
 export interface RestoredContext {
   execution_id: string;
   execution_mode: ExecutionMode;
+  execution_kind: ExecutionKind;
   project_path: string;
   name: string;
   completed_story_ids: string[];
@@ -83,6 +89,33 @@ export interface ResumeResult {
   events: ResumeEvent[];
 }
 
+/** A project file changed after the execution was interrupted */
+export interface ExternalFileChange {
+  path: string;
+  /** ISO 8601 modification time, null if the file was deleted */
+  modified_at: string | null;
+}
+
+/** Read-only preview of what resuming would restore */
+export interface ResumePreview {
+  execution_id: string;
+  execution_mode: ExecutionMode;
+  name: string;
+  project_path: string;
+  last_completed_story_id: string | null;
+  resume_from_story_id: string | null;
+  completed_story_ids: string[];
+  pending_story_ids: string[];
+  message_count: number;
+  context_keys: string[];
+  checkpoint_count: number;
+  latest_checkpoint_id: string | null;
+  interrupted_at: string | null;
+  externally_modified_files: ExternalFileChange[];
+  resumable: boolean;
+  warnings: string[];
+}
+
 /** Resume event for progress tracking */
 export interface ResumeEvent {
   type: 'Started' | 'ContextRestored' | 'StorySkipped' | 'Resuming' | 'Completed' | 'Error';
@@ -130,6 +163,9 @@ export interface RecoveryState {
   /** Detect incomplete tasks by scanning the database */
   detectIncompleteTasks: () => Promise<IncompleteTask[]>;
 
+  /** Preview what resuming a task would restore, without resuming it */
+  previewResume: (taskId: string) => Promise<ResumePreview | null>;
+
   /** Resume an interrupted task */
   resumeTask: (taskId: string) => Promise<ResumeResult | null>;
 
@@ -232,6 +268,26 @@ export const useRecoveryStore = create<RecoveryState>()((set, get) => ({
     }
   },
 
+  previewResume: async (taskId: string) => {
+    set({ error: null });
+
+    try {
+      const result = await invoke<CommandResponse<ResumePreview>>('preview_resume_task', {
+        taskId,
+      });
+
+      if (result.success && result.data) {
+        return result.data;
+      } else {
+        throw new Error(result.error || 'Failed to preview resume');
+      }
+    } catch (error) {
+      const errorMessage = error instanceof Error ? error.message : 'Unknown error';
+      set({ error: errorMessage });
+      return null;
+    }
+  },
+
   discardTask: async (taskId: string) => {
     set({ error: null });
 
@@ -76,6 +76,7 @@ describe('settings store migration', () => {
 
     expect(state.phaseConfigs.implementation.fallbackChain).toEqual(['codex', 'claude-code']);
     expect(state.memorySettings.autoExtractEnabled).toBe(true);
+    expect(state.memorySettings.sessionSummaryEnabled).toBe(false);
     expect(state.memorySettings.reviewMode).toBe('llm_review');
     expect(state.memorySettings.reviewAgentRef).toBe('');
     expect(state.developerModeEnabled).toBe(false);
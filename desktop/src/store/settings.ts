@@ -183,6 +183,7 @@ export interface PhaseAgentConfig {
 
 export interface MemorySettings {
   autoExtractEnabled: boolean;
+  sessionSummaryEnabled: boolean;
   reviewMode: MemoryReviewMode;
   reviewAgentRef: string;
   injectActiveOnly: true;
@@ -501,6 +502,7 @@ const defaultSettings = {
   // Memory pipeline
   memorySettings: {
     autoExtractEnabled: true,
+    sessionSummaryEnabled: false,
     reviewMode: 'llm_review' as MemoryReviewMode,
     reviewAgentRef: '',
     injectActiveOnly: true as const,
@@ -578,6 +580,10 @@ function ensureMemorySettings(settings: Partial<SettingsState>): MemorySettings
       typeof current.autoExtractEnabled === 'boolean'
         ? current.autoExtractEnabled
         : defaultSettings.memorySettings.autoExtractEnabled,
+    sessionSummaryEnabled:
+      typeof current.sessionSummaryEnabled === 'boolean'
+        ? current.sessionSummaryEnabled
+        : defaultSettings.memorySettings.sessionSummaryEnabled,
     reviewMode:
       current.reviewMode === 'auto_approve' ||
       current.reviewMode === 'manual_only' ||
@@ -119,6 +119,8 @@ export interface TaskStory {
   priority: string;
   dependencies: string[];
   acceptanceCriteria: string[];
+  /** Locked stories are kept unchanged when the PRD is regenerated */
+  locked?: boolean;
 }
 
 /** Execution batch */
@@ -129,11 +131,15 @@ export interface ExecutionBatch {
 }
 
 /** Task PRD */
+/** PRD-level section that can be locked against regeneration */
+export type TaskPrdSection = 'title' | 'description';
+
 export interface TaskPrd {
   title: string;
   description: string;
   stories: TaskStory[];
   batches: ExecutionBatch[];
+  lockedSections?: TaskPrdSection[];
 }
 
 export interface PrdFeedbackApplySummary {
@@ -153,6 +159,10 @@ export interface TaskWorkflowConfigPayload {
   qualityRetryMaxAttempts: number | null;
   customQualityGates: import('../types/workflowQuality').QualityCustomGate[];
   maxParallel: number;
+  /** Cap on story attempts running at once across all batches */
+  maxConcurrentStories?: number | null;
+  /** Total retries shared by all stories */
+  retryBudget?: number | null;
   skipVerification: boolean;
   skipReview: boolean;
   globalAgentOverride: string | null;
@@ -188,6 +198,16 @@ export interface BatchExecutionProgress {
   totalStories: number;
   storyStatuses: Record<string, string>;
   currentPhase: string;
+  activeConcurrency?: number;
+  retryBudgetRemaining?: number | null;
+}
+
+/** How the latest exploration result was obtained from the exploration cache */
+export interface ExplorationCacheStatus {
+  outcome: 'reused' | 'incremental' | 'refreshed' | 'uncached';
+  signature: string | null;
+  changedFiles: string[];
+  cachedAt: string | null;
 }
 
 /** Task execution status from Rust backend */
@@ -199,6 +219,9 @@ export interface TaskExecutionStatus {
   storyStatuses: Record<string, string>;
   storiesCompleted: number;
   storiesFailed: number;
+  activeConcurrency?: number;
+  retryBudgetRemaining?: number | null;
+  explorationCache?: ExplorationCacheStatus | null;
 }
 
 /** Execution report from Rust backend */
@@ -297,6 +320,7 @@ export interface TaskModeState {
     overrideModel?: string,
     overrideBaseUrl?: string,
     sessionId?: string | null,
+    previousPrd?: TaskPrd | null,
   ) => Promise<TaskPrd | null>;
   approvePrd: (
     prd: TaskPrd,
@@ -423,6 +447,8 @@ export const useTaskModeStore = create<TaskModeState>()((set, get) => ({
             qualityRetryMaxAttempts: workflowConfig.qualityRetryMaxAttempts,
             customQualityGates: workflowConfig.customQualityGates,
             maxParallel: workflowConfig.maxParallel,
+            maxConcurrentStories: workflowConfig.maxConcurrentStories ?? null,
+            retryBudget: workflowConfig.retryBudget ?? null,
             skipVerification: workflowConfig.skipVerification,
             skipReview: workflowConfig.skipReview,
             globalAgentOverride: workflowConfig.globalAgentOverride,
@@ -451,6 +477,7 @@ export const useTaskModeStore = create<TaskModeState>()((set, get) => ({
     overrideModel?: string,
     overrideBaseUrl?: string,
     sessionId?: string | null,
+    previousPrd?: TaskPrd | null,
   ) => {
     const resolvedSessionId = resolveSessionId(sessionId);
     if (!resolvedSessionId) {
@@ -484,6 +511,7 @@ export const useTaskModeStore = create<TaskModeState>()((set, get) => ({
           locale: i18n.language || null,
           contextSources,
           projectPath: settingsStore.workspacePath || null,
+          previousPrd: previousPrd ?? null,
         },
       });
       if (get()._requestId !== requestId) return null;
@@ -540,6 +568,8 @@ export const useTaskModeStore = create<TaskModeState>()((set, get) => ({
                 qualityRetryMaxAttempts: workflowConfig.qualityRetryMaxAttempts,
                 customQualityGates: workflowConfig.customQualityGates,
                 maxParallel: workflowConfig.maxParallel,
+                maxConcurrentStories: workflowConfig.maxConcurrentStories ?? null,
+                retryBudget: workflowConfig.retryBudget ?? null,
                 skipVerification: workflowConfig.skipVerification,
                 skipReview: workflowConfig.skipReview,
                 globalAgentOverride: workflowConfig.globalAgentOverride,
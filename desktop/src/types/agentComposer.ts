@@ -17,8 +17,26 @@ export interface AgentConfig {
   enable_compaction: boolean;
   /** LLM temperature setting */
   temperature: number | null;
+  /** Nucleus sampling (top_p) setting */
+  top_p?: number | null;
+  /** Sampling seed for reproducible runs (ignored by providers without seed support) */
+  seed?: number | null;
+  /** How a parallel step combines its branches' final outputs (default: concat) */
+  merge_strategy?: MergeStrategy;
+  /** What a sequential step does when a sub-step fails (default: abort) */
+  on_step_failure?: StepFailurePolicy;
 }
 
+/** Failure policy for sequential steps */
+export type StepFailurePolicy = 'abort' | 'continue' | { retry: number };
+
+/** Strategy for combining parallel branch outputs */
+export type MergeStrategy =
+  | { type: 'concat' }
+  | { type: 'llm_synthesize'; instruction?: string }
+  | { type: 'first_success' }
+  | { type: 'custom'; reducer: string };
+
 /** Default agent configuration */
 export const DEFAULT_AGENT_CONFIG: AgentConfig = {
   soft_limit_override: null,
@@ -105,11 +123,15 @@ export type AgentEvent =
   | { type: 'agent_transfer'; target: string; message: string }
   | { type: 'graph_node_started'; node_id: string }
   | { type: 'graph_node_completed'; node_id: string; output?: string | null }
+  | { type: 'graph_node_failed'; node_id: string; error: string; handler: string }
   | { type: 'human_review_required'; node_id: string; context: string }
   | { type: 'rich_content'; component_type: string; data: unknown; surface_id?: string }
   | { type: 'actions'; actions: unknown }
   | { type: 'completed'; run_id: string; output: string; duration_ms: number }
   | { type: 'failed'; run_id: string; error: string; duration_ms: number }
+  | { type: 'step_completed'; agent: string; index: number; output?: string }
+  | { type: 'step_failed'; agent: string; index: number; attempt: number; error: string; will_retry: boolean }
+  | { type: 'sequence_aborted'; failed_step: string; error: string; partial_outputs: string[] }
   | { type: 'cancelled'; run_id: string; duration_ms: number }
   | { type: 'usage'; input_tokens: number; output_tokens: number }
   | { type: 'done'; output: string | null };
@@ -53,6 +53,8 @@ export interface CheckEmbeddingHealthRequest {
   provider: string;
   model?: string;
   base_url?: string;
+  /** Skip the backend health cache and run a fresh check. */
+  force_refresh?: boolean;
 }
 
 /** Request payload for `get_embedding_api_key`. */
@@ -92,6 +94,12 @@ export interface EmbeddingHealthResponse {
   healthy: boolean;
   message: string;
   latency_ms?: number;
+  /** When the check ran (RFC 3339). */
+  checked_at?: string;
+  /** Served from the backend health cache. */
+  cached: boolean;
+  /** Cached result past its TTL while a background refresh runs. */
+  stale: boolean;
 }
 
 /** Response from `set_embedding_api_key`. */
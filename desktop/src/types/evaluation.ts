@@ -73,6 +73,12 @@ export interface ModelConfig {
   model: string;
   /** Optional display name for UI */
   display_name?: string | null;
+  /** Optional temperature override */
+  temperature?: number | null;
+  /** Optional top_p override */
+  top_p?: number | null;
+  /** Sampling seed; the runner uses a fixed default when unset */
+  seed?: number | null;
 }
 
 /** An evaluation run executing cases across multiple models */
@@ -41,12 +41,27 @@ export interface RestoredFile {
 /** Preview row for restore operation. */
 export interface RestorePreviewItem {
   path: string;
-  action: 'restore' | 'delete';
+  action: 'restore' | 'create' | 'delete';
   source_turn: number;
+  current_hash: string | null;
+  target_hash: string | null;
+  /** Unified diff from the on-disk content to the restored content. */
+  diff: string;
+  /** On-disk content changed outside the tracker since the last recorded change. */
+  externally_modified: boolean;
+}
+
+/** Full restore preview with per-file diffs. */
+export interface RestorePreview {
+  files: RestorePreviewItem[];
+  created: string[];
+  deleted: string[];
+  externally_modified: string[];
 }
 
 /** Restore execution result with optional undo handle. */
 export interface RestoreExecutionResult {
   operation_id: string | null;
   restored: RestoredFile[];
+  externally_modified: string[];
 }
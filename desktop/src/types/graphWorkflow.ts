@@ -48,7 +48,9 @@ export type Edge =
       condition: ConditionConfig;
       branches: Record<string, string>;
       default_branch: string | null;
-    };
+    }
+  /** Taken only when `from` fails; the error is written to the `__error` channel */
+  | { edge_type: 'error'; from: string; to: string };
 
 /** Configuration for a conditional edge */
 export interface ConditionConfig {
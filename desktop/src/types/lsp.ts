@@ -31,6 +31,12 @@ export interface EnrichmentReport {
   symbols_enriched: number;
   references_found: number;
   duration_ms: number;
+  /** Changed files re-enriched by the latest incremental pass */
+  files_enriched?: string[];
+  /** Files re-enriched because they reference a changed file */
+  dependents_enriched?: string[];
+  /** Files whose enrichment is out of date */
+  stale_files?: string[];
 }
 
 /** Language display metadata (for the UI) */
@@ -38,6 +38,73 @@ export interface FileMetadata {
   modified_at: string | null;
   /** Creation timestamp (ISO 8601) */
   created_at: string | null;
+  /** Tool-managed sections found in the file */
+  managed_sections: ManagedSectionInfo[];
+}
+
+/** A tool-managed section of a CLAUDE.md file */
+export interface ManagedSectionInfo {
+  /** Section id from the sentinel comment */
+  id: string;
+  /** Whether the section was edited since it was generated */
+  edited: boolean;
+  /** Whether the section holds unresolved merge conflict markers */
+  conflicted: boolean;
+}
+
+/** How `save_claude_md` writes content */
+export type ClaudeMdWriteMode = 'overwrite' | 'merge';
+
+/** Outcome of a merge-mode save */
+export interface ClaudeMdMergeReport {
+  /** Managed sections in the merged file, in order */
+  managed_sections: string[];
+  /** Managed sections that were edited by hand and now hold conflict markers */
+  conflicts: string[];
+  /** Generated sections that were not in the file before */
+  added: string[];
+  /** Unedited managed sections that are no longer generated */
+  removed: string[];
+}
+
+/** Where a CLAUDE.md file sits in the instruction hierarchy */
+export type ClaudeMdScope = 'user' | 'project' | 'directory';
+
+/** How a section combines with the same section from farther files */
+export type ClaudeMdMergeMode = 'override' | 'append';
+
+/** A CLAUDE.md file that contributed to the effective instructions */
+export interface ClaudeMdSource {
+  /** Full path to the file */
+  path: string;
+  /** Position in the hierarchy */
+  scope: ClaudeMdScope;
+}
+
+/** One section of the effective instructions */
+export interface EffectiveClaudeMdSection {
+  /** Section heading (empty for text before the first heading) */
+  heading: string;
+  /** Merged section body */
+  content: string;
+  /** Merge mode declared by the nearest file defining the section */
+  mode: ClaudeMdMergeMode;
+  /** Files whose content is included, farthest first */
+  sources: string[];
+  /** Files whose content was replaced by a nearer file */
+  overridden: string[];
+}
+
+/** Effective instructions for a working directory */
+export interface EffectiveClaudeMd {
+  /** Directory the hierarchy was resolved from */
+  working_dir: string;
+  /** Contributing files, farthest first */
+  sources: ClaudeMdSource[];
+  /** Merged sections in document order */
+  sections: EffectiveClaudeMdSection[];
+  /** Merged instructions as markdown */
+  content: string;
 }
 
 /** Result of a save operation */
@@ -48,6 +115,8 @@ export interface SaveResult {
   path: string;
   /** Error message if save failed */
   error: string | null;
+  /** Merge details when saved in merge mode */
+  merge?: ClaudeMdMergeReport;
 }
 
 /** View mode for the markdown editor */
@@ -17,6 +17,8 @@ export interface ToolPermissionRequest {
   toolName: string;
   arguments: string;
   risk: ToolRiskLevel;
+  /** Policy reason for the prompt (e.g. why a command was classified dangerous) */
+  reason?: string;
 }
 
 /** Response type for permission decisions */
@@ -4,6 +4,16 @@
  * TypeScript types mirroring the Rust PromptTemplate model.
  */
 
+/** Declared schema for a {{variable}} placeholder */
+export interface PromptVariable {
+  name: string;
+  description?: string | null;
+  /** Value used when the caller does not supply one */
+  default?: string | null;
+  /** Whether rendering fails when no value (and no default) is given */
+  required: boolean;
+}
+
 export interface PromptTemplate {
   id: string;
   title: string;
@@ -12,14 +22,20 @@ export interface PromptTemplate {
   category: string;
   tags: string[];
   variables: string[];
+  variable_schema: PromptVariable[];
   is_builtin: boolean;
   is_pinned: boolean;
   use_count: number;
   last_used_at: string | null;
+  /** Recency-weighted usage; only computed by the `usage` sort mode */
+  usage_score?: number;
   created_at: string | null;
   updated_at: string | null;
 }
 
+/** Ordering for `list_prompts`; pinned prompts always come first */
+export type PromptSortMode = 'use_count' | 'usage' | 'title';
+
 export interface PromptCreateRequest {
   title: string;
   content: string;
@@ -27,6 +43,7 @@ export interface PromptCreateRequest {
   category: string;
   tags: string[];
   is_pinned: boolean;
+  variable_schema?: PromptVariable[];
 }
 
 export interface PromptUpdateRequest {
@@ -36,6 +53,15 @@ export interface PromptUpdateRequest {
   category?: string;
   tags?: string[];
   is_pinned?: boolean;
+  variable_schema?: PromptVariable[];
+}
+
+/** A prompt with its variables substituted */
+export interface RenderedPrompt {
+  prompt_id: string;
+  content: string;
+  /** Value used for each variable, defaults included */
+  variables: Record<string, string>;
 }
 
 export interface CommandResponse<T> {
@@ -66,6 +66,8 @@ export interface SkillDocument {
   license: string | null;
   metadata: Record<string, string>;
   hooks: SkillHooks | null;
+  requires?: string[];
+  conflicts_with?: string[];
   source: SkillSource;
   priority: number;
   detect: SkillDetection | null;
@@ -97,17 +99,26 @@ export interface SkillDetection {
   patterns: string[];
 }
 
+/** Signals behind a match (detect rules that fired, or lexical terms) */
+export interface MatchExplanation {
+  matched_files: string[];
+  matched_patterns: string[];
+  matched_terms: string[];
+}
+
 /** Matched skill with relevance info */
 export interface SkillMatch {
   score: number;
   match_reason: MatchReason;
+  explanation?: MatchExplanation | null;
   skill: SkillSummary;
 }
 
 export type MatchReason =
   | { type: 'auto_detected' }
   | { type: 'lexical_match'; query: string }
-  | { type: 'user_forced' };
+  | { type: 'user_forced' }
+  | { type: 'required'; by: string };
 
 /** Skill index statistics */
 export interface SkillIndexStats {
@@ -122,10 +133,18 @@ export interface SkillIndexStats {
 }
 
 /** Overview returned by get_skills_overview */
+export interface IndexRefreshStats {
+  reparsed: number;
+  reused: number;
+  removed: number;
+}
+
 export interface SkillsOverview {
   stats: SkillIndexStats;
   detected_skills: SkillSummary[];
   sources: string[];
+  last_refreshed_at: string | null;
+  last_refresh: IndexRefreshStats | null;
 }
 
 export interface SkillSourceInfo {
@@ -512,6 +512,14 @@ export interface TurnChangeSummaryCardData {
 // Persona & New Phase Card Data
 // ============================================================================
 
+/** Source hash and timestamp of a cached analysis result */
+export interface AnalysisCacheInfo {
+  sourceHash: string;
+  cachedAt: string;
+  /** Served from the cache rather than a fresh LLM pass */
+  reused: boolean;
+}
+
 /** Requirement analysis card data (ProductManager persona) */
 export interface RequirementAnalysisCardData {
   personaRole: string;
@@ -519,6 +527,7 @@ export interface RequirementAnalysisCardData {
   keyRequirements: string[];
   identifiedGaps: string[];
   suggestedScope: string;
+  cache?: AnalysisCacheInfo;
 }
 
 /** Architecture review card data (SoftwareArchitect persona) */
@@ -560,6 +569,7 @@ export interface ArchitectureReviewCardData {
     confidence: number;
   }>;
   approved: boolean;
+  cache?: AnalysisCacheInfo;
 }
 
 /** Persona indicator badge data */